@@ -0,0 +1,13 @@
+//! Feed parser fuzz target: any byte string must come back as a parsed feed
+//! or a `FeedError`, never a panic
+//!
+//! Run with `cargo +nightly fuzz run parse_feed_bytes` from the repo root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use podcast_tui::podcast::parse_feed_bytes;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_feed_bytes(data);
+});
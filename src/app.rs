@@ -1,9 +1,11 @@
-use crate::ui::UIApp;
+use crate::ui::{events::AppEvent, UIApp};
 use crate::{
     audio::manager::AudioManager,
+    crash::{self, CrashContext},
     download::DownloadManager,
-    podcast::subscription::SubscriptionManager,
-    storage::{JsonStorage, Storage},
+    podcast::{subscription::SubscriptionManager, FeedAuth},
+    storage::{JsonStorage, SecretStore, Storage, StorageAccess, StorageLock},
+    utils::{http::ReqwestClient, user_agent::UserAgentPolicy},
     Config,
 };
 use anyhow::Result;
@@ -14,64 +16,66 @@ use tokio::sync::mpsc;
 pub struct App {
     config: Config,
     ui: UIApp,
+    /// Read for the MPRIS track metadata
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    storage: Arc<JsonStorage>,
+    /// Held for as long as the app runs
+    _lock: Option<StorageLock>,
 }
 
 impl App {
     /// Create a new application instance
     pub async fn new(config: Config) -> Result<Self> {
-        Self::new_with_progress(config, tokio::sync::mpsc::unbounded_channel().0).await
+        Self::new_with_progress(
+            config,
+            StorageAccess::default(),
+            tokio::sync::mpsc::unbounded_channel().0,
+        )
+        .await
     }
 
     /// Create a new application instance with progress reporting
     pub async fn new_with_progress(
         config: Config,
+        access: StorageAccess,
         status_tx: tokio::sync::mpsc::UnboundedSender<crate::InitStatus>,
     ) -> Result<Self> {
-        // Initialize storage
         status_tx.send(crate::InitStatus::InitializingStorage).ok();
-        let storage = if let Some(data_dir) = &config.storage.data_directory {
-            JsonStorage::with_data_dir(data_dir.into())
-        } else {
-            JsonStorage::new()?
-        };
+        let services = Services::start(&config, access, "tui").await?;
+        let storage = services.storage.clone();
 
-        // Initialize storage directories
-        storage.initialize().await?;
-        let storage = Arc::new(storage);
-
-        // Create download manager with configured downloads directory
-        let downloads_dir = shellexpand::tilde(&config.downloads.directory)
-            .into_owned()
-            .into();
-        let download_manager = Arc::new(DownloadManager::new(
-            storage.clone(),
-            downloads_dir,
-            config.downloads.clone(),
-        )?);
-
-        // Create subscription manager with download manager for automatic cleanup
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
+        // From here on a panic restores the terminal and leaves a crash report
+        let crash_context = CrashContext::new();
+        crash::install(services.storage.data_dir.clone(), crash_context.clone());
 
         // Create app event channel for async communication
         let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
 
         // Initialize UI with config and managers (with progress updates)
         status_tx.send(crate::InitStatus::CreatingBuffers).ok();
-        let ui = UIApp::new_with_progress(
+        let mut ui = UIApp::new_with_progress(
             config.clone(),
-            subscription_manager.clone(),
-            download_manager.clone(),
-            storage.clone(),
+            services.subscription_manager,
+            services.download_manager,
+            services.storage,
             app_event_tx,
             status_tx,
         )
         .await
         .map_err(|e| anyhow::anyhow!("Failed to initialize UI: {e}"))?;
+        ui.set_crash_context(crash_context);
+
+        Ok(Self {
+            config,
+            ui,
+            storage,
+            _lock: services.lock,
+        })
+    }
 
-        Ok(Self { config, ui })
+    /// Problems found in the config file, reported once the UI starts
+    pub fn set_config_issues(&mut self, issues: Vec<crate::config::ConfigIssue>) {
+        self.ui.set_config_issues(issues);
     }
 
     /// Run the main application loop
@@ -79,21 +83,30 @@ impl App {
         // Create app event channel for async communication
         let (app_event_tx, app_event_rx) = mpsc::unbounded_channel();
 
-        // Create AudioManager with graceful fallback when audio init fails
-        let audio_manager = match AudioManager::new(&self.config.audio, app_event_tx.clone()) {
-            Ok(mgr) => {
-                eprintln!("[audio] AudioManager initialized");
-                Some(mgr)
-            }
-            Err(e) => {
-                eprintln!("[audio] Audio init failed: {e}. Playback disabled.");
-                None
-            }
-        };
+        // Probe the audio backends; without one, playback is disabled and the
+        // UI says why as soon as it starts
+        let (audio_manager, audio_diagnostics) =
+            AudioManager::start(&self.config.audio, app_event_tx.clone());
+        if let Some(message) = audio_diagnostics.startup_warning() {
+            let _ = app_event_tx.send(AppEvent::AudioStartupWarning { message });
+        }
+        self.ui.set_audio_diagnostics(audio_diagnostics);
 
         let audio_command_tx = audio_manager.as_ref().map(|m| m.command_tx());
         let playback_status_rx = audio_manager.as_ref().map(|m| m.subscribe());
 
+        // Media keys and desktop panels; without a session bus this just ends
+        #[cfg(target_os = "linux")]
+        if self.config.audio.mpris {
+            if let (Some(tx), Some(rx)) = (&audio_command_tx, &playback_status_rx) {
+                tokio::spawn(crate::audio::mpris::serve(
+                    self.storage.clone(),
+                    tx.clone(),
+                    rx.clone(),
+                ));
+            }
+        }
+
         // Wire new event channel into existing UI (instead of recreating it)
         self.ui.set_app_event_tx(app_event_tx.clone());
 
@@ -113,3 +126,72 @@ impl App {
             .map_err(|e| anyhow::anyhow!("UI error: {e}"))
     }
 }
+
+/// Storage and the managers over it, shared by the TUI and `podcast-tui daemon`
+pub struct Services {
+    pub storage: Arc<JsonStorage>,
+    pub download_manager: Arc<DownloadManager<JsonStorage>>,
+    pub subscription_manager: Arc<SubscriptionManager<JsonStorage>>,
+    /// The data directory's lock; `None` when opened read-only
+    pub lock: Option<StorageLock>,
+}
+
+impl Services {
+    /// Open (creating if needed) the configured data directory as `role`
+    /// (`tui` or `daemon`), locking it unless `access` is read-only, and
+    /// build the download and subscription managers over it
+    pub async fn start(config: &Config, access: StorageAccess, role: &str) -> Result<Self> {
+        let storage = if let Some(data_dir) = &config.storage.data_directory {
+            JsonStorage::with_data_dir(data_dir.into())
+        } else {
+            JsonStorage::new()?
+        };
+        let (storage, lock) = match access {
+            StorageAccess::ReadOnly => (storage.read_only(), None),
+            StorageAccess::Exclusive | StorageAccess::Force => {
+                let force = access == StorageAccess::Force;
+                let lock = StorageLock::acquire(&storage.data_dir, role, force)?;
+                (storage, Some(lock))
+            }
+        };
+
+        // Initialize storage directories
+        storage.initialize().await?;
+        let storage = Arc::new(storage);
+
+        // Create download manager with configured downloads directory
+        let downloads_dir = shellexpand::tilde(&config.downloads.directory)
+            .into_owned()
+            .into();
+        let user_agents = UserAgentPolicy::from_config(&config.network);
+        let download_manager = Arc::new(
+            DownloadManager::new(storage.clone(), downloads_dir, config.downloads.clone())?
+                .with_user_agent_policy(user_agents.clone())
+                .with_transcription_config(config.transcription.clone()),
+        );
+
+        // Create subscription manager with download manager for automatic cleanup
+        let mut subscription_manager =
+            SubscriptionManager::with_download_manager(storage.clone(), download_manager.clone())
+                .with_user_agent_policy(user_agents);
+        if !config.network.oauth_providers.is_empty() {
+            let http_client = reqwest::Client::builder()
+                .user_agent(crate::constants::network::USER_AGENT)
+                .timeout(crate::constants::network::HTTP_TIMEOUT)
+                .build()?;
+            subscription_manager = subscription_manager.with_feed_auth(Arc::new(FeedAuth::new(
+                config.network.oauth_providers.clone(),
+                SecretStore::new(&storage.data_dir),
+                Arc::new(ReqwestClient::new(http_client)),
+            )));
+        }
+        let subscription_manager = Arc::new(subscription_manager);
+
+        Ok(Self {
+            storage,
+            download_manager,
+            subscription_manager,
+            lock,
+        })
+    }
+}
@@ -0,0 +1,729 @@
+// Chromecast renderers — mDNS discovery and the CASTV2 control protocol.
+//
+// Discovery sends a one-shot mDNS query for `_googlecast._tcp.local` and
+// reads the PTR/SRV/TXT/A records in the answers. Control is the CASTV2
+// protocol: length-prefixed protobuf `CastMessage` frames with JSON payloads
+// over TLS on port 8009. Devices present self-signed certificates, so the
+// certificate chain is not verified (handshake signatures still are).
+//
+// Playback uses the Default Media Receiver app, which fetches the episode
+// from our media endpoint like any other renderer.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream, UdpSocket};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, ClientConnection, DigitallySignedStruct, SignatureScheme, StreamOwned};
+use serde_json::{json, Value};
+
+use super::{CastEndpoint, CastKind, CastTarget, RemoteState, RemoteStatus, Renderer};
+use crate::audio::AudioError;
+
+const MDNS_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 251)), 5353);
+const SERVICE: &str = "_googlecast._tcp.local";
+const DNS_PTR: u16 = 12;
+const DNS_TXT: u16 = 16;
+const DNS_A: u16 = 1;
+const DNS_SRV: u16 = 33;
+
+const NS_CONNECTION: &str = "urn:x-cast:com.google.cast.tp.connection";
+const NS_HEARTBEAT: &str = "urn:x-cast:com.google.cast.tp.heartbeat";
+const NS_RECEIVER: &str = "urn:x-cast:com.google.cast.receiver";
+const NS_MEDIA: &str = "urn:x-cast:com.google.cast.media";
+const SENDER_ID: &str = "sender-0";
+const RECEIVER_ID: &str = "receiver-0";
+const DEFAULT_MEDIA_RECEIVER: &str = "CC1AD845";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest wait for a reply; launching the receiver app is the slow one
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// Largest frame accepted from a device
+const MAX_FRAME: usize = 64 * 1024;
+
+/// Ask the local network for Chromecasts and collect the answers
+pub fn discover(timeout: Duration) -> Vec<CastTarget> {
+    match query(timeout) {
+        Ok(targets) => targets,
+        Err(e) => {
+            eprintln!("Chromecast discovery failed: {e}");
+            Vec::new()
+        }
+    }
+}
+
+fn query(timeout: Duration) -> std::io::Result<Vec<CastTarget>> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    socket.send_to(&mdns_query(SERVICE), MDNS_ADDR)?;
+
+    let deadline = Instant::now() + timeout;
+    let mut targets: Vec<CastTarget> = Vec::new();
+    let mut buf = [0u8; 9000];
+    while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+        socket.set_read_timeout(Some(left.max(Duration::from_millis(1))))?;
+        let Ok((len, from)) = socket.recv_from(&mut buf) else {
+            break;
+        };
+        for target in parse_mdns_response(&buf[..len], from.ip()) {
+            if !targets
+                .iter()
+                .any(|known| known.endpoint == target.endpoint)
+            {
+                targets.push(target);
+            }
+        }
+    }
+    Ok(targets)
+}
+
+/// DNS query for the PTR records of `service`, asking for a unicast reply
+fn mdns_query(service: &str) -> Vec<u8> {
+    let mut packet = vec![0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0];
+    for label in service.split('.') {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&DNS_PTR.to_be_bytes());
+    // Class IN with the unicast-response bit
+    packet.extend_from_slice(&0x8001u16.to_be_bytes());
+    packet
+}
+
+/// Read a possibly compressed DNS name at `offset`; returns it and the offset
+/// just past it in the record
+fn read_name(packet: &[u8], mut offset: usize) -> Option<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    // Bounds pointer chains, so a looping packet can't hang discovery
+    for _ in 0..64 {
+        let len = *packet.get(offset)? as usize;
+        match len {
+            0 => {
+                return Some((labels.join("."), end.unwrap_or(offset + 1)));
+            }
+            l if l & 0xC0 == 0xC0 => {
+                let pointer = ((l & 0x3F) << 8) | *packet.get(offset + 1)? as usize;
+                end.get_or_insert(offset + 2);
+                offset = pointer;
+            }
+            l => {
+                let label = packet.get(offset + 1..offset + 1 + l)?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                offset += 1 + l;
+            }
+        }
+    }
+    None
+}
+
+fn read_u16(packet: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        packet.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+/// Chromecasts announced in one mDNS response. `from` stands in for the
+/// address when the A record is missing.
+fn parse_mdns_response(packet: &[u8], from: IpAddr) -> Vec<CastTarget> {
+    parse_mdns_records(packet, from).unwrap_or_default()
+}
+
+fn parse_mdns_records(packet: &[u8], from: IpAddr) -> Option<Vec<CastTarget>> {
+    let questions = read_u16(packet, 4)?;
+    let records = [6, 8, 10]
+        .iter()
+        .map(|offset| read_u16(packet, *offset).map(usize::from))
+        .sum::<Option<usize>>()?;
+
+    let mut offset = 12;
+    for _ in 0..questions {
+        offset = read_name(packet, offset)?.1 + 4;
+    }
+
+    let mut instances = Vec::new();
+    let mut services: HashMap<String, (String, u16)> = HashMap::new();
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut addresses: HashMap<String, IpAddr> = HashMap::new();
+    for _ in 0..records {
+        let (owner, after) = read_name(packet, offset)?;
+        let kind = read_u16(packet, after)?;
+        let len = read_u16(packet, after + 8)? as usize;
+        let data = after + 10;
+        packet.get(data..data + len)?;
+        match kind {
+            DNS_PTR if owner.eq_ignore_ascii_case(SERVICE) => {
+                instances.push(read_name(packet, data)?.0);
+            }
+            DNS_SRV => {
+                let port = read_u16(packet, data + 4)?;
+                services.insert(owner, (read_name(packet, data + 6)?.0, port));
+            }
+            DNS_TXT => {
+                let mut entry = data;
+                while entry < data + len {
+                    let entry_len = packet[entry] as usize;
+                    let text = packet.get(entry + 1..entry + 1 + entry_len)?;
+                    if let Some(name) = text.strip_prefix(b"fn=") {
+                        names.insert(owner.clone(), String::from_utf8_lossy(name).into_owned());
+                    }
+                    entry += 1 + entry_len;
+                }
+            }
+            DNS_A if len == 4 => {
+                let ip: [u8; 4] = packet[data..data + 4].try_into().ok()?;
+                addresses.insert(owner, IpAddr::from(ip));
+            }
+            _ => {}
+        }
+        offset = data + len;
+    }
+
+    // A response may carry only the SRV records, without the PTR
+    for instance in services.keys() {
+        if !instances.contains(instance) {
+            instances.push(instance.clone());
+        }
+    }
+    Some(
+        instances
+            .into_iter()
+            .filter_map(|instance| {
+                let (host, port) = services.get(&instance)?;
+                let address = addresses.get(host).copied().unwrap_or(from);
+                let name = names
+                    .get(&instance)
+                    .cloned()
+                    .unwrap_or_else(|| instance.split('.').next().unwrap_or_default().to_string());
+                Some(CastTarget {
+                    name,
+                    kind: CastKind::Chromecast,
+                    address,
+                    endpoint: CastEndpoint::Chromecast {
+                        addr: SocketAddr::new(address, *port),
+                    },
+                })
+            })
+            .collect(),
+    )
+}
+
+/// One CASTV2 `CastMessage` with a JSON payload
+#[derive(Debug, Clone, PartialEq)]
+struct CastMessage {
+    source: String,
+    destination: String,
+    namespace: String,
+    payload: String,
+}
+
+fn push_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(data: &[u8], offset: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*offset)?;
+        *offset += 1;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+impl CastMessage {
+    fn new(destination: &str, namespace: &str, payload: &Value) -> Self {
+        Self {
+            source: SENDER_ID.to_string(),
+            destination: destination.to_string(),
+            namespace: namespace.to_string(),
+            payload: payload.to_string(),
+        }
+    }
+
+    /// Protobuf encoding, without the length prefix
+    fn encode(&self) -> Vec<u8> {
+        let mut out = vec![0x08, 0x00]; // protocol_version = CASTV2_1_0
+        for (tag, text) in [
+            (0x12, &self.source),
+            (0x1a, &self.destination),
+            (0x22, &self.namespace),
+        ] {
+            out.push(tag);
+            push_varint(&mut out, text.len() as u64);
+            out.extend_from_slice(text.as_bytes());
+        }
+        out.extend_from_slice(&[0x28, 0x00]); // payload_type = STRING
+        out.push(0x32);
+        push_varint(&mut out, self.payload.len() as u64);
+        out.extend_from_slice(self.payload.as_bytes());
+        out
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        let mut message = Self {
+            source: String::new(),
+            destination: String::new(),
+            namespace: String::new(),
+            payload: String::new(),
+        };
+        let mut offset = 0;
+        while offset < data.len() {
+            let key = read_varint(data, &mut offset)?;
+            match key & 0x07 {
+                0 => {
+                    read_varint(data, &mut offset)?;
+                }
+                2 => {
+                    let len = read_varint(data, &mut offset)? as usize;
+                    let value = data.get(offset..offset.checked_add(len)?)?;
+                    offset += len;
+                    let text = String::from_utf8_lossy(value).into_owned();
+                    match key >> 3 {
+                        2 => message.source = text,
+                        3 => message.destination = text,
+                        4 => message.namespace = text,
+                        6 => message.payload = text,
+                        _ => {}
+                    }
+                }
+                _ => return None,
+            }
+        }
+        Some(message)
+    }
+
+    fn json(&self) -> Value {
+        serde_json::from_str(&self.payload).unwrap_or(Value::Null)
+    }
+}
+
+/// Chromecast certificates are self-signed; accept any chain but still check
+/// that the handshake was signed by the presented key
+#[derive(Debug)]
+struct AcceptDeviceCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptDeviceCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+fn unavailable(message: impl Into<String>) -> AudioError {
+    AudioError::DeviceUnavailable(message.into())
+}
+
+/// A TLS connection to one device
+struct Session {
+    stream: StreamOwned<ClientConnection, TcpStream>,
+    request_id: u64,
+    last_sent: Instant,
+    /// Receiver app session the media commands go to
+    transport_id: Option<String>,
+    media_session_id: Option<u64>,
+}
+
+impl Session {
+    fn open(addr: SocketAddr) -> Result<Self, AudioError> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let config = ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|e| unavailable(e.to_string()))?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptDeviceCertificate(provider)))
+            .with_no_client_auth();
+        let connection = ClientConnection::new(Arc::new(config), ServerName::from(addr.ip()))
+            .map_err(|e| unavailable(e.to_string()))?;
+
+        let socket = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)?;
+        socket.set_read_timeout(Some(REPLY_TIMEOUT))?;
+        socket.set_write_timeout(Some(CONNECT_TIMEOUT))?;
+
+        let mut session = Self {
+            stream: StreamOwned::new(connection, socket),
+            request_id: 0,
+            last_sent: Instant::now(),
+            transport_id: None,
+            media_session_id: None,
+        };
+        session.send(RECEIVER_ID, NS_CONNECTION, json!({"type": "CONNECT"}))?;
+        Ok(session)
+    }
+
+    fn send(
+        &mut self,
+        destination: &str,
+        namespace: &str,
+        payload: Value,
+    ) -> Result<(), AudioError> {
+        let body = CastMessage::new(destination, namespace, &payload).encode();
+        let mut frame = (body.len() as u32).to_be_bytes().to_vec();
+        frame.extend_from_slice(&body);
+        self.stream.write_all(&frame)?;
+        self.stream.flush()?;
+        self.last_sent = Instant::now();
+        Ok(())
+    }
+
+    fn receive(&mut self) -> Result<CastMessage, AudioError> {
+        let mut len = [0u8; 4];
+        self.stream.read_exact(&mut len)?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_FRAME {
+            return Err(unavailable("oversized message from device"));
+        }
+        let mut body = vec![0u8; len];
+        self.stream.read_exact(&mut body)?;
+        CastMessage::decode(&body).ok_or_else(|| unavailable("malformed message from device"))
+    }
+
+    /// Send a request and wait for the reply carrying its `requestId`
+    fn request(
+        &mut self,
+        destination: &str,
+        namespace: &str,
+        mut payload: Value,
+    ) -> Result<Value, AudioError> {
+        self.request_id += 1;
+        let request_id = self.request_id;
+        payload["requestId"] = json!(request_id);
+        self.send(destination, namespace, payload)?;
+
+        let deadline = Instant::now() + REPLY_TIMEOUT;
+        while Instant::now() < deadline {
+            let message = self.receive()?;
+            let reply = message.json();
+            match reply["type"].as_str() {
+                Some("PING") => {
+                    self.send(&message.source, NS_HEARTBEAT, json!({"type": "PONG"}))?
+                }
+                Some("CLOSE") if message.namespace == NS_CONNECTION => {
+                    return Err(unavailable("device closed the session"));
+                }
+                _ if reply["requestId"].as_u64() == Some(request_id) => return Ok(reply),
+                _ => {}
+            }
+        }
+        Err(unavailable("device did not reply"))
+    }
+
+    fn keep_alive(&mut self) -> Result<(), AudioError> {
+        if self.last_sent.elapsed() >= HEARTBEAT_INTERVAL {
+            self.send(RECEIVER_ID, NS_HEARTBEAT, json!({"type": "PING"}))?;
+        }
+        Ok(())
+    }
+
+    /// Start the media receiver app and attach to it
+    fn launch(&mut self) -> Result<String, AudioError> {
+        let status = self.request(
+            RECEIVER_ID,
+            NS_RECEIVER,
+            json!({"type": "LAUNCH", "appId": DEFAULT_MEDIA_RECEIVER}),
+        )?;
+        let transport_id = status["status"]["applications"]
+            .as_array()
+            .and_then(|apps| {
+                apps.iter()
+                    .find(|app| app["appId"] == DEFAULT_MEDIA_RECEIVER)
+                    .and_then(|app| app["transportId"].as_str())
+            })
+            .ok_or_else(|| unavailable("device did not start the media receiver"))?
+            .to_string();
+        self.send(&transport_id, NS_CONNECTION, json!({"type": "CONNECT"}))?;
+        self.transport_id = Some(transport_id.clone());
+        Ok(transport_id)
+    }
+
+    fn media(&mut self, mut payload: Value) -> Result<Value, AudioError> {
+        let transport_id = self
+            .transport_id
+            .clone()
+            .ok_or_else(|| unavailable("nothing is loaded"))?;
+        if let Some(id) = self.media_session_id {
+            payload["mediaSessionId"] = json!(id);
+        }
+        self.request(&transport_id, NS_MEDIA, payload)
+    }
+}
+
+/// Parse a MEDIA_STATUS reply
+fn parse_media_status(reply: &Value) -> (Option<u64>, RemoteStatus) {
+    let Some(status) = reply["status"].as_array().and_then(|s| s.first()) else {
+        return (None, RemoteStatus::default());
+    };
+    let state = match status["playerState"].as_str() {
+        Some("PLAYING" | "BUFFERING") => RemoteState::Playing,
+        Some("PAUSED") => RemoteState::Paused,
+        _ => RemoteState::Stopped,
+    };
+    let seconds = |value: &Value| {
+        value
+            .as_f64()
+            .filter(|s| s.is_finite() && *s >= 0.0)
+            .map(Duration::from_secs_f64)
+    };
+    (
+        status["mediaSessionId"].as_u64(),
+        RemoteStatus {
+            state,
+            position: seconds(&status["currentTime"]),
+            duration: seconds(&status["media"]["duration"]),
+        },
+    )
+}
+
+/// CASTV2 client for one Chromecast. Reconnects on the next command after
+/// the connection drops.
+pub struct ChromecastRenderer {
+    addr: SocketAddr,
+    session: Option<Session>,
+}
+
+impl ChromecastRenderer {
+    pub fn connect(addr: SocketAddr) -> Result<Self, AudioError> {
+        Ok(Self {
+            addr,
+            session: Some(Session::open(addr)?),
+        })
+    }
+
+    /// Run `f` on the session, dropping the session if it fails
+    fn with_session<T>(
+        &mut self,
+        f: impl FnOnce(&mut Session) -> Result<T, AudioError>,
+    ) -> Result<T, AudioError> {
+        let session = match &mut self.session {
+            Some(session) => session,
+            None => self.session.insert(Session::open(self.addr)?),
+        };
+        let result = session.keep_alive().and_then(|()| f(session));
+        if result.is_err() {
+            self.session = None;
+        }
+        result
+    }
+
+    fn media_command(&mut self, payload: Value) -> Result<(), AudioError> {
+        self.with_session(|session| session.media(payload).map(drop))
+    }
+}
+
+impl Renderer for ChromecastRenderer {
+    fn load(&mut self, url: &str, mime: &str, title: &str) -> Result<(), AudioError> {
+        let payload = json!({
+            "type": "LOAD",
+            "autoplay": true,
+            "media": {
+                "contentId": url,
+                "contentType": mime,
+                "streamType": "BUFFERED",
+                "metadata": {"metadataType": 0, "title": title},
+            },
+        });
+        let reply = self.with_session(|session| {
+            session.media_session_id = None;
+            session.launch()?;
+            session.media(payload)
+        })?;
+        if reply["type"] != "MEDIA_STATUS" {
+            let reason = reply["type"].as_str().unwrap_or("unknown reply");
+            return Err(unavailable(format!(
+                "device refused the episode ({reason})"
+            )));
+        }
+        let (media_session_id, _) = parse_media_status(&reply);
+        if let Some(session) = &mut self.session {
+            session.media_session_id = media_session_id;
+        }
+        Ok(())
+    }
+
+    fn play(&mut self) -> Result<(), AudioError> {
+        self.media_command(json!({"type": "PLAY"}))
+    }
+
+    fn pause(&mut self) -> Result<(), AudioError> {
+        self.media_command(json!({"type": "PAUSE"}))
+    }
+
+    fn stop(&mut self) -> Result<(), AudioError> {
+        self.media_command(json!({"type": "STOP"}))
+    }
+
+    fn seek(&mut self, position: Duration) -> Result<(), AudioError> {
+        self.media_command(json!({"type": "SEEK", "currentTime": position.as_secs_f64()}))
+    }
+
+    fn set_volume(&mut self, volume: f32) -> Result<(), AudioError> {
+        let payload = json!({"type": "SET_VOLUME", "volume": {"level": volume}});
+        self.with_session(|session| session.request(RECEIVER_ID, NS_RECEIVER, payload).map(drop))
+    }
+
+    fn status(&mut self) -> Result<RemoteStatus, AudioError> {
+        let reply = self.with_session(|session| session.media(json!({"type": "GET_STATUS"})))?;
+        let (media_session_id, status) = parse_media_status(&reply);
+        if let (Some(session), Some(id)) = (&mut self.session, media_session_id) {
+            session.media_session_id = Some(id);
+        }
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_name(packet: &mut Vec<u8>, name: &str) {
+        for label in name.split('.') {
+            packet.push(label.len() as u8);
+            packet.extend_from_slice(label.as_bytes());
+        }
+        packet.push(0);
+    }
+
+    fn push_record(packet: &mut Vec<u8>, owner: &[u8], kind: u16, data: &[u8]) {
+        packet.extend_from_slice(owner);
+        packet.extend_from_slice(&kind.to_be_bytes());
+        packet.extend_from_slice(&[0x80, 0x01, 0, 0, 0x11, 0x94]);
+        packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        packet.extend_from_slice(data);
+    }
+
+    #[test]
+    fn test_parse_mdns_response_with_compressed_names() {
+        // Arrange: PTR -> instance, SRV -> host:8009, TXT fn=, A record
+        let mut packet = vec![0, 0, 0x84, 0, 0, 0, 0, 4, 0, 0, 0, 0];
+        let service_offset = packet.len() as u8;
+        push_name(&mut packet, SERVICE);
+        packet.extend_from_slice(&DNS_PTR.to_be_bytes());
+        packet.extend_from_slice(&[0, 1, 0, 0, 0x11, 0x94]);
+        let mut instance = vec![18];
+        instance.extend_from_slice(b"Chromecast-abc1234");
+        instance.extend_from_slice(&[0xC0, service_offset]);
+        packet.extend_from_slice(&(instance.len() as u16).to_be_bytes());
+        let instance_offset = packet.len() as u8;
+        packet.extend_from_slice(&instance);
+
+        let mut srv = vec![0, 0, 0, 0];
+        srv.extend_from_slice(&8009u16.to_be_bytes());
+        push_name(&mut srv, "abc1234.local");
+        push_record(&mut packet, &[0xC0, instance_offset], DNS_SRV, &srv);
+        let mut txt = vec![7];
+        txt.extend_from_slice(b"id=abcd");
+        txt.push(10);
+        txt.extend_from_slice(b"fn=Bedroom");
+        push_record(&mut packet, &[0xC0, instance_offset], DNS_TXT, &txt);
+        let mut host = Vec::new();
+        push_name(&mut host, "abc1234.local");
+        push_record(&mut packet, &host, DNS_A, &[192, 168, 1, 30]);
+
+        // Act
+        let targets = parse_mdns_response(&packet, IpAddr::from([192, 168, 1, 99]));
+
+        // Assert
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name, "Bedroom");
+        assert_eq!(targets[0].kind, CastKind::Chromecast);
+        assert_eq!(
+            targets[0].endpoint,
+            CastEndpoint::Chromecast {
+                addr: "192.168.1.30:8009".parse().unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_mdns_response_rejects_garbage() {
+        assert!(parse_mdns_response(&[0xff; 40], IpAddr::from([10, 0, 0, 1])).is_empty());
+        // A pointer to itself must not loop forever
+        let looping = [0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0xC0, 12];
+        assert!(parse_mdns_response(&looping, IpAddr::from([10, 0, 0, 1])).is_empty());
+    }
+
+    #[test]
+    fn test_cast_message_round_trip() {
+        let payload = json!({"type": "LOAD", "media": {"contentId": "x".repeat(300)}});
+        let message = CastMessage::new("web-5", NS_MEDIA, &payload);
+
+        let decoded = CastMessage::decode(&message.encode()).unwrap();
+
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.json()["type"], "LOAD");
+    }
+
+    #[test]
+    fn test_parse_media_status() {
+        let reply = json!({
+            "type": "MEDIA_STATUS",
+            "status": [{
+                "mediaSessionId": 3,
+                "playerState": "BUFFERING",
+                "currentTime": 12.5,
+                "media": {"duration": 1800.0}
+            }]
+        });
+
+        let (session, status) = parse_media_status(&reply);
+
+        assert_eq!(session, Some(3));
+        assert_eq!(status.state, RemoteState::Playing);
+        assert_eq!(status.position, Some(Duration::from_millis(12_500)));
+        assert_eq!(status.duration, Some(Duration::from_secs(1800)));
+        assert_eq!(
+            parse_media_status(&json!({"status": []})).1.state,
+            RemoteState::Stopped
+        );
+    }
+}
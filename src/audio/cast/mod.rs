@@ -0,0 +1,596 @@
+// Cast backend — plays episodes on a network renderer (UPnP/DLNA, Sonos,
+// Chromecast) instead of the local sound card.
+//
+// The renderer fetches the audio itself from an embedded HTTP endpoint
+// (`server::MediaServer`) and is driven over its own control protocol
+// (`upnp`, `chromecast`). `CastBackend` implements `PlaybackBackend`, so
+// the audio thread controls a cast exactly like local playback; a worker
+// thread owns the network session and polls the renderer about once a
+// second for its state and position.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{AudioError, PlaybackBackend};
+
+pub mod chromecast;
+pub mod server;
+pub mod upnp;
+
+use server::MediaServer;
+
+/// How often the worker asks the renderer for its state
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Renderers report "stopped" for a moment while they start fetching; that
+/// isn't the end of the track
+const LOAD_GRACE: Duration = Duration::from_secs(6);
+/// Failed polls in a row before the renderer counts as gone
+const MAX_POLL_FAILURES: u32 = 5;
+/// Longest `play()` waits for the renderer to accept the media
+const LOAD_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Kind of network renderer, for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastKind {
+    Upnp,
+    Sonos,
+    Chromecast,
+}
+
+impl CastKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Upnp => "UPnP",
+            Self::Sonos => "Sonos",
+            Self::Chromecast => "Chromecast",
+        }
+    }
+}
+
+/// How to reach a renderer's control interface
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastEndpoint {
+    /// SOAP control URLs from the device description
+    Upnp {
+        av_transport: String,
+        rendering_control: Option<String>,
+    },
+    /// CASTV2 TLS socket
+    Chromecast { addr: SocketAddr },
+}
+
+/// A renderer found on the local network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastTarget {
+    /// Friendly name, e.g. "Living Room"
+    pub name: String,
+    pub kind: CastKind,
+    /// Address the renderer fetches media from us with
+    pub address: IpAddr,
+    pub endpoint: CastEndpoint,
+}
+
+impl CastTarget {
+    /// e.g. "Living Room (Sonos)"
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.name, self.kind.label())
+    }
+
+    fn connect(&self) -> Result<Box<dyn Renderer>, AudioError> {
+        match &self.endpoint {
+            CastEndpoint::Upnp {
+                av_transport,
+                rendering_control,
+            } => Ok(Box::new(upnp::UpnpRenderer::new(
+                av_transport.clone(),
+                rendering_control.clone(),
+            ))),
+            CastEndpoint::Chromecast { addr } => {
+                Ok(Box::new(chromecast::ChromecastRenderer::connect(*addr)?))
+            }
+        }
+    }
+}
+
+/// Find renderers on the local network, waiting up to `timeout` for replies.
+/// UPnP (SSDP) and Chromecast (mDNS) are searched at the same time.
+pub fn discover(timeout: Duration) -> Vec<CastTarget> {
+    let upnp = std::thread::spawn(move || upnp::discover(timeout));
+    let chromecast = std::thread::spawn(move || chromecast::discover(timeout));
+
+    let mut targets: Vec<CastTarget> = [upnp, chromecast]
+        .into_iter()
+        .flat_map(|search| search.join().unwrap_or_default())
+        .collect();
+    targets.sort_by_key(|target| target.name.to_lowercase());
+    targets
+}
+
+/// Transport state reported by a renderer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteState {
+    /// Playing, buffering or transitioning to playing
+    Playing,
+    Paused,
+    /// Idle, stopped or finished
+    Stopped,
+}
+
+/// Snapshot of a renderer's playback
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoteStatus {
+    pub state: RemoteState,
+    pub position: Option<Duration>,
+    pub duration: Option<Duration>,
+}
+
+impl Default for RemoteStatus {
+    fn default() -> Self {
+        Self {
+            state: RemoteState::Stopped,
+            position: None,
+            duration: None,
+        }
+    }
+}
+
+/// Control protocol of one kind of renderer. Calls block on the network and
+/// are only made from the cast worker thread.
+pub trait Renderer: Send {
+    /// Start playing `url`
+    fn load(&mut self, url: &str, mime: &str, title: &str) -> Result<(), AudioError>;
+    fn play(&mut self) -> Result<(), AudioError>;
+    fn pause(&mut self) -> Result<(), AudioError>;
+    fn stop(&mut self) -> Result<(), AudioError>;
+    fn seek(&mut self, position: Duration) -> Result<(), AudioError>;
+    fn set_volume(&mut self, volume: f32) -> Result<(), AudioError>;
+    fn status(&mut self) -> Result<RemoteStatus, AudioError>;
+}
+
+enum WorkerCommand {
+    Load {
+        url: String,
+        mime: &'static str,
+        title: String,
+        reply: mpsc::Sender<Result<(), AudioError>>,
+    },
+    Play,
+    Pause,
+    Stop,
+    Seek(Duration),
+    Volume(f32),
+}
+
+/// What the worker last learned about the renderer
+#[derive(Debug, Default)]
+struct SharedStatus {
+    status: RemoteStatus,
+    /// Set once the renderer stopped answering
+    lost: Option<String>,
+}
+
+/// `PlaybackBackend` that plays on a network renderer
+pub struct CastBackend {
+    target: CastTarget,
+    server: MediaServer,
+    commands: mpsc::Sender<WorkerCommand>,
+    shared: Arc<Mutex<SharedStatus>>,
+    lost_reported: bool,
+}
+
+impl std::fmt::Debug for CastBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CastBackend")
+            .field("target", &self.target.label())
+            .finish()
+    }
+}
+
+impl CastBackend {
+    /// Connect to `target` and start the media endpoint
+    pub fn connect(target: CastTarget) -> Result<Self, AudioError> {
+        let renderer = target.connect()?;
+        Ok(Self::with_renderer(target, renderer)?)
+    }
+
+    fn with_renderer(target: CastTarget, renderer: Box<dyn Renderer>) -> std::io::Result<Self> {
+        let server = MediaServer::start()?;
+        let shared = Arc::new(Mutex::new(SharedStatus::default()));
+        let (commands, command_rx) = mpsc::channel();
+
+        let worker_shared = shared.clone();
+        std::thread::Builder::new()
+            .name("cast-worker".into())
+            .spawn(move || run_worker(renderer, command_rx, worker_shared))?;
+
+        Ok(Self {
+            target,
+            server,
+            commands,
+            shared,
+            lost_reported: false,
+        })
+    }
+
+    pub fn target(&self) -> &CastTarget {
+        &self.target
+    }
+
+    fn status(&self) -> RemoteStatus {
+        self.shared
+            .lock()
+            .map(|shared| shared.status)
+            .unwrap_or_default()
+    }
+
+    /// Why the renderer stopped answering, if it did
+    pub fn lost_reason(&self) -> Option<String> {
+        self.shared.lock().ok().and_then(|s| s.lost.clone())
+    }
+
+    /// Show `state` right away; the next poll confirms it
+    fn assume_state(&self, state: RemoteState) {
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.status.state = state;
+        }
+    }
+
+    fn send(&self, command: WorkerCommand) {
+        let _ = self.commands.send(command);
+    }
+}
+
+impl PlaybackBackend for CastBackend {
+    fn play(&mut self, path: &Path) -> Result<(), AudioError> {
+        let url = self.server.serve(path, self.target.address)?;
+        let title = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let (reply, result) = mpsc::channel();
+        self.send(WorkerCommand::Load {
+            url,
+            mime: server::mime_type(path),
+            title,
+            reply,
+        });
+        match result.recv_timeout(LOAD_TIMEOUT) {
+            Ok(result) => result,
+            Err(_) => Err(AudioError::DeviceUnavailable(format!(
+                "{} did not respond",
+                self.target.name
+            ))),
+        }
+    }
+
+    fn pause(&mut self) {
+        if self.is_playing() {
+            self.send(WorkerCommand::Pause);
+            self.assume_state(RemoteState::Paused);
+        }
+    }
+
+    fn resume(&mut self) {
+        if self.is_paused() {
+            self.send(WorkerCommand::Play);
+            self.assume_state(RemoteState::Playing);
+        }
+    }
+
+    fn stop(&mut self) {
+        if !self.is_stopped() {
+            self.send(WorkerCommand::Stop);
+        }
+        self.assume_state(RemoteState::Stopped);
+        self.server.clear();
+    }
+
+    fn seek(&mut self, position: Duration) -> Result<(), AudioError> {
+        let position = match self.duration() {
+            Some(duration) => position.min(duration),
+            None => position,
+        };
+        self.send(WorkerCommand::Seek(position));
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.status.position = Some(position);
+        }
+        Ok(())
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.send(WorkerCommand::Volume(volume.clamp(0.0, 1.0)));
+    }
+
+    fn position(&self) -> Option<Duration> {
+        self.status().position
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.status().duration
+    }
+
+    fn is_playing(&self) -> bool {
+        self.status().state == RemoteState::Playing
+    }
+
+    fn is_paused(&self) -> bool {
+        self.status().state == RemoteState::Paused
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.status().state == RemoteState::Stopped
+    }
+
+    fn current_output_device(&self) -> Option<String> {
+        Some(self.target.label())
+    }
+
+    fn take_device_lost(&mut self) -> bool {
+        if self.lost_reported || self.lost_reason().is_none() {
+            return false;
+        }
+        self.lost_reported = true;
+        true
+    }
+}
+
+impl Drop for CastBackend {
+    fn drop(&mut self) {
+        // Leave the renderer idle rather than playing a URL that is going away
+        if !self.is_stopped() {
+            self.send(WorkerCommand::Stop);
+        }
+    }
+}
+
+/// Owns the renderer: runs commands as they come and polls in between.
+/// Exits when the backend (and with it the command sender) is dropped.
+fn run_worker(
+    mut renderer: Box<dyn Renderer>,
+    commands: mpsc::Receiver<WorkerCommand>,
+    shared: Arc<Mutex<SharedStatus>>,
+) {
+    let mut loaded_at: Option<Instant> = None;
+    let mut failures = 0;
+    let mut last_poll = Instant::now();
+
+    loop {
+        let wait = POLL_INTERVAL.saturating_sub(last_poll.elapsed());
+        match commands.recv_timeout(wait) {
+            Ok(command) => {
+                let result = match command {
+                    WorkerCommand::Load {
+                        url,
+                        mime,
+                        title,
+                        reply,
+                    } => {
+                        let result = renderer.load(&url, mime, &title);
+                        if result.is_ok() {
+                            loaded_at = Some(Instant::now());
+                            if let Ok(mut shared) = shared.lock() {
+                                shared.status = RemoteStatus {
+                                    state: RemoteState::Playing,
+                                    position: Some(Duration::ZERO),
+                                    duration: None,
+                                };
+                            }
+                        }
+                        let _ = reply.send(result);
+                        Ok(())
+                    }
+                    WorkerCommand::Play => renderer.play(),
+                    WorkerCommand::Pause => renderer.pause(),
+                    WorkerCommand::Stop => {
+                        loaded_at = None;
+                        renderer.stop()
+                    }
+                    WorkerCommand::Seek(position) => renderer.seek(position),
+                    WorkerCommand::Volume(volume) => renderer.set_volume(volume),
+                };
+                if let Err(e) = result {
+                    eprintln!("Cast command failed: {e}");
+                }
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+
+        last_poll = Instant::now();
+        let Some(started) = loaded_at else {
+            continue;
+        };
+        match renderer.status() {
+            Ok(mut status) => {
+                failures = 0;
+                if status.state == RemoteState::Stopped && started.elapsed() < LOAD_GRACE {
+                    status.state = RemoteState::Playing;
+                }
+                if status.state == RemoteState::Stopped {
+                    loaded_at = None;
+                }
+                if let Ok(mut shared) = shared.lock() {
+                    shared.status = RemoteStatus {
+                        // Keep the last known length when the renderer stops reporting it
+                        duration: status.duration.or(shared.status.duration),
+                        ..status
+                    };
+                }
+            }
+            Err(e) => {
+                failures += 1;
+                if failures >= MAX_POLL_FAILURES {
+                    if let Ok(mut shared) = shared.lock() {
+                        shared.lost = Some(e.to_string());
+                    }
+                    loaded_at = None;
+                }
+            }
+        }
+    }
+}
+
+/// `12:34:56` / `0:01:02` as used by UPnP and in Chromecast logs
+pub(crate) fn format_clock(position: Duration) -> String {
+    let secs = position.as_secs();
+    format!("{}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+}
+
+/// Parse `H:MM:SS[.fff]`; `None` for `NOT_IMPLEMENTED` and the like
+pub(crate) fn parse_clock(text: &str) -> Option<Duration> {
+    let mut parts = text.trim().split(':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    Some(Duration::from_secs(hours * 3600 + minutes * 60) + Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scripted renderer: records calls and replays statuses in order
+    struct FakeRenderer {
+        calls: Arc<Mutex<Vec<String>>>,
+        statuses: Arc<Mutex<Vec<Result<RemoteStatus, ()>>>>,
+    }
+
+    impl Renderer for FakeRenderer {
+        fn load(&mut self, url: &str, mime: &str, _title: &str) -> Result<(), AudioError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("load {mime} {url}"));
+            Ok(())
+        }
+        fn play(&mut self) -> Result<(), AudioError> {
+            self.calls.lock().unwrap().push("play".to_string());
+            Ok(())
+        }
+        fn pause(&mut self) -> Result<(), AudioError> {
+            self.calls.lock().unwrap().push("pause".to_string());
+            Ok(())
+        }
+        fn stop(&mut self) -> Result<(), AudioError> {
+            self.calls.lock().unwrap().push("stop".to_string());
+            Ok(())
+        }
+        fn seek(&mut self, position: Duration) -> Result<(), AudioError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("seek {}", format_clock(position)));
+            Ok(())
+        }
+        fn set_volume(&mut self, volume: f32) -> Result<(), AudioError> {
+            self.calls.lock().unwrap().push(format!("volume {volume}"));
+            Ok(())
+        }
+        fn status(&mut self) -> Result<RemoteStatus, AudioError> {
+            let mut statuses = self.statuses.lock().unwrap();
+            let next = if statuses.len() > 1 {
+                statuses.remove(0)
+            } else {
+                statuses.first().cloned().unwrap_or(Err(()))
+            };
+            next.map_err(|_| AudioError::DeviceUnavailable("no answer".to_string()))
+        }
+    }
+
+    fn target() -> CastTarget {
+        CastTarget {
+            name: "Kitchen".to_string(),
+            kind: CastKind::Sonos,
+            address: IpAddr::from([127, 0, 0, 1]),
+            endpoint: CastEndpoint::Upnp {
+                av_transport: "http://127.0.0.1:1400/AVTransport/Control".to_string(),
+                rendering_control: None,
+            },
+        }
+    }
+
+    fn backend(statuses: Vec<Result<RemoteStatus, ()>>) -> (CastBackend, Arc<Mutex<Vec<String>>>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let renderer = FakeRenderer {
+            calls: calls.clone(),
+            statuses: Arc::new(Mutex::new(statuses)),
+        };
+        let backend = CastBackend::with_renderer(target(), Box::new(renderer)).unwrap();
+        (backend, calls)
+    }
+
+    fn wait_until(condition: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !condition() {
+            assert!(Instant::now() < deadline, "timed out");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn test_play_serves_file_and_forwards_controls() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("episode.m4a");
+        std::fs::write(&file, b"audio").unwrap();
+        let playing = RemoteStatus {
+            state: RemoteState::Playing,
+            position: Some(Duration::from_secs(5)),
+            duration: Some(Duration::from_secs(600)),
+        };
+        let (mut backend, calls) = backend(vec![Ok(playing)]);
+
+        // Act
+        backend.play(&file).unwrap();
+        assert!(backend.is_playing());
+        backend.pause();
+        assert!(backend.is_paused());
+        backend.resume();
+        backend.seek(Duration::from_secs(65)).unwrap();
+        backend.set_volume(0.5);
+        wait_until(|| calls.lock().unwrap().len() >= 5);
+
+        // Assert
+        let calls = calls.lock().unwrap().clone();
+        assert!(calls[0].starts_with("load audio/mp4 http://127.0.0.1:"));
+        assert!(calls[0].ends_with("/episode.m4a"));
+        assert_eq!(calls[1..5], ["pause", "play", "seek 0:01:05", "volume 0.5"]);
+        assert_eq!(
+            backend.current_output_device().as_deref(),
+            Some("Kitchen (Sonos)")
+        );
+    }
+
+    #[test]
+    fn test_unanswered_polls_report_the_device_lost_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("episode.mp3");
+        std::fs::write(&file, b"audio").unwrap();
+        let (mut backend, _) = backend(vec![Err(())]);
+
+        backend.play(&file).unwrap();
+        wait_until(|| backend.lost_reason().is_some());
+
+        assert!(backend.take_device_lost());
+        assert!(!backend.take_device_lost());
+    }
+
+    #[test]
+    fn test_clock_round_trip() {
+        assert_eq!(format_clock(Duration::from_secs(3723)), "1:02:03");
+        assert_eq!(parse_clock("1:02:03"), Some(Duration::from_secs(3723)));
+        assert_eq!(
+            parse_clock("0:00:01.500"),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(parse_clock("NOT_IMPLEMENTED"), None);
+        assert_eq!(parse_clock("0:00:75"), None);
+    }
+}
@@ -0,0 +1,306 @@
+// MediaServer — serves the episode being cast to the renderer over HTTP.
+//
+// Cast targets fetch audio themselves, so the local file is offered on an
+// ephemeral port for as long as it is being cast. Only the current file is
+// served, under an unguessable path; everything else is a 404. Byte ranges
+// are supported because renderers seek by re-requesting from an offset.
+
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Longest request head accepted from a renderer
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// The file currently on offer
+#[derive(Debug, Clone)]
+struct ServedFile {
+    /// URL path, e.g. `/media/<uuid>/episode.mp3`
+    path: String,
+    file: PathBuf,
+    mime: &'static str,
+}
+
+/// HTTP endpoint for the file being cast
+pub struct MediaServer {
+    port: u16,
+    current: Arc<Mutex<Option<ServedFile>>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MediaServer {
+    /// Listen on an ephemeral port on all interfaces
+    pub fn start() -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("0.0.0.0", 0))?;
+        let port = listener.local_addr()?.port();
+        let current: Arc<Mutex<Option<ServedFile>>> = Arc::new(Mutex::new(None));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let served = current.clone();
+        let stop = shutdown.clone();
+        std::thread::Builder::new()
+            .name("cast-media-server".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let Ok(stream) = stream else { continue };
+                    let served = served.lock().map(|s| s.clone()).unwrap_or(None);
+                    // Renderers often hold one connection open while probing with another
+                    std::thread::spawn(move || {
+                        let _ = handle_connection(stream, served.as_ref());
+                    });
+                }
+            })?;
+
+        Ok(Self {
+            port,
+            current,
+            shutdown,
+        })
+    }
+
+    /// Offer `file` from now on and return the URL `peer` can fetch it from
+    pub fn serve(&self, file: &Path, peer: IpAddr) -> std::io::Result<String> {
+        let host = local_ip_for(peer)?;
+        let name = file
+            .file_name()
+            .map(|name| url::form_urlencoded::byte_serialize(name.as_encoded_bytes()).collect())
+            .unwrap_or_else(|| "episode".to_string());
+        let path = format!("/media/{}/{}", uuid::Uuid::new_v4().simple(), name);
+        let url = format!("http://{}{}", SocketAddr::new(host, self.port), path);
+
+        if let Ok(mut current) = self.current.lock() {
+            *current = Some(ServedFile {
+                path,
+                file: file.to_path_buf(),
+                mime: mime_type(file),
+            });
+        }
+        Ok(url)
+    }
+
+    /// Stop offering the current file
+    pub fn clear(&self) {
+        if let Ok(mut current) = self.current.lock() {
+            *current = None;
+        }
+    }
+}
+
+impl Drop for MediaServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        // Wake the accept loop so it sees the flag
+        let _ = TcpStream::connect_timeout(
+            &SocketAddr::from(([127, 0, 0, 1], self.port)),
+            Duration::from_millis(200),
+        );
+    }
+}
+
+/// Address of the interface that routes to `peer`; that is the address the
+/// renderer can reach us on. No packet is sent.
+pub fn local_ip_for(peer: IpAddr) -> std::io::Result<IpAddr> {
+    let socket = match peer {
+        IpAddr::V4(_) => UdpSocket::bind(("0.0.0.0", 0))?,
+        IpAddr::V6(_) => UdpSocket::bind(("::", 0))?,
+    };
+    socket.connect((peer, 9))?;
+    Ok(socket.local_addr()?.ip())
+}
+
+/// MIME type renderers expect for a podcast file, by extension
+pub fn mime_type(file: &Path) -> &'static str {
+    let extension = file
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("m4a" | "mp4" | "m4b") => "audio/mp4",
+        Some("aac") => "audio/aac",
+        Some("ogg" | "oga") => "audio/ogg",
+        Some("opus") => "audio/opus",
+        Some("flac") => "audio/flac",
+        Some("wav") => "audio/wav",
+        _ => "audio/mpeg",
+    }
+}
+
+/// Resolve a `Range: bytes=…` header against a file of `len` bytes into an
+/// inclusive `(start, end)`. `None` for a missing, malformed or multi-range
+/// header (the whole file is sent); `Some(Err(()))` when unsatisfiable.
+fn parse_range(header: Option<&str>, len: u64) -> Option<Result<(u64, u64), ()>> {
+    let spec = header?.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", "") => return None,
+        // Suffix range: the last N bytes
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().ok()?;
+            if suffix == 0 {
+                return Some(Err(()));
+            }
+            (len.saturating_sub(suffix), len.saturating_sub(1))
+        }
+        (start, "") => (start.parse().ok()?, len.saturating_sub(1)),
+        (start, end) => (
+            start.parse().ok()?,
+            end.parse::<u64>().ok()?.min(len.saturating_sub(1)),
+        ),
+    };
+    if len == 0 || start >= len || start > end {
+        return Some(Err(()));
+    }
+    Some(Ok((start, end)))
+}
+
+fn handle_connection(stream: TcpStream, served: Option<&ServedFile>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    // Bounded, so a misbehaving client can't grow the head without limit
+    let mut reader = BufReader::new(stream.try_clone()?.take(MAX_REQUEST_HEAD as u64));
+    let mut writer = stream;
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut range = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    if method != "GET" && method != "HEAD" {
+        return respond_status(&mut writer, "405 Method Not Allowed");
+    }
+    let Some(served) = served.filter(|served| served.path == target) else {
+        return respond_status(&mut writer, "404 Not Found");
+    };
+    let Ok(mut file) = std::fs::File::open(&served.file) else {
+        return respond_status(&mut writer, "404 Not Found");
+    };
+    let len = file.metadata()?.len();
+
+    let (status, start, end) = match parse_range(range.as_deref(), len) {
+        None => ("200 OK", 0, len.saturating_sub(1)),
+        Some(Ok((start, end))) => ("206 Partial Content", start, end),
+        Some(Err(())) => {
+            return write!(
+                writer,
+                "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */{len}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+        }
+    };
+    let body_len = if len == 0 { 0 } else { end - start + 1 };
+
+    let mut head = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {}\r\nContent-Length: {body_len}\r\nAccept-Ranges: bytes\r\n",
+        served.mime
+    );
+    if status.starts_with("206") {
+        head.push_str(&format!("Content-Range: bytes {start}-{end}/{len}\r\n"));
+    }
+    // DLNA renderers (Sonos among them) only seek when told byte ranges work
+    head.push_str("transferMode.dlna.org: Streaming\r\n");
+    head.push_str("contentFeatures.dlna.org: DLNA.ORG_OP=01;DLNA.ORG_CI=0;DLNA.ORG_FLAGS=01700000000000000000000000000000\r\n");
+    head.push_str("Connection: close\r\n\r\n");
+    writer.write_all(head.as_bytes())?;
+
+    if method == "GET" && body_len > 0 {
+        file.seek(SeekFrom::Start(start))?;
+        // The renderer hanging up mid-file is normal (seek, pause, stop)
+        let _ = std::io::copy(&mut file.take(body_len), &mut writer);
+    }
+    let _ = writer.shutdown(Shutdown::Write);
+    Ok(())
+}
+
+fn respond_status(writer: &mut TcpStream, status: &str) -> std::io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(url: &str, range: Option<&str>) -> (String, Vec<u8>) {
+        let url = url::Url::parse(url).unwrap();
+        let mut stream =
+            TcpStream::connect(("127.0.0.1", url.port().unwrap())).expect("server listening");
+        let range = range.map(|r| format!("Range: {r}\r\n")).unwrap_or_default();
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: test\r\n{range}\r\n",
+            url.path()
+        )
+        .unwrap();
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).unwrap();
+        let split = response
+            .windows(4)
+            .position(|w| w == b"\r\n\r\n")
+            .expect("complete head");
+        let head = String::from_utf8_lossy(&response[..split]).to_string();
+        (head, response[split + 4..].to_vec())
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range(None, 100), None);
+        assert_eq!(parse_range(Some("bytes=10-19"), 100), Some(Ok((10, 19))));
+        assert_eq!(parse_range(Some("bytes=90-"), 100), Some(Ok((90, 99))));
+        assert_eq!(parse_range(Some("bytes=-5"), 100), Some(Ok((95, 99))));
+        assert_eq!(parse_range(Some("bytes=50-500"), 100), Some(Ok((50, 99))));
+        assert_eq!(parse_range(Some("bytes=100-"), 100), Some(Err(())));
+        assert_eq!(parse_range(Some("bytes=0-1,5-6"), 100), None);
+        assert_eq!(parse_range(Some("items=0-1"), 100), None);
+    }
+
+    #[test]
+    fn test_serves_only_the_current_file_with_ranges() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("My Episode.mp3");
+        let content: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+        std::fs::write(&file, &content).unwrap();
+        let server = MediaServer::start().unwrap();
+
+        // Act
+        let url = server.serve(&file, IpAddr::from([127, 0, 0, 1])).unwrap();
+        let (full_head, full_body) = get(&url, None);
+        let (range_head, range_body) = get(&url, Some("bytes=100-199"));
+        let (other_head, _) = get(&url.replace("/media/", "/media/x"), None);
+        server.clear();
+        let (cleared_head, _) = get(&url, None);
+
+        // Assert
+        assert!(url.ends_with("/My+Episode.mp3"), "{url}");
+        assert!(full_head.starts_with("HTTP/1.1 200"));
+        assert!(full_head.contains("Content-Type: audio/mpeg"));
+        assert_eq!(full_body, content);
+        assert!(range_head.starts_with("HTTP/1.1 206"));
+        assert!(range_head.contains("Content-Range: bytes 100-199/4096"));
+        assert_eq!(range_body, content[100..200]);
+        assert!(other_head.starts_with("HTTP/1.1 404"));
+        assert!(cleared_head.starts_with("HTTP/1.1 404"));
+    }
+}
@@ -0,0 +1,505 @@
+// UPnP/DLNA renderers (Sonos included) — SSDP discovery and AVTransport
+// control over SOAP.
+//
+// Discovery multicasts an M-SEARCH for MediaRenderer AVTransport services,
+// fetches each responder's device description and keeps those with an
+// AVTransport control URL. Control is plain SOAP over HTTP/1.1; requests are
+// small and synchronous, so a minimal client over `TcpStream` is used rather
+// than pulling the async HTTP stack onto the audio thread.
+
+use std::collections::HashSet;
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::{format_clock, parse_clock, CastEndpoint, CastKind, CastTarget, RemoteState};
+use super::{RemoteStatus, Renderer};
+use crate::audio::AudioError;
+
+const SSDP_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(239, 255, 255, 250)), 1900);
+const AV_TRANSPORT: &str = "urn:schemas-upnp-org:service:AVTransport:1";
+const RENDERING_CONTROL: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
+const HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+/// Largest response accepted from a renderer
+const MAX_RESPONSE: u64 = 1024 * 1024;
+
+/// Multicast an SSDP search and describe every renderer that answers
+pub fn discover(timeout: Duration) -> Vec<CastTarget> {
+    let locations = match search(timeout) {
+        Ok(locations) => locations,
+        Err(e) => {
+            eprintln!("UPnP discovery failed: {e}");
+            return Vec::new();
+        }
+    };
+    locations
+        .iter()
+        .filter_map(|location| describe(location).ok().flatten())
+        .collect()
+}
+
+fn search(timeout: Duration) -> std::io::Result<Vec<String>> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    let request = format!(
+        "M-SEARCH * HTTP/1.1\r\nHOST: {SSDP_ADDR}\r\nMAN: \"ssdp:discover\"\r\nMX: 2\r\nST: {AV_TRANSPORT}\r\n\r\n"
+    );
+    // UDP is lossy; a second search catches devices that missed the first
+    socket.send_to(request.as_bytes(), SSDP_ADDR)?;
+    socket.send_to(request.as_bytes(), SSDP_ADDR)?;
+
+    let deadline = Instant::now() + timeout;
+    let mut locations = Vec::new();
+    let mut seen = HashSet::new();
+    let mut buf = [0u8; 2048];
+    while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+        socket.set_read_timeout(Some(left.max(Duration::from_millis(1))))?;
+        let Ok((len, _)) = socket.recv_from(&mut buf) else {
+            break;
+        };
+        if let Some(location) = parse_search_response(&String::from_utf8_lossy(&buf[..len])) {
+            if seen.insert(location.clone()) {
+                locations.push(location);
+            }
+        }
+    }
+    Ok(locations)
+}
+
+/// LOCATION of an SSDP search response
+fn parse_search_response(response: &str) -> Option<String> {
+    let mut lines = response.lines();
+    if !lines.next()?.contains(" 200") {
+        return None;
+    }
+    lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value.trim().to_string())
+}
+
+/// Fetch a device description; `None` when it has no AVTransport service
+fn describe(location: &str) -> Result<Option<CastTarget>, AudioError> {
+    let (status, body) = http_request("GET", location, &[], "")?;
+    if status != 200 {
+        return Err(unavailable(format!("{location} answered {status}")));
+    }
+    let address = url::Url::parse(location)
+        .ok()
+        .and_then(|url| url.socket_addrs(|| Some(80)).ok())
+        .and_then(|addrs| addrs.first().map(|addr| addr.ip()))
+        .ok_or_else(|| unavailable(format!("bad location {location}")))?;
+    Ok(parse_description(&body, location, address))
+}
+
+fn parse_description(xml: &str, location: &str, address: IpAddr) -> Option<CastTarget> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    let mut name = None;
+    let mut manufacturer = None;
+    let (mut service_type, mut control_url) = (None::<String>, None::<String>);
+    let mut services = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => path.push(e.local_name().as_ref().to_vec()),
+            Ok(Event::End(e)) => {
+                if e.local_name().as_ref() == b"service" {
+                    if let (Some(kind), Some(url)) = (service_type.take(), control_url.take()) {
+                        services.push((kind, url));
+                    }
+                }
+                path.pop();
+            }
+            Ok(Event::Text(t)) => {
+                let Ok(text) = t.unescape() else { continue };
+                let text = text.trim().to_string();
+                match path.last().map(Vec::as_slice) {
+                    // The first device is the root; embedded devices come later
+                    Some(b"friendlyName") if name.is_none() => name = Some(text),
+                    Some(b"manufacturer") if manufacturer.is_none() => manufacturer = Some(text),
+                    Some(b"serviceType") => service_type = Some(text),
+                    Some(b"controlURL") => control_url = Some(text),
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    let base = url::Url::parse(location).ok()?;
+    let control = |wanted: &str| {
+        services
+            .iter()
+            .find(|(kind, _)| kind == wanted)
+            .and_then(|(_, url)| base.join(url).ok())
+            .map(String::from)
+    };
+    let av_transport = control(AV_TRANSPORT)?;
+    let kind = match manufacturer.as_deref() {
+        Some(m) if m.to_ascii_lowercase().contains("sonos") => CastKind::Sonos,
+        _ => CastKind::Upnp,
+    };
+    Some(CastTarget {
+        name: name.unwrap_or_else(|| address.to_string()),
+        kind,
+        address,
+        endpoint: CastEndpoint::Upnp {
+            av_transport,
+            rendering_control: control(RENDERING_CONTROL),
+        },
+    })
+}
+
+/// AVTransport/RenderingControl client for one renderer
+pub struct UpnpRenderer {
+    av_transport: String,
+    rendering_control: Option<String>,
+}
+
+impl UpnpRenderer {
+    pub fn new(av_transport: String, rendering_control: Option<String>) -> Self {
+        Self {
+            av_transport,
+            rendering_control,
+        }
+    }
+
+    fn transport(&self, action: &str, arguments: &[(&str, &str)]) -> Result<String, AudioError> {
+        soap(&self.av_transport, AV_TRANSPORT, action, arguments)
+    }
+}
+
+impl Renderer for UpnpRenderer {
+    fn load(&mut self, url: &str, mime: &str, title: &str) -> Result<(), AudioError> {
+        let metadata = didl_metadata(url, mime, title);
+        self.transport(
+            "SetAVTransportURI",
+            &[
+                ("InstanceID", "0"),
+                ("CurrentURI", url),
+                ("CurrentURIMetaData", &metadata),
+            ],
+        )?;
+        self.play()
+    }
+
+    fn play(&mut self) -> Result<(), AudioError> {
+        self.transport("Play", &[("InstanceID", "0"), ("Speed", "1")])
+            .map(drop)
+    }
+
+    fn pause(&mut self) -> Result<(), AudioError> {
+        self.transport("Pause", &[("InstanceID", "0")]).map(drop)
+    }
+
+    fn stop(&mut self) -> Result<(), AudioError> {
+        self.transport("Stop", &[("InstanceID", "0")]).map(drop)
+    }
+
+    fn seek(&mut self, position: Duration) -> Result<(), AudioError> {
+        let target = format_clock(position);
+        self.transport(
+            "Seek",
+            &[
+                ("InstanceID", "0"),
+                ("Unit", "REL_TIME"),
+                ("Target", &target),
+            ],
+        )
+        .map(drop)
+    }
+
+    fn set_volume(&mut self, volume: f32) -> Result<(), AudioError> {
+        let Some(control) = &self.rendering_control else {
+            return Err(AudioError::Unsupported(
+                "renderer has no volume control".into(),
+            ));
+        };
+        let volume = ((volume * 100.0).round() as u32).min(100).to_string();
+        soap(
+            control,
+            RENDERING_CONTROL,
+            "SetVolume",
+            &[
+                ("InstanceID", "0"),
+                ("Channel", "Master"),
+                ("DesiredVolume", &volume),
+            ],
+        )
+        .map(drop)
+    }
+
+    fn status(&mut self) -> Result<RemoteStatus, AudioError> {
+        let transport = self.transport("GetTransportInfo", &[("InstanceID", "0")])?;
+        let position = self.transport("GetPositionInfo", &[("InstanceID", "0")])?;
+        Ok(parse_status(&transport, &position))
+    }
+}
+
+fn parse_status(transport_info: &str, position_info: &str) -> RemoteStatus {
+    let state = match xml_text(transport_info, "CurrentTransportState").as_deref() {
+        Some("PLAYING" | "TRANSITIONING") => RemoteState::Playing,
+        Some("PAUSED_PLAYBACK" | "PAUSED_RECORDING") => RemoteState::Paused,
+        _ => RemoteState::Stopped,
+    };
+    RemoteStatus {
+        state,
+        position: xml_text(position_info, "RelTime").and_then(|t| parse_clock(&t)),
+        duration: xml_text(position_info, "TrackDuration")
+            .and_then(|t| parse_clock(&t))
+            .filter(|d| !d.is_zero()),
+    }
+}
+
+/// DIDL-Lite item describing the stream; most renderers refuse a URI without it
+fn didl_metadata(url: &str, mime: &str, title: &str) -> String {
+    format!(
+        concat!(
+            r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" "#,
+            r#"xmlns:dc="http://purl.org/dc/elements/1.1/" "#,
+            r#"xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/">"#,
+            r#"<item id="0" parentID="-1" restricted="1">"#,
+            "<dc:title>{}</dc:title>",
+            "<upnp:class>object.item.audioItem.musicTrack</upnp:class>",
+            r#"<res protocolInfo="http-get:*:{}:*">{}</res>"#,
+            "</item></DIDL-Lite>"
+        ),
+        escape(title),
+        escape(mime),
+        escape(url)
+    )
+}
+
+fn escape(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+/// Call a SOAP action and return the response body; faults become errors
+fn soap(
+    control_url: &str,
+    service: &str,
+    action: &str,
+    arguments: &[(&str, &str)],
+) -> Result<String, AudioError> {
+    let arguments: String = arguments
+        .iter()
+        .map(|(name, value)| format!("<{name}>{}</{name}>", escape(value)))
+        .collect();
+    let body = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="utf-8"?>"#,
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" "#,
+            r#"s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/">"#,
+            r#"<s:Body><u:{action} xmlns:u="{service}">{arguments}</u:{action}></s:Body>"#,
+            "</s:Envelope>"
+        ),
+        action = action,
+        service = service,
+        arguments = arguments
+    );
+    let soap_action = format!("\"{service}#{action}\"");
+    let (status, response) = http_request(
+        "POST",
+        control_url,
+        &[
+            ("Content-Type", "text/xml; charset=\"utf-8\""),
+            ("SOAPACTION", &soap_action),
+        ],
+        &body,
+    )?;
+    if status != 200 {
+        let detail = xml_text(&response, "errorDescription")
+            .or_else(|| xml_text(&response, "errorCode"))
+            .unwrap_or_else(|| format!("HTTP {status}"));
+        return Err(unavailable(format!("{action} failed: {detail}")));
+    }
+    Ok(response)
+}
+
+/// Text of the first element named `name` (namespace prefix ignored)
+fn xml_text(xml: &str, name: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut inside = false;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => inside = e.local_name().as_ref() == name.as_bytes(),
+            Ok(Event::End(_)) if inside => return Some(String::new()),
+            Ok(Event::Text(t)) if inside => return t.unescape().ok().map(|t| t.into_owned()),
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+fn unavailable(message: String) -> AudioError {
+    AudioError::DeviceUnavailable(message)
+}
+
+/// One HTTP/1.1 request with `Connection: close`; returns status and body
+fn http_request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: &str,
+) -> Result<(u16, String), AudioError> {
+    let parsed = url::Url::parse(url).map_err(|e| unavailable(format!("bad URL {url}: {e}")))?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| unavailable(format!("bad URL {url}")))?;
+    let port = parsed.port_or_known_default().unwrap_or(80);
+    let addr = (host, port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| unavailable(format!("cannot resolve {host}")))?;
+
+    let mut stream = TcpStream::connect_timeout(&addr, HTTP_TIMEOUT)?;
+    stream.set_read_timeout(Some(HTTP_TIMEOUT))?;
+    stream.set_write_timeout(Some(HTTP_TIMEOUT))?;
+
+    let mut request = format!(
+        "{method} {} HTTP/1.1\r\nHost: {host}:{port}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        &parsed[url::Position::BeforePath..url::Position::AfterQuery],
+        body.len()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = Vec::new();
+    stream.take(MAX_RESPONSE).read_to_end(&mut response)?;
+    parse_http_response(&response).ok_or_else(|| unavailable(format!("bad response from {host}")))
+}
+
+fn parse_http_response(response: &[u8]) -> Option<(u16, String)> {
+    let split = response.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = &response[split + 4..];
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        body.to_vec()
+    };
+    Some((status, String::from_utf8_lossy(&body).into_owned()))
+}
+
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size_line = std::str::from_utf8(&body[..line_end]).ok()?;
+        let size = usize::from_str_radix(size_line.split(';').next()?.trim(), 16).ok()?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.extend_from_slice(body.get(..size)?);
+        body = body.get(size + 2..)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = r#"<?xml version="1.0"?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+  <device>
+    <friendlyName>Kitchen</friendlyName>
+    <manufacturer>Sonos, Inc.</manufacturer>
+    <deviceList>
+      <device>
+        <friendlyName>Kitchen Media Renderer</friendlyName>
+        <serviceList>
+          <service>
+            <serviceType>urn:schemas-upnp-org:service:RenderingControl:1</serviceType>
+            <controlURL>/MediaRenderer/RenderingControl/Control</controlURL>
+          </service>
+          <service>
+            <serviceType>urn:schemas-upnp-org:service:AVTransport:1</serviceType>
+            <controlURL>/MediaRenderer/AVTransport/Control</controlURL>
+          </service>
+        </serviceList>
+      </device>
+    </deviceList>
+  </device>
+</root>"#;
+
+    #[test]
+    fn test_parse_search_response() {
+        let response = "HTTP/1.1 200 OK\r\nCACHE-CONTROL: max-age=1800\r\nLocation: http://192.168.1.20:1400/xml/device_description.xml\r\nST: urn:schemas-upnp-org:service:AVTransport:1\r\n\r\n";
+        assert_eq!(
+            parse_search_response(response).as_deref(),
+            Some("http://192.168.1.20:1400/xml/device_description.xml")
+        );
+        assert_eq!(parse_search_response("NOTIFY * HTTP/1.1\r\n\r\n"), None);
+    }
+
+    #[test]
+    fn test_parse_description_resolves_control_urls() {
+        let target = parse_description(
+            DESCRIPTION,
+            "http://192.168.1.20:1400/xml/device_description.xml",
+            IpAddr::from([192, 168, 1, 20]),
+        )
+        .expect("AVTransport renderer");
+
+        assert_eq!(target.name, "Kitchen");
+        assert_eq!(target.kind, CastKind::Sonos);
+        assert_eq!(
+            target.endpoint,
+            CastEndpoint::Upnp {
+                av_transport: "http://192.168.1.20:1400/MediaRenderer/AVTransport/Control"
+                    .to_string(),
+                rendering_control: Some(
+                    "http://192.168.1.20:1400/MediaRenderer/RenderingControl/Control".to_string()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_status_from_soap_responses() {
+        let transport = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><u:GetTransportInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><CurrentTransportState>PAUSED_PLAYBACK</CurrentTransportState><CurrentTransportStatus>OK</CurrentTransportStatus></u:GetTransportInfoResponse></s:Body></s:Envelope>"#;
+        let position = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Body><u:GetPositionInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Track>1</Track><TrackDuration>0:42:10</TrackDuration><RelTime>0:03:05</RelTime></u:GetPositionInfoResponse></s:Body></s:Envelope>"#;
+
+        let status = parse_status(transport, position);
+
+        assert_eq!(status.state, RemoteState::Paused);
+        assert_eq!(status.position, Some(Duration::from_secs(185)));
+        assert_eq!(status.duration, Some(Duration::from_secs(2530)));
+    }
+
+    #[test]
+    fn test_didl_metadata_escapes_values() {
+        let metadata = didl_metadata("http://10.0.0.2:4000/media/a/b.mp3", "audio/mpeg", "Q&A");
+        assert!(metadata.contains("<dc:title>Q&amp;A</dc:title>"));
+        assert!(metadata.contains(r#"protocolInfo="http-get:*:audio/mpeg:*""#));
+    }
+
+    #[test]
+    fn test_parse_http_response_decodes_chunked_body() {
+        let response = b"HTTP/1.1 500 Internal Server Error\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n";
+        assert_eq!(
+            parse_http_response(response),
+            Some((500, "hello world".to_string()))
+        );
+    }
+}
@@ -57,14 +57,13 @@ impl ExternalPlayerBackend {
     ///
     /// Returns `Err(AudioError::ExternalPlayerNotFound)` when none is found.
     pub fn detect() -> Result<Self, AudioError> {
-        Self::detect_from_candidates(&["mpv", "vlc", "ffplay"])
+        Self::detect_from_candidates(&PLAYER_CANDIDATES)
     }
 
     /// Detect from a custom candidate list — primarily useful for unit tests.
     pub(crate) fn detect_from_candidates(candidates: &[&str]) -> Result<Self, AudioError> {
         for &candidate in candidates {
-            let ver_arg = version_flag(candidate);
-            if Command::new(candidate).arg(ver_arg).output().is_ok() {
+            if is_installed(candidate) {
                 return Ok(Self::new(candidate.to_string()));
             }
         }
@@ -199,6 +198,23 @@ fn player_basename(cmd: &str) -> String {
         .unwrap_or_else(|| cmd.to_lowercase())
 }
 
+/// Players tried by `detect()`, in order of preference
+pub const PLAYER_CANDIDATES: [&str; 3] = ["mpv", "vlc", "ffplay"];
+
+/// Whether `cmd` can be run, checked by asking it for its version
+pub fn is_installed(cmd: &str) -> bool {
+    Command::new(cmd).arg(version_flag(cmd)).output().is_ok()
+}
+
+/// The standard candidates that are installed, in order of preference
+pub fn installed_players() -> Vec<String> {
+    PLAYER_CANDIDATES
+        .iter()
+        .filter(|candidate| is_installed(candidate))
+        .map(|candidate| candidate.to_string())
+        .collect()
+}
+
 /// Returns the version-check flag for `cmd`.
 /// ffplay/ffmpeg/ffprobe use single-dash (`-version`); all others use `--version`.
 fn version_flag(cmd: &str) -> &'static str {
@@ -7,10 +7,13 @@
 //   AudioManager → UI:  watch::Receiver<PlaybackStatus>       (~4 Hz status)
 //   AudioManager → UI:  mpsc::UnboundedSender<AppEvent>       (one-shot events)
 
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use tokio::sync::{mpsc, watch};
 
+use crate::audio::cast::{CastBackend, CastTarget};
+use crate::audio::probe::{probe_backends, AudioDiagnostics};
 use crate::audio::{AudioCommand, AudioError, PlaybackBackend, PlaybackState, PlaybackStatus};
 use crate::config::AudioConfig;
 use crate::storage::{EpisodeId, PodcastId};
@@ -30,19 +33,63 @@ pub struct AudioManager {
 }
 
 impl AudioManager {
-    /// Create an `AudioManager`, selecting the appropriate backend from `config`
-    /// and spawning the audio thread.
+    /// Probe the backends, open the best one for `config` (see
+    /// [`probe_backends`]) and spawn the audio thread. The manager is `None`
+    /// when there is no audio output; the diagnostics say why.
     ///
-    /// Backend selection order:
-    /// 1. `config.external_player` set → `ExternalPlayerBackend`
-    /// 2. `RodioBackend::new()` succeeds → use it
-    /// 3. `RodioBackend` fails → `ExternalPlayerBackend::detect()`
-    /// 4. Both fail → return the original rodio error
+    /// With the built-in player, `config.decode_fallback` retries files it
+    /// can't decode with the first external player the probe found.
+    pub fn start(
+        config: &AudioConfig,
+        app_event_tx: mpsc::UnboundedSender<AppEvent>,
+    ) -> (Option<Self>, AudioDiagnostics) {
+        let (backend, mut diagnostics) = probe_backends(config);
+        let Some(backend) = backend else {
+            return (None, diagnostics);
+        };
+        let built_in = diagnostics.built_in == Some(Ok(()));
+        let decode_fallback = diagnostics
+            .external_players
+            .first()
+            .filter(|_| built_in && config.decode_fallback)
+            .map(|player| fallback_player(player.clone()));
+
+        match Self::spawn(backend, config, app_event_tx, decode_fallback) {
+            Ok(manager) => (Some(manager), diagnostics),
+            Err(e) => {
+                diagnostics.selected = None;
+                diagnostics.reason = format!("the audio thread could not start: {e}");
+                (None, diagnostics)
+            }
+        }
+    }
+
+    /// [`start`](Self::start), failing when there is no audio output
     pub fn new(
         config: &AudioConfig,
         app_event_tx: mpsc::UnboundedSender<AppEvent>,
     ) -> Result<Self, AudioError> {
-        let backend = create_backend(config)?;
+        match Self::start(config, app_event_tx) {
+            (Some(manager), _) => Ok(manager),
+            (None, diagnostics) => Err(AudioError::DeviceUnavailable(diagnostics.reason)),
+        }
+    }
+
+    fn spawn(
+        mut backend: Box<dyn PlaybackBackend>,
+        config: &AudioConfig,
+        app_event_tx: mpsc::UnboundedSender<AppEvent>,
+        decode_fallback: Option<FallbackPlayer>,
+    ) -> Result<Self, AudioError> {
+        if let Some(ref device) = config.output_device {
+            if let Err(e) = backend.set_output_device(Some(device)) {
+                // Keep going on the default device; the UI explains why
+                let _ = app_event_tx.send(AppEvent::AudioDeviceSelectFailed {
+                    device: device.clone(),
+                    error: e.to_string(),
+                });
+            }
+        }
 
         let (command_tx, command_rx) = mpsc::unbounded_channel();
         let initial_volume = config.volume.clamp(0.0, 1.0);
@@ -55,7 +102,14 @@ impl AudioManager {
         let thread = std::thread::Builder::new()
             .name("audio-manager".into())
             .spawn(move || {
-                run_loop(backend, command_rx, status_tx, app_event_tx, initial_volume);
+                run_loop(
+                    backend,
+                    command_rx,
+                    status_tx,
+                    app_event_tx,
+                    initial_volume,
+                    decode_fallback,
+                );
             })?;
 
         Ok(Self {
@@ -88,27 +142,17 @@ impl AudioManager {
     }
 }
 
-// ---------- Backend selection -----------------------------------------------
-
-fn create_backend(config: &AudioConfig) -> Result<Box<dyn PlaybackBackend>, AudioError> {
-    if let Some(ref player) = config.external_player {
-        return Ok(Box::new(
-            crate::audio::external::ExternalPlayerBackend::new(player.clone()),
-        ));
-    }
-
-    match crate::audio::rodio_backend::RodioBackend::new() {
-        Ok(backend) => Ok(Box::new(backend)),
-        Err(rodio_err) => {
-            eprintln!(
-                "RodioBackend init failed: {rodio_err}. Attempting external player fallback…"
-            );
-            match crate::audio::external::ExternalPlayerBackend::detect() {
-                Ok(backend) => Ok(Box::new(backend)),
-                Err(_) => Err(rodio_err),
-            }
-        }
-    }
+/// Opens the player a file goes to when the built-in one can't decode it,
+/// returning its name for the status message
+type FallbackPlayer =
+    Box<dyn FnMut() -> Result<(String, Box<dyn PlaybackBackend>), AudioError> + Send>;
+
+/// Opens `player` (mpv, vlc or ffplay) as the fallback
+fn fallback_player(player: String) -> FallbackPlayer {
+    Box::new(move || {
+        let backend = crate::audio::external::ExternalPlayerBackend::new(player.clone());
+        Ok((player.clone(), Box::new(backend)))
+    })
 }
 
 // ---------- Audio thread loop -----------------------------------------------
@@ -119,16 +163,71 @@ fn run_loop(
     status_tx: watch::Sender<PlaybackStatus>,
     app_event_tx: mpsc::UnboundedSender<AppEvent>,
     initial_volume: f32,
+    mut decode_fallback: Option<FallbackPlayer>,
 ) {
     let mut current_episode: Option<(EpisodeId, PodcastId)> = None;
+    let mut current_path: Option<PathBuf> = None;
+    let mut casting: Option<Casting> = None;
+    // The built-in backend, parked while a fallback player has the episode
+    let mut parked_primary: Option<Box<dyn PlaybackBackend>> = None;
     let mut was_playing = false;
-    let mut volume = initial_volume;
+    let mut volume = Volume::new(initial_volume);
 
     loop {
         // Drain all pending commands before the next status broadcast.
         loop {
             match command_rx.try_recv() {
+                Ok(AudioCommand::CastTo(target)) => {
+                    switch_cast_target(
+                        target,
+                        &mut backend,
+                        &mut casting,
+                        current_path.as_deref(),
+                        &volume,
+                        &app_event_tx,
+                    );
+                    // The switch itself is not the end of the track
+                    was_playing = backend.is_playing();
+                }
+                Ok(AudioCommand::Play {
+                    path,
+                    episode_id,
+                    podcast_id,
+                    start_at,
+                }) => {
+                    // A fallback player only keeps the episode it was started
+                    // for (while casting it is parked with the local backend)
+                    if casting.is_none() {
+                        if let Some(primary) = parked_primary.take() {
+                            backend.stop();
+                            backend = primary;
+                            backend.set_volume(volume.output());
+                        }
+                    }
+                    let fallback = decode_fallback.as_mut().filter(|_| casting.is_none());
+                    let result = play_or_fall_back(
+                        &mut backend,
+                        &path,
+                        fallback,
+                        &mut parked_primary,
+                        &volume,
+                        &app_event_tx,
+                    );
+                    let result = result.map(|()| resume_at(&mut *backend, start_at));
+                    report_play(
+                        result,
+                        episode_id,
+                        podcast_id,
+                        &mut current_episode,
+                        &app_event_tx,
+                    );
+                    current_path = Some(path);
+                }
                 Ok(cmd) => {
+                    // Remembered so a cast switch can pick the episode up
+                    if matches!(cmd, AudioCommand::Stop) {
+                        current_path = None;
+                    }
                     process_command(
                         cmd,
                         &mut *backend,
@@ -142,6 +241,26 @@ fn run_loop(
             }
         }
 
+        // A vanished device stalls the sink without emptying it, so handle it
+        // before track-end detection.
+        if backend.take_device_lost() {
+            match casting.as_ref().map(|c| c.target.clone()) {
+                Some(target) => {
+                    let _ = app_event_tx.send(AppEvent::CastConnectionLost { target });
+                    switch_cast_target(
+                        None,
+                        &mut backend,
+                        &mut casting,
+                        current_path.as_deref(),
+                        &volume,
+                        &app_event_tx,
+                    );
+                    was_playing = backend.is_playing();
+                }
+                None => handle_device_lost(&mut *backend, &app_event_tx),
+            }
+        }
+
         // Detect natural track end: was playing last tick, now stopped (not paused).
         let is_playing_now = backend.is_playing();
         if was_playing && !is_playing_now && !backend.is_paused() {
@@ -169,7 +288,10 @@ fn run_loop(
             podcast_id: current_episode.as_ref().map(|(_, p)| p.clone()),
             position: backend.position(),
             duration: backend.duration(),
-            volume,
+            volume: volume.level,
+            muted: volume.muted,
+            ducked: volume.ducked,
+            speed: backend.speed(),
         };
         let _ = status_tx.send(status);
 
@@ -177,35 +299,263 @@ fn run_loop(
     }
 }
 
+/// The user's volume plus the mute / duck attenuation applied on top of it.
+/// Muting and ducking never touch `level`, so clearing them restores it.
+#[derive(Debug, Clone, Copy)]
+struct Volume {
+    level: f32,
+    muted: bool,
+    ducked: bool,
+}
+
+impl Volume {
+    fn new(level: f32) -> Self {
+        Self {
+            level: level.clamp(0.0, 1.0),
+            muted: false,
+            ducked: false,
+        }
+    }
+
+    /// Level actually sent to the backend
+    fn output(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else if self.ducked {
+            self.level * crate::constants::audio::DUCK_LEVEL
+        } else {
+            self.level
+        }
+    }
+
+    /// Set the user's level; changing it by hand also unmutes
+    fn set_level(&mut self, level: f32) {
+        self.level = level.clamp(0.0, 1.0);
+        self.muted = false;
+    }
+}
+
+/// Fall back to the system default device after the selected one disappeared.
+/// The preferred device stays in the config so it is used again next start.
+fn handle_device_lost(
+    backend: &mut dyn PlaybackBackend,
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+) {
+    let lost = backend.current_output_device();
+    let error = backend.set_output_device(None).err().map(|e| e.to_string());
+    let _ = app_event_tx.send(AppEvent::AudioDeviceLost {
+        device: lost,
+        error,
+    });
+}
+
+/// The local backend, parked while a network renderer plays
+struct Casting {
+    /// Label of the renderer, e.g. "Kitchen (Sonos)"
+    target: String,
+    local: Box<dyn PlaybackBackend>,
+}
+
+/// Where the previous backend was in the episode, to continue from there
+#[derive(Debug, Clone, Copy)]
+struct Handoff {
+    position: Option<Duration>,
+    paused: bool,
+}
+
+impl Handoff {
+    /// `None` when nothing is playing or paused
+    fn capture(backend: &dyn PlaybackBackend) -> Option<Self> {
+        let paused = backend.is_paused();
+        (backend.is_playing() || paused).then(|| Self {
+            position: backend.position(),
+            paused,
+        })
+    }
+
+    fn apply(&self, backend: &mut dyn PlaybackBackend, path: &Path) -> Result<(), AudioError> {
+        backend.play(path)?;
+        if let Some(position) = self.position.filter(|p| !p.is_zero()) {
+            backend.seek(position)?;
+        }
+        if self.paused {
+            backend.pause();
+        }
+        Ok(())
+    }
+}
+
+/// Move playback to `target` (`None` = back to this computer). If the
+/// renderer can't be reached, playback stays where it was; if it refuses
+/// the episode, playback goes back to this computer.
+fn switch_cast_target(
+    target: Option<CastTarget>,
+    backend: &mut Box<dyn PlaybackBackend>,
+    casting: &mut Option<Casting>,
+    current_path: Option<&Path>,
+    volume: &Volume,
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+) {
+    let label = target.as_ref().map(CastTarget::label);
+    let next: Box<dyn PlaybackBackend> = match target {
+        Some(target) => match CastBackend::connect(target) {
+            Ok(cast) => Box::new(cast),
+            Err(e) => {
+                let _ = app_event_tx.send(AppEvent::CastFailed {
+                    target: label.unwrap_or_default(),
+                    error: e.to_string(),
+                });
+                return;
+            }
+        },
+        None => match casting.take() {
+            Some(parked) => parked.local,
+            None => {
+                let _ = app_event_tx.send(AppEvent::CastTargetChanged { target: None });
+                return;
+            }
+        },
+    };
+
+    let handoff = Handoff::capture(&**backend);
+    backend.stop();
+    let previous = std::mem::replace(backend, next);
+    if let Some(target) = label.clone() {
+        // Keep the local backend parked; a previous renderer is dropped
+        let local = match casting.take() {
+            Some(parked) => parked.local,
+            None => previous,
+        };
+        *casting = Some(Casting { target, local });
+    }
+    backend.set_volume(volume.output());
+
+    if let (Some(handoff), Some(path)) = (handoff, current_path) {
+        if let Err(e) = handoff.apply(&mut **backend, path) {
+            match (label, casting.take()) {
+                (Some(target), Some(parked)) => {
+                    let _ = app_event_tx.send(AppEvent::CastFailed {
+                        target,
+                        error: e.to_string(),
+                    });
+                    *backend = parked.local;
+                    backend.set_volume(volume.output());
+                    let _ = handoff.apply(&mut **backend, path);
+                }
+                _ => {
+                    let _ = app_event_tx.send(AppEvent::PlaybackError {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    let _ = app_event_tx.send(AppEvent::CastTargetChanged {
+        target: casting.as_ref().map(|c| c.target.clone()),
+    });
+}
+
+/// Play `path`, handing it to the fallback player when the backend can't
+/// decode it. On success through the fallback, `backend` is the fallback
+/// player and the original waits in `parked_primary`.
+fn play_or_fall_back(
+    backend: &mut Box<dyn PlaybackBackend>,
+    path: &Path,
+    fallback: Option<&mut FallbackPlayer>,
+    parked_primary: &mut Option<Box<dyn PlaybackBackend>>,
+    volume: &Volume,
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+) -> Result<(), AudioError> {
+    backend.stop();
+    let error = match backend.play(path) {
+        Ok(()) => return Ok(()),
+        Err(e) if e.is_decoding() => e,
+        Err(e) => return Err(e),
+    };
+    let Some(open_fallback) = fallback else {
+        return Err(error);
+    };
+    let (player, mut next) = match open_fallback() {
+        Ok(opened) => opened,
+        Err(_) => {
+            return Err(match error {
+                AudioError::UnsupportedCodec { codec, reason } => AudioError::UnsupportedCodec {
+                    codec,
+                    reason: format!("{reason}; install mpv, vlc or ffplay to play it"),
+                },
+                other => other,
+            })
+        }
+    };
+    next.set_volume(volume.output());
+    // The fallback's own failure is the more useful one to report
+    next.play(path)?;
+    let _ = app_event_tx.send(AppEvent::PlaybackFellBack {
+        player,
+        error: error.to_string(),
+    });
+    *parked_primary = Some(std::mem::replace(backend, next));
+    Ok(())
+}
+
+/// Seek a just-started episode to where it was left off. A failed seek
+/// only costs the resume, so playback carries on from the start.
+fn resume_at(backend: &mut dyn PlaybackBackend, start_at: Option<Duration>) {
+    if let Some(position) = start_at.filter(|p| !p.is_zero()) {
+        if let Err(e) = backend.seek(position) {
+            eprintln!("Resume at {}s failed: {e}", position.as_secs());
+        }
+    }
+}
+
+/// Tell the UI how starting an episode went
+fn report_play(
+    result: Result<(), AudioError>,
+    episode_id: EpisodeId,
+    podcast_id: PodcastId,
+    current_episode: &mut Option<(EpisodeId, PodcastId)>,
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+) {
+    match result {
+        Ok(()) => {
+            *current_episode = Some((episode_id.clone(), podcast_id.clone()));
+            let _ = app_event_tx.send(AppEvent::PlaybackStarted {
+                podcast_id,
+                episode_id,
+            });
+        }
+        Err(e) => {
+            *current_episode = None;
+            let _ = app_event_tx.send(AppEvent::PlaybackError {
+                error: e.to_string(),
+            });
+        }
+    }
+}
+
 fn process_command(
     cmd: AudioCommand,
     backend: &mut dyn PlaybackBackend,
     app_event_tx: &mpsc::UnboundedSender<AppEvent>,
     current_episode: &mut Option<(EpisodeId, PodcastId)>,
-    volume: &mut f32,
+    volume: &mut Volume,
 ) {
     match cmd {
         AudioCommand::Play {
             path,
             episode_id,
             podcast_id,
+            start_at,
         } => {
             backend.stop();
-            match backend.play(&path) {
-                Ok(()) => {
-                    *current_episode = Some((episode_id.clone(), podcast_id.clone()));
-                    let _ = app_event_tx.send(AppEvent::PlaybackStarted {
-                        podcast_id,
-                        episode_id,
-                    });
-                }
-                Err(e) => {
-                    *current_episode = None;
-                    let _ = app_event_tx.send(AppEvent::PlaybackError {
-                        error: e.to_string(),
-                    });
-                }
-            }
+            let result = backend.play(&path).map(|()| resume_at(backend, start_at));
+            report_play(
+                result,
+                episode_id,
+                podcast_id,
+                current_episode,
+                app_event_tx,
+            );
         }
         AudioCommand::TogglePlayPause => {
             if backend.is_playing() {
@@ -240,17 +590,51 @@ fn process_command(
             }
         }
         AudioCommand::SetVolume(v) => {
-            *volume = v.clamp(0.0, 1.0);
-            backend.set_volume(*volume);
+            volume.set_level(v);
+            backend.set_volume(volume.output());
         }
         AudioCommand::VolumeUp => {
-            *volume = (*volume + crate::constants::audio::VOLUME_STEP).clamp(0.0, 1.0);
-            backend.set_volume(*volume);
+            volume.set_level(volume.level + crate::constants::audio::VOLUME_STEP);
+            backend.set_volume(volume.output());
         }
         AudioCommand::VolumeDown => {
-            *volume = (*volume - crate::constants::audio::VOLUME_STEP).clamp(0.0, 1.0);
-            backend.set_volume(*volume);
+            volume.set_level(volume.level - crate::constants::audio::VOLUME_STEP);
+            backend.set_volume(volume.output());
+        }
+        AudioCommand::SetSpeed(speed) => backend.set_speed(speed),
+        AudioCommand::ToggleMute => {
+            volume.muted = !volume.muted;
+            backend.set_volume(volume.output());
+        }
+        AudioCommand::Duck(ducked) => {
+            volume.ducked = ducked;
+            backend.set_volume(volume.output());
+        }
+        AudioCommand::ToggleDuck => {
+            volume.ducked = !volume.ducked;
+            backend.set_volume(volume.output());
         }
+        AudioCommand::ListOutputDevices => {
+            let _ = app_event_tx.send(AppEvent::AudioDevicesListed {
+                devices: backend.output_devices(),
+                current: backend.current_output_device(),
+            });
+        }
+        AudioCommand::SelectOutputDevice(device) => {
+            match backend.set_output_device(device.as_deref()) {
+                Ok(()) => {
+                    let _ = app_event_tx.send(AppEvent::AudioDeviceChanged { device });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::AudioDeviceSelectFailed {
+                        device: device.unwrap_or_else(|| "system default".to_string()),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+        // Swaps the backend itself, so `run_loop` handles it
+        AudioCommand::CastTo(_) => {}
     }
 }
 
@@ -268,8 +652,14 @@ mod tests {
         playing: bool,
         paused: bool,
         volume: f32,
-        /// When `true`, `play()` returns a `DecodingFailed` error.
+        /// When `true`, `play()` returns an `UnsupportedCodec` error.
         fail_play: bool,
+        devices: Vec<String>,
+        device: Option<String>,
+        device_lost: bool,
+        /// Last position sought to
+        seeked_to: Option<Duration>,
+        speed: f32,
     }
 
     impl MockBackend {
@@ -279,6 +669,11 @@ mod tests {
                 paused: false,
                 volume: crate::constants::audio::DEFAULT_VOLUME,
                 fail_play: false,
+                devices: vec!["Speakers".to_string(), "USB Headset".to_string()],
+                device: None,
+                device_lost: false,
+                seeked_to: None,
+                speed: 1.0,
             }
         }
 
@@ -293,7 +688,10 @@ mod tests {
     impl PlaybackBackend for MockBackend {
         fn play(&mut self, _path: &std::path::Path) -> Result<(), AudioError> {
             if self.fail_play {
-                return Err(AudioError::DecodingFailed("mock error".into()));
+                return Err(AudioError::UnsupportedCodec {
+                    codec: "Opus audio (Ogg)".into(),
+                    reason: "mock error".into(),
+                });
             }
             self.playing = true;
             self.paused = false;
@@ -315,12 +713,19 @@ mod tests {
             self.playing = false;
             self.paused = false;
         }
-        fn seek(&mut self, _: Duration) -> Result<(), AudioError> {
+        fn seek(&mut self, position: Duration) -> Result<(), AudioError> {
+            self.seeked_to = Some(position);
             Ok(())
         }
         fn set_volume(&mut self, v: f32) {
             self.volume = v.clamp(0.0, 1.0);
         }
+        fn set_speed(&mut self, speed: f32) {
+            self.speed = speed;
+        }
+        fn speed(&self) -> f32 {
+            self.speed
+        }
         fn position(&self) -> Option<Duration> {
             if self.playing || self.paused {
                 Some(Duration::from_secs(1))
@@ -340,6 +745,26 @@ mod tests {
         fn is_stopped(&self) -> bool {
             !self.playing && !self.paused
         }
+        fn output_devices(&self) -> Vec<String> {
+            self.devices.clone()
+        }
+        fn current_output_device(&self) -> Option<String> {
+            self.device.clone()
+        }
+        fn set_output_device(&mut self, name: Option<&str>) -> Result<(), AudioError> {
+            match name {
+                Some(name) if !self.devices.iter().any(|d| d == name) => {
+                    Err(AudioError::DeviceUnavailable(name.to_string()))
+                }
+                _ => {
+                    self.device = name.map(str::to_string);
+                    Ok(())
+                }
+            }
+        }
+        fn take_device_lost(&mut self) -> bool {
+            std::mem::take(&mut self.device_lost)
+        }
     }
 
     // ── Helpers ───────────────────────────────────────────────────────────────
@@ -363,7 +788,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, mut rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
         let (ep_id, pod_id) = test_ids();
 
         // Act
@@ -372,6 +797,7 @@ mod tests {
                 path: "/tmp/ep.mp3".into(),
                 episode_id: ep_id.clone(),
                 podcast_id: pod_id.clone(),
+                start_at: None,
             },
             &mut backend,
             &tx,
@@ -388,6 +814,34 @@ mod tests {
         assert!(matches!(event, AppEvent::PlaybackStarted { .. }));
     }
 
+    #[test]
+    fn test_process_command_play_resumes_at_start_position() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, _rx) = make_app_channels();
+        let mut current_episode = None;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+        let (ep_id, pod_id) = test_ids();
+
+        // Act
+        process_command(
+            AudioCommand::Play {
+                path: "/tmp/ep.mp3".into(),
+                episode_id: ep_id,
+                podcast_id: pod_id,
+                start_at: Some(Duration::from_secs(754)),
+            },
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+
+        // Assert — playback started, then jumped to the saved position
+        assert!(backend.is_playing());
+        assert_eq!(backend.seeked_to, Some(Duration::from_secs(754)));
+    }
+
     // ── process_command — Play failure ────────────────────────────────────────
 
     #[test]
@@ -396,7 +850,7 @@ mod tests {
         let mut backend = MockBackend::new_failing();
         let (tx, mut rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
         let (ep_id, pod_id) = test_ids();
 
         // Act
@@ -405,6 +859,7 @@ mod tests {
                 path: "/tmp/ep.mp3".into(),
                 episode_id: ep_id,
                 podcast_id: pod_id,
+                start_at: None,
             },
             &mut backend,
             &tx,
@@ -419,6 +874,72 @@ mod tests {
         assert!(matches!(event, AppEvent::PlaybackError { .. }));
     }
 
+    // ── Decode fallback ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_play_or_fall_back_hands_undecodable_file_to_fallback_player() {
+        // Arrange
+        let mut backend: Box<dyn PlaybackBackend> = Box::new(MockBackend::new_failing());
+        let mut fallback: FallbackPlayer = Box::new(|| {
+            Ok((
+                "mpv".to_string(),
+                Box::new(MockBackend::new()) as Box<dyn PlaybackBackend>,
+            ))
+        });
+        let mut parked_primary = None;
+        let (tx, mut rx) = make_app_channels();
+        let volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+
+        // Act
+        let result = play_or_fall_back(
+            &mut backend,
+            Path::new("/tmp/ep.opus"),
+            Some(&mut fallback),
+            &mut parked_primary,
+            &volume,
+            &tx,
+        );
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(backend.is_playing(), "the fallback player has the episode");
+        assert!(parked_primary.is_some(), "the built-in backend is kept");
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::PlaybackFellBack { ref player, ref error })
+                if player == "mpv" && error.contains("Opus")
+        ));
+    }
+
+    #[test]
+    fn test_play_or_fall_back_names_the_codec_and_fix_without_a_player() {
+        // Arrange
+        let mut backend: Box<dyn PlaybackBackend> = Box::new(MockBackend::new_failing());
+        let mut fallback: FallbackPlayer =
+            Box::new(|| Err(AudioError::ExternalPlayerNotFound("none".into())));
+        let mut parked_primary = None;
+        let (tx, mut rx) = make_app_channels();
+        let volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+
+        // Act
+        let error = play_or_fall_back(
+            &mut backend,
+            Path::new("/tmp/ep.opus"),
+            Some(&mut fallback),
+            &mut parked_primary,
+            &volume,
+            &tx,
+        )
+        .unwrap_err()
+        .to_string();
+
+        // Assert
+        assert!(error.contains("Can't decode Opus audio (Ogg)"), "{error}");
+        assert!(error.contains("install mpv, vlc or ffplay"), "{error}");
+        assert!(parked_primary.is_none());
+        assert!(rx.try_recv().is_err());
+    }
+
     // ── process_command — Stop ────────────────────────────────────────────────
 
     #[test]
@@ -428,7 +949,7 @@ mod tests {
         let (tx, mut rx) = make_app_channels();
         let (ep_id, pod_id) = test_ids();
         let mut current_episode = Some((ep_id, pod_id));
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
 
         // Act
         process_command(
@@ -455,7 +976,7 @@ mod tests {
         backend.playing = true;
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
 
         // Act
         process_command(
@@ -478,7 +999,7 @@ mod tests {
         backend.paused = true;
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
 
         // Act
         process_command(
@@ -502,7 +1023,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = 0.98f32;
+        let mut volume = Volume::new(0.98);
 
         // Act
         process_command(
@@ -514,8 +1035,8 @@ mod tests {
         );
 
         // Assert — clamped to 1.0
-        assert!(volume <= 1.0, "volume {volume} must be ≤ 1.0");
-        assert!((backend.volume - volume).abs() < f32::EPSILON);
+        assert!(volume.level <= 1.0, "volume {} must be ≤ 1.0", volume.level);
+        assert!((backend.volume - volume.level).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -524,7 +1045,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = 0.02f32;
+        let mut volume = Volume::new(0.02);
 
         // Act
         process_command(
@@ -536,8 +1057,8 @@ mod tests {
         );
 
         // Assert — clamped to 0.0
-        assert!(volume >= 0.0, "volume {volume} must be ≥ 0.0");
-        assert!((backend.volume - volume).abs() < f32::EPSILON);
+        assert!(volume.level >= 0.0, "volume {} must be ≥ 0.0", volume.level);
+        assert!((backend.volume - volume.level).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -546,7 +1067,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = 0.5f32;
+        let mut volume = Volume::new(0.5);
 
         // Act
         process_command(
@@ -558,7 +1079,7 @@ mod tests {
         );
 
         // Assert
-        assert!((volume - 0.3).abs() < f32::EPSILON);
+        assert!((volume.level - 0.3).abs() < f32::EPSILON);
         assert!((backend.volume - 0.3).abs() < f32::EPSILON);
     }
 
@@ -568,7 +1089,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = 0.5f32;
+        let mut volume = Volume::new(0.5);
 
         // Act
         process_command(
@@ -580,18 +1101,107 @@ mod tests {
         );
 
         // Assert
-        assert!((volume - 1.0).abs() < f32::EPSILON);
+        assert!((volume.level - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_process_command_set_speed_applies_to_backend() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, _rx) = make_app_channels();
+        let mut current_episode = None;
+        let mut volume = Volume::new(0.5);
+
+        // Act
+        process_command(
+            AudioCommand::SetSpeed(1.5),
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+
+        // Assert
+        assert!((backend.speed() - 1.5).abs() < f32::EPSILON);
     }
 
     // ── process_command — SeekForward / SeekBackward ──────────────────────────
 
+    #[test]
+    fn test_process_command_mute_toggle_restores_prior_volume() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, _rx) = make_app_channels();
+        let mut current_episode = None;
+        let mut volume = Volume::new(0.6);
+
+        // Act / Assert — muting silences without forgetting the level
+        process_command(
+            AudioCommand::ToggleMute,
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+        assert!(volume.muted);
+        assert_eq!(backend.volume, 0.0);
+
+        process_command(
+            AudioCommand::ToggleMute,
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+        assert!(!volume.muted);
+        assert!((backend.volume - 0.6).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_process_command_duck_lowers_and_restores_volume() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, _rx) = make_app_channels();
+        let mut current_episode = None;
+        let mut volume = Volume::new(0.5);
+
+        // Act — duck, nudge the volume while ducked, then restore
+        process_command(
+            AudioCommand::Duck(true),
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+        let ducked = backend.volume;
+        process_command(
+            AudioCommand::VolumeUp,
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+        process_command(
+            AudioCommand::Duck(false),
+            &mut backend,
+            &tx,
+            &mut current_episode,
+            &mut volume,
+        );
+
+        // Assert
+        assert!((ducked - 0.5 * crate::constants::audio::DUCK_LEVEL).abs() < f32::EPSILON);
+        let expected = 0.5 + crate::constants::audio::VOLUME_STEP;
+        assert!((backend.volume - expected).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_process_command_seek_forward_when_stopped_seeks_to_delta() {
         // Arrange — backend stopped (position() returns None)
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
 
         // Act — SeekForward from 0 by 10 s → target = 0 + 10 = 10 s
         process_command(
@@ -610,7 +1220,7 @@ mod tests {
         let mut backend = MockBackend::new();
         let (tx, _rx) = make_app_channels();
         let mut current_episode = None;
-        let mut volume = crate::constants::audio::DEFAULT_VOLUME;
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
 
         // Act — seeking backward from 0 should saturate to 0 (not underflow)
         process_command(
@@ -623,6 +1233,137 @@ mod tests {
         // Assert — no panic (Duration::saturating_sub doesn't underflow)
     }
 
+    // ── Output devices ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_process_command_list_output_devices_reports_current_device() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        backend.device = Some("USB Headset".to_string());
+        let (tx, mut rx) = make_app_channels();
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+
+        // Act
+        process_command(
+            AudioCommand::ListOutputDevices,
+            &mut backend,
+            &tx,
+            &mut None,
+            &mut volume,
+        );
+
+        // Assert
+        match rx.try_recv() {
+            Ok(AppEvent::AudioDevicesListed { devices, current }) => {
+                assert_eq!(devices.len(), 2);
+                assert_eq!(current.as_deref(), Some("USB Headset"));
+            }
+            other => panic!("expected AudioDevicesListed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_process_command_select_unknown_device_keeps_current_and_reports() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, mut rx) = make_app_channels();
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+
+        // Act
+        process_command(
+            AudioCommand::SelectOutputDevice(Some("Unplugged DAC".to_string())),
+            &mut backend,
+            &tx,
+            &mut None,
+            &mut volume,
+        );
+
+        // Assert
+        assert!(backend.device.is_none());
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::AudioDeviceSelectFailed { ref device, .. }) if device == "Unplugged DAC"
+        ));
+    }
+
+    #[test]
+    fn test_process_command_select_device_switches_and_reports() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        let (tx, mut rx) = make_app_channels();
+        let mut volume = Volume::new(crate::constants::audio::DEFAULT_VOLUME);
+
+        // Act
+        process_command(
+            AudioCommand::SelectOutputDevice(Some("Speakers".to_string())),
+            &mut backend,
+            &tx,
+            &mut None,
+            &mut volume,
+        );
+
+        // Assert
+        assert_eq!(backend.device.as_deref(), Some("Speakers"));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::AudioDeviceChanged { device: Some(ref d) }) if d == "Speakers"
+        ));
+    }
+
+    #[test]
+    fn test_device_lost_falls_back_to_default_and_keeps_playing() {
+        // Arrange
+        let mut backend = MockBackend::new();
+        backend.playing = true;
+        backend.device = Some("USB Headset".to_string());
+        let (tx, mut rx) = make_app_channels();
+
+        // Act
+        handle_device_lost(&mut backend, &tx);
+
+        // Assert
+        assert!(backend.device.is_none());
+        assert!(backend.is_playing());
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::AudioDeviceLost { device: Some(ref d), error: None }) if d == "USB Headset"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_run_loop_reports_device_loss_without_ending_track() {
+        // Arrange
+        let (command_tx, command_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        let (status_tx, _status_rx) = watch::channel(PlaybackStatus::default());
+        let (app_tx, mut app_rx) = mpsc::unbounded_channel::<AppEvent>();
+        let mut backend = MockBackend::new();
+        backend.device_lost = true;
+        let initial_volume = crate::constants::audio::DEFAULT_VOLUME;
+
+        let _thread = std::thread::spawn(move || {
+            run_loop(
+                Box::new(backend),
+                command_rx,
+                status_tx,
+                app_tx,
+                initial_volume,
+                None,
+            );
+        });
+
+        // Assert — the first tick reports the loss
+        let deadline = std::time::Instant::now() + Duration::from_secs(1);
+        let event = loop {
+            if let Ok(e) = app_rx.try_recv() {
+                break e;
+            }
+            assert!(std::time::Instant::now() < deadline, "no event within 1 s");
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        };
+        assert!(matches!(event, AppEvent::AudioDeviceLost { .. }));
+        drop(command_tx);
+    }
+
     // ── Track-ended detection ─────────────────────────────────────────────────
 
     #[test]
@@ -683,6 +1424,89 @@ mod tests {
         assert!(current_episode.is_some());
     }
 
+    // ── Casting ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_handoff_resumes_paused_episode_paused() {
+        // Arrange
+        let mut from = MockBackend::new();
+        from.play(std::path::Path::new("/fake/ep.mp3")).unwrap();
+        from.pause();
+        let mut to = MockBackend::new();
+
+        // Act
+        let handoff = Handoff::capture(&from).expect("episode in progress");
+        handoff
+            .apply(&mut to, std::path::Path::new("/fake/ep.mp3"))
+            .unwrap();
+
+        // Assert
+        assert_eq!(handoff.position, Some(Duration::from_secs(1)));
+        assert!(to.is_paused());
+        assert!(Handoff::capture(&MockBackend::new()).is_none());
+    }
+
+    #[test]
+    fn test_refused_cast_falls_back_to_local_playback() {
+        // Arrange — nothing listens on the renderer's control port
+        let (tx, mut rx) = make_app_channels();
+        let mut backend: Box<dyn PlaybackBackend> = Box::new(MockBackend::new());
+        let path = std::path::PathBuf::from("/fake/ep.mp3");
+        backend.play(&path).unwrap();
+        let mut casting = None;
+        let target = CastTarget {
+            name: "Kitchen".to_string(),
+            kind: crate::audio::cast::CastKind::Upnp,
+            address: [127, 0, 0, 1].into(),
+            endpoint: crate::audio::cast::CastEndpoint::Upnp {
+                av_transport: "http://127.0.0.1:9/AVTransport/Control".to_string(),
+                rendering_control: None,
+            },
+        };
+
+        // Act
+        switch_cast_target(
+            Some(target),
+            &mut backend,
+            &mut casting,
+            Some(&path),
+            &Volume::new(0.5),
+            &tx,
+        );
+
+        // Assert
+        assert!(casting.is_none());
+        assert!(backend.is_playing(), "playback continues on this computer");
+        assert!(
+            matches!(rx.try_recv(), Ok(AppEvent::CastFailed { ref target, .. }) if target == "Kitchen (UPnP)")
+        );
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::CastTargetChanged { target: None })
+        ));
+    }
+
+    #[test]
+    fn test_stop_casting_when_not_casting_only_confirms() {
+        let (tx, mut rx) = make_app_channels();
+        let mut backend: Box<dyn PlaybackBackend> = Box::new(MockBackend::new());
+        let mut casting = None;
+
+        switch_cast_target(
+            None,
+            &mut backend,
+            &mut casting,
+            None,
+            &Volume::new(0.5),
+            &tx,
+        );
+
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::CastTargetChanged { target: None })
+        ));
+    }
+
     // ── run_loop channel plumbing ─────────────────────────────────────────────
 
     #[tokio::test]
@@ -696,7 +1520,7 @@ mod tests {
         let initial_volume = crate::constants::audio::DEFAULT_VOLUME;
 
         let _thread = std::thread::spawn(move || {
-            run_loop(backend, command_rx, status_tx, app_tx, initial_volume);
+            run_loop(backend, command_rx, status_tx, app_tx, initial_volume, None);
         });
 
         let (ep_id, pod_id) = test_ids();
@@ -707,6 +1531,7 @@ mod tests {
                 path: "/fake/ep.mp3".into(),
                 episode_id: ep_id,
                 podcast_id: pod_id,
+                start_at: None,
             })
             .unwrap();
 
@@ -741,7 +1566,7 @@ mod tests {
         let initial_volume = crate::constants::audio::DEFAULT_VOLUME;
 
         let thread = std::thread::spawn(move || {
-            run_loop(backend, command_rx, status_tx, app_tx, initial_volume);
+            run_loop(backend, command_rx, status_tx, app_tx, initial_volume, None);
         });
 
         // Act — drop the sender to disconnect the channel
@@ -753,42 +1578,4 @@ mod tests {
             .unwrap();
         assert!(result.is_ok(), "audio thread should exit without panicking");
     }
-
-    // ── Backend selection ─────────────────────────────────────────────────────
-
-    #[test]
-    fn test_create_backend_uses_external_player_when_configured() {
-        // Arrange — config with explicit external_player
-        let config = AudioConfig {
-            external_player: Some("mpv".into()),
-            ..Default::default()
-        };
-
-        // Act
-        let result = create_backend(&config);
-
-        // Assert — always succeeds: ExternalPlayerBackend::new() doesn't check existence
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_create_backend_succeeds_or_returns_rodio_error_when_no_external_player() {
-        // Arrange — no external player configured
-        let config = AudioConfig {
-            external_player: None,
-            ..Default::default()
-        };
-
-        // Act — may succeed (audio device present) or fail (headless CI)
-        let result = create_backend(&config);
-
-        // Assert — either Ok or an AudioError; the function must not panic
-        match result {
-            Ok(_) => {} // audio device present or external player found
-            Err(e) => {
-                // Must be an AudioError variant, not a panic
-                eprintln!("No audio backend available (expected in headless CI): {e}");
-            }
-        }
-    }
 }
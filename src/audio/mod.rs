@@ -11,8 +11,13 @@ use std::time::Duration;
 
 use crate::storage::{EpisodeId, PodcastId};
 
+pub mod cast;
 pub mod external;
 pub mod manager;
+#[cfg(target_os = "linux")]
+pub mod mpris;
+pub mod probe;
+pub mod queue;
 pub mod rodio_backend;
 
 /// Errors that can occur during audio playback.
@@ -20,8 +25,13 @@ pub mod rodio_backend;
 pub enum AudioError {
     #[error("Audio device not found")]
     DeviceNotFound,
+    #[error("Audio device not available: {0}")]
+    DeviceUnavailable(String),
     #[error("Failed to decode audio file: {0}")]
     DecodingFailed(String),
+    /// The built-in decoder can't read the file; `codec` is what it appears to hold
+    #[error("Can't decode {codec}: {reason}")]
+    UnsupportedCodec { codec: String, reason: String },
     #[error("Seek failed: {0}")]
     SeekFailed(String),
     #[error("External player not found: {0}")]
@@ -32,6 +42,16 @@ pub enum AudioError {
     Io(#[from] std::io::Error),
 }
 
+impl AudioError {
+    /// The file was read but couldn't be decoded, so another player may manage
+    pub fn is_decoding(&self) -> bool {
+        matches!(
+            self,
+            Self::DecodingFailed(_) | Self::UnsupportedCodec { .. }
+        )
+    }
+}
+
 /// Current playback state.
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlaybackState {
@@ -47,6 +67,8 @@ pub enum AudioCommand {
         path: std::path::PathBuf,
         episode_id: EpisodeId,
         podcast_id: PodcastId,
+        /// Where to resume a partly played episode from
+        start_at: Option<Duration>,
     },
     Pause,
     Resume,
@@ -55,8 +77,24 @@ pub enum AudioCommand {
     SeekForward(Duration),
     SeekBackward(Duration),
     SetVolume(f32),
+    /// Playback speed (1.0 = normal), kept for the tracks that follow until
+    /// changed; sent before `Play` with the podcast's own speed
+    SetSpeed(f32),
     VolumeUp,
     VolumeDown,
+    /// Report the available output devices via `AppEvent::AudioDevicesListed`
+    ListOutputDevices,
+    /// Switch output device (`None` = system default), continuing the current track
+    SelectOutputDevice(Option<String>),
+    /// Silence output, or restore the volume from before muting
+    ToggleMute,
+    /// Drop to `DUCK_LEVEL` of the volume (`true`) or back to full (`false`),
+    /// e.g. around a notification sound
+    Duck(bool),
+    ToggleDuck,
+    /// Play through a network renderer (`None` = back to this computer),
+    /// continuing the current episode from where it is
+    CastTo(Option<cast::CastTarget>),
 }
 
 /// Playback status broadcast from `AudioManager` to the UI.
@@ -67,7 +105,12 @@ pub struct PlaybackStatus {
     pub podcast_id: Option<PodcastId>,
     pub position: Option<Duration>,
     pub duration: Option<Duration>,
+    /// The user's volume; mute and ducking are applied on top of it
     pub volume: f32,
+    pub muted: bool,
+    pub ducked: bool,
+    /// Playback speed (1.0 = normal)
+    pub speed: f32,
 }
 
 impl Default for PlaybackStatus {
@@ -79,6 +122,9 @@ impl Default for PlaybackStatus {
             position: None,
             duration: None,
             volume: crate::constants::audio::DEFAULT_VOLUME,
+            muted: false,
+            ducked: false,
+            speed: 1.0,
         }
     }
 }
@@ -99,6 +145,39 @@ pub trait PlaybackBackend: Send {
     fn is_playing(&self) -> bool;
     fn is_paused(&self) -> bool;
     fn is_stopped(&self) -> bool;
+
+    /// Names of the selectable output devices. Empty for backends that
+    /// can't choose a device (e.g. external players).
+    fn output_devices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Device currently in use (`None` = system default)
+    fn current_output_device(&self) -> Option<String> {
+        None
+    }
+
+    /// Switch to `name` (`None` = system default) without losing the current
+    /// track, position or pause state.
+    fn set_output_device(&mut self, _name: Option<&str>) -> Result<(), AudioError> {
+        Err(AudioError::Unsupported(
+            "output device selection".to_string(),
+        ))
+    }
+
+    /// Play at `speed` (1.0 = normal) from now on. Backends that can't change
+    /// speed (e.g. external players) ignore it.
+    fn set_speed(&mut self, _speed: f32) {}
+
+    /// Speed in effect; 1.0 for backends that can't change it
+    fn speed(&self) -> f32 {
+        1.0
+    }
+
+    /// `true` once after the output device disappeared (e.g. headphones unplugged)
+    fn take_device_lost(&mut self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
@@ -146,6 +225,15 @@ mod tests {
         assert_eq!(err.to_string(), "Seek failed: sink error");
     }
 
+    #[test]
+    fn test_audio_error_display_device_unavailable() {
+        // Arrange / Act
+        let err = AudioError::DeviceUnavailable("USB DAC".to_string());
+
+        // Assert
+        assert_eq!(err.to_string(), "Audio device not available: USB DAC");
+    }
+
     #[test]
     fn test_audio_error_display_external_player_not_found() {
         // Arrange / Act
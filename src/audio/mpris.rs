@@ -0,0 +1,568 @@
+// MPRIS — media keys, desktop panels and playerctl control playback (Linux)
+//
+// `serve` registers `org.mpris.MediaPlayer2.podcast_tui.instance<pid>` on the
+// session bus and serves the MPRIS root and player interfaces at
+// `/org/mpris/MediaPlayer2`. Properties mirror the audio thread's
+// `PlaybackStatus`, with PropertiesChanged and Seeked signals sent as it
+// changes; Play, Pause, Stop, Seek, SetPosition, Volume and Rate become
+// `AudioCommand`s. The title, podcast and artwork of a newly started episode
+// are loaded from storage. Next/Previous aren't offered: what plays next is
+// decided by the UI (queue, playlist, auto-play), not the audio thread.
+//
+// Without a session bus (over SSH, in containers) `serve` fails straight away
+// and playback is unaffected.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{mpsc, watch};
+use zbus::{
+    fdo, interface,
+    object_server::SignalEmitter,
+    zvariant::{ObjectPath, OwnedValue, Value},
+};
+
+use super::{AudioCommand, PlaybackState, PlaybackStatus};
+use crate::constants::audio::PLAYBACK_SPEEDS;
+use crate::storage::{EpisodeId, Storage};
+
+const BUS_NAME: &str = "org.mpris.MediaPlayer2.podcast_tui";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+
+/// A position this far from where playback should have got to is a seek
+const SEEK_TOLERANCE: Duration = Duration::from_secs(2);
+
+/// What MPRIS shows of the episode being played
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    pub episode_id: EpisodeId,
+    pub title: String,
+    pub podcast: String,
+    /// Artwork URL, or a local image path
+    pub art_url: Option<String>,
+}
+
+/// Register on the session bus and mirror `status` until the audio thread
+/// goes away
+pub async fn serve<S: Storage + 'static>(
+    storage: Arc<S>,
+    commands: mpsc::UnboundedSender<AudioCommand>,
+    status: watch::Receiver<PlaybackStatus>,
+) -> zbus::Result<()> {
+    let track = Arc::new(Mutex::new(None));
+    let player = Player {
+        commands,
+        status: status.clone(),
+        track: track.clone(),
+    };
+    let connection = zbus::connection::Builder::session()?
+        .name(format!("{}.instance{}", BUS_NAME, std::process::id()))?
+        .serve_at(OBJECT_PATH, Root)?
+        .serve_at(OBJECT_PATH, player)?
+        .build()
+        .await?;
+    let player = connection
+        .object_server()
+        .interface::<_, Player>(OBJECT_PATH)
+        .await?;
+    mirror(storage, status, track, player).await;
+    Ok(())
+}
+
+/// Signal each change of `status` to MPRIS clients
+async fn mirror<S: Storage>(
+    storage: Arc<S>,
+    mut status: watch::Receiver<PlaybackStatus>,
+    track: Arc<Mutex<Option<Track>>>,
+    player: zbus::object_server::InterfaceRef<Player>,
+) {
+    let mut shown = Shown::default();
+    while status.changed().await.is_ok() {
+        let current = status.borrow_and_update().clone();
+
+        let loaded = track.lock().map(|t| t.clone()).unwrap_or_default();
+        if current.episode_id != loaded.as_ref().map(|t| t.episode_id.clone()) {
+            let new_track = match (&current.podcast_id, &current.episode_id) {
+                (Some(podcast_id), Some(episode_id)) => {
+                    load_track(storage.as_ref(), podcast_id, episode_id).await
+                }
+                _ => None,
+            };
+            if let Ok(mut track) = track.lock() {
+                *track = new_track;
+            }
+        }
+
+        let next = Shown::of(&current);
+        let emitter = player.signal_emitter();
+        let iface = player.get().await;
+        if next.episode_id != shown.episode_id || next.duration != shown.duration {
+            let _ = iface.metadata_changed(emitter).await;
+        }
+        if next.state != shown.state {
+            let _ = iface.playback_status_changed(emitter).await;
+            let _ = iface.can_play_changed(emitter).await;
+            let _ = iface.can_pause_changed(emitter).await;
+            let _ = iface.can_seek_changed(emitter).await;
+        }
+        if next.volume != shown.volume {
+            let _ = iface.volume_changed(emitter).await;
+        }
+        if next.speed != shown.speed {
+            let _ = iface.rate_changed(emitter).await;
+        }
+        if next.episode_id == shown.episode_id && shown.jumped_to(&next) {
+            if let Some(position) = next.position {
+                let _ = Player::seeked(emitter, micros(position)).await;
+            }
+        }
+        shown = next;
+    }
+}
+
+async fn load_track<S: Storage>(
+    storage: &S,
+    podcast_id: &crate::storage::PodcastId,
+    episode_id: &EpisodeId,
+) -> Option<Track> {
+    let episode = storage.load_episode(podcast_id, episode_id).await.ok()?;
+    let podcast = storage.load_podcast(podcast_id).await.ok()?;
+    Some(Track {
+        episode_id: episode.id.clone(),
+        title: episode.display_title().to_string(),
+        art_url: episode
+            .artwork_url()
+            .map(str::to_string)
+            .or(podcast.image_url),
+        podcast: podcast.title,
+    })
+}
+
+/// The state last signalled, to tell what changed
+#[derive(Debug, Clone, PartialEq)]
+struct Shown {
+    state: PlaybackState,
+    episode_id: Option<EpisodeId>,
+    duration: Option<Duration>,
+    volume: f64,
+    speed: f64,
+    position: Option<Duration>,
+    at: Instant,
+}
+
+impl Default for Shown {
+    fn default() -> Self {
+        Self::of(&PlaybackStatus::default())
+    }
+}
+
+impl Shown {
+    fn of(status: &PlaybackStatus) -> Self {
+        Self {
+            state: status.state.clone(),
+            episode_id: status.episode_id.clone(),
+            duration: status.duration,
+            volume: shown_volume(status),
+            speed: status.speed as f64,
+            position: status.position,
+            at: Instant::now(),
+        }
+    }
+
+    /// Whether `next` is further from where playback should be by now than
+    /// steady playing explains
+    fn jumped_to(&self, next: &Shown) -> bool {
+        let (Some(from), Some(to)) = (self.position, next.position) else {
+            return false;
+        };
+        let expected = if self.state == PlaybackState::Playing {
+            from + next.at.duration_since(self.at).mul_f64(self.speed)
+        } else {
+            from
+        };
+        to.abs_diff(expected) > SEEK_TOLERANCE
+    }
+}
+
+/// `org.mpris.MediaPlayer2`: the application itself
+struct Root;
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl Root {
+    fn raise(&self) {}
+
+    fn quit(&self) {}
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> String {
+        "Podcast TUI".to_string()
+    }
+
+    #[zbus(property)]
+    fn desktop_entry(&self) -> String {
+        "podcast-tui".to_string()
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// `org.mpris.MediaPlayer2.Player`: playback, driven through the audio thread
+struct Player {
+    commands: mpsc::UnboundedSender<AudioCommand>,
+    status: watch::Receiver<PlaybackStatus>,
+    track: Arc<Mutex<Option<Track>>>,
+}
+
+impl Player {
+    fn send(&self, command: AudioCommand) {
+        let _ = self.commands.send(command);
+    }
+
+    fn state(&self) -> PlaybackState {
+        self.status.borrow().state.clone()
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl Player {
+    fn next(&self) {}
+
+    fn previous(&self) {}
+
+    fn pause(&self) {
+        if self.state() == PlaybackState::Playing {
+            self.send(AudioCommand::Pause);
+        }
+    }
+
+    fn play_pause(&self) {
+        if self.state() != PlaybackState::Stopped {
+            self.send(AudioCommand::TogglePlayPause);
+        }
+    }
+
+    fn stop(&self) {
+        self.send(AudioCommand::Stop);
+    }
+
+    /// Resumes a paused episode; starting one is left to the UI
+    fn play(&self) {
+        if self.state() == PlaybackState::Paused {
+            self.send(AudioCommand::Resume);
+        }
+    }
+
+    fn seek(&self, offset: i64) {
+        if let Some(command) = seek_command(offset) {
+            self.send(command);
+        }
+    }
+
+    /// Ignored unless `track_id` is still playing and `position` lies within it
+    fn set_position(&self, track_id: ObjectPath<'_>, position: i64) {
+        let status = self.status.borrow().clone();
+        let Some(episode_id) = &status.episode_id else {
+            return;
+        };
+        let in_track = position >= 0
+            && status
+                .duration
+                .is_none_or(|duration| position <= micros(duration));
+        if track_id != track_path(episode_id) || !in_track {
+            return;
+        }
+        let current = status.position.map_or(0, micros);
+        if let Some(command) = seek_command(position - current) {
+            self.send(command);
+        }
+    }
+
+    fn open_uri(&self, _uri: &str) -> fdo::Result<()> {
+        Err(fdo::Error::NotSupported(
+            "Episodes are opened from podcast-tui".to_string(),
+        ))
+    }
+
+    #[zbus(signal)]
+    async fn seeked(emitter: &SignalEmitter<'_>, position: i64) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> String {
+        playback_status_name(&self.state()).to_string()
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        self.status.borrow().speed as f64
+    }
+
+    /// A rate of 0 pauses, as the spec suggests
+    #[zbus(property)]
+    fn set_rate(&mut self, rate: f64) {
+        if rate <= 0.0 {
+            self.pause();
+        } else {
+            self.send(AudioCommand::SetSpeed(rate as f32));
+        }
+    }
+
+    #[zbus(property)]
+    fn minimum_rate(&self) -> f64 {
+        PLAYBACK_SPEEDS[0] as f64
+    }
+
+    #[zbus(property)]
+    fn maximum_rate(&self) -> f64 {
+        PLAYBACK_SPEEDS[PLAYBACK_SPEEDS.len() - 1] as f64
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        let track = self.track.lock().ok().and_then(|track| track.clone());
+        metadata(track.as_ref(), &self.status.borrow())
+    }
+
+    #[zbus(property)]
+    fn volume(&self) -> f64 {
+        shown_volume(&self.status.borrow())
+    }
+
+    #[zbus(property)]
+    fn set_volume(&mut self, volume: f64) {
+        self.send(AudioCommand::SetVolume(volume.clamp(0.0, 1.0) as f32));
+    }
+
+    /// Polled by clients; jumps are announced with Seeked instead
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn position(&self) -> i64 {
+        self.status.borrow().position.map_or(0, micros)
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        self.state() != PlaybackState::Stopped
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        self.state() != PlaybackState::Stopped
+    }
+
+    #[zbus(property)]
+    fn can_seek(&self) -> bool {
+        self.state() != PlaybackState::Stopped
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+fn playback_status_name(state: &PlaybackState) -> &'static str {
+    match state {
+        PlaybackState::Playing => "Playing",
+        PlaybackState::Paused => "Paused",
+        PlaybackState::Stopped => "Stopped",
+    }
+}
+
+/// Muted playback reads as silent
+fn shown_volume(status: &PlaybackStatus) -> f64 {
+    if status.muted {
+        0.0
+    } else {
+        status.volume as f64
+    }
+}
+
+/// The seek `offset` microseconds asks for; `None` for no movement
+fn seek_command(offset: i64) -> Option<AudioCommand> {
+    let step = Duration::from_micros(offset.unsigned_abs());
+    match offset {
+        0 => None,
+        offset if offset > 0 => Some(AudioCommand::SeekForward(step)),
+        _ => Some(AudioCommand::SeekBackward(step)),
+    }
+}
+
+fn micros(duration: Duration) -> i64 {
+    i64::try_from(duration.as_micros()).unwrap_or(i64::MAX)
+}
+
+/// The MPRIS track id of an episode
+fn track_path(episode_id: &EpisodeId) -> ObjectPath<'static> {
+    // A simple UUID is hex digits only, which object paths allow
+    ObjectPath::from_string_unchecked(format!(
+        "/org/lqdev/podcast_tui/episode/{}",
+        episode_id.0.simple()
+    ))
+}
+
+/// `xesam:` / `mpris:` metadata of the episode playing; empty when stopped
+fn metadata(track: Option<&Track>, status: &PlaybackStatus) -> HashMap<String, OwnedValue> {
+    let Some(track) = track.filter(|track| status.episode_id.as_ref() == Some(&track.episode_id))
+    else {
+        return HashMap::new();
+    };
+    let mut fields: Vec<(&str, Value)> = vec![
+        ("mpris:trackid", Value::from(track_path(&track.episode_id))),
+        ("xesam:title", Value::from(track.title.as_str())),
+        ("xesam:album", Value::from(track.podcast.as_str())),
+        ("xesam:artist", Value::from(vec![track.podcast.as_str()])),
+    ];
+    if let Some(duration) = status.duration {
+        fields.push(("mpris:length", Value::from(micros(duration))));
+    }
+    if let Some(url) = &track.art_url {
+        let url = if url.contains("://") {
+            url.clone()
+        } else {
+            format!("file://{}", url)
+        };
+        fields.push(("mpris:artUrl", Value::from(url)));
+    }
+    fields
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), OwnedValue::try_from(value).ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_player(status: PlaybackStatus) -> (Player, mpsc::UnboundedReceiver<AudioCommand>) {
+        let (commands, rx) = mpsc::unbounded_channel();
+        let (_status_tx, status) = watch::channel(status);
+        let player = Player {
+            commands,
+            status,
+            track: Arc::new(Mutex::new(None)),
+        };
+        (player, rx)
+    }
+
+    fn playing(position_secs: u64) -> PlaybackStatus {
+        PlaybackStatus {
+            state: PlaybackState::Playing,
+            episode_id: Some(EpisodeId::new()),
+            position: Some(Duration::from_secs(position_secs)),
+            duration: Some(Duration::from_secs(600)),
+            ..PlaybackStatus::default()
+        }
+    }
+
+    #[test]
+    fn test_seek_and_set_position_become_relative_seeks() {
+        // Arrange
+        let status = playing(100);
+        let track_id = track_path(status.episode_id.as_ref().unwrap());
+        let (player, mut rx) = make_player(status);
+
+        // Act
+        player.seek(-5_000_000);
+        player.set_position(track_id.clone(), 130_000_000);
+        player.set_position(track_id, 700_000_000); // past the end
+        player.set_position(track_path(&EpisodeId::new()), 0); // another track
+
+        // Assert
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AudioCommand::SeekBackward(step)) if step == Duration::from_secs(5)
+        ));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AudioCommand::SeekForward(step)) if step == Duration::from_secs(30)
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_play_and_pause_follow_the_playback_state() {
+        let (player, mut rx) = make_player(PlaybackStatus::default());
+        player.play();
+        player.play_pause();
+        assert!(rx.try_recv().is_err());
+        assert_eq!(player.playback_status(), "Stopped");
+
+        let (player, mut rx) = make_player(PlaybackStatus {
+            state: PlaybackState::Paused,
+            ..PlaybackStatus::default()
+        });
+        player.pause();
+        player.play();
+        assert!(matches!(rx.try_recv(), Ok(AudioCommand::Resume)));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_metadata_describes_the_episode_playing() {
+        // Arrange
+        let status = playing(0);
+        let track = Track {
+            episode_id: status.episode_id.clone().unwrap(),
+            title: "Episode 1".to_string(),
+            podcast: "Show".to_string(),
+            art_url: Some("/tmp/cover.jpg".to_string()),
+        };
+
+        // Act
+        let fields = metadata(Some(&track), &status);
+
+        // Assert
+        let string = |key: &str| String::try_from(fields[key].clone()).unwrap();
+        assert_eq!(string("xesam:title"), "Episode 1");
+        assert_eq!(string("xesam:album"), "Show");
+        assert_eq!(string("mpris:artUrl"), "file:///tmp/cover.jpg");
+        assert_eq!(
+            i64::try_from(fields["mpris:length"].clone()).unwrap(),
+            600_000_000
+        );
+        assert!(metadata(Some(&track), &playing(0)).is_empty());
+    }
+
+    #[test]
+    fn test_only_unexplained_position_changes_count_as_seeks() {
+        let before = Shown::of(&playing(100));
+        let mut after = Shown::of(&playing(101));
+        after.at = before.at + Duration::from_secs(1);
+        assert!(!before.jumped_to(&after));
+        after.position = Some(Duration::from_secs(160));
+        assert!(before.jumped_to(&after));
+    }
+}
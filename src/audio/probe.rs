@@ -0,0 +1,178 @@
+// Backend probe — pick the audio backend at startup and record why.
+//
+// Backend selection used to try rodio and only fall back to an external
+// player when rodio failed, trusting `external_player` without checking it
+// existed; a missing player or audio device surfaced on the first play. The
+// probe checks everything up front: the configured player, the built-in
+// player's output devices, and which of mpv / vlc / ffplay are installed.
+// What it found is kept in `AudioDiagnostics` for `:audio-diagnostics`, and
+// `startup_warning` tells the UI when playback is degraded or unavailable.
+
+use crate::audio::external::{self, ExternalPlayerBackend};
+use crate::audio::rodio_backend::{self, RodioBackend};
+use crate::audio::PlaybackBackend;
+use crate::config::AudioConfig;
+
+/// What the startup probe found and which backend it chose
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AudioDiagnostics {
+    /// Backend in use, e.g. "built-in player"; `None` when there is no audio output
+    pub selected: Option<String>,
+    /// Why that backend was chosen, or why none could be
+    pub reason: String,
+    /// Outcome of opening the built-in player's output; `None` when not tried
+    pub built_in: Option<Result<(), String>>,
+    /// Output devices the built-in player can see
+    pub output_devices: Vec<String>,
+    pub default_device: Option<String>,
+    /// mpv / vlc / ffplay, those installed, in order of preference
+    pub external_players: Vec<String>,
+    /// `audio.external_player` and whether it could be run
+    pub configured_player: Option<(String, bool)>,
+}
+
+impl AudioDiagnostics {
+    /// Message to show once at startup when playback isn't as configured
+    pub fn startup_warning(&self) -> Option<String> {
+        match (&self.selected, &self.configured_player) {
+            (None, _) => Some(format!(
+                "No audio output: {}. Playback is disabled (:audio-diagnostics)",
+                self.reason
+            )),
+            (Some(selected), Some((player, false))) => Some(format!(
+                "External player \"{}\" not found; using {} (:audio-diagnostics)",
+                player, selected
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Probe the available backends and open the best one for `config`:
+/// 1. `external_player`, if it can be run
+/// 2. The built-in player, if it can open an output device
+/// 3. The first installed of mpv, vlc and ffplay
+pub fn probe_backends(
+    config: &AudioConfig,
+) -> (Option<Box<dyn PlaybackBackend>>, AudioDiagnostics) {
+    let mut diagnostics = AudioDiagnostics {
+        output_devices: rodio_backend::list_output_devices(),
+        default_device: rodio_backend::default_output_device(),
+        external_players: external::installed_players(),
+        configured_player: config
+            .external_player
+            .as_ref()
+            .map(|player| (player.clone(), external::is_installed(player))),
+        ..AudioDiagnostics::default()
+    };
+
+    if let Some((player, true)) = &diagnostics.configured_player {
+        let player = player.clone();
+        diagnostics.selected = Some(format!("external player ({player})"));
+        diagnostics.reason = "audio.external_player is set".to_string();
+        return (
+            Some(Box::new(ExternalPlayerBackend::new(player))),
+            diagnostics,
+        );
+    }
+
+    match RodioBackend::new() {
+        Ok(backend) => {
+            diagnostics.built_in = Some(Ok(()));
+            diagnostics.selected = Some("built-in player".to_string());
+            diagnostics.reason = match &diagnostics.configured_player {
+                Some((player, _)) => format!("audio.external_player \"{player}\" was not found"),
+                None => "an output device is available".to_string(),
+            };
+            (Some(Box::new(backend)), diagnostics)
+        }
+        Err(e) => {
+            diagnostics.built_in = Some(Err(e.to_string()));
+            match diagnostics.external_players.first().cloned() {
+                Some(player) => {
+                    diagnostics.selected = Some(format!("external player ({player})"));
+                    diagnostics.reason = format!("the built-in player failed: {e}");
+                    (
+                        Some(Box::new(ExternalPlayerBackend::new(player))),
+                        diagnostics,
+                    )
+                }
+                None => {
+                    diagnostics.reason = format!(
+                        "the built-in player failed ({e}) and none of {} is installed",
+                        external::PLAYER_CANDIDATES.join(", ")
+                    );
+                    (None, diagnostics)
+                }
+            }
+        }
+    }
+}
+
+// ---------- Tests -----------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_uses_configured_player_when_it_can_run() {
+        // Arrange — cargo is always on PATH where tests run
+        let config = AudioConfig {
+            external_player: Some("cargo".into()),
+            ..Default::default()
+        };
+
+        // Act
+        let (backend, diagnostics) = probe_backends(&config);
+
+        // Assert
+        assert!(backend.is_some());
+        assert_eq!(diagnostics.configured_player, Some(("cargo".into(), true)));
+        assert_eq!(
+            diagnostics.selected.as_deref(),
+            Some("external player (cargo)")
+        );
+        assert!(diagnostics.built_in.is_none(), "built-in player not opened");
+        assert_eq!(diagnostics.startup_warning(), None);
+    }
+
+    #[test]
+    fn test_probe_skips_a_missing_configured_player() {
+        // Arrange
+        let config = AudioConfig {
+            external_player: Some("no-such-player-xyz".into()),
+            ..Default::default()
+        };
+
+        // Act — the outcome depends on the machine's audio; either way the
+        // missing player is reported rather than selected
+        let (backend, diagnostics) = probe_backends(&config);
+
+        // Assert
+        assert_eq!(
+            diagnostics.configured_player,
+            Some(("no-such-player-xyz".into(), false))
+        );
+        assert_eq!(backend.is_some(), diagnostics.selected.is_some());
+        assert!(diagnostics.built_in.is_some());
+        let warning = diagnostics.startup_warning().expect("a warning");
+        assert!(
+            warning.contains("no-such-player-xyz") || warning.starts_with("No audio output"),
+            "{warning}"
+        );
+    }
+
+    #[test]
+    fn test_startup_warning_when_no_backend_explains_why() {
+        let diagnostics = AudioDiagnostics {
+            reason: "no output device".to_string(),
+            ..Default::default()
+        };
+
+        let warning = diagnostics.startup_warning().unwrap();
+
+        assert!(warning.starts_with("No audio output: no output device"));
+        assert!(warning.contains(":audio-diagnostics"));
+    }
+}
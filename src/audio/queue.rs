@@ -0,0 +1,221 @@
+// Playback queue - episodes lined up to play next, kept across restarts
+//
+// Episodes are enqueued from an episode list or What's New (`e`) and shown
+// in the Queue buffer, where they can be reordered or removed. When an
+// episode ends outside a playlist, the first queued one plays and leaves
+// the queue. The queue lives in `queue.json` in the data directory and is
+// saved after every change.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::fs;
+use tokio::sync::Mutex;
+
+use crate::playlist::queue::QueueEntry;
+use crate::storage::{EpisodeId, StorageError};
+
+const QUEUE_FILE: &str = "queue.json";
+
+/// Episodes waiting to be played, in order
+pub struct QueueManager {
+    path: PathBuf,
+    entries: Vec<QueueEntry>,
+    /// Bumped on every change, so an older save never overwrites a newer one
+    generation: u64,
+    /// Generation last written to disk
+    saved: Arc<Mutex<u64>>,
+}
+
+impl QueueManager {
+    /// An empty queue saved in `data_dir`
+    pub fn new(data_dir: &Path) -> Self {
+        Self {
+            path: data_dir.join(QUEUE_FILE),
+            entries: Vec::new(),
+            generation: 0,
+            saved: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// The queue saved in `data_dir`, or an empty one if there is none yet
+    pub async fn load(data_dir: &Path) -> Result<Self, StorageError> {
+        let mut queue = Self::new(data_dir);
+        match fs::read_to_string(&queue.path).await {
+            Ok(content) => queue.entries = serde_json::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(StorageError::file_operation("read", &queue.path, e)),
+        }
+        Ok(queue)
+    }
+
+    pub fn entries(&self) -> &[QueueEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add `entry` at the end; false when the episode is already queued
+    pub fn enqueue(&mut self, entry: QueueEntry) -> bool {
+        if self.contains(&entry.episode_id) {
+            return false;
+        }
+        self.entries.push(entry);
+        self.changed();
+        true
+    }
+
+    pub fn contains(&self, episode_id: &EpisodeId) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| &entry.episode_id == episode_id)
+    }
+
+    /// Take the entry at `index` out of the queue
+    pub fn remove(&mut self, index: usize) -> Option<QueueEntry> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        self.changed();
+        Some(self.entries.remove(index))
+    }
+
+    /// Take `episode_id` out of the queue, e.g. once it has been played
+    pub fn remove_episode(&mut self, episode_id: &EpisodeId) -> bool {
+        match self
+            .entries
+            .iter()
+            .position(|entry| &entry.episode_id == episode_id)
+        {
+            Some(index) => self.remove(index).is_some(),
+            None => false,
+        }
+    }
+
+    /// Move the entry at `from` to `to`; false when either is out of range
+    pub fn move_entry(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.entries.len() || to >= self.entries.len() || from == to {
+            return false;
+        }
+        let entry = self.entries.remove(from);
+        self.entries.insert(to, entry);
+        self.changed();
+        true
+    }
+
+    /// Take the first entry, the one to play next
+    pub fn pop_front(&mut self) -> Option<QueueEntry> {
+        self.remove(0)
+    }
+
+    pub fn clear(&mut self) {
+        if !self.entries.is_empty() {
+            self.entries.clear();
+            self.changed();
+        }
+    }
+
+    fn changed(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Write the queue as it is now. Runs detached from the queue, so the
+    /// caller can spawn it; saves that finish out of order are skipped.
+    pub fn save(&self) -> impl std::future::Future<Output = Result<(), StorageError>> + 'static {
+        let path = self.path.clone();
+        let entries = self.entries.clone();
+        let generation = self.generation;
+        let saved = self.saved.clone();
+        async move {
+            let mut saved = saved.lock().await;
+            if *saved > generation {
+                return Ok(());
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| StorageError::file_operation("create_dir_all", parent, e))?;
+            }
+            let temp_path = path.with_extension("tmp");
+            let json = serde_json::to_string_pretty(&entries)?;
+            fs::write(&temp_path, json)
+                .await
+                .map_err(|e| StorageError::file_operation("write_temp", &temp_path, e))?;
+            fs::rename(&temp_path, &path)
+                .await
+                .map_err(|e| StorageError::file_operation("rename", &path, e))?;
+            *saved = generation;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::PodcastId;
+    use tempfile::TempDir;
+
+    fn entry() -> QueueEntry {
+        QueueEntry {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queue_survives_a_restart() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let mut queue = QueueManager::load(dir.path()).await.unwrap();
+        let (first, second) = (entry(), entry());
+
+        // Act
+        assert!(queue.enqueue(first.clone()));
+        assert!(queue.enqueue(second.clone()));
+        assert!(!queue.enqueue(first.clone()), "already queued");
+        queue.save().await.unwrap();
+        let reloaded = QueueManager::load(dir.path()).await.unwrap();
+
+        // Assert
+        assert_eq!(reloaded.entries(), &[first, second]);
+    }
+
+    #[tokio::test]
+    async fn test_an_older_save_does_not_overwrite_a_newer_one() {
+        let dir = TempDir::new().unwrap();
+        let mut queue = QueueManager::new(dir.path());
+        queue.enqueue(entry());
+        let older = queue.save();
+        queue.clear();
+
+        queue.save().await.unwrap();
+        older.await.unwrap();
+
+        assert!(QueueManager::load(dir.path()).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reorder_remove_and_pop() {
+        let dir = TempDir::new().unwrap();
+        let mut queue = QueueManager::new(dir.path());
+        let entries = [entry(), entry(), entry()];
+        for e in &entries {
+            queue.enqueue(e.clone());
+        }
+
+        assert!(queue.move_entry(2, 0));
+        assert!(!queue.move_entry(0, 3));
+        assert!(queue.remove_episode(&entries[0].episode_id));
+
+        assert_eq!(queue.pop_front(), Some(entries[2].clone()));
+        assert_eq!(queue.pop_front(), Some(entries[1].clone()));
+        assert_eq!(queue.pop_front(), None);
+    }
+}
@@ -10,11 +10,18 @@
 //   - A fresh Sink is created on each play() call. Dropping the old Sink cleanly
 //     stops the previous track via its Drop impl.
 //   - OutputStream must remain alive for the duration of playback — drop = silence.
-
-use std::path::Path;
+//   - Each stream gets its own error callback that raises a `device_lost` flag when
+//     cpal reports `DeviceNotAvailable`; the AudioManager polls it to fall back to
+//     the default device instead of playing into a vanished device.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
-use rodio::{Decoder, OutputStream, OutputStreamBuilder, Sink, Source};
+use rodio::cpal::traits::HostTrait;
+use rodio::{cpal, Decoder, DeviceTrait, OutputStream, OutputStreamBuilder, Sink, Source};
 
 use super::{AudioError, PlaybackBackend};
 
@@ -32,6 +39,14 @@ pub struct RodioBackend {
     total_duration: Option<Duration>,
     /// Current volume level, clamped to [0.0, 1.0].
     volume: f32,
+    /// Playback speed, applied to every new sink.
+    speed: f32,
+    /// Selected output device (`None` = system default).
+    device_name: Option<String>,
+    /// File loaded by the last `play()`, replayed when the device changes.
+    current_path: Option<PathBuf>,
+    /// Raised by the stream's error callback when the device disappears.
+    device_lost: Arc<AtomicBool>,
 }
 
 impl std::fmt::Debug for RodioBackend {
@@ -41,6 +56,7 @@ impl std::fmt::Debug for RodioBackend {
             .field("is_paused", &self.is_paused())
             .field("position", &self.position())
             .field("volume", &self.volume)
+            .field("device", &self.device_name)
             .finish()
     }
 }
@@ -51,10 +67,8 @@ impl RodioBackend {
     /// Returns `Err(AudioError::DeviceNotFound)` when no output device is available
     /// (headless CI, WSL2 without audio passthrough, containers, etc.).
     pub fn new() -> Result<Self, AudioError> {
-        let mut stream =
-            OutputStreamBuilder::open_default_stream().map_err(|_| AudioError::DeviceNotFound)?;
-        // Suppress the "Dropping OutputStream" stderr message — not appropriate for a TUI.
-        stream.log_on_drop(false);
+        let device_lost = Arc::new(AtomicBool::new(false));
+        let stream = open_stream(None, device_lost.clone())?;
         let sink = Sink::connect_new(stream.mixer());
 
         Ok(Self {
@@ -62,10 +76,122 @@ impl RodioBackend {
             sink,
             total_duration: None,
             volume: crate::constants::audio::DEFAULT_VOLUME,
+            speed: 1.0,
+            device_name: None,
+            current_path: None,
+            device_lost,
         })
     }
 }
 
+/// Names of the output devices the default host can see.
+pub fn list_output_devices() -> Vec<String> {
+    cpal::default_host()
+        .output_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Name of the system default output device, if there is one
+pub fn default_output_device() -> Option<String> {
+    cpal::default_host()
+        .default_output_device()
+        .and_then(|d| d.name().ok())
+}
+
+/// Open an output stream on `name` (or the system default), wiring its error
+/// callback to `device_lost`.
+fn open_stream(
+    name: Option<&str>,
+    device_lost: Arc<AtomicBool>,
+) -> Result<OutputStream, AudioError> {
+    let on_error = move |err: cpal::StreamError| {
+        if matches!(err, cpal::StreamError::DeviceNotAvailable) {
+            device_lost.store(true, Ordering::SeqCst);
+        }
+    };
+
+    let mut stream = match name {
+        Some(name) => {
+            let device = cpal::default_host()
+                .output_devices()
+                .ok()
+                .and_then(|mut devices| devices.find(|d| d.name().is_ok_and(|n| n == name)))
+                .ok_or_else(|| AudioError::DeviceUnavailable(name.to_string()))?;
+            OutputStreamBuilder::from_device(device)
+                .map_err(|e| AudioError::DeviceUnavailable(format!("{name}: {e}")))?
+                .with_error_callback(on_error)
+                .open_stream_or_fallback()
+                .map_err(|e| AudioError::DeviceUnavailable(format!("{name}: {e}")))?
+        }
+        // The builder route keeps our error callback; fall back to rodio's
+        // device-probing default if the default device refuses its own config.
+        None => match OutputStreamBuilder::from_default_device()
+            .and_then(|b| b.with_error_callback(on_error).open_stream_or_fallback())
+        {
+            Ok(stream) => stream,
+            Err(_) => OutputStreamBuilder::open_default_stream()
+                .map_err(|_| AudioError::DeviceNotFound)?,
+        },
+    };
+    // Suppress the "Dropping OutputStream" stderr message — not appropriate for a TUI.
+    stream.log_on_drop(false);
+    Ok(stream)
+}
+
+/// Best guess at what `path` holds, from its first bytes, so a decode error
+/// can name the codec instead of just failing
+pub(crate) fn identify_codec(path: &Path) -> String {
+    let mut head = Vec::new();
+    if let Ok(file) = std::fs::File::open(path) {
+        let _ = file.take(64 * 1024).read_to_end(&mut head);
+    }
+    let contains = |needle: &[u8]| head.windows(needle.len()).any(|w| w == needle);
+
+    let codec = if head.starts_with(b"OggS") {
+        if contains(b"OpusHead") {
+            "Opus audio (Ogg)"
+        } else if contains(b"\x01vorbis") {
+            "Vorbis audio (Ogg)"
+        } else {
+            "Ogg audio"
+        }
+    } else if head.get(4..8) == Some(b"ftyp") {
+        if contains(b"alac") {
+            "ALAC audio (MP4)"
+        } else if contains(b"ac-3") || contains(b"ec-3") {
+            "Dolby Digital audio (MP4)"
+        } else if contains(b"mp4a") {
+            "AAC audio (MP4)"
+        } else {
+            "MP4 media"
+        }
+    } else if head.starts_with(b"fLaC") {
+        "FLAC audio"
+    } else if head.starts_with(b"RIFF") && head.get(8..12) == Some(b"WAVE") {
+        "WAV audio"
+    } else if head.starts_with(b"\x1a\x45\xdf\xa3") {
+        "WebM/Matroska media"
+    } else if head.len() > 1 && head[0] == 0xFF && head[1] & 0xF6 == 0xF0 {
+        "AAC audio (ADTS)"
+    } else if head.starts_with(b"ID3") || (head.len() > 1 && head[0] == 0xFF && head[1] >= 0xE0) {
+        "MP3 audio"
+    } else if head
+        .iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|&b| b == b'<')
+    {
+        // Typically an error page saved in place of the episode
+        "an HTML page, not audio"
+    } else {
+        return match path.extension() {
+            Some(ext) => format!("unrecognised audio (.{})", ext.to_string_lossy()),
+            None => "unrecognised audio".to_string(),
+        };
+    };
+    codec.to_string()
+}
+
 // ---------- PlaybackBackend impl --------------------------------------------
 
 impl PlaybackBackend for RodioBackend {
@@ -77,8 +203,10 @@ impl PlaybackBackend for RodioBackend {
         let file = std::fs::File::open(path).map_err(AudioError::Io)?;
         // Decoder::try_from(File) wraps in BufReader, reads byte_len from metadata,
         // and enables seeking — the recommended approach in rodio 0.21.
-        let decoder =
-            Decoder::try_from(file).map_err(|e| AudioError::DecodingFailed(e.to_string()))?;
+        let decoder = Decoder::try_from(file).map_err(|e| AudioError::UnsupportedCodec {
+            codec: identify_codec(path),
+            reason: e.to_string(),
+        })?;
 
         // Capture duration before the decoder is moved into the sink.
         let total_duration = decoder.total_duration();
@@ -89,10 +217,12 @@ impl PlaybackBackend for RodioBackend {
         // self._stream (for mixer()) while assigning to self.sink.
         let new_sink = Sink::connect_new(self._stream.mixer());
         new_sink.set_volume(self.volume);
+        new_sink.set_speed(self.speed);
         new_sink.append(decoder);
         self.sink = new_sink;
 
         self.total_duration = total_duration;
+        self.current_path = Some(path.to_path_buf());
         Ok(())
     }
 
@@ -116,6 +246,7 @@ impl PlaybackBackend for RodioBackend {
     fn stop(&mut self) {
         self.sink.clear();
         self.total_duration = None;
+        self.current_path = None;
     }
 
     /// Seek to `position` within the current track.
@@ -134,6 +265,22 @@ impl PlaybackBackend for RodioBackend {
         self.sink.set_volume(self.volume);
     }
 
+    /// Set the speed, clamped to the range of `constants::audio::PLAYBACK_SPEEDS`.
+    /// rodio resamples, so the pitch changes with it. `Sink::get_pos()` still
+    /// reports the position within the file.
+    fn set_speed(&mut self, speed: f32) {
+        use crate::constants::audio::PLAYBACK_SPEEDS;
+        self.speed = speed.clamp(
+            PLAYBACK_SPEEDS[0],
+            PLAYBACK_SPEEDS[PLAYBACK_SPEEDS.len() - 1],
+        );
+        self.sink.set_speed(self.speed);
+    }
+
+    fn speed(&self) -> f32 {
+        self.speed
+    }
+
     /// Returns the current playback position via `Sink::get_pos()`.
     ///
     /// Accuracy is within ~5 ms (the rodio periodic_access update interval).
@@ -169,6 +316,52 @@ impl PlaybackBackend for RodioBackend {
     fn is_stopped(&self) -> bool {
         self.sink.empty()
     }
+
+    fn output_devices(&self) -> Vec<String> {
+        list_output_devices()
+    }
+
+    fn current_output_device(&self) -> Option<String> {
+        self.device_name.clone()
+    }
+
+    /// Reopen the output stream on another device and, if a track was loaded,
+    /// reload it at the same position and pause state.
+    ///
+    /// The old stream is only replaced once the new one opened, so a failed
+    /// switch leaves playback untouched.
+    fn set_output_device(&mut self, name: Option<&str>) -> Result<(), AudioError> {
+        let device_lost = Arc::new(AtomicBool::new(false));
+        let stream = open_stream(name, device_lost.clone())?;
+
+        let resume = self.current_path.clone().filter(|_| !self.is_stopped());
+        let position = self.position();
+        let paused = self.is_paused();
+
+        self.sink.clear();
+        self.sink = Sink::connect_new(stream.mixer());
+        self.sink.set_volume(self.volume);
+        self.sink.set_speed(self.speed);
+        self._stream = stream;
+        self.device_lost = device_lost;
+        self.device_name = name.map(str::to_string);
+
+        if let Some(path) = resume {
+            self.play(&path)?;
+            if let Some(position) = position {
+                // Best effort: a failed seek restarts the episode rather than erroring
+                let _ = self.seek(position);
+            }
+            if paused {
+                self.sink.pause();
+            }
+        }
+        Ok(())
+    }
+
+    fn take_device_lost(&mut self) -> bool {
+        self.device_lost.swap(false, Ordering::SeqCst)
+    }
 }
 
 // ---------- Tests -----------------------------------------------------------
@@ -667,4 +860,38 @@ mod tests {
             "position after second play should be near 0, got {pos:?}"
         );
     }
+
+    // ── Codec identification ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_identify_codec_names_what_the_file_holds() {
+        // Arrange
+        let dir = tempfile::TempDir::new().unwrap();
+        let cases: [(&str, &[u8], &str); 5] = [
+            ("ep.opus", b"OggS\0\x02....OpusHead", "Opus audio (Ogg)"),
+            (
+                "ep.m4a",
+                b"\0\0\0\x20ftypM4A \0\0\0\0alac",
+                "ALAC audio (MP4)",
+            ),
+            ("ep.mp3", b"ID3\x04\0\0\0\0\0\0", "MP3 audio"),
+            (
+                "ep.mp3",
+                b"\n<!DOCTYPE html><html>",
+                "an HTML page, not audio",
+            ),
+            ("ep.xyz", b"\0\0\0\0", "unrecognised audio (.xyz)"),
+        ];
+
+        for (name, bytes, expected) in cases {
+            let path = dir.path().join(name);
+            std::fs::write(&path, bytes).unwrap();
+
+            // Act
+            let codec = identify_codec(&path);
+
+            // Assert
+            assert_eq!(codec, expected, "{name}");
+        }
+    }
 }
@@ -3,7 +3,7 @@ use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
-use crate::constants::{audio, downloads, storage, ui};
+use crate::constants::{audio, downloads, opml, storage, ui};
 
 /// Application configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -17,11 +17,42 @@ pub struct Config {
     pub playlist: PlaylistConfig,
     #[serde(default)]
     pub discovery: DiscoveryConfig,
+    #[serde(default)]
+    pub updates: UpdateConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub transcription: TranscriptionConfig,
+    #[serde(default)]
+    pub translation: TranslationConfig,
+    #[serde(default)]
+    pub alerts: AlertsConfig,
+    #[serde(default)]
+    pub goals: GoalsConfig,
+    #[serde(default)]
+    pub daemon: DaemonConfig,
 }
 
 impl Config {
+    /// Load configuration from file or create default, then apply
+    /// `PODCAST_TUI__*` environment variables and `--set` overrides on top.
+    /// Returns the problems found in the file alongside (see `from_json_checked`).
+    pub fn load_with_overrides(
+        custom_path: Option<&String>,
+        set: &[String],
+    ) -> Result<(Self, Vec<ConfigIssue>)> {
+        let mut overrides = ConfigOverride::from_env(std::env::vars());
+        for arg in set {
+            overrides.push(ConfigOverride::parse_set(arg)?);
+        }
+        let (config, mut issues) = Self::load_or_default(custom_path)?;
+        let (config, clamped) = config.with_overrides(&overrides)?;
+        issues.extend(clamped);
+        Ok((config, issues))
+    }
+
     /// Load configuration from file or create default
-    pub fn load_or_default(custom_path: Option<&String>) -> Result<Self> {
+    pub fn load_or_default(custom_path: Option<&String>) -> Result<(Self, Vec<ConfigIssue>)> {
         let config_path = match custom_path {
             Some(path) => PathBuf::from(path),
             None => Self::default_config_path()?,
@@ -29,15 +60,126 @@ impl Config {
 
         if config_path.exists() {
             let content = std::fs::read_to_string(&config_path)?;
-            let config: Config = serde_json::from_str(&content)?;
-            Ok(config)
+            Self::from_json_checked(&content)
+                .map_err(|e| anyhow::anyhow!("{}: {}", config_path.display(), e))
         } else {
             let default_config = Self::default();
             default_config.save(&config_path)?;
-            Ok(default_config)
+            Ok((default_config, Vec::new()))
         }
     }
 
+    /// Parse a config file, keeping everything in it that is valid.
+    ///
+    /// Only malformed JSON is an error. A value of the wrong type keeps its
+    /// default, narrowed down to the field at fault rather than the whole
+    /// section; unknown keys, invalid key notations and out-of-range numbers
+    /// (clamped) are reported too. Each problem is one `ConfigIssue`.
+    pub fn from_json_checked(content: &str) -> Result<(Self, Vec<ConfigIssue>)> {
+        let raw: serde_json::Value = serde_json::from_str(content)?;
+        let Some(sections) = raw.as_object() else {
+            anyhow::bail!("expected a JSON object at the top level");
+        };
+        let mut merged = serde_json::to_value(Self::default())?;
+        let mut issues = Vec::new();
+        merge_valid(sections, &mut merged, &mut Vec::new(), &mut issues);
+        let mut config: Config = serde_json::from_value(merged)?;
+
+        let parsed = serde_json::to_value(&config)?;
+        report_unknown_keys(&raw, &parsed, &mut Vec::new(), &mut issues);
+        config.check_keybindings(&mut issues);
+        config.clamp_ranges(&mut issues);
+        issues.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok((config, issues))
+    }
+
+    /// Report keybinding entries `KeyHandler` would skip: notations that
+    /// don't parse and an unknown preset
+    fn check_keybindings(&self, issues: &mut Vec<ConfigIssue>) {
+        let preset = self.keybindings.preset.as_str();
+        if !KEYBINDING_PRESETS.contains(&preset) {
+            issues.push(ConfigIssue::new(
+                "keybindings.preset",
+                format!(
+                    "unknown preset '{}'{}; using \"default\"",
+                    preset,
+                    did_you_mean(preset, KEYBINDING_PRESETS.iter().copied())
+                ),
+            ));
+        }
+
+        let Ok(serde_json::Value::Object(sections)) = serde_json::to_value(&self.keybindings)
+        else {
+            return;
+        };
+        for (section, keys) in &sections {
+            let Some(keys) = keys.as_object() else {
+                continue;
+            };
+            for (action, notations) in keys {
+                let notations = notations.as_array().map(Vec::as_slice).unwrap_or_default();
+                for notation in notations.iter().filter_map(serde_json::Value::as_str) {
+                    if let Err(e) = crate::ui::key_parser::parse_key_notation(notation) {
+                        issues.push(ConfigIssue::new(
+                            format!("keybindings.{}.{}", section, action),
+                            format!("invalid key notation '{}' ({}); ignored", notation, e),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Clamp numbers the app only handles within a range, reporting each
+    fn clamp_ranges(&mut self, issues: &mut Vec<ConfigIssue>) {
+        fn clamp<T: PartialOrd + Copy + std::fmt::Display>(
+            key: &str,
+            value: &mut T,
+            min: T,
+            max: T,
+            issues: &mut Vec<ConfigIssue>,
+        ) {
+            let clamped = if *value < min {
+                min
+            } else if *value > max {
+                max
+            } else {
+                return;
+            };
+            issues.push(ConfigIssue::new(
+                key,
+                format!(
+                    "{} is out of range ({} to {}); using {}",
+                    value, min, max, clamped
+                ),
+            ));
+            *value = clamped;
+        }
+
+        clamp("audio.volume", &mut self.audio.volume, 0.0, 1.0, issues);
+        clamp(
+            "downloads.concurrent_downloads",
+            &mut self.downloads.concurrent_downloads,
+            downloads::MIN_CONCURRENT_DOWNLOADS,
+            downloads::MAX_CONCURRENT_DOWNLOADS,
+            issues,
+        );
+        clamp(
+            "ui.whats_new_episode_limit",
+            &mut self.ui.whats_new_episode_limit,
+            ui::MIN_WHATS_NEW_LIMIT,
+            ui::MAX_WHATS_NEW_LIMIT,
+            issues,
+        );
+        clamp(
+            "ui.focus_mode_minutes",
+            &mut self.ui.focus_mode_minutes,
+            ui::MIN_FOCUS_MODE_MINUTES,
+            ui::MAX_FOCUS_MODE_MINUTES,
+            issues,
+        );
+    }
+
     /// Save configuration to file
     pub fn save(&self, path: &Path) -> Result<()> {
         // Create parent directory if it doesn't exist
@@ -51,11 +193,331 @@ impl Config {
     }
 
     /// Get the default configuration file path
-    fn default_config_path() -> Result<PathBuf> {
+    pub fn default_config_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("config.json"))
+    }
+
+    /// Get the configuration directory (holds `config.json` and `themes/`)
+    pub fn config_dir() -> Result<PathBuf> {
         let project_dirs = ProjectDirs::from("", "", "podcast-tui")
             .ok_or_else(|| anyhow::anyhow!("Unable to determine config directory"))?;
 
-        Ok(project_dirs.config_dir().join("config.json"))
+        Ok(project_dirs.config_dir().to_path_buf())
+    }
+
+    /// Change the default config file with `change`. Unlike saving the
+    /// running config, this leaves out `--set` / environment overrides.
+    pub fn persist(change: impl FnOnce(&mut Config)) -> Result<()> {
+        Self::persist_at(&Self::default_config_path()?, change)
+    }
+
+    /// `persist` for the config file at `path`. The file is read the way
+    /// startup reads it, and only the keys `change` altered are written
+    /// back, so invalid values and keys this version doesn't know are left
+    /// as they were. A file that isn't valid JSON is not rewritten.
+    fn persist_at(path: &Path, change: impl FnOnce(&mut Config)) -> Result<()> {
+        if !path.exists() {
+            let mut config = Self::default();
+            change(&mut config);
+            return config.save(path);
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        let (mut config, _) = Self::from_json_checked(&content)
+            .map_err(|e| anyhow::anyhow!("{}: {}; not rewriting it", path.display(), e))?;
+        let mut file: serde_json::Value = serde_json::from_str(&content)?;
+        let before = serde_json::to_value(&config)?;
+        change(&mut config);
+        let after = serde_json::to_value(&config)?;
+        copy_changed(&before, &after, &mut file);
+
+        std::fs::write(path, serde_json::to_string_pretty(&file)?)?;
+        Ok(())
+    }
+
+    /// This config with `overrides` applied in order, later ones winning.
+    /// Out-of-range numbers are clamped as in the file, and reported.
+    pub fn with_overrides(self, overrides: &[ConfigOverride]) -> Result<(Self, Vec<ConfigIssue>)> {
+        if overrides.is_empty() {
+            return Ok((self, Vec::new()));
+        }
+        let mut value = serde_json::to_value(&self)?;
+        for setting in overrides {
+            setting.apply(&mut value)?;
+        }
+        let mut config: Config = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Invalid config override: {}", e))?;
+
+        // Unknown keys are dropped when deserializing: catch typos here
+        let applied = serde_json::to_value(&config)?;
+        for setting in overrides {
+            if setting.lookup(&applied).is_none() {
+                anyhow::bail!(
+                    "Unknown config key '{}' ({})",
+                    setting.key(),
+                    setting.source
+                );
+            }
+        }
+
+        let mut issues = Vec::new();
+        config.clamp_ranges(&mut issues);
+        Ok((config, issues))
+    }
+}
+
+/// Values of `keybindings.preset`; the empty string means `default`
+const KEYBINDING_PRESETS: &[&str] = &["", "default", "vim", "emacs"];
+
+/// A problem found in the config file at startup. The rest of the file still
+/// applies; what the problem names keeps its default or is clamped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// Dotted key, e.g. `audio.volume`
+    pub key: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Copy each of `values` into `merged` at `path` as long as the whole still
+/// deserializes. A section that doesn't is retried field by field, so only
+/// the fields at fault keep their defaults.
+fn merge_valid(
+    values: &serde_json::Map<String, serde_json::Value>,
+    merged: &mut serde_json::Value,
+    path: &mut Vec<String>,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    for (key, value) in values {
+        let Some(parent) = json_object_at(merged, path) else {
+            return;
+        };
+        let previous = parent.insert(key.clone(), value.clone());
+        let Err(e) = serde_json::from_value::<Config>(merged.clone()) else {
+            continue;
+        };
+
+        let parent = json_object_at(merged, path).expect("checked above");
+        match previous {
+            Some(previous) => parent.insert(key.clone(), previous),
+            None => parent.remove(key),
+        };
+        path.push(key.clone());
+        let section_default = json_object_at(merged, path).is_some_and(|fields| !fields.is_empty());
+        match value.as_object() {
+            Some(fields) if section_default => merge_valid(fields, merged, path, issues),
+            _ => issues.push(ConfigIssue::new(
+                path.join("."),
+                format!("{}; using the default", e),
+            )),
+        }
+        path.pop();
+    }
+}
+
+/// Copy into `file` the values of `after` that differ from `before`
+fn copy_changed(
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+    file: &mut serde_json::Value,
+) {
+    let (Some(before), Some(after)) = (before.as_object(), after.as_object()) else {
+        *file = after.clone();
+        return;
+    };
+    if !file.is_object() {
+        *file = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let file = file.as_object_mut().expect("made an object above");
+    for (key, value) in after {
+        let previous = before.get(key).unwrap_or(&serde_json::Value::Null);
+        if previous == value {
+            continue;
+        }
+        match file.get_mut(key) {
+            Some(existing) if value.is_object() => copy_changed(previous, value, existing),
+            _ => {
+                file.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+fn json_object_at<'a>(
+    value: &'a mut serde_json::Value,
+    path: &[String],
+) -> Option<&'a mut serde_json::Map<String, serde_json::Value>> {
+    path.iter()
+        .try_fold(value, |value, part| value.get_mut(part))?
+        .as_object_mut()
+}
+
+/// Report keys of `raw` that didn't survive deserializing into `parsed`
+fn report_unknown_keys(
+    raw: &serde_json::Value,
+    parsed: &serde_json::Value,
+    path: &mut Vec<String>,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    match (raw, parsed) {
+        (serde_json::Value::Object(raw), serde_json::Value::Object(parsed)) => {
+            for (key, value) in raw {
+                path.push(key.clone());
+                match parsed.get(key) {
+                    Some(known) => report_unknown_keys(value, known, path, issues),
+                    None => {
+                        let dotted = path.join(".");
+                        // A key already reported as invalid is known
+                        if !issues.iter().any(|issue| issue.key == dotted) {
+                            let hint = did_you_mean(key, parsed.keys().map(String::as_str));
+                            issues.push(ConfigIssue::new(
+                                dotted,
+                                format!("unknown key{}; ignored", hint),
+                            ));
+                        }
+                    }
+                }
+                path.pop();
+            }
+        }
+        (serde_json::Value::Array(raw), serde_json::Value::Array(parsed)) => {
+            for (index, (value, known)) in raw.iter().zip(parsed).enumerate() {
+                path.push(index.to_string());
+                report_unknown_keys(value, known, path, issues);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `" (did you mean 'x'?)"` for the candidate closest to `word`, if close
+fn did_you_mean<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> String {
+    candidates
+        .filter(|candidate| !candidate.is_empty())
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= (word.chars().count() / 3).max(2))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(" (did you mean '{}'?)", candidate))
+        .unwrap_or_default()
+}
+
+/// Levenshtein distance between `a` and `b`, by characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Prefix of environment variables that override config keys:
+/// `PODCAST_TUI__AUDIO__VOLUME=0.5` sets `audio.volume`
+pub const ENV_OVERRIDE_PREFIX: &str = "PODCAST_TUI__";
+
+/// One config key set from outside the config file, for containers and
+/// scripts: `--set section.key=value` or a `PODCAST_TUI__SECTION__KEY`
+/// environment variable. Values are JSON (`true`, `3`, `["a","b"]`) except
+/// for keys holding text, which take the value as it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    /// Key path, e.g. `["downloads", "concurrent_downloads"]`
+    pub path: Vec<String>,
+    pub value: String,
+    /// Where it came from, for error messages
+    pub source: String,
+}
+
+impl ConfigOverride {
+    /// Parse a `--set section.key=value` argument
+    pub fn parse_set(arg: &str) -> Result<Self> {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("--set expects section.key=value, got '{}'", arg))?;
+        let path: Vec<String> = key.trim().split('.').map(str::to_string).collect();
+        if path.len() < 2 || path.iter().any(String::is_empty) {
+            anyhow::bail!("--set expects section.key=value, got '{}'", arg);
+        }
+        Ok(Self {
+            path,
+            value: value.to_string(),
+            source: format!("--set {}", arg),
+        })
+    }
+
+    /// Overrides from `PODCAST_TUI__SECTION__KEY` variables among `vars`,
+    /// sorted by name so the result doesn't depend on the environment's order
+    pub fn from_env(vars: impl IntoIterator<Item = (String, String)>) -> Vec<Self> {
+        let mut overrides: Vec<Self> = vars
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let path: Vec<String> = name
+                    .strip_prefix(ENV_OVERRIDE_PREFIX)?
+                    .split("__")
+                    .map(str::to_lowercase)
+                    .collect();
+                (path.len() >= 2 && path.iter().all(|part| !part.is_empty())).then_some(Self {
+                    path,
+                    value,
+                    source: name,
+                })
+            })
+            .collect();
+        overrides.sort_by(|a, b| a.source.cmp(&b.source));
+        overrides
+    }
+
+    /// Dotted key, e.g. `downloads.concurrent_downloads`
+    pub fn key(&self) -> String {
+        self.path.join(".")
+    }
+
+    fn apply(&self, config: &mut serde_json::Value) -> Result<()> {
+        let (key, sections) = self.path.split_last().expect("at least two parts");
+        let mut target = config;
+        for section in sections {
+            target = target
+                .get_mut(section)
+                .filter(|value| value.is_object())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Unknown config key '{}' ({})", self.key(), self.source)
+                })?;
+        }
+        let object = target.as_object_mut().expect("checked above");
+        let value = match object.get(key) {
+            Some(serde_json::Value::String(_)) => serde_json::Value::String(self.value.clone()),
+            _ => serde_json::from_str(&self.value)
+                .unwrap_or_else(|_| serde_json::Value::String(self.value.clone())),
+        };
+        object.insert(key.clone(), value);
+        Ok(())
+    }
+
+    fn lookup<'a>(&self, config: &'a serde_json::Value) -> Option<&'a serde_json::Value> {
+        self.path
+            .iter()
+            .try_fold(config, |value, part| value.get(part))
     }
 }
 
@@ -66,7 +528,27 @@ pub struct AudioConfig {
     pub seek_seconds: u32,
     pub external_player: Option<String>,
     pub auto_play_next: bool,
+    /// Resume partly played episodes where they were left off
     pub remember_position: bool,
+    /// Output device name for the built-in player (`None` = system default).
+    /// Set by `:select-audio-device`.
+    #[serde(default)]
+    pub output_device: Option<String>,
+    /// Retry files the built-in player can't decode with mpv, vlc or ffplay
+    #[serde(default = "default_decode_fallback")]
+    pub decode_fallback: bool,
+    /// Offer playback to media keys, desktop panels and `playerctl` over
+    /// MPRIS (Linux only)
+    #[serde(default = "default_mpris")]
+    pub mpris: bool,
+}
+
+fn default_decode_fallback() -> bool {
+    true
+}
+
+fn default_mpris() -> bool {
+    true
 }
 
 impl Default for AudioConfig {
@@ -77,6 +559,9 @@ impl Default for AudioConfig {
             external_player: None,
             auto_play_next: false,
             remember_position: true,
+            output_device: None,
+            decode_fallback: true,
+            mpris: true,
         }
     }
 }
@@ -127,6 +612,62 @@ pub struct DownloadConfig {
     /// If true, directory picker only shows removable/external drives.
     #[serde(default)]
     pub sync_filter_removable_only: bool, // Default: false (show all directories)
+
+    /// Hold new downloads and device syncs while on battery at or below
+    /// `battery_pause_threshold` percent; they resume automatically on AC.
+    #[serde(default)]
+    pub pause_on_battery: bool, // Default: false
+    #[serde(default = "default_battery_pause_threshold")]
+    pub battery_pause_threshold: u8, // Default: 30 (%)
+
+    /// What to do when a download is byte-identical to one already on disk
+    /// under another episode: "hardlink", "refuse" or "keep"
+    #[serde(default = "default_duplicate_downloads")]
+    pub duplicate_downloads: String, // Default: "hardlink"
+
+    /// Total size downloads may take up, in GB; `None` (or 0) means no quota
+    #[serde(default)]
+    pub max_storage_gb: Option<u32>,
+    /// Which downloads may be evicted to stay under `max_storage_gb`:
+    /// "played" (played episodes only) or "oldest" (played first, then
+    /// unplayed). Favourited episodes are never evicted.
+    #[serde(default = "default_quota_eviction")]
+    pub quota_eviction: String, // Default: "played"
+
+    /// Newest downloads kept per podcast that has no retention setting of
+    /// its own; `None` (or 0) keeps them all
+    #[serde(default)]
+    pub keep_latest: Option<u32>,
+    /// Delete a download this many days after it was played (favourites are
+    /// kept); `None` (or 0) disables it
+    #[serde(default)]
+    pub delete_played_after_days: Option<u32>,
+
+    /// ffmpeg executable for `convert-episode`; `None` looks up `ffmpeg` on PATH
+    #[serde(default)]
+    pub ffmpeg_path: Option<String>,
+
+    /// yt-dlp executable for video-page episodes; `None` looks up `yt-dlp` on PATH
+    #[serde(default)]
+    pub ytdlp_path: Option<String>,
+
+    /// Extra arguments passed to yt-dlp after the defaults (e.g. `--cookies`)
+    #[serde(default)]
+    pub ytdlp_args: Vec<String>,
+
+    /// Hosts fetched with yt-dlp in addition to YouTube (e.g. `vimeo.com`)
+    #[serde(default)]
+    pub ytdlp_hosts: Vec<String>,
+
+    /// Unplayed episodes after the playing one to download while idle on an
+    /// unmetered connection (0 = off, at most 2)
+    #[serde(default)]
+    pub prefetch_next: usize, // Default: 0
+
+    /// Newest new episodes per podcast that one refresh auto-downloads
+    /// (0 = all of them)
+    #[serde(default = "default_auto_download_limit")]
+    pub auto_download_limit: usize, // Default: 3
 }
 
 // Default functions for serde
@@ -166,6 +707,18 @@ fn default_sync_dry_run() -> bool {
 fn default_sync_include_playlists() -> bool {
     true
 }
+fn default_battery_pause_threshold() -> u8 {
+    downloads::DEFAULT_BATTERY_PAUSE_THRESHOLD
+}
+fn default_duplicate_downloads() -> String {
+    "hardlink".to_string()
+}
+fn default_quota_eviction() -> String {
+    "played".to_string()
+}
+fn default_auto_download_limit() -> usize {
+    downloads::DEFAULT_AUTO_DOWNLOAD_LIMIT
+}
 
 impl Default for DownloadConfig {
     fn default() -> Self {
@@ -194,6 +747,20 @@ impl Default for DownloadConfig {
             sync_include_playlists: true,
             sync_preview_before_sync: false,
             sync_filter_removable_only: false,
+
+            pause_on_battery: false,
+            battery_pause_threshold: downloads::DEFAULT_BATTERY_PAUSE_THRESHOLD,
+            duplicate_downloads: default_duplicate_downloads(),
+            max_storage_gb: None,
+            quota_eviction: default_quota_eviction(),
+            keep_latest: None,
+            delete_played_after_days: None,
+            ffmpeg_path: None,
+            ytdlp_path: None,
+            ytdlp_args: Vec::new(),
+            ytdlp_hosts: Vec::new(),
+            prefetch_next: 0,
+            auto_download_limit: downloads::DEFAULT_AUTO_DOWNLOAD_LIMIT,
         }
     }
 }
@@ -210,6 +777,10 @@ pub struct PlaylistConfig {
     /// Download retries when adding episodes to playlists.
     #[serde(default = "default_playlist_download_retries")]
     pub download_retries: u32,
+    /// Warn in playlist detail when a playlist runs longer than this (e.g. a
+    /// commute). `None` disables the warning.
+    #[serde(default)]
+    pub duration_budget_minutes: Option<u32>,
 }
 
 fn default_today_refresh_policy() -> String {
@@ -228,6 +799,7 @@ impl Default for PlaylistConfig {
             today_refresh_policy: default_today_refresh_policy(),
             auto_download_on_add: default_auto_download_on_add(),
             download_retries: default_playlist_download_retries(),
+            duration_budget_minutes: None,
         }
     }
 }
@@ -244,6 +816,178 @@ pub struct DiscoveryConfig {
     pub podcastindex_api_secret: String,
 }
 
+/// Release update check configuration.
+///
+/// The startup check is opt-in. `network_checks = false` also blocks the
+/// on-demand `:release-notes` fetch, so the app never contacts GitHub.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateConfig {
+    /// Look for a newer release when the app starts
+    pub check_on_startup: bool,
+    /// Allow any update-related network request
+    pub network_checks: bool,
+}
+
+impl Default for UpdateConfig {
+    fn default() -> Self {
+        Self {
+            check_on_startup: false,
+            network_checks: true,
+        }
+    }
+}
+
+/// Network identity for feed fetches and downloads.
+///
+/// Podcasts can override the User-Agent individually (`:user-agent`); these
+/// settings apply to the rest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// Preset name (`browser`, `apple-podcasts`, `overcast`, `pocket-casts`,
+    /// `spotify`, `podcast-tui`) or a custom User-Agent string
+    pub user_agent: Option<String>,
+    /// Present as a desktop browser when no User-Agent is set
+    pub spoof_user_agent: bool,
+    /// Platforms whose feeds need an OAuth sign-in (`:auth-login <name>`)
+    pub oauth_providers: Vec<OAuthProviderConfig>,
+}
+
+/// An OAuth 2.0 provider supporting the device authorization grant (RFC 8628).
+///
+/// Feeds on one of `hosts` (or their subdomains) are fetched with the
+/// provider's bearer token once signed in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OAuthProviderConfig {
+    /// Short name used in commands, e.g. `patreon`
+    pub name: String,
+    pub hosts: Vec<String>,
+    pub client_id: String,
+    pub device_authorization_url: String,
+    pub token_url: String,
+    pub scope: Option<String>,
+}
+
+/// Local speech-to-text with whisper.cpp (`:transcribe`).
+///
+/// Transcripts are stored on the episode and shown in its detail buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TranscriptionConfig {
+    /// Transcribe every new download in the background
+    pub auto_transcribe: bool,
+    /// whisper.cpp executable; `None` looks up `whisper-cli` on PATH
+    pub whisper_path: Option<String>,
+    /// Path to a ggml model file (e.g. `~/models/ggml-base.en.bin`)
+    pub model: Option<String>,
+    /// Spoken language code, or `auto` to detect it
+    pub language: String,
+    /// CPU threads for whisper; `None` uses its default
+    pub threads: Option<u32>,
+}
+
+impl Default for TranscriptionConfig {
+    fn default() -> Self {
+        Self {
+            auto_transcribe: false,
+            whisper_path: None,
+            model: None,
+            language: "auto".to_string(),
+            threads: None,
+        }
+    }
+}
+
+/// Episode descriptions run through a translation command of your own
+/// (`:translate`).
+///
+/// The command reads the description as plain text on stdin and prints the
+/// translation; the result is kept on the episode and shown in its detail
+/// buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TranslationConfig {
+    /// Shell command, e.g. `trans -b :en`; `None` leaves `:translate` off
+    pub command: Option<String>,
+    /// Seconds to wait for the command before giving up
+    pub timeout_secs: u64,
+}
+
+impl Default for TranslationConfig {
+    fn default() -> Self {
+        Self {
+            command: None,
+            timeout_secs: 60,
+        }
+    }
+}
+
+/// Keyword alerts raised when a refresh brings matching episodes (`:alerts`).
+///
+/// Titles, descriptions and transcripts of new episodes are checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertsConfig {
+    /// Text matched case-insensitively, or a regex written as `/pattern/`
+    pub keywords: Vec<String>,
+    /// Also raise a desktop notification (`notify-send` / `osascript`)
+    pub notify: bool,
+}
+
+/// The headless `podcast-tui daemon` run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// Minutes between refreshes of every subscription
+    pub refresh_interval_minutes: u64,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_minutes: 60,
+        }
+    }
+}
+
+/// Listening goals shown on the dashboard (`:dashboard`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GoalsConfig {
+    pub targets: Vec<GoalConfig>,
+    /// Remind in the minibuffer at startup when a goal is falling behind
+    pub reminders: bool,
+}
+
+impl Default for GoalsConfig {
+    fn default() -> Self {
+        Self {
+            targets: Vec::new(),
+            reminders: true,
+        }
+    }
+}
+
+/// One listening goal, measured over a calendar `period` (`day`, `week` or `month`).
+///
+/// `kind` is `listen_hours` (hours of episodes played to the end, `target`
+/// hours), `episodes` (`target` episodes finished or marked played) or
+/// `clear_inbox` (play every episode published during the previous period).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoalConfig {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    #[serde(default = "default_goal_period")]
+    pub period: String,
+}
+
+fn default_goal_period() -> String {
+    "week".to_string()
+}
+
 /// Global keybindings — apply in all buffers unless overridden by a context section.
 ///
 /// Each field is a list of key notations (Helix-style: "C-n", "S-Tab", "F1", etc.).
@@ -275,6 +1019,7 @@ pub struct GlobalKeys {
     pub move_to_bottom: Vec<String>,
     pub move_episode_up: Vec<String>,
     pub move_episode_down: Vec<String>,
+    pub move_episode_to_top: Vec<String>,
 
     // ── Buffer navigation ────────────────────────────────────────────────────
     pub next_buffer: Vec<String>,
@@ -288,6 +1033,7 @@ pub struct GlobalKeys {
     // ── Application control ──────────────────────────────────────────────────
     pub quit: Vec<String>,
     pub show_help: Vec<String>,
+    pub show_legend: Vec<String>,
     pub search: Vec<String>,
     pub clear_filters: Vec<String>,
     pub refresh: Vec<String>,
@@ -304,6 +1050,7 @@ pub struct GlobalKeys {
     pub delete_podcast: Vec<String>,
     pub refresh_podcast: Vec<String>,
     pub refresh_all: Vec<String>,
+    pub refresh_filtered: Vec<String>,
     pub hard_refresh_podcast: Vec<String>,
 
     // ── Episode actions ──────────────────────────────────────────────────────
@@ -319,10 +1066,12 @@ pub struct GlobalKeys {
     // ── Playlist ─────────────────────────────────────────────────────────────
     pub create_playlist: Vec<String>,
     pub add_to_playlist: Vec<String>,
+    pub add_all_to_playlist: Vec<String>,
 
     // ── OPML ─────────────────────────────────────────────────────────────────
     pub import_opml: Vec<String>,
     pub export_opml: Vec<String>,
+    pub cancel_import: Vec<String>,
 
     // ── Sync ─────────────────────────────────────────────────────────────────
     pub sync_to_device: Vec<String>,
@@ -334,10 +1083,13 @@ pub struct GlobalKeys {
     // ── Audio playback ────────────────────────────────────────────────────────
     pub toggle_play_pause: Vec<String>,
     pub play_episode: Vec<String>,
+    pub enqueue_episode: Vec<String>,
     pub seek_backward: Vec<String>,
     pub seek_forward: Vec<String>,
     pub volume_up: Vec<String>,
     pub volume_down: Vec<String>,
+    pub toggle_mute: Vec<String>,
+    pub toggle_duck: Vec<String>,
     pub open_now_playing: Vec<String>,
 }
 
@@ -357,6 +1109,7 @@ impl Default for GlobalKeys {
             move_to_bottom: vec![],
             move_episode_up: vec![],
             move_episode_down: vec![],
+            move_episode_to_top: vec![],
             next_buffer: vec![],
             prev_buffer: vec![],
             close_buffer: vec![],
@@ -366,6 +1119,7 @@ impl Default for GlobalKeys {
             open_sync: vec![],
             quit: vec![],
             show_help: vec![],
+            show_legend: vec![],
             search: vec![],
             clear_filters: vec![],
             refresh: vec![],
@@ -378,6 +1132,7 @@ impl Default for GlobalKeys {
             delete_podcast: vec![],
             refresh_podcast: vec![],
             refresh_all: vec![],
+            refresh_filtered: vec![],
             hard_refresh_podcast: vec![],
             download_episode: vec![],
             delete_downloaded_episode: vec![],
@@ -389,17 +1144,22 @@ impl Default for GlobalKeys {
             toggle_sort_direction: vec![],
             create_playlist: vec![],
             add_to_playlist: vec![],
+            add_all_to_playlist: vec![],
             import_opml: vec![],
             export_opml: vec![],
+            cancel_import: vec![],
             sync_to_device: vec![],
             prev_tab: vec![],
             next_tab: vec![],
             toggle_play_pause: vec![],
             play_episode: vec![],
+            enqueue_episode: vec![],
             seek_backward: vec![],
             seek_forward: vec![],
             volume_up: vec![],
             volume_down: vec![],
+            toggle_mute: vec![],
+            toggle_duck: vec![],
             open_now_playing: vec![],
         }
     }
@@ -422,6 +1182,7 @@ impl GlobalKeys {
             move_to_bottom: ["End", "S-G"].map(String::from).to_vec(),
             move_episode_up: ["C-Up"].map(String::from).to_vec(),
             move_episode_down: ["C-Down"].map(String::from).to_vec(),
+            move_episode_to_top: ["C-Home"].map(String::from).to_vec(),
 
             // Buffer navigation
             next_buffer: ["Tab", "C-PgDn"].map(String::from).to_vec(),
@@ -437,6 +1198,7 @@ impl GlobalKeys {
             // Application control
             quit: ["q", "F10"].map(String::from).to_vec(),
             show_help: ["F1", "h", "?", "S-?"].map(String::from).to_vec(),
+            show_legend: ["S-L"].map(String::from).to_vec(),
             search: ["F3", "/"].map(String::from).to_vec(),
             clear_filters: ["F6"].map(String::from).to_vec(),
             refresh: ["F5"].map(String::from).to_vec(),
@@ -453,6 +1215,7 @@ impl GlobalKeys {
             delete_podcast: ["d"].map(String::from).to_vec(),
             refresh_podcast: ["r"].map(String::from).to_vec(),
             refresh_all: ["S-R"].map(String::from).to_vec(),
+            refresh_filtered: ["M-r"].map(String::from).to_vec(),
             hard_refresh_podcast: ["C-r"].map(String::from).to_vec(),
 
             // Episode actions
@@ -468,10 +1231,12 @@ impl GlobalKeys {
             // Playlist
             create_playlist: ["c"].map(String::from).to_vec(),
             add_to_playlist: ["p"].map(String::from).to_vec(),
+            add_all_to_playlist: ["M-p"].map(String::from).to_vec(),
 
             // OPML
             import_opml: ["S-A"].map(String::from).to_vec(),
             export_opml: ["S-E"].map(String::from).to_vec(),
+            cancel_import: ["C-g"].map(String::from).to_vec(),
 
             // Sync
             sync_to_device: ["s"].map(String::from).to_vec(),
@@ -485,10 +1250,14 @@ impl GlobalKeys {
             // S-Enter plays the selected episode (Enter opens detail; S-Enter = play).
             toggle_play_pause: ["S-P"].map(String::from).to_vec(),
             play_episode: ["S-Enter"].map(String::from).to_vec(),
+            enqueue_episode: ["e"].map(String::from).to_vec(),
             seek_backward: ["C-Left"].map(String::from).to_vec(),
             seek_forward: ["C-Right"].map(String::from).to_vec(),
             volume_up: ["+", "="].map(String::from).to_vec(),
             volume_down: ["-"].map(String::from).to_vec(),
+            toggle_mute: ["S-M"].map(String::from).to_vec(),
+            // Unbound by default; meant for keys sent by notification hooks
+            toggle_duck: vec![],
             open_now_playing: ["F9"].map(String::from).to_vec(),
         }
     }
@@ -624,12 +1393,34 @@ pub struct StorageConfig {
     pub max_backups: u32,
     #[serde(default = "default_opml_export_directory")]
     pub opml_export_directory: String,
+    /// Days an unsubscribed podcast stays restorable before it is permanently deleted
+    /// (0 = delete immediately on unsubscribe)
+    #[serde(default = "default_podcast_restore_days")]
+    pub podcast_restore_days: u32,
+    /// Feeds fetched in parallel during OPML import
+    #[serde(default = "default_opml_import_concurrency")]
+    pub opml_import_concurrency: usize,
+    /// Minimum milliseconds between OPML import requests to the same host
+    #[serde(default = "default_opml_import_host_interval_ms")]
+    pub opml_import_host_interval_ms: u64,
 }
 
 fn default_opml_export_directory() -> String {
     "~/Documents/podcast-exports".to_string()
 }
 
+fn default_podcast_restore_days() -> u32 {
+    storage::DEFAULT_PODCAST_RESTORE_DAYS
+}
+
+fn default_opml_import_concurrency() -> usize {
+    opml::MAX_PARALLEL_IMPORTS
+}
+
+fn default_opml_import_host_interval_ms() -> u64 {
+    opml::DEFAULT_PER_HOST_INTERVAL_MS
+}
+
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
@@ -638,6 +1429,9 @@ impl Default for StorageConfig {
             backup_frequency_days: 7,
             max_backups: storage::MAX_BACKUPS as u32,
             opml_export_directory: default_opml_export_directory(),
+            podcast_restore_days: default_podcast_restore_days(),
+            opml_import_concurrency: default_opml_import_concurrency(),
+            opml_import_host_interval_ms: default_opml_import_host_interval_ms(),
         }
     }
 }
@@ -656,6 +1450,73 @@ pub struct UiConfig {
     // What's New buffer settings
     #[serde(default = "default_whats_new_episode_limit")]
     pub whats_new_episode_limit: usize,
+
+    /// Draw status glyphs with plain ASCII instead of Unicode symbols
+    #[serde(default)]
+    pub ascii_glyphs: bool,
+
+    /// Maximum open episode-list buffers; the least recently used is closed
+    /// beyond this (0 = unlimited)
+    #[serde(default = "default_max_episode_buffers")]
+    pub max_episode_buffers: usize,
+
+    /// Podcast list ordering: `alphabetical`, `recent` or `unplayed`.
+    /// Updated automatically when the sort is changed in the UI.
+    #[serde(default = "default_podcast_list_sort")]
+    pub podcast_list_sort: String,
+
+    /// Months without a new episode before the podcast list flags a show as
+    /// inactive and offers to archive it (`C`). 0 only flags shows whose feed
+    /// declares them complete.
+    #[serde(default = "default_inactive_after_months")]
+    pub inactive_after_months: u32,
+
+    /// Hide podcasts and episodes flagged `<itunes:explicit>` from every list
+    /// (for shared or family profiles)
+    #[serde(default)]
+    pub hide_explicit: bool,
+
+    /// Languages to list episodes in (e.g. `["en", "es"]`), matched on the
+    /// primary subtag so `en` covers `en-us`. Episodes whose feed declares
+    /// another language are hidden; empty shows every language.
+    #[serde(default)]
+    pub languages: Vec<String>,
+
+    /// Never convey a state by color alone: selections are also bold and
+    /// underlined. Pair with the `deuteranopia` or `protanopia` theme.
+    #[serde(default)]
+    pub color_blind_mode: bool,
+
+    /// Simplified UI for less technical users: only subscribe, browse, play
+    /// and download are available, advanced buffers are hidden, and a hint
+    /// bar lists the keys that work
+    #[serde(default)]
+    pub simple_mode: bool,
+
+    /// Episodes kept out of What's New and new-episode messages
+    #[serde(default)]
+    pub whats_new_exclusions: Vec<WhatsNewExclusion>,
+
+    /// Status bar layout
+    #[serde(default)]
+    pub statusbar: StatusBarConfig,
+
+    /// Draw a scrollbar and "position/total · percent" on list and text buffers
+    #[serde(default = "default_scroll_indicators")]
+    pub scroll_indicators: bool,
+
+    /// Tick rate, frame pacing, key repeat and completion debounce
+    #[serde(default)]
+    pub performance: PerformanceConfig,
+
+    /// Zone publication dates are shown in: `local` (the default), `utc`,
+    /// `original` (the publisher's) or a fixed offset such as `+05:30`
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+
+    /// Minutes `:focus` hides everything but errors for when no period is given
+    #[serde(default = "default_focus_mode_minutes")]
+    pub focus_mode_minutes: u32,
     // NOTE: Duration filter config (filter_short_max_minutes, filter_long_min_minutes)
     // deferred until episode duration data is populated from RSS feeds.
     // See Design Decision #13 in docs/SEARCH_AND_FILTER.md.
@@ -666,6 +1527,30 @@ fn default_whats_new_episode_limit() -> usize {
     ui::DEFAULT_WHATS_NEW_LIMIT
 }
 
+fn default_max_episode_buffers() -> usize {
+    ui::DEFAULT_MAX_EPISODE_BUFFERS
+}
+
+fn default_podcast_list_sort() -> String {
+    ui::DEFAULT_PODCAST_LIST_SORT.to_string()
+}
+
+fn default_inactive_after_months() -> u32 {
+    ui::DEFAULT_INACTIVE_AFTER_MONTHS
+}
+
+fn default_scroll_indicators() -> bool {
+    true
+}
+
+fn default_timezone() -> String {
+    "local".to_string()
+}
+
+fn default_focus_mode_minutes() -> u32 {
+    ui::DEFAULT_FOCUS_MODE_MINUTES
+}
+
 // NOTE: Duration filter default fns removed — deferred until extract_duration is implemented.
 // See Design Decision #13.
 
@@ -680,10 +1565,114 @@ impl Default for UiConfig {
             compact_mode: false,
             mouse_support: true,
             whats_new_episode_limit: ui::DEFAULT_WHATS_NEW_LIMIT,
+            ascii_glyphs: false,
+            max_episode_buffers: ui::DEFAULT_MAX_EPISODE_BUFFERS,
+            podcast_list_sort: default_podcast_list_sort(),
+            inactive_after_months: default_inactive_after_months(),
+            hide_explicit: false,
+            languages: Vec::new(),
+            color_blind_mode: false,
+            simple_mode: false,
+            whats_new_exclusions: Vec::new(),
+            statusbar: StatusBarConfig::default(),
+            scroll_indicators: true,
+            performance: PerformanceConfig::default(),
+            timezone: default_timezone(),
+            focus_mode_minutes: default_focus_mode_minutes(),
+        }
+    }
+}
+
+/// UI timing (`ui.performance`).
+///
+/// `preset` is `balanced` (the default), `responsive` or `battery-saver`;
+/// any value set alongside it overrides that part of the preset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PerformanceConfig {
+    pub preset: String,
+    /// Milliseconds between UI ticks (status bar, timers, background polling)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick_rate_ms: Option<u64>,
+    /// Longest wait between redraws when nothing happens (milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_interval_ms: Option<u64>,
+    /// Held navigation keys repeat at most this often (milliseconds, 0 = as
+    /// fast as the terminal sends them). Typed characters are never dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_repeat_ms: Option<u64>,
+    /// Typing pause before the minibuffer completion popup updates
+    /// (milliseconds, 0 = on every keystroke)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_debounce_ms: Option<u64>,
+}
+
+impl Default for PerformanceConfig {
+    fn default() -> Self {
+        Self {
+            preset: ui::DEFAULT_PERFORMANCE_PRESET.to_string(),
+            tick_rate_ms: None,
+            frame_interval_ms: None,
+            key_repeat_ms: None,
+            completion_debounce_ms: None,
+        }
+    }
+}
+
+/// Status bar layout: segments in display order.
+///
+/// Segments before `fill` are left-aligned; the rest are right-aligned and
+/// separated by `|`. Kinds: `buffer`, `keys`, `playback`, `downloads`,
+/// `clock`, `battery`, `network`, `indicator`, `hint`, `help`, `fill`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusBarConfig {
+    pub segments: Vec<StatusBarSegment>,
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            segments: ui::DEFAULT_STATUSBAR_SEGMENTS
+                .iter()
+                .map(|kind| StatusBarSegment {
+                    segment: kind.to_string(),
+                    style: None,
+                    format: None,
+                })
+                .collect(),
         }
     }
 }
 
+/// One status bar segment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusBarSegment {
+    /// Segment kind, e.g. `clock`
+    pub segment: String,
+    /// Theme role (`muted`, `primary`, `success`, `warning`, `error`, `title`,
+    /// `statusbar`) or a color (`#ff79c6`, `yellow`); the segment's own default when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// strftime format for `clock` (default `%H:%M`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+/// One What's New exclusion rule.
+///
+/// `podcast` alone hides a whole show; `title_pattern` alone hides matching
+/// episode titles from every show; both together scope the pattern to that show.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WhatsNewExclusion {
+    /// Podcast title (case-insensitive) or feed URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub podcast: Option<String>,
+    /// Regular expression matched against episode titles, e.g. `^(Teaser|Preview)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_pattern: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -760,10 +1749,52 @@ mod tests {
 
         let config: Config = serde_json::from_str(legacy_json).expect("Legacy config should parse");
         assert_eq!(config.playlist.today_refresh_policy, "daily");
+        assert!(config.playlist.duration_budget_minutes.is_none());
         assert!(config.downloads.sync_include_playlists);
         // Phase 3 fields should default to false when absent from legacy config
         assert!(!config.downloads.sync_preview_before_sync);
         assert!(!config.downloads.sync_filter_removable_only);
+        assert_eq!(config.downloads.duplicate_downloads, "hardlink");
+        assert!(config.downloads.ffmpeg_path.is_none());
+        assert!(config.downloads.ytdlp_path.is_none());
+        assert!(config.downloads.ytdlp_args.is_empty());
+        assert!(config.downloads.ytdlp_hosts.is_empty());
+        assert!(!config.downloads.pause_on_battery);
+        assert_eq!(
+            config.downloads.battery_pause_threshold,
+            downloads::DEFAULT_BATTERY_PAUSE_THRESHOLD
+        );
+        assert_eq!(
+            config.storage.podcast_restore_days,
+            storage::DEFAULT_PODCAST_RESTORE_DAYS
+        );
+        assert!(!config.ui.ascii_glyphs);
+        assert!(!config.ui.hide_explicit);
+        assert!(!config.ui.simple_mode);
+        assert!(!config.ui.color_blind_mode);
+        assert!(config.ui.scroll_indicators);
+        assert_eq!(config.ui.performance, PerformanceConfig::default());
+        assert!(config.ui.whats_new_exclusions.is_empty());
+        assert_eq!(config.alerts, AlertsConfig::default());
+        assert!(config.goals.targets.is_empty());
+        assert!(config.goals.reminders);
+        assert_eq!(config.ui.statusbar, StatusBarConfig::default());
+        assert!(!config.updates.check_on_startup);
+        assert!(config.updates.network_checks);
+        assert!(config.audio.output_device.is_none());
+        assert_eq!(
+            config.ui.max_episode_buffers,
+            ui::DEFAULT_MAX_EPISODE_BUFFERS
+        );
+        assert_eq!(config.ui.podcast_list_sort, ui::DEFAULT_PODCAST_LIST_SORT);
+        assert_eq!(
+            config.storage.opml_import_concurrency,
+            opml::MAX_PARALLEL_IMPORTS
+        );
+        assert_eq!(
+            config.storage.opml_import_host_interval_ms,
+            opml::DEFAULT_PER_HOST_INTERVAL_MS
+        );
     }
 
     #[test]
@@ -774,6 +1805,11 @@ mod tests {
         // Assert — new fields default to false (non-breaking)
         assert!(!config.downloads.sync_preview_before_sync);
         assert!(!config.downloads.sync_filter_removable_only);
+        assert!(!config.downloads.pause_on_battery);
+        assert_eq!(
+            config.downloads.battery_pause_threshold,
+            downloads::DEFAULT_BATTERY_PAUSE_THRESHOLD
+        );
     }
 
     #[test]
@@ -829,6 +1865,7 @@ mod tests {
         assert!(!keys.open_sync.is_empty());
         assert!(!keys.quit.is_empty());
         assert!(!keys.show_help.is_empty());
+        assert!(!keys.show_legend.is_empty());
         assert!(!keys.search.is_empty());
         assert!(!keys.clear_filters.is_empty()); // F6 → ClearFilters
         assert!(!keys.refresh.is_empty());
@@ -852,6 +1889,7 @@ mod tests {
         assert!(!keys.add_to_playlist.is_empty());
         assert!(!keys.import_opml.is_empty());
         assert!(!keys.export_opml.is_empty());
+        assert!(!keys.cancel_import.is_empty());
         assert!(!keys.sync_to_device.is_empty());
         assert!(!keys.prev_tab.is_empty());
         assert!(!keys.next_tab.is_empty());
@@ -893,6 +1931,7 @@ mod tests {
         assert!(keys.move_to_bottom.contains(&"S-G".to_string()));
         assert_eq!(keys.move_episode_up, vec!["C-Up"]);
         assert_eq!(keys.move_episode_down, vec!["C-Down"]);
+        assert_eq!(keys.move_episode_to_top, vec!["C-Home"]);
         assert!(keys.next_buffer.contains(&"Tab".to_string()));
         assert!(keys.next_buffer.contains(&"C-PgDn".to_string()));
         assert!(keys.prev_buffer.contains(&"S-Tab".to_string()));
@@ -925,6 +1964,7 @@ mod tests {
         assert_eq!(keys.delete_podcast, vec!["d"]);
         assert_eq!(keys.refresh_podcast, vec!["r"]);
         assert_eq!(keys.refresh_all, vec!["S-R"]);
+        assert_eq!(keys.refresh_filtered, vec!["M-r"]);
         assert_eq!(keys.hard_refresh_podcast, vec!["C-r"]);
         assert_eq!(keys.download_episode, vec!["S-D"]);
         assert!(keys.delete_downloaded_episode.contains(&"X".to_string()));
@@ -935,8 +1975,10 @@ mod tests {
         assert_eq!(keys.toggle_favorite, vec!["*", "S-*"]);
         assert_eq!(keys.create_playlist, vec!["c"]);
         assert_eq!(keys.add_to_playlist, vec!["p"]);
+        assert_eq!(keys.add_all_to_playlist, vec!["M-p"]);
         assert_eq!(keys.import_opml, vec!["S-A"]);
         assert_eq!(keys.export_opml, vec!["S-E"]);
+        assert_eq!(keys.cancel_import, vec!["C-g"]);
         assert_eq!(keys.sync_to_device, vec!["s"]);
         assert_eq!(keys.prev_tab, vec!["["]);
         assert_eq!(keys.next_tab, vec!["]"]);
@@ -944,11 +1986,14 @@ mod tests {
         // Audio playback — non-displacing defaults
         assert_eq!(keys.toggle_play_pause, vec!["S-P"]); // P (Shift+P), mnemonic for Play/Pause
         assert_eq!(keys.play_episode, vec!["S-Enter"]); // Shift+Enter; plain Enter = SelectItem
+        assert_eq!(keys.enqueue_episode, vec!["e"]);
         assert_eq!(keys.seek_backward, vec!["C-Left"]);
         assert_eq!(keys.seek_forward, vec!["C-Right"]);
         assert!(keys.volume_up.contains(&"+".to_string()));
         assert!(keys.volume_up.contains(&"=".to_string()));
         assert_eq!(keys.volume_down, vec!["-"]);
+        assert_eq!(keys.toggle_mute, vec!["S-M"]);
+        assert!(keys.toggle_duck.is_empty());
         assert_eq!(keys.open_now_playing, vec!["F9"]);
     }
 
@@ -1031,4 +2076,172 @@ mod tests {
         // Unspecified fields within the section are empty (= use global default)
         assert!(ep.download_episode.is_empty());
     }
+    #[test]
+    fn test_overrides_from_env_and_set_are_applied_in_order() {
+        // Arrange
+        let mut overrides = ConfigOverride::from_env([
+            ("PODCAST_TUI__UI__THEME".to_string(), "dark".to_string()),
+            (
+                "PODCAST_TUI__DOWNLOADS__CONCURRENT_DOWNLOADS".to_string(),
+                "7".to_string(),
+            ),
+            ("PODCAST_TUI_UNRELATED".to_string(), "1".to_string()),
+            ("HOME".to_string(), "/root".to_string()),
+        ]);
+        overrides.push(ConfigOverride::parse_set("downloads.concurrent_downloads=2").unwrap());
+        overrides.push(ConfigOverride::parse_set(r#"keybindings.global.quit=["C-q"]"#).unwrap());
+
+        // Act
+        let (config, issues) = Config::default().with_overrides(&overrides).unwrap();
+
+        // Assert — the later --set wins over the environment
+        assert_eq!(overrides.len(), 4);
+        assert_eq!(config.ui.theme, "dark");
+        assert_eq!(config.downloads.concurrent_downloads, 2);
+        assert_eq!(config.keybindings.global.quit, vec!["C-q"]);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_out_of_range_overrides_are_clamped_and_reported() {
+        // Arrange
+        let overrides = [
+            ConfigOverride::parse_set("audio.volume=4").unwrap(),
+            ConfigOverride::parse_set("downloads.concurrent_downloads=0").unwrap(),
+            ConfigOverride::parse_set("ui.focus_mode_minutes=0").unwrap(),
+        ];
+
+        // Act
+        let (config, issues) = Config::default().with_overrides(&overrides).unwrap();
+
+        // Assert
+        assert_eq!(config.audio.volume, 1.0);
+        assert_eq!(
+            config.downloads.concurrent_downloads,
+            downloads::MIN_CONCURRENT_DOWNLOADS
+        );
+        let keys: Vec<&str> = issues.iter().map(|issue| issue.key.as_str()).collect();
+        assert_eq!(config.ui.focus_mode_minutes, ui::MIN_FOCUS_MODE_MINUTES);
+        assert_eq!(
+            keys,
+            vec![
+                "audio.volume",
+                "downloads.concurrent_downloads",
+                "ui.focus_mode_minutes"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_keys_take_the_value_as_is() {
+        // "true" would parse as JSON, but the theme is text
+        let set = ConfigOverride::parse_set("ui.theme=true").unwrap();
+
+        let (config, _) = Config::default().with_overrides(&[set]).unwrap();
+
+        assert_eq!(config.ui.theme, "true");
+    }
+
+    #[test]
+    fn test_bad_overrides_are_rejected() {
+        assert!(ConfigOverride::parse_set("theme").is_err());
+        assert!(ConfigOverride::parse_set("theme=dark").is_err());
+
+        let typo = ConfigOverride::parse_set("ui.theem=dark").unwrap();
+        let error = Config::default().with_overrides(&[typo]).unwrap_err();
+        assert!(error.to_string().contains("Unknown config key 'ui.theem'"));
+
+        let section = ConfigOverride::parse_set("uii.theme=dark").unwrap();
+        assert!(Config::default().with_overrides(&[section]).is_err());
+
+        let wrong_type = ConfigOverride::parse_set("downloads.concurrent_downloads=many").unwrap();
+        assert!(Config::default().with_overrides(&[wrong_type]).is_err());
+    }
+
+    #[test]
+    fn test_persist_keeps_invalid_values_and_unknown_keys() {
+        // Arrange: a file startup accepts with issues
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        std::fs::write(
+            &path,
+            r#"{ "audio": { "volume": "loud" }, "ui": { "theme": "dark" }, "future": { "x": 1 } }"#,
+        )
+        .unwrap();
+
+        // Act
+        Config::persist_at(&path, |config| {
+            config.ui.podcast_list_sort = "recent".to_string()
+        })
+        .unwrap();
+
+        // Assert: only the changed key was written
+        let file: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(file["ui"]["podcast_list_sort"], "recent");
+        assert_eq!(file["ui"]["theme"], "dark");
+        assert_eq!(file["audio"]["volume"], "loud");
+        assert_eq!(file["future"]["x"], 1);
+        assert!(file["downloads"].is_null());
+    }
+
+    #[test]
+    fn test_persist_refuses_to_rewrite_malformed_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        std::fs::write(&path, "{ \"ui\": ").unwrap();
+
+        let result = Config::persist_at(&path, |config| config.ui.theme = "light".to_string());
+
+        assert!(result.unwrap_err().to_string().contains("not rewriting it"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{ \"ui\": ");
+    }
+
+    #[test]
+    fn test_checked_load_keeps_valid_keys_and_reports_the_rest() {
+        // Arrange: one bad value in audio, a typo in ui, a bad notation
+        let content = r#"{
+  "audio": { "volume": "loud", "seek_seconds": 30 },
+  "ui": { "theem": "dark", "whats_new_episode_limit": 5000 },
+  "keybindings": { "preset": "vmi", "global": { "quit": ["C-", "q"] } }
+}"#;
+
+        // Act
+        let (config, issues) = Config::from_json_checked(content).unwrap();
+
+        // Assert: the rest of each section still applies
+        assert_eq!(config.audio.seek_seconds, 30);
+        assert_eq!(config.audio.volume, audio::DEFAULT_VOLUME);
+        assert_eq!(config.ui.whats_new_episode_limit, ui::MAX_WHATS_NEW_LIMIT);
+        assert_eq!(config.keybindings.global.quit, vec!["C-", "q"]);
+        let keys: Vec<&str> = issues.iter().map(|issue| issue.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "audio.volume",
+                "keybindings.global.quit",
+                "keybindings.preset",
+                "ui.theem",
+                "ui.whats_new_episode_limit",
+            ]
+        );
+        assert!(issues[0].message.contains("invalid type"));
+        assert!(issues[1].message.contains("invalid key notation 'C-'"));
+        assert!(issues[2].message.contains("did you mean 'vim'?"));
+        assert_eq!(
+            issues[3].message,
+            "unknown key (did you mean 'theme'?); ignored"
+        );
+        assert!(issues[4].message.contains("out of range"));
+    }
+
+    #[test]
+    fn test_checked_load_of_a_clean_file_finds_nothing() {
+        let content = serde_json::to_string_pretty(&Config::default()).unwrap();
+
+        let (_, issues) = Config::from_json_checked(&content).unwrap();
+
+        assert!(issues.is_empty(), "{issues:?}");
+        assert!(Config::from_json_checked("{ \"audio\": ").is_err());
+    }
 }
@@ -23,6 +23,37 @@ pub mod network {
 
     /// User agent string for HTTP requests
     pub const USER_AGENT: &str = concat!("podcast-tui/", env!("CARGO_PKG_VERSION"));
+
+    /// User agent for feed fetches and episode downloads; browser-like, since
+    /// some podcast hosts reject unknown clients
+    pub const FEED_USER_AGENT: &str = "Mozilla/5.0 (compatible; podcast-tui/1.0; +https://github.com/podcast-tui) AppleWebKit/537.36 (KHTML, like Gecko)";
+
+    /// Feeds refreshed at once by refresh-all
+    pub const REFRESH_ALL_CONCURRENCY: usize = 4;
+
+    /// Concurrent feed requests allowed to a single host
+    pub const FEED_REQUESTS_PER_HOST: usize = 2;
+
+    /// Retries of one feed request after a transient failure (429, 5xx, timeout)
+    pub const FEED_MAX_RETRIES: u32 = 2;
+
+    /// Base delay before a feed retry, doubled per attempt and jittered
+    pub const FEED_RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+
+    /// Retries allowed per host within `FEED_RETRY_BUDGET_WINDOW`, across all feeds
+    pub const FEED_RETRY_BUDGET_PER_HOST: u32 = 6;
+
+    /// Window over which the per-host retry budget is counted
+    pub const FEED_RETRY_BUDGET_WINDOW: Duration = Duration::from_secs(300);
+
+    /// Longest `Retry-After` honoured before giving up on the request
+    pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(120);
+
+    /// Time between checks for the connection coming back while offline
+    pub const OFFLINE_PROBE_INTERVAL: Duration = Duration::from_secs(15);
+
+    /// How long one connectivity check waits for a connection
+    pub const CONNECTIVITY_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
 }
 
 /// File system-related constants
@@ -44,6 +75,8 @@ pub mod filesystem {
 
 /// Download configuration constants
 pub mod downloads {
+    use super::*;
+
     /// Default number of concurrent downloads
     pub const DEFAULT_CONCURRENT_DOWNLOADS: usize = 3;
 
@@ -64,6 +97,45 @@ pub mod downloads {
 
     /// Default device path used when no sync_device_path is configured
     pub const DEFAULT_SYNC_DEVICE_PATH: &str = "/mnt/mp3player";
+
+    /// Battery percentage at or below which downloads and syncs are held
+    /// when `pause_on_battery` is enabled
+    pub const DEFAULT_BATTERY_PAUSE_THRESHOLD: u8 = 30;
+
+    /// How often the power source is re-checked while `pause_on_battery` is on
+    pub const POWER_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// New episodes per podcast one refresh auto-downloads by default
+    pub const DEFAULT_AUTO_DOWNLOAD_LIMIT: usize = 3;
+
+    /// Most episodes `prefetch_next` fetches ahead
+    pub const MAX_PREFETCH_EPISODES: usize = 2;
+
+    /// Time without key presses after which idle prefetching starts
+    pub const PREFETCH_IDLE_AFTER: Duration = Duration::from_secs(30);
+
+    /// Minimum gap between download progress updates sent to the UI
+    pub const DOWNLOAD_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+    /// Speed samples kept per download for the Downloads buffer's graph
+    /// (one per progress update, so about 15 seconds)
+    pub const SPEED_HISTORY_SAMPLES: usize = 60;
+
+    /// Marker in the downloads directory recording that the filename collision
+    /// check has run
+    pub const FILENAME_COLLISION_MARKER: &str = ".filename-collisions-checked";
+
+    /// Directory in the downloads folder holding fetched artwork
+    pub const ARTWORK_CACHE_DIR: &str = ".artwork";
+
+    /// Artwork fetched at once when prefetching after a refresh
+    pub const ARTWORK_PREFETCH_CONCURRENCY: usize = 2;
+
+    /// Artwork larger than this is not downloaded
+    pub const ARTWORK_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+    /// Artwork is scaled down to fit this many pixels on its longer side
+    pub const ARTWORK_MAX_DIMENSION: u32 = 1400;
 }
 
 /// UI configuration constants
@@ -88,14 +160,100 @@ pub mod ui {
     /// Frame rate cap for rendering (milliseconds between frames)
     pub const MIN_FRAME_INTERVAL_MS: u64 = 16; // ~60 FPS
 
+    /// `ui.performance.preset` when not configured
+    pub const DEFAULT_PERFORMANCE_PRESET: &str = "balanced";
+
+    /// `responsive` preset: quicker ticks for snappier timers and status
+    pub const RESPONSIVE_TICK_RATE_MS: u64 = 100;
+
+    /// `battery-saver` preset: fewer wakeups while idle
+    pub const BATTERY_SAVER_TICK_RATE_MS: u64 = 1000;
+    pub const BATTERY_SAVER_FRAME_INTERVAL_MS: u64 = 100; // ~10 FPS
+    pub const BATTERY_SAVER_KEY_REPEAT_MS: u64 = 50;
+    pub const BATTERY_SAVER_COMPLETION_DEBOUNCE_MS: u64 = 150;
+
+    /// How long quitting waits for storage writes and downloads to finish
+    pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Status message display duration (milliseconds)
     pub const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(3);
 
     /// Minibuffer history size
     pub const MINIBUFFER_HISTORY_SIZE: usize = 100;
 
+    /// Most played episodes the History buffer lists
+    pub const HISTORY_LIMIT: usize = 500;
+
+    /// Default cap on open episode-list buffers before LRU recycling kicks in
+    pub const DEFAULT_MAX_EPISODE_BUFFERS: usize = 8;
+
+    /// Default podcast list ordering (`alphabetical`, `recent` or `unplayed`)
+    pub const DEFAULT_PODCAST_LIST_SORT: &str = "alphabetical";
+
+    /// Default months without a new episode before a show is flagged inactive
+    pub const DEFAULT_INACTIVE_AFTER_MONTHS: u32 = 12;
+
+    /// Workspace every buffer starts in; it can't be closed
+    pub const DEFAULT_WORKSPACE: &str = "main";
+
+    /// Default minutes `:focus` hides messages for
+    pub const DEFAULT_FOCUS_MODE_MINUTES: u32 = 60;
+
+    /// Shortest and longest `ui.focus_mode_minutes`; 0 would end focus
+    /// mode as soon as it starts
+    pub const MIN_FOCUS_MODE_MINUTES: u32 = 1;
+    pub const MAX_FOCUS_MODE_MINUTES: u32 = 24 * 60;
+
+    /// Minimum main-area width (columns) before the buffer list shows a preview pane
+    pub const BUFFER_LIST_PREVIEW_MIN_WIDTH: u16 = 100;
+
+    /// Maximum rows shown in the minibuffer completion popup (one page)
+    pub const COMPLETION_POPUP_MAX_ROWS: usize = 10;
+
+    /// Text rows the multi-line input popup always offers (it grows to half the screen)
+    pub const MULTILINE_POPUP_MIN_ROWS: u16 = 6;
+
+    /// Status bar layout when `ui.statusbar` is not configured
+    pub const DEFAULT_STATUSBAR_SEGMENTS: &[&str] =
+        &["buffer", "keys", "fill", "indicator", "hint", "help"];
+
+    /// Default strftime format for the status bar clock
+    pub const DEFAULT_STATUSBAR_CLOCK_FORMAT: &str = "%H:%M";
+
+    /// Show notes shorter than this (in approximate screen rows) are never folded
+    pub const DETAIL_FOLD_MIN_ROWS: usize = 20;
+
+    /// Lines kept visible when a long paragraph is folded
+    pub const DETAIL_FOLD_PREVIEW_LINES: usize = 3;
+
+    /// Character budget for a folded paragraph's preview
+    pub const DETAIL_FOLD_PREVIEW_CHARS: usize = 240;
+
     /// Maximum number of concurrent storage I/O operations during background buffer refresh
     pub const REFRESH_IO_CONCURRENCY: usize = 16;
+
+    /// Commands that still run with `ui.simple_mode` on
+    pub const SIMPLE_MODE_COMMANDS: &[&str] = &[
+        "quit",
+        "q",
+        "help",
+        "h",
+        "legend",
+        "add-podcast",
+        "refresh",
+        "search",
+        "clear-filters",
+        "widen",
+        "buffer",
+        "b",
+        "switch-to-buffer",
+        "switch-buffer",
+        "list-buffers",
+        "buffers",
+        "close-buffer",
+        "kill-buffer",
+        "mute",
+    ];
 }
 
 /// Storage-related constants
@@ -117,6 +275,15 @@ pub mod storage {
 
     /// Maximum number of backups to keep
     pub const MAX_BACKUPS: usize = 5;
+
+    /// Days an unsubscribed podcast can be restored before it is permanently deleted
+    pub const DEFAULT_PODCAST_RESTORE_DAYS: u32 = 30;
+
+    /// Activity timeline entries kept per podcast
+    pub const MAX_PODCAST_HISTORY_EVENTS: usize = 50;
+
+    /// Days of listening history kept for statistics and goals
+    pub const LISTENING_LOG_DAYS: i64 = 400;
 }
 
 /// Podcast feed constants
@@ -157,6 +324,9 @@ pub mod audio {
     /// Volume adjustment step
     pub const VOLUME_STEP: f32 = 0.05;
 
+    /// Share of the volume kept while ducked
+    pub const DUCK_LEVEL: f32 = 0.2;
+
     /// Seek step forward/backward (seconds)
     pub const SEEK_STEP_SECS: u64 = 10;
 
@@ -169,9 +339,20 @@ pub mod audio {
     /// Crossfade duration between tracks (milliseconds)
     pub const CROSSFADE_DURATION_MS: u64 = 1000;
 
+    /// Playback speeds offered in the podcast settings view, slowest first;
+    /// speeds outside this range are clamped to it
+    pub const PLAYBACK_SPEEDS: &[f32] = &[0.75, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0];
+
+    /// Countdown before the next episode auto-plays (seconds)
+    pub const AUTO_PLAY_COUNTDOWN_SECS: u64 = 5;
+
+    /// How long `:cast` waits for renderers to answer (seconds)
+    pub const CAST_DISCOVERY_TIMEOUT_SECS: u64 = 3;
+
     /// Error message shown to the user when audio hardware init failed and playback
     /// is requested. Centralised here to keep all user-facing strings consistent.
-    pub const UNAVAILABLE_ERROR: &str = "Audio playback not available on this system";
+    pub const UNAVAILABLE_ERROR: &str =
+        "Audio playback not available on this system (:audio-diagnostics)";
 }
 
 /// Podcast discovery (PodcastIndex API) constants
@@ -191,6 +372,20 @@ pub mod discovery {
     pub const MAX_SEARCH_RESULTS: usize = 50;
 }
 
+/// Update check constants
+pub mod updates {
+    use super::*;
+
+    /// GitHub releases API for this project
+    pub const RELEASES_API_URL: &str = "https://api.github.com/repos/lqdev/podcast-tui/releases";
+
+    /// HTTP request timeout for the release check
+    pub const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Version of this build, compared against release tags
+    pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+}
+
 /// OPML import/export constants
 pub mod opml {
     use super::*;
@@ -198,6 +393,9 @@ pub mod opml {
     /// Maximum number of feeds to import in parallel
     pub const MAX_PARALLEL_IMPORTS: usize = 5;
 
+    /// Default minimum spacing between import requests to the same host (ms)
+    pub const DEFAULT_PER_HOST_INTERVAL_MS: u64 = 1000;
+
     /// Timeout for individual feed import during OPML
     pub const IMPORT_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -0,0 +1,227 @@
+// Crash handler - panic hook with terminal restore and a crash report
+//
+// A panic used to leave the terminal in raw mode on the alternate screen.
+// The hook installed by `install` first puts the terminal back, then writes a
+// report (panic message, location, recent UI actions, open buffers and a
+// backtrace) to `crash-reports/` under the data directory and says where it
+// went. The UI feeds the report through a shared `CrashContext`.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use crossterm::{cursor, execute, terminal};
+
+/// Directory under the data directory that crash reports are written to
+pub const CRASH_REPORTS_DIR: &str = "crash-reports";
+
+/// UI actions kept for the report
+const MAX_RECENT_ACTIONS: usize = 30;
+
+/// Longest action description kept; episode-carrying actions debug-print
+/// whole episodes
+const MAX_ACTION_LEN: usize = 200;
+
+/// What the UI was doing, as of the last handled action
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrashState {
+    /// Oldest first
+    pub recent_actions: VecDeque<String>,
+    pub buffers: Vec<String>,
+    pub current_buffer: Option<String>,
+}
+
+/// Handle the UI updates and the panic hook reads
+#[derive(Debug, Clone, Default)]
+pub struct CrashContext {
+    state: Arc<Mutex<CrashState>>,
+}
+
+impl CrashContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Note a handled action and the buffers open after it
+    pub fn record_action(
+        &self,
+        action: &str,
+        buffers: Vec<String>,
+        current_buffer: Option<String>,
+    ) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.recent_actions.len() == MAX_RECENT_ACTIONS {
+            state.recent_actions.pop_front();
+        }
+        let action = match action.char_indices().nth(MAX_ACTION_LEN) {
+            Some((cut, _)) => format!("{}…", &action[..cut]),
+            None => action.to_string(),
+        };
+        state.recent_actions.push_back(action);
+        state.buffers = buffers;
+        state.current_buffer = current_buffer;
+    }
+
+    pub fn snapshot(&self) -> CrashState {
+        // A panic while the lock was held must not stop the report
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Install the panic hook; reports go to `data_dir/crash-reports/`
+pub fn install(data_dir: PathBuf, context: CrashContext) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        default_hook(info);
+
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+        let report = format_report(
+            &message,
+            location.as_deref(),
+            &context.snapshot(),
+            Utc::now(),
+            &std::backtrace::Backtrace::force_capture().to_string(),
+        );
+        match write_report(&data_dir, &report, Utc::now()) {
+            Ok(path) => eprintln!(
+                "\npodcast-tui crashed. A crash report was saved to {}\nPlease attach it when reporting the problem.",
+                path.display()
+            ),
+            Err(e) => eprintln!("\npodcast-tui crashed. Could not save a crash report: {}", e),
+        }
+    }));
+}
+
+/// Leave raw mode and the alternate screen, and show the cursor again
+fn restore_terminal() {
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(io::stdout(), terminal::LeaveAlternateScreen, cursor::Show);
+}
+
+/// Crash report text
+pub fn format_report(
+    message: &str,
+    location: Option<&str>,
+    state: &CrashState,
+    time: DateTime<Utc>,
+    backtrace: &str,
+) -> String {
+    let mut report = format!(
+        "podcast-tui {} crash report\nTime:     {}\nPlatform: {} {}\nPanic:    {}\nLocation: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        time.format("%Y-%m-%d %H:%M:%S UTC"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        message,
+        location.unwrap_or("unknown")
+    );
+
+    report.push_str(&format!(
+        "\nCurrent buffer: {}\nOpen buffers:\n",
+        state.current_buffer.as_deref().unwrap_or("none")
+    ));
+    for buffer in &state.buffers {
+        report.push_str(&format!("  {}\n", buffer));
+    }
+
+    report.push_str("\nRecent actions (oldest first):\n");
+    if state.recent_actions.is_empty() {
+        report.push_str("  none\n");
+    }
+    for action in &state.recent_actions {
+        report.push_str(&format!("  {}\n", action));
+    }
+
+    report.push_str("\nBacktrace:\n");
+    report.push_str(backtrace);
+    report.push('\n');
+    report
+}
+
+/// Write `report` to `crash-<timestamp>.txt` in the crash reports directory
+pub fn write_report(data_dir: &Path, report: &str, time: DateTime<Utc>) -> io::Result<PathBuf> {
+    let dir = data_dir.join(CRASH_REPORTS_DIR);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("crash-{}.txt", time.format("%Y%m%d-%H%M%S")));
+    let mut file = fs::File::create(&path)?;
+    file.write_all(report.as_bytes())?;
+    file.sync_all()?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_context_keeps_recent_actions_and_buffers() {
+        // Arrange
+        let context = CrashContext::new();
+
+        // Act
+        for i in 0..MAX_RECENT_ACTIONS + 5 {
+            context.record_action(
+                &format!("MoveDown {}", i),
+                vec!["podcast-list".to_string(), "downloads".to_string()],
+                Some("downloads".to_string()),
+            );
+        }
+        context.record_action(&"x".repeat(500), Vec::new(), None);
+
+        // Assert
+        let state = context.snapshot();
+        assert_eq!(state.recent_actions.len(), MAX_RECENT_ACTIONS);
+        assert_eq!(state.recent_actions[0], "MoveDown 6");
+        assert_eq!(
+            state.recent_actions.back().unwrap().chars().count(),
+            MAX_ACTION_LEN + 1
+        );
+        assert!(state.buffers.is_empty());
+    }
+
+    #[test]
+    fn test_report_lists_panic_buffers_and_actions_and_is_saved() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let context = CrashContext::new();
+        context.record_action(
+            "SelectItem",
+            vec!["podcast-list".to_string()],
+            Some("podcast-list".to_string()),
+        );
+
+        // Act
+        let report = format_report(
+            "index out of bounds",
+            Some("src/ui/app.rs:10:5"),
+            &context.snapshot(),
+            Utc::now(),
+            "0: main",
+        );
+        let path = write_report(dir.path(), &report, Utc::now()).unwrap();
+
+        // Assert
+        assert!(report.contains("Panic:    index out of bounds"));
+        assert!(report.contains("Location: src/ui/app.rs:10:5"));
+        assert!(report.contains("Current buffer: podcast-list\nOpen buffers:\n  podcast-list\n"));
+        assert!(report.contains("Recent actions (oldest first):\n  SelectItem\n"));
+        assert!(path.starts_with(dir.path().join(CRASH_REPORTS_DIR)));
+        assert_eq!(fs::read_to_string(path).unwrap(), report);
+    }
+}
@@ -0,0 +1,276 @@
+// Headless daemon - `podcast-tui daemon`
+//
+// Runs the TUI's background upkeep on a server or NAS, without a terminal:
+// every `daemon.refresh_interval_minutes` it refreshes all subscriptions
+// (but not before a podcast's own refresh interval), downloads the new
+// episodes when `downloads.auto_download_new` or the podcast's own setting is
+// on (at most `downloads.auto_download_limit` per podcast), and applies the
+// retention settings (`downloads.cleanup_after_days`, the retention rules of
+// `download::retention`, `storage.podcast_restore_days`). It reads and writes
+// the same data directory as the TUI, so a TUI whose `storage.data_directory`
+// points at the share sees what the daemon fetched. It holds the data
+// directory's lock while it runs, so a TUI alongside it opens with
+// `--read-only`. Progress is logged to stdout.
+//
+// There is no remote HTTP API to serve yet, so the daemon doesn't listen on
+// any port; it only does the upkeep above.
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::app::Services;
+use crate::download::AutoDownloads;
+use crate::Config;
+
+/// What one daemon pass did
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CycleReport {
+    pub podcasts: usize,
+    pub new_episodes: usize,
+    /// Podcasts whose feed server couldn't be reached
+    pub unreachable: usize,
+    pub downloaded: usize,
+    pub download_failures: usize,
+    /// Old downloads deleted by `downloads.cleanup_after_days` or the
+    /// retention rules
+    pub cleaned_up: usize,
+}
+
+impl CycleReport {
+    /// One log line describing the pass
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "Refreshed {} podcast(s): {} new episode(s)",
+            self.podcasts, self.new_episodes
+        );
+        if self.unreachable > 0 {
+            summary.push_str(&format!(", {} unreachable", self.unreachable));
+        }
+        if self.downloaded > 0 || self.download_failures > 0 {
+            summary.push_str(&format!(", {} downloaded", self.downloaded));
+            if self.download_failures > 0 {
+                summary.push_str(&format!(" ({} failed)", self.download_failures));
+            }
+        }
+        if self.cleaned_up > 0 {
+            summary.push_str(&format!(", {} old download(s) removed", self.cleaned_up));
+        }
+        summary
+    }
+}
+
+/// Background upkeep without the TUI
+pub struct Daemon {
+    config: Config,
+    services: Services,
+}
+
+impl Daemon {
+    pub fn new(config: Config, services: Services) -> Self {
+        Self { config, services }
+    }
+
+    /// Time between passes
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.config.daemon.refresh_interval_minutes.max(1) * 60)
+    }
+
+    /// Run passes until interrupted (Ctrl-C, or SIGTERM on Unix)
+    pub async fn run(&self) -> Result<()> {
+        log(&format!(
+            "podcast-tui daemon started on {} (every {} min)",
+            self.services.storage.data_dir.display(),
+            self.interval().as_secs() / 60
+        ));
+        let shutdown = shutdown_signal();
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => {
+                    log("Stopping in the middle of a pass");
+                    break;
+                }
+                report = self.run_once() => log(&report.summary()),
+            }
+            tokio::select! {
+                _ = &mut shutdown => break,
+                _ = tokio::time::sleep(self.interval()) => {}
+            }
+        }
+        log("podcast-tui daemon stopped");
+        Ok(())
+    }
+
+    /// One pass: purge expired removals, refresh, auto-download, clean up
+    pub async fn run_once(&self) -> CycleReport {
+        let Services {
+            download_manager,
+            subscription_manager,
+            ..
+        } = &self.services;
+        let mut report = CycleReport::default();
+
+        if let Err(e) = subscription_manager
+            .purge_expired_removed(self.config.storage.podcast_restore_days)
+            .await
+        {
+            log(&format!("Could not purge removed podcasts: {}", e));
+        }
+        if let Err(e) = download_manager.cleanup_stuck_downloads().await {
+            log(&format!("Could not clean up stuck downloads: {}", e));
+        }
+
+        let auto_downloads = AutoDownloads::new(&self.config.downloads);
+        let now = chrono::Utc::now();
+        let counts = subscription_manager
+            .refresh_matching_counting(
+                |podcast| podcast.refresh_due(now),
+                |podcast, episode| {
+                    auto_downloads.offer(podcast, episode);
+                    true
+                },
+            )
+            .await;
+        match counts {
+            Ok(counts) => {
+                report.podcasts = counts.podcasts;
+                report.new_episodes = counts.new_episodes;
+                report.unreachable = counts.unreachable.len();
+            }
+            Err(e) => log(&format!("Refresh failed: {}", e)),
+        }
+
+        for (podcast_id, episode_id) in auto_downloads.into_selection() {
+            match download_manager
+                .download_episode(&podcast_id, &episode_id)
+                .await
+            {
+                Ok(()) => report.downloaded += 1,
+                Err(e) => {
+                    report.download_failures += 1;
+                    log(&format!("Download failed: {}", e));
+                }
+            }
+        }
+
+        if let Some(days) = self.config.downloads.cleanup_after_days.filter(|d| *d > 0) {
+            match download_manager.cleanup_old_downloads(days).await {
+                Ok(count) => report.cleaned_up = count,
+                Err(e) => log(&format!("Auto-cleanup failed: {}", e)),
+            }
+        }
+        match download_manager.apply_retention_policies().await {
+            Ok(count) => report.cleaned_up += count,
+            Err(e) => log(&format!("Retention cleanup failed: {}", e)),
+        }
+
+        report
+    }
+}
+
+/// Resolves on Ctrl-C, or on SIGTERM (as sent by systemd and `docker stop`)
+fn shutdown_signal() -> impl std::future::Future<Output = ()> {
+    // Registered now rather than when first awaited, so a signal during
+    // the first pass is caught instead of killing the process
+    #[cfg(unix)]
+    let listeners = {
+        use tokio::signal::unix::{signal, SignalKind};
+        (
+            signal(SignalKind::interrupt()).ok(),
+            signal(SignalKind::terminate()).ok(),
+        )
+    };
+    async move {
+        #[cfg(unix)]
+        if let (Some(mut interrupt), Some(mut terminate)) = listeners {
+            tokio::select! {
+                _ = interrupt.recv() => {}
+                _ = terminate.recv() => {}
+            }
+            return;
+        }
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Timestamped line on stdout
+fn log(message: &str) {
+    println!(
+        "[{}] {}",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        message
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{Storage, StorageAccess};
+
+    #[tokio::test]
+    async fn test_run_once_on_empty_library() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.storage.data_directory = Some(dir.path().join("data").display().to_string());
+        config.downloads.directory = dir.path().join("downloads").display().to_string();
+        config.daemon.refresh_interval_minutes = 0;
+        let services = Services::start(&config, StorageAccess::Exclusive, "daemon")
+            .await
+            .unwrap();
+        let daemon = Daemon::new(config, services);
+
+        // Act
+        let report = daemon.run_once().await;
+
+        // Assert
+        assert_eq!(report, CycleReport::default());
+        assert_eq!(report.summary(), "Refreshed 0 podcast(s): 0 new episode(s)");
+        // An interval of 0 still waits a minute between passes
+        assert_eq!(daemon.interval(), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_tui_next_to_running_daemon_can_only_browse() {
+        // Arrange — the daemon holds the data directory
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.storage.data_directory = Some(dir.path().join("data").display().to_string());
+        config.downloads.directory = dir.path().join("downloads").display().to_string();
+        let daemon = Services::start(&config, StorageAccess::Exclusive, "daemon")
+            .await
+            .unwrap();
+
+        // Act
+        let locked = Services::start(&config, StorageAccess::Exclusive, "tui").await;
+        let browsing = Services::start(&config, StorageAccess::ReadOnly, "tui")
+            .await
+            .unwrap();
+
+        // Assert
+        let error = locked.err().expect("the lock is held").to_string();
+        assert!(error.contains("podcast-tui daemon (pid"), "{error}");
+        assert!(error.contains("--read-only"), "{error}");
+        assert!(browsing.lock.is_none());
+        assert!(browsing.storage.is_read_only());
+        assert!(daemon.lock.is_some());
+    }
+
+    #[test]
+    fn test_cycle_report_summary_mentions_downloads_and_cleanup() {
+        let report = CycleReport {
+            podcasts: 12,
+            new_episodes: 3,
+            unreachable: 1,
+            downloaded: 2,
+            download_failures: 1,
+            cleaned_up: 4,
+        };
+
+        assert_eq!(
+            report.summary(),
+            "Refreshed 12 podcast(s): 3 new episode(s), 1 unreachable, 2 downloaded (1 failed), 4 old download(s) removed"
+        );
+    }
+}
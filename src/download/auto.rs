@@ -0,0 +1,140 @@
+// Auto-download - which new episodes a refresh downloads by itself
+//
+// A podcast downloads its new episodes when its own "Auto-download new
+// episodes" setting is on, or when it follows the config and
+// `downloads.auto_download_new` is on. Only the newest
+// `downloads.auto_download_limit` new episodes of each podcast are fetched
+// per refresh, so subscribing to a show with a long back catalogue doesn't
+// download all of it. Both the TUI and the daemon pick episodes this way.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use crate::config::DownloadConfig;
+use crate::podcast::{Episode, Podcast};
+use crate::storage::{EpisodeId, PodcastId};
+
+/// New episodes offered per podcast, with their publish dates
+type Offered = HashMap<PodcastId, Vec<(DateTime<Utc>, EpisodeId)>>;
+
+/// Collects the new episodes a refresh finds and picks the ones to download
+pub struct AutoDownloads {
+    enabled: bool,
+    limit: usize,
+    offered: Mutex<Offered>,
+}
+
+impl AutoDownloads {
+    pub fn new(config: &DownloadConfig) -> Self {
+        Self {
+            enabled: config.auto_download_new,
+            limit: config.auto_download_limit,
+            offered: Mutex::new(Offered::new()),
+        }
+    }
+
+    /// Note a new episode of `podcast`; kept only when the podcast auto-downloads
+    pub fn offer(&self, podcast: &Podcast, episode: &Episode) {
+        if !podcast.settings.auto_download_new.unwrap_or(self.enabled) {
+            return;
+        }
+        if let Ok(mut offered) = self.offered.lock() {
+            offered
+                .entry(podcast.id.clone())
+                .or_default()
+                .push((episode.published, episode.id.clone()));
+        }
+    }
+
+    /// The episodes to download: the newest `limit` of each podcast
+    /// (all of them when `limit` is 0)
+    pub fn into_selection(self) -> Vec<(PodcastId, EpisodeId)> {
+        let limit = if self.limit == 0 {
+            usize::MAX
+        } else {
+            self.limit
+        };
+        let mut selection = Vec::new();
+        for (podcast_id, mut episodes) in self.offered.into_inner().unwrap_or_default() {
+            episodes.sort_by_key(|(published, _)| std::cmp::Reverse(*published));
+            selection.extend(
+                episodes
+                    .into_iter()
+                    .take(limit)
+                    .map(|(_, episode_id)| (podcast_id.clone(), episode_id)),
+            );
+        }
+        selection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn episodes(podcast: &Podcast, count: i64) -> Vec<Episode> {
+        let now = Utc::now();
+        (0..count)
+            .map(|days_ago| {
+                Episode::new(
+                    podcast.id.clone(),
+                    format!("Episode {}", days_ago),
+                    format!("https://example.com/{}.mp3", days_ago),
+                    now - Duration::days(days_ago),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_selection_keeps_the_newest_episodes_up_to_the_limit() {
+        // Arrange
+        let config = DownloadConfig {
+            auto_download_new: true,
+            auto_download_limit: 2,
+            ..DownloadConfig::default()
+        };
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let episodes = episodes(&podcast, 5);
+        let auto = AutoDownloads::new(&config);
+
+        // Act — offered oldest first, as a back catalogue might arrive
+        for episode in episodes.iter().rev() {
+            auto.offer(&podcast, episode);
+        }
+        let selection = auto.into_selection();
+
+        // Assert
+        let picked: Vec<_> = selection.into_iter().map(|(_, id)| id).collect();
+        assert_eq!(picked, vec![episodes[0].id.clone(), episodes[1].id.clone()]);
+    }
+
+    #[test]
+    fn test_podcast_setting_overrides_the_config() {
+        let config = DownloadConfig {
+            auto_download_new: true,
+            auto_download_limit: 0,
+            ..DownloadConfig::default()
+        };
+        let mut opted_out = Podcast::new("Off".to_string(), "https://example.com/off".to_string());
+        opted_out.settings.auto_download_new = Some(false);
+        let follows = Podcast::new("On".to_string(), "https://example.com/on".to_string());
+        let auto = AutoDownloads::new(&config);
+
+        for episode in episodes(&opted_out, 3) {
+            auto.offer(&opted_out, &episode);
+        }
+        for episode in episodes(&follows, 3) {
+            auto.offer(&follows, &episode);
+        }
+
+        let selection = auto.into_selection();
+        assert_eq!(selection.len(), 3);
+        assert!(selection
+            .iter()
+            .all(|(podcast_id, _)| *podcast_id == follows.id));
+    }
+}
@@ -0,0 +1,275 @@
+// Episode format conversion via ffmpeg
+//
+// Large WAV/FLAC enclosures can be shrunk in place with `:convert-episode`.
+// This module owns the ffmpeg side: target parsing, argument building and
+// progress parsing. `DownloadManager::convert_episode` handles the episode
+// bookkeeping around it.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Command;
+
+/// Executable used when `downloads.ffmpeg_path` is not set
+pub const DEFAULT_FFMPEG: &str = "ffmpeg";
+
+/// Output formats `convert-episode` can produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertFormat {
+    Mp3,
+    Opus,
+    M4a,
+    Ogg,
+}
+
+impl ConvertFormat {
+    pub const NAMES: &'static str = "mp3, opus, m4a, ogg";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "mp3" => Some(Self::Mp3),
+            "opus" => Some(Self::Opus),
+            "m4a" | "aac" => Some(Self::M4a),
+            "ogg" | "vorbis" => Some(Self::Ogg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::M4a => "m4a",
+            Self::Ogg => "ogg",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+            Self::M4a => "audio/mp4",
+            Self::Ogg => "audio/ogg",
+        }
+    }
+
+    fn codec(self) -> &'static str {
+        match self {
+            Self::Mp3 => "libmp3lame",
+            Self::Opus => "libopus",
+            Self::M4a => "aac",
+            Self::Ogg => "libvorbis",
+        }
+    }
+
+    /// Speech-friendly default bitrate in kbps
+    fn default_bitrate_kbps(self) -> u32 {
+        match self {
+            Self::Mp3 => 96,
+            Self::Opus => 48,
+            Self::M4a => 64,
+            Self::Ogg => 80,
+        }
+    }
+}
+
+/// Format and bitrate to convert an episode to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvertTarget {
+    pub format: ConvertFormat,
+    pub bitrate_kbps: u32,
+}
+
+impl ConvertTarget {
+    /// Parse `<format> [bitrate]`, where bitrate is e.g. `64k` or `64`
+    pub fn parse(format: &str, bitrate: Option<&str>) -> Result<Self, String> {
+        let format = ConvertFormat::parse(format).ok_or_else(|| {
+            format!(
+                "Unknown format '{}' (expected {})",
+                format,
+                ConvertFormat::NAMES
+            )
+        })?;
+        let bitrate_kbps = match bitrate {
+            None => format.default_bitrate_kbps(),
+            Some(value) => value
+                .trim_end_matches(['k', 'K'])
+                .parse::<u32>()
+                .ok()
+                .filter(|kbps| (8..=512).contains(kbps))
+                .ok_or_else(|| format!("Invalid bitrate '{}' (e.g. 64k)", value))?,
+        };
+        Ok(Self {
+            format,
+            bitrate_kbps,
+        })
+    }
+
+    /// Short description for messages ("opus 48k")
+    pub fn label(&self) -> String {
+        format!("{} {}k", self.format.extension(), self.bitrate_kbps)
+    }
+}
+
+/// ffmpeg arguments converting `input` to `output`, with machine-readable progress on stdout
+pub fn ffmpeg_args(input: &Path, output: &Path, target: &ConvertTarget) -> Vec<OsString> {
+    let mut args: Vec<OsString> = ["-hide_banner", "-nostdin", "-loglevel", "error", "-y", "-i"]
+        .iter()
+        .map(OsString::from)
+        .collect();
+    args.push(input.as_os_str().to_owned());
+    args.extend(
+        [
+            "-map".to_string(),
+            "0:a:0".to_string(),
+            "-map_metadata".to_string(),
+            "0".to_string(),
+            "-c:a".to_string(),
+            target.format.codec().to_string(),
+            "-b:a".to_string(),
+            format!("{}k", target.bitrate_kbps),
+            "-progress".to_string(),
+            "pipe:1".to_string(),
+            "-nostats".to_string(),
+        ]
+        .map(OsString::from),
+    );
+    args.push(output.as_os_str().to_owned());
+    args
+}
+
+/// Percent complete from one `-progress` line, given the input duration
+fn parse_progress_line(line: &str, duration_secs: u32) -> Option<u8> {
+    // `out_time_ms` is (despite the name) microseconds, same as `out_time_us`
+    let (key, value) = line.split_once('=')?;
+    if key != "out_time_us" && key != "out_time_ms" {
+        return None;
+    }
+    let micros: u64 = value.trim().parse().ok()?;
+    if duration_secs == 0 {
+        return None;
+    }
+    let percent = micros / 10_000 / duration_secs as u64;
+    Some(percent.min(100) as u8)
+}
+
+/// Run ffmpeg, reporting whole-percent progress when the duration is known
+pub async fn transcode(
+    ffmpeg: &str,
+    input: &Path,
+    output: &Path,
+    target: &ConvertTarget,
+    duration_secs: Option<u32>,
+    mut on_progress: impl FnMut(u8) + Send,
+) -> Result<(), String> {
+    let mut child = Command::new(ffmpeg)
+        .args(ffmpeg_args(input, output, target))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!(
+                    "'{}' not found; install ffmpeg or set downloads.ffmpeg_path",
+                    ffmpeg
+                )
+            } else {
+                format!("Could not start {}: {}", ffmpeg, e)
+            }
+        })?;
+
+    // Drain stderr alongside stdout so a chatty ffmpeg can't block on a full pipe
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stderr_task = tokio::spawn(async move {
+        let mut text = String::new();
+        let _ = stderr.read_to_string(&mut text).await;
+        text
+    });
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let mut lines = BufReader::new(stdout).lines();
+    let mut last_percent = None;
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Some(percent) = duration_secs.and_then(|d| parse_progress_line(&line, d)) {
+            if last_percent != Some(percent) {
+                last_percent = Some(percent);
+                on_progress(percent);
+            }
+        }
+    }
+
+    let status = child.wait().await.map_err(|e| e.to_string())?;
+    let stderr = stderr_task.await.unwrap_or_default();
+    if status.success() {
+        Ok(())
+    } else {
+        let detail = stderr.lines().last().unwrap_or("no error output").trim();
+        Err(format!("ffmpeg exited with {}: {}", status, detail))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target_with_and_without_bitrate() {
+        assert_eq!(
+            ConvertTarget::parse("opus", None).unwrap(),
+            ConvertTarget {
+                format: ConvertFormat::Opus,
+                bitrate_kbps: 48
+            }
+        );
+        assert_eq!(
+            ConvertTarget::parse("MP3", Some("128k"))
+                .unwrap()
+                .bitrate_kbps,
+            128
+        );
+        assert_eq!(
+            ConvertTarget::parse("aac", Some("64")).unwrap().format,
+            ConvertFormat::M4a
+        );
+    }
+
+    #[test]
+    fn test_parse_target_rejects_bad_input() {
+        assert!(ConvertTarget::parse("wav", None)
+            .unwrap_err()
+            .contains("Unknown format"));
+        assert!(ConvertTarget::parse("mp3", Some("fast")).is_err());
+        assert!(ConvertTarget::parse("mp3", Some("4000k")).is_err());
+    }
+
+    #[test]
+    fn test_ffmpeg_args_select_codec_and_bitrate() {
+        let target = ConvertTarget::parse("ogg", Some("80k")).unwrap();
+        let args: Vec<String> = ffmpeg_args(Path::new("in.wav"), Path::new("out.ogg"), &target)
+            .into_iter()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+
+        let after = |flag: &str| {
+            let index = args.iter().position(|a| a == flag).unwrap();
+            args[index + 1].clone()
+        };
+        assert_eq!(after("-i"), "in.wav");
+        assert_eq!(after("-c:a"), "libvorbis");
+        assert_eq!(after("-b:a"), "80k");
+        assert_eq!(args.last().unwrap(), "out.ogg");
+    }
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(parse_progress_line("out_time_us=30000000", 120), Some(25));
+        assert_eq!(parse_progress_line("out_time_ms=240000000", 120), Some(100));
+        assert_eq!(parse_progress_line("progress=continue", 120), None);
+        assert_eq!(parse_progress_line("out_time_us=N/A", 120), None);
+        assert_eq!(parse_progress_line("out_time_us=5000000", 0), None);
+    }
+}
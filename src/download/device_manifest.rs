@@ -0,0 +1,215 @@
+// Device manifest - the files a sync put on a device
+//
+// Orphan deletion used to remove any audio file under the managed `Podcasts/`
+// and `Playlists/` folders that wasn't on the PC, including files the user
+// had copied there by hand. Each sync now records what it copied (or found
+// already identical) in a manifest at the device root, and orphan deletion
+// only removes files listed there. A device without a manifest (first sync
+// with this version) loses nothing; its files are adopted as they are seen
+// to match the PC.
+//
+// Each entry also records the size and hash of what was copied, and the
+// modification time of the PC file it came from. Later syncs decide what is
+// current from the manifest and the PC side alone, so a file that hasn't
+// changed costs a directory listing on the device rather than a stat or a read.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path};
+use tokio::fs;
+
+/// Manifest file name, at the root of the device
+pub const MANIFEST_FILE: &str = ".podcast-tui-manifest.json";
+
+const MANIFEST_VERSION: u32 = 2;
+
+/// What a sync copied to one device path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub size: u64,
+    /// Lowercase hex SHA-1 of the contents
+    pub hash: String,
+    /// Modification time of the PC file when it was hashed, in seconds since
+    /// the epoch; while it's unchanged the hash is trusted without re-reading
+    pub source_modified: u64,
+}
+
+/// Device-relative paths of the files the app put on a device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceManifest {
+    version: u32,
+    /// `/`-separated, so a device moved between Windows and Unix still matches.
+    /// `None` for files known to be the app's but not what they hold (adopted
+    /// from a version 1 manifest, or their hash couldn't be taken).
+    #[serde(deserialize_with = "deserialize_files")]
+    files: BTreeMap<String, Option<ManifestEntry>>,
+}
+
+impl Default for DeviceManifest {
+    fn default() -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+/// Version 1 manifests listed paths only
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredFiles {
+    Entries(BTreeMap<String, Option<ManifestEntry>>),
+    Paths(BTreeSet<String>),
+}
+
+fn deserialize_files<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, Option<ManifestEntry>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match StoredFiles::deserialize(deserializer)? {
+        StoredFiles::Entries(entries) => entries,
+        StoredFiles::Paths(paths) => paths.into_iter().map(|path| (path, None)).collect(),
+    })
+}
+
+impl DeviceManifest {
+    /// The device's manifest; empty when there is none or it can't be read,
+    /// which protects every file
+    pub async fn load(device_path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(device_path.join(MANIFEST_FILE)).await else {
+            return Self::default();
+        };
+        let mut manifest: Self = serde_json::from_str(&content).unwrap_or_default();
+        // Older versions are migrated on read and written back as current
+        manifest.version = MANIFEST_VERSION;
+        manifest
+    }
+
+    pub async fn save(&self, device_path: &Path) -> std::io::Result<()> {
+        let path = device_path.join(MANIFEST_FILE);
+        let tmp = path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(&tmp, json).await?;
+        fs::rename(&tmp, &path).await
+    }
+
+    pub fn contains(&self, relative_path: &Path) -> bool {
+        self.files.contains_key(&manifest_key(relative_path))
+    }
+
+    /// What was copied to `relative_path`, if it's known
+    pub fn entry(&self, relative_path: &Path) -> Option<&ManifestEntry> {
+        self.files.get(&manifest_key(relative_path))?.as_ref()
+    }
+
+    /// Mark `relative_path` as the app's without recording what it holds
+    pub fn insert(&mut self, relative_path: &Path) {
+        self.files
+            .entry(manifest_key(relative_path))
+            .or_insert(None);
+    }
+
+    pub fn record(&mut self, relative_path: &Path, entry: ManifestEntry) {
+        self.files.insert(manifest_key(relative_path), Some(entry));
+    }
+
+    pub fn remove(&mut self, relative_path: &Path) {
+        self.files.remove(&manifest_key(relative_path));
+    }
+
+    /// Drop entries under `root` that `keep` rejects, e.g. files no longer on
+    /// the device
+    pub fn retain_under(&mut self, root: &Path, keep: impl Fn(&Path) -> bool) {
+        let root = manifest_key(root);
+        self.files.retain(|file, _| {
+            let under_root = file
+                .strip_prefix(&root)
+                .is_some_and(|rest| rest.starts_with('/'));
+            !under_root || keep(Path::new(file))
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+fn manifest_key(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_manifest_round_trips_and_prunes_under_a_root() {
+        // Arrange
+        let device = TempDir::new().unwrap();
+        let mut manifest = DeviceManifest::load(device.path()).await;
+        assert!(manifest.is_empty());
+        let kept = PathBuf::from("Podcasts").join("Show").join("a.mp3");
+        let gone = PathBuf::from("Podcasts").join("Show").join("b.mp3");
+        let other = PathBuf::from("Playlists").join("Mix").join("001-c.mp3");
+        for path in [&kept, &gone, &other] {
+            manifest.insert(path);
+        }
+
+        // Act
+        manifest.retain_under(Path::new("Podcasts"), |path| path.ends_with("a.mp3"));
+        manifest.save(device.path()).await.unwrap();
+        let restored = DeviceManifest::load(device.path()).await;
+
+        // Assert
+        assert_eq!(restored, manifest);
+        assert!(restored.contains(&kept));
+        assert!(!restored.contains(&gone));
+        assert!(restored.contains(&other), "outside the pruned root");
+        assert!(restored.contains(Path::new("Podcasts/Show/a.mp3")));
+    }
+
+    #[tokio::test]
+    async fn test_manifest_keeps_entries_and_reads_version_1_paths() {
+        // Arrange
+        let device = TempDir::new().unwrap();
+        let entry = ManifestEntry {
+            size: 5,
+            hash: "abc".to_string(),
+            source_modified: 1_700_000_000,
+        };
+        let mut manifest = DeviceManifest::default();
+        manifest.record(Path::new("Podcasts/Show/a.mp3"), entry.clone());
+        manifest.insert(Path::new("Podcasts/Show/a.mp3"));
+        manifest.save(device.path()).await.unwrap();
+        let old_device = TempDir::new().unwrap();
+        fs::write(
+            old_device.path().join(MANIFEST_FILE),
+            r#"{"version":1,"files":["Podcasts/Show/b.mp3"]}"#,
+        )
+        .await
+        .unwrap();
+
+        // Act
+        let restored = DeviceManifest::load(device.path()).await;
+        let migrated = DeviceManifest::load(old_device.path()).await;
+
+        // Assert
+        assert_eq!(
+            restored.entry(Path::new("Podcasts/Show/a.mp3")),
+            Some(&entry),
+            "insert doesn't forget a recorded entry"
+        );
+        assert!(migrated.contains(Path::new("Podcasts/Show/b.mp3")));
+        assert_eq!(migrated.entry(Path::new("Podcasts/Show/b.mp3")), None);
+    }
+}
@@ -1,20 +1,31 @@
-use crate::config::DownloadConfig;
-use crate::podcast::{Episode, EpisodeStatus};
+use super::convert::{self, ConvertFormat, ConvertTarget};
+use super::device_manifest::{DeviceManifest, ManifestEntry, MANIFEST_FILE};
+use super::quota::{self, EvictionCandidate, EvictionPolicy, FileId, StorageUsage};
+use super::retention::{self, CleanupPlan, RetainedDownload, RetentionRules};
+use super::{probe, transcribe, ytdlp};
+use crate::config::{DownloadConfig, TranscriptionConfig};
+use crate::podcast::{DownloadState, Enclosure, Episode};
 use crate::storage::{EpisodeId, PodcastId, Storage};
+use crate::utils::fs::format_file_size;
+use crate::utils::http::{HttpClient, HttpError, HttpRequest, ReqwestClient};
+use crate::utils::power::IoGate;
+use crate::utils::user_agent::UserAgentPolicy;
 use anyhow::Result;
 use chrono::Datelike;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use thiserror::Error;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 #[derive(Debug, Error)]
 pub enum DownloadError {
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[from] HttpError),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Storage error: {0}")]
@@ -23,6 +34,34 @@ pub enum DownloadError {
     InvalidPath(String),
     #[error("Sync error: {0}")]
     Sync(String),
+    #[error("Same audio is already downloaded as '{0}'")]
+    Duplicate(String),
+    #[error("Conversion failed: {0}")]
+    Conversion(String),
+    #[error("Transcription failed: {0}")]
+    Transcription(String),
+    #[error("yt-dlp download failed: {0}")]
+    Ytdlp(String),
+    #[error("Storage quota exceeded: {0}")]
+    QuotaExceeded(String),
+    #[error("Episode is already downloading")]
+    AlreadyDownloading,
+}
+
+impl DownloadError {
+    /// True when the server couldn't be reached at all (offline, DNS, timeout)
+    pub fn is_network(&self) -> bool {
+        matches!(self, Self::Http(e) if e.is_connect() || e.is_timeout())
+    }
+}
+
+/// Outcome of the filename collision check
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CollisionReport {
+    /// Episodes that pointed at another episode's file and were reset for re-download
+    pub reset: usize,
+    /// Shared files whose rightful episode couldn't be determined
+    pub ambiguous: Vec<PathBuf>,
 }
 
 /// Device sync error types
@@ -58,6 +97,17 @@ pub enum SyncProgressEvent {
     Complete { report: SyncReport },
 }
 
+/// How a sync reconciles the device with the PC
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// Delete files in the managed directories that aren't on the PC
+    pub delete_orphans: bool,
+    /// Only report what would be done
+    pub dry_run: bool,
+    /// Wipe the managed directories before copying
+    pub hard_sync: bool,
+}
+
 /// Report of sync operations performed
 #[derive(Debug, Clone)]
 pub struct SyncReport {
@@ -65,6 +115,8 @@ pub struct SyncReport {
     pub files_deleted: Vec<PathBuf>,
     pub files_skipped: Vec<PathBuf>,
     pub errors: Vec<(PathBuf, String)>,
+    /// Orphans left on the device because no sync put them there
+    pub files_protected: Vec<PathBuf>,
     /// File sizes (bytes) for files to be copied — populated during both real and dry-run syncs.
     pub file_sizes: std::collections::HashMap<PathBuf, u64>,
 }
@@ -77,6 +129,7 @@ impl SyncReport {
             files_deleted: Vec::new(),
             files_skipped: Vec::new(),
             errors: Vec::new(),
+            files_protected: Vec::new(),
             file_sizes: std::collections::HashMap::new(),
         }
     }
@@ -145,8 +198,127 @@ pub enum DownloadStatus {
 pub struct DownloadManager<S: Storage> {
     storage: Arc<S>,
     downloads_dir: PathBuf,
-    client: reqwest::Client,
+    client: Arc<dyn HttpClient>,
     config: DownloadConfig,
+    io_gate: Arc<IoGate>,
+    /// content hash → episode holding that audio; built lazily from storage
+    hash_index: tokio::sync::Mutex<Option<HashMap<String, (PodcastId, EpisodeId)>>>,
+    /// Downloads currently inside `download_episode`
+    active_downloads: AtomicUsize,
+    /// Episodes being downloaded; a second request for one is refused
+    in_flight: Mutex<HashSet<EpisodeId>>,
+    /// Targets (lowercased filename in its podcast folder) picked by running
+    /// downloads, so a same-titled episode downloading alongside picks another
+    reserved_targets: Mutex<HashMap<PathBuf, EpisodeId>>,
+    /// Held while a podcast's download folder is recorded, so downloads
+    /// starting together don't each load and save the podcast
+    folder_claim: tokio::sync::Mutex<()>,
+    /// User-Agent for podcasts without their own
+    user_agents: UserAgentPolicy,
+    transcription: TranscriptionConfig,
+    /// whisper is CPU-heavy: transcriptions run one at a time
+    transcription_slot: tokio::sync::Semaphore,
+}
+
+/// Decrements the active download count when a download ends, however it ends
+struct ActiveDownload<'a>(&'a AtomicUsize);
+
+impl<'a> ActiveDownload<'a> {
+    fn start(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Self(counter)
+    }
+}
+
+impl Drop for ActiveDownload<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Claims an episode for one download and releases it when the download
+/// ends, however it ends (including the task being aborted)
+struct InFlightDownload<'a> {
+    in_flight: &'a Mutex<HashSet<EpisodeId>>,
+    episode_id: EpisodeId,
+}
+
+impl<'a> InFlightDownload<'a> {
+    /// `None` when the episode is already being downloaded
+    fn claim(in_flight: &'a Mutex<HashSet<EpisodeId>>, episode_id: &EpisodeId) -> Option<Self> {
+        let claimed = in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(episode_id.clone());
+        claimed.then(|| Self {
+            in_flight,
+            episode_id: episode_id.clone(),
+        })
+    }
+}
+
+impl Drop for InFlightDownload<'_> {
+    fn drop(&mut self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.episode_id);
+    }
+}
+
+/// Holds a download's target filename until the download ends, however it
+/// ends, so no other download picks the same file meanwhile
+struct ReservedTarget<'a> {
+    reserved: &'a Mutex<HashMap<PathBuf, EpisodeId>>,
+    key: PathBuf,
+}
+
+impl Drop for ReservedTarget<'_> {
+    fn drop(&mut self) {
+        self.reserved
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.key);
+    }
+}
+
+/// Whether other paths share `path`'s file, as deduplication's hard links
+/// do; assumed so where the link count can't be read
+async fn has_other_links(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        fs::metadata(path)
+            .await
+            .map_or(true, |metadata| metadata.nlink() > 1)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        true
+    }
+}
+
+/// How a download that duplicates existing audio is handled
+/// (`downloads.duplicate_downloads`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DuplicatePolicy {
+    /// Replace the new copy with a hard link to the existing file
+    HardLink,
+    /// Discard the new copy and report the episode already holding it
+    Refuse,
+    /// Keep both copies
+    Keep,
+}
+
+impl DuplicatePolicy {
+    fn from_config(value: &str) -> Self {
+        match value {
+            "refuse" => Self::Refuse,
+            "keep" => Self::Keep,
+            _ => Self::HardLink,
+        }
+    }
 }
 
 impl<S: Storage> DownloadManager<S> {
@@ -155,24 +327,77 @@ impl<S: Storage> DownloadManager<S> {
             .timeout(std::time::Duration::from_secs(60)) // Longer timeout for downloads
             .connect_timeout(std::time::Duration::from_secs(10))
             .redirect(reqwest::redirect::Policy::limited(10)) // Handle redirects
-            .user_agent("Mozilla/5.0 (compatible; podcast-tui/1.0; +https://github.com/podcast-tui) AppleWebKit/537.36 (KHTML, like Gecko)")
+            .user_agent(crate::constants::network::FEED_USER_AGENT)
             .build()?;
 
         Ok(Self {
             storage,
             downloads_dir,
-            client,
+            client: Arc::new(ReqwestClient::new(client)),
             config,
+            io_gate: Arc::new(IoGate::new()),
+            hash_index: tokio::sync::Mutex::new(None),
+            active_downloads: AtomicUsize::new(0),
+            in_flight: Mutex::new(HashSet::new()),
+            reserved_targets: Mutex::new(HashMap::new()),
+            folder_claim: tokio::sync::Mutex::new(()),
+            user_agents: UserAgentPolicy::default(),
+            transcription: TranscriptionConfig::default(),
+            transcription_slot: tokio::sync::Semaphore::new(1),
         })
     }
 
+    /// Download episodes of podcasts without their own User-Agent as
+    /// `user_agents` says
+    pub fn with_user_agent_policy(mut self, user_agents: UserAgentPolicy) -> Self {
+        self.user_agents = user_agents;
+        self
+    }
+
+    /// Transcribe with whisper.cpp as `transcription` says
+    pub fn with_transcription_config(mut self, transcription: TranscriptionConfig) -> Self {
+        self.transcription = transcription;
+        self
+    }
+
+    pub fn transcription_config(&self) -> &TranscriptionConfig {
+        &self.transcription
+    }
+
+    /// Download through `client` instead of the network (e.g. a
+    /// `MockHttpClient` in tests)
+    pub fn with_http_client(mut self, client: Arc<dyn HttpClient>) -> Self {
+        self.client = client;
+        self
+    }
+
     /// Get a reference to the storage
     pub fn storage(&self) -> &Arc<S> {
         &self.storage
     }
 
+    /// Gate that holds new downloads and device copies while paused
+    /// (e.g. on low battery). Transfers already in flight are not interrupted.
+    pub fn io_gate(&self) -> &Arc<IoGate> {
+        &self.io_gate
+    }
+
+    /// Number of downloads in progress (for the status bar)
+    pub fn active_downloads(&self) -> usize {
+        self.active_downloads.load(Ordering::Relaxed)
+    }
+
+    /// Whether `episode_id` is being downloaded right now
+    pub fn is_downloading(&self, episode_id: &EpisodeId) -> bool {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(episode_id)
+    }
+
     /// Clean up stuck downloads on startup - resets episodes stuck in "Downloading" status
-    /// when there's no actual download happening
+    /// when there's no actual download happening, and removes partial
+    /// downloads nothing will resume
     pub async fn cleanup_stuck_downloads(&self) -> Result<(), DownloadError> {
         // Load all podcast IDs
         let podcast_ids = self
@@ -191,7 +416,7 @@ impl<S: Storage> DownloadManager<S> {
 
             for mut episode in episodes {
                 // Reset stuck "Downloading" episodes back to "New" status
-                if matches!(episode.status, EpisodeStatus::Downloading) {
+                if episode.status.download == DownloadState::Downloading {
                     // Check if the file actually exists and is complete
                     let should_reset = if let Some(ref local_path) = episode.local_path {
                         !tokio::fs::try_exists(local_path).await.unwrap_or(false)
@@ -200,7 +425,7 @@ impl<S: Storage> DownloadManager<S> {
                     };
 
                     if should_reset {
-                        episode.status = EpisodeStatus::New;
+                        episode.status.download = DownloadState::NotDownloaded;
                         episode.local_path = None;
 
                         self.storage
@@ -212,14 +437,168 @@ impl<S: Storage> DownloadManager<S> {
             }
         }
 
+        self.remove_orphaned_partials(false).await;
+
         Ok(())
     }
+    /// ffprobe installed alongside the configured ffmpeg
+    fn ffprobe(&self) -> PathBuf {
+        probe::ffprobe_for(self.config.ffmpeg_path.as_deref())
+    }
+
+    /// Fill in missing durations of downloaded episodes by probing their files.
+    /// Returns how many episodes gained a duration; files that can't be
+    /// probed are left alone and tried again on the next run.
+    pub async fn backfill_durations(&self) -> Result<usize, DownloadError> {
+        let ffprobe = self.ffprobe();
+        let mut filled = 0;
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        for podcast_id in podcast_ids {
+            let episodes = self
+                .storage
+                .load_episodes(&podcast_id)
+                .await
+                .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+            for mut episode in episodes {
+                if episode.duration.is_some()
+                    || episode.status.download != DownloadState::Downloaded
+                {
+                    continue;
+                }
+                let Some(path) = episode.local_path.clone().filter(|path| path.exists()) else {
+                    continue;
+                };
+                if let Some(duration) = probe::probe_duration(&path, &ffprobe).await {
+                    episode.duration = Some(duration);
+                    self.storage
+                        .save_episode(&podcast_id, &episode)
+                        .await
+                        .map_err(|e| DownloadError::Storage(e.to_string()))?;
+                    filled += 1;
+                }
+            }
+        }
+
+        Ok(filled)
+    }
+
+    /// One-time pass over the library for episodes sharing a download file,
+    /// left behind by versions that let same-titled episodes overwrite each
+    /// other. The episode whose recorded hash (or, failing that, enclosure
+    /// size) matches the file keeps it; the others are reset so they can be
+    /// downloaded again under distinct names. Files are never deleted.
+    pub async fn resolve_filename_collisions(&self) -> Result<CollisionReport, DownloadError> {
+        let marker = self
+            .downloads_dir
+            .join(crate::constants::downloads::FILENAME_COLLISION_MARKER);
+        if marker.exists() {
+            return Ok(CollisionReport::default());
+        }
+
+        let mut report = CollisionReport::default();
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        for podcast_id in podcast_ids {
+            let episodes = self
+                .storage
+                .load_episodes(&podcast_id)
+                .await
+                .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+            let mut by_path: HashMap<PathBuf, Vec<Episode>> = HashMap::new();
+            for episode in episodes {
+                if let Some(path) = episode.local_path.clone() {
+                    by_path.entry(path).or_default().push(episode);
+                }
+            }
+
+            for (path, claimants) in by_path {
+                if claimants.len() < 2 || !path.exists() {
+                    continue;
+                }
+
+                let hash = hash_file(&path).await.ok();
+                let mut owners: Vec<bool> = claimants
+                    .iter()
+                    .map(|episode| hash.is_some() && episode.content_hash == hash)
+                    .collect();
+                if !owners.contains(&true) {
+                    let size = fs::metadata(&path).await.ok().map(|m| m.len());
+                    owners = claimants
+                        .iter()
+                        .map(|episode| size.is_some() && episode.file_size == size)
+                        .collect();
+                    if owners.iter().filter(|owner| **owner).count() != 1 {
+                        report.ambiguous.push(path);
+                        continue;
+                    }
+                }
+
+                for (mut episode, owner) in claimants.into_iter().zip(owners) {
+                    if owner {
+                        continue;
+                    }
+                    episode.status.download = DownloadState::NotDownloaded;
+                    episode.local_path = None;
+                    episode.content_hash = None;
+                    self.storage
+                        .save_episode(&podcast_id, &episode)
+                        .await
+                        .map_err(|e| DownloadError::Storage(e.to_string()))?;
+                    report.reset += 1;
+                }
+            }
+        }
+
+        if self.downloads_dir.exists() {
+            fs::write(&marker, b"").await?;
+        }
+        Ok(report)
+    }
+
     /// Download an episode (simple implementation)
     pub async fn download_episode(
         &self,
         podcast_id: &PodcastId,
         episode_id: &EpisodeId,
     ) -> Result<(), DownloadError> {
+        self.download_episode_with_progress(podcast_id, episode_id, |_, _| {})
+            .await
+    }
+
+    /// Download an episode, reporting (downloaded, total) bytes when the
+    /// transfer method provides them (currently yt-dlp downloads).
+    ///
+    /// Returns `DownloadError::AlreadyDownloading` when the episode is
+    /// already being downloaded (e.g. pressing D twice, or an auto-download
+    /// racing a manual one); the running download is left alone.
+    pub async fn download_episode_with_progress(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+        on_progress: impl FnMut(u64, Option<u64>) + Send,
+    ) -> Result<(), DownloadError> {
+        // The file would land with nothing recording it
+        if self.storage.is_read_only() {
+            return Err(DownloadError::Storage(
+                crate::storage::StorageError::ReadOnly.to_string(),
+            ));
+        }
+        let Some(_in_flight) = InFlightDownload::claim(&self.in_flight, episode_id) else {
+            return Err(DownloadError::AlreadyDownloading);
+        };
+        let _active = ActiveDownload::start(&self.active_downloads);
+
         // Load episode from storage
         let mut episode = self
             .storage
@@ -234,21 +613,12 @@ impl<S: Storage> DownloadManager<S> {
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
-        // Generate folder name based on configuration
-        let folder_name = self.generate_podcast_folder_name(&podcast);
-        let podcast_dir = self.downloads_dir.join(folder_name);
-
-        // Create download directory
-        fs::create_dir_all(&podcast_dir).await?;
-
-        // Generate filename
-        let filename = self.generate_filename(&episode)?;
-        let file_path = podcast_dir.join(&filename);
+        let (file_path, _reserved) = self.reserve_download_target(&podcast, &episode).await?;
 
         // Skip if already downloaded
         if file_path.exists() {
             episode.local_path = Some(file_path);
-            episode.status = EpisodeStatus::Downloaded;
+            episode.status.download = DownloadState::Downloaded;
             self.storage
                 .save_episode(podcast_id, &episode)
                 .await
@@ -256,15 +626,25 @@ impl<S: Storage> DownloadManager<S> {
             return Ok(());
         }
 
+        // The encoding the podcast's quality setting picks
+        let encoding = episode.encoding_for(podcast.download_quality.unwrap_or_default());
+
+        // Make room under the storage quota, or refuse before fetching anything
+        let expected_size = encoding.estimated_size(episode.duration).unwrap_or(0);
+        self.make_room(expected_size, &episode.id, true).await?;
+
+        // Hold here while heavy I/O is paused; the episode keeps its current status
+        self.io_gate.wait_until_open().await;
+
         // Update status to downloading
-        episode.status = EpisodeStatus::Downloading;
+        episode.status.download = DownloadState::Downloading;
         self.storage
             .save_episode(podcast_id, &episode)
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
         // Get the audio URL - if empty, try using the GUID as fallback
-        let audio_url = if episode.audio_url.is_empty() {
+        let audio_url = if encoding.url.is_empty() {
             // Check if GUID looks like a URL and use it as fallback
             episode
                 .guid
@@ -273,12 +653,12 @@ impl<S: Storage> DownloadManager<S> {
                 .map(|s| s.as_str())
                 .unwrap_or("")
         } else {
-            &episode.audio_url
+            &encoding.url
         };
 
         if audio_url.is_empty() {
             // Mark episode as failed with specific reason
-            episode.status = EpisodeStatus::DownloadFailed;
+            episode.status.download = DownloadState::Failed;
             self.storage
                 .save_episode(podcast_id, &episode)
                 .await
@@ -289,14 +669,54 @@ impl<S: Storage> DownloadManager<S> {
             ));
         }
 
-        // Download the file
-        match self.download_file(audio_url, &file_path).await {
+        // Download the file; video pages go through yt-dlp
+        let user_agent = self.user_agents.resolve(podcast.user_agent.as_deref());
+        let result = if ytdlp::handles(audio_url, &self.config.ytdlp_hosts) {
+            let ytdlp_path = self
+                .config
+                .ytdlp_path
+                .as_deref()
+                .unwrap_or(ytdlp::DEFAULT_YTDLP);
+            let mut args = self.config.ytdlp_args.clone();
+            if let Some(user_agent) = user_agent {
+                args.extend(["--user-agent".to_string(), user_agent]);
+            }
+            ytdlp::fetch(ytdlp_path, audio_url, &file_path, &args, on_progress)
+                .await
+                .map_err(DownloadError::Ytdlp)
+        } else {
+            self.download_file(
+                audio_url,
+                &file_path,
+                user_agent.as_deref(),
+                (podcast_id, &episode.id),
+            )
+            .await
+        };
+        match result {
             Ok(_) => {
-                episode.status = EpisodeStatus::Downloaded;
+                // A linked file already carries the original episode's tags
+                let linked = match self.deduplicate_download(&mut episode, &file_path).await {
+                    Ok(linked) => linked,
+                    Err(e) => {
+                        self.storage
+                            .save_episode(podcast_id, &episode)
+                            .await
+                            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+                        return Err(e);
+                    }
+                };
+                episode.status.download = DownloadState::Downloaded;
                 episode.local_path = Some(file_path.clone());
+                episode.downloaded_enclosure = Some(Enclosure::of(&episode));
+                episode.updated_enclosure = None;
+                if episode.duration.is_none() {
+                    episode.duration = probe::probe_duration(&file_path, &self.ffprobe()).await;
+                }
 
                 // Embed ID3 metadata if configured and file is MP3
-                if self.config.embed_id3_metadata
+                if !linked
+                    && self.config.embed_id3_metadata
                     && file_path.extension().is_some_and(|ext| ext == "mp3")
                 {
                     if let Err(e) = self
@@ -309,8 +729,9 @@ impl<S: Storage> DownloadManager<S> {
                 }
             }
             Err(e) => {
-                episode.status = EpisodeStatus::DownloadFailed;
-                // Clean up partial file
+                episode.status.download = DownloadState::Failed;
+                // Clean up a partial yt-dlp file; an HTTP download keeps its
+                // `.part` file to resume from
                 let _ = fs::remove_file(&file_path).await;
                 self.storage
                     .save_episode(podcast_id, &episode)
@@ -326,9 +747,503 @@ impl<S: Storage> DownloadManager<S> {
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
+        if let Some(ref hash) = episode.content_hash {
+            if let Some(index) = self.hash_index.lock().await.as_mut() {
+                index
+                    .entry(hash.clone())
+                    .or_insert_with(|| (podcast_id.clone(), episode.id.clone()));
+            }
+        }
+
+        // The announced size may have been missing or wrong; settle up now
+        if let Err(e) = self.make_room(0, &episode.id, false).await {
+            eprintln!("Warning: Failed to enforce storage quota: {}", e);
+        }
+
         Ok(())
     }
 
+    /// Where `episode` downloads to, creating its podcast folder; the
+    /// filename is one no other episode of the podcast is using or
+    /// downloading to
+    async fn download_target(
+        &self,
+        podcast: &crate::podcast::Podcast,
+        episode: &Episode,
+    ) -> Result<PathBuf, DownloadError> {
+        self.choose_download_target(podcast, episode, false)
+            .await
+            .map(|(path, _)| path)
+    }
+
+    /// `download_target`, reserved for `episode` until the returned guard
+    /// is dropped
+    async fn reserve_download_target(
+        &self,
+        podcast: &crate::podcast::Podcast,
+        episode: &Episode,
+    ) -> Result<(PathBuf, ReservedTarget<'_>), DownloadError> {
+        let (path, key) = self.choose_download_target(podcast, episode, true).await?;
+        let reserved = ReservedTarget {
+            reserved: &self.reserved_targets,
+            key,
+        };
+        Ok((path, reserved))
+    }
+
+    /// Pick the target and its reservation key, with `reserve` recording
+    /// the reservation in the same step so two downloads can't both pick it
+    async fn choose_download_target(
+        &self,
+        podcast: &crate::podcast::Podcast,
+        episode: &Episode,
+        reserve: bool,
+    ) -> Result<(PathBuf, PathBuf), DownloadError> {
+        let folder_name = self.claim_podcast_folder(podcast).await?;
+        let podcast_dir = self.downloads_dir.join(folder_name);
+
+        // Create download directory
+        fs::create_dir_all(&podcast_dir).await?;
+
+        let mut taken = self
+            .filenames_in_use(&podcast.id, &episode.id, &podcast_dir)
+            .await?;
+        // The extension follows the encoding downloaded at the podcast's quality
+        let encoding = episode.encoding_for(podcast.download_quality.unwrap_or_default());
+        let chosen = if encoding.url == episode.audio_url {
+            None
+        } else {
+            let mut chosen = episode.clone();
+            chosen.audio_url = encoding.url;
+            Some(chosen)
+        };
+
+        let mut reserved = self
+            .reserved_targets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        taken.extend(
+            reserved
+                .iter()
+                .filter(|(key, holder)| {
+                    *holder != &episode.id && key.parent() == Some(podcast_dir.as_path())
+                })
+                .filter_map(|(key, _)| key.file_name())
+                .map(|name| name.to_string_lossy().into_owned()),
+        );
+        let filename = self.generate_filename(chosen.as_ref().unwrap_or(episode), &taken)?;
+        let key = podcast_dir.join(filename.to_lowercase());
+        if reserve {
+            reserved.insert(key.clone(), episode.id.clone());
+        }
+        Ok((podcast_dir.join(&filename), key))
+    }
+
+    /// Undo a download that was cut off (e.g. on quit): a partial file at the
+    /// target is removed and the episode goes back to `New`, so the next
+    /// download doesn't mistake it for a finished one. An HTTP download's
+    /// `.part` file is kept, and the next download resumes from it.
+    pub async fn abandon_download(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+    ) -> Result<(), DownloadError> {
+        let mut episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        if episode.status.download != DownloadState::Downloading {
+            return Ok(());
+        }
+        let podcast = self
+            .storage
+            .load_podcast(podcast_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        let partial = self.download_target(&podcast, &episode).await?;
+        let _ = fs::remove_file(&partial).await;
+        episode.status.download = DownloadState::NotDownloaded;
+        episode.local_path = None;
+        self.storage
+            .save_episode(podcast_id, &episode)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))
+    }
+
+    /// Configured storage quota in bytes, if any
+    fn quota(&self) -> Option<u64> {
+        StorageUsage::quota_bytes(self.config.max_storage_gb)
+    }
+
+    /// Every downloaded file with its size, and their total
+    async fn downloaded_files(&self) -> Result<(u64, Vec<EvictionCandidate>), DownloadError> {
+        let mut files = Vec::new();
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        for podcast_id in podcast_ids {
+            let episodes = self
+                .storage
+                .load_episodes(&podcast_id)
+                .await
+                .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+            for episode in episodes {
+                let Some(path) = episode.local_path.clone() else {
+                    continue;
+                };
+                let Ok(metadata) = fs::metadata(&path).await else {
+                    continue;
+                };
+                let (file, links) = FileId::of(&metadata);
+                files.push(EvictionCandidate {
+                    podcast_id: podcast_id.clone(),
+                    episode_id: episode.id.clone(),
+                    path,
+                    size: metadata.len(),
+                    played: episode.is_played(),
+                    pinned: episode.favorited,
+                    modified: metadata.modified().unwrap_or(std::time::UNIX_EPOCH),
+                    file,
+                    links,
+                });
+            }
+        }
+
+        // Hard-linked duplicates are one file on disk
+        let used = quota::total_size(files.iter().map(|file| (file.file, file.size)));
+        Ok((used, files))
+    }
+
+    /// Space used by downloads against the configured quota (for the
+    /// downloads buffer gauge)
+    pub async fn storage_usage(&self) -> Result<StorageUsage, DownloadError> {
+        let (used, _) = self.downloaded_files().await?;
+        Ok(StorageUsage {
+            used,
+            quota: self.quota(),
+        })
+    }
+
+    /// Evict downloads until `incoming` more bytes fit under the quota,
+    /// never touching `keep`. With `require_fit`, nothing is evicted and
+    /// `DownloadError::QuotaExceeded` is returned when enough room can't be
+    /// made; otherwise as much as the policy allows is evicted.
+    /// Returns the number of downloads evicted.
+    async fn make_room(
+        &self,
+        incoming: u64,
+        keep: &EpisodeId,
+        require_fit: bool,
+    ) -> Result<usize, DownloadError> {
+        let Some(quota) = self.quota() else {
+            return Ok(0);
+        };
+        let (used, mut files) = self.downloaded_files().await?;
+        if used.saturating_add(incoming) <= quota {
+            return Ok(0);
+        }
+        files.retain(|file| &file.episode_id != keep);
+
+        let policy = EvictionPolicy::from_config(&self.config.quota_eviction);
+        let plan = quota::plan_evictions(&files, used, incoming, quota, policy);
+        if require_fit && !plan.fits {
+            return Err(DownloadError::QuotaExceeded(format!(
+                "{} in use of {}, and not enough evictable downloads to make room for {} more",
+                format_file_size(used),
+                format_file_size(quota),
+                format_file_size(incoming)
+            )));
+        }
+
+        let mut evicted = 0;
+        for i in plan.evict {
+            let file = &files[i];
+            self.delete_episode(&file.podcast_id, &file.episode_id)
+                .await?;
+            evicted += 1;
+        }
+        if evicted > 0 {
+            self.cleanup_empty_directories().await?;
+        }
+        Ok(evicted)
+    }
+
+    /// Hash a freshly downloaded file and apply the duplicate policy.
+    ///
+    /// Returns `Ok(true)` when `file_path` was replaced by a hard link to an
+    /// existing copy. On refusal the new file is removed, the episode is reset
+    /// and `DownloadError::Duplicate` names the episode that already has it.
+    async fn deduplicate_download(
+        &self,
+        episode: &mut Episode,
+        file_path: &Path,
+    ) -> Result<bool, DownloadError> {
+        let hash = hash_file(file_path).await?;
+        episode.content_hash = Some(hash.clone());
+
+        let policy = DuplicatePolicy::from_config(&self.config.duplicate_downloads);
+        if policy == DuplicatePolicy::Keep {
+            return Ok(false);
+        }
+        let Some(original) = self.find_duplicate(&hash, &episode.id).await else {
+            return Ok(false);
+        };
+        let Some(original_path) = original.local_path.as_deref() else {
+            return Ok(false);
+        };
+
+        match policy {
+            DuplicatePolicy::Refuse => {
+                let _ = fs::remove_file(file_path).await;
+                episode.content_hash = None;
+                episode.local_path = None;
+                episode.status.download = DownloadState::NotDownloaded;
+                Err(DownloadError::Duplicate(original.title.clone()))
+            }
+            DuplicatePolicy::HardLink => {
+                // Link beside the target first so a failure leaves the downloaded copy intact
+                let staging = file_path.with_extension("dedup-link");
+                let linked = match fs::hard_link(original_path, &staging).await {
+                    Ok(()) => fs::rename(&staging, file_path).await.is_ok(),
+                    Err(_) => false,
+                };
+                if !linked {
+                    let _ = fs::remove_file(&staging).await;
+                }
+                Ok(linked)
+            }
+            DuplicatePolicy::Keep => Ok(false),
+        }
+    }
+
+    /// Find another downloaded episode whose audio hashes to `hash`
+    async fn find_duplicate(&self, hash: &str, exclude: &EpisodeId) -> Option<Episode> {
+        let mut guard = self.hash_index.lock().await;
+        if guard.is_none() {
+            *guard = Some(self.build_hash_index().await);
+        }
+        let index = guard.as_mut()?;
+        let (podcast_id, episode_id) = index.get(hash).cloned()?;
+        if episode_id == *exclude {
+            return None;
+        }
+
+        // Entries go stale when files are deleted; verify before trusting one
+        match self.storage.load_episode(&podcast_id, &episode_id).await {
+            Ok(episode)
+                if episode.content_hash.as_deref() == Some(hash)
+                    && episode.local_path.as_ref().is_some_and(|p| p.exists()) =>
+            {
+                Some(episode)
+            }
+            _ => {
+                index.remove(hash);
+                None
+            }
+        }
+    }
+
+    async fn build_hash_index(&self) -> HashMap<String, (PodcastId, EpisodeId)> {
+        let mut index = HashMap::new();
+        let podcast_ids = self.storage.list_podcasts().await.unwrap_or_default();
+        for podcast_id in podcast_ids {
+            let episodes = self
+                .storage
+                .load_episodes(&podcast_id)
+                .await
+                .unwrap_or_default();
+            for episode in episodes {
+                if let (Some(hash), Some(_)) = (episode.content_hash, episode.local_path) {
+                    index
+                        .entry(hash)
+                        .or_insert_with(|| (podcast_id.clone(), episode.id.clone()));
+                }
+            }
+        }
+        index
+    }
+
+    /// Rewrite the ID3 tags of a downloaded MP3 after its metadata was edited.
+    ///
+    /// Returns `false` when nothing was tagged: tagging is disabled, or the
+    /// episode has no downloaded MP3. Device sync picks up the rewritten file
+    /// on its next run because its size and modification time change.
+    pub async fn retag_episode(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+    ) -> Result<bool, DownloadError> {
+        if !self.config.embed_id3_metadata {
+            return Ok(false);
+        }
+        let episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        let Some(path) = episode
+            .local_path
+            .clone()
+            .filter(|path| path.exists() && path.extension().is_some_and(|ext| ext == "mp3"))
+        else {
+            return Ok(false);
+        };
+        let podcast = self
+            .storage
+            .load_podcast(podcast_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        self.embed_id3_metadata(&path, &episode, &podcast).await?;
+        Ok(true)
+    }
+
+    /// Transcode a downloaded episode with ffmpeg and point the episode at the result.
+    ///
+    /// The original file is replaced only after ffmpeg succeeds. `on_progress`
+    /// receives whole percentages when the episode duration is known.
+    pub async fn convert_episode(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+        target: &ConvertTarget,
+        on_progress: impl FnMut(u8) + Send,
+    ) -> Result<PathBuf, DownloadError> {
+        let mut episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        let input = episode
+            .local_path
+            .clone()
+            .filter(|path| path.exists())
+            .ok_or_else(|| DownloadError::InvalidPath("Episode is not downloaded".to_string()))?;
+        let extension = target.format.extension();
+        let output = input.with_extension(extension);
+        if output != input && output.exists() {
+            return Err(DownloadError::InvalidPath(format!(
+                "{} already exists",
+                output.display()
+            )));
+        }
+        let stem = input
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let staging = input.with_file_name(format!("{}.converting.{}", stem, extension));
+
+        self.io_gate.wait_until_open().await;
+
+        let ffmpeg = self
+            .config
+            .ffmpeg_path
+            .as_deref()
+            .unwrap_or(convert::DEFAULT_FFMPEG);
+        if let Err(e) = convert::transcode(
+            ffmpeg,
+            &input,
+            &staging,
+            target,
+            episode.duration,
+            on_progress,
+        )
+        .await
+        {
+            let _ = fs::remove_file(&staging).await;
+            return Err(DownloadError::Conversion(e));
+        }
+
+        if output != input {
+            fs::remove_file(&input).await?;
+        }
+        fs::rename(&staging, &output).await?;
+
+        episode.local_path = Some(output.clone());
+        episode.file_size = Some(fs::metadata(&output).await?.len());
+        episode.mime_type = Some(target.format.mime_type().to_string());
+        // The hash describes the audio as downloaded; converted audio no longer matches it
+        episode.content_hash = None;
+
+        // ffmpeg drops embedded artwork, so re-tag MP3 output the same way downloads are tagged
+        if self.config.embed_id3_metadata && target.format == ConvertFormat::Mp3 {
+            if let Ok(podcast) = self.storage.load_podcast(podcast_id).await {
+                if let Err(e) = self.embed_id3_metadata(&output, &episode, &podcast).await {
+                    eprintln!("Warning: Failed to embed ID3 metadata: {}", e);
+                }
+            }
+        }
+
+        self.storage
+            .save_episode(podcast_id, &episode)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        Ok(output)
+    }
+
+    /// Transcribe a downloaded episode with whisper.cpp and store the
+    /// transcript on it, returning the updated episode. Waits for any transcription
+    /// already running; `on_progress` gets whisper's percent complete.
+    pub async fn transcribe_episode(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+        on_progress: impl FnMut(u8) + Send,
+    ) -> Result<Episode, DownloadError> {
+        let episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        let input = episode
+            .local_path
+            .clone()
+            .filter(|path| path.exists())
+            .ok_or_else(|| DownloadError::InvalidPath("Episode is not downloaded".to_string()))?;
+
+        let _slot = self
+            .transcription_slot
+            .acquire()
+            .await
+            .map_err(|e| DownloadError::Transcription(e.to_string()))?;
+        self.io_gate.wait_until_open().await;
+
+        let ffmpeg = self
+            .config
+            .ffmpeg_path
+            .as_deref()
+            .unwrap_or(convert::DEFAULT_FFMPEG);
+        let transcript = transcribe::transcribe(ffmpeg, &self.transcription, &input, on_progress)
+            .await
+            .map_err(DownloadError::Transcription)?;
+        if transcript.is_empty() {
+            return Err(DownloadError::Transcription(
+                "whisper produced no text".to_string(),
+            ));
+        }
+
+        // Reload: the episode may have been played or edited meanwhile
+        let mut episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        episode.transcript = Some(transcript);
+        self.storage
+            .save_episode(podcast_id, &episode)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        Ok(episode)
+    }
+
     /// Delete downloaded episode file
     pub async fn delete_episode(
         &self,
@@ -341,16 +1256,18 @@ impl<S: Storage> DownloadManager<S> {
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
+        // A partial download won't be resumed for an episode the user cleared
+        if let Ok(podcast) = self.storage.load_podcast(podcast_id).await {
+            self.remove_podcast_partials(&podcast, Some(episode_id))
+                .await;
+        }
+
         if let Some(ref local_path) = episode.local_path {
             if local_path.exists() {
                 fs::remove_file(local_path).await?;
             }
             episode.local_path = None;
-            episode.status = if episode.status == EpisodeStatus::Downloaded {
-                EpisodeStatus::New
-            } else {
-                episode.status
-            };
+            episode.status.download = DownloadState::NotDownloaded;
 
             self.storage
                 .save_episode(podcast_id, &episode)
@@ -361,6 +1278,41 @@ impl<S: Storage> DownloadManager<S> {
         Ok(())
     }
 
+    /// Make an episode whose feed enclosure changed after its download ready
+    /// to download again: delete the stale file and take the feed's new
+    /// enclosure. Returns false when no update was flagged.
+    pub async fn prepare_redownload(
+        &self,
+        podcast_id: &PodcastId,
+        episode_id: &EpisodeId,
+    ) -> Result<bool, DownloadError> {
+        let episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        let Some(update) = episode.updated_enclosure else {
+            return Ok(false);
+        };
+
+        self.delete_episode(podcast_id, episode_id).await?;
+        let mut episode = self
+            .storage
+            .load_episode(podcast_id, episode_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        episode.audio_url = update.url;
+        episode.file_size = update.length;
+        episode.content_hash = None;
+        episode.downloaded_enclosure = None;
+        episode.updated_enclosure = None;
+        self.storage
+            .save_episode(podcast_id, &episode)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        Ok(true)
+    }
+
     /// Delete all downloaded episodes for a specific podcast
     /// This is called when unsubscribing from a podcast to clean up downloaded files
     pub async fn delete_podcast_downloads(
@@ -374,7 +1326,7 @@ impl<S: Storage> DownloadManager<S> {
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
-        let folder_name = self.generate_podcast_folder_name(&podcast);
+        let folder_name = self.podcast_folder_name(&podcast);
 
         // Load episodes for this podcast
         let episodes = self
@@ -388,7 +1340,7 @@ impl<S: Storage> DownloadManager<S> {
 
         for mut episode in episodes {
             // Only process downloaded episodes
-            if matches!(episode.status, EpisodeStatus::Downloaded) {
+            if episode.status.download == DownloadState::Downloaded {
                 if let Some(ref local_path) = episode.local_path {
                     // Try to delete the file
                     if local_path.exists() {
@@ -397,7 +1349,7 @@ impl<S: Storage> DownloadManager<S> {
                                 deleted_count += 1;
                                 // Update episode status
                                 episode.local_path = None;
-                                episode.status = EpisodeStatus::New;
+                                episode.status.download = DownloadState::NotDownloaded;
 
                                 // Save updated episode
                                 if self
@@ -417,7 +1369,7 @@ impl<S: Storage> DownloadManager<S> {
                         // File doesn't exist, but episode thinks it's downloaded
                         // Clean up the status
                         episode.local_path = None;
-                        episode.status = EpisodeStatus::New;
+                        episode.status.download = DownloadState::NotDownloaded;
 
                         if self
                             .storage
@@ -432,6 +1384,9 @@ impl<S: Storage> DownloadManager<S> {
             }
         }
 
+        // Partial downloads would otherwise keep the folder alive
+        self.remove_podcast_partials(&podcast, None).await;
+
         // Try to remove the podcast-specific directory if it exists and is empty
         self.cleanup_podcast_directory_by_name(&folder_name).await?;
 
@@ -467,7 +1422,7 @@ impl<S: Storage> DownloadManager<S> {
 
             for mut episode in episodes {
                 // Only process downloaded episodes
-                if matches!(episode.status, EpisodeStatus::Downloaded) {
+                if episode.status.download == DownloadState::Downloaded {
                     if let Some(ref local_path) = episode.local_path {
                         // Try to delete the file
                         if local_path.exists() {
@@ -476,7 +1431,7 @@ impl<S: Storage> DownloadManager<S> {
                                     deleted_count += 1;
                                     // Update episode status
                                     episode.local_path = None;
-                                    episode.status = EpisodeStatus::New;
+                                    episode.status.download = DownloadState::NotDownloaded;
 
                                     // Save updated episode
                                     if self
@@ -496,7 +1451,7 @@ impl<S: Storage> DownloadManager<S> {
                             // File doesn't exist, but episode thinks it's downloaded
                             // Clean up the status
                             episode.local_path = None;
-                            episode.status = EpisodeStatus::New;
+                            episode.status.download = DownloadState::NotDownloaded;
 
                             if self
                                 .storage
@@ -512,6 +1467,8 @@ impl<S: Storage> DownloadManager<S> {
             }
         }
 
+        self.remove_orphaned_partials(true).await;
+
         // Clean up empty directories in downloads folder
         self.cleanup_empty_directories().await?;
 
@@ -527,11 +1484,12 @@ impl<S: Storage> DownloadManager<S> {
 
     /// Delete downloaded episodes whose files are older than `max_age_days` days.
     ///
-    /// Convenience wrapper that converts days to hours and delegates to
-    /// `cleanup_old_downloads_hours`. Uses file modification time to determine age.
-    /// Returns the number of episodes cleaned up.
+    /// The `downloads.cleanup_after_days` pass: podcasts with their own
+    /// retention setting are left to `apply_retention_policies`. Uses file
+    /// modification time to determine age. Returns the number of episodes
+    /// cleaned up.
     pub async fn cleanup_old_downloads(&self, max_age_days: u32) -> Result<usize, DownloadError> {
-        self.cleanup_old_downloads_hours((max_age_days as u64) * 24)
+        self.cleanup_downloads_older_than((max_age_days as u64) * 24, true)
             .await
     }
 
@@ -543,6 +1501,108 @@ impl<S: Storage> DownloadManager<S> {
     pub async fn cleanup_old_downloads_hours(
         &self,
         max_age_hours: u64,
+    ) -> Result<usize, DownloadError> {
+        self.cleanup_downloads_older_than(max_age_hours, false)
+            .await
+    }
+
+    /// Work out what the retention rules would remove right now, without
+    /// deleting anything (see `retention` for the rules). Downloads whose
+    /// files are missing are left out.
+    pub async fn plan_cleanup(&self) -> Result<CleanupPlan, DownloadError> {
+        let rules = RetentionRules::from_config(&self.config);
+        let now = std::time::SystemTime::now();
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+        let mut plan = CleanupPlan {
+            quota: self.quota(),
+            ..CleanupPlan::default()
+        };
+        let mut kept = Vec::new();
+        let mut disk_files = Vec::new();
+        for podcast_id in &podcast_ids {
+            let Ok(podcast) = self.storage.load_podcast(podcast_id).await else {
+                continue;
+            };
+            let episodes = self
+                .storage
+                .load_episodes(podcast_id)
+                .await
+                .map_err(|e| DownloadError::Storage(e.to_string()))?;
+
+            let mut downloads = Vec::new();
+            for episode in episodes {
+                if episode.status.download != DownloadState::Downloaded {
+                    continue;
+                }
+                let Some(path) = &episode.local_path else {
+                    continue;
+                };
+                let Ok(metadata) = fs::metadata(path).await else {
+                    continue;
+                };
+                let (file, links) = FileId::of(&metadata);
+                disk_files.push((file, metadata.len()));
+                downloads.push(RetainedDownload {
+                    podcast_id: podcast_id.clone(),
+                    podcast_title: podcast.title.clone(),
+                    played: episode.is_played(),
+                    played_at: episode.played_at,
+                    favorited: episode.favorited,
+                    published: episode.published,
+                    size: metadata.len(),
+                    modified: metadata.modified().unwrap_or(std::time::UNIX_EPOCH),
+                    file,
+                    links,
+                    episode_id: episode.id,
+                    title: episode.title,
+                });
+            }
+
+            let (removed, rest) =
+                retention::plan_podcast(podcast.settings.retention, &rules, downloads, now);
+            plan.items.extend(removed);
+            kept.extend(rest);
+        }
+
+        // Hard-linked duplicates are one file on disk
+        plan.used = quota::total_size(disk_files);
+        if let Some(quota) = plan.quota {
+            let policy = EvictionPolicy::from_config(&self.config.quota_eviction);
+            let evicted = retention::plan_over_quota(kept, plan.used, &plan.items, quota, policy);
+            plan.items.extend(evicted);
+        }
+        Ok(plan)
+    }
+
+    /// Apply the retention rules: each podcast's own setting, or else
+    /// `downloads.keep_latest` and `downloads.cleanup_after_days`, then
+    /// `downloads.delete_played_after_days` and the storage cap. Run on
+    /// startup and after each refresh. Returns the number of episodes
+    /// cleaned up.
+    pub async fn apply_retention_policies(&self) -> Result<usize, DownloadError> {
+        let plan = self.plan_cleanup().await?;
+        for item in &plan.items {
+            self.delete_episode(&item.download.podcast_id, &item.download.episode_id)
+                .await?;
+        }
+
+        if !plan.is_empty() {
+            self.cleanup_empty_directories().await?;
+        }
+        Ok(plan.items.len())
+    }
+
+    /// `cleanup_old_downloads_hours`, optionally passing over the podcasts
+    /// that have their own retention setting
+    async fn cleanup_downloads_older_than(
+        &self,
+        max_age_hours: u64,
+        skip_own_retention: bool,
     ) -> Result<usize, DownloadError> {
         if max_age_hours == 0 {
             return Err(DownloadError::Storage(
@@ -574,6 +1634,15 @@ impl<S: Storage> DownloadManager<S> {
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
         for podcast_id in &podcast_ids {
+            if skip_own_retention
+                && self
+                    .storage
+                    .load_podcast(podcast_id)
+                    .await
+                    .is_ok_and(|podcast| podcast.settings.retention.is_some())
+            {
+                continue;
+            }
             let episodes = self
                 .storage
                 .load_episodes(podcast_id)
@@ -581,7 +1650,7 @@ impl<S: Storage> DownloadManager<S> {
                 .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
             for mut episode in episodes {
-                if !matches!(episode.status, EpisodeStatus::Downloaded) {
+                if episode.status.download != DownloadState::Downloaded {
                     continue;
                 }
                 if let Some(ref local_path) = episode.local_path {
@@ -592,7 +1661,7 @@ impl<S: Storage> DownloadManager<S> {
                                     // File is old enough — delete it
                                     match fs::remove_file(local_path).await {
                                         Ok(_) => {
-                                            episode.status = EpisodeStatus::New;
+                                            episode.status.download = DownloadState::NotDownloaded;
                                             episode.local_path = None;
                                             if let Err(e) = self
                                                 .storage
@@ -626,7 +1695,7 @@ impl<S: Storage> DownloadManager<S> {
                     } else {
                         // File doesn't exist, but episode thinks it's downloaded
                         // Clean up the stale status
-                        episode.status = EpisodeStatus::New;
+                        episode.status.download = DownloadState::NotDownloaded;
                         episode.local_path = None;
                         if let Err(e) = self.storage.save_episode(podcast_id, &episode).await {
                             failed_count += 1;
@@ -694,7 +1763,7 @@ impl<S: Storage> DownloadManager<S> {
             .await
             .map_err(|e| DownloadError::Storage(e.to_string()))?;
 
-        let folder_name = self.generate_podcast_folder_name(&podcast);
+        let folder_name = self.podcast_folder_name(&podcast);
         self.cleanup_podcast_directory_by_name(&folder_name).await
     }
 
@@ -727,19 +1796,137 @@ impl<S: Storage> DownloadManager<S> {
         Ok(())
     }
 
-    /// Simple file download implementation
-    async fn download_file(&self, url: &str, path: &Path) -> Result<(), DownloadError> {
-        let response = self.client.get(url).send().await?;
+    /// Remove partial downloads that no download will resume: ones whose
+    /// episode is gone or already downloaded, or with no `PartialSource` to
+    /// tell. With `all`, every partial not being written right now goes.
+    /// Returns how many were removed.
+    pub async fn remove_orphaned_partials(&self, all: bool) -> usize {
+        let Ok(mut dirs) = fs::read_dir(&self.downloads_dir).await else {
+            return 0;
+        };
+        let mut removed = 0;
+        while let Ok(Some(entry)) = dirs.next_entry().await {
+            if !entry.file_type().await.is_ok_and(|kind| kind.is_dir()) {
+                continue;
+            }
+            for (part_path, source) in partials_in(&entry.path()).await {
+                let orphaned = match &source {
+                    Some(source) if self.is_downloading(&source.episode_id) => false,
+                    _ if all => true,
+                    Some(source) => self
+                        .storage
+                        .load_episode(&source.podcast_id, &source.episode_id)
+                        .await
+                        .map_or(true, |episode| {
+                            episode.status.download == DownloadState::Downloaded
+                        }),
+                    None => true,
+                };
+                if orphaned {
+                    remove_partial(&part_path).await;
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Remove the partial downloads in `podcast`'s folder, only those of
+    /// `episode_id` when given; one being written right now is left alone
+    async fn remove_podcast_partials(
+        &self,
+        podcast: &crate::podcast::Podcast,
+        episode_id: Option<&EpisodeId>,
+    ) {
+        let podcast_dir = self.downloads_dir.join(self.podcast_folder_name(podcast));
+        for (part_path, source) in partials_in(&podcast_dir).await {
+            let ours = match (episode_id, &source) {
+                (_, Some(source)) if self.is_downloading(&source.episode_id) => false,
+                (None, _) => true,
+                (Some(episode_id), Some(source)) => &source.episode_id == episode_id,
+                (Some(_), None) => false,
+            };
+            if ours {
+                remove_partial(&part_path).await;
+            }
+        }
+    }
+
+    /// Download `url` to `path` by way of `<path>.part`, which keeps the
+    /// bytes that arrived when a transfer breaks off. Beside it a
+    /// `PartialSource` records the episode, the URL and the validators the
+    /// server sent. The next download asks for the rest with a `Range`
+    /// request guarded by `If-Range`, and appends it; a partial from another
+    /// URL, or with no validator to check it by, is thrown away, and when the
+    /// server answers with the whole file (the audio changed) it starts over.
+    /// The file is moved to `path` once complete.
+    async fn download_file(
+        &self,
+        url: &str,
+        path: &Path,
+        user_agent: Option<&str>,
+        owner: (&PodcastId, &EpisodeId),
+    ) -> Result<(), DownloadError> {
+        let part_path = partial_path(path);
+        let mut resume_from = fs::metadata(&part_path)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        let source = PartialSource::read(&part_path)
+            .await
+            .filter(|source| source.url == url && source.if_range().is_some());
+        if resume_from > 0 && source.is_none() {
+            remove_partial(&part_path).await;
+            resume_from = 0;
+        }
+
+        let response = loop {
+            let mut request = HttpRequest::get(url).user_agent(user_agent);
+            if let (true, Some(validator)) = (
+                resume_from > 0,
+                source.as_ref().and_then(PartialSource::if_range),
+            ) {
+                // A compressed transfer would make the byte offsets meaningless
+                request = request
+                    .header("Range", format!("bytes={}-", resume_from))
+                    .header("If-Range", validator)
+                    .header("Accept-Encoding", "identity");
+            }
+            let response = self.client.get(request).await?;
+            let content_range = response.header("content-range");
+            let resumed = match response.status {
+                206 => {
+                    content_range.and_then(content_range_start) == Some(resume_from)
+                        && source
+                            .as_ref()
+                            .is_some_and(|source| source.matches(response.header("etag")))
+                }
+                // Asked for bytes past the end: the file may be complete already
+                416 if resume_from > 0 => {
+                    if content_range.and_then(content_range_total) == Some(resume_from) {
+                        fs::rename(&part_path, path).await?;
+                        remove_partial(&part_path).await;
+                        return Ok(());
+                    }
+                    false
+                }
+                // 200 (ranges not supported, or If-Range saw the file
+                // changed) or an error, handled below
+                _ => break response,
+            };
+            if resumed || resume_from == 0 {
+                break response;
+            }
+            // The server can't continue where we left off: start over
+            remove_partial(&part_path).await;
+            resume_from = 0;
+        };
 
         // Check if the response is successful, otherwise error_for_status will return an error
         let response = response.error_for_status()?;
 
         // Get content type to verify it's actually audio
-        let content_type = response
-            .headers()
-            .get("content-type")
-            .and_then(|ct| ct.to_str().ok())
-            .unwrap_or("unknown");
+        let content_type = response.header("content-type").unwrap_or("unknown");
 
         // Reject downloads that are not audio files
         // This catches cases where servers return HTML error pages with 200 OK status
@@ -756,7 +1943,24 @@ impl<S: Storage> DownloadManager<S> {
             )));
         }
 
-        let mut file = fs::File::create(path).await?;
+        let mut file = if response.status == 206 && resume_from > 0 {
+            fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&part_path)
+                .await?
+        } else {
+            PartialSource {
+                podcast_id: owner.0.clone(),
+                episode_id: owner.1.clone(),
+                url: url.to_string(),
+                etag: response.header("etag").map(str::to_string),
+                last_modified: response.header("last-modified").map(str::to_string),
+            }
+            .write(&part_path)
+            .await?;
+            fs::File::create(&part_path).await?
+        };
         let mut stream = response.bytes_stream();
 
         while let Some(chunk_result) = stream.next().await {
@@ -765,11 +1969,42 @@ impl<S: Storage> DownloadManager<S> {
         }
 
         file.sync_all().await?;
+        drop(file);
+        fs::rename(&part_path, path).await?;
+        remove_partial(&part_path).await;
         Ok(())
     }
 
-    /// Generate safe filename for episode
-    fn generate_filename(&self, episode: &Episode) -> Result<String, DownloadError> {
+    /// Lowercased names of files in `podcast_dir` held by other episodes of the podcast
+    async fn filenames_in_use(
+        &self,
+        podcast_id: &PodcastId,
+        exclude: &EpisodeId,
+        podcast_dir: &Path,
+    ) -> Result<HashSet<String>, DownloadError> {
+        let episodes = self
+            .storage
+            .load_episodes(podcast_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        Ok(episodes
+            .iter()
+            .filter(|episode| &episode.id != exclude)
+            .filter_map(|episode| episode.local_path.as_deref())
+            .filter(|path| path.parent() == Some(podcast_dir))
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .collect())
+    }
+
+    /// Generate safe filename for episode. Names in `taken` (lowercased, since
+    /// many filesystems are case-insensitive) belong to other episodes; on a
+    /// clash the publish date and then a short GUID hash are appended.
+    fn generate_filename(
+        &self,
+        episode: &Episode,
+        taken: &HashSet<String>,
+    ) -> Result<String, DownloadError> {
         let mut filename_parts = Vec::new();
 
         // Add episode number if configured and available
@@ -791,20 +2026,12 @@ impl<S: Storage> DownloadManager<S> {
         filename_parts.push(title);
 
         // Join parts with underscores
-        let mut base_filename = filename_parts.join("_");
-
-        // Truncate if too long (reserve space for extension)
-        let max_base_len = self.config.max_filename_length.saturating_sub(4); // Reserve for .mp3
-        if base_filename.len() > max_base_len {
-            base_filename.truncate(max_base_len);
-            // Ensure we don't cut in the middle of a UTF-8 character
-            while !base_filename.is_char_boundary(base_filename.len()) {
-                base_filename.pop();
-            }
-        }
+        let base_filename = filename_parts.join("_");
 
         // Determine extension from audio URL
-        let extension = if !episode.audio_url.is_empty() {
+        let extension = if ytdlp::handles(&episode.audio_url, &self.config.ytdlp_hosts) {
+            ytdlp::AUDIO_EXTENSION.to_string()
+        } else if !episode.audio_url.is_empty() {
             episode
                 .audio_url
                 .split('.')
@@ -812,7 +2039,9 @@ impl<S: Storage> DownloadManager<S> {
                 .and_then(|ext| {
                     let ext = ext.split('?').next().unwrap_or(ext); // Remove query params
                     match ext.to_lowercase().as_str() {
-                        "mp3" | "m4a" | "aac" | "ogg" | "wav" | "flac" => Some(ext.to_lowercase()),
+                        "mp3" | "m4a" | "aac" | "ogg" | "opus" | "wav" | "flac" => {
+                            Some(ext.to_lowercase())
+                        }
                         _ => None,
                     }
                 })
@@ -826,7 +2055,7 @@ impl<S: Storage> DownloadManager<S> {
                     guid.split('.').next_back().and_then(|ext| {
                         let ext = ext.split('?').next().unwrap_or(ext);
                         match ext.to_lowercase().as_str() {
-                            "mp3" | "m4a" | "aac" | "ogg" | "wav" | "flac" => {
+                            "mp3" | "m4a" | "aac" | "ogg" | "opus" | "wav" | "flac" => {
                                 Some(ext.to_lowercase())
                             }
                             _ => None,
@@ -836,7 +2065,153 @@ impl<S: Storage> DownloadManager<S> {
                 .unwrap_or_else(|| "mp3".to_string())
         };
 
-        Ok(format!("{}.{}", base_filename, extension))
+        let mut suffixes = vec![String::new()];
+        if !self.config.include_dates {
+            suffixes.push(format!("_{}", episode.published.format("%Y-%m-%d")));
+        }
+        suffixes.push(format!("_{}", episode_short_hash(episode)));
+
+        let mut filename = String::new();
+        for suffix in &suffixes {
+            // Truncate if too long (reserve space for the suffix and extension)
+            let max_base_len = self
+                .config
+                .max_filename_length
+                .saturating_sub(4 + suffix.len()); // Reserve for .mp3
+            let mut base = base_filename.clone();
+            if base.len() > max_base_len {
+                base.truncate(max_base_len);
+                // Ensure we don't cut in the middle of a UTF-8 character
+                while !base.is_char_boundary(base.len()) {
+                    base.pop();
+                }
+            }
+            filename = format!("{}{}.{}", base, suffix, extension);
+            if !taken.contains(&filename.to_lowercase()) {
+                break;
+            }
+        }
+
+        Ok(filename)
+    }
+
+    /// Folder under the downloads directory for `podcast`: the one recorded at
+    /// its first download, else the one its title (or ID) gives now
+    fn podcast_folder_name(&self, podcast: &crate::podcast::Podcast) -> String {
+        podcast
+            .download_folder
+            .clone()
+            .unwrap_or_else(|| self.generate_podcast_folder_name(podcast))
+    }
+
+    /// The podcast's download folder, recorded on the stored podcast the first
+    /// time so later downloads keep using it whatever the title becomes
+    async fn claim_podcast_folder(
+        &self,
+        podcast: &crate::podcast::Podcast,
+    ) -> Result<String, DownloadError> {
+        if let Some(folder) = &podcast.download_folder {
+            return Ok(folder.clone());
+        }
+        let _claim = self.folder_claim.lock().await;
+        // Reload so a podcast saved since `podcast` was read isn't overwritten,
+        // including by a download that claimed the folder while this one waited
+        let mut stored = self
+            .storage
+            .load_podcast(&podcast.id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        if let Some(folder) = stored.download_folder {
+            return Ok(folder);
+        }
+        let folder = self
+            .existing_podcast_folder(&podcast.id)
+            .await
+            .unwrap_or_else(|| self.generate_podcast_folder_name(&stored));
+        stored.download_folder = Some(folder.clone());
+        self.storage
+            .save_podcast(&stored)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        Ok(folder)
+    }
+
+    /// Folder the podcast's downloads are already in, for podcasts downloaded
+    /// to before the folder was recorded
+    async fn existing_podcast_folder(&self, podcast_id: &PodcastId) -> Option<String> {
+        let episodes = self.storage.load_episodes(podcast_id).await.ok()?;
+        episodes.iter().find_map(|episode| {
+            let parent = episode.local_path.as_ref()?.parent()?;
+            if parent.parent()? != self.downloads_dir {
+                return None;
+            }
+            Some(parent.file_name()?.to_string_lossy().into_owned())
+        })
+    }
+
+    /// After a podcast's title changed, move its download folder to the name
+    /// the new title gives and repoint its episodes at the moved files.
+    /// Returns the new folder, or `None` when nothing moved: no downloads yet,
+    /// the name is unchanged (or ID-based), or another folder already has it.
+    pub async fn rename_podcast_folder(
+        &self,
+        podcast_id: &PodcastId,
+    ) -> Result<Option<String>, DownloadError> {
+        let mut podcast = self
+            .storage
+            .load_podcast(podcast_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        if !self.config.use_readable_folders {
+            return Ok(None);
+        }
+        let old_folder = match podcast.download_folder.clone() {
+            Some(folder) => folder,
+            None => match self.existing_podcast_folder(podcast_id).await {
+                Some(folder) => folder,
+                None => return Ok(None),
+            },
+        };
+        let new_folder = self.generate_podcast_folder_name(&podcast);
+        if new_folder == old_folder {
+            return Ok(None);
+        }
+        let old_dir = self.downloads_dir.join(&old_folder);
+        let new_dir = self.downloads_dir.join(&new_folder);
+        if new_dir.exists() {
+            return Ok(None);
+        }
+        if old_dir.exists() {
+            fs::rename(&old_dir, &new_dir).await?;
+        }
+
+        let episodes = self
+            .storage
+            .load_episodes(podcast_id)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        for mut episode in episodes {
+            let Some(moved) = episode
+                .local_path
+                .as_ref()
+                .and_then(|path| path.strip_prefix(&old_dir).ok())
+                .map(|rest| new_dir.join(rest))
+            else {
+                continue;
+            };
+            episode.local_path = Some(moved);
+            self.storage
+                .save_episode(podcast_id, &episode)
+                .await
+                .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        }
+
+        podcast.download_folder = Some(new_folder.clone());
+        self.storage
+            .save_podcast(&podcast)
+            .await
+            .map_err(|e| DownloadError::Storage(e.to_string()))?;
+        Ok(Some(new_folder))
     }
 
     /// Generate podcast folder name based on configuration with robust cross-platform sanitization
@@ -1018,7 +2393,7 @@ impl<S: Storage> DownloadManager<S> {
         let mut tag = Tag::read_from_path(file_path).unwrap_or_default();
 
         // Set basic metadata
-        tag.set_title(&episode.title);
+        tag.set_title(episode.display_title());
         tag.set_artist(&podcast.title);
         tag.set_album(&podcast.title);
         tag.set_genre("Podcast");
@@ -1035,7 +2410,7 @@ impl<S: Storage> DownloadManager<S> {
         }
 
         // Set comment with description (truncated if necessary)
-        if let Some(ref description) = episode.description {
+        if let Some(description) = episode.display_description() {
             let comment = if description.len() > self.config.max_id3_comment_length {
                 let mut truncated = description
                     .chars()
@@ -1044,7 +2419,7 @@ impl<S: Storage> DownloadManager<S> {
                 truncated.push_str("...");
                 truncated
             } else {
-                description.clone()
+                description.to_string()
             };
             // Use add_frame with a Comment frame
             let comment_frame = id3::frame::Comment {
@@ -1055,10 +2430,11 @@ impl<S: Storage> DownloadManager<S> {
             tag.add_frame(comment_frame);
         }
 
-        // Download and embed artwork if configured
+        // Download and embed artwork if configured; the episode's own art wins over the show's
         if self.config.download_artwork {
-            if let Some(ref artwork_url) = podcast.image_url {
-                if let Ok(artwork_data) = self.download_artwork(artwork_url).await {
+            let artwork = episode.artwork_url().or(podcast.image_url.as_deref());
+            if let Some(artwork) = artwork {
+                if let Ok(artwork_data) = self.load_artwork(artwork).await {
                     let picture = id3::frame::Picture {
                         mime_type: artwork_data.0,
                         picture_type: id3::frame::PictureType::CoverFront,
@@ -1070,32 +2446,160 @@ impl<S: Storage> DownloadManager<S> {
             }
         }
 
-        // Write the tag back to the file
-        tag.write_to_path(file_path, id3::Version::Id3v23)
-            .map_err(|e| DownloadError::InvalidPath(format!("Failed to write ID3 tags: {}", e)))?;
+        // Tags are written in place, which would retag every episode
+        // deduplication linked to this file: tag a copy of its own instead
+        // and move it over this path
+        let shared = has_other_links(file_path).await;
+        let target = if shared {
+            let copy = file_path.with_extension("retag.mp3");
+            fs::copy(file_path, &copy).await?;
+            copy
+        } else {
+            file_path.to_path_buf()
+        };
+        if let Err(e) = tag.write_to_path(&target, id3::Version::Id3v23) {
+            if shared {
+                let _ = fs::remove_file(&target).await;
+            }
+            return Err(DownloadError::InvalidPath(format!(
+                "Failed to write ID3 tags: {}",
+                e
+            )));
+        }
+        if shared {
+            fs::rename(&target, file_path).await?;
+        }
 
         Ok(())
     }
 
-    /// Download artwork and return MIME type and data
+    /// Load artwork from a URL or a local image path and return MIME type and data
+    async fn load_artwork(&self, source: &str) -> Result<(String, Vec<u8>), DownloadError> {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            return self.download_artwork(source).await;
+        }
+        let path = shellexpand::tilde(source).into_owned();
+        let data = fs::read(&path).await?;
+        Self::encode_artwork("image/jpeg".to_string(), data)
+    }
+
+    /// Cache file for the artwork at `url`
+    fn artwork_cache_path(&self, url: &str) -> PathBuf {
+        use sha1::{Digest, Sha1};
+
+        let key: String = Sha1::digest(url.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        self.downloads_dir
+            .join(crate::constants::downloads::ARTWORK_CACHE_DIR)
+            .join(format!("{}.jpg", key))
+    }
+
+    /// Artwork from the cache, or downloaded (and cached) and return MIME type and data
     async fn download_artwork(&self, url: &str) -> Result<(String, Vec<u8>), DownloadError> {
-        let response = self.client.get(url).send().await?;
+        use crate::constants::downloads::ARTWORK_MAX_BYTES;
+
+        let cache_path = self.artwork_cache_path(url);
+        if let Ok(data) = fs::read(&cache_path).await {
+            return Ok(("image/jpeg".to_string(), data));
+        }
+
+        let response = self.client.get(HttpRequest::get(url)).await?;
 
         // Check if the response is successful
         let response = response.error_for_status()?;
+        let too_large = || {
+            DownloadError::InvalidPath(format!(
+                "Artwork at {} is larger than {} bytes",
+                url, ARTWORK_MAX_BYTES
+            ))
+        };
+        if response
+            .content_length()
+            .is_some_and(|length| length > ARTWORK_MAX_BYTES)
+        {
+            return Err(too_large());
+        }
 
         let content_type = response
-            .headers()
-            .get("content-type")
-            .and_then(|ct| ct.to_str().ok())
+            .header("content-type")
             .unwrap_or("image/jpeg")
             .to_string();
 
-        let data = response.bytes().await?.to_vec();
+        // Servers may omit or understate the length, so count while reading
+        let mut data = Vec::new();
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            data.extend_from_slice(&chunk?);
+            if data.len() as u64 > ARTWORK_MAX_BYTES {
+                return Err(too_large());
+            }
+        }
+
+        let (mime_type, data) = Self::encode_artwork(content_type, data)?;
+        // Only images we could re-encode are worth keeping
+        if mime_type == "image/jpeg" {
+            if let Some(dir) = cache_path.parent() {
+                fs::create_dir_all(dir).await?;
+            }
+            fs::write(&cache_path, &data).await?;
+        }
+        Ok((mime_type, data))
+    }
+
+    /// Fetch artwork into the cache ahead of time so tagging a download (and
+    /// the next device sync) doesn't wait on it. Local paths and cached URLs
+    /// are skipped, at most `ARTWORK_PREFETCH_CONCURRENCY` fetches run at once,
+    /// and nothing is fetched while downloads are paused or artwork is off.
+    /// Returns the number of images newly cached.
+    pub async fn prefetch_artwork(&self, sources: Vec<String>) -> usize {
+        use crate::constants::downloads::ARTWORK_PREFETCH_CONCURRENCY;
+
+        if !self.config.download_artwork || self.io_gate.is_paused() {
+            return 0;
+        }
+        let mut urls: Vec<String> = sources
+            .into_iter()
+            .filter(|source| source.starts_with("http://") || source.starts_with("https://"))
+            .filter(|url| !self.artwork_cache_path(url).exists())
+            .collect();
+        urls.sort();
+        urls.dedup();
+
+        let fetched: Vec<bool> = futures_util::stream::iter(urls)
+            .map(|url| async move {
+                match self.download_artwork(&url).await {
+                    Ok(_) => self.artwork_cache_path(&url).exists(),
+                    Err(e) => {
+                        eprintln!("Warning: Failed to prefetch artwork {}: {}", url, e);
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(ARTWORK_PREFETCH_CONCURRENCY)
+            .collect()
+            .await;
+        fetched.into_iter().filter(|cached| *cached).count()
+    }
 
+    /// Re-encode artwork as JPEG, passing undecodable data through unchanged
+    fn encode_artwork(
+        content_type: String,
+        data: Vec<u8>,
+    ) -> Result<(String, Vec<u8>), DownloadError> {
         // Validate it's actually an image and convert if needed
         let (final_mime_type, final_data) = match image::load_from_memory(&data) {
             Ok(img) => {
+                // Players show covers small; full-size art only bloats every file
+                let max = crate::constants::downloads::ARTWORK_MAX_DIMENSION;
+                let img = if img.width() > max || img.height() > max {
+                    img.thumbnail(max, max)
+                } else {
+                    img
+                };
+                // JPEG has no alpha channel
+                let img = image::DynamicImage::ImageRgb8(img.to_rgb8());
                 // Convert to JPEG for maximum compatibility
                 let mut jpeg_data = Vec::new();
                 img.write_to(
@@ -1143,8 +2647,89 @@ impl<S: Storage> DownloadManager<S> {
         hard_sync: bool,
         progress_tx: Option<tokio::sync::mpsc::UnboundedSender<SyncProgressEvent>>,
     ) -> Result<SyncReport, SyncError> {
+        Self::check_device_path(&device_path).await?;
+
+        let mut managed_roots = vec![PathBuf::from("Podcasts")];
+        if playlists_dir.is_some() {
+            managed_roots.push(PathBuf::from("Playlists"));
+        }
+
+        // Step 1: Build a map of all downloaded episodes on PC
+        let mut pc_files: std::collections::HashMap<PathBuf, (PathBuf, u64)> =
+            std::collections::HashMap::new();
+
+        // Scan downloads directory with Podcasts/ prefix
+        self.scan_directory_with_prefix(&self.downloads_dir, Path::new("Podcasts"), &mut pc_files)
+            .await?;
+
+        // Scan playlists directory with Playlists/ prefix
+        if let Some(playlists_dir) = &playlists_dir {
+            if playlists_dir.exists() {
+                self.scan_playlists_for_sync(playlists_dir, &mut pc_files)
+                    .await?;
+            }
+        }
+
+        self.reconcile_device(
+            &device_path,
+            &managed_roots,
+            pc_files,
+            SyncReport::new(),
+            SyncOptions {
+                delete_orphans,
+                dry_run,
+                hard_sync,
+            },
+            progress_tx,
+        )
+        .await
+    }
+
+    /// Sync one playlist to a device: `files` are its entries in order, each
+    /// a path under `managed_root` (`Playlists/<name>/003-….mp3`) and the
+    /// audio to copy there. Entries without audio (not downloaded) are
+    /// reported as errors; the rest of the device isn't touched.
+    pub async fn sync_files_to_device(
+        &self,
+        device_path: PathBuf,
+        managed_root: PathBuf,
+        files: Vec<(PathBuf, Option<PathBuf>)>,
+        options: SyncOptions,
+        progress_tx: Option<tokio::sync::mpsc::UnboundedSender<SyncProgressEvent>>,
+    ) -> Result<SyncReport, SyncError> {
+        Self::check_device_path(&device_path).await?;
+
         let mut report = SyncReport::new();
+        let mut pc_files: std::collections::HashMap<PathBuf, (PathBuf, u64)> =
+            std::collections::HashMap::new();
+        for (relative_path, source) in files {
+            let size = match &source {
+                Some(source) => fs::metadata(source).await.ok().map(|m| m.len()),
+                None => None,
+            };
+            match (source, size) {
+                (Some(source), Some(size)) => {
+                    pc_files.insert(relative_path, (source, size));
+                }
+                _ => report
+                    .errors
+                    .push((relative_path, "Not downloaded".to_string())),
+            }
+        }
+
+        self.reconcile_device(
+            &device_path,
+            &[managed_root],
+            pc_files,
+            report,
+            options,
+            progress_tx,
+        )
+        .await
+    }
 
+    /// Fail unless `device_path` is a writable directory
+    async fn check_device_path(device_path: &Path) -> Result<(), SyncError> {
         // Validate device path exists and is accessible
         if !device_path.exists() {
             return Err(SyncError::DevicePathInvalid(format!(
@@ -1171,28 +2756,37 @@ impl<S: Storage> DownloadManager<S> {
         }
         let _ = fs::remove_file(&test_file).await;
 
+        Ok(())
+    }
+
+    /// Bring the `managed_roots` of a device in line with `pc_files`
+    /// (device-relative path → source file and size): copy what's new or
+    /// changed, skip what matches, and delete what's gone when asked.
+    async fn reconcile_device(
+        &self,
+        device_path: &Path,
+        managed_roots: &[PathBuf],
+        pc_files: std::collections::HashMap<PathBuf, (PathBuf, u64)>,
+        mut report: SyncReport,
+        options: SyncOptions,
+        progress_tx: Option<tokio::sync::mpsc::UnboundedSender<SyncProgressEvent>>,
+    ) -> Result<SyncReport, SyncError> {
+        let SyncOptions {
+            delete_orphans,
+            dry_run,
+            hard_sync,
+        } = options;
+
         // In hard sync mode, clear managed device directories before reconciliation.
         if hard_sync {
             let mut managed_files_to_delete: std::collections::HashMap<PathBuf, (PathBuf, u64)> =
                 std::collections::HashMap::new();
-            let podcasts_target = device_path.join("Podcasts");
-            if podcasts_target.exists() {
-                self.scan_directory_with_prefix(
-                    &podcasts_target,
-                    Path::new("Podcasts"),
-                    &mut managed_files_to_delete,
-                )
-                .await?;
-            }
-
-            let playlists_target = device_path.join("Playlists");
-            if playlists_dir.is_some() && playlists_target.exists() {
-                self.scan_directory_with_prefix(
-                    &playlists_target,
-                    Path::new("Playlists"),
-                    &mut managed_files_to_delete,
-                )
-                .await?;
+            for root in managed_roots {
+                let target = device_path.join(root);
+                if target.exists() {
+                    self.scan_directory_with_prefix(&target, root, &mut managed_files_to_delete)
+                        .await?;
+                }
             }
 
             for (relative_path, _) in managed_files_to_delete {
@@ -1200,74 +2794,61 @@ impl<S: Storage> DownloadManager<S> {
             }
 
             if !dry_run {
-                if podcasts_target.exists() {
-                    fs::remove_dir_all(&podcasts_target).await?;
-                }
-                if playlists_dir.is_some() && playlists_target.exists() {
-                    fs::remove_dir_all(&playlists_target).await?;
+                for root in managed_roots {
+                    let target = device_path.join(root);
+                    if target.exists() {
+                        fs::remove_dir_all(&target).await?;
+                    }
                 }
             }
         }
 
-        // Step 1: Build a map of all downloaded episodes on PC
-        let mut pc_files: std::collections::HashMap<PathBuf, (PathBuf, u64)> =
-            std::collections::HashMap::new();
-
-        // Scan downloads directory with Podcasts/ prefix
-        self.scan_directory_with_prefix(&self.downloads_dir, Path::new("Podcasts"), &mut pc_files)
-            .await?;
-
-        // Scan playlists directory with Playlists/ prefix
-        if let Some(playlists_dir) = &playlists_dir {
-            if playlists_dir.exists() {
-                self.scan_playlists_for_sync(playlists_dir, &mut pc_files)
-                    .await?;
+        // Only files a sync put on the device are ever deleted as orphans
+        let mut manifest = DeviceManifest::load(device_path).await;
+        if hard_sync && !dry_run {
+            for root in managed_roots {
+                manifest.retain_under(root, |_| false);
             }
         }
 
-        // Step 2: Build a map of managed files already on the device.
+        // Step 2: List the managed files already on the device.
         // We intentionally scope this to managed sync roots so regular sync doesn't
-        // touch unrelated user media elsewhere on the device.
-        let mut device_files: std::collections::HashMap<PathBuf, (PathBuf, u64)> =
+        // touch unrelated user media elsewhere on the device. Only names are
+        // read here; sizes come from the manifest.
+        let mut device_files: std::collections::HashMap<PathBuf, PathBuf> =
             std::collections::HashMap::new();
 
-        self.scan_directory_with_prefix(
-            &device_path.join("Podcasts"),
-            Path::new("Podcasts"),
-            &mut device_files,
-        )
-        .await?;
+        for root in managed_roots {
+            Self::list_device_files(&device_path.join(root), root, &mut device_files).await?;
+        }
 
-        if playlists_dir.is_some() {
-            self.scan_directory_with_prefix(
-                &device_path.join("Playlists"),
-                Path::new("Playlists"),
-                &mut device_files,
-            )
-            .await?;
+        // Decide which device copies are current before touching anything, so
+        // ScanComplete can report what is left to copy
+        let mut current: std::collections::HashMap<PathBuf, ManifestEntry> =
+            std::collections::HashMap::new();
+        for (relative_path, (source_path, source_size)) in &pc_files {
+            if let Some(device_file_path) = device_files.get(relative_path) {
+                if let Some(entry) = Self::current_device_copy(
+                    &manifest,
+                    relative_path,
+                    device_file_path,
+                    source_path,
+                    *source_size,
+                )
+                .await
+                {
+                    current.insert(relative_path.clone(), entry);
+                }
+            }
         }
 
         // Emit ScanComplete: calculate total bytes for files that need copying
         if let Some(ref tx) = progress_tx {
-            let total_bytes: u64 = pc_files
-                .iter()
-                .filter(|(rel, (_, src_size))| {
-                    device_files
-                        .get(*rel)
-                        .map(|(_, dev_size)| src_size != dev_size)
-                        .unwrap_or(true)
-                })
-                .map(|(_, (_, size))| size)
-                .sum();
-            let total_files = pc_files
+            let to_copy = pc_files
                 .iter()
-                .filter(|(rel, (_, src_size))| {
-                    device_files
-                        .get(*rel)
-                        .map(|(_, dev_size)| src_size != dev_size)
-                        .unwrap_or(true)
-                })
-                .count();
+                .filter(|(rel, _)| !current.contains_key(*rel));
+            let total_files = to_copy.clone().count();
+            let total_bytes: u64 = to_copy.map(|(_, (_, size))| size).sum();
             let _ = tx.send(SyncProgressEvent::ScanComplete {
                 total_bytes,
                 total_files,
@@ -1281,46 +2862,45 @@ impl<S: Storage> DownloadManager<S> {
 
         // Step 3: Determine what needs to be copied (new or changed files)
         for (relative_path, (source_path, source_size)) in &pc_files {
-            if let Some((device_file_path, device_size)) = device_files.get(relative_path) {
-                // File exists on device - check if size matches
-                if source_size == device_size {
-                    // File is identical (by metadata), skip
-                    report.files_skipped.push(relative_path.clone());
-                    if let Some(ref tx) = progress_tx {
-                        let _ = tx.send(SyncProgressEvent::FileSkipped {
-                            path: relative_path.clone(),
-                        });
-                    }
-                } else {
-                    // File size differs, needs update
-                    if !dry_run {
-                        match self
-                            .copy_file_to_device(source_path, device_file_path)
-                            .await
-                        {
-                            Ok(_) => {
-                                report.files_copied.push(relative_path.clone());
-                                if let Some(ref tx) = progress_tx {
-                                    let _ = tx.send(SyncProgressEvent::FileCopied {
-                                        path: relative_path.clone(),
-                                        bytes: *source_size,
-                                    });
-                                }
+            if let Some(entry) = current.remove(relative_path) {
+                // The device copy matches the PC file, skip
+                report.files_skipped.push(relative_path.clone());
+                manifest.record(relative_path, entry);
+                if let Some(ref tx) = progress_tx {
+                    let _ = tx.send(SyncProgressEvent::FileSkipped {
+                        path: relative_path.clone(),
+                    });
+                }
+            } else if let Some(device_file_path) = device_files.get(relative_path) {
+                // File changed on the PC, needs update
+                if !dry_run {
+                    match self
+                        .copy_file_to_device(source_path, device_file_path)
+                        .await
+                    {
+                        Ok(_) => {
+                            report.files_copied.push(relative_path.clone());
+                            Self::record_copy(&mut manifest, relative_path, source_path).await;
+                            if let Some(ref tx) = progress_tx {
+                                let _ = tx.send(SyncProgressEvent::FileCopied {
+                                    path: relative_path.clone(),
+                                    bytes: *source_size,
+                                });
                             }
-                            Err(e) => {
-                                let msg = format!("Copy failed: {}", e);
-                                if let Some(ref tx) = progress_tx {
-                                    let _ = tx.send(SyncProgressEvent::Error {
-                                        path: relative_path.clone(),
-                                        message: msg.clone(),
-                                    });
-                                }
-                                report.errors.push((relative_path.clone(), msg));
+                        }
+                        Err(e) => {
+                            let msg = format!("Copy failed: {}", e);
+                            if let Some(ref tx) = progress_tx {
+                                let _ = tx.send(SyncProgressEvent::Error {
+                                    path: relative_path.clone(),
+                                    message: msg.clone(),
+                                });
                             }
+                            report.errors.push((relative_path.clone(), msg));
                         }
-                    } else {
-                        report.files_copied.push(relative_path.clone());
                     }
+                } else {
+                    report.files_copied.push(relative_path.clone());
                 }
             } else {
                 // File doesn't exist on device, needs to be copied
@@ -1347,6 +2927,7 @@ impl<S: Storage> DownloadManager<S> {
                     match self.copy_file_to_device(source_path, &target_path).await {
                         Ok(_) => {
                             report.files_copied.push(relative_path.clone());
+                            Self::record_copy(&mut manifest, relative_path, source_path).await;
                             if let Some(ref tx) = progress_tx {
                                 let _ = tx.send(SyncProgressEvent::FileCopied {
                                     path: relative_path.clone(),
@@ -1373,13 +2954,19 @@ impl<S: Storage> DownloadManager<S> {
 
         // Step 4: Delete orphan files on device (files not present on PC)
         if delete_orphans && !hard_sync {
-            for (relative_path, (device_file_path, _)) in &device_files {
+            for (relative_path, device_file_path) in &device_files {
                 if !pc_files.contains_key(relative_path) {
+                    if !manifest.contains(relative_path) {
+                        // Not put there by a sync: the user's, leave it
+                        report.files_protected.push(relative_path.clone());
+                        continue;
+                    }
                     // File exists on device but not on PC, delete it
                     if !dry_run {
                         match fs::remove_file(device_file_path).await {
                             Ok(_) => {
                                 report.files_deleted.push(relative_path.clone());
+                                manifest.remove(relative_path);
                                 if let Some(ref tx) = progress_tx {
                                     let _ = tx.send(SyncProgressEvent::FileDeleted {
                                         path: relative_path.clone(),
@@ -1405,20 +2992,32 @@ impl<S: Storage> DownloadManager<S> {
 
             // Clean up empty directories in managed roots (only if not dry run)
             if !dry_run {
-                let podcasts_root = device_path.join("Podcasts");
-                if podcasts_root.exists() {
-                    let _ = self.cleanup_empty_directories_in(&podcasts_root).await;
-                }
-
-                if playlists_dir.is_some() {
-                    let playlists_root = device_path.join("Playlists");
-                    if playlists_root.exists() {
-                        let _ = self.cleanup_empty_directories_in(&playlists_root).await;
+                for root in managed_roots {
+                    let root = device_path.join(root);
+                    if root.exists() {
+                        let _ = self.cleanup_empty_directories_in(&root).await;
                     }
                 }
             }
         }
 
+        if !dry_run {
+            // Forget files that are no longer on the device, e.g. deleted by
+            // hand, going by the listing rather than a stat per file
+            let copied: HashSet<&PathBuf> = report.files_copied.iter().collect();
+            for root in managed_roots {
+                manifest.retain_under(root, |path| {
+                    device_files.contains_key(path) || copied.contains(&path.to_path_buf())
+                });
+            }
+            if let Err(e) = manifest.save(device_path).await {
+                report.errors.push((
+                    PathBuf::from(MANIFEST_FILE),
+                    format!("Could not save the sync manifest: {}", e),
+                ));
+            }
+        }
+
         // Emit Complete event
         if let Some(ref tx) = progress_tx {
             let _ = tx.send(SyncProgressEvent::Complete {
@@ -1429,6 +3028,77 @@ impl<S: Storage> DownloadManager<S> {
         Ok(report)
     }
 
+    /// Whether the device copy at `relative_path` matches `source_path`,
+    /// returning the manifest entry to keep for it. A file the manifest
+    /// describes is judged from the manifest alone: its size, and its hash when
+    /// the PC file was touched since. Anything else falls back to comparing
+    /// the device file's size, as syncs before the manifest did.
+    async fn current_device_copy(
+        manifest: &DeviceManifest,
+        relative_path: &Path,
+        device_file_path: &Path,
+        source_path: &Path,
+        source_size: u64,
+    ) -> Option<ManifestEntry> {
+        match manifest.entry(relative_path) {
+            Some(entry) => {
+                if entry.size != source_size {
+                    return None;
+                }
+                if Some(entry.source_modified) == modified_secs(source_path).await {
+                    return Some(entry.clone());
+                }
+                let source = manifest_entry(source_path).await.ok()?;
+                (source.hash == entry.hash).then_some(source)
+            }
+            None => {
+                let device_size = fs::metadata(device_file_path).await.ok()?.len();
+                if device_size != source_size {
+                    return None;
+                }
+                manifest_entry(source_path).await.ok()
+            }
+        }
+    }
+
+    /// Record a file just copied from `source_path` in the manifest
+    async fn record_copy(manifest: &mut DeviceManifest, relative_path: &Path, source_path: &Path) {
+        match manifest_entry(source_path).await {
+            Ok(entry) => manifest.record(relative_path, entry),
+            Err(_) => manifest.insert(relative_path),
+        }
+    }
+
+    /// List the audio files under `dir` as `prefix`-relative path → absolute
+    /// path. Reads directory entries only, no per-file metadata.
+    fn list_device_files<'a>(
+        dir: &'a Path,
+        prefix: &'a Path,
+        files: &'a mut std::collections::HashMap<PathBuf, PathBuf>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), SyncError>> + 'a + Send>>
+    {
+        Box::pin(async move {
+            let mut entries = match fs::read_dir(dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                Err(e) => return Err(e.into()),
+            };
+
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let relative_path = prefix.join(entry.file_name());
+                let file_type = entry.file_type().await?;
+                if file_type.is_dir() {
+                    Self::list_device_files(&path, &relative_path, files).await?;
+                } else if file_type.is_file() && is_audio_file(&path) {
+                    files.insert(relative_path, path);
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Recursively scan a directory and build a map of relative paths to (absolute path, file size)
     ///
     /// # Arguments
@@ -1452,25 +3122,19 @@ impl<S: Storage> DownloadManager<S> {
 
                 if metadata.is_file() {
                     // Only include audio files
-                    if let Some(ext) = path.extension() {
-                        let ext_str = ext.to_string_lossy().to_lowercase();
-                        if matches!(
-                            ext_str.as_str(),
-                            "mp3" | "m4a" | "aac" | "ogg" | "wav" | "flac"
-                        ) {
-                            // Calculate relative path from root
-                            let relative_path = path
-                                .strip_prefix(root_path)
-                                .map_err(|e| {
-                                    SyncError::Io(std::io::Error::other(format!(
-                                        "Failed to compute relative path: {}",
-                                        e
-                                    )))
-                                })?
-                                .to_path_buf();
-
-                            files.insert(relative_path, (path.clone(), metadata.len()));
-                        }
+                    if is_audio_file(&path) {
+                        // Calculate relative path from root
+                        let relative_path = path
+                            .strip_prefix(root_path)
+                            .map_err(|e| {
+                                SyncError::Io(std::io::Error::other(format!(
+                                    "Failed to compute relative path: {}",
+                                    e
+                                )))
+                            })?
+                            .to_path_buf();
+
+                        files.insert(relative_path, (path.clone(), metadata.len()));
                     }
                 } else if metadata.is_dir() {
                     // Recursively scan subdirectories
@@ -1563,6 +3227,8 @@ impl<S: Storage> DownloadManager<S> {
         source_path: &Path,
         dest_path: &Path,
     ) -> Result<(), SyncError> {
+        self.io_gate.wait_until_open().await;
+
         // Use tokio's fs::copy which is more efficient
         fs::copy(source_path, dest_path)
             .await
@@ -1605,11 +3271,187 @@ impl<S: Storage> DownloadManager<S> {
     }
 }
 
+/// Short stable hash identifying an episode, used to disambiguate filenames
+fn episode_short_hash(episode: &Episode) -> String {
+    use sha1::{Digest, Sha1};
+
+    let key = episode.guid.as_deref().unwrap_or(&episode.audio_url);
+    Sha1::digest(key.as_bytes())
+        .iter()
+        .take(4)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Whether sync handles `path`, going by its extension
+fn is_audio_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        matches!(
+            ext.to_string_lossy().to_lowercase().as_str(),
+            "mp3" | "m4a" | "aac" | "ogg" | "opus" | "wav" | "flac"
+        )
+    })
+}
+
+/// Where the download to `path` is written until it is complete
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// Where the `PartialSource` of the partial download at `part_path` is kept
+fn partial_source_path(part_path: &Path) -> PathBuf {
+    let mut name = part_path.as_os_str().to_owned();
+    name.push(".json");
+    PathBuf::from(name)
+}
+
+/// Partial downloads in `dir`, with their `PartialSource` when it can be
+/// read. A source left without its `.part` file is listed too, so it gets
+/// cleaned up with the rest.
+async fn partials_in(dir: &Path) -> Vec<(PathBuf, Option<PartialSource>)> {
+    let Ok(mut entries) = fs::read_dir(dir).await else {
+        return Vec::new();
+    };
+    let mut part_paths = HashSet::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(part_name) = name.strip_suffix(".part.json") {
+            part_paths.insert(dir.join(format!("{}.part", part_name)));
+        } else if name.ends_with(".part") {
+            part_paths.insert(entry.path());
+        }
+    }
+    let mut partials = Vec::new();
+    for part_path in part_paths {
+        let source = PartialSource::read(&part_path).await;
+        partials.push((part_path, source));
+    }
+    partials
+}
+
+/// Remove a partial download and its `PartialSource`, whichever exist
+async fn remove_partial(part_path: &Path) {
+    let _ = fs::remove_file(part_path).await;
+    let _ = fs::remove_file(partial_source_path(part_path)).await;
+}
+
+/// What a `.part` file holds: the episode it is for, the URL it came from
+/// and the validators the server sent, so a resume only appends to bytes of
+/// the same file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PartialSource {
+    podcast_id: PodcastId,
+    episode_id: EpisodeId,
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl PartialSource {
+    async fn read(part_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(partial_source_path(part_path))
+            .await
+            .ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    async fn write(&self, part_path: &Path) -> Result<(), DownloadError> {
+        let json =
+            serde_json::to_string(self).map_err(|e| DownloadError::Storage(e.to_string()))?;
+        fs::write(partial_source_path(part_path), json).await?;
+        Ok(())
+    }
+
+    /// `If-Range` value: a strong ETag, else the Last-Modified date. A weak
+    /// ETag can't guard a byte range.
+    fn if_range(&self) -> Option<&str> {
+        self.etag
+            .as_deref()
+            .filter(|etag| !etag.starts_with("W/"))
+            .or(self.last_modified.as_deref())
+    }
+
+    /// Whether a range response's ETag is the one the partial was read with,
+    /// for servers that send a range without honouring `If-Range`
+    fn matches(&self, etag: Option<&str>) -> bool {
+        match (self.etag.as_deref(), etag) {
+            (Some(ours), Some(theirs)) => ours == theirs,
+            _ => true,
+        }
+    }
+}
+
+/// First byte of a `Content-Range: bytes <first>-<last>/<total>` header
+fn content_range_start(value: &str) -> Option<u64> {
+    value
+        .trim()
+        .strip_prefix("bytes ")?
+        .split('-')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Total size in a `Content-Range` header, when the server gives it
+fn content_range_total(value: &str) -> Option<u64> {
+    value.rsplit('/').next()?.trim().parse().ok()
+}
+
+/// Modification time of `path` in whole seconds since the epoch
+async fn modified_secs(path: &Path) -> Option<u64> {
+    let modified = fs::metadata(path).await.ok()?.modified().ok()?;
+    Some(
+        modified
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs(),
+    )
+}
+
+/// Size, hash and modification time of a PC file, for the device manifest
+async fn manifest_entry(path: &Path) -> std::io::Result<ManifestEntry> {
+    let metadata = fs::metadata(path).await?;
+    let source_modified = metadata
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok(ManifestEntry {
+        size: metadata.len(),
+        hash: hash_file(path).await?,
+        source_modified,
+    })
+}
+
+/// Lowercase hex SHA-1 of a file's contents, read in chunks
+async fn hash_file(path: &Path) -> std::io::Result<String> {
+    use sha1::{Digest, Sha1};
+
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha1::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::DownloadConfig;
-    use crate::podcast::Episode;
+    use crate::podcast::{Episode, EpisodeStatus};
     use crate::storage::{JsonStorage, PodcastId};
     use chrono::Utc;
     use tempfile::TempDir;
@@ -1629,7 +3471,9 @@ mod tests {
             Utc::now(),
         );
 
-        let filename = manager.generate_filename(&episode).unwrap();
+        let filename = manager
+            .generate_filename(&episode, &HashSet::new())
+            .unwrap();
         // With default config, it includes dates and preserves title formatting
         assert!(filename.contains("Test Episode"));
         assert!(filename.ends_with(".mp3"));
@@ -1637,6 +3481,403 @@ mod tests {
         assert!(filename.contains(&Utc::now().format("%Y-%m-%d").to_string()));
     }
 
+    #[tokio::test]
+    async fn test_generate_filename_disambiguates_collisions() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let config = DownloadConfig {
+            include_dates: false,
+            include_episode_numbers: false,
+            ..DownloadConfig::default()
+        };
+        let manager =
+            DownloadManager::new(storage, temp_dir.path().join("downloads"), config).unwrap();
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Bonus".to_string(),
+            "https://example.com/bonus.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.guid = Some("guid-1".to_string());
+        let date = episode.published.format("%Y-%m-%d").to_string();
+
+        // Act
+        let free = manager
+            .generate_filename(&episode, &HashSet::new())
+            .unwrap();
+        let mut taken = HashSet::from(["bonus.mp3".to_string()]);
+        let dated = manager.generate_filename(&episode, &taken).unwrap();
+        taken.insert(dated.to_lowercase());
+        let hashed = manager.generate_filename(&episode, &taken).unwrap();
+
+        // Assert
+        assert_eq!(free, "Bonus.mp3");
+        assert_eq!(dated, format!("Bonus_{}.mp3", date));
+        assert_eq!(
+            hashed,
+            format!("Bonus_{}.mp3", episode_short_hash(&episode))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backfill_durations_probes_downloaded_files() {
+        // Arrange — one unprobed download, one that already has a duration
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            ffmpeg_path: Some(temp_dir.path().join("no-ffmpeg").display().to_string()),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let (podcast_id, unprobed) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Show", "Ep 1", "ep1.wav").await;
+        fs::write(unprobed.local_path.as_ref().unwrap(), probe::silent_wav(3))
+            .await
+            .unwrap();
+        let (_, mut known) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Show", "Ep 2", "ep2.wav").await;
+        known.podcast_id = podcast_id.clone();
+        known.duration = Some(1234);
+        storage.save_episode(&podcast_id, &known).await.unwrap();
+
+        // Act
+        let filled = manager.backfill_durations().await.unwrap();
+
+        // Assert
+        assert_eq!(filled, 1);
+        let episodes = storage.load_episodes(&podcast_id).await.unwrap();
+        let duration_of = |id: &EpisodeId| episodes.iter().find(|e| &e.id == id).unwrap().duration;
+        assert_eq!(duration_of(&unprobed.id), Some(3));
+        assert_eq!(duration_of(&known.id), Some(1234));
+        assert_eq!(manager.backfill_durations().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_quota_evicts_oldest_played_download_but_not_pinned() {
+        // Arrange — three sparse 600 MB downloads against a 1 GB quota
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            max_storage_gb: Some(1),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let mut episodes = Vec::new();
+        for (title, age_days, pinned) in
+            [("Old", 30, true), ("Older", 20, false), ("New", 1, false)]
+        {
+            let (podcast_id, mut episode) = setup_downloaded_episode(
+                &storage,
+                &downloads_dir,
+                "Show",
+                title,
+                &format!("{}.mp3", title),
+            )
+            .await;
+            let path = episode.local_path.clone().unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_len(600 * 1024 * 1024)
+                .unwrap();
+            set_file_mtime_age(&path, std::time::Duration::from_secs(age_days * 86_400));
+            episode.mark_played();
+            episode.favorited = pinned;
+            storage.save_episode(&podcast_id, &episode).await.unwrap();
+            episodes.push((podcast_id, episode));
+        }
+
+        // Act
+        let evicted = manager
+            .make_room(0, &episodes[2].1.id, false)
+            .await
+            .unwrap();
+
+        // Assert — the pinned one stays, the older unpinned one goes
+        assert_eq!(evicted, 1);
+        let still_there = |i: usize| episodes[i].1.local_path.as_ref().unwrap().exists();
+        assert!(still_there(0));
+        assert!(!still_there(1));
+        assert!(still_there(2));
+        let usage = manager.storage_usage().await.unwrap();
+        assert_eq!(usage.used, 2 * 600 * 1024 * 1024);
+        assert_eq!(usage.quota, Some(1024 * 1024 * 1024));
+    }
+
+    #[tokio::test]
+    async fn test_download_refused_when_quota_cannot_fit_it() {
+        // Arrange — nothing evictable and an enclosure larger than the quota
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let config = DownloadConfig {
+            max_storage_gb: Some(1),
+            ..DownloadConfig::default()
+        };
+        let manager =
+            DownloadManager::new(storage.clone(), temp_dir.path().join("downloads"), config)
+                .unwrap();
+        let podcast = crate::podcast::Podcast::new(
+            "Show".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap();
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Huge".to_string(),
+            "https://example.invalid/huge.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.file_size = Some(2 * 1024 * 1024 * 1024);
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
+
+        // Act
+        let result = manager.download_episode(&podcast.id, &episode.id).await;
+
+        // Assert
+        assert!(matches!(result, Err(DownloadError::QuotaExceeded(_))));
+        let stored = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap();
+        assert_eq!(stored.status.download, DownloadState::NotDownloaded);
+    }
+
+    #[tokio::test]
+    async fn test_second_download_of_an_episode_in_flight_is_refused() {
+        // Arrange — the episode is already claimed by a running download
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let config = DownloadConfig {
+            max_storage_gb: Some(1),
+            ..DownloadConfig::default()
+        };
+        let manager =
+            DownloadManager::new(storage.clone(), temp_dir.path().join("downloads"), config)
+                .unwrap();
+        let podcast = crate::podcast::Podcast::new(
+            "Show".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap();
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Twice".to_string(),
+            "https://example.invalid/twice.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.file_size = Some(2 * 1024 * 1024 * 1024);
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
+        let running = InFlightDownload::claim(&manager.in_flight, &episode.id).unwrap();
+
+        // Act
+        let second = manager.download_episode(&podcast.id, &episode.id).await;
+
+        // Assert
+        assert!(matches!(second, Err(DownloadError::AlreadyDownloading)));
+        assert!(manager.is_downloading(&episode.id));
+        assert_eq!(manager.active_downloads(), 0);
+
+        // Once the running download ends, the episode can be downloaded
+        // again, and a failed attempt releases it too
+        drop(running);
+        let retry = manager.download_episode(&podcast.id, &episode.id).await;
+        assert!(matches!(retry, Err(DownloadError::QuotaExceeded(_))));
+        assert!(!manager.is_downloading(&episode.id));
+    }
+
+    #[test]
+    fn test_content_range_parts() {
+        assert_eq!(content_range_start("bytes 1000-4999/5000"), Some(1000));
+        assert_eq!(content_range_total("bytes 1000-4999/5000"), Some(5000));
+        assert_eq!(content_range_start("bytes */5000"), None);
+        assert_eq!(content_range_total("bytes */5000"), Some(5000));
+        assert_eq!(content_range_total("bytes 0-99/*"), None);
+        assert_eq!(
+            partial_path(Path::new("/tmp/ep.mp3")),
+            PathBuf::from("/tmp/ep.mp3.part")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_abandon_download_removes_partial_file_and_resets_episode() {
+        // Arrange — a download cut off mid-transfer
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let manager = DownloadManager::new(
+            storage.clone(),
+            temp_dir.path().join("downloads"),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let podcast = crate::podcast::Podcast::new(
+            "Show".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap();
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Long One".to_string(),
+            "https://example.com/long.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.status.download = DownloadState::Downloading;
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
+        let partial = manager.download_target(&podcast, &episode).await.unwrap();
+        fs::write(&partial, b"half an episode").await.unwrap();
+
+        // Act
+        manager
+            .abandon_download(&podcast.id, &episode.id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!partial.exists());
+        let stored = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap();
+        assert_eq!(stored.status.download, DownloadState::NotDownloaded);
+    }
+
+    #[tokio::test]
+    async fn test_download_folder_is_kept_and_moved_when_the_title_changes() {
+        // Arrange — an episode downloaded while the show had its old title
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let mut podcast = crate::podcast::Podcast::new(
+            "Old Name".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap();
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "First".to_string(),
+            "https://example.com/first.mp3".to_string(),
+            Utc::now(),
+        );
+        let path = manager.download_target(&podcast, &episode).await.unwrap();
+        fs::write(&path, b"audio").await.unwrap();
+        episode.local_path = Some(path.clone());
+        episode.status.download = DownloadState::Downloaded;
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
+
+        // Act — the title changes; downloads still go to the recorded folder
+        podcast = storage.load_podcast(&podcast.id).await.unwrap();
+        assert_eq!(podcast.download_folder.as_deref(), Some("Old Name"));
+        podcast.title = "New Name".to_string();
+        storage.save_podcast(&podcast).await.unwrap();
+        let before_rename = manager.download_target(&podcast, &episode).await.unwrap();
+        let renamed = manager.rename_podcast_folder(&podcast.id).await.unwrap();
+
+        // Assert
+        assert_eq!(before_rename.parent(), path.parent());
+        assert_eq!(renamed.as_deref(), Some("New Name"));
+        assert!(!downloads_dir.join("Old Name").exists());
+        let moved = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap()
+            .local_path
+            .unwrap();
+        assert_eq!(
+            moved,
+            downloads_dir
+                .join("New Name")
+                .join(path.file_name().unwrap())
+        );
+        assert!(moved.exists());
+        let stored = storage.load_podcast(&podcast.id).await.unwrap();
+        assert_eq!(stored.download_folder.as_deref(), Some("New Name"));
+        assert_eq!(
+            manager.rename_podcast_folder(&podcast.id).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_filename_collisions_resets_non_owner() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (podcast_id, mut owner) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Show", "Bonus", "Bonus.mp3").await;
+        owner.content_hash = Some(hash_file(owner.local_path.as_ref().unwrap()).await.unwrap());
+        storage.save_episode(&podcast_id, &owner).await.unwrap();
+        let mut overwritten = Episode::new(
+            podcast_id.clone(),
+            "Bonus".to_string(),
+            "https://example.com/other/Bonus.mp3".to_string(),
+            Utc::now(),
+        );
+        overwritten.status.download = DownloadState::Downloaded;
+        overwritten.local_path = owner.local_path.clone();
+        overwritten.content_hash = Some("0000".to_string());
+        storage
+            .save_episode(&podcast_id, &overwritten)
+            .await
+            .unwrap();
+
+        // Act
+        let report = manager.resolve_filename_collisions().await.unwrap();
+        let second = manager.resolve_filename_collisions().await.unwrap();
+
+        // Assert
+        assert_eq!(report.reset, 1);
+        assert!(report.ambiguous.is_empty());
+        assert_eq!(second, CollisionReport::default());
+        let kept = storage.load_episode(&podcast_id, &owner.id).await.unwrap();
+        assert_eq!(kept.status.download, DownloadState::Downloaded);
+        let reset = storage
+            .load_episode(&podcast_id, &overwritten.id)
+            .await
+            .unwrap();
+        assert_eq!(reset.status.download, DownloadState::NotDownloaded);
+        assert!(reset.local_path.is_none());
+        assert!(owner.local_path.unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_filename_collisions_reports_ambiguous() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (podcast_id, first) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Show", "Bonus", "Bonus.mp3").await;
+        let mut second = first.clone();
+        second.id = crate::storage::EpisodeId::new();
+        storage.save_episode(&podcast_id, &second).await.unwrap();
+
+        let report = manager.resolve_filename_collisions().await.unwrap();
+
+        assert_eq!(report.reset, 0);
+        assert_eq!(report.ambiguous, vec![first.local_path.unwrap()]);
+    }
+
     #[tokio::test]
     async fn test_sync_report_creation() {
         let report = SyncReport::new();
@@ -1835,6 +4076,108 @@ mod tests {
         assert!(report.is_success());
     }
 
+    #[tokio::test]
+    async fn test_sync_to_device_detects_changes_from_the_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let podcast_dir = downloads_dir.join("Test Podcast");
+        fs::create_dir_all(&podcast_dir).await.unwrap();
+        let test_file = podcast_dir.join("episode1.mp3");
+        fs::write(&test_file, b"first take").await.unwrap();
+        let manager =
+            DownloadManager::new(storage, downloads_dir, DownloadConfig::default()).unwrap();
+        let device_path = temp_dir.path().join("device");
+        fs::create_dir_all(&device_path).await.unwrap();
+        let device_file = device_path
+            .join("Podcasts")
+            .join("Test Podcast")
+            .join("episode1.mp3");
+        let relative_path = device_file
+            .strip_prefix(&device_path)
+            .unwrap()
+            .to_path_buf();
+        let sync = || manager.sync_to_device(device_path.clone(), None, false, false, false, None);
+
+        let first = sync().await.unwrap();
+        assert_eq!(first.files_copied, vec![relative_path.clone()]);
+        let recorded = DeviceManifest::load(&device_path)
+            .await
+            .entry(&relative_path)
+            .cloned()
+            .expect("the copy is described in the manifest");
+        assert_eq!(recorded.size, 10);
+        assert_eq!(recorded.hash, hash_file(&test_file).await.unwrap());
+
+        // The device copy is judged from the manifest, not by statting it
+        fs::write(&device_file, b"changed on the device")
+            .await
+            .unwrap();
+        let unchanged = sync().await.unwrap();
+        assert_eq!(unchanged.files_skipped, vec![relative_path.clone()]);
+        assert!(unchanged.files_copied.is_empty());
+
+        // Same size, new content and a later modification time on the PC
+        fs::write(&test_file, b"second one").await.unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&test_file)
+            .unwrap()
+            .set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(2_000_000_000))
+            .unwrap();
+        let changed = sync().await.unwrap();
+        assert_eq!(changed.files_copied, vec![relative_path.clone()]);
+        assert_eq!(fs::read(&device_file).await.unwrap(), b"second one");
+    }
+
+    /// Record `file` in the device manifest, as if an earlier sync copied it
+    async fn mark_synced(device_path: &Path, file: &Path) {
+        let mut manifest = DeviceManifest::load(device_path).await;
+        manifest.insert(file.strip_prefix(device_path).unwrap());
+        manifest.save(device_path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sync_to_device_delete_orphans_keeps_files_it_did_not_copy() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let podcast_dir = downloads_dir.join("Test Podcast");
+        fs::create_dir_all(&podcast_dir).await.unwrap();
+        fs::write(podcast_dir.join("episode1.mp3"), b"audio")
+            .await
+            .unwrap();
+        let manager =
+            DownloadManager::new(storage, downloads_dir, DownloadConfig::default()).unwrap();
+
+        // A file copied onto the device by hand, inside the managed folder
+        let device_path = temp_dir.path().join("device");
+        let device_podcast_dir = device_path.join("Podcasts").join("Test Podcast");
+        fs::create_dir_all(&device_podcast_dir).await.unwrap();
+        let manual_file = device_podcast_dir.join("bonus.mp3");
+        fs::write(&manual_file, b"bought separately").await.unwrap();
+
+        let report = manager
+            .sync_to_device(device_path.clone(), None, true, false, false, None)
+            .await
+            .unwrap();
+        assert!(report.files_deleted.is_empty());
+        assert_eq!(report.files_protected.len(), 1);
+        assert!(manual_file.exists());
+
+        // What the sync copied is removed once it's gone from the PC
+        fs::remove_file(podcast_dir.join("episode1.mp3"))
+            .await
+            .unwrap();
+        let report = manager
+            .sync_to_device(device_path.clone(), None, true, false, false, None)
+            .await
+            .unwrap();
+        assert_eq!(report.files_deleted.len(), 1);
+        assert!(!device_podcast_dir.join("episode1.mp3").exists());
+        assert!(manual_file.exists());
+    }
+
     #[tokio::test]
     async fn test_sync_to_device_delete_orphans() {
         let temp_dir = TempDir::new().unwrap();
@@ -1857,6 +4200,7 @@ mod tests {
         fs::create_dir_all(&device_podcast_dir).await.unwrap();
         let orphan_file = device_podcast_dir.join("old_episode.mp3");
         fs::write(&orphan_file, b"old content").await.unwrap();
+        mark_synced(&device_path, &orphan_file).await;
 
         // Run sync with delete_orphans=true
         let report = manager
@@ -1903,6 +4247,7 @@ mod tests {
             .unwrap();
         fs::write(&managed_orphan, b"old content").await.unwrap();
         fs::write(&unmanaged_audio, b"user file").await.unwrap();
+        mark_synced(&device_path, &managed_orphan).await;
 
         // Run sync with delete_orphans=true
         let report = manager
@@ -1945,21 +4290,72 @@ mod tests {
         let device_file = device_podcast_dir.join("episode1.mp3");
         fs::write(&device_file, b"old content").await.unwrap();
 
-        // Run sync
+        // Run sync
+        let report = manager
+            .sync_to_device(device_path.clone(), None, false, false, false, None)
+            .await
+            .unwrap();
+
+        // Verify file was updated
+        assert_eq!(report.files_copied.len(), 1);
+        assert_eq!(report.files_skipped.len(), 0);
+        assert_eq!(report.errors.len(), 0);
+        assert!(report.is_success());
+
+        // Verify content was updated
+        let device_content = fs::read(&device_file).await.unwrap();
+        assert_eq!(device_content, new_content);
+    }
+
+    #[tokio::test]
+    async fn test_sync_files_to_device_touches_only_the_playlist_folder() {
+        // Arrange — a podcast folder on the device, a stale playlist file
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        fs::create_dir_all(&downloads_dir).await.unwrap();
+        let source = downloads_dir.join("episode.mp3");
+        fs::write(&source, b"audio").await.unwrap();
+
+        let device_path = temp_dir.path().join("device");
+        let root = PathBuf::from("Playlists").join("Commute");
+        fs::create_dir_all(device_path.join(&root)).await.unwrap();
+        fs::write(device_path.join(&root).join("009-old.mp3"), b"old")
+            .await
+            .unwrap();
+        mark_synced(&device_path, &device_path.join(&root).join("009-old.mp3")).await;
+        fs::create_dir_all(device_path.join("Podcasts").join("Show"))
+            .await
+            .unwrap();
+        let podcast_file = device_path.join("Podcasts").join("Show").join("ep.mp3");
+        fs::write(&podcast_file, b"kept").await.unwrap();
+        let manager =
+            DownloadManager::new(storage, downloads_dir, DownloadConfig::default()).unwrap();
+
+        // Act
         let report = manager
-            .sync_to_device(device_path.clone(), None, false, false, false, None)
+            .sync_files_to_device(
+                device_path.clone(),
+                root.clone(),
+                vec![
+                    (root.join("001-episode.mp3"), Some(source)),
+                    (root.join("002-Not yet"), None),
+                ],
+                SyncOptions {
+                    delete_orphans: true,
+                    ..Default::default()
+                },
+                None,
+            )
             .await
             .unwrap();
 
-        // Verify file was updated
-        assert_eq!(report.files_copied.len(), 1);
-        assert_eq!(report.files_skipped.len(), 0);
-        assert_eq!(report.errors.len(), 0);
-        assert!(report.is_success());
-
-        // Verify content was updated
-        let device_content = fs::read(&device_file).await.unwrap();
-        assert_eq!(device_content, new_content);
+        // Assert
+        assert_eq!(report.files_copied, vec![root.join("001-episode.mp3")]);
+        assert_eq!(report.files_deleted, vec![root.join("009-old.mp3")]);
+        assert_eq!(report.errors.len(), 1);
+        assert!(device_path.join(&root).join("001-episode.mp3").exists());
+        assert!(podcast_file.exists(), "outside the playlist folder");
     }
 
     #[tokio::test]
@@ -2112,6 +4508,7 @@ mod tests {
         fs::create_dir_all(&orphan_playlist_dir).await.unwrap();
         let orphan_file = orphan_playlist_dir.join("001-old.mp3");
         fs::write(&orphan_file, b"orphan").await.unwrap();
+        mark_synced(&device_path, &orphan_file).await;
 
         let report = manager
             .sync_to_device(
@@ -2314,13 +4711,434 @@ mod tests {
             format!("https://example.com/{}", filename),
             Utc::now(),
         );
-        episode.status = EpisodeStatus::Downloaded;
+        episode.status.download = DownloadState::Downloaded;
         episode.local_path = Some(episode_file);
         storage.save_episode(&podcast_id, &episode).await.unwrap();
 
         (podcast_id, episode)
     }
 
+    /// An existing download with a recorded hash plus a fresh byte-identical copy
+    /// under another podcast, as `download_episode` would leave it
+    async fn setup_duplicate_download(
+        storage: &Arc<JsonStorage>,
+        downloads_dir: &std::path::Path,
+    ) -> (Episode, Episode, PathBuf) {
+        let (_, mut original) =
+            setup_downloaded_episode(storage, downloads_dir, "Original", "Ep 1", "ep1.mp3").await;
+        original.content_hash = Some(
+            hash_file(original.local_path.as_ref().unwrap())
+                .await
+                .unwrap(),
+        );
+        storage
+            .save_episode(&original.podcast_id, &original)
+            .await
+            .unwrap();
+
+        let refeed_dir = downloads_dir.join("Refeed");
+        fs::create_dir_all(&refeed_dir).await.unwrap();
+        let new_path = refeed_dir.join("ep1-again.mp3");
+        fs::write(&new_path, b"fake audio data").await.unwrap();
+        let copy = Episode::new(
+            PodcastId::new(),
+            "Ep 1 (re-feed)".to_string(),
+            "https://example.com/refeed/ep1.mp3".to_string(),
+            Utc::now(),
+        );
+
+        (original, copy, new_path)
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_download_is_hard_linked() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (original, mut copy, new_path) =
+            setup_duplicate_download(&storage, &downloads_dir).await;
+
+        // Act
+        let linked = manager
+            .deduplicate_download(&mut copy, &new_path)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(linked);
+        assert_eq!(copy.content_hash, original.content_hash);
+        assert!(new_path.exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let a = std::fs::metadata(original.local_path.as_ref().unwrap()).unwrap();
+            let b = std::fs::metadata(&new_path).unwrap();
+            assert_eq!(a.ino(), b.ino(), "both episodes share one file on disk");
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_hard_linked_downloads_count_once_against_the_quota() {
+        // Arrange — two episodes sharing one file, as deduplication leaves them
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            max_storage_gb: Some(1),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let (_, original) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Original", "Ep 1", "ep1.mp3").await;
+        let (_, copy) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Refeed", "Ep 1", "ep1.mp3").await;
+        let copy_path = copy.local_path.clone().unwrap();
+        fs::remove_file(&copy_path).await.unwrap();
+        fs::hard_link(original.local_path.as_ref().unwrap(), &copy_path)
+            .await
+            .unwrap();
+
+        // Act
+        let usage = manager.storage_usage().await.unwrap();
+        let plan = manager.plan_cleanup().await.unwrap();
+
+        // Assert
+        assert_eq!(usage.used, b"fake audio data".len() as u64);
+        assert_eq!(plan.used, usage.used);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_download_refused_names_original() {
+        // Arrange
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            duplicate_downloads: "refuse".to_string(),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let (_, mut copy, new_path) = setup_duplicate_download(&storage, &downloads_dir).await;
+
+        // Act
+        let result = manager.deduplicate_download(&mut copy, &new_path).await;
+
+        // Assert
+        assert!(matches!(result, Err(DownloadError::Duplicate(ref title)) if title == "Ep 1"));
+        assert!(!new_path.exists());
+        assert_eq!(copy.status.download, DownloadState::NotDownloaded);
+        assert!(copy.content_hash.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stale_duplicate_entry_is_ignored() {
+        // Arrange — the original file was deleted outside the app
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (original, mut copy, new_path) =
+            setup_duplicate_download(&storage, &downloads_dir).await;
+        fs::remove_file(original.local_path.as_ref().unwrap())
+            .await
+            .unwrap();
+
+        // Act
+        let linked = manager
+            .deduplicate_download(&mut copy, &new_path)
+            .await
+            .unwrap();
+
+        // Assert — the new copy is kept as-is and still hashed
+        assert!(!linked);
+        assert!(new_path.exists());
+        assert!(copy.content_hash.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_convert_episode_failure_keeps_original() {
+        // Arrange — point at an ffmpeg that doesn't exist
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            ffmpeg_path: Some(temp_dir.path().join("no-ffmpeg").display().to_string()),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let (podcast_id, episode) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Big", "Lossless", "ep.wav").await;
+        let target = ConvertTarget::parse("opus", None).unwrap();
+
+        // Act
+        let result = manager
+            .convert_episode(&podcast_id, &episode.id, &target, |_| {})
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(DownloadError::Conversion(ref e)) if e.contains("not found")));
+        let saved = storage
+            .load_episode(&podcast_id, &episode.id)
+            .await
+            .unwrap();
+        assert_eq!(saved.local_path, episode.local_path);
+        assert!(episode.local_path.as_ref().unwrap().exists());
+        assert!(!downloads_dir
+            .join("Big")
+            .join("ep.converting.opus")
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_convert_episode_requires_download() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (podcast_id, episode) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Pod", "Gone", "gone.mp3").await;
+        fs::remove_file(episode.local_path.as_ref().unwrap())
+            .await
+            .unwrap();
+
+        let target = ConvertTarget::parse("mp3", None).unwrap();
+        let result = manager
+            .convert_episode(&podcast_id, &episode.id, &target, |_| {})
+            .await;
+
+        assert!(matches!(result, Err(DownloadError::InvalidPath(_))));
+    }
+
+    /// Executable shell script standing in for ffmpeg or whisper
+    #[cfg(unix)]
+    fn fake_tool(dir: &std::path::Path, name: &str, body: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path.display().to_string()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_transcribe_episode_stores_transcript_and_cleans_up() {
+        // Arrange — ffmpeg touches its output; whisper writes `<-of>.txt`
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let ffmpeg = fake_tool(
+            temp_dir.path(),
+            "ffmpeg",
+            r#"for last; do :; done; : > "$last""#,
+        );
+        let whisper = fake_tool(
+            temp_dir.path(),
+            "whisper",
+            r#"while [ $# -gt 0 ]; do [ "$1" = "-of" ] && out="$2"; shift; done
+echo "whisper_print_progress_callback: progress =  50%" >&2
+printf ' Hello there.\n\n General Kenobi.\n' > "$out.txt""#,
+        );
+        let model = temp_dir.path().join("ggml-base.bin");
+        std::fs::write(&model, b"model").unwrap();
+        let config = DownloadConfig {
+            ffmpeg_path: Some(ffmpeg),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config)
+            .unwrap()
+            .with_transcription_config(TranscriptionConfig {
+                whisper_path: Some(whisper),
+                model: Some(model.display().to_string()),
+                ..TranscriptionConfig::default()
+            });
+        let (podcast_id, episode) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Pod", "Talk", "talk.mp3").await;
+        let mut progress = Vec::new();
+
+        // Act
+        let transcribed = manager
+            .transcribe_episode(&podcast_id, &episode.id, |percent| progress.push(percent))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(progress, vec![50]);
+        assert_eq!(transcribed.id, episode.id);
+        let saved = storage
+            .load_episode(&podcast_id, &episode.id)
+            .await
+            .unwrap();
+        assert_eq!(
+            saved.transcript.as_deref(),
+            Some("Hello there.\nGeneral Kenobi.")
+        );
+        let leftovers: Vec<_> = std::fs::read_dir(downloads_dir.join("Pod"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, vec![std::ffi::OsString::from("talk.mp3")]);
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_episode_needs_a_model() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (podcast_id, episode) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Pod", "Talk", "talk.mp3").await;
+
+        let result = manager
+            .transcribe_episode(&podcast_id, &episode.id, |_| {})
+            .await;
+
+        assert!(
+            matches!(result, Err(DownloadError::Transcription(ref e)) if e.contains("transcription.model"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cached_artwork_is_used_without_fetching() {
+        // Arrange — artwork for an unreachable URL already in the cache
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let manager = DownloadManager::new(
+            storage,
+            temp_dir.path().join("downloads"),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let url = "http://127.0.0.1:9/cover.jpg";
+        let cache_path = manager.artwork_cache_path(url);
+        std::fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+        std::fs::write(&cache_path, b"cached jpeg").unwrap();
+
+        // Act
+        let (mime_type, data) = manager.load_artwork(url).await.unwrap();
+        let prefetched = manager
+            .prefetch_artwork(vec![url.to_string(), "~/local.png".to_string()])
+            .await;
+
+        // Assert — served from the cache; nothing left to prefetch
+        assert_eq!(mime_type, "image/jpeg");
+        assert_eq!(data, b"cached jpeg");
+        assert_eq!(prefetched, 0);
+    }
+
+    #[test]
+    fn test_encode_artwork_scales_down_large_images() {
+        let max = crate::constants::downloads::ARTWORK_MAX_DIMENSION;
+        let mut png = Vec::new();
+        image::RgbaImage::new(max * 2, max)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        let (mime_type, jpeg) =
+            DownloadManager::<JsonStorage>::encode_artwork("image/png".to_string(), png).unwrap();
+
+        assert_eq!(mime_type, "image/jpeg");
+        let scaled = image::load_from_memory(&jpeg).unwrap();
+        assert_eq!((scaled.width(), scaled.height()), (max, max / 2));
+    }
+
+    #[tokio::test]
+    async fn test_retag_episode_uses_local_overrides() {
+        // Arrange — a downloaded episode with an edited title and local artwork
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+        let (podcast_id, mut episode) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Pod", "Feed Title", "ep.mp3").await;
+        let artwork = temp_dir.path().join("cover.png");
+        image::RgbImage::new(4, 4).save(&artwork).unwrap();
+        episode.overrides.title = Some("My Title".to_string());
+        episode.overrides.image_url = Some(artwork.display().to_string());
+        storage.save_episode(&podcast_id, &episode).await.unwrap();
+
+        // Act
+        let tagged = manager
+            .retag_episode(&podcast_id, &episode.id)
+            .await
+            .unwrap();
+
+        // Assert
+        use id3::TagLike;
+        assert!(tagged);
+        let tag = id3::Tag::read_from_path(episode.local_path.as_ref().unwrap()).unwrap();
+        assert_eq!(tag.title(), Some("My Title"));
+        let picture = tag.pictures().next().unwrap();
+        assert_eq!(picture.mime_type, "image/jpeg");
+    }
+
+    #[tokio::test]
+    async fn test_retag_leaves_a_hard_linked_duplicate_alone() {
+        // Arrange — two episodes sharing one file, as deduplication links them
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        let config = DownloadConfig {
+            download_artwork: false,
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+        let (_, original) =
+            setup_downloaded_episode(&storage, &downloads_dir, "First", "Ep", "ep.mp3").await;
+        let (podcast_id, mut linked) =
+            setup_downloaded_episode(&storage, &downloads_dir, "Second", "Ep", "ep.mp3").await;
+        let original_path = original.local_path.clone().unwrap();
+        let linked_path = linked.local_path.clone().unwrap();
+        fs::remove_file(&linked_path).await.unwrap();
+        fs::hard_link(&original_path, &linked_path).await.unwrap();
+        linked.overrides.title = Some("Renamed".to_string());
+        storage.save_episode(&podcast_id, &linked).await.unwrap();
+
+        // Act
+        let tagged = manager
+            .retag_episode(&podcast_id, &linked.id)
+            .await
+            .unwrap();
+
+        // Assert
+        use id3::TagLike;
+        assert!(tagged);
+        let tag = id3::Tag::read_from_path(&linked_path).unwrap();
+        assert_eq!(tag.title(), Some("Renamed"));
+        assert!(id3::Tag::read_from_path(&original_path).is_err());
+        assert_eq!(
+            std::fs::read(&original_path).unwrap(),
+            b"fake audio data".to_vec()
+        );
+    }
+
     #[tokio::test]
     async fn test_cleanup_deletes_old_files() {
         let temp_dir = TempDir::new().unwrap();
@@ -2349,7 +5167,7 @@ mod tests {
             "https://example.com/new.mp3".to_string(),
             Utc::now(),
         );
-        new_ep.status = EpisodeStatus::Downloaded;
+        new_ep.status.download = DownloadState::Downloaded;
         new_ep.local_path = Some(new_file.clone());
         storage.save_episode(&podcast_id, &new_ep).await.unwrap();
 
@@ -2371,11 +5189,11 @@ mod tests {
         // Verify episode statuses persisted
         let episodes = storage.load_episodes(&podcast_id).await.unwrap();
         let old_saved = episodes.iter().find(|e| e.id == old_ep.id).unwrap();
-        assert_eq!(old_saved.status, EpisodeStatus::New);
+        assert_eq!(old_saved.status.download, DownloadState::NotDownloaded);
         assert!(old_saved.local_path.is_none());
 
         let new_saved = episodes.iter().find(|e| e.id == new_ep.id).unwrap();
-        assert_eq!(new_saved.status, EpisodeStatus::Downloaded);
+        assert_eq!(new_saved.status.download, DownloadState::Downloaded);
         assert!(new_saved.local_path.is_some());
     }
 
@@ -2411,7 +5229,7 @@ mod tests {
         assert!(episode.local_path.as_ref().unwrap().exists());
         let episodes = storage.load_episodes(&podcast_id).await.unwrap();
         let saved = episodes.iter().find(|e| e.id == episode.id).unwrap();
-        assert_eq!(saved.status, EpisodeStatus::Downloaded);
+        assert_eq!(saved.status.download, DownloadState::Downloaded);
         assert!(saved.local_path.is_some());
     }
 
@@ -2447,7 +5265,7 @@ mod tests {
             "https://example.com/ghost.mp3".to_string(),
             Utc::now(),
         );
-        episode.status = EpisodeStatus::Downloaded;
+        episode.status.download = DownloadState::Downloaded;
         episode.local_path = Some(phantom_path.clone());
         storage.save_episode(&podcast_id, &episode).await.unwrap();
 
@@ -2460,7 +5278,7 @@ mod tests {
         // Episode status should be reset to New
         let episodes = storage.load_episodes(&podcast_id).await.unwrap();
         let saved = episodes.iter().find(|e| e.id == episode.id).unwrap();
-        assert_eq!(saved.status, EpisodeStatus::New);
+        assert_eq!(saved.status.download, DownloadState::NotDownloaded);
         assert!(saved.local_path.is_none());
     }
 
@@ -2489,9 +5307,9 @@ mod tests {
 
         // Create episodes with non-Downloaded statuses
         let statuses = [
-            EpisodeStatus::New,
-            EpisodeStatus::Downloading,
-            EpisodeStatus::DownloadFailed,
+            EpisodeStatus::default(),
+            EpisodeStatus::downloading(),
+            EpisodeStatus::download_failed(),
         ];
 
         let mut episode_ids = Vec::new();
@@ -2502,8 +5320,8 @@ mod tests {
                 format!("https://example.com/ep{}.mp3", i),
                 Utc::now(),
             );
-            ep.status = status.clone();
-            episode_ids.push((ep.id.clone(), status.clone()));
+            ep.status = *status;
+            episode_ids.push((ep.id.clone(), *status));
             storage.save_episode(&podcast_id, &ep).await.unwrap();
         }
 
@@ -2612,7 +5430,7 @@ mod tests {
         // Verify episode status
         let episodes = storage.load_episodes(&podcast_id).await.unwrap();
         let saved = episodes.iter().find(|e| e.id == episode.id).unwrap();
-        assert_eq!(saved.status, EpisodeStatus::New);
+        assert_eq!(saved.status.download, DownloadState::NotDownloaded);
         assert!(saved.local_path.is_none());
     }
 
@@ -2651,6 +5469,85 @@ mod tests {
         assert!(!episode.local_path.as_ref().unwrap().exists());
     }
 
+    #[tokio::test]
+    async fn test_apply_retention_policies_keeps_the_latest_downloads() {
+        use crate::podcast::RetentionPolicy;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        fs::create_dir_all(&downloads_dir).await.unwrap();
+        let manager = DownloadManager::new(
+            storage.clone(),
+            downloads_dir.clone(),
+            DownloadConfig::default(),
+        )
+        .unwrap();
+
+        let (podcast_id, newest) =
+            setup_downloaded_episode(&storage, &downloads_dir, "KeepPod", "New", "new.mp3").await;
+        let older_file = downloads_dir.join("KeepPod").join("old.mp3");
+        fs::write(&older_file, b"fake audio data").await.unwrap();
+        let mut older = Episode::new(
+            podcast_id.clone(),
+            "Old".to_string(),
+            "https://example.com/old.mp3".to_string(),
+            Utc::now() - chrono::Duration::days(7),
+        );
+        older.status.download = DownloadState::Downloaded;
+        older.local_path = Some(older_file.clone());
+        storage.save_episode(&podcast_id, &older).await.unwrap();
+        set_file_mtime_age(&older_file, std::time::Duration::from_secs(30 * 24 * 3600));
+
+        let mut podcast = storage.load_podcast(&podcast_id).await.unwrap();
+        podcast.settings.retention = Some(RetentionPolicy::KeepLatest(1));
+        storage.save_podcast(&podcast).await.unwrap();
+
+        // The app-wide pass leaves a podcast with its own policy alone
+        assert_eq!(manager.cleanup_old_downloads(7).await.unwrap(), 0);
+        assert!(older_file.exists());
+
+        assert_eq!(manager.apply_retention_policies().await.unwrap(), 1);
+        assert!(!older_file.exists());
+        assert!(newest.local_path.as_ref().unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn test_plan_cleanup_is_a_dry_run_of_the_app_wide_rules() {
+        use super::retention::CleanupReason;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let downloads_dir = temp_dir.path().join("downloads");
+        fs::create_dir_all(&downloads_dir).await.unwrap();
+        let config = DownloadConfig {
+            keep_latest: Some(5),
+            delete_played_after_days: Some(7),
+            ..DownloadConfig::default()
+        };
+        let manager = DownloadManager::new(storage.clone(), downloads_dir.clone(), config).unwrap();
+
+        let (podcast_id, mut played) =
+            setup_downloaded_episode(&storage, &downloads_dir, "PlayedPod", "Heard", "heard.mp3")
+                .await;
+        played.mark_played();
+        played.played_at = Some(Utc::now() - chrono::Duration::days(8));
+        storage.save_episode(&podcast_id, &played).await.unwrap();
+        let (_, unplayed) =
+            setup_downloaded_episode(&storage, &downloads_dir, "PlayedPod", "Fresh", "fresh.mp3")
+                .await;
+
+        let plan = manager.plan_cleanup().await.unwrap();
+        assert_eq!(plan.items.len(), 1);
+        assert_eq!(plan.items[0].download.title, "Heard");
+        assert_eq!(plan.items[0].reason, CleanupReason::PlayedDaysAgo(7));
+        assert!(played.local_path.as_ref().unwrap().exists());
+
+        assert_eq!(manager.apply_retention_policies().await.unwrap(), 1);
+        assert!(!played.local_path.as_ref().unwrap().exists());
+        assert!(unplayed.local_path.as_ref().unwrap().exists());
+    }
+
     // -----------------------------------------------------------------------
     // Failure-tracking test using MockStorage (generated via mockall::automock)
     // -----------------------------------------------------------------------
@@ -2692,7 +5589,7 @@ mod tests {
             "https://example.com/episode.mp3".to_string(),
             Utc::now(),
         );
-        episode.status = EpisodeStatus::Downloaded;
+        episode.status.download = DownloadState::Downloaded;
         episode.local_path = Some(episode_file.clone());
 
         // Configure MockStorage:
@@ -1,6 +1,21 @@
+pub mod auto;
+pub mod convert;
+pub mod device_manifest;
 pub mod manager;
+pub mod prefetch;
+pub mod probe;
+pub mod quota;
+pub mod retention;
+pub mod throughput;
+pub mod transcribe;
+pub mod ytdlp;
 
+pub use auto::AutoDownloads;
+pub use convert::{ConvertFormat, ConvertTarget};
 pub use manager::{
-    DownloadError, DownloadManager, DownloadProgress, DownloadStatus, SyncError,
-    SyncHistorySummary, SyncProgressEvent, SyncReport,
+    CollisionReport, DownloadError, DownloadManager, DownloadProgress, DownloadStatus, SyncError,
+    SyncHistorySummary, SyncOptions, SyncProgressEvent, SyncReport,
 };
+pub use quota::{EvictionPolicy, StorageUsage};
+pub use retention::{CleanupPlan, CleanupReason};
+pub use throughput::SpeedHistory;
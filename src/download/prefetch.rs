@@ -0,0 +1,133 @@
+// Idle prefetch - which episodes to fetch ahead of the one playing
+//
+// With `downloads.prefetch_next` set, the app downloads the next unplayed
+// episodes while the user is idle on an unmetered connection, so moving on
+// never waits for a download. Following a queue, the next queue entries are
+// fetched; otherwise the episodes of the playing podcast that come after the
+// playing one. Auto-play (`audio.auto_play_next`) picks from the same order,
+// among the episodes already downloaded.
+
+use crate::podcast::{DownloadState, Episode};
+use crate::storage::EpisodeId;
+
+/// True for unplayed episodes that would still need downloading before they can play
+pub fn needs_prefetch(episode: &Episode) -> bool {
+    !episode.is_played()
+        && matches!(
+            episode.status.download,
+            DownloadState::NotDownloaded | DownloadState::Failed
+        )
+}
+
+/// Up to `count` episodes of a podcast to fetch after `playing`: unplayed,
+/// not yet downloaded, published after it (oldest first). When `playing` is
+/// the newest, the catalogue continues backwards (newest first).
+pub fn next_unplayed(episodes: &[Episode], playing: &EpisodeId, count: usize) -> Vec<EpisodeId> {
+    following(episodes, playing, needs_prefetch)
+        .iter()
+        .take(count)
+        .map(|e| e.id.clone())
+        .collect()
+}
+
+/// The episode to auto-play after `playing`: the first unplayed, downloaded
+/// one in the same order as `next_unplayed`
+pub fn next_playable<'a>(episodes: &'a [Episode], playing: &EpisodeId) -> Option<&'a Episode> {
+    following(episodes, playing, |e| !e.is_played() && e.is_downloaded())
+        .into_iter()
+        .next()
+}
+
+/// Episodes matching `keep` after `playing`: those published later (oldest
+/// first), or when there are none, those published earlier (newest first)
+fn following<'a>(
+    episodes: &'a [Episode],
+    playing: &EpisodeId,
+    keep: impl Fn(&Episode) -> bool,
+) -> Vec<&'a Episode> {
+    let Some(current) = episodes.iter().find(|e| &e.id == playing) else {
+        return Vec::new();
+    };
+    let candidates = episodes.iter().filter(|e| &e.id != playing && keep(e));
+
+    let mut later: Vec<&Episode> = candidates
+        .clone()
+        .filter(|e| e.published > current.published)
+        .collect();
+    if later.is_empty() {
+        let mut earlier: Vec<&Episode> = candidates.collect();
+        earlier.sort_by_key(|e| std::cmp::Reverse(e.published));
+        return earlier;
+    }
+    later.sort_by_key(|e| e.published);
+    later
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::PlayState;
+    use crate::storage::PodcastId;
+    use chrono::{Duration, Utc};
+
+    fn episodes(podcast_id: &PodcastId, count: i64) -> Vec<Episode> {
+        (0..count)
+            .map(|day| {
+                Episode::new(
+                    podcast_id.clone(),
+                    format!("Day {}", day),
+                    format!("https://example.com/{}.mp3", day),
+                    Utc::now() - Duration::days(count - day),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_next_unplayed_follows_playing_episode_and_skips_handled_ones() {
+        // Arrange — day 1 is playing, day 2 is already downloaded
+        let podcast_id = PodcastId::new();
+        let mut list = episodes(&podcast_id, 5);
+        list[2].status.download = DownloadState::Downloaded;
+
+        // Act
+        let next = next_unplayed(&list, &list[1].id, 2);
+
+        // Assert
+        assert_eq!(next, vec![list[3].id.clone(), list[4].id.clone()]);
+    }
+
+    #[test]
+    fn test_next_unplayed_goes_backwards_from_newest() {
+        let podcast_id = PodcastId::new();
+        let mut list = episodes(&podcast_id, 4);
+        list[2].status.play = PlayState::Played;
+
+        let next = next_unplayed(&list, &list[3].id, 2);
+
+        assert_eq!(next, vec![list[1].id.clone(), list[0].id.clone()]);
+        assert!(next_unplayed(&list, &EpisodeId::new(), 2).is_empty());
+    }
+
+    #[test]
+    fn test_next_playable_skips_played_and_undownloaded_episodes() {
+        // Arrange — day 1 is playing; day 2 isn't downloaded, day 3 is played
+        let dir = tempfile::tempdir().unwrap();
+        let podcast_id = PodcastId::new();
+        let mut list = episodes(&podcast_id, 5);
+        for episode in &mut list[3..] {
+            let path = dir.path().join(format!("{}.mp3", episode.id));
+            std::fs::write(&path, b"audio").unwrap();
+            episode.status.download = DownloadState::Downloaded;
+            episode.local_path = Some(path);
+        }
+        list[3].status.play = PlayState::Played;
+
+        // Act
+        let next = next_playable(&list, &list[1].id);
+
+        // Assert
+        assert_eq!(next.map(|e| &e.id), Some(&list[4].id));
+        assert!(next_playable(&list, &list[4].id).is_none());
+    }
+}
@@ -0,0 +1,153 @@
+// Duration probing for downloaded episodes
+//
+// Many feeds omit `<itunes:duration>`, which leaves duration-based smart
+// playlists and sorting with nothing to work on. Once the audio is on disk the
+// length can be read from the file itself: the bundled decoder (symphonia via
+// rodio) handles most files from their headers, and ffprobe — installed next to
+// ffmpeg — covers what it can't (e.g. CBR MP3s without a Xing header).
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use rodio::Source;
+use tokio::process::Command;
+
+use super::convert::DEFAULT_FFMPEG;
+
+/// The ffprobe that ships alongside the configured ffmpeg (`ffmpeg` → `ffprobe`,
+/// `/opt/bin/ffmpeg.exe` → `/opt/bin/ffprobe.exe`)
+pub fn ffprobe_for(ffmpeg: Option<&str>) -> PathBuf {
+    let ffmpeg = Path::new(ffmpeg.unwrap_or(DEFAULT_FFMPEG));
+    let name = match ffmpeg.extension() {
+        Some(ext) => format!("ffprobe.{}", ext.to_string_lossy()),
+        None => "ffprobe".to_string(),
+    };
+    ffmpeg.with_file_name(name)
+}
+
+/// Length of an audio file in whole seconds, or `None` when neither the
+/// bundled decoder nor ffprobe can tell
+pub async fn probe_duration(path: &Path, ffprobe: &Path) -> Option<u32> {
+    let native_path = path.to_path_buf();
+    let native = tokio::task::spawn_blocking(move || decoder_duration(&native_path))
+        .await
+        .ok()
+        .flatten();
+    match native {
+        Some(seconds) => Some(seconds),
+        None => ffprobe_duration(ffprobe, path).await,
+    }
+}
+
+fn decoder_duration(path: &Path) -> Option<u32> {
+    let file = std::fs::File::open(path).ok()?;
+    let decoder = rodio::Decoder::try_from(file).ok()?;
+    decoder
+        .total_duration()
+        .map(|duration| duration.as_secs_f64().round() as u32)
+        .filter(|seconds| *seconds > 0)
+}
+
+async fn ffprobe_duration(ffprobe: &Path, path: &Path) -> Option<u32> {
+    let output = Command::new(ffprobe)
+        .args([
+            "-v",
+            "error",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "default=noprint_wrappers=1:nokey=1",
+        ])
+        .arg(path)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ffprobe_duration(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// ffprobe prints the container duration in seconds ("3600.052000"), or
+/// "N/A" when it doesn't know
+fn parse_ffprobe_duration(stdout: &str) -> Option<u32> {
+    stdout
+        .lines()
+        .find_map(|line| line.trim().parse::<f64>().ok())
+        .filter(|seconds| seconds.is_finite() && *seconds >= 1.0)
+        .map(|seconds| seconds.round() as u32)
+}
+
+/// `seconds` of 8 kHz mono 16-bit silence as a WAV file
+#[cfg(test)]
+pub(crate) fn silent_wav(seconds: u32) -> Vec<u8> {
+    let data_len = 8000 * 2 * seconds;
+    let mut wav = Vec::new();
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&8000u32.to_le_bytes());
+    wav.extend_from_slice(&16000u32.to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.resize(wav.len() + data_len as usize, 0);
+    wav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ffprobe_duration() {
+        assert_eq!(parse_ffprobe_duration("3600.052000\n"), Some(3600));
+        assert_eq!(parse_ffprobe_duration("59.6\n"), Some(60));
+        assert_eq!(parse_ffprobe_duration("N/A\n"), None);
+        assert_eq!(parse_ffprobe_duration(""), None);
+    }
+
+    #[test]
+    fn test_ffprobe_sits_next_to_ffmpeg() {
+        assert_eq!(ffprobe_for(None), PathBuf::from("ffprobe"));
+        assert_eq!(
+            ffprobe_for(Some("/opt/ffmpeg/bin/ffmpeg")),
+            PathBuf::from("/opt/ffmpeg/bin/ffprobe")
+        );
+        assert_eq!(
+            ffprobe_for(Some("C:/tools/ffmpeg.exe")),
+            PathBuf::from("C:/tools/ffprobe.exe")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_probe_duration_reads_wav_header() {
+        // Arrange
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("silence.wav");
+        std::fs::write(&path, silent_wav(2)).unwrap();
+
+        // Act
+        let duration = probe_duration(&path, Path::new("/nonexistent/ffprobe")).await;
+
+        // Assert
+        assert_eq!(duration, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_probe_duration_gives_up_on_non_audio() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("notes.mp3");
+        std::fs::write(&path, b"not audio at all").unwrap();
+
+        let duration = probe_duration(&path, Path::new("/nonexistent/ffprobe")).await;
+
+        assert_eq!(duration, None);
+    }
+}
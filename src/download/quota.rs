@@ -0,0 +1,340 @@
+// Downloads storage quota
+//
+// With `downloads.max_storage_gb` set, downloaded audio is kept under a fixed
+// total size. Before a download starts, room is made for its announced size
+// (or the download is refused when that isn't possible), and once it finishes
+// anything still over the quota is evicted. Favourited episodes are pinned
+// and never evicted; played episodes go first, oldest download first, and
+// the eviction policy decides whether unplayed ones may follow. Hard links
+// left by duplicate detection are one file: it is counted once, and its
+// space only comes back when every link to it goes.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::storage::{EpisodeId, PodcastId};
+
+const BYTES_PER_GB: u64 = 1024 * 1024 * 1024;
+
+/// Which downloads may be evicted to stay under the quota
+/// (`downloads.quota_eviction`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Only played episodes
+    Played,
+    /// Played episodes, then unplayed ones, oldest first
+    Oldest,
+}
+
+impl EvictionPolicy {
+    pub fn from_config(value: &str) -> Self {
+        match value {
+            "oldest" => Self::Oldest,
+            _ => Self::Played,
+        }
+    }
+
+    fn allows(&self, candidate: &EvictionCandidate) -> bool {
+        !candidate.pinned && (candidate.played || *self == Self::Oldest)
+    }
+}
+
+/// Space taken by downloaded episodes against the configured quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StorageUsage {
+    pub used: u64,
+    pub quota: Option<u64>,
+}
+
+impl StorageUsage {
+    /// Quota in bytes for a `max_storage_gb` setting (0 disables it)
+    pub fn quota_bytes(max_storage_gb: Option<u32>) -> Option<u64> {
+        max_storage_gb
+            .filter(|gb| *gb > 0)
+            .map(|gb| gb as u64 * BYTES_PER_GB)
+    }
+
+    /// Share of the quota in use, capped at 1.0; `None` without a quota
+    pub fn ratio(&self) -> Option<f64> {
+        self.quota
+            .map(|quota| (self.used as f64 / quota as f64).min(1.0))
+    }
+}
+
+/// Which file on disk a download is, so hard links to one file are told apart
+/// from copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileId {
+    dev: u64,
+    ino: u64,
+}
+
+impl FileId {
+    /// The file `metadata` describes and how many links it has. Where the
+    /// platform doesn't say, there is no identity and each path is its own file.
+    pub fn of(metadata: &std::fs::Metadata) -> (Option<Self>, u64) {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            (
+                Some(Self {
+                    dev: metadata.dev(),
+                    ino: metadata.ino(),
+                }),
+                metadata.nlink(),
+            )
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = metadata;
+            (None, 1)
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn for_test(ino: u64) -> Self {
+        Self { dev: 1, ino }
+    }
+}
+
+/// Total size of `files` (identity and size), counting each file once
+pub fn total_size(files: impl IntoIterator<Item = (Option<FileId>, u64)>) -> u64 {
+    let mut seen = HashSet::new();
+    files
+        .into_iter()
+        .filter(|(file, _)| file.is_none_or(|file| seen.insert(file)))
+        .map(|(_, size)| size)
+        .sum()
+}
+
+/// Bytes deleting `files` (identity, link count and size) gives back: a
+/// linked file only once all its links are among them
+pub fn freed_size(files: impl IntoIterator<Item = (Option<FileId>, u64, u64)>) -> u64 {
+    let mut removed_links: HashMap<FileId, u64> = HashMap::new();
+    let mut freed = 0;
+    for (file, links, size) in files {
+        match file {
+            Some(file) => {
+                let removed = removed_links.entry(file).or_insert(0);
+                *removed += 1;
+                if *removed == links.max(1) {
+                    freed += size;
+                }
+            }
+            None => freed += size,
+        }
+    }
+    freed
+}
+
+/// A downloaded episode that could make room
+#[derive(Debug, Clone)]
+pub struct EvictionCandidate {
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+    pub path: PathBuf,
+    pub size: u64,
+    pub played: bool,
+    /// Favourited episodes are never evicted
+    pub pinned: bool,
+    /// When the file was written, used as its age
+    pub modified: SystemTime,
+    /// The file on disk, shared with other candidates when hard-linked
+    pub file: Option<FileId>,
+    /// Links to the file, including ones held outside the candidates
+    pub links: u64,
+}
+
+/// What has to go to fit a download under the quota
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictionPlan {
+    /// Indexes into the candidate list, in eviction order
+    pub evict: Vec<usize>,
+    /// Whether usage ends up within the quota once they are gone
+    pub fits: bool,
+}
+
+/// Choose candidates to evict so that `used + incoming` fits in `quota`.
+///
+/// Played episodes are taken before unplayed ones, oldest first within each
+/// group, stopping as soon as enough space is freed. Links to one file go
+/// together, and only when every link may go; otherwise deleting them
+/// would free nothing. When even evicting every eligible download isn't
+/// enough, all of them are listed and `fits` is false.
+pub fn plan_evictions(
+    candidates: &[EvictionCandidate],
+    used: u64,
+    incoming: u64,
+    quota: u64,
+    policy: EvictionPolicy,
+) -> EvictionPlan {
+    let mut order: Vec<usize> = (0..candidates.len())
+        .filter(|&i| policy.allows(&candidates[i]))
+        .collect();
+    order.sort_by_key(|&i| (!candidates[i].played, candidates[i].modified));
+
+    let mut links: HashMap<FileId, Vec<usize>> = HashMap::new();
+    for &i in &order {
+        if let Some(file) = candidates[i].file {
+            links.entry(file).or_default().push(i);
+        }
+    }
+
+    let mut remaining = used.saturating_add(incoming);
+    let mut evict = Vec::new();
+    for i in order {
+        if remaining <= quota {
+            break;
+        }
+        let candidate = &candidates[i];
+        match candidate.file {
+            None => evict.push(i),
+            Some(file) => {
+                let Some(group) = links.remove(&file) else {
+                    continue; // Evicted with an earlier link
+                };
+                if (group.len() as u64) < candidate.links {
+                    continue; // A link that must stay keeps the file
+                }
+                evict.extend(group);
+            }
+        }
+        remaining = remaining.saturating_sub(candidate.size);
+    }
+
+    EvictionPlan {
+        evict,
+        fits: remaining <= quota,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn candidate(size: u64, played: bool, pinned: bool, age_days: u64) -> EvictionCandidate {
+        EvictionCandidate {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            path: PathBuf::from("episode.mp3"),
+            size,
+            played,
+            pinned,
+            modified: SystemTime::now() - Duration::from_secs(age_days * 86_400),
+            file: None,
+            links: 1,
+        }
+    }
+
+    fn linked(mut candidate: EvictionCandidate, ino: u64, links: u64) -> EvictionCandidate {
+        candidate.file = Some(FileId::for_test(ino));
+        candidate.links = links;
+        candidate
+    }
+
+    #[test]
+    fn test_plan_evicts_oldest_played_first_and_stops_when_it_fits() {
+        // Arrange
+        let candidates = vec![
+            candidate(40, true, false, 1),
+            candidate(40, true, false, 9),
+            candidate(40, false, false, 30),
+            candidate(40, true, false, 5),
+        ];
+
+        // Act
+        let plan = plan_evictions(&candidates, 160, 50, 150, EvictionPolicy::Oldest);
+
+        // Assert
+        assert_eq!(plan.evict, vec![1, 3]);
+        assert!(plan.fits);
+    }
+
+    #[test]
+    fn test_plan_never_evicts_pinned_episodes() {
+        let candidates = vec![
+            candidate(100, true, true, 30),
+            candidate(10, true, false, 1),
+        ];
+
+        let plan = plan_evictions(&candidates, 110, 0, 50, EvictionPolicy::Oldest);
+
+        assert_eq!(plan.evict, vec![1]);
+        assert!(!plan.fits);
+    }
+
+    #[test]
+    fn test_played_policy_keeps_unplayed_downloads() {
+        let candidates = vec![
+            candidate(100, false, false, 30),
+            candidate(20, true, false, 1),
+        ];
+
+        let played = plan_evictions(&candidates, 120, 0, 90, EvictionPolicy::Played);
+        let oldest = plan_evictions(&candidates, 120, 0, 90, EvictionPolicy::Oldest);
+
+        assert_eq!(played.evict, vec![1]);
+        assert!(!played.fits);
+        assert_eq!(oldest.evict, vec![1, 0]);
+        assert!(oldest.fits);
+    }
+
+    #[test]
+    fn test_hard_links_free_space_only_together() {
+        // Arrange — two episodes linked to one 100-byte file, a 30-byte copy
+        let candidates = vec![
+            linked(candidate(100, true, false, 30), 7, 2),
+            candidate(30, true, false, 20),
+            linked(candidate(100, true, false, 10), 7, 2),
+        ];
+
+        // Act — 130 on disk, cap of 50
+        let plan = plan_evictions(&candidates, 130, 0, 50, EvictionPolicy::Played);
+
+        // Assert
+        assert_eq!(plan.evict, vec![0, 2]);
+        assert!(plan.fits);
+    }
+
+    #[test]
+    fn test_link_held_elsewhere_is_not_evicted() {
+        // One link is pinned, so the file stays whatever happens to the other
+        let candidates = vec![
+            linked(candidate(100, true, false, 30), 7, 2),
+            linked(candidate(100, true, true, 10), 7, 2),
+            candidate(30, true, false, 20),
+        ];
+
+        let plan = plan_evictions(&candidates, 130, 0, 50, EvictionPolicy::Played);
+
+        assert_eq!(plan.evict, vec![2]);
+        assert!(!plan.fits);
+    }
+
+    #[test]
+    fn test_linked_files_count_once() {
+        let file = Some(FileId::for_test(7));
+
+        assert_eq!(total_size([(file, 100), (file, 100), (None, 30)]), 130);
+        assert_eq!(freed_size([(file, 2, 100)]), 0);
+        assert_eq!(
+            freed_size([(file, 2, 100), (file, 2, 100), (None, 1, 30)]),
+            130
+        );
+    }
+
+    #[test]
+    fn test_usage_ratio_and_quota_bytes() {
+        assert_eq!(StorageUsage::quota_bytes(Some(0)), None);
+        assert_eq!(StorageUsage::quota_bytes(Some(2)), Some(2 * BYTES_PER_GB));
+
+        let usage = StorageUsage {
+            used: 3 * BYTES_PER_GB,
+            quota: Some(4 * BYTES_PER_GB),
+        };
+        assert_eq!(usage.ratio(), Some(0.75));
+        assert_eq!(StorageUsage::default().ratio(), None);
+    }
+}
@@ -0,0 +1,388 @@
+// Download retention
+//
+// Decides which downloads the cleanup pass removes, on startup and after each
+// refresh. A podcast's own retention setting wins; podcasts without one keep
+// their newest `downloads.keep_latest` downloads and lose those older than
+// `downloads.cleanup_after_days`. Independently, downloads played more than
+// `downloads.delete_played_after_days` ago go (favourites are kept, as is
+// everything from a podcast set to keep all), and whatever then remains over
+// `downloads.max_storage_gb` is evicted as the quota would. The same plan
+// backs `:cleanup-preview`, which only lists what would be removed.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+
+use super::quota::{self, EvictionCandidate, EvictionPolicy, FileId};
+use crate::config::DownloadConfig;
+use crate::podcast::RetentionPolicy;
+use crate::storage::{EpisodeId, PodcastId};
+use crate::utils::fs::format_file_size;
+
+const SECONDS_PER_DAY: u64 = 24 * 3600;
+
+/// App-wide retention rules from the downloads config (0 disables a rule)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RetentionRules {
+    /// Newest downloads kept per podcast without its own setting
+    pub keep_latest: Option<u32>,
+    /// Age in days at which downloads of podcasts without their own setting go
+    pub cleanup_after_days: Option<u32>,
+    /// Days after being played that a download goes
+    pub delete_played_after_days: Option<u32>,
+}
+
+impl RetentionRules {
+    pub fn from_config(config: &DownloadConfig) -> Self {
+        Self {
+            keep_latest: config.keep_latest.filter(|n| *n > 0),
+            cleanup_after_days: config.cleanup_after_days.filter(|d| *d > 0),
+            delete_played_after_days: config.delete_played_after_days.filter(|d| *d > 0),
+        }
+    }
+}
+
+/// Why a download is removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupReason {
+    /// Beyond the newest N downloads of its podcast
+    KeepLatest(u32),
+    /// Downloaded more than N days ago
+    OlderThanDays(u32),
+    /// Played more than N days ago
+    PlayedDaysAgo(u32),
+    /// Evicted to get back under `max_storage_gb`
+    OverQuota,
+}
+
+impl CleanupReason {
+    pub fn label(&self) -> String {
+        match self {
+            Self::KeepLatest(count) => format!("beyond the newest {}", count),
+            Self::OlderThanDays(days) => format!("downloaded over {} days ago", days),
+            Self::PlayedDaysAgo(days) => format!("played over {} days ago", days),
+            Self::OverQuota => "over the storage cap".to_string(),
+        }
+    }
+}
+
+/// A downloaded episode as the retention rules see it
+#[derive(Debug, Clone)]
+pub struct RetainedDownload {
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+    pub podcast_title: String,
+    pub title: String,
+    pub published: DateTime<Utc>,
+    pub played_at: Option<DateTime<Utc>>,
+    pub played: bool,
+    pub favorited: bool,
+    pub size: u64,
+    /// When the file was written, used as its download age
+    pub modified: SystemTime,
+    /// The file on disk, shared with other downloads when hard-linked
+    pub file: Option<FileId>,
+    /// Links to the file
+    pub links: u64,
+}
+
+/// A download the cleanup pass removes, and why
+#[derive(Debug, Clone)]
+pub struct CleanupItem {
+    pub download: RetainedDownload,
+    pub reason: CleanupReason,
+}
+
+/// Everything one cleanup pass would remove
+#[derive(Debug, Clone, Default)]
+pub struct CleanupPlan {
+    pub items: Vec<CleanupItem>,
+    /// Space taken by downloads before the pass
+    pub used: u64,
+    pub quota: Option<u64>,
+}
+
+impl CleanupPlan {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Bytes the pass frees; a hard-linked file only once all its links go
+    pub fn freed(&self) -> u64 {
+        quota::freed_size(self.items.iter().map(|item| {
+            let download = &item.download;
+            (download.file, download.links, download.size)
+        }))
+    }
+
+    /// One-line account, e.g. "3 download(s), 120.0 MB"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} download(s), {}",
+            self.items.len(),
+            format_file_size(self.freed())
+        )
+    }
+}
+
+fn older_than(time: SystemTime, days: u32, now: SystemTime) -> bool {
+    now.duration_since(time)
+        .is_ok_and(|age| age > Duration::from_secs(days as u64 * SECONDS_PER_DAY))
+}
+
+/// Split one podcast's downloads into those its retention removes and those
+/// it keeps. `policy` is the podcast's own setting.
+pub fn plan_podcast(
+    policy: Option<RetentionPolicy>,
+    rules: &RetentionRules,
+    mut downloads: Vec<RetainedDownload>,
+    now: SystemTime,
+) -> (Vec<CleanupItem>, Vec<RetainedDownload>) {
+    downloads.sort_by_key(|download| std::cmp::Reverse(download.published));
+
+    let (keep_latest, max_age_days) = match policy {
+        Some(RetentionPolicy::KeepAll) => (None, None),
+        Some(RetentionPolicy::KeepLatest(count)) => (Some(count), None),
+        Some(RetentionPolicy::DeleteAfterDays(days)) => (None, Some(days)),
+        None => (rules.keep_latest, rules.cleanup_after_days),
+    };
+    let played_days = rules
+        .delete_played_after_days
+        .filter(|_| policy != Some(RetentionPolicy::KeepAll));
+    let now_utc = DateTime::<Utc>::from(now);
+
+    let mut removed = Vec::new();
+    let mut kept = Vec::new();
+    for (rank, download) in downloads.into_iter().enumerate() {
+        let reason = match (keep_latest, max_age_days, played_days) {
+            (Some(count), _, _) if rank >= count as usize => Some(CleanupReason::KeepLatest(count)),
+            (_, Some(days), _) if older_than(download.modified, days, now) => {
+                Some(CleanupReason::OlderThanDays(days))
+            }
+            (_, _, Some(days))
+                if !download.favorited
+                    && download.played_at.is_some_and(|played_at| {
+                        now_utc - played_at > chrono::Duration::days(days as i64)
+                    }) =>
+            {
+                Some(CleanupReason::PlayedDaysAgo(days))
+            }
+            _ => None,
+        };
+        match reason {
+            Some(reason) => removed.push(CleanupItem { download, reason }),
+            None => kept.push(download),
+        }
+    }
+    (removed, kept)
+}
+
+/// Downloads to evict from `kept` so that what remains of `used` after
+/// `removed` go fits in `quota`
+pub fn plan_over_quota(
+    kept: Vec<RetainedDownload>,
+    used: u64,
+    removed: &[CleanupItem],
+    quota: u64,
+    policy: EvictionPolicy,
+) -> Vec<CleanupItem> {
+    let mut removed_links: HashMap<FileId, u64> = HashMap::new();
+    for item in removed {
+        if let Some(file) = item.download.file {
+            *removed_links.entry(file).or_insert(0) += 1;
+        }
+    }
+    let freed = quota::freed_size(removed.iter().map(|item| {
+        let download = &item.download;
+        (download.file, download.links, download.size)
+    }));
+    let candidates: Vec<EvictionCandidate> = kept
+        .iter()
+        .map(|download| EvictionCandidate {
+            podcast_id: download.podcast_id.clone(),
+            episode_id: download.episode_id.clone(),
+            path: Default::default(),
+            size: download.size,
+            played: download.played,
+            pinned: download.favorited,
+            modified: download.modified,
+            file: download.file,
+            // Links the rules already remove don't hold the file any more
+            links: download.links.saturating_sub(
+                download
+                    .file
+                    .and_then(|file| removed_links.get(&file).copied())
+                    .unwrap_or(0),
+            ),
+        })
+        .collect();
+    let plan = quota::plan_evictions(&candidates, used.saturating_sub(freed), 0, quota, policy);
+
+    let mut kept: Vec<Option<RetainedDownload>> = kept.into_iter().map(Some).collect();
+    plan.evict
+        .into_iter()
+        .filter_map(|i| kept[i].take())
+        .map(|download| CleanupItem {
+            download,
+            reason: CleanupReason::OverQuota,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn download(title: &str, published_days_ago: i64, age_days: u64) -> RetainedDownload {
+        RetainedDownload {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            podcast_title: "Show".to_string(),
+            title: title.to_string(),
+            published: Utc::now() - chrono::Duration::days(published_days_ago),
+            played_at: None,
+            played: false,
+            favorited: false,
+            size: 10,
+            modified: SystemTime::now() - Duration::from_secs(age_days * SECONDS_PER_DAY),
+            file: None,
+            links: 1,
+        }
+    }
+
+    fn played(mut download: RetainedDownload, days_ago: i64) -> RetainedDownload {
+        download.played = true;
+        download.played_at = Some(Utc::now() - chrono::Duration::days(days_ago));
+        download
+    }
+
+    fn titles(items: &[CleanupItem]) -> Vec<(&str, CleanupReason)> {
+        items
+            .iter()
+            .map(|item| (item.download.title.as_str(), item.reason))
+            .collect()
+    }
+
+    #[test]
+    fn test_app_wide_rules_keep_latest_and_drop_long_played() {
+        // Arrange
+        let rules = RetentionRules {
+            keep_latest: Some(2),
+            cleanup_after_days: None,
+            delete_played_after_days: Some(7),
+        };
+        let downloads = vec![
+            download("Oldest", 30, 1),
+            played(download("Newest", 1, 1), 10),
+            download("Middle", 10, 1),
+        ];
+
+        // Act
+        let (removed, kept) = plan_podcast(None, &rules, downloads, SystemTime::now());
+
+        // Assert
+        assert_eq!(
+            titles(&removed),
+            vec![
+                ("Newest", CleanupReason::PlayedDaysAgo(7)),
+                ("Oldest", CleanupReason::KeepLatest(2)),
+            ]
+        );
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].title, "Middle");
+    }
+
+    #[test]
+    fn test_podcast_setting_overrides_app_wide_rules() {
+        let rules = RetentionRules {
+            keep_latest: Some(1),
+            cleanup_after_days: Some(7),
+            delete_played_after_days: Some(7),
+        };
+        let downloads = || vec![played(download("Recent", 1, 1), 30), download("Old", 5, 40)];
+
+        let (keep_all, _) = plan_podcast(
+            Some(RetentionPolicy::KeepAll),
+            &rules,
+            downloads(),
+            SystemTime::now(),
+        );
+        let (after_days, _) = plan_podcast(
+            Some(RetentionPolicy::DeleteAfterDays(60)),
+            &rules,
+            downloads(),
+            SystemTime::now(),
+        );
+
+        assert!(keep_all.is_empty());
+        assert_eq!(
+            titles(&after_days),
+            vec![("Recent", CleanupReason::PlayedDaysAgo(7))]
+        );
+    }
+
+    #[test]
+    fn test_favourites_outlive_the_played_rule() {
+        let rules = RetentionRules {
+            delete_played_after_days: Some(7),
+            ..RetentionRules::default()
+        };
+        let mut favourite = played(download("Favourite", 1, 1), 30);
+        favourite.favorited = true;
+
+        let (removed, kept) = plan_podcast(None, &rules, vec![favourite], SystemTime::now());
+
+        assert!(removed.is_empty());
+        assert_eq!(kept.len(), 1);
+    }
+
+    #[test]
+    fn test_over_quota_evicts_after_what_the_rules_freed() {
+        let kept = vec![
+            played(download("Played", 1, 20), 1),
+            download("Unplayed", 2, 30),
+        ];
+
+        // 40 in use, 10 already freed by the rules, cap of 25
+        let removed = vec![CleanupItem {
+            download: download("Removed", 3, 40),
+            reason: CleanupReason::OlderThanDays(30),
+        }];
+        let items = plan_over_quota(kept, 40, &removed, 25, EvictionPolicy::Played);
+
+        assert_eq!(titles(&items), vec![("Played", CleanupReason::OverQuota)]);
+    }
+
+    #[test]
+    fn test_removing_one_link_of_a_shared_file_frees_nothing() {
+        // Arrange — the rules remove one of two links to a file
+        let shared = |title| {
+            let mut download = played(download(title, 1, 1), 1);
+            download.file = Some(FileId::for_test(7));
+            download.links = 2;
+            download
+        };
+        let plan = CleanupPlan {
+            items: vec![CleanupItem {
+                download: shared("Old link"),
+                reason: CleanupReason::KeepLatest(1),
+            }],
+            used: 10,
+            quota: Some(5),
+        };
+
+        // Act
+        let items = plan_over_quota(
+            vec![shared("New link")],
+            plan.used,
+            &plan.items,
+            5,
+            EvictionPolicy::Played,
+        );
+
+        // Assert — the other link is evicted, which finally frees the file
+        assert_eq!(plan.freed(), 0);
+        assert_eq!(titles(&items), vec![("New link", CleanupReason::OverQuota)]);
+    }
+}
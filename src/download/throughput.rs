@@ -0,0 +1,139 @@
+// Download throughput - speed samples behind the Downloads buffer's graph
+//
+// Each progress update of a running download becomes a speed sample: the
+// bytes fetched since the previous update over the time between them. The
+// last samples draw a sparkline, and the average and peak tell a slow host
+// (one download crawls while others are fast) from a slow local network
+// (every download crawls, and their combined speed is flat).
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use crate::constants::downloads::SPEED_HISTORY_SAMPLES;
+use crate::utils::fs::format_file_size;
+
+/// Speed samples of one download, newest last
+#[derive(Debug, Clone, Default)]
+pub struct SpeedHistory {
+    /// Bytes per second, at most `SPEED_HISTORY_SAMPLES` of them
+    samples: VecDeque<u64>,
+    /// First progress update: time and bytes, for the average
+    started: Option<(Instant, u64)>,
+    /// Latest progress update
+    last: Option<(Instant, u64)>,
+    peak: u64,
+}
+
+impl SpeedHistory {
+    /// Record that `downloaded` bytes had been fetched at `at`
+    pub fn record(&mut self, downloaded: u64, at: Instant) {
+        match self.last {
+            // Restarted from scratch (retry without resume): start over
+            Some((_, bytes)) if downloaded < bytes => {
+                *self = Self::default();
+                self.started = Some((at, downloaded));
+            }
+            Some((time, bytes)) => {
+                let elapsed = at.saturating_duration_since(time).as_secs_f64();
+                if elapsed <= 0.0 {
+                    return;
+                }
+                let speed = ((downloaded - bytes) as f64 / elapsed) as u64;
+                if self.samples.len() == SPEED_HISTORY_SAMPLES {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back(speed);
+                self.peak = self.peak.max(speed);
+            }
+            None => self.started = Some((at, downloaded)),
+        }
+        self.last = Some((at, downloaded));
+    }
+
+    /// Speed samples, oldest first, for the sparkline
+    pub fn samples(&self) -> Vec<u64> {
+        self.samples.iter().copied().collect()
+    }
+
+    /// Latest speed in bytes per second
+    pub fn current(&self) -> Option<u64> {
+        self.samples.back().copied()
+    }
+
+    /// Average over the whole download in bytes per second
+    pub fn average(&self) -> Option<u64> {
+        let ((start, start_bytes), (end, end_bytes)) = (self.started?, self.last?);
+        let elapsed = end.saturating_duration_since(start).as_secs_f64();
+        (elapsed > 0.0).then(|| ((end_bytes - start_bytes) as f64 / elapsed) as u64)
+    }
+
+    /// Fastest sample in bytes per second
+    pub fn peak(&self) -> Option<u64> {
+        (!self.samples.is_empty()).then_some(self.peak)
+    }
+
+    /// "now 1.2 MB/s · avg 900.0 KB/s · peak 2.1 MB/s", leaving out what isn't known yet
+    pub fn summary(&self) -> String {
+        [
+            ("now", self.current()),
+            ("avg", self.average()),
+            ("peak", self.peak()),
+        ]
+        .iter()
+        .filter_map(|(label, speed)| {
+            speed.map(|speed| format!("{} {}", label, format_speed(speed)))
+        })
+        .collect::<Vec<_>>()
+        .join(" · ")
+    }
+}
+
+/// Bytes per second as e.g. "1.2 MB/s"
+pub fn format_speed(bytes_per_second: u64) -> String {
+    format!("{}/s", format_file_size(bytes_per_second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn test_speeds_come_from_consecutive_updates() {
+        // Arrange
+        let start = Instant::now();
+        let mut history = SpeedHistory::default();
+
+        // Act — 1 MB in the first second, 3 MB in the next
+        history.record(0, start);
+        history.record(MB, start + Duration::from_secs(1));
+        history.record(4 * MB, start + Duration::from_secs(2));
+
+        // Assert
+        assert_eq!(history.samples(), vec![MB, 3 * MB]);
+        assert_eq!(history.current(), Some(3 * MB));
+        assert_eq!(history.average(), Some(2 * MB));
+        assert_eq!(history.peak(), Some(3 * MB));
+        assert_eq!(
+            history.summary(),
+            "now 3.0 MB/s · avg 2.0 MB/s · peak 3.0 MB/s"
+        );
+    }
+
+    #[test]
+    fn test_history_is_capped_and_restarts_when_bytes_go_back() {
+        let start = Instant::now();
+        let mut history = SpeedHistory::default();
+        for second in 0..=(SPEED_HISTORY_SAMPLES as u64 + 5) {
+            history.record(second * MB, start + Duration::from_secs(second));
+        }
+        assert_eq!(history.samples().len(), SPEED_HISTORY_SAMPLES);
+
+        history.record(0, start + Duration::from_secs(100));
+
+        assert!(history.samples().is_empty());
+        assert_eq!(history.summary(), "");
+    }
+}
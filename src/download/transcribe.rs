@@ -0,0 +1,260 @@
+// Episode transcription via whisper.cpp
+//
+// whisper.cpp wants 16 kHz mono WAV, so ffmpeg decodes the download first;
+// whisper then writes a plain-text transcript next to it. This module owns
+// both commands and the output handling. `DownloadManager::transcribe_episode`
+// stores the result on the episode, where the episode detail buffer and
+// search pick it up.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+
+use crate::config::TranscriptionConfig;
+
+/// Executable used when `transcription.whisper_path` is not set
+pub const DEFAULT_WHISPER: &str = "whisper-cli";
+
+/// Input format whisper.cpp requires
+const SAMPLE_RATE: &str = "16000";
+
+/// ffmpeg arguments decoding `input` to the WAV whisper reads
+pub fn decode_args(input: &Path, wav: &Path) -> Vec<OsString> {
+    let mut args: Vec<OsString> = ["-hide_banner", "-nostdin", "-loglevel", "error", "-y", "-i"]
+        .iter()
+        .map(OsString::from)
+        .collect();
+    args.push(input.as_os_str().to_owned());
+    args.extend(
+        [
+            "-map",
+            "0:a:0",
+            "-ar",
+            SAMPLE_RATE,
+            "-ac",
+            "1",
+            "-c:a",
+            "pcm_s16le",
+        ]
+        .map(OsString::from),
+    );
+    args.push(wav.as_os_str().to_owned());
+    args
+}
+
+/// whisper.cpp arguments transcribing `wav` to `<output_base>.txt`, with
+/// progress on stderr
+pub fn whisper_args(
+    config: &TranscriptionConfig,
+    model: &Path,
+    wav: &Path,
+    output_base: &Path,
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec!["-m".into(), model.as_os_str().to_owned()];
+    args.push("-f".into());
+    args.push(wav.as_os_str().to_owned());
+    args.extend(["-l", config.language.as_str(), "-otxt", "-pp"].map(OsString::from));
+    args.push("-of".into());
+    args.push(output_base.as_os_str().to_owned());
+    if let Some(threads) = config.threads {
+        args.push("-t".into());
+        args.push(threads.to_string().into());
+    }
+    args
+}
+
+/// Percent complete from a whisper.cpp `-pp` line
+/// ("whisper_print_progress_callback: progress =  40%")
+fn parse_progress_line(line: &str) -> Option<u8> {
+    let (_, rest) = line.split_once("progress =")?;
+    let percent: u8 = rest.trim().trim_end_matches('%').trim().parse().ok()?;
+    Some(percent.min(100))
+}
+
+/// Segment lines from whisper's `.txt` output, trimmed, without blanks
+pub fn clean_transcript(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Start `program`, naming the config setting to fix when it is missing
+fn spawn(
+    command: &mut Command,
+    program: &str,
+    setting: &str,
+) -> Result<tokio::process::Child, String> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!("'{}' not found; install it or set {}", program, setting)
+            } else {
+                format!("Could not start {}: {}", program, e)
+            }
+        })
+}
+
+/// Wait for `child`, reading stderr line by line; the last line explains a failure
+async fn finish(
+    mut child: tokio::process::Child,
+    program: &str,
+    mut on_line: impl FnMut(&str),
+) -> Result<(), String> {
+    let stderr = child.stderr.take().expect("stderr is piped");
+    let mut lines = BufReader::new(stderr).lines();
+    let mut last_line = String::new();
+    while let Ok(Some(line)) = lines.next_line().await {
+        on_line(&line);
+        if !line.trim().is_empty() {
+            last_line = line;
+        }
+    }
+
+    let status = child.wait().await.map_err(|e| e.to_string())?;
+    if status.success() {
+        Ok(())
+    } else {
+        let detail = if last_line.is_empty() {
+            "no error output"
+        } else {
+            last_line.trim()
+        };
+        Err(format!("{} exited with {}: {}", program, status, detail))
+    }
+}
+
+/// Transcribe `input`, reporting whole-percent progress. Intermediate files
+/// go next to the input and are removed afterwards.
+pub async fn transcribe(
+    ffmpeg: &str,
+    config: &TranscriptionConfig,
+    input: &Path,
+    mut on_progress: impl FnMut(u8) + Send,
+) -> Result<String, String> {
+    let model = config
+        .model
+        .as_deref()
+        .map(|model| PathBuf::from(shellexpand::tilde(model).as_ref()))
+        .ok_or_else(|| "No whisper model configured (transcription.model)".to_string())?;
+    if !model.exists() {
+        return Err(format!("Whisper model {} not found", model.display()));
+    }
+    let whisper = config.whisper_path.as_deref().unwrap_or(DEFAULT_WHISPER);
+
+    let stem = input
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let wav = input.with_file_name(format!("{}.transcribing.wav", stem));
+    let output_base = input.with_file_name(format!("{}.transcribing", stem));
+    let text_path = input.with_file_name(format!("{}.transcribing.txt", stem));
+
+    let result = async {
+        let child = spawn(
+            Command::new(ffmpeg).args(decode_args(input, &wav)),
+            ffmpeg,
+            "downloads.ffmpeg_path",
+        )?;
+        finish(child, "ffmpeg", |_| {}).await?;
+
+        let child = spawn(
+            Command::new(whisper).args(whisper_args(config, &model, &wav, &output_base)),
+            whisper,
+            "transcription.whisper_path",
+        )?;
+        let mut last_percent = None;
+        finish(child, whisper, |line| {
+            if let Some(percent) = parse_progress_line(line) {
+                if last_percent != Some(percent) {
+                    last_percent = Some(percent);
+                    on_progress(percent);
+                }
+            }
+        })
+        .await?;
+
+        let text = tokio::fs::read_to_string(&text_path)
+            .await
+            .map_err(|e| format!("No transcript was written: {}", e))?;
+        Ok(clean_transcript(&text))
+    }
+    .await;
+
+    let _ = tokio::fs::remove_file(&wav).await;
+    let _ = tokio::fs::remove_file(&text_path).await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whisper_args_include_model_language_output_and_threads() {
+        let config = TranscriptionConfig {
+            language: "en".to_string(),
+            threads: Some(4),
+            ..TranscriptionConfig::default()
+        };
+
+        let args = whisper_args(
+            &config,
+            Path::new("/models/base.bin"),
+            Path::new("/tmp/ep.wav"),
+            Path::new("/tmp/ep.transcribing"),
+        );
+
+        let args: Vec<String> = args
+            .iter()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "-m",
+                "/models/base.bin",
+                "-f",
+                "/tmp/ep.wav",
+                "-l",
+                "en",
+                "-otxt",
+                "-pp",
+                "-of",
+                "/tmp/ep.transcribing",
+                "-t",
+                "4"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(
+            parse_progress_line("whisper_print_progress_callback: progress =  40%"),
+            Some(40)
+        );
+        assert_eq!(parse_progress_line("progress = 100%"), Some(100));
+        assert_eq!(
+            parse_progress_line("whisper_init_from_file: loading model"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_clean_transcript_drops_blank_lines_and_padding() {
+        assert_eq!(
+            clean_transcript(" Hello and welcome.\n\n  To the show.\n"),
+            "Hello and welcome.\nTo the show."
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// Video-as-podcast downloads via yt-dlp
+//
+// Feeds built from YouTube channels (natively or through RSS-Bridge) point
+// episodes at video pages instead of audio files. Those URLs are handed to
+// yt-dlp, which fetches the audio track. This module owns the yt-dlp side:
+// URL matching, argument building and progress parsing.
+// `DownloadManager::download_episode` decides when to use it.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Command;
+
+/// Executable used when `downloads.ytdlp_path` is not set
+pub const DEFAULT_YTDLP: &str = "yt-dlp";
+
+/// Extension given to audio fetched by yt-dlp (it prefers an m4a stream)
+pub const AUDIO_EXTENSION: &str = "m4a";
+
+/// Hosts always routed through yt-dlp
+const VIDEO_HOSTS: &[&str] = &["youtube.com", "youtu.be", "youtube-nocookie.com"];
+
+/// Marks our progress lines on stdout
+const PROGRESS_PREFIX: &str = "ytdlp-progress";
+
+/// Whether `url` is on `host` or one of its subdomains
+fn host_matches(url: &str, host: &str) -> bool {
+    let Some(url_host) = url::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+    else {
+        return false;
+    };
+    let host = host.trim().trim_start_matches('.').to_ascii_lowercase();
+    !host.is_empty() && (url_host == host || url_host.ends_with(&format!(".{}", host)))
+}
+
+/// Whether `url` is a video page on a built-in host (YouTube)
+pub fn is_video_page(url: &str) -> bool {
+    VIDEO_HOSTS.iter().any(|host| host_matches(url, host))
+}
+
+/// Whether `url` should be fetched with yt-dlp rather than plain HTTP.
+/// `extra_hosts` comes from `downloads.ytdlp_hosts`.
+pub fn handles(url: &str, extra_hosts: &[String]) -> bool {
+    is_video_page(url) || extra_hosts.iter().any(|host| host_matches(url, host))
+}
+
+/// yt-dlp arguments fetching the audio of `url` into exactly `output`.
+/// `extra` (from `downloads.ytdlp_args`) comes after the defaults so it can
+/// override them, e.g. `["-f", "bestaudio"]` or `["--cookies", "..."]`.
+pub fn ytdlp_args(url: &str, output: &Path, extra: &[String]) -> Vec<OsString> {
+    let progress_template = format!(
+        "download:{} %(progress.downloaded_bytes)s %(progress.total_bytes)s %(progress.total_bytes_estimate)s",
+        PROGRESS_PREFIX
+    );
+    let mut args: Vec<OsString> = [
+        "--no-playlist",
+        "--quiet",
+        "--no-warnings",
+        "--progress",
+        "--newline",
+        "--progress-template",
+        &progress_template,
+        "-f",
+        "bestaudio[ext=m4a]/bestaudio",
+        "-o",
+    ]
+    .iter()
+    .map(OsString::from)
+    .collect();
+    // -o is a template; escape % so the literal path is used
+    args.push(OsString::from(output.to_string_lossy().replace('%', "%%")));
+    args.extend(extra.iter().map(OsString::from));
+    args.push(OsString::from("--"));
+    args.push(OsString::from(url));
+    args
+}
+
+/// Bytes downloaded and total (exact or estimated) from one progress line
+fn parse_progress_line(line: &str) -> Option<(u64, Option<u64>)> {
+    let mut fields = line
+        .trim()
+        .strip_prefix(PROGRESS_PREFIX)?
+        .split_whitespace();
+    // Byte counts print as "NA" when unknown; estimates may be fractional
+    let number = |field: Option<&str>| {
+        field
+            .and_then(|value| value.parse::<f64>().ok())
+            .map(|value| value as u64)
+    };
+    let downloaded = number(fields.next())?;
+    let total = number(fields.next());
+    let estimate = number(fields.next());
+    Some((downloaded, total.or(estimate)))
+}
+
+/// Run yt-dlp, reporting (downloaded, total) bytes as it goes
+pub async fn fetch(
+    ytdlp: &str,
+    url: &str,
+    output: &Path,
+    extra: &[String],
+    mut on_progress: impl FnMut(u64, Option<u64>) + Send,
+) -> Result<(), String> {
+    let mut child = Command::new(ytdlp)
+        .args(ytdlp_args(url, output, extra))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!(
+                    "'{}' not found; install yt-dlp or set downloads.ytdlp_path",
+                    ytdlp
+                )
+            } else {
+                format!("Could not start {}: {}", ytdlp, e)
+            }
+        })?;
+
+    // Drain stderr alongside stdout so yt-dlp can't block on a full pipe
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stderr_task = tokio::spawn(async move {
+        let mut text = String::new();
+        let _ = stderr.read_to_string(&mut text).await;
+        text
+    });
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Some((downloaded, total)) = parse_progress_line(&line) {
+            on_progress(downloaded, total);
+        }
+    }
+
+    let status = child.wait().await.map_err(|e| e.to_string())?;
+    let stderr = stderr_task.await.unwrap_or_default();
+    if status.success() {
+        Ok(())
+    } else {
+        let detail = stderr.lines().last().unwrap_or("no error output").trim();
+        Err(format!("yt-dlp exited with {}: {}", status, detail))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handles_youtube_and_configured_hosts() {
+        assert!(handles("https://www.youtube.com/watch?v=abc123", &[]));
+        assert!(handles("https://youtu.be/abc123", &[]));
+        assert!(!handles("https://cdn.example.com/ep1.mp3", &[]));
+        assert!(!handles("https://notyoutube.com/watch?v=abc", &[]));
+        assert!(handles(
+            "https://vimeo.com/12345",
+            &["vimeo.com".to_string()]
+        ));
+        assert!(!handles("not a url", &["vimeo.com".to_string()]));
+    }
+
+    #[test]
+    fn test_ytdlp_args_end_with_url_and_keep_extras() {
+        let extra = vec!["--cookies".to_string(), "cookies.txt".to_string()];
+        let args: Vec<String> =
+            ytdlp_args("https://youtu.be/abc", Path::new("/tmp/100%.m4a"), &extra)
+                .into_iter()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect();
+
+        let output = args.iter().position(|a| a == "-o").unwrap();
+        assert_eq!(args[output + 1], "/tmp/100%%.m4a");
+        let cookies = args.iter().position(|a| a == "--cookies").unwrap();
+        assert!(cookies > output);
+        assert_eq!(&args[args.len() - 2..], ["--", "https://youtu.be/abc"]);
+    }
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(
+            parse_progress_line("ytdlp-progress 1024 4096 NA"),
+            Some((1024, Some(4096)))
+        );
+        assert_eq!(
+            parse_progress_line("ytdlp-progress 1024 NA 8000.5"),
+            Some((1024, Some(8000)))
+        );
+        assert_eq!(
+            parse_progress_line("ytdlp-progress 1024 NA NA"),
+            Some((1024, None))
+        );
+        assert_eq!(parse_progress_line("[youtube] abc: Downloading"), None);
+    }
+}
@@ -2,11 +2,16 @@ pub mod app;
 pub mod audio;
 pub mod config;
 pub mod constants;
+pub mod crash;
+pub mod daemon;
 pub mod download;
 pub mod playlist;
 pub mod podcast;
+pub mod search;
+pub mod settings_bundle;
 pub mod storage;
 pub mod ui;
+pub mod update_check;
 pub mod utils;
 
 // Re-export commonly used types
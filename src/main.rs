@@ -5,7 +5,13 @@ use crossterm::{
     style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
     terminal::{Clear, ClearType},
 };
-use podcast_tui::{app::App, config::Config, InitStatus};
+use podcast_tui::{
+    app::{App, Services},
+    config::Config,
+    daemon::Daemon,
+    storage::StorageAccess,
+    InitStatus,
+};
 use std::io::{stdout, Write};
 use tokio::sync::mpsc;
 
@@ -436,10 +442,74 @@ async fn main() -> Result<()> {
                 .short('c')
                 .long("config")
                 .value_name("FILE")
+                .global(true)
                 .help("Sets a custom config file"),
         )
+        .arg(
+            Arg::new("set")
+                .long("set")
+                .value_name("SECTION.KEY=VALUE")
+                .action(clap::ArgAction::Append)
+                .global(true)
+                .help("Overrides a config key for this run (repeatable); PODCAST_TUI__SECTION__KEY environment variables do the same"),
+        )
+        .arg(
+            Arg::new("read-only")
+                .long("read-only")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("force")
+                .help("Browse without saving anything, alongside another instance or the daemon"),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .action(clap::ArgAction::SetTrue)
+                .global(true)
+                .help("Take over the data directory's lock from another instance (only if it has stopped)"),
+        )
+        .subcommand(
+            Command::new("daemon")
+                .about("Refresh, auto-download and clean up on a schedule without the TUI (for servers and NAS boxes)")
+                .arg(
+                    Arg::new("once")
+                        .long("once")
+                        .action(clap::ArgAction::SetTrue)
+                        .help("Run a single pass and exit (e.g. from cron)"),
+                ),
+        )
         .get_matches();
 
+    let config_path = matches.get_one::<String>("config");
+    let set: Vec<String> = matches
+        .get_many::<String>("set")
+        .unwrap_or_default()
+        .cloned()
+        .collect();
+    let access = if matches.get_flag("read-only") {
+        StorageAccess::ReadOnly
+    } else if matches.get_flag("force") {
+        StorageAccess::Force
+    } else {
+        StorageAccess::Exclusive
+    };
+
+    if let Some(daemon_args) = matches.subcommand_matches("daemon") {
+        if access == StorageAccess::ReadOnly {
+            anyhow::bail!("The daemon refreshes and downloads, so it can't run with --read-only");
+        }
+        let (config, issues) = Config::load_with_overrides(config_path, &set)?;
+        for issue in &issues {
+            eprintln!("[config] {issue}");
+        }
+        let services = Services::start(&config, access, "daemon").await?;
+        let daemon = Daemon::new(config, services);
+        if daemon_args.get_flag("once") {
+            println!("{}", daemon.run_once().await.summary());
+            return Ok(());
+        }
+        return daemon.run().await;
+    }
+
     // Create channel for initialization status updates
     let (status_tx, mut status_rx) = mpsc::unbounded_channel::<InitStatus>();
 
@@ -462,11 +532,23 @@ async fn main() -> Result<()> {
     // Load configuration
     update_splash_status(InitStatus::LoadingConfig.message())?;
 
-    let config_path = matches.get_one::<String>("config");
-    let config = Config::load_or_default(config_path)?;
-
-    // Initialize app with status updates
-    let mut app = App::new_with_progress(config, status_tx.clone()).await?;
+    let (config, config_issues) = Config::load_with_overrides(config_path, &set)?;
+
+    // Initialize app with status updates; on failure (e.g. the data
+    // directory is locked) clear the splash so the error reads cleanly
+    let mut app = match App::new_with_progress(config, access, status_tx.clone()).await {
+        Ok(app) => app,
+        Err(e) => {
+            execute!(
+                stdout,
+                Clear(ClearType::All),
+                cursor::MoveTo(0, 0),
+                cursor::Show
+            )?;
+            return Err(e);
+        }
+    };
+    app.set_config_issues(config_issues);
 
     // Send final status
     status_tx.send(InitStatus::Complete).ok();
@@ -105,6 +105,7 @@ impl TodayGenerator {
             created: now,
             last_updated: now,
             smart_rules: None,
+            playback: Default::default(),
         };
         self.storage
             .save_playlist(&playlist)
@@ -127,6 +128,10 @@ impl TodayGenerator {
 
         let mut eligible = Vec::new();
         for podcast_id in podcast_ids {
+            // Unsubscribed podcasts awaiting purge don't contribute to Today
+            if matches!(self.storage.load_podcast(&podcast_id).await, Ok(p) if p.is_removed()) {
+                continue;
+            }
             let episodes = self
                 .storage
                 .load_episodes(&podcast_id)
@@ -325,7 +330,7 @@ impl TodayGenerator {
 mod tests {
     use super::*;
     use crate::config::DownloadConfig;
-    use crate::podcast::{Episode, EpisodeStatus, Podcast};
+    use crate::podcast::{DownloadState, Episode, Podcast};
     use crate::storage::{EpisodeId, PodcastId};
     use tempfile::TempDir;
 
@@ -397,7 +402,7 @@ mod tests {
             tokio::fs::write(&file, b"audio")
                 .await
                 .expect("Failed to write episode file");
-            episode.status = EpisodeStatus::Downloaded;
+            episode.status.download = DownloadState::Downloaded;
             episode.local_path = Some(file);
         }
 
@@ -423,6 +428,7 @@ mod tests {
             created: Utc::now(),
             last_updated: Utc::now(),
             smart_rules: None,
+            playback: Default::default(),
         };
         assert!(TodayGenerator::should_refresh(
             &playlist,
@@ -445,6 +451,7 @@ mod tests {
             created: Utc::now(),
             last_updated: Utc::now(),
             smart_rules: None,
+            playback: Default::default(),
         };
         assert!(TodayGenerator::should_refresh(
             &playlist,
@@ -0,0 +1,239 @@
+// Commute fill - picks episodes that fit a listening time budget
+//
+// `:commute 45m` evaluates a smart playlist rule over the library and keeps
+// the best candidates whose remaining time adds up to no more than the
+// budget. Favourites come first, then episodes already started, then the
+// rule's own order; each show contributes at most a few episodes and the
+// result is ordered so the same show doesn't play twice in a row.
+
+use std::collections::HashMap;
+
+use crate::podcast::{Episode, Podcast};
+use crate::storage::PodcastId;
+
+use super::{SmartFilter, SmartPlaylistRule};
+
+/// Episodes per show in one commute unless `--per-podcast` says otherwise
+pub const DEFAULT_MAX_PER_PODCAST: usize = 2;
+
+/// What to fill a commute with
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommuteRequest {
+    /// Listening time available, in seconds
+    pub budget_secs: u32,
+    /// Candidate filter and order; only downloaded, unplayed episodes qualify
+    pub rule: SmartPlaylistRule,
+    pub max_per_podcast: usize,
+}
+
+/// Episodes chosen for a commute, in play order
+#[derive(Debug, Clone, Default)]
+pub struct CommutePlan {
+    pub episodes: Vec<Episode>,
+    /// Listening time of the chosen episodes, in seconds
+    pub total_secs: u32,
+    /// Downloaded episodes left out because their length is unknown
+    pub skipped_unknown_duration: usize,
+}
+
+/// Time left to listen to `episode`, from its duration and saved position
+fn remaining_secs(episode: &Episode) -> Option<u32> {
+    let duration = episode.duration.filter(|d| *d > 0)?;
+    Some(duration.saturating_sub(episode.last_played_position.unwrap_or(0)))
+}
+
+impl CommuteRequest {
+    pub fn new(budget_secs: u32, rule: SmartPlaylistRule) -> Self {
+        Self {
+            budget_secs,
+            rule,
+            max_per_podcast: DEFAULT_MAX_PER_PODCAST,
+        }
+    }
+
+    /// Choose episodes from `episodes` that fit the budget
+    pub fn plan(&self, episodes: &[Episode], podcasts: &[Podcast]) -> CommutePlan {
+        // Only what can be played right now; the rule narrows and orders it
+        let rule = SmartPlaylistRule {
+            filter: SmartFilter::And(vec![SmartFilter::Downloaded, self.rule.filter.clone()]),
+            sort: self.rule.sort.clone(),
+            limit: None,
+        };
+        let mut candidates = rule.evaluate(episodes, podcasts);
+        let before = candidates.len();
+        candidates.retain(|episode| remaining_secs(episode).is_some_and(|secs| secs > 0));
+        let skipped_unknown_duration = before - candidates.len();
+
+        // Stable, so the rule's order decides within each group
+        candidates.sort_by_key(|episode| {
+            let started = episode.last_played_position.is_some_and(|p| p > 0);
+            (!episode.favorited, !started)
+        });
+
+        let mut chosen = Vec::new();
+        let mut per_podcast: HashMap<PodcastId, usize> = HashMap::new();
+        let mut total_secs = 0u32;
+        for episode in candidates {
+            if self.rule.limit.is_some_and(|limit| chosen.len() >= limit) {
+                break;
+            }
+            let secs = remaining_secs(&episode).unwrap_or(0);
+            let count = per_podcast.entry(episode.podcast_id.clone()).or_default();
+            if *count >= self.max_per_podcast || total_secs + secs > self.budget_secs {
+                continue;
+            }
+            *count += 1;
+            total_secs += secs;
+            chosen.push(episode);
+        }
+
+        CommutePlan {
+            episodes: interleave_podcasts(chosen),
+            total_secs,
+            skipped_unknown_duration,
+        }
+    }
+}
+
+/// Reorder so consecutive episodes come from different shows where possible,
+/// otherwise keeping the priority order
+fn interleave_podcasts(mut remaining: Vec<Episode>) -> Vec<Episode> {
+    let mut ordered: Vec<Episode> = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let previous = ordered.last().map(|episode| &episode.podcast_id);
+        let next = remaining
+            .iter()
+            .position(|episode| Some(&episode.podcast_id) != previous)
+            .unwrap_or(0);
+        ordered.push(remaining.remove(next));
+    }
+    ordered
+}
+
+/// Parse a commute length: `45` or `45m` (minutes), `1h`, `1h30m` or `1:30`
+pub fn parse_budget(input: &str) -> Option<u32> {
+    let input = input.trim().to_ascii_lowercase();
+    if let Some((hours, minutes)) = input.split_once(':') {
+        let hours: u32 = hours.parse().ok()?;
+        let minutes: u32 = minutes.parse().ok().filter(|m| *m < 60)?;
+        return Some((hours * 60 + minutes) * 60).filter(|secs| *secs > 0);
+    }
+
+    let (hours, rest) = match input.split_once('h') {
+        Some((hours, rest)) => (hours.parse::<u32>().ok()?, rest),
+        None => (0, input.as_str()),
+    };
+    let minutes = match rest.strip_suffix("min").or_else(|| rest.strip_suffix('m')) {
+        Some("") => return None,
+        Some(minutes) => minutes.parse::<u32>().ok()?,
+        None if rest.is_empty() => 0,
+        None => rest.parse::<u32>().ok()?,
+    };
+    Some((hours * 60 + minutes) * 60).filter(|secs| *secs > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::{DownloadState, PlayState};
+    use chrono::{Duration, Utc};
+
+    fn episode(podcast_id: &PodcastId, minutes: u32, age_days: i64) -> Episode {
+        let mut episode = Episode::new(
+            podcast_id.clone(),
+            format!("{minutes} min"),
+            "https://example.com/ep.mp3".to_string(),
+            Utc::now() - Duration::days(age_days),
+        );
+        episode.status.download = DownloadState::Downloaded;
+        episode.duration = Some(minutes * 60);
+        episode
+    }
+
+    fn request(minutes: u32) -> CommuteRequest {
+        CommuteRequest::new(minutes * 60, SmartPlaylistRule::new(SmartFilter::Unplayed))
+    }
+
+    #[test]
+    fn test_plan_fits_the_budget_newest_first() {
+        // Arrange
+        let (a, b, c) = (PodcastId::new(), PodcastId::new(), PodcastId::new());
+        let episodes = vec![
+            episode(&a, 30, 1),
+            episode(&b, 20, 2),
+            episode(&c, 10, 3),
+            episode(&c, 5, 4),
+        ];
+
+        // Act
+        let plan = request(45).plan(&episodes, &[]);
+
+        // Assert: 30 + 10 + 5; the 20 minute episode doesn't fit after 30
+        let titles: Vec<&str> = plan.episodes.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["30 min", "10 min", "5 min"]);
+        assert_eq!(plan.total_secs, 45 * 60);
+    }
+
+    #[test]
+    fn test_plan_puts_favourites_and_started_episodes_first() {
+        let (a, b) = (PodcastId::new(), PodcastId::new());
+        let mut favourite = episode(&a, 20, 10);
+        favourite.favorited = true;
+        let mut started = episode(&b, 40, 9);
+        started.last_played_position = Some(30 * 60);
+        let episodes = vec![episode(&a, 25, 1), started, favourite];
+
+        let plan = request(30).plan(&episodes, &[]);
+
+        let titles: Vec<&str> = plan.episodes.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["20 min", "40 min"]);
+        assert_eq!(plan.total_secs, 30 * 60);
+    }
+
+    #[test]
+    fn test_plan_limits_each_show_and_avoids_back_to_back() {
+        let (a, b) = (PodcastId::new(), PodcastId::new());
+        let episodes = vec![
+            episode(&a, 5, 1),
+            episode(&a, 5, 2),
+            episode(&a, 5, 3),
+            episode(&b, 5, 4),
+        ];
+
+        let plan = request(60).plan(&episodes, &[]);
+
+        assert_eq!(plan.episodes.len(), 3);
+        assert_eq!(plan.episodes[0].podcast_id, a);
+        assert_eq!(plan.episodes[1].podcast_id, b);
+        assert_eq!(plan.episodes[2].podcast_id, a);
+    }
+
+    #[test]
+    fn test_plan_skips_undownloaded_played_and_unknown_length() {
+        let podcast = PodcastId::new();
+        let mut not_downloaded = episode(&podcast, 5, 1);
+        not_downloaded.status.download = DownloadState::NotDownloaded;
+        let mut played = episode(&podcast, 5, 2);
+        played.status.play = PlayState::Played;
+        let mut unknown = episode(&podcast, 5, 3);
+        unknown.duration = None;
+
+        let plan = request(60).plan(&[not_downloaded, played, unknown], &[]);
+
+        assert!(plan.episodes.is_empty());
+        assert_eq!(plan.skipped_unknown_duration, 1);
+    }
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!(parse_budget("45"), Some(45 * 60));
+        assert_eq!(parse_budget("45m"), Some(45 * 60));
+        assert_eq!(parse_budget("45min"), Some(45 * 60));
+        assert_eq!(parse_budget("1h"), Some(60 * 60));
+        assert_eq!(parse_budget("1h30m"), Some(90 * 60));
+        assert_eq!(parse_budget("1:15"), Some(75 * 60));
+        assert_eq!(parse_budget("0"), None);
+        assert_eq!(parse_budget("1:75"), None);
+        assert_eq!(parse_budget("soon"), None);
+    }
+}
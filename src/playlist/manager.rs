@@ -1,8 +1,8 @@
 use crate::download::DownloadManager;
 use crate::playlist::{
     file_manager::{PlaylistFileError, PlaylistFileManager},
-    AutoPlaylistKind, Playlist, PlaylistEpisode, PlaylistId, PlaylistType, RefreshPolicy,
-    SmartPlaylistRule,
+    AutoPlaylistKind, PlaybackModes, Playlist, PlaylistEpisode, PlaylistId, PlaylistType,
+    RefreshPolicy, SmartPlaylistRule,
 };
 use crate::storage::{EpisodeId, JsonStorage, PodcastId, Storage};
 use chrono::Utc;
@@ -44,6 +44,31 @@ pub struct RebuildResult {
     pub failed: usize,
 }
 
+/// A playlist entry as it goes onto a device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistSyncEntry {
+    /// Device-relative path, numbered by position: `Playlists/<name>/003-….mp3`
+    pub device_path: std::path::PathBuf,
+    /// Audio to copy; `None` when the episode isn't downloaded
+    pub source: Option<std::path::PathBuf>,
+}
+
+/// Where a playlist's files go on a device, relative to its root
+pub fn playlist_device_dir(playlist_name: &str) -> std::path::PathBuf {
+    std::path::Path::new("Playlists").join(crate::utils::validation::sanitize_playlist_name(
+        playlist_name,
+    ))
+}
+
+/// Outcome of adding several episodes to a playlist at once
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AddEpisodesResult {
+    pub added: usize,
+    /// Already in the playlist, left where they were
+    pub already_present: usize,
+    pub failed: usize,
+}
+
 impl PlaylistManager {
     pub fn new(
         storage: Arc<JsonStorage>,
@@ -84,6 +109,7 @@ impl PlaylistManager {
             created: now,
             last_updated: now,
             smart_rules: None,
+            playback: Default::default(),
         };
 
         self.storage
@@ -126,6 +152,7 @@ impl PlaylistManager {
             created: now,
             last_updated: now,
             smart_rules: Some(rule),
+            playback: Default::default(),
         };
 
         self.storage
@@ -136,6 +163,38 @@ impl PlaylistManager {
         Ok(playlist)
     }
 
+    /// Create a smart playlist, or replace the rules of an existing one with the same name.
+    ///
+    /// Returns `true` if an existing playlist was updated.
+    pub async fn upsert_smart_playlist(
+        &self,
+        name: &str,
+        description: Option<String>,
+        rule: SmartPlaylistRule,
+    ) -> Result<bool, PlaylistError> {
+        match self.get_playlist_by_name(name.trim()).await {
+            Ok(mut playlist) => {
+                if Self::is_today_playlist(&playlist) {
+                    return Err(PlaylistError::AutoPlaylistReadOnly(playlist.name));
+                }
+                playlist.description = description;
+                playlist.smart_rules = Some(rule);
+                playlist.episodes.clear();
+                playlist.last_updated = Utc::now();
+                self.storage
+                    .save_playlist(&playlist)
+                    .await
+                    .map_err(|e| PlaylistError::Storage(e.to_string()))?;
+                Ok(true)
+            }
+            Err(PlaylistError::NotFound(_)) => {
+                self.create_smart_playlist(name, description, rule).await?;
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub async fn delete_playlist(&self, id: &PlaylistId) -> Result<(), PlaylistError> {
         let playlist = self.get_playlist(id).await?;
         if matches!(playlist.playlist_type, PlaylistType::AutoGenerated { .. }) {
@@ -224,6 +283,130 @@ impl PlaylistManager {
         Ok(playlist_episode)
     }
 
+    /// Append `episodes` in the given order, downloading those that aren't
+    /// yet. Episodes already in the playlist are skipped and one failing
+    /// doesn't stop the rest; only an unknown or read-only playlist is an error.
+    pub async fn add_episodes_to_playlist(
+        &self,
+        playlist_id: &PlaylistId,
+        episodes: &[(PodcastId, EpisodeId)],
+    ) -> Result<AddEpisodesResult, PlaylistError> {
+        let playlist = self.get_playlist(playlist_id).await?;
+        if matches!(playlist.playlist_type, PlaylistType::AutoGenerated { .. }) {
+            return Err(PlaylistError::AutoPlaylistReadOnly(playlist.name));
+        }
+
+        let mut result = AddEpisodesResult::default();
+        for (podcast_id, episode_id) in episodes {
+            match self
+                .add_episode_to_playlist(playlist_id, podcast_id, episode_id)
+                .await
+            {
+                Ok(_) => result.added += 1,
+                Err(PlaylistError::AlreadyExists(_)) => result.already_present += 1,
+                Err(_) => result.failed += 1,
+            }
+        }
+        Ok(result)
+    }
+
+    /// The playlist's entries in order, as files for a device holding only
+    /// this playlist. Smart playlists are evaluated first. Each file is
+    /// numbered by its position so players that sort by name keep the order;
+    /// the copy in the playlist's folder is used when there is one, else the
+    /// episode's download.
+    pub async fn sync_entries(
+        &self,
+        playlist: &Playlist,
+    ) -> Result<Vec<PlaylistSyncEntry>, PlaylistError> {
+        let entries = match &playlist.smart_rules {
+            Some(rule) => self.evaluate_smart_rule(rule).await?,
+            None => {
+                let mut entries = playlist.episodes.clone();
+                entries.sort_by_key(|entry| entry.order);
+                entries
+            }
+        };
+        let audio_dir = self.file_manager.playlist_audio_dir(&playlist.name);
+        let device_dir = playlist_device_dir(&playlist.name);
+
+        let mut sync_entries = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            let playlist_copy = entry
+                .filename
+                .as_ref()
+                .map(|filename| audio_dir.join(filename))
+                .filter(|path| path.exists());
+            let source = match playlist_copy {
+                Some(path) => Some(path),
+                None => self
+                    .storage
+                    .load_episode(&entry.podcast_id, &entry.episode_id)
+                    .await
+                    .ok()
+                    .and_then(|episode| episode.local_path)
+                    .filter(|path| path.exists()),
+            };
+            // The playlist copy is already numbered; renumber by position
+            let name = match (&source, &entry.filename) {
+                (Some(path), Some(filename)) if path.starts_with(&audio_dir) => filename
+                    .split_once('-')
+                    .map_or(filename.clone(), |(_, rest)| rest.to_string()),
+                (Some(path), _) => path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "Untitled.mp3".to_string()),
+                (None, _) => crate::utils::validation::sanitize_playlist_name(
+                    entry.episode_title.as_deref().unwrap_or("Untitled"),
+                ),
+            };
+            sync_entries.push(PlaylistSyncEntry {
+                device_path: device_dir.join(format!("{:03}-{}", index + 1, name)),
+                source,
+            });
+        }
+        Ok(sync_entries)
+    }
+
+    /// A smart rule's current episodes, as playlist entries
+    async fn evaluate_smart_rule(
+        &self,
+        rule: &SmartPlaylistRule,
+    ) -> Result<Vec<PlaylistEpisode>, PlaylistError> {
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| PlaylistError::Storage(e.to_string()))?;
+        let mut podcasts = Vec::new();
+        let mut episodes = Vec::new();
+        for podcast_id in &podcast_ids {
+            match self.storage.load_podcast(podcast_id).await {
+                Ok(podcast) if podcast.is_removed() => continue,
+                Ok(podcast) => podcasts.push(podcast),
+                Err(_) => continue,
+            }
+            if let Ok(loaded) = self.storage.load_episodes(podcast_id).await {
+                episodes.extend(loaded);
+            }
+        }
+
+        Ok(rule
+            .evaluate(&episodes, &podcasts)
+            .into_iter()
+            .enumerate()
+            .map(|(index, episode)| PlaylistEpisode {
+                podcast_id: episode.podcast_id.clone(),
+                episode_id: episode.id.clone(),
+                episode_title: Some(episode.display_title().to_string()),
+                added_at: Utc::now(),
+                order: index + 1,
+                file_synced: false,
+                filename: None,
+            })
+            .collect())
+    }
+
     pub async fn remove_episode_from_playlist(
         &self,
         playlist_id: &PlaylistId,
@@ -289,6 +472,20 @@ impl PlaylistManager {
         Ok(())
     }
 
+    /// Remember shuffle / repeat for a playlist
+    pub async fn set_playback_modes(
+        &self,
+        playlist_id: &PlaylistId,
+        modes: PlaybackModes,
+    ) -> Result<(), PlaylistError> {
+        let mut playlist = self.get_playlist(playlist_id).await?;
+        playlist.playback = modes;
+        self.storage
+            .save_playlist(&playlist)
+            .await
+            .map_err(|e| PlaylistError::Storage(e.to_string()))
+    }
+
     pub async fn list_playlists(&self) -> Result<Vec<Playlist>, PlaylistError> {
         let ids = self
             .storage
@@ -505,6 +702,7 @@ impl PlaylistManager {
             created: now,
             last_updated: now,
             smart_rules: None,
+            playback: Default::default(),
         };
         self.storage
             .save_playlist(&today)
@@ -518,7 +716,7 @@ impl PlaylistManager {
 mod tests {
     use super::*;
     use crate::config::DownloadConfig;
-    use crate::podcast::{Episode, EpisodeStatus, Podcast};
+    use crate::podcast::{DownloadState, Episode, Podcast};
     use tempfile::TempDir;
 
     struct TestContext {
@@ -581,7 +779,7 @@ mod tests {
             Utc::now(),
         );
         episode.id = episode_id.clone();
-        episode.status = EpisodeStatus::Downloaded;
+        episode.status.download = DownloadState::Downloaded;
         let file_path = base.join("downloads").join("episode.mp3");
         tokio::fs::create_dir_all(file_path.parent().expect("Missing parent"))
             .await
@@ -625,6 +823,39 @@ mod tests {
         assert!(matches!(err, PlaylistError::AlreadyExists(_)));
     }
 
+    #[tokio::test]
+    async fn test_upsert_smart_playlist_creates_then_replaces_rules() {
+        let ctx = create_test_context().await;
+        let updated = ctx
+            .manager
+            .upsert_smart_playlist(
+                "Favs",
+                None,
+                SmartPlaylistRule::new(crate::playlist::SmartFilter::Favorited),
+            )
+            .await
+            .expect("Failed to create smart playlist");
+        assert!(!updated);
+
+        let mut rule = SmartPlaylistRule::new(crate::playlist::SmartFilter::Favorited);
+        rule.limit = Some(5);
+        let updated = ctx
+            .manager
+            .upsert_smart_playlist("favs", Some("Top five".to_string()), rule.clone())
+            .await
+            .expect("Failed to replace smart playlist");
+        assert!(updated);
+
+        let playlist = ctx
+            .manager
+            .get_playlist_by_name("Favs")
+            .await
+            .expect("Playlist should exist");
+        assert_eq!(playlist.smart_rules, Some(rule));
+        assert_eq!(playlist.description.as_deref(), Some("Top five"));
+        assert_eq!(ctx.manager.list_playlists().await.unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_add_episode_to_playlist() {
         let ctx = create_test_context().await;
@@ -646,6 +877,81 @@ mod tests {
         assert!(added.filename.is_some());
     }
 
+    #[tokio::test]
+    async fn test_add_episodes_to_playlist_skips_present_and_counts_failures() {
+        let ctx = create_test_context().await;
+        let playlist = ctx
+            .manager
+            .create_playlist("Themed", None)
+            .await
+            .expect("Failed to create playlist");
+        let (podcast_id, episode_id, _) =
+            seed_downloaded_episode(&ctx.storage, ctx._tmp.path()).await;
+        let episodes = vec![
+            (podcast_id.clone(), episode_id.clone()),
+            (podcast_id.clone(), episode_id.clone()),
+            (podcast_id.clone(), EpisodeId::new()),
+        ];
+
+        let result = ctx
+            .manager
+            .add_episodes_to_playlist(&playlist.id, &episodes)
+            .await
+            .expect("Failed to add episodes");
+
+        assert_eq!(
+            result,
+            AddEpisodesResult {
+                added: 1,
+                already_present: 1,
+                failed: 1,
+            }
+        );
+        let loaded = ctx.manager.get_playlist(&playlist.id).await.unwrap();
+        assert_eq!(loaded.episodes.len(), 1);
+        assert!(ctx
+            .manager
+            .add_episodes_to_playlist(&PlaylistId::new(), &episodes)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_entries_number_files_by_position() {
+        let ctx = create_test_context().await;
+        let playlist = ctx
+            .manager
+            .create_playlist("Device", None)
+            .await
+            .expect("Failed to create playlist");
+        let (podcast_id, episode_id, _) =
+            seed_downloaded_episode(&ctx.storage, ctx._tmp.path()).await;
+        ctx.manager
+            .add_episode_to_playlist(&playlist.id, &podcast_id, &episode_id)
+            .await
+            .expect("Failed to add episode");
+        let mut playlist = ctx.manager.get_playlist(&playlist.id).await.unwrap();
+        let mut missing = playlist.episodes[0].clone();
+        missing.episode_id = EpisodeId::new();
+        missing.episode_title = Some("Not yet".to_string());
+        missing.filename = None;
+        missing.order = 0;
+        playlist.episodes.push(missing);
+
+        let entries = ctx
+            .manager
+            .sync_entries(&playlist)
+            .await
+            .expect("Failed to list sync entries");
+
+        let dir = playlist_device_dir("Device");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].device_path, dir.join("001-Not yet"));
+        assert_eq!(entries[0].source, None);
+        assert_eq!(entries[1].device_path, dir.join("002-episode.mp3"));
+        assert!(entries[1].source.as_ref().is_some_and(|path| path.exists()));
+    }
+
     #[tokio::test]
     async fn test_remove_episode_from_playlist() {
         let ctx = create_test_context().await;
@@ -692,7 +998,7 @@ mod tests {
             Utc::now(),
         );
         second.id = EpisodeId::new();
-        second.status = EpisodeStatus::Downloaded;
+        second.status.download = DownloadState::Downloaded;
         let second_path = ctx._tmp.path().join("downloads").join("episode2.mp3");
         tokio::fs::write(&second_path, b"audio2")
             .await
@@ -1,7 +1,9 @@
 pub mod auto_generator;
+pub mod commute;
 pub mod file_manager;
 pub mod manager;
 pub mod models;
+pub mod queue;
 
 pub use auto_generator::*;
 pub use file_manager::*;
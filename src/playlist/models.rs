@@ -1,4 +1,4 @@
-use crate::podcast::{Episode, EpisodeStatus, Podcast};
+use crate::podcast::{DownloadState, Episode, Podcast};
 use crate::storage::{EpisodeId, PodcastId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -57,6 +57,9 @@ pub struct Playlist {
     /// is computed on every open by calling [`SmartPlaylistRule::evaluate`].
     #[serde(default)]
     pub smart_rules: Option<SmartPlaylistRule>,
+    /// Shuffle / repeat state, remembered per playlist
+    #[serde(default)]
+    pub playback: PlaybackModes,
 }
 
 impl Playlist {
@@ -66,6 +69,73 @@ impl Playlist {
     }
 }
 
+/// What happens when playback reaches the end of an entry or of the playlist
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepeatMode {
+    #[default]
+    Off,
+    /// Start over after the last entry
+    All,
+    /// Replay the current entry
+    One,
+}
+
+impl RepeatMode {
+    pub const NAMES: &'static str = "off, all, one";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "off" | "none" => Some(Self::Off),
+            "all" | "playlist" => Some(Self::All),
+            "one" | "episode" => Some(Self::One),
+            _ => None,
+        }
+    }
+
+    /// Off → all → one → off
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::All,
+            Self::All => Self::One,
+            Self::One => Self::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::All => "all",
+            Self::One => "one",
+        }
+    }
+}
+
+/// Shuffle and repeat settings for playing through a playlist
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaybackModes {
+    #[serde(default)]
+    pub shuffle: bool,
+    #[serde(default)]
+    pub repeat: RepeatMode,
+}
+
+impl PlaybackModes {
+    /// Status bar marker: `⇄` for shuffle, `↻` / `↻1` for repeat; empty when both are off
+    pub fn indicator(&self) -> String {
+        let repeat = match self.repeat {
+            RepeatMode::Off => None,
+            RepeatMode::All => Some("↻"),
+            RepeatMode::One => Some("↻1"),
+        };
+        [self.shuffle.then_some("⇄"), repeat]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 /// Distinguishes user-created from auto-generated playlists.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PlaylistType {
@@ -157,12 +227,10 @@ impl SmartFilter {
     /// (e.g., [`SmartFilter::Tag`]).
     pub fn matches(&self, episode: &Episode, podcasts: &[Podcast]) -> bool {
         match self {
-            SmartFilter::Downloaded => {
-                matches!(episode.status, EpisodeStatus::Downloaded)
-            }
+            SmartFilter::Downloaded => episode.status.download == DownloadState::Downloaded,
             SmartFilter::Favorited => episode.favorited,
-            SmartFilter::Played => matches!(episode.status, EpisodeStatus::Played),
-            SmartFilter::Unplayed => !matches!(episode.status, EpisodeStatus::Played),
+            SmartFilter::Played => episode.is_played(),
+            SmartFilter::Unplayed => !episode.is_played(),
             SmartFilter::Tag(tag) => podcasts
                 .iter()
                 .any(|p| p.id == episode.podcast_id && p.has_tag(tag)),
@@ -319,9 +387,9 @@ mod tests {
     fn test_smart_filter_downloaded_matches_only_downloaded_episodes() {
         // Arrange
         let id = PodcastId::new();
-        let downloaded = make_episode(id.clone(), EpisodeStatus::Downloaded, false);
-        let new_ep = make_episode(id.clone(), EpisodeStatus::New, false);
-        let played = make_episode(id.clone(), EpisodeStatus::Played, false);
+        let downloaded = make_episode(id.clone(), EpisodeStatus::downloaded(), false);
+        let new_ep = make_episode(id.clone(), EpisodeStatus::default(), false);
+        let played = make_episode(id.clone(), EpisodeStatus::played(), false);
 
         // Act & Assert
         assert!(SmartFilter::Downloaded.matches(&downloaded, &[]));
@@ -333,8 +401,8 @@ mod tests {
     fn test_smart_filter_favorited_matches_only_favorited_episodes() {
         // Arrange
         let id = PodcastId::new();
-        let fav = make_episode(id.clone(), EpisodeStatus::New, true);
-        let not_fav = make_episode(id.clone(), EpisodeStatus::New, false);
+        let fav = make_episode(id.clone(), EpisodeStatus::default(), true);
+        let not_fav = make_episode(id.clone(), EpisodeStatus::default(), false);
 
         // Act & Assert
         assert!(SmartFilter::Favorited.matches(&fav, &[]));
@@ -345,8 +413,8 @@ mod tests {
     fn test_smart_filter_played_and_unplayed() {
         // Arrange
         let id = PodcastId::new();
-        let played = make_episode(id.clone(), EpisodeStatus::Played, false);
-        let new_ep = make_episode(id.clone(), EpisodeStatus::New, false);
+        let played = make_episode(id.clone(), EpisodeStatus::played(), false);
+        let new_ep = make_episode(id.clone(), EpisodeStatus::default(), false);
 
         // Act & Assert
         assert!(SmartFilter::Played.matches(&played, &[]));
@@ -359,7 +427,7 @@ mod tests {
     fn test_smart_filter_tag_matches_episodes_from_tagged_podcast() {
         // Arrange
         let mut podcast = make_podcast(&["tech"]);
-        let episode = make_episode(podcast.id.clone(), EpisodeStatus::New, false);
+        let episode = make_episode(podcast.id.clone(), EpisodeStatus::default(), false);
         let podcasts = [podcast.clone()];
 
         // Act & Assert — correct tag matches
@@ -380,11 +448,11 @@ mod tests {
         // Arrange: downloaded AND favorited
         let id = PodcastId::new();
         let both = {
-            let mut e = make_episode(id.clone(), EpisodeStatus::Downloaded, true);
+            let mut e = make_episode(id.clone(), EpisodeStatus::downloaded(), true);
             e
         };
-        let only_downloaded = make_episode(id.clone(), EpisodeStatus::Downloaded, false);
-        let only_favorited = make_episode(id.clone(), EpisodeStatus::New, true);
+        let only_downloaded = make_episode(id.clone(), EpisodeStatus::downloaded(), false);
+        let only_favorited = make_episode(id.clone(), EpisodeStatus::default(), true);
 
         let filter = SmartFilter::And(vec![SmartFilter::Downloaded, SmartFilter::Favorited]);
 
@@ -398,9 +466,9 @@ mod tests {
     fn test_smart_filter_or_requires_any_to_match() {
         // Arrange: downloaded OR favorited
         let id = PodcastId::new();
-        let downloaded = make_episode(id.clone(), EpisodeStatus::Downloaded, false);
-        let favorited = make_episode(id.clone(), EpisodeStatus::New, true);
-        let neither = make_episode(id.clone(), EpisodeStatus::New, false);
+        let downloaded = make_episode(id.clone(), EpisodeStatus::downloaded(), false);
+        let favorited = make_episode(id.clone(), EpisodeStatus::default(), true);
+        let neither = make_episode(id.clone(), EpisodeStatus::default(), false);
 
         let filter = SmartFilter::Or(vec![SmartFilter::Downloaded, SmartFilter::Favorited]);
 
@@ -415,7 +483,7 @@ mod tests {
         // Arrange: 5 downloaded episodes, limit 3
         let id = PodcastId::new();
         let episodes: Vec<Episode> = (0..5)
-            .map(|_| make_episode(id.clone(), EpisodeStatus::Downloaded, false))
+            .map(|_| make_episode(id.clone(), EpisodeStatus::downloaded(), false))
             .collect();
 
         let rule = SmartPlaylistRule {
@@ -435,9 +503,9 @@ mod tests {
     fn test_smart_playlist_rule_sort_by_title_ascending() {
         // Arrange
         let id = PodcastId::new();
-        let mut ep_z = make_episode(id.clone(), EpisodeStatus::Downloaded, false);
+        let mut ep_z = make_episode(id.clone(), EpisodeStatus::downloaded(), false);
         ep_z.title = "Zebra".to_string();
-        let mut ep_a = make_episode(id.clone(), EpisodeStatus::Downloaded, false);
+        let mut ep_a = make_episode(id.clone(), EpisodeStatus::downloaded(), false);
         ep_a.title = "Apple".to_string();
         let episodes = vec![ep_z, ep_a];
 
@@ -494,6 +562,7 @@ mod tests {
             created: now,
             last_updated: now,
             smart_rules: None,
+            playback: Default::default(),
         };
 
         // Assert: regular playlist is not smart
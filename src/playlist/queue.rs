@@ -0,0 +1,460 @@
+// Play queue - plays a playlist's entries one after another
+//
+// Built when playback starts from a playlist. The app asks it for the next
+// entry whenever a track ends. Shuffle is podcast-aware: two episodes from
+// the same show only follow each other when nothing else is left.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::playlist::{PlaybackModes, PlaylistId, RepeatMode};
+use crate::storage::{EpisodeId, PodcastId};
+
+/// One episode in the queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueEntry {
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+}
+
+/// Small xorshift generator; shuffles only need to look random
+struct Shuffler(u64);
+
+impl Shuffler {
+    fn new() -> Self {
+        Self::with_seed(uuid::Uuid::new_v4().as_u128() as u64)
+    }
+
+    fn with_seed(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+/// Shuffle `indices` so that consecutive entries come from different podcasts
+/// wherever possible. `after` is the podcast playing just before the first one.
+fn podcast_aware_shuffle(
+    entries: &[QueueEntry],
+    indices: Vec<usize>,
+    after: Option<&PodcastId>,
+    rng: &mut Shuffler,
+) -> Vec<usize> {
+    let mut groups: HashMap<&PodcastId, Vec<usize>> = HashMap::new();
+    for index in indices {
+        groups
+            .entry(&entries[index].podcast_id)
+            .or_default()
+            .push(index);
+    }
+    let mut groups: Vec<(&PodcastId, Vec<usize>)> = groups.into_iter().collect();
+    for (_, group) in &mut groups {
+        for i in (1..group.len()).rev() {
+            group.swap(i, rng.below(i + 1));
+        }
+    }
+
+    // Taking from the largest remaining show (other than the previous one)
+    // keeps a long backlog from one show from piling up at the end
+    let mut order = Vec::new();
+    let mut previous = after;
+    while !groups.is_empty() {
+        let largest = groups
+            .iter()
+            .filter(|(podcast, _)| Some(*podcast) != previous)
+            .map(|(_, group)| group.len())
+            .max();
+        let pick = match largest {
+            Some(size) => {
+                let candidates: Vec<usize> = (0..groups.len())
+                    .filter(|&i| Some(groups[i].0) != previous && groups[i].1.len() == size)
+                    .collect();
+                candidates[rng.below(candidates.len())]
+            }
+            // Only the previous show is left
+            None => 0,
+        };
+        let (podcast, group) = &mut groups[pick];
+        order.push(group.pop().expect("groups are never empty"));
+        previous = Some(*podcast);
+        if group.is_empty() {
+            groups.swap_remove(pick);
+        }
+    }
+    order
+}
+
+/// Entries being played in order (or shuffled), with repeat handling
+pub struct PlayQueue {
+    playlist_id: PlaylistId,
+    playlist_name: String,
+    entries: Vec<QueueEntry>,
+    /// Play order as indices into `entries`
+    order: Vec<usize>,
+    position: usize,
+    modes: PlaybackModes,
+    rng: Shuffler,
+    /// Built on the fly (e.g. by `:commute`) rather than from a saved playlist
+    temporary: bool,
+}
+
+impl PlayQueue {
+    /// Queue `entries`, starting with the one at `start`. With shuffle on,
+    /// the rest follow in shuffled order.
+    pub fn new(
+        playlist_id: PlaylistId,
+        playlist_name: String,
+        entries: Vec<QueueEntry>,
+        start: usize,
+        modes: PlaybackModes,
+    ) -> Self {
+        Self::with_rng(
+            playlist_id,
+            playlist_name,
+            entries,
+            start,
+            modes,
+            Shuffler::new(),
+        )
+    }
+
+    /// Queue `entries` that don't belong to any saved playlist, in order
+    pub fn temporary(name: String, entries: Vec<QueueEntry>) -> Self {
+        let mut queue = Self::new(
+            PlaylistId::from_name(&name),
+            name,
+            entries,
+            0,
+            PlaybackModes::default(),
+        );
+        queue.temporary = true;
+        queue
+    }
+
+    fn with_rng(
+        playlist_id: PlaylistId,
+        playlist_name: String,
+        entries: Vec<QueueEntry>,
+        start: usize,
+        modes: PlaybackModes,
+        rng: Shuffler,
+    ) -> Self {
+        let start = start.min(entries.len().saturating_sub(1));
+        let mut queue = Self {
+            playlist_id,
+            playlist_name,
+            order: (0..entries.len()).collect(),
+            entries,
+            position: start,
+            modes: PlaybackModes::default(),
+            rng,
+            temporary: false,
+        };
+        queue.set_modes(modes);
+        queue
+    }
+
+    pub fn playlist_id(&self) -> &PlaylistId {
+        &self.playlist_id
+    }
+
+    pub fn playlist_name(&self) -> &str {
+        &self.playlist_name
+    }
+
+    /// No saved playlist backs this queue, so its modes aren't persisted
+    pub fn is_temporary(&self) -> bool {
+        self.temporary
+    }
+
+    pub fn modes(&self) -> PlaybackModes {
+        self.modes
+    }
+
+    /// The entry playing now
+    pub fn current(&self) -> Option<&QueueEntry> {
+        self.order
+            .get(self.position)
+            .map(|&index| &self.entries[index])
+    }
+
+    /// Change shuffle / repeat. Turning shuffle on shuffles what is left after
+    /// the current entry; turning it off continues in playlist order from it.
+    pub fn set_modes(&mut self, modes: PlaybackModes) {
+        if modes.shuffle != self.modes.shuffle {
+            let current = self.order.get(self.position).copied();
+            if modes.shuffle {
+                let rest: Vec<usize> = (0..self.entries.len())
+                    .filter(|&index| Some(index) != current)
+                    .collect();
+                let after = current.map(|index| &self.entries[index].podcast_id);
+                let shuffled = podcast_aware_shuffle(&self.entries, rest, after, &mut self.rng);
+                self.order = current.into_iter().chain(shuffled).collect();
+                self.position = 0;
+            } else {
+                self.order = (0..self.entries.len()).collect();
+                self.position = current.unwrap_or(0);
+            }
+        }
+        self.modes = modes;
+    }
+
+    /// Take on a new order of the same entries, after the playlist was
+    /// reordered while playing. In order, play carries on from the current
+    /// entry in its new place; shuffled, the shuffle stands. `false` (and no
+    /// change) when `entries` aren't a reordering of the queued ones.
+    pub fn reorder(&mut self, entries: Vec<QueueEntry>) -> bool {
+        if entries.len() != self.entries.len() {
+            return false;
+        }
+        let new_index: HashMap<&EpisodeId, usize> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (&entry.episode_id, index))
+            .collect();
+        let moved: Option<Vec<usize>> = self
+            .entries
+            .iter()
+            .map(|entry| new_index.get(&entry.episode_id).copied())
+            .collect();
+        let Some(moved) = moved.filter(|moved| {
+            let mut sorted = moved.clone();
+            sorted.sort_unstable();
+            sorted.into_iter().eq(0..entries.len())
+        }) else {
+            return false;
+        };
+
+        if self.modes.shuffle {
+            self.order = self.order.iter().map(|&index| moved[index]).collect();
+        } else if let Some(&current) = self.order.get(self.position) {
+            self.position = moved[current];
+        }
+        self.entries = entries;
+        true
+    }
+
+    /// Move to the entry to play after the current one finished.
+    /// `None` when the queue is done.
+    pub fn advance(&mut self) -> Option<&QueueEntry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        if self.modes.repeat == RepeatMode::One {
+            return self.current();
+        }
+        if self.position + 1 < self.order.len() {
+            self.position += 1;
+            return self.current();
+        }
+        if self.modes.repeat != RepeatMode::All {
+            self.position = self.order.len();
+            return None;
+        }
+        // Start over; a fresh shuffle each pass, still avoiding a repeat of the last show
+        if self.modes.shuffle {
+            let last = self
+                .order
+                .last()
+                .map(|&index| &self.entries[index].podcast_id);
+            self.order = podcast_aware_shuffle(
+                &self.entries,
+                (0..self.entries.len()).collect(),
+                last,
+                &mut self.rng,
+            );
+        }
+        self.position = 0;
+        self.current()
+    }
+
+    /// Up to `count` entries due after the current one (a repeat-all queue
+    /// isn't followed into its next pass)
+    pub fn upcoming(&self, count: usize) -> Vec<QueueEntry> {
+        if self.modes.repeat == RepeatMode::One {
+            return Vec::new();
+        }
+        self.order
+            .iter()
+            .skip(self.position + 1)
+            .take(count)
+            .map(|&index| self.entries[index].clone())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(podcasts: &[&PodcastId]) -> Vec<QueueEntry> {
+        podcasts
+            .iter()
+            .map(|podcast_id| QueueEntry {
+                podcast_id: (*podcast_id).clone(),
+                episode_id: EpisodeId::new(),
+            })
+            .collect()
+    }
+
+    fn play_all(queue: &mut PlayQueue) -> Vec<QueueEntry> {
+        let mut played: Vec<QueueEntry> = queue.current().into_iter().cloned().collect();
+        while let Some(entry) = queue.advance() {
+            played.push(entry.clone());
+        }
+        played
+    }
+
+    #[test]
+    fn test_shuffle_avoids_same_podcast_back_to_back() {
+        // Arrange — 3 + 2 + 1 episodes from three shows
+        let (a, b, c) = (PodcastId::new(), PodcastId::new(), PodcastId::new());
+        let entries = entries(&[&a, &a, &a, &b, &b, &c]);
+
+        for seed in 1..50 {
+            // Act
+            let order = podcast_aware_shuffle(
+                &entries,
+                (0..entries.len()).collect(),
+                None,
+                &mut Shuffler::with_seed(seed),
+            );
+
+            // Assert
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3, 4, 5]);
+            for pair in order.windows(2) {
+                assert_ne!(entries[pair[0]].podcast_id, entries[pair[1]].podcast_id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reorder_continues_from_the_current_entry_in_its_new_place() {
+        // Arrange — playing the second of four
+        let podcast = PodcastId::new();
+        let entries = entries(&[&podcast, &podcast, &podcast, &podcast]);
+        let mut queue = PlayQueue::new(
+            PlaylistId::new(),
+            "Commute".to_string(),
+            entries.clone(),
+            1,
+            PlaybackModes::default(),
+        );
+
+        // Act — the last entry moves to the top
+        let reordered = vec![
+            entries[3].clone(),
+            entries[0].clone(),
+            entries[1].clone(),
+            entries[2].clone(),
+        ];
+        assert!(queue.reorder(reordered));
+
+        // Assert
+        assert_eq!(queue.current(), Some(&entries[1]));
+        assert_eq!(queue.upcoming(5), vec![entries[2].clone()]);
+        assert!(!queue.reorder(entries[..3].to_vec()));
+    }
+
+    #[test]
+    fn test_queue_plays_in_order_and_stops_without_repeat() {
+        let podcast = PodcastId::new();
+        let entries = entries(&[&podcast, &podcast, &podcast]);
+        let mut queue = PlayQueue::new(
+            PlaylistId::new(),
+            "Commute".to_string(),
+            entries.clone(),
+            1,
+            PlaybackModes::default(),
+        );
+
+        assert_eq!(play_all(&mut queue), entries[1..].to_vec());
+        assert!(queue.advance().is_none());
+    }
+
+    #[test]
+    fn test_upcoming_lists_entries_after_current() {
+        let podcast = PodcastId::new();
+        let entries = entries(&[&podcast, &podcast, &podcast, &podcast]);
+        let mut queue = PlayQueue::new(
+            PlaylistId::new(),
+            "Commute".to_string(),
+            entries.clone(),
+            1,
+            PlaybackModes::default(),
+        );
+
+        assert_eq!(queue.upcoming(2), entries[2..4].to_vec());
+        queue.advance();
+        queue.advance();
+        assert!(queue.upcoming(2).is_empty());
+    }
+
+    #[test]
+    fn test_repeat_modes() {
+        let podcast = PodcastId::new();
+        let entries = entries(&[&podcast, &podcast]);
+
+        // Repeat one keeps returning the same entry
+        let mut queue = PlayQueue::new(
+            PlaylistId::new(),
+            "Loop".to_string(),
+            entries.clone(),
+            0,
+            PlaybackModes {
+                shuffle: false,
+                repeat: RepeatMode::One,
+            },
+        );
+        assert_eq!(queue.advance(), Some(&entries[0]));
+        assert_eq!(queue.advance(), Some(&entries[0]));
+
+        // Repeat all wraps around
+        queue.set_modes(PlaybackModes {
+            shuffle: false,
+            repeat: RepeatMode::All,
+        });
+        assert_eq!(queue.advance(), Some(&entries[1]));
+        assert_eq!(queue.advance(), Some(&entries[0]));
+    }
+
+    #[test]
+    fn test_shuffle_starts_with_chosen_entry_and_plays_everything() {
+        let (a, b) = (PodcastId::new(), PodcastId::new());
+        let entries = entries(&[&a, &b, &a, &b]);
+        let mut queue = PlayQueue::with_rng(
+            PlaylistId::new(),
+            "Mix".to_string(),
+            entries.clone(),
+            2,
+            PlaybackModes {
+                shuffle: true,
+                repeat: RepeatMode::Off,
+            },
+            Shuffler::with_seed(7),
+        );
+
+        let played = play_all(&mut queue);
+        assert_eq!(played[0], entries[2]);
+        assert_eq!(played.len(), 4);
+        for entry in &entries {
+            assert!(played.contains(entry));
+        }
+    }
+}
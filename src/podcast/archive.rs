@@ -0,0 +1,388 @@
+// Static HTML archive of a podcast
+//
+// `export-archive` writes a self-contained, browsable copy of a show: an
+// index page listing every episode and one page per episode with its show
+// notes, chapters and links. Downloaded episodes link to (and embed) the
+// local audio file, so the archive keeps working if the feed disappears.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::podcast::{Episode, Podcast};
+use crate::utils::validation::sanitize_filename;
+
+/// Directory, under the archive's, holding the episode pages
+const EPISODES_DIR: &str = "episodes";
+
+/// Longest slug used in an episode page's file name
+const MAX_SLUG_LEN: usize = 60;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:48rem;margin:2rem auto;padding:0 1rem;line-height:1.5;color:#222}\
+a{color:#0645ad}img.cover{max-width:12rem;float:right;margin:0 0 1rem 1rem}\
+ul.episodes{list-style:none;padding:0}ul.episodes li{margin:.6rem 0}\
+.meta{color:#666;font-size:.9rem}audio{width:100%;margin:1rem 0}";
+
+/// What an export wrote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveSummary {
+    /// The archive's `index.html`
+    pub index_path: PathBuf,
+    pub episode_count: usize,
+    /// Episodes whose pages link to a local audio file
+    pub downloaded_count: usize,
+}
+
+/// Writes a podcast's static HTML archive
+pub struct ArchiveExporter;
+
+impl ArchiveExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write the archive of `podcast` to `dir/<podcast title>/`, replacing the
+    /// pages of an earlier export there
+    pub async fn export(
+        &self,
+        podcast: &Podcast,
+        episodes: &[Episode],
+        dir: &Path,
+    ) -> std::io::Result<ArchiveSummary> {
+        let root = dir.join(sanitize_filename(&podcast.title));
+        tokio::fs::create_dir_all(root.join(EPISODES_DIR)).await?;
+
+        let mut episodes: Vec<&Episode> = episodes.iter().collect();
+        episodes.sort_by_key(|episode| std::cmp::Reverse(episode.published));
+        let pages = page_names(&episodes);
+
+        for (index, episode) in episodes.iter().enumerate() {
+            let newer = index.checked_sub(1).map(|i| pages[i].as_str());
+            let older = pages.get(index + 1).map(String::as_str);
+            let html = render_episode(podcast, episode, newer, older);
+            tokio::fs::write(root.join(EPISODES_DIR).join(&pages[index]), html).await?;
+        }
+
+        let index_path = root.join("index.html");
+        tokio::fs::write(&index_path, render_index(podcast, &episodes, &pages)).await?;
+
+        Ok(ArchiveSummary {
+            index_path,
+            episode_count: episodes.len(),
+            downloaded_count: episodes
+                .iter()
+                .filter(|episode| local_audio(episode).is_some())
+                .count(),
+        })
+    }
+}
+
+impl Default for ArchiveExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Page file names for `episodes` (newest first), numbered oldest = 1 so
+/// names stay put as new episodes arrive
+fn page_names(episodes: &[&Episode]) -> Vec<String> {
+    let count = episodes.len();
+    episodes
+        .iter()
+        .enumerate()
+        .map(|(index, episode)| {
+            let slug = slug(episode.display_title());
+            if slug.is_empty() {
+                format!("{:04}.html", count - index)
+            } else {
+                format!("{:04}-{}.html", count - index, slug)
+            }
+        })
+        .collect()
+}
+
+fn slug(title: &str) -> String {
+    let mut slug = String::new();
+    for ch in title.chars().flat_map(char::to_lowercase) {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LEN {
+            break;
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// `file://` URL of the episode's downloaded audio, if it is on disk
+fn local_audio(episode: &Episode) -> Option<String> {
+    if !episode.is_downloaded() {
+        return None;
+    }
+    let path = episode.local_path.as_ref()?;
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+    url::Url::from_file_path(&path)
+        .ok()
+        .map(|url| url.to_string())
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Only web links are written into `href`/`src`; anything else (e.g.
+/// `javascript:`) from a feed is dropped
+fn safe_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let lower = url.to_ascii_lowercase();
+    (lower.starts_with("http://") || lower.starts_with("https://")).then(|| escape(url))
+}
+
+/// Plain-text show notes as paragraphs
+fn paragraphs(text: &str) -> String {
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| {
+            let lines: Vec<String> = paragraph.lines().map(escape).collect();
+            format!("<p>{}</p>\n", lines.join("<br>\n"))
+        })
+        .collect()
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <meta name=\"generator\" content=\"podcast-tui\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+fn episode_meta(episode: &Episode) -> String {
+    let mut meta = vec![episode.published.format("%Y-%m-%d").to_string()];
+    if episode.duration.is_some() {
+        meta.push(episode.formatted_duration());
+    }
+    match (episode.season, episode.episode_number) {
+        (Some(season), Some(number)) => meta.push(format!("S{season} E{number}")),
+        (None, Some(number)) => meta.push(format!("#{number}")),
+        _ => {}
+    }
+    if local_audio(episode).is_some() {
+        meta.push("downloaded".to_string());
+    }
+    escape(&meta.join(" · "))
+}
+
+fn render_index(podcast: &Podcast, episodes: &[&Episode], pages: &[String]) -> String {
+    let mut body = String::new();
+    if let Some(image) = podcast.image_url.as_deref().and_then(safe_url) {
+        let _ = writeln!(body, "<img class=\"cover\" src=\"{image}\" alt=\"\">");
+    }
+    let _ = writeln!(body, "<h1>{}</h1>", escape(&podcast.title));
+    if let Some(author) = &podcast.author {
+        let _ = writeln!(body, "<p class=\"meta\">{}</p>", escape(author));
+    }
+    if let Some(description) = &podcast.description {
+        body.push_str(&paragraphs(description));
+    }
+    if let Some(feed) = safe_url(&podcast.url) {
+        let _ = writeln!(body, "<p><a href=\"{feed}\">Feed</a></p>");
+    }
+
+    let _ = writeln!(
+        body,
+        "<h2>Episodes ({})</h2>\n<ul class=\"episodes\">",
+        episodes.len()
+    );
+    for (episode, page) in episodes.iter().zip(pages) {
+        let _ = writeln!(
+            body,
+            "<li><a href=\"{EPISODES_DIR}/{}\">{}</a><br><span class=\"meta\">{}</span></li>",
+            escape(page),
+            escape(episode.display_title()),
+            episode_meta(episode)
+        );
+    }
+    body.push_str("</ul>\n");
+    let _ = writeln!(
+        body,
+        "<p class=\"meta\">Archived {} with podcast-tui</p>",
+        chrono::Utc::now().format("%Y-%m-%d")
+    );
+
+    page(&podcast.title, &body)
+}
+
+fn render_episode(
+    podcast: &Podcast,
+    episode: &Episode,
+    newer: Option<&str>,
+    older: Option<&str>,
+) -> String {
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "<p><a href=\"../index.html\">{}</a></p>",
+        escape(&podcast.title)
+    );
+    let _ = writeln!(body, "<h1>{}</h1>", escape(episode.display_title()));
+    let _ = writeln!(body, "<p class=\"meta\">{}</p>", episode_meta(episode));
+
+    if let Some(audio) = local_audio(episode) {
+        let audio = escape(&audio);
+        let _ = writeln!(
+            body,
+            "<audio controls preload=\"none\" src=\"{audio}\"></audio>\n<p><a href=\"{audio}\">Downloaded audio</a></p>"
+        );
+    }
+    let mut links = Vec::new();
+    if let Some(link) = episode.link.as_deref().and_then(safe_url) {
+        links.push(format!("<a href=\"{link}\">Episode page</a>"));
+    }
+    if let Some(original) = safe_url(&episode.audio_url) {
+        links.push(format!("<a href=\"{original}\">Original audio</a>"));
+    }
+    if !links.is_empty() {
+        let _ = writeln!(body, "<p>{}</p>", links.join(" · "));
+    }
+
+    if let Some(description) = episode.display_description() {
+        body.push_str("<h2>Show notes</h2>\n");
+        body.push_str(&paragraphs(description));
+    }
+    if !episode.chapters.is_empty() {
+        body.push_str("<h2>Chapters</h2>\n<ul>\n");
+        for chapter in &episode.chapters {
+            let start = chapter.start_time;
+            let time = format!("{}:{:02}:{:02}", start / 3600, start / 60 % 60, start % 60);
+            let title = match chapter.url.as_deref().and_then(safe_url) {
+                Some(url) => format!("<a href=\"{url}\">{}</a>", escape(&chapter.title)),
+                None => escape(&chapter.title),
+            };
+            let _ = writeln!(body, "<li>{time} {title}</li>");
+        }
+        body.push_str("</ul>\n");
+    }
+
+    let mut nav = Vec::new();
+    if let Some(newer) = newer {
+        nav.push(format!("<a href=\"{}\">← Newer</a>", escape(newer)));
+    }
+    if let Some(older) = older {
+        nav.push(format!("<a href=\"{}\">Older →</a>", escape(older)));
+    }
+    if !nav.is_empty() {
+        let _ = writeln!(body, "<p>{}</p>", nav.join(" · "));
+    }
+
+    page(episode.display_title(), &body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::DownloadState;
+    use crate::storage::PodcastId;
+    use chrono::{Duration, Utc};
+    use tempfile::TempDir;
+
+    fn episode(podcast_id: &PodcastId, title: &str, days_ago: i64) -> Episode {
+        let mut episode = Episode::new(
+            podcast_id.clone(),
+            title.to_string(),
+            format!("https://cdn.example.com/{days_ago}.mp3"),
+            Utc::now() - Duration::days(days_ago),
+        );
+        episode.description = Some("First <b>line</b>\n\nSecond paragraph".to_string());
+        episode
+    }
+
+    #[test]
+    fn test_slug_and_page_names_number_oldest_first() {
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let newest = episode(&podcast.id, "Ep 2: The Sequel!", 1);
+        let oldest = episode(&podcast.id, "???", 5);
+
+        let pages = page_names(&[&newest, &oldest]);
+
+        assert_eq!(pages, vec!["0002-ep-2-the-sequel.html", "0001.html"]);
+        assert_eq!(slug(&"a".repeat(100)).len(), MAX_SLUG_LEN);
+    }
+
+    #[test]
+    fn test_episode_page_escapes_feed_text_and_drops_unsafe_links() {
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let mut episode = episode(&podcast.id, "<script>alert(1)</script>", 1);
+        episode.link = Some("javascript:alert(1)".to_string());
+
+        let html = render_episode(&podcast, &episode, None, Some("0001.html"));
+
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("<p>First &lt;b&gt;line&lt;/b&gt;</p>"));
+        assert!(html.contains("<a href=\"0001.html\">Older →</a>"));
+        assert!(html.contains("<a href=\"https://cdn.example.com/1.mp3\">Original audio</a>"));
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_index_and_pages_linking_downloaded_audio() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let podcast = Podcast::new(
+            "My Show".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        let audio_path = dir.path().join("ep.mp3");
+        std::fs::write(&audio_path, b"audio").unwrap();
+        let mut downloaded = episode(&podcast.id, "Downloaded", 1);
+        downloaded.status.download = DownloadState::Downloaded;
+        downloaded.local_path = Some(audio_path);
+        let streamed = episode(&podcast.id, "Streamed", 2);
+
+        // Act
+        let summary = ArchiveExporter::new()
+            .export(&podcast, &[streamed, downloaded], dir.path())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(summary.episode_count, 2);
+        assert_eq!(summary.downloaded_count, 1);
+        assert_eq!(
+            summary.index_path,
+            dir.path().join("My Show").join("index.html")
+        );
+        let index = std::fs::read_to_string(&summary.index_path).unwrap();
+        let newest = index.find("0002-downloaded.html").unwrap();
+        let oldest = index.find("0001-streamed.html").unwrap();
+        assert!(newest < oldest, "newest episode is listed first");
+        let page = std::fs::read_to_string(
+            dir.path()
+                .join("My Show")
+                .join(EPISODES_DIR)
+                .join("0002-downloaded.html"),
+        )
+        .unwrap();
+        assert!(page.contains("<audio controls"));
+        assert!(page.contains("file://"));
+        assert!(page.contains("<a href=\"../index.html\">My Show</a>"));
+    }
+}
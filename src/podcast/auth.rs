@@ -0,0 +1,522 @@
+// OAuth sign-in for feeds behind a membership platform
+//
+// Providers are configured in `network.oauth_providers`. Signing in uses the
+// device authorization grant (RFC 8628): the user opens a URL on any device
+// and enters a short code while we poll the token endpoint. The refresh
+// token goes into the secrets store; access tokens live in memory only and
+// are refreshed when they expire. Feed fetches to a provider's hosts carry
+// `Authorization: Bearer <access token>`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::config::OAuthProviderConfig;
+use crate::storage::{SecretStore, StorageError};
+use crate::utils::http::{HttpClient, HttpError, HttpRequest};
+
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Default polling interval when the provider doesn't give one (RFC 8628 §3.2)
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 5;
+
+/// Added to the interval when the provider asks us to slow down
+const SLOW_DOWN_STEP_SECS: u64 = 5;
+
+/// Access tokens this close to expiry are refreshed before use
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("No OAuth provider named '{0}' in network.oauth_providers")]
+    UnknownProvider(String),
+
+    #[error("Not signed in to {provider} (use :auth-login {provider})")]
+    NotSignedIn { provider: String },
+
+    #[error("Sign-in was denied")]
+    AccessDenied,
+
+    #[error("The sign-in code expired before it was approved")]
+    Expired,
+
+    #[error("{provider} refused the request: {error}")]
+    Rejected { provider: String, error: String },
+
+    #[error("Network error: {0}")]
+    Network(#[from] HttpError),
+
+    #[error("Unexpected response from the provider: {0}")]
+    InvalidResponse(String),
+
+    #[error("Could not access stored credentials: {0}")]
+    Storage(#[from] StorageError),
+}
+
+/// What the user needs to approve a sign-in
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    /// Some providers (e.g. Google) call it `verification_url`
+    #[serde(alias = "verification_url")]
+    pub verification_uri: String,
+    /// Verification URL with the code filled in, when offered
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+fn default_interval() -> u64 {
+    DEFAULT_POLL_INTERVAL_SECS
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+}
+
+struct AccessToken {
+    token: String,
+    expires_at: Option<Instant>,
+}
+
+impl AccessToken {
+    fn is_fresh(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| Instant::now() + EXPIRY_MARGIN < expires_at)
+    }
+}
+
+/// Result of one POST to a token endpoint
+enum TokenReply {
+    Granted(TokenResponse),
+    /// The OAuth `error` code, e.g. `authorization_pending`
+    Error(String),
+}
+
+/// Signs in to OAuth providers and hands out bearer tokens for their feeds
+pub struct FeedAuth {
+    providers: Vec<OAuthProviderConfig>,
+    http_client: Arc<dyn HttpClient>,
+    secrets: SecretStore,
+    access_tokens: Mutex<HashMap<String, AccessToken>>,
+}
+
+impl FeedAuth {
+    pub fn new(
+        providers: Vec<OAuthProviderConfig>,
+        secrets: SecretStore,
+        http_client: Arc<dyn HttpClient>,
+    ) -> Self {
+        Self {
+            providers,
+            http_client,
+            secrets,
+            access_tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn providers(&self) -> &[OAuthProviderConfig] {
+        &self.providers
+    }
+
+    pub fn provider(&self, name: &str) -> Result<&OAuthProviderConfig, AuthError> {
+        self.providers
+            .iter()
+            .find(|provider| provider.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| AuthError::UnknownProvider(name.to_string()))
+    }
+
+    /// Provider whose hosts serve `feed_url`, if any
+    pub fn provider_for_url(&self, feed_url: &str) -> Option<&OAuthProviderConfig> {
+        let url = url::Url::parse(feed_url).ok()?;
+        let host = url.host_str()?.to_ascii_lowercase();
+        self.providers.iter().find(|provider| {
+            provider.hosts.iter().any(|pattern| {
+                let pattern = pattern.trim().to_ascii_lowercase();
+                host == pattern || host.ends_with(&format!(".{pattern}"))
+            })
+        })
+    }
+
+    /// Step 1 of signing in: get the code for the user to enter
+    pub async fn start_sign_in(&self, name: &str) -> Result<DeviceAuthorization, AuthError> {
+        let provider = self.provider(name)?;
+        let mut fields = vec![("client_id", provider.client_id.as_str())];
+        if let Some(scope) = &provider.scope {
+            fields.push(("scope", scope.as_str()));
+        }
+
+        let response = self
+            .http_client
+            .get(HttpRequest::post_form(
+                &provider.device_authorization_url,
+                &fields,
+            ))
+            .await?;
+        let status = response.status;
+        let body = response.bytes().await?;
+        if !(200..300).contains(&status) {
+            return Err(match serde_json::from_slice::<TokenErrorResponse>(&body) {
+                Ok(reply) => AuthError::Rejected {
+                    provider: provider.name.clone(),
+                    error: reply.error,
+                },
+                Err(_) => AuthError::Network(HttpError::Status {
+                    status,
+                    url: provider.device_authorization_url.clone(),
+                }),
+            });
+        }
+        serde_json::from_slice(&body).map_err(|e| AuthError::InvalidResponse(e.to_string()))
+    }
+
+    /// Step 2: poll until the user approves (or denies, or the code expires),
+    /// then keep the refresh token
+    pub async fn finish_sign_in(
+        &self,
+        name: &str,
+        authorization: &DeviceAuthorization,
+    ) -> Result<(), AuthError> {
+        let provider = self.provider(name)?;
+        let deadline = Instant::now() + Duration::from_secs(authorization.expires_in);
+        let mut interval = authorization.interval.max(1);
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+            if Instant::now() >= deadline {
+                return Err(AuthError::Expired);
+            }
+
+            let reply = self
+                .request_token(
+                    provider,
+                    &[
+                        ("grant_type", DEVICE_CODE_GRANT),
+                        ("device_code", &authorization.device_code),
+                        ("client_id", &provider.client_id),
+                    ],
+                )
+                .await?;
+            match reply {
+                TokenReply::Granted(token) => {
+                    let refresh_token = token.refresh_token.clone().ok_or_else(|| {
+                        AuthError::InvalidResponse("no refresh token was issued".to_string())
+                    })?;
+                    self.secrets
+                        .set(&secret_key(&provider.name), &refresh_token)
+                        .await?;
+                    self.cache_access_token(&provider.name, &token).await;
+                    return Ok(());
+                }
+                TokenReply::Error(error) => match error.as_str() {
+                    "authorization_pending" => {}
+                    "slow_down" => interval += SLOW_DOWN_STEP_SECS,
+                    "access_denied" => return Err(AuthError::AccessDenied),
+                    "expired_token" => return Err(AuthError::Expired),
+                    _ => {
+                        return Err(AuthError::Rejected {
+                            provider: provider.name.clone(),
+                            error,
+                        })
+                    }
+                },
+            }
+        }
+    }
+
+    pub async fn is_signed_in(&self, name: &str) -> Result<bool, AuthError> {
+        let provider = self.provider(name)?;
+        Ok(self
+            .secrets
+            .get(&secret_key(&provider.name))
+            .await?
+            .is_some())
+    }
+
+    /// Forget the provider's tokens; `false` if we weren't signed in
+    pub async fn sign_out(&self, name: &str) -> Result<bool, AuthError> {
+        let provider = self.provider(name)?;
+        self.access_tokens.lock().await.remove(&provider.name);
+        Ok(self.secrets.remove(&secret_key(&provider.name)).await?)
+    }
+
+    /// Bearer token to fetch `feed_url` with: `None` for feeds not behind a
+    /// configured provider, an error when signed out or the refresh fails
+    pub async fn bearer_for(&self, feed_url: &str) -> Result<Option<String>, AuthError> {
+        let Some(provider) = self.provider_for_url(feed_url) else {
+            return Ok(None);
+        };
+
+        if let Some(token) = self.access_tokens.lock().await.get(&provider.name) {
+            if token.is_fresh() {
+                return Ok(Some(token.token.clone()));
+            }
+        }
+
+        let key = secret_key(&provider.name);
+        let not_signed_in = || AuthError::NotSignedIn {
+            provider: provider.name.clone(),
+        };
+        let refresh_token = self.secrets.get(&key).await?.ok_or_else(not_signed_in)?;
+        let reply = self
+            .request_token(
+                provider,
+                &[
+                    ("grant_type", "refresh_token"),
+                    ("refresh_token", &refresh_token),
+                    ("client_id", &provider.client_id),
+                ],
+            )
+            .await?;
+        match reply {
+            TokenReply::Granted(token) => {
+                // Providers that rotate refresh tokens invalidate the old one
+                if let Some(rotated) = token.refresh_token.as_deref() {
+                    if rotated != refresh_token {
+                        self.secrets.set(&key, rotated).await?;
+                    }
+                }
+                self.cache_access_token(&provider.name, &token).await;
+                Ok(Some(token.access_token))
+            }
+            TokenReply::Error(error) if error == "invalid_grant" => {
+                // Revoked or expired: a new sign-in is the only way back
+                self.secrets.remove(&key).await?;
+                Err(not_signed_in())
+            }
+            TokenReply::Error(error) => Err(AuthError::Rejected {
+                provider: provider.name.clone(),
+                error,
+            }),
+        }
+    }
+
+    async fn request_token(
+        &self,
+        provider: &OAuthProviderConfig,
+        fields: &[(&str, &str)],
+    ) -> Result<TokenReply, AuthError> {
+        let response = self
+            .http_client
+            .get(HttpRequest::post_form(&provider.token_url, fields))
+            .await?;
+        let status = response.status;
+        let body = response.bytes().await?;
+
+        if (200..300).contains(&status) {
+            return serde_json::from_slice(&body)
+                .map(TokenReply::Granted)
+                .map_err(|e| AuthError::InvalidResponse(e.to_string()));
+        }
+        // Token endpoints answer errors with 400/401 and a JSON `error` code
+        match serde_json::from_slice::<TokenErrorResponse>(&body) {
+            Ok(reply) => Ok(TokenReply::Error(reply.error)),
+            Err(_) => Err(AuthError::Network(HttpError::Status {
+                status,
+                url: provider.token_url.clone(),
+            })),
+        }
+    }
+
+    async fn cache_access_token(&self, provider: &str, token: &TokenResponse) {
+        self.access_tokens.lock().await.insert(
+            provider.to_string(),
+            AccessToken {
+                token: token.access_token.clone(),
+                expires_at: token
+                    .expires_in
+                    .map(|secs| Instant::now() + Duration::from_secs(secs)),
+            },
+        );
+    }
+}
+
+fn secret_key(provider: &str) -> String {
+    format!("oauth:{}", provider.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::http::{MockHttpClient, MockResponse};
+    use tempfile::TempDir;
+
+    const DEVICE_URL: &str = "https://auth.example.com/device";
+    const TOKEN_URL: &str = "https://auth.example.com/token";
+    const FEED_URL: &str = "https://feeds.members.example.com/show.rss";
+
+    fn provider() -> OAuthProviderConfig {
+        OAuthProviderConfig {
+            name: "members".to_string(),
+            hosts: vec!["members.example.com".to_string()],
+            client_id: "client-1".to_string(),
+            device_authorization_url: DEVICE_URL.to_string(),
+            token_url: TOKEN_URL.to_string(),
+            scope: Some("feeds".to_string()),
+        }
+    }
+
+    fn auth(dir: &TempDir, http: Arc<MockHttpClient>) -> FeedAuth {
+        FeedAuth::new(vec![provider()], SecretStore::new(dir.path()), http)
+    }
+
+    fn json_error(status: u16, error: &str) -> MockResponse {
+        MockResponse::status(status).with_body(format!(r#"{{"error":"{error}"}}"#))
+    }
+
+    #[test]
+    fn test_provider_for_url_matches_host_and_subdomains() {
+        let dir = TempDir::new().unwrap();
+        let auth = auth(&dir, MockHttpClient::new().shared());
+
+        assert!(auth.provider_for_url(FEED_URL).is_some());
+        assert!(auth
+            .provider_for_url("https://members.example.com/rss")
+            .is_some());
+        assert!(auth
+            .provider_for_url("https://notmembers.example.com/rss")
+            .is_none());
+        assert!(auth.provider_for_url("not a url").is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_device_flow_polls_until_approved_then_stores_refresh_token() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let http = MockHttpClient::new().shared();
+        http.respond(
+            DEVICE_URL,
+            MockResponse::ok(
+                r#"{"device_code":"dev-1","user_code":"ABCD-EFGH","verification_uri":"https://auth.example.com/activate","expires_in":600,"interval":2}"#,
+            ),
+        );
+        http.respond(TOKEN_URL, json_error(400, "authorization_pending"))
+            .respond(TOKEN_URL, json_error(400, "slow_down"))
+            .respond(
+                TOKEN_URL,
+                MockResponse::ok(
+                    r#"{"access_token":"access-1","refresh_token":"refresh-1","expires_in":3600,"token_type":"Bearer"}"#,
+                ),
+            );
+        let auth = auth(&dir, http.clone());
+
+        // Act
+        let authorization = auth.start_sign_in("members").await.unwrap();
+        auth.finish_sign_in("members", &authorization)
+            .await
+            .unwrap();
+        let bearer = auth.bearer_for(FEED_URL).await.unwrap();
+
+        // Assert
+        assert_eq!(authorization.user_code, "ABCD-EFGH");
+        assert_eq!(bearer.as_deref(), Some("access-1"));
+        assert_eq!(
+            SecretStore::new(dir.path())
+                .get("oauth:members")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("refresh-1")
+        );
+        let requests = http.requests();
+        assert_eq!(requests[0].form_value("scope"), Some("feeds"));
+        assert_eq!(
+            requests[1].form_value("grant_type"),
+            Some(DEVICE_CODE_GRANT)
+        );
+        assert_eq!(requests[1].form_value("device_code"), Some("dev-1"));
+        assert_eq!(http.request_count(TOKEN_URL), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_denied_sign_in_stores_nothing() {
+        let dir = TempDir::new().unwrap();
+        let http = MockHttpClient::new().shared();
+        http.respond(TOKEN_URL, json_error(400, "access_denied"));
+        let auth = auth(&dir, http);
+        let authorization = DeviceAuthorization {
+            device_code: "dev-1".to_string(),
+            user_code: "ABCD".to_string(),
+            verification_uri: "https://auth.example.com/activate".to_string(),
+            verification_uri_complete: None,
+            expires_in: 600,
+            interval: 5,
+        };
+
+        let result = auth.finish_sign_in("members", &authorization).await;
+
+        assert!(matches!(result, Err(AuthError::AccessDenied)));
+        assert!(!auth.is_signed_in("members").await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bearer_refreshes_expired_access_token_and_keeps_rotated_refresh_token() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let secrets = SecretStore::new(dir.path());
+        secrets.set("oauth:members", "refresh-1").await.unwrap();
+        let http = MockHttpClient::new().shared();
+        http.respond(
+            TOKEN_URL,
+            MockResponse::ok(
+                r#"{"access_token":"access-1","refresh_token":"refresh-2","expires_in":120}"#,
+            ),
+        )
+        .respond(
+            TOKEN_URL,
+            MockResponse::ok(r#"{"access_token":"access-2","expires_in":120}"#),
+        );
+        let auth = auth(&dir, http.clone());
+
+        // Act
+        let first = auth.bearer_for(FEED_URL).await.unwrap();
+        let cached = auth.bearer_for(FEED_URL).await.unwrap();
+        tokio::time::advance(Duration::from_secs(90)).await;
+        let refreshed = auth.bearer_for(FEED_URL).await.unwrap();
+
+        // Assert
+        assert_eq!(first.as_deref(), Some("access-1"));
+        assert_eq!(cached.as_deref(), Some("access-1"));
+        assert_eq!(refreshed.as_deref(), Some("access-2"));
+        assert_eq!(http.request_count(TOKEN_URL), 2);
+        assert_eq!(
+            http.requests()[1].form_value("refresh_token"),
+            Some("refresh-2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoked_refresh_token_signs_out() {
+        let dir = TempDir::new().unwrap();
+        let secrets = SecretStore::new(dir.path());
+        secrets.set("oauth:members", "refresh-1").await.unwrap();
+        let http = MockHttpClient::new().shared();
+        http.respond(TOKEN_URL, json_error(400, "invalid_grant"));
+        let auth = auth(&dir, http);
+
+        let result = auth.bearer_for(FEED_URL).await;
+
+        assert!(matches!(result, Err(AuthError::NotSignedIn { .. })));
+        assert!(!auth.is_signed_in("members").await.unwrap());
+        assert_eq!(
+            auth.bearer_for("https://example.org/free.rss")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
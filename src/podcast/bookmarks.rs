@@ -0,0 +1,238 @@
+// Bookmark export - an episode's bookmarks as chapter files
+//
+// `export-bookmarks` writes the bookmarks next to the downloaded audio as a
+// Podcasting 2.0 JSON chapters file (`<audio stem>.chapters.json`) and a CUE
+// sheet (`<audio stem>.cue`), so the markers travel with the file to other
+// players and to whoever it is shared with.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::podcast::Episode;
+
+/// Version of the JSON chapters format written
+const CHAPTERS_VERSION: &str = "1.2.0";
+
+/// Chapter file format bookmarks are exported as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkFormat {
+    /// Podcasting 2.0 JSON chapters
+    Json,
+    /// CUE sheet
+    Cue,
+}
+
+impl BookmarkFormat {
+    pub const ALL: [Self; 2] = [Self::Json, Self::Cue];
+
+    pub const NAMES: &'static str = "json, cue";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "json" | "chapters" => Some(Self::Json),
+            "cue" => Some(Self::Cue),
+            _ => None,
+        }
+    }
+
+    /// Suffix put after the audio file's stem
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Json => ".chapters.json",
+            Self::Cue => ".cue",
+        }
+    }
+
+    /// The file next to `audio` this format is written to
+    pub fn sidecar_path(&self, audio: &Path) -> PathBuf {
+        let stem = audio
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "episode".to_string());
+        audio.with_file_name(format!("{}{}", stem, self.suffix()))
+    }
+
+    /// File contents for `episode`'s bookmarks; `audio` is the file they mark
+    pub fn render(&self, episode: &Episode, audio: &Path) -> String {
+        match self {
+            Self::Json => chapters_json(episode),
+            Self::Cue => {
+                let file_name = audio
+                    .file_name()
+                    .map(|s| s.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                cue_sheet(episode, &file_name)
+            }
+        }
+    }
+}
+
+/// The bookmarks as a Podcasting 2.0 JSON chapters document
+pub fn chapters_json(episode: &Episode) -> String {
+    let chapters: Vec<_> = episode
+        .bookmarks
+        .iter()
+        .map(|bookmark| {
+            serde_json::json!({
+                "startTime": bookmark.position,
+                "title": bookmark.label,
+            })
+        })
+        .collect();
+    let document = serde_json::json!({
+        "version": CHAPTERS_VERSION,
+        "title": episode.display_title(),
+        "chapters": chapters,
+    });
+    serde_json::to_string_pretty(&document).unwrap_or_default()
+}
+
+/// The bookmarks as a CUE sheet over `audio_file`, one track per bookmark.
+/// Tracks must cover the whole file, so a "Start" track is added when the
+/// first bookmark isn't at the beginning.
+pub fn cue_sheet(episode: &Episode, audio_file: &str) -> String {
+    let mut cue = String::new();
+    let _ = writeln!(cue, "TITLE \"{}\"", cue_text(episode.display_title()));
+    let _ = writeln!(
+        cue,
+        "FILE \"{}\" {}",
+        cue_text(audio_file),
+        cue_file_type(audio_file)
+    );
+
+    let starts_at_zero = episode.bookmarks.first().is_some_and(|b| b.position == 0);
+    let tracks = (!starts_at_zero).then_some((0, "Start")).into_iter().chain(
+        episode
+            .bookmarks
+            .iter()
+            .map(|bookmark| (bookmark.position, bookmark.label.as_str())),
+    );
+    for (number, (position, label)) in tracks.enumerate() {
+        let _ = writeln!(cue, "  TRACK {:02} AUDIO", number + 1);
+        let _ = writeln!(cue, "    TITLE \"{}\"", cue_text(label));
+        let _ = writeln!(
+            cue,
+            "    INDEX 01 {:02}:{:02}:00",
+            position / 60,
+            position % 60
+        );
+    }
+    cue
+}
+
+/// Write `episode`'s bookmarks next to its downloaded audio in each of
+/// `formats`, returning the files written
+pub fn export_bookmarks(
+    episode: &Episode,
+    formats: &[BookmarkFormat],
+) -> Result<Vec<PathBuf>, String> {
+    if episode.bookmarks.is_empty() {
+        return Err("the episode has no bookmarks (B adds one while playing)".to_string());
+    }
+    let audio = episode
+        .local_path
+        .as_deref()
+        .filter(|path| path.exists())
+        .ok_or_else(|| "the episode isn't downloaded".to_string())?;
+
+    let mut written = Vec::new();
+    for format in formats {
+        let path = format.sidecar_path(audio);
+        std::fs::write(&path, format.render(episode, audio))
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// CUE strings are double-quoted with no escape syntax
+fn cue_text(text: &str) -> String {
+    text.replace('"', "'").replace(['\r', '\n'], " ")
+}
+
+/// CUE `FILE` type for an audio file name
+fn cue_file_type(file_name: &str) -> &'static str {
+    let extension = Path::new(file_name)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase());
+    match extension.as_deref() {
+        Some("mp3") => "MP3",
+        Some("aif" | "aiff") => "AIFF",
+        _ => "WAVE",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::PodcastId;
+
+    fn bookmarked_episode() -> Episode {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "The \"Big\" Interview".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.add_bookmark(754, Some("Best answer".to_string()));
+        episode.add_bookmark(90, None);
+        episode
+    }
+
+    #[test]
+    fn test_chapters_json_lists_bookmarks_in_order() {
+        let episode = bookmarked_episode();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&chapters_json(&episode)).expect("valid JSON");
+
+        assert_eq!(json["version"], CHAPTERS_VERSION);
+        assert_eq!(json["chapters"][0]["startTime"], 90);
+        assert_eq!(json["chapters"][0]["title"], "Bookmark 2");
+        assert_eq!(json["chapters"][1]["startTime"], 754);
+        assert_eq!(json["chapters"][1]["title"], "Best answer");
+    }
+
+    #[test]
+    fn test_cue_sheet_starts_at_zero_and_quotes_safely() {
+        let episode = bookmarked_episode();
+
+        let cue = cue_sheet(&episode, "big-interview.mp3");
+
+        assert_eq!(
+            cue,
+            "TITLE \"The 'Big' Interview\"\n\
+             FILE \"big-interview.mp3\" MP3\n\
+             \x20 TRACK 01 AUDIO\n\
+             \x20   TITLE \"Start\"\n\
+             \x20   INDEX 01 00:00:00\n\
+             \x20 TRACK 02 AUDIO\n\
+             \x20   TITLE \"Bookmark 2\"\n\
+             \x20   INDEX 01 01:30:00\n\
+             \x20 TRACK 03 AUDIO\n\
+             \x20   TITLE \"Best answer\"\n\
+             \x20   INDEX 01 12:34:00\n"
+        );
+    }
+
+    #[test]
+    fn test_export_bookmarks_writes_next_to_audio() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio = dir.path().join("big-interview.mp3");
+        std::fs::write(&audio, b"audio").unwrap();
+        let mut episode = bookmarked_episode();
+
+        assert!(export_bookmarks(&episode, &BookmarkFormat::ALL).is_err());
+        episode.local_path = Some(audio);
+        let written = export_bookmarks(&episode, &BookmarkFormat::ALL).unwrap();
+
+        assert_eq!(
+            written,
+            vec![
+                dir.path().join("big-interview.chapters.json"),
+                dir.path().join("big-interview.cue"),
+            ]
+        );
+        assert!(written.iter().all(|path| path.exists()));
+    }
+}
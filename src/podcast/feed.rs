@@ -1,23 +1,48 @@
 //! RSS feed parsing and management
 //!
-//! This module handles RSS/Atom feed parsing and metadata extraction
+//! This module handles RSS/Atom/JSON Feed parsing and metadata extraction
 //! for podcast subscriptions.
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use feed_rs::parser;
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::podcast::{Episode, EpisodeStatus, Podcast};
+use crate::constants::network::{
+    FEED_MAX_RETRIES, FEED_REQUESTS_PER_HOST, FEED_RETRY_BASE_DELAY, FEED_RETRY_BUDGET_PER_HOST,
+    FEED_RETRY_BUDGET_WINDOW, MAX_RETRY_AFTER,
+};
+use crate::podcast::auth::{AuthError, FeedAuth};
+use crate::podcast::{AudioVariant, Episode, EpisodeStatus, Person, Podcast};
 use crate::storage::models::{EpisodeId, PodcastId};
+use crate::utils::http::{HttpClient, HttpError, HttpRequest, ReqwestClient};
+use crate::utils::rate_limit::{jittered_backoff, parse_retry_after, HostPoliteness};
 use crate::utils::text::strip_html;
+use crate::utils::user_agent::UserAgentPolicy;
 use crate::utils::validation::validate_feed_url;
 
 /// RSS feed parser and manager
 pub struct FeedParser {
-    http_client: Client,
+    http_client: Arc<dyn HttpClient>,
+    /// Shared by every fetch through this parser (refresh, refresh-all, OPML import)
+    politeness: HostPoliteness,
+    /// User-Agent for podcasts without their own
+    user_agents: UserAgentPolicy,
+    /// Bearer tokens for feeds behind an OAuth provider
+    auth: Option<Arc<FeedAuth>>,
+}
+
+/// A failed feed fetch attempt
+struct FetchFailure {
+    error: FeedError,
+    /// Worth retrying (429, 5xx, timeout, connection failure)
+    transient: bool,
+    retry_after: Option<Duration>,
 }
 
 /// Feed metadata extracted during parsing
@@ -26,11 +51,23 @@ pub struct FeedMetadata {
     pub title: String,
     pub description: Option<String>,
     pub language: Option<String>,
+    /// Channel-level `<itunes:explicit>` flag
+    #[serde(default)]
+    pub explicit: bool,
     pub author: Option<String>,
     pub image_url: Option<String>,
     pub website_url: Option<String>,
     pub last_build_date: Option<DateTime<Utc>>,
     pub total_episodes: usize,
+    /// Channel-level `<itunes:new-feed-url>`: the publisher moved the feed here
+    #[serde(default)]
+    pub new_feed_url: Option<String>,
+    /// Channel-level `<itunes:complete>Yes`: no more episodes will come
+    #[serde(default)]
+    pub complete: bool,
+    /// Channel-level `<podcast:person>` credits
+    #[serde(default)]
+    pub people: Vec<Person>,
 }
 
 /// Errors that can occur during feed parsing
@@ -40,7 +77,7 @@ pub enum FeedError {
     InvalidUrl(String),
 
     #[error("Network error: {0}")]
-    Network(#[from] reqwest::Error),
+    Network(#[from] HttpError),
 
     #[error("Feed parsing failed: {0}")]
     ParseError(String),
@@ -50,20 +87,56 @@ pub enum FeedError {
 
     #[error("No episodes found in feed")]
     NoEpisodes,
+
+    #[error("Feed sign-in failed: {0}")]
+    Auth(#[from] AuthError),
 }
 
 impl FeedParser {
     /// Create a new feed parser
     pub fn new() -> Self {
         let http_client = Client::builder()
-            .user_agent("Mozilla/5.0 (compatible; podcast-tui/1.0; +https://github.com/podcast-tui) AppleWebKit/537.36 (KHTML, like Gecko)")
+            .user_agent(crate::constants::network::FEED_USER_AGENT)
             .timeout(Duration::from_secs(30))
             .connect_timeout(Duration::from_secs(10))
             .redirect(reqwest::redirect::Policy::limited(10)) // Handle up to 10 redirects
             .build()
             .expect("Failed to create HTTP client");
 
-        Self { http_client }
+        Self::with_http_client(Arc::new(ReqwestClient::new(http_client)))
+    }
+
+    /// Create a feed parser that fetches through `http_client` (e.g. a
+    /// `MockHttpClient` in tests)
+    pub fn with_http_client(http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            http_client,
+            politeness: HostPoliteness::new(
+                FEED_REQUESTS_PER_HOST,
+                FEED_RETRY_BUDGET_PER_HOST,
+                FEED_RETRY_BUDGET_WINDOW,
+            ),
+            user_agents: UserAgentPolicy::default(),
+            auth: None,
+        }
+    }
+
+    /// Send the User-Agent chosen by `user_agents` (config-wide settings)
+    pub fn set_user_agent_policy(&mut self, user_agents: UserAgentPolicy) {
+        self.user_agents = user_agents;
+    }
+
+    pub fn user_agent_policy(&self) -> &UserAgentPolicy {
+        &self.user_agents
+    }
+
+    /// Fetch feeds on a configured provider's hosts with its bearer token
+    pub fn set_feed_auth(&mut self, auth: Arc<FeedAuth>) {
+        self.auth = Some(auth);
+    }
+
+    pub fn feed_auth(&self) -> Option<&Arc<FeedAuth>> {
+        self.auth.as_ref()
     }
 
     /// Parse a podcast feed from a URL
@@ -72,25 +145,14 @@ impl FeedParser {
         validate_feed_url(feed_url).map_err(FeedError::ValidationError)?;
 
         // Download the feed
-        let feed_content = self.download_feed(feed_url).await?;
-
-        // Parse the feed content
-        let feed = parser::parse(feed_content.as_bytes())
-            .map_err(|e| FeedError::ParseError(e.to_string()))?;
+        let user_agent = self.user_agents.resolve(None);
+        let feed_content = self.download_feed(feed_url, user_agent.as_deref()).await?;
 
         // Create podcast ID from URL
         let podcast_id = PodcastId::from_url(feed_url);
 
-        // Extract metadata
-        let metadata = self.extract_feed_metadata(&feed);
-
-        // Extract episodes
-        let mut episodes = Vec::new();
-        for (index, entry) in feed.entries.iter().enumerate() {
-            if let Ok(episode) = self.extract_episode(entry, &podcast_id, index) {
-                episodes.push(episode);
-            }
-        }
+        // Parse metadata and episodes
+        let (metadata, episodes) = parse_document(&feed_content, &podcast_id)?;
 
         if episodes.is_empty() {
             return Err(FeedError::NoEpisodes);
@@ -106,10 +168,21 @@ impl FeedParser {
             image_url: metadata.image_url,
             language: metadata.language,
             categories: Vec::new(), // TODO: Extract from feed
-            explicit: false,        // TODO: Extract from iTunes extensions
+            explicit: metadata.explicit,
             last_updated: Utc::now(),
             episodes: Vec::new(), // Episodes IDs will be added as they're saved
             tags: Vec::new(),
+            removed_at: None,
+            history: Vec::new(),
+            user_agent: None,
+            notes: None,
+            download_folder: None,
+            auto_play_next: None,
+            complete: metadata.complete,
+            archived_at: None,
+            download_quality: None,
+            settings: Default::default(),
+            people: metadata.people,
         };
 
         Ok(podcast)
@@ -121,67 +194,148 @@ impl FeedParser {
         feed_url: &str,
         podcast_id: &PodcastId,
     ) -> Result<Vec<Episode>, FeedError> {
+        let (_, episodes) = self.get_feed_update(feed_url, podcast_id).await?;
+        Ok(episodes)
+    }
+
+    /// Get the channel metadata and episodes from a feed in a single download.
+    /// Used by refresh so language/explicit changes reach existing subscriptions.
+    pub async fn get_feed_update(
+        &self,
+        feed_url: &str,
+        podcast_id: &PodcastId,
+    ) -> Result<(FeedMetadata, Vec<Episode>), FeedError> {
         validate_feed_url(feed_url).map_err(FeedError::ValidationError)?;
 
-        let feed_content = self.download_feed(feed_url).await?;
-        let feed = parser::parse(feed_content.as_bytes())
-            .map_err(|e| FeedError::ParseError(e.to_string()))?;
+        let user_agent = self.user_agents.resolve(None);
+        let feed_content = self.download_feed(feed_url, user_agent.as_deref()).await?;
+        parse_document(&feed_content, podcast_id)
+    }
 
-        let mut episodes = Vec::new();
-        for (index, entry) in feed.entries.iter().enumerate() {
-            if let Ok(episode) = self.extract_episode(entry, podcast_id, index) {
-                episodes.push(episode);
-            }
-        }
+    /// `get_feed_update` for a subscribed podcast, sending its own
+    /// User-Agent if it has one
+    pub async fn get_feed_update_for(
+        &self,
+        podcast: &Podcast,
+    ) -> Result<(FeedMetadata, Vec<Episode>), FeedError> {
+        validate_feed_url(&podcast.url).map_err(FeedError::ValidationError)?;
+
+        let user_agent = self.user_agents.resolve(podcast.user_agent.as_deref());
+        let feed_content = self
+            .download_feed(&podcast.url, user_agent.as_deref())
+            .await?;
+        parse_document(&feed_content, &podcast.id)
+    }
 
-        Ok(episodes)
+    /// Feeds advertised by a web page (e.g. a show's homepage) through
+    /// `<link rel="alternate">` tags, in page order
+    pub async fn discover_feeds(&self, page_url: &str) -> Result<Vec<FeedLink>, FeedError> {
+        validate_feed_url(page_url).map_err(FeedError::ValidationError)?;
+        let user_agent = self.user_agents.resolve(None);
+        let page = self.download_feed(page_url, user_agent.as_deref()).await?;
+        Ok(find_feed_links(&page, page_url))
     }
 
     /// Check if a feed URL is valid and accessible
     pub async fn validate_feed(&self, feed_url: &str) -> Result<FeedMetadata, FeedError> {
         validate_feed_url(feed_url).map_err(FeedError::ValidationError)?;
 
-        let feed_content = self.download_feed(feed_url).await?;
-        let feed = parser::parse(feed_content.as_bytes())
-            .map_err(|e| FeedError::ParseError(e.to_string()))?;
+        let user_agent = self.user_agents.resolve(None);
+        let feed_content = self.download_feed(feed_url, user_agent.as_deref()).await?;
+        let (feed, _) = parse_feed_content(&feed_content)?;
 
-        Ok(self.extract_feed_metadata(&feed))
+        let mut metadata = Self::extract_feed_metadata(&feed);
+        metadata.explicit = scan_explicit_flags(&feed_content).channel.unwrap_or(false);
+        Ok(metadata)
     }
 
-    /// Download feed content from URL
-    async fn download_feed(&self, feed_url: &str) -> Result<String, FeedError> {
-        let response = self
-            .http_client
-            .get(feed_url)
-            .header("Accept", "application/rss+xml, application/rdf+xml, application/atom+xml, application/xml, text/xml, */*")
-            .send()
-            .await
-            .map_err(FeedError::Network)?;
-
-        let status = response.status();
-        let _final_url = response.url().clone();
+    /// Download feed content from URL. Requests to one host are capped and
+    /// transient failures are retried with jittered back-off (or after the
+    /// server's `Retry-After`) while the host's retry budget lasts.
+    async fn download_feed(
+        &self,
+        feed_url: &str,
+        user_agent: Option<&str>,
+    ) -> Result<String, FeedError> {
+        let _permit = self.politeness.acquire(feed_url).await;
+        let mut attempt = 0;
+        loop {
+            let failure = match self.fetch_feed_once(feed_url, user_agent).await {
+                Ok(content) => return Ok(content),
+                Err(failure) => failure,
+            };
+
+            if let Some(delay) = failure.retry_after {
+                self.politeness
+                    .defer(feed_url, delay.min(MAX_RETRY_AFTER))
+                    .await;
+            }
+            let retryable = failure.transient
+                && attempt < FEED_MAX_RETRIES
+                && failure
+                    .retry_after
+                    .is_none_or(|delay| delay <= MAX_RETRY_AFTER);
+            if !retryable || !self.politeness.try_retry(feed_url).await {
+                return Err(failure.error);
+            }
 
-        if !status.is_success() {
-            return Err(FeedError::Network(response.error_for_status().unwrap_err()));
+            let delay = failure
+                .retry_after
+                .unwrap_or_else(|| jittered_backoff(FEED_RETRY_BASE_DELAY, attempt));
+            attempt += 1;
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        // Check content type if available (validation only)
-        if let Some(content_type) = response.headers().get("content-type") {
-            if let Ok(ct_str) = content_type.to_str() {
-                // Validate content type but don't log in production
-                if !ct_str.contains("xml") && !ct_str.contains("rss") && !ct_str.contains("atom") {
-                    // Content type validation - could be added to error handling if needed
-                }
+    /// One attempt at downloading a feed
+    async fn fetch_feed_once(
+        &self,
+        feed_url: &str,
+        user_agent: Option<&str>,
+    ) -> Result<String, FetchFailure> {
+        let network_failure = |e: HttpError| FetchFailure {
+            transient: e.is_timeout() || e.is_connect(),
+            error: FeedError::Network(e),
+            retry_after: None,
+        };
+
+        let mut request = HttpRequest::get(feed_url)
+            .header("Accept", "application/rss+xml, application/rdf+xml, application/atom+xml, application/feed+json, application/xml, text/xml, application/json, */*")
+            .user_agent(user_agent);
+        if let Some(auth) = &self.auth {
+            let bearer = auth.bearer_for(feed_url).await.map_err(|e| FetchFailure {
+                transient: matches!(&e, AuthError::Network(http) if http.is_timeout() || http.is_connect()),
+                error: FeedError::Auth(e),
+                retry_after: None,
+            })?;
+            if let Some(token) = bearer {
+                request = request.header("Authorization", format!("Bearer {token}"));
             }
         }
+        let response = self
+            .http_client
+            .get(request)
+            .await
+            .map_err(network_failure)?;
+
+        if !response.is_success() {
+            let status = response.status;
+            let retry_after = response.header("retry-after").and_then(parse_retry_after);
+            return Err(FetchFailure {
+                transient: status == 429 || (500..600).contains(&status),
+                error: FeedError::Network(response.error_for_status().unwrap_err()),
+                retry_after,
+            });
+        }
 
-        let content = response.text().await.map_err(FeedError::Network)?;
+        let content_type = response.header("content-type").map(str::to_string);
+        let bytes = response.bytes().await.map_err(network_failure)?;
 
-        Ok(content)
+        Ok(decode_feed_bytes(&bytes, content_type.as_deref()))
     }
 
     /// Extract feed metadata
-    fn extract_feed_metadata(&self, feed: &feed_rs::model::Feed) -> FeedMetadata {
+    fn extract_feed_metadata(feed: &feed_rs::model::Feed) -> FeedMetadata {
         FeedMetadata {
             title: feed
                 .title
@@ -194,6 +348,7 @@ impl FeedParser {
                 .map(|d| strip_html(&d.content))
                 .filter(|s| !s.is_empty()),
             language: feed.language.clone(),
+            explicit: false,
             author: feed.authors.first().map(|a| a.name.clone()),
             image_url: feed
                 .logo
@@ -203,12 +358,14 @@ impl FeedParser {
             website_url: feed.links.first().map(|l| l.href.clone()),
             last_build_date: feed.updated,
             total_episodes: feed.entries.len(),
+            new_feed_url: None,
+            complete: false,
+            people: Vec::new(),
         }
     }
 
     /// Extract episode from feed entry
     fn extract_episode(
-        &self,
         entry: &feed_rs::model::Entry,
         podcast_id: &PodcastId,
         index: usize,
@@ -222,10 +379,18 @@ impl FeedParser {
             EpisodeId::new()
         };
 
+        // Atom titles may be `type="html"`; RSS titles are plain text
         let title = entry
             .title
             .as_ref()
-            .map(|t| t.content.clone())
+            .map(|t| {
+                if t.content_type.subty() == "html" {
+                    strip_html(&t.content)
+                } else {
+                    t.content.clone()
+                }
+            })
+            .filter(|t| !t.trim().is_empty())
             .unwrap_or_else(|| format!("Episode {}", index + 1));
 
         // Extract and sanitize description
@@ -243,17 +408,20 @@ impl FeedParser {
             .filter(|s| !s.is_empty()); // Filter out empty descriptions
 
         // Find audio enclosure using comprehensive strategy
-        let audio_url = self.extract_audio_url(entry);
+        let audio_url = Self::extract_audio_url(entry);
 
         // Parse duration from iTunes extension or other sources
-        let duration = self.extract_duration(entry);
+        let duration = Self::extract_duration(entry);
 
-        // Get file size from enclosure
+        // Get file size from the enclosure (RSS) or enclosure link (Atom/JSON Feed).
+        // Publishers often omit the length or send 0; that's treated as unknown.
         let file_size = entry
-            .links
+            .media
             .iter()
-            .find(|link| link.length.is_some())
-            .and_then(|link| link.length);
+            .flat_map(|media| media.content.iter())
+            .filter_map(|content| content.size)
+            .chain(entry.links.iter().filter_map(|link| link.length))
+            .find(|size| *size > 0);
 
         // Get published date
         let published = entry.published.or(entry.updated).unwrap_or_else(Utc::now);
@@ -271,6 +439,7 @@ impl FeedParser {
             description,
             audio_url,
             published,
+            published_offset: None, // Filled in from the raw XML by the caller
             duration: duration_secs,
             file_size,
             mime_type: entry
@@ -284,26 +453,42 @@ impl FeedParser {
                 Some(entry.id.clone())
             },
             link: entry.links.first().map(|l| l.href.clone()),
-            image_url: None, // TODO: Extract from entry if available
-            explicit: false, // TODO: Extract from iTunes extensions
+            // <itunes:image> / <media:thumbnail> on the item
+            image_url: entry
+                .media
+                .iter()
+                .flat_map(|media| media.thumbnails.iter())
+                .map(|thumbnail| thumbnail.image.uri.clone())
+                .next(),
+            explicit: false, // Filled in from the raw XML by the caller
             season: None,
             episode_number: None,
             episode_type: None,
-            status: EpisodeStatus::New,
+            status: EpisodeStatus::default(),
             local_path: None,
             last_played_position: None,
             play_count: 0,
+            played_at: None,
             notes: None,
             chapters: Vec::new(),
+            bookmarks: Vec::new(),
             transcript: None,
             favorited: false,
+            content_hash: None,
+            overrides: Default::default(),
+            language: None, // Filled in from the raw XML by the caller
+            translated_description: None,
+            audio_variants: Vec::new(), // Filled in from the raw XML by the caller
+            people: Vec::new(),         // Likewise
+            downloaded_enclosure: None,
+            updated_enclosure: None,
         };
 
         Ok(episode)
     }
 
     /// Extract audio URL from feed entry using multiple strategies
-    fn extract_audio_url(&self, entry: &feed_rs::model::Entry) -> Option<String> {
+    fn extract_audio_url(entry: &feed_rs::model::Entry) -> Option<String> {
         // Strategy 1: Look for media enclosures (RSS 2.0 <enclosure> elements)
         // In feed-rs 2.0, enclosures are stored in the media field as MediaContent objects
         for media_object in &entry.media {
@@ -401,6 +586,15 @@ impl FeedParser {
             }
         }
 
+        // Strategy 7: Video page (YouTube channel feeds, RSS-Bridge); downloaded with yt-dlp
+        if let Some(video_link) = entry
+            .links
+            .iter()
+            .find(|link| crate::download::ytdlp::is_video_page(&link.href))
+        {
+            return Some(video_link.href.clone());
+        }
+
         None
     }
 
@@ -412,13 +606,897 @@ impl FeedParser {
     ///
     /// Implementing this will also unblock the deferred `:filter-duration`
     /// command. See Design Decision #13 in `docs/SEARCH_AND_FILTER.md`.
-    fn extract_duration(&self, _entry: &feed_rs::model::Entry) -> Option<chrono::Duration> {
+    fn extract_duration(_entry: &feed_rs::model::Entry) -> Option<chrono::Duration> {
         // TODO: Parse duration from iTunes extensions when feed-rs supports it
         // For now, return None — duration filter deferred (Decision #13)
         None
     }
 }
 
+/// A feed document parsed from raw bytes, without any network access
+#[derive(Debug, Clone)]
+pub struct ParsedFeed {
+    pub metadata: FeedMetadata,
+    /// Episodes in document order, attached to a placeholder podcast until
+    /// [`ParsedFeed::for_podcast`] assigns the real one
+    pub episodes: Vec<Episode>,
+}
+
+impl ParsedFeed {
+    /// Attach the episodes to `podcast_id`
+    pub fn for_podcast(mut self, podcast_id: &PodcastId) -> Self {
+        for episode in &mut self.episodes {
+            episode.podcast_id = podcast_id.clone();
+        }
+        self
+    }
+}
+
+/// Decode and parse a feed document (RSS, Atom or JSON Feed) from raw bytes.
+///
+/// Pure: no network, file or clock access beyond defaulting missing dates, so
+/// it is the entry point for fuzzing (`fuzz/fuzz_targets/parse_feed_bytes.rs`)
+/// and for tests that parse fixture files. Malformed input of any kind comes
+/// back as [`FeedError::ParseError`], never as a panic.
+pub fn parse_feed_bytes(bytes: &[u8]) -> Result<ParsedFeed, FeedError> {
+    let content = decode_feed_bytes(bytes, None);
+    let (metadata, episodes) = parse_document(&content, &PodcastId::from_url(""))?;
+    Ok(ParsedFeed { metadata, episodes })
+}
+
+/// Channel metadata and episodes from an already decoded feed document
+fn parse_document(
+    feed_content: &str,
+    podcast_id: &PodcastId,
+) -> Result<(FeedMetadata, Vec<Episode>), FeedError> {
+    let (feed, json_items) = parse_feed_content(feed_content)?;
+    let flags = scan_explicit_flags(feed_content);
+    let offsets = scan_item_date_offsets(feed_content);
+    let languages = scan_item_languages(feed_content);
+    let variants = scan_item_audio_variants(feed_content);
+    let (channel_people, item_people) = scan_people(feed_content);
+    let mut metadata = FeedParser::extract_feed_metadata(&feed);
+
+    let mut episodes = Vec::new();
+    for (index, entry) in feed.entries.iter().enumerate() {
+        if let Ok(mut episode) = FeedParser::extract_episode(entry, podcast_id, index) {
+            episode.explicit = flags.episode(index);
+            episode.published_offset = offsets.get(index).copied().flatten();
+            episode.language = languages.get(index).cloned().flatten();
+            episode.audio_variants = variants
+                .get(index)
+                .into_iter()
+                .flatten()
+                .filter(|variant| variant.url != episode.audio_url)
+                .cloned()
+                .collect();
+            episode.people = item_people.get(index).cloned().unwrap_or_default();
+            if let Some(item) = json_items.get(index) {
+                item.apply(&mut episode);
+            }
+            if episode.language.is_none() {
+                episode.language = metadata.language.clone();
+            }
+            episodes.push(episode);
+        }
+    }
+
+    metadata.explicit = flags.channel.unwrap_or(false);
+    metadata.new_feed_url = scan_new_feed_url(feed_content);
+    metadata.complete = scan_complete(feed_content);
+    metadata.people = channel_people;
+
+    Ok((metadata, episodes))
+}
+
+/// Explicit-content flags read straight from the feed XML.
+///
+/// feed-rs only keeps a channel-level rating (and only for the literal `true`),
+/// so `<itunes:explicit>` is scanned separately. Items are recorded in document
+/// order, which matches the order of `feed.entries`.
+#[derive(Debug, Default, PartialEq)]
+struct ExplicitFlags {
+    channel: Option<bool>,
+    items: Vec<Option<bool>>,
+}
+
+impl ExplicitFlags {
+    /// Explicit flag for the entry at `index`; untagged items inherit the channel value
+    fn episode(&self, index: usize) -> bool {
+        self.items
+            .get(index)
+            .copied()
+            .flatten()
+            .or(self.channel)
+            .unwrap_or(false)
+    }
+}
+
+/// Interpret an `<itunes:explicit>` value. Apple has accepted `yes`/`clean`
+/// historically and `true`/`false` since 2019; anything else is treated as unknown.
+fn parse_explicit_value(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "yes" | "true" | "explicit" => Some(true),
+        "no" | "false" | "clean" => Some(false),
+        _ => None,
+    }
+}
+
+/// Collect channel and per-item `explicit` tags (any namespace prefix, e.g.
+/// `itunes:` or `googleplay:`). Malformed XML just stops the scan early.
+fn scan_explicit_flags(xml: &str) -> ExplicitFlags {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut flags = ExplicitFlags::default();
+    let mut in_item = false;
+    let mut in_explicit = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => {
+                    in_item = true;
+                    flags.items.push(None);
+                }
+                b"explicit" if e.name().prefix().is_some() => in_explicit = true,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"explicit" => in_explicit = false,
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_explicit => {
+                let value = t.unescape().ok().and_then(|v| parse_explicit_value(&v));
+                let slot = if in_item {
+                    flags.items.last_mut()
+                } else {
+                    Some(&mut flags.channel)
+                };
+                // The first tag wins when a feed carries both itunes: and googleplay: flags
+                if let Some(slot) = slot.filter(|s| s.is_none()) {
+                    *slot = value;
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    flags
+}
+
+/// UTC offset (seconds east) each item's date was written in, in document order.
+///
+/// feed-rs converts dates to UTC and drops the publisher's zone, so the first
+/// `pubDate` / `published` / `updated` / `dc:date` of every item is re-read here.
+/// Dates that don't parse as RFC 2822 or RFC 3339 leave `None`.
+fn scan_item_date_offsets(xml: &str) -> Vec<Option<i32>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut offsets = Vec::new();
+    let mut in_item = false;
+    let mut in_date = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => {
+                    in_item = true;
+                    offsets.push(None);
+                }
+                b"pubDate" | b"published" | b"updated" | b"date" if in_item => in_date = true,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"pubDate" | b"published" | b"updated" | b"date" => in_date = false,
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_date => {
+                if let Some(slot) = offsets.last_mut().filter(|slot| slot.is_none()) {
+                    *slot = t.unescape().ok().and_then(|value| date_offset(&value));
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    offsets
+}
+
+/// Language each item declares, in document order: an `xml:lang` attribute
+/// on the item or a `<language>` / `<dc:language>` inside it. Items that
+/// don't say leave `None` and take the feed's language.
+fn scan_item_languages(xml: &str) -> Vec<Option<String>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut languages = Vec::new();
+    let mut in_item = false;
+    let mut in_language = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => {
+                    in_item = true;
+                    let lang = e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"xml:lang")
+                        .and_then(|attr| attr.unescape_value().ok())
+                        .and_then(|value| language_tag(&value));
+                    languages.push(lang);
+                }
+                b"language" if in_item => in_language = true,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"language" => in_language = false,
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_language => {
+                if let Some(slot) = languages.last_mut().filter(|slot| slot.is_none()) {
+                    *slot = t.unescape().ok().and_then(|value| language_tag(&value));
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    languages
+}
+
+/// A trimmed, non-empty language tag
+fn language_tag(value: &str) -> Option<String> {
+    Some(value.trim().to_string()).filter(|tag| !tag.is_empty())
+}
+
+/// Offset (seconds east of UTC) of an RFC 2822 or RFC 3339 date
+fn date_offset(value: &str) -> Option<i32> {
+    let value = value.trim();
+    chrono::DateTime::parse_from_rfc2822(value)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(value))
+        .ok()
+        .map(|date| date.offset().local_minus_utc())
+}
+
+/// A feed advertised by a web page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedLink {
+    /// Absolute feed URL
+    pub url: String,
+    /// The link's `title` attribute, when the page gives one
+    pub title: Option<String>,
+}
+
+/// MIME types of `<link rel="alternate">` tags that point at a feed
+const FEED_LINK_TYPES: &[&str] = &[
+    "application/rss+xml",
+    "application/atom+xml",
+    "application/feed+json",
+];
+
+/// Feed autodiscovery: collect `<link rel="alternate" type="application/rss+xml">`
+/// (or Atom) tags from `html`, resolving relative hrefs against `page_url`
+pub fn find_feed_links(html: &str, page_url: &str) -> Vec<FeedLink> {
+    let tag_pattern = regex::Regex::new(r"(?is)<link\b[^>]*>").expect("valid regex");
+    let attr_pattern =
+        regex::Regex::new(r#"(?s)([A-Za-z-]+)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#)
+            .expect("valid regex");
+    let base = url::Url::parse(page_url).ok();
+
+    let mut links: Vec<FeedLink> = Vec::new();
+    for tag in tag_pattern.find_iter(html) {
+        let mut rel = None;
+        let mut kind = None;
+        let mut href = None;
+        let mut title = None;
+        for attr in attr_pattern.captures_iter(tag.as_str()) {
+            let value = attr
+                .get(2)
+                .or_else(|| attr.get(3))
+                .or_else(|| attr.get(4))
+                .map(|value| value.as_str().replace("&amp;", "&").trim().to_string());
+            match attr[1].to_ascii_lowercase().as_str() {
+                "rel" => rel = value,
+                "type" => kind = value,
+                "href" => href = value,
+                "title" => title = value.filter(|title| !title.is_empty()),
+                _ => {}
+            }
+        }
+
+        let is_alternate = rel.is_some_and(|rel| {
+            rel.split_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case("alternate"))
+        });
+        let is_feed = kind.is_some_and(|kind| {
+            FEED_LINK_TYPES
+                .iter()
+                .any(|feed_type| kind.eq_ignore_ascii_case(feed_type))
+        });
+        let Some(href) = href.filter(|href| is_alternate && is_feed && !href.is_empty()) else {
+            continue;
+        };
+        let url = match &base {
+            Some(base) => match base.join(&href) {
+                Ok(url) => url.to_string(),
+                Err(_) => continue,
+            },
+            None => href,
+        };
+        if !links.iter().any(|link| link.url == url) {
+            links.push(FeedLink { url, title });
+        }
+    }
+    links
+}
+
+/// Find a channel-level `<itunes:new-feed-url>`, ignoring anything inside items
+fn scan_new_feed_url(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut in_item = false;
+    let mut in_new_url = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = true,
+                b"new-feed-url" if !in_item => in_new_url = true,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"new-feed-url" => in_new_url = false,
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_new_url => {
+                return t
+                    .unescape()
+                    .ok()
+                    .map(|url| url.trim().to_string())
+                    .filter(|url| !url.is_empty());
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+/// Audio encodings each item offers, in document order: every audio
+/// `<enclosure>`, `<media:content>` and Atom enclosure link, and each
+/// `<podcast:alternateEnclosure>` with its first `<podcast:source>`.
+/// feed-rs keeps neither bitrates nor alternate enclosures.
+fn scan_item_audio_variants(xml: &str) -> Vec<Vec<AudioVariant>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut items: Vec<Vec<AudioVariant>> = Vec::new();
+    let mut in_item = false;
+    // The `<podcast:alternateEnclosure>` being read, waiting for its source
+    let mut alternate: Option<AudioVariant> = None;
+
+    fn attribute(e: &quick_xml::events::BytesStart, name: &[u8]) -> Option<String> {
+        e.attributes()
+            .flatten()
+            .find(|attr| attr.key.local_name().as_ref() == name)
+            .and_then(|attr| attr.unescape_value().ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+    fn number(value: Option<String>) -> Option<f64> {
+        value?.parse::<f64>().ok().filter(|n| *n > 0.0)
+    }
+    fn push(items: &mut [Vec<AudioVariant>], variant: AudioVariant) {
+        let Some(variants) = items.last_mut() else {
+            return;
+        };
+        let audio = match &variant.mime_type {
+            Some(mime) => mime.starts_with("audio/"),
+            None => has_audio_extension(&variant.url),
+        };
+        if audio && !variants.iter().any(|known| known.url == variant.url) {
+            variants.push(variant);
+        }
+    }
+
+    loop {
+        let (e, empty) = match reader.read_event() {
+            Ok(Event::Start(e)) => (e, false),
+            Ok(Event::Empty(e)) => (e, true),
+            Ok(Event::End(e)) => {
+                match e.local_name().as_ref() {
+                    b"item" | b"entry" => in_item = false,
+                    b"alternateEnclosure" => {
+                        if let Some(variant) = alternate.take().filter(|v| !v.url.is_empty()) {
+                            push(&mut items, variant);
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => continue,
+        };
+        match e.local_name().as_ref() {
+            b"item" | b"entry" if !empty => {
+                in_item = true;
+                items.push(Vec::new());
+            }
+            b"enclosure" if in_item => {
+                if let Some(url) = attribute(&e, b"url") {
+                    push(
+                        &mut items,
+                        AudioVariant {
+                            url,
+                            bitrate_kbps: None,
+                            file_size: number(attribute(&e, b"length")).map(|n| n as u64),
+                            mime_type: attribute(&e, b"type"),
+                        },
+                    );
+                }
+            }
+            b"link" if in_item && attribute(&e, b"rel").as_deref() == Some("enclosure") => {
+                if let Some(url) = attribute(&e, b"href") {
+                    push(
+                        &mut items,
+                        AudioVariant {
+                            url,
+                            bitrate_kbps: None,
+                            file_size: number(attribute(&e, b"length")).map(|n| n as u64),
+                            mime_type: attribute(&e, b"type"),
+                        },
+                    );
+                }
+            }
+            // media:content, in kbps and `fileSize`
+            b"content" if in_item => {
+                if let Some(url) = attribute(&e, b"url") {
+                    push(
+                        &mut items,
+                        AudioVariant {
+                            url,
+                            bitrate_kbps: number(attribute(&e, b"bitrate")).map(|n| n as u32),
+                            file_size: number(attribute(&e, b"fileSize")).map(|n| n as u64),
+                            mime_type: attribute(&e, b"type"),
+                        },
+                    );
+                }
+            }
+            // Podcasting 2.0, in bits per second and `length`
+            b"alternateEnclosure" if in_item && !empty => {
+                alternate = Some(AudioVariant {
+                    url: String::new(),
+                    bitrate_kbps: number(attribute(&e, b"bitrate")).map(|n| (n / 1000.0) as u32),
+                    file_size: number(attribute(&e, b"length")).map(|n| n as u64),
+                    mime_type: attribute(&e, b"type"),
+                });
+            }
+            b"source" => {
+                if let Some(variant) = alternate.as_mut().filter(|v| v.url.is_empty()) {
+                    variant.url = attribute(&e, b"uri").unwrap_or_default();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    items
+}
+
+/// Whether `url`'s path ends in an audio file extension
+fn has_audio_extension(url: &str) -> bool {
+    let url = url.to_lowercase();
+    let path = url.split('?').next().unwrap_or(&url);
+    [
+        ".mp3", ".m4a", ".mp4", ".ogg", ".opus", ".wav", ".aac", ".flac",
+    ]
+    .iter()
+    .any(|extension| path.ends_with(extension))
+}
+
+/// `<podcast:person>` credits of the channel and of each item, in document
+/// order. feed-rs doesn't read the Podcasting 2.0 namespace.
+fn scan_people(xml: &str) -> (Vec<Person>, Vec<Vec<Person>>) {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut channel = Vec::new();
+    let mut items: Vec<Vec<Person>> = Vec::new();
+    let mut in_item = false;
+    // The `<podcast:person>` being read, waiting for its name
+    let mut person: Option<Person> = None;
+
+    fn attribute(e: &quick_xml::events::BytesStart, name: &[u8]) -> Option<String> {
+        e.attributes()
+            .flatten()
+            .find(|attr| attr.key.local_name().as_ref() == name)
+            .and_then(|attr| attr.unescape_value().ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => {
+                    in_item = true;
+                    items.push(Vec::new());
+                }
+                b"person" if e.name().prefix().is_some() => {
+                    person = Some(Person {
+                        name: String::new(),
+                        role: attribute(&e, b"role")
+                            .map(|role| role.to_lowercase())
+                            .unwrap_or_else(|| "host".to_string()),
+                        href: attribute(&e, b"href"),
+                        img: attribute(&e, b"img"),
+                    });
+                }
+                _ => {}
+            },
+            Ok(Event::Text(t)) => {
+                if let Some(person) = person.as_mut() {
+                    if let Ok(name) = t.unescape() {
+                        person.name.push_str(name.trim());
+                    }
+                }
+            }
+            Ok(Event::CData(t)) => {
+                if let Some(person) = person.as_mut() {
+                    person
+                        .name
+                        .push_str(String::from_utf8_lossy(&t.into_inner()).trim());
+                }
+            }
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"person" => {
+                    if let Some(person) = person.take().filter(|p| !p.name.is_empty()) {
+                        let people = if in_item {
+                            items.last_mut()
+                        } else {
+                            Some(&mut channel)
+                        };
+                        if let Some(people) = people.filter(|people| !people.contains(&person)) {
+                            people.push(person);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    (channel, items)
+}
+
+/// Whether the channel carries `<itunes:complete>Yes</itunes:complete>`
+fn scan_complete(xml: &str) -> bool {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut in_item = false;
+    let mut in_complete = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = true,
+                b"complete" if !in_item && e.name().prefix().is_some() => in_complete = true,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => in_item = false,
+                b"complete" => in_complete = false,
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_complete => {
+                return t
+                    .unescape()
+                    .is_ok_and(|value| value.trim().eq_ignore_ascii_case("yes"));
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => {}
+        }
+    }
+}
+
+/// Per-item JSON Feed fields that feed-rs does not carry over to its entries
+#[derive(Debug, Default, PartialEq)]
+struct JsonFeedItem {
+    duration: Option<u32>,
+    image_url: Option<String>,
+    published_offset: Option<i32>,
+    language: Option<String>,
+}
+
+impl JsonFeedItem {
+    fn apply(&self, episode: &mut Episode) {
+        if episode.duration.is_none() {
+            episode.duration = self.duration;
+        }
+        if episode.image_url.is_none() {
+            episode.image_url = self.image_url.clone();
+        }
+        if episode.published_offset.is_none() {
+            episode.published_offset = self.published_offset;
+        }
+        if episode.language.is_none() {
+            episode.language = self.language.clone();
+        }
+    }
+}
+
+/// Parse downloaded feed content with feed-rs. JSON Feeds are normalised first
+/// and also yield their per-item extras, in document order (empty for XML feeds);
+/// XML goes through [`repair_feed_xml`]. A panic inside feed-rs on hostile input
+/// is reported as a parse error rather than unwinding through a refresh task.
+fn parse_feed_content(
+    content: &str,
+) -> Result<(feed_rs::model::Feed, Vec<JsonFeedItem>), FeedError> {
+    let (normalized, json_items) = match normalize_json_feed(content) {
+        Some((json, items)) => (json, items),
+        None => (repair_feed_xml(content).into_owned(), Vec::new()),
+    };
+    let bytes = normalized.as_bytes();
+    let feed = std::panic::catch_unwind(|| parser::parse(bytes))
+        .map_err(|_| FeedError::ParseError("feed parser crashed on malformed input".to_string()))?
+        .map_err(|e| FeedError::ParseError(e.to_string()))?;
+    Ok((feed, json_items))
+}
+
+/// Decode a downloaded feed to UTF-8.
+///
+/// The charset comes from, in order: a byte-order mark, the `Content-Type`
+/// header, the XML declaration, then a guess — valid UTF-8 is taken as is and
+/// anything else is read as Windows-1252 (the usual mislabelled "Latin-1").
+/// A non-UTF-8 XML declaration is rewritten so feed-rs doesn't decode twice.
+fn decode_feed_bytes(bytes: &[u8], content_type: Option<&str>) -> String {
+    let header_charset = content_type.and_then(|ct| {
+        ct.split(';')
+            .filter_map(|param| param.trim().strip_prefix("charset="))
+            .find_map(|label| encoding_rs::Encoding::for_label(label.trim_matches('"').as_bytes()))
+    });
+    let declared = || {
+        let head = &bytes[..bytes.len().min(256)];
+        let head = String::from_utf8_lossy(head);
+        xml_declared_encoding(&head)
+            .and_then(|label| encoding_rs::Encoding::for_label(label.as_bytes()))
+    };
+    let encoding = encoding_rs::Encoding::for_bom(bytes)
+        .map(|(encoding, _)| encoding)
+        .or(header_charset)
+        .or_else(declared)
+        .unwrap_or_else(|| {
+            if std::str::from_utf8(bytes).is_ok() {
+                encoding_rs::UTF_8
+            } else {
+                encoding_rs::WINDOWS_1252
+            }
+        });
+
+    let (text, _, _) = encoding.decode(bytes);
+    let text = text.trim_start_matches('\u{feff}');
+    match xml_declared_encoding(text) {
+        Some(label) if !label.eq_ignore_ascii_case("utf-8") => text.replacen(&label, "UTF-8", 1),
+        _ => text.to_string(),
+    }
+}
+
+/// The `encoding` named by a leading `<?xml ... ?>` declaration
+fn xml_declared_encoding(text: &str) -> Option<String> {
+    let declaration = text.trim_start().strip_prefix("<?xml")?;
+    let declaration = &declaration[..declaration.find("?>")?];
+    let value = declaration.split("encoding").nth(1)?.trim_start();
+    let value = value.strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    Some(value[..value.find(quote)?].to_string())
+}
+
+/// Named HTML entities seen in real feeds, which XML doesn't define. feed-rs
+/// silently drops the text around them, leaving empty titles.
+const HTML_ENTITIES: &[(&str, u32)] = &[
+    ("nbsp", 160),
+    ("iexcl", 161),
+    ("copy", 169),
+    ("laquo", 171),
+    ("reg", 174),
+    ("deg", 176),
+    ("middot", 183),
+    ("raquo", 187),
+    ("iquest", 191),
+    ("agrave", 224),
+    ("aacute", 225),
+    ("auml", 228),
+    ("ccedil", 231),
+    ("egrave", 232),
+    ("eacute", 233),
+    ("iacute", 237),
+    ("ntilde", 241),
+    ("oacute", 243),
+    ("ouml", 246),
+    ("uacute", 250),
+    ("uuml", 252),
+    ("ndash", 8211),
+    ("mdash", 8212),
+    ("lsquo", 8216),
+    ("rsquo", 8217),
+    ("ldquo", 8220),
+    ("rdquo", 8221),
+    ("bull", 8226),
+    ("hellip", 8230),
+    ("euro", 8364),
+    ("trade", 8482),
+];
+
+/// Repair the two XML mistakes that make feed-rs lose text: bare `&`
+/// (`Q&A`) and HTML-only named entities (`&rsquo;`). CDATA sections are
+/// copied verbatim since neither is an error there.
+fn repair_feed_xml(xml: &str) -> std::borrow::Cow<'_, str> {
+    if !xml.contains('&') {
+        return std::borrow::Cow::Borrowed(xml);
+    }
+
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+    while !rest.is_empty() {
+        let (markup, cdata) = match rest.find("<![CDATA[") {
+            Some(start) => {
+                let end = rest[start..]
+                    .find("]]>")
+                    .map_or(rest.len(), |end| start + end + 3);
+                (&rest[..start], &rest[start..end])
+            }
+            None => (rest, ""),
+        };
+        repair_entities(markup, &mut out);
+        out.push_str(cdata);
+        rest = &rest[markup.len() + cdata.len()..];
+    }
+    std::borrow::Cow::Owned(out)
+}
+
+fn repair_entities(text: &str, out: &mut String) {
+    let mut pieces = text.split('&');
+    out.push_str(pieces.next().unwrap_or_default());
+    for piece in pieces {
+        let name = piece.find(';').map(|end| &piece[..end]).filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '#')
+        });
+        match name {
+            Some("amp" | "lt" | "gt" | "quot" | "apos") => out.push('&'),
+            Some(name) if name.starts_with('#') => out.push('&'),
+            Some(name) => match HTML_ENTITIES.iter().find(|(entity, _)| *entity == name) {
+                Some((_, code)) => {
+                    out.push_str(&format!("&#{code};"));
+                    out.push_str(&piece[name.len() + 1..]);
+                    continue;
+                }
+                None => out.push_str("&amp;"),
+            },
+            None => out.push_str("&amp;"),
+        }
+        out.push_str(piece);
+    }
+}
+
+/// Read a JSON number that publishers write as an integer, a float or a string
+fn json_feed_number(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .or_else(|| n.as_f64().filter(|f| *f >= 0.0).map(|f| f.round() as u64)),
+        serde_json::Value::String(s) => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|f| *f >= 0.0)
+            .map(|f| f.round() as u64),
+        _ => None,
+    }
+}
+
+/// Rewrite a JSON Feed (1.0 or 1.1) into the strict shape feed-rs accepts.
+///
+/// feed-rs rejects the whole document over common publisher quirks: numeric
+/// item ids, fractional or quoted `size_in_bytes`/`duration_in_seconds`, and
+/// attachments without a `mime_type`. Returns `None` for anything that isn't a
+/// JSON Feed so XML content goes to feed-rs untouched.
+fn normalize_json_feed(content: &str) -> Option<(String, Vec<JsonFeedItem>)> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    let mut root: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+    let is_json_feed = root
+        .get("version")
+        .and_then(|v| v.as_str())
+        .is_some_and(|v| v.contains("jsonfeed.org"));
+    if !is_json_feed {
+        return None;
+    }
+
+    let mut extras = Vec::new();
+    let items = root
+        .get_mut("items")
+        .and_then(|items| items.as_array_mut())
+        .map(|items| items.as_mut_slice())
+        .unwrap_or_default();
+    for item in items.iter_mut().filter_map(|item| item.as_object_mut()) {
+        // Ids must be strings; fall back to the item URL when one is missing
+        let id = match item.get("id") {
+            Some(serde_json::Value::String(_)) => None,
+            Some(serde_json::Value::Null) | None => item.get("url").cloned(),
+            Some(other) => Some(other.to_string().into()),
+        };
+        if let Some(id) = id {
+            item.insert("id".to_string(), id);
+        }
+
+        let mut extra = JsonFeedItem {
+            duration: None,
+            image_url: item
+                .get("image")
+                .or_else(|| item.get("banner_image"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            published_offset: item
+                .get("date_published")
+                .or_else(|| item.get("date_modified"))
+                .and_then(|v| v.as_str())
+                .and_then(date_offset),
+            language: item
+                .get("language")
+                .and_then(|v| v.as_str())
+                .and_then(language_tag),
+        };
+        let attachments = item
+            .get_mut("attachments")
+            .and_then(|a| a.as_array_mut())
+            .map(|a| a.as_mut_slice())
+            .unwrap_or_default();
+        for attachment in attachments.iter_mut().filter_map(|a| a.as_object_mut()) {
+            if !attachment.get("mime_type").is_some_and(|v| v.is_string()) {
+                attachment.insert("mime_type".to_string(), "application/octet-stream".into());
+            }
+            for field in ["size_in_bytes", "duration_in_seconds"] {
+                match attachment.get(field).map(json_feed_number) {
+                    Some(Some(n)) => {
+                        attachment.insert(field.to_string(), n.into());
+                    }
+                    Some(None) => {
+                        attachment.remove(field);
+                    }
+                    None => {}
+                }
+            }
+            if extra.duration.is_none() {
+                extra.duration = attachment
+                    .get("duration_in_seconds")
+                    .and_then(|v| v.as_u64())
+                    .and_then(|secs| u32::try_from(secs).ok());
+            }
+        }
+        extras.push(extra);
+    }
+
+    Some((root.to_string(), extras))
+}
+
 impl Default for FeedParser {
     fn default() -> Self {
         Self::new()
@@ -431,18 +1509,28 @@ mod tests {
 
     #[tokio::test]
     async fn test_feed_parser_creation() {
-        let parser = FeedParser::new();
-        // Just test that it creates successfully
-        assert_eq!(
-            parser
-                .http_client
-                .get("https://example.com")
-                .build()
-                .unwrap()
-                .url()
-                .as_str(),
-            "https://example.com/"
+        // Just test that it creates successfully; fetches go through the
+        // injected client
+        let _parser = FeedParser::new();
+
+        let mock = crate::utils::http::MockHttpClient::new().shared();
+        mock.respond(
+            "https://example.com/feed.xml",
+            crate::utils::http::MockResponse::ok(
+                r#"<rss version="2.0"><channel><title>Mocked</title></channel></rss>"#,
+            ),
         );
+        let parser = FeedParser::with_http_client(mock.clone());
+        let metadata = parser
+            .validate_feed("https://example.com/feed.xml")
+            .await
+            .unwrap();
+
+        assert_eq!(metadata.title, "Mocked");
+        let request = &mock.requests()[0];
+        assert!(request
+            .header_value("accept")
+            .is_some_and(|accept| accept.contains("application/rss+xml")));
     }
 
     #[tokio::test]
@@ -457,6 +1545,484 @@ mod tests {
         // For unit tests, we'd want to mock the HTTP client
     }
 
+    const EXPLICIT_FEED: &str = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Late Night</title>
+    <language>en-us</language>
+    <itunes:explicit>yes</itunes:explicit>
+    <item><title>One</title><itunes:explicit>clean</itunes:explicit></item>
+    <item><title>Two</title></item>
+    <item><title>Three</title><itunes:explicit>true</itunes:explicit></item>
+  </channel>
+</rss>"#;
+
+    #[test]
+    fn test_extract_episode_reads_item_artwork() {
+        let xml = r#"<?xml version="1.0"?>
+            <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel><title>Show</title>
+            <item><title>Ep 1</title><guid>ep1</guid>
+                <enclosure url="https://example.com/ep1.mp3" type="audio/mpeg" length="1"/>
+                <itunes:image href="https://example.com/ep1.jpg"/>
+            </item>
+            </channel></rss>"#;
+        let feed = parser::parse(xml.as_bytes()).unwrap();
+
+        let episode = FeedParser::extract_episode(&feed.entries[0], &PodcastId::new(), 0).unwrap();
+
+        assert_eq!(
+            episode.image_url.as_deref(),
+            Some("https://example.com/ep1.jpg")
+        );
+    }
+
+    #[test]
+    fn test_parse_json_feed_maps_attachments_to_episodes() {
+        // Arrange — numeric id, fractional size/duration, no attachment MIME type
+        let json = r#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Indie Show",
+            "description": "Made at home",
+            "icon": "https://indie.example.com/icon.png",
+            "items": [
+                {
+                    "id": 42,
+                    "url": "https://indie.example.com/ep1",
+                    "title": "Episode 1",
+                    "summary": "First one",
+                    "image": "https://indie.example.com/ep1.png",
+                    "date_published": "2024-01-02T03:04:05Z",
+                    "attachments": [
+                        {"url": "https://indie.example.com/ep1.mp3", "size_in_bytes": 1234.0, "duration_in_seconds": "3600.4"}
+                    ]
+                }
+            ]
+        }"#;
+        assert!(parser::parse(json.as_bytes()).is_err());
+
+        // Act
+        let (feed, json_items) = parse_feed_content(json).unwrap();
+        let metadata = FeedParser::extract_feed_metadata(&feed);
+        let mut episode =
+            FeedParser::extract_episode(&feed.entries[0], &PodcastId::new(), 0).unwrap();
+        json_items[0].apply(&mut episode);
+
+        // Assert
+        assert_eq!(metadata.title, "Indie Show");
+        assert_eq!(metadata.description.as_deref(), Some("Made at home"));
+        assert_eq!(episode.guid.as_deref(), Some("42"));
+        assert_eq!(episode.audio_url, "https://indie.example.com/ep1.mp3");
+        assert_eq!(episode.file_size, Some(1234));
+        assert_eq!(episode.duration, Some(3600));
+        assert_eq!(
+            episode.image_url.as_deref(),
+            Some("https://indie.example.com/ep1.png")
+        );
+        assert_eq!(
+            episode.link.as_deref(),
+            Some("https://indie.example.com/ep1")
+        );
+    }
+
+    #[test]
+    fn test_normalize_json_feed_ignores_other_content() {
+        assert!(normalize_json_feed("<rss><channel/></rss>").is_none());
+        assert!(normalize_json_feed(r#"{"title": "not a feed"}"#).is_none());
+    }
+
+    fn fixture_bytes(name: &str) -> Vec<u8> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/feeds")
+            .join(name);
+        std::fs::read(&path).unwrap()
+    }
+
+    /// Decode and parse a file from the problem-feed corpus in `tests/fixtures/feeds`
+    fn parse_fixture(name: &str) -> (FeedMetadata, Vec<Episode>) {
+        let bytes = fixture_bytes(name);
+        let parsed = parse_feed_bytes(&bytes).unwrap_or_else(|e| panic!("{name}: {e}"));
+        (parsed.metadata, parsed.episodes)
+    }
+
+    #[test]
+    fn test_feed_corpus_atom_enclosures() {
+        let (metadata, episodes) = parse_fixture("atom_enclosures.xml");
+
+        assert_eq!(metadata.title, "Atom Radio");
+        assert_eq!(episodes.len(), 2);
+        assert_eq!(episodes[0].title, "Episode 2: Q&A special");
+        assert_eq!(episodes[0].audio_url, "https://atom.example.com/2.mp3");
+        assert_eq!(episodes[0].file_size, Some(2048));
+        assert_eq!(
+            episodes[0].description.as_deref(),
+            Some("Listener questions answered")
+        );
+        assert_eq!(episodes[1].audio_url, "https://atom.example.com/1.m4a");
+        assert_eq!(episodes[1].file_size, None);
+    }
+
+    #[test]
+    fn test_feed_corpus_missing_enclosure_lengths() {
+        let (_, episodes) = parse_fixture("missing_enclosure_lengths.xml");
+
+        let sizes: Vec<_> = episodes.iter().map(|e| e.file_size).collect();
+        assert_eq!(sizes, vec![None, None, None, Some(31337)]);
+        assert!(episodes.iter().all(|e| e.audio_url.ends_with(".mp3")));
+    }
+
+    #[test]
+    fn test_feed_corpus_cdata_descriptions() {
+        let (metadata, episodes) = parse_fixture("cdata_descriptions.xml");
+
+        assert_eq!(metadata.title, "CDATA Weekly");
+        assert_eq!(metadata.description.as_deref(), Some("A show about markup"));
+        assert_eq!(episodes[0].title, "Tags & Things");
+        assert_eq!(
+            episodes[0].description.as_deref(),
+            Some("Double wrapped one two")
+        );
+        assert_eq!(
+            episodes[0].audio_url,
+            "https://cdata.example.com/1.mp3?source=feed&id=1"
+        );
+    }
+
+    #[test]
+    fn test_feed_corpus_html_entities_and_bare_ampersands() {
+        let (metadata, episodes) = parse_fixture("html_entities.xml");
+
+        assert_eq!(metadata.title, "Q&A Tonight");
+        assert!(metadata.explicit);
+        assert_eq!(episodes[0].title, "It\u{2019}s\u{a0}here \u{2014} finally");
+        assert_eq!(
+            episodes[0].audio_url,
+            "https://entities.example.com/1.mp3?a=1&b=2"
+        );
+    }
+
+    #[test]
+    fn test_feed_corpus_undeclared_namespaces() {
+        let (metadata, episodes) = parse_fixture("undeclared_namespaces.xml");
+
+        assert_eq!(metadata.title, "Sloppy Namespaces");
+        assert!(metadata.explicit);
+        assert!(!episodes[0].explicit);
+        assert_eq!(episodes[0].audio_url, "https://ns.example.com/1.mp3");
+        assert_eq!(episodes[0].file_size, Some(42));
+    }
+
+    #[test]
+    fn test_feed_corpus_charsets() {
+        let (metadata, episodes) = parse_fixture("iso_8859_1.xml");
+        assert_eq!(metadata.title, "Café Crème");
+        assert_eq!(episodes[0].title, "Épisode un");
+
+        let (metadata, episodes) = parse_fixture("windows_1252_undeclared.xml");
+        assert_eq!(metadata.title, "Undeclared \u{2019}quotes\u{2019}");
+        assert_eq!(episodes[0].title, "Naïve \u{201c}guesses\u{201d}");
+
+        let (metadata, episodes) = parse_fixture("utf16_bom.xml");
+        assert_eq!(metadata.title, "Sixteen über");
+        assert_eq!(episodes[0].audio_url, "https://utf16.example.com/1.mp3");
+    }
+
+    #[test]
+    fn test_decode_feed_bytes_prefers_header_charset() {
+        let bytes = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><rss><title>Caf\xe9</title></rss>";
+
+        let decoded = decode_feed_bytes(bytes, Some("application/rss+xml; charset=ISO-8859-1"));
+
+        assert!(decoded.contains("Café"));
+        assert!(decoded.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    }
+
+    #[test]
+    fn test_find_feed_links_resolves_and_filters_alternates() {
+        // Arrange — a typical show homepage head
+        let html = r#"<html><head>
+            <link rel="stylesheet" type="text/css" href="/style.css">
+            <link rel="alternate" type="application/rss+xml" title="The Show" href="/feed.xml?a=1&amp;b=2" />
+            <LINK REL='alternate' TYPE='application/atom+xml' HREF='https://cdn.example.com/atom'>
+            <link rel="alternate" hreflang="de" href="/de/">
+            <link rel="alternate" type="application/rss+xml" href="/feed.xml?a=1&amp;b=2">
+        </head></html>"#;
+
+        // Act
+        let links = find_feed_links(html, "https://example.com/shows/the-show/");
+
+        // Assert
+        assert_eq!(
+            links,
+            vec![
+                FeedLink {
+                    url: "https://example.com/feed.xml?a=1&b=2".to_string(),
+                    title: Some("The Show".to_string()),
+                },
+                FeedLink {
+                    url: "https://cdn.example.com/atom".to_string(),
+                    title: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_explicit_value_accepts_legacy_and_current_spellings() {
+        assert_eq!(parse_explicit_value("Yes"), Some(true));
+        assert_eq!(parse_explicit_value(" true "), Some(true));
+        assert_eq!(parse_explicit_value("clean"), Some(false));
+        assert_eq!(parse_explicit_value("false"), Some(false));
+        assert_eq!(parse_explicit_value("maybe"), None);
+    }
+
+    #[test]
+    fn test_scan_explicit_flags_reads_channel_and_items() {
+        // Act
+        let flags = scan_explicit_flags(EXPLICIT_FEED);
+
+        // Assert
+        assert_eq!(flags.channel, Some(true));
+        assert_eq!(flags.items, vec![Some(false), None, Some(true)]);
+        assert!(!flags.episode(0));
+        assert!(flags.episode(1), "untagged item inherits the channel flag");
+        assert!(flags.episode(2));
+    }
+
+    #[test]
+    fn test_scan_explicit_flags_lines_up_with_feed_entries() {
+        // Arrange
+        let feed = parser::parse(EXPLICIT_FEED.as_bytes()).unwrap();
+
+        // Act
+        let flags = scan_explicit_flags(EXPLICIT_FEED);
+
+        // Assert
+        assert_eq!(flags.items.len(), feed.entries.len());
+        assert_eq!(feed.language.as_deref(), Some("en-us"));
+    }
+
+    #[test]
+    fn test_scan_explicit_flags_without_tags_defaults_to_clean() {
+        let flags = scan_explicit_flags("<rss><channel><item/></channel></rss>");
+        assert_eq!(flags.channel, None);
+        assert!(!flags.episode(0));
+        assert!(!flags.episode(5));
+    }
+
+    #[test]
+    fn test_scan_new_feed_url_reads_channel_tag() {
+        let xml = r#"<rss xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+            <itunes:new-feed-url> https://new.example.com/feed.xml </itunes:new-feed-url>
+            <item><title>Ep</title></item>
+        </channel></rss>"#;
+        assert_eq!(
+            scan_new_feed_url(xml).as_deref(),
+            Some("https://new.example.com/feed.xml")
+        );
+        assert_eq!(scan_new_feed_url(EXPLICIT_FEED), None);
+    }
+
+    #[test]
+    fn test_parse_feed_bytes_keeps_publication_offsets() {
+        let xml = br#"<rss version="2.0"><channel><title>Late Show</title>
+            <item><title>Night</title><guid>a</guid>
+                <pubDate>Tue, 13 Oct 2026 23:30:00 -0400</pubDate></item>
+            <item><title>Undated</title><guid>b</guid></item>
+            <item><title>Odd</title><guid>c</guid>
+                <pubDate>sometime last week</pubDate></item>
+        </channel></rss>"#;
+
+        let parsed = parse_feed_bytes(xml).unwrap();
+
+        let offsets: Vec<_> = parsed.episodes.iter().map(|e| e.published_offset).collect();
+        assert_eq!(offsets, vec![Some(-4 * 3600), None, None]);
+        // Sorting still uses the UTC instant, which is already the next day
+        assert_eq!(
+            parsed.episodes[0].published.to_rfc3339(),
+            "2026-10-14T03:30:00+00:00"
+        );
+        assert_eq!(
+            scan_item_date_offsets(
+                r#"<feed><entry><published>2026-10-14T09:00:00+09:00</published></entry></feed>"#
+            ),
+            vec![Some(9 * 3600)]
+        );
+    }
+
+    #[test]
+    fn test_scan_complete_reads_channel_flag_only() {
+        let complete = r#"<rss xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel><title>Finished</title><itunes:complete>Yes</itunes:complete>
+            <item><title>Finale</title></item></channel></rss>"#;
+        let item_only = r#"<rss xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel><title>Ongoing</title><itunes:complete>no</itunes:complete>
+            <item><title>One</title><itunes:complete>Yes</itunes:complete></item></channel></rss>"#;
+
+        assert!(scan_complete(complete));
+        assert!(!scan_complete(item_only));
+        assert!(!scan_complete(EXPLICIT_FEED));
+    }
+
+    #[test]
+    fn test_scan_people_reads_channel_and_item_credits() {
+        let xml = r#"<rss xmlns:podcast="https://podcastindex.org/namespace/1.0"><channel>
+            <title>Talks</title>
+            <podcast:person href="https://example.com/jane">Jane Host</podcast:person>
+            <podcast:person role="Editor" img="https://example.com/sam.jpg">Sam</podcast:person>
+            <item><title>One</title>
+              <podcast:person role="guest"><![CDATA[Ada Lovelace]]></podcast:person>
+              <podcast:person role="guest">Ada Lovelace</podcast:person>
+              <podcast:person role="guest">  </podcast:person>
+            </item>
+            <item><title>Two</title></item></channel></rss>"#;
+
+        let (channel, items) = scan_people(xml);
+
+        let credits: Vec<_> = channel
+            .iter()
+            .map(|p| (p.name.as_str(), p.role.as_str()))
+            .collect();
+        assert_eq!(credits, vec![("Jane Host", "host"), ("Sam", "editor")]);
+        assert_eq!(channel[0].href.as_deref(), Some("https://example.com/jane"));
+        assert_eq!(channel[1].role_label(), "Editor");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].len(), 1);
+        assert!(items[0][0].is_guest());
+        assert!(items[1].is_empty());
+    }
+
+    #[test]
+    fn test_scan_item_audio_variants_reads_enclosures_and_alternates() {
+        let xml = r#"<rss xmlns:media="http://search.yahoo.com/mrss/"
+            xmlns:podcast="https://podcastindex.org/namespace/1.0"><channel>
+            <item><title>One</title>
+              <enclosure url="https://cdn.example.com/1.mp3" type="audio/mpeg" length="0"/>
+              <media:content url="https://cdn.example.com/1-hq.mp3" type="audio/mpeg" bitrate="192" fileSize="80000000"/>
+              <media:content url="https://cdn.example.com/1.mp4" type="video/mp4"/>
+              <podcast:alternateEnclosure type="audio/opus" bitrate="24000.5">
+                <podcast:source uri="https://cdn.example.com/1.opus"/>
+                <podcast:source uri="ipfs://1.opus"/>
+              </podcast:alternateEnclosure>
+            </item>
+            <item><title>Two</title></item></channel></rss>"#;
+
+        let items = scan_item_audio_variants(xml);
+
+        assert_eq!(items.len(), 2);
+        let urls: Vec<&str> = items[0].iter().map(|v| v.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://cdn.example.com/1.mp3",
+                "https://cdn.example.com/1-hq.mp3",
+                "https://cdn.example.com/1.opus",
+            ]
+        );
+        assert_eq!(items[0][0].file_size, None);
+        assert_eq!(items[0][1].bitrate_kbps, Some(192));
+        assert_eq!(items[0][1].file_size, Some(80_000_000));
+        assert_eq!(items[0][2].bitrate_kbps, Some(24));
+        assert!(items[1].is_empty());
+    }
+
+    #[test]
+    fn test_parse_feed_bytes_reads_item_languages() {
+        let xml = br#"<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+            <channel><title>Bilingual</title><language>en-us</language>
+            <item><title>English</title><guid>a</guid></item>
+            <item><title>Deutsch</title><guid>b</guid><dc:language>de</dc:language></item>
+            <item xml:lang="fr-CA"><title>Francais</title><guid>c</guid></item>
+        </channel></rss>"#;
+
+        let parsed = parse_feed_bytes(xml).unwrap();
+
+        let languages: Vec<_> = parsed
+            .episodes
+            .iter()
+            .map(|e| e.language.as_deref())
+            .collect();
+        // Items without a tag take the channel's language
+        assert_eq!(languages, vec![Some("en-us"), Some("de"), Some("fr-CA")]);
+        assert_eq!(scan_item_languages("<rss><channel/></rss>"), Vec::new());
+    }
+
+    #[test]
+    fn test_extract_audio_url_falls_back_to_video_page() {
+        // Arrange: a YouTube channel feed entry has no audio enclosure
+        let xml = r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/">
+  <title>Channel</title>
+  <entry>
+    <id>yt:video:abc123</id>
+    <title>Video</title>
+    <link rel="alternate" href="https://www.youtube.com/watch?v=abc123"/>
+    <media:group>
+      <media:content url="https://www.youtube.com/v/abc123?version=3" type="application/x-shockwave-flash"/>
+    </media:group>
+  </entry>
+</feed>"#;
+        let feed = parser::parse(xml.as_bytes()).unwrap();
+
+        // Act
+        let url = FeedParser::extract_audio_url(&feed.entries[0]);
+
+        // Assert
+        assert_eq!(
+            url.as_deref(),
+            Some("https://www.youtube.com/watch?v=abc123")
+        );
+    }
+
+    #[test]
+    fn test_parse_feed_bytes_assigns_podcast_id() {
+        let podcast_id = PodcastId::new();
+
+        let parsed = parse_feed_bytes(&fixture_bytes("cdata_descriptions.xml"))
+            .unwrap()
+            .for_podcast(&podcast_id);
+
+        assert!(!parsed.episodes.is_empty());
+        assert!(parsed.episodes.iter().all(|e| e.podcast_id == podcast_id));
+        assert!(matches!(
+            parse_feed_bytes(b"<rss><channel>"),
+            Err(FeedError::ParseError(_))
+        ));
+    }
+
+    // A quick in-tree version of the fuzz target: truncated and corrupted
+    // corpus feeds must fail cleanly, never panic
+    #[test]
+    fn test_parse_feed_bytes_survives_truncated_and_corrupted_feeds() {
+        let names = [
+            "atom_enclosures.xml",
+            "cdata_descriptions.xml",
+            "html_entities.xml",
+            "iso_8859_1.xml",
+            "utf16_bom.xml",
+            "windows_1252_undeclared.xml",
+        ];
+        for name in names {
+            let bytes = fixture_bytes(name);
+            for cut in (0..bytes.len()).step_by(11) {
+                let _ = parse_feed_bytes(&bytes[..cut]);
+
+                let mut corrupted = bytes.clone();
+                corrupted[cut] ^= 0xA5;
+                corrupted.insert(cut, b'&');
+                let _ = parse_feed_bytes(&corrupted);
+            }
+        }
+        for input in [
+            &b""[..],
+            b"\xff\xfe",
+            b"<?xml encoding=\"",
+            b"{\"version\":\"jsonfeed.org\"",
+        ] {
+            assert!(parse_feed_bytes(input).is_err());
+        }
+    }
+
     // Commented out test that depends on Feed::default() which isn't available
     /*
     #[test]
@@ -1,12 +1,33 @@
+pub mod archive;
+pub mod auth;
+pub mod bookmarks;
 pub mod discovery;
 pub mod feed;
 pub mod models;
 pub mod opml;
+pub mod refresh_diff;
+pub mod stats;
 pub mod subscription;
+pub mod translate;
 
 // Re-export main types
+pub use archive::{ArchiveExporter, ArchiveSummary};
+pub use auth::{AuthError, DeviceAuthorization, FeedAuth};
+pub use bookmarks::BookmarkFormat;
 pub use discovery::{DiscoveryError, PodcastIndexClient, PodcastSearchResult};
-pub use feed::{FeedError, FeedMetadata, FeedParser};
-pub use models::{Episode, EpisodeStatus, Podcast, PodcastSubscription};
-pub use opml::{FailedImport, ImportResult, OpmlDocument, OpmlError, OpmlExporter, OpmlParser};
-pub use subscription::{SubscriptionError, SubscriptionManager};
+pub use feed::{parse_feed_bytes, FeedError, FeedLink, FeedMetadata, FeedParser, ParsedFeed};
+pub use models::{
+    format_file_size, AudioVariant, Bookmark, DownloadQuality, DownloadState, Enclosure, Episode,
+    EpisodeField, EpisodeOverrides, EpisodeStatus, Person, PlayState, Podcast, PodcastEvent,
+    PodcastEventKind, PodcastSettings, PodcastSubscription, RetentionPolicy, ShowEnded,
+    TriageState,
+};
+pub use opml::{
+    FailedImport, ImportProgress, ImportResult, OpmlDocument, OpmlError, OpmlExporter,
+    OpmlImportOptions, OpmlParser, OpmlPreview, OpmlPreviewFeed,
+};
+pub use refresh_diff::{ChangedField, EpisodeChange, FieldChange, RefreshDiff};
+pub use stats::{GoalPeriod, GoalProgress, ListeningGoal, ListeningStats, PodcastStats};
+pub use subscription::{
+    HardRefreshScope, HardRefreshSummary, SubscriptionError, SubscriptionManager,
+};
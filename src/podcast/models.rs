@@ -21,6 +21,260 @@ pub struct Podcast {
     /// Defaults to empty for backward compatibility with existing data files.
     #[serde(default)]
     pub tags: Vec<String>,
+    /// When the podcast was unsubscribed. A removed podcast is hidden from lists but
+    /// keeps its episode history until the restore window expires.
+    /// Defaults to `None` for backward compatibility with existing data files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_at: Option<DateTime<Utc>>,
+    /// Subscription activity timeline, oldest first and capped at
+    /// `storage::MAX_PODCAST_HISTORY_EVENTS`. Empty for podcasts saved before it existed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<PodcastEvent>,
+    /// User-Agent preset name or custom string for this feed and its
+    /// downloads; `None` uses the `network` config
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// The user's own notes about the show (`:podcast-note`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Folder under the downloads directory holding this podcast's episodes,
+    /// recorded at its first download so a title change doesn't strand them.
+    /// `None` until then (and for podcasts saved before it existed).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_folder: Option<String>,
+    /// Auto-play this podcast's next unplayed episode when one ends
+    /// (`:auto-play`); `None` follows `audio.auto_play_next`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_play_next: Option<bool>,
+    /// The feed declares the show finished (`<itunes:complete>Yes`)
+    #[serde(default)]
+    pub complete: bool,
+    /// When the user archived the show (`:archive-ended`). Archived shows
+    /// stay subscribed but leave the podcast list and refresh-all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Which encoding to download when the feed offers several
+    /// (`:quality`); `None` takes the feed's main enclosure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_quality: Option<DownloadQuality>,
+    /// Overrides of app-wide settings for this show (the podcast settings
+    /// view, `,` in the podcast list)
+    #[serde(default, skip_serializing_if = "PodcastSettings::is_default")]
+    pub settings: PodcastSettings,
+    /// Hosts and other regulars credited at channel level (`<podcast:person>`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub people: Vec<Person>,
+}
+
+/// Per-podcast overrides; `None` fields follow the config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PodcastSettings {
+    /// Download new episodes when a refresh finds them; `None` follows
+    /// `downloads.auto_download_new`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_download_new: Option<bool>,
+    /// Minimum minutes between refreshes of this feed when refreshing all
+    /// podcasts (here and in the daemon); `None` refreshes it every time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_interval_minutes: Option<u32>,
+    /// Playback speed of this show's episodes; `None` is normal speed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playback_speed: Option<f32>,
+    /// Which downloads to keep; `None` follows `downloads.cleanup_after_days`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionPolicy>,
+}
+
+impl PodcastSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Speed to play the show's episodes at
+    pub fn speed(&self) -> f32 {
+        self.playback_speed.unwrap_or(1.0)
+    }
+}
+
+/// How many of a podcast's downloads to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionPolicy {
+    /// Never delete the show's downloads automatically
+    KeepAll,
+    /// Keep the newest this many downloaded episodes
+    KeepLatest(u32),
+    /// Delete downloads older than this many days
+    DeleteAfterDays(u32),
+}
+
+impl RetentionPolicy {
+    pub fn label(self) -> String {
+        match self {
+            Self::KeepAll => "keep all".to_string(),
+            Self::KeepLatest(count) => format!("keep latest {}", count),
+            Self::DeleteAfterDays(days) => format!("delete after {} days", days),
+        }
+    }
+}
+
+/// Which of an episode's encodings to download
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadQuality {
+    /// The largest encoding (highest bitrate)
+    High,
+    /// The feed's main enclosure
+    #[default]
+    Normal,
+    /// The smallest encoding (lowest bitrate)
+    Low,
+}
+
+impl DownloadQuality {
+    pub const NAMES: &'static str = "high, normal, low";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "high" | "best" => Some(Self::High),
+            "normal" | "default" => Some(Self::Normal),
+            "low" | "smallest" => Some(Self::Low),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Normal => "normal",
+            Self::Low => "low",
+        }
+    }
+}
+
+/// One encoding of an episode's audio: the main enclosure, or another the
+/// feed offers in extra `<enclosure>` / `<media:content>` elements or a
+/// `<podcast:alternateEnclosure>`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioVariant {
+    pub url: String,
+    /// Kilobits per second, when the feed says
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bitrate_kbps: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+impl AudioVariant {
+    /// Bytes this encoding takes: the size the feed gives, else bitrate
+    /// times `duration` (seconds)
+    pub fn estimated_size(&self, duration: Option<u32>) -> Option<u64> {
+        self.file_size.or_else(|| {
+            let kbps = u64::from(self.bitrate_kbps?);
+            Some(kbps * 1000 / 8 * u64::from(duration?))
+        })
+    }
+}
+
+/// Someone a feed credits with a Podcasting 2.0 `<podcast:person>` tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Person {
+    pub name: String,
+    /// Lowercased `role` attribute; the namespace's default is `host`
+    pub role: String,
+    /// Page about the person
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub href: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+}
+
+impl Person {
+    pub fn is_guest(&self) -> bool {
+        self.role == "guest"
+    }
+
+    /// The role for display, capitalized ("Guest", "Cover art designer")
+    pub fn role_label(&self) -> String {
+        let mut chars = self.role.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => "Host".to_string(),
+        }
+    }
+}
+
+/// Why a podcast looks like it has stopped publishing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShowEnded {
+    /// The feed says so (`<itunes:complete>`)
+    Complete,
+    /// Nothing new for longer than `ui.inactive_after_months`
+    Inactive,
+}
+
+impl ShowEnded {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Complete => "complete",
+            Self::Inactive => "inactive",
+        }
+    }
+}
+
+/// One entry in a podcast's activity timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PodcastEvent {
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: PodcastEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum PodcastEventKind {
+    Subscribed,
+    Unsubscribed,
+    Restored,
+    Archived,
+    Unarchived,
+    /// The feed announced a new location via `<itunes:new-feed-url>`
+    FeedUrlChanged {
+        from: String,
+        to: String,
+    },
+    /// Consecutive failures with the same error share one entry; `at` is the first
+    RefreshFailed {
+        error: String,
+        attempts: u32,
+    },
+    /// A refresh that found new episodes
+    NewEpisodes {
+        count: usize,
+    },
+}
+
+impl PodcastEventKind {
+    /// One-line description for the podcast detail timeline
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Subscribed => "Subscribed".to_string(),
+            Self::Unsubscribed => "Unsubscribed".to_string(),
+            Self::Restored => "Restored".to_string(),
+            Self::Archived => "Archived".to_string(),
+            Self::Unarchived => "Taken out of the archive".to_string(),
+            Self::FeedUrlChanged { from, to } => format!("Feed moved: {} → {}", from, to),
+            Self::RefreshFailed { error, attempts: 1 } => format!("Refresh failed: {}", error),
+            Self::RefreshFailed { error, attempts } => {
+                format!("Refresh failed ({}× in a row): {}", attempts, error)
+            }
+            Self::NewEpisodes { count: 1 } => "1 new episode".to_string(),
+            Self::NewEpisodes { count } => format!("{} new episodes", count),
+        }
+    }
 }
 
 impl Podcast {
@@ -39,6 +293,17 @@ impl Podcast {
             last_updated: Utc::now(),
             episodes: Vec::new(),
             tags: Vec::new(),
+            removed_at: None,
+            history: Vec::new(),
+            user_agent: None,
+            notes: None,
+            download_folder: None,
+            auto_play_next: None,
+            complete: false,
+            archived_at: None,
+            download_quality: None,
+            settings: PodcastSettings::default(),
+            people: Vec::new(),
         }
     }
 
@@ -62,6 +327,51 @@ impl Podcast {
         self.tags.contains(&tag)
     }
 
+    /// Check whether this podcast has been unsubscribed and is awaiting purge.
+    pub fn is_removed(&self) -> bool {
+        self.removed_at.is_some()
+    }
+
+    /// Check whether a removed podcast is past its restore window.
+    pub fn is_restore_window_expired(&self, restore_days: u32, now: DateTime<Utc>) -> bool {
+        match self.removed_at {
+            Some(removed_at) => now - removed_at >= chrono::Duration::days(restore_days as i64),
+            None => false,
+        }
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Whether refreshing all podcasts should fetch this feed at `now`: its
+    /// own refresh interval (if any) has passed since the last refresh
+    pub fn refresh_due(&self, now: DateTime<Utc>) -> bool {
+        self.settings
+            .refresh_interval_minutes
+            .is_none_or(|minutes| {
+                now - self.last_updated >= chrono::Duration::minutes(minutes as i64)
+            })
+    }
+
+    /// Whether the show looks finished: the feed declares it complete, or its
+    /// newest episode (`last_episode`) is more than `inactive_months` old.
+    /// `inactive_months` of 0 only trusts the feed.
+    pub fn ended(
+        &self,
+        last_episode: Option<DateTime<Utc>>,
+        inactive_months: u32,
+        now: DateTime<Utc>,
+    ) -> Option<ShowEnded> {
+        if self.complete {
+            return Some(ShowEnded::Complete);
+        }
+        let cutoff = now.checked_sub_months(chrono::Months::new(inactive_months))?;
+        last_episode
+            .filter(|published| inactive_months > 0 && *published < cutoff)
+            .map(|_| ShowEnded::Inactive)
+    }
+
     /// Update the last updated timestamp
     pub fn touch(&mut self) {
         self.last_updated = Utc::now();
@@ -78,6 +388,46 @@ impl Podcast {
     pub fn remove_episode(&mut self, episode_id: &EpisodeId) {
         self.episodes.retain(|id| id != episode_id);
     }
+
+    /// Append an event to the activity timeline, dropping the oldest entries
+    /// beyond the cap. A repeat of the previous refresh failure bumps its count.
+    pub fn record_event(&mut self, kind: PodcastEventKind) {
+        if let (
+            Some(PodcastEvent {
+                kind:
+                    PodcastEventKind::RefreshFailed {
+                        error: last_error,
+                        attempts,
+                    },
+                ..
+            }),
+            PodcastEventKind::RefreshFailed { error, .. },
+        ) = (self.history.last_mut(), &kind)
+        {
+            if last_error == error {
+                *attempts += 1;
+                return;
+            }
+        }
+
+        self.history.push(PodcastEvent {
+            at: Utc::now(),
+            kind,
+        });
+        let excess = self
+            .history
+            .len()
+            .saturating_sub(crate::constants::storage::MAX_PODCAST_HISTORY_EVENTS);
+        self.history.drain(..excess);
+    }
+
+    /// When the podcast was subscribed, if recorded
+    pub fn subscribed_at(&self) -> Option<DateTime<Utc>> {
+        self.history
+            .iter()
+            .find(|event| event.kind == PodcastEventKind::Subscribed)
+            .map(|event| event.at)
+    }
 }
 
 /// Represents a podcast episode
@@ -89,6 +439,10 @@ pub struct Episode {
     pub description: Option<String>,
     pub audio_url: String,
     pub published: DateTime<Utc>,
+    /// UTC offset (seconds east) the feed wrote `published` in, so it can be
+    /// shown on the publisher's clock. `None` for older data and dateless feeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_offset: Option<i32>,
     pub duration: Option<u32>,  // Duration in seconds
     pub file_size: Option<u64>, // File size in bytes
     pub mime_type: Option<String>,
@@ -103,13 +457,135 @@ pub struct Episode {
     pub local_path: Option<PathBuf>,       // Path to downloaded file
     pub last_played_position: Option<u32>, // Last playback position in seconds
     pub play_count: u32,
+    /// When the episode was last marked played, by hand or by finishing it;
+    /// cleared when it is marked unplayed. Lists it in the History buffer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub played_at: Option<DateTime<Utc>>,
     pub notes: Option<String>, // User-added notes
     pub chapters: Vec<Chapter>,
+    /// Positions the user marked while listening, in playback order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bookmarks: Vec<Bookmark>,
     pub transcript: Option<String>,
     /// Whether this episode has been starred/favorited by the user.
     /// Defaults to false for backward compatibility with existing data files.
     #[serde(default)]
     pub favorited: bool,
+    /// SHA-1 of the audio as downloaded (before ID3 tagging), used to spot
+    /// byte-identical episodes across podcasts. `None` for older downloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// Local edits layered over the feed's title, description and artwork.
+    /// Refreshes never touch them.
+    #[serde(default, skip_serializing_if = "EpisodeOverrides::is_empty")]
+    pub overrides: EpisodeOverrides,
+    /// Language the episode is in (e.g. `en-us`): the item's own tag, else
+    /// the feed's. `None` when the feed doesn't say.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The description as rewritten by `translation.command` (`:translate`).
+    /// Dropped when a refresh changes the description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translated_description: Option<String>,
+    /// Other encodings of the audio than `audio_url`, for `:quality`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio_variants: Vec<AudioVariant>,
+    /// People the item itself credits (`<podcast:person>`), e.g. its guests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub people: Vec<Person>,
+    /// The enclosure the download came from. `None` for episodes never
+    /// downloaded, or downloaded before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downloaded_enclosure: Option<Enclosure>,
+    /// The feed's enclosure, when a refresh found it changed after the
+    /// download (re-uploaded audio); cleared by `:redownload`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_enclosure: Option<Enclosure>,
+}
+
+/// An episode's main enclosure as the feed gave it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enclosure {
+    pub url: String,
+    /// Bytes, per the enclosure's `length`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+impl Enclosure {
+    pub fn of(episode: &Episode) -> Self {
+        Self {
+            url: episode.audio_url.clone(),
+            length: episode.file_size.filter(|length| *length > 0),
+        }
+    }
+
+    /// Whether `other` is a different file: another URL, or another length
+    /// when both are known. Query strings are ignored, since some hosts sign
+    /// or tag enclosure URLs afresh on every fetch.
+    pub fn differs_from(&self, other: &Enclosure) -> bool {
+        fn without_query(url: &str) -> &str {
+            url.split(['?', '#']).next().unwrap_or(url)
+        }
+        without_query(&self.url) != without_query(&other.url)
+            || matches!((self.length, other.length), (Some(a), Some(b)) if a != b)
+    }
+}
+
+/// User-edited episode metadata that takes precedence over the feed's values
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EpisodeOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Artwork URL or local image path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+}
+
+impl EpisodeOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.image_url.is_none()
+    }
+
+    /// The override for `field`, if one is set
+    pub fn get(&self, field: EpisodeField) -> Option<&str> {
+        match field {
+            EpisodeField::Title => self.title.as_deref(),
+            EpisodeField::Description => self.description.as_deref(),
+            EpisodeField::Artwork => self.image_url.as_deref(),
+        }
+    }
+}
+
+/// Episode metadata the user can override locally
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpisodeField {
+    Title,
+    Description,
+    Artwork,
+}
+
+impl EpisodeField {
+    pub const NAMES: &'static str = "title, description, artwork";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "title" => Some(Self::Title),
+            "description" | "notes" => Some(Self::Description),
+            "artwork" | "image" => Some(Self::Artwork),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Artwork => "artwork",
+        }
+    }
 }
 
 impl Episode {
@@ -127,6 +603,7 @@ impl Episode {
             description: None,
             audio_url,
             published,
+            published_offset: None,
             duration: None,
             file_size: None,
             mime_type: None,
@@ -137,14 +614,72 @@ impl Episode {
             season: None,
             episode_number: None,
             episode_type: None,
-            status: EpisodeStatus::New,
+            status: EpisodeStatus::default(),
             local_path: None,
             last_played_position: None,
             play_count: 0,
+            played_at: None,
             notes: None,
             chapters: Vec::new(),
+            bookmarks: Vec::new(),
             transcript: None,
             favorited: false,
+            content_hash: None,
+            overrides: EpisodeOverrides::default(),
+            language: None,
+            translated_description: None,
+            audio_variants: Vec::new(),
+            people: Vec::new(),
+            downloaded_enclosure: None,
+            updated_enclosure: None,
+        }
+    }
+
+    /// Title to show and tag with: the local override, else the feed's
+    pub fn display_title(&self) -> &str {
+        self.overrides.title.as_deref().unwrap_or(&self.title)
+    }
+
+    /// Description to show and tag with: the local override, else the feed's
+    pub fn display_description(&self) -> Option<&str> {
+        self.overrides
+            .description
+            .as_deref()
+            .or(self.description.as_deref())
+    }
+
+    /// Episode artwork (URL or local path): the local override, else the feed's
+    pub fn artwork_url(&self) -> Option<&str> {
+        self.overrides
+            .image_url
+            .as_deref()
+            .or(self.image_url.as_deref())
+    }
+
+    /// Current value of `field`, with any local override applied
+    pub fn field_value(&self, field: EpisodeField) -> Option<&str> {
+        match field {
+            EpisodeField::Title => Some(self.display_title()),
+            EpisodeField::Description => self.display_description(),
+            EpisodeField::Artwork => self.artwork_url(),
+        }
+    }
+
+    /// Override `field` locally. Blank text, or text equal to the feed's
+    /// value, removes the override so the feed's value shows again.
+    pub fn set_override(&mut self, field: EpisodeField, value: Option<String>) {
+        let feed_value = match field {
+            EpisodeField::Title => Some(self.title.as_str()),
+            EpisodeField::Description => self.description.as_deref(),
+            EpisodeField::Artwork => self.image_url.as_deref(),
+        };
+        let value = value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty() && Some(value.as_str()) != feed_value);
+        match field {
+            EpisodeField::Title => self.overrides.title = value,
+            EpisodeField::Description => self.overrides.description = value,
+            EpisodeField::Artwork => self.overrides.image_url = value,
         }
     }
 
@@ -158,36 +693,89 @@ impl Episode {
         self.favorited = !self.favorited;
     }
 
+    /// What `updated_enclosure` should become after a refresh that found
+    /// `feed` for this stored episode: the feed's enclosure when it no longer
+    /// matches the downloaded one, else `None`
+    pub fn enclosure_update(&self, feed: &Episode) -> Option<Enclosure> {
+        if self.status.download != DownloadState::Downloaded {
+            return None;
+        }
+        let downloaded = self
+            .downloaded_enclosure
+            .clone()
+            .unwrap_or_else(|| Enclosure::of(self));
+        let current = Enclosure::of(feed);
+        current.differs_from(&downloaded).then_some(current)
+    }
+
     /// Check if the episode is downloaded
     pub fn is_downloaded(&self) -> bool {
-        matches!(self.status, EpisodeStatus::Downloaded)
+        self.status.download == DownloadState::Downloaded
             && self.local_path.as_ref().is_some_and(|p| p.exists())
     }
 
     /// Check if the episode has been played
     pub fn is_played(&self) -> bool {
-        matches!(self.status, EpisodeStatus::Played)
+        self.status.play == PlayState::Played
     }
 
     /// Mark episode as played
     pub fn mark_played(&mut self) {
-        if !matches!(self.status, EpisodeStatus::Played) {
-            self.status = EpisodeStatus::Played;
+        if self.status.play != PlayState::Played {
+            self.status.play = PlayState::Played;
             self.play_count += 1;
+            self.played_at = Some(Utc::now());
         }
     }
 
     /// Mark episode as unplayed
     pub fn mark_unplayed(&mut self) {
-        if matches!(self.status, EpisodeStatus::Played) {
-            self.status = EpisodeStatus::New;
+        if self.status.play == PlayState::Played {
+            self.status.play = PlayState::Unplayed;
+            self.played_at = None;
             // Note: We don't reset play_count as it's historical data
         }
     }
 
+    /// Take the episode out of the inbox without touching its download or play state
+    pub fn archive(&mut self) {
+        self.status.triage = TriageState::Archived;
+    }
+
+    /// Put an archived episode back in the inbox
+    pub fn unarchive(&mut self) {
+        self.status.triage = TriageState::Inbox;
+    }
+
+    /// Bookmark `position` (seconds), labelled `label` or "Bookmark N".
+    /// A bookmark already at that position is relabelled instead.
+    pub fn add_bookmark(&mut self, position: u32, label: Option<String>) -> &Bookmark {
+        let label = label
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| format!("Bookmark {}", self.bookmarks.len() + 1));
+        let index = match self
+            .bookmarks
+            .binary_search_by_key(&position, |b| b.position)
+        {
+            Ok(index) => {
+                self.bookmarks[index].label = label;
+                index
+            }
+            Err(index) => {
+                self.bookmarks.insert(index, Bookmark { position, label });
+                index
+            }
+        };
+        &self.bookmarks[index]
+    }
+
     /// Update playback position
     pub fn update_position(&mut self, position: u32) {
         self.last_played_position = Some(position);
+        if position > 0 && self.status.play == PlayState::Unplayed {
+            self.status.play = PlayState::InProgress;
+        }
 
         // Auto-mark as played if we're near the end (95% or more)
         if let Some(duration) = self.duration {
@@ -197,6 +785,31 @@ impl Episode {
         }
     }
 
+    /// Where to pick a partly played episode back up, in seconds. Played
+    /// episodes start over.
+    pub fn resume_position(&self) -> Option<u32> {
+        if self.status.play == PlayState::Played {
+            return None;
+        }
+        self.last_played_position.filter(|p| *p > 0)
+    }
+
+    /// How far into a partly played episode the saved position is, as a
+    /// percentage; needs the duration
+    pub fn progress_percent(&self) -> Option<u8> {
+        let position = self.resume_position()?;
+        let duration = self.duration.filter(|d| *d > 0)?;
+        Some((position as u64 * 100 / duration as u64).min(99) as u8)
+    }
+
+    /// Seconds left to play from the saved position of a partly played
+    /// episode; needs the duration
+    pub fn remaining_seconds(&self) -> Option<u32> {
+        let position = self.resume_position()?;
+        let duration = self.duration.filter(|d| *d > 0)?;
+        Some(duration.saturating_sub(position))
+    }
+
     /// Get formatted duration string
     pub fn formatted_duration(&self) -> String {
         match self.duration {
@@ -218,46 +831,215 @@ impl Episode {
     /// Get formatted file size string
     pub fn formatted_file_size(&self) -> String {
         match self.file_size {
-            Some(bytes) => {
-                const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
-                let mut size = bytes as f64;
-                let mut unit_index = 0;
-
-                while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-                    size /= 1024.0;
-                    unit_index += 1;
-                }
-
-                format!("{:.1} {}", size, UNITS[unit_index])
-            }
+            Some(bytes) => format_file_size(bytes),
             None => "Unknown".to_string(),
         }
     }
+
+    /// The main enclosure followed by the other encodings
+    pub fn encodings(&self) -> Vec<AudioVariant> {
+        let main = AudioVariant {
+            url: self.audio_url.clone(),
+            bitrate_kbps: None,
+            file_size: self.file_size,
+            mime_type: self.mime_type.clone(),
+        };
+        std::iter::once(main)
+            .chain(self.audio_variants.iter().cloned())
+            .collect()
+    }
+
+    /// The encoding to download at `quality`. `Normal` is the main
+    /// enclosure; `High` and `Low` pick the largest and smallest encoding
+    /// whose size is known or can be estimated, falling back to the main one.
+    pub fn encoding_for(&self, quality: DownloadQuality) -> AudioVariant {
+        let mut encodings = self.encodings();
+        let sized = encodings
+            .iter()
+            .enumerate()
+            .filter_map(|(index, encoding)| Some((encoding.estimated_size(self.duration)?, index)));
+        let chosen = match quality {
+            DownloadQuality::Normal => None,
+            DownloadQuality::High => sized.max(),
+            DownloadQuality::Low => sized.min(),
+        };
+        encodings.swap_remove(chosen.map_or(0, |(_, index)| index))
+    }
 }
 
-/// Episode status tracking
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub enum EpisodeStatus {
-    /// New, not yet downloaded or played
-    New,
-    /// Currently being downloaded
+/// `bytes` in the largest unit that keeps it at or above 1, e.g. `45.2 MB`
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit_index])
+}
+
+/// Episode status tracking, one field per independent dimension, so an
+/// episode can be e.g. downloaded and half-played at the same time.
+///
+/// Older data files stored a single status (`"Downloaded"`, `"Played"`, ...);
+/// those still load, see `LegacyEpisodeStatus`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "StoredEpisodeStatus")]
+pub struct EpisodeStatus {
+    pub download: DownloadState,
+    pub play: PlayState,
+    pub triage: TriageState,
+}
+
+/// Where the episode's audio is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DownloadState {
+    /// Only available from the feed
+    #[default]
+    NotDownloaded,
     Downloading,
-    /// Downloaded but not yet played
     Downloaded,
-    /// Played (either partially or completely)
+    /// The last download attempt failed
+    Failed,
+}
+
+/// How far the user got listening
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayState {
+    #[default]
+    Unplayed,
+    /// Started but not finished
+    InProgress,
     Played,
-    /// Download failed
-    DownloadFailed,
+}
+
+/// Whether the episode still waits for the user's attention
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriageState {
+    #[default]
+    Inbox,
+    /// Put aside by the user
+    Archived,
+}
+
+impl EpisodeStatus {
+    /// Status of an episode that is downloaded and not played yet
+    pub fn downloaded() -> Self {
+        Self {
+            download: DownloadState::Downloaded,
+            ..Self::default()
+        }
+    }
+
+    /// Status of an episode being downloaded
+    pub fn downloading() -> Self {
+        Self {
+            download: DownloadState::Downloading,
+            ..Self::default()
+        }
+    }
+
+    /// Status of an episode whose download failed
+    pub fn download_failed() -> Self {
+        Self {
+            download: DownloadState::Failed,
+            ..Self::default()
+        }
+    }
+
+    /// Status of a played episode that isn't downloaded
+    pub fn played() -> Self {
+        Self {
+            play: PlayState::Played,
+            ..Self::default()
+        }
+    }
+
+    /// Not downloaded (nor being or failing to be) and not started
+    pub fn is_new(&self) -> bool {
+        self.download == DownloadState::NotDownloaded && self.play == PlayState::Unplayed
+    }
 }
 
 impl std::fmt::Display for EpisodeStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            EpisodeStatus::New => write!(f, "New"),
-            EpisodeStatus::Downloading => write!(f, "Downloading"),
-            EpisodeStatus::Downloaded => write!(f, "Downloaded"),
-            EpisodeStatus::Played => write!(f, "Played"),
-            EpisodeStatus::DownloadFailed => write!(f, "Failed"),
+        let download = match self.download {
+            DownloadState::NotDownloaded => None,
+            DownloadState::Downloading => Some("Downloading"),
+            DownloadState::Downloaded => Some("Downloaded"),
+            DownloadState::Failed => Some("Failed"),
+        };
+        let play = match self.play {
+            PlayState::Unplayed => None,
+            PlayState::InProgress => Some("In progress"),
+            PlayState::Played => Some("Played"),
+        };
+        match (download, play) {
+            (Some(download), Some(play)) => write!(f, "{}, {}", download, play.to_lowercase())?,
+            (Some(label), None) | (None, Some(label)) => write!(f, "{}", label)?,
+            (None, None) => write!(f, "New")?,
+        }
+        if self.triage == TriageState::Archived {
+            write!(f, " (archived)")?;
+        }
+        Ok(())
+    }
+}
+
+/// The single-valued status older data files stored
+#[derive(Deserialize)]
+enum LegacyEpisodeStatus {
+    New,
+    Downloading,
+    Downloaded,
+    Played,
+    DownloadFailed,
+}
+
+impl From<LegacyEpisodeStatus> for EpisodeStatus {
+    fn from(legacy: LegacyEpisodeStatus) -> Self {
+        // A played episode's file may still be on disk; storage restores
+        // `Downloaded` for those when it loads them
+        match legacy {
+            LegacyEpisodeStatus::New => Self::default(),
+            LegacyEpisodeStatus::Downloading => Self::downloading(),
+            LegacyEpisodeStatus::Downloaded => Self::downloaded(),
+            LegacyEpisodeStatus::Played => Self::played(),
+            LegacyEpisodeStatus::DownloadFailed => Self::download_failed(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredEpisodeStatus {
+    Legacy(LegacyEpisodeStatus),
+    Current {
+        #[serde(default)]
+        download: DownloadState,
+        #[serde(default)]
+        play: PlayState,
+        #[serde(default)]
+        triage: TriageState,
+    },
+}
+
+impl From<StoredEpisodeStatus> for EpisodeStatus {
+    fn from(stored: StoredEpisodeStatus) -> Self {
+        match stored {
+            StoredEpisodeStatus::Legacy(legacy) => legacy.into(),
+            StoredEpisodeStatus::Current {
+                download,
+                play,
+                triage,
+            } => Self {
+                download,
+                play,
+                triage,
+            },
         }
     }
 }
@@ -295,6 +1077,14 @@ impl Chapter {
     }
 }
 
+/// A position the user marked in an episode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// Seconds from the start
+    pub position: u32,
+    pub label: String,
+}
+
 /// Simple podcast subscription information for UI lists
 #[derive(Debug, Clone)]
 pub struct PodcastSubscription {
@@ -336,6 +1126,80 @@ mod tests {
         assert!(!podcast.explicit);
     }
 
+    #[test]
+    fn test_repeated_refresh_failures_coalesce() {
+        // Arrange
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let failed = |error: &str| PodcastEventKind::RefreshFailed {
+            error: error.to_string(),
+            attempts: 1,
+        };
+
+        // Act
+        podcast.record_event(PodcastEventKind::Subscribed);
+        podcast.record_event(failed("HTTP 404"));
+        podcast.record_event(failed("HTTP 404"));
+        podcast.record_event(failed("timed out"));
+
+        // Assert
+        let kinds: Vec<_> = podcast.history.iter().map(|e| &e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &PodcastEventKind::Subscribed,
+                &PodcastEventKind::RefreshFailed {
+                    error: "HTTP 404".to_string(),
+                    attempts: 2
+                },
+                &failed("timed out"),
+            ]
+        );
+        assert_eq!(
+            podcast.history[1].kind.describe(),
+            "Refresh failed (2× in a row): HTTP 404"
+        );
+        assert!(podcast.subscribed_at().is_some());
+    }
+
+    #[test]
+    fn test_history_is_capped_and_keeps_newest() {
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let max = crate::constants::storage::MAX_PODCAST_HISTORY_EVENTS;
+
+        for count in 1..=max + 5 {
+            podcast.record_event(PodcastEventKind::NewEpisodes { count });
+        }
+
+        assert_eq!(podcast.history.len(), max);
+        assert_eq!(
+            podcast.history.last().unwrap().kind,
+            PodcastEventKind::NewEpisodes { count: max + 5 }
+        );
+    }
+
+    #[test]
+    fn test_history_defaults_for_legacy_podcasts() {
+        let json = r#"{
+            "id": "00000000-0000-0000-0000-000000000001",
+            "title": "Legacy",
+            "url": "https://example.com/feed",
+            "description": null,
+            "author": null,
+            "image_url": null,
+            "language": null,
+            "categories": [],
+            "explicit": false,
+            "last_updated": "2024-01-01T00:00:00Z",
+            "episodes": []
+        }"#;
+
+        let podcast: Podcast = serde_json::from_str(json).unwrap();
+        assert!(podcast.history.is_empty());
+
+        // Empty history is omitted when saving
+        assert!(!serde_json::to_string(&podcast).unwrap().contains("history"));
+    }
+
     #[test]
     fn test_episode_creation() {
         let podcast_id = PodcastId::new();
@@ -348,12 +1212,68 @@ mod tests {
 
         assert_eq!(episode.podcast_id, podcast_id);
         assert_eq!(episode.title, "Test Episode");
-        assert_eq!(episode.status, EpisodeStatus::New);
+        assert_eq!(episode.status, EpisodeStatus::default());
         assert_eq!(episode.play_count, 0);
         assert!(!episode.is_played());
         assert!(!episode.is_downloaded());
     }
 
+    #[test]
+    fn test_refresh_due_waits_out_the_podcast_interval() {
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com".to_string());
+        let now = podcast.last_updated + chrono::Duration::minutes(90);
+        assert!(podcast.refresh_due(now), "no interval refreshes every time");
+
+        podcast.settings.refresh_interval_minutes = Some(120);
+        assert!(!podcast.refresh_due(now));
+        assert!(podcast.refresh_due(now + chrono::Duration::minutes(30)));
+
+        let json = serde_json::to_string(&podcast).unwrap();
+        assert!(json.contains("\"refresh_interval_minutes\":120"));
+        podcast.settings = PodcastSettings::default();
+        let json = serde_json::to_string(&podcast).unwrap();
+        assert!(!json.contains("\"settings\""), "defaults are not written");
+    }
+
+    #[test]
+    fn test_encoding_for_picks_by_size_or_bitrate_estimate() {
+        // Arrange: a 1-hour episode, main enclosure 60 MB
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Long Talk".to_string(),
+            "https://example.com/talk.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.file_size = Some(60_000_000);
+        episode.duration = Some(3600);
+        let variant = |url: &str, kbps| AudioVariant {
+            url: url.to_string(),
+            bitrate_kbps: Some(kbps),
+            file_size: None,
+            mime_type: None,
+        };
+        episode.audio_variants = vec![
+            variant("https://example.com/talk-32.mp3", 32),
+            variant("https://example.com/talk-256.mp3", 256),
+        ];
+
+        // Act + Assert
+        let low = episode.encoding_for(DownloadQuality::Low);
+        assert_eq!(low.url, "https://example.com/talk-32.mp3");
+        assert_eq!(low.estimated_size(episode.duration), Some(14_400_000));
+        let high = episode.encoding_for(DownloadQuality::High);
+        assert_eq!(high.url, "https://example.com/talk-256.mp3");
+        let normal = episode.encoding_for(DownloadQuality::Normal);
+        assert_eq!(normal.url, episode.audio_url);
+
+        // Without a duration the bitrates can't be compared to the main size
+        episode.duration = None;
+        assert_eq!(
+            episode.encoding_for(DownloadQuality::Low).url,
+            episode.audio_url
+        );
+    }
+
     #[test]
     fn test_episode_status_updates() {
         let mut episode = Episode::new(
@@ -366,16 +1286,139 @@ mod tests {
         // Mark as played
         episode.mark_played();
         assert!(episode.is_played());
-        assert_eq!(episode.status, EpisodeStatus::Played);
+        assert_eq!(episode.status, EpisodeStatus::played());
         assert_eq!(episode.play_count, 1);
+        assert!(episode.played_at.is_some());
 
         // Mark as unplayed
         episode.mark_unplayed();
         assert!(!episode.is_played());
-        assert_eq!(episode.status, EpisodeStatus::New);
+        assert!(episode.played_at.is_none());
+        assert_eq!(episode.status, EpisodeStatus::default());
         assert_eq!(episode.play_count, 1); // Play count persists
     }
 
+    #[test]
+    fn test_downloaded_episode_can_be_half_played() {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Test".to_string(),
+            "https://example.com/test.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.duration = Some(1000);
+        episode.status.download = DownloadState::Downloaded;
+
+        episode.update_position(400);
+        assert_eq!(episode.status.play, PlayState::InProgress);
+        assert_eq!(episode.status.download, DownloadState::Downloaded);
+        assert_eq!(episode.status.to_string(), "Downloaded, in progress");
+
+        episode.update_position(990);
+        assert!(episode.is_played());
+        assert_eq!(episode.status.download, DownloadState::Downloaded);
+        assert_eq!(episode.play_count, 1);
+    }
+
+    #[test]
+    fn test_enclosure_update_flags_replaced_audio_of_downloads_only() {
+        // Arrange
+        let mut stored = Episode::new(
+            PodcastId::new(),
+            "Test".to_string(),
+            "https://cdn.example.com/ep.mp3?token=a".to_string(),
+            Utc::now(),
+        );
+        stored.file_size = Some(1000);
+        let mut feed = stored.clone();
+        feed.audio_url = "https://cdn.example.com/ep.mp3?token=b".to_string();
+
+        // Act / Assert — not downloaded, then a re-signed URL of the same file
+        assert_eq!(stored.enclosure_update(&feed), None);
+        stored.status.download = DownloadState::Downloaded;
+        assert_eq!(stored.enclosure_update(&feed), None);
+
+        feed.file_size = Some(1200);
+        assert_eq!(
+            stored.enclosure_update(&feed),
+            Some(Enclosure {
+                url: feed.audio_url.clone(),
+                length: Some(1200),
+            })
+        );
+
+        // The recorded download wins over the stored (maybe converted) size
+        stored.downloaded_enclosure = Some(Enclosure::of(&feed));
+        assert_eq!(stored.enclosure_update(&feed), None);
+        feed.audio_url = "https://cdn.example.com/ep-fixed.mp3".to_string();
+        assert!(stored.enclosure_update(&feed).is_some());
+    }
+
+    #[test]
+    fn test_podcast_ended_by_flag_or_silence() {
+        let now = Utc::now();
+        let mut podcast = Podcast::new("Old Show".to_string(), "https://example.com".to_string());
+        let last_year = Some(now - chrono::Duration::days(400));
+        let last_week = Some(now - chrono::Duration::days(7));
+
+        assert_eq!(podcast.ended(last_year, 12, now), Some(ShowEnded::Inactive));
+        assert_eq!(podcast.ended(last_week, 12, now), None);
+        // 0 months turns the inactivity check off; no episodes says nothing
+        assert_eq!(podcast.ended(last_year, 0, now), None);
+        assert_eq!(podcast.ended(None, 12, now), None);
+
+        podcast.complete = true;
+        assert_eq!(podcast.ended(last_week, 0, now), Some(ShowEnded::Complete));
+    }
+
+    #[test]
+    fn test_resume_position_and_progress() {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Test".to_string(),
+            "https://example.com/test.mp3".to_string(),
+            Utc::now(),
+        );
+        assert_eq!(episode.resume_position(), None);
+
+        episode.update_position(300);
+        assert_eq!(episode.resume_position(), Some(300));
+        // No duration, no percentage
+        assert_eq!(episode.progress_percent(), None);
+        episode.duration = Some(1200);
+        assert_eq!(episode.progress_percent(), Some(25));
+
+        // A finished episode starts over
+        episode.update_position(1190);
+        assert_eq!(episode.resume_position(), None);
+        assert_eq!(episode.progress_percent(), None);
+    }
+
+    #[test]
+    fn test_episode_status_round_trips_and_loads_legacy_values() {
+        let status = EpisodeStatus {
+            download: DownloadState::Downloaded,
+            play: PlayState::InProgress,
+            triage: TriageState::Archived,
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            serde_json::from_str::<EpisodeStatus>(&json).unwrap(),
+            status
+        );
+
+        // Older files stored one status value
+        let legacy = |value: &str| serde_json::from_str::<EpisodeStatus>(value).unwrap();
+        assert_eq!(legacy(r#""New""#), EpisodeStatus::default());
+        assert_eq!(legacy(r#""Downloaded""#), EpisodeStatus::downloaded());
+        assert_eq!(legacy(r#""Played""#), EpisodeStatus::played());
+        assert_eq!(
+            legacy(r#""DownloadFailed""#),
+            EpisodeStatus::download_failed()
+        );
+        assert_eq!(legacy(r#"{"play":"Played"}"#), EpisodeStatus::played());
+    }
+
     #[test]
     fn test_duration_formatting() {
         let mut episode = Episode::new(
@@ -518,6 +1561,52 @@ mod tests {
         assert!(!episode.favorited);
     }
 
+    #[test]
+    fn test_episode_overrides_layer_over_feed_values() {
+        // Arrange
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Feed Title".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.image_url = Some("https://example.com/feed.jpg".to_string());
+
+        // Act
+        episode.set_override(EpisodeField::Title, Some("  My Title ".to_string()));
+        episode.set_override(EpisodeField::Description, Some("My notes".to_string()));
+        episode.set_override(
+            EpisodeField::Artwork,
+            Some("https://example.com/feed.jpg".to_string()),
+        );
+
+        // Assert — feed data is untouched; an override equal to it is dropped
+        assert_eq!(episode.title, "Feed Title");
+        assert_eq!(episode.display_title(), "My Title");
+        assert_eq!(episode.display_description(), Some("My notes"));
+        assert_eq!(episode.overrides.image_url, None);
+
+        episode.set_override(EpisodeField::Title, Some("   ".to_string()));
+        assert_eq!(episode.display_title(), "Feed Title");
+    }
+
+    #[test]
+    fn test_episode_overrides_skipped_when_empty() {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Plain".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            Utc::now(),
+        );
+        let json = serde_json::to_string(&episode).unwrap();
+        assert!(!json.contains("overrides"));
+
+        episode.set_override(EpisodeField::Title, Some("Edited".to_string()));
+        let json = serde_json::to_string(&episode).unwrap();
+        let restored: Episode = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.display_title(), "Edited");
+    }
+
     #[test]
     fn test_podcast_tags_default_to_empty() {
         // Arrange
@@ -638,4 +1727,51 @@ mod tests {
         // Assert: missing tags field defaults to empty vec (backward compatible)
         assert!(podcast.tags.is_empty());
     }
+
+    #[test]
+    fn test_podcast_removed_at_defaults_on_missing_field() {
+        // Arrange: JSON without the removed_at field (simulates old data files)
+        let json = r#"{
+            "id": "00000000-0000-0000-0000-000000000001",
+            "title": "Old Podcast",
+            "url": "https://example.com/feed.xml",
+            "description": null,
+            "author": null,
+            "image_url": null,
+            "language": null,
+            "categories": [],
+            "explicit": false,
+            "last_updated": "2024-01-01T00:00:00Z",
+            "episodes": []
+        }"#;
+
+        // Act
+        let podcast: Podcast = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert!(podcast.removed_at.is_none());
+        assert!(!podcast.is_removed());
+    }
+
+    #[test]
+    fn test_podcast_restore_window_expiry() {
+        // Arrange
+        let now = Utc::now();
+        let mut podcast = Podcast::new(
+            "Removed Show".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+
+        // Assert: active podcasts never expire
+        assert!(!podcast.is_restore_window_expired(0, now));
+
+        // Act
+        podcast.removed_at = Some(now - chrono::Duration::days(3));
+
+        // Assert
+        assert!(podcast.is_removed());
+        assert!(!podcast.is_restore_window_expired(7, now));
+        assert!(podcast.is_restore_window_expired(3, now));
+        assert!(podcast.is_restore_window_expired(0, now));
+    }
 }
@@ -37,13 +37,17 @@ use regex::Regex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::constants::opml;
 use crate::podcast::Podcast;
+use crate::utils::http::{HttpClient, HttpError, HttpRequest, ReqwestClient};
 
 /// OPML parser for importing podcast subscriptions
 pub struct OpmlParser {
-    client: Client,
+    client: Arc<dyn HttpClient>,
 }
 
 impl OpmlParser {
@@ -55,6 +59,12 @@ impl OpmlParser {
             .build()
             .expect("Failed to create HTTP client");
 
+        Self::with_http_client(Arc::new(ReqwestClient::new(client)))
+    }
+
+    /// Create an OPML parser that downloads through `client` (e.g. a
+    /// `MockHttpClient` in tests)
+    pub fn with_http_client(client: Arc<dyn HttpClient>) -> Self {
         Self { client }
     }
 
@@ -168,18 +178,11 @@ impl OpmlParser {
     async fn download_opml(&self, url: &str) -> Result<String, OpmlError> {
         let response = self
             .client
-            .get(url)
-            .send()
-            .await
-            .map_err(OpmlError::NetworkError)?;
-
-        if !response.status().is_success() {
-            return Err(OpmlError::NetworkError(
-                response.error_for_status().unwrap_err(),
-            ));
-        }
+            .get(HttpRequest::get(url))
+            .await?
+            .error_for_status()?;
 
-        let content = response.text().await.map_err(OpmlError::NetworkError)?;
+        let content = response.text().await?;
 
         Ok(content)
     }
@@ -280,6 +283,60 @@ impl OpmlExporter {
         Ok(())
     }
 
+    /// A minimal OPML document for `podcasts`: titles and feed URLs only,
+    /// unindented, small enough to hand over as a QR code
+    pub fn snippet(&self, podcasts: &[Podcast]) -> Result<String, OpmlError> {
+        let outlines = podcasts
+            .iter()
+            .map(|p| OpmlOutlineRaw {
+                outline_type: Some("rss".to_string()),
+                text: Some(p.title.clone()),
+                title: None,
+                xml_url: Some(p.url.clone()),
+                url: None,
+                description: None,
+                outlines: None,
+            })
+            .collect();
+        let opml = OpmlRoot {
+            version: "2.0".to_string(),
+            head: None,
+            body: OpmlBodyRaw { outlines },
+        };
+        to_string(&opml)
+            .map_err(|e| OpmlError::ParseError(format!("Failed to generate XML: {}", e)))
+    }
+
+    /// An OPML document of the feeds an import couldn't add, each preceded by
+    /// a comment giving the reason, ready to be fixed up and imported again
+    pub fn failures(&self, failed: &[FailedImport]) -> String {
+        let escape = |text: &str| quick_xml::escape::escape(text).into_owned();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<opml version=\"2.0\">\n  <head>\n");
+        xml.push_str("    <title>Failed OPML Imports</title>\n");
+        xml.push_str(&format!(
+            "    <dateCreated>{}</dateCreated>\n  </head>\n  <body>\n",
+            Utc::now().to_rfc2822()
+        ));
+        for failure in failed {
+            // "--" may not appear inside an XML comment
+            let reason = failure.error.replace("--", "- -");
+            xml.push_str(&format!(
+                "    <!-- Failed: {} -->\n",
+                reason.trim_end_matches('-')
+            ));
+            let title = failure.title.as_deref().unwrap_or(&failure.url);
+            xml.push_str(&format!(
+                "    <outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\"/>\n",
+                escape(title),
+                escape(title),
+                escape(&failure.url)
+            ));
+        }
+        xml.push_str("  </body>\n</opml>\n");
+        xml
+    }
+
     /// Generate OPML XML from podcast list
     fn generate_opml(&self, podcasts: &[Podcast]) -> Result<String, OpmlError> {
         let outlines: Vec<OpmlOutlineRaw> = podcasts
@@ -354,6 +411,26 @@ impl OpmlOutline {
     }
 }
 
+/// An OPML file's feeds, looked over before importing any of them
+#[derive(Debug, Clone)]
+pub struct OpmlPreview {
+    /// File path or URL the document was read from
+    pub source: String,
+    pub document: OpmlDocument,
+    /// One entry per distinct feed URL, in document order
+    pub feeds: Vec<OpmlPreviewFeed>,
+}
+
+/// One feed of an [`OpmlPreview`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpmlPreviewFeed {
+    pub url: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Already in the library; left unchecked and skipped by the import
+    pub subscribed: bool,
+}
+
 /// Result of OPML import operation
 #[derive(Debug, Clone)]
 pub struct ImportResult {
@@ -361,6 +438,10 @@ pub struct ImportResult {
     pub imported: usize,
     pub skipped: usize,
     pub failed: Vec<FailedImport>,
+    /// Feeds never attempted because the import was cancelled
+    pub cancelled: usize,
+    /// OPML file listing just the failed feeds, written when any failed
+    pub failed_opml_path: Option<String>,
 }
 
 impl ImportResult {
@@ -371,18 +452,34 @@ impl ImportResult {
             imported: 0,
             skipped: 0,
             failed: Vec::new(),
+            cancelled: 0,
+            failed_opml_path: None,
         }
     }
 
     /// Format summary for display
     pub fn summary(&self) -> String {
-        format!(
+        let mut summary = format!(
             "Total: {}, Imported: {}, Skipped: {}, Failed: {}",
             self.total_feeds,
             self.imported,
             self.skipped,
             self.failed.len()
-        )
+        );
+        if self.cancelled > 0 {
+            summary.push_str(&format!(", Cancelled: {}", self.cancelled));
+        }
+        summary
+    }
+
+    /// Number of feeds that have been processed (imported, skipped or failed)
+    pub fn processed(&self) -> usize {
+        self.imported + self.skipped + self.failed.len()
+    }
+
+    /// Check if the import was stopped before every feed was attempted
+    pub fn was_cancelled(&self) -> bool {
+        self.cancelled > 0
     }
 
     /// Check if any imports failed
@@ -418,6 +515,62 @@ pub struct FailedImport {
     pub error: String,
 }
 
+/// Tuning for bulk OPML imports
+#[derive(Debug, Clone)]
+pub struct OpmlImportOptions {
+    /// Maximum number of feeds fetched at the same time (minimum 1)
+    pub concurrency: usize,
+    /// Minimum spacing between requests to the same host
+    pub per_host_interval: Duration,
+    /// Set to `true` to stop starting new feeds; in-flight feeds finish
+    pub cancel: Arc<AtomicBool>,
+}
+
+impl OpmlImportOptions {
+    pub fn new(concurrency: usize, per_host_interval: Duration) -> Self {
+        Self {
+            concurrency: concurrency.max(1),
+            per_host_interval,
+            cancel: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Request cancellation of the running import
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for OpmlImportOptions {
+    fn default() -> Self {
+        Self::new(
+            opml::MAX_PARALLEL_IMPORTS,
+            Duration::from_millis(opml::DEFAULT_PER_HOST_INTERVAL_MS),
+        )
+    }
+}
+
+/// Live counts reported while an import runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportProgress {
+    pub total: usize,
+    /// Imported or skipped
+    pub done: usize,
+    pub failed: usize,
+    pub status: String,
+}
+
+impl ImportProgress {
+    /// Feeds not yet finished
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.done + self.failed)
+    }
+}
+
 /// OPML operation errors
 #[derive(Debug, thiserror::Error)]
 pub enum OpmlError {
@@ -425,7 +578,7 @@ pub enum OpmlError {
     FileRead(#[from] std::io::Error),
 
     #[error("Failed to download OPML: {0}")]
-    NetworkError(#[from] reqwest::Error),
+    NetworkError(#[from] HttpError),
 
     #[error("Invalid OPML format: {0}")]
     InvalidFormat(String),
@@ -496,6 +649,22 @@ struct OpmlOutlineRaw {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_snippet_lists_titles_and_feeds_only() {
+        let mut podcast = Podcast::new(
+            "Rust & Friends".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        podcast.description = Some("A long description".to_string());
+
+        let snippet = OpmlExporter::new().snippet(&[podcast]).unwrap();
+
+        assert_eq!(
+            snippet,
+            r#"<opml version="2.0"><body><outline type="rss" text="Rust &amp; Friends" xmlUrl="https://example.com/feed.xml"/></body></opml>"#
+        );
+    }
+
     #[test]
     fn test_is_url() {
         assert!(OpmlParser::is_url("http://example.com/feed.opml"));
@@ -552,6 +721,46 @@ mod tests {
         assert_eq!(doc.outlines[0].text, "Test Podcast");
     }
 
+    #[tokio::test]
+    async fn test_failures_opml_reimports_with_reasons_as_comments() {
+        // Arrange
+        let failed = vec![
+            FailedImport {
+                url: "https://example.com/a.xml?x=1&y=2".to_string(),
+                title: Some("Tom & Jerry".to_string()),
+                error: "HTTP 404 -- not found".to_string(),
+            },
+            FailedImport {
+                url: "https://example.com/b.xml".to_string(),
+                title: None,
+                error: "Timed out after 30s".to_string(),
+            },
+        ];
+
+        // Act
+        let xml = OpmlExporter::new().failures(&failed);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("failed.opml");
+        tokio::fs::write(&path, &xml).await.unwrap();
+        let doc = OpmlParser::new()
+            .parse(path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(xml.contains("<!-- Failed: HTTP 404 - - not found -->"));
+        assert!(xml.contains("<!-- Failed: Timed out after 30s -->"));
+        let feeds: Vec<_> = doc.outlines.iter().map(|o| o.feed_url().unwrap()).collect();
+        assert_eq!(
+            feeds,
+            vec![
+                "https://example.com/a.xml?x=1&y=2",
+                "https://example.com/b.xml"
+            ]
+        );
+        assert_eq!(doc.outlines[0].text, "Tom & Jerry");
+    }
+
     #[test]
     fn test_import_result_summary() {
         let mut result = ImportResult::new(10);
@@ -568,6 +777,33 @@ mod tests {
         assert!(summary.contains("Imported: 7"));
         assert!(summary.contains("Skipped: 2"));
         assert!(summary.contains("Failed: 1"));
+        assert!(!summary.contains("Cancelled"));
+        assert_eq!(result.processed(), 10);
+    }
+
+    #[test]
+    fn test_import_options_cancel_and_minimum_concurrency() {
+        // Arrange
+        let options = OpmlImportOptions::new(0, Duration::ZERO);
+        let shared = options.clone();
+
+        // Act
+        shared.cancel();
+
+        // Assert
+        assert_eq!(options.concurrency, 1);
+        assert!(options.is_cancelled());
+    }
+
+    #[test]
+    fn test_import_progress_remaining() {
+        let progress = ImportProgress {
+            total: 300,
+            done: 40,
+            failed: 2,
+            status: String::new(),
+        };
+        assert_eq!(progress.remaining(), 258);
     }
 
     #[tokio::test]
@@ -588,6 +824,17 @@ mod tests {
             last_updated: Utc::now(),
             episodes: vec![],
             tags: vec![],
+            removed_at: None,
+            history: Vec::new(),
+            user_agent: None,
+            notes: None,
+            download_folder: None,
+            auto_play_next: None,
+            complete: false,
+            archived_at: None,
+            download_quality: None,
+            settings: Default::default(),
+            people: Vec::new(),
         }];
 
         let temp_dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,179 @@
+//! What a feed refresh changed, for the `:refresh-diff` view
+//!
+//! Built while a refresh matches freshly parsed episodes against the stored
+//! ones, so it reflects exactly the matching the refresh itself used.
+
+use chrono::{DateTime, Utc};
+
+use crate::podcast::Episode;
+use crate::storage::{EpisodeId, PodcastId};
+
+/// An episode field that differs between the stored copy and the feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangedField {
+    Title,
+    Description,
+    Enclosure,
+}
+
+impl ChangedField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Title => "Title",
+            Self::Description => "Description",
+            Self::Enclosure => "Enclosure",
+        }
+    }
+}
+
+/// One field's stored and feed values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: ChangedField,
+    pub before: String,
+    pub after: String,
+}
+
+/// A stored episode whose feed entry now differs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpisodeChange {
+    pub episode_id: EpisodeId,
+    /// The stored title, so renamed episodes are still recognisable
+    pub title: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Everything one refresh of one podcast found
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshDiff {
+    pub podcast_id: PodcastId,
+    pub podcast_title: String,
+    pub refreshed_at: DateTime<Utc>,
+    /// Hard refreshes write `updated` back to storage; normal ones only report it
+    pub hard: bool,
+    /// Titles of episodes that weren't stored before
+    pub new: Vec<String>,
+    pub updated: Vec<EpisodeChange>,
+    /// Titles of stored episodes the feed no longer lists (kept locally)
+    pub removed: Vec<String>,
+    /// Titles of downloaded episodes whose audio the feed replaced since
+    /// (newly flagged by this refresh)
+    pub audio_updated: Vec<String>,
+}
+
+impl RefreshDiff {
+    pub fn new(podcast_id: PodcastId, podcast_title: String, hard: bool) -> Self {
+        Self {
+            podcast_id,
+            podcast_title,
+            refreshed_at: Utc::now(),
+            hard,
+            new: Vec::new(),
+            updated: Vec::new(),
+            removed: Vec::new(),
+            audio_updated: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty()
+            && self.updated.is_empty()
+            && self.removed.is_empty()
+            && self.audio_updated.is_empty()
+    }
+
+    /// Short summary, e.g. "2 new, 1 updated, 3 removed"
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no changes".to_string();
+        }
+        [
+            (self.new.len(), "new"),
+            (self.updated.len(), "updated"),
+            (self.removed.len(), "removed"),
+            (self.audio_updated.len(), "with new audio"),
+        ]
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{} {}", count, label))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+
+    /// Record differences between a stored episode and its feed entry, if any
+    pub fn compare(&mut self, stored: &Episode, fresh: &Episode) {
+        let mut changes = Vec::new();
+        let mut check = |field, before: &str, after: &str| {
+            if before != after {
+                changes.push(FieldChange {
+                    field,
+                    before: before.to_string(),
+                    after: after.to_string(),
+                });
+            }
+        };
+        check(ChangedField::Title, &stored.title, &fresh.title);
+        check(
+            ChangedField::Description,
+            stored.description.as_deref().unwrap_or_default(),
+            fresh.description.as_deref().unwrap_or_default(),
+        );
+        check(ChangedField::Enclosure, &stored.audio_url, &fresh.audio_url);
+
+        if !changes.is_empty() {
+            self.updated.push(EpisodeChange {
+                episode_id: stored.id.clone(),
+                title: stored.title.clone(),
+                changes,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(title: &str, audio_url: &str) -> Episode {
+        Episode::new(
+            PodcastId::new(),
+            title.to_string(),
+            audio_url.to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_compare_records_only_changed_fields() {
+        // Arrange
+        let mut diff = RefreshDiff::new(PodcastId::new(), "Show".to_string(), false);
+        let stored = episode("Ep 1", "https://example.com/1.mp3");
+        let mut fresh = stored.clone();
+        fresh.title = "Ep 1 (remastered)".to_string();
+        fresh.audio_url = "https://cdn.example.com/1.mp3".to_string();
+
+        // Act
+        diff.compare(&stored, &stored.clone());
+        diff.compare(&stored, &fresh);
+
+        // Assert
+        assert_eq!(diff.updated.len(), 1);
+        let fields: Vec<_> = diff.updated[0].changes.iter().map(|c| c.field).collect();
+        assert_eq!(fields, vec![ChangedField::Title, ChangedField::Enclosure]);
+        assert_eq!(diff.updated[0].title, "Ep 1");
+    }
+
+    #[test]
+    fn test_summary_lists_non_empty_groups() {
+        let mut diff = RefreshDiff::new(PodcastId::new(), "Show".to_string(), true);
+        assert_eq!(diff.summary(), "no changes");
+
+        diff.new.push("Ep 3".to_string());
+        diff.removed.push("Ep 0".to_string());
+        diff.removed.push("Ep -1".to_string());
+        assert_eq!(diff.summary(), "1 new, 2 removed");
+
+        diff.audio_updated.push("Ep 1".to_string());
+        assert_eq!(diff.summary(), "1 new, 2 removed, 1 with new audio");
+    }
+}
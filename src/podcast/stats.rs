@@ -0,0 +1,547 @@
+// Listening statistics and goals
+//
+// Figures come from the listening log (what was finished, and when) and the
+// stored episodes (what is still unplayed). Goals from `goals.targets` are
+// measured over calendar periods in local time; the dashboard shows their
+// progress and a goal falling behind its pace triggers a reminder. The
+// podcast detail view breaks the figures down for one podcast.
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, TimeZone, Utc};
+
+use crate::config::GoalConfig;
+use crate::podcast::{Episode, PlayState};
+use crate::storage::{ListenRecord, PodcastId};
+
+/// How far (as a fraction of the target) progress may trail the elapsed
+/// part of the period before a goal counts as behind
+const PACE_SLACK: f64 = 0.15;
+
+/// Calendar period a goal is measured over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalPeriod {
+    Day,
+    /// Monday to Sunday
+    Week,
+    Month,
+}
+
+impl GoalPeriod {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "day" | "daily" => Some(Self::Day),
+            "week" | "weekly" => Some(Self::Week),
+            "month" | "monthly" => Some(Self::Month),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    fn first_day(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => date - Days::new(u64::from(date.weekday().num_days_from_monday())),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    fn shift(self, first_day: NaiveDate, forward: bool) -> NaiveDate {
+        let shifted = match (self, forward) {
+            (Self::Day, true) => first_day.checked_add_days(Days::new(1)),
+            (Self::Day, false) => first_day.checked_sub_days(Days::new(1)),
+            (Self::Week, true) => first_day.checked_add_days(Days::new(7)),
+            (Self::Week, false) => first_day.checked_sub_days(Days::new(7)),
+            (Self::Month, true) => first_day.checked_add_months(Months::new(1)),
+            (Self::Month, false) => first_day.checked_sub_months(Months::new(1)),
+        };
+        shifted.unwrap_or(first_day)
+    }
+
+    /// Start of the period containing `now`
+    pub fn start<Tz: TimeZone>(self, now: &DateTime<Tz>) -> DateTime<Tz> {
+        midnight(&now.timezone(), self.first_day(now.date_naive()))
+    }
+
+    /// Start of the period after the one containing `now`
+    pub fn end<Tz: TimeZone>(self, now: &DateTime<Tz>) -> DateTime<Tz> {
+        let first = self.first_day(now.date_naive());
+        midnight(&now.timezone(), self.shift(first, true))
+    }
+
+    /// Start of the period before the one containing `now`
+    pub fn previous_start<Tz: TimeZone>(self, now: &DateTime<Tz>) -> DateTime<Tz> {
+        let first = self.first_day(now.date_naive());
+        midnight(&now.timezone(), self.shift(first, false))
+    }
+}
+
+/// First instant of `date` in `tz`
+fn midnight<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> DateTime<Tz> {
+    let local = date.and_time(NaiveTime::MIN);
+    tz.from_local_datetime(&local)
+        .earliest()
+        .unwrap_or_else(|| tz.from_utc_datetime(&local))
+}
+
+/// What a goal counts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GoalKind {
+    /// Hours of episodes played to the end
+    ListenHours(f64),
+    /// Episodes finished or marked played
+    Episodes(u32),
+    /// Every episode published during the previous period played
+    ClearInbox,
+}
+
+/// A compiled `goals.targets` entry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ListeningGoal {
+    pub kind: GoalKind,
+    pub period: GoalPeriod,
+}
+
+impl ListeningGoal {
+    /// Compile configured goals. Invalid entries are skipped and described
+    /// in the returned error list.
+    pub fn compile(configs: &[GoalConfig]) -> (Vec<Self>, Vec<String>) {
+        let mut goals = Vec::new();
+        let mut errors = Vec::new();
+
+        for config in configs {
+            let Some(period) = GoalPeriod::parse(&config.period) else {
+                errors.push(format!(
+                    "Unknown goal period '{}' (use day, week or month)",
+                    config.period
+                ));
+                continue;
+            };
+            let target = config.target.filter(|t| t.is_finite() && *t > 0.0);
+            let kind = match (config.kind.trim(), target) {
+                ("listen_hours", Some(hours)) => GoalKind::ListenHours(hours),
+                ("episodes", Some(count)) => GoalKind::Episodes(count.ceil() as u32),
+                ("clear_inbox", _) => GoalKind::ClearInbox,
+                ("listen_hours" | "episodes", None) => {
+                    errors.push(format!(
+                        "Goal '{}' needs a target above 0",
+                        config.kind.trim()
+                    ));
+                    continue;
+                }
+                (other, _) => {
+                    errors.push(format!(
+                        "Unknown goal kind '{}' (use listen_hours, episodes or clear_inbox)",
+                        other
+                    ));
+                    continue;
+                }
+            };
+            goals.push(Self { kind, period });
+        }
+
+        (goals, errors)
+    }
+
+    /// "Listen 5 h per week", "Finish 10 episodes per week", ...
+    pub fn label(&self) -> String {
+        match self.kind {
+            GoalKind::ListenHours(hours) => {
+                format!(
+                    "Listen {} h per {}",
+                    format_number(hours),
+                    self.period.label()
+                )
+            }
+            GoalKind::Episodes(count) => {
+                format!("Finish {} episodes per {}", count, self.period.label())
+            }
+            GoalKind::ClearInbox => format!("Clear last {}'s episodes", self.period.label()),
+        }
+    }
+
+    /// Progress at `now` from the listening log and every stored episode
+    pub fn evaluate<Tz: TimeZone>(
+        &self,
+        records: &[ListenRecord],
+        episodes: &[Episode],
+        now: &DateTime<Tz>,
+    ) -> GoalProgress {
+        let start = self.period.start(now).with_timezone(&Utc);
+        let end = self.period.end(now).with_timezone(&Utc);
+        let now_utc = now.with_timezone(&Utc);
+        let in_period = records.iter().filter(|r| r.at >= start && r.at < end);
+
+        let (done, target, unit) = match self.kind {
+            GoalKind::ListenHours(hours) => {
+                let seconds: u64 = in_period.map(|r| u64::from(r.seconds)).sum();
+                (seconds as f64 / 3600.0, hours, "h")
+            }
+            GoalKind::Episodes(count) => (in_period.count() as f64, f64::from(count), "episodes"),
+            GoalKind::ClearInbox => {
+                let previous = self.period.previous_start(now).with_timezone(&Utc);
+                let inbox: Vec<_> = episodes
+                    .iter()
+                    .filter(|e| e.published >= previous && e.published < start)
+                    .collect();
+                let played = inbox.iter().filter(|e| e.is_played()).count();
+                (played as f64, inbox.len() as f64, "episodes")
+            }
+        };
+
+        let length = (end - start).num_seconds().max(1) as f64;
+        GoalProgress {
+            label: self.label(),
+            done,
+            target,
+            unit,
+            elapsed: ((now_utc - start).num_seconds() as f64 / length).clamp(0.0, 1.0),
+            remaining: end - now_utc,
+        }
+    }
+}
+
+/// Where a goal stands within its current period
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalProgress {
+    pub label: String,
+    pub done: f64,
+    pub target: f64,
+    pub unit: &'static str,
+    /// Fraction of the period already gone
+    pub elapsed: f64,
+    /// Time left in the period
+    pub remaining: chrono::Duration,
+}
+
+impl GoalProgress {
+    /// Fraction of the target reached, 0.0–1.0 (an empty target is met)
+    pub fn fraction(&self) -> f64 {
+        if self.target <= 0.0 {
+            1.0
+        } else {
+            (self.done / self.target).clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.fraction() >= 1.0
+    }
+
+    /// Further behind than the elapsed part of the period allows
+    pub fn is_behind(&self) -> bool {
+        !self.is_met() && self.fraction() + PACE_SLACK < self.elapsed
+    }
+
+    /// "1.5 / 5 h"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} / {} {}",
+            format_number(self.done),
+            format_number(self.target),
+            self.unit
+        )
+    }
+
+    /// "3 days left", "5 hours left"
+    pub fn remaining_label(&self) -> String {
+        let days = self.remaining.num_days();
+        if days >= 1 {
+            format!("{} day{} left", days, if days == 1 { "" } else { "s" })
+        } else {
+            let hours = self.remaining.num_hours().max(1);
+            format!("{} hour{} left", hours, if hours == 1 { "" } else { "s" })
+        }
+    }
+}
+
+/// Minibuffer reminder for the first goal falling behind, if any
+pub fn goal_reminder(progress: &[GoalProgress]) -> Option<String> {
+    let behind = progress.iter().find(|p| p.is_behind())?;
+    Some(format!(
+        "Goal reminder: {} — {} so far, {}",
+        behind.label,
+        behind.summary(),
+        behind.remaining_label()
+    ))
+}
+
+/// Listening figures for one period
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListeningStats {
+    pub listened_seconds: u64,
+    pub episodes_finished: usize,
+    /// Unplayed episodes across all subscriptions
+    pub unplayed: usize,
+}
+
+impl ListeningStats {
+    /// Figures for records at or after `since`
+    pub fn compute(records: &[ListenRecord], episodes: &[Episode], since: DateTime<Utc>) -> Self {
+        let recent = records.iter().filter(|r| r.at >= since);
+        Self {
+            listened_seconds: recent.clone().map(|r| u64::from(r.seconds)).sum(),
+            episodes_finished: recent.count(),
+            unplayed: episodes.iter().filter(|e| !e.is_played()).count(),
+        }
+    }
+}
+
+/// Listening figures for one podcast over the whole listening log
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PodcastStats {
+    pub listened_seconds: u64,
+    /// Stored episodes played to the end or marked played
+    pub episodes_finished: usize,
+    /// Stored episodes finished or left part-way through
+    pub episodes_started: usize,
+    pub unplayed: usize,
+    /// Mean time from an episode's release to finishing it, over episodes
+    /// listened to (not just marked played) that are still stored
+    pub average_lag: Option<chrono::Duration>,
+}
+
+impl PodcastStats {
+    /// Figures for `podcast_id` from the log `records` and its stored `episodes`
+    pub fn compute(podcast_id: &PodcastId, records: &[ListenRecord], episodes: &[Episode]) -> Self {
+        let records: Vec<_> = records
+            .iter()
+            .filter(|r| &r.podcast_id == podcast_id)
+            .collect();
+        let lags: Vec<i64> = records
+            .iter()
+            .filter(|r| r.seconds > 0)
+            .filter_map(|r| {
+                let episode = episodes.iter().find(|e| e.id == r.episode_id)?;
+                Some((r.at - episode.published).num_seconds().max(0))
+            })
+            .collect();
+        let count = |state: PlayState| episodes.iter().filter(|e| e.status.play == state).count();
+        let finished = count(PlayState::Played);
+
+        Self {
+            listened_seconds: records.iter().map(|r| u64::from(r.seconds)).sum(),
+            episodes_finished: finished,
+            episodes_started: finished + count(PlayState::InProgress),
+            unplayed: count(PlayState::Unplayed),
+            average_lag: (!lags.is_empty())
+                .then(|| chrono::Duration::seconds(lags.iter().sum::<i64>() / lags.len() as i64)),
+        }
+    }
+
+    /// Share of started episodes that were finished, 0.0–1.0
+    pub fn completion_rate(&self) -> Option<f64> {
+        (self.episodes_started > 0)
+            .then(|| self.episodes_finished as f64 / self.episodes_started as f64)
+    }
+
+    /// "12.5 h"
+    pub fn listened_label(&self) -> String {
+        format!("{} h", format_number(self.listened_seconds as f64 / 3600.0))
+    }
+
+    /// "3 days", "5 hours"; `None` before anything was listened to
+    pub fn average_lag_label(&self) -> Option<String> {
+        let lag = self.average_lag?;
+        let days = lag.num_hours() as f64 / 24.0;
+        Some(if days >= 1.0 {
+            let days = format_number(days);
+            format!("{} day{}", days, if days == "1" { "" } else { "s" })
+        } else {
+            let hours = lag.num_hours();
+            format!("{} hour{}", hours, if hours == 1 { "" } else { "s" })
+        })
+    }
+}
+
+/// Whole numbers without decimals, others to one place
+fn format_number(value: f64) -> String {
+    if (value - value.round()).abs() < 0.05 {
+        format!("{}", value.round())
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::EpisodeId;
+
+    fn goal(kind: &str, target: Option<f64>, period: &str) -> GoalConfig {
+        GoalConfig {
+            kind: kind.to_string(),
+            target,
+            period: period.to_string(),
+        }
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    fn listened(when: DateTime<Utc>, seconds: u32) -> ListenRecord {
+        ListenRecord {
+            at: when,
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            seconds,
+        }
+    }
+
+    fn published(when: DateTime<Utc>, played: bool) -> Episode {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Episode".to_string(),
+            "https://example.com/a.mp3".to_string(),
+            when,
+        );
+        if played {
+            episode.status.play = PlayState::Played;
+        }
+        episode
+    }
+
+    #[test]
+    fn test_period_boundaries() {
+        // Thursday 2026-10-15, 12:00
+        let now = at(2026, 10, 15, 12);
+
+        assert_eq!(GoalPeriod::Day.start(&now), at(2026, 10, 15, 0));
+        assert_eq!(GoalPeriod::Week.start(&now), at(2026, 10, 12, 0));
+        assert_eq!(GoalPeriod::Week.end(&now), at(2026, 10, 19, 0));
+        assert_eq!(GoalPeriod::Week.previous_start(&now), at(2026, 10, 5, 0));
+        assert_eq!(GoalPeriod::Month.start(&now), at(2026, 10, 1, 0));
+        assert_eq!(GoalPeriod::Month.end(&now), at(2026, 11, 1, 0));
+        assert_eq!(GoalPeriod::Month.previous_start(&now), at(2026, 9, 1, 0));
+    }
+
+    #[test]
+    fn test_listen_hours_goal_counts_this_period_and_flags_slow_pace() {
+        // Arrange: 1.5 h this week, plus 3 h last week that must not count
+        let (goals, errors) = ListeningGoal::compile(&[goal("listen_hours", Some(5.0), "week")]);
+        let records = [
+            listened(at(2026, 10, 8, 20), 3 * 3600),
+            listened(at(2026, 10, 13, 8), 3600),
+            listened(at(2026, 10, 14, 8), 1800),
+        ];
+        let thursday = at(2026, 10, 15, 12);
+
+        // Act
+        let progress = goals[0].evaluate(&records, &[], &thursday);
+
+        // Assert
+        assert!(errors.is_empty());
+        assert_eq!(progress.label, "Listen 5 h per week");
+        assert_eq!(progress.summary(), "1.5 / 5 h");
+        assert!(
+            progress.is_behind(),
+            "30% done with over half the week gone"
+        );
+        assert_eq!(progress.remaining_label(), "3 days left");
+        assert!(goal_reminder(&[progress])
+            .unwrap()
+            .starts_with("Goal reminder: Listen 5 h per week"));
+
+        // Early in the week the same progress is on pace
+        let monday = goals[0].evaluate(&records, &[], &at(2026, 10, 13, 12));
+        assert!(!monday.is_behind());
+    }
+
+    #[test]
+    fn test_clear_inbox_goal_looks_at_previous_period() {
+        let (goals, _) = ListeningGoal::compile(&[goal("clear_inbox", None, "weekly")]);
+        let episodes = [
+            published(at(2026, 10, 6, 9), true),
+            published(at(2026, 10, 9, 9), false),
+            published(at(2026, 10, 14, 9), false), // this week: not in the inbox yet
+            published(at(2026, 9, 30, 9), false),  // two weeks ago: out of scope
+        ];
+
+        let progress = goals[0].evaluate(&[], &episodes, &at(2026, 10, 15, 12));
+
+        assert_eq!(progress.label, "Clear last week's episodes");
+        assert_eq!(progress.summary(), "1 / 2 episodes");
+        assert!((progress.fraction() - 0.5).abs() < f64::EPSILON);
+
+        let empty = goals[0].evaluate(&[], &[], &at(2026, 10, 15, 12));
+        assert!(empty.is_met());
+    }
+
+    #[test]
+    fn test_compile_reports_invalid_goals() {
+        let (goals, errors) = ListeningGoal::compile(&[
+            goal("episodes", None, "week"),
+            goal("listen_hours", Some(2.0), "fortnight"),
+            goal("meditate", Some(1.0), "day"),
+            goal("episodes", Some(3.0), "day"),
+        ]);
+
+        assert_eq!(goals.len(), 1);
+        assert_eq!(goals[0].kind, GoalKind::Episodes(3));
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn test_podcast_stats_compute() {
+        // Arrange: two finished episodes (one only marked played), one half-heard
+        let podcast_id = PodcastId::new();
+        let mut episodes = vec![
+            published(at(2026, 10, 1, 0), true),
+            published(at(2026, 10, 5, 0), true),
+            published(at(2026, 10, 8, 0), false),
+            published(at(2026, 10, 9, 0), false),
+        ];
+        episodes[2].status.play = PlayState::InProgress;
+        let record = |episode: &Episode, when, seconds| ListenRecord {
+            at: when,
+            podcast_id: podcast_id.clone(),
+            episode_id: episode.id.clone(),
+            seconds,
+        };
+        let records = [
+            record(&episodes[0], at(2026, 10, 3, 0), 3600),
+            record(&episodes[1], at(2026, 10, 14, 0), 0),
+            listened(at(2026, 10, 14, 0), 7200), // another podcast
+        ];
+
+        // Act
+        let stats = PodcastStats::compute(&podcast_id, &records, &episodes);
+
+        // Assert
+        assert_eq!(stats.listened_label(), "1 h");
+        assert_eq!(stats.episodes_finished, 2);
+        assert_eq!(stats.episodes_started, 3);
+        assert_eq!(stats.unplayed, 1);
+        assert!((stats.completion_rate().unwrap() - 2.0 / 3.0).abs() < f64::EPSILON);
+        // Only the episode actually listened to counts towards the lag
+        assert_eq!(stats.average_lag_label().as_deref(), Some("2 days"));
+
+        let empty = PodcastStats::compute(&PodcastId::new(), &records, &[]);
+        assert_eq!(empty.completion_rate(), None);
+        assert_eq!(empty.average_lag_label(), None);
+    }
+
+    #[test]
+    fn test_listening_stats_compute() {
+        let records = [
+            listened(at(2026, 10, 1, 0), 600),
+            listened(at(2026, 10, 14, 0), 1200),
+        ];
+        let episodes = [
+            published(at(2026, 10, 1, 0), true),
+            published(at(2026, 10, 2, 0), false),
+        ];
+
+        let stats = ListeningStats::compute(&records, &episodes, at(2026, 10, 12, 0));
+
+        assert_eq!(stats.listened_seconds, 1200);
+        assert_eq!(stats.episodes_finished, 1);
+        assert_eq!(stats.unplayed, 1);
+    }
+}
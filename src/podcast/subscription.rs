@@ -1,16 +1,26 @@
 //! Podcast subscription management
 
 use crate::download::DownloadManager;
-use crate::podcast::{Episode, FeedError, FeedParser, Podcast};
-use crate::storage::{PodcastId, Storage};
+use crate::podcast::{
+    Episode, FeedAuth, FeedError, FeedLink, FeedParser, Podcast, PodcastEventKind, RefreshDiff,
+};
+use crate::storage::{EpisodeId, PodcastId, Storage};
+use crate::utils::http::HttpClient;
+use crate::utils::user_agent::UserAgentPolicy;
+use crate::utils::validation::validate_feed_url;
 use chrono::Utc;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 /// Subscription manager that handles podcast subscriptions
 pub struct SubscriptionManager<S: Storage> {
     pub storage: Arc<S>,
     feed_parser: FeedParser,
     download_manager: Option<Arc<DownloadManager<S>>>,
+    /// What the latest refresh of each podcast changed (this session only)
+    refresh_diffs: Mutex<HashMap<PodcastId, RefreshDiff>>,
+    /// Injected client for OPML downloads; each parser builds its own otherwise
+    http_client: Option<Arc<dyn HttpClient>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -37,6 +47,125 @@ pub enum SubscriptionError {
     Io(#[from] std::io::Error),
 }
 
+/// What is kept of a podcast's data when unsubscribing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsubscribeMode {
+    /// Hide the podcast with its episode history and downloads until the
+    /// restore window ends; `:restore-podcast` brings it all back
+    KeepAll,
+    /// Hide the podcast with its episode history, deleting its downloads
+    KeepHistory,
+    /// Delete the podcast and its episode history, leaving the downloaded
+    /// files on disk
+    KeepDownloads,
+    /// Delete the podcast, its episode history and its downloads
+    PurgeAll,
+}
+
+impl UnsubscribeMode {
+    /// Whether the podcast can be restored afterwards
+    pub fn keeps_history(self) -> bool {
+        matches!(self, Self::KeepAll | Self::KeepHistory)
+    }
+}
+
+/// How much of an already stored episode a hard refresh rewrites from the feed.
+/// User state (status, download, position, play count, notes, favourite,
+/// local edits) is always kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HardRefreshScope {
+    /// Title, publish date, GUID, link, artwork, explicit flag, language and numbering
+    Metadata,
+    /// Metadata plus the description
+    Descriptions,
+    /// Everything, including the enclosure URL, size, type and duration
+    #[default]
+    Full,
+}
+
+impl HardRefreshScope {
+    pub const NAMES: &'static str = "metadata, descriptions, full";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "metadata" | "meta" => Some(Self::Metadata),
+            "descriptions" | "description" => Some(Self::Descriptions),
+            "full" | "enclosures" => Some(Self::Full),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Metadata => "metadata",
+            Self::Descriptions => "descriptions",
+            Self::Full => "full",
+        }
+    }
+
+    /// The stored episode with the feed's values written over it, within this scope
+    pub fn apply(self, stored: &Episode, feed: &Episode) -> Episode {
+        let mut episode = stored.clone();
+        episode.title = feed.title.clone();
+        episode.published = feed.published;
+        episode.published_offset = feed.published_offset;
+        episode.guid = feed.guid.clone();
+        episode.link = feed.link.clone();
+        episode.image_url = feed.image_url.clone();
+        episode.explicit = feed.explicit;
+        episode.season = feed.season;
+        episode.episode_number = feed.episode_number;
+        episode.episode_type = feed.episode_type.clone();
+        episode.language = feed.language.clone();
+        episode.people = feed.people.clone();
+
+        if self != Self::Metadata {
+            if episode.description != feed.description {
+                episode.translated_description = None;
+            }
+            episode.description = feed.description.clone();
+        }
+        if self == Self::Full {
+            episode.audio_url = feed.audio_url.clone();
+            episode.duration = feed.duration;
+            episode.file_size = feed.file_size;
+            episode.mime_type = feed.mime_type.clone();
+            episode.audio_variants = feed.audio_variants.clone();
+        }
+        episode
+    }
+}
+
+/// Outcome of [`SubscriptionManager::refresh_matching_counting`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshCounts {
+    /// Podcasts selected for the refresh
+    pub podcasts: usize,
+    /// New episodes the counting filter accepted
+    pub new_episodes: usize,
+    /// Podcasts whose feed server couldn't be reached
+    pub unreachable: Vec<PodcastId>,
+}
+
+/// Outcome of [`SubscriptionManager::hard_refresh_all`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HardRefreshSummary {
+    /// Podcasts refreshed successfully
+    pub podcasts: usize,
+    /// Episodes added or rewritten
+    pub episodes: usize,
+    /// Titles of podcasts whose feed couldn't be refreshed
+    pub failed: Vec<String>,
+}
+
+impl SubscriptionError {
+    /// True when the feed couldn't be reached at all (offline, DNS, timeout),
+    /// as opposed to the server answering with an error
+    pub fn is_network(&self) -> bool {
+        matches!(self, Self::Feed(FeedError::Network(e)) if e.is_connect() || e.is_timeout())
+    }
+}
+
 impl<S: Storage> SubscriptionManager<S> {
     /// Create a new subscription manager
     pub fn new(storage: Arc<S>) -> Self {
@@ -44,6 +173,8 @@ impl<S: Storage> SubscriptionManager<S> {
             storage,
             feed_parser: FeedParser::new(),
             download_manager: None,
+            refresh_diffs: Mutex::new(HashMap::new()),
+            http_client: None,
         }
     }
 
@@ -56,9 +187,42 @@ impl<S: Storage> SubscriptionManager<S> {
             storage,
             feed_parser: FeedParser::new(),
             download_manager: Some(download_manager),
+            refresh_diffs: Mutex::new(HashMap::new()),
+            http_client: None,
         }
     }
 
+    /// Fetch feeds and OPML files through `http_client` instead of the
+    /// network (e.g. a `MockHttpClient` in tests)
+    pub fn with_http_client(mut self, http_client: Arc<dyn HttpClient>) -> Self {
+        let user_agents = self.feed_parser.user_agent_policy().clone();
+        let auth = self.feed_parser.feed_auth().cloned();
+        self.feed_parser = FeedParser::with_http_client(http_client.clone());
+        self.feed_parser.set_user_agent_policy(user_agents);
+        if let Some(auth) = auth {
+            self.feed_parser.set_feed_auth(auth);
+        }
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Fetch feeds of podcasts without their own User-Agent as `user_agents` says
+    pub fn with_user_agent_policy(mut self, user_agents: UserAgentPolicy) -> Self {
+        self.feed_parser.set_user_agent_policy(user_agents);
+        self
+    }
+
+    /// Sign feed requests to OAuth-protected hosts with `auth`'s tokens
+    pub fn with_feed_auth(mut self, auth: Arc<FeedAuth>) -> Self {
+        self.feed_parser.set_feed_auth(auth);
+        self
+    }
+
+    /// Sign-in state for OAuth-protected feeds, when configured
+    pub fn feed_auth(&self) -> Option<&Arc<FeedAuth>> {
+        self.feed_parser.feed_auth()
+    }
+
     /// Set the download manager for automatic cleanup during unsubscribe
     pub fn set_download_manager(&mut self, download_manager: Arc<DownloadManager<S>>) {
         self.download_manager = Some(download_manager);
@@ -75,8 +239,8 @@ impl<S: Storage> SubscriptionManager<S> {
 
         for id in podcast_ids {
             match self.storage.load_podcast(&id).await {
-                Ok(podcast) => podcasts.push(podcast),
-                Err(_) => continue,
+                Ok(podcast) if !podcast.is_removed() => podcasts.push(podcast),
+                _ => continue,
             }
         }
 
@@ -93,6 +257,12 @@ impl<S: Storage> SubscriptionManager<S> {
             .map_err(|e| SubscriptionError::Storage(e.to_string()))
     }
 
+    /// Feeds a web page advertises, for when the user pasted a show's homepage
+    /// instead of its feed
+    pub async fn discover_feeds(&self, page_url: &str) -> Result<Vec<FeedLink>, SubscriptionError> {
+        Ok(self.feed_parser.discover_feeds(page_url).await?)
+    }
+
     /// Subscribe to a new podcast by feed URL
     pub async fn subscribe(&self, feed_url: &str) -> Result<Podcast, SubscriptionError> {
         // Check if already subscribed (prevent duplicates)
@@ -103,11 +273,17 @@ impl<S: Storage> SubscriptionManager<S> {
             .await
             .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
         if exists {
+            // Re-subscribing to a recently removed podcast restores its history
+            let podcast = self.get_podcast(&podcast_id).await?;
+            if podcast.is_removed() {
+                return self.restore_podcast(&podcast_id).await;
+            }
             return Err(SubscriptionError::AlreadySubscribed(feed_url.to_string()));
         }
 
         // Parse the feed and create podcast
-        let podcast = self.feed_parser.parse_feed(feed_url).await?;
+        let mut podcast = self.feed_parser.parse_feed(feed_url).await?;
+        podcast.record_event(PodcastEventKind::Subscribed);
 
         // Get episodes for the podcast
         let episodes = self.feed_parser.get_episodes(feed_url, &podcast.id).await?;
@@ -129,9 +305,125 @@ impl<S: Storage> SubscriptionManager<S> {
         Ok(podcast)
     }
 
-    /// Unsubscribe from a podcast
+    /// Unsubscribe from a podcast, keeping what `mode` says
+    ///
+    /// When the history is kept, the podcast is tombstoned rather than deleted: it
+    /// disappears from all lists but its episodes (and, with `KeepAll`, downloads) are
+    /// retained so it can be brought back with [`restore_podcast`](Self::restore_podcast).
+    /// Physical deletion happens in [`purge_expired_removed`](Self::purge_expired_removed)
+    /// once the restore window ends. Otherwise the podcast is deleted straight away.
+    pub async fn unsubscribe(
+        &self,
+        podcast_id: &PodcastId,
+        mode: UnsubscribeMode,
+    ) -> Result<(), SubscriptionError> {
+        let mut podcast = self.load_existing_podcast(podcast_id).await?;
+        if podcast.is_removed() {
+            return Err(SubscriptionError::NotFound(podcast_id.to_string()));
+        }
+
+        match mode {
+            UnsubscribeMode::KeepAll => {}
+            UnsubscribeMode::KeepHistory => {
+                if let Some(ref download_manager) = self.download_manager {
+                    download_manager
+                        .delete_podcast_downloads(podcast_id)
+                        .await
+                        .map_err(|e| {
+                            SubscriptionError::Storage(format!("Could not delete downloads: {}", e))
+                        })?;
+                }
+            }
+            UnsubscribeMode::KeepDownloads => {
+                return self
+                    .storage
+                    .delete_podcast(podcast_id)
+                    .await
+                    .map_err(|e| SubscriptionError::Storage(e.to_string()));
+            }
+            UnsubscribeMode::PurgeAll => return self.purge_podcast(podcast_id).await,
+        }
+
+        podcast.removed_at = Some(Utc::now());
+        podcast.record_event(PodcastEventKind::Unsubscribed);
+        self.storage
+            .save_podcast(&podcast)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Restore a previously unsubscribed podcast along with its episode history
+    pub async fn restore_podcast(
+        &self,
+        podcast_id: &PodcastId,
+    ) -> Result<Podcast, SubscriptionError> {
+        let mut podcast = self.load_existing_podcast(podcast_id).await?;
+        if !podcast.is_removed() {
+            return Err(SubscriptionError::AlreadySubscribed(podcast.url));
+        }
+
+        podcast.removed_at = None;
+        podcast.record_event(PodcastEventKind::Restored);
+        self.storage
+            .save_podcast(&podcast)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+
+        Ok(podcast)
+    }
+
+    /// Archive (`archived: true`) or un-archive a subscription. Archived
+    /// podcasts keep their episodes but leave the list and refresh-all.
+    pub async fn set_archived(
+        &self,
+        podcast_id: &PodcastId,
+        archived: bool,
+    ) -> Result<Podcast, SubscriptionError> {
+        let mut podcast = self.get_podcast(podcast_id).await?;
+        if podcast.is_archived() == archived {
+            return Ok(podcast);
+        }
+        if archived {
+            podcast.archived_at = Some(Utc::now());
+            podcast.record_event(PodcastEventKind::Archived);
+        } else {
+            podcast.archived_at = None;
+            podcast.record_event(PodcastEventKind::Unarchived);
+        }
+        self.storage
+            .save_podcast(&podcast)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+
+        Ok(podcast)
+    }
+
+    /// Get all unsubscribed podcasts that are still within their restore window
+    pub async fn list_removed_podcasts(&self) -> Result<Vec<Podcast>, SubscriptionError> {
+        let podcast_ids = self
+            .storage
+            .list_podcasts()
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+        let mut podcasts = Vec::new();
+
+        for id in podcast_ids {
+            match self.storage.load_podcast(&id).await {
+                Ok(podcast) if podcast.is_removed() => podcasts.push(podcast),
+                _ => continue,
+            }
+        }
+
+        // Most recently removed first
+        podcasts.sort_by_key(|p| std::cmp::Reverse(p.removed_at));
+        Ok(podcasts)
+    }
+
+    /// Permanently delete a podcast
     /// This will also delete all downloaded episodes for the podcast
-    pub async fn unsubscribe(&self, podcast_id: &PodcastId) -> Result<(), SubscriptionError> {
+    pub async fn purge_podcast(&self, podcast_id: &PodcastId) -> Result<(), SubscriptionError> {
         // Check if podcast exists
         let exists = self
             .storage
@@ -145,7 +437,7 @@ impl<S: Storage> SubscriptionManager<S> {
         // Delete all downloaded episodes for this podcast if download manager is available
         if let Some(ref download_manager) = self.download_manager {
             if let Err(e) = download_manager.delete_podcast_downloads(podcast_id).await {
-                // Log the error but don't fail the unsubscribe operation
+                // Log the error but don't fail the purge operation
                 eprintln!("Warning: Failed to delete some downloaded episodes: {}", e);
             }
         }
@@ -159,6 +451,40 @@ impl<S: Storage> SubscriptionManager<S> {
         Ok(())
     }
 
+    /// Permanently delete removed podcasts whose restore window has expired.
+    /// Returns the number of podcasts purged.
+    pub async fn purge_expired_removed(
+        &self,
+        restore_days: u32,
+    ) -> Result<usize, SubscriptionError> {
+        let now = Utc::now();
+        let mut purged = 0;
+
+        for podcast in self.list_removed_podcasts().await? {
+            if podcast.is_restore_window_expired(restore_days, now) {
+                self.purge_podcast(&podcast.id).await?;
+                purged += 1;
+            }
+        }
+
+        Ok(purged)
+    }
+
+    async fn load_existing_podcast(
+        &self,
+        podcast_id: &PodcastId,
+    ) -> Result<Podcast, SubscriptionError> {
+        let exists = self
+            .storage
+            .podcast_exists(podcast_id)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+        if !exists {
+            return Err(SubscriptionError::NotFound(podcast_id.to_string()));
+        }
+        self.get_podcast(podcast_id).await
+    }
+
     /// Refresh a podcast feed and get new episodes
     /// If hard_refresh is true, existing episodes will be updated with new data
     pub async fn refresh_feed(
@@ -175,16 +501,58 @@ impl<S: Storage> SubscriptionManager<S> {
         podcast_id: &PodcastId,
         hard_refresh: bool,
     ) -> Result<Vec<Episode>, SubscriptionError> {
+        self.refresh_feed_with_scope(podcast_id, hard_refresh.then_some(HardRefreshScope::Full))
+            .await
+    }
+
+    /// Refresh a podcast feed. With a hard refresh scope, stored episodes are
+    /// rewritten from the feed within that scope and all episodes are renumbered.
+    pub async fn refresh_feed_with_scope(
+        &self,
+        podcast_id: &PodcastId,
+        hard_scope: Option<HardRefreshScope>,
+    ) -> Result<Vec<Episode>, SubscriptionError> {
+        let hard_refresh = hard_scope.is_some();
+
         // Load the podcast
         let mut podcast = self.get_podcast(podcast_id).await?;
 
         // Get episodes from the feed
-        let feed_episodes = self
-            .feed_parser
-            .get_episodes(&podcast.url, podcast_id)
-            .await?;
+        let (metadata, feed_episodes) = match self.feed_parser.get_feed_update_for(&podcast).await {
+            Ok(update) => update,
+            Err(e) => {
+                // Keep the failure on the timeline; the refresh error itself matters more
+                podcast.record_event(PodcastEventKind::RefreshFailed {
+                    error: e.to_string(),
+                    attempts: 1,
+                });
+                let _ = self.storage.save_podcast(&podcast).await;
+                return Err(e.into());
+            }
+        };
+
+        // Follow a publisher-announced move; the podcast keeps its ID and history
+        if let Some(new_url) = metadata.new_feed_url.filter(|url| *url != podcast.url) {
+            if validate_feed_url(&new_url).is_ok() {
+                let from = std::mem::replace(&mut podcast.url, new_url.clone());
+                podcast.record_event(PodcastEventKind::FeedUrlChanged { from, to: new_url });
+            }
+        }
+
+        // Keep content metadata current; shows change title, language or rating over time
+        let retitled = !metadata.title.trim().is_empty() && metadata.title != podcast.title;
+        if retitled {
+            podcast.title = metadata.title.clone();
+        }
+        if metadata.language.is_some() {
+            podcast.language = metadata.language;
+        }
+        podcast.explicit = metadata.explicit;
+        podcast.complete = metadata.complete;
+        podcast.people = metadata.people;
 
         // Assign track numbers to episodes
+        let feed_ids: HashSet<EpisodeId> = feed_episodes.iter().map(|e| e.id.clone()).collect();
         let episodes_with_tracks = self
             .assign_track_numbers(podcast_id, feed_episodes, hard_refresh)
             .await?;
@@ -199,8 +567,19 @@ impl<S: Storage> SubscriptionManager<S> {
         // Filter out episodes we already have, or update existing ones if hard_refresh
         let mut new_episodes = Vec::new();
         let mut updated_episodes = Vec::new();
-
-        for episode in episodes_with_tracks {
+        // Downloaded episodes whose audio was re-uploaded, or no longer is;
+        // saved but not reported as changes
+        let mut reflagged = Vec::new();
+        let mut diff = RefreshDiff::new(podcast_id.clone(), podcast.title.clone(), hard_refresh);
+        let mut matched = HashSet::new();
+
+        // A hard refresh also returns stored episodes for renumbering; feed
+        // episodes go first so a stale stored copy never overwrites feed data
+        let (feed_episodes, stored_only): (Vec<_>, Vec<_>) = episodes_with_tracks
+            .into_iter()
+            .partition(|episode| feed_ids.contains(&episode.id));
+
+        for episode in feed_episodes {
             // Check if episode already exists using multiple strategies
             let existing_episode = existing_episodes.iter().find(|existing_episode| {
                 // Strategy 1: Compare deterministic IDs (based on GUID)
@@ -239,24 +618,40 @@ impl<S: Storage> SubscriptionManager<S> {
             });
 
             if let Some(existing) = existing_episode {
-                if hard_refresh {
-                    // Update existing episode with new data (preserving user-specific fields)
-                    let mut updated_episode = episode.clone();
-                    updated_episode.id = existing.id.clone(); // Keep the same ID
-                    updated_episode.status = existing.status.clone(); // Preserve download status
-                    updated_episode.local_path = existing.local_path.clone(); // Preserve local file
-                    updated_episode.last_played_position = existing.last_played_position; // Preserve playback position
-                    updated_episode.play_count = existing.play_count; // Preserve play count
-                    updated_episode.notes = existing.notes.clone(); // Preserve user notes
-
-                    updated_episodes.push(updated_episode);
+                matched.insert(existing.id.clone());
+                diff.compare(existing, &episode);
+                let enclosure_update = existing.enclosure_update(&episode);
+                if enclosure_update.is_some() && enclosure_update != existing.updated_enclosure {
+                    diff.audio_updated.push(existing.title.clone());
+                }
+                if let Some(scope) = hard_scope {
+                    // Update the stored episode (same ID, user state kept) from the feed
+                    let mut updated = scope.apply(existing, &episode);
+                    updated.updated_enclosure = enclosure_update;
+                    updated_episodes.push(updated);
+                } else if enclosure_update != existing.updated_enclosure {
+                    let mut flagged = existing.clone();
+                    flagged.updated_enclosure = enclosure_update;
+                    reflagged.push(flagged);
                 }
-                // If not hard refresh, skip existing episodes (current behavior)
+                // Otherwise a normal refresh leaves existing episodes alone
             } else {
                 // Truly new episode
+                diff.new.push(episode.title.clone());
                 new_episodes.push(episode);
             }
         }
+        // Stored episodes the feed no longer lists still take their new track number
+        updated_episodes.extend(
+            stored_only
+                .into_iter()
+                .filter(|episode| !matched.contains(&episode.id)),
+        );
+        diff.removed = existing_episodes
+            .iter()
+            .filter(|episode| !matched.contains(&episode.id))
+            .map(|episode| episode.title.clone())
+            .collect();
 
         // Save new episodes
         for episode in &new_episodes {
@@ -267,7 +662,7 @@ impl<S: Storage> SubscriptionManager<S> {
         }
 
         // Save updated episodes (for hard refresh)
-        for episode in &updated_episodes {
+        for episode in updated_episodes.iter().chain(&reflagged) {
             self.storage
                 .save_episode(podcast_id, episode)
                 .await
@@ -278,6 +673,12 @@ impl<S: Storage> SubscriptionManager<S> {
         let mut all_changes = new_episodes.clone();
         all_changes.extend(updated_episodes);
 
+        if !new_episodes.is_empty() {
+            podcast.record_event(PodcastEventKind::NewEpisodes {
+                count: new_episodes.len(),
+            });
+        }
+
         // Update podcast's last_updated timestamp
         podcast.last_updated = Utc::now();
         self.storage
@@ -285,19 +686,81 @@ impl<S: Storage> SubscriptionManager<S> {
             .await
             .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
 
+        // Downloads follow the new title rather than staying in the old folder
+        if retitled {
+            if let Some(ref download_manager) = self.download_manager {
+                if let Err(e) = download_manager.rename_podcast_folder(podcast_id).await {
+                    eprintln!("Warning: Failed to rename download folder: {}", e);
+                }
+            }
+        }
+
+        if let Ok(mut diffs) = self.refresh_diffs.lock() {
+            diffs.insert(podcast_id.clone(), diff);
+        }
+
         Ok(all_changes)
     }
 
+    /// What the most recent refresh of a podcast changed, if it was refreshed
+    /// this session
+    pub fn last_refresh_diff(&self, podcast_id: &PodcastId) -> Option<RefreshDiff> {
+        self.refresh_diffs.lock().ok()?.get(podcast_id).cloned()
+    }
+
     /// Refresh all subscribed podcasts
     pub async fn refresh_all(&self) -> Result<usize, SubscriptionError> {
-        let podcasts = self.list_subscriptions().await?;
-        let mut total_new_episodes = 0;
+        self.refresh_all_counting(|_, _| true).await
+    }
+
+    /// Refresh all subscribed podcasts, counting only the new episodes `counts` accepts
+    pub async fn refresh_all_counting(
+        &self,
+        counts: impl Fn(&Podcast, &Episode) -> bool,
+    ) -> Result<usize, SubscriptionError> {
+        let total_new_episodes = self
+            .refresh_matching_counting(|_| true, counts)
+            .await?
+            .new_episodes;
+        Ok(total_new_episodes)
+    }
+
+    /// Refresh the subscribed podcasts `select` accepts (e.g. one tag),
+    /// counting only the new episodes `counts` accepts. Archived podcasts
+    /// are left out.
+    pub async fn refresh_matching_counting(
+        &self,
+        select: impl Fn(&Podcast) -> bool,
+        counts: impl Fn(&Podcast, &Episode) -> bool,
+    ) -> Result<RefreshCounts, SubscriptionError> {
+        use crate::constants::network::REFRESH_ALL_CONCURRENCY;
+        use crate::utils::rate_limit::interleave_by_host;
+        use futures_util::stream::{self, StreamExt};
+
+        let mut podcasts = self.list_subscriptions().await?;
+        podcasts.retain(|podcast| !podcast.is_archived() && select(podcast));
+        let selected = podcasts.len();
+
+        // Feeds on the same host are spread out so a slow host (capped by the
+        // feed parser's per-host limit) doesn't hold every refresh slot
+        let podcasts = interleave_by_host(podcasts, |p| &p.url);
+        let mut refreshes = stream::iter(podcasts.into_iter().map(|podcast| async move {
+            let result = self.refresh_feed(&podcast.id).await;
+            (podcast, result)
+        }))
+        .buffer_unordered(REFRESH_ALL_CONCURRENCY);
 
-        for podcast in podcasts {
-            match self.refresh_feed(&podcast.id).await {
+        let mut total_new_episodes = 0;
+        let mut unreachable = Vec::new();
+        while let Some((podcast, result)) = refreshes.next().await {
+            match result {
                 Ok(new_episodes) => {
-                    total_new_episodes += new_episodes.len();
+                    total_new_episodes += new_episodes
+                        .iter()
+                        .filter(|episode| counts(&podcast, episode))
+                        .count();
                 }
+                Err(e) if e.is_network() => unreachable.push(podcast.id.clone()),
                 Err(_e) => {
                     // Log error but continue with other podcasts
                     // TODO: Add proper error reporting mechanism
@@ -305,15 +768,58 @@ impl<S: Storage> SubscriptionManager<S> {
             }
         }
 
-        Ok(total_new_episodes)
+        Ok(RefreshCounts {
+            podcasts: selected,
+            new_episodes: total_new_episodes,
+            unreachable,
+        })
+    }
+
+    /// Hard refresh every subscription within `scope`, calling `progress` with
+    /// `(finished, total, podcast title)` as each podcast completes
+    pub async fn hard_refresh_all(
+        &self,
+        scope: HardRefreshScope,
+        progress: impl Fn(usize, usize, &str),
+    ) -> Result<HardRefreshSummary, SubscriptionError> {
+        use crate::constants::network::REFRESH_ALL_CONCURRENCY;
+        use crate::utils::rate_limit::interleave_by_host;
+        use futures_util::stream::{self, StreamExt};
+
+        let podcasts = interleave_by_host(self.list_subscriptions().await?, |p| &p.url);
+        let total = podcasts.len();
+        let mut refreshes = stream::iter(podcasts.into_iter().map(|podcast| async move {
+            let result = self.refresh_feed_with_scope(&podcast.id, Some(scope)).await;
+            (podcast, result)
+        }))
+        .buffer_unordered(REFRESH_ALL_CONCURRENCY);
+
+        let mut summary = HardRefreshSummary::default();
+        while let Some((podcast, result)) = refreshes.next().await {
+            match result {
+                Ok(episodes) => {
+                    summary.podcasts += 1;
+                    summary.episodes += episodes.len();
+                }
+                Err(_) => summary.failed.push(podcast.title.clone()),
+            }
+            progress(
+                summary.podcasts + summary.failed.len(),
+                total,
+                &podcast.title,
+            );
+        }
+
+        Ok(summary)
     }
 
     /// Check if a podcast is already subscribed
     pub async fn is_subscribed(&self, feed_url: &str) -> bool {
         let podcast_id = PodcastId::from_url(feed_url);
         self.storage
-            .podcast_exists(&podcast_id)
+            .load_podcast(&podcast_id)
             .await
+            .map(|podcast| !podcast.is_removed())
             .unwrap_or(false)
     }
 
@@ -332,11 +838,11 @@ impl<S: Storage> SubscriptionManager<S> {
                 .await
                 .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
 
-            // Combine and deduplicate by ID
-            let mut all_episodes = existing_episodes;
-            for new_ep in new_episodes {
-                if !all_episodes.iter().any(|existing| existing.id == new_ep.id) {
-                    all_episodes.push(new_ep);
+            // Combine and deduplicate by ID, keeping the feed's copy
+            let mut all_episodes = new_episodes;
+            for existing in existing_episodes {
+                if !all_episodes.iter().any(|feed_ep| feed_ep.id == existing.id) {
+                    all_episodes.push(existing);
                 }
             }
 
@@ -378,18 +884,15 @@ impl<S: Storage> SubscriptionManager<S> {
 
     /// Get subscription count
     pub async fn subscription_count(&self) -> Result<usize, SubscriptionError> {
-        let podcasts = self
-            .storage
-            .list_podcasts()
-            .await
-            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
-        Ok(podcasts.len())
+        Ok(self.list_subscriptions().await?.len())
     }
 
     /// Import podcasts from OPML file or URL
     ///
-    /// Non-destructive import that skips duplicates and processes feeds sequentially.
-    /// Returns detailed statistics about the import operation.
+    /// Non-destructive import that skips duplicates. Uses the default
+    /// [`OpmlImportOptions`] and reports only status text; see
+    /// [`Self::import_opml_with_options`] for concurrency, rate limiting and
+    /// cancellation control.
     ///
     /// # Arguments
     ///
@@ -407,17 +910,146 @@ impl<S: Storage> SubscriptionManager<S> {
     where
         F: Fn(String) + Send + Sync,
     {
-        use crate::podcast::{FailedImport, ImportResult, OpmlParser};
-        use chrono::Local;
+        let options = crate::podcast::OpmlImportOptions::default();
+        self.import_opml_with_options(source, &options, |progress| {
+            progress_callback(progress.status)
+        })
+        .await
+    }
+
+    /// Read an OPML file or URL and list its feeds, marking the ones already
+    /// subscribed, without importing anything
+    pub async fn preview_opml(
+        &self,
+        source: &str,
+    ) -> Result<crate::podcast::OpmlPreview, SubscriptionError> {
+        use crate::podcast::{OpmlPreview, OpmlPreviewFeed};
+        use std::collections::HashSet;
+
+        let document = self.opml_parser().parse(source).await?;
+        let mut seen = HashSet::new();
+        let mut feeds = Vec::new();
+        for outline in &document.outlines {
+            let Some(url) = outline
+                .feed_url()
+                .filter(|url| seen.insert(url.to_string()))
+            else {
+                continue;
+            };
+            feeds.push(OpmlPreviewFeed {
+                url: url.to_string(),
+                title: outline
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| outline.text.clone()),
+                description: outline.description.clone(),
+                subscribed: self.is_subscribed(url).await,
+            });
+        }
+        Ok(OpmlPreview {
+            source: source.to_string(),
+            document,
+            feeds,
+        })
+    }
+
+    /// Import the feeds of a previewed OPML file whose URLs are in
+    /// `selected`; the rest are skipped. See
+    /// [`Self::import_opml_with_options`].
+    pub async fn import_opml_selected<F>(
+        &self,
+        preview: &crate::podcast::OpmlPreview,
+        selected: &std::collections::HashSet<String>,
+        options: &crate::podcast::OpmlImportOptions,
+        progress_callback: F,
+    ) -> Result<(crate::podcast::ImportResult, String), SubscriptionError>
+    where
+        F: Fn(crate::podcast::ImportProgress) + Send + Sync,
+    {
+        self.import_opml_document(
+            &preview.source,
+            &preview.document,
+            Some(selected),
+            options,
+            progress_callback,
+        )
+        .await
+    }
+
+    /// Import podcasts from OPML file or URL with bounded concurrency
+    ///
+    /// Up to `options.concurrency` feeds are fetched at once, requests to the
+    /// same host are spaced by `options.per_host_interval`, and setting
+    /// `options.cancel` stops new feeds from starting (feeds already being
+    /// fetched finish; the rest are counted as cancelled). Every progress
+    /// update carries live done/failed/remaining counts.
+    pub async fn import_opml_with_options<F>(
+        &self,
+        source: &str,
+        options: &crate::podcast::OpmlImportOptions,
+        progress_callback: F,
+    ) -> Result<(crate::podcast::ImportResult, String), SubscriptionError>
+    where
+        F: Fn(crate::podcast::ImportProgress) + Send + Sync,
+    {
+        progress_callback(crate::podcast::ImportProgress {
+            total: 0,
+            done: 0,
+            failed: 0,
+            status: "Validating OPML file...".to_string(),
+        });
+        let document = self.opml_parser().parse(source).await?;
+        self.import_opml_document(source, &document, None, options, progress_callback)
+            .await
+    }
+
+    fn opml_parser(&self) -> crate::podcast::OpmlParser {
+        match &self.http_client {
+            Some(client) => crate::podcast::OpmlParser::with_http_client(client.clone()),
+            None => crate::podcast::OpmlParser::new(),
+        }
+    }
 
-        progress_callback("Validating OPML file...".to_string());
+    /// Import the feeds of a parsed OPML `document`, only those whose URL is
+    /// in `selected` when given
+    async fn import_opml_document<F>(
+        &self,
+        source: &str,
+        document: &crate::podcast::OpmlDocument,
+        selected: Option<&std::collections::HashSet<String>>,
+        options: &crate::podcast::OpmlImportOptions,
+        progress_callback: F,
+    ) -> Result<(crate::podcast::ImportResult, String), SubscriptionError>
+    where
+        F: Fn(crate::podcast::ImportProgress) + Send + Sync,
+    {
+        use crate::constants::opml::IMPORT_TIMEOUT;
+        use crate::podcast::{FailedImport, ImportProgress, ImportResult};
+        use crate::utils::rate_limit::HostRateLimiter;
+        use chrono::Local;
+        use futures_util::stream::{self, StreamExt};
+        use std::collections::HashSet;
+
+        enum FeedOutcome {
+            Imported,
+            Skipped(&'static str),
+            Failed(String),
+            Cancelled,
+        }
 
-        // Parse and validate OPML
-        let parser = OpmlParser::new();
-        let document = parser.parse(source).await?;
+        let report = |result: &ImportResult, status: String| {
+            progress_callback(ImportProgress {
+                total: result.total_feeds,
+                done: result.imported + result.skipped,
+                failed: result.failed.len(),
+                status,
+            });
+        };
 
+        let mut result = ImportResult::new(0);
         let total_feeds = document.outlines.len();
-        progress_callback(format!("Found {} feeds in OPML", total_feeds));
+        result.total_feeds = total_feeds;
+        report(&result, format!("Found {} feeds in OPML", total_feeds));
 
         // Create log file
         let log_dir = dirs::data_local_dir()
@@ -434,128 +1066,172 @@ impl<S: Storage> SubscriptionManager<S> {
         let log_path_str = log_path.to_string_lossy().to_string();
 
         let mut log_content = format!(
-            "OPML Import Log\nStarted: {}\nSource: {}\n\n=== Processing ===\n",
+            "OPML Import Log\nStarted: {}\nSource: {}\nConcurrency: {}\nPer-host interval: {}ms\n\n=== Processing ===\n",
             Local::now().format("%Y-%m-%d %H:%M:%S"),
-            source
+            source,
+            options.concurrency,
+            options.per_host_interval.as_millis()
         );
 
-        let mut result = ImportResult::new(total_feeds);
-
-        // Process feeds sequentially
-        for (index, outline) in document.outlines.iter().enumerate() {
-            let feed_url = match outline.feed_url() {
-                Some(url) => url,
+        // Outlines without a feed URL and repeated URLs are skipped up front so
+        // two concurrent tasks never subscribe to the same feed.
+        let mut seen = HashSet::new();
+        let mut feeds = Vec::new();
+        for outline in &document.outlines {
+            let feed_title = outline.title.as_deref().unwrap_or(&outline.text);
+            match outline.feed_url() {
+                Some(url) if selected.is_some_and(|selected| !selected.contains(url)) => {
+                    log_content.push_str(&format!(
+                        "[{}] ⊘ Skipped (not selected): {}\n",
+                        Local::now().format("%H:%M:%S"),
+                        feed_title
+                    ));
+                    result.skipped += 1;
+                }
+                Some(url) if seen.insert(url.to_string()) => {
+                    feeds.push((url.to_string(), feed_title.to_string()));
+                }
+                Some(_) => {
+                    log_content.push_str(&format!(
+                        "[{}] ⊘ Skipped duplicate entry: {}\n",
+                        Local::now().format("%H:%M:%S"),
+                        feed_title
+                    ));
+                    result.skipped += 1;
+                }
                 None => {
-                    // Skip outlines without feed URLs
-                    continue;
+                    log_content.push_str(&format!(
+                        "[{}] ⊘ Skipped (no feed URL): {}\n",
+                        Local::now().format("%H:%M:%S"),
+                        feed_title
+                    ));
+                    result.skipped += 1;
                 }
-            };
-
-            let feed_title = outline.title.as_deref().unwrap_or(&outline.text);
-            let current = index + 1;
-
-            progress_callback(format!(
-                "Importing [{}/{}]: {}...",
-                current, total_feeds, feed_title
-            ));
-
-            log_content.push_str(&format!(
-                "[{}] [{}/{}] Importing: {} ({})\n",
-                Local::now().format("%H:%M:%S"),
-                current,
-                total_feeds,
-                feed_title,
-                feed_url
-            ));
-
-            // Check if already subscribed
-            if self.is_subscribed(feed_url).await {
-                progress_callback(format!(
-                    "⊘ Skipped [{}/{}]: {} (already subscribed)",
-                    current, total_feeds, feed_title
-                ));
+            }
+        }
 
-                log_content.push_str(&format!(
-                    "[{}] [{}/{}] ⊘ Skipped (already subscribed)\n",
-                    Local::now().format("%H:%M:%S"),
-                    current,
-                    total_feeds
-                ));
+        let limiter = HostRateLimiter::new(options.per_host_interval);
+        let limiter = &limiter;
+        let mut outcomes =
+            stream::iter(feeds.into_iter().map(|(feed_url, feed_title)| async move {
+                if options.is_cancelled() {
+                    return (feed_url, feed_title, FeedOutcome::Cancelled);
+                }
+                limiter.acquire(&feed_url).await;
+                if options.is_cancelled() {
+                    return (feed_url, feed_title, FeedOutcome::Cancelled);
+                }
 
-                result.skipped += 1;
-                continue;
-            }
+                // Check if already subscribed
+                if self.is_subscribed(&feed_url).await {
+                    return (
+                        feed_url,
+                        feed_title,
+                        FeedOutcome::Skipped("already subscribed"),
+                    );
+                }
 
-            // Attempt to subscribe
-            match self.subscribe(feed_url).await {
-                Ok(_) => {
-                    progress_callback(format!(
-                        "✓ Imported [{}/{}]: {}",
-                        current, total_feeds, feed_title
+                let outcome =
+                    match tokio::time::timeout(IMPORT_TIMEOUT, self.subscribe(&feed_url)).await {
+                        Ok(Ok(_)) => FeedOutcome::Imported,
+                        Ok(Err(e)) => {
+                            // Check if this is an "already subscribed" error
+                            // If so, treat it as a skip rather than a failure (defensive programming)
+                            let error_msg = e.to_string();
+                            if error_msg.contains("already subscribed")
+                                || error_msg.contains("AlreadySubscribed")
+                            {
+                                FeedOutcome::Skipped("already subscribed, caught by subscribe")
+                            } else {
+                                FeedOutcome::Failed(error_msg)
+                            }
+                        }
+                        Err(_) => FeedOutcome::Failed(format!(
+                            "Timed out after {}s",
+                            IMPORT_TIMEOUT.as_secs()
+                        )),
+                    };
+                (feed_url, feed_title, outcome)
+            }))
+            .buffer_unordered(options.concurrency.max(1));
+
+        while let Some((feed_url, feed_title, outcome)) = outcomes.next().await {
+            let current = result.processed() + result.cancelled + 1;
+            let status = match outcome {
+                FeedOutcome::Imported => {
+                    result.imported += 1;
+                    log_content.push_str(&format!(
+                        "[{}] [{}/{}] ✓ Success: {} ({})\n",
+                        Local::now().format("%H:%M:%S"),
+                        current,
+                        total_feeds,
+                        feed_title,
+                        feed_url
                     ));
-
+                    format!("✓ Imported [{}/{}]: {}", current, total_feeds, feed_title)
+                }
+                FeedOutcome::Skipped(reason) => {
+                    result.skipped += 1;
                     log_content.push_str(&format!(
-                        "[{}] [{}/{}] ✓ Success\n",
+                        "[{}] [{}/{}] ⊘ Skipped ({}): {} ({})\n",
                         Local::now().format("%H:%M:%S"),
                         current,
-                        total_feeds
+                        total_feeds,
+                        reason,
+                        feed_title,
+                        feed_url
                     ));
-
-                    result.imported += 1;
+                    format!(
+                        "⊘ Skipped [{}/{}]: {} (already subscribed)",
+                        current, total_feeds, feed_title
+                    )
                 }
-                Err(e) => {
-                    // Check if this is an "already subscribed" error
-                    // If so, treat it as a skip rather than a failure (defensive programming)
-                    let error_msg = e.to_string();
-                    if error_msg.contains("already subscribed")
-                        || error_msg.contains("AlreadySubscribed")
-                    {
-                        progress_callback(format!(
-                            "⊘ Skipped [{}/{}]: {} (already subscribed)",
-                            current, total_feeds, feed_title
-                        ));
-
-                        log_content.push_str(&format!(
-                            "[{}] [{}/{}] ⊘ Skipped (already subscribed, caught by subscribe)\n",
-                            Local::now().format("%H:%M:%S"),
-                            current,
-                            total_feeds
-                        ));
-
-                        result.skipped += 1;
-                    } else {
-                        // Genuine error - add to failed list
-                        progress_callback(format!(
-                            "✗ Failed [{}/{}]: {} - {}",
-                            current, total_feeds, feed_title, error_msg
-                        ));
-
-                        log_content.push_str(&format!(
-                            "[{}] [{}/{}] ✗ Failed: {}\n",
-                            Local::now().format("%H:%M:%S"),
-                            current,
-                            total_feeds,
-                            error_msg
-                        ));
-
-                        result.failed.push(FailedImport {
-                            url: feed_url.to_string(),
-                            title: Some(feed_title.to_string()),
-                            error: error_msg,
-                        });
-                    }
+                FeedOutcome::Failed(error_msg) => {
+                    log_content.push_str(&format!(
+                        "[{}] [{}/{}] ✗ Failed: {} ({}): {}\n",
+                        Local::now().format("%H:%M:%S"),
+                        current,
+                        total_feeds,
+                        feed_title,
+                        feed_url,
+                        error_msg
+                    ));
+                    let status = format!(
+                        "✗ Failed [{}/{}]: {} - {}",
+                        current, total_feeds, feed_title, error_msg
+                    );
+                    result.failed.push(FailedImport {
+                        url: feed_url,
+                        title: Some(feed_title),
+                        error: error_msg,
+                    });
+                    status
                 }
-            }
+                FeedOutcome::Cancelled => {
+                    result.cancelled += 1;
+                    continue;
+                }
+            };
+            report(&result, status);
+        }
+
+        if result.was_cancelled() {
+            log_content.push_str(&format!(
+                "[{}] Import cancelled, {} feeds not attempted\n",
+                Local::now().format("%H:%M:%S"),
+                result.cancelled
+            ));
         }
 
         // Write summary to log
         log_content.push_str(&format!(
-            "\n=== Summary ===\nCompleted: {}\nTotal feeds: {}\nImported: {}\nSkipped: {}\nFailed: {}\n",
+            "\n=== Summary ===\nCompleted: {}\nTotal feeds: {}\nImported: {}\nSkipped: {}\nFailed: {}\nCancelled: {}\n",
             Local::now().format("%Y-%m-%d %H:%M:%S"),
             result.total_feeds,
             result.imported,
             result.skipped,
-            result.failed.len()
+            result.failed.len(),
+            result.cancelled
         ));
 
         if result.has_failures() {
@@ -569,6 +1245,16 @@ impl<S: Storage> SubscriptionManager<S> {
                     failure.error
                 ));
             }
+
+            // Just the failed feeds, to retry or fix up and import again
+            let failed_path = log_dir.join(format!("opml-import-{}-failed.opml", timestamp));
+            let failed_opml = crate::podcast::OpmlExporter::new().failures(&result.failed);
+            tokio::fs::write(&failed_path, failed_opml).await?;
+            log_content.push_str(&format!(
+                "Failed feeds saved to: {}\n",
+                failed_path.display()
+            ));
+            result.failed_opml_path = Some(failed_path.to_string_lossy().to_string());
         }
 
         // Write log file
@@ -615,6 +1301,45 @@ impl<S: Storage> SubscriptionManager<S> {
 
         Ok(feed_count)
     }
+
+    /// Podcast whose title is `title` (case-insensitive), or else the only
+    /// one whose title contains it
+    pub async fn find_by_title(&self, title: &str) -> Result<Podcast, SubscriptionError> {
+        let needle = title.trim().to_lowercase();
+        let podcasts = self.list_subscriptions().await?;
+        if let Some(podcast) = podcasts.iter().find(|p| p.title.to_lowercase() == needle) {
+            return Ok(podcast.clone());
+        }
+        let mut matches = podcasts
+            .into_iter()
+            .filter(|p| p.title.to_lowercase().contains(&needle));
+        match (matches.next(), matches.next()) {
+            (Some(podcast), None) => Ok(podcast),
+            _ => Err(SubscriptionError::NotFound(title.to_string())),
+        }
+    }
+
+    /// Write a static HTML archive of a podcast under `dir`
+    pub async fn export_archive(
+        &self,
+        podcast_id: &PodcastId,
+        dir: &std::path::Path,
+    ) -> Result<crate::podcast::ArchiveSummary, SubscriptionError> {
+        let podcast = self
+            .storage
+            .load_podcast(podcast_id)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+        let episodes = self
+            .storage
+            .load_episodes(podcast_id)
+            .await
+            .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+
+        Ok(crate::podcast::ArchiveExporter::new()
+            .export(&podcast, &episodes, dir)
+            .await?)
+    }
 }
 
 #[cfg(test)]
@@ -635,6 +1360,64 @@ mod tests {
         assert_eq!(count, 0);
     }
 
+    #[test]
+    fn test_hard_refresh_scope_limits_rewritten_fields() {
+        // Arrange — a played, favourited download whose feed entry changed everywhere
+        let mut stored = Episode::new(
+            PodcastId::new(),
+            "Old title".to_string(),
+            "https://old.example.com/1.mp3".to_string(),
+            Utc::now(),
+        );
+        stored.description = Some("Old notes".to_string());
+        stored.play_count = 2;
+        stored.favorited = true;
+        let mut feed = stored.clone();
+        feed.id = EpisodeId::new();
+        feed.title = "New title".to_string();
+        feed.description = Some("New notes".to_string());
+        feed.audio_url = "https://cdn.example.com/1.mp3".to_string();
+        feed.duration = Some(1800);
+        feed.play_count = 0;
+        feed.favorited = false;
+
+        // Act
+        let metadata = HardRefreshScope::Metadata.apply(&stored, &feed);
+        let descriptions = HardRefreshScope::Descriptions.apply(&stored, &feed);
+        let full = HardRefreshScope::Full.apply(&stored, &feed);
+
+        // Assert
+        assert_eq!(metadata.title, "New title");
+        assert_eq!(metadata.description.as_deref(), Some("Old notes"));
+        assert_eq!(descriptions.description.as_deref(), Some("New notes"));
+        assert_eq!(descriptions.audio_url, stored.audio_url);
+        assert_eq!(full.audio_url, feed.audio_url);
+        assert_eq!(full.duration, Some(1800));
+        for episode in [&metadata, &descriptions, &full] {
+            assert_eq!(episode.id, stored.id);
+            assert_eq!(episode.play_count, 2);
+            assert!(episode.favorited);
+        }
+    }
+
+    #[test]
+    fn test_hard_refresh_scope_parse() {
+        assert_eq!(
+            HardRefreshScope::parse("Metadata"),
+            Some(HardRefreshScope::Metadata)
+        );
+        assert_eq!(
+            HardRefreshScope::parse("descriptions"),
+            Some(HardRefreshScope::Descriptions)
+        );
+        assert_eq!(
+            HardRefreshScope::parse("full"),
+            Some(HardRefreshScope::Full)
+        );
+        assert_eq!(HardRefreshScope::parse("all"), None);
+        assert_eq!(HardRefreshScope::default(), HardRefreshScope::Full);
+    }
+
     #[tokio::test]
     async fn test_duplicate_detection() {
         let temp_dir = TempDir::new().unwrap();
@@ -648,4 +1431,174 @@ mod tests {
         let subscribed = manager.is_subscribed("https://example.com/feed.xml").await;
         assert!(!subscribed);
     }
+
+    async fn make_manager_with_podcast() -> (TempDir, SubscriptionManager<JsonStorage>, Podcast) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = JsonStorage::with_data_dir(temp_dir.path().to_path_buf());
+        storage.initialize().await.unwrap();
+        let storage = Arc::new(storage);
+
+        let mut podcast = Podcast::new(
+            "Removable Show".to_string(),
+            "https://example.com/removable.xml".to_string(),
+        );
+        podcast.id = PodcastId::from_url(&podcast.url);
+        storage.save_podcast(&podcast).await.unwrap();
+
+        (temp_dir, SubscriptionManager::new(storage), podcast)
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_tombstones_podcast() {
+        // Arrange
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+
+        // Act
+        manager
+            .unsubscribe(&podcast.id, UnsubscribeMode::KeepAll)
+            .await
+            .unwrap();
+
+        // Assert: hidden from lists but retained in storage
+        assert!(manager.list_subscriptions().await.unwrap().is_empty());
+        assert!(!manager.is_subscribed(&podcast.url).await);
+        assert!(manager.storage.podcast_exists(&podcast.id).await.unwrap());
+        let removed = manager.list_removed_podcasts().await.unwrap();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].id, podcast.id);
+    }
+
+    #[tokio::test]
+    async fn test_restore_podcast_brings_back_subscription() {
+        // Arrange
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+        manager
+            .unsubscribe(&podcast.id, UnsubscribeMode::KeepAll)
+            .await
+            .unwrap();
+
+        // Act
+        let restored = manager.restore_podcast(&podcast.id).await.unwrap();
+
+        // Assert
+        assert!(!restored.is_removed());
+        assert_eq!(manager.subscription_count().await.unwrap(), 1);
+        assert!(manager.list_removed_podcasts().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_active_podcast_fails() {
+        // Arrange
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+
+        // Act
+        let result = manager.restore_podcast(&podcast.id).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(SubscriptionError::AlreadySubscribed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_failures_are_recorded_on_the_timeline() {
+        // Arrange: an invalid URL fails validation without touching the network
+        let (_temp_dir, manager, mut podcast) = make_manager_with_podcast().await;
+        podcast.url = "not a feed url".to_string();
+        manager.storage.save_podcast(&podcast).await.unwrap();
+
+        // Act
+        assert!(manager.refresh_feed(&podcast.id).await.is_err());
+        assert!(manager.refresh_feed(&podcast.id).await.is_err());
+
+        // Assert
+        let stored = manager.get_podcast(&podcast.id).await.unwrap();
+        assert_eq!(stored.history.len(), 1);
+        assert!(matches!(
+            stored.history[0].kind,
+            PodcastEventKind::RefreshFailed { attempts: 2, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_and_restore_are_recorded() {
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+
+        manager
+            .unsubscribe(&podcast.id, UnsubscribeMode::KeepAll)
+            .await
+            .unwrap();
+        let restored = manager.restore_podcast(&podcast.id).await.unwrap();
+
+        let kinds: Vec<_> = restored.history.into_iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![PodcastEventKind::Unsubscribed, PodcastEventKind::Restored]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archived_podcasts_are_skipped_by_refresh_all() {
+        // Arrange
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+
+        // Act
+        let archived = manager.set_archived(&podcast.id, true).await.unwrap();
+        let counts = manager
+            .refresh_matching_counting(|_| true, |_, _| true)
+            .await
+            .unwrap();
+        let unarchived = manager.set_archived(&podcast.id, false).await.unwrap();
+
+        // Assert — still subscribed, just not refreshed
+        assert!(archived.is_archived());
+        assert_eq!(counts.podcasts, 0);
+        assert_eq!(manager.list_subscriptions().await.unwrap().len(), 1);
+        assert!(!unarchived.is_archived());
+        let kinds: Vec<_> = unarchived.history.into_iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![PodcastEventKind::Archived, PodcastEventKind::Unarchived]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_restores_removed_podcast_without_fetching() {
+        // Arrange
+        let (_temp_dir, manager, podcast) = make_manager_with_podcast().await;
+        manager
+            .unsubscribe(&podcast.id, UnsubscribeMode::KeepAll)
+            .await
+            .unwrap();
+
+        // Act: the feed URL is unreachable, so success proves no fetch happened
+        let restored = manager.subscribe(&podcast.url).await.unwrap();
+
+        // Assert
+        assert_eq!(restored.id, podcast.id);
+        assert!(manager.is_subscribed(&podcast.url).await);
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_removed_respects_restore_window() {
+        // Arrange
+        let (_temp_dir, manager, mut podcast) = make_manager_with_podcast().await;
+        podcast.removed_at = Some(Utc::now() - chrono::Duration::days(10));
+        manager.storage.save_podcast(&podcast).await.unwrap();
+
+        // Act: still inside a 30-day window
+        let purged = manager.purge_expired_removed(30).await.unwrap();
+
+        // Assert
+        assert_eq!(purged, 0);
+        assert!(manager.storage.podcast_exists(&podcast.id).await.unwrap());
+
+        // Act: window of 7 days has passed
+        let purged = manager.purge_expired_removed(7).await.unwrap();
+
+        // Assert
+        assert_eq!(purged, 1);
+        assert!(!manager.storage.podcast_exists(&podcast.id).await.unwrap());
+    }
 }
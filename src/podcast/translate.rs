@@ -0,0 +1,148 @@
+// Description translation through a user-provided command
+//
+// `translation.command` runs through the shell with the episode's
+// description, as plain text, on stdin; whatever it prints is the
+// translation. `:translate` keeps it on the episode
+// (`Episode::translated_description`) so the detail buffer can show it
+// without running the command again, until a refresh changes the
+// description.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::config::TranslationConfig;
+use crate::podcast::Episode;
+use crate::utils::text::strip_html;
+
+/// The text `:translate` sends: the shown description without markup
+pub fn source_text(episode: &Episode) -> Option<String> {
+    episode
+        .display_description()
+        .map(|description| strip_html(description).trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+/// Run `text` through `config.command`, returning what it printed
+pub async fn translate(config: &TranslationConfig, text: &str) -> Result<String, String> {
+    let command_line = config
+        .command
+        .as_deref()
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .ok_or_else(|| "no translation command; set translation.command".to_string())?;
+
+    let mut child = shell(command_line)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Could not start '{}': {}", command_line, e))?;
+
+    // Fed from its own task so a command that prints before reading all of
+    // its input can't block on a full pipe; dropping stdin sends EOF
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = text.to_string();
+    let feeder = tokio::spawn(async move {
+        let _ = stdin.write_all(input.as_bytes()).await;
+    });
+
+    let timeout = Duration::from_secs(config.timeout_secs.max(1));
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            format!(
+                "'{}' gave no answer within {}s",
+                command_line,
+                timeout.as_secs()
+            )
+        })?
+        .map_err(|e| format!("'{}' failed: {}", command_line, e))?;
+    let _ = feeder.await;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr
+            .lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .map(str::trim)
+            .map(str::to_string)
+            .unwrap_or_else(|| output.status.to_string());
+        return Err(format!("'{}' failed: {}", command_line, reason));
+    }
+    let translation = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if translation.is_empty() {
+        return Err(format!("'{}' printed nothing", command_line));
+    }
+    Ok(translation)
+}
+
+/// `command_line` run by the platform shell, so pipes and quoting work
+fn shell(command_line: &str) -> Command {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.arg("/C");
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command
+    };
+    command.arg(command_line);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::PodcastId;
+
+    fn config(command: &str) -> TranslationConfig {
+        TranslationConfig {
+            command: Some(command.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_source_text_strips_markup() {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Folge 12".to_string(),
+            "https://example.com/12.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        assert_eq!(source_text(&episode), None);
+
+        episode.description = Some("<p>Heute: <b>Kaffee</b></p>".to_string());
+
+        assert_eq!(source_text(&episode).as_deref(), Some("Heute: Kaffee"));
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_translate_pipes_text_through_command() {
+        let translation = translate(&config("tr a-z A-Z"), "guten morgen").await;
+
+        assert_eq!(translation.as_deref(), Ok("GUTEN MORGEN"));
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_translate_reports_failures() {
+        let failed = translate(&config("echo 'no API key' >&2; exit 3"), "hola").await;
+        let silent = translate(&config("cat > /dev/null"), "hola").await;
+        let missing = translate(&TranslationConfig::default(), "hola").await;
+
+        assert_eq!(
+            failed,
+            Err("'echo 'no API key' >&2; exit 3' failed: no API key".to_string())
+        );
+        assert!(silent.unwrap_err().ends_with("printed nothing"));
+        assert!(missing.unwrap_err().contains("translation.command"));
+    }
+}
@@ -0,0 +1,292 @@
+// Search index - word postings for every episode of every subscription
+//
+// Text is split into lowercase alphanumeric words. A query matches an
+// episode when each of its words is a prefix of some word in the episode,
+// so results narrow while the last word is still being typed. Title matches
+// rank above matches on the people an episode credits (`<podcast:person>`),
+// then note matches, then show-note matches; whole-word matches rank above
+// prefix matches, and ties go to the newer episode.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+
+use crate::podcast::{Episode, Podcast};
+use crate::storage::{EpisodeId, PodcastId, Storage};
+use crate::utils::text::strip_html;
+
+/// Most results a search returns
+pub const MAX_RESULTS: usize = 200;
+
+/// Part of an episode a query matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchField {
+    ShowNotes,
+    Notes,
+    People,
+    Title,
+}
+
+impl MatchField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Notes => "my notes",
+            Self::People => "people",
+            Self::ShowNotes => "show notes",
+        }
+    }
+
+    fn weight(&self) -> u32 {
+        match self {
+            Self::Title => 4,
+            Self::People => 3,
+            Self::Notes => 2,
+            Self::ShowNotes => 1,
+        }
+    }
+}
+
+/// One episode a query matched
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+    pub podcast_title: String,
+    pub episode_title: String,
+    pub published: DateTime<Utc>,
+    /// The feed's UTC offset for `published`, for display
+    pub published_offset: Option<i32>,
+    /// Best-ranked part of the episode that matched
+    pub matched: MatchField,
+}
+
+/// Inverted index over episode text
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    episodes: Vec<SearchHit>,
+    /// Word → (episode index, field) for each field containing the word
+    words: BTreeMap<String, Vec<(usize, MatchField)>>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index the episodes of `podcasts` held in `storage`; podcasts whose
+    /// episodes can't be loaded are left out
+    pub async fn build<S: Storage>(storage: &S, podcasts: &[Podcast]) -> Self {
+        let mut index = Self::new();
+        for podcast in podcasts {
+            if let Ok(episodes) = storage.load_episodes(&podcast.id).await {
+                for episode in &episodes {
+                    index.add(podcast, episode);
+                }
+            }
+        }
+        index
+    }
+
+    /// Add one episode of `podcast`
+    pub fn add(&mut self, podcast: &Podcast, episode: &Episode) {
+        let doc = self.episodes.len();
+        self.episodes.push(SearchHit {
+            podcast_id: podcast.id.clone(),
+            episode_id: episode.id.clone(),
+            podcast_title: podcast.title.clone(),
+            episode_title: episode.display_title().to_string(),
+            published: episode.published,
+            published_offset: episode.published_offset,
+            matched: MatchField::Title,
+        });
+
+        let fields = [
+            (MatchField::Title, Some(episode.display_title().to_string())),
+            (
+                MatchField::ShowNotes,
+                episode.display_description().map(strip_html),
+            ),
+            (MatchField::Notes, episode.notes.clone()),
+            (
+                MatchField::People,
+                (!episode.people.is_empty()).then(|| {
+                    episode
+                        .people
+                        .iter()
+                        .map(|person| person.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }),
+            ),
+        ];
+        for (field, text) in fields {
+            let Some(text) = text else {
+                continue;
+            };
+            let mut words = words(&text);
+            words.sort_unstable();
+            words.dedup();
+            for word in words {
+                self.words.entry(word).or_default().push((doc, field));
+            }
+        }
+    }
+
+    /// Number of episodes indexed
+    pub fn len(&self) -> usize {
+        self.episodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.episodes.is_empty()
+    }
+
+    /// Episodes matching every word of `query`, best first, at most `limit`
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let mut terms = words(query);
+        terms.dedup();
+        let mut matches: Option<HashMap<usize, (u32, MatchField)>> = None;
+        for term in &terms {
+            let mut term_matches: HashMap<usize, (u32, MatchField)> = HashMap::new();
+            for (word, postings) in self
+                .words
+                .range(term.clone()..)
+                .take_while(|(word, _)| word.starts_with(term.as_str()))
+            {
+                let whole_word = if word == term { 2 } else { 1 };
+                for &(doc, field) in postings {
+                    let score = field.weight() * whole_word;
+                    let entry = term_matches.entry(doc).or_insert((0, field));
+                    entry.0 = entry.0.max(score);
+                    entry.1 = entry.1.max(field);
+                }
+            }
+            matches = Some(match matches {
+                None => term_matches,
+                Some(previous) => previous
+                    .into_iter()
+                    .filter_map(|(doc, (score, field))| {
+                        let (term_score, term_field) = term_matches.get(&doc)?;
+                        Some((doc, (score + term_score, field.max(*term_field))))
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut ranked: Vec<_> = matches.unwrap_or_default().into_iter().collect();
+        ranked.sort_by(|(a_doc, (a_score, _)), (b_doc, (b_score, _))| {
+            b_score.cmp(a_score).then_with(|| {
+                self.episodes[*b_doc]
+                    .published
+                    .cmp(&self.episodes[*a_doc].published)
+            })
+        });
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(doc, (_, matched))| SearchHit {
+                matched,
+                ..self.episodes[doc].clone()
+            })
+            .collect()
+    }
+}
+
+/// Lowercase alphanumeric words of `text`
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(podcast: &Podcast, title: &str, description: &str, days_ago: i64) -> Episode {
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            title.to_string(),
+            "https://example.com/a.mp3".to_string(),
+            Utc::now() - chrono::Duration::days(days_ago),
+        );
+        episode.description = Some(description.to_string());
+        episode
+    }
+
+    #[test]
+    fn test_search_needs_every_word_and_ranks_titles_first() {
+        // Arrange
+        let podcast = Podcast::new(
+            "Science Hour".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        let mut index = SearchIndex::new();
+        index.add(
+            &podcast,
+            &episode(&podcast, "Deep sea creatures", "<p>Squid and whales</p>", 3),
+        );
+        index.add(
+            &podcast,
+            &episode(&podcast, "Whales of the Arctic", "Ice and cold water", 1),
+        );
+        index.add(
+            &podcast,
+            &episode(&podcast, "Volcanoes", "Lava under the sea", 2),
+        );
+
+        // Act
+        let whales = index.search("whales", MAX_RESULTS);
+        let both = index.search("Sea SQUI", MAX_RESULTS);
+
+        // Assert — the title match ranks above the show-notes match
+        let titles: Vec<_> = whales
+            .iter()
+            .map(|hit| hit.episode_title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Whales of the Arctic", "Deep sea creatures"]);
+        assert_eq!(whales[0].matched, MatchField::Title);
+        // Every word must match; the last one may be a prefix
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].episode_title, "Deep sea creatures");
+        assert!(index.search("", MAX_RESULTS).is_empty());
+        assert!(index.search("penguins", MAX_RESULTS).is_empty());
+    }
+
+    #[test]
+    fn test_search_finds_user_notes() {
+        let podcast = Podcast::new("Weekly".to_string(), "https://example.com/feed".to_string());
+        let mut tagged = episode(&podcast, "Episode 12", "News roundup", 5);
+        tagged.notes = Some("Recommend to Sam".to_string());
+        let mut index = SearchIndex::new();
+        index.add(&podcast, &tagged);
+
+        let hits = index.search("recommend", 10);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].matched, MatchField::Notes);
+    }
+
+    #[test]
+    fn test_search_finds_credited_people() {
+        let podcast = Podcast::new("Talks".to_string(), "https://example.com/feed".to_string());
+        let mut interview = episode(&podcast, "Episode 40", "A long chat", 2);
+        interview.people.push(crate::podcast::Person {
+            name: "Ada Lovelace".to_string(),
+            role: "guest".to_string(),
+            href: None,
+            img: None,
+        });
+        let mut index = SearchIndex::new();
+        index.add(&podcast, &interview);
+        index.add(&podcast, &episode(&podcast, "Episode 41", "Ada's notes", 1));
+
+        let hits = index.search("ada lovelace", 10);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].episode_title, "Episode 40");
+        assert_eq!(hits[0].matched, MatchField::People);
+    }
+}
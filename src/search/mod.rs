@@ -0,0 +1,10 @@
+// Full-text episode search
+//
+// `index` holds an inverted index over episode titles, the people episodes
+// credit, show notes and the user's own notes across every subscription;
+// the `*Search*` buffer (C-s, `:search-all`, `:search-person`) queries it as
+// you type.
+
+pub mod index;
+
+pub use index::*;
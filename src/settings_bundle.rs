@@ -0,0 +1,635 @@
+//! Settings bundle import/export
+//!
+//! A settings bundle is a single JSON file that carries everything needed to
+//! reproduce a user's setup on another machine: the full configuration
+//! (including keybindings), user theme files, and smart playlist definitions.
+//! Subscriptions are deliberately excluded — OPML already covers those, and
+//! so are API secrets. Paths that only make sense on the exporting machine
+//! keep their local values on import unless the user asks for them.
+
+use crate::config::Config;
+use crate::playlist::{Playlist, SmartPlaylistRule};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Current on-disk format version of settings bundles.
+/// Bump when the bundle layout changes in a way older readers can't handle.
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum SettingsBundleError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid settings bundle: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("Unsupported settings bundle format v{found} (this version reads up to v{supported})")]
+    UnsupportedVersion { found: u32, supported: u32 },
+}
+
+/// Config keys never exported or shown in an import preview
+const SECRET_KEYS: &[&str] = &[
+    "discovery.podcastindex_api_key",
+    "discovery.podcastindex_api_secret",
+];
+
+/// Config keys naming paths or devices on one machine; an import keeps the
+/// local values unless asked to take the bundle's
+const MACHINE_KEYS: &[&str] = &[
+    "audio.output_device",
+    "downloads.directory",
+    "downloads.sync_device_path",
+    "downloads.ffmpeg_path",
+    "downloads.ytdlp_path",
+    "storage.data_directory",
+    "storage.opml_export_directory",
+    "transcription.whisper_path",
+    "transcription.model",
+];
+
+/// A user theme file carried inside a bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeFile {
+    /// File name inside the `themes/` config directory (e.g. `ocean.toml`)
+    pub file_name: String,
+    /// Raw TOML content
+    pub content: String,
+}
+
+/// A smart playlist definition (rules only, no evaluated episodes)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmartPlaylistDefinition {
+    pub name: String,
+    pub description: Option<String>,
+    pub rule: SmartPlaylistRule,
+}
+
+/// Portable bundle of app settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBundle {
+    pub format_version: u32,
+    /// Version of podcast-tui that produced the bundle
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub config: Config,
+    #[serde(default)]
+    pub themes: Vec<ThemeFile>,
+    #[serde(default)]
+    pub smart_playlists: Vec<SmartPlaylistDefinition>,
+}
+
+impl SettingsBundle {
+    /// Build a bundle from the current configuration, theme files and playlists.
+    /// Non-smart playlists are ignored and API secrets are left out.
+    pub fn new(mut config: Config, themes: Vec<ThemeFile>, playlists: &[Playlist]) -> Self {
+        config.discovery.podcastindex_api_key.clear();
+        config.discovery.podcastindex_api_secret.clear();
+
+        let smart_playlists = playlists
+            .iter()
+            .filter_map(|p| {
+                p.smart_rules.as_ref().map(|rule| SmartPlaylistDefinition {
+                    name: p.name.clone(),
+                    description: p.description.clone(),
+                    rule: rule.clone(),
+                })
+            })
+            .collect();
+
+        Self {
+            format_version: BUNDLE_FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: Utc::now(),
+            config,
+            themes,
+            smart_playlists,
+        }
+    }
+
+    /// Collect all `.toml` theme files from `config_dir/themes/`.
+    /// Returns an empty list if the directory does not exist.
+    pub fn collect_themes(config_dir: &Path) -> Result<Vec<ThemeFile>, SettingsBundleError> {
+        let themes_dir = config_dir.join("themes");
+        if !themes_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut themes = Vec::new();
+        for entry in std::fs::read_dir(&themes_dir)?.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "toml") {
+                if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
+                    themes.push(ThemeFile {
+                        file_name: file_name.to_string(),
+                        content: std::fs::read_to_string(&path)?,
+                    });
+                }
+            }
+        }
+        themes.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+        Ok(themes)
+    }
+
+    /// Write the bundle to `path` as pretty-printed JSON
+    pub async fn write(&self, path: &Path) -> Result<(), SettingsBundleError> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, json).await?;
+        Ok(())
+    }
+
+    /// Read a bundle from `path`, rejecting formats newer than this build understands
+    pub async fn read(path: &Path) -> Result<Self, SettingsBundleError> {
+        let content = tokio::fs::read_to_string(path).await?;
+        let bundle: Self = serde_json::from_str(&content)?;
+        bundle.check_version()?;
+        Ok(bundle)
+    }
+
+    /// Verify the bundle format can be read by this build
+    pub fn check_version(&self) -> Result<(), SettingsBundleError> {
+        if self.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(SettingsBundleError::UnsupportedVersion {
+                found: self.format_version,
+                supported: BUNDLE_FORMAT_VERSION,
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether the bundle was exported by a newer podcast-tui than this one
+    pub fn is_from_newer_app(&self) -> bool {
+        parse_version(&self.app_version) > parse_version(env!("CARGO_PKG_VERSION"))
+    }
+
+    /// The config an import writes: the bundle's, with the local API secrets
+    /// and, unless `with_paths`, the local machine-specific paths
+    pub fn merged_config(&self, current: &Config, with_paths: bool) -> Config {
+        let mut merged = self.config.clone();
+        merged.discovery.podcastindex_api_key = current.discovery.podcastindex_api_key.clone();
+        merged.discovery.podcastindex_api_secret =
+            current.discovery.podcastindex_api_secret.clone();
+        if !with_paths {
+            merged.audio.output_device = current.audio.output_device.clone();
+            merged.downloads.directory = current.downloads.directory.clone();
+            merged.downloads.sync_device_path = current.downloads.sync_device_path.clone();
+            merged.downloads.ffmpeg_path = current.downloads.ffmpeg_path.clone();
+            merged.downloads.ytdlp_path = current.downloads.ytdlp_path.clone();
+            merged.storage.data_directory = current.storage.data_directory.clone();
+            merged.storage.opml_export_directory = current.storage.opml_export_directory.clone();
+            merged.transcription.whisper_path = current.transcription.whisper_path.clone();
+            merged.transcription.model = current.transcription.model.clone();
+        }
+        merged
+    }
+
+    /// Produce a diff-style preview of what importing this bundle would change.
+    ///
+    /// Lines are prefixed with `+` (added), `~` (changed) or `!` (warning).
+    /// `existing_themes` are file names already present in the themes directory;
+    /// `existing_playlists` are names of playlists already in storage.
+    /// `with_paths` is passed on to `merged_config`; machine-specific keys it
+    /// keeps are listed so the user knows to ask for them.
+    pub fn preview(
+        &self,
+        current: &Config,
+        existing_themes: &[ThemeFile],
+        existing_playlists: &[Playlist],
+        with_paths: bool,
+    ) -> Vec<String> {
+        let mut lines = vec![
+            "--- current settings".to_string(),
+            format!(
+                "+++ bundle (podcast-tui {}, exported {})",
+                self.app_version,
+                self.exported_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+            String::new(),
+        ];
+
+        if self.is_from_newer_app() {
+            lines.push(format!(
+                "! Bundle was exported by a newer version ({}); unknown settings will be dropped",
+                self.app_version
+            ));
+            lines.push(String::new());
+        }
+
+        lines.push("CONFIG:".to_string());
+        let changes = config_changes(current, &self.merged_config(current, with_paths));
+        if changes.is_empty() {
+            lines.push("  (no changes)".to_string());
+        } else {
+            for (key, old, new) in changes {
+                match old {
+                    Some(old) => lines.push(format!("~ {}: {} -> {}", key, old, new)),
+                    None => lines.push(format!("+ {}: {}", key, new)),
+                }
+            }
+        }
+        if !with_paths {
+            let kept: Vec<String> = config_changes(current, &self.config)
+                .into_iter()
+                .map(|(key, _, _)| key)
+                .filter(|key| MACHINE_KEYS.contains(&key.as_str()))
+                .collect();
+            if !kept.is_empty() {
+                lines.push(format!(
+                    "! Kept this machine's {} (import with --with-paths to take the bundle's)",
+                    kept.join(", ")
+                ));
+            }
+        }
+        lines.push(String::new());
+
+        lines.push("THEMES:".to_string());
+        if self.themes.is_empty() {
+            lines.push("  (none)".to_string());
+        }
+        for theme in &self.themes {
+            match existing_themes
+                .iter()
+                .find(|t| t.file_name == theme.file_name)
+            {
+                Some(existing) if existing.content == theme.content => {
+                    lines.push(format!("  {} (unchanged)", theme.file_name))
+                }
+                Some(_) => lines.push(format!("~ {} (overwrite)", theme.file_name)),
+                None => lines.push(format!("+ {}", theme.file_name)),
+            }
+        }
+        lines.push(String::new());
+
+        lines.push("SMART PLAYLISTS:".to_string());
+        if self.smart_playlists.is_empty() {
+            lines.push("  (none)".to_string());
+        }
+        for def in &self.smart_playlists {
+            match existing_playlists
+                .iter()
+                .find(|p| p.name.eq_ignore_ascii_case(&def.name))
+            {
+                Some(existing) if existing.smart_rules.as_ref() == Some(&def.rule) => {
+                    lines.push(format!("  {} (unchanged)", def.name))
+                }
+                Some(_) => lines.push(format!("~ {} (replace rules)", def.name)),
+                None => lines.push(format!("+ {}", def.name)),
+            }
+        }
+
+        lines
+    }
+
+    /// Write bundled theme files into `config_dir/themes/`. Returns the number written.
+    pub fn install_themes(&self, config_dir: &Path) -> Result<usize, SettingsBundleError> {
+        if self.themes.is_empty() {
+            return Ok(0);
+        }
+        let themes_dir = config_dir.join("themes");
+        std::fs::create_dir_all(&themes_dir)?;
+        let mut written = 0;
+        for theme in &self.themes {
+            // Only keep the final path component so a crafted bundle can't escape the directory
+            let file_name = PathBuf::from(&theme.file_name)
+                .file_name()
+                .map(|n| n.to_owned())
+                .unwrap_or_default();
+            if file_name.is_empty() {
+                continue;
+            }
+            std::fs::write(themes_dir.join(file_name), &theme.content)?;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+/// Flatten both configs to dotted keys and list `(key, old, new)` for every
+/// difference, leaving out secrets so their values are never displayed
+fn config_changes(current: &Config, incoming: &Config) -> Vec<(String, Option<String>, String)> {
+    let mut old = BTreeMap::new();
+    let mut new = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(current) {
+        flatten_json("", &value, &mut old);
+    }
+    if let Ok(value) = serde_json::to_value(incoming) {
+        flatten_json("", &value, &mut new);
+    }
+
+    new.into_iter()
+        .filter(|(key, _)| !SECRET_KEYS.contains(&key.as_str()))
+        .filter_map(|(key, value)| match old.get(&key) {
+            Some(existing) if *existing == value => None,
+            Some(existing) => Some((key, Some(existing.clone()), value)),
+            None => Some((key, None, value)),
+        })
+        .collect()
+}
+
+fn flatten_json(prefix: &str, value: &serde_json::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_json(&path, child, out);
+            }
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Parse a `major.minor.patch` string into comparable numbers; junk parts count as 0
+fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| {
+            part.chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::playlist::{PlaylistId, PlaylistType, SmartFilter};
+    use tempfile::TempDir;
+
+    fn make_smart_playlist(name: &str, rule: SmartPlaylistRule) -> Playlist {
+        Playlist {
+            id: PlaylistId::new(),
+            name: name.to_string(),
+            description: None,
+            playlist_type: PlaylistType::User,
+            episodes: Vec::new(),
+            created: Utc::now(),
+            last_updated: Utc::now(),
+            smart_rules: Some(rule),
+            playback: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bundle_write_read_roundtrip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("settings.json");
+        let mut config = Config::default();
+        config.ui.theme = "light".to_string();
+        let playlists = vec![make_smart_playlist(
+            "Favs",
+            SmartPlaylistRule::new(SmartFilter::Favorited),
+        )];
+        let themes = vec![ThemeFile {
+            file_name: "ocean.toml".to_string(),
+            content: "[metadata]\nname = \"ocean\"\n".to_string(),
+        }];
+        let bundle = SettingsBundle::new(config, themes.clone(), &playlists);
+
+        // Act
+        bundle.write(&path).await.unwrap();
+        let restored = SettingsBundle::read(&path).await.unwrap();
+
+        // Assert
+        assert_eq!(restored.format_version, BUNDLE_FORMAT_VERSION);
+        assert_eq!(restored.config.ui.theme, "light");
+        assert_eq!(restored.themes, themes);
+        assert_eq!(restored.smart_playlists.len(), 1);
+        assert_eq!(restored.smart_playlists[0].name, "Favs");
+    }
+
+    #[tokio::test]
+    async fn test_bundle_read_rejects_newer_format() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("settings.json");
+        let mut bundle = SettingsBundle::new(Config::default(), Vec::new(), &[]);
+        bundle.format_version = BUNDLE_FORMAT_VERSION + 1;
+        bundle.write(&path).await.unwrap();
+
+        // Act
+        let result = SettingsBundle::read(&path).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(SettingsBundleError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_bundle_ignores_non_smart_playlists() {
+        // Arrange
+        let mut plain =
+            make_smart_playlist("Plain", SmartPlaylistRule::new(SmartFilter::Favorited));
+        plain.smart_rules = None;
+
+        // Act
+        let bundle = SettingsBundle::new(Config::default(), Vec::new(), &[plain]);
+
+        // Assert
+        assert!(bundle.smart_playlists.is_empty());
+    }
+
+    #[test]
+    fn test_preview_lists_config_theme_and_playlist_changes() {
+        // Arrange
+        let current = Config::default();
+        let mut incoming = Config::default();
+        incoming.ui.theme = "solarized".to_string();
+        let bundle = SettingsBundle::new(
+            incoming,
+            vec![ThemeFile {
+                file_name: "ocean.toml".to_string(),
+                content: "x".to_string(),
+            }],
+            &[make_smart_playlist(
+                "Favs",
+                SmartPlaylistRule::new(SmartFilter::Favorited),
+            )],
+        );
+
+        // Act
+        let lines = bundle.preview(&current, &[], &[], false);
+
+        // Assert
+        assert!(lines
+            .iter()
+            .any(|l| l == "~ ui.theme: \"default\" -> \"solarized\""));
+        assert!(lines.iter().any(|l| l == "+ ocean.toml"));
+        assert!(lines.iter().any(|l| l == "+ Favs"));
+    }
+
+    #[test]
+    fn test_bundle_leaves_out_api_secrets() {
+        // Arrange
+        let mut config = Config::default();
+        config.discovery.podcastindex_api_key = "key123".to_string();
+        config.discovery.podcastindex_api_secret = "secret456".to_string();
+
+        // Act
+        let bundle = SettingsBundle::new(config, Vec::new(), &[]);
+        let json = serde_json::to_string(&bundle).unwrap();
+
+        // Assert
+        assert!(!json.contains("key123"));
+        assert!(!json.contains("secret456"));
+    }
+
+    #[test]
+    fn test_import_keeps_local_secrets_and_never_previews_them() {
+        // Arrange — a bundle carrying secrets, as an older export would
+        let mut current = Config::default();
+        current.discovery.podcastindex_api_key = "local-key".to_string();
+        let mut bundle = SettingsBundle::new(Config::default(), Vec::new(), &[]);
+        bundle.config.discovery.podcastindex_api_key = "their-key".to_string();
+        bundle.config.discovery.podcastindex_api_secret = "their-secret".to_string();
+
+        // Act
+        let lines = bundle.preview(&current, &[], &[], true);
+        let merged = bundle.merged_config(&current, true);
+
+        // Assert
+        assert!(lines
+            .iter()
+            .all(|l| !l.contains("key") && !l.contains("secret")));
+        assert_eq!(merged.discovery.podcastindex_api_key, "local-key");
+        assert_eq!(merged.discovery.podcastindex_api_secret, "");
+    }
+
+    #[test]
+    fn test_import_keeps_machine_paths_unless_asked() {
+        // Arrange
+        let mut current = Config::default();
+        current.downloads.directory = "/home/me/Podcasts".to_string();
+        let mut incoming = Config::default();
+        incoming.downloads.directory = "D:\\Podcasts".to_string();
+        incoming.ui.theme = "light".to_string();
+        let bundle = SettingsBundle::new(incoming, Vec::new(), &[]);
+
+        // Act
+        let kept = bundle.merged_config(&current, false);
+        let taken = bundle.merged_config(&current, true);
+        let lines = bundle.preview(&current, &[], &[], false);
+
+        // Assert
+        assert_eq!(kept.downloads.directory, "/home/me/Podcasts");
+        assert_eq!(kept.ui.theme, "light");
+        assert_eq!(taken.downloads.directory, "D:\\Podcasts");
+        assert!(!lines.iter().any(|l| l.starts_with("~ downloads.directory")));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("! Kept this machine's downloads.directory")));
+    }
+
+    #[test]
+    fn test_preview_reports_no_changes_for_identical_config() {
+        // Arrange
+        let bundle = SettingsBundle::new(Config::default(), Vec::new(), &[]);
+
+        // Act
+        let lines = bundle.preview(&Config::default(), &[], &[], false);
+
+        // Assert
+        assert!(lines.iter().any(|l| l == "  (no changes)"));
+    }
+
+    #[test]
+    fn test_preview_warns_for_newer_app_version() {
+        // Arrange
+        let mut bundle = SettingsBundle::new(Config::default(), Vec::new(), &[]);
+        bundle.app_version = "999.0.0".to_string();
+
+        // Act
+        let lines = bundle.preview(&Config::default(), &[], &[], false);
+
+        // Assert
+        assert!(bundle.is_from_newer_app());
+        assert!(lines.iter().any(|l| l.starts_with("! ")));
+    }
+
+    #[test]
+    fn test_collect_and_install_themes_roundtrip() {
+        // Arrange
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        std::fs::create_dir_all(source.path().join("themes")).unwrap();
+        std::fs::write(source.path().join("themes/ocean.toml"), "a = 1").unwrap();
+        std::fs::write(source.path().join("themes/readme.txt"), "ignored").unwrap();
+
+        // Act
+        let themes = SettingsBundle::collect_themes(source.path()).unwrap();
+        let bundle = SettingsBundle::new(Config::default(), themes, &[]);
+        let written = bundle.install_themes(target.path()).unwrap();
+
+        // Assert
+        assert_eq!(written, 1);
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("themes/ocean.toml")).unwrap(),
+            "a = 1"
+        );
+        assert!(!target.path().join("themes/readme.txt").exists());
+    }
+
+    #[test]
+    fn test_install_themes_strips_directory_components() {
+        // Arrange
+        let target = TempDir::new().unwrap();
+        let bundle = SettingsBundle::new(
+            Config::default(),
+            vec![ThemeFile {
+                file_name: "../escape.toml".to_string(),
+                content: "x".to_string(),
+            }],
+            &[],
+        );
+
+        // Act
+        bundle.install_themes(target.path()).unwrap();
+
+        // Assert
+        assert!(target.path().join("themes/escape.toml").exists());
+        assert!(!target.path().join("escape.toml").exists());
+    }
+
+    #[test]
+    fn test_install_themes_counts_only_files_written() {
+        // Arrange
+        let target = TempDir::new().unwrap();
+        let bundle = SettingsBundle::new(
+            Config::default(),
+            vec![
+                ThemeFile {
+                    file_name: "ocean.toml".to_string(),
+                    content: "x".to_string(),
+                },
+                ThemeFile {
+                    file_name: "..".to_string(),
+                    content: "x".to_string(),
+                },
+            ],
+            &[],
+        );
+
+        // Act
+        let written = bundle.install_themes(target.path()).unwrap();
+
+        // Assert
+        assert_eq!(written, 1);
+    }
+}
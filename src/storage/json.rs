@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use directories::ProjectDirs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::fs;
 
 use crate::playlist::{Playlist, PlaylistId};
-use crate::podcast::{Episode, Podcast};
+use crate::podcast::{DownloadState, Episode, Podcast};
 use crate::storage::{EpisodeId, PodcastId, Storage, StorageError};
 use crate::utils::text::strip_html;
 use crate::utils::validation::sanitize_playlist_name;
@@ -28,6 +29,8 @@ pub struct JsonStorage {
     podcasts_dir: PathBuf,
     episodes_dir: PathBuf,
     playlists_dir: PathBuf,
+    /// Refuse writes, for browsing a data directory another instance holds
+    read_only: bool,
 }
 
 impl JsonStorage {
@@ -54,6 +57,7 @@ impl JsonStorage {
             podcasts_dir,
             episodes_dir,
             playlists_dir,
+            read_only: false,
         })
     }
 
@@ -68,6 +72,21 @@ impl JsonStorage {
             podcasts_dir,
             episodes_dir,
             playlists_dir,
+            read_only: false,
+        }
+    }
+
+    /// Refuse every write with `StorageError::ReadOnly`
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    fn check_writable(&self) -> Result<(), StorageError> {
+        if self.read_only {
+            Err(StorageError::ReadOnly)
+        } else {
+            Ok(())
         }
     }
 
@@ -147,16 +166,23 @@ impl JsonStorage {
                 .map_err(|e| StorageError::file_operation("create_dir_all", parent, e))?;
         }
 
-        // Write to temporary file first
-        let temp_path = path.with_extension("tmp");
+        // Write to a temporary file first, named per write so two writes of
+        // the same record can't rename each other's file away
+        static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+        let temp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+        ));
         fs::write(&temp_path, content)
             .await
             .map_err(|e| StorageError::file_operation("write_temp", &temp_path, e))?;
 
         // Atomically move to final location
-        fs::rename(&temp_path, path)
-            .await
-            .map_err(|e| StorageError::file_operation("rename", path, e))?;
+        if let Err(e) = fs::rename(&temp_path, path).await {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(StorageError::file_operation("rename", path, e));
+        }
 
         Ok(())
     }
@@ -167,6 +193,7 @@ impl Storage for JsonStorage {
     type Error = StorageError;
 
     async fn save_podcast(&self, podcast: &Podcast) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let path = self.podcast_path(&podcast.id);
         let json = serde_json::to_string_pretty(podcast)?;
 
@@ -197,6 +224,7 @@ impl Storage for JsonStorage {
     }
 
     async fn delete_podcast(&self, id: &PodcastId) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let path = self.podcast_path(id);
 
         if !path.exists() {
@@ -266,6 +294,7 @@ impl Storage for JsonStorage {
         podcast_id: &PodcastId,
         episode: &Episode,
     ) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let path = self.episode_path(podcast_id, &episode.id);
         let json = serde_json::to_string_pretty(episode)?;
 
@@ -300,6 +329,15 @@ impl Storage for JsonStorage {
             }
         }
 
+        // Migration: the single status older files stored couldn't say
+        // "played and still downloaded", so played episodes came back as
+        // not downloaded even with their file on disk
+        if episode.status.download == DownloadState::NotDownloaded
+            && episode.local_path.as_ref().is_some_and(|p| p.exists())
+        {
+            episode.status.download = DownloadState::Downloaded;
+        }
+
         Ok(episode)
     }
 
@@ -308,6 +346,7 @@ impl Storage for JsonStorage {
         podcast_id: &PodcastId,
         episode_id: &EpisodeId,
     ) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let path = self.episode_path(podcast_id, episode_id);
 
         if !path.exists() {
@@ -378,6 +417,7 @@ impl Storage for JsonStorage {
         podcast_id: &PodcastId,
         episodes: &[Episode],
     ) -> Result<(), Self::Error> {
+        self.check_writable()?;
         // Create episodes directory for this podcast if it doesn't exist
         let episodes_dir = self.podcast_episodes_dir(podcast_id);
         fs::create_dir_all(&episodes_dir)
@@ -405,6 +445,7 @@ impl Storage for JsonStorage {
     }
 
     async fn save_playlist(&self, playlist: &Playlist) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let playlist_dir = self.playlist_dir_by_name(&playlist.name);
         let metadata_path = self.playlist_metadata_path_by_name(&playlist.name);
         let audio_dir = playlist_dir.join("audio");
@@ -470,6 +511,7 @@ impl Storage for JsonStorage {
     }
 
     async fn delete_playlist(&self, id: &PlaylistId) -> Result<(), Self::Error> {
+        self.check_writable()?;
         let metadata_path = self
             .find_playlist_metadata_path_by_id(id)
             .await?
@@ -529,6 +571,10 @@ impl Storage for JsonStorage {
     }
 
     async fn initialize(&self) -> Result<(), Self::Error> {
+        // Migrating or creating directories is writing too
+        if self.read_only {
+            return Ok(());
+        }
         let legacy_playlists_dir = self.data_dir.join("playlists");
         if legacy_playlists_dir.exists() && !self.playlists_dir.exists() {
             fs::rename(&legacy_playlists_dir, &self.playlists_dir)
@@ -586,6 +632,10 @@ impl Storage for JsonStorage {
         // For now, this is a no-op
         Ok(())
     }
+
+    fn is_read_only(&self) -> bool {
+        self.read_only
+    }
 }
 
 impl Default for JsonStorage {
@@ -619,6 +669,63 @@ mod tests {
         assert!(storage.playlists_dir.exists());
     }
 
+    #[tokio::test]
+    async fn test_read_only_storage_loads_but_refuses_writes() {
+        // Arrange — a podcast written by the instance holding the directory
+        let (storage, temp_dir) = create_test_storage();
+        storage.initialize().await.unwrap();
+        let podcast = Podcast::new(
+            "Held Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap();
+        let browser = JsonStorage::with_data_dir(temp_dir.path().to_path_buf()).read_only();
+
+        // Act
+        browser.initialize().await.unwrap();
+        let loaded = browser.load_podcast(&podcast.id).await.unwrap();
+        let saved = browser.save_podcast(&loaded).await;
+        let deleted = browser.delete_podcast(&podcast.id).await;
+
+        // Assert
+        assert!(browser.is_read_only());
+        assert_eq!(loaded.title, "Held Podcast");
+        assert!(matches!(saved, Err(StorageError::ReadOnly)));
+        assert!(matches!(deleted, Err(StorageError::ReadOnly)));
+        assert!(storage.podcast_exists(&podcast.id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_saves_of_one_podcast_all_succeed() {
+        // Arrange
+        let (storage, _temp_dir) = create_test_storage();
+        storage.initialize().await.unwrap();
+        let storage = std::sync::Arc::new(storage);
+        let podcast = Podcast::new(
+            "Busy Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+
+        // Act
+        let saves: Vec<_> = (0..16)
+            .map(|_| {
+                let storage = storage.clone();
+                let podcast = podcast.clone();
+                tokio::spawn(async move { storage.save_podcast(&podcast).await })
+            })
+            .collect();
+        let results = futures_util::future::join_all(saves).await;
+
+        // Assert — every save landed and no temp file was left behind
+        assert!(results.into_iter().all(|r| r.unwrap().is_ok()));
+        assert!(storage.podcast_exists(&podcast.id).await.unwrap());
+        let leftovers = std::fs::read_dir(&storage.podcasts_dir)
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().path().extension() == Some("tmp".as_ref()))
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
     #[tokio::test]
     async fn test_podcast_crud_operations() {
         let (storage, _temp_dir) = create_test_storage();
@@ -641,6 +748,17 @@ mod tests {
             last_updated: chrono::Utc::now(),
             episodes: Vec::new(),
             tags: Vec::new(),
+            removed_at: None,
+            history: Vec::new(),
+            user_agent: None,
+            notes: None,
+            download_folder: None,
+            auto_play_next: None,
+            complete: false,
+            archived_at: None,
+            download_quality: None,
+            settings: Default::default(),
+            people: Vec::new(),
         };
 
         // Save podcast
@@ -682,6 +800,41 @@ mod tests {
         assert!(!exists);
     }
 
+    #[tokio::test]
+    async fn test_legacy_played_episode_keeps_its_download() {
+        // Arrange — a file from before the status split: played, file on disk
+        let (storage, temp_dir) = create_test_storage();
+        storage.initialize().await.unwrap();
+        let audio = temp_dir.path().join("episode.mp3");
+        std::fs::write(&audio, b"audio").unwrap();
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Played".to_string(),
+            "https://example.com/episode.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.local_path = Some(audio);
+        storage
+            .save_episode(&episode.podcast_id, &episode)
+            .await
+            .unwrap();
+        let path = storage.episode_path(&episode.podcast_id, &episode.id);
+        let mut json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        json["status"] = serde_json::Value::String("Played".to_string());
+        std::fs::write(&path, json.to_string()).unwrap();
+
+        // Act
+        let loaded = storage
+            .load_episode(&episode.podcast_id, &episode.id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(loaded.is_played());
+        assert!(loaded.is_downloaded());
+    }
+
     // Additional tests would go here for episode operations, error handling, etc.
 
     fn create_test_playlist(name: &str) -> Playlist {
@@ -702,6 +855,7 @@ mod tests {
             created: chrono::Utc::now(),
             last_updated: chrono::Utc::now(),
             smart_rules: None,
+            playback: Default::default(),
         }
     }
 
@@ -0,0 +1,156 @@
+// Listening log - when episodes were finished, for statistics and goals
+//
+// Episodes only remember *whether* they were played, so a separate
+// `listening_log.json` in the data directory records each finish (played to
+// the end, or marked played) with the time it happened. Entries older than
+// `LISTENING_LOG_DAYS` are dropped on write.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::sync::Mutex;
+
+use crate::constants::storage::LISTENING_LOG_DAYS;
+use crate::storage::{EpisodeId, PodcastId, StorageError};
+
+const LISTENING_LOG_FILE: &str = "listening_log.json";
+
+/// One finished episode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListenRecord {
+    pub at: DateTime<Utc>,
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+    /// Seconds of audio played; 0 when the episode was only marked played
+    pub seconds: u32,
+}
+
+/// Append-only log of finished episodes
+pub struct ListeningLog {
+    path: PathBuf,
+    /// Serialises read-modify-write cycles
+    lock: Mutex<()>,
+    /// Drop new records instead of writing them (`--read-only`)
+    read_only: bool,
+}
+
+impl ListeningLog {
+    /// Log kept in `data_dir`
+    pub fn new(data_dir: &Path) -> Self {
+        Self {
+            path: data_dir.join(LISTENING_LOG_FILE),
+            lock: Mutex::new(()),
+            read_only: false,
+        }
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub async fn record(&self, record: ListenRecord) -> Result<(), StorageError> {
+        if self.read_only {
+            return Ok(());
+        }
+        let _guard = self.lock.lock().await;
+        let cutoff = Utc::now() - Duration::days(LISTENING_LOG_DAYS);
+        let mut records = self.read().await?;
+        records.retain(|r| r.at >= cutoff);
+        records.push(record);
+        self.write(&records).await
+    }
+
+    /// Records at or after `since`, oldest first
+    pub async fn since(&self, since: DateTime<Utc>) -> Result<Vec<ListenRecord>, StorageError> {
+        let _guard = self.lock.lock().await;
+        let mut records = self.read().await?;
+        records.retain(|r| r.at >= since);
+        Ok(records)
+    }
+
+    async fn read(&self) -> Result<Vec<ListenRecord>, StorageError> {
+        match fs::read_to_string(&self.path).await {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(StorageError::file_operation("read", &self.path, e)),
+        }
+    }
+
+    async fn write(&self, records: &[ListenRecord]) -> Result<(), StorageError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| StorageError::file_operation("create_dir_all", parent, e))?;
+        }
+
+        let temp_path = self.path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(records)?;
+        fs::write(&temp_path, json)
+            .await
+            .map_err(|e| StorageError::file_operation("write_temp", &temp_path, e))?;
+        fs::rename(&temp_path, &self.path)
+            .await
+            .map_err(|e| StorageError::file_operation("rename", &self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(at: DateTime<Utc>, seconds: u32) -> ListenRecord {
+        ListenRecord {
+            at,
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            seconds,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_persist_and_filter_by_time() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let log = ListeningLog::new(dir.path());
+        let now = Utc::now();
+
+        // Act
+        log.record(record(now - Duration::days(10), 600))
+            .await
+            .unwrap();
+        log.record(record(now, 1800)).await.unwrap();
+        let reopened = ListeningLog::new(dir.path());
+
+        // Assert
+        assert_eq!(
+            reopened
+                .since(now - Duration::days(30))
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        let recent = reopened.since(now - Duration::days(1)).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].seconds, 1800);
+    }
+
+    #[tokio::test]
+    async fn test_old_records_are_dropped_on_write() {
+        let dir = TempDir::new().unwrap();
+        let log = ListeningLog::new(dir.path());
+        let now = Utc::now();
+
+        log.record(record(now - Duration::days(LISTENING_LOG_DAYS + 1), 60))
+            .await
+            .unwrap();
+        log.record(record(now, 60)).await.unwrap();
+
+        let all = log.since(DateTime::<Utc>::MIN_UTC).await.unwrap();
+        assert_eq!(all.len(), 1);
+    }
+}
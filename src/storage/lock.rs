@@ -0,0 +1,325 @@
+// Storage lock - one writer per data directory
+//
+// Two instances writing the same JSON files (two TUIs, or the TUI next to
+// `podcast-tui daemon`) can interleave and corrupt them, so the writer holds
+// `podcast-tui.lock` in the data directory for as long as it runs. A second
+// instance is turned away with who holds the lock; it can browse with
+// `--read-only`, or take the lock over with `--force` when the holder is
+// gone. The lock names the holder's host as well as its pid, since a shared
+// data directory may be used from several machines: a lock left behind by a
+// process that no longer runs is taken over without asking only when it was
+// taken on this host and that can be checked (Linux). The lock file is
+// linked into place already written, so it never exists without its holder,
+// and one that can't be read counts as held.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::StorageError;
+
+const LOCK_FILE: &str = "podcast-tui.lock";
+
+/// How an instance opens the data directory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageAccess {
+    /// Take the lock, failing when another instance holds it
+    #[default]
+    Exclusive,
+    /// Take the lock even from another instance (`--force`)
+    Force,
+    /// Don't lock and don't write (`--read-only`)
+    ReadOnly,
+}
+
+/// Who holds a lock
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockHolder {
+    pub pid: u32,
+    /// Host the holder runs on; empty in locks written before it was recorded
+    #[serde(default)]
+    pub host: String,
+    /// `tui` or `daemon`
+    pub role: String,
+    pub since: DateTime<Utc>,
+}
+
+impl LockHolder {
+    fn this_process(role: &str) -> Self {
+        Self {
+            pid: std::process::id(),
+            host: host_name(),
+            role: role.to_string(),
+            since: Utc::now(),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let host = if self.host.is_empty() {
+            String::new()
+        } else {
+            format!(" on {}", self.host)
+        };
+        format!(
+            "podcast-tui {} (pid {}{}, since {})",
+            self.role,
+            self.pid,
+            host,
+            self.since.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        )
+    }
+
+    /// Whether this process holds the lock
+    fn is_this_process(&self) -> bool {
+        self.pid == std::process::id() && self.host == host_name()
+    }
+
+    /// Whether the holder has certainly stopped: it ran on this host and
+    /// its process is gone
+    fn is_gone(&self) -> bool {
+        !self.host.is_empty() && self.host == host_name() && !process_running(self.pid)
+    }
+}
+
+/// The held lock, released when dropped
+#[derive(Debug)]
+pub struct StorageLock {
+    path: PathBuf,
+}
+
+impl StorageLock {
+    /// Lock `data_dir` for this process, acting as `role`. With `force` an
+    /// existing lock is taken over whoever holds it.
+    pub fn acquire(data_dir: &Path, role: &str, force: bool) -> Result<Self, StorageError> {
+        std::fs::create_dir_all(data_dir).map_err(|_| StorageError::DirectoryCreation {
+            path: data_dir.display().to_string(),
+        })?;
+        let path = data_dir.join(LOCK_FILE);
+        let content = serde_json::to_string(&LockHolder::this_process(role))?;
+
+        // Written in full beside the lock, then linked into place
+        let staging = data_dir.join(format!("{}.{}.tmp", LOCK_FILE, std::process::id()));
+        std::fs::File::create(&staging)
+            .and_then(|mut file| {
+                file.write_all(content.as_bytes())?;
+                file.sync_all()
+            })
+            .map_err(|e| StorageError::file_operation("write", &staging, e))?;
+        let result = Self::link_into_place(data_dir, &staging, &content, &path, force);
+        let _ = std::fs::remove_file(&staging);
+        result
+    }
+
+    fn link_into_place(
+        data_dir: &Path,
+        staging: &Path,
+        content: &str,
+        path: &Path,
+        force: bool,
+    ) -> Result<Self, StorageError> {
+        // A second attempt follows removing a stale or overridden lock
+        for _ in 0..2 {
+            match place(staging, content, path) {
+                Ok(()) => {
+                    return Ok(Self {
+                        path: path.to_path_buf(),
+                    })
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let found = std::fs::read_to_string(path).ok();
+                    let holder = found
+                        .as_deref()
+                        .and_then(|content| serde_json::from_str::<LockHolder>(content).ok());
+                    match &holder {
+                        _ if force => {}
+                        Some(other) if other.is_gone() => {}
+                        Some(other) => {
+                            return Err(StorageError::Locked {
+                                holder: other.describe(),
+                            })
+                        }
+                        None => {
+                            return Err(StorageError::Locked {
+                                holder: format!(
+                                    "an instance whose lock can't be read ({})",
+                                    path.display()
+                                ),
+                            })
+                        }
+                    }
+                    // Only remove the lock judged, not one that replaced it since
+                    if std::fs::read_to_string(path).ok() == found {
+                        std::fs::remove_file(path)
+                            .map_err(|e| StorageError::file_operation("remove", path, e))?;
+                    }
+                }
+                Err(e) => return Err(StorageError::file_operation("create", path, e)),
+            }
+        }
+        let holder = Self::holder(data_dir).map_or_else(
+            || "another instance".to_string(),
+            |holder| holder.describe(),
+        );
+        Err(StorageError::Locked { holder })
+    }
+
+    /// Who holds the lock on `data_dir`, if anyone (readably)
+    pub fn holder(data_dir: &Path) -> Option<LockHolder> {
+        let content = std::fs::read_to_string(data_dir.join(LOCK_FILE)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+}
+
+impl Drop for StorageLock {
+    fn drop(&mut self) {
+        // Leave a lock that was forced away from us to its new holder
+        let ours = std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<LockHolder>(&content).ok())
+            .is_some_and(|holder| holder.is_this_process());
+        if ours {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Put the lock at `path` with `content`, failing with `AlreadyExists` when
+/// one is there. Linking the written `staging` file means the lock never
+/// exists empty; where hard links aren't supported it is created in place,
+/// and a lock caught half-written reads as held.
+fn place(staging: &Path, content: &str, path: &Path) -> std::io::Result<()> {
+    match std::fs::hard_link(staging, path) {
+        Err(e) if e.kind() != std::io::ErrorKind::AlreadyExists => {
+            let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+            file.write_all(content.as_bytes())
+        }
+        result => result,
+    }
+}
+
+/// Whether process `pid` still runs; assumed so where that can't be checked
+fn process_running(pid: u32) -> bool {
+    if cfg!(target_os = "linux") {
+        Path::new("/proc").join(pid.to_string()).exists()
+    } else {
+        true
+    }
+}
+
+/// This machine's name, or empty when it can't be found
+fn host_name() -> String {
+    let from_kernel = std::fs::read_to_string("/proc/sys/kernel/hostname").ok();
+    from_kernel
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| {
+            std::process::Command::new("hostname")
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+        })
+        .map(|name| name.trim().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_instance_is_turned_away_until_forced() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let lock = StorageLock::acquire(dir.path(), "tui", false).unwrap();
+
+        // Act — this process still runs, so its lock holds
+        let second = StorageLock::acquire(dir.path(), "daemon", false);
+
+        // Assert
+        match second {
+            Err(StorageError::Locked { holder }) => {
+                assert!(holder.starts_with("podcast-tui tui (pid "), "{holder}");
+            }
+            other => panic!("expected Locked, got {:?}", other),
+        }
+        let forced = StorageLock::acquire(dir.path(), "daemon", true).unwrap();
+        assert_eq!(StorageLock::holder(dir.path()).unwrap().role, "daemon");
+        drop(forced);
+        assert!(StorageLock::holder(dir.path()).is_none());
+        drop(lock);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_lock_of_finished_process_is_taken_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let stale = LockHolder {
+            pid: u32::MAX,
+            host: host_name(),
+            role: "tui".to_string(),
+            since: Utc::now(),
+        };
+        std::fs::write(
+            dir.path().join(LOCK_FILE),
+            serde_json::to_string(&stale).unwrap(),
+        )
+        .unwrap();
+
+        let lock = StorageLock::acquire(dir.path(), "tui", false).unwrap();
+
+        assert_eq!(
+            StorageLock::holder(dir.path()).unwrap().pid,
+            std::process::id()
+        );
+        drop(lock);
+    }
+
+    #[test]
+    fn test_lock_from_another_host_is_only_taken_by_force() {
+        // Arrange — its pid means nothing here, running or not
+        let dir = tempfile::tempdir().unwrap();
+        let remote = LockHolder {
+            pid: u32::MAX,
+            host: "nas-that-is-not-this-host".to_string(),
+            role: "daemon".to_string(),
+            since: Utc::now(),
+        };
+        std::fs::write(
+            dir.path().join(LOCK_FILE),
+            serde_json::to_string(&remote).unwrap(),
+        )
+        .unwrap();
+
+        // Act
+        let refused = StorageLock::acquire(dir.path(), "tui", false);
+
+        // Assert
+        match refused {
+            Err(StorageError::Locked { holder }) => {
+                assert!(holder.contains("on nas-that-is-not-this-host"), "{holder}");
+            }
+            other => panic!("expected Locked, got {:?}", other),
+        }
+        let forced = StorageLock::acquire(dir.path(), "tui", true).unwrap();
+        assert!(StorageLock::holder(dir.path()).unwrap().is_this_process());
+        drop(forced);
+    }
+
+    #[test]
+    fn test_unreadable_lock_counts_as_held() {
+        // A lock caught before its holder was written reads as empty
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(LOCK_FILE), "").unwrap();
+
+        let refused = StorageLock::acquire(dir.path(), "tui", false);
+
+        assert!(matches!(refused, Err(StorageError::Locked { .. })));
+        assert!(dir.path().join(LOCK_FILE).exists());
+        let names: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(names.len(), 1, "the staged lock is cleaned up");
+    }
+}
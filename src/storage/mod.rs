@@ -1,8 +1,14 @@
 pub mod json;
+pub mod listening_log;
+pub mod lock;
 pub mod models;
+pub mod secrets;
 pub mod traits;
 
 // Re-export the storage trait and main implementation
 pub use json::JsonStorage;
+pub use listening_log::{ListenRecord, ListeningLog};
+pub use lock::{StorageAccess, StorageLock};
 pub use models::*;
+pub use secrets::SecretStore;
 pub use traits::Storage;
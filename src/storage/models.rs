@@ -130,6 +130,12 @@ pub enum StorageError {
 
     #[error("Could not restore from backup: {reason}")]
     RestoreFailed { reason: String },
+
+    #[error("{holder} is using this data directory. Run with --read-only to browse it, or --force if it has stopped.")]
+    Locked { holder: String },
+
+    #[error("Read-only mode: changes are not saved.")]
+    ReadOnly,
 }
 
 impl StorageError {
@@ -180,6 +186,8 @@ impl StorageError {
             }
             Self::BackupFailed { reason } => format!("Backup operation failed: {}", reason),
             Self::RestoreFailed { reason } => format!("Restore operation failed: {}", reason),
+            Self::Locked { holder } => format!("Storage locked by {}", holder),
+            Self::ReadOnly => "Write refused in read-only mode".to_string(),
         }
     }
 }
@@ -0,0 +1,165 @@
+// Secrets store - credentials kept out of podcast and config files
+//
+// A single `secrets.json` in the data directory maps keys (e.g.
+// `oauth:patreon`) to secret strings. It is written atomically and, on Unix,
+// readable only by the owner, so it can hold refresh tokens.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use crate::storage::StorageError;
+
+const SECRETS_FILE: &str = "secrets.json";
+
+/// Key-value store for tokens and other credentials
+pub struct SecretStore {
+    path: PathBuf,
+    /// Serialises read-modify-write cycles
+    lock: Mutex<()>,
+}
+
+impl SecretStore {
+    /// Store kept in `data_dir`
+    pub fn new(data_dir: &Path) -> Self {
+        Self {
+            path: data_dir.join(SECRETS_FILE),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+        let _guard = self.lock.lock().await;
+        Ok(self.read().await?.remove(key))
+    }
+
+    pub async fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        let _guard = self.lock.lock().await;
+        let mut secrets = self.read().await?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.write(&secrets).await
+    }
+
+    /// Forget `key`; `false` if it wasn't stored
+    pub async fn remove(&self, key: &str) -> Result<bool, StorageError> {
+        let _guard = self.lock.lock().await;
+        let mut secrets = self.read().await?;
+        if secrets.remove(key).is_none() {
+            return Ok(false);
+        }
+        self.write(&secrets).await?;
+        Ok(true)
+    }
+
+    async fn read(&self) -> Result<BTreeMap<String, String>, StorageError> {
+        match fs::read_to_string(&self.path).await {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(StorageError::file_operation("read", &self.path, e)),
+        }
+    }
+
+    async fn write(&self, secrets: &BTreeMap<String, String>) -> Result<(), StorageError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| StorageError::file_operation("create_dir_all", parent, e))?;
+        }
+
+        let temp_path = self.path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(secrets)?;
+        // Created owner-only rather than restricted after writing, so the
+        // secrets are never readable by others; a leftover temp file would
+        // keep its old mode, hence `create_new`
+        let _ = fs::remove_file(&temp_path).await;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&temp_path)
+            .await
+            .map_err(|e| StorageError::file_operation("create_temp", &temp_path, e))?;
+        file.write_all(json.as_bytes())
+            .await
+            .map_err(|e| StorageError::file_operation("write_temp", &temp_path, e))?;
+        drop(file);
+        fs::rename(&temp_path, &self.path)
+            .await
+            .map_err(|e| StorageError::file_operation("rename", &self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_set_get_remove_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let store = SecretStore::new(dir.path());
+
+        // Act
+        store.set("oauth:patreon", "refresh-1").await.unwrap();
+        store.set("oauth:other", "refresh-2").await.unwrap();
+        let reopened = SecretStore::new(dir.path());
+
+        // Assert
+        assert_eq!(
+            reopened.get("oauth:patreon").await.unwrap().as_deref(),
+            Some("refresh-1")
+        );
+        assert!(reopened.remove("oauth:patreon").await.unwrap());
+        assert!(!reopened.remove("oauth:patreon").await.unwrap());
+        assert_eq!(reopened.get("oauth:patreon").await.unwrap(), None);
+        assert_eq!(
+            reopened.get("oauth:other").await.unwrap().as_deref(),
+            Some("refresh-2")
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_file_is_private_to_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let store = SecretStore::new(dir.path());
+        store.set("key", "value").await.unwrap();
+
+        let mode = std::fs::metadata(dir.path().join(SECRETS_FILE))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_leftover_temp_file_does_not_widen_access() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange — a world-readable temp file left by an interrupted write
+        let dir = TempDir::new().unwrap();
+        let temp_path = dir.path().join(SECRETS_FILE).with_extension("tmp");
+        std::fs::write(&temp_path, "{}").unwrap();
+        std::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let store = SecretStore::new(dir.path());
+
+        // Act
+        store.set("key", "value").await.unwrap();
+
+        // Assert
+        let mode = std::fs::metadata(dir.path().join(SECRETS_FILE))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(store.get("key").await.unwrap().as_deref(), Some("value"));
+    }
+}
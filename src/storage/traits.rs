@@ -64,6 +64,11 @@ pub trait Storage: Send + Sync {
     async fn backup(&self, path: &std::path::Path) -> Result<(), Self::Error>;
     async fn restore(&self, path: &std::path::Path) -> Result<(), Self::Error>;
     async fn cleanup(&self) -> Result<(), Self::Error>;
+
+    /// Whether writes are refused (`--read-only`)
+    fn is_read_only(&self) -> bool {
+        false
+    }
 }
 
 /// Convenience type for boxed storage implementations
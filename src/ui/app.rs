@@ -3,6 +3,7 @@
 //! all UI components, manages state, and handles the event loop.
 
 use std::{
+    collections::HashMap,
     io,
     time::{Duration, Instant},
 };
@@ -15,31 +16,66 @@ use futures_util::stream::{self, StreamExt};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame, Terminal,
 };
 use tokio::sync::mpsc;
 
 use crate::{
-    audio::{AudioCommand, PlaybackStatus},
+    audio::{queue::QueueManager, AudioCommand, PlaybackState, PlaybackStatus},
     config::Config,
     constants::ui as ui_constants,
-    download::DownloadManager,
-    playlist::{auto_generator::TodayGenerator, manager::PlaylistManager},
-    podcast::subscription::SubscriptionManager,
-    storage::{JsonStorage, Storage},
+    crash::CrashContext,
+    download::{
+        prefetch, AutoDownloads, ConvertFormat, ConvertTarget, DownloadError, DownloadManager,
+    },
+    playlist::{
+        auto_generator::TodayGenerator,
+        manager::PlaylistManager,
+        queue::{PlayQueue, QueueEntry},
+        PlaybackModes, PlaylistId, RepeatMode,
+    },
+    podcast::{
+        stats::goal_reminder,
+        subscription::{SubscriptionManager, UnsubscribeMode},
+        BookmarkFormat, DownloadState, EpisodeField, FeedAuth, FeedError, GoalPeriod,
+        HardRefreshScope, ListeningGoal, ListeningStats, PodcastStats, SubscriptionError,
+    },
+    settings_bundle::SettingsBundle,
+    storage::{JsonStorage, ListenRecord, ListeningLog, Storage},
     ui::{
-        buffers::BufferManager,
-        components::{minibuffer::Minibuffer, minibuffer::MinibufferContent, statusbar::StatusBar},
+        background::{BackgroundTasks, TaskKind},
+        buffers::{
+            capabilities, playlist_detail::PlaylistEntryDetails, podcast_list::PodcastSortMode,
+            whats_new::EpisodeScope, BufferManager,
+        },
+        components::{
+            minibuffer::Minibuffer,
+            minibuffer::MinibufferContent,
+            scroll_indicator,
+            statusbar::{SegmentKind, StatusBar},
+            QrPopup, TextArea,
+        },
         events::{
             AggregatedEpisode, AppEvent, BufferRefreshData, BufferRefreshType, DownloadEntry,
-            UIEvent, UIEventHandler,
+            KeywordAlert, PerformanceSettings, PodcastSummary, UIEvent, UIEventHandler,
         },
-        keybindings::KeyHandler,
+        filters::{KeywordAlerts, WhatsNewExclusions},
+        glyphs::{self, GlyphSet},
+        key_parser::key_to_notation,
+        key_recorder::{KeyRecord, KeyRecorder, RecordedBufferState},
+        keybindings::{KeyChord, KeyHandler},
         theme_loader::ThemeRegistry,
         themes::Theme,
         UIAction, UIComponent, UIError, UIResult,
     },
+    utils::{
+        network::{read_network_metering, NetworkMetering},
+        power::{read_power_source, PowerSource},
+        time::DisplayZone,
+    },
 };
 use directories::ProjectDirs;
 use std::sync::Arc;
@@ -85,12 +121,20 @@ pub struct UIApp {
     /// Event handler
     event_handler: UIEventHandler,
 
+    /// Tick rate, frame pacing and debounce from `ui.performance`
+    performance: PerformanceSettings,
+
     /// App event sender for async communication
     app_event_tx: mpsc::UnboundedSender<AppEvent>,
 
     /// Whether the application should quit
     should_quit: bool,
 
+    /// Whether the status glyph legend popup is showing
+    show_glyph_legend: bool,
+    /// `:share-podcast` QR code, closed by any key
+    share_popup: Option<QrPopup>,
+
     /// Podcast ID pending deletion confirmation
     pending_deletion: Option<crate::storage::PodcastId>,
 
@@ -103,9 +147,100 @@ pub struct UIApp {
     /// Pending cleanup duration in hours (set when user confirms age-based cleanup)
     pending_cleanup_hours: Option<u64>,
 
+    /// Ended shows awaiting confirmation to be archived
+    pending_archive: Option<Vec<crate::storage::PodcastId>>,
+
+    /// Settings bundle (source path, bundle) awaiting import confirmation
+    pending_settings_import: Option<(String, Box<crate::settings_bundle::SettingsBundle>)>,
+
+    /// Episode field whose new value the minibuffer is prompting for
+    pending_episode_edit: Option<(
+        crate::storage::PodcastId,
+        crate::storage::EpisodeId,
+        EpisodeField,
+    )>,
+
+    /// Time of the last key press, for idle prefetching
+    last_input: Instant,
+
+    /// Started episode whose successors are still to be prefetched
+    prefetch_after: Option<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+
+    /// Episode that auto-plays when its countdown runs out (C-g cancels)
+    auto_play: Option<AutoPlayCountdown>,
+
+    /// Set while no feed server can be reached; refreshes and downloads
+    /// wait in it for the connection to return
+    offline: Option<OfflineMode>,
+    /// Network failures waiting on a connectivity check: if it reaches no
+    /// other feed server they go offline with the rest, otherwise their
+    /// error is shown (that one server is down, not the connection)
+    unconfirmed_offline: Vec<(PendingNetworkOp, String)>,
+
+    /// Full-text index the Search buffer queries, rebuilt each time it opens
+    search_index: Option<Arc<crate::search::SearchIndex>>,
+
+    /// Refreshes, writes and downloads wound down on quit
+    background_tasks: BackgroundTasks,
+
+    /// What the crash handler reports, when installed
+    crash_context: Option<CrashContext>,
+
+    /// Running `:record-keys` recording
+    key_recorder: Option<KeyRecorder>,
+
+    /// Podcast (and episode, for episode notes) whose notes are being edited
+    pending_notes: Option<(crate::storage::PodcastId, Option<crate::storage::EpisodeId>)>,
+
+    /// Options of the running OPML import; holds its cancellation flag
+    opml_import: Option<crate::podcast::OpmlImportOptions>,
+
+    /// OPML file of the feeds the last import couldn't add, for M-i to retry
+    failed_import_opml: Option<String>,
+
     /// Sender for dispatching audio playback commands (None when audio init failed).
     audio_command_tx: Option<mpsc::UnboundedSender<AudioCommand>>,
 
+    /// What the audio probe found at startup, for `:audio-diagnostics`
+    audio_diagnostics: Option<crate::audio::probe::AudioDiagnostics>,
+
+    /// Problems found in the config file at startup, for `:config-report`
+    config_issues: Vec<crate::config::ConfigIssue>,
+
+    /// Renderer playback is cast to (`None` = this computer)
+    cast_target: Option<String>,
+
+    /// Playlist being played through; the next entry starts when a track ends
+    play_queue: Option<PlayQueue>,
+
+    /// Last power source reported by the power monitor
+    power_source: PowerSource,
+
+    /// Manual heavy-I/O override: `Some(true)` pauses regardless of power,
+    /// `Some(false)` ignores the battery pause until AC returns
+    io_pause_override: Option<bool>,
+
+    /// When focus mode (`:focus`) ends; until then only errors are shown
+    focus_until: Option<Instant>,
+
+    /// Compiled `ui.whats_new_exclusions`, shared with background refresh tasks
+    whats_new_exclusions: Arc<WhatsNewExclusions>,
+
+    /// Compiled `alerts.keywords`, shared with background refresh tasks
+    keyword_alerts: Arc<KeywordAlerts>,
+
+    /// Keyword alerts raised this session, oldest first
+    alerts: Vec<KeywordAlert>,
+
+    /// When episodes were finished, for statistics and goals
+    listening_log: Arc<ListeningLog>,
+
+    /// Episodes lined up to play next, saved across restarts
+    queue: QueueManager,
+
+    /// Compiled `goals.targets`
+    listening_goals: Vec<ListeningGoal>,
+
     /// Last render time for performance tracking
     last_render: Instant,
 
@@ -145,12 +280,27 @@ impl UIApp {
             .cloned()
             .ok_or_else(|| {
                 UIError::InvalidOperation(format!("Unknown theme: {}", config.ui.theme))
-            })?;
-        let buffer_manager = BufferManager::new();
+            })?
+            .with_glyphs(GlyphSet::from_ascii_flag(config.ui.ascii_glyphs))
+            .with_color_blind_mode(config.ui.color_blind_mode)
+            .with_time_zone(DisplayZone::parse(&config.ui.timezone).unwrap_or_default());
+        let mut buffer_manager = BufferManager::new();
+        buffer_manager.set_max_episode_buffers(config.ui.max_episode_buffers);
+        buffer_manager.set_hide_explicit_all(config.ui.hide_explicit);
+        buffer_manager.set_languages_all(&config.ui.languages);
         let mut status_bar = StatusBar::new();
         status_bar.set_theme(theme.clone());
+        for error in status_bar.configure(&config.ui.statusbar) {
+            eprintln!("[statusbar] {error}");
+        }
 
-        let minibuffer = Minibuffer::new();
+        let (performance, performance_error) =
+            PerformanceSettings::from_config(&config.ui.performance);
+        if let Some(error) = performance_error {
+            eprintln!("[config] {error}");
+        }
+        let mut minibuffer = Minibuffer::new();
+        minibuffer.set_completion_debounce(performance.completion_debounce);
         let key_handler = KeyHandler::from_config(&config.keybindings);
 
         // Validate keybindings: warn on conflicts, error on unbound critical actions.
@@ -170,8 +320,14 @@ impl UIApp {
             )));
         }
 
-        let event_handler =
-            UIEventHandler::new(Duration::from_millis(ui_constants::UI_TICK_RATE_MS));
+        let whats_new_exclusions = compile_whats_new_exclusions(&config);
+        let keyword_alerts = compile_keyword_alerts(&config);
+        let listening_log =
+            Arc::new(ListeningLog::new(&storage.data_dir).with_read_only(storage.is_read_only()));
+        let queue = QueueManager::new(&storage.data_dir);
+        let listening_goals = compile_listening_goals(&config);
+
+        let event_handler = UIEventHandler::with_settings(&performance);
 
         Ok(Self {
             config,
@@ -187,15 +343,46 @@ impl UIApp {
             minibuffer,
             key_handler,
             event_handler,
+            performance,
             app_event_tx,
             should_quit: false,
+            show_glyph_legend: false,
+            share_popup: None,
             audio_command_tx: None,
+            audio_diagnostics: None,
+            config_issues: Vec::new(),
+            cast_target: None,
             last_render: Instant::now(),
             frame_count: 0,
             pending_deletion: None,
             pending_playlist_deletion: None,
             pending_bulk_deletion: false,
             pending_cleanup_hours: None,
+            pending_archive: None,
+            pending_settings_import: None,
+            pending_episode_edit: None,
+            pending_notes: None,
+            key_recorder: None,
+            crash_context: None,
+            background_tasks: BackgroundTasks::new(),
+            last_input: Instant::now(),
+            prefetch_after: None,
+            auto_play: None,
+            offline: None,
+            unconfirmed_offline: Vec::new(),
+            search_index: None,
+            opml_import: None,
+            failed_import_opml: None,
+            play_queue: None,
+            power_source: PowerSource::Unknown,
+            io_pause_override: None,
+            focus_until: None,
+            whats_new_exclusions,
+            keyword_alerts,
+            alerts: Vec::new(),
+            listening_log,
+            queue,
+            listening_goals,
         })
     }
 
@@ -231,12 +418,27 @@ impl UIApp {
             .cloned()
             .ok_or_else(|| {
                 UIError::InvalidOperation(format!("Unknown theme: {}", config.ui.theme))
-            })?;
+            })?
+            .with_glyphs(GlyphSet::from_ascii_flag(config.ui.ascii_glyphs))
+            .with_color_blind_mode(config.ui.color_blind_mode)
+            .with_time_zone(DisplayZone::parse(&config.ui.timezone).unwrap_or_default());
         let mut buffer_manager = BufferManager::new();
+        buffer_manager.set_max_episode_buffers(config.ui.max_episode_buffers);
+        buffer_manager.set_hide_explicit_all(config.ui.hide_explicit);
+        buffer_manager.set_languages_all(&config.ui.languages);
         let mut status_bar = StatusBar::new();
         status_bar.set_theme(theme.clone());
+        for error in status_bar.configure(&config.ui.statusbar) {
+            eprintln!("[statusbar] {error}");
+        }
 
-        let minibuffer = Minibuffer::new();
+        let (performance, performance_error) =
+            PerformanceSettings::from_config(&config.ui.performance);
+        if let Some(error) = performance_error {
+            eprintln!("[config] {error}");
+        }
+        let mut minibuffer = Minibuffer::new();
+        minibuffer.set_completion_debounce(performance.completion_debounce);
         let key_handler = KeyHandler::from_config(&config.keybindings);
 
         // Validate keybindings: warn on conflicts, error on unbound critical actions.
@@ -256,16 +458,29 @@ impl UIApp {
             )));
         }
 
-        let event_handler =
-            UIEventHandler::new(Duration::from_millis(ui_constants::UI_TICK_RATE_MS));
+        let whats_new_exclusions = compile_whats_new_exclusions(&config);
+        let keyword_alerts = compile_keyword_alerts(&config);
+        let listening_log =
+            Arc::new(ListeningLog::new(&storage.data_dir).with_read_only(storage.is_read_only()));
+        let queue = QueueManager::new(&storage.data_dir);
+        let listening_goals = compile_listening_goals(&config);
+
+        let event_handler = UIEventHandler::with_settings(&performance);
 
         // Create buffers with progress updates
         status_tx.send(crate::InitStatus::CreatingBuffers).ok();
         buffer_manager.create_help_buffer(key_handler.generate_help_text());
-        buffer_manager.create_podcast_list_buffer(subscription_manager.clone());
+        buffer_manager.create_podcast_list_buffer(
+            subscription_manager.clone(),
+            PodcastSortMode::parse(&config.ui.podcast_list_sort).unwrap_or_default(),
+            config.ui.inactive_after_months,
+        );
         buffer_manager.create_downloads_buffer(download_manager.clone(), storage.clone());
-        buffer_manager.create_sync_buffer(download_manager.clone(), storage.data_dir.clone());
-        buffer_manager.create_playlist_list_buffer(playlist_manager.clone());
+        // Simple mode leaves out the advanced buffers
+        if !config.ui.simple_mode {
+            buffer_manager.create_sync_buffer(download_manager.clone(), storage.data_dir.clone());
+            buffer_manager.create_playlist_list_buffer(playlist_manager.clone());
+        }
         buffer_manager.create_whats_new_buffer(
             subscription_manager.clone(),
             download_manager.clone(),
@@ -296,15 +511,46 @@ impl UIApp {
             minibuffer,
             key_handler,
             event_handler,
+            performance,
             app_event_tx,
             should_quit: false,
+            show_glyph_legend: false,
+            share_popup: None,
             audio_command_tx: None,
+            audio_diagnostics: None,
+            config_issues: Vec::new(),
+            cast_target: None,
             last_render: Instant::now(),
             frame_count: 0,
             pending_deletion: None,
             pending_playlist_deletion: None,
             pending_bulk_deletion: false,
             pending_cleanup_hours: None,
+            pending_archive: None,
+            pending_settings_import: None,
+            pending_episode_edit: None,
+            pending_notes: None,
+            key_recorder: None,
+            crash_context: None,
+            background_tasks: BackgroundTasks::new(),
+            last_input: Instant::now(),
+            prefetch_after: None,
+            auto_play: None,
+            offline: None,
+            unconfirmed_offline: Vec::new(),
+            search_index: None,
+            opml_import: None,
+            failed_import_opml: None,
+            play_queue: None,
+            power_source: PowerSource::Unknown,
+            io_pause_override: None,
+            focus_until: None,
+            whats_new_exclusions,
+            keyword_alerts,
+            alerts: Vec::new(),
+            listening_log,
+            queue,
+            listening_goals,
         })
     }
 
@@ -316,6 +562,40 @@ impl UIApp {
         self.audio_command_tx = Some(tx);
     }
 
+    /// Keep the problems found in the config file for `:config-report`;
+    /// `run` opens the report when there are any
+    pub fn set_config_issues(&mut self, issues: Vec<crate::config::ConfigIssue>) {
+        self.config_issues = issues;
+    }
+
+    /// Open the config report buffer
+    fn open_config_report(&mut self) {
+        if self.config_issues.is_empty() {
+            self.show_message("No problems found in the config file".to_string());
+            return;
+        }
+        let buffer_id = self
+            .buffer_manager
+            .create_config_report_buffer(self.config_issues.clone());
+        let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+        self.show_error(format!(
+            "{} problem(s) in the config file; the rest of it applies (:config-report)",
+            self.config_issues.len()
+        ));
+    }
+
+    /// Keep the startup audio probe results for `:audio-diagnostics`
+    pub fn set_audio_diagnostics(&mut self, diagnostics: crate::audio::probe::AudioDiagnostics) {
+        self.audio_diagnostics = Some(diagnostics);
+    }
+
+    /// Share recent actions and open buffers with the crash handler
+    pub fn set_crash_context(&mut self, context: CrashContext) {
+        self.crash_context = Some(context);
+    }
+
     /// Replace the app event sender after construction (used when wiring AudioManager).
     pub fn set_app_event_tx(&mut self, tx: mpsc::UnboundedSender<AppEvent>) {
         self.app_event_tx = tx;
@@ -343,6 +623,16 @@ impl UIApp {
             event_handler.run(event_tx).await;
         });
 
+        self.spawn_power_monitor();
+
+        if self.config.updates.check_on_startup && self.config.updates.network_checks {
+            self.trigger_async_release_check(false);
+        }
+
+        if self.config.goals.reminders && !self.listening_goals.is_empty() {
+            self.trigger_async_goal_evaluation(true);
+        }
+
         // Initialize UI state only if buffers weren't already loaded
         if self.buffer_manager.get_buffer_ids().is_empty() {
             self.initialize().await?;
@@ -359,14 +649,44 @@ impl UIApp {
 
             // Defer download cleanup to background — don't block the first render
             let dm = self.download_manager.clone();
+            let subscription_manager = self.subscription_manager.clone();
             let cleanup_days = self.config.downloads.cleanup_after_days;
+            let restore_days = self.config.storage.podcast_restore_days;
             let app_event_tx = self.app_event_tx.clone();
             tokio::spawn(async move {
+                // Permanently delete podcasts whose restore window has expired
+                if let Err(e) = subscription_manager
+                    .purge_expired_removed(restore_days)
+                    .await
+                {
+                    eprintln!("Warning: failed to purge removed podcasts: {}", e);
+                }
                 if let Err(e) = dm.cleanup_stuck_downloads().await {
                     let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
                         error: format!("Stuck download cleanup failed: {e}"),
                     });
                 }
+                match dm.resolve_filename_collisions().await {
+                    Ok(report) if report.reset > 0 || !report.ambiguous.is_empty() => {
+                        let _ = app_event_tx.send(AppEvent::FilenameCollisionsResolved {
+                            reset: report.reset,
+                            ambiguous: report.ambiguous.len(),
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
+                            error: format!("Filename collision check failed: {e}"),
+                        });
+                    }
+                }
+                // Feeds often omit durations; read them from the downloaded files
+                if let Ok(filled) = dm.backfill_durations().await {
+                    let _ = app_event_tx.send(AppEvent::DurationsBackfilled {
+                        filled,
+                        manual: false,
+                    });
+                }
                 if let Some(days) = cleanup_days {
                     if days > 0 {
                         match dm.cleanup_old_downloads(days).await {
@@ -385,13 +705,33 @@ impl UIApp {
                         }
                     }
                 }
+                match dm.apply_retention_policies().await {
+                    Ok(0) => {}
+                    Ok(deleted_count) => {
+                        let _ = app_event_tx
+                            .send(AppEvent::RetentionCleanupCompleted { deleted_count });
+                    }
+                    Err(e) => {
+                        let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
+                            error: e.to_string(),
+                        });
+                    }
+                }
             });
         }
 
+        // The queue left from the last session
+        self.load_queue().await;
+
+        if !self.config_issues.is_empty() {
+            self.open_config_report();
+        }
+
         // Wire the AudioManager status receiver into the NowPlaying buffer so it
         // receives live playback state updates (~4 Hz from the audio thread).
         if let Some(rx) = playback_status_rx.as_ref() {
             self.buffer_manager.set_now_playing_status_rx(rx.clone());
+            self.status_bar.set_playback_status_rx(rx.clone());
         }
 
         // Perform initial render to display UI immediately (before event loop)
@@ -443,7 +783,7 @@ impl UIApp {
                     // Status updated; fall through to render below.
                 }
                 // Render timeout
-                _ = tokio::time::sleep(Duration::from_millis(16)) => {
+                _ = tokio::time::sleep(self.performance.frame_interval) => {
                     // Continue to rendering
                 }
             }
@@ -453,6 +793,8 @@ impl UIApp {
                 break Ok(());
             }
 
+            self.run_pending_buffer_refreshes();
+
             // Render the UI
             match terminal.draw(|f| self.render(f)) {
                 Ok(_) => {
@@ -463,23 +805,167 @@ impl UIApp {
             }
         };
 
+        let shutdown_note = self
+            .shutdown(&mut terminal, playback_status_rx.as_ref())
+            .await;
+
         // Cleanup terminal
         disable_raw_mode().map_err(UIError::Terminal)?;
         execute!(terminal.backend_mut(), LeaveAlternateScreen).map_err(UIError::Terminal)?;
         terminal.show_cursor().map_err(UIError::Terminal)?;
+        if let Some(note) = shutdown_note {
+            eprintln!("{}", note);
+        }
 
         result
     }
 
+    /// Wind down before the terminal is restored: save the playback position,
+    /// stop the audio thread, cancel refreshes and give writes and downloads
+    /// `SHUTDOWN_TIMEOUT` to finish. Returns a note for the shell when
+    /// something had to be cut off.
+    async fn shutdown<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        playback_status_rx: Option<&tokio::sync::watch::Receiver<PlaybackStatus>>,
+    ) -> Option<String> {
+        self.show_message("Shutting down…".to_string());
+        let _ = terminal.draw(|f| self.render(f));
+
+        if let Some(rx) = playback_status_rx {
+            let status = rx.borrow().clone();
+            self.save_playback_position(&status).await;
+        }
+        // Dropping the last command sender ends the audio thread
+        if let Some(tx) = self.audio_command_tx.take() {
+            let _ = tx.send(AudioCommand::Stop);
+        }
+        if let Some(recorder) = self.key_recorder.take() {
+            let _ = recorder.finish();
+        }
+
+        let report = self
+            .background_tasks
+            .shutdown(ui_constants::SHUTDOWN_TIMEOUT)
+            .await;
+        for (podcast_id, episode_id) in &report.interrupted_downloads {
+            let _ = self
+                .download_manager
+                .abandon_download(podcast_id, episode_id)
+                .await;
+        }
+
+        let interrupted = report.interrupted_downloads.len() + report.interrupted_writes;
+        (interrupted > 0).then(|| {
+            format!(
+                "Stopped {} background task(s) that did not finish within {} s; \
+                 {} unfinished download(s) will need to be started again",
+                interrupted,
+                ui_constants::SHUTDOWN_TIMEOUT.as_secs(),
+                report.interrupted_downloads.len()
+            )
+        })
+    }
+
+    /// Remember how far into the current episode playback got
+    async fn save_playback_position(&self, status: &PlaybackStatus) {
+        if status.state == PlaybackState::Stopped {
+            return;
+        }
+        let (Some(podcast_id), Some(episode_id), Some(position)) =
+            (&status.podcast_id, &status.episode_id, status.position)
+        else {
+            return;
+        };
+        if let Ok(mut episode) = self._storage.load_episode(podcast_id, episode_id).await {
+            episode.update_position(position.as_secs() as u32);
+            let _ = self._storage.save_episode(podcast_id, &episode).await;
+        }
+    }
+
+    /// Save the position of the episode the audio thread has loaded, so
+    /// playing it again resumes there. With `only_if_playing`, a paused
+    /// episode (already saved when it was paused) is left alone.
+    async fn remember_playback_position(&mut self, only_if_playing: bool) {
+        let Some(status) = self
+            .buffer_manager
+            .get_now_playing_buffer_mut()
+            .map(|buffer| buffer.current_status())
+        else {
+            return;
+        };
+        if only_if_playing && status.state != PlaybackState::Playing {
+            return;
+        }
+        self.save_playback_position(&status).await;
+        if status.state != PlaybackState::Stopped {
+            if let Some(podcast_id) = status.podcast_id {
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+            }
+        }
+    }
+
+    /// The command that starts an episode where it was left off (with
+    /// `audio.remember_position`). Whatever is playing now has its position
+    /// saved first, so it resumes in turn, and the podcast's playback speed
+    /// is sent ahead of it when it differs from the current one.
+    async fn play_command(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        path: std::path::PathBuf,
+    ) -> AudioCommand {
+        self.remember_playback_position(false).await;
+        let current_speed = self
+            .buffer_manager
+            .get_now_playing_buffer_mut()
+            .map_or(1.0, |buffer| buffer.current_status().speed);
+        let speed = self
+            ._storage
+            .load_podcast(&podcast_id)
+            .await
+            .map(|podcast| podcast.settings.speed())
+            .unwrap_or(1.0);
+        if speed != current_speed {
+            if let Some(ref tx) = self.audio_command_tx {
+                let _ = tx.send(AudioCommand::SetSpeed(speed));
+            }
+        }
+        let start_at = self
+            ._storage
+            .load_episode(&podcast_id, &episode_id)
+            .await
+            .ok()
+            .and_then(|episode| episode.resume_position())
+            .filter(|_| self.config.audio.remember_position)
+            .map(|position| Duration::from_secs(position as u64));
+        AudioCommand::Play {
+            path,
+            episode_id,
+            podcast_id,
+            start_at,
+        }
+    }
+
     /// Initialize the UI application
     async fn initialize(&mut self) -> UIResult<()> {
+        // Startup upkeep writes; read-only it's left to the lock's holder
+        let read_only = self._storage.is_read_only();
+
         // Clean up any stuck downloads on startup
-        if let Err(e) = self.download_manager.cleanup_stuck_downloads().await {
-            self.show_error(format!("Could not clean up stuck downloads: {}", e));
+        if !read_only {
+            if let Err(e) = self.download_manager.cleanup_stuck_downloads().await {
+                self.show_error(format!("Could not clean up stuck downloads: {}", e));
+            }
         }
 
         // Auto-cleanup old downloads on startup if configured
-        if let Some(days) = self.config.downloads.cleanup_after_days {
+        if let Some(days) = self
+            .config
+            .downloads
+            .cleanup_after_days
+            .filter(|_| !read_only)
+        {
             if days > 0 {
                 match self.download_manager.cleanup_old_downloads(days).await {
                     Ok(0) => {} // Nothing to clean, stay silent
@@ -495,27 +981,45 @@ impl UIApp {
                 }
             }
         }
+        if !read_only {
+            match self.download_manager.apply_retention_policies().await {
+                Ok(0) => {}
+                Ok(count) => self.show_message(format!(
+                    "Auto-cleanup: deleted {} download(s) under the retention rules",
+                    count
+                )),
+                Err(e) => self.show_error(format!("Could not complete auto-cleanup: {}", e)),
+            }
+        }
 
         // Create initial buffers
         self.buffer_manager
             .create_help_buffer(self.key_handler.generate_help_text());
-        self.buffer_manager
-            .create_podcast_list_buffer(self.subscription_manager.clone());
+        self.buffer_manager.create_podcast_list_buffer(
+            self.subscription_manager.clone(),
+            PodcastSortMode::parse(&self.config.ui.podcast_list_sort).unwrap_or_default(),
+            self.config.ui.inactive_after_months,
+        );
         self.buffer_manager.create_downloads_buffer(
             self.download_manager.clone(),
             self.download_manager.storage().clone(),
         );
-        self.buffer_manager.create_sync_buffer(
-            self.download_manager.clone(),
-            self._storage.data_dir.clone(),
-        );
+        // Simple mode leaves out the advanced buffers
+        if !self.config.ui.simple_mode {
+            self.buffer_manager.create_sync_buffer(
+                self.download_manager.clone(),
+                self._storage.data_dir.clone(),
+            );
+        }
         self.buffer_manager.create_whats_new_buffer(
             self.subscription_manager.clone(),
             self.download_manager.clone(),
             self.config.ui.whats_new_episode_limit,
         );
-        self.buffer_manager
-            .create_playlist_list_buffer(self.playlist_manager.clone());
+        if !self.config.ui.simple_mode {
+            self.buffer_manager
+                .create_playlist_list_buffer(self.playlist_manager.clone());
+        }
         self.buffer_manager.create_now_playing_buffer();
 
         // Set initial buffer
@@ -527,13 +1031,23 @@ impl UIApp {
         self.update_status_bar();
 
         // Show welcome message
-        self.show_message("Welcome to Podcast TUI! Press F1 or ? for help.".to_string());
+        if read_only {
+            self.status_bar
+                .set_indicator(READ_ONLY_INDICATOR.to_string());
+            self.show_message(
+                "Read-only mode: browse freely, but nothing is saved or downloaded".to_string(),
+            );
+        } else {
+            self.show_message("Welcome to Podcast TUI! Press F1 or ? for help.".to_string());
+        }
 
         // Trigger background loading of buffer data (non-blocking)
         self.trigger_background_refresh(crate::ui::events::BufferRefreshType::PodcastList);
         self.trigger_background_refresh(crate::ui::events::BufferRefreshType::Downloads);
         self.trigger_background_refresh(crate::ui::events::BufferRefreshType::WhatsNew);
-        self.trigger_async_refresh_today();
+        if !read_only {
+            self.trigger_async_refresh_today();
+        }
 
         Ok(())
     }
@@ -542,14 +1056,14 @@ impl UIApp {
     async fn handle_event(&mut self, event: UIEvent) -> UIResult<bool> {
         match event {
             UIEvent::Key(key_event) => {
-                // Check if minibuffer is in input mode and handle input
-                if self.minibuffer.is_input_mode() {
-                    return self.handle_minibuffer_key(key_event).await;
+                self.last_input = Instant::now();
+                if self.key_recorder.is_none() {
+                    return self.handle_key_event(key_event).await.0;
                 }
-
-                // Handle key event through keybinding system
-                let action = self.key_handler.handle_key(key_event);
-                self.handle_action(action).await
+                let before = self.recorded_buffer_state();
+                let (result, action) = self.handle_key_event(key_event).await;
+                self.record_key(key_event, action, before);
+                result
             }
             UIEvent::Mouse(_) => {
                 // Mouse events not implemented for MVP
@@ -570,6 +1084,176 @@ impl UIApp {
         }
     }
 
+    /// Handle a key press, returning the result and what the key resolved to
+    /// (for `:record-keys`)
+    async fn handle_key_event(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> (UIResult<bool>, String) {
+        // Check if minibuffer is in input mode and handle input
+        if self.minibuffer.is_input_mode() {
+            let result = self.handle_minibuffer_key(key_event).await;
+            return (result, "minibuffer input".to_string());
+        }
+
+        // Any key dismisses the glyph legend popup
+        if self.show_glyph_legend {
+            self.show_glyph_legend = false;
+            return (Ok(true), "dismiss legend".to_string());
+        }
+        if self.share_popup.take().is_some() {
+            return (Ok(true), "dismiss QR code".to_string());
+        }
+
+        // Handle key event through keybinding system
+        let action = self.key_handler.handle_key(key_event);
+        let resolved = format!("{:?}", action);
+        self.note_for_crash_report(&resolved);
+        if self.config.ui.simple_mode && !action.allowed_in_simple_mode() {
+            self.show_message("Not available in simple mode".to_string());
+            return (Ok(true), format!("{} (simple mode)", resolved));
+        }
+        (self.handle_action(action).await, resolved)
+    }
+
+    /// Add an action to the crash report's recent actions
+    fn note_for_crash_report(&self, action: &str) {
+        if let Some(context) = &self.crash_context {
+            context.record_action(
+                action,
+                self.buffer_manager.get_buffer_ids(),
+                self.buffer_manager.current_buffer_id(),
+            );
+        }
+    }
+
+    /// The active buffer, as the user saw it at the last render
+    fn recorded_buffer_state(&mut self) -> Option<RecordedBufferState> {
+        let buffer = self.buffer_manager.current_buffer_mut()?;
+        Some(RecordedBufferState {
+            buffer_id: buffer.id(),
+            kind: buffer.info().kind,
+            position: buffer
+                .scroll_position()
+                .map(|p| format!("{}/{}", p.current, p.total)),
+        })
+    }
+
+    /// Append a handled key to the running `:record-keys` recording
+    fn record_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        action: String,
+        before: Option<RecordedBufferState>,
+    ) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+        let chord = KeyChord::from(key_event);
+        // Typed text stays private; only editing keys are spelled out
+        let key = match (action.as_str(), key_event.code) {
+            ("minibuffer input", KeyCode::Char(_))
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                "<text>".to_string()
+            }
+            _ => key_to_notation(&chord),
+        };
+        let record = KeyRecord {
+            key,
+            action,
+            before,
+            after: self.recorded_buffer_state(),
+            message: self.minibuffer.text_content(),
+        };
+        if let Some(recorder) = self.key_recorder.as_mut() {
+            if let Err(e) = recorder.record(&record) {
+                self.key_recorder = None;
+                self.show_error(format!("Key recording stopped: {}", e));
+            }
+        }
+    }
+
+    /// Show the current podcast's feed URL as a QR code, or with `filtered`
+    /// an OPML snippet of the podcasts the podcast list shows
+    fn share_podcasts(&mut self, filtered: bool) {
+        let (title, caption, data) = if filtered {
+            let Some(list) = self.buffer_manager.get_podcast_list_buffer_mut() else {
+                self.show_error("Podcast list not available".to_string());
+                return;
+            };
+            let podcasts: Vec<_> = list.visible_podcasts().cloned().collect();
+            if podcasts.is_empty() {
+                self.show_error("No podcasts to share".to_string());
+                return;
+            }
+            match crate::podcast::opml::OpmlExporter::new().snippet(&podcasts) {
+                Ok(opml) => (
+                    format!("Share {} podcast(s)", podcasts.len()),
+                    "Scan to import as OPML".to_string(),
+                    opml,
+                ),
+                Err(e) => {
+                    self.show_error(format!("Could not build OPML: {}", e));
+                    return;
+                }
+            }
+        } else {
+            let Some(podcast_id) = self.podcast_id_for_current_buffer() else {
+                self.show_error(
+                    "Select a podcast in the podcast or episode list first".to_string(),
+                );
+                return;
+            };
+            let Some(podcast) = self
+                .buffer_manager
+                .get_podcast_list_buffer_mut()
+                .and_then(|list| list.podcasts().iter().find(|p| p.id == podcast_id))
+            else {
+                self.show_error("Podcast not found".to_string());
+                return;
+            };
+            (
+                format!("Share {}", podcast.title),
+                podcast.url.clone(),
+                podcast.url.clone(),
+            )
+        };
+
+        match QrPopup::new(title, caption, &data) {
+            Ok(popup) => self.share_popup = Some(popup),
+            Err(_) if filtered => self.show_error(
+                "Too many podcasts for one QR code; narrow the podcast list filter".to_string(),
+            ),
+            Err(e) => self.show_error(format!("Could not make a QR code: {}", e)),
+        }
+    }
+
+    /// Start a `:record-keys` recording, or stop the running one
+    fn toggle_key_recording(&mut self) {
+        match self.key_recorder.take() {
+            Some(recorder) => {
+                let keys = recorder.keys();
+                match recorder.finish() {
+                    Ok(path) => {
+                        self.show_message(format!("Recorded {} key(s) to {}", keys, path.display()))
+                    }
+                    Err(e) => self.show_error(format!("Could not finish key recording: {}", e)),
+                }
+            }
+            None => match KeyRecorder::start(&self._storage.data_dir) {
+                Ok(recorder) => {
+                    self.show_message(format!(
+                        "Recording keys to {} (:record-keys again to stop)",
+                        recorder.path().display()
+                    ));
+                    self.key_recorder = Some(recorder);
+                }
+                Err(e) => self.show_error(format!("Could not start key recording: {}", e)),
+            },
+        }
+    }
+
     /// Handle a UI action
     async fn handle_action(&mut self, action: UIAction) -> UIResult<bool> {
         match action {
@@ -578,6 +1262,10 @@ impl UIApp {
                 self.should_quit = true;
                 Ok(false)
             }
+            UIAction::ShowGlyphLegend => {
+                self.show_glyph_legend = true;
+                Ok(true)
+            }
             UIAction::ShowHelp => {
                 // Try to find existing help buffer by name, or create a new one
                 let mut help_id = self
@@ -737,9 +1425,20 @@ impl UIApp {
                         // Store the podcast ID for deletion confirmation
                         self.pending_deletion = Some(podcast_id);
 
-                        // Show confirmation prompt
+                        // Ask what to keep; without a restore window nothing can be restored
+                        let prompt = if self.config.storage.podcast_restore_days > 0 {
+                            format!(
+                                "Unsubscribe from '{}': keep (a)ll, keep (h)istory, keep (d)ownloads, (p)urge everything, or (n)o? ",
+                                podcast_title
+                            )
+                        } else {
+                            format!(
+                                "Delete podcast '{}': keep (d)ownloads, (p)urge everything, or (n)o? ",
+                                podcast_title
+                            )
+                        };
                         self.minibuffer.set_content(MinibufferContent::Input {
-                            prompt: format!("Delete podcast '{}' (y/n)? ", podcast_title),
+                            prompt,
                             input: String::new(),
                         });
                     } else {
@@ -850,11 +1549,8 @@ impl UIApp {
                                 playlist_name,
                                 detail_id.clone(),
                             );
-                        } else if let Some(detail_buffer) = self
-                            .buffer_manager
-                            .get_playlist_detail_buffer_mut_by_id(&detail_id)
-                        {
-                            detail_buffer.set_playlist(playlist);
+                        } else {
+                            self.show_playlist_in_detail(&detail_id, playlist).await;
                         }
                         let _ = self.buffer_manager.switch_to_buffer(&detail_id);
                         self.update_status_bar();
@@ -875,58 +1571,56 @@ impl UIApp {
                 if let Some(current_id) = self.buffer_manager.current_buffer_id() {
                     if !self.add_to_playlist_supported_in_buffer(&current_id) {
                         self.show_message(
-                            "Add-to-playlist is available from episode lists, episode detail, and What's New".to_string(),
+                            "Add-to-playlist is available from episode lists, episode detail, What's New and search results".to_string(),
                         );
                         return Ok(true);
                     }
 
-                    if let Some((podcast_id, episode_id)) =
-                        self.resolve_add_to_playlist_selection(&current_id)
-                    {
-                        match self.playlist_manager.list_playlists().await {
-                            Ok(playlists) => {
-                                let options: Vec<_> = playlists
-                                    .into_iter()
-                                    .filter_map(|playlist| {
-                                        if matches!(
-                                            playlist.playlist_type,
-                                            crate::playlist::PlaylistType::User
-                                        ) {
-                                            Some((
-                                                playlist.id,
-                                                playlist.name,
-                                                playlist.episodes.len(),
-                                            ))
-                                        } else {
-                                            None
-                                        }
-                                    })
-                                    .collect();
-                                if options.is_empty() {
-                                    self.show_error(
-                                        "No user playlists available; create one first".to_string(),
-                                    );
-                                } else {
-                                    let _ = self
-                                        .buffer_manager
-                                        .remove_buffer(&"playlist-picker".to_string());
-                                    self.buffer_manager.create_playlist_picker_buffer(
-                                        options, podcast_id, episode_id,
-                                    );
-                                    let _ = self
-                                        .buffer_manager
-                                        .switch_to_buffer(&"playlist-picker".to_string());
-                                    self.update_status_bar();
-                                }
-                            }
-                            Err(e) => self.show_error(format!("Could not list playlists: {}", e)),
-                        }
+                    if let Some(target) = self.resolve_selected_episode(&current_id) {
+                        self.open_playlist_picker(vec![target]).await;
                     } else {
                         self.show_message("No episode selected".to_string());
                     }
                 }
                 Ok(true)
             }
+            UIAction::EnqueueEpisode => {
+                let Some(current_id) = self.buffer_manager.current_buffer_id() else {
+                    return Ok(true);
+                };
+                if !self.add_to_playlist_supported_in_buffer(&current_id) {
+                    self.show_message(
+                        "Queueing is available from episode lists, episode detail, What's New and search results"
+                            .to_string(),
+                    );
+                    return Ok(true);
+                }
+                match self.resolve_selected_episode(&current_id) {
+                    Some((podcast_id, episode_id)) => {
+                        self.enqueue_episode(podcast_id, episode_id).await
+                    }
+                    None => self.show_message("No episode selected".to_string()),
+                }
+                Ok(true)
+            }
+            UIAction::AddAllToPlaylist => {
+                let Some(current_id) = self.buffer_manager.current_buffer_id() else {
+                    return Ok(true);
+                };
+                let Some(targets) = self.resolve_shown_episodes(&current_id) else {
+                    self.show_message(
+                        "Add-all-to-playlist is available from episode lists, What's New and search results"
+                            .to_string(),
+                    );
+                    return Ok(true);
+                };
+                if targets.is_empty() {
+                    self.show_message("No episodes shown".to_string());
+                } else {
+                    self.open_playlist_picker(targets).await;
+                }
+                Ok(true)
+            }
             UIAction::RefreshAutoPlaylists => {
                 self.trigger_async_refresh_today();
                 Ok(true)
@@ -955,6 +1649,14 @@ impl UIApp {
                 self.trigger_async_add_to_playlist(playlist_id, podcast_id, episode_id);
                 Ok(true)
             }
+            UIAction::TriggerAddEpisodesToPlaylist {
+                playlist_id,
+                episodes,
+            } => {
+                self.show_message(format!("Adding {} episodes to playlist...", episodes.len()));
+                self.trigger_async_add_episodes_to_playlist(playlist_id, episodes);
+                Ok(true)
+            }
             UIAction::TriggerRemoveFromPlaylist {
                 playlist_id,
                 episode_id,
@@ -992,7 +1694,7 @@ impl UIApp {
                         ));
 
                         // Trigger async hard refresh
-                        self.trigger_async_hard_refresh_single(podcast_id);
+                        self.trigger_async_hard_refresh_single(podcast_id, HardRefreshScope::Full);
                     } else {
                         self.show_message("No podcast selected for hard refresh".to_string());
                     }
@@ -1006,82 +1708,49 @@ impl UIApp {
                 self.trigger_async_refresh_all();
                 Ok(true)
             }
-            UIAction::DownloadEpisode => {
+            UIAction::RefreshFiltered => {
+                self.refresh_filtered_podcasts();
+                Ok(true)
+            }
+            UIAction::DownloadEpisode
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("sync") =>
+            {
                 // 'D' in sync buffer → dry-run preview with active target (or prompt if none)
-                if self.buffer_manager.current_buffer_id().as_deref() == Some("sync") {
-                    if let Some(active_path) = self
-                        .buffer_manager
-                        .get_sync_buffer_mut()
-                        .and_then(|b| b.active_target().cloned())
-                    {
-                        let path_str = active_path.to_string_lossy().to_string();
-                        self.trigger_async_device_sync(path_str, false, true, false);
-                    } else {
-                        let default_path = self.get_default_sync_path();
-                        self.minibuffer.set_content(MinibufferContent::Input {
-                            prompt: format!("Dry run sync to (default: {}): ", default_path),
-                            input: String::new(),
-                        });
-                    }
-                    return Ok(true);
-                }
-                // Pass to the current buffer to handle
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    // Handle the result action immediately (non-recursively)
-                    match result_action {
-                        UIAction::TriggerDownload {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        } => {
-                            self.show_message(format!("Starting download: {}", episode_title));
-                            self.trigger_async_download(podcast_id, episode_id);
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        _ => {}
-                    }
-                    Ok(true)
+                if let Some(active_path) = self
+                    .buffer_manager
+                    .get_sync_buffer_mut()
+                    .and_then(|b| b.active_target().cloned())
+                {
+                    let path_str = active_path.to_string_lossy().to_string();
+                    self.trigger_async_device_sync(path_str, false, true, false);
                 } else {
-                    self.show_message("No active buffer to handle download".to_string());
-                    Ok(true)
+                    let default_path = self.get_default_sync_path();
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: format!("Dry run sync to (default: {}): ", default_path),
+                        input: String::new(),
+                    });
                 }
+                Ok(true)
             }
-            UIAction::DeleteDownloadedEpisode => {
-                // Pass to the current buffer to handle
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    // Handle the result action immediately (non-recursively)
-                    match result_action {
-                        UIAction::TriggerDeleteDownload {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        } => {
-                            self.show_message(format!("Deleting download: {}", episode_title));
-                            self.trigger_async_delete_download(podcast_id, episode_id);
-                        }
-                        UIAction::TriggerRemoveFromPlaylist {
-                            playlist_id,
-                            episode_id,
-                        } => {
-                            self.trigger_async_remove_from_playlist(playlist_id, episode_id);
-                        }
-                        UIAction::ShowError(msg) => {
-                            self.show_error(msg);
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        _ => {}
+            // Download, play and refresh go to the buffer's capability; buffers
+            // without it get the action through `handle_action`
+            action if capabilities::is_capability_action(&action) => {
+                let Some(buffer) = self.buffer_manager.current_buffer_mut() else {
+                    self.show_message("No active buffer".to_string());
+                    return Ok(true);
+                };
+                let result = match capabilities::dispatch(buffer.as_mut(), &action) {
+                    Some(result) => result,
+                    None if action == UIAction::Refresh => {
+                        UIAction::ShowMessage("Refresh not supported for this buffer".to_string())
                     }
-                    Ok(true)
-                } else {
-                    self.show_message("No active buffer to handle delete".to_string());
-                    Ok(true)
+                    None => buffer.handle_action(action),
+                };
+                if result == UIAction::RefreshAutoPlaylists {
+                    self.show_message("Refreshing playlists...".to_string());
                 }
+                self.apply_buffer_result(result).await;
+                Ok(true)
             }
             UIAction::TriggerDownload {
                 podcast_id,
@@ -1101,50 +1770,6 @@ impl UIApp {
                 self.trigger_async_delete_download(podcast_id, episode_id);
                 Ok(true)
             }
-            UIAction::MarkPlayed => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    match result_action {
-                        UIAction::TriggerMarkPlayed {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        } => {
-                            self.trigger_async_mark_played(podcast_id, episode_id, episode_title);
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        _ => {}
-                    }
-                    Ok(true)
-                } else {
-                    self.show_message("No active buffer".to_string());
-                    Ok(true)
-                }
-            }
-            UIAction::MarkUnplayed => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    match result_action {
-                        UIAction::TriggerMarkUnplayed {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        } => {
-                            self.trigger_async_mark_unplayed(podcast_id, episode_id, episode_title);
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        _ => {}
-                    }
-                    Ok(true)
-                } else {
-                    self.show_message("No active buffer".to_string());
-                    Ok(true)
-                }
-            }
             UIAction::TriggerMarkPlayed {
                 podcast_id,
                 episode_id,
@@ -1161,40 +1786,6 @@ impl UIApp {
                 self.trigger_async_mark_unplayed(podcast_id, episode_id, episode_title);
                 Ok(true)
             }
-            UIAction::ToggleFavorite => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    match result_action {
-                        UIAction::TriggerToggleFavorite {
-                            podcast_id,
-                            episode_id,
-                            ref episode_title,
-                            favorited,
-                        } => {
-                            let msg = if favorited {
-                                format!("★ Favorited: {}", episode_title)
-                            } else {
-                                format!("Unfavorited: {}", episode_title)
-                            };
-                            self.show_message(msg);
-                            self.trigger_async_toggle_favorite(
-                                podcast_id,
-                                episode_id,
-                                episode_title.clone(),
-                                favorited,
-                            );
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        _ => {}
-                    }
-                    Ok(true)
-                } else {
-                    self.show_message("No active buffer".to_string());
-                    Ok(true)
-                }
-            }
             UIAction::TriggerToggleFavorite {
                 podcast_id,
                 episode_id,
@@ -1213,6 +1804,7 @@ impl UIApp {
                 if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
                     current_buffer.handle_action(action);
                 }
+                self.persist_podcast_sort();
                 Ok(true)
             }
             UIAction::TriggerRefreshDownloads => {
@@ -1230,6 +1822,16 @@ impl UIApp {
                 self.pending_bulk_deletion = true;
                 Ok(true)
             }
+            UIAction::ImportOpml
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("opml-preview") =>
+            {
+                // S-A in the preview imports the checked feeds
+                if let Some(buffer) = self.buffer_manager.get_opml_preview_buffer_mut() {
+                    let result = buffer.handle_action(UIAction::ImportOpml);
+                    self.apply_buffer_result(result).await;
+                }
+                Ok(true)
+            }
             UIAction::ImportOpml => {
                 // Show prompt for file path or URL
                 self.minibuffer.set_content(MinibufferContent::Input {
@@ -1248,6 +1850,15 @@ impl UIApp {
                 });
                 Ok(true)
             }
+            UIAction::CancelImport => {
+                // C-g cancels a pending auto-play first
+                if self.cancel_auto_play() {
+                    self.show_message("Auto-play cancelled".to_string());
+                } else {
+                    self.cancel_opml_import();
+                }
+                Ok(true)
+            }
             UIAction::TriggerOpmlImport { source } => {
                 self.show_message(format!("Importing OPML from: {}...", source));
                 self.trigger_async_opml_import(source);
@@ -1310,107 +1921,19 @@ impl UIApp {
                 }
                 Ok(true)
             }
-            UIAction::Refresh => {
-                // Handle F5 refresh - refresh current buffer based on its type
-                let current_buffer_id = self.buffer_manager.current_buffer_id();
-                if let Some(buffer_id) = current_buffer_id {
-                    if buffer_id.starts_with("episodes-") {
-                        // If it's an episode list buffer, trigger background refresh of its episodes
-                        if let Some(episode_buffer) = self
-                            .buffer_manager
-                            .get_episode_list_buffer_mut_by_id(&buffer_id)
-                        {
-                            let podcast_id = episode_buffer.podcast_id.clone();
-                            self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
-                                podcast_id,
-                            });
-                            self.show_message("Refreshing episode list...".to_string());
-                        }
-                    } else if buffer_id == "podcast-list" {
-                        // If it's the podcast list, trigger background refresh of podcasts
-                        self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                        self.show_message("Refreshing podcast list...".to_string());
-                    } else if buffer_id == "downloads" {
-                        // If it's the downloads buffer, trigger background refresh of downloads
-                        self.trigger_background_refresh(BufferRefreshType::Downloads);
-                        self.show_message("Refreshing downloads...".to_string());
-                    } else if buffer_id == "playlist-list" {
-                        self.trigger_async_refresh_today();
-                        self.show_message("Refreshing playlists...".to_string());
-                    } else if buffer_id.starts_with("playlist-") {
-                        let result_action = if let Some(detail_buffer) = self
-                            .buffer_manager
-                            .get_playlist_detail_buffer_mut_by_id(&buffer_id)
-                        {
-                            detail_buffer.handle_action(UIAction::Refresh)
-                        } else {
-                            UIAction::ShowError("Playlist detail not available".to_string())
-                        };
-                        match result_action {
-                            UIAction::RefreshAutoPlaylists => {
-                                self.show_message("Refreshing Today playlist...".to_string());
-                                self.trigger_async_refresh_today();
-                            }
-                            UIAction::RebuildPlaylistFiles { playlist_id } => {
-                                self.show_message("Rebuilding playlist files...".to_string());
-                                self.trigger_async_rebuild_playlist(playlist_id);
-                            }
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {}
-                        }
-                    } else if buffer_id == "whats-new" {
-                        // If it's the What's New buffer, trigger background refresh of episodes
-                        self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                        self.show_message("Refreshing What's New...".to_string());
-                    } else {
-                        self.show_message("Refresh not supported for this buffer".to_string());
-                    }
-                } else {
-                    self.show_message("No active buffer to refresh".to_string());
-                }
-                Ok(true)
-            }
             UIAction::SubscribeFromDiscovery { feed_url } => {
                 self.show_message(format!("Subscribing to {}…", feed_url));
                 self.trigger_async_add_podcast(feed_url);
                 Ok(true)
             }
-            // Audio playback — pass to buffer to resolve the selected episode, then dispatch
-            // to AudioManager.
-            UIAction::PlayEpisode { .. } => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result = current_buffer.handle_action(action);
-                    match result {
-                        UIAction::PlayEpisode {
-                            podcast_id,
-                            episode_id,
-                            path,
-                        } => {
-                            if let Some(ref tx) = self.audio_command_tx {
-                                let _ = tx.send(AudioCommand::Play {
-                                    path,
-                                    episode_id,
-                                    podcast_id,
-                                });
-                            } else {
-                                self.show_error(
-                                    crate::constants::audio::UNAVAILABLE_ERROR.to_string(),
-                                );
-                            }
-                        }
-                        UIAction::ShowError(msg) => self.show_error(msg),
-                        _ => {}
-                    }
-                }
-                Ok(true)
-            }
             // Simple playback controls forwarded to AudioManager
             UIAction::TogglePlayPause => {
                 // Guard: S-P in minibuffer input mode is a typed character, not a command.
                 if self.minibuffer.is_input_mode() {
                     return Ok(true);
                 }
+                // Pausing saves the position, in case the app doesn't get to
+                self.remember_playback_position(true).await;
                 if let Some(ref tx) = self.audio_command_tx {
                     let _ = tx.send(AudioCommand::TogglePlayPause);
                 } else {
@@ -1419,6 +1942,8 @@ impl UIApp {
                 Ok(true)
             }
             UIAction::StopPlayback => {
+                self.set_play_queue(None);
+                self.remember_playback_position(false).await;
                 if let Some(ref tx) = self.audio_command_tx {
                     let _ = tx.send(AudioCommand::Stop);
                 }
@@ -1452,5331 +1977,13175 @@ impl UIApp {
                 }
                 Ok(true)
             }
+            UIAction::ToggleMute => {
+                if let Some(ref tx) = self.audio_command_tx {
+                    let _ = tx.send(AudioCommand::ToggleMute);
+                }
+                Ok(true)
+            }
+            UIAction::ToggleDuck => {
+                if let Some(ref tx) = self.audio_command_tx {
+                    let _ = tx.send(AudioCommand::ToggleDuck);
+                }
+                Ok(true)
+            }
+            UIAction::SelectAudioDevice { device } => {
+                self.select_audio_device(device);
+                Ok(true)
+            }
+            UIAction::SelectCastTarget { target } => {
+                self.select_cast_target(target);
+                Ok(true)
+            }
             // Buffer-specific actions
             action => {
                 if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result_action = current_buffer.handle_action(action);
-                    // If the buffer returns an action, handle it
-                    match result_action {
-                        UIAction::SwitchBuffer(buffer_id) => {
-                            // Handle buffer switching from buffer list
-                            if self.buffer_manager.switch_to_buffer(&buffer_id).is_err() {
-                                self.show_error(format!(
-                                    "Could not switch to buffer: {}",
-                                    buffer_id
-                                ));
-                            } else {
-                                self.update_status_bar();
-                                self.show_message(format!("Switched to buffer: {}", buffer_id));
-                            }
-                        }
-                        UIAction::CloseBuffer(buffer_id) => {
-                            // Handle buffer closing from buffer list
-                            match self.buffer_manager.remove_buffer(&buffer_id) {
-                                Ok(_) => {
-                                    self.update_status_bar();
-                                    self.show_message(format!("Closed buffer: {}", buffer_id));
-                                }
-                                Err(e) => {
-                                    self.show_error(format!("Cannot close buffer: {}", e));
-                                }
-                            }
-                        }
-                        UIAction::OpenEpisodeList {
-                            podcast_name,
-                            podcast_id,
-                        } => {
-                            // Handle this specific action directly to avoid recursion
-                            let episode_buffer_id = format!(
-                                "episodes-{}",
-                                podcast_name.replace(' ', "-").to_lowercase()
-                            );
-
-                            // Check if buffer already exists
-                            if !self
-                                .buffer_manager
-                                .get_buffer_ids()
-                                .contains(&episode_buffer_id)
-                            {
-                                self.buffer_manager.create_episode_list_buffer(
-                                    podcast_name.clone(),
-                                    podcast_id.clone(),
-                                    self.subscription_manager.clone(),
-                                    self.download_manager.clone(),
-                                );
-                            }
-
-                            // Switch to the buffer
-                            let _ = self.buffer_manager.switch_to_buffer(&episode_buffer_id);
-                            self.update_status_bar();
-
-                            // Refresh any open buffer list buffers
-                            self.refresh_buffer_list_if_open();
-
-                            // Trigger async loading of episodes
-                            self.trigger_async_load_episodes(podcast_id, podcast_name.clone());
-
-                            self.show_message(format!("Loading episodes for: {}", podcast_name));
-                        }
-                        UIAction::OpenEpisodeDetail { episode } => {
-                            self.open_episode_detail_buffer(*episode);
-                        }
-                        UIAction::OpenEpisodeDetailById {
-                            podcast_id,
-                            episode_id,
-                        } => match self._storage.load_episode(&podcast_id, &episode_id).await {
-                            Ok(episode) => self.open_episode_detail_buffer(episode),
-                            Err(e) => {
-                                self.show_error(format!("Could not open episode details: {}", e))
-                            }
-                        },
-                        UIAction::OpenPlaylistDetail {
-                            playlist_id,
-                            playlist_name,
-                        } => match self.playlist_manager.get_playlist(&playlist_id).await {
-                            Ok(playlist) => {
-                                let detail_id = format!(
-                                    "playlist-{}",
-                                    playlist_name.replace(' ', "-").to_lowercase()
-                                );
-                                if !self.buffer_manager.get_buffer_ids().contains(&detail_id) {
-                                    self.buffer_manager.create_playlist_detail_buffer(
-                                        playlist_id.clone(),
-                                        playlist_name.clone(),
-                                        playlist.playlist_type.clone(),
-                                        self.playlist_manager.clone(),
-                                    );
-                                }
-                                if let Some(detail_buffer) = self
-                                    .buffer_manager
-                                    .get_playlist_detail_buffer_mut_by_id(&detail_id)
-                                {
-                                    detail_buffer.set_playlist(playlist);
-                                }
-                                let _ = self.buffer_manager.switch_to_buffer(&detail_id);
-                                self.update_status_bar();
-                            }
-                            Err(e) => self.show_error(format!("Could not open playlist: {}", e)),
-                        },
-                        UIAction::TriggerDeletePlaylist { playlist_id } => {
-                            self.trigger_async_delete_playlist(playlist_id);
-                        }
-                        UIAction::TriggerAddToPlaylist {
-                            playlist_id,
-                            podcast_id,
-                            episode_id,
-                        } => {
-                            self.trigger_async_add_to_playlist(playlist_id, podcast_id, episode_id);
-                        }
-                        UIAction::TriggerRemoveFromPlaylist {
-                            playlist_id,
-                            episode_id,
-                        } => {
-                            self.trigger_async_remove_from_playlist(playlist_id, episode_id);
-                        }
-                        UIAction::TriggerReorderPlaylist {
-                            playlist_id,
-                            from_idx,
-                            to_idx,
-                        } => {
-                            self.trigger_async_reorder_playlist(playlist_id, from_idx, to_idx);
-                        }
-                        UIAction::RefreshAutoPlaylists => {
-                            self.trigger_async_refresh_today();
-                        }
-                        UIAction::ShowMessage(msg) => {
-                            self.show_message(msg);
-                        }
-                        UIAction::ShowError(msg) => {
-                            self.show_error(msg);
-                        }
-                        UIAction::Search => {
-                            // Buffer bubbled up Search — open the minibuffer prompt
-                            self.minibuffer.set_content(MinibufferContent::Input {
-                                prompt: "Search: ".to_string(),
-                                input: String::new(),
-                            });
-                        }
-                        UIAction::PromptInput(prompt) => {
-                            // Buffer wants to prompt the user for input
-                            self.minibuffer.set_content(MinibufferContent::Input {
-                                prompt,
-                                input: String::new(),
-                            });
-                        }
-                        UIAction::TriggerDeviceSync {
-                            device_path,
-                            delete_orphans,
-                            mut dry_run,
-                        } => {
-                            // Buffer has an active target and wants to sync directly (no prompt).
-                            // If sync_preview_before_sync is set and we're NOT already coming from
-                            // the DryRunPreview mode, convert to a dry-run first.
-                            let is_from_preview = self
-                                .buffer_manager
-                                .get_sync_buffer_mut()
-                                .map(|b| b.is_in_dry_run_preview_mode())
-                                .unwrap_or(false);
-                            if !dry_run
-                                && self.config.downloads.sync_preview_before_sync
-                                && !is_from_preview
-                            {
-                                dry_run = true;
-                            }
-                            let path_str = device_path.to_string_lossy().to_string();
-                            self.trigger_async_device_sync(
-                                path_str,
-                                delete_orphans,
-                                dry_run,
-                                false,
-                            );
-                        }
-                        _ => {
-                            // Ignore other actions to avoid infinite recursion
-                        }
-                    }
+                    let result = current_buffer.handle_action(action);
+                    self.apply_buffer_result(result).await;
                 }
                 Ok(true)
             }
         }
     }
 
-    /// Handle periodic tick
-    async fn handle_tick(&mut self) -> UIResult<bool> {
-        // Update status bar with current key sequence
-        let sequence = self.key_handler.current_sequence_string();
-        if !sequence.is_empty() {
-            self.status_bar.set_key_sequence(sequence);
-        } else {
-            self.status_bar.set_key_sequence(String::new());
-        }
-
-        Ok(true)
-    }
-
-    /// Handle app events from async tasks
-    async fn handle_app_event(&mut self, event: AppEvent) -> UIResult<()> {
-        match event {
-            AppEvent::PodcastSubscribed { podcast } => {
-                // Trigger background refresh of podcast list
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.show_message(format!("Successfully subscribed to: {}", podcast.title));
-            }
-            AppEvent::PodcastSubscriptionFailed { url: _, error } => {
-                self.show_error(format!("Could not subscribe to podcast: {}", error));
-            }
-            AppEvent::PodcastRefreshed {
-                podcast_id: _,
-                new_episode_count,
-            } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                if new_episode_count > 0 {
-                    self.show_message(format!("Found {} new episode(s)", new_episode_count));
+    /// Carry out the action a buffer returned from `handle_action` or a
+    /// capability method
+    async fn apply_buffer_result(&mut self, result: UIAction) {
+        match result {
+            UIAction::SwitchBuffer(buffer_id) => {
+                // Handle buffer switching from buffer list
+                if self.buffer_manager.switch_to_buffer(&buffer_id).is_err() {
+                    self.show_error(format!("Could not switch to buffer: {}", buffer_id));
                 } else {
-                    self.show_message("No new episodes found".to_string());
+                    self.update_status_bar();
+                    self.show_message(format!("Switched to buffer: {}", buffer_id));
                 }
             }
-            AppEvent::PodcastRefreshFailed {
-                podcast_id: _,
-                error,
-            } => {
-                self.show_error(format!("Could not refresh podcast feed: {}", error));
+            UIAction::CloseBuffer(buffer_id) => {
+                // Handle buffer closing from buffer list
+                match self.buffer_manager.remove_buffer(&buffer_id) {
+                    Ok(_) => {
+                        self.update_status_bar();
+                        self.show_message(format!("Closed buffer: {}", buffer_id));
+                    }
+                    Err(e) => {
+                        self.show_error(format!("Cannot close buffer: {}", e));
+                    }
+                }
             }
-            AppEvent::AllPodcastsRefreshed { total_new_episodes } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                if total_new_episodes > 0 {
-                    self.show_message(format!(
-                        "Podcast refresh completed. Found {} new episode(s). Updating buffers...",
-                        total_new_episodes
-                    ));
+            UIAction::CloseBuffers(buffer_ids) => {
+                // Bulk close of buffers marked in the buffer list
+                let mut closed = 0;
+                let mut failed = Vec::new();
+                for buffer_id in &buffer_ids {
+                    match self.buffer_manager.remove_buffer(buffer_id) {
+                        Ok(_) => closed += 1,
+                        Err(_) => failed.push(buffer_id.clone()),
+                    }
+                }
+                // Stay in the buffer list so the user sees the result
+                let _ = self
+                    .buffer_manager
+                    .switch_to_buffer(&"*Buffer List*".to_string());
+                self.refresh_buffer_list_if_open();
+                self.update_status_bar();
+                if failed.is_empty() {
+                    self.show_message(format!("Closed {} buffer(s)", closed));
                 } else {
-                    self.show_message(
-                        "Podcast refresh completed. No new episodes found".to_string(),
-                    );
+                    self.show_error(format!(
+                        "Closed {} buffer(s); could not close: {}",
+                        closed,
+                        failed.join(", ")
+                    ));
                 }
             }
-            AppEvent::BufferDataRefreshed { buffer_type, data } => {
-                // Update buffer data without blocking the UI thread
-                self.handle_buffer_data_refresh(buffer_type, data);
-            }
-            AppEvent::PodcastDeleted {
-                podcast_id: _,
-                podcast_title,
+            UIAction::OpenEpisodeList {
+                podcast_name,
+                podcast_id,
             } => {
-                // Trigger background refresh of podcast list
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.show_message(format!("Successfully deleted: {}", podcast_title));
+                // Handle this specific action directly to avoid recursion
+                let episode_buffer_id =
+                    format!("episodes-{}", podcast_name.replace(' ', "-").to_lowercase());
+
+                // Check if buffer already exists
+                if !self
+                    .buffer_manager
+                    .get_buffer_ids()
+                    .contains(&episode_buffer_id)
+                {
+                    self.buffer_manager.create_episode_list_buffer(
+                        podcast_name.clone(),
+                        podcast_id.clone(),
+                        self.subscription_manager.clone(),
+                        self.download_manager.clone(),
+                    );
+                }
+
+                // Switch to the buffer
+                let _ = self.buffer_manager.switch_to_buffer(&episode_buffer_id);
+                self.update_status_bar();
+
+                // Refresh any open buffer list buffers
+                self.refresh_buffer_list_if_open();
+
+                // Trigger async loading of episodes
+                self.trigger_async_load_episodes(podcast_id, podcast_name.clone());
+
+                self.show_message(format!("Loading episodes for: {}", podcast_name));
             }
-            AppEvent::PodcastDownloadsDeleted {
-                podcast_id: _,
-                deleted_count,
-            } => {
-                if deleted_count > 0 {
-                    self.show_message(format!("Deleted {} downloaded episodes", deleted_count));
+            UIAction::OpenEpisodeDetail { episode } => {
+                self.open_episode_detail_buffer(*episode);
+            }
+            UIAction::OpenEpisodeDetailById {
+                podcast_id,
+                episode_id,
+            } => match self._storage.load_episode(&podcast_id, &episode_id).await {
+                Ok(episode) => self.open_episode_detail_buffer(episode),
+                Err(e) => self.show_error(format!("Could not open episode details: {}", e)),
+            },
+            UIAction::OpenPlaylistDetail {
+                playlist_id,
+                playlist_name,
+            } => match self.playlist_manager.get_playlist(&playlist_id).await {
+                Ok(playlist) => {
+                    let detail_id = format!(
+                        "playlist-{}",
+                        playlist_name.replace(' ', "-").to_lowercase()
+                    );
+                    if !self.buffer_manager.get_buffer_ids().contains(&detail_id) {
+                        self.buffer_manager.create_playlist_detail_buffer(
+                            playlist_id.clone(),
+                            playlist_name.clone(),
+                            playlist.playlist_type.clone(),
+                            self.playlist_manager.clone(),
+                        );
+                    }
+                    self.show_playlist_in_detail(&detail_id, playlist).await;
+                    let _ = self.buffer_manager.switch_to_buffer(&detail_id);
+                    self.update_status_bar();
                 }
+                Err(e) => self.show_error(format!("Could not open playlist: {}", e)),
+            },
+            UIAction::TriggerDeletePlaylist { playlist_id } => {
+                self.trigger_async_delete_playlist(playlist_id);
             }
-            AppEvent::PodcastDeletionFailed {
-                podcast_id: _,
-                error,
+            UIAction::TriggerAddToPlaylist {
+                playlist_id,
+                podcast_id,
+                episode_id,
             } => {
-                self.show_error(format!("Could not delete podcast: {}", error));
+                self.trigger_async_add_to_playlist(playlist_id, podcast_id, episode_id);
             }
-            AppEvent::EpisodesLoaded {
-                podcast_id: _,
-                podcast_name,
+            UIAction::TriggerAddEpisodesToPlaylist {
+                playlist_id,
                 episodes,
             } => {
-                // Update the episode buffer with the loaded episodes
-                if let Some(episode_buffer) = self
-                    .buffer_manager
-                    .get_episode_list_buffer_mut(&podcast_name)
-                {
-                    episode_buffer.set_episodes(episodes.clone());
-                    self.show_message(format!("Loaded {} episodes", episodes.len()));
-                } else {
-                    self.show_message(format!("Loaded {} episodes", episodes.len()));
-                }
+                self.show_message(format!("Adding {} episodes to playlist...", episodes.len()));
+                self.trigger_async_add_episodes_to_playlist(playlist_id, episodes);
             }
-            AppEvent::EpisodesLoadFailed {
-                podcast_id: _,
-                error,
+            UIAction::TriggerRemoveFromPlaylist {
+                playlist_id,
+                episode_id,
             } => {
-                self.show_error(format!("Could not load episodes: {}", error));
+                self.trigger_async_remove_from_playlist(playlist_id, episode_id);
             }
-            AppEvent::EpisodeDownloaded {
-                podcast_id,
-                episode_id: _,
+            UIAction::TriggerReorderPlaylist {
+                playlist_id,
+                from_idx,
+                to_idx,
             } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_message("Episode download completed successfully".to_string());
+                self.trigger_async_reorder_playlist(playlist_id, from_idx, to_idx);
             }
-            AppEvent::EpisodeDownloadFailed {
-                podcast_id,
-                episode_id: _,
-                error,
+            UIAction::TriggerReorderQueue { from_idx, to_idx } => {
+                let moved = self.queue.move_entry(from_idx, to_idx);
+                if moved {
+                    self.queue_changed();
+                }
+            }
+            UIAction::TriggerRemoveFromQueue { index } => {
+                let removed = self.queue.remove(index);
+                if removed.is_some() {
+                    self.queue_changed();
+                    self.show_message(format!(
+                        "Removed from the queue ({} left)",
+                        self.queue.len()
+                    ));
+                }
+            }
+            UIAction::TriggerPlayQueued { index } => {
+                if let Some(entry) = self.queue.remove(index) {
+                    self.queue_changed();
+                    if !self.play_queued(entry).await {
+                        self.show_error(
+                            "That episode isn't downloaded; it was removed from the queue"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+            UIAction::RefreshAutoPlaylists => {
+                self.trigger_async_refresh_today();
+            }
+            UIAction::SelectAudioDevice { device } => {
+                self.select_audio_device(device);
+            }
+            UIAction::SelectCastTarget { target } => {
+                self.select_cast_target(target);
+            }
+            UIAction::ShowMessage(msg) => {
+                self.show_message(msg);
+            }
+            UIAction::ShowError(msg) => {
+                self.show_error(msg);
+            }
+            UIAction::Search => {
+                // Buffer bubbled up Search — open the minibuffer prompt
+                self.minibuffer.set_content(MinibufferContent::Input {
+                    prompt: "Search: ".to_string(),
+                    input: String::new(),
+                });
+            }
+            UIAction::PromptInput(prompt) => {
+                // Buffer wants to prompt the user for input
+                self.minibuffer.set_content(MinibufferContent::Input {
+                    prompt,
+                    input: String::new(),
+                });
+            }
+            UIAction::TriggerDeviceSync {
+                device_path,
+                delete_orphans,
+                mut dry_run,
             } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_error(format!("Episode download failed: {}", error));
+                // Buffer has an active target and wants to sync directly (no prompt).
+                // If sync_preview_before_sync is set and we're NOT already coming from
+                // the DryRunPreview mode, convert to a dry-run first.
+                let is_from_preview = self
+                    .buffer_manager
+                    .get_sync_buffer_mut()
+                    .map(|b| b.is_in_dry_run_preview_mode())
+                    .unwrap_or(false);
+                if !dry_run && self.config.downloads.sync_preview_before_sync && !is_from_preview {
+                    dry_run = true;
+                }
+                let path_str = device_path.to_string_lossy().to_string();
+                self.trigger_async_device_sync(path_str, delete_orphans, dry_run, false);
             }
-            AppEvent::EpisodeDownloadDeleted {
+            UIAction::TriggerDownload {
                 podcast_id,
-                episode_id: _,
+                episode_id,
+                episode_title,
             } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_message("Episode download deleted successfully".to_string());
+                self.show_message(format!("Starting download: {}", episode_title));
+                self.trigger_async_download(podcast_id, episode_id);
             }
-            AppEvent::EpisodeDownloadDeletionFailed {
+            UIAction::TriggerDeleteDownload {
                 podcast_id,
-                episode_id: _,
-                error,
+                episode_id,
+                episode_title,
             } => {
-                // Trigger background refresh of buffers
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_error(format!("Could not delete episode download: {}", error));
+                self.show_message(format!("Deleting download: {}", episode_title));
+                self.trigger_async_delete_download(podcast_id, episode_id);
             }
-            AppEvent::EpisodeMarkedPlayed {
+            UIAction::TriggerMarkPlayed {
                 podcast_id,
-                episode_id: _,
+                episode_id,
                 episode_title,
             } => {
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_message(format!("Marked as played: {}", episode_title));
+                self.trigger_async_mark_played(podcast_id, episode_id, episode_title);
             }
-            AppEvent::EpisodeMarkPlayedFailed {
+            UIAction::TriggerMarkUnplayed {
                 podcast_id,
-                episode_id: _,
-                error,
+                episode_id,
+                episode_title,
             } => {
-                // Refresh buffers to revert optimistic UI update
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_error(format!("Could not mark episode as played: {}", error));
+                self.trigger_async_mark_unplayed(podcast_id, episode_id, episode_title);
             }
-            AppEvent::EpisodeMarkedUnplayed {
+            UIAction::TriggerToggleFavorite {
                 podcast_id,
-                episode_id: _,
+                episode_id,
                 episode_title,
+                favorited,
             } => {
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_message(format!("Marked as unplayed: {}", episode_title));
+                let msg = if favorited {
+                    format!("★ Favorited: {}", episode_title)
+                } else {
+                    format!("Unfavorited: {}", episode_title)
+                };
+                self.show_message(msg);
+                self.trigger_async_toggle_favorite(
+                    podcast_id,
+                    episode_id,
+                    episode_title,
+                    favorited,
+                );
             }
-            AppEvent::EpisodeMarkUnplayedFailed {
+            UIAction::PlayEpisode {
                 podcast_id,
-                episode_id: _,
-                error,
+                episode_id,
+                path,
             } => {
-                // Refresh buffers to revert optimistic UI update
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_error(format!("Could not mark episode as unplayed: {}", error));
+                self.set_play_queue(None);
+                let command = self.play_command(podcast_id, episode_id, path).await;
+                if let Some(ref tx) = self.audio_command_tx {
+                    let _ = tx.send(command);
+                } else {
+                    self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+                }
             }
-            AppEvent::EpisodeFavoriteToggled {
-                podcast_id: _,
-                episode_id: _,
-                episode_title: _,
-                favorited: _,
+            UIAction::PlayPlaylist {
+                playlist_id,
+                entries,
+                start,
             } => {
-                // Optimistic update already applied; nothing further needed
+                self.start_playlist_playback(playlist_id, entries, start)
+                    .await
             }
-            AppEvent::EpisodeFavoriteToggleFailed {
-                podcast_id,
-                episode_id: _,
-                error,
-            } => {
-                // Refresh buffers to revert optimistic UI update
-                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
-                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
-                self.show_error(format!("Could not save favorite: {}", error));
+            UIAction::RebuildPlaylistFiles { playlist_id } => {
+                self.show_message("Rebuilding playlist files...".to_string());
+                self.trigger_async_rebuild_playlist(playlist_id);
             }
-            AppEvent::DownloadsRefreshed => {
-                // Trigger background refresh of downloads buffer
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_message("Downloads refreshed".to_string());
+            UIAction::ConfirmOpmlImport => self.import_previewed_opml(),
+            UIAction::SavePodcastSettings {
+                podcast_id,
+                settings,
+            } => self.save_podcast_settings(podcast_id, settings),
+            UIAction::RefreshBuffer(refresh) => {
+                let message = match &refresh {
+                    BufferRefreshType::PodcastList => "Refreshing podcast list...",
+                    BufferRefreshType::Downloads => "Refreshing downloads...",
+                    BufferRefreshType::WhatsNew => "Refreshing What's New...",
+                    BufferRefreshType::Unplayed => "Refreshing unplayed episodes...",
+                    BufferRefreshType::AllEpisodeBuffers
+                    | BufferRefreshType::EpisodeBuffers { .. } => "Refreshing episode list...",
+                };
+                self.trigger_background_refresh(refresh);
+                self.show_message(message.to_string());
             }
-            AppEvent::AllDownloadsDeleted { deleted_count } => {
-                // Trigger background refresh of all episode buffers and downloads buffer
-                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_message(format!(
-                    "Successfully deleted {} downloaded episodes and cleaned up downloads folder",
-                    deleted_count
-                ));
+            _ => {
+                // Ignore other actions to avoid infinite recursion
             }
-            AppEvent::AllDownloadsDeletionFailed { error } => {
-                // Still trigger background refresh of buffers in case some deletions succeeded
-                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_error(format!("Could not delete all downloads: {}", error));
-            }
-            AppEvent::OpmlImportStarted { source } => {
-                self.show_message(format!("Starting OPML import from: {}...", source));
-            }
-            AppEvent::OpmlImportProgress { status, .. } => {
-                self.show_message(status);
-            }
-            AppEvent::OpmlImportCompleted { result, log_path } => {
-                // Trigger background refresh of podcast list to show newly imported podcasts
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+        }
+    }
 
-                // Build summary message
-                let mut summary = format!(
-                    "Import complete: {} imported, {} skipped",
-                    result.imported, result.skipped
-                );
+    /// Handle periodic tick
+    async fn handle_tick(&mut self) -> UIResult<bool> {
+        // Update status bar with current key sequence
+        let sequence = self.key_handler.current_sequence_string();
+        if !sequence.is_empty() {
+            self.status_bar.set_key_sequence(sequence);
+        } else {
+            self.status_bar.set_key_sequence(String::new());
+        }
 
-                if result.has_failures() {
-                    summary.push_str(&format!(", {} failed", result.failed.len()));
-                    summary.push_str(&format!("\nSee log: {}", log_path));
-                }
+        if self.last_input.elapsed() >= crate::constants::downloads::PREFETCH_IDLE_AFTER
+            && !self._storage.is_read_only()
+        {
+            self.trigger_async_prefetch();
+        }
+        self.tick_auto_play();
+        self.tick_offline_probe();
+        self.tick_focus_mode();
 
-                self.show_message(summary);
-            }
-            AppEvent::OpmlImportFailed { source: _, error } => {
-                self.show_error(format!("Could not import OPML: {}", error));
+        Ok(true)
+    }
+
+    /// Enter offline mode (if not already in it): show the banner and start
+    /// checking for the connection to return
+    fn go_offline(&mut self) {
+        self.status_bar.set_network_online(Some(false));
+        self.offline.get_or_insert_with(|| OfflineMode {
+            next_probe: Instant::now() + crate::constants::network::OFFLINE_PROBE_INTERVAL,
+            probing: false,
+            announce: false,
+            pending: Vec::new(),
+        });
+    }
+
+    /// `op` failed because its server couldn't be reached. Already offline,
+    /// it waits for the connection; otherwise one unreachable server isn't
+    /// enough to go offline, so check whether other feed servers (those not
+    /// on `podcast_id`'s host) answer before deciding between waiting and
+    /// showing `error`.
+    fn network_failure(
+        &mut self,
+        op: PendingNetworkOp,
+        error: String,
+        podcast_id: Option<crate::storage::PodcastId>,
+    ) {
+        if self.offline.is_some() {
+            self.defer_while_offline(op);
+            return;
+        }
+        if self.unconfirmed_offline.is_empty() {
+            self.spawn_connectivity_probe(podcast_id);
+        }
+        if !self
+            .unconfirmed_offline
+            .iter()
+            .any(|(queued, _)| *queued == op)
+        {
+            self.unconfirmed_offline.push((op, error));
+        }
+    }
+
+    /// Queue `op` for when the connection returns if offline, telling the
+    /// user so. Returns false (and does nothing) when online.
+    fn defer_while_offline(&mut self, op: PendingNetworkOp) -> bool {
+        let Some(offline) = &mut self.offline else {
+            return false;
+        };
+        let what = op.describe();
+        if !offline.pending.contains(&op) {
+            offline.pending.push(op);
+        }
+        self.show_message(format!(
+            "Offline — {} will start when the connection returns",
+            what
+        ));
+        true
+    }
+
+    /// Explain that `what` needs the connection if offline. Returns whether it did.
+    fn refuse_while_offline(&mut self, what: &str) -> bool {
+        if self.offline.is_none() {
+            return false;
+        }
+        self.show_message(format!(
+            "Offline — {} needs the connection; try again once it returns (:reconnect checks now)",
+            what
+        ));
+        true
+    }
+
+    /// Explain that `what` would write if the data directory is opened
+    /// `--read-only`. Returns whether it did.
+    fn refuse_while_read_only(&mut self, what: &str) -> bool {
+        if !self._storage.is_read_only() {
+            return false;
+        }
+        self.show_message(format!(
+            "Read-only mode — {} is left to the instance holding the data directory",
+            what
+        ));
+        true
+    }
+
+    /// Check the connection now instead of waiting for the next probe
+    fn reconnect(&mut self) {
+        let Some(offline) = &mut self.offline else {
+            self.show_message("Already online".to_string());
+            return;
+        };
+        offline.announce = true;
+        if !offline.probing {
+            offline.probing = true;
+            self.spawn_connectivity_probe(None);
+        }
+        self.show_message("Checking the connection...".to_string());
+    }
+
+    /// Leave offline mode and start the work that waited for it
+    fn back_online(&mut self) {
+        let Some(offline) = self.offline.take() else {
+            return;
+        };
+        self.status_bar.set_network_online(Some(true));
+        let resumed = offline.pending.len();
+        for op in offline.pending {
+            match op {
+                PendingNetworkOp::RefreshPodcast(podcast_id) => {
+                    self.trigger_async_refresh_single(podcast_id)
+                }
+                PendingNetworkOp::RefreshAll => self.trigger_async_refresh_all(),
+                PendingNetworkOp::Download(podcast_id, episode_id) => {
+                    self.trigger_async_download(podcast_id, episode_id)
+                }
             }
-            AppEvent::OpmlExportStarted { path } => {
-                self.show_message(format!("Starting OPML export to: {}...", path));
+        }
+        if resumed > 0 {
+            self.show_message(format!(
+                "Back online — resuming {} pending operation(s)",
+                resumed
+            ));
+        } else {
+            self.show_message("Back online".to_string());
+        }
+    }
+
+    /// Check for the connection returning once the probe interval is up
+    fn tick_offline_probe(&mut self) {
+        let Some(offline) = &mut self.offline else {
+            return;
+        };
+        if offline.probing || Instant::now() < offline.next_probe {
+            return;
+        }
+        offline.probing = true;
+        self.spawn_connectivity_probe(None);
+    }
+
+    /// Try to reach a few subscribed feed servers in the background,
+    /// reporting the result as [`AppEvent::ConnectivityProbed`]. Servers on
+    /// the host of `skip` (a feed that just failed) aren't tried.
+    fn spawn_connectivity_probe(&self, skip: Option<crate::storage::PodcastId>) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let podcasts = subscription_manager
+                .list_subscriptions()
+                .await
+                .unwrap_or_default();
+            let skipped = podcasts
+                .iter()
+                .find(|podcast| Some(&podcast.id) == skip.as_ref())
+                .and_then(|podcast| crate::utils::network::probe_target(&podcast.url));
+            let mut targets = Vec::new();
+            for podcast in podcasts {
+                if let Some(target) = crate::utils::network::probe_target(&podcast.url) {
+                    if skipped.as_ref() != Some(&target) && !targets.contains(&target) {
+                        targets.push(target);
+                    }
+                }
             }
-            AppEvent::OpmlExportProgress { status } => {
-                self.show_message(status);
+            // Nothing to reach means nothing to wait for
+            let mut online = targets.is_empty();
+            for (host, port) in targets.into_iter().take(3) {
+                let timeout = crate::constants::network::CONNECTIVITY_PROBE_TIMEOUT;
+                if crate::utils::network::is_reachable(&host, port, timeout).await {
+                    online = true;
+                    break;
+                }
             }
-            AppEvent::OpmlExportCompleted { path, feed_count } => {
-                self.show_message(format!(
-                    "Successfully exported {} feeds to {}",
-                    feed_count, path
-                ));
+            let _ = app_event_tx.send(AppEvent::ConnectivityProbed { online });
+        });
+    }
+
+    /// Count down to the pending auto-play in the status bar, and play the
+    /// episode once the countdown runs out
+    fn tick_auto_play(&mut self) {
+        let Some(countdown) = &self.auto_play else {
+            return;
+        };
+        let remaining = countdown.deadline.saturating_duration_since(Instant::now());
+        if !remaining.is_zero() {
+            self.status_bar.set_status_message(format!(
+                "Next: {} in {}s (C-g cancels)",
+                countdown.title,
+                remaining.as_secs() + 1
+            ));
+            return;
+        }
+
+        let countdown = self.auto_play.take().expect("checked above");
+        self.status_bar.clear_status_message();
+        match self.audio_command_tx {
+            Some(ref tx) => {
+                let _ = tx.send(AudioCommand::Play {
+                    path: countdown.path,
+                    episode_id: countdown.episode_id,
+                    podcast_id: countdown.podcast_id,
+                    start_at: countdown.start_at,
+                });
             }
-            AppEvent::OpmlExportFailed { path: _, error } => {
-                self.show_error(format!("Could not export OPML: {}", error));
+            None => self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string()),
+        }
+    }
+
+    /// Start the auto-play countdown for the episode after the one that just
+    /// ended, when the podcast (or `audio.auto_play_next`) asks for it and an
+    /// unplayed episode is downloaded. Returns whether one was started.
+    async fn start_auto_play_countdown(
+        &mut self,
+        podcast_id: &crate::storage::PodcastId,
+        episode_id: &crate::storage::EpisodeId,
+    ) -> bool {
+        let enabled = match self._storage.load_podcast(podcast_id).await {
+            Ok(podcast) => podcast
+                .auto_play_next
+                .unwrap_or(self.config.audio.auto_play_next),
+            Err(_) => false,
+        };
+        if !enabled {
+            return false;
+        }
+        let Ok(episodes) = self._storage.load_episodes(podcast_id).await else {
+            return false;
+        };
+        let Some(next) = prefetch::next_playable(&episodes, episode_id) else {
+            return false;
+        };
+        let Some(path) = next.local_path.clone() else {
+            return false;
+        };
+
+        let title = next.display_title().to_string();
+        self.show_message(format!("Finished playing episode; up next: {}", title));
+        self.auto_play = Some(AutoPlayCountdown {
+            podcast_id: podcast_id.clone(),
+            episode_id: next.id.clone(),
+            path,
+            title,
+            start_at: next
+                .resume_position()
+                .filter(|_| self.config.audio.remember_position)
+                .map(|position| Duration::from_secs(position as u64)),
+            deadline: Instant::now()
+                + Duration::from_secs(crate::constants::audio::AUTO_PLAY_COUNTDOWN_SECS),
+        });
+        self.tick_auto_play();
+        true
+    }
+
+    /// Drop a pending auto-play; returns whether there was one
+    fn cancel_auto_play(&mut self) -> bool {
+        if self.auto_play.take().is_none() {
+            return false;
+        }
+        self.status_bar.clear_status_message();
+        true
+    }
+
+    /// Once per started episode, pick the episodes to play after it and
+    /// download them, unless the connection is metered (`downloads.prefetch_next`)
+    fn trigger_async_prefetch(&mut self) {
+        let Some((podcast_id, episode_id)) = self.prefetch_after.take() else {
+            return;
+        };
+        let count = self
+            .config
+            .downloads
+            .prefetch_next
+            .min(crate::constants::downloads::MAX_PREFETCH_EPISODES);
+        // Following a queue, its next entries are what plays next
+        let queued = self
+            .play_queue
+            .as_ref()
+            .filter(|queue| {
+                queue
+                    .current()
+                    .is_some_and(|entry| entry.episode_id == episode_id)
+            })
+            .map(|queue| queue.upcoming(count));
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let metering = tokio::task::spawn_blocking(read_network_metering)
+                .await
+                .unwrap_or(NetworkMetering::Unknown);
+            if metering.is_metered() {
+                return;
             }
-            AppEvent::PlaylistCreated { playlist } => {
-                self.load_playlists_into_buffer().await;
-                self.show_message(format!("Playlist created: {}", playlist.name));
+
+            let mut episodes = Vec::new();
+            match queued {
+                Some(entries) => {
+                    for entry in entries {
+                        if let Ok(episode) = storage
+                            .load_episode(&entry.podcast_id, &entry.episode_id)
+                            .await
+                        {
+                            if prefetch::needs_prefetch(&episode) {
+                                episodes.push((entry.podcast_id, entry.episode_id));
+                            }
+                        }
+                    }
+                }
+                None => {
+                    if let Ok(all) = storage.load_episodes(&podcast_id).await {
+                        episodes = prefetch::next_unplayed(&all, &episode_id, count)
+                            .into_iter()
+                            .map(|id| (podcast_id.clone(), id))
+                            .collect();
+                    }
+                }
             }
-            AppEvent::PlaylistCreationFailed { name, error } => {
-                self.show_error(format!("Could not create playlist '{}': {}", name, error));
+            if !episodes.is_empty() {
+                let _ = app_event_tx.send(AppEvent::PrefetchEpisodes { episodes });
             }
-            AppEvent::PlaylistDeleted { name } => {
-                self.load_playlists_into_buffer().await;
-                self.show_message(format!("Playlist deleted: {}", name));
+        });
+    }
+
+    /// Handle app events from async tasks
+    async fn handle_app_event(&mut self, event: AppEvent) -> UIResult<()> {
+        match event {
+            AppEvent::PodcastSubscribed { podcast } => {
+                // Trigger background refresh of podcast list
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                if let Some(image_url) = podcast.image_url.clone() {
+                    let download_manager = self.download_manager.clone();
+                    tokio::spawn(async move {
+                        download_manager.prefetch_artwork(vec![image_url]).await;
+                    });
+                }
+                self.show_message(format!("Successfully subscribed to: {}", podcast.title));
             }
-            AppEvent::PlaylistDeletionFailed { name, error } => {
-                self.show_error(format!("Could not delete playlist '{}': {}", name, error));
+            AppEvent::PodcastSubscriptionFailed { url: _, error } => {
+                self.show_error(format!("Could not subscribe to podcast: {}", error));
             }
-            AppEvent::EpisodeAddedToPlaylist {
-                playlist_name,
-                episode_title,
+            AppEvent::PodcastRefreshed {
+                podcast_id,
+                new_episode_count,
+                changed_episodes,
             } => {
-                self.load_playlists_into_buffer().await;
-                self.refresh_open_playlist_detail_buffers().await;
-                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
-                    let _ = self
-                        .buffer_manager
-                        .remove_buffer(&"playlist-picker".to_string());
-                    self.update_status_bar();
+                self.status_bar.set_network_online(Some(true));
+                self.back_online();
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                match (
+                    changed_episodes,
+                    self.buffer_manager.get_whats_new_buffer_mut(),
+                ) {
+                    (Some(changed), Some(whats_new)) => whats_new.apply_episode_changes(changed),
+                    (None, Some(_)) => self.trigger_background_refresh(BufferRefreshType::WhatsNew),
+                    (_, None) => {}
+                }
+                let mut message = if new_episode_count > 0 {
+                    format!("Found {} new episode(s)", new_episode_count)
+                } else {
+                    "No new episodes found".to_string()
+                };
+                if let Some(diff) = self
+                    .subscription_manager
+                    .last_refresh_diff(&podcast_id)
+                    .filter(|diff| {
+                        !diff.updated.is_empty()
+                            || !diff.removed.is_empty()
+                            || !diff.audio_updated.is_empty()
+                    })
+                {
+                    message.push_str(&format!(" ({}; :refresh-diff for details)", diff.summary()));
                 }
-                self.show_message(format!(
-                    "Added '{}' to playlist '{}'",
-                    episode_title, playlist_name
-                ));
+                self.show_message(message);
+                self.trigger_retention_cleanup(false);
             }
-            AppEvent::EpisodeAddToPlaylistFailed {
-                playlist_name,
-                episode_title,
+            AppEvent::PodcastRefreshFailed {
+                podcast_id,
                 error,
+                offline,
             } => {
-                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
-                    let _ = self
-                        .buffer_manager
-                        .remove_buffer(&"playlist-picker".to_string());
-                    self.update_status_bar();
+                let error = format!("Could not refresh podcast feed: {}", error);
+                if offline {
+                    self.network_failure(
+                        PendingNetworkOp::RefreshPodcast(podcast_id.clone()),
+                        error,
+                        Some(podcast_id),
+                    );
+                } else {
+                    self.show_error(error);
+                }
+            }
+            AppEvent::AllPodcastsRefreshed {
+                podcasts,
+                unreachable,
+                scope,
+                ..
+            } if podcasts > 0 && unreachable.len() == podcasts => {
+                // No feed answered: the connection is down, not the feeds
+                self.go_offline();
+                if scope.is_some() {
+                    for podcast_id in unreachable {
+                        self.defer_while_offline(PendingNetworkOp::RefreshPodcast(podcast_id));
+                    }
+                } else {
+                    self.defer_while_offline(PendingNetworkOp::RefreshAll);
                 }
-                self.show_error(format!(
-                    "Could not add '{}' to playlist '{}': {}",
-                    episode_title, playlist_name, error
-                ));
             }
-            AppEvent::EpisodeRemovedFromPlaylist {
-                playlist_name,
-                episode_title,
+            AppEvent::ConnectivityProbed { online } => {
+                let unconfirmed = std::mem::take(&mut self.unconfirmed_offline);
+                if self.offline.is_none() && !unconfirmed.is_empty() {
+                    // A check after single failures: only their servers are down
+                    if online {
+                        for (_, error) in unconfirmed {
+                            self.show_error(error);
+                        }
+                        return Ok(());
+                    }
+                    self.go_offline();
+                }
+                for (op, _) in unconfirmed {
+                    self.defer_while_offline(op);
+                }
+                if online {
+                    self.back_online();
+                } else if let Some(offline) = &mut self.offline {
+                    offline.probing = false;
+                    offline.next_probe =
+                        Instant::now() + crate::constants::network::OFFLINE_PROBE_INTERVAL;
+                    if std::mem::take(&mut offline.announce) {
+                        self.show_message("Still offline — checking again shortly".to_string());
+                    }
+                }
+            }
+            AppEvent::AllPodcastsRefreshed {
+                total_new_episodes,
+                podcasts,
+                scope,
+                ..
+            } => {
+                if self.offline.is_some() && podcasts > 0 {
+                    self.back_online();
+                }
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                if let Some(scope) = scope {
+                    if podcasts == 0 {
+                        self.show_message(format!("No podcasts match {}", scope));
+                    } else if total_new_episodes > 0 {
+                        self.show_message(format!(
+                            "Refresh of {} podcast(s) [{}] completed. Found {} new episode(s). Updating buffers...",
+                            podcasts, scope, total_new_episodes
+                        ));
+                    } else {
+                        self.show_message(format!(
+                            "Refresh of {} podcast(s) [{}] completed. No new episodes found",
+                            podcasts, scope
+                        ));
+                    }
+                } else if total_new_episodes > 0 {
+                    self.show_message(format!(
+                        "Podcast refresh completed. Found {} new episode(s). Updating buffers...",
+                        total_new_episodes
+                    ));
+                } else {
+                    self.show_message(
+                        "Podcast refresh completed. No new episodes found".to_string(),
+                    );
+                }
+                self.trigger_retention_cleanup(false);
+            }
+            AppEvent::HardRefreshAllProgress {
+                done,
+                total,
+                podcast_title,
             } => {
-                self.load_playlists_into_buffer().await;
-                self.refresh_open_playlist_detail_buffers().await;
                 self.show_message(format!(
-                    "Removed '{}' from playlist '{}'",
-                    episode_title, playlist_name
+                    "Hard refresh: {}/{} podcasts ({})",
+                    done, total, podcast_title
                 ));
             }
-            AppEvent::EpisodeRemoveFromPlaylistFailed {
-                playlist_name,
-                episode_title,
-                error,
-            } => {
-                self.show_error(format!(
-                    "Could not remove '{}' from playlist '{}': {}",
-                    episode_title, playlist_name, error
-                ));
-            }
-            AppEvent::PlaylistReordered { name } => {
-                self.load_playlists_into_buffer().await;
-                self.refresh_open_playlist_detail_buffers().await;
-                self.show_message(format!("Playlist reordered: {}", name));
+            AppEvent::HardRefreshAllCompleted { scope, summary } => {
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                let mut message = format!(
+                    "Hard refresh ({}) completed: {} podcast(s), {} episode(s) updated",
+                    scope.label(),
+                    summary.podcasts,
+                    summary.episodes
+                );
+                if summary.failed.is_empty() {
+                    self.show_message(message);
+                } else {
+                    message.push_str(&format!(
+                        "; {} failed: {}",
+                        summary.failed.len(),
+                        summary.failed.join(", ")
+                    ));
+                    self.show_error(message);
+                }
             }
-            AppEvent::PlaylistReorderFailed { name, error } => {
-                self.show_error(format!("Could not reorder playlist '{}': {}", name, error));
+            AppEvent::BufferDataRefreshed { buffer_type, data } => {
+                // Update buffer data without blocking the UI thread
+                self.handle_buffer_data_refresh(buffer_type, data);
             }
-            AppEvent::PlaylistRebuilt {
-                name,
-                rebuilt,
-                skipped,
-                failed,
+            AppEvent::PodcastDeleted {
+                podcast_id: _,
+                podcast_title,
+                mode,
             } => {
-                self.load_playlists_into_buffer().await;
-                self.refresh_open_playlist_detail_buffers().await;
-                self.show_message(format!(
-                    "Playlist rebuilt '{}': {} rebuilt, {} skipped, {} failed",
-                    name, rebuilt, skipped, failed
-                ));
-            }
-            AppEvent::PlaylistRebuildFailed { name, error } => {
-                self.show_error(format!("Could not rebuild playlist '{}': {}", name, error));
-            }
-            AppEvent::TodayPlaylistRefreshed { added, removed } => {
-                self.load_playlists_into_buffer().await;
-                self.refresh_open_playlist_detail_buffers().await;
-                self.show_message(format!(
-                    "Today playlist refreshed: {} added, {} removed",
-                    added, removed
-                ));
-            }
-            AppEvent::TodayPlaylistRefreshFailed { error } => {
-                self.show_error(format!("Could not refresh Today playlist: {}", error));
+                // Trigger background refresh of podcast list
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                let restore_days = self.config.storage.podcast_restore_days;
+                self.show_message(match mode {
+                    UnsubscribeMode::KeepAll => format!(
+                        "Unsubscribed: {} (:restore-podcast within {} days to undo)",
+                        podcast_title, restore_days
+                    ),
+                    UnsubscribeMode::KeepHistory => format!(
+                        "Unsubscribed: {}, downloads deleted (:restore-podcast within {} days to restore its history)",
+                        podcast_title, restore_days
+                    ),
+                    UnsubscribeMode::KeepDownloads => {
+                        format!("Deleted: {} (downloaded files kept)", podcast_title)
+                    }
+                    UnsubscribeMode::PurgeAll => {
+                        format!("Successfully deleted: {}", podcast_title)
+                    }
+                });
             }
-            AppEvent::DeviceSyncStarted {
-                device_path,
-                dry_run,
-                hard_sync,
+            AppEvent::SettingsExported {
+                path,
+                theme_count,
+                playlist_count,
             } => {
-                let mode = if dry_run { " (dry run)" } else { "" };
-                let sync_kind = if hard_sync { "hard " } else { "" };
                 self.show_message(format!(
-                    "Starting {}device sync to {}{}...",
-                    sync_kind,
-                    device_path.display(),
-                    mode
+                    "Settings exported to {} ({} themes, {} smart playlists)",
+                    path, theme_count, playlist_count
                 ));
             }
-            AppEvent::DeviceSyncCompleted {
-                device_path,
-                report,
-                dry_run,
+            AppEvent::SettingsExportFailed { path, error } => {
+                self.show_error(format!("Could not export settings to {}: {}", path, error));
+            }
+            AppEvent::SettingsImportPreviewReady {
+                path,
+                bundle,
+                preview,
             } => {
-                if dry_run {
-                    // Enter dry-run preview mode so the user can review and confirm
-                    if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
-                        sync_buffer.enter_dry_run_preview(device_path.clone(), report.clone());
-                    }
-                    let summary = if report.is_success() {
-                        format!(
-                            "Dry run: {} to copy, {} to delete, {} skip — press Enter/s to confirm",
-                            report.files_copied.len(),
-                            report.files_deleted.len(),
-                            report.files_skipped.len()
-                        )
-                    } else {
-                        format!(
-                            "Dry run: {} to copy, {} to delete, {} skip ({} errors)",
-                            report.files_copied.len(),
-                            report.files_deleted.len(),
-                            report.files_skipped.len(),
-                            report.errors.len()
-                        )
-                    };
-                    self.show_message(summary);
-                } else {
-                    // Real sync completed — update history, return to overview
-                    if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
-                        sync_buffer.add_sync_result(device_path.clone(), report.clone(), dry_run);
-                        sync_buffer.reset_to_overview();
-                    }
-
-                    let summary = if report.is_success() {
-                        format!(
-                            "Sync complete: {} copied, {} deleted, {} skipped",
-                            report.files_copied.len(),
-                            report.files_deleted.len(),
-                            report.files_skipped.len()
-                        )
-                    } else {
-                        format!(
-                            "Sync complete with {} errors: {} copied, {} deleted, {} skipped",
-                            report.errors.len(),
-                            report.files_copied.len(),
-                            report.files_deleted.len(),
-                            report.files_skipped.len()
-                        )
-                    };
-                    self.load_playlists_into_buffer().await;
-                    self.show_message(summary);
+                let preview_name = "*Settings Import Preview*";
+                if let Some(id) = self.buffer_manager.find_buffer_id_by_name(preview_name) {
+                    let _ = self.buffer_manager.remove_buffer(&id);
                 }
-            }
-            AppEvent::DeviceSyncProgress { event } => {
-                if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
-                    let complete = sync_buffer.handle_progress_event(event);
-                    if complete {
-                        // Complete event will be followed by DeviceSyncCompleted; nothing extra needed
-                    }
+                let mut preview_buffer = crate::ui::buffers::help::HelpBuffer::with_content(
+                    preview_name.to_string(),
+                    preview,
+                );
+                preview_buffer.set_theme(self.theme.clone());
+                let _ = self.buffer_manager.add_buffer(Box::new(preview_buffer));
+                if let Some(id) = self.buffer_manager.find_buffer_id_by_name(preview_name) {
+                    let _ = self.buffer_manager.switch_to_buffer(&id);
                 }
+                self.update_status_bar();
+
+                self.minibuffer.set_content(MinibufferContent::Input {
+                    prompt: format!("Apply settings from {} (y/n)? ", path),
+                    input: String::new(),
+                });
+                self.pending_settings_import = Some((path, bundle));
             }
-            AppEvent::DeviceSyncFailed {
-                device_path: _,
-                error,
+            AppEvent::SettingsImported {
+                config,
+                theme_count,
+                playlist_count,
             } => {
-                // On failure, return sync buffer to Overview
-                if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
-                    // enter_progress_mode is only called for real syncs; reset to overview on error
-                    if sync_buffer.is_in_progress_mode() {
-                        sync_buffer.reset_to_overview();
+                self.config = *config;
+                self.whats_new_exclusions = compile_whats_new_exclusions(&self.config);
+                self.keyword_alerts = compile_keyword_alerts(&self.config);
+                self.listening_goals = compile_listening_goals(&self.config);
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                if let Ok(config_dir) = Config::config_dir() {
+                    for err in self.theme_registry.load_user_themes(&config_dir) {
+                        eprintln!("[themes] Warning: {err}");
                     }
                 }
-                self.show_error(format!("Could not sync device: {}", error));
+                let theme_name = self.config.ui.theme.clone();
+                let _ = self.set_theme_direct(&theme_name);
+                self.show_message(format!(
+                    "Settings imported ({} themes, {} smart playlists). Restart to apply keybindings",
+                    theme_count, playlist_count
+                ));
             }
-            AppEvent::DownloadCleanupCompleted {
-                deleted_count,
-                duration_label,
-            } => {
-                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
-                self.trigger_background_refresh(BufferRefreshType::Downloads);
-                if deleted_count > 0 {
-                    self.show_message(format!(
-                        "Cleaned up {} episode(s) older than {}",
-                        deleted_count, duration_label
-                    ));
+            AppEvent::SettingsImportFailed { path, error } => {
+                self.pending_settings_import = None;
+                self.show_error(format!(
+                    "Could not import settings from {}: {}",
+                    path, error
+                ));
+            }
+            AppEvent::RemovedPodcastsListed { podcasts } => {
+                if podcasts.is_empty() {
+                    self.show_message("No recently removed podcasts".to_string());
                 } else {
+                    let now = chrono::Utc::now();
+                    let entries: Vec<String> = podcasts
+                        .iter()
+                        .map(|p| {
+                            let days = p
+                                .removed_at
+                                .map(|removed_at| (now - removed_at).num_days())
+                                .unwrap_or(0);
+                            format!("{} ({}d ago)", p.title, days)
+                        })
+                        .collect();
                     self.show_message(format!(
-                        "No downloaded episodes older than {}",
-                        duration_label
+                        "Removed: {} — use :restore-podcast <title>",
+                        entries.join(", ")
                     ));
                 }
             }
-            AppEvent::DownloadCleanupFailed { error } => {
-                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+            AppEvent::PodcastRestored { podcast_title } => {
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
                 self.trigger_background_refresh(BufferRefreshType::Downloads);
-                self.show_error(format!("Could not clean up downloads: {}", error));
-            }
-            AppEvent::PodcastTagAdded {
-                podcast_id: _,
-                tag: _,
-            } => {
-                // Optimistic update already applied; nothing further needed
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.show_message(format!("Restored: {}", podcast_title));
             }
-            AppEvent::PodcastTagAddFailed {
-                podcast_id: _,
-                error,
-            } => {
-                // Revert optimistic update by refreshing from storage
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.show_error(format!("Could not add tag: {}", error));
+            AppEvent::PodcastRestoreFailed { error } => {
+                self.show_error(format!("Could not restore podcast: {}", error));
             }
-            AppEvent::PodcastTagRemoved {
+            AppEvent::PodcastDownloadsDeleted {
                 podcast_id: _,
-                tag: _,
+                deleted_count,
             } => {
-                // Optimistic update already applied; nothing further needed
+                if deleted_count > 0 {
+                    self.show_message(format!("Deleted {} downloaded episodes", deleted_count));
+                }
             }
-            AppEvent::PodcastTagRemoveFailed {
+            AppEvent::PodcastDeletionFailed {
                 podcast_id: _,
                 error,
             } => {
-                // Revert optimistic update by refreshing from storage
-                self.trigger_background_refresh(BufferRefreshType::PodcastList);
-                self.show_error(format!("Could not remove tag: {}", error));
+                self.show_error(format!("Could not delete podcast: {}", error));
             }
-            AppEvent::SmartPlaylistEvaluated {
-                detail_buffer_id,
+            AppEvent::EpisodesLoaded {
+                podcast_id: _,
+                podcast_name,
                 episodes,
             } => {
-                if let Some(detail_buffer) = self
+                // Update the episode buffer with the loaded episodes
+                if let Some(episode_buffer) = self
                     .buffer_manager
-                    .get_playlist_detail_buffer_mut_by_id(&detail_buffer_id)
+                    .get_episode_list_buffer_mut(&podcast_name)
                 {
-                    detail_buffer.set_evaluated_episodes(episodes);
+                    episode_buffer.set_episodes(episodes.clone());
+                    self.show_message(format!("Loaded {} episodes", episodes.len()));
+                } else {
+                    self.show_message(format!("Loaded {} episodes", episodes.len()));
                 }
             }
-            AppEvent::SmartPlaylistEvaluationFailed {
-                playlist_name,
+            AppEvent::EpisodesLoadFailed {
+                podcast_id: _,
                 error,
             } => {
-                self.show_error(format!(
-                    "Smart playlist '{}' failed: {}",
-                    playlist_name, error
-                ));
+                self.show_error(format!("Could not load episodes: {}", error));
             }
-            AppEvent::DiscoveryResultsLoaded {
-                buffer_id,
-                results,
-                title: _,
+            AppEvent::EpisodeDownloaded {
+                podcast_id,
+                episode_id,
             } => {
-                if let Some(buf) = self
-                    .buffer_manager
-                    .get_discovery_buffer_mut_by_id(&buffer_id)
-                {
-                    buf.set_results(results);
+                self.back_online();
+                // What's New only lists episodes still to download
+                if let Some(whats_new) = self.buffer_manager.get_whats_new_buffer_mut() {
+                    whats_new.remove_episode_by_id(&episode_id);
                 }
-                self.update_status_bar();
+                if self.config.transcription.auto_transcribe {
+                    self.trigger_async_transcribe_episode(podcast_id.clone(), episode_id, false);
+                }
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                // Playlist statistics count downloaded entries
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message("Episode download completed successfully".to_string());
             }
-            AppEvent::DiscoveryLoadFailed { buffer_id, error } => {
-                if let Some(buf) = self
-                    .buffer_manager
-                    .get_discovery_buffer_mut_by_id(&buffer_id)
-                {
-                    buf.set_error(error.clone());
+            AppEvent::EpisodeDownloadProgress {
+                episode_id,
+                downloaded,
+                total,
+            } => {
+                if let Some(downloads_buffer) = self.buffer_manager.get_downloads_buffer_mut() {
+                    downloads_buffer.set_progress(&episode_id, downloaded, total);
                 }
-                self.show_error(format!("Discovery failed: {}", error));
             }
-            AppEvent::PlaybackStarted {
+            AppEvent::EpisodeAlreadyDownloading { episode_id: _ } => {
+                self.show_message("Episode is already downloading".to_string());
+            }
+            AppEvent::EpisodeDownloadFailed {
                 podcast_id,
                 episode_id,
+                error,
+                offline,
             } => {
-                // Look up episode title and podcast name for the NowPlaying buffer.
-                let episode_title = self
-                    ._storage
-                    .load_episode(&podcast_id, &episode_id)
-                    .await
-                    .ok()
-                    .map(|ep| ep.title);
-                let podcast_name = self
-                    ._storage
-                    .load_podcast(&podcast_id)
-                    .await
-                    .ok()
-                    .map(|p| p.title);
-                self.buffer_manager
-                    .set_now_playing_info(episode_title, podcast_name);
-                self.show_message("Now playing…".to_string());
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
+                    podcast_id: podcast_id.clone(),
+                });
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                let error = format!("Episode download failed: {}", error);
+                if offline {
+                    self.network_failure(
+                        PendingNetworkOp::Download(podcast_id, episode_id),
+                        error,
+                        None,
+                    );
+                } else {
+                    self.show_error(error);
+                }
             }
-            AppEvent::PlaybackStopped => {
-                self.show_message("Playback stopped".to_string());
+            AppEvent::EpisodeConversionProgress {
+                episode_title,
+                percent,
+            } => {
+                self.show_message(format!("Converting '{}'... {}%", episode_title, percent));
             }
-            AppEvent::TrackEnded {
+            AppEvent::EpisodeConverted {
                 podcast_id,
-                episode_id,
+                episode_title,
+                path,
             } => {
-                // Persist completion: update position to full duration (auto-marks played at ≥95%).
-                match self._storage.load_episode(&podcast_id, &episode_id).await {
-                    Ok(mut episode) => {
-                        if let Some(duration) = episode.duration {
-                            episode.update_position(duration);
-                        } else {
-                            // No duration recorded — mark as played without position update.
-                            episode.mark_played();
-                        }
-                        if let Err(e) = self._storage.save_episode(&podcast_id, &episode).await {
-                            eprintln!("[audio] Failed to save episode after track end: {e}");
-                        }
-                        // Refresh episode buffers so played status is reflected immediately.
-                        self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
-                            podcast_id: podcast_id.clone(),
-                        });
-                        self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
-                    }
-                    Err(e) => {
-                        eprintln!("[audio] Failed to load episode for track-end update: {e}");
-                    }
-                }
-                self.show_message("Finished playing episode".to_string());
-            }
-            AppEvent::PlaybackError { error } => {
-                self.show_error(format!("Playback error: {}", error));
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                let size = std::fs::metadata(&path)
+                    .map(|m| format!(" ({:.1} MB)", m.len() as f64 / 1_048_576.0))
+                    .unwrap_or_default();
+                self.show_message(format!("Converted '{}'{}", episode_title, size));
             }
-        }
-        Ok(())
-    }
-
-    /// Execute a command directly without recursion
-    fn execute_command_direct(&mut self, command: String) -> UIResult<bool> {
-        let parts: Vec<&str> = command.split_whitespace().collect();
-        if parts.is_empty() {
-            return Ok(true);
-        }
-
-        match parts[0] {
-            "quit" | "q" => {
-                self.should_quit = true;
-                Ok(false)
+            AppEvent::EpisodeConversionFailed {
+                episode_title,
+                error,
+            } => {
+                self.show_error(format!("Could not convert '{}': {}", episode_title, error));
             }
-            "help" | "h" => {
-                let mut help_id = self
+            AppEvent::EpisodeTranscriptionProgress {
+                episode_title,
+                percent,
+            } => {
+                self.show_message(format!("Transcribing '{}'... {}%", episode_title, percent));
+            }
+            AppEvent::EpisodeTranscribed { episode } => {
+                let podcast_id = episode.podcast_id.clone();
+                let message = format!(
+                    "Transcribed '{}' ({} lines)",
+                    episode.display_title(),
+                    episode
+                        .transcript
+                        .as_deref()
+                        .map_or(0, |t| t.lines().count())
+                );
+                let podcast = self
                     .buffer_manager
-                    .find_buffer_id_by_name("*Help: Keybindings*");
-                if help_id.is_none() {
-                    let entries = self.key_handler.generate_help_text();
-                    self.buffer_manager.create_help_buffer(entries);
-                    help_id = self
-                        .buffer_manager
-                        .find_buffer_id_by_name("*Help: Keybindings*");
+                    .get_podcast_list_buffer_mut()
+                    .and_then(|list| list.podcast(&podcast_id))
+                    .cloned();
+                let alert = podcast
+                    .and_then(|podcast| keyword_alert(&self.keyword_alerts, &podcast, &episode));
+                if let Some(detail) = self
+                    .buffer_manager
+                    .get_episode_detail_buffer_mut_by_id(&format!("episode-detail-{}", episode.id))
+                {
+                    detail.set_episode(*episode);
                 }
-                if let Some(id) = help_id {
-                    let _ = self.buffer_manager.switch_to_buffer(&id);
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.show_message(message);
+                if let Some(alert) = alert {
+                    self.raise_keyword_alerts(vec![alert]);
+                }
+            }
+            AppEvent::KeywordAlertsRaised { alerts } => {
+                self.raise_keyword_alerts(alerts);
+            }
+            AppEvent::AutoDownloadsQueued { episodes } => {
+                let episodes: Vec<_> = episodes
+                    .into_iter()
+                    .filter(|(_, episode_id)| !self.download_manager.is_downloading(episode_id))
+                    .collect();
+                if !episodes.is_empty() {
+                    let count = episodes.len();
+                    for (podcast_id, episode_id) in episodes {
+                        self.trigger_async_download(podcast_id, episode_id);
+                    }
+                    self.show_message(format!("Auto-downloading {} new episode(s)", count));
                 }
-                self.update_status_bar();
-                Ok(true)
             }
-            "theme" => {
-                if parts.len() > 1 {
-                    self.set_theme_direct(parts[1])
-                } else {
-                    let names = self.theme_registry.list_names().join(", ");
-                    self.show_error(format!("Usage: theme <name> ({})", names));
-                    Ok(true)
+            AppEvent::ListeningGoalsEvaluated {
+                stats,
+                progress,
+                remind,
+            } => {
+                let reminder = goal_reminder(&progress);
+                if let Some(dashboard) = self.buffer_manager.get_dashboard_buffer_mut() {
+                    dashboard.set_progress(stats, progress);
                 }
-            }
-            "buffer" | "b" => {
-                if parts.len() > 1 {
-                    let buffer_name = parts[1].to_string();
-                    self.switch_to_buffer_by_name(buffer_name)
-                } else {
-                    self.show_buffer_list();
-                    Ok(true)
+                if let Some(reminder) = reminder.filter(|_| remind) {
+                    self.show_message(reminder);
                 }
             }
-            "switch-to-buffer" | "switch-buffer" => {
-                if parts.len() > 1 {
-                    let buffer_name = parts[1].to_string();
-                    self.switch_to_buffer_by_name(buffer_name)
-                } else {
-                    self.prompt_buffer_switch();
-                    Ok(true)
+            AppEvent::EpisodeTranscriptionFailed {
+                episode_title,
+                error,
+            } => {
+                self.show_error(format!(
+                    "Could not transcribe '{}': {}",
+                    episode_title, error
+                ));
+            }
+            AppEvent::EpisodeTranslated { episode } => {
+                let message = format!("Translated '{}'", episode.display_title());
+                if let Some(detail) = self
+                    .buffer_manager
+                    .get_episode_detail_buffer_mut_by_id(&format!("episode-detail-{}", episode.id))
+                {
+                    detail.set_episode(*episode);
                 }
+                self.show_message(message);
             }
-            "list-buffers" | "buffers" => {
-                self.show_buffer_list();
-                Ok(true)
+            AppEvent::EpisodeTranslationFailed {
+                episode_title,
+                error,
+            } => {
+                self.show_error(format!(
+                    "Could not translate '{}': {}",
+                    episode_title, error
+                ));
             }
-            "close-buffer" | "kill-buffer" => {
-                if parts.len() > 1 {
-                    let buffer_name = parts[1].to_string();
-                    self.close_buffer_by_name(buffer_name)
-                } else {
-                    // Close current buffer
-                    if let Some(current_id) = self.buffer_manager.current_buffer_id() {
-                        match self.buffer_manager.remove_buffer(&current_id) {
-                            Ok(_) => {
-                                self.update_status_bar();
-                                self.show_message(format!("Closed buffer: {}", current_id));
-                                Ok(true)
-                            }
-                            Err(e) => {
-                                self.show_error(format!("Cannot close buffer: {}", e));
-                                Ok(true)
-                            }
-                        }
-                    } else {
-                        self.show_message("No buffer to close".to_string());
-                        Ok(true)
+            AppEvent::EpisodeMetadataEdited {
+                episode,
+                field,
+                retagged,
+            } => {
+                let podcast_id = episode.podcast_id.clone();
+                let message = match field {
+                    Some(field) if episode.overrides.get(field).is_some() => {
+                        format!("Episode {} updated", field.label())
                     }
+                    Some(field) => format!("Episode {} reverted to the feed's", field.label()),
+                    None => "Episode metadata reverted to the feed's".to_string(),
+                };
+                if let Some(detail) = self
+                    .buffer_manager
+                    .get_episode_detail_buffer_mut_by_id(&format!("episode-detail-{}", episode.id))
+                {
+                    detail.set_episode(*episode);
                 }
-            }
-            "add-podcast" => {
-                if parts.len() > 1 {
-                    let url = parts[1].to_string();
-                    self.show_message(format!("Adding podcast: {}...", url));
-                    self.trigger_async_add_podcast(url);
-                    Ok(true)
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.trigger_background_refresh(BufferRefreshType::Unplayed);
+                if retagged {
+                    self.trigger_background_refresh(BufferRefreshType::Downloads);
+                    self.show_message(format!("{} (file tags rewritten)", message));
                 } else {
-                    self.show_error("Usage: add-podcast <URL>".to_string());
-                    Ok(true)
+                    self.show_message(message);
                 }
             }
-            "delete-all-downloads" | "clean-downloads" => {
-                // Show confirmation prompt for bulk deletion
-                self.minibuffer.set_content(MinibufferContent::Input {
-                    prompt: "Delete ALL downloaded episodes? This will remove all downloaded files! (y/n) ".to_string(),
-                    input: String::new(),
-                });
-                self.pending_bulk_deletion = true;
-                Ok(true)
+            AppEvent::EpisodeMetadataEditFailed { error } => {
+                self.show_error(format!("Could not save episode metadata: {}", error));
             }
-            "import-opml" => {
-                if parts.len() > 1 {
-                    let source = parts[1..].join(" ");
-                    self.show_message(format!("Importing OPML from: {}...", source));
-                    self.trigger_async_opml_import(source);
-                    Ok(true)
-                } else {
-                    // Prompt for file path/URL
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: "Import OPML from (file path or URL): ".to_string(),
-                        input: String::new(),
+            AppEvent::NotesSaved { podcast, episode } => {
+                if let Some(episode) = episode {
+                    let podcast_id = episode.podcast_id.clone();
+                    if let Some(detail) =
+                        self.buffer_manager
+                            .get_episode_detail_buffer_mut_by_id(&format!(
+                                "episode-detail-{}",
+                                episode.id
+                            ))
+                    {
+                        detail.set_episode(*episode);
+                    }
+                    self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
+                        podcast_id,
                     });
-                    Ok(true)
+                    self.show_message("Episode notes saved".to_string());
+                }
+                if let Some(podcast) = podcast {
+                    if let Some(detail) =
+                        self.buffer_manager
+                            .get_podcast_detail_buffer_mut_by_id(&format!(
+                                "podcast-detail-{}",
+                                podcast.id
+                            ))
+                    {
+                        detail.set_podcast(*podcast);
+                    }
+                    self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                    self.show_message("Podcast notes saved".to_string());
                 }
             }
-            "export-opml" => {
-                if parts.len() > 1 {
-                    let path = parts[1..].join(" ");
-                    self.trigger_async_opml_export(path);
-                    Ok(true)
-                } else {
-                    // Prompt for output path with default
-                    let default_path =
-                        shellexpand::tilde(&self.config.storage.opml_export_directory).to_string();
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: format!("Export to (default: {}): ", default_path),
-                        input: String::new(),
-                    });
-                    Ok(true)
-                }
+            AppEvent::NotesSaveFailed { error } => {
+                self.show_error(format!("Could not save notes: {}", error));
             }
-            "sync" | "sync-device" => {
-                let (device_path, hard_sync) = Self::parse_sync_command_args(&parts[1..]);
-                if let Some(device_path) = device_path {
-                    self.trigger_async_device_sync(device_path, false, false, hard_sync);
-                    Ok(true)
-                } else if hard_sync {
-                    let default_path = self.get_default_sync_path();
-                    self.trigger_async_device_sync(default_path, false, false, true);
-                    Ok(true)
-                } else {
-                    // Prompt for device path with default from config
-                    let default_path = self.get_default_sync_path();
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: format!("Sync to device path (default: {}): ", default_path),
-                        input: String::new(),
-                    });
-                    Ok(true)
+            AppEvent::BookmarkAdded { episode, position } => {
+                let label = episode
+                    .bookmarks
+                    .iter()
+                    .find(|b| b.position == position)
+                    .map(|b| b.label.clone())
+                    .unwrap_or_default();
+                if let Some(detail) = self
+                    .buffer_manager
+                    .get_episode_detail_buffer_mut_by_id(&format!("episode-detail-{}", episode.id))
+                {
+                    detail.set_episode(*episode);
                 }
+                self.show_message(format!(
+                    "Bookmarked {} at {}",
+                    label,
+                    crate::utils::time::format_duration(position)
+                ));
             }
-            "sync-dry-run" | "sync-preview" => {
-                let (device_path, hard_sync) = Self::parse_sync_command_args(&parts[1..]);
-                if let Some(device_path) = device_path {
-                    self.trigger_async_device_sync(device_path, false, true, hard_sync);
-                    Ok(true)
-                } else if hard_sync {
-                    let default_path = self.get_default_sync_path();
-                    self.trigger_async_device_sync(default_path, false, true, true);
-                    Ok(true)
-                } else {
-                    // Prompt for device path
-                    let default_path = self.get_default_sync_path();
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: format!("Dry run sync to (default: {}): ", default_path),
-                        input: String::new(),
-                    });
-                    Ok(true)
-                }
+            AppEvent::BookmarksExported {
+                episode_title,
+                paths,
+            } => {
+                let files: Vec<_> = paths.iter().map(|p| p.display().to_string()).collect();
+                self.show_message(format!(
+                    "Exported bookmarks of '{}' to {}",
+                    episode_title,
+                    files.join(", ")
+                ));
             }
-            "playlists" => {
-                let list_id = "playlist-list".to_string();
-                if !self.buffer_manager.get_buffer_ids().contains(&list_id) {
-                    self.buffer_manager
-                        .create_playlist_list_buffer(self.playlist_manager.clone());
-                }
-                let _ = self.buffer_manager.switch_to_buffer(&list_id);
-                self.update_status_bar();
-                self.trigger_async_refresh_today();
-                Ok(true)
+            AppEvent::BookmarkFailed { error } => {
+                self.show_error(error);
             }
-            "playlist-create" | "playlist-new" => {
-                if parts.len() > 1 {
-                    let name = parts[1..].join(" ");
-                    self.trigger_async_create_playlist(name, None);
-                } else {
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: "Create playlist: ".to_string(),
-                        input: String::new(),
-                    });
-                }
-                Ok(true)
+            AppEvent::PlaybackModesSaveFailed { name, error } => {
+                self.show_error(format!(
+                    "Could not save shuffle/repeat for '{}': {}",
+                    name, error
+                ));
             }
-            "smart-playlist" => {
-                if parts.len() < 2 {
-                    self.show_error(
-                        "Usage: :smart-playlist <name> [--filter <spec>...] [--sort <field>] [--limit <n>]".to_string(),
-                    );
-                    return Ok(true);
-                }
-                // Allow multi-word names: consume tokens until the first --flag
-                let flags_start_idx = parts[2..]
-                    .iter()
-                    .position(|p| p.starts_with("--"))
-                    .map(|i| i + 2)
-                    .unwrap_or(parts.len());
-                let name = parts[1..flags_start_idx].join(" ");
-                let args = &parts[flags_start_idx..];
-                match crate::ui::app::parse_smart_playlist_args(args) {
-                    Ok(rule) => self.trigger_async_create_smart_playlist(name, rule),
-                    Err(msg) => self.show_error(msg),
-                }
-                Ok(true)
+            AppEvent::EpisodeDownloadDeleted {
+                podcast_id,
+                episode_id: _,
+            } => {
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message("Episode download deleted successfully".to_string());
             }
-            "discover" | "search-podcasts" => {
-                if parts.len() < 2 {
-                    self.show_error("Usage: :discover <search term>".to_string());
-                    return Ok(true);
-                }
-                let query = parts[1..].join(" ");
-                let buffer_id = format!("discovery-{}", query.replace(' ', "-").to_lowercase());
-                let display_title = format!("Search: {}", query);
-                // Reuse existing buffer if query is the same, otherwise create fresh
-                if self.buffer_manager.get_buffer(&buffer_id).is_none() {
-                    self.buffer_manager
-                        .create_discovery_buffer(buffer_id.clone(), display_title.clone());
-                }
-                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
-                self.trigger_async_discover(query, buffer_id);
-                self.update_status_bar();
-                Ok(true)
+            AppEvent::EpisodeDownloadDeletionFailed {
+                podcast_id,
+                episode_id: _,
+                error,
+            } => {
+                // Trigger background refresh of buffers
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_error(format!("Could not delete episode download: {}", error));
             }
-            "trending" => {
-                let buffer_id = "discovery-trending".to_string();
-                let display_title = "Trending".to_string();
-                if self.buffer_manager.get_buffer(&buffer_id).is_none() {
-                    self.buffer_manager
-                        .create_discovery_buffer(buffer_id.clone(), display_title.clone());
-                }
-                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
-                self.trigger_async_trending(buffer_id);
-                self.update_status_bar();
-                Ok(true)
+            AppEvent::RedownloadPrepared {
+                podcast_id,
+                episode_id,
+            } => {
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
+                    podcast_id: podcast_id.clone(),
+                });
+                self.trigger_async_download(podcast_id, episode_id);
             }
-            "playlist-delete" => {
-                if parts.len() > 1 {
-                    let name = parts[1..].join(" ");
-                    self.trigger_async_delete_playlist_by_name(name);
-                } else {
-                    self.show_error("Usage: playlist-delete <name>".to_string());
-                }
-                Ok(true)
+            AppEvent::EpisodeMarkedPlayed {
+                podcast_id,
+                episode_id: _,
+                episode_title,
+            } => {
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.refresh_dashboard_if_open();
+                self.refresh_history_if_open();
+                self.show_message(format!("Marked as played: {}", episode_title));
             }
-            "playlist-refresh" => {
-                self.trigger_async_refresh_today();
-                Ok(true)
+            AppEvent::EpisodeMarkPlayedFailed {
+                podcast_id,
+                episode_id: _,
+                error,
+            } => {
+                // Refresh buffers to revert optimistic UI update
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.show_error(format!("Could not mark episode as played: {}", error));
             }
-            "playlist-sync" => {
-                let default_path = self.get_default_sync_path();
-                self.trigger_async_device_sync(default_path, false, false, false);
-                Ok(true)
+            AppEvent::EpisodeMarkedUnplayed {
+                podcast_id,
+                episode_id: _,
+                episode_title,
+            } => {
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.refresh_history_if_open();
+                self.show_message(format!("Marked as unplayed: {}", episode_title));
             }
-            "clean-older-than" | "cleanup" => {
-                if parts.len() > 1 {
-                    let duration_str = parts[1];
-                    if let Some(total_hours) =
-                        crate::utils::time::parse_cleanup_duration(duration_str)
-                    {
-                        let label = crate::utils::time::format_cleanup_duration(total_hours);
-                        self.minibuffer.set_content(MinibufferContent::Input {
-                            prompt: format!(
-                                "Delete downloaded episodes older than {}? (y/n) ",
-                                label
-                            ),
-                            input: String::new(),
-                        });
-                        self.pending_cleanup_hours = Some(total_hours);
-                    } else {
-                        self.show_error(format!(
-                            "Invalid duration: '{}'. Use e.g., 7d, 2w, 1m, 12h",
-                            duration_str
-                        ));
-                    }
-                    Ok(true)
+            AppEvent::EpisodeMarkUnplayedFailed {
+                podcast_id,
+                episode_id: _,
+                error,
+            } => {
+                // Refresh buffers to revert optimistic UI update
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.refresh_history_if_open();
+                self.show_error(format!("Could not mark episode as unplayed: {}", error));
+            }
+            AppEvent::EpisodeFavoriteToggled {
+                podcast_id: _,
+                episode_id: _,
+                episode_title: _,
+                favorited: _,
+            } => {
+                // Optimistic update already applied; nothing further needed
+            }
+            AppEvent::EpisodeFavoriteToggleFailed {
+                podcast_id,
+                episode_id: _,
+                error,
+            } => {
+                // Refresh buffers to revert optimistic UI update
+                self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers { podcast_id });
+                self.trigger_background_refresh(BufferRefreshType::WhatsNew);
+                self.show_error(format!("Could not save favorite: {}", error));
+            }
+            AppEvent::DownloadsRefreshed => {
+                // Trigger background refresh of downloads buffer
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_message("Downloads refreshed".to_string());
+            }
+            AppEvent::AllDownloadsDeleted { deleted_count } => {
+                // Trigger background refresh of all episode buffers and downloads buffer
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_message(format!(
+                    "Successfully deleted {} downloaded episodes and cleaned up downloads folder",
+                    deleted_count
+                ));
+            }
+            AppEvent::AllDownloadsDeletionFailed { error } => {
+                // Still trigger background refresh of buffers in case some deletions succeeded
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_error(format!("Could not delete all downloads: {}", error));
+            }
+            AppEvent::OpmlImportStarted { source } => {
+                self.show_message(format!("Starting OPML import from: {}...", source));
+            }
+            AppEvent::OpmlImportProgress {
+                done,
+                failed,
+                total,
+                status,
+            } => {
+                if total == 0 {
+                    self.show_message(status);
                 } else {
-                    // Prompt for duration
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: "Delete downloads older than (e.g., 7d, 2w, 1m, 12h): ".to_string(),
-                        input: String::new(),
-                    });
-                    Ok(true)
+                    let remaining = total.saturating_sub(done + failed);
+                    self.show_message(format!(
+                        "{}  [{} done, {} failed, {} remaining - C-g to cancel]",
+                        status, done, failed, remaining
+                    ));
                 }
             }
-            "search" => {
-                if parts.len() > 1 {
-                    let query = parts[1..].join(" ");
-                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                        let result = current_buffer.handle_action(UIAction::ApplySearch { query });
-                        if let UIAction::ShowMessage(msg) = result {
-                            self.show_message(msg);
-                        }
-                    }
-                    Ok(true)
+            AppEvent::OpmlImportCompleted { result, log_path } => {
+                self.opml_import = None;
+                // Trigger background refresh of podcast list to show newly imported podcasts
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+
+                // Build summary message
+                let mut summary = if result.was_cancelled() {
+                    format!(
+                        "Import cancelled: {} imported, {} skipped, {} not attempted",
+                        result.imported, result.skipped, result.cancelled
+                    )
                 } else {
-                    // Open search prompt
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: "Search: ".to_string(),
-                        input: String::new(),
-                    });
-                    Ok(true)
+                    format!(
+                        "Import complete: {} imported, {} skipped",
+                        result.imported, result.skipped
+                    )
+                };
+
+                if result.has_failures() {
+                    summary.push_str(&format!(", {} failed", result.failed.len()));
+                    if result.failed_opml_path.is_some() {
+                        summary.push_str(" (M-i retries them)");
+                    }
+                    summary.push_str(&format!("\nSee log: {}", log_path));
                 }
+                self.failed_import_opml = result.failed_opml_path;
+
+                self.show_message(summary);
             }
-            "filter-status" => {
-                if parts.len() > 1 {
-                    let status = parts[1].to_string();
-                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                        let result =
-                            current_buffer.handle_action(UIAction::SetStatusFilter { status });
-                        match result {
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {}
-                        }
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error(
-                        "Usage: filter-status <status> (new, downloaded, played, downloading, failed, favorited)"
-                            .to_string(),
-                    );
-                    Ok(true)
-                }
-            }
-            "filter-date" => {
-                if parts.len() > 1 {
-                    let range = parts[1].to_string();
-                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                        let result =
-                            current_buffer.handle_action(UIAction::SetDateRangeFilter { range });
-                        match result {
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {}
-                        }
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error(
-                        "Usage: filter-date <range> (today, 12h, 7d, 2w, 1m)".to_string(),
-                    );
-                    Ok(true)
-                }
+            AppEvent::OpmlImportFailed { source: _, error } => {
+                self.opml_import = None;
+                self.show_error(format!("Could not import OPML: {}", error));
             }
-            "tag" => {
-                if parts.len() > 1 {
-                    let tag = parts[1..].join(" ");
-                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
-                    {
-                        let result = podcast_buffer.handle_action(UIAction::AddTag { tag });
-                        match result {
-                            UIAction::TriggerAddTag {
-                                podcast_id,
-                                podcast_title,
-                                tag,
-                            } => {
-                                self.show_message(format!(
-                                    "Tagged '{}' with \"{}\"",
-                                    podcast_title, tag
-                                ));
-                                self.trigger_async_add_tag(podcast_id, tag);
-                            }
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {
-                                self.show_error(
-                                    "Could not add tag. Is a podcast selected?".to_string(),
-                                );
-                            }
-                        }
-                    } else {
-                        self.show_error("Podcast list not available".to_string());
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error("Usage: tag <name>".to_string());
-                    Ok(true)
-                }
+            AppEvent::OpmlPreviewLoaded { preview } => {
+                let (total, new) = (
+                    preview.feeds.len(),
+                    preview.feeds.iter().filter(|feed| !feed.subscribed).count(),
+                );
+                self.buffer_manager.create_opml_preview_buffer(preview);
+                let _ = self
+                    .buffer_manager
+                    .switch_to_buffer(&"opml-preview".to_string());
+                self.update_status_bar();
+                self.refresh_buffer_list_if_open();
+                self.show_message(format!(
+                    "{} feeds, {} new — Space toggles, S-A imports the checked ones",
+                    total, new
+                ));
             }
-            "untag" => {
-                if parts.len() > 1 {
-                    let tag = parts[1..].join(" ");
-                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
-                    {
-                        let result = podcast_buffer.handle_action(UIAction::RemoveTag { tag });
-                        match result {
-                            UIAction::TriggerRemoveTag {
-                                podcast_id,
-                                podcast_title,
-                                tag,
-                            } => {
-                                self.show_message(format!(
-                                    "Removed tag \"{}\" from '{}'",
-                                    tag, podcast_title
-                                ));
-                                self.trigger_async_remove_tag(podcast_id, tag);
-                            }
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {
-                                self.show_error(
-                                    "Could not remove tag. Is a podcast selected?".to_string(),
-                                );
-                            }
-                        }
-                    } else {
-                        self.show_error("Podcast list not available".to_string());
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error("Usage: untag <name>".to_string());
-                    Ok(true)
-                }
+            AppEvent::OpmlPreviewFailed { source: _, error } => {
+                self.show_error(format!("Could not read OPML: {}", error));
             }
-            "tags" => {
-                // Collect all unique tags from in-memory podcast list
-                let tags: Vec<String> = if let Some(podcast_buffer) =
-                    self.buffer_manager.get_podcast_list_buffer_mut()
-                {
-                    let mut all_tags: Vec<String> = podcast_buffer
-                        .podcasts()
-                        .iter()
-                        .flat_map(|p| p.tags.iter().cloned())
-                        .collect();
-                    all_tags.sort_unstable();
-                    all_tags.dedup();
-                    all_tags
-                } else {
-                    Vec::new()
-                };
-
-                if tags.is_empty() {
-                    self.show_message(
-                        "No tags defined. Use :tag <name> to tag a podcast.".to_string(),
-                    );
-                } else {
-                    self.show_message(format!("Tags: {}", tags.join(", ")));
-                }
-                Ok(true)
+            AppEvent::OpmlExportStarted { path } => {
+                self.show_message(format!("Starting OPML export to: {}...", path));
             }
-            "filter-tag" => {
-                if parts.len() > 1 {
-                    let tag = parts[1..].join(" ");
-                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
-                    {
-                        let result = podcast_buffer
-                            .handle_action(UIAction::FilterByTag { tag: tag.clone() });
-                        match result {
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {}
-                        }
-                        // Switch to podcast list so user sees the filter applied
-                        let _ = self
-                            .buffer_manager
-                            .switch_to_buffer(&"podcast-list".to_string());
-                    } else {
-                        self.show_error("Podcast list not available".to_string());
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error("Usage: filter-tag <tag>".to_string());
-                    Ok(true)
-                }
+            AppEvent::OpmlExportProgress { status } => {
+                self.show_message(status);
             }
-            "clear-filters" | "widen" => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    current_buffer.handle_action(UIAction::ClearFilters);
-                }
-                self.show_message("Filters cleared".to_string());
-                Ok(true)
+            AppEvent::OpmlExportCompleted { path, feed_count } => {
+                self.show_message(format!(
+                    "Successfully exported {} feeds to {}",
+                    feed_count, path
+                ));
             }
-            "sort" => {
-                if parts.len() > 1 {
-                    let field = parts[1].to_string();
-                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                        let result = current_buffer.handle_action(UIAction::SetSort { field });
-                        match result {
-                            UIAction::ShowMessage(msg) => self.show_message(msg),
-                            UIAction::ShowError(msg) => self.show_error(msg),
-                            _ => {}
-                        }
-                    }
-                    Ok(true)
-                } else {
-                    self.show_error(
-                        "Usage: sort <field> (date, title, duration, downloaded)".to_string(),
-                    );
-                    Ok(true)
-                }
+            AppEvent::OpmlExportFailed { path: _, error } => {
+                self.show_error(format!("Could not export OPML: {}", error));
             }
-            "sort-asc" => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result = current_buffer.handle_action(UIAction::SetSortDirection {
-                        direction: "asc".to_string(),
-                    });
-                    match result {
-                        UIAction::ShowMessage(msg) => self.show_message(msg),
-                        UIAction::ShowError(msg) => self.show_error(msg),
-                        _ => {}
-                    }
-                }
-                Ok(true)
+            AppEvent::PlaylistCreated { playlist } => {
+                self.load_playlists_into_buffer().await;
+                self.show_message(format!("Playlist created: {}", playlist.name));
             }
-            "sort-desc" => {
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    let result = current_buffer.handle_action(UIAction::SetSortDirection {
-                        direction: "desc".to_string(),
-                    });
-                    match result {
-                        UIAction::ShowMessage(msg) => self.show_message(msg),
-                        UIAction::ShowError(msg) => self.show_error(msg),
-                        _ => {}
-                    }
-                }
-                Ok(true)
+            AppEvent::PlaylistCreationFailed { name, error } => {
+                self.show_error(format!("Could not create playlist '{}': {}", name, error));
             }
-            _ => {
-                self.show_error(format!("Unknown command: {}", parts[0]));
-                Ok(true)
+            AppEvent::PlaylistDeleted { name } => {
+                self.load_playlists_into_buffer().await;
+                self.show_message(format!("Playlist deleted: {}", name));
             }
-        }
-    }
-
-    /// Set the application theme
-    #[allow(dead_code)]
-    async fn set_theme(&mut self, theme_name: &str) -> UIResult<bool> {
-        match self.theme_registry.get(theme_name).cloned() {
-            Some(new_theme) => {
-                self.theme = new_theme.clone();
-                self.status_bar.set_theme(new_theme);
-                self.show_message(format!("Theme changed to: {}", theme_name));
-                Ok(true)
+            AppEvent::PlaylistDeletionFailed { name, error } => {
+                self.show_error(format!("Could not delete playlist '{}': {}", name, error));
             }
-            None => {
-                self.show_error(format!("Unknown theme: {}", theme_name));
-                Ok(true)
+            AppEvent::EpisodeAddedToPlaylist {
+                playlist_name,
+                episode_title,
+            } => {
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
+                    let _ = self
+                        .buffer_manager
+                        .remove_buffer(&"playlist-picker".to_string());
+                    self.update_status_bar();
+                }
+                self.show_message(format!(
+                    "Added '{}' to playlist '{}'",
+                    episode_title, playlist_name
+                ));
             }
-        }
-    }
+            AppEvent::EpisodeAddToPlaylistFailed {
+                playlist_name,
+                episode_title,
+                error,
+            } => {
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
+                    let _ = self
+                        .buffer_manager
+                        .remove_buffer(&"playlist-picker".to_string());
+                    self.update_status_bar();
+                }
+                self.show_error(format!(
+                    "Could not add '{}' to playlist '{}': {}",
+                    episode_title, playlist_name, error
+                ));
+            }
+            AppEvent::EpisodesAddedToPlaylist {
+                playlist_name,
+                result,
+            } => {
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
+                    let _ = self
+                        .buffer_manager
+                        .remove_buffer(&"playlist-picker".to_string());
+                    self.update_status_bar();
+                }
+                let mut message = format!(
+                    "Added {} episode{} to playlist '{}'",
+                    result.added,
+                    if result.added == 1 { "" } else { "s" },
+                    playlist_name
+                );
+                if result.already_present > 0 {
+                    message.push_str(&format!(", {} already in it", result.already_present));
+                }
+                if result.failed > 0 {
+                    message.push_str(&format!(", {} failed", result.failed));
+                    self.show_error(message);
+                } else {
+                    self.show_message(message);
+                }
+            }
+            AppEvent::EpisodesAddToPlaylistFailed {
+                playlist_name,
+                error,
+            } => {
+                if self.buffer_manager.current_buffer_id().as_deref() == Some("playlist-picker") {
+                    let _ = self
+                        .buffer_manager
+                        .remove_buffer(&"playlist-picker".to_string());
+                    self.update_status_bar();
+                }
+                self.show_error(format!(
+                    "Could not add episodes to playlist '{}': {}",
+                    playlist_name, error
+                ));
+            }
+            AppEvent::EpisodeRemovedFromPlaylist {
+                playlist_name,
+                episode_title,
+            } => {
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message(format!(
+                    "Removed '{}' from playlist '{}'",
+                    episode_title, playlist_name
+                ));
+            }
+            AppEvent::EpisodeRemoveFromPlaylistFailed {
+                playlist_name,
+                episode_title,
+                error,
+            } => {
+                self.show_error(format!(
+                    "Could not remove '{}' from playlist '{}': {}",
+                    episode_title, playlist_name, error
+                ));
+            }
+            AppEvent::PlaylistReordered {
+                name,
+                playlist_id,
+                entries,
+            } => {
+                // A playlist playing now continues in the new order
+                if let Some(queue) = self
+                    .play_queue
+                    .as_mut()
+                    .filter(|queue| queue.playlist_id() == &playlist_id)
+                {
+                    queue.reorder(entries);
+                }
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message(format!("Playlist reordered: {}", name));
+            }
+            AppEvent::PlaylistReorderFailed { name, error } => {
+                self.show_error(format!("Could not reorder playlist '{}': {}", name, error));
+            }
+            AppEvent::PlaylistRebuilt {
+                name,
+                rebuilt,
+                skipped,
+                failed,
+            } => {
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message(format!(
+                    "Playlist rebuilt '{}': {} rebuilt, {} skipped, {} failed",
+                    name, rebuilt, skipped, failed
+                ));
+            }
+            AppEvent::PlaylistRebuildFailed { name, error } => {
+                self.show_error(format!("Could not rebuild playlist '{}': {}", name, error));
+            }
+            AppEvent::TodayPlaylistRefreshed { added, removed } => {
+                self.load_playlists_into_buffer().await;
+                self.refresh_open_playlist_detail_buffers().await;
+                self.show_message(format!(
+                    "Today playlist refreshed: {} added, {} removed",
+                    added, removed
+                ));
+            }
+            AppEvent::TodayPlaylistRefreshFailed { error } => {
+                self.show_error(format!("Could not refresh Today playlist: {}", error));
+            }
+            AppEvent::DeviceSyncStarted {
+                device_path,
+                dry_run,
+                hard_sync,
+            } => {
+                let mode = if dry_run { " (dry run)" } else { "" };
+                let sync_kind = if hard_sync { "hard " } else { "" };
+                self.show_message(format!(
+                    "Starting {}device sync to {}{}...",
+                    sync_kind,
+                    device_path.display(),
+                    mode
+                ));
+            }
+            AppEvent::DeviceSyncCompleted {
+                device_path,
+                report,
+                dry_run,
+            } => {
+                if dry_run {
+                    // Enter dry-run preview mode so the user can review and confirm
+                    if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                        sync_buffer.enter_dry_run_preview(device_path.clone(), report.clone());
+                    }
+                    let mut summary = if report.is_success() {
+                        format!(
+                            "Dry run: {} to copy, {} to delete, {} skip — press Enter/s to confirm",
+                            report.files_copied.len(),
+                            report.files_deleted.len(),
+                            report.files_skipped.len()
+                        )
+                    } else {
+                        format!(
+                            "Dry run: {} to copy, {} to delete, {} skip ({} errors)",
+                            report.files_copied.len(),
+                            report.files_deleted.len(),
+                            report.files_skipped.len(),
+                            report.errors.len()
+                        )
+                    };
+                    if !report.files_protected.is_empty() {
+                        summary.push_str(&format!(
+                            " · {} not deleted (not copied by a sync)",
+                            report.files_protected.len()
+                        ));
+                    }
+                    self.show_message(summary);
+                } else {
+                    // Real sync completed — update history, return to overview
+                    if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                        sync_buffer.add_sync_result(device_path.clone(), report.clone(), dry_run);
+                        sync_buffer.reset_to_overview();
+                    }
+
+                    let mut summary = if report.is_success() {
+                        format!(
+                            "Sync complete: {} copied, {} deleted, {} skipped",
+                            report.files_copied.len(),
+                            report.files_deleted.len(),
+                            report.files_skipped.len()
+                        )
+                    } else {
+                        format!(
+                            "Sync complete with {} errors: {} copied, {} deleted, {} skipped",
+                            report.errors.len(),
+                            report.files_copied.len(),
+                            report.files_deleted.len(),
+                            report.files_skipped.len()
+                        )
+                    };
+                    if !report.files_protected.is_empty() {
+                        summary.push_str(&format!(
+                            " · {} not deleted (not copied by a sync)",
+                            report.files_protected.len()
+                        ));
+                    }
+                    self.load_playlists_into_buffer().await;
+                    self.show_message(summary);
+                }
+            }
+            AppEvent::DeviceSyncProgress { event } => {
+                if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                    let complete = sync_buffer.handle_progress_event(event);
+                    if complete {
+                        // Complete event will be followed by DeviceSyncCompleted; nothing extra needed
+                    }
+                }
+            }
+            AppEvent::DeviceSyncFailed {
+                device_path: _,
+                error,
+            } => {
+                // On failure, return sync buffer to Overview
+                if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                    // enter_progress_mode is only called for real syncs; reset to overview on error
+                    if sync_buffer.is_in_progress_mode() {
+                        sync_buffer.reset_to_overview();
+                    }
+                }
+                self.show_error(format!("Could not sync device: {}", error));
+            }
+            AppEvent::DownloadCleanupCompleted {
+                deleted_count,
+                duration_label,
+            } => {
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                if deleted_count > 0 {
+                    self.show_message(format!(
+                        "Cleaned up {} episode(s) older than {}",
+                        deleted_count, duration_label
+                    ));
+                } else {
+                    self.show_message(format!(
+                        "No downloaded episodes older than {}",
+                        duration_label
+                    ));
+                }
+            }
+            AppEvent::DownloadCleanupFailed { error } => {
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_error(format!("Could not clean up downloads: {}", error));
+            }
+            AppEvent::PrefetchEpisodes { episodes } => {
+                self.show_message(format!(
+                    "Prefetching {} upcoming episode(s)",
+                    episodes.len()
+                ));
+                for (podcast_id, episode_id) in episodes {
+                    self.trigger_async_download(podcast_id, episode_id);
+                }
+            }
+            AppEvent::DurationsBackfilled { filled, manual } => {
+                if filled > 0 {
+                    self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                    self.trigger_background_refresh(BufferRefreshType::Downloads);
+                    self.show_message(format!(
+                        "Filled in the duration of {} downloaded episode(s)",
+                        filled
+                    ));
+                } else if manual {
+                    self.show_message("No downloaded episodes are missing a duration".to_string());
+                }
+            }
+            AppEvent::DurationBackfillFailed { error } => {
+                self.show_error(format!("Could not backfill durations: {}", error));
+            }
+            AppEvent::FilenameCollisionsResolved { reset, ambiguous } => {
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                if ambiguous > 0 {
+                    self.show_error(format!(
+                        "{} download file(s) are shared by episodes with the same title; \
+                         delete and re-download them to separate the episodes",
+                        ambiguous
+                    ));
+                } else {
+                    self.show_message(format!(
+                        "{} episode(s) shared a download file with another episode and \
+                         were reset for re-download",
+                        reset
+                    ));
+                }
+            }
+            AppEvent::PodcastUserAgentSet {
+                podcast_title,
+                user_agent,
+            } => {
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                match user_agent {
+                    Some(user_agent) => self.show_message(format!(
+                        "'{}' now fetches as \"{}\"",
+                        podcast_title, user_agent
+                    )),
+                    None => self
+                        .show_message(format!("'{}' uses the default user agent", podcast_title)),
+                }
+            }
+            AppEvent::PodcastUserAgentSetFailed { error } => {
+                self.show_error(format!("Could not save user agent: {}", error));
+            }
+            AppEvent::PodcastAutoPlaySet {
+                podcast_title,
+                auto_play,
+            } => {
+                self.show_message(match auto_play {
+                    Some(true) => format!("'{}' auto-plays its next episode", podcast_title),
+                    Some(false) => format!("'{}' never auto-plays", podcast_title),
+                    None => format!(
+                        "'{}' follows audio.auto_play_next ({})",
+                        podcast_title,
+                        if self.config.audio.auto_play_next {
+                            "on"
+                        } else {
+                            "off"
+                        }
+                    ),
+                });
+            }
+            AppEvent::PodcastAutoPlaySetFailed { error } => {
+                self.show_error(format!("Could not save auto-play setting: {}", error));
+            }
+            AppEvent::PodcastQualitySet {
+                podcast_title,
+                quality,
+            } => {
+                self.show_message(format!(
+                    "'{}' downloads at {} quality from now on",
+                    podcast_title,
+                    quality.label()
+                ));
+                self.trigger_background_refresh(crate::ui::events::BufferRefreshType::PodcastList);
+            }
+            AppEvent::PodcastQualitySetFailed { error } => {
+                self.show_error(format!("Could not save download quality: {}", error));
+            }
+            AppEvent::PodcastSettingsSaved {
+                podcast_id,
+                podcast_title,
+                settings,
+            } => {
+                // The speed applies at once if the show is playing
+                let playing = self
+                    .buffer_manager
+                    .get_now_playing_buffer_mut()
+                    .map(|buffer| buffer.current_status())
+                    .filter(|status| status.state != PlaybackState::Stopped)
+                    .is_some_and(|status| status.podcast_id.as_ref() == Some(&podcast_id));
+                if playing {
+                    if let Some(ref tx) = self.audio_command_tx {
+                        let _ = tx.send(AudioCommand::SetSpeed(settings.speed()));
+                    }
+                }
+                if let Some(buffer) = self
+                    .buffer_manager
+                    .get_podcast_settings_buffer_mut()
+                    .filter(|buffer| *buffer.podcast_id() == podcast_id)
+                {
+                    buffer.set_settings(settings);
+                }
+                self.show_message(format!("Saved settings for '{}'", podcast_title));
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+            }
+            AppEvent::PodcastSettingsSaveFailed { error } => {
+                self.show_error(format!("Could not save podcast settings: {}", error));
+            }
+            AppEvent::RetentionCleanupCompleted { deleted_count } => {
+                self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                self.trigger_background_refresh(BufferRefreshType::Downloads);
+                self.show_message(format!(
+                    "Deleted {} download(s) under the retention rules",
+                    deleted_count
+                ));
+            }
+            AppEvent::CleanupPlanned { plan } => {
+                let message = if plan.is_empty() {
+                    "Cleanup would delete nothing".to_string()
+                } else {
+                    format!("Cleanup would delete {}", plan.summary())
+                };
+                let buffer_id = self.buffer_manager.create_cleanup_preview_buffer(plan);
+                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                self.update_status_bar();
+                self.refresh_buffer_list_if_open();
+                self.show_message(message);
+            }
+            AppEvent::PodcastsArchived {
+                titles,
+                archived,
+                failed,
+            } => {
+                let message = match (titles.as_slice(), archived) {
+                    ([title], true) => format!("Archived '{}'", title),
+                    ([title], false) => format!("Took '{}' out of the archive", title),
+                    (titles, true) => format!("Archived {} shows", titles.len()),
+                    (titles, false) => format!("Took {} shows out of the archive", titles.len()),
+                };
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                if failed > 0 {
+                    self.show_error(format!("{} ({} could not be saved)", message, failed));
+                } else {
+                    self.show_message(message);
+                }
+            }
+            AppEvent::SearchIndexBuilt { index } => {
+                self.search_index = Some(index);
+                if let Some(buffer) = self.buffer_manager.get_search_buffer_mut() {
+                    buffer.set_indexing(false);
+                    let query = buffer.query().to_string();
+                    self.run_episode_search(query);
+                }
+            }
+            AppEvent::QueueItemsLoaded { items } => {
+                if let Some(buffer) = self.buffer_manager.get_queue_buffer_mut() {
+                    buffer.set_items(items);
+                }
+            }
+            AppEvent::HistoryItemsLoaded { items } => {
+                if let Some(buffer) = self.buffer_manager.get_history_buffer_mut() {
+                    buffer.set_items(items);
+                }
+            }
+            AppEvent::QueueSaveFailed { error } => {
+                self.show_error(format!("Could not save the playback queue: {}", error));
+            }
+            AppEvent::PodcastTagAdded {
+                podcast_id: _,
+                tag: _,
+            } => {
+                // Optimistic update already applied; nothing further needed
+            }
+            AppEvent::PodcastTagAddFailed {
+                podcast_id: _,
+                error,
+            } => {
+                // Revert optimistic update by refreshing from storage
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                self.show_error(format!("Could not add tag: {}", error));
+            }
+            AppEvent::PodcastTagRemoved {
+                podcast_id: _,
+                tag: _,
+            } => {
+                // Optimistic update already applied; nothing further needed
+            }
+            AppEvent::PodcastTagRemoveFailed {
+                podcast_id: _,
+                error,
+            } => {
+                // Revert optimistic update by refreshing from storage
+                self.trigger_background_refresh(BufferRefreshType::PodcastList);
+                self.show_error(format!("Could not remove tag: {}", error));
+            }
+            AppEvent::SmartPlaylistEvaluated {
+                detail_buffer_id,
+                episodes,
+            } => {
+                let details = self.playlist_entry_details(&episodes).await;
+                let budget = self.config.playlist.duration_budget_minutes;
+                if let Some(detail_buffer) = self
+                    .buffer_manager
+                    .get_playlist_detail_buffer_mut_by_id(&detail_buffer_id)
+                {
+                    detail_buffer.set_evaluated_episodes(episodes);
+                    detail_buffer.set_entry_details(details);
+                    detail_buffer.set_duration_budget(budget);
+                }
+            }
+            AppEvent::SmartPlaylistEvaluationFailed {
+                playlist_name,
+                error,
+            } => {
+                self.show_error(format!(
+                    "Smart playlist '{}' failed: {}",
+                    playlist_name, error
+                ));
+            }
+            AppEvent::CommuteFilled { budget_secs, plan } => {
+                let budget = crate::utils::time::format_duration(budget_secs);
+                if plan.episodes.is_empty() {
+                    let mut message = format!("No downloaded, unplayed episodes fit in {}", budget);
+                    if plan.skipped_unknown_duration > 0 {
+                        message.push_str(&format!(
+                            " ({} skipped: length unknown)",
+                            plan.skipped_unknown_duration
+                        ));
+                    }
+                    self.show_error(message);
+                    return Ok(());
+                }
+                if self.audio_command_tx.is_none() {
+                    self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+                    return Ok(());
+                }
+                let entries = plan
+                    .episodes
+                    .iter()
+                    .map(|episode| QueueEntry {
+                        podcast_id: episode.podcast_id.clone(),
+                        episode_id: episode.id.clone(),
+                    })
+                    .collect();
+                let name = format!("Commute ({})", budget);
+                self.set_play_queue(Some(PlayQueue::temporary(name, entries)));
+                self.play_from_queue().await;
+                self.show_message(format!(
+                    "Commute: {} episode{}, {} of {}",
+                    plan.episodes.len(),
+                    if plan.episodes.len() == 1 { "" } else { "s" },
+                    crate::utils::time::format_duration(plan.total_secs),
+                    budget
+                ));
+            }
+            AppEvent::CommuteFillFailed { error } => {
+                self.show_error(format!("Commute failed: {}", error));
+            }
+            AppEvent::DiscoveryResultsLoaded {
+                buffer_id,
+                results,
+                title: _,
+            } => {
+                if let Some(buf) = self
+                    .buffer_manager
+                    .get_discovery_buffer_mut_by_id(&buffer_id)
+                {
+                    buf.set_results(results);
+                }
+                self.update_status_bar();
+            }
+            AppEvent::FeedCandidatesFound {
+                page_url,
+                candidates,
+            } => {
+                self.show_feed_candidates(page_url, candidates);
+            }
+            AppEvent::DiscoveryLoadFailed { buffer_id, error } => {
+                if let Some(buf) = self
+                    .buffer_manager
+                    .get_discovery_buffer_mut_by_id(&buffer_id)
+                {
+                    buf.set_error(error.clone());
+                }
+                self.show_error(format!("Discovery failed: {}", error));
+            }
+            AppEvent::PlaybackStarted {
+                podcast_id,
+                episode_id,
+            } => {
+                if self.config.downloads.prefetch_next > 0 {
+                    self.prefetch_after = Some((podcast_id.clone(), episode_id.clone()));
+                }
+                // Played some other way than from the queue: it needn't wait there
+                if self.queue.remove_episode(&episode_id) {
+                    self.queue_changed();
+                }
+                // Look up episode title and podcast name for the NowPlaying buffer.
+                let episode_title = self
+                    ._storage
+                    .load_episode(&podcast_id, &episode_id)
+                    .await
+                    .ok()
+                    .map(|ep| ep.display_title().to_string());
+                let podcast_name = self
+                    ._storage
+                    .load_podcast(&podcast_id)
+                    .await
+                    .ok()
+                    .map(|p| p.title);
+                self.status_bar.set_now_playing(episode_title.clone());
+                self.buffer_manager
+                    .set_now_playing_info(episode_title, podcast_name);
+                self.show_message("Now playing…".to_string());
+            }
+            AppEvent::PlaybackStopped => {
+                self.show_message("Playback stopped".to_string());
+            }
+            AppEvent::TrackEnded {
+                podcast_id,
+                episode_id,
+            } => {
+                // Persist completion: update position to full duration (auto-marks played at ≥95%).
+                match self._storage.load_episode(&podcast_id, &episode_id).await {
+                    Ok(mut episode) => {
+                        if let Some(duration) = episode.duration {
+                            episode.update_position(duration);
+                        } else {
+                            // No duration recorded — mark as played without position update.
+                            episode.mark_played();
+                        }
+                        if let Err(e) = self._storage.save_episode(&podcast_id, &episode).await {
+                            eprintln!("[audio] Failed to save episode after track end: {e}");
+                        }
+                        let record = listen_record(&episode, episode.duration.unwrap_or(0));
+                        if let Err(e) = self.listening_log.record(record).await {
+                            eprintln!("[stats] Failed to log finished episode: {e}");
+                        }
+                        self.refresh_dashboard_if_open();
+                        self.refresh_history_if_open();
+                        // Refresh episode buffers so played status is reflected immediately.
+                        self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
+                            podcast_id: podcast_id.clone(),
+                        });
+                        self.trigger_background_refresh(BufferRefreshType::AllEpisodeBuffers);
+                    }
+                    Err(e) => {
+                        eprintln!("[audio] Failed to load episode for track-end update: {e}");
+                    }
+                }
+                let from_queue = self
+                    .play_queue
+                    .as_ref()
+                    .and_then(|queue| queue.current())
+                    .is_some_and(|entry| entry.episode_id == episode_id);
+                if from_queue {
+                    self.play_next_in_queue().await;
+                } else if self.play_next_queued().await {
+                    // The playback queue had something lined up
+                } else if !self
+                    .start_auto_play_countdown(&podcast_id, &episode_id)
+                    .await
+                {
+                    self.show_message("Finished playing episode".to_string());
+                }
+            }
+            AppEvent::PlaybackError { error } => {
+                self.show_error(format!("Playback error: {}", error));
+            }
+            AppEvent::AudioStartupWarning { message } => {
+                self.show_error(message);
+            }
+            AppEvent::PlaybackFellBack { player, error } => {
+                self.show_message(format!(
+                    "{}; playing in {} instead (no seeking or pausing)",
+                    error, player
+                ));
+            }
+            AppEvent::AudioDevicesListed { devices, current } => {
+                if devices.is_empty() {
+                    self.show_message(
+                        "This audio backend cannot choose an output device".to_string(),
+                    );
+                } else {
+                    self.buffer_manager
+                        .create_audio_device_picker_buffer(devices, current);
+                    let _ = self
+                        .buffer_manager
+                        .switch_to_buffer(&"audio-device-picker".to_string());
+                    self.update_status_bar();
+                    self.refresh_buffer_list_if_open();
+                }
+            }
+            AppEvent::AudioDeviceChanged { device } => {
+                let label = device
+                    .clone()
+                    .unwrap_or_else(|| "system default".to_string());
+                self.persist_audio_device(device);
+                self.show_message(format!("Audio output: {}", label));
+            }
+            AppEvent::AudioDeviceSelectFailed { device, error } => {
+                self.show_error(format!(
+                    "Could not switch audio output to {}: {}",
+                    device, error
+                ));
+            }
+            AppEvent::AudioDeviceLost { device, error } => {
+                let device = device.unwrap_or_else(|| "Audio device".to_string());
+                match error {
+                    None => self.show_message(format!(
+                        "{} disconnected; playing through the system default",
+                        device
+                    )),
+                    Some(error) => self.show_error(format!(
+                        "{} disconnected and the default output failed: {}",
+                        device, error
+                    )),
+                }
+            }
+            AppEvent::CastTargetsDiscovered { targets } => {
+                if targets.is_empty() {
+                    self.show_message("No cast targets found on the local network".to_string());
+                } else {
+                    self.buffer_manager
+                        .create_cast_picker_buffer(targets, self.cast_target.clone());
+                    let _ = self
+                        .buffer_manager
+                        .switch_to_buffer(&"cast-picker".to_string());
+                    self.update_status_bar();
+                    self.refresh_buffer_list_if_open();
+                }
+            }
+            AppEvent::CastTargetChanged { target } => {
+                match target {
+                    Some(ref target) => self.show_message(format!("Casting to {}", target)),
+                    None if self.cast_target.is_some() => {
+                        self.show_message("Playing on this computer".to_string())
+                    }
+                    None => {}
+                }
+                self.cast_target = target;
+            }
+            AppEvent::CastFailed { target, error } => {
+                self.show_error(format!("Could not cast to {}: {}", target, error));
+            }
+            AppEvent::FeedSignInCode {
+                provider,
+                authorization,
+            } => {
+                let url = authorization
+                    .verification_uri_complete
+                    .unwrap_or(authorization.verification_uri);
+                self.show_message(format!(
+                    "Sign in to {}: open {} and enter code {}",
+                    provider, url, authorization.user_code
+                ));
+            }
+            AppEvent::FeedSignedIn { provider } => {
+                self.show_message(format!("Signed in to {}", provider));
+            }
+            AppEvent::FeedSignedOut {
+                provider,
+                was_signed_in,
+            } => {
+                if was_signed_in {
+                    self.show_message(format!("Signed out of {}", provider));
+                } else {
+                    self.show_message(format!("Not signed in to {}", provider));
+                }
+            }
+            AppEvent::FeedSignInFailed { provider, error } => {
+                self.show_error(format!("Could not sign in to {}: {}", provider, error));
+            }
+            AppEvent::ArchiveExported {
+                podcast_title,
+                summary,
+            } => {
+                self.show_message(format!(
+                    "Archived '{}' ({} episodes, {} downloaded) to {}",
+                    podcast_title,
+                    summary.episode_count,
+                    summary.downloaded_count,
+                    summary.index_path.display()
+                ));
+            }
+            AppEvent::ArchiveExportFailed { error } => {
+                self.show_error(format!("Archive export failed: {}", error));
+            }
+            AppEvent::CastConnectionLost { target } => {
+                self.show_error(format!(
+                    "Lost connection to {}; playing on this computer",
+                    target
+                ));
+            }
+            AppEvent::PodcastDetailLoaded { podcast, stats } => {
+                let buffer_id = self
+                    .buffer_manager
+                    .create_podcast_detail_buffer(podcast, stats);
+                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                self.update_status_bar();
+                self.refresh_buffer_list_if_open();
+            }
+            AppEvent::PodcastDetailLoadFailed { error } => {
+                self.show_error(format!("Failed to load podcast details: {}", error));
+            }
+            AppEvent::ReleasesChecked {
+                releases,
+                open_buffer,
+            } => {
+                let hint = releases
+                    .first()
+                    .map(|latest| format!("⬆ v{} available (:release-notes)", latest.version()))
+                    .unwrap_or_default();
+                self.status_bar.set_hint(hint);
+                if open_buffer {
+                    let buffer_id = self.buffer_manager.create_release_notes_buffer(
+                        crate::constants::updates::CURRENT_VERSION.to_string(),
+                        releases,
+                    );
+                    let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                    self.update_status_bar();
+                    self.refresh_buffer_list_if_open();
+                }
+            }
+            AppEvent::ReleaseCheckFailed { error, open_buffer } => {
+                // The startup check stays silent; only report failures the user asked for
+                if open_buffer {
+                    self.show_error(format!("Could not check for updates: {}", error));
+                }
+            }
+            AppEvent::PowerSourceChanged { source } => {
+                self.power_source = source;
+                self.status_bar.set_power_source(source);
+                if source == PowerSource::Ac && self.io_pause_override == Some(false) {
+                    self.io_pause_override = None;
+                }
+
+                let was_paused = self.download_manager.io_gate().is_paused();
+                self.apply_io_pause();
+                match (was_paused, self.download_manager.io_gate().is_paused()) {
+                    (false, true) => self.show_message(format!(
+                        "On {}: downloads and device sync paused (resume-downloads to override)",
+                        source.label()
+                    )),
+                    (true, false) => {
+                        self.show_message("Downloads and device sync resumed".to_string())
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute a command directly without recursion
+    fn execute_command_direct(&mut self, command: String) -> UIResult<bool> {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+        if parts.is_empty() {
+            return Ok(true);
+        }
+        // Only the command name: arguments can be URLs or search terms
+        self.note_for_crash_report(&format!(":{}", parts[0]));
+
+        if self.config.ui.simple_mode && !ui_constants::SIMPLE_MODE_COMMANDS.contains(&parts[0]) {
+            self.show_message(format!("'{}' is not available in simple mode", parts[0]));
+            return Ok(true);
+        }
+
+        match parts[0] {
+            "quit" | "q" => {
+                self.should_quit = true;
+                Ok(false)
+            }
+            "help" | "h" => {
+                let mut help_id = self
+                    .buffer_manager
+                    .find_buffer_id_by_name("*Help: Keybindings*");
+                if help_id.is_none() {
+                    let entries = self.key_handler.generate_help_text();
+                    self.buffer_manager.create_help_buffer(entries);
+                    help_id = self
+                        .buffer_manager
+                        .find_buffer_id_by_name("*Help: Keybindings*");
+                }
+                if let Some(id) = help_id {
+                    let _ = self.buffer_manager.switch_to_buffer(&id);
+                }
+                self.update_status_bar();
+                Ok(true)
+            }
+            "legend" => {
+                self.show_glyph_legend = true;
+                Ok(true)
+            }
+            "theme" => {
+                if parts.len() > 1 {
+                    self.set_theme_direct(parts[1])
+                } else {
+                    let names = self.theme_registry.list_names().join(", ");
+                    self.show_error(format!("Usage: theme <name> ({})", names));
+                    Ok(true)
+                }
+            }
+            "buffer" | "b" => {
+                if parts.len() > 1 {
+                    let buffer_name = parts[1].to_string();
+                    self.switch_to_buffer_by_name(buffer_name)
+                } else {
+                    self.show_buffer_list();
+                    Ok(true)
+                }
+            }
+            "switch-to-buffer" | "switch-buffer" => {
+                if parts.len() > 1 {
+                    let buffer_name = parts[1].to_string();
+                    self.switch_to_buffer_by_name(buffer_name)
+                } else {
+                    self.prompt_buffer_switch();
+                    Ok(true)
+                }
+            }
+            "list-buffers" | "buffers" => {
+                self.show_buffer_list();
+                Ok(true)
+            }
+            "workspace" | "ws" => {
+                let name = parts[1..].join(" ");
+                if name.is_empty() {
+                    let names = self.workspace_completions();
+                    self.minibuffer
+                        .show_prompt_with_completion(WORKSPACE_PROMPT.to_string(), names);
+                } else {
+                    self.switch_workspace(&name);
+                }
+                Ok(true)
+            }
+            "workspace-close" => {
+                let name = match parts[1..].join(" ") {
+                    name if name.is_empty() => {
+                        self.buffer_manager.current_workspace_name().to_string()
+                    }
+                    name => name,
+                };
+                match self.buffer_manager.close_workspace(&name) {
+                    Ok(closed) => {
+                        self.update_status_bar();
+                        self.refresh_buffer_list_if_open();
+                        self.show_message(format!(
+                            "Closed workspace '{}' and {} buffer(s) only it showed",
+                            name, closed
+                        ));
+                    }
+                    Err(e) => self.show_error(format!("Could not close workspace: {}", e)),
+                }
+                Ok(true)
+            }
+            "list-workspaces" | "workspaces" => {
+                let current = self.buffer_manager.current_workspace_name().to_string();
+                let list = self
+                    .buffer_manager
+                    .workspaces()
+                    .into_iter()
+                    .map(|(name, count)| {
+                        let marker = if name == current { "*" } else { "" };
+                        format!("{}{} ({})", marker, name, count)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.show_message(format!("Workspaces: {}", list));
+                Ok(true)
+            }
+            "unplayed" => {
+                self.open_unplayed_buffer();
+                Ok(true)
+            }
+            "alerts" => {
+                self.open_alerts_buffer();
+                Ok(true)
+            }
+            "queue" => {
+                self.open_queue_buffer();
+                Ok(true)
+            }
+            "history" => {
+                self.open_history_buffer();
+                Ok(true)
+            }
+            "reconnect" => {
+                self.reconnect();
+                Ok(true)
+            }
+            "search-all" | "search-episodes" => {
+                let query = parts[1..].join(" ");
+                self.open_episode_search((!query.is_empty()).then_some(query));
+                Ok(true)
+            }
+            "redownload" => {
+                let selected = self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|id| self.selected_episode_snapshot(&id));
+                match selected {
+                    Some(episode) if episode.updated_enclosure.is_some() => {
+                        self.show_message(format!(
+                            "Re-downloading updated audio of '{}'...",
+                            episode.display_title()
+                        ));
+                        self.trigger_async_redownload(episode.podcast_id, episode.id);
+                    }
+                    Some(episode) => self.show_error(format!(
+                        "The feed hasn't changed the audio of '{}' since it was downloaded",
+                        episode.display_title()
+                    )),
+                    None => self.show_message("No episode selected".to_string()),
+                }
+                Ok(true)
+            }
+            "search-person" => {
+                let name = parts[1..].join(" ");
+                if !name.is_empty() {
+                    self.open_episode_search(Some(name));
+                    return Ok(true);
+                }
+                let mut names = self.credited_people();
+                match names.len() {
+                    0 => self.show_error(
+                        "No one is credited here; use :search-person <name>".to_string(),
+                    ),
+                    1 => self.open_episode_search(names.pop()),
+                    _ => self
+                        .minibuffer
+                        .show_prompt_with_completion(FIND_PERSON_PROMPT.to_string(), names),
+                }
+                Ok(true)
+            }
+            "queue-clear" | "clear-queue" => {
+                let cleared = self.queue.len();
+                self.queue.clear();
+                self.queue_changed();
+                self.show_message(format!("Cleared {} queued episode(s)", cleared));
+                Ok(true)
+            }
+            "dashboard" | "goals" => {
+                self.open_dashboard_buffer();
+                Ok(true)
+            }
+            "alerts-clear" | "clear-alerts" => {
+                let cleared = std::mem::take(&mut self.alerts).len();
+                if let Some(buffer) = self.buffer_manager.get_alerts_buffer_mut() {
+                    buffer.set_alerts(&[]);
+                }
+                self.show_message(format!("Cleared {} alert(s)", cleared));
+                Ok(true)
+            }
+            "close-buffer" | "kill-buffer" => {
+                if parts.len() > 1 {
+                    let buffer_name = parts[1].to_string();
+                    self.close_buffer_by_name(buffer_name)
+                } else {
+                    // Close current buffer
+                    if let Some(current_id) = self.buffer_manager.current_buffer_id() {
+                        match self.buffer_manager.remove_buffer(&current_id) {
+                            Ok(_) => {
+                                self.update_status_bar();
+                                self.show_message(format!("Closed buffer: {}", current_id));
+                                Ok(true)
+                            }
+                            Err(e) => {
+                                self.show_error(format!("Cannot close buffer: {}", e));
+                                Ok(true)
+                            }
+                        }
+                    } else {
+                        self.show_message("No buffer to close".to_string());
+                        Ok(true)
+                    }
+                }
+            }
+            "add-podcast" => {
+                if parts.len() > 1 {
+                    let url = parts[1].to_string();
+                    self.show_message(format!("Adding podcast: {}...", url));
+                    self.trigger_async_add_podcast(url);
+                    Ok(true)
+                } else {
+                    self.show_error("Usage: add-podcast <URL>".to_string());
+                    Ok(true)
+                }
+            }
+            "podcast-info" | "timeline" => {
+                match self.podcast_id_for_current_buffer() {
+                    Some(podcast_id) => self.trigger_async_load_podcast_detail(podcast_id),
+                    None => self.show_error(
+                        "Select a podcast in the podcast or episode list first".to_string(),
+                    ),
+                }
+                Ok(true)
+            }
+            "refresh" => {
+                match parts.get(1).map(|arg| arg.trim_start_matches("--")) {
+                    None | Some("all") => {
+                        self.show_message("Refreshing all podcasts...".to_string());
+                        self.trigger_async_refresh_all();
+                    }
+                    Some("tag") => {
+                        let tag = parts[2..].join(" ").trim().to_lowercase();
+                        if tag.is_empty() {
+                            self.show_error("Usage: refresh --tag <tag>".to_string());
+                        } else {
+                            self.show_message(format!("Refreshing podcasts tagged \"{}\"...", tag));
+                            let scope = format!("tag: \"{}\"", tag);
+                            self.trigger_async_refresh(Some(scope), move |podcast| {
+                                podcast.has_tag(&tag)
+                            });
+                        }
+                    }
+                    Some("filtered") => self.refresh_filtered_podcasts(),
+                    Some(other) => self.show_error(format!(
+                        "Unknown refresh selection '{}' (expected all, --tag <tag>, --filtered)",
+                        other
+                    )),
+                }
+                Ok(true)
+            }
+            "hard-refresh" => {
+                let mut scope = HardRefreshScope::default();
+                let mut all = false;
+                for arg in &parts[1..] {
+                    if arg.eq_ignore_ascii_case("all") {
+                        all = true;
+                        continue;
+                    }
+                    match HardRefreshScope::parse(arg) {
+                        Some(parsed) => scope = parsed,
+                        None => {
+                            self.show_error(format!(
+                                "Unknown hard refresh scope '{}' (expected {})",
+                                arg,
+                                HardRefreshScope::NAMES
+                            ));
+                            return Ok(true);
+                        }
+                    }
+                }
+                if all {
+                    self.show_message(format!(
+                        "Hard refreshing all podcasts ({})...",
+                        scope.label()
+                    ));
+                    self.trigger_async_hard_refresh_all(scope);
+                } else if let Some(podcast_id) = self.podcast_id_for_current_buffer() {
+                    self.show_message(format!("Hard refreshing podcast ({})...", scope.label()));
+                    self.trigger_async_hard_refresh_single(podcast_id, scope);
+                } else {
+                    self.show_error(
+                        "Select a podcast first, or use 'hard-refresh [scope] all'".to_string(),
+                    );
+                }
+                Ok(true)
+            }
+            "refresh-diff" | "what-changed" => {
+                match self.podcast_id_for_current_buffer() {
+                    Some(podcast_id) => {
+                        match self.subscription_manager.last_refresh_diff(&podcast_id) {
+                            Some(diff) => {
+                                let buffer_id =
+                                    self.buffer_manager.create_refresh_diff_buffer(diff);
+                                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                                self.update_status_bar();
+                                self.refresh_buffer_list_if_open();
+                            }
+                            None => self.show_error(
+                                "This podcast hasn't been refreshed this session".to_string(),
+                            ),
+                        }
+                    }
+                    None => self.show_error(
+                        "Select a podcast in the podcast or episode list first".to_string(),
+                    ),
+                }
+                Ok(true)
+            }
+            "release-notes" => {
+                if self.config.updates.network_checks {
+                    self.show_message("Checking for newer releases...".to_string());
+                    self.trigger_async_release_check(true);
+                } else {
+                    self.show_error(
+                        "Update checks are disabled (updates.network_checks is false)".to_string(),
+                    );
+                }
+                Ok(true)
+            }
+            "view-raw" => {
+                let toggled = self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .filter(|id| id.starts_with("episode-detail-"))
+                    .and_then(|id| self.buffer_manager.get_episode_detail_buffer_mut_by_id(&id))
+                    .map(|detail| detail.toggle_raw());
+                match toggled {
+                    Some(true) => self.show_message("Showing raw show notes".to_string()),
+                    Some(false) => self.show_message("Showing folded show notes".to_string()),
+                    None => {
+                        self.show_error("view-raw works in an episode detail buffer".to_string())
+                    }
+                }
+                Ok(true)
+            }
+            "convert-episode" | "convert" => {
+                let target = match parts.get(1) {
+                    Some(format) => ConvertTarget::parse(format, parts.get(2).copied()),
+                    None => Err(format!(
+                        "Usage: convert-episode <format> [bitrate] (formats: {})",
+                        ConvertFormat::NAMES
+                    )),
+                };
+                let selection = self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id));
+                match (target, selection) {
+                    (Err(e), _) => self.show_error(e),
+                    (Ok(_), None) => {
+                        self.show_error("Select a downloaded episode to convert".to_string())
+                    }
+                    (Ok(target), Some((podcast_id, episode_id))) => {
+                        self.trigger_async_convert_episode(podcast_id, episode_id, target)
+                    }
+                }
+                Ok(true)
+            }
+            "transcribe" => {
+                match self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id))
+                {
+                    Some((podcast_id, episode_id)) => {
+                        self.trigger_async_transcribe_episode(podcast_id, episode_id, true)
+                    }
+                    None => {
+                        self.show_error("Select a downloaded episode to transcribe".to_string())
+                    }
+                }
+                Ok(true)
+            }
+            "translate" => {
+                if self.refuse_while_read_only("Translation") {
+                    return Ok(true);
+                }
+                match self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id))
+                {
+                    Some((podcast_id, episode_id)) => {
+                        self.trigger_async_translate_episode(podcast_id, episode_id)
+                    }
+                    None => self.show_error("Select an episode to translate".to_string()),
+                }
+                Ok(true)
+            }
+            "edit-episode" => {
+                let field = match parts.get(1) {
+                    Some(name) => EpisodeField::parse(name).ok_or_else(|| {
+                        format!(
+                            "Unknown field '{}' (expected {})",
+                            name,
+                            EpisodeField::NAMES
+                        )
+                    }),
+                    None => Err(format!(
+                        "Usage: edit-episode <field> [value] (fields: {})",
+                        EpisodeField::NAMES
+                    )),
+                };
+                let selection = self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id));
+                match (field, selection) {
+                    (Err(e), _) => self.show_error(e),
+                    (Ok(_), None) => self.show_error("Select an episode to edit".to_string()),
+                    (Ok(field), Some((podcast_id, episode_id))) if parts.len() > 2 => {
+                        let value = parts[2..].join(" ");
+                        self.trigger_async_edit_episode(
+                            podcast_id,
+                            episode_id,
+                            Some(field),
+                            Some(value),
+                        );
+                    }
+                    (Ok(field), Some((podcast_id, episode_id))) => {
+                        self.prompt_episode_edit(podcast_id, episode_id, field)
+                    }
+                }
+                Ok(true)
+            }
+            "share-podcast" => {
+                self.share_podcasts(parts.get(1) == Some(&"filtered"));
+                Ok(true)
+            }
+            "record-keys" => {
+                self.toggle_key_recording();
+                Ok(true)
+            }
+            "episode-note" | "note" => {
+                self.prompt_notes(true);
+                Ok(true)
+            }
+            "bookmark" => {
+                let label = (parts.len() > 1).then(|| parts[1..].join(" "));
+                self.add_bookmark(label);
+                Ok(true)
+            }
+            "export-bookmarks" => {
+                let formats = match parts.get(1) {
+                    None => Ok(BookmarkFormat::ALL.to_vec()),
+                    Some(name) => BookmarkFormat::parse(name).map(|f| vec![f]).ok_or_else(|| {
+                        format!(
+                            "Unknown format '{}' (expected {})",
+                            name,
+                            BookmarkFormat::NAMES
+                        )
+                    }),
+                };
+                let selection = self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id))
+                    .or_else(|| self.now_playing_episode());
+                match (formats, selection) {
+                    (Err(e), _) => self.show_error(e),
+                    (Ok(_), None) => self.show_error(
+                        "Select a downloaded episode to export its bookmarks".to_string(),
+                    ),
+                    (Ok(formats), Some((podcast_id, episode_id))) => {
+                        self.trigger_async_export_bookmarks(podcast_id, episode_id, formats)
+                    }
+                }
+                Ok(true)
+            }
+            "podcast-note" => {
+                self.prompt_notes(false);
+                Ok(true)
+            }
+            "revert-episode" => {
+                let field = match parts.get(1) {
+                    Some(name) => match EpisodeField::parse(name) {
+                        Some(field) => Some(field),
+                        None => {
+                            self.show_error(format!(
+                                "Unknown field '{}' (expected {})",
+                                name,
+                                EpisodeField::NAMES
+                            ));
+                            return Ok(true);
+                        }
+                    },
+                    None => None,
+                };
+                match self
+                    .buffer_manager
+                    .current_buffer_id()
+                    .and_then(|buffer_id| self.resolve_selected_episode(&buffer_id))
+                {
+                    Some((podcast_id, episode_id)) => {
+                        self.trigger_async_edit_episode(podcast_id, episode_id, field, None)
+                    }
+                    None => self.show_error("Select an episode to revert".to_string()),
+                }
+                Ok(true)
+            }
+            "pause-downloads" => {
+                self.io_pause_override = Some(true);
+                self.apply_io_pause();
+                self.show_message(
+                    "Downloads and device sync paused (resume-downloads to continue)".to_string(),
+                );
+                Ok(true)
+            }
+            "resume-downloads" => {
+                // Only an active battery pause needs overriding; otherwise go back to automatic
+                self.io_pause_override = self.battery_pause_active().then_some(false);
+                self.apply_io_pause();
+                self.show_message("Downloads and device sync resumed".to_string());
+                Ok(true)
+            }
+            "delete-all-downloads" | "clean-downloads" => {
+                // Show confirmation prompt for bulk deletion
+                self.minibuffer.set_content(MinibufferContent::Input {
+                    prompt: "Delete ALL downloaded episodes? This will remove all downloaded files! (y/n) ".to_string(),
+                    input: String::new(),
+                });
+                self.pending_bulk_deletion = true;
+                Ok(true)
+            }
+            "backfill-durations" => {
+                self.show_message(
+                    "Probing downloaded episodes for missing durations...".to_string(),
+                );
+                let download_manager = self.download_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+                tokio::spawn(async move {
+                    let event = match download_manager.backfill_durations().await {
+                        Ok(filled) => AppEvent::DurationsBackfilled {
+                            filled,
+                            manual: true,
+                        },
+                        Err(e) => AppEvent::DurationBackfillFailed {
+                            error: e.to_string(),
+                        },
+                    };
+                    let _ = app_event_tx.send(event);
+                });
+                Ok(true)
+            }
+            "import-opml" => {
+                if parts.len() > 1 {
+                    self.trigger_async_opml_preview(parts[1..].join(" "));
+                    Ok(true)
+                } else {
+                    // Prompt for file path/URL
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: "Import OPML from (file path or URL): ".to_string(),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "cancel-import" => {
+                self.cancel_opml_import();
+                Ok(true)
+            }
+            "opml-select" => {
+                let checked = match parts.get(1).copied() {
+                    Some("all") => true,
+                    Some("none") => false,
+                    _ => {
+                        self.show_error("Usage: opml-select all|none".to_string());
+                        return Ok(true);
+                    }
+                };
+                match self.buffer_manager.get_opml_preview_buffer_mut() {
+                    Some(buffer) => buffer.set_all(checked),
+                    None => self.show_error(
+                        "No OPML file is being previewed (import-opml opens one)".to_string(),
+                    ),
+                }
+                Ok(true)
+            }
+            "retry-failed-imports" => {
+                match self.failed_import_opml.clone() {
+                    Some(path) => {
+                        self.show_message(format!("Retrying failed imports from: {}...", path));
+                        self.trigger_async_opml_import(path);
+                    }
+                    None => self.show_error("No failed OPML imports to retry".to_string()),
+                }
+                Ok(true)
+            }
+            "select-audio-device" | "audio-device" => {
+                if parts.len() > 1 {
+                    let name = parts[1..].join(" ");
+                    let device = (name != "default").then_some(name);
+                    self.select_audio_device(device);
+                } else if let Some(ref tx) = self.audio_command_tx {
+                    let _ = tx.send(AudioCommand::ListOutputDevices);
+                } else {
+                    self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+                }
+                Ok(true)
+            }
+            "config-report" => {
+                self.open_config_report();
+                Ok(true)
+            }
+            "audio-diagnostics" => {
+                match self.audio_diagnostics.clone() {
+                    Some(diagnostics) => {
+                        let buffer_id = self
+                            .buffer_manager
+                            .create_audio_diagnostics_buffer(diagnostics);
+                        let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                        self.update_status_bar();
+                        self.refresh_buffer_list_if_open();
+                    }
+                    None => self.show_error("Audio has not been probed yet".to_string()),
+                }
+                Ok(true)
+            }
+            "cast" => {
+                if self.audio_command_tx.is_none() {
+                    self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+                } else {
+                    self.trigger_async_discover_cast_targets();
+                }
+                Ok(true)
+            }
+            "cast-stop" => {
+                self.select_cast_target(None);
+                Ok(true)
+            }
+            "mute" => {
+                match self.audio_command_tx {
+                    Some(ref tx) => {
+                        let _ = tx.send(AudioCommand::ToggleMute);
+                    }
+                    None => self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string()),
+                }
+                Ok(true)
+            }
+            "focus" => {
+                let minutes = match parts.get(1).copied() {
+                    None if self.focus_until.is_some() => None,
+                    None => Some(self.config.ui.focus_mode_minutes),
+                    Some("off") => None,
+                    Some(arg) => match arg.parse::<u32>() {
+                        Ok(minutes) if minutes > 0 => Some(minutes),
+                        _ => {
+                            self.show_error(format!("Usage: focus [minutes|off] (got '{}')", arg));
+                            return Ok(true);
+                        }
+                    },
+                };
+                self.set_focus_mode(minutes);
+                Ok(true)
+            }
+            "duck" | "unduck" => {
+                let command = match (parts[0], parts.get(1).copied()) {
+                    ("unduck", _) | (_, Some("off")) => AudioCommand::Duck(false),
+                    (_, Some("on")) => AudioCommand::Duck(true),
+                    (_, None) => AudioCommand::ToggleDuck,
+                    (_, Some(other)) => {
+                        self.show_error(format!("Usage: duck [on|off] (got '{}')", other));
+                        return Ok(true);
+                    }
+                };
+                match self.audio_command_tx {
+                    Some(ref tx) => {
+                        let _ = tx.send(command);
+                    }
+                    None => self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string()),
+                }
+                Ok(true)
+            }
+            "shuffle" => {
+                let shuffle = match parts.get(1).copied() {
+                    Some("on") => Some(true),
+                    Some("off") => Some(false),
+                    Some(other) => {
+                        self.show_error(format!("Usage: shuffle [on|off] (got '{}')", other));
+                        return Ok(true);
+                    }
+                    None => None,
+                };
+                self.update_playback_modes(|modes| {
+                    modes.shuffle = shuffle.unwrap_or(!modes.shuffle);
+                });
+                Ok(true)
+            }
+            "repeat" => {
+                let repeat = match parts.get(1) {
+                    Some(name) => match RepeatMode::parse(name) {
+                        Some(repeat) => Some(repeat),
+                        None => {
+                            self.show_error(format!(
+                                "Unknown repeat mode '{}' (expected {})",
+                                name,
+                                RepeatMode::NAMES
+                            ));
+                            return Ok(true);
+                        }
+                    },
+                    None => None,
+                };
+                self.update_playback_modes(|modes| {
+                    modes.repeat = repeat.unwrap_or(modes.repeat.next());
+                });
+                Ok(true)
+            }
+            "export-opml" => {
+                if parts.len() > 1 {
+                    let path = parts[1..].join(" ");
+                    self.trigger_async_opml_export(path);
+                    Ok(true)
+                } else {
+                    // Prompt for output path with default
+                    let default_path =
+                        shellexpand::tilde(&self.config.storage.opml_export_directory).to_string();
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: format!("Export to (default: {}): ", default_path),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "export-archive" => {
+                match parts.len() {
+                    0 | 1 => self.show_error("Usage: export-archive [podcast] <dir>".to_string()),
+                    2 => match self.podcast_id_for_current_buffer() {
+                        Some(podcast_id) => {
+                            self.trigger_async_archive_export(Ok(podcast_id), parts[1].to_string())
+                        }
+                        None => self.show_error("No podcast selected".to_string()),
+                    },
+                    n => self.trigger_async_archive_export(
+                        Err(parts[1..n - 1].join(" ")),
+                        parts[n - 1].to_string(),
+                    ),
+                }
+                Ok(true)
+            }
+            "export-settings" => {
+                if parts.len() > 1 {
+                    let path = shellexpand::tilde(&parts[1..].join(" ")).to_string();
+                    self.trigger_async_settings_export(path);
+                } else {
+                    self.show_error("Usage: export-settings <path>".to_string());
+                }
+                Ok(true)
+            }
+            "import-settings" => {
+                let with_paths = parts.get(1) == Some(&"--with-paths");
+                let args = if with_paths { &parts[2..] } else { &parts[1..] };
+                if !args.is_empty() {
+                    let path = shellexpand::tilde(&args.join(" ")).to_string();
+                    self.show_message(format!("Reading settings from {}...", path));
+                    self.trigger_async_settings_import_preview(path, with_paths);
+                } else {
+                    self.show_error("Usage: import-settings [--with-paths] <path>".to_string());
+                }
+                Ok(true)
+            }
+            "sync" | "sync-device" => {
+                let (device_path, hard_sync) = Self::parse_sync_command_args(&parts[1..]);
+                if let Some(device_path) = device_path {
+                    self.trigger_async_device_sync(device_path, false, false, hard_sync);
+                    Ok(true)
+                } else if hard_sync {
+                    let default_path = self.get_default_sync_path();
+                    self.trigger_async_device_sync(default_path, false, false, true);
+                    Ok(true)
+                } else {
+                    // Prompt for device path with default from config
+                    let default_path = self.get_default_sync_path();
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: format!("Sync to device path (default: {}): ", default_path),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "sync-dry-run" | "sync-preview" => {
+                let (device_path, hard_sync) = Self::parse_sync_command_args(&parts[1..]);
+                if let Some(device_path) = device_path {
+                    self.trigger_async_device_sync(device_path, false, true, hard_sync);
+                    Ok(true)
+                } else if hard_sync {
+                    let default_path = self.get_default_sync_path();
+                    self.trigger_async_device_sync(default_path, false, true, true);
+                    Ok(true)
+                } else {
+                    // Prompt for device path
+                    let default_path = self.get_default_sync_path();
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: format!("Dry run sync to (default: {}): ", default_path),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "playlists" => {
+                let list_id = "playlist-list".to_string();
+                if !self.buffer_manager.get_buffer_ids().contains(&list_id) {
+                    self.buffer_manager
+                        .create_playlist_list_buffer(self.playlist_manager.clone());
+                }
+                let _ = self.buffer_manager.switch_to_buffer(&list_id);
+                self.update_status_bar();
+                self.trigger_async_refresh_today();
+                Ok(true)
+            }
+            "playlist-create" | "playlist-new" => {
+                if parts.len() > 1 {
+                    let name = parts[1..].join(" ");
+                    self.trigger_async_create_playlist(name, None);
+                } else {
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: "Create playlist: ".to_string(),
+                        input: String::new(),
+                    });
+                }
+                Ok(true)
+            }
+            "smart-playlist" => {
+                if parts.len() < 2 {
+                    self.show_error(
+                        "Usage: :smart-playlist <name> [--filter <spec>...] [--sort <field>] [--limit <n>]".to_string(),
+                    );
+                    return Ok(true);
+                }
+                // Allow multi-word names: consume tokens until the first --flag
+                let flags_start_idx = parts[2..]
+                    .iter()
+                    .position(|p| p.starts_with("--"))
+                    .map(|i| i + 2)
+                    .unwrap_or(parts.len());
+                let name = parts[1..flags_start_idx].join(" ");
+                let args = &parts[flags_start_idx..];
+                match crate::ui::app::parse_smart_playlist_args(args) {
+                    Ok(rule) => self.trigger_async_create_smart_playlist(name, rule),
+                    Err(msg) => self.show_error(msg),
+                }
+                Ok(true)
+            }
+            "commute" => {
+                let configured = self
+                    .config
+                    .playlist
+                    .duration_budget_minutes
+                    .map(|minutes| minutes * 60);
+                let (budget, args) = match parts.get(1) {
+                    Some(arg) if !arg.starts_with("--") => {
+                        (crate::playlist::commute::parse_budget(arg), &parts[2..])
+                    }
+                    _ => (configured, &parts[1..]),
+                };
+                let Some(budget) = budget else {
+                    self.show_error(
+                        "Usage: :commute <45m|1h30m|1:15> [--filter <spec>...] [--sort <field>] [--per-podcast <n>]".to_string(),
+                    );
+                    return Ok(true);
+                };
+                match parse_commute_args(budget, args) {
+                    Ok(request) => self.trigger_async_fill_commute(request),
+                    Err(msg) => self.show_error(msg),
+                }
+                Ok(true)
+            }
+            "discover" | "search-podcasts" => {
+                if parts.len() < 2 {
+                    self.show_error("Usage: :discover <search term>".to_string());
+                    return Ok(true);
+                }
+                let query = parts[1..].join(" ");
+                let buffer_id = format!("discovery-{}", query.replace(' ', "-").to_lowercase());
+                let display_title = format!("Search: {}", query);
+                // Reuse existing buffer if query is the same, otherwise create fresh
+                if self.buffer_manager.get_buffer(&buffer_id).is_none() {
+                    self.buffer_manager
+                        .create_discovery_buffer(buffer_id.clone(), display_title.clone());
+                }
+                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                self.trigger_async_discover(query, buffer_id);
+                self.update_status_bar();
+                Ok(true)
+            }
+            "trending" => {
+                let buffer_id = "discovery-trending".to_string();
+                let display_title = "Trending".to_string();
+                if self.buffer_manager.get_buffer(&buffer_id).is_none() {
+                    self.buffer_manager
+                        .create_discovery_buffer(buffer_id.clone(), display_title.clone());
+                }
+                let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+                self.trigger_async_trending(buffer_id);
+                self.update_status_bar();
+                Ok(true)
+            }
+            "playlist-delete" => {
+                if parts.len() > 1 {
+                    let name = parts[1..].join(" ");
+                    self.trigger_async_delete_playlist_by_name(name);
+                } else {
+                    self.show_error("Usage: playlist-delete <name>".to_string());
+                }
+                Ok(true)
+            }
+            "playlist-refresh" => {
+                self.trigger_async_refresh_today();
+                Ok(true)
+            }
+            "playlist-sync" => {
+                let default_path = self.get_default_sync_path();
+                self.trigger_async_device_sync(default_path, false, false, false);
+                Ok(true)
+            }
+            "sync-target-playlist" => {
+                let default_path = std::path::PathBuf::from(self.get_default_sync_path());
+                let playlist = (parts.len() > 1).then(|| parts[1..].join(" "));
+                if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                    let target = sync_buffer.active_target().cloned().unwrap_or(default_path);
+                    sync_buffer.set_target_playlist(target.clone(), playlist.clone());
+                    match playlist {
+                        Some(name) => self.show_message(format!(
+                            "{} now syncs only playlist '{}'",
+                            target.display(),
+                            name
+                        )),
+                        None => self.show_message(format!(
+                            "{} now syncs the whole download library",
+                            target.display()
+                        )),
+                    }
+                }
+                Ok(true)
+            }
+            "clean-older-than" | "cleanup" => {
+                if parts.len() > 1 {
+                    let duration_str = parts[1];
+                    if let Some(total_hours) =
+                        crate::utils::time::parse_cleanup_duration(duration_str)
+                    {
+                        let label = crate::utils::time::format_cleanup_duration(total_hours);
+                        self.minibuffer.set_content(MinibufferContent::Input {
+                            prompt: format!(
+                                "Delete downloaded episodes older than {}? (y/n) ",
+                                label
+                            ),
+                            input: String::new(),
+                        });
+                        self.pending_cleanup_hours = Some(total_hours);
+                    } else {
+                        self.show_error(format!(
+                            "Invalid duration: '{}'. Use e.g., 7d, 2w, 1m, 12h",
+                            duration_str
+                        ));
+                    }
+                    Ok(true)
+                } else {
+                    // Prompt for duration
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: "Delete downloads older than (e.g., 7d, 2w, 1m, 12h): ".to_string(),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "cleanup-preview" | "cleanup-dry-run" => {
+                self.show_message("Checking downloads against the retention rules...".to_string());
+                self.trigger_async_cleanup_preview();
+                Ok(true)
+            }
+            "cleanup-now" => {
+                if !self.refuse_while_read_only("Cleanup") {
+                    self.show_message("Applying the retention rules...".to_string());
+                    self.trigger_retention_cleanup(true);
+                }
+                Ok(true)
+            }
+            "search" => {
+                if parts.len() > 1 {
+                    let query = parts[1..].join(" ");
+                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                        let result = current_buffer.handle_action(UIAction::ApplySearch { query });
+                        if let UIAction::ShowMessage(msg) = result {
+                            self.show_message(msg);
+                        }
+                    }
+                    Ok(true)
+                } else {
+                    // Open search prompt
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: "Search: ".to_string(),
+                        input: String::new(),
+                    });
+                    Ok(true)
+                }
+            }
+            "filter-status" => {
+                if parts.len() > 1 {
+                    let status = parts[1].to_string();
+                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                        let result =
+                            current_buffer.handle_action(UIAction::SetStatusFilter { status });
+                        match result {
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {}
+                        }
+                    }
+                    Ok(true)
+                } else {
+                    self.show_error(
+                        "Usage: filter-status <status> (new, downloaded, played, unplayed, in-progress, downloading, failed, archived, favorited)"
+                            .to_string(),
+                    );
+                    Ok(true)
+                }
+            }
+            "filter-date" => {
+                if parts.len() > 1 {
+                    let range = parts[1].to_string();
+                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                        let result =
+                            current_buffer.handle_action(UIAction::SetDateRangeFilter { range });
+                        match result {
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {}
+                        }
+                    }
+                    Ok(true)
+                } else {
+                    self.show_error(
+                        "Usage: filter-date <range> (today, 12h, 7d, 2w, 1m)".to_string(),
+                    );
+                    Ok(true)
+                }
+            }
+            "tag" => {
+                if parts.len() > 1 {
+                    let tag = parts[1..].join(" ");
+                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
+                    {
+                        let result = podcast_buffer.handle_action(UIAction::AddTag { tag });
+                        match result {
+                            UIAction::TriggerAddTag {
+                                podcast_id,
+                                podcast_title,
+                                tag,
+                            } => {
+                                self.show_message(format!(
+                                    "Tagged '{}' with \"{}\"",
+                                    podcast_title, tag
+                                ));
+                                self.trigger_async_add_tag(podcast_id, tag);
+                            }
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {
+                                self.show_error(
+                                    "Could not add tag. Is a podcast selected?".to_string(),
+                                );
+                            }
+                        }
+                    } else {
+                        self.show_error("Podcast list not available".to_string());
+                    }
+                    Ok(true)
+                } else {
+                    self.show_error("Usage: tag <name>".to_string());
+                    Ok(true)
+                }
+            }
+            "auth-login" => {
+                self.trigger_async_feed_sign_in(parts.get(1).copied());
+                Ok(true)
+            }
+            "auth-logout" => {
+                self.trigger_async_feed_sign_out(parts.get(1).copied());
+                Ok(true)
+            }
+            "user-agent" => {
+                self.set_podcast_user_agent(parts[1..].join(" "));
+                Ok(true)
+            }
+            "auto-play" => {
+                self.set_podcast_auto_play(parts.get(1).copied());
+                Ok(true)
+            }
+            "quality" => {
+                self.set_podcast_quality(parts.get(1).copied());
+                Ok(true)
+            }
+            "podcast-settings" => {
+                self.open_podcast_settings();
+                Ok(true)
+            }
+            "archive-ended" => {
+                self.prompt_archive_ended();
+                Ok(true)
+            }
+            "archive" | "unarchive" => {
+                let archive = parts[0] == "archive";
+                if self.refuse_while_read_only("Archiving") {
+                    return Ok(true);
+                }
+                match self
+                    .buffer_manager
+                    .get_podcast_list_buffer_mut()
+                    .and_then(|buffer| buffer.selected_podcast().cloned())
+                {
+                    Some(podcast) if podcast.is_archived() == archive => {
+                        self.show_message(if archive {
+                            format!("'{}' is already archived", podcast.title)
+                        } else {
+                            format!("'{}' isn't archived", podcast.title)
+                        })
+                    }
+                    Some(podcast) => self.trigger_async_set_archived(vec![podcast.id], archive),
+                    None => self.show_error("No podcast selected".to_string()),
+                }
+                Ok(true)
+            }
+            "archived" => {
+                match self.buffer_manager.get_podcast_list_buffer_mut() {
+                    Some(buffer) => {
+                        let message = if buffer.toggle_archived() {
+                            "Showing archived podcasts (:archived again or F6 to go back)"
+                        } else {
+                            "Showing active podcasts"
+                        };
+                        let _ = self
+                            .buffer_manager
+                            .switch_to_buffer(&"podcast-list".to_string());
+                        self.show_message(message.to_string());
+                    }
+                    None => self.show_error("Podcast list not available".to_string()),
+                }
+                Ok(true)
+            }
+            "untag" => {
+                if parts.len() > 1 {
+                    let tag = parts[1..].join(" ");
+                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
+                    {
+                        let result = podcast_buffer.handle_action(UIAction::RemoveTag { tag });
+                        match result {
+                            UIAction::TriggerRemoveTag {
+                                podcast_id,
+                                podcast_title,
+                                tag,
+                            } => {
+                                self.show_message(format!(
+                                    "Removed tag \"{}\" from '{}'",
+                                    tag, podcast_title
+                                ));
+                                self.trigger_async_remove_tag(podcast_id, tag);
+                            }
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {
+                                self.show_error(
+                                    "Could not remove tag. Is a podcast selected?".to_string(),
+                                );
+                            }
+                        }
+                    } else {
+                        self.show_error("Podcast list not available".to_string());
+                    }
+                    Ok(true)
+                } else {
+                    self.show_error("Usage: untag <name>".to_string());
+                    Ok(true)
+                }
+            }
+            "restore-podcast" => {
+                if parts.len() > 1 {
+                    let title = parts[1..].join(" ");
+                    self.show_message(format!("Restoring {}...", title));
+                    self.trigger_async_restore_podcast(title);
+                } else {
+                    self.trigger_async_list_removed_podcasts();
+                }
+                Ok(true)
+            }
+            "tags" => {
+                // Collect all unique tags from in-memory podcast list
+                let tags: Vec<String> = if let Some(podcast_buffer) =
+                    self.buffer_manager.get_podcast_list_buffer_mut()
+                {
+                    let mut all_tags: Vec<String> = podcast_buffer
+                        .podcasts()
+                        .iter()
+                        .flat_map(|p| p.tags.iter().cloned())
+                        .collect();
+                    all_tags.sort_unstable();
+                    all_tags.dedup();
+                    all_tags
+                } else {
+                    Vec::new()
+                };
+
+                if tags.is_empty() {
+                    self.show_message(
+                        "No tags defined. Use :tag <name> to tag a podcast.".to_string(),
+                    );
+                } else {
+                    self.show_message(format!("Tags: {}", tags.join(", ")));
+                }
+                Ok(true)
+            }
+            "filter-tag" => {
+                if parts.len() > 1 {
+                    let tag = parts[1..].join(" ");
+                    if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut()
+                    {
+                        let result = podcast_buffer
+                            .handle_action(UIAction::FilterByTag { tag: tag.clone() });
+                        match result {
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {}
+                        }
+                        // Switch to podcast list so user sees the filter applied
+                        let _ = self
+                            .buffer_manager
+                            .switch_to_buffer(&"podcast-list".to_string());
+                    } else {
+                        self.show_error("Podcast list not available".to_string());
+                    }
+                    Ok(true)
+                } else {
+                    self.show_error("Usage: filter-tag <tag>".to_string());
+                    Ok(true)
+                }
+            }
+            "clear-filters" | "widen" => {
+                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                    current_buffer.handle_action(UIAction::ClearFilters);
+                }
+                self.show_message("Filters cleared".to_string());
+                Ok(true)
+            }
+            "sort" => {
+                if parts.len() > 1 {
+                    let field = parts[1].to_string();
+                    if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                        let result = current_buffer.handle_action(UIAction::SetSort { field });
+                        match result {
+                            UIAction::ShowMessage(msg) => self.show_message(msg),
+                            UIAction::ShowError(msg) => self.show_error(msg),
+                            _ => {}
+                        }
+                    }
+                    self.persist_podcast_sort();
+                    Ok(true)
+                } else {
+                    self.show_error(
+                        "Usage: sort <field> (date, title, duration, downloaded)".to_string(),
+                    );
+                    Ok(true)
+                }
+            }
+            "sort-asc" => {
+                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                    let result = current_buffer.handle_action(UIAction::SetSortDirection {
+                        direction: "asc".to_string(),
+                    });
+                    match result {
+                        UIAction::ShowMessage(msg) => self.show_message(msg),
+                        UIAction::ShowError(msg) => self.show_error(msg),
+                        _ => {}
+                    }
+                }
+                Ok(true)
+            }
+            "sort-desc" => {
+                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                    let result = current_buffer.handle_action(UIAction::SetSortDirection {
+                        direction: "desc".to_string(),
+                    });
+                    match result {
+                        UIAction::ShowMessage(msg) => self.show_message(msg),
+                        UIAction::ShowError(msg) => self.show_error(msg),
+                        _ => {}
+                    }
+                }
+                Ok(true)
+            }
+            _ => {
+                self.show_error(format!("Unknown command: {}", parts[0]));
+                Ok(true)
+            }
+        }
+    }
+
+    /// Set the application theme
+    #[allow(dead_code)]
+    async fn set_theme(&mut self, theme_name: &str) -> UIResult<bool> {
+        let glyphs = GlyphSet::from_ascii_flag(self.config.ui.ascii_glyphs);
+        match self.theme_registry.get(theme_name).cloned() {
+            Some(new_theme) => {
+                let new_theme = new_theme
+                    .with_glyphs(glyphs)
+                    .with_color_blind_mode(self.config.ui.color_blind_mode)
+                    .with_time_zone(
+                        DisplayZone::parse(&self.config.ui.timezone).unwrap_or_default(),
+                    );
+                self.theme = new_theme.clone();
+                self.status_bar.set_theme(new_theme);
+                self.show_message(format!("Theme changed to: {}", theme_name));
+                Ok(true)
+            }
+            None => {
+                self.show_error(format!("Unknown theme: {}", theme_name));
+                Ok(true)
+            }
+        }
+    }
+
+    /// Set the application theme (direct version)
+    fn set_theme_direct(&mut self, theme_name: &str) -> UIResult<bool> {
+        let glyphs = GlyphSet::from_ascii_flag(self.config.ui.ascii_glyphs);
+        match self.theme_registry.get(theme_name).cloned() {
+            Some(new_theme) => {
+                let new_theme = new_theme
+                    .with_glyphs(glyphs)
+                    .with_color_blind_mode(self.config.ui.color_blind_mode)
+                    .with_time_zone(
+                        DisplayZone::parse(&self.config.ui.timezone).unwrap_or_default(),
+                    );
+                self.theme = new_theme.clone();
+                self.buffer_manager.set_theme_all(&new_theme);
+                self.minibuffer.set_theme(new_theme.clone());
+                self.status_bar.set_theme(new_theme);
+                self.show_message(format!("Theme changed to: {}", theme_name));
+                Ok(true)
+            }
+            None => {
+                self.show_error(format!("Unknown theme: {}", theme_name));
+                Ok(true)
+            }
+        }
+    }
+
+    /// Show list of available buffers
+    fn show_buffer_list(&mut self) {
+        use crate::ui::buffers::buffer_list::BufferListBuffer;
+
+        let buffer_infos = self.buffer_manager.buffer_infos();
+        let current_id = self.buffer_manager.current_buffer_id();
+
+        // Remove existing buffer list if it exists
+        let buffer_list_id = "*Buffer List*".to_string();
+        let _ = self.buffer_manager.remove_buffer(&buffer_list_id);
+
+        // Create new buffer list buffer
+        let mut buffer_list_buffer = BufferListBuffer::new();
+        buffer_list_buffer.set_theme(self.theme.clone());
+        buffer_list_buffer.update_buffer_list(buffer_infos, current_id.as_ref());
+
+        // Add and switch to buffer list buffer
+        if self
+            .buffer_manager
+            .add_buffer(Box::new(buffer_list_buffer))
+            .is_ok()
+        {
+            let _ = self.buffer_manager.switch_to_buffer(&buffer_list_id);
+        }
+
+        self.update_status_bar();
+    }
+
+    /// Switch to buffer by name with smart matching
+    fn switch_to_buffer_by_name(&mut self, buffer_name: String) -> UIResult<bool> {
+        let buffer_names = self.buffer_manager.buffer_names();
+
+        // Handle common aliases
+        let normalized_name = match buffer_name.as_str() {
+            "podcasts" | "podcast" | "main" => "podcast-list".to_string(),
+            "help" => "*Help: Keybindings*".to_string(),
+            "download" | "dl" => "downloads".to_string(),
+            "new" | "whats-new" | "latest" => "whats-new".to_string(),
+            "unplayed" | "backlog" => {
+                self.open_unplayed_buffer();
+                return Ok(true);
+            }
+            "sync" | "device-sync" => "sync".to_string(),
+            "playlist" | "playlists" => "playlist-list".to_string(),
+            _ => buffer_name.clone(),
+        };
+
+        // Try exact match first (by ID)
+        if buffer_names.iter().any(|(id, _)| id == &normalized_name) {
+            let _ = self.buffer_manager.switch_to_buffer(&normalized_name);
+            self.update_status_bar();
+            return Ok(true);
+        }
+
+        // Try exact match by display name
+        if let Some((id, _)) = buffer_names
+            .iter()
+            .find(|(_, name)| name == &normalized_name)
+        {
+            let _ = self.buffer_manager.switch_to_buffer(id);
+            self.update_status_bar();
+            return Ok(true);
+        }
+
+        // Try partial match (case insensitive)
+        let lower_search = normalized_name.to_lowercase();
+        let matches: Vec<_> = buffer_names
+            .iter()
+            .filter(|(id, name)| {
+                id.to_lowercase().contains(&lower_search)
+                    || name.to_lowercase().contains(&lower_search)
+            })
+            .collect();
+
+        match matches.len() {
+            0 => {
+                self.show_error(format!("No buffer found matching: {}", buffer_name));
+                Ok(true)
+            }
+            1 => {
+                let (id, _) = matches[0];
+                let _ = self.buffer_manager.switch_to_buffer(id);
+                self.update_status_bar();
+                Ok(true)
+            }
+            _ => {
+                let mut msg = format!("Multiple buffers match '{}':\n", buffer_name);
+                for (id, name) in matches {
+                    if id != name {
+                        msg.push_str(&format!("  {} ({})\n", name, id));
+                    } else {
+                        msg.push_str(&format!("  {}\n", name));
+                    }
+                }
+                self.show_message(msg);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Get all available commands for auto-completion
+    fn get_available_commands(&self) -> Vec<String> {
+        let mut commands = vec![
+            // Core commands
+            "quit".to_string(),
+            "q".to_string(),
+            "help".to_string(),
+            "h".to_string(),
+            "legend".to_string(),
+            "record-keys".to_string(),
+            "share-podcast".to_string(),
+            "share-podcast filtered".to_string(),
+            // Theme commands
+            "theme".to_string(),
+        ];
+        // Add one completion entry per registered theme name
+        for name in self.theme_registry.list_names() {
+            commands.push(format!("theme {}", name));
+        }
+        commands.extend([
+            // Buffer commands
+            "buffer".to_string(),
+            "b".to_string(),
+            "switch-to-buffer".to_string(),
+            "switch-buffer".to_string(),
+            "list-buffers".to_string(),
+            "buffers".to_string(),
+            "workspace".to_string(),
+            "ws".to_string(),
+            "workspace-close".to_string(),
+            "list-workspaces".to_string(),
+            "workspaces".to_string(),
+            "close-buffer".to_string(),
+            "kill-buffer".to_string(),
+            "unplayed".to_string(),
+            "alerts".to_string(),
+            "queue".to_string(),
+            "queue-clear".to_string(),
+            "history".to_string(),
+            "alerts-clear".to_string(),
+            "dashboard".to_string(),
+            "search-all".to_string(),
+            "search-person".to_string(),
+            "redownload".to_string(),
+            // Podcast commands
+            "reconnect".to_string(),
+            "add-podcast".to_string(),
+            "restore-podcast".to_string(),
+            "podcast-info".to_string(),
+            "refresh".to_string(),
+            "refresh --tag".to_string(),
+            "refresh --filtered".to_string(),
+            "hard-refresh".to_string(),
+            "hard-refresh metadata".to_string(),
+            "hard-refresh descriptions".to_string(),
+            "hard-refresh full".to_string(),
+            "hard-refresh all".to_string(),
+            "refresh-diff".to_string(),
+            "view-raw".to_string(),
+            "release-notes".to_string(),
+            "user-agent".to_string(),
+            "user-agent default".to_string(),
+            "auth-login".to_string(),
+            "auth-logout".to_string(),
+            "user-agent browser".to_string(),
+            "auto-play".to_string(),
+            "quality".to_string(),
+            "podcast-settings".to_string(),
+            "archive-ended".to_string(),
+            "archive".to_string(),
+            "unarchive".to_string(),
+            "archived".to_string(),
+            "auto-play on".to_string(),
+            "auto-play off".to_string(),
+            "auto-play default".to_string(),
+            // Downloads commands
+            "delete-all-downloads".to_string(),
+            "clean-downloads".to_string(),
+            "backfill-durations".to_string(),
+            "convert-episode".to_string(),
+            "convert-episode mp3".to_string(),
+            "convert-episode opus".to_string(),
+            "convert-episode m4a".to_string(),
+            "convert-episode ogg".to_string(),
+            "edit-episode title".to_string(),
+            "edit-episode description".to_string(),
+            "edit-episode artwork".to_string(),
+            "revert-episode".to_string(),
+            "episode-note".to_string(),
+            "podcast-note".to_string(),
+            "transcribe".to_string(),
+            "translate".to_string(),
+            "pause-downloads".to_string(),
+            "resume-downloads".to_string(),
+            // OPML commands
+            "import-opml".to_string(),
+            "cancel-import".to_string(),
+            "bookmark".to_string(),
+            "export-bookmarks".to_string(),
+            "retry-failed-imports".to_string(),
+            "opml-select".to_string(),
+            "export-opml".to_string(),
+            "export-archive".to_string(),
+            // Audio commands
+            "select-audio-device".to_string(),
+            "select-audio-device default".to_string(),
+            "audio-diagnostics".to_string(),
+            "config-report".to_string(),
+            "cast".to_string(),
+            "cast-stop".to_string(),
+            "mute".to_string(),
+            "duck".to_string(),
+            "duck on".to_string(),
+            "duck off".to_string(),
+            "unduck".to_string(),
+            "focus".to_string(),
+            "focus off".to_string(),
+            "shuffle".to_string(),
+            "shuffle on".to_string(),
+            "shuffle off".to_string(),
+            "repeat".to_string(),
+            "repeat off".to_string(),
+            "repeat all".to_string(),
+            "repeat one".to_string(),
+            // Settings bundle commands
+            "export-settings".to_string(),
+            "import-settings".to_string(),
+            // Sync commands
+            "sync".to_string(),
+            "sync-device".to_string(),
+            "sync-dry-run".to_string(),
+            "sync-preview".to_string(),
+            // Playlist commands
+            "playlists".to_string(),
+            "playlist-create".to_string(),
+            "playlist-new".to_string(),
+            "playlist-delete".to_string(),
+            "playlist-refresh".to_string(),
+            "playlist-sync".to_string(),
+            "sync-target-playlist".to_string(),
+            "smart-playlist".to_string(),
+            "commute".to_string(),
+            // Discovery commands
+            "discover".to_string(),
+            "search-podcasts".to_string(),
+            "trending".to_string(),
+            // Cleanup commands
+            "clean-older-than".to_string(),
+            "cleanup".to_string(),
+            "cleanup-preview".to_string(),
+            "cleanup-now".to_string(),
+            // Search & filter commands
+            "search".to_string(),
+            "filter-status".to_string(),
+            "filter-status new".to_string(),
+            "filter-status downloaded".to_string(),
+            "filter-status played".to_string(),
+            "filter-status unplayed".to_string(),
+            "filter-status in-progress".to_string(),
+            "filter-status downloading".to_string(),
+            "filter-status failed".to_string(),
+            "filter-status archived".to_string(),
+            "filter-status favorited".to_string(),
+            "filter-date".to_string(),
+            "filter-date today".to_string(),
+            "filter-date 1d".to_string(),
+            "filter-date 7d".to_string(),
+            "filter-date 2w".to_string(),
+            "filter-date 1m".to_string(),
+            "clear-filters".to_string(),
+            "widen".to_string(),
+            // Sort commands
+            "sort".to_string(),
+            "sort date".to_string(),
+            "sort title".to_string(),
+            "sort duration".to_string(),
+            "sort downloaded".to_string(),
+            "sort-asc".to_string(),
+            "sort-desc".to_string(),
+        ]);
+        if self.config.ui.simple_mode {
+            commands.retain(|command| {
+                let name = command.split_whitespace().next().unwrap_or_default();
+                ui_constants::SIMPLE_MODE_COMMANDS.contains(&name)
+            });
+        }
+        commands
+    }
+
+    /// Update completion mode based on the current input context
+    fn update_completion_mode_for_input(&mut self, input: &str) {
+        let input_lower = input.to_lowercase();
+
+        // Check if we're typing a buffer-related command that needs buffer name completion
+        if input_lower.starts_with("switch-to-buffer ")
+            || input_lower.starts_with("switch-buffer ")
+            || input_lower.starts_with("buffer ")
+            || input_lower.starts_with("b ")
+            || input_lower.starts_with("close-buffer ")
+            || input_lower.starts_with("kill-buffer ")
+        {
+            // Get the command part and the buffer name part
+            let parts: Vec<&str> = input.split_whitespace().collect();
+            if !parts.is_empty() {
+                let command_part = parts[0];
+
+                // Get available buffer names (just the names)
+                let buffer_names = self.buffer_manager.buffer_completion_names();
+                let mut buffer_completions = Vec::new();
+
+                // If there's already a space, we're completing buffer names
+                if input.contains(' ') {
+                    let buffer_search = if parts.len() > 1 {
+                        parts[1..].join(" ").to_lowercase()
+                    } else {
+                        String::new()
+                    };
+
+                    for name in &buffer_names {
+                        if buffer_search.is_empty()
+                            || name.to_lowercase().starts_with(&buffer_search)
+                        {
+                            buffer_completions.push(format!("{} {}", command_part, name));
+                        }
+                    }
+                } else {
+                    // Still typing the command, offer command completion + buffer suggestions
+                    buffer_completions.push(format!("{} ", command_part));
+                    for name in &buffer_names {
+                        buffer_completions.push(format!("{} {}", command_part, name));
+                    }
+                }
+
+                // Sort the completions
+                buffer_completions.sort();
+
+                // Update the minibuffer with buffer name completions
+                if !buffer_completions.is_empty() {
+                    // Update the completion candidates to be buffer names instead of all commands
+                    self.minibuffer
+                        .set_completion_candidates(buffer_completions.clone());
+
+                    // Convert to PromptWithCompletion mode if not already
+                    if let Some(current_input) = self.minibuffer.current_input() {
+                        let cursor_pos = current_input.len();
+                        self.minibuffer
+                            .set_content(MinibufferContent::PromptWithCompletion {
+                                prompt: "M-x ".to_string(),
+                                input: current_input,
+                                cursor_pos,
+                                completions: buffer_completions,
+                                completion_index: None,
+                            });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get contextual command completions based on current input
+    fn get_contextual_command_completions(&self, input: &str) -> Vec<String> {
+        let input_lower = input.to_lowercase();
+        let mut completions = Vec::new();
+
+        // Basic command completion - match from start
+        let base_commands = self.get_available_commands();
+        for cmd in &base_commands {
+            if cmd.to_lowercase().starts_with(&input_lower) {
+                completions.push(cmd.clone());
+            }
+        }
+
+        // Add contextual completions for specific command patterns
+        if let Some(theme_part) = input_lower.strip_prefix("theme ") {
+            for name in self.theme_registry.list_names() {
+                if name.starts_with(theme_part) {
+                    completions.push(format!("theme {}", name));
+                }
+            }
+        } else if input_lower.starts_with("buffer ") || input_lower.starts_with("b ") {
+            // Add buffer names as completions
+            let buffer_names = self.buffer_manager.buffer_names();
+            let prefix = if input_lower.starts_with("buffer ") {
+                "buffer "
+            } else {
+                "b "
+            };
+            let search_term = &input_lower[prefix.len()..];
+
+            for (_, name) in &buffer_names {
+                if name.to_lowercase().starts_with(search_term) {
+                    completions.push(format!("{}{}", prefix, name));
+                }
+            }
+        } else if input_lower.starts_with("switch-to-buffer ")
+            || input_lower.starts_with("switch-buffer ")
+        {
+            // Add buffer names as completions for switch commands
+            let buffer_names = self.buffer_manager.buffer_names();
+            let prefix = if input_lower.starts_with("switch-to-buffer ") {
+                "switch-to-buffer "
+            } else {
+                "switch-buffer "
+            };
+            let search_term = &input_lower[prefix.len()..];
+
+            for (_, name) in &buffer_names {
+                if name.to_lowercase().starts_with(search_term) {
+                    completions.push(format!("{}{}", prefix, name));
+                }
+            }
+        } else if input_lower.starts_with("close-buffer ")
+            || input_lower.starts_with("kill-buffer ")
+        {
+            // Add buffer names as completions for close commands
+            let buffer_names = self.buffer_manager.buffer_names();
+            let prefix = if input_lower.starts_with("close-buffer ") {
+                "close-buffer "
+            } else {
+                "kill-buffer "
+            };
+            let search_term = &input_lower[prefix.len()..];
+
+            for (_, name) in &buffer_names {
+                if name.to_lowercase().starts_with(search_term) {
+                    completions.push(format!("{}{}", prefix, name));
+                }
+            }
+        } else if input_lower.starts_with("add-podcast ") {
+            // For add-podcast, we could suggest common podcast URLs or show a hint
+            if input_lower == "add-podcast " {
+                completions.push("add-podcast https://".to_string());
+            }
+        }
+
+        // Remove duplicates and sort
+        completions.sort();
+        completions.dedup();
+
+        // If no specific matches, fall back to all commands that contain the input
+        if completions.is_empty() && !input.is_empty() {
+            for cmd in &base_commands {
+                if cmd.to_lowercase().contains(&input_lower) {
+                    completions.push(cmd.clone());
+                }
+            }
+        }
+
+        completions
+    }
+
+    /// Show command prompt with auto-completion
+    fn show_command_prompt_with_completion(&mut self) {
+        let commands = self.get_available_commands();
+        let prompt = "M-x ".to_string();
+        self.minibuffer
+            .show_prompt_with_completion(prompt, commands);
+    }
+
+    /// Prompt for buffer switch with completion hints
+    fn prompt_buffer_switch(&mut self) {
+        let buffer_names = self.buffer_manager.buffer_names();
+
+        // Create completion candidates from buffer names
+        let completions: Vec<String> = buffer_names
+            .iter()
+            .map(|(id, name)| {
+                // Prefer display name, but include ID as fallback
+                if id != name {
+                    name.clone()
+                } else {
+                    id.clone()
+                }
+            })
+            .collect();
+
+        let prompt = "Switch to buffer: ".to_string();
+        self.minibuffer
+            .show_prompt_with_completion(prompt, completions);
+    }
+
+    /// Close buffer by name with smart matching
+    fn close_buffer_by_name(&mut self, buffer_name: String) -> UIResult<bool> {
+        let buffer_names = self.buffer_manager.buffer_names();
+
+        // Try exact match first (by ID)
+        if buffer_names.iter().any(|(id, _)| id == &buffer_name) {
+            match self.buffer_manager.remove_buffer(&buffer_name) {
+                Ok(_) => {
+                    self.update_status_bar();
+                    self.show_message(format!("Closed buffer: {}", buffer_name));
+                    return Ok(true);
+                }
+                Err(e) => {
+                    self.show_error(format!("Cannot close buffer: {}", e));
+                    return Ok(true);
+                }
+            }
+        }
+
+        // Try exact match by display name
+        if let Some((id, _)) = buffer_names.iter().find(|(_, name)| name == &buffer_name) {
+            match self.buffer_manager.remove_buffer(id) {
+                Ok(_) => {
+                    self.update_status_bar();
+                    self.show_message(format!("Closed buffer: {}", buffer_name));
+                    return Ok(true);
+                }
+                Err(e) => {
+                    self.show_error(format!("Cannot close buffer: {}", e));
+                    return Ok(true);
+                }
+            }
+        }
+
+        // Try partial match (case insensitive)
+        let lower_search = buffer_name.to_lowercase();
+        let matches: Vec<_> = buffer_names
+            .iter()
+            .filter(|(id, name)| {
+                id.to_lowercase().contains(&lower_search)
+                    || name.to_lowercase().contains(&lower_search)
+            })
+            .collect();
+
+        match matches.len() {
+            0 => {
+                self.show_error(format!("No buffer found matching: {}", buffer_name));
+                Ok(true)
+            }
+            1 => {
+                let (id, _) = matches[0];
+                match self.buffer_manager.remove_buffer(id) {
+                    Ok(_) => {
+                        self.update_status_bar();
+                        self.show_message(format!("Closed buffer: {}", buffer_name));
+                        Ok(true)
+                    }
+                    Err(e) => {
+                        self.show_error(format!("Cannot close buffer: {}", e));
+                        Ok(true)
+                    }
+                }
+            }
+            _ => {
+                let mut msg = format!("Multiple buffers match '{}':\n", buffer_name);
+                for (id, name) in matches {
+                    if id != name {
+                        msg.push_str(&format!("  {} ({})\n", name, id));
+                    } else {
+                        msg.push_str(&format!("  {}\n", name));
+                    }
+                }
+                msg.push_str("\nSpecify the exact buffer name to close.");
+                self.show_message(msg);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Show a message in the minibuffer, unless focus mode hides it
+    fn show_message(&mut self, message: String) {
+        if self.focus_active() {
+            return;
+        }
+        self.minibuffer
+            .set_content(MinibufferContent::Message(message));
+        self.status_bar.clear_status_message();
+    }
+
+    /// Show an error in the minibuffer
+    fn show_error(&mut self, error: String) {
+        self.minibuffer.set_content(MinibufferContent::Error(error));
+        self.status_bar.clear_status_message();
+    }
+
+    /// Update the status bar with current state
+    fn update_status_bar(&mut self) {
+        if let Some(buffer_name) = self.buffer_manager.current_buffer_name() {
+            // The workspace is named once there is more than one
+            let buffer_name = if self.buffer_manager.workspaces().len() > 1 {
+                format!(
+                    "[{}] {}",
+                    self.buffer_manager.current_workspace_name(),
+                    buffer_name
+                )
+            } else {
+                buffer_name
+            };
+            self.status_bar.set_buffer_name(buffer_name);
+        }
+    }
+
+    /// Show workspace `name`, creating it (with the podcast list) when new
+    fn switch_workspace(&mut self, name: &str) {
+        match self
+            .buffer_manager
+            .switch_workspace(name, &"podcast-list".to_string())
+        {
+            Ok(created) => {
+                self.update_status_bar();
+                self.refresh_buffer_list_if_open();
+                if created {
+                    self.show_message(format!(
+                        "New workspace '{}': buffers you open join it",
+                        name.trim()
+                    ));
+                } else {
+                    self.show_message(format!("Workspace '{}'", name.trim()));
+                }
+            }
+            Err(e) => self.show_error(format!("Could not switch workspace: {}", e)),
+        }
+    }
+
+    /// Workspace names for completion, the current one last
+    fn workspace_completions(&self) -> Vec<String> {
+        let current = self.buffer_manager.current_workspace_name().to_string();
+        let mut names: Vec<String> = self
+            .buffer_manager
+            .workspaces()
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| *name != current)
+            .collect();
+        names.push(current);
+        names
+    }
+
+    /// Record new keyword alerts (an episode alerts once per session), update
+    /// the Alerts buffer, and announce them
+    fn raise_keyword_alerts(&mut self, alerts: Vec<KeywordAlert>) {
+        let mut raised = Vec::new();
+        for alert in alerts {
+            if !self
+                .alerts
+                .iter()
+                .any(|seen| seen.episode.id == alert.episode.id)
+            {
+                self.alerts.push(alert.clone());
+                raised.push(alert);
+            }
+        }
+        let Some(first) = raised.first() else {
+            return;
+        };
+
+        if let Some(buffer) = self.buffer_manager.get_alerts_buffer_mut() {
+            buffer.set_alerts(&self.alerts);
+        }
+        let summary = if raised.len() == 1 {
+            format!(
+                "Alert: '{}' mentions \"{}\" (:alerts)",
+                first.episode.display_title(),
+                first.keyword
+            )
+        } else {
+            format!(
+                "{} keyword alerts, e.g. '{}' mentions \"{}\" (:alerts)",
+                raised.len(),
+                first.episode.display_title(),
+                first.keyword
+            )
+        };
+        self.show_message(summary);
+
+        if self.config.alerts.notify && !self.focus_active() {
+            let body = raised
+                .iter()
+                .map(|alert| {
+                    format!(
+                        "{}: {} [{}]",
+                        alert.podcast_title,
+                        alert.episode.display_title(),
+                        alert.keyword
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            tokio::spawn(async move {
+                crate::utils::notify::send("Podcast keyword alert", &body).await;
+            });
+        }
+    }
+
+    /// Compute listening statistics and goal progress in the background
+    fn trigger_async_goal_evaluation(&mut self, remind: bool) {
+        let subscription_manager = self.subscription_manager.clone();
+        let listening_log = self.listening_log.clone();
+        let goals = self.listening_goals.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let now = chrono::Local::now();
+            let week_start = GoalPeriod::Week.start(&now);
+            let since = goals
+                .iter()
+                .map(|goal| goal.period.start(&now))
+                .chain(std::iter::once(week_start))
+                .min()
+                .unwrap_or(week_start);
+            let records = listening_log
+                .since(since.with_timezone(&chrono::Utc))
+                .await
+                .unwrap_or_default();
+
+            let storage = &subscription_manager.storage;
+            let mut episodes = Vec::new();
+            if let Ok(podcasts) = subscription_manager.list_subscriptions().await {
+                for podcast in podcasts {
+                    if let Ok(loaded) = storage.load_episodes(&podcast.id).await {
+                        episodes.extend(loaded);
+                    }
+                }
+            }
+
+            let stats = ListeningStats::compute(
+                &records,
+                &episodes,
+                week_start.with_timezone(&chrono::Utc),
+            );
+            let progress = goals
+                .iter()
+                .map(|goal| goal.evaluate(&records, &episodes, &now))
+                .collect();
+            let _ = app_event_tx.send(AppEvent::ListeningGoalsEvaluated {
+                stats,
+                progress,
+                remind,
+            });
+        });
+    }
+
+    /// Recompute the dashboard after listening activity, when it is open
+    fn refresh_dashboard_if_open(&mut self) {
+        if self.buffer_manager.get_dashboard_buffer_mut().is_some() {
+            self.trigger_async_goal_evaluation(false);
+        }
+    }
+
+    /// Open (creating on first use) the Dashboard buffer and recompute it
+    fn open_dashboard_buffer(&mut self) {
+        if self.buffer_manager.get_dashboard_buffer_mut().is_none() {
+            self.buffer_manager.create_dashboard_buffer();
+        }
+        let _ = self
+            .buffer_manager
+            .switch_to_buffer(&"dashboard".to_string());
+        self.trigger_async_goal_evaluation(false);
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    /// Open (creating on first use) the Alerts buffer
+    fn open_alerts_buffer(&mut self) {
+        if self.buffer_manager.get_alerts_buffer_mut().is_none() {
+            self.buffer_manager.create_alerts_buffer();
+        }
+        if let Some(buffer) = self.buffer_manager.get_alerts_buffer_mut() {
+            buffer.set_alerts(&self.alerts);
+        }
+        let _ = self.buffer_manager.switch_to_buffer(&"alerts".to_string());
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    /// Open (creating on first use) the History buffer
+    fn open_history_buffer(&mut self) {
+        if self.buffer_manager.get_history_buffer_mut().is_none() {
+            self.buffer_manager.create_history_buffer();
+        }
+        self.trigger_async_history_refresh();
+        let _ = self.buffer_manager.switch_to_buffer(&"history".to_string());
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    fn refresh_history_if_open(&mut self) {
+        if self.buffer_manager.get_history_buffer_mut().is_some() {
+            self.trigger_async_history_refresh();
+        }
+    }
+
+    /// Collect the played episodes of every subscription for the History
+    /// buffer
+    fn trigger_async_history_refresh(&mut self) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let mut items = Vec::new();
+            for podcast_id in storage.list_podcasts().await.unwrap_or_default() {
+                let Ok(podcast) = storage.load_podcast(&podcast_id).await else {
+                    continue;
+                };
+                if podcast.is_removed() {
+                    continue;
+                }
+                let episodes = storage.load_episodes(&podcast_id).await.unwrap_or_default();
+                items.extend(episodes.into_iter().filter_map(|episode| {
+                    Some(crate::ui::buffers::history::HistoryItem {
+                        played_at: episode.played_at.filter(|_| episode.is_played())?,
+                        podcast_id: podcast_id.clone(),
+                        episode_id: episode.id.clone(),
+                        podcast_title: podcast.title.clone(),
+                        episode_title: episode.display_title().to_string(),
+                    })
+                }));
+            }
+            let _ = app_event_tx.send(AppEvent::HistoryItemsLoaded { items });
+        });
+    }
+
+    /// Open (creating on first use) the Queue buffer
+    fn open_queue_buffer(&mut self) {
+        if self.buffer_manager.get_queue_buffer_mut().is_none() {
+            self.buffer_manager.create_queue_buffer();
+        }
+        self.trigger_async_queue_refresh();
+        let _ = self.buffer_manager.switch_to_buffer(&"queue".to_string());
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    /// Open the Search buffer and rebuild its index in the background. Runs
+    /// `query` when given, otherwise prompts for one and searches as it's typed.
+    fn open_episode_search(&mut self, query: Option<String>) {
+        if self.buffer_manager.get_search_buffer_mut().is_none() {
+            self.buffer_manager.create_search_buffer();
+        }
+        self.spawn_search_index_build();
+        let _ = self.buffer_manager.switch_to_buffer(&"search".to_string());
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+        match query {
+            Some(query) => self.run_episode_search(query),
+            None => self.minibuffer.set_content(MinibufferContent::Input {
+                prompt: SEARCH_EPISODES_PROMPT.to_string(),
+                input: String::new(),
+            }),
+        }
+    }
+
+    /// Names credited in the episode or podcast detail view on screen,
+    /// guests first
+    fn credited_people(&mut self) -> Vec<String> {
+        let Some(id) = self.buffer_manager.current_buffer_id() else {
+            return Vec::new();
+        };
+        let mut people = if id.starts_with("episode-detail-") {
+            self.buffer_manager
+                .get_episode_detail_buffer_mut_by_id(&id)
+                .map(|detail| detail.episode().people.clone())
+        } else if id.starts_with("podcast-detail-") {
+            self.buffer_manager
+                .get_podcast_detail_buffer_mut_by_id(&id)
+                .map(|detail| detail.podcast().people.clone())
+        } else {
+            None
+        }
+        .unwrap_or_default();
+        people.sort_by_key(|person| !person.is_guest());
+        let mut names: Vec<String> = Vec::new();
+        for person in people {
+            if !names.contains(&person.name) {
+                names.push(person.name);
+            }
+        }
+        names
+    }
+
+    /// Index every subscribed episode, reporting it as [`AppEvent::SearchIndexBuilt`]
+    fn spawn_search_index_build(&mut self) {
+        if let Some(buffer) = self.buffer_manager.get_search_buffer_mut() {
+            buffer.set_indexing(self.search_index.is_none());
+        }
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let podcasts = subscription_manager
+                .list_subscriptions()
+                .await
+                .unwrap_or_default();
+            let index =
+                crate::search::SearchIndex::build(subscription_manager.storage.as_ref(), &podcasts)
+                    .await;
+            let _ = app_event_tx.send(AppEvent::SearchIndexBuilt {
+                index: Arc::new(index),
+            });
+        });
+    }
+
+    /// Show the episodes matching `query` in the Search buffer. Before the
+    /// index is built, the query is kept and run once it is.
+    fn run_episode_search(&mut self, query: String) {
+        let hits = match &self.search_index {
+            Some(index) => index.search(&query, crate::search::MAX_RESULTS),
+            None => Vec::new(),
+        };
+        if let Some(buffer) = self.buffer_manager.get_search_buffer_mut() {
+            buffer.set_results(query, hits);
+        }
+    }
+
+    /// Load the queue saved by the last session
+    async fn load_queue(&mut self) {
+        match QueueManager::load(&self._storage.data_dir).await {
+            Ok(queue) => self.queue = queue,
+            Err(e) => self.show_error(format!("Could not load the playback queue: {}", e)),
+        }
+    }
+
+    /// Save the queue after a change, and update the Queue buffer if it is open
+    fn queue_changed(&mut self) {
+        // Read-only, the queue lasts until quitting
+        if self._storage.is_read_only() {
+            if self.buffer_manager.get_queue_buffer_mut().is_some() {
+                self.trigger_async_queue_refresh();
+            }
+            return;
+        }
+        let save = self.queue.save();
+        let app_event_tx = self.app_event_tx.clone();
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            if let Err(e) = save.await {
+                let _ = app_event_tx.send(AppEvent::QueueSaveFailed {
+                    error: e.to_string(),
+                });
+            }
+        });
+        if self.buffer_manager.get_queue_buffer_mut().is_some() {
+            self.trigger_async_queue_refresh();
+        }
+    }
+
+    /// Look up titles and download state of the queued episodes for the
+    /// Queue buffer
+    fn trigger_async_queue_refresh(&mut self) {
+        let entries = self.queue.entries().to_vec();
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let mut podcast_titles: HashMap<crate::storage::PodcastId, String> = HashMap::new();
+            let mut items = Vec::with_capacity(entries.len());
+            for entry in entries {
+                if !podcast_titles.contains_key(&entry.podcast_id) {
+                    let title = match storage.load_podcast(&entry.podcast_id).await {
+                        Ok(podcast) => podcast.title,
+                        Err(_) => "Unknown podcast".to_string(),
+                    };
+                    podcast_titles.insert(entry.podcast_id.clone(), title);
+                }
+                let (episode_title, downloaded) = match storage
+                    .load_episode(&entry.podcast_id, &entry.episode_id)
+                    .await
+                {
+                    Ok(episode) => (episode.display_title().to_string(), episode.is_downloaded()),
+                    Err(_) => ("Unknown episode".to_string(), false),
+                };
+                items.push(crate::ui::buffers::queue::QueueItem {
+                    episode_id: entry.episode_id,
+                    podcast_title: podcast_titles[&entry.podcast_id].clone(),
+                    episode_title,
+                    downloaded,
+                });
+            }
+            let _ = app_event_tx.send(AppEvent::QueueItemsLoaded { items });
+        });
+    }
+
+    /// Add the episode to the end of the playback queue
+    async fn enqueue_episode(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        let title = match self._storage.load_episode(&podcast_id, &episode_id).await {
+            Ok(episode) => episode.display_title().to_string(),
+            Err(e) => {
+                self.show_error(format!("Failed to load episode: {}", e));
+                return;
+            }
+        };
+        if self.queue.enqueue(QueueEntry {
+            podcast_id,
+            episode_id,
+        }) {
+            self.queue_changed();
+            self.show_message(format!(
+                "Queued '{}' ({} in queue)",
+                title,
+                self.queue.len()
+            ));
+        } else {
+            self.show_message(format!("'{}' is already in the queue", title));
+        }
+    }
+
+    /// Play a queued episode now; returns false when it isn't downloaded
+    async fn play_queued(&mut self, entry: QueueEntry) -> bool {
+        let episode = match self
+            ._storage
+            .load_episode(&entry.podcast_id, &entry.episode_id)
+            .await
+        {
+            Ok(episode) => episode,
+            Err(_) => return false,
+        };
+        let (true, Some(path)) = (episode.is_downloaded(), episode.local_path) else {
+            return false;
+        };
+        // Playing from the queue leaves any playlist being played
+        self.set_play_queue(None);
+        let command = self
+            .play_command(entry.podcast_id, entry.episode_id, path)
+            .await;
+        match self.audio_command_tx {
+            Some(ref tx) => {
+                let _ = tx.send(command);
+            }
+            None => self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string()),
+        }
+        true
+    }
+
+    /// Play the first downloaded episode in the queue, dropping the ones
+    /// before it that can't play. Returns whether one started.
+    async fn play_next_queued(&mut self) -> bool {
+        let mut skipped = 0;
+        let mut played = false;
+        while let Some(entry) = self.queue.pop_front() {
+            if self.play_queued(entry).await {
+                played = true;
+                break;
+            }
+            skipped += 1;
+        }
+        if played || skipped > 0 {
+            self.queue_changed();
+        }
+        if skipped > 0 {
+            self.show_message(format!(
+                "Skipped {} queued episode(s) that aren't downloaded",
+                skipped
+            ));
+        }
+        played
+    }
+
+    /// Open (creating on first use) the Unplayed buffer and refresh its contents
+    fn open_unplayed_buffer(&mut self) {
+        if self.buffer_manager.get_unplayed_buffer_mut().is_none() {
+            self.buffer_manager.create_unplayed_buffer(
+                self.subscription_manager.clone(),
+                self.download_manager.clone(),
+            );
+        }
+        let _ = self
+            .buffer_manager
+            .switch_to_buffer(&"unplayed".to_string());
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    fn open_episode_detail_buffer(&mut self, episode: crate::podcast::Episode) {
+        self.buffer_manager
+            .create_episode_detail_buffer(episode.clone());
+        let episode_buffer_id = format!("episode-detail-{}", episode.id);
+        let _ = self.buffer_manager.switch_to_buffer(&episode_buffer_id);
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    /// Podcast the current buffer is about: the podcast list selection, an
+    /// episode list's podcast, or the podcast an open detail buffer shows
+    fn podcast_id_for_current_buffer(&mut self) -> Option<crate::storage::PodcastId> {
+        let buffer_id = self.buffer_manager.current_buffer_id()?;
+        self.buffer_manager
+            .get_buffer(&buffer_id)?
+            .current_podcast_id()
+    }
+
+    /// Look for releases newer than this build; `open_buffer` shows them in the release notes buffer
+    fn trigger_async_release_check(&mut self, open_buffer: bool) {
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let event = match crate::update_check::fetch_newer_releases().await {
+                Ok(releases) => AppEvent::ReleasesChecked {
+                    releases,
+                    open_buffer,
+                },
+                Err(e) => AppEvent::ReleaseCheckFailed {
+                    error: e.to_string(),
+                    open_buffer,
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    /// Load a podcast fresh from storage (so the timeline is current) with its
+    /// listening stats, and open its detail buffer
+    fn trigger_async_load_podcast_detail(&mut self, podcast_id: crate::storage::PodcastId) {
+        let subscription_manager = self.subscription_manager.clone();
+        let listening_log = self.listening_log.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let event = match subscription_manager.get_podcast(&podcast_id).await {
+                Ok(podcast) => {
+                    let records = listening_log.since(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+                    let episodes = subscription_manager.storage.load_episodes(&podcast_id);
+                    let stats = match (records.await, episodes.await) {
+                        (Ok(records), Ok(episodes)) => {
+                            Some(PodcastStats::compute(&podcast_id, &records, &episodes))
+                        }
+                        _ => None,
+                    };
+                    AppEvent::PodcastDetailLoaded { podcast, stats }
+                }
+                Err(e) => AppEvent::PodcastDetailLoadFailed {
+                    error: e.to_string(),
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    fn add_to_playlist_supported_in_buffer(&mut self, buffer_id: &str) -> bool {
+        self.buffer_manager
+            .get_buffer(&buffer_id.to_string())
+            .is_some_and(|buffer| buffer.as_episode_selection().is_some())
+    }
+
+    /// Let the user pick a user playlist for `targets`, or create one for them
+    async fn open_playlist_picker(
+        &mut self,
+        targets: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    ) {
+        match self.playlist_manager.list_playlists().await {
+            Ok(playlists) => {
+                let options: Vec<_> = playlists
+                    .into_iter()
+                    .filter(|playlist| {
+                        matches!(playlist.playlist_type, crate::playlist::PlaylistType::User)
+                    })
+                    .map(|playlist| (playlist.id, playlist.name, playlist.episodes.len()))
+                    .collect();
+                let _ = self
+                    .buffer_manager
+                    .remove_buffer(&"playlist-picker".to_string());
+                self.buffer_manager
+                    .create_playlist_picker_buffer(options, targets);
+                let _ = self
+                    .buffer_manager
+                    .switch_to_buffer(&"playlist-picker".to_string());
+                self.update_status_bar();
+            }
+            Err(e) => self.show_error(format!("Could not list playlists: {}", e)),
+        }
+    }
+
+    /// Replace the play queue, keeping the status bar's shuffle / repeat marker in step
+    fn set_play_queue(&mut self, queue: Option<PlayQueue>) {
+        // Playing or stopping something else supersedes a pending auto-play
+        self.cancel_auto_play();
+        let indicator = queue
+            .as_ref()
+            .map(|queue| queue.modes().indicator())
+            .unwrap_or_default();
+        self.status_bar.set_queue_modes(indicator);
+        self.play_queue = queue;
+    }
+
+    /// Play `entries` of a playlist from `start`, using the playlist's saved modes
+    async fn start_playlist_playback(
+        &mut self,
+        playlist_id: PlaylistId,
+        entries: Vec<QueueEntry>,
+        start: usize,
+    ) {
+        if self.audio_command_tx.is_none() {
+            self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+            return;
+        }
+        let (name, modes) = match self.playlist_manager.get_playlist(&playlist_id).await {
+            Ok(playlist) => (playlist.name, playlist.playback),
+            Err(_) => (String::new(), PlaybackModes::default()),
+        };
+        let queue = PlayQueue::new(playlist_id, name, entries, start, modes);
+        self.set_play_queue(Some(queue));
+        self.play_from_queue().await;
+    }
+
+    /// Play the queue's current entry, skipping past ones that are not downloaded
+    async fn play_from_queue(&mut self) {
+        let Some(queue) = self.play_queue.as_mut() else {
+            return;
+        };
+        let mut candidate = queue.current().cloned();
+        // Every entry gets one chance; repeat-one would otherwise retry forever
+        for _ in 0..queue.len() {
+            let Some(entry) = candidate.take() else {
+                break;
+            };
+            if let Ok(episode) = self
+                ._storage
+                .load_episode(&entry.podcast_id, &entry.episode_id)
+                .await
+            {
+                if let (true, Some(path)) = (episode.is_downloaded(), episode.local_path) {
+                    let command = self
+                        .play_command(entry.podcast_id, entry.episode_id, path)
+                        .await;
+                    if let Some(ref tx) = self.audio_command_tx {
+                        let _ = tx.send(command);
+                    }
+                    return;
+                }
+            }
+            let Some(queue) = self.play_queue.as_mut() else {
+                return;
+            };
+            if queue.modes().repeat == RepeatMode::One {
+                break;
+            }
+            candidate = queue.advance().cloned();
+        }
+        let name = self
+            .play_queue
+            .as_ref()
+            .map(|queue| queue.playlist_name().to_string())
+            .unwrap_or_default();
+        self.set_play_queue(None);
+        self.show_error(format!(
+            "Nothing left to play in '{}': download its episodes first",
+            name
+        ));
+    }
+
+    /// Move the queue on after its current entry finished
+    async fn play_next_in_queue(&mut self) {
+        let Some(queue) = self.play_queue.as_mut() else {
+            return;
+        };
+        if queue.advance().is_some() {
+            self.play_from_queue().await;
+        } else {
+            let name = queue.playlist_name().to_string();
+            self.set_play_queue(None);
+            self.show_message(format!("Finished playlist '{}'", name));
+        }
+    }
+
+    /// Apply `change` to the shuffle / repeat modes of the playlist being
+    /// played, or else of the playlist open in the current buffer, and save them
+    fn update_playback_modes(&mut self, change: impl FnOnce(&mut PlaybackModes)) {
+        let current_detail = self
+            .buffer_manager
+            .current_buffer_id()
+            .filter(|buffer_id| buffer_id.starts_with("playlist-") && buffer_id != "playlist-list")
+            .and_then(|buffer_id| {
+                let detail = self
+                    .buffer_manager
+                    .get_playlist_detail_buffer_mut_by_id(&buffer_id)?;
+                Some((
+                    detail.playlist_id().clone(),
+                    detail.playlist_name().to_string(),
+                    detail.playback_modes(),
+                ))
+            });
+        let target = match self.play_queue.as_ref() {
+            Some(queue) => Some((
+                queue.playlist_id().clone(),
+                queue.playlist_name().to_string(),
+                queue.modes(),
+            )),
+            None => current_detail,
+        };
+        let Some((playlist_id, name, mut modes)) = target else {
+            self.show_error("Open or play a playlist to change shuffle/repeat".to_string());
+            return;
+        };
+
+        change(&mut modes);
+        if let Some(queue) = self.play_queue.as_mut() {
+            if queue.playlist_id() == &playlist_id {
+                queue.set_modes(modes);
+                self.status_bar.set_queue_modes(modes.indicator());
+            }
+        }
+        for buffer_id in self.buffer_manager.get_buffer_ids() {
+            if !buffer_id.starts_with("playlist-") || buffer_id == "playlist-list" {
+                continue;
+            }
+            if let Some(detail) = self
+                .buffer_manager
+                .get_playlist_detail_buffer_mut_by_id(&buffer_id)
+            {
+                if detail.playlist_id() == &playlist_id {
+                    detail.set_playback_modes(modes);
+                }
+            }
+        }
+
+        self.show_message(format!(
+            "'{}': shuffle {}, repeat {}",
+            name,
+            if modes.shuffle { "on" } else { "off" },
+            modes.repeat.label()
+        ));
+        let temporary = self
+            .play_queue
+            .as_ref()
+            .is_some_and(|queue| queue.playlist_id() == &playlist_id && queue.is_temporary());
+        if !temporary {
+            self.trigger_async_save_playback_modes(playlist_id, name, modes);
+        }
+    }
+
+    fn trigger_async_save_playback_modes(
+        &mut self,
+        playlist_id: PlaylistId,
+        name: String,
+        modes: PlaybackModes,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            if let Err(e) = playlist_manager
+                .set_playback_modes(&playlist_id, modes)
+                .await
+            {
+                let _ = app_event_tx.send(AppEvent::PlaybackModesSaveFailed {
+                    name,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+
+    /// Copy of the episode under the cursor in an episode, aggregate or detail buffer
+    fn selected_episode_snapshot(&mut self, buffer_id: &str) -> Option<crate::podcast::Episode> {
+        let buffer = self.buffer_manager.get_buffer(&buffer_id.to_string())?;
+        let (_, episode) = buffer.as_episode_selection()?.selected_episode_entry()?;
+        Some(episode.clone())
+    }
+
+    fn resolve_selected_episode(
+        &mut self,
+        buffer_id: &str,
+    ) -> Option<(crate::storage::PodcastId, crate::storage::EpisodeId)> {
+        self.buffer_manager
+            .get_buffer(&buffer_id.to_string())?
+            .as_episode_selection()?
+            .selected_episode_ids()
+    }
+
+    /// Every episode a list buffer shows, in display order: what filters and
+    /// search left visible, not the whole feed. `None` when the buffer isn't
+    /// an episode list.
+    fn resolve_shown_episodes(
+        &mut self,
+        buffer_id: &str,
+    ) -> Option<Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>> {
+        self.buffer_manager
+            .get_buffer(&buffer_id.to_string())?
+            .as_episode_selection()?
+            .shown_episodes()
+    }
+
+    /// Trigger async podcast addition
+    fn trigger_async_add_podcast(&mut self, url: String) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let url_clone = url.clone();
+
+        // Spawn async task to add the podcast
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let mut result = subscription_manager.subscribe(&url_clone).await;
+
+            // Not a feed: maybe a show's web page that links to one
+            if let Err(SubscriptionError::Feed(FeedError::ParseError(_))) = result {
+                match subscription_manager.discover_feeds(&url_clone).await {
+                    Ok(mut candidates) if candidates.len() == 1 => {
+                        let feed = candidates.remove(0);
+                        result = subscription_manager.subscribe(&feed.url).await;
+                    }
+                    Ok(candidates) if !candidates.is_empty() => {
+                        let _ = app_event_tx.send(AppEvent::FeedCandidatesFound {
+                            page_url: url_clone,
+                            candidates,
+                        });
+                        return;
+                    }
+                    _ => {
+                        let _ = app_event_tx.send(AppEvent::PodcastSubscriptionFailed {
+                            url: url_clone,
+                            error: "not a podcast feed, and the page links to no feed".to_string(),
+                        });
+                        return;
+                    }
+                }
+            }
+
+            match result {
+                Ok(podcast) => {
+                    // Send success event back to UI
+                    let _ = app_event_tx.send(AppEvent::PodcastSubscribed { podcast });
+                }
+                Err(e) => {
+                    // Send error event back to UI
+                    let _ = app_event_tx.send(AppEvent::PodcastSubscriptionFailed {
+                        url: url_clone.clone(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+
+        // Show immediate feedback
+        self.show_message(format!("Adding podcast: {}...", url));
+    }
+
+    /// Trigger async single podcast refresh
+    fn trigger_async_refresh_single(&mut self, podcast_id: crate::storage::PodcastId) {
+        if self.refuse_while_read_only("Refresh") {
+            return;
+        }
+        if self.defer_while_offline(PendingNetworkOp::RefreshPodcast(podcast_id.clone())) {
+            return;
+        }
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+
+        let exclusions = self.whats_new_exclusions.clone();
+        let keywords = self.keyword_alerts.clone();
+        let download_manager = self.download_manager.clone();
+        let auto_downloads = AutoDownloads::new(&self.config.downloads);
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            match subscription_manager.refresh_feed(&podcast_id).await {
+                Ok(new_episodes) => {
+                    let podcast = subscription_manager.storage.load_podcast(&podcast_id).await;
+                    let new_episode_count = match &podcast {
+                        Ok(podcast) => new_episodes
+                            .iter()
+                            .filter(|episode| !exclusions.excludes(podcast, &episode.title))
+                            .count(),
+                        Err(_) => new_episodes.len(),
+                    };
+                    let _ = app_event_tx.send(AppEvent::PodcastRefreshed {
+                        podcast_id: podcast_id_clone,
+                        new_episode_count,
+                        changed_episodes: podcast
+                            .as_ref()
+                            .ok()
+                            .map(|podcast| whats_new_changes(podcast, &new_episodes, &exclusions)),
+                    });
+                    if let Ok(podcast) = &podcast {
+                        let alerts: Vec<_> = new_episodes
+                            .iter()
+                            .filter_map(|episode| keyword_alert(&keywords, podcast, episode))
+                            .collect();
+                        if !alerts.is_empty() {
+                            let _ = app_event_tx.send(AppEvent::KeywordAlertsRaised { alerts });
+                        }
+                        for episode in &new_episodes {
+                            if !exclusions.excludes(podcast, &episode.title) {
+                                auto_downloads.offer(podcast, episode);
+                            }
+                        }
+                        let episodes = auto_downloads.into_selection();
+                        if !episodes.is_empty() {
+                            let _ = app_event_tx.send(AppEvent::AutoDownloadsQueued { episodes });
+                        }
+                    }
+
+                    // Warm the artwork cache now the refresh is reported
+                    let sources = podcast
+                        .ok()
+                        .and_then(|podcast| podcast.image_url)
+                        .into_iter()
+                        .chain(
+                            new_episodes
+                                .iter()
+                                .filter_map(|episode| episode.artwork_url().map(str::to_string)),
+                        )
+                        .collect();
+                    download_manager.prefetch_artwork(sources).await;
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRefreshFailed {
+                        podcast_id: podcast_id_clone,
+                        offline: e.is_network(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async single podcast hard refresh (re-parses episodes within `scope`)
+    fn trigger_async_hard_refresh_single(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        scope: HardRefreshScope,
+    ) {
+        if self.refuse_while_read_only("Hard refresh") || self.refuse_while_offline("Hard refresh")
+        {
+            return;
+        }
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let exclusions = self.whats_new_exclusions.clone();
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            match subscription_manager
+                .refresh_feed_with_scope(&podcast_id, Some(scope))
+                .await
+            {
+                Ok(updated_episodes) => {
+                    let podcast = subscription_manager.storage.load_podcast(&podcast_id).await;
+                    let _ = app_event_tx.send(AppEvent::PodcastRefreshed {
+                        podcast_id: podcast_id_clone,
+                        new_episode_count: updated_episodes.len(),
+                        changed_episodes: podcast.ok().map(|podcast| {
+                            whats_new_changes(&podcast, &updated_episodes, &exclusions)
+                        }),
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRefreshFailed {
+                        podcast_id: podcast_id_clone,
+                        offline: e.is_network(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async hard refresh of every subscription, reporting progress per podcast
+    fn trigger_async_hard_refresh_all(&mut self, scope: HardRefreshScope) {
+        if self.refuse_while_read_only("Hard refresh") || self.refuse_while_offline("Hard refresh")
+        {
+            return;
+        }
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let progress_tx = app_event_tx.clone();
+            let progress = move |done, total, podcast_title: &str| {
+                let _ = progress_tx.send(AppEvent::HardRefreshAllProgress {
+                    done,
+                    total,
+                    podcast_title: podcast_title.to_string(),
+                });
+            };
+            if let Ok(summary) = subscription_manager.hard_refresh_all(scope, progress).await {
+                let _ = app_event_tx.send(AppEvent::HardRefreshAllCompleted { scope, summary });
+            }
+        });
+    }
+
+    /// Trigger async refresh of all podcasts, skipping those refreshed within
+    /// their own refresh interval
+    fn trigger_async_refresh_all(&mut self) {
+        if self.refuse_while_read_only("Refresh") {
+            return;
+        }
+        if self.defer_while_offline(PendingNetworkOp::RefreshAll) {
+            return;
+        }
+        self.trigger_async_refresh(None, |podcast| podcast.refresh_due(chrono::Utc::now()));
+    }
+
+    /// Refresh the podcasts passing the podcast list's filter
+    fn refresh_filtered_podcasts(&mut self) {
+        let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut() else {
+            self.show_error("Podcast list not available".to_string());
+            return;
+        };
+        let Some(scope) = podcast_buffer.active_filter() else {
+            self.show_error(
+                "No podcast list filter is active (S-R refreshes all podcasts)".to_string(),
+            );
+            return;
+        };
+        let ids: std::collections::HashSet<crate::storage::PodcastId> = podcast_buffer
+            .visible_podcasts()
+            .map(|podcast| podcast.id.clone())
+            .collect();
+        if self.refuse_while_read_only("Refresh") {
+            return;
+        }
+        if self.offline.is_some() {
+            for podcast_id in ids {
+                self.defer_while_offline(PendingNetworkOp::RefreshPodcast(podcast_id));
+            }
+            return;
+        }
+        self.show_message(format!("Refreshing {} filtered podcast(s)...", ids.len()));
+        self.trigger_async_refresh(Some(scope), move |podcast| ids.contains(&podcast.id));
+    }
+
+    /// Trigger async refresh of the subscriptions `select` accepts; `scope`
+    /// describes a selective refresh in the completion message
+    fn trigger_async_refresh(
+        &mut self,
+        scope: Option<String>,
+        select: impl Fn(&crate::podcast::Podcast) -> bool + Send + 'static,
+    ) {
+        if self.refuse_while_read_only("Refresh") || self.refuse_while_offline("Refresh") {
+            return;
+        }
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        let exclusions = self.whats_new_exclusions.clone();
+        let keywords = self.keyword_alerts.clone();
+        let download_manager = self.download_manager.clone();
+        let auto_downloads = AutoDownloads::new(&self.config.downloads);
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let artwork = std::sync::Mutex::new(Vec::new());
+            let alerts = std::sync::Mutex::new(Vec::new());
+            match subscription_manager
+                .refresh_matching_counting(select, |podcast, episode| {
+                    if let Some(url) = episode.artwork_url() {
+                        artwork.lock().unwrap().push(url.to_string());
+                    }
+                    if let Some(alert) = keyword_alert(&keywords, podcast, episode) {
+                        alerts.lock().unwrap().push(alert);
+                    }
+                    let shown = !exclusions.excludes(podcast, &episode.title);
+                    if shown {
+                        auto_downloads.offer(podcast, episode);
+                    }
+                    shown
+                })
+                .await
+            {
+                Ok(counts) => {
+                    let _ = app_event_tx.send(AppEvent::AllPodcastsRefreshed {
+                        total_new_episodes: counts.new_episodes,
+                        podcasts: counts.podcasts,
+                        unreachable: counts.unreachable,
+                        scope,
+                    });
+                    let alerts = alerts.into_inner().unwrap_or_default();
+                    if !alerts.is_empty() {
+                        let _ = app_event_tx.send(AppEvent::KeywordAlertsRaised { alerts });
+                    }
+                    let episodes = auto_downloads.into_selection();
+                    if !episodes.is_empty() {
+                        let _ = app_event_tx.send(AppEvent::AutoDownloadsQueued { episodes });
+                    }
+
+                    // Warm the artwork cache now the refresh is reported
+                    let mut sources = artwork.into_inner().unwrap_or_default();
+                    if let Ok(podcasts) = subscription_manager.list_subscriptions().await {
+                        sources.extend(podcasts.into_iter().filter_map(|p| p.image_url));
+                    }
+                    download_manager.prefetch_artwork(sources).await;
+                }
+                Err(_e) => {
+                    // For all refresh, we'll just show a general error
+                    // TODO: Add proper error reporting mechanism for TUI
+                }
+            }
+        });
+    }
+
+    /// Trigger async episode download
+    fn trigger_async_download(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        if self.download_manager.is_downloading(&episode_id) {
+            self.show_message("Episode is already downloading".to_string());
+            return;
+        }
+        if self.refuse_while_read_only("Downloading") {
+            return;
+        }
+        if self.defer_while_offline(PendingNetworkOp::Download(
+            podcast_id.clone(),
+            episode_id.clone(),
+        )) {
+            return;
+        }
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let episode_id_clone = episode_id.clone();
+        let kind = TaskKind::Download {
+            podcast_id: podcast_id.clone(),
+            episode_id: episode_id.clone(),
+        };
+
+        self.background_tasks.spawn(kind, async move {
+            let progress_tx = app_event_tx.clone();
+            let progress_episode_id = episode_id.clone();
+            let mut last_sent: Option<Instant> = None;
+            let on_progress = move |downloaded, total| {
+                // Progress lines arrive far faster than the screen needs them
+                if last_sent.is_some_and(|sent| {
+                    sent.elapsed() < crate::constants::downloads::DOWNLOAD_PROGRESS_INTERVAL
+                }) {
+                    return;
+                }
+                last_sent = Some(Instant::now());
+                let _ = progress_tx.send(AppEvent::EpisodeDownloadProgress {
+                    episode_id: progress_episode_id.clone(),
+                    downloaded,
+                    total,
+                });
+            };
+            match download_manager
+                .download_episode_with_progress(&podcast_id, &episode_id, on_progress)
+                .await
+            {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeDownloaded {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                    });
+                }
+                // Another download of it started first (e.g. auto-download)
+                Err(DownloadError::AlreadyDownloading) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeAlreadyDownloading {
+                        episode_id: episode_id_clone,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadFailed {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                        offline: e.is_network(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Transcode a downloaded episode in the background, streaming progress to the minibuffer
+    fn trigger_async_convert_episode(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        target: ConvertTarget,
+    ) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let episode_title = download_manager
+                .storage()
+                .load_episode(&podcast_id, &episode_id)
+                .await
+                .map(|episode| episode.display_title().to_string())
+                .unwrap_or_else(|_| "episode".to_string());
+            let _ = app_event_tx.send(AppEvent::EpisodeConversionProgress {
+                episode_title: episode_title.clone(),
+                percent: 0,
+            });
+
+            let progress_tx = app_event_tx.clone();
+            let progress_title = episode_title.clone();
+            let result = download_manager
+                .convert_episode(&podcast_id, &episode_id, &target, move |percent| {
+                    let _ = progress_tx.send(AppEvent::EpisodeConversionProgress {
+                        episode_title: progress_title.clone(),
+                        percent,
+                    });
+                })
+                .await;
+
+            let _ = app_event_tx.send(match result {
+                Ok(path) => AppEvent::EpisodeConverted {
+                    podcast_id,
+                    episode_title,
+                    path,
+                },
+                Err(e) => AppEvent::EpisodeConversionFailed {
+                    episode_title,
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// Transcribe a downloaded episode in the background; `report_progress`
+    /// shows whisper's progress (off for automatic transcriptions)
+    fn trigger_async_transcribe_episode(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        report_progress: bool,
+    ) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let episode_title = download_manager
+                .storage()
+                .load_episode(&podcast_id, &episode_id)
+                .await
+                .map(|episode| episode.display_title().to_string())
+                .unwrap_or_else(|_| "episode".to_string());
+            if report_progress {
+                let _ = app_event_tx.send(AppEvent::EpisodeTranscriptionProgress {
+                    episode_title: episode_title.clone(),
+                    percent: 0,
+                });
+            }
+
+            let progress_tx = app_event_tx.clone();
+            let progress_title = episode_title.clone();
+            let result = download_manager
+                .transcribe_episode(&podcast_id, &episode_id, move |percent| {
+                    if report_progress {
+                        let _ = progress_tx.send(AppEvent::EpisodeTranscriptionProgress {
+                            episode_title: progress_title.clone(),
+                            percent,
+                        });
+                    }
+                })
+                .await;
+
+            let _ = app_event_tx.send(match result {
+                Ok(episode) => AppEvent::EpisodeTranscribed {
+                    episode: Box::new(episode),
+                },
+                Err(e) => AppEvent::EpisodeTranscriptionFailed {
+                    episode_title,
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// Run the episode's description through `translation.command` in the
+    /// background and keep the result on the episode
+    fn trigger_async_translate_episode(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        if self.config.translation.command.is_none() {
+            self.show_error(
+                "No translation command configured; set translation.command".to_string(),
+            );
+            return;
+        }
+        let storage = self.download_manager.storage().clone();
+        let config = self.config.translation.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.show_message("Translating...".to_string());
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let mut episode_title = "episode".to_string();
+            let result = async {
+                let mut episode = storage
+                    .load_episode(&podcast_id, &episode_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                episode_title = episode.display_title().to_string();
+                let text = crate::podcast::translate::source_text(&episode)
+                    .ok_or_else(|| "the episode has no description".to_string())?;
+                episode.translated_description =
+                    Some(crate::podcast::translate::translate(&config, &text).await?);
+                storage
+                    .save_episode(&podcast_id, &episode)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>(episode)
+            }
+            .await;
+
+            let _ = app_event_tx.send(match result {
+                Ok(episode) => AppEvent::EpisodeTranslated {
+                    episode: Box::new(episode),
+                },
+                Err(error) => AppEvent::EpisodeTranslationFailed {
+                    episode_title,
+                    error,
+                },
+            });
+        });
+    }
+
+    /// Ask for a new value of `field`, pre-filled with the current one. Descriptions
+    /// are edited in the multi-line popup, other fields only when they fit on a line.
+    fn prompt_episode_edit(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        field: EpisodeField,
+    ) {
+        let current = self
+            .buffer_manager
+            .current_buffer_id()
+            .and_then(|buffer_id| self.selected_episode_snapshot(&buffer_id))
+            .filter(|episode| episode.id == episode_id)
+            .and_then(|episode| episode.field_value(field).map(str::to_string));
+        let prompt = format!("Episode {} (empty reverts to feed): ", field.label());
+        if field == EpisodeField::Description {
+            // Descriptions span paragraphs; edit them in the multi-line popup
+            self.minibuffer
+                .show_multiline_prompt(prompt, current.as_deref().unwrap_or_default());
+        } else {
+            self.minibuffer.set_content(MinibufferContent::Input {
+                prompt,
+                input: current
+                    .filter(|value| !value.contains('\n'))
+                    .unwrap_or_default(),
+            });
+        }
+        self.pending_episode_edit = Some((podcast_id, episode_id, field));
+    }
+
+    /// Open the multi-line editor on the notes of the selected episode, or of
+    /// the current podcast when `episode` is false
+    fn prompt_notes(&mut self, episode: bool) {
+        let buffer_id = self.buffer_manager.current_buffer_id();
+        if episode {
+            let Some(selected) = buffer_id.and_then(|id| self.selected_episode_snapshot(&id))
+            else {
+                self.show_error("Select an episode to take notes on".to_string());
+                return;
+            };
+            self.minibuffer.show_multiline_prompt(
+                "Notes for episode: ".to_string(),
+                selected.notes.as_deref().unwrap_or_default(),
+            );
+            self.pending_notes = Some((selected.podcast_id, Some(selected.id)));
+            return;
+        }
+
+        let Some(podcast_id) = self.podcast_id_for_current_buffer() else {
+            self.show_error("Select a podcast in the podcast or episode list first".to_string());
+            return;
+        };
+        let notes = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .and_then(|list| list.podcasts().iter().find(|p| p.id == podcast_id))
+            .and_then(|podcast| podcast.notes.clone())
+            .unwrap_or_default();
+        self.minibuffer
+            .show_multiline_prompt("Notes for podcast: ".to_string(), &notes);
+        self.pending_notes = Some((podcast_id, None));
+    }
+
+    /// Save notes prompted for by `prompt_notes`; empty notes are removed
+    fn trigger_async_save_notes(&mut self, notes: &str) {
+        let Some((podcast_id, episode_id)) = self.pending_notes.take() else {
+            return;
+        };
+        let notes = Some(notes.to_string()).filter(|n| !n.is_empty());
+        let storage = self.download_manager.storage().clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let result = async {
+                match episode_id {
+                    Some(episode_id) => {
+                        let mut episode = storage.load_episode(&podcast_id, &episode_id).await?;
+                        episode.notes = notes;
+                        storage.save_episode(&podcast_id, &episode).await?;
+                        Ok(AppEvent::NotesSaved {
+                            podcast: None,
+                            episode: Some(Box::new(episode)),
+                        })
+                    }
+                    None => {
+                        let mut podcast = storage.load_podcast(&podcast_id).await?;
+                        podcast.notes = notes;
+                        storage.save_podcast(&podcast).await?;
+                        Ok(AppEvent::NotesSaved {
+                            podcast: Some(Box::new(podcast)),
+                            episode: None,
+                        })
+                    }
+                }
+            }
+            .await;
+
+            let _ = app_event_tx.send(result.unwrap_or_else(|e: crate::storage::StorageError| {
+                AppEvent::NotesSaveFailed {
+                    error: e.to_string(),
+                }
+            }));
+        });
+    }
+
+    /// Save a local metadata override and re-tag the download to match.
+    /// `value: None` reverts `field`; `field: None` reverts every override.
+    fn trigger_async_edit_episode(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        field: Option<EpisodeField>,
+        value: Option<String>,
+    ) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let storage = download_manager.storage();
+            let result = async {
+                let mut episode = storage
+                    .load_episode(&podcast_id, &episode_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                match field {
+                    Some(field) => episode.set_override(field, value),
+                    None => episode.overrides = Default::default(),
+                }
+                storage
+                    .save_episode(&podcast_id, &episode)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>(episode)
+            }
+            .await;
+
+            let _ = app_event_tx.send(match result {
+                Ok(episode) => {
+                    let retagged = match download_manager
+                        .retag_episode(&podcast_id, &episode_id)
+                        .await
+                    {
+                        Ok(retagged) => retagged,
+                        Err(e) => {
+                            eprintln!("Warning: Failed to re-tag edited episode: {}", e);
+                            false
+                        }
+                    };
+                    AppEvent::EpisodeMetadataEdited {
+                        episode: Box::new(episode),
+                        field,
+                        retagged,
+                    }
+                }
+                Err(error) => AppEvent::EpisodeMetadataEditFailed { error },
+            });
+        });
+    }
+
+    /// Podcast and episode the audio thread has loaded, if any
+    fn now_playing_episode(
+        &mut self,
+    ) -> Option<(crate::storage::PodcastId, crate::storage::EpisodeId)> {
+        let status = self
+            .buffer_manager
+            .get_now_playing_buffer_mut()?
+            .current_status();
+        status.podcast_id.zip(status.episode_id)
+    }
+
+    /// Bookmark the playing episode at its current position
+    fn add_bookmark(&mut self, label: Option<String>) {
+        let status = self
+            .buffer_manager
+            .get_now_playing_buffer_mut()
+            .map(|buffer| buffer.current_status())
+            .unwrap_or_default();
+        let (Some(podcast_id), Some(episode_id), Some(position)) =
+            (status.podcast_id, status.episode_id, status.position)
+        else {
+            self.show_error("Nothing is playing to bookmark".to_string());
+            return;
+        };
+        let position = position.as_secs() as u32;
+        let storage = self.download_manager.storage().clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let result = async {
+                let mut episode = storage
+                    .load_episode(&podcast_id, &episode_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                episode.add_bookmark(position, label);
+                storage
+                    .save_episode(&podcast_id, &episode)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>(episode)
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(episode) => AppEvent::BookmarkAdded {
+                    episode: Box::new(episode),
+                    position,
+                },
+                Err(error) => AppEvent::BookmarkFailed {
+                    error: format!("Could not save bookmark: {}", error),
+                },
+            });
+        });
+    }
+
+    /// Write an episode's bookmarks next to its audio file as chapter files
+    fn trigger_async_export_bookmarks(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        formats: Vec<BookmarkFormat>,
+    ) {
+        let storage = self.download_manager.storage().clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let result = match storage.load_episode(&podcast_id, &episode_id).await {
+                Ok(episode) => tokio::task::spawn_blocking(move || {
+                    crate::podcast::bookmarks::export_bookmarks(&episode, &formats)
+                        .map(|paths| (episode.display_title().to_string(), paths))
+                })
+                .await
+                .unwrap_or_else(|e| Err(e.to_string())),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = app_event_tx.send(match result {
+                Ok((episode_title, paths)) => AppEvent::BookmarksExported {
+                    episode_title,
+                    paths,
+                },
+                Err(error) => AppEvent::BookmarkFailed {
+                    error: format!("Could not export bookmarks: {}", error),
+                },
+            });
+        });
+    }
+
+    /// Trigger async episode download deletion
+    fn trigger_async_delete_download(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let episode_id_clone = episode_id.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match download_manager
+                .delete_episode(&podcast_id, &episode_id)
+                .await
+            {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadDeleted {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadDeletionFailed {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Replace a download whose audio the feed has since updated
+    fn trigger_async_redownload(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        if self.refuse_while_read_only("Re-downloading")
+            || self.refuse_while_offline("Re-downloading")
+        {
+            return;
+        }
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let event = match download_manager
+                .prepare_redownload(&podcast_id, &episode_id)
+                .await
+            {
+                Ok(_) => AppEvent::RedownloadPrepared {
+                    podcast_id,
+                    episode_id,
+                },
+                Err(e) => AppEvent::EpisodeDownloadDeletionFailed {
+                    podcast_id,
+                    episode_id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    /// Trigger async mark episode as played
+    fn trigger_async_mark_played(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        episode_title: String,
+    ) {
+        let storage = self._storage.clone();
+        let listening_log = self.listening_log.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let episode_id_clone = episode_id.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match storage.load_episode(&podcast_id, &episode_id).await {
+                Ok(mut episode) => {
+                    let newly_played = !episode.is_played();
+                    episode.mark_played();
+                    match storage.save_episode(&podcast_id, &episode).await {
+                        Ok(()) => {
+                            if newly_played {
+                                let _ = listening_log.record(listen_record(&episode, 0)).await;
+                            }
+                            let _ = app_event_tx.send(AppEvent::EpisodeMarkedPlayed {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                episode_title,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::EpisodeMarkPlayedFailed {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeMarkPlayedFailed {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async mark episode as unplayed
+    fn trigger_async_mark_unplayed(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        episode_title: String,
+    ) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let episode_id_clone = episode_id.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match storage.load_episode(&podcast_id, &episode_id).await {
+                Ok(mut episode) => {
+                    episode.mark_unplayed();
+                    match storage.save_episode(&podcast_id, &episode).await {
+                        Ok(()) => {
+                            let _ = app_event_tx.send(AppEvent::EpisodeMarkedUnplayed {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                episode_title,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::EpisodeMarkUnplayedFailed {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeMarkUnplayedFailed {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async persist of the favorited state for an episode
+    fn trigger_async_toggle_favorite(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+        episode_title: String,
+        favorited: bool,
+    ) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let episode_id_clone = episode_id.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match storage.load_episode(&podcast_id, &episode_id).await {
+                Ok(mut episode) => {
+                    episode.favorited = favorited;
+                    match storage.save_episode(&podcast_id, &episode).await {
+                        Ok(()) => {
+                            let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggled {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                episode_title,
+                                favorited,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggleFailed {
+                                podcast_id: podcast_id_clone,
+                                episode_id: episode_id_clone,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggleFailed {
+                        podcast_id: podcast_id_clone,
+                        episode_id: episode_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async persist of adding a tag to a podcast
+    fn trigger_async_add_tag(&mut self, podcast_id: crate::storage::PodcastId, tag: String) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let tag_clone = tag.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match storage.load_podcast(&podcast_id).await {
+                Ok(mut podcast) => {
+                    podcast.add_tag(&tag);
+                    match storage.save_podcast(&podcast).await {
+                        Ok(()) => {
+                            let _ = app_event_tx.send(AppEvent::PodcastTagAdded {
+                                podcast_id: podcast_id_clone,
+                                tag: tag_clone,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::PodcastTagAddFailed {
+                                podcast_id: podcast_id_clone,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastTagAddFailed {
+                        podcast_id: podcast_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// The configured OAuth provider called `name`, or the only one when no
+    /// name is given
+    fn feed_auth_provider(&mut self, name: Option<&str>) -> Option<(Arc<FeedAuth>, String)> {
+        let Some(auth) = self.subscription_manager.feed_auth().cloned() else {
+            self.show_error("No OAuth providers configured (network.oauth_providers)".to_string());
+            return None;
+        };
+        let provider = match name {
+            Some(name) => auth.provider(name).map(|p| p.name.clone()),
+            None if auth.providers().len() == 1 => Ok(auth.providers()[0].name.clone()),
+            None => {
+                let names: Vec<&str> = auth.providers().iter().map(|p| p.name.as_str()).collect();
+                self.show_error(format!("Which provider? {}", names.join(", ")));
+                return None;
+            }
+        };
+        match provider {
+            Ok(provider) => Some((auth, provider)),
+            Err(e) => {
+                self.show_error(e.to_string());
+                None
+            }
+        }
+    }
+
+    /// `:auth-login [provider]`: show the device code, then wait for approval
+    fn trigger_async_feed_sign_in(&mut self, name: Option<&str>) {
+        let Some((auth, provider)) = self.feed_auth_provider(name) else {
+            return;
+        };
+        self.show_message(format!("Requesting a sign-in code from {}...", provider));
+
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let authorization = auth.start_sign_in(&provider).await?;
+                let _ = app_event_tx.send(AppEvent::FeedSignInCode {
+                    provider: provider.clone(),
+                    authorization: authorization.clone(),
+                });
+                auth.finish_sign_in(&provider, &authorization).await
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(()) => AppEvent::FeedSignedIn { provider },
+                Err(e) => AppEvent::FeedSignInFailed {
+                    provider,
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// `:auth-logout [provider]`: forget the stored tokens
+    fn trigger_async_feed_sign_out(&mut self, name: Option<&str>) {
+        let Some((auth, provider)) = self.feed_auth_provider(name) else {
+            return;
+        };
+
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let event = match auth.sign_out(&provider).await {
+                Ok(was_signed_in) => AppEvent::FeedSignedOut {
+                    provider,
+                    was_signed_in,
+                },
+                Err(e) => AppEvent::FeedSignInFailed {
+                    provider,
+                    error: e.to_string(),
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    /// `:user-agent [preset|custom|default]` for the podcast selected in the
+    /// podcast list; without a value, shows the current setting
+    fn set_podcast_user_agent(&mut self, value: String) {
+        use crate::utils::user_agent::{UserAgentPreset, UserAgentSetting};
+
+        let Some(podcast) = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .and_then(|buffer| buffer.selected_podcast().cloned())
+        else {
+            self.show_error("No podcast selected".to_string());
+            return;
+        };
+
+        if value.trim().is_empty() {
+            let presets: Vec<&str> = UserAgentPreset::ALL.iter().map(|p| p.name()).collect();
+            self.show_message(format!(
+                "User agent for '{}': {} (presets: {}, or default)",
+                podcast.title,
+                podcast.user_agent.as_deref().unwrap_or("default"),
+                presets.join(", ")
+            ));
+            return;
+        }
+        let setting = match UserAgentSetting::parse(&value) {
+            Ok(setting) => setting.map(|s| s.to_string()),
+            Err(e) => {
+                self.show_error(e);
+                return;
+            }
+        };
+
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let mut podcast = storage.load_podcast(&podcast.id).await?;
+                podcast.user_agent = setting.clone();
+                storage.save_podcast(&podcast).await?;
+                Ok::<_, crate::storage::StorageError>(podcast.title)
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(podcast_title) => AppEvent::PodcastUserAgentSet {
+                    podcast_title,
+                    user_agent: setting,
+                },
+                Err(e) => AppEvent::PodcastUserAgentSetFailed {
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// `:auto-play [on|off|default]` for the podcast selected in the podcast
+    /// list; without a value, shows the current setting
+    fn set_podcast_auto_play(&mut self, value: Option<&str>) {
+        let Some(podcast) = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .and_then(|buffer| buffer.selected_podcast().cloned())
+        else {
+            self.show_error("No podcast selected".to_string());
+            return;
+        };
+
+        let setting = match value {
+            None => {
+                let current = match podcast.auto_play_next {
+                    Some(true) => "on",
+                    Some(false) => "off",
+                    None if self.config.audio.auto_play_next => "default (on)",
+                    None => "default (off)",
+                };
+                self.show_message(format!(
+                    "Auto-play for '{}': {} (on, off or default)",
+                    podcast.title, current
+                ));
+                return;
+            }
+            Some("on") => Some(true),
+            Some("off") => Some(false),
+            Some("default") => None,
+            Some(other) => {
+                self.show_error(format!(
+                    "Unknown auto-play setting '{}': use on, off or default",
+                    other
+                ));
+                return;
+            }
+        };
+
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let mut podcast = storage.load_podcast(&podcast.id).await?;
+                podcast.auto_play_next = setting;
+                storage.save_podcast(&podcast).await?;
+                Ok::<_, crate::storage::StorageError>(podcast.title)
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(podcast_title) => AppEvent::PodcastAutoPlaySet {
+                    podcast_title,
+                    auto_play: setting,
+                },
+                Err(e) => AppEvent::PodcastAutoPlaySetFailed {
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// `:quality [high|normal|low]` for the podcast selected in the podcast
+    /// list; without a value, shows the current setting
+    fn set_podcast_quality(&mut self, value: Option<&str>) {
+        use crate::podcast::DownloadQuality;
+
+        let Some(podcast) = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .and_then(|buffer| buffer.selected_podcast().cloned())
+        else {
+            self.show_error("No podcast selected".to_string());
+            return;
+        };
+
+        let quality = match value {
+            None => {
+                self.show_message(format!(
+                    "Download quality for '{}': {} ({})",
+                    podcast.title,
+                    podcast.download_quality.unwrap_or_default().label(),
+                    DownloadQuality::NAMES
+                ));
+                return;
+            }
+            Some(name) => match DownloadQuality::parse(name) {
+                Some(quality) => quality,
+                None => {
+                    self.show_error(format!(
+                        "Unknown quality '{}': use {}",
+                        name,
+                        DownloadQuality::NAMES
+                    ));
+                    return;
+                }
+            },
+        };
+        if self.refuse_while_read_only("Changing the download quality") {
+            return;
+        }
+
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let mut podcast = storage.load_podcast(&podcast.id).await?;
+                podcast.download_quality = (quality != DownloadQuality::Normal).then_some(quality);
+                storage.save_podcast(&podcast).await?;
+                Ok::<_, crate::storage::StorageError>(podcast.title)
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(podcast_title) => AppEvent::PodcastQualitySet {
+                    podcast_title,
+                    quality,
+                },
+                Err(e) => AppEvent::PodcastQualitySetFailed {
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// Open the settings view of the podcast selected in the podcast list
+    fn open_podcast_settings(&mut self) {
+        let Some(podcast) = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .and_then(|buffer| buffer.selected_podcast().cloned())
+        else {
+            self.show_error("No podcast selected".to_string());
+            return;
+        };
+        let defaults = crate::ui::buffers::podcast_settings::SettingDefaults {
+            auto_download_new: self.config.downloads.auto_download_new,
+            cleanup_after_days: self.config.downloads.cleanup_after_days,
+        };
+        let buffer_id = self
+            .buffer_manager
+            .create_podcast_settings_buffer(&podcast, defaults);
+        let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+        self.update_status_bar();
+        self.refresh_buffer_list_if_open();
+    }
+
+    /// Save settings changed in the podcast settings view
+    fn save_podcast_settings(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        settings: crate::podcast::PodcastSettings,
+    ) {
+        if self.refuse_while_read_only("Changing podcast settings") {
+            return;
+        }
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let mut podcast = storage.load_podcast(&podcast_id).await?;
+                podcast.settings = settings;
+                storage.save_podcast(&podcast).await?;
+                Ok::<_, crate::storage::StorageError>(podcast)
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok(podcast) => AppEvent::PodcastSettingsSaved {
+                    podcast_id: podcast.id,
+                    podcast_title: podcast.title,
+                    settings: podcast.settings,
+                },
+                Err(e) => AppEvent::PodcastSettingsSaveFailed {
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    /// Ask to archive every show the podcast list flags as ended
+    fn prompt_archive_ended(&mut self) {
+        if self.refuse_while_read_only("Archiving") {
+            return;
+        }
+        let ended: Vec<(crate::storage::PodcastId, String)> = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .map(|buffer| {
+                buffer
+                    .ended_podcasts()
+                    .into_iter()
+                    .map(|podcast| (podcast.id.clone(), podcast.title.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if ended.is_empty() {
+            let message = match self.config.ui.inactive_after_months {
+                0 => "No shows are marked complete by their feed".to_string(),
+                months => format!(
+                    "No complete shows, and every show published in the last {} months",
+                    months
+                ),
+            };
+            self.show_message(message);
+            return;
+        }
+
+        let mut names: Vec<&str> = ended
+            .iter()
+            .take(3)
+            .map(|(_, title)| title.as_str())
+            .collect();
+        if ended.len() > names.len() {
+            names.push("…");
+        }
+        self.minibuffer.set_content(MinibufferContent::Input {
+            prompt: format!(
+                "Archive {} ended show(s): {}? (y/n) ",
+                ended.len(),
+                names.join(", ")
+            ),
+            input: String::new(),
+        });
+        self.pending_archive = Some(ended.into_iter().map(|(id, _)| id).collect());
+    }
+
+    /// Archive (or un-archive) podcasts in the background
+    fn trigger_async_set_archived(
+        &mut self,
+        podcast_ids: Vec<crate::storage::PodcastId>,
+        archived: bool,
+    ) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let mut titles = Vec::new();
+            let mut failed = 0;
+            for podcast_id in &podcast_ids {
+                match subscription_manager
+                    .set_archived(podcast_id, archived)
+                    .await
+                {
+                    Ok(podcast) => titles.push(podcast.title),
+                    Err(_) => failed += 1,
+                }
+            }
+            let _ = app_event_tx.send(AppEvent::PodcastsArchived {
+                titles,
+                archived,
+                failed,
+            });
+        });
+    }
+
+    /// Trigger async persist of removing a tag from a podcast
+    fn trigger_async_remove_tag(&mut self, podcast_id: crate::storage::PodcastId, tag: String) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let tag_clone = tag.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match storage.load_podcast(&podcast_id).await {
+                Ok(mut podcast) => {
+                    podcast.remove_tag(&tag);
+                    match storage.save_podcast(&podcast).await {
+                        Ok(()) => {
+                            let _ = app_event_tx.send(AppEvent::PodcastTagRemoved {
+                                podcast_id: podcast_id_clone,
+                                tag: tag_clone,
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::PodcastTagRemoveFailed {
+                                podcast_id: podcast_id_clone,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastTagRemoveFailed {
+                        podcast_id: podcast_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async cleanup of old downloads
+    fn trigger_async_cleanup_downloads(&mut self, max_age_hours: u64, duration_label: String) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let label = duration_label.clone();
+
+        self.show_message(format!(
+            "Cleaning up downloads older than {}...",
+            duration_label
+        ));
+
+        tokio::spawn(async move {
+            match download_manager
+                .cleanup_old_downloads_hours(max_age_hours)
+                .await
+            {
+                Ok(deleted_count) => {
+                    let _ = app_event_tx.send(AppEvent::DownloadCleanupCompleted {
+                        deleted_count,
+                        duration_label: label,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Apply the retention rules in the background. After a refresh this
+    /// stays quiet unless something was deleted; `announce` reports a clean
+    /// run too (for `:cleanup-now`).
+    fn trigger_retention_cleanup(&mut self, announce: bool) {
+        if self._storage.is_read_only() {
+            return;
+        }
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            match download_manager.apply_retention_policies().await {
+                Ok(0) if !announce => {}
+                Ok(deleted_count) => {
+                    let _ =
+                        app_event_tx.send(AppEvent::RetentionCleanupCompleted { deleted_count });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Work out what the retention rules would delete, for `:cleanup-preview`
+    fn trigger_async_cleanup_preview(&mut self) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            match download_manager.plan_cleanup().await {
+                Ok(plan) => {
+                    let _ = app_event_tx.send(AppEvent::CleanupPlanned { plan });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async downloads refresh
+    fn trigger_async_refresh_downloads(&mut self) {
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            // Send a custom event to trigger refresh on the UI thread
+            let _ = app_event_tx.send(AppEvent::DownloadsRefreshed);
+        });
+    }
+
+    fn trigger_async_create_playlist(&mut self, name: String, description: Option<String>) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let name_for_error = name.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match playlist_manager.create_playlist(&name, description).await {
+                Ok(playlist) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistCreated { playlist });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistCreationFailed {
+                        name: name_for_error,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_create_smart_playlist(
+        &mut self,
+        name: String,
+        rule: crate::playlist::models::SmartPlaylistRule,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let name_for_error = name.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match playlist_manager
+                .create_smart_playlist(&name, None, rule)
+                .await
+            {
+                Ok(playlist) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistCreated { playlist });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistCreationFailed {
+                        name: name_for_error,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Pick downloaded episodes that fit a commute and play them as a temporary queue
+    fn trigger_async_fill_commute(&mut self, request: crate::playlist::commute::CommuteRequest) {
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let podcast_ids = match storage.list_podcasts().await {
+                Ok(ids) => ids,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::CommuteFillFailed {
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            let mut all_episodes = Vec::new();
+            let mut all_podcasts = Vec::new();
+            for pid in &podcast_ids {
+                match storage.load_podcast(pid).await {
+                    Ok(podcast) if podcast.is_removed() => continue,
+                    Ok(podcast) => all_podcasts.push(podcast),
+                    Err(_) => continue,
+                }
+                if let Ok(episodes) = storage.load_episodes(pid).await {
+                    all_episodes.extend(episodes);
+                }
+            }
+
+            let plan = request.plan(&all_episodes, &all_podcasts);
+            let _ = app_event_tx.send(AppEvent::CommuteFilled {
+                budget_secs: request.budget_secs,
+                plan,
+            });
+        });
+    }
+
+    fn trigger_async_evaluate_smart_playlist(
+        &mut self,
+        playlist_id: crate::playlist::PlaylistId,
+        playlist_name: String,
+        detail_buffer_id: String,
+    ) {
+        let storage = self._storage.clone();
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            // Load the playlist to get its rule
+            let playlist = match playlist_manager.get_playlist(&playlist_id).await {
+                Ok(p) => p,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluationFailed {
+                        playlist_name,
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            let rule = match playlist.smart_rules {
+                Some(r) => r,
+                None => return, // not a smart playlist, nothing to do
+            };
+
+            // Load all podcasts and episodes
+            let podcast_ids = match storage.list_podcasts().await {
+                Ok(ids) => ids,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluationFailed {
+                        playlist_name,
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            let mut all_episodes = Vec::new();
+            let mut all_podcasts = Vec::new();
+            for pid in &podcast_ids {
+                match storage.load_podcast(pid).await {
+                    Ok(podcast) if podcast.is_removed() => continue,
+                    Ok(podcast) => all_podcasts.push(podcast),
+                    Err(e) => eprintln!(
+                        "Warning: failed to load podcast '{}' for smart playlist evaluation: {}",
+                        pid, e
+                    ),
+                }
+                match storage.load_episodes(pid).await {
+                    Ok(episodes) => all_episodes.extend(episodes),
+                    Err(e) => eprintln!(
+                        "Warning: failed to load episodes for podcast '{}' in smart playlist evaluation: {}",
+                        pid, e
+                    ),
+                }
+            }
+
+            let raw = rule.evaluate(&all_episodes, &all_podcasts);
+            // Convert Episode → PlaylistEpisode for display
+            let episodes: Vec<crate::playlist::PlaylistEpisode> = raw
+                .into_iter()
+                .enumerate()
+                .map(|(idx, ep)| crate::playlist::PlaylistEpisode {
+                    podcast_id: ep.podcast_id.clone(),
+                    episode_id: ep.id.clone(),
+                    episode_title: Some(ep.display_title().to_string()),
+                    added_at: chrono::Utc::now(),
+                    order: idx + 1,
+                    file_synced: false,
+                    filename: None,
+                })
+                .collect();
+            let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluated {
+                detail_buffer_id,
+                episodes,
+            });
+        });
+    }
+
+    /// List the feeds a pasted web page links to in a discovery buffer;
+    /// selecting one subscribes to it
+    fn show_feed_candidates(
+        &mut self,
+        page_url: String,
+        candidates: Vec<crate::podcast::FeedLink>,
+    ) {
+        let host = url::Url::parse(&page_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| page_url.clone());
+        let results = candidates
+            .into_iter()
+            .map(|feed| crate::podcast::PodcastSearchResult {
+                title: feed.title.unwrap_or_else(|| feed.url.clone()),
+                author: host.clone(),
+                description: feed.url.clone(),
+                feed_url: feed.url,
+                artwork_url: None,
+                categories: Default::default(),
+            })
+            .collect();
+
+        let buffer_id = format!("discovery-feeds-{}", host);
+        if self.buffer_manager.get_buffer(&buffer_id).is_none() {
+            self.buffer_manager
+                .create_discovery_buffer(buffer_id.clone(), format!("Feeds on {}", host));
+        }
+        if let Some(buffer) = self
+            .buffer_manager
+            .get_discovery_buffer_mut_by_id(&buffer_id)
+        {
+            buffer.set_results(results);
+        }
+        let _ = self.buffer_manager.switch_to_buffer(&buffer_id);
+        self.update_status_bar();
+        self.show_message(format!("{} has several feeds; pick one to subscribe", host));
+    }
+
+    /// Trigger an async PodcastIndex search and load results into the discovery buffer.
+    fn trigger_async_discover(&mut self, query: String, buffer_id: String) {
+        let app_event_tx = self.app_event_tx.clone();
+        let api_key = self.config.discovery.podcastindex_api_key.clone();
+        let api_secret = self.config.discovery.podcastindex_api_secret.clone();
+        let display_title = format!("Search: {}", query);
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let client = match crate::podcast::PodcastIndexClient::new(api_key, api_secret) {
+                Ok(c) => c,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
+                        buffer_id,
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            match client.search(&query).await {
+                Ok(results) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryResultsLoaded {
+                        buffer_id,
+                        results,
+                        title: display_title,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
+                        buffer_id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger an async PodcastIndex trending fetch and load results into the discovery buffer.
+    fn trigger_async_trending(&mut self, buffer_id: String) {
+        let app_event_tx = self.app_event_tx.clone();
+        let api_key = self.config.discovery.podcastindex_api_key.clone();
+        let api_secret = self.config.discovery.podcastindex_api_secret.clone();
+
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let client = match crate::podcast::PodcastIndexClient::new(api_key, api_secret) {
+                Ok(c) => c,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
+                        buffer_id,
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            match client.trending().await {
+                Ok(results) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryResultsLoaded {
+                        buffer_id,
+                        results,
+                        title: "Trending".to_string(),
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
+                        buffer_id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_delete_playlist(&mut self, playlist_id: crate::playlist::PlaylistId) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+
+            match playlist_manager.delete_playlist(&playlist_id).await {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistDeleted {
+                        name: playlist_name,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
+                        name: playlist_name,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_delete_playlist_by_name(&mut self, playlist_name: String) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let lookup_name = playlist_name.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match playlist_manager.get_playlist_by_name(&lookup_name).await {
+                Ok(playlist) => match playlist_manager.delete_playlist(&playlist.id).await {
+                    Ok(_) => {
+                        let _ = app_event_tx.send(AppEvent::PlaylistDeleted {
+                            name: playlist.name,
+                        });
+                    }
+                    Err(e) => {
+                        let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
+                            name: playlist.name,
+                            error: e.to_string(),
+                        });
+                    }
+                },
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
+                        name: playlist_name,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_add_to_playlist(
+        &mut self,
+        playlist_id: crate::playlist::PlaylistId,
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let storage = self._storage.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+            let episode_title = storage
+                .load_episode(&podcast_id, &episode_id)
+                .await
+                .map(|episode| episode.display_title().to_string())
+                .unwrap_or_else(|_| episode_id.to_string());
+
+            match playlist_manager
+                .add_episode_to_playlist(&playlist_id, &podcast_id, &episode_id)
+                .await
+            {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeAddedToPlaylist {
+                        playlist_name,
+                        episode_title,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeAddToPlaylistFailed {
+                        playlist_name,
+                        episode_title,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_add_episodes_to_playlist(
+        &mut self,
+        playlist_id: crate::playlist::PlaylistId,
+        episodes: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+            let event =
+                add_episodes_event(&playlist_manager, &playlist_id, playlist_name, &episodes).await;
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    /// Quick-create from the playlist picker: a new playlist holding `episodes`
+    fn trigger_async_create_playlist_with_episodes(
+        &mut self,
+        name: String,
+        episodes: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let event = match playlist_manager.create_playlist(&name, None).await {
+                Ok(playlist) => {
+                    add_episodes_event(&playlist_manager, &playlist.id, playlist.name, &episodes)
+                        .await
+                }
+                Err(e) => AppEvent::PlaylistCreationFailed {
+                    name,
+                    error: e.to_string(),
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    fn trigger_async_remove_from_playlist(
+        &mut self,
+        playlist_id: crate::playlist::PlaylistId,
+        episode_id: crate::storage::EpisodeId,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+
+            match playlist_manager
+                .remove_episode_from_playlist(&playlist_id, &episode_id)
+                .await
+            {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeRemovedFromPlaylist {
+                        playlist_name,
+                        episode_title: episode_id.to_string(),
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodeRemoveFromPlaylistFailed {
+                        playlist_name,
+                        episode_title: episode_id.to_string(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_reorder_playlist(
+        &mut self,
+        playlist_id: crate::playlist::PlaylistId,
+        from_idx: usize,
+        to_idx: usize,
+    ) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+            match playlist_manager
+                .reorder_episode(&playlist_id, from_idx, to_idx)
+                .await
+            {
+                Ok(_) => {
+                    let entries = playlist_manager
+                        .get_playlist(&playlist_id)
+                        .await
+                        .map(|playlist| {
+                            playlist
+                                .episodes
+                                .into_iter()
+                                .map(|entry| QueueEntry {
+                                    podcast_id: entry.podcast_id,
+                                    episode_id: entry.episode_id,
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let _ = app_event_tx.send(AppEvent::PlaylistReordered {
+                        name: playlist_name,
+                        playlist_id,
+                        entries,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistReorderFailed {
+                        name: playlist_name,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_rebuild_playlist(&mut self, playlist_id: crate::playlist::PlaylistId) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let playlist_name = playlist_manager
+                .get_playlist(&playlist_id)
+                .await
+                .map(|playlist| playlist.name)
+                .unwrap_or_else(|_| playlist_id.to_string());
+
+            match playlist_manager.rebuild_playlist_files(&playlist_id).await {
+                Ok(result) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistRebuilt {
+                        name: playlist_name,
+                        rebuilt: result.rebuilt,
+                        skipped: result.skipped,
+                        failed: result.failed,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PlaylistRebuildFailed {
+                        name: playlist_name,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn trigger_async_refresh_today(&mut self) {
+        let today_generator = self.today_generator.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            match today_generator.refresh().await {
+                Ok(result) => {
+                    let _ = app_event_tx.send(AppEvent::TodayPlaylistRefreshed {
+                        added: result.added,
+                        removed: result.removed,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::TodayPlaylistRefreshFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async deletion of all downloads
+    fn trigger_async_delete_all_downloads(&mut self) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        // Show immediate feedback
+        self.show_message("Deleting all downloaded episodes...".to_string());
+
+        tokio::spawn(async move {
+            match download_manager.delete_all_downloads().await {
+                Ok(deleted_count) => {
+                    let _ = app_event_tx.send(AppEvent::AllDownloadsDeleted { deleted_count });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::AllDownloadsDeletionFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Refresh all buffer list buffers when buffers change
+    #[allow(dead_code)]
+    fn refresh_buffer_lists(&mut self) {
+        let _buffer_names = self.buffer_manager.buffer_names();
+        let _current_buffer = self.buffer_manager.current_buffer_id();
+
+        // Find and update any buffer list buffers
+        let buffer_ids = self.buffer_manager.get_buffer_ids();
+        for buffer_id in buffer_ids {
+            if buffer_id == "*Buffer List*" {
+                if let Some(_buffer) = self.buffer_manager.get_buffer(&buffer_id) {
+                    // We need to downcast to update the buffer list
+                    // For now, we'll handle this by recreating the buffer when needed
+                }
+                break;
+            }
+        }
+    }
+
+    /// Refresh buffer list if one is currently open
+    fn refresh_buffer_list_if_open(&mut self) {
+        use crate::ui::buffers::buffer_list::BufferListBuffer;
+
+        let buffer_list_id = "*Buffer List*".to_string();
+        let buffer_infos = self.buffer_manager.buffer_infos();
+        let current_id = self.buffer_manager.current_buffer_id();
+
+        // Update in place so sort order, selection and marks survive
+        if let Some(list) = self
+            .buffer_manager
+            .get_buffer(&buffer_list_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut::<BufferListBuffer>())
+        {
+            list.update_buffer_list(buffer_infos, current_id.as_ref());
+        }
+    }
+
+    /// Whether `prompt` is the one asking what to keep of a podcast being deleted
+    fn is_unsubscribe_prompt(prompt: &str) -> bool {
+        prompt.starts_with("Unsubscribe from '") || prompt.starts_with("Delete podcast '")
+    }
+
+    /// What `y` keeps: everything while it can be restored, else nothing
+    fn default_unsubscribe_mode(&self) -> UnsubscribeMode {
+        if self.config.storage.podcast_restore_days > 0 {
+            UnsubscribeMode::KeepAll
+        } else {
+            UnsubscribeMode::PurgeAll
+        }
+    }
+
+    /// Act on the reply to the unsubscribe prompt
+    fn answer_unsubscribe_prompt(&mut self, input: &str) {
+        let Some(podcast_id) = self.pending_deletion.take() else {
+            self.show_message("No deletion pending".to_string());
+            return;
+        };
+        let restorable = self.config.storage.podcast_restore_days > 0;
+        let mode = match input.to_lowercase().as_str() {
+            "a" | "all" if restorable => UnsubscribeMode::KeepAll,
+            "h" | "history" if restorable => UnsubscribeMode::KeepHistory,
+            "d" | "downloads" => UnsubscribeMode::KeepDownloads,
+            "p" | "purge" => UnsubscribeMode::PurgeAll,
+            "y" | "yes" => self.default_unsubscribe_mode(),
+            "" | "n" | "no" => {
+                self.show_message("Podcast deletion cancelled".to_string());
+                return;
+            }
+            other => {
+                self.show_error(format!("Unknown choice '{}': podcast not deleted", other));
+                return;
+            }
+        };
+        self.trigger_async_delete_podcast(podcast_id, mode);
+    }
+
+    /// Trigger async podcast deletion, keeping what `mode` says
+    fn trigger_async_delete_podcast(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        mode: UnsubscribeMode,
+    ) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+
+        // Get podcast title for the event
+        let podcast_title =
+            if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut() {
+                podcast_buffer
+                    .selected_podcast()
+                    .map(|p| p.title.clone())
+                    .unwrap_or_else(|| "Unknown".to_string())
+            } else {
+                "Unknown".to_string()
+            };
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            match subscription_manager.unsubscribe(&podcast_id, mode).await {
+                Ok(_) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastDeleted {
+                        podcast_id: podcast_id_clone,
+                        podcast_title,
+                        mode,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastDeletionFailed {
+                        podcast_id: podcast_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+
+        // Show immediate feedback
+        self.show_message("Deleting podcast...".to_string());
+    }
+
+    /// Trigger async export of config, themes and smart playlists into a settings bundle
+    /// Export the podcast given by id, or (`Err`) by title, as an HTML
+    /// archive under `dir`
+    fn trigger_async_archive_export(
+        &mut self,
+        podcast: Result<crate::storage::PodcastId, String>,
+        dir: String,
+    ) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let dir = std::path::PathBuf::from(shellexpand::tilde(&dir).to_string());
+        self.show_message("Exporting archive...".to_string());
+
+        tokio::spawn(async move {
+            let result = async {
+                let podcast_id = match podcast {
+                    Ok(podcast_id) => podcast_id,
+                    Err(title) => subscription_manager.find_by_title(&title).await?.id,
+                };
+                let podcast = subscription_manager
+                    .storage
+                    .load_podcast(&podcast_id)
+                    .await
+                    .map_err(|e| SubscriptionError::Storage(e.to_string()))?;
+                let summary = subscription_manager
+                    .export_archive(&podcast_id, &dir)
+                    .await?;
+                Ok::<_, SubscriptionError>((podcast.title, summary))
+            }
+            .await;
+            let _ = app_event_tx.send(match result {
+                Ok((podcast_title, summary)) => AppEvent::ArchiveExported {
+                    podcast_title,
+                    summary,
+                },
+                Err(e) => AppEvent::ArchiveExportFailed {
+                    error: e.to_string(),
+                },
+            });
+        });
+    }
+
+    fn trigger_async_settings_export(&mut self, path: String) {
+        let playlist_manager = self.playlist_manager.clone();
+        let config = self.config.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let themes = match Config::config_dir() {
+                    Ok(dir) => SettingsBundle::collect_themes(&dir).map_err(|e| e.to_string())?,
+                    Err(_) => Vec::new(),
+                };
+                let playlists = playlist_manager
+                    .list_playlists()
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let bundle = SettingsBundle::new(config, themes, &playlists);
+                bundle
+                    .write(std::path::Path::new(&path))
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>((bundle.themes.len(), bundle.smart_playlists.len()))
+            }
+            .await;
+
+            match result {
+                Ok((theme_count, playlist_count)) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsExported {
+                        path,
+                        theme_count,
+                        playlist_count,
+                    });
+                }
+                Err(error) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsExportFailed { path, error });
+                }
+            }
+        });
+    }
+
+    /// Trigger async load of a settings bundle and build its diff preview.
+    /// The bundle handed back carries the config the import will write, with
+    /// this machine's secrets and (unless `with_paths`) its paths kept.
+    fn trigger_async_settings_import_preview(&mut self, path: String, with_paths: bool) {
+        let playlist_manager = self.playlist_manager.clone();
+        let config = self.config.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let mut bundle = SettingsBundle::read(std::path::Path::new(&path))
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let existing_themes = match Config::config_dir() {
+                    Ok(dir) => SettingsBundle::collect_themes(&dir).unwrap_or_default(),
+                    Err(_) => Vec::new(),
+                };
+                let playlists = playlist_manager
+                    .list_playlists()
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let preview = bundle.preview(&config, &existing_themes, &playlists, with_paths);
+                bundle.config = bundle.merged_config(&config, with_paths);
+                Ok::<_, String>((bundle, preview))
+            }
+            .await;
+
+            match result {
+                Ok((bundle, preview)) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsImportPreviewReady {
+                        path,
+                        bundle: Box::new(bundle),
+                        preview,
+                    });
+                }
+                Err(error) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsImportFailed { path, error });
+                }
+            }
+        });
+    }
+
+    /// Trigger async application of a confirmed settings bundle
+    fn trigger_async_settings_import_apply(&mut self, path: String, bundle: Box<SettingsBundle>) {
+        let playlist_manager = self.playlist_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            let result = async {
+                let config_dir = Config::config_dir().map_err(|e| e.to_string())?;
+                let theme_count = bundle
+                    .install_themes(&config_dir)
+                    .map_err(|e| e.to_string())?;
+                for def in &bundle.smart_playlists {
+                    playlist_manager
+                        .upsert_smart_playlist(&def.name, def.description.clone(), def.rule.clone())
+                        .await
+                        .map_err(|e| format!("playlist '{}': {}", def.name, e))?;
+                }
+                let config_path = Config::default_config_path().map_err(|e| e.to_string())?;
+                bundle
+                    .config
+                    .save(&config_path)
+                    .map_err(|e| e.to_string())?;
+                Ok::<_, String>(theme_count)
+            }
+            .await;
+
+            match result {
+                Ok(theme_count) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsImported {
+                        playlist_count: bundle.smart_playlists.len(),
+                        config: Box::new(bundle.config),
+                        theme_count,
+                    });
+                }
+                Err(error) => {
+                    let _ = app_event_tx.send(AppEvent::SettingsImportFailed { path, error });
+                }
+            }
+        });
+
+        self.show_message("Applying settings...".to_string());
+    }
+
+    /// Trigger async listing of recently removed podcasts
+    fn trigger_async_list_removed_podcasts(&mut self) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            match subscription_manager.list_removed_podcasts().await {
+                Ok(podcasts) => {
+                    let _ = app_event_tx.send(AppEvent::RemovedPodcastsListed { podcasts });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRestoreFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async restore of a removed podcast, matched by title (case-insensitive)
+    fn trigger_async_restore_podcast(&mut self, title: String) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+
+        tokio::spawn(async move {
+            let removed = match subscription_manager.list_removed_podcasts().await {
+                Ok(podcasts) => podcasts,
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRestoreFailed {
+                        error: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            let needle = title.to_lowercase();
+            let Some(podcast) = removed
+                .iter()
+                .find(|p| p.title.to_lowercase() == needle)
+                .or_else(|| {
+                    removed
+                        .iter()
+                        .find(|p| p.title.to_lowercase().contains(&needle))
+                })
+            else {
+                let _ = app_event_tx.send(AppEvent::PodcastRestoreFailed {
+                    error: format!("No recently removed podcast matches '{}'", title),
+                });
+                return;
+            };
+            match subscription_manager.restore_podcast(&podcast.id).await {
+                Ok(podcast) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRestored {
+                        podcast_title: podcast.title,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::PodcastRestoreFailed {
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Read an OPML file in the background and list its feeds in the
+    /// preview buffer
+    fn trigger_async_opml_preview(&mut self, source: String) {
+        self.show_message(format!("Reading OPML from: {}...", source));
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        self.background_tasks.spawn(TaskKind::Refresh, async move {
+            let event = match subscription_manager.preview_opml(&source).await {
+                Ok(preview) => AppEvent::OpmlPreviewLoaded { preview },
+                Err(e) => AppEvent::OpmlPreviewFailed {
+                    source,
+                    error: e.to_string(),
+                },
+            };
+            let _ = app_event_tx.send(event);
+        });
+    }
+
+    /// Import the feeds checked in the OPML preview buffer and close it
+    fn import_previewed_opml(&mut self) {
+        let Some(buffer) = self.buffer_manager.get_opml_preview_buffer_mut() else {
+            return;
+        };
+        let selection = (buffer.preview().clone(), buffer.selected_urls());
+        if self.start_opml_import(selection.0.source.clone(), Some(selection)) {
+            let _ = self
+                .buffer_manager
+                .remove_buffer(&"opml-preview".to_string());
+            let _ = self
+                .buffer_manager
+                .switch_to_buffer(&"podcast-list".to_string());
+            self.update_status_bar();
+            self.refresh_buffer_list_if_open();
+        }
+    }
+
+    /// Trigger async OPML import of every feed in `source`
+    fn trigger_async_opml_import(&mut self, source: String) {
+        self.start_opml_import(source, None);
+    }
+
+    /// Import `source`, or only the chosen feeds of its preview when
+    /// `selection` is given. Returns whether the import started.
+    fn start_opml_import(
+        &mut self,
+        source: String,
+        selection: Option<(
+            crate::podcast::OpmlPreview,
+            std::collections::HashSet<String>,
+        )>,
+    ) -> bool {
+        if self.opml_import.is_some() {
+            self.show_error("An OPML import is already running (C-g to cancel it)".to_string());
+            return false;
+        }
+
+        let options = crate::podcast::OpmlImportOptions::new(
+            self.config.storage.opml_import_concurrency,
+            Duration::from_millis(self.config.storage.opml_import_host_interval_ms),
+        );
+        self.opml_import = Some(options.clone());
+
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let source_clone = source.clone();
+
+        // Send start event
+        let _ = app_event_tx.send(AppEvent::OpmlImportStarted {
+            source: source.clone(),
+        });
+
+        tokio::spawn(async move {
+            // Create progress callback
+            let app_event_tx_progress = app_event_tx.clone();
+            let progress_callback = move |progress: crate::podcast::ImportProgress| {
+                let _ = app_event_tx_progress.send(AppEvent::OpmlImportProgress {
+                    done: progress.done,
+                    failed: progress.failed,
+                    total: progress.total,
+                    status: progress.status,
+                });
+            };
+
+            let imported = match selection {
+                Some((preview, selected)) => {
+                    subscription_manager
+                        .import_opml_selected(&preview, &selected, &options, progress_callback)
+                        .await
+                }
+                None => {
+                    subscription_manager
+                        .import_opml_with_options(&source, &options, progress_callback)
+                        .await
+                }
+            };
+            match imported {
+                Ok((result, log_path)) => {
+                    let _ = app_event_tx.send(AppEvent::OpmlImportCompleted { result, log_path });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::OpmlImportFailed {
+                        source: source_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+        true
+    }
+
+    /// Save the podcast list ordering to the config file when it has changed
+    fn persist_podcast_sort(&mut self) {
+        let Some(mode) = self
+            .buffer_manager
+            .get_podcast_list_buffer_mut()
+            .map(|b| b.sort_mode())
+        else {
+            return;
+        };
+        if self.config.ui.podcast_list_sort == mode.as_config_str() {
+            return;
+        }
+        self.config.ui.podcast_list_sort = mode.as_config_str().to_string();
+
+        if let Err(e) =
+            Config::persist(|config| config.ui.podcast_list_sort = mode.as_config_str().to_string())
+        {
+            self.show_error(format!("Could not save podcast list sort: {}", e));
+        }
+    }
+
+    /// Close the device picker and ask the audio thread to switch output.
+    /// The choice is saved once the switch succeeds (`AudioDeviceChanged`).
+    fn select_audio_device(&mut self, device: Option<String>) {
+        let picker_id = "audio-device-picker".to_string();
+        if self.buffer_manager.current_buffer_id().as_ref() == Some(&picker_id) {
+            let _ = self.buffer_manager.remove_buffer(&picker_id);
+            self.update_status_bar();
+            self.refresh_buffer_list_if_open();
+        }
+        match self.audio_command_tx {
+            Some(ref tx) => {
+                let _ = tx.send(AudioCommand::SelectOutputDevice(device));
+                self.show_message("Switching audio output...".to_string());
+            }
+            None => self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string()),
+        }
+    }
+
+    /// Close the cast picker and ask the audio thread to move playback to
+    /// `target` (`None` = this computer)
+    fn select_cast_target(&mut self, target: Option<crate::audio::cast::CastTarget>) {
+        let picker_id = "cast-picker".to_string();
+        if self.buffer_manager.current_buffer_id().as_ref() == Some(&picker_id) {
+            let _ = self.buffer_manager.remove_buffer(&picker_id);
+            self.update_status_bar();
+            self.refresh_buffer_list_if_open();
+        }
+        let Some(tx) = self.audio_command_tx.clone() else {
+            self.show_error(crate::constants::audio::UNAVAILABLE_ERROR.to_string());
+            return;
+        };
+        match target {
+            Some(ref target) => self.show_message(format!("Connecting to {}...", target.label())),
+            None if self.cast_target.is_none() => {
+                self.show_message("Not casting".to_string());
+                return;
+            }
+            None => {}
+        }
+        let _ = tx.send(AudioCommand::CastTo(target));
+    }
+
+    /// Search the local network for renderers; the picker opens when done
+    fn trigger_async_discover_cast_targets(&mut self) {
+        let app_event_tx = self.app_event_tx.clone();
+        self.show_message("Looking for cast targets...".to_string());
+
+        tokio::spawn(async move {
+            let timeout = Duration::from_secs(crate::constants::audio::CAST_DISCOVERY_TIMEOUT_SECS);
+            let targets =
+                tokio::task::spawn_blocking(move || crate::audio::cast::discover(timeout))
+                    .await
+                    .unwrap_or_default();
+            let _ = app_event_tx.send(AppEvent::CastTargetsDiscovered { targets });
+        });
+    }
+
+    /// Save the chosen output device so the next start uses it too
+    fn persist_audio_device(&mut self, device: Option<String>) {
+        if self.config.audio.output_device == device {
+            return;
+        }
+        self.config.audio.output_device = device.clone();
+
+        if let Err(e) = Config::persist(|config| config.audio.output_device = device) {
+            self.show_error(format!("Could not save audio device: {}", e));
+        }
+    }
+
+    /// Poll the power source in the background while `pause_on_battery` is on
+    /// or the status bar shows the battery
+    fn spawn_power_monitor(&self) {
+        if !self.config.downloads.pause_on_battery && !self.status_bar.shows(SegmentKind::Battery) {
+            return;
+        }
+
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(crate::constants::downloads::POWER_POLL_INTERVAL);
+            let mut last = None;
+            loop {
+                interval.tick().await;
+                let source = tokio::task::spawn_blocking(read_power_source)
+                    .await
+                    .unwrap_or(PowerSource::Unknown);
+                if last != Some(source) {
+                    last = Some(source);
+                    if app_event_tx
+                        .send(AppEvent::PowerSourceChanged { source })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Whether the configured low-battery rule currently calls for a pause
+    fn battery_pause_active(&self) -> bool {
+        self.config.downloads.pause_on_battery
+            && self
+                .power_source
+                .is_low_battery(self.config.downloads.battery_pause_threshold)
+    }
+
+    /// The status bar flag for a heavy-I/O pause, `None` when not paused
+    fn io_pause_indicator(&self) -> Option<String> {
+        match self.io_pause_override {
+            Some(true) => Some("⏸ I/O paused".to_string()),
+            Some(false) => None,
+            None => self
+                .battery_pause_active()
+                .then(|| format!("⏸ I/O paused ({})", self.power_source.label())),
+        }
+    }
+
+    /// Open or close the download manager's I/O gate and update the status bar
+    fn apply_io_pause(&mut self) {
+        let paused = self.io_pause_indicator().is_some();
+        self.download_manager.io_gate().set_paused(paused);
+        self.update_indicator();
+    }
+
+    /// Show read-only mode, an I/O pause and focus mode side by side in the
+    /// status bar's indicator
+    fn update_indicator(&mut self) {
+        let focus = self.focus_until.map(|until| {
+            let minutes = until.saturating_duration_since(Instant::now()).as_secs() / 60 + 1;
+            format!("Focus {}m", minutes)
+        });
+        let indicator = self
+            ._storage
+            .is_read_only()
+            .then(|| READ_ONLY_INDICATOR.to_string())
+            .into_iter()
+            .chain(self.io_pause_indicator())
+            .chain(focus)
+            .collect::<Vec<_>>()
+            .join(" · ");
+        self.status_bar.set_indicator(indicator);
+    }
+
+    /// Whether focus mode is hiding messages
+    fn focus_active(&self) -> bool {
+        self.focus_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Start focus mode for `minutes`, or end it with `None`
+    fn set_focus_mode(&mut self, minutes: Option<u32>) {
+        self.focus_until =
+            minutes.map(|minutes| Instant::now() + Duration::from_secs(minutes as u64 * 60));
+        self.update_indicator();
+        match minutes {
+            // Said directly, since show_message is now muted
+            Some(minutes) => self
+                .minibuffer
+                .set_content(MinibufferContent::Message(format!(
+                    "Focus mode for {} min: only errors are shown (:focus off ends it)",
+                    minutes
+                ))),
+            None => self.show_message("Focus mode off".to_string()),
+        }
+    }
+
+    /// Keep the focus mode countdown current, and end it once it runs out
+    fn tick_focus_mode(&mut self) {
+        if self.focus_until.is_none() {
+            return;
+        }
+        if self.focus_active() {
+            self.update_indicator();
+        } else {
+            self.focus_until = None;
+            self.update_indicator();
+            self.show_message("Focus mode ended".to_string());
+        }
+    }
+
+    /// Ask the running OPML import to stop starting new feeds
+    fn cancel_opml_import(&mut self) {
+        match &self.opml_import {
+            Some(options) if options.is_cancelled() => {
+                self.show_message("OPML import is already stopping...".to_string());
+            }
+            Some(options) => {
+                options.cancel();
+                self.show_message(
+                    "Cancelling OPML import; waiting for feeds already in flight...".to_string(),
+                );
+            }
+            None => self.show_message("No OPML import is running".to_string()),
+        }
+    }
+
+    /// Get the default sync device path from config, falling back to the constant default
+    fn get_default_sync_path(&self) -> String {
+        self.config
+            .downloads
+            .sync_device_path
+            .as_ref()
+            .map(|p| shellexpand::tilde(p).to_string())
+            .unwrap_or_else(|| crate::constants::downloads::DEFAULT_SYNC_DEVICE_PATH.to_string())
+    }
+
+    /// Parse sync command arguments, extracting optional --hard and optional device path.
+    fn parse_sync_command_args(args: &[&str]) -> (Option<String>, bool) {
+        let mut hard_sync = false;
+        let mut path_parts = Vec::new();
+
+        for arg in args {
+            if *arg == "--hard" {
+                hard_sync = true;
+            } else {
+                path_parts.push(*arg);
+            }
+        }
+
+        if path_parts.is_empty() {
+            (None, hard_sync)
+        } else {
+            (Some(path_parts.join(" ")), hard_sync)
+        }
+    }
+
+    /// Trigger async device sync
+    fn trigger_async_device_sync(
+        &mut self,
+        device_path_str: String,
+        delete_orphans: bool,
+        dry_run: bool,
+        hard_sync: bool,
+    ) {
+        let download_manager = self.download_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let playlists_dir = if self.config.downloads.sync_include_playlists {
+            Some(self._storage.data_dir.join("Playlists"))
+        } else {
+            None
+        };
+
+        // Expand tilde and convert to PathBuf
+        let expanded_path = shellexpand::tilde(&device_path_str).to_string();
+        let device_path = std::path::PathBuf::from(expanded_path);
+        let device_path_clone = device_path.clone();
+
+        // Use config defaults if not specified
+        let delete_orphans = if delete_orphans {
+            true
+        } else {
+            self.config.downloads.sync_delete_orphans
+        };
+
+        // Send start event
+        let _ = app_event_tx.send(AppEvent::DeviceSyncStarted {
+            device_path: device_path.clone(),
+            dry_run,
+            hard_sync,
+        });
+
+        // For real syncs (not dry-run): create a progress channel and enter Progress mode.
+        let progress_tx = if !dry_run {
+            if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
+                sync_buffer.enter_progress_mode(device_path.clone());
+            }
+            let (tx, mut rx) =
+                tokio::sync::mpsc::unbounded_channel::<crate::download::SyncProgressEvent>();
+            // Relay progress events to app_event_tx
+            let relay_tx = app_event_tx.clone();
+            tokio::spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    let _ = relay_tx.send(AppEvent::DeviceSyncProgress { event });
+                }
+            });
+            Some(tx)
+        } else {
+            None
+        };
+
+        // A target set to one playlist gets only that playlist, in order
+        let target_playlist = self
+            .buffer_manager
+            .get_sync_buffer_mut()
+            .and_then(|sync_buffer| sync_buffer.target_playlist(&device_path))
+            .map(str::to_string);
+        let playlist_manager = self.playlist_manager.clone();
+
+        tokio::spawn(async move {
+            let result = match target_playlist {
+                Some(playlist_name) => {
+                    let files = match playlist_manager.get_playlist_by_name(&playlist_name).await {
+                        Ok(playlist) => playlist_manager.sync_entries(&playlist).await,
+                        Err(e) => Err(e),
+                    };
+                    match files {
+                        Ok(files) => {
+                            download_manager
+                                .sync_files_to_device(
+                                    device_path.clone(),
+                                    crate::playlist::playlist_device_dir(&playlist_name),
+                                    files
+                                        .into_iter()
+                                        .map(|file| (file.device_path, file.source))
+                                        .collect(),
+                                    crate::download::SyncOptions {
+                                        delete_orphans,
+                                        dry_run,
+                                        hard_sync,
+                                    },
+                                    progress_tx,
+                                )
+                                .await
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::DeviceSyncFailed {
+                                device_path: device_path_clone,
+                                error: e.to_string(),
+                            });
+                            return;
+                        }
+                    }
+                }
+                None => {
+                    download_manager
+                        .sync_to_device(
+                            device_path.clone(),
+                            playlists_dir,
+                            delete_orphans,
+                            dry_run,
+                            hard_sync,
+                            progress_tx,
+                        )
+                        .await
+                }
+            };
+            match result {
+                Ok(report) => {
+                    let _ = app_event_tx.send(AppEvent::DeviceSyncCompleted {
+                        device_path,
+                        report,
+                        dry_run,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::DeviceSyncFailed {
+                        device_path: device_path_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async OPML export
+    fn trigger_async_opml_export(&mut self, output_path: String) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let output_path_clone = output_path.clone();
+
+        // Expand tilde and generate filename if needed
+        let expanded_path = shellexpand::tilde(&output_path).to_string();
+        let final_path =
+            if std::path::Path::new(&expanded_path).is_dir() || !expanded_path.contains('.') {
+                // It's a directory or doesn't have an extension, generate filename
+                use chrono::Local;
+                let timestamp = Local::now().format("%Y-%m-%d-%H%M%S");
+                let filename = format!("podcasts-export-{}.opml", timestamp);
+                std::path::PathBuf::from(expanded_path).join(filename)
+            } else {
+                // It's a full file path
+                std::path::PathBuf::from(expanded_path)
+            };
+
+        let final_path_str = final_path.to_string_lossy().to_string();
 
-    /// Set the application theme (direct version)
-    fn set_theme_direct(&mut self, theme_name: &str) -> UIResult<bool> {
-        match self.theme_registry.get(theme_name).cloned() {
-            Some(new_theme) => {
-                self.theme = new_theme.clone();
-                self.buffer_manager.set_theme_all(&new_theme);
-                self.minibuffer.set_theme(new_theme.clone());
-                self.status_bar.set_theme(new_theme);
-                self.show_message(format!("Theme changed to: {}", theme_name));
-                Ok(true)
+        // Send start event
+        let _ = app_event_tx.send(AppEvent::OpmlExportStarted {
+            path: final_path_str.clone(),
+        });
+
+        self.background_tasks.spawn(TaskKind::Write, async move {
+            // Create progress callback
+            let app_event_tx_progress = app_event_tx.clone();
+            let progress_callback = move |status: String| {
+                let _ = app_event_tx_progress.send(AppEvent::OpmlExportProgress { status });
+            };
+
+            match subscription_manager
+                .export_opml(&final_path, progress_callback)
+                .await
+            {
+                Ok(feed_count) => {
+                    let _ = app_event_tx.send(AppEvent::OpmlExportCompleted {
+                        path: final_path_str,
+                        feed_count,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::OpmlExportFailed {
+                        path: output_path_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger async episode loading
+    fn trigger_async_load_episodes(
+        &mut self,
+        podcast_id: crate::storage::PodcastId,
+        podcast_name: String,
+    ) {
+        let subscription_manager = self.subscription_manager.clone();
+        let app_event_tx = self.app_event_tx.clone();
+        let podcast_id_clone = podcast_id.clone();
+        let podcast_name_clone = podcast_name.clone();
+
+        tokio::spawn(async move {
+            match subscription_manager
+                .storage
+                .load_episodes(&podcast_id)
+                .await
+            {
+                Ok(episodes) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodesLoaded {
+                        podcast_id: podcast_id_clone,
+                        podcast_name: podcast_name_clone,
+                        episodes,
+                    });
+                }
+                Err(e) => {
+                    let _ = app_event_tx.send(AppEvent::EpisodesLoadFailed {
+                        podcast_id: podcast_id_clone,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Trigger background buffer refresh to avoid blocking UI thread
+    fn trigger_background_refresh(&mut self, refresh_type: BufferRefreshType) {
+        match refresh_type.clone() {
+            BufferRefreshType::PodcastList => {
+                let subscription_manager = self.subscription_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+
+                self.background_tasks.spawn(TaskKind::Refresh, async move {
+                    match subscription_manager.storage.list_podcasts().await {
+                        Ok(podcast_ids) => {
+                            let storage = subscription_manager.storage.clone();
+                            let mut indexed: Vec<(usize, _, _)> =
+                                stream::iter(podcast_ids.into_iter().enumerate())
+                                    .map(|(idx, id)| {
+                                        let storage = storage.clone();
+                                        async move {
+                                            let podcast = storage
+                                                .load_podcast(&id)
+                                                .await
+                                                .ok()
+                                                .filter(|p| !p.is_removed())?;
+                                            let episodes = storage
+                                                .load_episodes(&id)
+                                                .await
+                                                .unwrap_or_default();
+                                            let mut summary =
+                                                PodcastSummary::from_episodes(&episodes);
+                                            for path in episodes
+                                                .iter()
+                                                .filter(|e| e.is_downloaded())
+                                                .filter_map(|e| e.local_path.as_ref())
+                                            {
+                                                if let Ok(meta) = tokio::fs::metadata(path).await {
+                                                    summary.disk_bytes += meta.len();
+                                                }
+                                            }
+                                            Some((idx, podcast, summary))
+                                        }
+                                    })
+                                    .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
+                                    .filter_map(|r| async { r })
+                                    .collect()
+                                    .await;
+                            indexed.sort_unstable_by_key(|(idx, ..)| *idx);
+                            let mut summaries = HashMap::new();
+                            let podcasts: Vec<_> = indexed
+                                .into_iter()
+                                .map(|(_, podcast, summary)| {
+                                    summaries.insert(podcast.id.clone(), summary);
+                                    podcast
+                                })
+                                .collect();
+                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                                buffer_type: BufferRefreshType::PodcastList,
+                                data: BufferRefreshData::PodcastList {
+                                    podcasts,
+                                    summaries,
+                                },
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                                buffer_type: BufferRefreshType::PodcastList,
+                                data: BufferRefreshData::Error {
+                                    message: e.to_string(),
+                                },
+                            });
+                        }
+                    }
+                });
+            }
+            BufferRefreshType::Downloads => {
+                let download_manager = self.download_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+
+                self.background_tasks.spawn(TaskKind::Refresh, async move {
+                    // Load all (podcast, episodes) pairs concurrently
+                    let downloads = if let Ok(podcast_ids) =
+                        download_manager.storage().list_podcasts().await
+                    {
+                        let dm = download_manager.clone();
+                        let mut podcast_pairs: Vec<_> =
+                            stream::iter(podcast_ids.into_iter().enumerate())
+                                .map(|(idx, podcast_id)| {
+                                    let dm = dm.clone();
+                                    async move {
+                                        let podcast = dm
+                                            .storage()
+                                            .load_podcast(&podcast_id)
+                                            .await
+                                            .ok()
+                                            .filter(|p| !p.is_removed())?;
+                                        let episodes =
+                                            dm.storage().load_episodes(&podcast_id).await.ok()?;
+                                        Some((idx, podcast_id, podcast, episodes))
+                                    }
+                                })
+                                .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
+                                .filter_map(|r| async { r })
+                                .collect()
+                                .await;
+                        podcast_pairs.sort_unstable_by_key(|(idx, ..)| *idx);
+
+                        // Collect candidates (filter without I/O), then fetch metadata concurrently
+                        let mut candidates: Vec<(
+                            usize,
+                            crate::storage::PodcastId,
+                            crate::podcast::Podcast,
+                            crate::podcast::Episode,
+                        )> = Vec::new();
+                        let mut candidate_idx = 0usize;
+                        for (_, podcast_id, podcast, episodes) in podcast_pairs {
+                            for episode in episodes {
+                                if episode.is_downloaded()
+                                    || matches!(
+                                        episode.status.download,
+                                        DownloadState::Downloading | DownloadState::Failed
+                                    )
+                                {
+                                    candidates.push((
+                                        candidate_idx,
+                                        podcast_id.clone(),
+                                        podcast.clone(),
+                                        episode,
+                                    ));
+                                    candidate_idx += 1;
+                                }
+                            }
+                        }
+
+                        // Fetch file sizes concurrently with bounded concurrency; sort to restore order
+                        let mut indexed: Vec<(usize, DownloadEntry)> = stream::iter(candidates)
+                            .map(|(idx, podcast_id, podcast, episode)| async move {
+                                let status = match episode.status.download {
+                                    DownloadState::Downloaded => {
+                                        crate::download::DownloadStatus::Completed
+                                    }
+                                    DownloadState::Downloading => {
+                                        crate::download::DownloadStatus::InProgress
+                                    }
+                                    DownloadState::Failed => {
+                                        crate::download::DownloadStatus::Failed(
+                                            "Download failed".to_string(),
+                                        )
+                                    }
+                                    DownloadState::NotDownloaded => return None,
+                                };
+                                let file_size = match episode.local_path.as_ref() {
+                                    Some(path) => {
+                                        tokio::fs::metadata(path).await.ok().map(|m| m.len())
+                                    }
+                                    None => None,
+                                };
+                                Some((
+                                    idx,
+                                    DownloadEntry {
+                                        podcast_id,
+                                        episode_id: episode.id.clone(),
+                                        podcast_name: podcast.title.clone(),
+                                        episode_title: episode.display_title().to_string(),
+                                        status,
+                                        file_path: episode.local_path.clone(),
+                                        file_size,
+                                    },
+                                ))
+                            })
+                            .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
+                            .filter_map(|r| async { r })
+                            .collect()
+                            .await;
+                        indexed.sort_unstable_by_key(|(idx, _)| *idx);
+                        indexed.into_iter().map(|(_, entry)| entry).collect()
+                    } else {
+                        Vec::new()
+                    };
+                    let usage = download_manager.storage_usage().await.unwrap_or_default();
+
+                    let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                        buffer_type: BufferRefreshType::Downloads,
+                        data: BufferRefreshData::Downloads { downloads, usage },
+                    });
+                });
+            }
+            BufferRefreshType::WhatsNew => {
+                // The Unplayed view shows a superset of the same data, so keep it in step
+                if self.buffer_manager.get_unplayed_buffer_mut().is_some() {
+                    self.trigger_background_refresh(BufferRefreshType::Unplayed);
+                }
+
+                let subscription_manager = self.subscription_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+                let episode_limit = self.config.ui.whats_new_episode_limit;
+                let exclusions = self.whats_new_exclusions.clone();
+
+                self.background_tasks.spawn(TaskKind::Refresh, async move {
+                    // Load What's New episodes data in background
+                    let mut all_episodes = load_aggregated_episodes(
+                        &subscription_manager,
+                        EpisodeScope::Latest,
+                        Some(&exclusions),
+                    )
+                    .await;
+
+                    // Apply episode limit
+                    all_episodes.truncate(episode_limit);
+
+                    let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                        buffer_type: BufferRefreshType::WhatsNew,
+                        data: BufferRefreshData::WhatsNew {
+                            episodes: all_episodes,
+                        },
+                    });
+                });
+            }
+            BufferRefreshType::Unplayed => {
+                let subscription_manager = self.subscription_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+
+                self.background_tasks.spawn(TaskKind::Refresh, async move {
+                    let episodes = load_aggregated_episodes(
+                        &subscription_manager,
+                        EpisodeScope::Unplayed,
+                        None,
+                    )
+                    .await;
+                    let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                        buffer_type: BufferRefreshType::Unplayed,
+                        data: BufferRefreshData::Unplayed { episodes },
+                    });
+                });
+            }
+            BufferRefreshType::EpisodeBuffers { podcast_id } => {
+                let subscription_manager = self.subscription_manager.clone();
+                let app_event_tx = self.app_event_tx.clone();
+
+                self.background_tasks.spawn(TaskKind::Refresh, async move {
+                    match subscription_manager
+                        .storage
+                        .load_episodes(&podcast_id)
+                        .await
+                    {
+                        Ok(episodes) => {
+                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                                buffer_type: BufferRefreshType::EpisodeBuffers {
+                                    podcast_id: podcast_id.clone(),
+                                },
+                                data: BufferRefreshData::Episodes {
+                                    podcast_id,
+                                    episodes,
+                                },
+                            });
+                        }
+                        Err(e) => {
+                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
+                                buffer_type: BufferRefreshType::EpisodeBuffers { podcast_id },
+                                data: BufferRefreshData::Error {
+                                    message: e.to_string(),
+                                },
+                            });
+                        }
+                    }
+                });
             }
-            None => {
-                self.show_error(format!("Unknown theme: {}", theme_name));
-                Ok(true)
+            BufferRefreshType::AllEpisodeBuffers => {
+                // Each open episode buffer reloads its own podcast
+                for refresh in self.buffer_manager.open_buffer_refreshes() {
+                    if matches!(refresh, BufferRefreshType::EpisodeBuffers { .. }) {
+                        self.trigger_background_refresh(refresh);
+                    }
+                }
             }
         }
     }
 
-    /// Show list of available buffers
-    fn show_buffer_list(&mut self) {
-        use crate::ui::buffers::buffer_list::BufferListBuffer;
-
-        let buffer_names = self.buffer_manager.buffer_names();
-        let current_id = self.buffer_manager.current_buffer_id();
-
-        // Remove existing buffer list if it exists
-        let buffer_list_id = "*Buffer List*".to_string();
-        let _ = self.buffer_manager.remove_buffer(&buffer_list_id);
-
-        // Create new buffer list buffer
-        let mut buffer_list_buffer = BufferListBuffer::new();
-        buffer_list_buffer.set_theme(self.theme.clone());
-        buffer_list_buffer.update_buffer_list(buffer_names, current_id.as_ref());
-
-        // Add and switch to buffer list buffer
-        if self
-            .buffer_manager
-            .add_buffer(Box::new(buffer_list_buffer))
-            .is_ok()
-        {
-            let _ = self.buffer_manager.switch_to_buffer(&buffer_list_id);
+    /// Start the background reloads buffers asked for when they gained focus
+    fn run_pending_buffer_refreshes(&mut self) {
+        for refresh in self.buffer_manager.take_pending_refreshes() {
+            self.trigger_background_refresh(refresh);
         }
-
-        self.update_status_bar();
     }
 
-    /// Switch to buffer by name with smart matching
-    fn switch_to_buffer_by_name(&mut self, buffer_name: String) -> UIResult<bool> {
-        let buffer_names = self.buffer_manager.buffer_names();
-
-        // Handle common aliases
-        let normalized_name = match buffer_name.as_str() {
-            "podcasts" | "podcast" | "main" => "podcast-list".to_string(),
-            "help" => "*Help: Keybindings*".to_string(),
-            "download" | "dl" => "downloads".to_string(),
-            "new" | "whats-new" | "latest" => "whats-new".to_string(),
-            "sync" | "device-sync" => "sync".to_string(),
-            "playlist" | "playlists" => "playlist-list".to_string(),
-            _ => buffer_name.clone(),
-        };
-
-        // Try exact match first (by ID)
-        if buffer_names.iter().any(|(id, _)| id == &normalized_name) {
-            let _ = self.buffer_manager.switch_to_buffer(&normalized_name);
-            self.update_status_bar();
-            return Ok(true);
-        }
-
-        // Try exact match by display name
-        if let Some((id, _)) = buffer_names
-            .iter()
-            .find(|(_, name)| name == &normalized_name)
-        {
-            let _ = self.buffer_manager.switch_to_buffer(id);
-            self.update_status_bar();
-            return Ok(true);
-        }
-
-        // Try partial match (case insensitive)
-        let lower_search = normalized_name.to_lowercase();
-        let matches: Vec<_> = buffer_names
-            .iter()
-            .filter(|(id, name)| {
-                id.to_lowercase().contains(&lower_search)
-                    || name.to_lowercase().contains(&lower_search)
-            })
-            .collect();
+    /// Handle buffer data refresh by updating buffers with pre-loaded data
+    fn handle_buffer_data_refresh(
+        &mut self,
+        buffer_type: BufferRefreshType,
+        data: BufferRefreshData,
+    ) {
+        match (buffer_type, data) {
+            (
+                BufferRefreshType::PodcastList,
+                BufferRefreshData::PodcastList {
+                    podcasts,
+                    summaries,
+                },
+            ) => {
+                if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut() {
+                    podcast_buffer.set_summaries(summaries);
+                    podcast_buffer.set_podcasts(podcasts);
+                }
+            }
+            (BufferRefreshType::Downloads, BufferRefreshData::Downloads { downloads, usage }) => {
+                if let Some(downloads_buffer) = self.buffer_manager.get_downloads_buffer_mut() {
+                    downloads_buffer.set_downloads(downloads);
+                    downloads_buffer.set_storage_usage(usage);
+                }
+            }
+            (BufferRefreshType::WhatsNew, BufferRefreshData::WhatsNew { episodes }) => {
+                if let Some(whats_new_buffer) = self.buffer_manager.get_whats_new_buffer_mut() {
+                    let episode_count = episodes.len();
+                    whats_new_buffer.set_episodes(episodes);
 
-        match matches.len() {
-            0 => {
-                self.show_error(format!("No buffer found matching: {}", buffer_name));
-                Ok(true)
+                    // Show message only if we're currently viewing the What's New buffer
+                    if let Some(active_id) = self.buffer_manager.active_buffer_id() {
+                        if active_id == "whats-new" {
+                            self.show_message(format!(
+                                "What's New updated with {} episode(s)",
+                                episode_count
+                            ));
+                        }
+                    }
+                }
             }
-            1 => {
-                let (id, _) = matches[0];
-                let _ = self.buffer_manager.switch_to_buffer(id);
-                self.update_status_bar();
-                Ok(true)
+            (BufferRefreshType::Unplayed, BufferRefreshData::Unplayed { episodes }) => {
+                if let Some(unplayed_buffer) = self.buffer_manager.get_unplayed_buffer_mut() {
+                    unplayed_buffer.set_episodes(episodes);
+                }
             }
-            _ => {
-                let mut msg = format!("Multiple buffers match '{}':\n", buffer_name);
-                for (id, name) in matches {
-                    if id != name {
-                        msg.push_str(&format!("  {} ({})\n", name, id));
-                    } else {
-                        msg.push_str(&format!("  {}\n", name));
+            (
+                BufferRefreshType::EpisodeBuffers { podcast_id },
+                BufferRefreshData::Episodes { episodes, .. },
+            ) => {
+                let buffer_ids = self.buffer_manager.get_buffer_ids();
+                for buffer_id in buffer_ids {
+                    // Only episode lists downcast; other buffers are skipped
+                    if let Some(episode_buffer) = self
+                        .buffer_manager
+                        .get_episode_list_buffer_mut_by_id(&buffer_id)
+                    {
+                        // Check if this buffer belongs to the podcast
+                        if episode_buffer.podcast_id == podcast_id {
+                            episode_buffer.set_episodes(episodes.clone());
+                        }
                     }
                 }
-                self.show_message(msg);
-                Ok(true)
+            }
+            (_, BufferRefreshData::Error { message }) => {
+                self.show_error(format!("Could not refresh buffer: {}", message));
+            }
+            _ => {
+                // Mismatched buffer type and data, ignore
             }
         }
     }
 
-    /// Get all available commands for auto-completion
-    fn get_available_commands(&self) -> Vec<String> {
-        let mut commands = vec![
-            // Core commands
-            "quit".to_string(),
-            "q".to_string(),
-            "help".to_string(),
-            "h".to_string(),
-            // Theme commands
-            "theme".to_string(),
-        ];
-        // Add one completion entry per registered theme name
-        for name in self.theme_registry.list_names() {
-            commands.push(format!("theme {}", name));
+    async fn load_playlists_into_buffer(&mut self) {
+        match self.playlist_manager.list_playlists().await {
+            Ok(playlists) => {
+                if let Some(buffer) = self.buffer_manager.get_playlist_list_buffer_mut() {
+                    buffer.set_playlists(playlists);
+                }
+            }
+            Err(e) => self.show_error(format!("Could not load playlists: {}", e)),
         }
-        commands.extend([
-            // Buffer commands
-            "buffer".to_string(),
-            "b".to_string(),
-            "switch-to-buffer".to_string(),
-            "switch-buffer".to_string(),
-            "list-buffers".to_string(),
-            "buffers".to_string(),
-            "close-buffer".to_string(),
-            "kill-buffer".to_string(),
-            // Podcast commands
-            "add-podcast".to_string(),
-            // Downloads commands
-            "delete-all-downloads".to_string(),
-            "clean-downloads".to_string(),
-            // OPML commands
-            "import-opml".to_string(),
-            "export-opml".to_string(),
-            // Sync commands
-            "sync".to_string(),
-            "sync-device".to_string(),
-            "sync-dry-run".to_string(),
-            "sync-preview".to_string(),
-            // Playlist commands
-            "playlists".to_string(),
-            "playlist-create".to_string(),
-            "playlist-new".to_string(),
-            "playlist-delete".to_string(),
-            "playlist-refresh".to_string(),
-            "playlist-sync".to_string(),
-            "smart-playlist".to_string(),
-            // Discovery commands
-            "discover".to_string(),
-            "search-podcasts".to_string(),
-            "trending".to_string(),
-            // Cleanup commands
-            "clean-older-than".to_string(),
-            "cleanup".to_string(),
-            // Search & filter commands
-            "search".to_string(),
-            "filter-status".to_string(),
-            "filter-status new".to_string(),
-            "filter-status downloaded".to_string(),
-            "filter-status played".to_string(),
-            "filter-status downloading".to_string(),
-            "filter-status failed".to_string(),
-            "filter-status favorited".to_string(),
-            "filter-date".to_string(),
-            "filter-date today".to_string(),
-            "filter-date 1d".to_string(),
-            "filter-date 7d".to_string(),
-            "filter-date 2w".to_string(),
-            "filter-date 1m".to_string(),
-            "clear-filters".to_string(),
-            "widen".to_string(),
-            // Sort commands
-            "sort".to_string(),
-            "sort date".to_string(),
-            "sort title".to_string(),
-            "sort duration".to_string(),
-            "sort downloaded".to_string(),
-            "sort-asc".to_string(),
-            "sort-desc".to_string(),
-        ]);
-        commands
     }
 
-    /// Update completion mode based on the current input context
-    fn update_completion_mode_for_input(&mut self, input: &str) {
-        let input_lower = input.to_lowercase();
+    async fn refresh_open_playlist_detail_buffers(&mut self) {
+        let mut details_to_refresh: Vec<(String, crate::playlist::PlaylistId)> = Vec::new();
+        for buffer_id in self.buffer_manager.get_buffer_ids() {
+            if !buffer_id.starts_with("playlist-") || buffer_id == "playlist-list" {
+                continue;
+            }
+            if let Some(detail_buffer) = self
+                .buffer_manager
+                .get_playlist_detail_buffer_mut_by_id(&buffer_id)
+            {
+                details_to_refresh.push((buffer_id, detail_buffer.playlist_id().clone()));
+            }
+        }
+
+        for (buffer_id, playlist_id) in details_to_refresh {
+            if let Ok(playlist) = self.playlist_manager.get_playlist(&playlist_id).await {
+                self.show_playlist_in_detail(&buffer_id, playlist).await;
+            }
+        }
+    }
 
-        // Check if we're typing a buffer-related command that needs buffer name completion
-        if input_lower.starts_with("switch-to-buffer ")
-            || input_lower.starts_with("switch-buffer ")
-            || input_lower.starts_with("buffer ")
-            || input_lower.starts_with("b ")
-            || input_lower.starts_with("close-buffer ")
-            || input_lower.starts_with("kill-buffer ")
+    /// Load `playlist` into its detail buffer along with the episode facts
+    /// its statistics header is built from
+    async fn show_playlist_in_detail(
+        &mut self,
+        detail_id: &str,
+        playlist: crate::playlist::Playlist,
+    ) {
+        let details = self.playlist_entry_details(&playlist.episodes).await;
+        let budget = self.config.playlist.duration_budget_minutes;
+        if let Some(detail_buffer) = self
+            .buffer_manager
+            .get_playlist_detail_buffer_mut_by_id(detail_id)
         {
-            // Get the command part and the buffer name part
-            let parts: Vec<&str> = input.split_whitespace().collect();
-            if !parts.is_empty() {
-                let command_part = parts[0];
+            detail_buffer.set_playlist(playlist);
+            detail_buffer.set_entry_details(details);
+            detail_buffer.set_duration_budget(budget);
+        }
+    }
 
-                // Get available buffer names (just the names)
-                let buffer_names = self.buffer_manager.buffer_completion_names();
-                let mut buffer_completions = Vec::new();
+    /// Duration, size and download state of each entry's episode
+    async fn playlist_entry_details(
+        &self,
+        entries: &[crate::playlist::PlaylistEpisode],
+    ) -> std::collections::HashMap<crate::storage::EpisodeId, PlaylistEntryDetails> {
+        let mut details = std::collections::HashMap::new();
+        for entry in entries {
+            if let Ok(episode) = self
+                ._storage
+                .load_episode(&entry.podcast_id, &entry.episode_id)
+                .await
+            {
+                details.insert(
+                    entry.episode_id.clone(),
+                    PlaylistEntryDetails {
+                        duration: episode.duration,
+                        file_size: episode.file_size,
+                        downloaded: episode.is_downloaded(),
+                    },
+                );
+            }
+        }
+        details
+    }
 
-                // If there's already a space, we're completing buffer names
-                if input.contains(' ') {
-                    let buffer_search = if parts.len() > 1 {
-                        parts[1..].join(" ").to_lowercase()
-                    } else {
-                        String::new()
-                    };
+    /// Handle minibuffer input submission with context
+    fn handle_minibuffer_input_with_context(
+        &mut self,
+        input: String,
+        prompt_context: Option<String>,
+    ) {
+        let input = input.trim();
 
-                    for name in &buffer_names {
-                        if buffer_search.is_empty()
-                            || name.to_lowercase().starts_with(&buffer_search)
-                        {
-                            buffer_completions.push(format!("{} {}", command_part, name));
-                        }
+        if input.is_empty() {
+            // Handle empty input for specific prompts
+            if let Some(prompt) = &prompt_context {
+                if prompt.starts_with("Export to") {
+                    // Empty input means use default export path
+                    let default_path =
+                        shellexpand::tilde(&self.config.storage.opml_export_directory).to_string();
+                    self.trigger_async_opml_export(default_path);
+                    return;
+                } else if prompt.starts_with("Sync to device path") {
+                    // Empty input means use default sync path
+                    let default_path = self.get_default_sync_path();
+                    self.trigger_async_device_sync(default_path, false, false, false);
+                    return;
+                } else if prompt.starts_with("Dry run sync to") {
+                    // Empty input means use default sync path for dry run
+                    let default_path = self.get_default_sync_path();
+                    self.trigger_async_device_sync(default_path, false, true, false);
+                    return;
+                } else if prompt.starts_with("Notes for") {
+                    // Empty notes are removed
+                    self.trigger_async_save_notes("");
+                    return;
+                } else if Self::is_unsubscribe_prompt(prompt) {
+                    self.answer_unsubscribe_prompt(input);
+                    return;
+                } else if prompt.starts_with("Episode ") {
+                    // Empty input reverts the field to the feed's value
+                    if let Some((podcast_id, episode_id, field)) = self.pending_episode_edit.take()
+                    {
+                        self.trigger_async_edit_episode(podcast_id, episode_id, Some(field), None);
+                    }
+                    return;
+                }
+            }
+            return;
+        }
+
+        // Check context from prompt FIRST (before checking for URLs)
+        if let Some(prompt) = &prompt_context {
+            if Self::is_unsubscribe_prompt(prompt) {
+                self.answer_unsubscribe_prompt(input);
+                return;
+            } else if prompt == FIND_PERSON_PROMPT {
+                self.open_episode_search(Some(input.to_string()));
+                return;
+            } else if prompt == WORKSPACE_PROMPT {
+                self.switch_workspace(input);
+                return;
+            } else if prompt == SEARCH_EPISODES_PROMPT {
+                self.run_episode_search(input.to_string());
+                let matches = self
+                    .buffer_manager
+                    .get_search_buffer_mut()
+                    .map_or(0, |buffer| buffer.hits().len());
+                self.show_message(format!("{} episode(s) match \"{}\"", matches, input));
+                return;
+            } else if prompt.starts_with("Search:") {
+                // This is a search query — dispatch to active buffer
+                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+                    current_buffer.handle_action(UIAction::ApplySearch {
+                        query: input.to_string(),
+                    });
+                }
+                return;
+            } else if prompt.starts_with("Import OPML from") {
+                // Feeds are picked in the preview before anything is imported
+                self.trigger_async_opml_preview(input.to_string());
+                return;
+            } else if prompt.starts_with("Export to") {
+                // This is an OPML export
+                self.trigger_async_opml_export(input.to_string());
+                return;
+            } else if prompt.starts_with("Sync to device path") {
+                // This is a device sync
+                let args: Vec<&str> = input.split_whitespace().collect();
+                let (device_path, hard_sync) = Self::parse_sync_command_args(&args);
+                let device_path = device_path.unwrap_or_else(|| self.get_default_sync_path());
+                self.trigger_async_device_sync(device_path, false, false, hard_sync);
+                return;
+            } else if prompt.starts_with("Dry run sync to") {
+                // This is a device sync dry run
+                let args: Vec<&str> = input.split_whitespace().collect();
+                let (device_path, hard_sync) = Self::parse_sync_command_args(&args);
+                let device_path = device_path.unwrap_or_else(|| self.get_default_sync_path());
+                self.trigger_async_device_sync(device_path, false, true, hard_sync);
+                return;
+            } else if prompt.starts_with("Episode ") {
+                if let Some((podcast_id, episode_id, field)) = self.pending_episode_edit.take() {
+                    self.trigger_async_edit_episode(
+                        podcast_id,
+                        episode_id,
+                        Some(field),
+                        Some(input.to_string()),
+                    );
+                }
+                return;
+            } else if prompt.starts_with("Notes for") {
+                self.trigger_async_save_notes(input);
+                return;
+            } else if prompt.starts_with("Create playlist and add:") {
+                let targets = self
+                    .buffer_manager
+                    .get_playlist_picker_buffer_mut()
+                    .map(|picker| picker.targets().to_vec())
+                    .unwrap_or_default();
+                self.trigger_async_create_playlist_with_episodes(input.to_string(), targets);
+                return;
+            } else if prompt.starts_with("Create playlist:") {
+                self.trigger_async_create_playlist(input.to_string(), None);
+                return;
+            } else if prompt.starts_with("Archive ") {
+                let ids = self.pending_archive.take();
+                if input.eq_ignore_ascii_case("y") || input.eq_ignore_ascii_case("yes") {
+                    if let Some(ids) = ids {
+                        self.trigger_async_set_archived(ids, true);
                     }
                 } else {
-                    // Still typing the command, offer command completion + buffer suggestions
-                    buffer_completions.push(format!("{} ", command_part));
-                    for name in &buffer_names {
-                        buffer_completions.push(format!("{} {}", command_part, name));
+                    self.show_message("Archiving cancelled".to_string());
+                }
+                return;
+            } else if prompt.starts_with("Apply settings from") {
+                // This is a settings import confirmation (y/n)
+                if input.to_lowercase() == "y" || input.to_lowercase() == "yes" {
+                    if let Some((path, bundle)) = self.pending_settings_import.take() {
+                        self.trigger_async_settings_import_apply(path, bundle);
                     }
+                } else {
+                    self.pending_settings_import = None;
+                    self.show_message("Settings import cancelled".to_string());
                 }
-
-                // Sort the completions
-                buffer_completions.sort();
-
-                // Update the minibuffer with buffer name completions
-                if !buffer_completions.is_empty() {
-                    // Update the completion candidates to be buffer names instead of all commands
-                    self.minibuffer
-                        .set_completion_candidates(buffer_completions.clone());
-
-                    // Convert to PromptWithCompletion mode if not already
-                    if let Some(current_input) = self.minibuffer.current_input() {
-                        let cursor_pos = current_input.len();
-                        self.minibuffer
-                            .set_content(MinibufferContent::PromptWithCompletion {
-                                prompt: "M-x ".to_string(),
-                                input: current_input,
-                                cursor_pos,
-                                completions: buffer_completions,
-                                completion_index: None,
-                            });
+                return;
+            } else if prompt.starts_with("Delete downloaded episodes older than") {
+                // This is a cleanup confirmation (y/n)
+                if input.to_lowercase() == "y" || input.to_lowercase() == "yes" {
+                    if let Some(hours) = self.pending_cleanup_hours.take() {
+                        let label = crate::utils::time::format_cleanup_duration(hours);
+                        self.trigger_async_cleanup_downloads(hours, label);
                     }
+                } else {
+                    self.pending_cleanup_hours = None;
+                    self.show_message("Download cleanup cancelled".to_string());
+                }
+                return;
+            } else if prompt.starts_with("Delete downloads older than") {
+                // This is a duration input prompt (no argument was provided)
+                if let Some(total_hours) = crate::utils::time::parse_cleanup_duration(input) {
+                    let label = crate::utils::time::format_cleanup_duration(total_hours);
+                    self.minibuffer.set_content(MinibufferContent::Input {
+                        prompt: format!("Delete downloaded episodes older than {}? (y/n) ", label),
+                        input: String::new(),
+                    });
+                    self.pending_cleanup_hours = Some(total_hours);
+                } else {
+                    self.show_error(format!(
+                        "Invalid duration: '{}'. Use e.g., 7d, 2w, 1m, 12h",
+                        input
+                    ));
                 }
+                return;
             }
         }
+
+        // If no prompt context, fall back to old behavior
+        self.handle_minibuffer_input_legacy(input.to_string());
     }
 
-    /// Get contextual command completions based on current input
-    fn get_contextual_command_completions(&self, input: &str) -> Vec<String> {
-        let input_lower = input.to_lowercase();
-        let mut completions = Vec::new();
+    /// Handle minibuffer input submission (legacy method for backward compatibility)
+    fn handle_minibuffer_input(&mut self, input: String) {
+        self.handle_minibuffer_input_legacy(input);
+    }
 
-        // Basic command completion - match from start
-        let base_commands = self.get_available_commands();
-        for cmd in &base_commands {
-            if cmd.to_lowercase().starts_with(&input_lower) {
-                completions.push(cmd.clone());
-            }
-        }
+    /// Legacy minibuffer input handler
+    fn handle_minibuffer_input_legacy(&mut self, input: String) {
+        let input = input.trim();
 
-        // Add contextual completions for specific command patterns
-        if let Some(theme_part) = input_lower.strip_prefix("theme ") {
-            for name in self.theme_registry.list_names() {
-                if name.starts_with(theme_part) {
-                    completions.push(format!("theme {}", name));
-                }
-            }
-        } else if input_lower.starts_with("buffer ") || input_lower.starts_with("b ") {
-            // Add buffer names as completions
-            let buffer_names = self.buffer_manager.buffer_names();
-            let prefix = if input_lower.starts_with("buffer ") {
-                "buffer "
+        // Check if this looks like a URL (basic heuristic for podcast addition)
+        if input.starts_with("http://") || input.starts_with("https://") {
+            self.show_message(format!("Adding podcast: {}...", input));
+            self.trigger_async_add_podcast(input.to_string());
+        } else if input.to_lowercase() == "y" || input.to_lowercase() == "yes" {
+            // Handle podcast deletion confirmation
+            if let Some(podcast_id) = self.pending_deletion.take() {
+                let mode = self.default_unsubscribe_mode();
+                self.trigger_async_delete_podcast(podcast_id, mode);
+            } else if let Some(playlist_id) = self.pending_playlist_deletion.take() {
+                self.trigger_async_delete_playlist(playlist_id);
+            } else if self.pending_bulk_deletion {
+                // Handle bulk deletion confirmation
+                self.pending_bulk_deletion = false;
+                self.trigger_async_delete_all_downloads();
             } else {
-                "b "
-            };
-            let search_term = &input_lower[prefix.len()..];
-
-            for (_, name) in &buffer_names {
-                if name.to_lowercase().starts_with(search_term) {
-                    completions.push(format!("{}{}", prefix, name));
-                }
+                self.show_message("No deletion pending".to_string());
             }
-        } else if input_lower.starts_with("switch-to-buffer ")
-            || input_lower.starts_with("switch-buffer ")
-        {
-            // Add buffer names as completions for switch commands
-            let buffer_names = self.buffer_manager.buffer_names();
-            let prefix = if input_lower.starts_with("switch-to-buffer ") {
-                "switch-to-buffer "
-            } else {
-                "switch-buffer "
-            };
-            let search_term = &input_lower[prefix.len()..];
-
-            for (_, name) in &buffer_names {
-                if name.to_lowercase().starts_with(search_term) {
-                    completions.push(format!("{}{}", prefix, name));
-                }
+        } else if input.to_lowercase() == "n" || input.to_lowercase() == "no" {
+            // Cancel deletion
+            if self.pending_deletion.is_some() {
+                self.pending_deletion = None;
+                self.show_message("Podcast deletion cancelled".to_string());
+            } else if self.pending_playlist_deletion.is_some() {
+                self.pending_playlist_deletion = None;
+                self.show_message("Playlist deletion cancelled".to_string());
+            } else if self.pending_bulk_deletion {
+                self.pending_bulk_deletion = false;
+                self.show_message("Bulk deletion cancelled".to_string());
+            } else {
+                self.show_message("No deletion to cancel".to_string());
             }
-        } else if input_lower.starts_with("close-buffer ")
-            || input_lower.starts_with("kill-buffer ")
-        {
-            // Add buffer names as completions for close commands
+        } else {
+            // Check if this is a buffer name
             let buffer_names = self.buffer_manager.buffer_names();
-            let prefix = if input_lower.starts_with("close-buffer ") {
-                "close-buffer "
-            } else {
-                "kill-buffer "
-            };
-            let search_term = &input_lower[prefix.len()..];
+            let matching_buffer = buffer_names.iter().find(|(_, name)| {
+                name.to_lowercase().contains(&input.to_lowercase())
+                    || name.to_lowercase() == input.to_lowercase()
+            });
 
-            for (_, name) in &buffer_names {
-                if name.to_lowercase().starts_with(search_term) {
-                    completions.push(format!("{}{}", prefix, name));
+            if let Some((buffer_id, buffer_name)) = matching_buffer {
+                if self.buffer_manager.switch_to_buffer(buffer_id).is_err() {
+                    self.show_error(format!("Could not switch to buffer: {}", buffer_name));
+                } else {
+                    self.update_status_bar();
+                    self.show_message(format!("Switched to buffer: {}", buffer_name));
                 }
-            }
-        } else if input_lower.starts_with("add-podcast ") {
-            // For add-podcast, we could suggest common podcast URLs or show a hint
-            if input_lower == "add-podcast " {
-                completions.push("add-podcast https://".to_string());
+            } else {
+                // Treat as a command
+                let _ = self.execute_command_direct(input.to_string());
             }
         }
 
-        // Remove duplicates and sort
-        completions.sort();
-        completions.dedup();
-
-        // If no specific matches, fall back to all commands that contain the input
-        if completions.is_empty() && !input.is_empty() {
-            for cmd in &base_commands {
-                if cmd.to_lowercase().contains(&input_lower) {
-                    completions.push(cmd.clone());
-                }
-            }
+        // Only clear the minibuffer if execute_command_direct didn't set up a new prompt.
+        // Commands like clean-older-than and delete-all-downloads set the minibuffer to
+        // a confirmation prompt, which would be wiped out by an unconditional clear().
+        if !self.minibuffer.is_input_mode() {
+            self.minibuffer.clear();
         }
-
-        completions
     }
 
-    /// Show command prompt with auto-completion
-    fn show_command_prompt_with_completion(&mut self) {
-        let commands = self.get_available_commands();
-        let prompt = "M-x ".to_string();
-        self.minibuffer
-            .show_prompt_with_completion(prompt, commands);
+    /// Search as the query is typed at the episode search prompt
+    fn update_live_search(&mut self) {
+        if self.minibuffer.current_prompt().as_deref() != Some(SEARCH_EPISODES_PROMPT) {
+            return;
+        }
+        if let Some(query) = self.minibuffer.current_input() {
+            self.run_episode_search(query);
+        }
     }
 
-    /// Prompt for buffer switch with completion hints
-    fn prompt_buffer_switch(&mut self) {
-        let buffer_names = self.buffer_manager.buffer_names();
+    /// Handle key events when minibuffer is in input mode
+    async fn handle_minibuffer_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> UIResult<bool> {
+        use crossterm::event::{KeyCode, KeyModifiers};
 
-        // Create completion candidates from buffer names
-        let completions: Vec<String> = buffer_names
-            .iter()
-            .map(|(id, name)| {
-                // Prefer display name, but include ID as fallback
-                if id != name {
-                    name.clone()
-                } else {
-                    id.clone()
+        // Multi-line input: Enter breaks the line, C-s submits and the arrows
+        // move between lines; cancelling and plain typing are shared below
+        if self.minibuffer.is_multiline() {
+            match (key_event.code, key_event.modifiers) {
+                (KeyCode::Enter, _) => self.minibuffer.edit_multiline(TextArea::insert_newline),
+                (KeyCode::Char('s'), KeyModifiers::CONTROL) => {
+                    let prompt = self.minibuffer.current_prompt();
+                    if let Some(input) = self.minibuffer.submit() {
+                        self.handle_minibuffer_input_with_context(input, prompt);
+                    }
                 }
-            })
-            .collect();
-
-        let prompt = "Switch to buffer: ".to_string();
-        self.minibuffer
-            .show_prompt_with_completion(prompt, completions);
-    }
-
-    /// Close buffer by name with smart matching
-    fn close_buffer_by_name(&mut self, buffer_name: String) -> UIResult<bool> {
-        let buffer_names = self.buffer_manager.buffer_names();
-
-        // Try exact match first (by ID)
-        if buffer_names.iter().any(|(id, _)| id == &buffer_name) {
-            match self.buffer_manager.remove_buffer(&buffer_name) {
-                Ok(_) => {
-                    self.update_status_bar();
-                    self.show_message(format!("Closed buffer: {}", buffer_name));
-                    return Ok(true);
+                (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                    self.minibuffer.edit_multiline(TextArea::cursor_up)
                 }
-                Err(e) => {
-                    self.show_error(format!("Cannot close buffer: {}", e));
-                    return Ok(true);
+                (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                    self.minibuffer.edit_multiline(TextArea::cursor_down)
                 }
-            }
-        }
-
-        // Try exact match by display name
-        if let Some((id, _)) = buffer_names.iter().find(|(_, name)| name == &buffer_name) {
-            match self.buffer_manager.remove_buffer(id) {
-                Ok(_) => {
-                    self.update_status_bar();
-                    self.show_message(format!("Closed buffer: {}", buffer_name));
-                    return Ok(true);
+                (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
+                    self.minibuffer.edit_multiline(TextArea::cursor_line_start)
                 }
-                Err(e) => {
-                    self.show_error(format!("Cannot close buffer: {}", e));
-                    return Ok(true);
+                (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+                    self.minibuffer.edit_multiline(TextArea::cursor_line_end)
+                }
+                (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+                    self.minibuffer.edit_multiline(TextArea::delete)
                 }
+                (KeyCode::Tab, _) | (KeyCode::PageUp, _) | (KeyCode::PageDown, _) => {}
+                _ => return self.handle_minibuffer_edit_key(key_event).await,
             }
+            return Ok(true);
         }
 
-        // Try partial match (case insensitive)
-        let lower_search = buffer_name.to_lowercase();
-        let matches: Vec<_> = buffer_names
-            .iter()
-            .filter(|(id, name)| {
-                id.to_lowercase().contains(&lower_search)
-                    || name.to_lowercase().contains(&lower_search)
-            })
-            .collect();
+        self.handle_minibuffer_edit_key(key_event).await
+    }
 
-        match matches.len() {
-            0 => {
-                self.show_error(format!("No buffer found matching: {}", buffer_name));
+    /// Keys shared by every minibuffer input mode
+    async fn handle_minibuffer_edit_key(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> UIResult<bool> {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        match (key_event.code, key_event.modifiers) {
+            // Submit input on Enter
+            (KeyCode::Enter, _) => {
+                // Get the prompt BEFORE submit() clears it
+                let prompt = self.minibuffer.current_prompt();
+                if let Some(input) = self.minibuffer.submit() {
+                    self.handle_minibuffer_input_with_context(input, prompt);
+                }
                 Ok(true)
             }
-            1 => {
-                let (id, _) = matches[0];
-                match self.buffer_manager.remove_buffer(id) {
-                    Ok(_) => {
-                        self.update_status_bar();
-                        self.show_message(format!("Closed buffer: {}", buffer_name));
-                        Ok(true)
-                    }
-                    Err(e) => {
-                        self.show_error(format!("Cannot close buffer: {}", e));
-                        Ok(true)
+            // Tab completion
+            (KeyCode::Tab, _) => {
+                self.minibuffer.tab_complete();
+                Ok(true)
+            }
+            // Cancel on Ctrl+G or Escape (also clear any pending operations)
+            (KeyCode::Esc, _) | (KeyCode::Char('g'), KeyModifiers::CONTROL) => {
+                self.minibuffer.clear();
+                self.pending_deletion = None;
+                self.pending_playlist_deletion = None;
+                self.pending_bulk_deletion = false;
+                self.pending_cleanup_hours = None;
+                self.pending_settings_import = None;
+                self.pending_episode_edit = None;
+                self.pending_notes = None;
+                Ok(true)
+            }
+            // Backspace
+            (KeyCode::Backspace, _) => {
+                self.minibuffer.backspace();
+                self.update_live_search();
+
+                // Update command completion dynamically if in command prompt mode
+                if self.minibuffer.is_command_prompt() {
+                    if let Some(current_input) = self.minibuffer.current_input() {
+                        let commands = self.get_contextual_command_completions(&current_input);
+                        self.minibuffer.set_completion_candidates(commands);
+
+                        // If this is a buffer-related command, update to buffer name completion mode
+                        self.update_completion_mode_for_input(&current_input);
                     }
                 }
+
+                Ok(true)
             }
-            _ => {
-                let mut msg = format!("Multiple buffers match '{}':\n", buffer_name);
-                for (id, name) in matches {
-                    if id != name {
-                        msg.push_str(&format!("  {} ({})\n", name, id));
-                    } else {
-                        msg.push_str(&format!("  {}\n", name));
+            // Cursor movement
+            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+                self.minibuffer.cursor_left();
+                Ok(true)
+            }
+            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
+                self.minibuffer.cursor_right();
+                Ok(true)
+            }
+            // Completion popup selection, falling back to history navigation
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                if self.minibuffer.has_completion_popup() {
+                    self.minibuffer.select_prev_completion();
+                } else {
+                    self.minibuffer.history_up();
+                }
+                Ok(true)
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                if self.minibuffer.has_completion_popup() {
+                    self.minibuffer.select_next_completion();
+                } else {
+                    self.minibuffer.history_down();
+                }
+                Ok(true)
+            }
+            (KeyCode::PageUp, _) | (KeyCode::Char('v'), KeyModifiers::ALT) => {
+                self.minibuffer.page_completions_up();
+                Ok(true)
+            }
+            (KeyCode::PageDown, _) | (KeyCode::Char('v'), KeyModifiers::CONTROL) => {
+                self.minibuffer.page_completions_down();
+                Ok(true)
+            }
+            // Regular character input
+            (KeyCode::Char(c), KeyModifiers::NONE) | (KeyCode::Char(c), KeyModifiers::SHIFT) => {
+                self.minibuffer.add_char(c);
+                self.update_live_search();
+
+                // Update command completion dynamically if in command prompt mode
+                if self.minibuffer.is_command_prompt() {
+                    if let Some(current_input) = self.minibuffer.current_input() {
+                        let commands = self.get_contextual_command_completions(&current_input);
+                        self.minibuffer.set_completion_candidates(commands);
+
+                        // If this is a buffer-related command, update to buffer name completion mode
+                        self.update_completion_mode_for_input(&current_input);
                     }
                 }
-                msg.push_str("\nSpecify the exact buffer name to close.");
-                self.show_message(msg);
+
                 Ok(true)
             }
+            // Ignore other keys in input mode
+            _ => Ok(true),
+        }
+    }
+
+    /// Render the UI
+    fn render(&mut self, frame: &mut Frame) {
+        let size = frame.area();
+
+        // Create layout: main area + minibuffer + status bar, with the
+        // simple-mode hint bar above the minibuffer
+        let hint_height = if self.config.ui.simple_mode { 3 } else { 0 };
+        let banner_height = if self.offline.is_some() { 1 } else { 0 };
+        let outer = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(banner_height), Constraint::Min(0)])
+            .split(size);
+        if let Some(offline) = &self.offline {
+            self.render_offline_banner(frame, outer[0], offline.pending.len());
         }
+        let size = outer[1];
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),              // Main content area
+                Constraint::Length(hint_height), // Simple-mode hints
+                Constraint::Length(2),           // Minibuffer (1 for border + 1 for text)
+                Constraint::Length(1),           // Status bar
+            ])
+            .split(size);
+        let (hints_area, minibuffer_area, status_area) = (chunks[1], chunks[2], chunks[3]);
+
+        // Render main content area
+        self.render_main_content(frame, chunks[0]);
+
+        if self.show_glyph_legend {
+            self.render_glyph_legend(frame, chunks[0]);
+        }
+        if let Some(popup) = &self.share_popup {
+            popup.render(frame, chunks[0], &self.theme);
+        }
+
+        // Completion candidates and the multi-line editor pop up directly
+        // above the minibuffer
+        self.minibuffer.render_completion_popup(frame, chunks[0]);
+        self.minibuffer.render_multiline_popup(frame, chunks[0]);
+
+        if self.config.ui.simple_mode {
+            self.render_simple_mode_hints(frame, hints_area);
+        }
+
+        // Render minibuffer
+        self.minibuffer.render(frame, minibuffer_area);
+
+        // Render status bar
+        self.status_bar
+            .set_active_downloads(self.download_manager.active_downloads());
+        self.status_bar.render(frame, status_area);
     }
 
-    /// Show a message in the minibuffer
-    fn show_message(&mut self, message: String) {
-        self.minibuffer
-            .set_content(MinibufferContent::Message(message));
-        self.status_bar.clear_status_message();
+    /// Key hints shown in simple mode, using the keys actually bound
+    fn simple_mode_hints(&self) -> Vec<(String, &'static str)> {
+        let play = UIAction::PlayEpisode {
+            podcast_id: crate::storage::PodcastId(uuid::Uuid::nil()),
+            episode_id: crate::storage::EpisodeId(uuid::Uuid::nil()),
+            path: std::path::PathBuf::new(),
+        };
+        [
+            (UIAction::SelectItem, "Open"),
+            (play, "Play"),
+            (UIAction::TogglePlayPause, "Pause"),
+            (UIAction::DownloadEpisode, "Download"),
+            (UIAction::AddPodcast, "Subscribe"),
+            (UIAction::ShowHelp, "Help"),
+            (UIAction::Quit, "Quit"),
+        ]
+        .into_iter()
+        .filter_map(|(action, label)| Some((self.key_handler.key_for(&action)?, label)))
+        .collect()
     }
 
-    /// Show an error in the minibuffer
-    fn show_error(&mut self, error: String) {
-        self.minibuffer.set_content(MinibufferContent::Error(error));
-        self.status_bar.clear_status_message();
+    /// Render the simple-mode hint bar
+    fn render_simple_mode_hints(&self, frame: &mut Frame, area: Rect) {
+        let key_style = self.theme.title_style().add_modifier(Modifier::BOLD);
+        let mut spans = Vec::new();
+        for (key, label) in self.simple_mode_hints() {
+            spans.push(Span::styled(format!(" {} ", key), key_style));
+            spans.push(Span::styled(
+                format!(" {}    ", label),
+                self.theme.default_style(),
+            ));
+        }
+
+        let hints = Paragraph::new(Line::from(spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(self.theme.border_style()),
+            )
+            .alignment(ratatui::layout::Alignment::Center)
+            .style(self.theme.default_style());
+        frame.render_widget(hints, area);
     }
 
-    /// Update the status bar with current state
-    fn update_status_bar(&mut self) {
-        if let Some(buffer_name) = self.buffer_manager.current_buffer_name() {
-            self.status_bar.set_buffer_name(buffer_name);
+    /// One-line banner shown across the top while offline
+    fn render_offline_banner(&self, frame: &mut Frame, area: Rect, pending: usize) {
+        let mut text = " ⚠ Offline — refreshes and downloads wait for the connection".to_string();
+        if pending > 0 {
+            text.push_str(&format!(" ({} pending)", pending));
         }
+        text.push_str(" · :reconnect to check now ");
+        let banner = Paragraph::new(Line::from(text))
+            .alignment(ratatui::layout::Alignment::Center)
+            .style(self.theme.error_style().add_modifier(Modifier::BOLD));
+        frame.render_widget(banner, area);
     }
 
-    fn open_episode_detail_buffer(&mut self, episode: crate::podcast::Episode) {
-        self.buffer_manager
-            .create_episode_detail_buffer(episode.clone());
-        let episode_buffer_id = format!("episode-detail-{}", episode.id);
-        let _ = self.buffer_manager.switch_to_buffer(&episode_buffer_id);
-        self.update_status_bar();
-        self.refresh_buffer_list_if_open();
+    /// Render the status glyph legend as a popup centered over `area`
+    fn render_glyph_legend(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = glyphs::legend_lines(self.theme.glyphs)
+            .into_iter()
+            .map(|(glyph, text)| Line::styled(format!(" {}", text), self.theme.glyph_style(glyph)))
+            .collect();
+
+        let width = 24.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+
+        let legend = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Legend ")
+                    .title_style(self.theme.title_style())
+                    .border_style(self.theme.border_focused_style()),
+            )
+            .style(self.theme.default_style());
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(legend, popup);
     }
 
-    fn add_to_playlist_supported_in_buffer(&self, buffer_id: &str) -> bool {
-        buffer_id.starts_with("episodes-")
-            || buffer_id.starts_with("episode-detail-")
-            || buffer_id == "whats-new"
+    /// Buffer selected in the buffer list, when the buffer list is active
+    fn buffer_list_preview_id(&mut self) -> Option<String> {
+        use crate::ui::buffers::buffer_list::BufferListBuffer;
+
+        self.buffer_manager
+            .current_buffer_mut()?
+            .as_any()
+            .downcast_ref::<BufferListBuffer>()?
+            .selected_buffer_id()
+            .cloned()
     }
 
-    fn resolve_add_to_playlist_selection(
-        &mut self,
-        buffer_id: &str,
-    ) -> Option<(crate::storage::PodcastId, crate::storage::EpisodeId)> {
-        if buffer_id.starts_with("episodes-") {
-            if let Some(episode_buffer) = self
-                .buffer_manager
-                .get_episode_list_buffer_mut_by_id(buffer_id)
-            {
-                if let Some(episode) = episode_buffer.selected_episode() {
-                    return Some((episode_buffer.podcast_id.clone(), episode.id.clone()));
+    /// Render the main content area
+    fn render_main_content(&mut self, frame: &mut Frame, area: Rect) {
+        // The buffer list previews the selected buffer in a split pane
+        if area.width >= ui_constants::BUFFER_LIST_PREVIEW_MIN_WIDTH {
+            if let Some(preview_id) = self.buffer_list_preview_id() {
+                let panes = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+                    .split(area);
+                if let Some(list) = self.buffer_manager.current_buffer_mut() {
+                    list.render(frame, panes[0]);
+                }
+                if let Some(preview) = self.buffer_manager.get_buffer(&preview_id) {
+                    preview.render(frame, panes[1]);
                 }
+                return;
             }
-        } else if buffer_id == "whats-new" {
-            if let Some(whats_new_buffer) = self.buffer_manager.get_whats_new_buffer_mut() {
-                if let Some(agg_episode) = whats_new_buffer.selected_episode() {
-                    return Some((
-                        agg_episode.podcast_id.clone(),
-                        agg_episode.episode.id.clone(),
-                    ));
+        }
+
+        if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
+            current_buffer.render(frame, area);
+            if self.config.ui.scroll_indicators {
+                if let Some(position) = current_buffer.scroll_position() {
+                    scroll_indicator::render(frame, &position, &self.theme);
                 }
             }
-        } else if buffer_id.starts_with("episode-detail-") {
-            if let Some(episode_detail_buffer) = self
-                .buffer_manager
-                .get_episode_detail_buffer_mut_by_id(buffer_id)
-            {
-                return Some((
-                    episode_detail_buffer.podcast_id().clone(),
-                    episode_detail_buffer.episode_id().clone(),
-                ));
-            }
+        } else {
+            // No buffer selected, show empty area
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title("No Buffer")
+                .style(self.theme.default_style());
+            frame.render_widget(block, area);
         }
-
-        None
     }
+}
 
-    /// Trigger async podcast addition
-    fn trigger_async_add_podcast(&mut self, url: String) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let url_clone = url.clone();
+/// Parse `:smart-playlist` command arguments into a `SmartPlaylistRule`.
+///
+/// Supported flags:
+/// - `--filter <spec>` — one or more; multiple filters are AND-ed
+/// - `--sort <field>`  — `date-asc` | `date-desc` | `title-asc` | `title-desc`
+/// - `--limit <n>`     — maximum episodes to return
+///
+/// Filter specs: `downloaded`, `favorited`, `played`, `unplayed`,
+///               `tag:<name>`, `podcast:<id>`, `newer-than:<days>`
+pub(crate) fn parse_smart_playlist_args(
+    args: &[&str],
+) -> Result<crate::playlist::models::SmartPlaylistRule, String> {
+    use crate::playlist::models::{SmartFilter, SmartPlaylistRule, SmartSort};
 
-        // Spawn async task to add the podcast
-        tokio::spawn(async move {
-            match subscription_manager.subscribe(&url_clone).await {
-                Ok(podcast) => {
-                    // Send success event back to UI
-                    let _ = app_event_tx.send(AppEvent::PodcastSubscribed { podcast });
+    let mut filters: Vec<SmartFilter> = Vec::new();
+    let mut sort: Option<SmartSort> = None;
+    let mut limit: Option<usize> = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i] {
+            "--filter" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("--filter requires a value".to_string());
                 }
-                Err(e) => {
-                    // Send error event back to UI
-                    let _ = app_event_tx.send(AppEvent::PodcastSubscriptionFailed {
-                        url: url_clone.clone(),
-                        error: e.to_string(),
-                    });
+                let spec = args[i];
+                let filter = parse_filter_spec(spec)?;
+                filters.push(filter);
+            }
+            "--sort" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("--sort requires a value".to_string());
                 }
+                sort = Some(parse_sort_spec(args[i])?);
             }
-        });
+            "--limit" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("--limit requires a value".to_string());
+                }
+                limit =
+                    Some(args[i].parse::<usize>().map_err(|_| {
+                        format!("--limit value must be a number, got '{}'", args[i])
+                    })?);
+            }
+            other => {
+                return Err(format!(
+                    "Unknown argument '{}'. Use --filter, --sort, or --limit",
+                    other
+                ));
+            }
+        }
+        i += 1;
+    }
 
-        // Show immediate feedback
-        self.show_message(format!("Adding podcast: {}...", url));
+    let filter = match filters.len() {
+        0 => SmartFilter::Downloaded, // sensible default
+        1 => filters.remove(0),
+        _ => SmartFilter::And(filters),
+    };
+
+    Ok(SmartPlaylistRule {
+        filter,
+        sort,
+        limit,
+    })
+}
+
+/// Parse the flags after `:commute <length>`: those of `:smart-playlist`
+/// (`--filter`, `--sort`, `--limit`) plus `--per-podcast <n>`
+pub(crate) fn parse_commute_args(
+    budget_secs: u32,
+    args: &[&str],
+) -> Result<crate::playlist::commute::CommuteRequest, String> {
+    let mut max_per_podcast = crate::playlist::commute::DEFAULT_MAX_PER_PODCAST;
+    let mut rule_args = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--per-podcast" {
+            i += 1;
+            max_per_podcast = args
+                .get(i)
+                .and_then(|n| n.parse::<usize>().ok())
+                .filter(|n| *n > 0)
+                .ok_or_else(|| "--per-podcast requires a number above 0".to_string())?;
+        } else {
+            rule_args.push(args[i]);
+        }
+        i += 1;
     }
 
-    /// Trigger async single podcast refresh
-    fn trigger_async_refresh_single(&mut self, podcast_id: crate::storage::PodcastId) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
+    // Without --filter the rule matches downloaded episodes, which is what
+    // a commute is limited to anyway
+    let rule = parse_smart_playlist_args(&rule_args)?;
+    let mut request = crate::playlist::commute::CommuteRequest::new(budget_secs, rule);
+    request.max_per_podcast = max_per_podcast;
+    Ok(request)
+}
 
-        tokio::spawn(async move {
-            match subscription_manager.refresh_feed(&podcast_id).await {
-                Ok(new_episodes) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastRefreshed {
-                        podcast_id: podcast_id_clone,
-                        new_episode_count: new_episodes.len(),
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastRefreshFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+/// Minibuffer prompt of the full-text episode search
+const SEARCH_EPISODES_PROMPT: &str = "Search episodes: ";
+const FIND_PERSON_PROMPT: &str = "Find episodes with: ";
+const WORKSPACE_PROMPT: &str = "Workspace: ";
+
+/// Status bar indicator while the data directory is opened `--read-only`
+const READ_ONLY_INDICATOR: &str = "read-only";
+
+/// Network work put off until the connection returns
+#[derive(Debug, Clone, PartialEq)]
+enum PendingNetworkOp {
+    RefreshPodcast(crate::storage::PodcastId),
+    RefreshAll,
+    Download(crate::storage::PodcastId, crate::storage::EpisodeId),
+}
+
+impl PendingNetworkOp {
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::RefreshPodcast(_) => "the refresh",
+            Self::RefreshAll => "the refresh of all podcasts",
+            Self::Download(..) => "the download",
+        }
     }
+}
 
-    /// Trigger async single podcast hard refresh (re-parses all episodes)
-    fn trigger_async_hard_refresh_single(&mut self, podcast_id: crate::storage::PodcastId) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
+/// Offline state: when to check the connection next and what's waiting on it
+struct OfflineMode {
+    next_probe: Instant,
+    probing: bool,
+    /// Report the outcome of the running check (`:reconnect` asked for it)
+    announce: bool,
+    pending: Vec<PendingNetworkOp>,
+}
 
-        tokio::spawn(async move {
-            match subscription_manager
-                .refresh_feed_with_options(&podcast_id, true)
-                .await
-            {
-                Ok(updated_episodes) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastRefreshed {
-                        podcast_id: podcast_id_clone,
-                        new_episode_count: updated_episodes.len(),
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastRefreshFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
+/// Episode waiting to auto-play after the previous one ended
+struct AutoPlayCountdown {
+    podcast_id: crate::storage::PodcastId,
+    episode_id: crate::storage::EpisodeId,
+    path: std::path::PathBuf,
+    title: String,
+    /// Where the episode was left off, if it was started before
+    start_at: Option<Duration>,
+    deadline: Instant,
+}
+
+fn parse_filter_spec(spec: &str) -> Result<crate::playlist::models::SmartFilter, String> {
+    use crate::playlist::models::SmartFilter;
+    match spec {
+        "downloaded" => Ok(SmartFilter::Downloaded),
+        "favorited" => Ok(SmartFilter::Favorited),
+        "played" => Ok(SmartFilter::Played),
+        "unplayed" => Ok(SmartFilter::Unplayed),
+        other => {
+            if let Some(tag) = other.strip_prefix("tag:") {
+                Ok(SmartFilter::Tag(tag.to_string()))
+            } else if let Some(id) = other.strip_prefix("podcast:") {
+                Ok(SmartFilter::Podcast(id.to_string()))
+            } else if let Some(days_str) = other.strip_prefix("newer-than:") {
+                let days = days_str.parse::<u32>().map_err(|_| {
+                    format!(
+                        "newer-than value must be a number of days, got '{}'",
+                        days_str
+                    )
+                })?;
+                Ok(SmartFilter::NewerThan(days))
+            } else {
+                Err(format!(
+                    "Unknown filter '{}'. Valid: downloaded, favorited, played, unplayed, tag:<name>, podcast:<id>, newer-than:<days>",
+                    other
+                ))
             }
-        });
+        }
+    }
+}
+
+fn parse_sort_spec(spec: &str) -> Result<crate::playlist::models::SmartSort, String> {
+    use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
+    match spec {
+        "date-desc" => Ok(SmartSort {
+            field: SmartSortField::Date,
+            direction: SmartSortDirection::Descending,
+        }),
+        "date-asc" => Ok(SmartSort {
+            field: SmartSortField::Date,
+            direction: SmartSortDirection::Ascending,
+        }),
+        "title-asc" => Ok(SmartSort {
+            field: SmartSortField::Title,
+            direction: SmartSortDirection::Ascending,
+        }),
+        "title-desc" => Ok(SmartSort {
+            field: SmartSortField::Title,
+            direction: SmartSortDirection::Descending,
+        }),
+        "duration-asc" => Ok(SmartSort {
+            field: SmartSortField::Duration,
+            direction: SmartSortDirection::Ascending,
+        }),
+        "duration-desc" => Ok(SmartSort {
+            field: SmartSortField::Duration,
+            direction: SmartSortDirection::Descending,
+        }),
+        other => Err(format!(
+            "Unknown sort '{}'. Valid: date-desc, date-asc, title-asc, title-desc, duration-asc, duration-desc",
+            other
+        )),
     }
+}
 
-    /// Trigger async refresh of all podcasts
-    fn trigger_async_refresh_all(&mut self) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+/// Load episodes matching `scope` from every subscribed podcast, newest first.
+/// Podcasts are read with bounded concurrency; the filtering is CPU only.
+/// Compile the configured What's New exclusions, warning about rules that can't be used
+fn compile_whats_new_exclusions(config: &Config) -> Arc<WhatsNewExclusions> {
+    let (exclusions, errors) = WhatsNewExclusions::compile(&config.ui.whats_new_exclusions);
+    for error in &errors {
+        eprintln!("[whats_new_exclusions] {error}");
+    }
+    Arc::new(exclusions)
+}
 
-        tokio::spawn(async move {
-            match subscription_manager.refresh_all().await {
-                Ok(total_new_episodes) => {
-                    let _ =
-                        app_event_tx.send(AppEvent::AllPodcastsRefreshed { total_new_episodes });
-                }
-                Err(_e) => {
-                    // For all refresh, we'll just show a general error
-                    // TODO: Add proper error reporting mechanism for TUI
-                }
-            }
-        });
+fn compile_listening_goals(config: &Config) -> Vec<ListeningGoal> {
+    let (goals, errors) = ListeningGoal::compile(&config.goals.targets);
+    for error in &errors {
+        eprintln!("[goals] {error}");
     }
+    goals
+}
 
-    /// Trigger async episode download
-    fn trigger_async_download(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-    ) {
-        let download_manager = self.download_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let episode_id_clone = episode_id.clone();
+/// Log entry for `episode` finishing now, `seconds` of it heard
+fn listen_record(episode: &crate::podcast::Episode, seconds: u32) -> ListenRecord {
+    ListenRecord {
+        at: chrono::Utc::now(),
+        podcast_id: episode.podcast_id.clone(),
+        episode_id: episode.id.clone(),
+        seconds,
+    }
+}
 
-        tokio::spawn(async move {
-            match download_manager
-                .download_episode(&podcast_id, &episode_id)
-                .await
-            {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeDownloaded {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadFailed {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+fn compile_keyword_alerts(config: &Config) -> Arc<KeywordAlerts> {
+    let (alerts, errors) = KeywordAlerts::compile(&config.alerts.keywords);
+    for error in &errors {
+        eprintln!("[alerts] {error}");
     }
+    Arc::new(alerts)
+}
 
-    /// Trigger async episode download deletion
-    fn trigger_async_delete_download(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-    ) {
-        let download_manager = self.download_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let episode_id_clone = episode_id.clone();
+/// An alert for `episode` of `podcast` when it matches a keyword
+fn keyword_alert(
+    keywords: &KeywordAlerts,
+    podcast: &crate::podcast::Podcast,
+    episode: &crate::podcast::Episode,
+) -> Option<KeywordAlert> {
+    keywords
+        .matching_keyword(episode)
+        .map(|keyword| KeywordAlert {
+            podcast_id: podcast.id.clone(),
+            podcast_title: podcast.title.clone(),
+            episode: episode.clone(),
+            keyword: keyword.to_string(),
+            raised_at: chrono::Utc::now(),
+        })
+}
 
-        tokio::spawn(async move {
-            match download_manager
-                .delete_episode(&podcast_id, &episode_id)
-                .await
-            {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadDeleted {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                    });
+async fn load_aggregated_episodes(
+    subscription_manager: &SubscriptionManager<JsonStorage>,
+    scope: EpisodeScope,
+    exclusions: Option<&WhatsNewExclusions>,
+) -> Vec<AggregatedEpisode> {
+    let mut all_episodes = Vec::new();
+
+    if let Ok(podcast_ids) = subscription_manager.storage.list_podcasts().await {
+        let storage = subscription_manager.storage.clone();
+        let podcast_pairs: Vec<_> = stream::iter(podcast_ids)
+            .map(|podcast_id| {
+                let storage = storage.clone();
+                async move {
+                    let podcast = storage
+                        .load_podcast(&podcast_id)
+                        .await
+                        .ok()
+                        .filter(|p| !p.is_removed())?;
+                    let episodes = storage.load_episodes(&podcast_id).await.ok()?;
+                    Some((podcast_id, podcast, episodes))
                 }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeDownloadDeletionFailed {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                        error: e.to_string(),
+            })
+            .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
+            .filter_map(|r| async { r })
+            .collect()
+            .await;
+
+        // Process results (CPU only, no I/O)
+        for (podcast_id, podcast, episodes) in podcast_pairs {
+            for episode in episodes {
+                if scope.includes(&episode)
+                    && !exclusions.is_some_and(|rules| rules.excludes(&podcast, &episode.title))
+                {
+                    all_episodes.push(AggregatedEpisode {
+                        podcast_id: podcast_id.clone(),
+                        podcast_title: podcast.title.clone(),
+                        episode,
                     });
                 }
             }
-        });
+        }
     }
 
-    /// Trigger async mark episode as played
-    fn trigger_async_mark_played(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-        episode_title: String,
-    ) {
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let episode_id_clone = episode_id.clone();
+    // Sort by publication date (newest first)
+    all_episodes.sort_by(|a, b| b.episode.published.cmp(&a.episode.published));
+    all_episodes
+}
 
-        tokio::spawn(async move {
-            match storage.load_episode(&podcast_id, &episode_id).await {
-                Ok(mut episode) => {
-                    episode.mark_played();
-                    match storage.save_episode(&podcast_id, &episode).await {
-                        Ok(()) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeMarkedPlayed {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                episode_title,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeMarkPlayedFailed {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeMarkPlayedFailed {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+/// Episodes a refresh of `podcast` added or rewrote, as What's New rows;
+/// the buffer decides which are in its scope
+fn whats_new_changes(
+    podcast: &crate::podcast::Podcast,
+    episodes: &[crate::podcast::Episode],
+    exclusions: &WhatsNewExclusions,
+) -> Vec<AggregatedEpisode> {
+    episodes
+        .iter()
+        .filter(|episode| !exclusions.excludes(podcast, &episode.title))
+        .map(|episode| AggregatedEpisode {
+            podcast_id: podcast.id.clone(),
+            podcast_title: podcast.title.clone(),
+            episode: episode.clone(),
+        })
+        .collect()
+}
+
+/// Add `episodes` to a playlist and report how it went
+async fn add_episodes_event(
+    playlist_manager: &crate::playlist::PlaylistManager,
+    playlist_id: &crate::playlist::PlaylistId,
+    playlist_name: String,
+    episodes: &[(crate::storage::PodcastId, crate::storage::EpisodeId)],
+) -> AppEvent {
+    match playlist_manager
+        .add_episodes_to_playlist(playlist_id, episodes)
+        .await
+    {
+        Ok(result) => AppEvent::EpisodesAddedToPlaylist {
+            playlist_name,
+            result,
+        },
+        Err(e) => AppEvent::EpisodesAddToPlaylistFailed {
+            playlist_name,
+            error: e.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::UiConfig, download::DownloadManager};
+
+    #[tokio::test]
+    async fn test_ui_app_creation() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
+
+        let config = Config {
+            ui: UiConfig::default(),
+            ..Default::default()
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager.clone(),
+            storage.clone(),
+            app_event_tx,
+        );
+        assert!(app.is_ok());
+
+        let app = app.unwrap();
+        assert!(!app.should_quit);
+        assert_eq!(app.frame_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_quit_action() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
+
+        let config = Config::default();
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+
+        let result = app.handle_action(UIAction::Quit).await;
+        assert!(result.is_ok());
+        assert!(!result.unwrap()); // Should return false to indicate stopping
+        assert!(app.should_quit);
     }
 
-    /// Trigger async mark episode as unplayed
-    fn trigger_async_mark_unplayed(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-        episode_title: String,
-    ) {
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let episode_id_clone = episode_id.clone();
+    #[tokio::test]
+    async fn test_show_help_action() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
+
+        let config = Config::default();
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
+
+        let result = app.handle_action(UIAction::ShowHelp).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
 
-        tokio::spawn(async move {
-            match storage.load_episode(&podcast_id, &episode_id).await {
-                Ok(mut episode) => {
-                    episode.mark_unplayed();
-                    match storage.save_episode(&podcast_id, &episode).await {
-                        Ok(()) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeMarkedUnplayed {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                episode_title,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeMarkUnplayedFailed {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeMarkUnplayedFailed {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Should have switched to help buffer
+        assert_eq!(
+            app.buffer_manager.current_buffer_name().unwrap(),
+            "*Help: Keybindings*"
+        );
     }
 
-    /// Trigger async persist of the favorited state for an episode
-    fn trigger_async_toggle_favorite(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-        episode_title: String,
-        favorited: bool,
-    ) {
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let episode_id_clone = episode_id.clone();
+    #[tokio::test]
+    async fn test_show_help_reopens_after_close() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            match storage.load_episode(&podcast_id, &episode_id).await {
-                Ok(mut episode) => {
-                    episode.favorited = favorited;
-                    match storage.save_episode(&podcast_id, &episode).await {
-                        Ok(()) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggled {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                episode_title,
-                                favorited,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggleFailed {
-                                podcast_id: podcast_id_clone,
-                                episode_id: episode_id_clone,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeFavoriteToggleFailed {
-                        podcast_id: podcast_id_clone,
-                        episode_id: episode_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let config = Config::default();
 
-    /// Trigger async persist of adding a tag to a podcast
-    fn trigger_async_add_tag(&mut self, podcast_id: crate::storage::PodcastId, tag: String) {
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let tag_clone = tag.clone();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
 
-        tokio::spawn(async move {
-            match storage.load_podcast(&podcast_id).await {
-                Ok(mut podcast) => {
-                    podcast.add_tag(&tag);
-                    match storage.save_podcast(&podcast).await {
-                        Ok(()) => {
-                            let _ = app_event_tx.send(AppEvent::PodcastTagAdded {
-                                podcast_id: podcast_id_clone,
-                                tag: tag_clone,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::PodcastTagAddFailed {
-                                podcast_id: podcast_id_clone,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastTagAddFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-    /// Trigger async persist of removing a tag from a podcast
-    fn trigger_async_remove_tag(&mut self, podcast_id: crate::storage::PodcastId, tag: String) {
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let tag_clone = tag.clone();
+        // Open help buffer
+        let result = app.handle_action(UIAction::ShowHelp).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_name().unwrap(),
+            "*Help: Keybindings*"
+        );
 
-        tokio::spawn(async move {
-            match storage.load_podcast(&podcast_id).await {
-                Ok(mut podcast) => {
-                    podcast.remove_tag(&tag);
-                    match storage.save_podcast(&podcast).await {
-                        Ok(()) => {
-                            let _ = app_event_tx.send(AppEvent::PodcastTagRemoved {
-                                podcast_id: podcast_id_clone,
-                                tag: tag_clone,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::PodcastTagRemoveFailed {
-                                podcast_id: podcast_id_clone,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastTagRemoveFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Close the help buffer
+        let help_id = app.buffer_manager.current_buffer_id().unwrap();
+        let _ = app.buffer_manager.remove_buffer(&help_id);
+
+        // Verify help buffer is gone
+        let has_help = app
+            .buffer_manager
+            .buffer_names()
+            .iter()
+            .any(|(_, name)| name == "*Help: Keybindings*");
+        assert!(!has_help, "Help buffer should be removed");
+
+        // Reopen help buffer via ShowHelp action
+        let result = app.handle_action(UIAction::ShowHelp).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+
+        // Should have recreated and switched to help buffer
+        assert_eq!(
+            app.buffer_manager.current_buffer_name().unwrap(),
+            "*Help: Keybindings*"
+        );
     }
 
-    /// Trigger async cleanup of old downloads
-    fn trigger_async_cleanup_downloads(&mut self, max_age_hours: u64, duration_label: String) {
-        let download_manager = self.download_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let label = duration_label.clone();
+    #[tokio::test]
+    async fn test_command_execution() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
+
+        let config = Config::default();
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
+
+        // Test quit command
+        let result = app.execute_command_direct("quit".to_string());
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+        assert!(app.should_quit);
 
-        self.show_message(format!(
-            "Cleaning up downloads older than {}...",
-            duration_label
-        ));
+        // Reset for next test
+        app.should_quit = false;
 
-        tokio::spawn(async move {
-            match download_manager
-                .cleanup_old_downloads_hours(max_age_hours)
-                .await
-            {
-                Ok(deleted_count) => {
-                    let _ = app_event_tx.send(AppEvent::DownloadCleanupCompleted {
-                        deleted_count,
-                        duration_label: label,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DownloadCleanupFailed {
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Test help command
+        let result = app.execute_command_direct("help".to_string());
+        assert!(result.is_ok());
+        assert!(result.unwrap());
     }
 
-    /// Trigger async downloads refresh
-    fn trigger_async_refresh_downloads(&mut self) {
-        let app_event_tx = self.app_event_tx.clone();
+    #[tokio::test]
+    async fn test_theme_setting() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            // Send a custom event to trigger refresh on the UI thread
-            let _ = app_event_tx.send(AppEvent::DownloadsRefreshed);
-        });
-    }
+        let config = Config::default();
 
-    fn trigger_async_create_playlist(&mut self, name: String, description: Option<String>) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let name_for_error = name.clone();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
 
-        tokio::spawn(async move {
-            match playlist_manager.create_playlist(&name, description).await {
-                Ok(playlist) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistCreated { playlist });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistCreationFailed {
-                        name: name_for_error,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
 
-    fn trigger_async_create_smart_playlist(
-        &mut self,
-        name: String,
-        rule: crate::playlist::models::SmartPlaylistRule,
-    ) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let name_for_error = name.clone();
+        let result = app.set_theme_direct("light");
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+        // Verify theme propagated to app
+        assert_eq!(app.theme.name, "Light");
 
-        tokio::spawn(async move {
-            match playlist_manager
-                .create_smart_playlist(&name, None, rule)
-                .await
-            {
-                Ok(playlist) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistCreated { playlist });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistCreationFailed {
-                        name: name_for_error,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        let result = app.set_theme_direct("invalid-theme");
+        assert!(result.is_ok());
+        assert!(result.unwrap());
     }
 
-    fn trigger_async_evaluate_smart_playlist(
-        &mut self,
-        playlist_id: crate::playlist::PlaylistId,
-        playlist_name: String,
-        detail_buffer_id: String,
-    ) {
-        let storage = self._storage.clone();
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+    #[tokio::test]
+    async fn test_add_to_playlist_opens_picker_from_supported_buffers() {
+        use crate::config::DownloadConfig;
+        use crate::podcast::Episode;
+        use crate::storage::JsonStorage;
+        use chrono::Utc;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            // Load the playlist to get its rule
-            let playlist = match playlist_manager.get_playlist(&playlist_id).await {
-                Ok(p) => p,
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluationFailed {
-                        playlist_name,
-                        error: e.to_string(),
-                    });
-                    return;
-                }
-            };
-            let rule = match playlist.smart_rules {
-                Some(r) => r,
-                None => return, // not a smart playlist, nothing to do
-            };
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-            // Load all podcasts and episodes
-            let podcast_ids = match storage.list_podcasts().await {
-                Ok(ids) => ids,
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluationFailed {
-                        playlist_name,
-                        error: e.to_string(),
-                    });
-                    return;
-                }
-            };
-            let mut all_episodes = Vec::new();
-            let mut all_podcasts = Vec::new();
-            for pid in &podcast_ids {
-                match storage.load_podcast(pid).await {
-                    Ok(podcast) => all_podcasts.push(podcast),
-                    Err(e) => eprintln!(
-                        "Warning: failed to load podcast '{}' for smart playlist evaluation: {}",
-                        pid, e
-                    ),
-                }
-                match storage.load_episodes(pid).await {
-                    Ok(episodes) => all_episodes.extend(episodes),
-                    Err(e) => eprintln!(
-                        "Warning: failed to load episodes for podcast '{}' in smart playlist evaluation: {}",
-                        pid, e
-                    ),
-                }
-            }
+        app.playlist_manager
+            .create_playlist("Issue55 Playlist", None)
+            .await
+            .unwrap();
+
+        let podcast_id = crate::storage::PodcastId::new();
+        let episode = Episode::new(
+            podcast_id.clone(),
+            "Issue55 Episode".to_string(),
+            "https://example.com/issue55.mp3".to_string(),
+            Utc::now(),
+        );
+
+        let episode_buffer_id = "episodes-issue55-podcast".to_string();
+        app.buffer_manager.create_episode_list_buffer(
+            "Issue55 Podcast".to_string(),
+            podcast_id.clone(),
+            app.subscription_manager.clone(),
+            app.download_manager.clone(),
+        );
+        if let Some(episode_buffer) = app
+            .buffer_manager
+            .get_episode_list_buffer_mut_by_id(&episode_buffer_id)
+        {
+            episode_buffer.set_episodes(vec![episode.clone()]);
+        }
+        app.buffer_manager
+            .switch_to_buffer(&episode_buffer_id)
+            .unwrap();
+        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("playlist-picker".to_string())
+        );
+
+        if let Some(whats_new_buffer) = app.buffer_manager.get_whats_new_buffer_mut() {
+            whats_new_buffer.set_episodes(vec![crate::ui::events::AggregatedEpisode {
+                podcast_id: podcast_id.clone(),
+                podcast_title: "Issue55 Podcast".to_string(),
+                episode: episode.clone(),
+            }]);
+        }
+        app.buffer_manager
+            .switch_to_buffer(&"whats-new".to_string())
+            .unwrap();
+        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("playlist-picker".to_string())
+        );
 
-            let raw = rule.evaluate(&all_episodes, &all_podcasts);
-            // Convert Episode → PlaylistEpisode for display
-            let episodes: Vec<crate::playlist::PlaylistEpisode> = raw
-                .into_iter()
-                .enumerate()
-                .map(|(idx, ep)| crate::playlist::PlaylistEpisode {
-                    podcast_id: ep.podcast_id.clone(),
-                    episode_id: ep.id.clone(),
-                    episode_title: Some(ep.title.clone()),
-                    added_at: chrono::Utc::now(),
-                    order: idx + 1,
-                    file_synced: false,
-                    filename: None,
-                })
-                .collect();
-            let _ = app_event_tx.send(AppEvent::SmartPlaylistEvaluated {
-                detail_buffer_id,
-                episodes,
-            });
-        });
+        let episode_detail_id = format!("episode-detail-{}", episode.id);
+        app.buffer_manager
+            .create_episode_detail_buffer(episode.clone());
+        app.buffer_manager
+            .switch_to_buffer(&episode_detail_id)
+            .unwrap();
+        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("playlist-picker".to_string())
+        );
     }
 
-    /// Trigger an async PodcastIndex search and load results into the discovery buffer.
-    fn trigger_async_discover(&mut self, query: String, buffer_id: String) {
-        let app_event_tx = self.app_event_tx.clone();
-        let api_key = self.config.discovery.podcastindex_api_key.clone();
-        let api_secret = self.config.discovery.podcastindex_api_secret.clone();
-        let display_title = format!("Search: {}", query);
+    #[tokio::test]
+    async fn test_add_all_to_playlist_picks_up_only_the_shown_episodes() {
+        use crate::config::DownloadConfig;
+        use crate::podcast::Episode;
+        use crate::storage::JsonStorage;
+        use chrono::Utc;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            let client = match crate::podcast::PodcastIndexClient::new(api_key, api_secret) {
-                Ok(c) => c,
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
-                        buffer_id,
-                        error: e.to_string(),
-                    });
-                    return;
-                }
-            };
-            match client.search(&query).await {
-                Ok(results) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryResultsLoaded {
-                        buffer_id,
-                        results,
-                        title: display_title,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
-                        buffer_id,
-                        error: e.to_string(),
-                    });
-                }
-            }
+        // Arrange — three episodes, a search leaves two of them
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
+
+        let podcast_id = crate::storage::PodcastId::new();
+        let episodes: Vec<Episode> = ["Rust async", "Cooking pasta", "Rust traits"]
+            .into_iter()
+            .map(|title| {
+                Episode::new(
+                    podcast_id.clone(),
+                    title.to_string(),
+                    "https://example.com/episode.mp3".to_string(),
+                    Utc::now(),
+                )
+            })
+            .collect();
+        app.buffer_manager.create_episode_list_buffer(
+            "Bulk Podcast".to_string(),
+            podcast_id.clone(),
+            app.subscription_manager.clone(),
+            app.download_manager.clone(),
+        );
+        let episode_buffer_id = "episodes-bulk-podcast".to_string();
+        let episode_buffer = app
+            .buffer_manager
+            .get_episode_list_buffer_mut_by_id(&episode_buffer_id)
+            .unwrap();
+        episode_buffer.set_episodes(episodes.clone());
+        episode_buffer.handle_action(UIAction::ApplySearch {
+            query: "rust".to_string(),
         });
+        app.buffer_manager
+            .switch_to_buffer(&episode_buffer_id)
+            .unwrap();
+
+        // Act — no playlists yet: the picker still opens to quick-create one
+        app.handle_action(UIAction::AddAllToPlaylist).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("playlist-picker".to_string())
+        );
+        let targets: Vec<_> = app
+            .buffer_manager
+            .get_playlist_picker_buffer_mut()
+            .unwrap()
+            .targets()
+            .iter()
+            .map(|(_, episode_id)| episode_id.clone())
+            .collect();
+        let expected: Vec<_> = episodes
+            .iter()
+            .filter(|episode| episode.title.starts_with("Rust"))
+            .map(|episode| episode.id.clone())
+            .collect();
+        assert_eq!(targets.len(), 2);
+        assert!(expected.iter().all(|id| targets.contains(id)));
+        assert_eq!(
+            app.buffer_manager
+                .current_buffer_mut()
+                .unwrap()
+                .handle_action(UIAction::CreatePlaylist),
+            UIAction::PromptInput("Create playlist and add: ".to_string())
+        );
     }
 
-    /// Trigger an async PodcastIndex trending fetch and load results into the discovery buffer.
-    fn trigger_async_trending(&mut self, buffer_id: String) {
-        let app_event_tx = self.app_event_tx.clone();
-        let api_key = self.config.discovery.podcastindex_api_key.clone();
-        let api_secret = self.config.discovery.podcastindex_api_secret.clone();
+    #[tokio::test]
+    async fn test_add_all_to_playlist_from_search_results() {
+        use crate::search::{MatchField, SearchHit};
 
-        tokio::spawn(async move {
-            let client = match crate::podcast::PodcastIndexClient::new(api_key, api_secret) {
-                Ok(c) => c,
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
-                        buffer_id,
-                        error: e.to_string(),
-                    });
-                    return;
-                }
-            };
-            match client.trending().await {
-                Ok(results) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryResultsLoaded {
-                        buffer_id,
-                        results,
-                        title: "Trending".to_string(),
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DiscoveryLoadFailed {
-                        buffer_id,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Arrange — two search hits from different podcasts
+        let mut app = make_test_app().await;
+        let hits: Vec<SearchHit> = ["Rust async", "Rust traits"]
+            .into_iter()
+            .map(|title| SearchHit {
+                podcast_id: crate::storage::PodcastId::new(),
+                episode_id: crate::storage::EpisodeId::new(),
+                podcast_title: "Show".to_string(),
+                episode_title: title.to_string(),
+                published: chrono::Utc::now(),
+                published_offset: None,
+                matched: MatchField::Title,
+            })
+            .collect();
+        app.buffer_manager.create_search_buffer();
+        app.buffer_manager
+            .get_search_buffer_mut()
+            .unwrap()
+            .set_results("rust".to_string(), hits.clone());
+        app.buffer_manager
+            .switch_to_buffer(&"search".to_string())
+            .unwrap();
+
+        // Act
+        app.handle_action(UIAction::AddAllToPlaylist).await.unwrap();
+
+        // Assert
+        let targets: Vec<_> = app
+            .buffer_manager
+            .get_playlist_picker_buffer_mut()
+            .unwrap()
+            .targets()
+            .to_vec();
+        let expected: Vec<_> = hits
+            .iter()
+            .map(|hit| (hit.podcast_id.clone(), hit.episode_id.clone()))
+            .collect();
+        assert_eq!(targets, expected);
     }
 
-    fn trigger_async_delete_playlist(&mut self, playlist_id: crate::playlist::PlaylistId) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+    #[tokio::test]
+    async fn test_add_to_playlist_in_unsupported_buffer_shows_info_message() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            let playlist_name = playlist_manager
-                .get_playlist(&playlist_id)
-                .await
-                .map(|playlist| playlist.name)
-                .unwrap_or_else(|_| playlist_id.to_string());
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-            match playlist_manager.delete_playlist(&playlist_id).await {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistDeleted {
-                        name: playlist_name,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
-                        name: playlist_name,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        app.buffer_manager
+            .switch_to_buffer(&"podcast-list".to_string())
+            .unwrap();
+        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("podcast-list".to_string())
+        );
+        assert_ne!(
+            app.buffer_manager.current_buffer_id(),
+            Some("playlist-picker".to_string())
+        );
+        assert!(app.minibuffer.is_visible());
     }
 
-    fn trigger_async_delete_playlist_by_name(&mut self, playlist_name: String) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let lookup_name = playlist_name.clone();
+    #[tokio::test]
+    async fn test_playlist_detail_select_item_opens_episode_detail() {
+        use crate::config::DownloadConfig;
+        use crate::playlist::{Playlist, PlaylistEpisode, PlaylistId, PlaylistType};
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::JsonStorage;
+        use chrono::Utc;
+        use tempfile::TempDir;
+
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage.clone(),
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-        tokio::spawn(async move {
-            match playlist_manager.get_playlist_by_name(&lookup_name).await {
-                Ok(playlist) => match playlist_manager.delete_playlist(&playlist.id).await {
-                    Ok(_) => {
-                        let _ = app_event_tx.send(AppEvent::PlaylistDeleted {
-                            name: playlist.name,
-                        });
-                    }
-                    Err(e) => {
-                        let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
-                            name: playlist.name,
-                            error: e.to_string(),
-                        });
-                    }
-                },
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistDeletionFailed {
-                        name: playlist_name,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let mut podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        let podcast_id = podcast.id.clone();
+        storage.save_podcast(&podcast).await.unwrap();
 
-    fn trigger_async_add_to_playlist(
-        &mut self,
-        playlist_id: crate::playlist::PlaylistId,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
-    ) {
-        let playlist_manager = self.playlist_manager.clone();
-        let storage = self._storage.clone();
-        let app_event_tx = self.app_event_tx.clone();
+        let episode = Episode::new(
+            podcast_id.clone(),
+            "Episode 1".to_string(),
+            "https://example.com/ep1.mp3".to_string(),
+            Utc::now(),
+        );
+        let episode_id = episode.id.clone();
+        podcast.add_episode(episode_id.clone());
+        storage.save_episode(&podcast_id, &episode).await.unwrap();
+        storage.save_podcast(&podcast).await.unwrap();
 
-        tokio::spawn(async move {
-            let playlist_name = playlist_manager
-                .get_playlist(&playlist_id)
-                .await
-                .map(|playlist| playlist.name)
-                .unwrap_or_else(|_| playlist_id.to_string());
-            let episode_title = storage
-                .load_episode(&podcast_id, &episode_id)
-                .await
-                .map(|episode| episode.title)
-                .unwrap_or_else(|_| episode_id.to_string());
+        let playlist_id = PlaylistId::new();
+        let playlist_name = "Test Playlist".to_string();
+        let detail_id = format!(
+            "playlist-{}",
+            playlist_name.replace(' ', "-").to_lowercase()
+        );
+        let playlist_manager = app.playlist_manager.clone();
+        app.buffer_manager.create_playlist_detail_buffer(
+            playlist_id.clone(),
+            playlist_name.clone(),
+            PlaylistType::User,
+            playlist_manager,
+        );
+        if let Some(detail_buffer) = app
+            .buffer_manager
+            .get_playlist_detail_buffer_mut_by_id(&detail_id)
+        {
+            detail_buffer.set_playlist(Playlist {
+                id: playlist_id,
+                name: playlist_name,
+                description: None,
+                playlist_type: PlaylistType::User,
+                episodes: vec![PlaylistEpisode {
+                    podcast_id: podcast_id.clone(),
+                    episode_id: episode_id.clone(),
+                    episode_title: Some("Episode 1".to_string()),
+                    added_at: Utc::now(),
+                    order: 1,
+                    file_synced: false,
+                    filename: None,
+                }],
+                created: Utc::now(),
+                last_updated: Utc::now(),
+                smart_rules: None,
+                playback: Default::default(),
+            });
+        }
+        app.buffer_manager.switch_to_buffer(&detail_id).unwrap();
 
-            match playlist_manager
-                .add_episode_to_playlist(&playlist_id, &podcast_id, &episode_id)
-                .await
-            {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeAddedToPlaylist {
-                        playlist_name,
-                        episode_title,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeAddToPlaylistFailed {
-                        playlist_name,
-                        episode_title,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        let result = app.handle_action(UIAction::SelectItem).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some(format!("episode-detail-{}", episode_id))
+        );
     }
 
-    fn trigger_async_remove_from_playlist(
-        &mut self,
-        playlist_id: crate::playlist::PlaylistId,
-        episode_id: crate::storage::EpisodeId,
-    ) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+    #[tokio::test]
+    async fn test_playlist_detail_select_item_with_missing_episode_shows_error() {
+        use crate::config::DownloadConfig;
+        use crate::playlist::{Playlist, PlaylistEpisode, PlaylistId, PlaylistType};
+        use crate::podcast::Podcast;
+        use crate::storage::JsonStorage;
+        use chrono::Utc;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            let playlist_name = playlist_manager
-                .get_playlist(&playlist_id)
-                .await
-                .map(|playlist| playlist.name)
-                .unwrap_or_else(|_| playlist_id.to_string());
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().to_path_buf(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage.clone(),
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-            match playlist_manager
-                .remove_episode_from_playlist(&playlist_id, &episode_id)
-                .await
-            {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeRemovedFromPlaylist {
-                        playlist_name,
-                        episode_title: episode_id.to_string(),
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodeRemoveFromPlaylistFailed {
-                        playlist_name,
-                        episode_title: episode_id.to_string(),
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        let podcast_id = podcast.id.clone();
+        storage.save_podcast(&podcast).await.unwrap();
+        let missing_episode_id = crate::storage::EpisodeId::new();
 
-    fn trigger_async_reorder_playlist(
-        &mut self,
-        playlist_id: crate::playlist::PlaylistId,
-        from_idx: usize,
-        to_idx: usize,
-    ) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+        let playlist_id = PlaylistId::new();
+        let playlist_name = "Broken Playlist".to_string();
+        let detail_id = format!(
+            "playlist-{}",
+            playlist_name.replace(' ', "-").to_lowercase()
+        );
+        let playlist_manager = app.playlist_manager.clone();
+        app.buffer_manager.create_playlist_detail_buffer(
+            playlist_id.clone(),
+            playlist_name.clone(),
+            PlaylistType::User,
+            playlist_manager,
+        );
+        if let Some(detail_buffer) = app
+            .buffer_manager
+            .get_playlist_detail_buffer_mut_by_id(&detail_id)
+        {
+            detail_buffer.set_playlist(Playlist {
+                id: playlist_id,
+                name: playlist_name,
+                description: None,
+                playlist_type: PlaylistType::User,
+                episodes: vec![PlaylistEpisode {
+                    podcast_id,
+                    episode_id: missing_episode_id,
+                    episode_title: Some("Missing".to_string()),
+                    added_at: Utc::now(),
+                    order: 1,
+                    file_synced: false,
+                    filename: None,
+                }],
+                created: Utc::now(),
+                last_updated: Utc::now(),
+                smart_rules: None,
+                playback: Default::default(),
+            });
+        }
+        app.buffer_manager.switch_to_buffer(&detail_id).unwrap();
 
-        tokio::spawn(async move {
-            let playlist_name = playlist_manager
-                .get_playlist(&playlist_id)
-                .await
-                .map(|playlist| playlist.name)
-                .unwrap_or_else(|_| playlist_id.to_string());
-            match playlist_manager
-                .reorder_episode(&playlist_id, from_idx, to_idx)
-                .await
-            {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistReordered {
-                        name: playlist_name,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistReorderFailed {
-                        name: playlist_name,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        let result = app.handle_action(UIAction::SelectItem).await;
+        assert!(result.is_ok());
+        assert_eq!(app.buffer_manager.current_buffer_id(), Some(detail_id));
+        assert!(app.minibuffer.is_visible());
     }
 
-    fn trigger_async_rebuild_playlist(&mut self, playlist_id: crate::playlist::PlaylistId) {
-        let playlist_manager = self.playlist_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+    /// Helper: build a minimal UIApp with the sync buffer registered and active.
+    async fn make_app_with_sync_buffer() -> UIApp {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-        tokio::spawn(async move {
-            let playlist_name = playlist_manager
-                .get_playlist(&playlist_id)
-                .await
-                .map(|playlist| playlist.name)
-                .unwrap_or_else(|_| playlist_id.to_string());
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.keep();
 
-            match playlist_manager.rebuild_playlist_files(&playlist_id).await {
-                Ok(result) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistRebuilt {
-                        name: playlist_name,
-                        rebuilt: result.rebuilt,
-                        skipped: result.skipped,
-                        failed: result.failed,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PlaylistRebuildFailed {
-                        name: playlist_name,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
-    }
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_path.clone(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager.clone(),
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-    fn trigger_async_refresh_today(&mut self) {
-        let today_generator = self.today_generator.clone();
-        let app_event_tx = self.app_event_tx.clone();
+        // Switch to the sync buffer (now created by initialize())
+        app.buffer_manager
+            .switch_to_buffer(&"sync".to_string())
+            .unwrap();
 
-        tokio::spawn(async move {
-            match today_generator.refresh().await {
-                Ok(result) => {
-                    let _ = app_event_tx.send(AppEvent::TodayPlaylistRefreshed {
-                        added: result.added,
-                        removed: result.removed,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::TodayPlaylistRefreshFailed {
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        app
     }
 
-    /// Trigger async deletion of all downloads
-    fn trigger_async_delete_all_downloads(&mut self) {
-        let download_manager = self.download_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
+    #[tokio::test]
+    async fn test_prompt_input_from_sync_buffer_opens_minibuffer() {
+        // Arrange
+        let mut app = make_app_with_sync_buffer().await;
 
-        // Show immediate feedback
-        self.show_message("Deleting all downloaded episodes...".to_string());
+        // Act — SyncToDevice falls through to catch-all → sync buffer returns
+        // PromptInput → new PromptInput arm opens the minibuffer
+        let result = app.handle_action(UIAction::SyncToDevice).await;
 
-        tokio::spawn(async move {
-            match download_manager.delete_all_downloads().await {
-                Ok(deleted_count) => {
-                    let _ = app_event_tx.send(AppEvent::AllDownloadsDeleted { deleted_count });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::AllDownloadsDeletionFailed {
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Assert
+        assert!(result.is_ok());
+        assert!(
+            app.minibuffer.is_input_mode(),
+            "Minibuffer should be in input mode after SyncToDevice"
+        );
+        assert!(
+            app.minibuffer
+                .current_prompt()
+                .map(|p| p.starts_with("Sync to device path"))
+                .unwrap_or(false),
+            "Prompt should start with 'Sync to device path'"
+        );
     }
 
-    /// Refresh all buffer list buffers when buffers change
-    #[allow(dead_code)]
-    fn refresh_buffer_lists(&mut self) {
-        let _buffer_names = self.buffer_manager.buffer_names();
-        let _current_buffer = self.buffer_manager.current_buffer_id();
-
-        // Find and update any buffer list buffers
-        let buffer_ids = self.buffer_manager.get_buffer_ids();
-        for buffer_id in buffer_ids {
-            if buffer_id == "*Buffer List*" {
-                if let Some(_buffer) = self.buffer_manager.get_buffer(&buffer_id) {
-                    // We need to downcast to update the buffer list
-                    // For now, we'll handle this by recreating the buffer when needed
-                }
-                break;
-            }
-        }
-    }
+    #[tokio::test]
+    async fn test_download_episode_in_sync_buffer_opens_dry_run_prompt() {
+        // Arrange — 'D' maps to DownloadEpisode; sync buffer context guard intercepts it
+        let mut app = make_app_with_sync_buffer().await;
 
-    /// Refresh buffer list if one is currently open
-    fn refresh_buffer_list_if_open(&mut self) {
-        let buffer_list_id = "*Buffer List*".to_string();
-        if self
-            .buffer_manager
-            .get_buffer_ids()
-            .contains(&buffer_list_id)
-        {
-            // Only refresh the buffer list contents without switching to it
-            use crate::ui::buffers::buffer_list::BufferListBuffer;
+        // Act
+        let result = app.handle_action(UIAction::DownloadEpisode).await;
 
-            let buffer_names = self.buffer_manager.buffer_names();
-            let current_id = self.buffer_manager.current_buffer_id();
+        // Assert — should open dry-run prompt, not download-episode flow
+        assert!(result.is_ok());
+        assert!(
+            app.minibuffer.is_input_mode(),
+            "Minibuffer should be in input mode after 'D' in sync buffer"
+        );
+        assert!(
+            app.minibuffer
+                .current_prompt()
+                .map(|p| p.starts_with("Dry run sync to"))
+                .unwrap_or(false),
+            "Prompt should start with 'Dry run sync to'"
+        );
+    }
 
-            // Remove existing buffer list
-            let _ = self.buffer_manager.remove_buffer(&buffer_list_id);
+    #[tokio::test]
+    async fn test_unsubscribe_prompt_offers_history_only_while_restorable() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let prompt = Some("Delete podcast 'Show': keep (d)ownloads, ...".to_string());
+        app.config.storage.podcast_restore_days = 0;
 
-            // Create new buffer list buffer with updated data
-            let mut buffer_list_buffer = BufferListBuffer::new();
-            buffer_list_buffer.set_theme(self.theme.clone());
-            buffer_list_buffer.update_buffer_list(buffer_names, current_id.as_ref());
+        // Act — nothing can be restored, so keeping history is no choice
+        app.pending_deletion = Some(crate::storage::PodcastId::new());
+        app.handle_minibuffer_input_with_context("h".to_string(), prompt.clone());
 
-            // Add back without switching to it
-            let _ = self.buffer_manager.add_buffer(Box::new(buffer_list_buffer));
-        }
+        // Assert
+        assert!(app.pending_deletion.is_none());
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Unknown choice 'h': podcast not deleted"));
+        app.pending_deletion = Some(crate::storage::PodcastId::new());
+        app.handle_minibuffer_input_with_context(String::new(), prompt);
+        assert!(app.pending_deletion.is_none());
+        assert_eq!(app.default_unsubscribe_mode(), UnsubscribeMode::PurgeAll);
+        app.config.storage.podcast_restore_days = 30;
+        assert_eq!(app.default_unsubscribe_mode(), UnsubscribeMode::KeepAll);
     }
 
-    /// Trigger async podcast deletion
-    fn trigger_async_delete_podcast(&mut self, podcast_id: crate::storage::PodcastId) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
+    #[tokio::test]
+    async fn test_delete_podcast_in_sync_buffer_shows_nothing_to_delete() {
+        // Arrange — 'd' now consistently means delete; sync buffer has nothing to delete
+        let mut app = make_app_with_sync_buffer().await;
 
-        // Get podcast title for the event
-        let podcast_title =
-            if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut() {
-                podcast_buffer
-                    .selected_podcast()
-                    .map(|p| p.title.clone())
-                    .unwrap_or_else(|| "Unknown".to_string())
-            } else {
-                "Unknown".to_string()
-            };
+        // Act
+        let result = app.handle_action(UIAction::DeletePodcast).await;
 
-        tokio::spawn(async move {
-            match subscription_manager.unsubscribe(&podcast_id).await {
-                Ok(_) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastDeleted {
-                        podcast_id: podcast_id_clone,
-                        podcast_title,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::PodcastDeletionFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Assert — should show info message, not open a dry-run prompt
+        assert!(result.is_ok());
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should NOT enter input mode for 'd' in sync buffer"
+        );
+        // Verify show_message was actually called (not a silent no-op)
+        assert!(
+            app.minibuffer.is_visible(),
+            "Minibuffer should be visible (showing 'nothing to delete' message)"
+        );
+    }
 
-        // Show immediate feedback
-        self.show_message("Deleting podcast...".to_string());
+    #[tokio::test]
+    async fn test_refresh_podcast_in_sync_buffer_is_noop() {
+        // Arrange — 'r' maps to RefreshPodcast; sync buffer context guard short-circuits it
+        let mut app = make_app_with_sync_buffer().await;
+
+        // Act
+        let result = app.handle_action(UIAction::RefreshPodcast).await;
+
+        // Assert — should return cleanly without touching the podcast refresh flow
+        assert!(result.is_ok());
+        assert!(result.unwrap(), "Should return true (continue running)");
+        // Minibuffer should not have been opened for a prompt
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should NOT enter input mode for 'r' in sync buffer"
+        );
+        // Buffer should still be sync
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("sync".to_string())
+        );
     }
 
-    /// Trigger async OPML import
-    fn trigger_async_opml_import(&mut self, source: String) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let source_clone = source.clone();
+    #[tokio::test]
+    async fn test_initialize_creates_sync_buffer() {
+        // Arrange — build UIApp via new() which leaves BufferManager empty,
+        // then call initialize() (the real startup path from App::run)
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-        // Send start event
-        let _ = app_event_tx.send(AppEvent::OpmlImportStarted {
-            source: source.clone(),
-        });
+        let config = Config::default();
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.keep();
 
-        tokio::spawn(async move {
-            // Create progress callback
-            let app_event_tx_progress = app_event_tx.clone();
-            let progress_callback = move |status: String| {
-                let _ = app_event_tx_progress.send(AppEvent::OpmlImportProgress {
-                    current: 0,
-                    total: 0,
-                    status,
-                });
-            };
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
+        let download_manager = Arc::new(
+            DownloadManager::new(storage.clone(), temp_path, DownloadConfig::default()).unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
 
-            match subscription_manager
-                .import_opml(&source, progress_callback)
-                .await
-            {
-                Ok((result, log_path)) => {
-                    let _ = app_event_tx.send(AppEvent::OpmlImportCompleted { result, log_path });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::OpmlImportFailed {
-                        source: source_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        // Act
+        app.initialize().await.unwrap();
+
+        // Assert — sync buffer must be registered and switchable
+        let buffer_ids = app.buffer_manager.get_buffer_ids();
+        assert!(
+            buffer_ids.contains(&"sync".to_string()),
+            "initialize() must create the sync buffer; got: {:?}",
+            buffer_ids
+        );
+        assert!(
+            app.buffer_manager
+                .switch_to_buffer(&"sync".to_string())
+                .is_ok(),
+            "Should be able to switch to sync buffer after initialize()"
+        );
     }
 
-    /// Get the default sync device path from config, falling back to the constant default
-    fn get_default_sync_path(&self) -> String {
-        self.config
-            .downloads
-            .sync_device_path
-            .as_ref()
-            .map(|p| shellexpand::tilde(p).to_string())
-            .unwrap_or_else(|| crate::constants::downloads::DEFAULT_SYNC_DEVICE_PATH.to_string())
+    // ── parse_smart_playlist_args / parse_filter_spec / parse_sort_spec ────
+
+    #[test]
+    fn test_parse_smart_playlist_args_empty_defaults_to_downloaded() {
+        // Arrange
+        let args: &[&str] = &[];
+        // Act
+        let rule = parse_smart_playlist_args(args).unwrap();
+        // Assert
+        use crate::playlist::models::SmartFilter;
+        assert_eq!(rule.filter, SmartFilter::Downloaded);
+        assert!(rule.sort.is_none());
+        assert!(rule.limit.is_none());
     }
 
-    /// Parse sync command arguments, extracting optional --hard and optional device path.
-    fn parse_sync_command_args(args: &[&str]) -> (Option<String>, bool) {
-        let mut hard_sync = false;
-        let mut path_parts = Vec::new();
+    #[test]
+    fn test_parse_smart_playlist_args_single_filter_used_directly() {
+        // Arrange / Act
+        let rule = parse_smart_playlist_args(&["--filter", "favorited"]).unwrap();
+        // Assert
+        use crate::playlist::models::SmartFilter;
+        assert_eq!(rule.filter, SmartFilter::Favorited);
+    }
 
-        for arg in args {
-            if *arg == "--hard" {
-                hard_sync = true;
-            } else {
-                path_parts.push(*arg);
-            }
-        }
+    #[test]
+    fn test_parse_smart_playlist_args_multiple_filters_become_and() {
+        // Arrange / Act
+        let rule = parse_smart_playlist_args(&["--filter", "downloaded", "--filter", "favorited"])
+            .unwrap();
+        // Assert
+        use crate::playlist::models::SmartFilter;
+        assert_eq!(
+            rule.filter,
+            SmartFilter::And(vec![SmartFilter::Downloaded, SmartFilter::Favorited])
+        );
+    }
 
-        if path_parts.is_empty() {
-            (None, hard_sync)
-        } else {
-            (Some(path_parts.join(" ")), hard_sync)
-        }
+    #[test]
+    fn test_parse_smart_playlist_args_sort_date_desc() {
+        use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
+        let rule = parse_smart_playlist_args(&["--sort", "date-desc"]).unwrap();
+        assert_eq!(
+            rule.sort,
+            Some(SmartSort {
+                field: SmartSortField::Date,
+                direction: SmartSortDirection::Descending,
+            })
+        );
     }
 
-    /// Trigger async device sync
-    fn trigger_async_device_sync(
-        &mut self,
-        device_path_str: String,
-        delete_orphans: bool,
-        dry_run: bool,
-        hard_sync: bool,
-    ) {
-        let download_manager = self.download_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let playlists_dir = if self.config.downloads.sync_include_playlists {
-            Some(self._storage.data_dir.join("Playlists"))
-        } else {
-            None
-        };
+    #[test]
+    fn test_parse_smart_playlist_args_sort_duration_asc() {
+        use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
+        let rule = parse_smart_playlist_args(&["--sort", "duration-asc"]).unwrap();
+        assert_eq!(
+            rule.sort,
+            Some(SmartSort {
+                field: SmartSortField::Duration,
+                direction: SmartSortDirection::Ascending,
+            })
+        );
+    }
 
-        // Expand tilde and convert to PathBuf
-        let expanded_path = shellexpand::tilde(&device_path_str).to_string();
-        let device_path = std::path::PathBuf::from(expanded_path);
-        let device_path_clone = device_path.clone();
+    #[test]
+    fn test_parse_smart_playlist_args_limit_valid() {
+        // Arrange / Act
+        let rule = parse_smart_playlist_args(&["--limit", "25"]).unwrap();
+        // Assert
+        assert_eq!(rule.limit, Some(25));
+    }
 
-        // Use config defaults if not specified
-        let delete_orphans = if delete_orphans {
-            true
-        } else {
-            self.config.downloads.sync_delete_orphans
-        };
+    #[test]
+    fn test_parse_smart_playlist_args_limit_invalid_returns_error() {
+        // Arrange / Act
+        let err = parse_smart_playlist_args(&["--limit", "abc"]).unwrap_err();
+        // Assert
+        assert!(
+            err.contains("number"),
+            "Error should mention 'number': {err}"
+        );
+    }
 
-        // Send start event
-        let _ = app_event_tx.send(AppEvent::DeviceSyncStarted {
-            device_path: device_path.clone(),
-            dry_run,
-            hard_sync,
-        });
+    #[test]
+    fn test_parse_smart_playlist_args_unknown_flag_returns_error() {
+        let err = parse_smart_playlist_args(&["--unknown", "foo"]).unwrap_err();
+        assert!(
+            err.contains("Unknown argument"),
+            "Error should say 'Unknown argument': {err}"
+        );
+    }
 
-        // For real syncs (not dry-run): create a progress channel and enter Progress mode.
-        let progress_tx = if !dry_run {
-            if let Some(sync_buffer) = self.buffer_manager.get_sync_buffer_mut() {
-                sync_buffer.enter_progress_mode(device_path.clone());
-            }
-            let (tx, mut rx) =
-                tokio::sync::mpsc::unbounded_channel::<crate::download::SyncProgressEvent>();
-            // Relay progress events to app_event_tx
-            let relay_tx = app_event_tx.clone();
-            tokio::spawn(async move {
-                while let Some(event) = rx.recv().await {
-                    let _ = relay_tx.send(AppEvent::DeviceSyncProgress { event });
-                }
-            });
-            Some(tx)
-        } else {
-            None
-        };
+    #[test]
+    fn test_parse_smart_playlist_args_filter_tag() {
+        use crate::playlist::models::SmartFilter;
+        let rule = parse_smart_playlist_args(&["--filter", "tag:rust"]).unwrap();
+        assert_eq!(rule.filter, SmartFilter::Tag("rust".to_string()));
+    }
 
-        tokio::spawn(async move {
-            match download_manager
-                .sync_to_device(
-                    device_path.clone(),
-                    playlists_dir,
-                    delete_orphans,
-                    dry_run,
-                    hard_sync,
-                    progress_tx,
-                )
-                .await
-            {
-                Ok(report) => {
-                    let _ = app_event_tx.send(AppEvent::DeviceSyncCompleted {
-                        device_path,
-                        report,
-                        dry_run,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::DeviceSyncFailed {
-                        device_path: device_path_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+    #[test]
+    fn test_parse_smart_playlist_args_filter_newer_than_valid() {
+        use crate::playlist::models::SmartFilter;
+        let rule = parse_smart_playlist_args(&["--filter", "newer-than:7"]).unwrap();
+        assert_eq!(rule.filter, SmartFilter::NewerThan(7));
     }
 
-    /// Trigger async OPML export
-    fn trigger_async_opml_export(&mut self, output_path: String) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let output_path_clone = output_path.clone();
+    #[test]
+    fn test_parse_smart_playlist_args_filter_newer_than_invalid() {
+        let err = parse_smart_playlist_args(&["--filter", "newer-than:notanumber"]).unwrap_err();
+        assert!(
+            err.contains("number of days"),
+            "Error should mention 'number of days': {err}"
+        );
+    }
 
-        // Expand tilde and generate filename if needed
-        let expanded_path = shellexpand::tilde(&output_path).to_string();
-        let final_path =
-            if std::path::Path::new(&expanded_path).is_dir() || !expanded_path.contains('.') {
-                // It's a directory or doesn't have an extension, generate filename
-                use chrono::Local;
-                let timestamp = Local::now().format("%Y-%m-%d-%H%M%S");
-                let filename = format!("podcasts-export-{}.opml", timestamp);
-                std::path::PathBuf::from(expanded_path).join(filename)
-            } else {
-                // It's a full file path
-                std::path::PathBuf::from(expanded_path)
-            };
+    #[test]
+    fn test_parse_smart_playlist_args_filter_unknown_returns_error() {
+        let err = parse_smart_playlist_args(&["--filter", "bogus"]).unwrap_err();
+        assert!(
+            err.contains("Unknown filter"),
+            "Error should say 'Unknown filter': {err}"
+        );
+    }
 
-        let final_path_str = final_path.to_string_lossy().to_string();
+    #[test]
+    fn test_parse_smart_playlist_args_all_sort_variants() {
+        for spec in &[
+            "date-asc",
+            "date-desc",
+            "title-asc",
+            "title-desc",
+            "duration-asc",
+            "duration-desc",
+        ] {
+            let result = parse_smart_playlist_args(&["--sort", spec]);
+            assert!(
+                result.is_ok(),
+                "Sort spec '{spec}' should parse successfully"
+            );
+        }
+    }
 
-        // Send start event
-        let _ = app_event_tx.send(AppEvent::OpmlExportStarted {
-            path: final_path_str.clone(),
-        });
+    #[test]
+    fn test_parse_smart_playlist_args_sort_unknown_returns_error() {
+        let err = parse_smart_playlist_args(&["--sort", "random"]).unwrap_err();
+        assert!(
+            err.contains("Unknown sort"),
+            "Error should say 'Unknown sort': {err}"
+        );
+    }
 
-        tokio::spawn(async move {
-            // Create progress callback
-            let app_event_tx_progress = app_event_tx.clone();
-            let progress_callback = move |status: String| {
-                let _ = app_event_tx_progress.send(AppEvent::OpmlExportProgress { status });
-            };
+    #[test]
+    fn test_parse_commute_args_takes_per_podcast_and_rule_flags() {
+        use crate::playlist::models::SmartFilter;
 
-            match subscription_manager
-                .export_opml(&final_path, progress_callback)
-                .await
-            {
-                Ok(feed_count) => {
-                    let _ = app_event_tx.send(AppEvent::OpmlExportCompleted {
-                        path: final_path_str,
-                        feed_count,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::OpmlExportFailed {
-                        path: output_path_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+        let request = parse_commute_args(
+            2700,
+            &[
+                "--per-podcast",
+                "1",
+                "--filter",
+                "favorited",
+                "--limit",
+                "3",
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(request.budget_secs, 2700);
+        assert_eq!(request.max_per_podcast, 1);
+        assert_eq!(request.rule.filter, SmartFilter::Favorited);
+        assert_eq!(request.rule.limit, Some(3));
+        assert!(parse_commute_args(2700, &["--per-podcast", "0"]).is_err());
     }
 
-    /// Trigger async episode loading
-    fn trigger_async_load_episodes(
-        &mut self,
-        podcast_id: crate::storage::PodcastId,
-        podcast_name: String,
-    ) {
-        let subscription_manager = self.subscription_manager.clone();
-        let app_event_tx = self.app_event_tx.clone();
-        let podcast_id_clone = podcast_id.clone();
-        let podcast_name_clone = podcast_name.clone();
+    // ── AudioManager wiring tests (#141) ─────────────────────────────────────
 
-        tokio::spawn(async move {
-            match subscription_manager
-                .storage
-                .load_episodes(&podcast_id)
-                .await
-            {
-                Ok(episodes) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodesLoaded {
-                        podcast_id: podcast_id_clone,
-                        podcast_name: podcast_name_clone,
-                        episodes,
-                    });
-                }
-                Err(e) => {
-                    let _ = app_event_tx.send(AppEvent::EpisodesLoadFailed {
-                        podcast_id: podcast_id_clone,
-                        error: e.to_string(),
-                    });
-                }
-            }
-        });
+    /// Creates a UIApp backed by real storage (initialized).
+    /// Returns both the app and the storage so tests can pre-populate data.
+    async fn make_test_app_with_storage() -> (UIApp, Arc<crate::storage::JsonStorage>) {
+        make_test_app_with_config(Config::default()).await
     }
 
-    /// Trigger background buffer refresh to avoid blocking UI thread
-    fn trigger_background_refresh(&mut self, refresh_type: BufferRefreshType) {
-        match refresh_type.clone() {
-            BufferRefreshType::PodcastList => {
-                let subscription_manager = self.subscription_manager.clone();
-                let app_event_tx = self.app_event_tx.clone();
+    async fn make_test_app_with_config(
+        config: Config,
+    ) -> (UIApp, Arc<crate::storage::JsonStorage>) {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
+        use tempfile::TempDir;
 
-                tokio::spawn(async move {
-                    match subscription_manager.storage.list_podcasts().await {
-                        Ok(podcast_ids) => {
-                            let storage = subscription_manager.storage.clone();
-                            let mut indexed: Vec<(usize, _)> =
-                                stream::iter(podcast_ids.into_iter().enumerate())
-                                    .map(|(idx, id)| {
-                                        let storage = storage.clone();
-                                        async move {
-                                            storage.load_podcast(&id).await.ok().map(|p| (idx, p))
-                                        }
-                                    })
-                                    .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
-                                    .filter_map(|r| async { r })
-                                    .collect()
-                                    .await;
-                            indexed.sort_unstable_by_key(|(idx, _)| *idx);
-                            let podcasts: Vec<_> = indexed.into_iter().map(|(_, p)| p).collect();
-                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                                buffer_type: BufferRefreshType::PodcastList,
-                                data: BufferRefreshData::PodcastList { podcasts },
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                                buffer_type: BufferRefreshType::PodcastList,
-                                data: BufferRefreshData::Error {
-                                    message: e.to_string(),
-                                },
-                            });
-                        }
-                    }
-                });
-            }
-            BufferRefreshType::Downloads => {
-                let download_manager = self.download_manager.clone();
-                let app_event_tx = self.app_event_tx.clone();
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.keep();
 
-                tokio::spawn(async move {
-                    // Load all (podcast, episodes) pairs concurrently
-                    let downloads = if let Ok(podcast_ids) =
-                        download_manager.storage().list_podcasts().await
-                    {
-                        let dm = download_manager.clone();
-                        let mut podcast_pairs: Vec<_> =
-                            stream::iter(podcast_ids.into_iter().enumerate())
-                                .map(|(idx, podcast_id)| {
-                                    let dm = dm.clone();
-                                    async move {
-                                        let podcast =
-                                            dm.storage().load_podcast(&podcast_id).await.ok()?;
-                                        let episodes =
-                                            dm.storage().load_episodes(&podcast_id).await.ok()?;
-                                        Some((idx, podcast_id, podcast, episodes))
-                                    }
-                                })
-                                .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
-                                .filter_map(|r| async { r })
-                                .collect()
-                                .await;
-                        podcast_pairs.sort_unstable_by_key(|(idx, ..)| *idx);
+        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
+        storage.initialize().await.unwrap();
 
-                        // Collect candidates (filter without I/O), then fetch metadata concurrently
-                        let mut candidates: Vec<(
-                            usize,
-                            crate::storage::PodcastId,
-                            crate::podcast::Podcast,
-                            crate::podcast::Episode,
-                        )> = Vec::new();
-                        let mut candidate_idx = 0usize;
-                        for (_, podcast_id, podcast, episodes) in podcast_pairs {
-                            for episode in episodes {
-                                if episode.is_downloaded()
-                                    || matches!(
-                                        episode.status,
-                                        crate::podcast::EpisodeStatus::Downloading
-                                            | crate::podcast::EpisodeStatus::DownloadFailed
-                                    )
-                                {
-                                    candidates.push((
-                                        candidate_idx,
-                                        podcast_id.clone(),
-                                        podcast.clone(),
-                                        episode,
-                                    ));
-                                    candidate_idx += 1;
-                                }
-                            }
-                        }
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_path.clone(),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
 
-                        // Fetch file sizes concurrently with bounded concurrency; sort to restore order
-                        let mut indexed: Vec<(usize, DownloadEntry)> = stream::iter(candidates)
-                            .map(|(idx, podcast_id, podcast, episode)| async move {
-                                let status = match episode.status {
-                                    crate::podcast::EpisodeStatus::Downloaded => {
-                                        crate::download::DownloadStatus::Completed
-                                    }
-                                    crate::podcast::EpisodeStatus::Downloading => {
-                                        crate::download::DownloadStatus::InProgress
-                                    }
-                                    crate::podcast::EpisodeStatus::DownloadFailed => {
-                                        crate::download::DownloadStatus::Failed(
-                                            "Download failed".to_string(),
-                                        )
-                                    }
-                                    _ => return None,
-                                };
-                                let file_size = match episode.local_path.as_ref() {
-                                    Some(path) => {
-                                        tokio::fs::metadata(path).await.ok().map(|m| m.len())
-                                    }
-                                    None => None,
-                                };
-                                Some((
-                                    idx,
-                                    DownloadEntry {
-                                        podcast_id,
-                                        episode_id: episode.id.clone(),
-                                        podcast_name: podcast.title.clone(),
-                                        episode_title: episode.title.clone(),
-                                        status,
-                                        file_path: episode.local_path.clone(),
-                                        file_size,
-                                    },
-                                ))
-                            })
-                            .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
-                            .filter_map(|r| async { r })
-                            .collect()
-                            .await;
-                        indexed.sort_unstable_by_key(|(idx, _)| *idx);
-                        indexed.into_iter().map(|(_, entry)| entry).collect()
-                    } else {
-                        Vec::new()
-                    };
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            config,
+            subscription_manager,
+            download_manager,
+            storage.clone(),
+            app_event_tx,
+        )
+        .unwrap();
+        app.initialize().await.unwrap();
 
-                    let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                        buffer_type: BufferRefreshType::Downloads,
-                        data: BufferRefreshData::Downloads { downloads },
-                    });
-                });
-            }
-            BufferRefreshType::WhatsNew => {
-                let subscription_manager = self.subscription_manager.clone();
-                let app_event_tx = self.app_event_tx.clone();
-                let episode_limit = self.config.ui.whats_new_episode_limit;
+        (app, storage)
+    }
 
-                tokio::spawn(async move {
-                    // Load What's New episodes data in background
-                    let mut all_episodes = Vec::new();
-
-                    if let Ok(podcast_ids) = subscription_manager.storage.list_podcasts().await {
-                        let storage = subscription_manager.storage.clone();
-                        let podcast_pairs: Vec<_> = stream::iter(podcast_ids)
-                            .map(|podcast_id| {
-                                let storage = storage.clone();
-                                async move {
-                                    let podcast = storage.load_podcast(&podcast_id).await.ok()?;
-                                    let episodes = storage.load_episodes(&podcast_id).await.ok()?;
-                                    Some((podcast_id, podcast, episodes))
-                                }
-                            })
-                            .buffer_unordered(ui_constants::REFRESH_IO_CONCURRENCY)
-                            .filter_map(|r| async { r })
-                            .collect()
-                            .await;
+    async fn make_test_app() -> UIApp {
+        make_test_app_with_storage().await.0
+    }
 
-                        // Process results (CPU only, no I/O)
-                        for (podcast_id, podcast, episodes) in podcast_pairs {
-                            for episode in episodes {
-                                // Only show episodes that aren't downloaded or downloading
-                                if !episode.is_downloaded()
-                                    && !matches!(
-                                        episode.status,
-                                        crate::podcast::EpisodeStatus::Downloading
-                                    )
-                                {
-                                    all_episodes.push(AggregatedEpisode {
-                                        podcast_id: podcast_id.clone(),
-                                        podcast_title: podcast.title.clone(),
-                                        episode,
-                                    });
-                                }
-                            }
-                        }
-                    }
+    #[tokio::test]
+    async fn test_read_only_app_flags_itself_and_refuses_refresh() {
+        use crate::config::DownloadConfig;
+        use crate::storage::JsonStorage;
 
-                    // Sort by publication date (newest first)
-                    all_episodes.sort_by(|a, b| b.episode.published.cmp(&a.episode.published));
+        // Arrange — a data directory another instance holds
+        let temp_dir = tempfile::tempdir().unwrap();
+        JsonStorage::with_data_dir(temp_dir.path().to_path_buf())
+            .initialize()
+            .await
+            .unwrap();
+        let storage =
+            Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()).read_only());
+        let download_manager = Arc::new(
+            DownloadManager::new(
+                storage.clone(),
+                temp_dir.path().join("downloads"),
+                DownloadConfig::default(),
+            )
+            .unwrap(),
+        );
+        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
+            storage.clone(),
+            download_manager.clone(),
+        ));
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let mut app = UIApp::new(
+            Config::default(),
+            subscription_manager,
+            download_manager,
+            storage,
+            app_event_tx,
+        )
+        .unwrap();
 
-                    // Apply episode limit
-                    all_episodes.truncate(episode_limit);
+        // Act
+        app.initialize().await.unwrap();
+        let opened = app.minibuffer.text_content();
+        app.execute_command_direct("refresh".to_string()).unwrap();
 
-                    let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                        buffer_type: BufferRefreshType::WhatsNew,
-                        data: BufferRefreshData::WhatsNew {
-                            episodes: all_episodes,
-                        },
-                    });
-                });
-            }
-            BufferRefreshType::EpisodeBuffers { podcast_id } => {
-                let subscription_manager = self.subscription_manager.clone();
-                let app_event_tx = self.app_event_tx.clone();
+        // Assert
+        assert_eq!(app.status_bar.indicator(), READ_ONLY_INDICATOR);
+        assert!(opened.contains("Read-only mode"), "{opened}");
+        assert!(app.minibuffer.text_content().contains(
+            "Read-only mode — Refresh is left to the instance holding the data directory"
+        ));
+    }
 
-                tokio::spawn(async move {
-                    match subscription_manager
-                        .storage
-                        .load_episodes(&podcast_id)
-                        .await
-                    {
-                        Ok(episodes) => {
-                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                                buffer_type: BufferRefreshType::EpisodeBuffers {
-                                    podcast_id: podcast_id.clone(),
-                                },
-                                data: BufferRefreshData::Episodes {
-                                    podcast_id,
-                                    episodes,
-                                },
-                            });
-                        }
-                        Err(e) => {
-                            let _ = app_event_tx.send(AppEvent::BufferDataRefreshed {
-                                buffer_type: BufferRefreshType::EpisodeBuffers { podcast_id },
-                                data: BufferRefreshData::Error {
-                                    message: e.to_string(),
-                                },
-                            });
-                        }
-                    }
-                });
-            }
-            BufferRefreshType::AllEpisodeBuffers => {
-                // Collect podcast IDs from all open episode buffers, then
-                // trigger an individual refresh for each one.
-                let buffer_ids = self.buffer_manager.get_buffer_ids();
-                let mut podcast_ids: Vec<crate::storage::PodcastId> = Vec::new();
-                for buffer_id in &buffer_ids {
-                    if buffer_id.starts_with("episodes-") {
-                        if let Some(episode_buffer) = self
-                            .buffer_manager
-                            .get_episode_list_buffer_mut_by_id(buffer_id)
-                        {
-                            podcast_ids.push(episode_buffer.podcast_id.clone());
-                        }
-                    }
-                }
-                for podcast_id in podcast_ids {
-                    self.trigger_background_refresh(BufferRefreshType::EpisodeBuffers {
-                        podcast_id,
-                    });
-                }
-            }
-        }
+    #[tokio::test]
+    async fn test_handle_action_toggle_play_pause_sends_command_when_audio_available() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+
+        // Act
+        let result = app.handle_action(UIAction::TogglePlayPause).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let cmd = audio_rx
+            .try_recv()
+            .expect("Expected AudioCommand to be sent");
+        assert!(
+            matches!(cmd, AudioCommand::TogglePlayPause),
+            "Expected TogglePlayPause, got {cmd:?}"
+        );
     }
 
-    /// Handle buffer data refresh by updating buffers with pre-loaded data
-    fn handle_buffer_data_refresh(
-        &mut self,
-        buffer_type: BufferRefreshType,
-        data: BufferRefreshData,
-    ) {
-        match (buffer_type, data) {
-            (BufferRefreshType::PodcastList, BufferRefreshData::PodcastList { podcasts }) => {
-                if let Some(podcast_buffer) = self.buffer_manager.get_podcast_list_buffer_mut() {
-                    podcast_buffer.set_podcasts(podcasts);
-                }
-            }
-            (BufferRefreshType::Downloads, BufferRefreshData::Downloads { downloads }) => {
-                if let Some(downloads_buffer) = self.buffer_manager.get_downloads_buffer_mut() {
-                    downloads_buffer.set_downloads(downloads);
-                }
-            }
-            (BufferRefreshType::WhatsNew, BufferRefreshData::WhatsNew { episodes }) => {
-                if let Some(whats_new_buffer) = self.buffer_manager.get_whats_new_buffer_mut() {
-                    let episode_count = episodes.len();
-                    whats_new_buffer.set_episodes(episodes);
+    #[tokio::test]
+    async fn test_handle_action_toggle_play_pause_blocked_in_minibuffer_input_mode() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        app.minibuffer.show_prompt("test: ".to_string());
+        assert!(app.minibuffer.is_input_mode());
 
-                    // Show message only if we're currently viewing the What's New buffer
-                    if let Some(active_id) = self.buffer_manager.active_buffer_id() {
-                        if active_id == "whats-new" {
-                            self.show_message(format!(
-                                "What's New updated with {} episode(s)",
-                                episode_count
-                            ));
-                        }
-                    }
-                }
-            }
-            (
-                BufferRefreshType::EpisodeBuffers { podcast_id },
-                BufferRefreshData::Episodes { episodes, .. },
-            ) => {
-                let buffer_ids = self.buffer_manager.get_buffer_ids();
-                for buffer_id in buffer_ids {
-                    if buffer_id.starts_with("episodes-") {
-                        if let Some(episode_buffer) = self
-                            .buffer_manager
-                            .get_episode_list_buffer_mut_by_id(&buffer_id)
-                        {
-                            // Check if this buffer belongs to the podcast
-                            if episode_buffer.podcast_id == podcast_id {
-                                episode_buffer.set_episodes(episodes.clone());
-                            }
-                        }
-                    }
-                }
-            }
-            (_, BufferRefreshData::Error { message }) => {
-                self.show_error(format!("Could not refresh buffer: {}", message));
-            }
-            _ => {
-                // Mismatched buffer type and data, ignore
-            }
-        }
+        // Act
+        let result = app.handle_action(UIAction::TogglePlayPause).await;
+
+        // Assert — no command should be dispatched while minibuffer is in input mode
+        assert!(result.is_ok());
+        assert!(
+            audio_rx.try_recv().is_err(),
+            "No AudioCommand should be sent when minibuffer is in input mode"
+        );
     }
 
-    async fn load_playlists_into_buffer(&mut self) {
-        match self.playlist_manager.list_playlists().await {
-            Ok(playlists) => {
-                if let Some(buffer) = self.buffer_manager.get_playlist_list_buffer_mut() {
-                    buffer.set_playlists(playlists);
-                }
-            }
-            Err(e) => self.show_error(format!("Could not load playlists: {}", e)),
-        }
+    #[tokio::test]
+    async fn test_handle_action_stop_playback_sends_stop_command() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+
+        // Act
+        let result = app.handle_action(UIAction::StopPlayback).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let cmd = audio_rx.try_recv().expect("Expected AudioCommand::Stop");
+        assert!(
+            matches!(cmd, AudioCommand::Stop),
+            "Expected Stop, got {cmd:?}"
+        );
     }
 
-    async fn refresh_open_playlist_detail_buffers(&mut self) {
-        let mut details_to_refresh: Vec<(String, crate::playlist::PlaylistId)> = Vec::new();
-        for buffer_id in self.buffer_manager.get_buffer_ids() {
-            if !buffer_id.starts_with("playlist-") || buffer_id == "playlist-list" {
-                continue;
-            }
-            if let Some(detail_buffer) = self
-                .buffer_manager
-                .get_playlist_detail_buffer_mut_by_id(&buffer_id)
-            {
-                details_to_refresh.push((buffer_id, detail_buffer.playlist_id().clone()));
-            }
-        }
+    #[tokio::test]
+    async fn test_handle_action_volume_up_sends_volume_up_command() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
 
-        for (buffer_id, playlist_id) in details_to_refresh {
-            if let Ok(playlist) = self.playlist_manager.get_playlist(&playlist_id).await {
-                if let Some(detail_buffer) = self
-                    .buffer_manager
-                    .get_playlist_detail_buffer_mut_by_id(&buffer_id)
-                {
-                    detail_buffer.set_playlist(playlist);
-                }
-            }
-        }
+        // Act
+        let result = app.handle_action(UIAction::VolumeUp).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let cmd = audio_rx
+            .try_recv()
+            .expect("Expected AudioCommand::VolumeUp");
+        assert!(
+            matches!(cmd, AudioCommand::VolumeUp),
+            "Expected VolumeUp, got {cmd:?}"
+        );
     }
 
-    /// Handle minibuffer input submission with context
-    fn handle_minibuffer_input_with_context(
-        &mut self,
-        input: String,
-        prompt_context: Option<String>,
-    ) {
-        let input = input.trim();
+    #[tokio::test]
+    async fn test_handle_app_event_track_ended_marks_episode_played_and_persists() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
+        use chrono::Utc;
 
-        if input.is_empty() {
-            // Handle empty input for specific prompts
-            if let Some(prompt) = &prompt_context {
-                if prompt.starts_with("Export to") {
-                    // Empty input means use default export path
-                    let default_path =
-                        shellexpand::tilde(&self.config.storage.opml_export_directory).to_string();
-                    self.trigger_async_opml_export(default_path);
-                    return;
-                } else if prompt.starts_with("Sync to device path") {
-                    // Empty input means use default sync path
-                    let default_path = self.get_default_sync_path();
-                    self.trigger_async_device_sync(default_path, false, false, false);
-                    return;
-                } else if prompt.starts_with("Dry run sync to") {
-                    // Empty input means use default sync path for dry run
-                    let default_path = self.get_default_sync_path();
-                    self.trigger_async_device_sync(default_path, false, true, false);
-                    return;
-                }
-            }
-            return;
-        }
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
 
-        // Check context from prompt FIRST (before checking for URLs)
-        if let Some(prompt) = &prompt_context {
-            if prompt.starts_with("Search:") {
-                // This is a search query — dispatch to active buffer
-                if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-                    current_buffer.handle_action(UIAction::ApplySearch {
-                        query: input.to_string(),
-                    });
-                }
-                return;
-            } else if prompt.starts_with("Import OPML from") {
-                // This is an OPML import
-                self.trigger_async_opml_import(input.to_string());
-                return;
-            } else if prompt.starts_with("Export to") {
-                // This is an OPML export
-                self.trigger_async_opml_export(input.to_string());
-                return;
-            } else if prompt.starts_with("Sync to device path") {
-                // This is a device sync
-                let args: Vec<&str> = input.split_whitespace().collect();
-                let (device_path, hard_sync) = Self::parse_sync_command_args(&args);
-                let device_path = device_path.unwrap_or_else(|| self.get_default_sync_path());
-                self.trigger_async_device_sync(device_path, false, false, hard_sync);
-                return;
-            } else if prompt.starts_with("Dry run sync to") {
-                // This is a device sync dry run
-                let args: Vec<&str> = input.split_whitespace().collect();
-                let (device_path, hard_sync) = Self::parse_sync_command_args(&args);
-                let device_path = device_path.unwrap_or_else(|| self.get_default_sync_path());
-                self.trigger_async_device_sync(device_path, false, true, hard_sync);
-                return;
-            } else if prompt.starts_with("Create playlist:") {
-                self.trigger_async_create_playlist(input.to_string(), None);
-                return;
-            } else if prompt.starts_with("Delete downloaded episodes older than") {
-                // This is a cleanup confirmation (y/n)
-                if input.to_lowercase() == "y" || input.to_lowercase() == "yes" {
-                    if let Some(hours) = self.pending_cleanup_hours.take() {
-                        let label = crate::utils::time::format_cleanup_duration(hours);
-                        self.trigger_async_cleanup_downloads(hours, label);
-                    }
-                } else {
-                    self.pending_cleanup_hours = None;
-                    self.show_message("Download cleanup cancelled".to_string());
-                }
-                return;
-            } else if prompt.starts_with("Delete downloads older than") {
-                // This is a duration input prompt (no argument was provided)
-                if let Some(total_hours) = crate::utils::time::parse_cleanup_duration(input) {
-                    let label = crate::utils::time::format_cleanup_duration(total_hours);
-                    self.minibuffer.set_content(MinibufferContent::Input {
-                        prompt: format!("Delete downloaded episodes older than {}? (y/n) ", label),
-                        input: String::new(),
-                    });
-                    self.pending_cleanup_hours = Some(total_hours);
-                } else {
-                    self.show_error(format!(
-                        "Invalid duration: '{}'. Use e.g., 7d, 2w, 1m, 12h",
-                        input
-                    ));
-                }
-                return;
-            }
-        }
+        let mut podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Test Episode".to_string(),
+            "http://example.com/ep1.mp3".to_string(),
+            // Older than a day, so the Today playlist doesn't download it meanwhile
+            Utc::now() - chrono::Duration::days(2),
+        );
+        episode.duration = Some(3600);
+        podcast.episodes.push(episode.id.clone());
+        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
+        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
 
-        // If no prompt context, fall back to old behavior
-        self.handle_minibuffer_input_legacy(input.to_string());
-    }
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id: podcast.id.clone(),
+            episode_id: episode.id.clone(),
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-    /// Handle minibuffer input submission (legacy method for backward compatibility)
-    fn handle_minibuffer_input(&mut self, input: String) {
-        self.handle_minibuffer_input_legacy(input);
+        // Assert — reload from storage and verify persistence
+        let saved = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap(); // unwrap OK — episode was saved above
+        assert!(saved.is_played(), "Episode should be marked as played");
+        assert_eq!(
+            saved.last_played_position,
+            Some(3600),
+            "Position should be set to full duration"
+        );
+        assert_eq!(saved.play_count, 1, "play_count should be incremented to 1");
     }
 
-    /// Legacy minibuffer input handler
-    fn handle_minibuffer_input_legacy(&mut self, input: String) {
-        let input = input.trim();
-
-        // Check if this looks like a URL (basic heuristic for podcast addition)
-        if input.starts_with("http://") || input.starts_with("https://") {
-            self.show_message(format!("Adding podcast: {}...", input));
-            self.trigger_async_add_podcast(input.to_string());
-        } else if input.to_lowercase() == "y" || input.to_lowercase() == "yes" {
-            // Handle podcast deletion confirmation
-            if let Some(podcast_id) = self.pending_deletion.take() {
-                self.trigger_async_delete_podcast(podcast_id);
-            } else if let Some(playlist_id) = self.pending_playlist_deletion.take() {
-                self.trigger_async_delete_playlist(playlist_id);
-            } else if self.pending_bulk_deletion {
-                // Handle bulk deletion confirmation
-                self.pending_bulk_deletion = false;
-                self.trigger_async_delete_all_downloads();
-            } else {
-                self.show_message("No deletion pending".to_string());
-            }
-        } else if input.to_lowercase() == "n" || input.to_lowercase() == "no" {
-            // Cancel deletion
-            if self.pending_deletion.is_some() {
-                self.pending_deletion = None;
-                self.show_message("Podcast deletion cancelled".to_string());
-            } else if self.pending_playlist_deletion.is_some() {
-                self.pending_playlist_deletion = None;
-                self.show_message("Playlist deletion cancelled".to_string());
-            } else if self.pending_bulk_deletion {
-                self.pending_bulk_deletion = false;
-                self.show_message("Bulk deletion cancelled".to_string());
-            } else {
-                self.show_message("No deletion to cancel".to_string());
-            }
-        } else {
-            // Check if this is a buffer name
-            let buffer_names = self.buffer_manager.buffer_names();
-            let matching_buffer = buffer_names.iter().find(|(_, name)| {
-                name.to_lowercase().contains(&input.to_lowercase())
-                    || name.to_lowercase() == input.to_lowercase()
-            });
+    #[tokio::test]
+    async fn test_track_ended_logs_listening_time_for_goals() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
 
-            if let Some((buffer_id, buffer_name)) = matching_buffer {
-                if self.buffer_manager.switch_to_buffer(buffer_id).is_err() {
-                    self.show_error(format!("Could not switch to buffer: {}", buffer_name));
-                } else {
-                    self.update_status_bar();
-                    self.show_message(format!("Switched to buffer: {}", buffer_name));
-                }
-            } else {
-                // Treat as a command
-                let _ = self.execute_command_direct(input.to_string());
-            }
-        }
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Test Episode".to_string(),
+            "http://example.com/ep1.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.duration = Some(1800);
+        storage.save_podcast(&podcast).await.unwrap();
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
 
-        // Only clear the minibuffer if execute_command_direct didn't set up a new prompt.
-        // Commands like clean-older-than and delete-all-downloads set the minibuffer to
-        // a confirmation prompt, which would be wiped out by an unconditional clear().
-        if !self.minibuffer.is_input_mode() {
-            self.minibuffer.clear();
-        }
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id: podcast.id.clone(),
+            episode_id: episode.id.clone(),
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        let records = app
+            .listening_log
+            .since(chrono::Utc::now() - chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].episode_id, episode.id);
+        assert_eq!(records[0].seconds, 1800);
     }
 
-    /// Handle key events when minibuffer is in input mode
-    async fn handle_minibuffer_key(
-        &mut self,
-        key_event: crossterm::event::KeyEvent,
-    ) -> UIResult<bool> {
-        use crossterm::event::{KeyCode, KeyModifiers};
+    #[tokio::test]
+    async fn test_quitting_saves_playback_position_and_stops_audio() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
 
-        match (key_event.code, key_event.modifiers) {
-            // Submit input on Enter
-            (KeyCode::Enter, _) => {
-                // Get the prompt BEFORE submit() clears it
-                let prompt = self.minibuffer.current_prompt();
-                if let Some(input) = self.minibuffer.submit() {
-                    self.handle_minibuffer_input_with_context(input, prompt);
-                }
-                Ok(true)
-            }
-            // Tab completion
-            (KeyCode::Tab, _) => {
-                self.minibuffer.tab_complete();
-                Ok(true)
-            }
-            // Cancel on Ctrl+G or Escape (also clear any pending operations)
-            (KeyCode::Esc, _) | (KeyCode::Char('g'), KeyModifiers::CONTROL) => {
-                self.minibuffer.clear();
-                self.pending_deletion = None;
-                self.pending_playlist_deletion = None;
-                self.pending_bulk_deletion = false;
-                self.pending_cleanup_hours = None;
-                Ok(true)
-            }
-            // Backspace
-            (KeyCode::Backspace, _) => {
-                self.minibuffer.backspace();
+        // Arrange — an episode is 10 minutes in
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Test Episode".to_string(),
+            "http://example.com/ep1.mp3".to_string(),
+            chrono::Utc::now() - chrono::Duration::days(2),
+        );
+        episode.duration = Some(1800);
+        storage.save_podcast(&podcast).await.unwrap();
+        storage.save_episode(&podcast.id, &episode).await.unwrap();
+        let (_status_tx, status_rx) = tokio::sync::watch::channel(PlaybackStatus {
+            state: PlaybackState::Playing,
+            episode_id: Some(episode.id.clone()),
+            podcast_id: Some(podcast.id.clone()),
+            position: Some(std::time::Duration::from_secs(600)),
+            ..PlaybackStatus::default()
+        });
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
 
-                // Update command completion dynamically if in command prompt mode
-                if self.minibuffer.is_command_prompt() {
-                    if let Some(current_input) = self.minibuffer.current_input() {
-                        let commands = self.get_contextual_command_completions(&current_input);
-                        self.minibuffer.set_completion_candidates(commands);
+        // Act
+        let note = app.shutdown(&mut terminal, Some(&status_rx)).await;
 
-                        // If this is a buffer-related command, update to buffer name completion mode
-                        self.update_completion_mode_for_input(&current_input);
-                    }
-                }
+        // Assert
+        assert!(note.is_none());
+        let saved = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap();
+        assert_eq!(saved.last_played_position, Some(600));
+        assert!(matches!(audio_rx.try_recv(), Ok(AudioCommand::Stop)));
+        assert!(app.audio_command_tx.is_none());
+    }
 
-                Ok(true)
-            }
-            // Cursor movement
-            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
-                self.minibuffer.cursor_left();
-                Ok(true)
-            }
-            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => {
-                self.minibuffer.cursor_right();
-                Ok(true)
-            }
-            // History navigation
-            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
-                self.minibuffer.history_up();
-                Ok(true)
-            }
-            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
-                self.minibuffer.history_down();
-                Ok(true)
-            }
-            // Regular character input
-            (KeyCode::Char(c), KeyModifiers::NONE) | (KeyCode::Char(c), KeyModifiers::SHIFT) => {
-                self.minibuffer.add_char(c);
+    #[tokio::test]
+    async fn test_prefetch_waits_for_idle_and_runs_once_per_episode() {
+        // Arrange
+        let mut config = Config::default();
+        config.downloads.prefetch_next = 2;
+        let (mut app, _storage) = make_test_app_with_config(config).await;
+        let ids = (
+            crate::storage::PodcastId::new(),
+            crate::storage::EpisodeId::new(),
+        );
+        app.handle_app_event(AppEvent::PlaybackStarted {
+            podcast_id: ids.0.clone(),
+            episode_id: ids.1.clone(),
+        })
+        .await
+        .unwrap();
+        // Settle the buffer reloads started so far
+        app.background_tasks.shutdown(Duration::from_secs(5)).await;
 
-                // Update command completion dynamically if in command prompt mode
-                if self.minibuffer.is_command_prompt() {
-                    if let Some(current_input) = self.minibuffer.current_input() {
-                        let commands = self.get_contextual_command_completions(&current_input);
-                        self.minibuffer.set_completion_candidates(commands);
+        // Act — a key was just pressed
+        app.last_input = Instant::now();
+        app.handle_tick().await.unwrap();
 
-                        // If this is a buffer-related command, update to buffer name completion mode
-                        self.update_completion_mode_for_input(&current_input);
-                    }
-                }
+        // Assert
+        assert_eq!(app.prefetch_after, Some(ids));
 
-                Ok(true)
-            }
-            // Ignore other keys in input mode
-            _ => Ok(true),
-        }
-    }
+        // Act — idle long enough
+        app.last_input = Instant::now() - crate::constants::downloads::PREFETCH_IDLE_AFTER;
+        app.handle_tick().await.unwrap();
 
-    /// Render the UI
-    fn render(&mut self, frame: &mut Frame) {
-        let size = frame.area();
+        // Assert
+        assert!(app.prefetch_after.is_none());
+        assert_eq!(app.background_tasks.running(), 1);
+    }
 
-        // Create layout: main area + minibuffer + status bar
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Min(0),    // Main content area
-                Constraint::Length(2), // Minibuffer (1 for border + 1 for text)
-                Constraint::Length(1), // Status bar
-            ])
-            .split(size);
+    #[tokio::test]
+    async fn test_handle_app_event_track_ended_marks_played_when_no_duration() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
+        use chrono::Utc;
 
-        // Render main content area
-        self.render_main_content(frame, chunks[0]);
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
 
-        // Render minibuffer
-        self.minibuffer.render(frame, chunks[1]);
+        let mut podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        let episode = Episode::new(
+            podcast.id.clone(),
+            "Test Episode No Duration".to_string(),
+            "http://example.com/ep2.mp3".to_string(),
+            Utc::now() - chrono::Duration::days(2),
+        );
+        // No duration set — episode.duration is None
+        podcast.episodes.push(episode.id.clone());
+        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
+        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
 
-        // Render status bar
-        self.status_bar.render(frame, chunks[2]);
-    }
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id: podcast.id.clone(),
+            episode_id: episode.id.clone(),
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-    /// Render the main content area
-    fn render_main_content(&mut self, frame: &mut Frame, area: Rect) {
-        if let Some(current_buffer) = self.buffer_manager.current_buffer_mut() {
-            current_buffer.render(frame, area);
-        } else {
-            // No buffer selected, show empty area
-            let block = Block::default()
-                .borders(Borders::ALL)
-                .title("No Buffer")
-                .style(self.theme.default_style());
-            frame.render_widget(block, area);
-        }
+        // Assert — mark_played() is called when no duration is available
+        let saved = storage
+            .load_episode(&podcast.id, &episode.id)
+            .await
+            .unwrap(); // unwrap OK — episode was saved above
+        assert!(
+            saved.is_played(),
+            "Episode with no duration should still be marked played"
+        );
+        assert_eq!(saved.play_count, 1);
     }
-}
 
-/// Parse `:smart-playlist` command arguments into a `SmartPlaylistRule`.
-///
-/// Supported flags:
-/// - `--filter <spec>` — one or more; multiple filters are AND-ed
-/// - `--sort <field>`  — `date-asc` | `date-desc` | `title-asc` | `title-desc`
-/// - `--limit <n>`     — maximum episodes to return
-///
-/// Filter specs: `downloaded`, `favorited`, `played`, `unplayed`,
-///               `tag:<name>`, `podcast:<id>`, `newer-than:<days>`
-pub(crate) fn parse_smart_playlist_args(
-    args: &[&str],
-) -> Result<crate::playlist::models::SmartPlaylistRule, String> {
-    use crate::playlist::models::{SmartFilter, SmartPlaylistRule, SmartSort};
+    #[tokio::test]
+    async fn test_track_ended_plays_next_downloaded_queue_entry() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
+        use chrono::Utc;
 
-    let mut filters: Vec<SmartFilter> = Vec::new();
-    let mut sort: Option<SmartSort> = None;
-    let mut limit: Option<usize> = None;
+        // Arrange — three queued episodes, the middle one not downloaded
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::TempDir::new().unwrap();
 
-    let mut i = 0;
-    while i < args.len() {
-        match args[i] {
-            "--filter" => {
-                i += 1;
-                if i >= args.len() {
-                    return Err("--filter requires a value".to_string());
-                }
-                let spec = args[i];
-                let filter = parse_filter_spec(spec)?;
-                filters.push(filter);
-            }
-            "--sort" => {
-                i += 1;
-                if i >= args.len() {
-                    return Err("--sort requires a value".to_string());
-                }
-                sort = Some(parse_sort_spec(args[i])?);
-            }
-            "--limit" => {
-                i += 1;
-                if i >= args.len() {
-                    return Err("--limit requires a value".to_string());
-                }
-                limit =
-                    Some(args[i].parse::<usize>().map_err(|_| {
-                        format!("--limit value must be a number, got '{}'", args[i])
-                    })?);
-            }
-            other => {
-                return Err(format!(
-                    "Unknown argument '{}'. Use --filter, --sort, or --limit",
-                    other
-                ));
-            }
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
+        let mut entries = Vec::new();
+        for (i, downloaded) in [true, false, true].into_iter().enumerate() {
+            let mut episode = Episode::new(
+                podcast.id.clone(),
+                format!("Episode {}", i),
+                format!("http://example.com/ep{}.mp3", i),
+                Utc::now(),
+            );
+            if downloaded {
+                let path = audio_dir.path().join(format!("ep{}.mp3", i));
+                std::fs::write(&path, b"audio").unwrap(); // unwrap OK — test setup
+                episode.status.download = DownloadState::Downloaded;
+                episode.local_path = Some(path);
+            }
+            storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
+            entries.push(QueueEntry {
+                podcast_id: podcast.id.clone(),
+                episode_id: episode.id.clone(),
+            });
         }
-        i += 1;
-    }
+        app.start_playlist_playback(PlaylistId::new(), entries.clone(), 0)
+            .await;
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { ref episode_id, .. }) if *episode_id == entries[0].episode_id
+        ));
 
-    let filter = match filters.len() {
-        0 => SmartFilter::Downloaded, // sensible default
-        1 => filters.remove(0),
-        _ => SmartFilter::And(filters),
-    };
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id: podcast.id.clone(),
+            episode_id: entries[0].episode_id.clone(),
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-    Ok(SmartPlaylistRule {
-        filter,
-        sort,
-        limit,
-    })
-}
+        // Assert — the undownloaded episode is skipped
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { ref episode_id, .. }) if *episode_id == entries[2].episode_id
+        ));
+    }
 
-fn parse_filter_spec(spec: &str) -> Result<crate::playlist::models::SmartFilter, String> {
-    use crate::playlist::models::SmartFilter;
-    match spec {
-        "downloaded" => Ok(SmartFilter::Downloaded),
-        "favorited" => Ok(SmartFilter::Favorited),
-        "played" => Ok(SmartFilter::Played),
-        "unplayed" => Ok(SmartFilter::Unplayed),
-        other => {
-            if let Some(tag) = other.strip_prefix("tag:") {
-                Ok(SmartFilter::Tag(tag.to_string()))
-            } else if let Some(id) = other.strip_prefix("podcast:") {
-                Ok(SmartFilter::Podcast(id.to_string()))
-            } else if let Some(days_str) = other.strip_prefix("newer-than:") {
-                let days = days_str.parse::<u32>().map_err(|_| {
-                    format!(
-                        "newer-than value must be a number of days, got '{}'",
-                        days_str
-                    )
-                })?;
-                Ok(SmartFilter::NewerThan(days))
-            } else {
-                Err(format!(
-                    "Unknown filter '{}'. Valid: downloaded, favorited, played, unplayed, tag:<name>, podcast:<id>, newer-than:<days>",
-                    other
-                ))
-            }
+    /// A podcast with auto-play on and two downloaded episodes, the first of
+    /// which has just ended
+    async fn auto_play_fixture(
+        storage: &crate::storage::JsonStorage,
+        audio_dir: &std::path::Path,
+    ) -> (
+        crate::storage::PodcastId,
+        crate::storage::EpisodeId,
+        crate::storage::EpisodeId,
+    ) {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
+        use chrono::Utc;
+
+        let mut podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
+        );
+        podcast.auto_play_next = Some(true);
+        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
+        let mut ids = Vec::new();
+        for day in [2, 1] {
+            let mut episode = Episode::new(
+                podcast.id.clone(),
+                format!("Day {}", day),
+                format!("http://example.com/{}.mp3", day),
+                Utc::now() - chrono::Duration::days(day),
+            );
+            let path = audio_dir.join(format!("{}.mp3", day));
+            std::fs::write(&path, b"audio").unwrap(); // unwrap OK — test setup
+            episode.status.download = DownloadState::Downloaded;
+            episode.local_path = Some(path);
+            storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
+            ids.push(episode.id);
         }
+        let next = ids.pop().unwrap();
+        (podcast.id, ids.pop().unwrap(), next)
     }
-}
 
-fn parse_sort_spec(spec: &str) -> Result<crate::playlist::models::SmartSort, String> {
-    use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
-    match spec {
-        "date-desc" => Ok(SmartSort {
-            field: SmartSortField::Date,
-            direction: SmartSortDirection::Descending,
-        }),
-        "date-asc" => Ok(SmartSort {
-            field: SmartSortField::Date,
-            direction: SmartSortDirection::Ascending,
-        }),
-        "title-asc" => Ok(SmartSort {
-            field: SmartSortField::Title,
-            direction: SmartSortDirection::Ascending,
-        }),
-        "title-desc" => Ok(SmartSort {
-            field: SmartSortField::Title,
-            direction: SmartSortDirection::Descending,
-        }),
-        "duration-asc" => Ok(SmartSort {
-            field: SmartSortField::Duration,
-            direction: SmartSortDirection::Ascending,
-        }),
-        "duration-desc" => Ok(SmartSort {
-            field: SmartSortField::Duration,
-            direction: SmartSortDirection::Descending,
-        }),
-        other => Err(format!(
-            "Unknown sort '{}'. Valid: date-desc, date-asc, title-asc, title-desc, duration-asc, duration-desc",
-            other
-        )),
+    #[tokio::test]
+    async fn test_bookmark_saves_the_playing_position() {
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let audio_dir = tempfile::tempdir().unwrap();
+        let (podcast_id, episode_id, _) = auto_play_fixture(&storage, audio_dir.path()).await;
+        app.execute_command_direct("bookmark".to_string()).unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Nothing is playing to bookmark"));
+        let (_status_tx, status_rx) = tokio::sync::watch::channel(PlaybackStatus {
+            state: PlaybackState::Playing,
+            podcast_id: Some(podcast_id.clone()),
+            episode_id: Some(episode_id.clone()),
+            position: Some(std::time::Duration::from_secs(754)),
+            ..PlaybackStatus::default()
+        });
+        app.buffer_manager.set_now_playing_status_rx(status_rx);
+
+        // Act
+        app.execute_command_direct("bookmark Great quote".to_string())
+            .unwrap();
+        app.background_tasks
+            .shutdown(std::time::Duration::from_secs(5))
+            .await;
+
+        // Assert
+        let saved = storage
+            .load_episode(&podcast_id, &episode_id)
+            .await
+            .unwrap();
+        assert_eq!(saved.bookmarks.len(), 1);
+        assert_eq!(saved.bookmarks[0].position, 754);
+        assert_eq!(saved.bookmarks[0].label, "Great quote");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{config::UiConfig, download::DownloadManager};
+    #[tokio::test]
+    async fn test_stopping_saves_position_and_playing_again_resumes() {
+        // Arrange — the first episode is 12:34 in
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::tempdir().unwrap();
+        let (podcast_id, episode_id, other_id) =
+            auto_play_fixture(&storage, audio_dir.path()).await;
+        let (_status_tx, status_rx) = tokio::sync::watch::channel(PlaybackStatus {
+            state: PlaybackState::Playing,
+            podcast_id: Some(podcast_id.clone()),
+            episode_id: Some(episode_id.clone()),
+            position: Some(std::time::Duration::from_secs(754)),
+            ..PlaybackStatus::default()
+        });
+        app.buffer_manager.set_now_playing_status_rx(status_rx);
+
+        // Act
+        app.handle_action(UIAction::StopPlayback).await.unwrap();
+        let path = audio_dir.path().join("replay.mp3");
+        app.apply_buffer_result(UIAction::PlayEpisode {
+            podcast_id: podcast_id.clone(),
+            episode_id: episode_id.clone(),
+            path: path.clone(),
+        })
+        .await;
+        app.apply_buffer_result(UIAction::PlayEpisode {
+            podcast_id,
+            episode_id: other_id,
+            path,
+        })
+        .await;
+
+        // Assert — the episode picks up where it stopped; a fresh one starts over
+        assert!(matches!(audio_rx.try_recv(), Ok(AudioCommand::Stop)));
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { episode_id: ref played, start_at: Some(at), .. })
+                if *played == episode_id && at == std::time::Duration::from_secs(754)
+        ));
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { start_at: None, .. })
+        ));
+    }
 
     #[tokio::test]
-    async fn test_ui_app_creation() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_track_ended_auto_plays_next_episode_after_countdown() {
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::TempDir::new().unwrap();
+        let (podcast_id, ended, next) = auto_play_fixture(&storage, audio_dir.path()).await;
 
-        let config = Config {
-            ui: UiConfig::default(),
-            ..Default::default()
-        };
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id,
+            episode_id: ended,
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        // Assert — nothing plays until the countdown runs out
+        assert!(audio_rx.try_recv().is_err());
+        assert_eq!(
+            app.status_bar.status_message(),
+            "Next: Day 1 in 5s (C-g cancels)"
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
+        app.auto_play.as_mut().unwrap().deadline = Instant::now();
+        app.tick_auto_play();
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { ref episode_id, .. }) if *episode_id == next
         ));
+        assert!(app.auto_play.is_none());
+    }
 
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager.clone(),
-            storage.clone(),
-            app_event_tx,
+    #[tokio::test]
+    async fn test_f_searches_for_people_credited_in_detail_views() {
+        use crate::podcast::{Episode, Person, Podcast};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let person = |name: &str, role: &str| Person {
+            name: name.to_string(),
+            role: role.to_string(),
+            href: None,
+            img: None,
+        };
+        // Arrange — an episode with one guest
+        let mut app = make_test_app().await;
+        let podcast = Podcast::new("Talks".to_string(), "https://example.com/feed".to_string());
+        let mut episode = Episode::new(
+            podcast.id.clone(),
+            "Episode 40".to_string(),
+            "https://example.com/40.mp3".to_string(),
+            chrono::Utc::now(),
         );
-        assert!(app.is_ok());
+        episode.people = vec![person("Ada Lovelace", "guest")];
+        app.buffer_manager
+            .create_episode_detail_buffer(episode.clone());
+        let _ = app
+            .buffer_manager
+            .switch_to_buffer(&format!("episode-detail-{}", episode.id));
 
-        let app = app.unwrap();
-        assert!(!app.should_quit);
-        assert_eq!(app.frame_count, 0);
+        // Act
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+
+        // Assert — the search runs straight away
+        assert_eq!(
+            app.buffer_manager
+                .get_search_buffer_mut()
+                .map(|search| search.query().to_string())
+                .as_deref(),
+            Some("Ada Lovelace")
+        );
+
+        // Arrange — a podcast crediting a host and a guest
+        let mut podcast = podcast;
+        podcast.people = vec![person("Jane Host", "host"), person("Ada Lovelace", "guest")];
+        let detail_id = app
+            .buffer_manager
+            .create_podcast_detail_buffer(podcast, None);
+        let _ = app.buffer_manager.switch_to_buffer(&detail_id);
+
+        // Act
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+
+        // Assert — several people: pick one, guests first
+        assert_eq!(
+            app.minibuffer.current_prompt().as_deref(),
+            Some(FIND_PERSON_PROMPT)
+        );
+        assert_eq!(
+            app.minibuffer.get_completion_candidates(),
+            ["Ada Lovelace".to_string(), "Jane Host".to_string()]
+        );
     }
 
     #[tokio::test]
-    async fn test_quit_action() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_episode_search_follows_typed_query() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        let config = Config::default();
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let audio_dir = tempfile::TempDir::new().unwrap();
+        auto_play_fixture(&storage, audio_dir.path()).await;
+        app.execute_command_direct("search-all".to_string())
+            .unwrap();
+        for c in "da".chars() {
+            app.handle_minibuffer_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await
+                .unwrap();
+        }
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
+        // Act — the index arrives after typing started
+        let podcasts = app.subscription_manager.list_subscriptions().await.unwrap();
+        let index = crate::search::SearchIndex::build(storage.as_ref(), &podcasts).await;
+        app.handle_app_event(AppEvent::SearchIndexBuilt {
+            index: Arc::new(index),
+        })
+        .await
+        .unwrap();
+
+        // Assert — the query typed so far runs, newest first
+        let titles = |app: &mut UIApp| -> Vec<String> {
+            app.buffer_manager
+                .get_search_buffer_mut()
+                .unwrap()
+                .hits()
+                .iter()
+                .map(|hit| hit.episode_title.clone())
+                .collect()
+        };
+        assert_eq!(titles(&mut app), vec!["Day 1", "Day 2"]);
+
+        // Act — keep typing, then submit
+        for c in "y 2".chars() {
+            app.handle_minibuffer_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await
+                .unwrap();
+        }
+        app.handle_minibuffer_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(titles(&mut app), vec!["Day 2"]);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("1 episode(s) match \"day 2\""));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_key_stops_a_pending_auto_play() {
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::TempDir::new().unwrap();
+        let (podcast_id, ended, _) = auto_play_fixture(&storage, audio_dir.path()).await;
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id,
+            episode_id: ended,
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
+
+        // Act
+        app.handle_action(UIAction::CancelImport).await.unwrap();
+
+        // Assert
+        assert!(app.auto_play.is_none());
+        assert!(app.status_bar.status_message().is_empty());
+        app.tick_auto_play();
+        assert!(audio_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_track_ended_plays_queued_episode_before_auto_play() {
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::TempDir::new().unwrap();
+        let (podcast_id, ended, next) = auto_play_fixture(&storage, audio_dir.path()).await;
+        app.enqueue_episode(podcast_id.clone(), next.clone()).await;
 
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
+        // Act
+        app.handle_app_event(AppEvent::TrackEnded {
+            podcast_id,
+            episode_id: ended,
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-        let result = app.handle_action(UIAction::Quit).await;
-        assert!(result.is_ok());
-        assert!(!result.unwrap()); // Should return false to indicate stopping
-        assert!(app.should_quit);
+        // Assert — played straight away, without a countdown
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { ref episode_id, .. }) if *episode_id == next
+        ));
+        assert!(app.auto_play.is_none());
+        assert!(app.queue.is_empty());
     }
 
     #[tokio::test]
-    async fn test_show_help_action() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_queue_is_restored_at_startup_and_skips_undownloaded_entries() {
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        let audio_dir = tempfile::TempDir::new().unwrap();
+        let (podcast_id, first, second) = auto_play_fixture(&storage, audio_dir.path()).await;
+        app.enqueue_episode(podcast_id.clone(), first.clone()).await;
+        app.enqueue_episode(podcast_id.clone(), second.clone())
+            .await;
+        app.queue.save().await.unwrap(); // unwrap OK — test setup
+        let first_episode = storage.load_episode(&podcast_id, &first).await.unwrap();
+        std::fs::remove_file(first_episode.local_path.unwrap()).unwrap(); // unwrap OK — test setup
 
-        let config = Config::default();
+        // Act
+        app.queue = QueueManager::new(&storage.data_dir);
+        app.load_queue().await;
+        assert_eq!(app.queue.len(), 2);
+        let played = app.play_next_queued().await;
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
+        // Assert
+        assert!(played);
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::Play { ref episode_id, .. }) if *episode_id == second
         ));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        assert!(app.queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shuffle_and_repeat_commands_update_open_playlist() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let playlist = app
+            .playlist_manager
+            .create_playlist("Commute", None)
+            .await
+            .unwrap(); // unwrap OK — test setup
+        let detail_id = "playlist-commute".to_string();
+        let playlist_manager = app.playlist_manager.clone();
+        app.buffer_manager.create_playlist_detail_buffer(
+            playlist.id.clone(),
+            playlist.name.clone(),
+            playlist.playlist_type.clone(),
+            playlist_manager,
         );
+        app.buffer_manager.switch_to_buffer(&detail_id).unwrap();
 
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
+        // Act — one save in flight at a time
+        app.execute_command_direct("repeat one".to_string())
+            .unwrap();
+        wait_for_saved_modes(&app, &playlist.id, RepeatMode::One, false).await;
+        app.execute_command_direct("shuffle".to_string()).unwrap();
 
-        let result = app.handle_action(UIAction::ShowHelp).await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        // Assert
+        let modes = app
+            .buffer_manager
+            .get_playlist_detail_buffer_mut_by_id(&detail_id)
+            .unwrap()
+            .playback_modes();
+        assert!(modes.shuffle);
+        assert_eq!(modes.repeat, RepeatMode::One);
+        wait_for_saved_modes(&app, &playlist.id, RepeatMode::One, true).await;
+    }
 
-        // Should have switched to help buffer
+    #[tokio::test]
+    async fn test_feed_candidates_open_picker_that_subscribes_to_choice() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let candidates = vec![
+            crate::podcast::FeedLink {
+                url: "https://example.com/feed/mp3".to_string(),
+                title: Some("The Show (MP3)".to_string()),
+            },
+            crate::podcast::FeedLink {
+                url: "https://example.com/feed/aac".to_string(),
+                title: None,
+            },
+        ];
+
+        // Act
+        app.handle_app_event(AppEvent::FeedCandidatesFound {
+            page_url: "https://example.com/the-show".to_string(),
+            candidates,
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
+
+        // Assert — the picker is focused and Enter subscribes to the first feed
+        let buffer_id = "discovery-feeds-example.com".to_string();
         assert_eq!(
-            app.buffer_manager.current_buffer_name().unwrap(),
-            "*Help: Keybindings*"
+            app.buffer_manager.current_buffer_id(),
+            Some(buffer_id.clone())
+        );
+        let picker = app
+            .buffer_manager
+            .get_discovery_buffer_mut_by_id(&buffer_id)
+            .unwrap();
+        assert_eq!(
+            picker.handle_action(UIAction::SelectItem),
+            UIAction::SubscribeFromDiscovery {
+                feed_url: "https://example.com/feed/mp3".to_string(),
+            }
         );
     }
 
+    /// Poll until the background save of a playlist's modes has landed
+    async fn wait_for_saved_modes(
+        app: &UIApp,
+        playlist_id: &PlaylistId,
+        repeat: RepeatMode,
+        shuffle: bool,
+    ) {
+        let expected = PlaybackModes { shuffle, repeat };
+        for _ in 0..50 {
+            let saved = app.playlist_manager.get_playlist(playlist_id).await;
+            if saved.is_ok_and(|saved| saved.playback == expected) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("playback modes were not persisted");
+    }
+
     #[tokio::test]
-    async fn test_show_help_reopens_after_close() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_handle_app_event_playback_started_sets_now_playing_info() {
+        use crate::podcast::{Episode, Podcast};
+        use crate::storage::Storage;
+        use chrono::Utc;
 
-        let config = Config::default();
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        let mut podcast = Podcast::new(
+            "My Podcast".to_string(),
+            "http://example.com/feed.xml".to_string(),
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
-
-        // Open help buffer
-        let result = app.handle_action(UIAction::ShowHelp).await;
-        assert!(result.is_ok());
-        assert_eq!(
-            app.buffer_manager.current_buffer_name().unwrap(),
-            "*Help: Keybindings*"
+        let episode = Episode::new(
+            podcast.id.clone(),
+            "My Episode".to_string(),
+            "http://example.com/ep1.mp3".to_string(),
+            Utc::now(),
         );
+        podcast.episodes.push(episode.id.clone());
+        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
+        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
 
-        // Close the help buffer
-        let help_id = app.buffer_manager.current_buffer_id().unwrap();
-        let _ = app.buffer_manager.remove_buffer(&help_id);
+        // Act
+        app.handle_app_event(AppEvent::PlaybackStarted {
+            podcast_id: podcast.id.clone(),
+            episode_id: episode.id.clone(),
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
 
-        // Verify help buffer is gone
-        let has_help = app
+        // Assert — NowPlaying buffer should display the episode and podcast names
+        let buf = app
             .buffer_manager
-            .buffer_names()
-            .iter()
-            .any(|(_, name)| name == "*Help: Keybindings*");
-        assert!(!has_help, "Help buffer should be removed");
+            .get_now_playing_buffer_mut()
+            .expect("NowPlaying buffer should exist after initialize()");
+        assert_eq!(buf.episode_title(), Some("My Episode"));
+        assert_eq!(buf.podcast_name(), Some("My Podcast"));
+    }
 
-        // Reopen help buffer via ShowHelp action
-        let result = app.handle_action(UIAction::ShowHelp).await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+    #[tokio::test]
+    async fn test_handle_app_event_playback_error_shows_error_message() {
+        // Arrange
+        let mut app = make_test_app().await;
 
-        // Should have recreated and switched to help buffer
-        assert_eq!(
-            app.buffer_manager.current_buffer_name().unwrap(),
-            "*Help: Keybindings*"
+        // Act
+        app.handle_app_event(AppEvent::PlaybackError {
+            error: "device unavailable".to_string(),
+        })
+        .await
+        .unwrap(); // unwrap OK — testing success path
+
+        // Assert
+        assert!(
+            app.minibuffer.is_visible(),
+            "Minibuffer should show error after PlaybackError"
+        );
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should be in message/error display mode, not input mode"
         );
     }
 
     #[tokio::test]
-    async fn test_command_execution() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
-
-        let config = Config::default();
+    async fn test_share_podcast_shows_qr_code_until_a_key_is_pressed() {
+        use crate::podcast::Podcast;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        // Arrange
+        let mut app = make_test_app().await;
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            buf.set_podcasts(vec![podcast]);
+        }
+        let _ = app
+            .buffer_manager
+            .switch_to_buffer(&"podcast-list".to_string());
 
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
+        // Act
+        app.execute_command_direct("share-podcast".to_string())
+            .unwrap();
+
+        // Assert
+        assert!(app.share_popup.is_some());
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+        )))
+        .await
         .unwrap();
-        app.initialize().await.unwrap();
+        assert!(app.share_popup.is_none());
+
+        // The filtered form shares what the list shows
+        app.execute_command_direct("share-podcast filtered".to_string())
+            .unwrap();
+        assert!(app.share_popup.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_tag_command_routes_to_podcast_list_not_active_buffer() {
+        use crate::podcast::Podcast;
 
-        // Test quit command
-        let result = app.execute_command_direct("quit".to_string());
-        assert!(result.is_ok());
-        assert!(!result.unwrap());
-        assert!(app.should_quit);
+        // Arrange — seed podcast list buffer, then make episode list the active buffer
+        let mut app = make_test_app().await;
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        let podcast_id = podcast.id.clone();
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            buf.set_podcasts(vec![podcast]);
+        }
+        app.buffer_manager.create_episode_list_buffer(
+            "Test Podcast".to_string(),
+            podcast_id.clone(),
+            app.subscription_manager.clone(),
+            app.download_manager.clone(),
+        );
+        // Switch to an episode list so podcast-list is NOT the active buffer
+        let _ = app
+            .buffer_manager
+            .switch_to_buffer(&format!("episodes-{}", podcast_id));
 
-        // Reset for next test
-        app.should_quit = false;
+        // Act
+        let result = app.execute_command_direct("tag tech".to_string());
 
-        // Test help command
-        let result = app.execute_command_direct("help".to_string());
+        // Assert — command succeeded and tag was added to the podcast in the podcast list buffer
         assert!(result.is_ok());
-        assert!(result.unwrap());
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            let tagged = buf
+                .podcasts()
+                .iter()
+                .any(|p| p.id == podcast_id && p.has_tag("tech"));
+            assert!(
+                tagged,
+                "Expected podcast to have tag 'tech' after :tag command"
+            );
+        }
     }
 
     #[tokio::test]
-    async fn test_theme_setting() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
-
-        let config = Config::default();
+    async fn test_untag_command_routes_to_podcast_list_not_active_buffer() {
+        use crate::podcast::Podcast;
 
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        // Arrange — seed podcast list with a tagged podcast, make episode list active
+        let mut app = make_test_app().await;
+        let mut podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
+        podcast.add_tag("tech");
+        let podcast_id = podcast.id.clone();
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            buf.set_podcasts(vec![podcast]);
+        }
+        app.buffer_manager.create_episode_list_buffer(
+            "Test Podcast".to_string(),
+            podcast_id.clone(),
+            app.subscription_manager.clone(),
+            app.download_manager.clone(),
+        );
+        // Switch to episode list so podcast-list is NOT the active buffer
+        let _ = app
+            .buffer_manager
+            .switch_to_buffer(&format!("episodes-{}", podcast_id));
 
-        let result = app.set_theme_direct("light");
-        assert!(result.is_ok());
-        assert!(result.unwrap());
-        // Verify theme propagated to app
-        assert_eq!(app.theme.name, "Light");
+        // Act
+        let result = app.execute_command_direct("untag tech".to_string());
 
-        let result = app.set_theme_direct("invalid-theme");
+        // Assert — tag was removed from the podcast in the podcast list buffer
         assert!(result.is_ok());
-        assert!(result.unwrap());
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            let still_tagged = buf
+                .podcasts()
+                .iter()
+                .any(|p| p.id == podcast_id && p.has_tag("tech"));
+            assert!(
+                !still_tagged,
+                "Expected 'tech' tag to be removed after :untag command"
+            );
+        }
     }
 
     #[tokio::test]
-    async fn test_add_to_playlist_opens_picker_from_supported_buffers() {
-        use crate::config::DownloadConfig;
-        use crate::podcast::Episode;
-        use crate::storage::JsonStorage;
-        use chrono::Utc;
-        use tempfile::TempDir;
+    async fn test_filter_tag_routes_to_podcast_list_and_switches_buffer() {
+        use crate::podcast::Podcast;
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
+        // Arrange — 2 podcasts: one tagged "tech", one untagged; episode list is active
+        let mut app = make_test_app().await;
+        let mut tagged_podcast = Podcast::new(
+            "Tech Podcast".to_string(),
+            "https://example.com/tech.xml".to_string(),
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
-
-        app.playlist_manager
-            .create_playlist("Issue55 Playlist", None)
-            .await
-            .unwrap();
+        tagged_podcast.add_tag("tech");
+        let tagged_id = tagged_podcast.id.clone();
 
-        let podcast_id = crate::storage::PodcastId::new();
-        let episode = Episode::new(
-            podcast_id.clone(),
-            "Issue55 Episode".to_string(),
-            "https://example.com/issue55.mp3".to_string(),
-            Utc::now(),
+        let other_podcast = Podcast::new(
+            "News Podcast".to_string(),
+            "https://example.com/news.xml".to_string(),
         );
-
-        let episode_buffer_id = "episodes-issue55-podcast".to_string();
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            buf.set_podcasts(vec![tagged_podcast, other_podcast]);
+        }
         app.buffer_manager.create_episode_list_buffer(
-            "Issue55 Podcast".to_string(),
-            podcast_id.clone(),
+            "Tech Podcast".to_string(),
+            tagged_id.clone(),
             app.subscription_manager.clone(),
             app.download_manager.clone(),
         );
-        if let Some(episode_buffer) = app
+        // Switch to episode list so podcast-list is NOT the active buffer
+        let _ = app
             .buffer_manager
-            .get_episode_list_buffer_mut_by_id(&episode_buffer_id)
-        {
-            episode_buffer.set_episodes(vec![episode.clone()]);
-        }
-        app.buffer_manager
-            .switch_to_buffer(&episode_buffer_id)
-            .unwrap();
-        let result = app.handle_action(UIAction::AddToPlaylist).await;
-        assert!(result.is_ok());
-        assert_eq!(
+            .switch_to_buffer(&format!("episodes-{}", tagged_id));
+        assert_ne!(
             app.buffer_manager.current_buffer_id(),
-            Some("playlist-picker".to_string())
+            Some("podcast-list".to_string()),
+            "Precondition: episode list should be active, not podcast list"
         );
 
-        if let Some(whats_new_buffer) = app.buffer_manager.get_whats_new_buffer_mut() {
-            whats_new_buffer.set_episodes(vec![crate::ui::events::AggregatedEpisode {
-                podcast_id: podcast_id.clone(),
-                podcast_title: "Issue55 Podcast".to_string(),
-                episode: episode.clone(),
-            }]);
-        }
-        app.buffer_manager
-            .switch_to_buffer(&"whats-new".to_string())
-            .unwrap();
-        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        // Act
+        let result = app.execute_command_direct("filter-tag tech".to_string());
+
+        // Assert — command succeeded AND we switched to podcast-list
         assert!(result.is_ok());
         assert_eq!(
             app.buffer_manager.current_buffer_id(),
-            Some("playlist-picker".to_string())
+            Some("podcast-list".to_string()),
+            "Expected :filter-tag to switch to podcast list buffer"
         );
+        // Only the tagged podcast should be visible after filter
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            // selected_podcast() returns Some only if a filtered result exists
+            let selected = buf.selected_podcast().map(|p| p.id.clone());
+            assert_eq!(
+                selected,
+                Some(tagged_id),
+                "Expected the tech-tagged podcast to be selected after filter"
+            );
+            // The other (untagged) podcast should not be visible — verify it has the tag
+            let selected_has_tag = buf
+                .selected_podcast()
+                .map(|p| p.has_tag("tech"))
+                .unwrap_or(false);
+            assert!(
+                selected_has_tag,
+                "Selected podcast should have the 'tech' tag after :filter-tag tech"
+            );
+        }
+    }
 
-        let episode_detail_id = format!("episode-detail-{}", episode.id);
-        app.buffer_manager
-            .create_episode_detail_buffer(episode.clone());
-        app.buffer_manager
-            .switch_to_buffer(&episode_detail_id)
-            .unwrap();
-        let result = app.handle_action(UIAction::AddToPlaylist).await;
+    #[tokio::test]
+    async fn test_tag_command_shows_error_when_no_podcast_selected() {
+        // Arrange — empty podcast list (no selection possible)
+        let mut app = make_test_app().await;
+        // podcast list buffer is created by UIApp::new() but is empty by default
+
+        // Act
+        let result = app.execute_command_direct("tag tech".to_string());
+
+        // Assert — returns Ok but shows error in minibuffer (no silent failure)
         assert!(result.is_ok());
-        assert_eq!(
-            app.buffer_manager.current_buffer_id(),
-            Some("playlist-picker".to_string())
+        assert!(
+            app.minibuffer.is_visible(),
+            "Expected error message in minibuffer when no podcast is selected"
+        );
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should be in error display mode, not input mode"
         );
     }
 
     #[tokio::test]
-    async fn test_add_to_playlist_in_unsupported_buffer_shows_info_message() {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_untag_command_shows_error_when_no_podcast_selected() {
+        // Arrange — empty podcast list (no selection possible)
+        let mut app = make_test_app().await;
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
+        // Act
+        let result = app.execute_command_direct("untag tech".to_string());
 
-        app.buffer_manager
-            .switch_to_buffer(&"podcast-list".to_string())
-            .unwrap();
-        let result = app.handle_action(UIAction::AddToPlaylist).await;
+        // Assert — returns Ok but shows error in minibuffer (no silent failure)
         assert!(result.is_ok());
-        assert_eq!(
-            app.buffer_manager.current_buffer_id(),
-            Some("podcast-list".to_string())
+        assert!(
+            app.minibuffer.is_visible(),
+            "Expected error message in minibuffer when no podcast is selected for :untag"
         );
-        assert_ne!(
-            app.buffer_manager.current_buffer_id(),
-            Some("playlist-picker".to_string())
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should be in error display mode, not input mode"
         );
-        assert!(app.minibuffer.is_visible());
     }
 
-    #[tokio::test]
-    async fn test_playlist_detail_select_item_opens_episode_detail() {
-        use crate::config::DownloadConfig;
-        use crate::playlist::{Playlist, PlaylistEpisode, PlaylistId, PlaylistType};
-        use crate::podcast::{Episode, Podcast};
-        use crate::storage::JsonStorage;
-        use chrono::Utc;
-        use tempfile::TempDir;
+    // ── Issue #171: PlayEpisode default keybinding / TogglePlayPause feedback ──
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage.clone(),
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
+    #[tokio::test]
+    async fn test_toggle_play_pause_shows_error_when_audio_none() {
+        // Arrange — audio_command_tx is None (audio init failed / not available)
+        let mut app = make_test_app().await;
+        // Do NOT call set_audio_command_tx — leave audio_command_tx as None
 
-        let mut podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "https://example.com/feed.xml".to_string(),
-        );
-        let podcast_id = podcast.id.clone();
-        storage.save_podcast(&podcast).await.unwrap();
+        // Act
+        let result = app.handle_action(UIAction::TogglePlayPause).await;
 
-        let episode = Episode::new(
-            podcast_id.clone(),
-            "Episode 1".to_string(),
-            "https://example.com/ep1.mp3".to_string(),
-            Utc::now(),
+        // Assert — returns Ok(true) and shows an error in the minibuffer
+        assert!(result.is_ok());
+        assert!(
+            result.unwrap(),
+            "handle_action should return true (continue running)"
         );
-        let episode_id = episode.id.clone();
-        podcast.add_episode(episode_id.clone());
-        storage.save_episode(&podcast_id, &episode).await.unwrap();
-        storage.save_podcast(&podcast).await.unwrap();
-
-        let playlist_id = PlaylistId::new();
-        let playlist_name = "Test Playlist".to_string();
-        let detail_id = format!(
-            "playlist-{}",
-            playlist_name.replace(' ', "-").to_lowercase()
+        assert!(
+            app.minibuffer.is_visible(),
+            "Minibuffer should show error when audio is unavailable"
         );
-        let playlist_manager = app.playlist_manager.clone();
-        app.buffer_manager.create_playlist_detail_buffer(
-            playlist_id.clone(),
-            playlist_name.clone(),
-            PlaylistType::User,
-            playlist_manager,
+        assert!(
+            !app.minibuffer.is_input_mode(),
+            "Minibuffer should be in error display mode, not input mode"
         );
-        if let Some(detail_buffer) = app
-            .buffer_manager
-            .get_playlist_detail_buffer_mut_by_id(&detail_id)
-        {
-            detail_buffer.set_playlist(Playlist {
-                id: playlist_id,
-                name: playlist_name,
-                description: None,
-                playlist_type: PlaylistType::User,
-                episodes: vec![PlaylistEpisode {
-                    podcast_id: podcast_id.clone(),
-                    episode_id: episode_id.clone(),
-                    episode_title: Some("Episode 1".to_string()),
-                    added_at: Utc::now(),
-                    order: 1,
-                    file_synced: false,
-                    filename: None,
-                }],
-                created: Utc::now(),
-                last_updated: Utc::now(),
-                smart_rules: None,
-            });
-        }
-        app.buffer_manager.switch_to_buffer(&detail_id).unwrap();
-
-        let result = app.handle_action(UIAction::SelectItem).await;
-        assert!(result.is_ok());
-        assert_eq!(
-            app.buffer_manager.current_buffer_id(),
-            Some(format!("episode-detail-{}", episode_id))
+        // Verify the exact error message so we catch silent regressions if the
+        // string changes (e.g. a different branch fires instead)
+        let text = app.minibuffer.text_content();
+        assert!(
+            text.contains(crate::constants::audio::UNAVAILABLE_ERROR),
+            "Minibuffer should contain the audio-unavailable error, got: {text:?}"
         );
     }
 
     #[tokio::test]
-    async fn test_playlist_detail_select_item_with_missing_episode_shows_error() {
-        use crate::config::DownloadConfig;
-        use crate::playlist::{Playlist, PlaylistEpisode, PlaylistId, PlaylistType};
-        use crate::podcast::Podcast;
-        use crate::storage::JsonStorage;
-        use chrono::Utc;
-        use tempfile::TempDir;
+    async fn test_glyph_legend_opens_and_any_key_dismisses() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_dir.path().to_path_buf(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage.clone(),
-            app_event_tx,
-        )
-        .unwrap();
-        app.initialize().await.unwrap();
+        // Arrange
+        let mut app = make_test_app().await;
 
-        let podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "https://example.com/feed.xml".to_string(),
-        );
-        let podcast_id = podcast.id.clone();
-        storage.save_podcast(&podcast).await.unwrap();
-        let missing_episode_id = crate::storage::EpisodeId::new();
+        // Act
+        let result = app.handle_action(UIAction::ShowGlyphLegend).await;
 
-        let playlist_id = PlaylistId::new();
-        let playlist_name = "Broken Playlist".to_string();
-        let detail_id = format!(
-            "playlist-{}",
-            playlist_name.replace(' ', "-").to_lowercase()
-        );
-        let playlist_manager = app.playlist_manager.clone();
-        app.buffer_manager.create_playlist_detail_buffer(
-            playlist_id.clone(),
-            playlist_name.clone(),
-            PlaylistType::User,
-            playlist_manager,
-        );
-        if let Some(detail_buffer) = app
-            .buffer_manager
-            .get_playlist_detail_buffer_mut_by_id(&detail_id)
-        {
-            detail_buffer.set_playlist(Playlist {
-                id: playlist_id,
-                name: playlist_name,
-                description: None,
-                playlist_type: PlaylistType::User,
-                episodes: vec![PlaylistEpisode {
-                    podcast_id,
-                    episode_id: missing_episode_id,
-                    episode_title: Some("Missing".to_string()),
-                    added_at: Utc::now(),
-                    order: 1,
-                    file_synced: false,
-                    filename: None,
-                }],
-                created: Utc::now(),
-                last_updated: Utc::now(),
-                smart_rules: None,
-            });
-        }
-        app.buffer_manager.switch_to_buffer(&detail_id).unwrap();
+        // Assert
+        assert!(result.unwrap());
+        assert!(app.show_glyph_legend);
+
+        // Act — the next key only closes the popup
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        let result = app.handle_event(UIEvent::Key(key)).await;
+
+        // Assert
+        assert!(result.unwrap());
+        assert!(!app.show_glyph_legend);
+        assert!(!app.should_quit, "Dismissing key must not be dispatched");
+    }
 
-        let result = app.handle_action(UIAction::SelectItem).await;
-        assert!(result.is_ok());
-        assert_eq!(app.buffer_manager.current_buffer_id(), Some(detail_id));
-        assert!(app.minibuffer.is_visible());
+    #[tokio::test]
+    async fn test_ascii_glyphs_config_survives_theme_change() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.config.ui.ascii_glyphs = true;
+
+        // Act
+        app.set_theme_direct("light").unwrap();
+
+        // Assert
+        assert_eq!(app.theme.glyphs, GlyphSet::Ascii);
     }
 
-    /// Helper: build a minimal UIApp with the sync buffer registered and active.
-    async fn make_app_with_sync_buffer() -> UIApp {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    #[tokio::test]
+    async fn test_color_blind_mode_survives_theme_change() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.config.ui.color_blind_mode = true;
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let temp_path = temp_dir.keep();
+        // Act
+        app.set_theme_direct("deuteranopia").unwrap();
 
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_path.clone(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager.clone(),
-            storage,
-            app_event_tx,
-        )
+        // Assert
+        assert_eq!(app.theme.name, "Deuteranopia");
+        assert!(app.theme.color_blind_mode);
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_imports_reimports_the_failed_feeds() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.execute_command_direct("retry-failed-imports".to_string())
+            .unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("No failed OPML imports to retry"));
+        let mut result = crate::podcast::ImportResult::new(3);
+        result.imported = 2;
+        result.failed.push(crate::podcast::FailedImport {
+            url: "https://example.com/gone.xml".to_string(),
+            title: Some("Gone".to_string()),
+            error: "HTTP 404".to_string(),
+        });
+        result.failed_opml_path = Some("/tmp/opml-import-failed.opml".to_string());
+
+        // Act
+        app.handle_app_event(AppEvent::OpmlImportCompleted {
+            result,
+            log_path: "/tmp/opml-import.log".to_string(),
+        })
+        .await
         .unwrap();
-        app.initialize().await.unwrap();
 
-        // Switch to the sync buffer (now created by initialize())
-        app.buffer_manager
-            .switch_to_buffer(&"sync".to_string())
+        // Assert
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("1 failed (M-i retries them)"));
+
+        // Act
+        app.execute_command_direct("retry-failed-imports".to_string())
             .unwrap();
 
-        app
+        // Assert — an import of just the failed feeds is running
+        assert!(app.opml_import.is_some());
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("/tmp/opml-import-failed.opml"));
     }
 
     #[tokio::test]
-    async fn test_prompt_input_from_sync_buffer_opens_minibuffer() {
+    async fn test_cancel_import_sets_flag_on_running_import() {
         // Arrange
-        let mut app = make_app_with_sync_buffer().await;
+        let mut app = make_test_app().await;
+        let options = crate::podcast::OpmlImportOptions::default();
+        app.opml_import = Some(options.clone());
 
-        // Act — SyncToDevice falls through to catch-all → sync buffer returns
-        // PromptInput → new PromptInput arm opens the minibuffer
-        let result = app.handle_action(UIAction::SyncToDevice).await;
+        // Act
+        let result = app.handle_action(UIAction::CancelImport).await;
 
         // Assert
-        assert!(result.is_ok());
-        assert!(
-            app.minibuffer.is_input_mode(),
-            "Minibuffer should be in input mode after SyncToDevice"
-        );
-        assert!(
-            app.minibuffer
-                .current_prompt()
-                .map(|p| p.starts_with("Sync to device path"))
-                .unwrap_or(false),
-            "Prompt should start with 'Sync to device path'"
-        );
+        assert!(result.unwrap());
+        assert!(options.is_cancelled());
+        assert!(app.minibuffer.text_content().contains("Cancelling"));
     }
 
     #[tokio::test]
-    async fn test_download_episode_in_sync_buffer_opens_dry_run_prompt() {
-        // Arrange — 'D' maps to DownloadEpisode; sync buffer context guard intercepts it
-        let mut app = make_app_with_sync_buffer().await;
+    async fn test_cancel_import_without_running_import() {
+        // Arrange
+        let mut app = make_test_app().await;
 
         // Act
-        let result = app.handle_action(UIAction::DownloadEpisode).await;
+        let result = app.execute_command_direct("cancel-import".to_string());
 
-        // Assert — should open dry-run prompt, not download-episode flow
-        assert!(result.is_ok());
-        assert!(
-            app.minibuffer.is_input_mode(),
-            "Minibuffer should be in input mode after 'D' in sync buffer"
-        );
-        assert!(
-            app.minibuffer
-                .current_prompt()
-                .map(|p| p.starts_with("Dry run sync to"))
-                .unwrap_or(false),
-            "Prompt should start with 'Dry run sync to'"
-        );
+        // Assert
+        assert!(result.unwrap());
+        assert!(app.minibuffer.text_content().contains("No OPML import"));
     }
 
     #[tokio::test]
-    async fn test_delete_podcast_in_sync_buffer_shows_nothing_to_delete() {
-        // Arrange — 'd' now consistently means delete; sync buffer has nothing to delete
-        let mut app = make_app_with_sync_buffer().await;
+    async fn test_import_progress_shows_live_counts() {
+        // Arrange
+        let mut app = make_test_app().await;
 
         // Act
-        let result = app.handle_action(UIAction::DeletePodcast).await;
+        app.handle_app_event(AppEvent::OpmlImportProgress {
+            done: 10,
+            failed: 2,
+            total: 300,
+            status: "✓ Imported [12/300]: Show".to_string(),
+        })
+        .await
+        .unwrap();
 
-        // Assert — should show info message, not open a dry-run prompt
-        assert!(result.is_ok());
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should NOT enter input mode for 'd' in sync buffer"
-        );
-        // Verify show_message was actually called (not a silent no-op)
-        assert!(
-            app.minibuffer.is_visible(),
-            "Minibuffer should be visible (showing 'nothing to delete' message)"
-        );
+        // Assert
+        let text = app.minibuffer.text_content();
+        assert!(text.contains("10 done, 2 failed, 288 remaining"), "{text}");
     }
 
     #[tokio::test]
-    async fn test_refresh_podcast_in_sync_buffer_is_noop() {
-        // Arrange — 'r' maps to RefreshPodcast; sync buffer context guard short-circuits it
-        let mut app = make_app_with_sync_buffer().await;
+    async fn test_keyword_alerts_raised_once_per_episode_and_cleared() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let podcast = crate::podcast::Podcast::new(
+            "Local Radio".to_string(),
+            "https://example.com/feed".to_string(),
+        );
+        let mut episode = crate::podcast::Episode::new(
+            podcast.id.clone(),
+            "Road works on Main Street".to_string(),
+            "https://example.com/1.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.description = Some("Live from Springfield".to_string());
+        let (keywords, _) = KeywordAlerts::compile(&["springfield".to_string()]);
+        let alert = keyword_alert(&keywords, &podcast, &episode).expect("keyword matches");
 
         // Act
-        let result = app.handle_action(UIAction::RefreshPodcast).await;
+        app.execute_command_direct("alerts".to_string()).unwrap();
+        app.raise_keyword_alerts(vec![alert.clone()]);
+        app.raise_keyword_alerts(vec![alert]);
 
-        // Assert — should return cleanly without touching the podcast refresh flow
-        assert!(result.is_ok());
-        assert!(result.unwrap(), "Should return true (continue running)");
-        // Minibuffer should not have been opened for a prompt
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should NOT enter input mode for 'r' in sync buffer"
+        // Assert
+        assert_eq!(app.alerts.len(), 1);
+        assert_eq!(
+            app.buffer_manager.active_buffer_id().map(String::as_str),
+            Some("alerts")
         );
-        // Buffer should still be sync
+        let buffer = app.buffer_manager.get_alerts_buffer_mut().unwrap();
         assert_eq!(
-            app.buffer_manager.current_buffer_id(),
-            Some("sync".to_string())
+            buffer.selected_alert().map(|a| a.keyword.as_str()),
+            Some("springfield")
         );
+
+        app.execute_command_direct("alerts-clear".to_string())
+            .unwrap();
+        assert!(app.alerts.is_empty());
+        assert!(app
+            .buffer_manager
+            .get_alerts_buffer_mut()
+            .unwrap()
+            .selected_alert()
+            .is_none());
     }
 
     #[tokio::test]
-    async fn test_initialize_creates_sync_buffer() {
-        // Arrange — build UIApp via new() which leaves BufferManager empty,
-        // then call initialize() (the real startup path from App::run)
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+    async fn test_simple_mode_hides_advanced_buffers_and_commands() {
+        // Arrange
+        let mut config = Config::default();
+        config.ui.simple_mode = true;
+        let (mut app, _storage) = make_test_app_with_config(config).await;
+        let active = app.buffer_manager.active_buffer_id().cloned();
+        let f7 = crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::F(7),
+            crossterm::event::KeyModifiers::NONE,
+        );
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let temp_path = temp_dir.keep();
+        // Act
+        app.execute_command_direct("playlists".to_string()).unwrap();
+        app.handle_event(UIEvent::Key(f7)).await.unwrap();
 
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
-        let download_manager = Arc::new(
-            DownloadManager::new(storage.clone(), temp_path, DownloadConfig::default()).unwrap(),
+        // Assert
+        let ids = app.buffer_manager.get_buffer_ids();
+        assert!(!ids.iter().any(|id| id == "sync" || id == "playlist-list"));
+        assert_eq!(app.buffer_manager.active_buffer_id().cloned(), active);
+        let commands = app.get_available_commands();
+        assert!(commands.contains(&"add-podcast".to_string()));
+        assert!(!commands.contains(&"delete-all-downloads".to_string()));
+        assert!(app
+            .simple_mode_hints()
+            .contains(&("a".to_string(), "Subscribe")));
+    }
+
+    #[tokio::test]
+    async fn test_unplayed_command_opens_closable_buffer() {
+        // Arrange
+        let mut app = make_test_app().await;
+
+        // Act
+        let result = app.execute_command_direct("unplayed".to_string());
+
+        // Assert
+        assert!(result.unwrap());
+        assert_eq!(
+            app.buffer_manager.active_buffer_id().map(String::as_str),
+            Some("unplayed")
         );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
+        assert!(app.add_to_playlist_supported_in_buffer("unplayed"));
+
+        // Running it again reuses the same buffer
+        app.execute_command_direct("unplayed".to_string()).unwrap();
+        let count = app
+            .buffer_manager
+            .get_buffer_ids()
+            .iter()
+            .filter(|id| *id == "unplayed")
+            .count();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_select_audio_device_command_requests_device_list() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+
+        // Act
+        let result = app.execute_command_direct("select-audio-device".to_string());
+
+        // Assert
+        assert!(result.unwrap());
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::ListOutputDevices)
         ));
-        let (app_event_tx, _rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage,
-            app_event_tx,
-        )
+    }
+
+    #[tokio::test]
+    async fn test_audio_diagnostics_command_opens_probe_results() {
+        // Arrange — no audio output, as after a failed probe
+        let mut app = make_test_app().await;
+        let diagnostics = crate::audio::probe::AudioDiagnostics {
+            reason: "no output device".to_string(),
+            ..Default::default()
+        };
+        app.handle_app_event(AppEvent::AudioStartupWarning {
+            message: diagnostics.startup_warning().unwrap(),
+        })
+        .await
         .unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("No audio output: no output device"));
+        app.set_audio_diagnostics(diagnostics);
 
         // Act
-        app.initialize().await.unwrap();
+        let result = app.execute_command_direct("audio-diagnostics".to_string());
 
-        // Assert — sync buffer must be registered and switchable
-        let buffer_ids = app.buffer_manager.get_buffer_ids();
-        assert!(
-            buffer_ids.contains(&"sync".to_string()),
-            "initialize() must create the sync buffer; got: {:?}",
-            buffer_ids
+        // Assert
+        assert!(result.unwrap());
+        assert_eq!(
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some("audio-diagnostics")
         );
-        assert!(
-            app.buffer_manager
-                .switch_to_buffer(&"sync".to_string())
-                .is_ok(),
-            "Should be able to switch to sync buffer after initialize()"
+    }
+
+    #[tokio::test]
+    async fn test_podcast_settings_view_opens_on_comma_and_shows_saved_settings() {
+        use crate::podcast::{Podcast, PodcastSettings};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        // Arrange
+        let mut app = make_test_app().await;
+        let podcast = Podcast::new(
+            "Test Podcast".to_string(),
+            "https://example.com/feed.xml".to_string(),
+        );
+        let podcast_id = podcast.id.clone();
+        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
+            buf.set_podcasts(vec![podcast]);
+        }
+        let _ = app
+            .buffer_manager
+            .switch_to_buffer(&"podcast-list".to_string());
+
+        // Act
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char(','),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        let settings = PodcastSettings {
+            playback_speed: Some(1.5),
+            ..PodcastSettings::default()
+        };
+        app.handle_app_event(AppEvent::PodcastSettingsSaved {
+            podcast_id,
+            podcast_title: "Test Podcast".to_string(),
+            settings: settings.clone(),
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some("podcast-settings")
         );
+        let view = app
+            .buffer_manager
+            .get_podcast_settings_buffer_mut()
+            .unwrap();
+        assert_eq!(*view.settings(), settings);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Saved settings for 'Test Podcast'"));
     }
 
-    // ── parse_smart_playlist_args / parse_filter_spec / parse_sort_spec ────
-
-    #[test]
-    fn test_parse_smart_playlist_args_empty_defaults_to_downloaded() {
+    #[tokio::test]
+    async fn test_auto_downloads_queued_starts_a_download_per_episode() {
         // Arrange
-        let args: &[&str] = &[];
+        let mut app = make_test_app().await;
+        app.background_tasks.shutdown(Duration::from_secs(5)).await;
+        let podcast_id = crate::storage::PodcastId::new();
+        let episodes = vec![
+            (podcast_id.clone(), crate::storage::EpisodeId::new()),
+            (podcast_id, crate::storage::EpisodeId::new()),
+        ];
+
         // Act
-        let rule = parse_smart_playlist_args(args).unwrap();
-        // Assert
-        use crate::playlist::models::SmartFilter;
-        assert_eq!(rule.filter, SmartFilter::Downloaded);
-        assert!(rule.sort.is_none());
-        assert!(rule.limit.is_none());
-    }
+        app.handle_app_event(AppEvent::AutoDownloadsQueued { episodes })
+            .await
+            .unwrap();
 
-    #[test]
-    fn test_parse_smart_playlist_args_single_filter_used_directly() {
-        // Arrange / Act
-        let rule = parse_smart_playlist_args(&["--filter", "favorited"]).unwrap();
         // Assert
-        use crate::playlist::models::SmartFilter;
-        assert_eq!(rule.filter, SmartFilter::Favorited);
+        assert_eq!(app.background_tasks.running(), 2);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Auto-downloading 2 new episode(s)"));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_multiple_filters_become_and() {
-        // Arrange / Act
-        let rule = parse_smart_playlist_args(&["--filter", "downloaded", "--filter", "favorited"])
+    #[tokio::test]
+    async fn test_config_report_command_lists_startup_problems() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.execute_command_direct("config-report".to_string())
             .unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("No problems found in the config file"));
+        let (_, issues) = Config::from_json_checked(r#"{ "audio": { "volum": 0.5 } }"#).unwrap();
+        app.set_config_issues(issues);
+
+        // Act
+        let result = app.execute_command_direct("config-report".to_string());
+
         // Assert
-        use crate::playlist::models::SmartFilter;
+        assert!(result.unwrap());
         assert_eq!(
-            rule.filter,
-            SmartFilter::And(vec![SmartFilter::Downloaded, SmartFilter::Favorited])
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some("config-report")
         );
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("1 problem(s) in the config file"));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_sort_date_desc() {
-        use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
-        let rule = parse_smart_playlist_args(&["--sort", "date-desc"]).unwrap();
+    #[tokio::test]
+    async fn test_audio_device_picker_selection_closes_picker_and_switches() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
+        app.set_audio_command_tx(audio_tx);
+        app.handle_app_event(AppEvent::AudioDevicesListed {
+            devices: vec!["Speakers".to_string(), "USB Headset".to_string()],
+            current: None,
+        })
+        .await
+        .unwrap();
         assert_eq!(
-            rule.sort,
-            Some(SmartSort {
-                field: SmartSortField::Date,
-                direction: SmartSortDirection::Descending,
-            })
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some("audio-device-picker")
         );
-    }
 
-    #[test]
-    fn test_parse_smart_playlist_args_sort_duration_asc() {
-        use crate::playlist::models::{SmartSort, SmartSortDirection, SmartSortField};
-        let rule = parse_smart_playlist_args(&["--sort", "duration-asc"]).unwrap();
-        assert_eq!(
-            rule.sort,
-            Some(SmartSort {
-                field: SmartSortField::Duration,
-                direction: SmartSortDirection::Ascending,
-            })
-        );
-    }
+        // Act — move from "System default" to "Speakers" and confirm
+        app.handle_action(UIAction::MoveDown).await.unwrap();
+        app.handle_action(UIAction::SelectItem).await.unwrap();
 
-    #[test]
-    fn test_parse_smart_playlist_args_limit_valid() {
-        // Arrange / Act
-        let rule = parse_smart_playlist_args(&["--limit", "25"]).unwrap();
         // Assert
-        assert_eq!(rule.limit, Some(25));
+        assert!(matches!(
+            audio_rx.try_recv(),
+            Ok(AudioCommand::SelectOutputDevice(Some(ref d))) if d == "Speakers"
+        ));
+        assert!(!app
+            .buffer_manager
+            .get_buffer_ids()
+            .contains(&"audio-device-picker".to_string()));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_limit_invalid_returns_error() {
-        // Arrange / Act
-        let err = parse_smart_playlist_args(&["--limit", "abc"]).unwrap_err();
+    #[tokio::test]
+    async fn test_audio_device_lost_notifies_instead_of_erroring() {
+        // Arrange
+        let mut app = make_test_app().await;
+
+        // Act
+        app.handle_app_event(AppEvent::AudioDeviceLost {
+            device: Some("USB Headset".to_string()),
+            error: None,
+        })
+        .await
+        .unwrap();
+
         // Assert
-        assert!(
-            err.contains("number"),
-            "Error should mention 'number': {err}"
-        );
+        let text = app.minibuffer.text_content();
+        assert!(text.contains("USB Headset disconnected"));
+        assert!(text.contains("system default"));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_unknown_flag_returns_error() {
-        let err = parse_smart_playlist_args(&["--unknown", "foo"]).unwrap_err();
-        assert!(
-            err.contains("Unknown argument"),
-            "Error should say 'Unknown argument': {err}"
-        );
-    }
+    #[tokio::test]
+    async fn test_backend_without_devices_does_not_open_picker() {
+        // Arrange
+        let mut app = make_test_app().await;
 
-    #[test]
-    fn test_parse_smart_playlist_args_filter_tag() {
-        use crate::playlist::models::SmartFilter;
-        let rule = parse_smart_playlist_args(&["--filter", "tag:rust"]).unwrap();
-        assert_eq!(rule.filter, SmartFilter::Tag("rust".to_string()));
-    }
+        // Act
+        app.handle_app_event(AppEvent::AudioDevicesListed {
+            devices: Vec::new(),
+            current: None,
+        })
+        .await
+        .unwrap();
 
-    #[test]
-    fn test_parse_smart_playlist_args_filter_newer_than_valid() {
-        use crate::playlist::models::SmartFilter;
-        let rule = parse_smart_playlist_args(&["--filter", "newer-than:7"]).unwrap();
-        assert_eq!(rule.filter, SmartFilter::NewerThan(7));
+        // Assert
+        assert!(!app
+            .buffer_manager
+            .get_buffer_ids()
+            .contains(&"audio-device-picker".to_string()));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_filter_newer_than_invalid() {
-        let err = parse_smart_playlist_args(&["--filter", "newer-than:notanumber"]).unwrap_err();
-        assert!(
-            err.contains("number of days"),
-            "Error should mention 'number of days': {err}"
-        );
+    #[tokio::test]
+    async fn test_low_battery_pauses_io_and_ac_resumes() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.config.downloads.pause_on_battery = true;
+        app.config.downloads.battery_pause_threshold = 30;
+
+        // Act — drop below the threshold
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Battery { percent: Some(20) },
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        assert!(app.download_manager.io_gate().is_paused());
+        assert!(app.status_bar.indicator().contains("battery 20%"));
+        assert!(app.minibuffer.text_content().contains("paused"));
+
+        // Act — plug in
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Ac,
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        assert!(!app.download_manager.io_gate().is_paused());
+        assert!(app.minibuffer.text_content().contains("resumed"));
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_filter_unknown_returns_error() {
-        let err = parse_smart_playlist_args(&["--filter", "bogus"]).unwrap_err();
-        assert!(
-            err.contains("Unknown filter"),
-            "Error should say 'Unknown filter': {err}"
-        );
+    #[tokio::test]
+    async fn test_battery_pause_ignored_when_disabled() {
+        let mut app = make_test_app().await;
+        app.config.downloads.pause_on_battery = false;
+
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Battery { percent: Some(5) },
+        })
+        .await
+        .unwrap();
+
+        assert!(!app.download_manager.io_gate().is_paused());
     }
 
-    #[test]
-    fn test_parse_smart_playlist_args_all_sort_variants() {
-        for spec in &[
-            "date-asc",
-            "date-desc",
-            "title-asc",
-            "title-desc",
-            "duration-asc",
-            "duration-desc",
-        ] {
-            let result = parse_smart_playlist_args(&["--sort", spec]);
-            assert!(
-                result.is_ok(),
-                "Sort spec '{spec}' should parse successfully"
-            );
+    #[tokio::test]
+    async fn test_resume_downloads_overrides_battery_pause_until_ac() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.config.downloads.pause_on_battery = true;
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Battery { percent: Some(10) },
+        })
+        .await
+        .unwrap();
+
+        // Act
+        app.execute_command_direct("resume-downloads".to_string())
+            .unwrap();
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Battery { percent: Some(9) },
+        })
+        .await
+        .unwrap();
+
+        // Assert — still running despite the lower charge
+        assert!(!app.download_manager.io_gate().is_paused());
+
+        // Act — back on AC clears the override, so the next drop pauses again
+        for source in [PowerSource::Ac, PowerSource::Battery { percent: Some(8) }] {
+            app.handle_app_event(AppEvent::PowerSourceChanged { source })
+                .await
+                .unwrap();
         }
-    }
 
-    #[test]
-    fn test_parse_smart_playlist_args_sort_unknown_returns_error() {
-        let err = parse_smart_playlist_args(&["--sort", "random"]).unwrap_err();
-        assert!(
-            err.contains("Unknown sort"),
-            "Error should say 'Unknown sort': {err}"
-        );
+        // Assert
+        assert!(app.download_manager.io_gate().is_paused());
     }
 
-    // ── AudioManager wiring tests (#141) ─────────────────────────────────────
+    #[tokio::test]
+    async fn test_pause_downloads_holds_io_on_ac() {
+        // Arrange
+        let mut app = make_test_app().await;
+        app.handle_app_event(AppEvent::PowerSourceChanged {
+            source: PowerSource::Ac,
+        })
+        .await
+        .unwrap();
 
-    /// Creates a UIApp backed by real storage (initialized).
-    /// Returns both the app and the storage so tests can pre-populate data.
-    async fn make_test_app_with_storage() -> (UIApp, Arc<crate::storage::JsonStorage>) {
-        use crate::config::DownloadConfig;
-        use crate::storage::JsonStorage;
-        use tempfile::TempDir;
+        // Act
+        app.execute_command_direct("pause-downloads".to_string())
+            .unwrap();
 
-        let config = Config::default();
-        let temp_dir = TempDir::new().unwrap();
-        let temp_path = temp_dir.keep();
+        // Assert
+        assert!(app.download_manager.io_gate().is_paused());
+        assert_eq!(app.status_bar.indicator(), "⏸ I/O paused");
 
-        let storage = Arc::new(JsonStorage::with_data_dir(temp_path.clone()));
-        storage.initialize().await.unwrap();
+        app.execute_command_direct("resume-downloads".to_string())
+            .unwrap();
+        assert!(!app.download_manager.io_gate().is_paused());
+        assert!(app.status_bar.indicator().is_empty());
+    }
 
-        let download_manager = Arc::new(
-            DownloadManager::new(
-                storage.clone(),
-                temp_path.clone(),
-                DownloadConfig::default(),
-            )
-            .unwrap(),
-        );
-        let subscription_manager = Arc::new(SubscriptionManager::with_download_manager(
-            storage.clone(),
-            download_manager.clone(),
-        ));
+    #[tokio::test]
+    async fn test_alt_w_switches_workspaces_named_in_the_status_bar() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
-        let mut app = UIApp::new(
-            config,
-            subscription_manager,
-            download_manager,
-            storage.clone(),
-            app_event_tx,
-        )
+        // Arrange
+        let mut app = make_test_app().await;
+        app.execute_command_direct("workspace news".to_string())
+            .unwrap();
+        app.execute_command_direct("buffer downloads".to_string())
+            .unwrap();
+
+        // Act
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('w'),
+            KeyModifiers::ALT,
+        )))
+        .await
         .unwrap();
-        app.initialize().await.unwrap();
 
-        (app, storage)
-    }
+        // Assert — the current workspace is offered last
+        let prompt = app.minibuffer.current_prompt();
+        assert_eq!(prompt.as_deref(), Some(WORKSPACE_PROMPT));
+        assert_eq!(
+            app.minibuffer.get_completion_candidates(),
+            ["main".to_string(), "news".to_string()]
+        );
+        app.handle_minibuffer_input_with_context("main".to_string(), prompt);
+        assert_eq!(app.buffer_manager.current_workspace_name(), "main");
+        assert!(app.status_bar.buffer_name().starts_with("[main] "));
 
-    async fn make_test_app() -> UIApp {
-        make_test_app_with_storage().await.0
+        app.execute_command_direct("workspace-close news".to_string())
+            .unwrap();
+        assert_eq!(app.buffer_manager.workspaces().len(), 1);
+        assert!(!app.status_bar.buffer_name().starts_with('['));
     }
 
     #[tokio::test]
-    async fn test_handle_action_toggle_play_pause_sends_command_when_audio_available() {
+    async fn test_focus_mode_hides_messages_but_not_errors_until_it_ends() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
         // Arrange
         let mut app = make_test_app().await;
-        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
-        app.set_audio_command_tx(audio_tx);
+        app.execute_command_direct("pause-downloads".to_string())
+            .unwrap();
 
         // Act
-        let result = app.handle_action(UIAction::TogglePlayPause).await;
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::F(12),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
 
-        // Assert
-        assert!(result.is_ok());
-        let cmd = audio_rx
-            .try_recv()
-            .expect("Expected AudioCommand to be sent");
-        assert!(
-            matches!(cmd, AudioCommand::TogglePlayPause),
-            "Expected TogglePlayPause, got {cmd:?}"
-        );
+        // Assert — the indicator sits beside the I/O pause
+        assert!(app.focus_active());
+        assert_eq!(app.status_bar.indicator(), "⏸ I/O paused · Focus 60m");
+        app.show_message("Refreshed 3 podcasts".to_string());
+        assert!(app
+            .minibuffer
+            .text_content()
+            .starts_with("Focus mode for 60 min"));
+        app.show_error("Feed unreachable".to_string());
+        assert_eq!(app.minibuffer.text_content(), "Error: Feed unreachable");
+
+        // Running out ends it like `:focus off` would
+        app.focus_until = Some(Instant::now());
+        app.handle_tick().await.unwrap();
+        assert!(app.focus_until.is_none());
+        assert_eq!(app.status_bar.indicator(), "⏸ I/O paused");
+        assert_eq!(app.minibuffer.text_content(), "Focus mode ended");
+
+        app.execute_command_direct("focus 0".to_string()).unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .starts_with("Error: Usage: focus"));
     }
 
     #[tokio::test]
-    async fn test_handle_action_toggle_play_pause_blocked_in_minibuffer_input_mode() {
+    async fn test_podcast_detail_loaded_opens_timeline_buffer() {
         // Arrange
         let mut app = make_test_app().await;
-        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
-        app.set_audio_command_tx(audio_tx);
-        app.minibuffer.show_prompt("test: ".to_string());
-        assert!(app.minibuffer.is_input_mode());
+        let mut podcast = crate::podcast::Podcast::new(
+            "Timeline Show".to_string(),
+            "https://example.com/timeline.xml".to_string(),
+        );
+        podcast.record_event(crate::podcast::PodcastEventKind::Subscribed);
+        let expected_id = format!("podcast-detail-{}", podcast.id);
 
         // Act
-        let result = app.handle_action(UIAction::TogglePlayPause).await;
+        app.handle_app_event(AppEvent::PodcastDetailLoaded {
+            podcast,
+            stats: None,
+        })
+        .await
+        .unwrap();
 
-        // Assert — no command should be dispatched while minibuffer is in input mode
-        assert!(result.is_ok());
-        assert!(
-            audio_rx.try_recv().is_err(),
-            "No AudioCommand should be sent when minibuffer is in input mode"
+        // Assert
+        assert_eq!(
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some(expected_id.as_str())
         );
     }
 
     #[tokio::test]
-    async fn test_handle_action_stop_playback_sends_stop_command() {
+    async fn test_podcast_info_without_podcast_context_shows_error() {
         // Arrange
         let mut app = make_test_app().await;
-        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
-        app.set_audio_command_tx(audio_tx);
+        app.buffer_manager
+            .add_buffer(Box::new(
+                crate::ui::buffers::audio_device_picker::AudioDevicePickerBuffer::new(
+                    Vec::new(),
+                    None,
+                ),
+            ))
+            .unwrap();
+        app.buffer_manager
+            .switch_to_buffer(&"audio-device-picker".to_string())
+            .unwrap();
 
         // Act
-        let result = app.handle_action(UIAction::StopPlayback).await;
+        let handled = app
+            .execute_command_direct("podcast-info".to_string())
+            .unwrap();
 
         // Assert
-        assert!(result.is_ok());
-        let cmd = audio_rx.try_recv().expect("Expected AudioCommand::Stop");
-        assert!(
-            matches!(cmd, AudioCommand::Stop),
-            "Expected Stop, got {cmd:?}"
-        );
+        assert!(handled);
+        assert!(app.minibuffer.text_content().contains("Select a podcast"));
     }
 
     #[tokio::test]
-    async fn test_handle_action_volume_up_sends_volume_up_command() {
+    async fn test_hard_refresh_rejects_unknown_scope() {
+        let mut app = make_test_app().await;
+
+        let handled = app
+            .execute_command_direct("hard-refresh everything all".to_string())
+            .unwrap();
+
+        assert!(handled);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Unknown hard refresh scope 'everything'"));
+    }
+
+    #[tokio::test]
+    async fn test_hard_refresh_all_reports_progress_and_failures() {
         // Arrange
         let mut app = make_test_app().await;
-        let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<AudioCommand>();
-        app.set_audio_command_tx(audio_tx);
 
-        // Act
-        let result = app.handle_action(UIAction::VolumeUp).await;
+        // Act — progress
+        app.handle_app_event(AppEvent::HardRefreshAllProgress {
+            done: 3,
+            total: 10,
+            podcast_title: "Show".to_string(),
+        })
+        .await
+        .unwrap();
 
         // Assert
-        assert!(result.is_ok());
-        let cmd = audio_rx
-            .try_recv()
-            .expect("Expected AudioCommand::VolumeUp");
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Hard refresh: 3/10 podcasts (Show)"));
+
+        // Act — completion with a failed feed
+        app.handle_app_event(AppEvent::HardRefreshAllCompleted {
+            scope: HardRefreshScope::Descriptions,
+            summary: crate::podcast::HardRefreshSummary {
+                podcasts: 9,
+                episodes: 120,
+                failed: vec!["Gone Show".to_string()],
+            },
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        let text = app.minibuffer.text_content();
         assert!(
-            matches!(cmd, AudioCommand::VolumeUp),
-            "Expected VolumeUp, got {cmd:?}"
+            text.contains("Hard refresh (descriptions) completed: 9 podcast(s), 120 episode(s)")
         );
+        assert!(text.contains("1 failed: Gone Show"));
     }
 
     #[tokio::test]
-    async fn test_handle_app_event_track_ended_marks_episode_played_and_persists() {
-        use crate::podcast::{Episode, Podcast};
-        use crate::storage::Storage;
-        use chrono::Utc;
+    async fn test_refresh_filtered_requires_an_active_filter() {
+        let mut app = make_test_app().await;
 
-        // Arrange
-        let (mut app, storage) = make_test_app_with_storage().await;
+        let handled = app
+            .execute_command_direct("refresh --filtered".to_string())
+            .unwrap();
 
-        let mut podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "http://example.com/feed.xml".to_string(),
-        );
-        let mut episode = Episode::new(
-            podcast.id.clone(),
-            "Test Episode".to_string(),
-            "http://example.com/ep1.mp3".to_string(),
-            Utc::now(),
-        );
-        episode.duration = Some(3600);
-        podcast.episodes.push(episode.id.clone());
-        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
-        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
+        assert!(handled);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("No podcast list filter is active"));
+    }
+
+    #[tokio::test]
+    async fn test_selective_refresh_summary_names_its_scope() {
+        // Arrange
+        let mut app = make_test_app().await;
 
         // Act
-        app.handle_app_event(AppEvent::TrackEnded {
-            podcast_id: podcast.id.clone(),
-            episode_id: episode.id.clone(),
+        app.handle_app_event(AppEvent::AllPodcastsRefreshed {
+            total_new_episodes: 4,
+            podcasts: 2,
+            unreachable: Vec::new(),
+            scope: Some("tag: \"news\"".to_string()),
         })
         .await
-        .unwrap(); // unwrap OK — testing success path
+        .unwrap();
 
-        // Assert — reload from storage and verify persistence
-        let saved = storage
-            .load_episode(&podcast.id, &episode.id)
+        // Assert
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Refresh of 2 podcast(s) [tag: \"news\"] completed. Found 4 new episode(s)"));
+
+        // Act — a tag nobody has
+        app.handle_app_event(AppEvent::AllPodcastsRefreshed {
+            total_new_episodes: 0,
+            podcasts: 0,
+            unreachable: Vec::new(),
+            scope: Some("tag: \"nope\"".to_string()),
+        })
+        .await
+        .unwrap();
+
+        // Assert
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("No podcasts match tag: \"nope\""));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_feed_defers_network_work_until_back_online() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let podcast_id = crate::storage::PodcastId::new();
+        let episode_id = crate::storage::EpisodeId::new();
+
+        // Act — a refresh fails because the feed can't be reached, and
+        // neither can any other feed server
+        app.handle_app_event(AppEvent::PodcastRefreshFailed {
+            podcast_id: podcast_id.clone(),
+            error: "error sending request".to_string(),
+            offline: true,
+        })
+        .await
+        .unwrap();
+        assert!(app.offline.is_none());
+        app.handle_app_event(AppEvent::ConnectivityProbed { online: false })
             .await
-            .unwrap(); // unwrap OK — episode was saved above
-        assert!(saved.is_played(), "Episode should be marked as played");
+            .unwrap();
+
+        // Assert — friendly message instead of the raw error, refresh queued
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Offline — the refresh will start when the connection returns"));
+        assert!(!app
+            .minibuffer
+            .text_content()
+            .contains("error sending request"));
+
+        // Act — a download asked for while offline waits too
+        app.trigger_async_download(podcast_id.clone(), episode_id.clone());
+
+        // Assert
+        let pending = &app.offline.as_ref().unwrap().pending;
         assert_eq!(
-            saved.last_played_position,
-            Some(3600),
-            "Position should be set to full duration"
+            pending,
+            &vec![
+                PendingNetworkOp::RefreshPodcast(podcast_id.clone()),
+                PendingNetworkOp::Download(podcast_id, episode_id),
+            ]
         );
-        assert_eq!(saved.play_count, 1, "play_count should be incremented to 1");
+
+        // Act — the connection comes back
+        app.handle_app_event(AppEvent::ConnectivityProbed { online: true })
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(app.offline.is_none());
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Back online — resuming 2 pending operation(s)"));
     }
 
     #[tokio::test]
-    async fn test_handle_app_event_track_ended_marks_played_when_no_duration() {
-        use crate::podcast::{Episode, Podcast};
-        use crate::storage::Storage;
-        use chrono::Utc;
-
+    async fn test_one_unreachable_server_does_not_go_offline() {
         // Arrange
-        let (mut app, storage) = make_test_app_with_storage().await;
+        let mut app = make_test_app().await;
+        let podcast_id = crate::storage::PodcastId::new();
 
-        let mut podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "http://example.com/feed.xml".to_string(),
-        );
-        let episode = Episode::new(
-            podcast.id.clone(),
-            "Test Episode No Duration".to_string(),
-            "http://example.com/ep2.mp3".to_string(),
-            Utc::now(),
-        );
-        // No duration set — episode.duration is None
-        podcast.episodes.push(episode.id.clone());
-        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
-        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
+        // Act — a download fails, but other feed servers still answer
+        app.handle_app_event(AppEvent::EpisodeDownloadFailed {
+            podcast_id,
+            episode_id: crate::storage::EpisodeId::new(),
+            error: "error sending request".to_string(),
+            offline: true,
+        })
+        .await
+        .unwrap();
+        app.handle_app_event(AppEvent::ConnectivityProbed { online: true })
+            .await
+            .unwrap();
+
+        // Assert — the error is shown and nothing waits for a connection
+        assert!(app.offline.is_none());
+        assert!(app.unconfirmed_offline.is_empty());
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Episode download failed: error sending request"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reaching_no_feed_goes_offline() {
+        // Arrange
+        let mut app = make_test_app().await;
+        let unreachable = vec![
+            crate::storage::PodcastId::new(),
+            crate::storage::PodcastId::new(),
+        ];
 
         // Act
-        app.handle_app_event(AppEvent::TrackEnded {
-            podcast_id: podcast.id.clone(),
-            episode_id: episode.id.clone(),
+        app.handle_app_event(AppEvent::AllPodcastsRefreshed {
+            total_new_episodes: 0,
+            podcasts: 2,
+            unreachable,
+            scope: None,
         })
         .await
-        .unwrap(); // unwrap OK — testing success path
+        .unwrap();
 
-        // Assert — mark_played() is called when no duration is available
-        let saved = storage
-            .load_episode(&podcast.id, &episode.id)
+        // Assert
+        let offline = app.offline.as_ref().expect("offline mode");
+        assert_eq!(offline.pending, vec![PendingNetworkOp::RefreshAll]);
+
+        // Act — still unreachable when checked by hand
+        app.execute_command_direct("reconnect".to_string()).unwrap();
+        app.handle_app_event(AppEvent::ConnectivityProbed { online: false })
             .await
-            .unwrap(); // unwrap OK — episode was saved above
-        assert!(
-            saved.is_played(),
-            "Episode with no duration should still be marked played"
-        );
-        assert_eq!(saved.play_count, 1);
+            .unwrap();
+
+        // Assert
+        assert!(app.offline.is_some());
+        assert!(app.minibuffer.text_content().contains("Still offline"));
     }
 
     #[tokio::test]
-    async fn test_handle_app_event_playback_started_sets_now_playing_info() {
-        use crate::podcast::{Episode, Podcast};
-        use crate::storage::Storage;
-        use chrono::Utc;
+    async fn test_refresh_diff_requires_a_refresh_this_session() {
+        // Arrange — an episode list for a podcast that hasn't been refreshed
+        let mut app = make_test_app().await;
+        let podcast_id = crate::storage::PodcastId::new();
+        app.buffer_manager.create_episode_list_buffer(
+            "Show".to_string(),
+            podcast_id.clone(),
+            app.subscription_manager.clone(),
+            app.download_manager.clone(),
+        );
+        app.buffer_manager
+            .switch_to_buffer(&"episodes-show".to_string())
+            .unwrap();
 
-        // Arrange
-        let (mut app, storage) = make_test_app_with_storage().await;
+        // Act
+        let handled = app
+            .execute_command_direct("refresh-diff".to_string())
+            .unwrap();
 
-        let mut podcast = Podcast::new(
-            "My Podcast".to_string(),
-            "http://example.com/feed.xml".to_string(),
-        );
-        let episode = Episode::new(
-            podcast.id.clone(),
-            "My Episode".to_string(),
-            "http://example.com/ep1.mp3".to_string(),
-            Utc::now(),
-        );
-        podcast.episodes.push(episode.id.clone());
-        storage.save_podcast(&podcast).await.unwrap(); // unwrap OK — test setup
-        storage.save_episode(&podcast.id, &episode).await.unwrap(); // unwrap OK — test setup
+        // Assert
+        assert!(handled);
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("hasn't been refreshed this session"));
+        assert!(!app
+            .buffer_manager
+            .get_buffer_ids()
+            .iter()
+            .any(|id| id.starts_with("refresh-diff-")));
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_plan_opens_the_preview_without_deleting() {
+        // Arrange
+        let mut app = make_test_app().await;
 
         // Act
-        app.handle_app_event(AppEvent::PlaybackStarted {
-            podcast_id: podcast.id.clone(),
-            episode_id: episode.id.clone(),
+        app.handle_app_event(AppEvent::CleanupPlanned {
+            plan: crate::download::CleanupPlan::default(),
         })
         .await
-        .unwrap(); // unwrap OK — testing success path
+        .unwrap();
 
-        // Assert — NowPlaying buffer should display the episode and podcast names
-        let buf = app
-            .buffer_manager
-            .get_now_playing_buffer_mut()
-            .expect("NowPlaying buffer should exist after initialize()");
-        assert_eq!(buf.episode_title(), Some("My Episode"));
-        assert_eq!(buf.podcast_name(), Some("My Podcast"));
+        // Assert
+        assert_eq!(
+            app.buffer_manager.current_buffer_id(),
+            Some("cleanup-preview".to_string())
+        );
+        assert_eq!(
+            app.minibuffer.text_content(),
+            "Cleanup would delete nothing"
+        );
     }
 
     #[tokio::test]
-    async fn test_handle_app_event_playback_error_shows_error_message() {
-        // Arrange
+    async fn test_edit_episode_prompts_and_updates_detail_buffer() {
+        // Arrange — an episode detail buffer is open
         let mut app = make_test_app().await;
+        let episode = crate::podcast::Episode::new(
+            crate::storage::PodcastId::new(),
+            "Feed Title".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        app.open_episode_detail_buffer(episode.clone());
 
-        // Act
-        app.handle_app_event(AppEvent::PlaybackError {
-            error: "device unavailable".to_string(),
+        // Act — no value: prompt pre-filled with the current title
+        app.execute_command_direct("edit-episode title".to_string())
+            .unwrap();
+
+        // Assert
+        assert!(app.minibuffer.text_content().contains("Feed Title"));
+        assert_eq!(
+            app.pending_episode_edit,
+            Some((
+                episode.podcast_id.clone(),
+                episode.id.clone(),
+                EpisodeField::Title
+            ))
+        );
+
+        // Act — the saved edit comes back from the background task
+        let mut edited = episode.clone();
+        edited.set_override(EpisodeField::Title, Some("My Title".to_string()));
+        app.handle_app_event(AppEvent::EpisodeMetadataEdited {
+            episode: Box::new(edited),
+            field: Some(EpisodeField::Title),
+            retagged: false,
         })
         .await
-        .unwrap(); // unwrap OK — testing success path
+        .unwrap();
 
         // Assert
-        assert!(
-            app.minibuffer.is_visible(),
-            "Minibuffer should show error after PlaybackError"
-        );
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should be in message/error display mode, not input mode"
-        );
+        let detail_id = format!("episode-detail-{}", episode.id);
+        let detail = app
+            .buffer_manager
+            .get_episode_detail_buffer_mut_by_id(&detail_id)
+            .unwrap();
+        assert_eq!(detail.episode().display_title(), "My Title");
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Episode title updated"));
     }
 
     #[tokio::test]
-    async fn test_tag_command_routes_to_podcast_list_not_active_buffer() {
-        use crate::podcast::Podcast;
+    async fn test_episode_note_edits_multiple_lines() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        // Arrange — seed podcast list buffer, then make episode list the active buffer
+        // Arrange — an episode detail buffer is open
         let mut app = make_test_app().await;
-        let podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "https://example.com/feed.xml".to_string(),
+        let episode = crate::podcast::Episode::new(
+            crate::storage::PodcastId::new(),
+            "Interview".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            chrono::Utc::now(),
         );
-        let podcast_id = podcast.id.clone();
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            buf.set_podcasts(vec![podcast]);
+        app.open_episode_detail_buffer(episode.clone());
+
+        // Act — Enter starts a new line instead of submitting
+        app.execute_command_direct("episode-note".to_string())
+            .unwrap();
+        for code in [KeyCode::Char('a'), KeyCode::Enter, KeyCode::Char('b')] {
+            app.handle_minibuffer_key(KeyEvent::new(code, KeyModifiers::NONE))
+                .await
+                .unwrap();
         }
-        app.buffer_manager.create_episode_list_buffer(
-            "Test Podcast".to_string(),
-            podcast_id.clone(),
-            app.subscription_manager.clone(),
-            app.download_manager.clone(),
+
+        // Assert
+        assert!(app.minibuffer.is_multiline());
+        assert_eq!(app.minibuffer.current_input().as_deref(), Some("a\nb"));
+        assert_eq!(
+            app.pending_notes,
+            Some((episode.podcast_id.clone(), Some(episode.id.clone())))
         );
-        // Switch to an episode list so podcast-list is NOT the active buffer
-        let _ = app
-            .buffer_manager
-            .switch_to_buffer(&format!("episodes-{}", podcast_id));
 
-        // Act
-        let result = app.execute_command_direct("tag tech".to_string());
+        // Act — the saved notes come back from the background task
+        let mut saved = episode.clone();
+        saved.notes = Some("a\nb".to_string());
+        app.handle_app_event(AppEvent::NotesSaved {
+            podcast: None,
+            episode: Some(Box::new(saved)),
+        })
+        .await
+        .unwrap();
 
-        // Assert — command succeeded and tag was added to the podcast in the podcast list buffer
-        assert!(result.is_ok());
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            let tagged = buf
-                .podcasts()
-                .iter()
-                .any(|p| p.id == podcast_id && p.has_tag("tech"));
-            assert!(
-                tagged,
-                "Expected podcast to have tag 'tech' after :tag command"
-            );
-        }
+        // Assert
+        let detail = app
+            .buffer_manager
+            .get_episode_detail_buffer_mut_by_id(&format!("episode-detail-{}", episode.id))
+            .unwrap();
+        assert_eq!(detail.episode().notes.as_deref(), Some("a\nb"));
+        assert!(app.minibuffer.text_content().contains("notes saved"));
     }
 
     #[tokio::test]
-    async fn test_untag_command_routes_to_podcast_list_not_active_buffer() {
-        use crate::podcast::Podcast;
+    async fn test_edit_episode_rejects_unknown_field() {
+        let mut app = make_test_app().await;
+        app.execute_command_direct("edit-episode author".to_string())
+            .unwrap();
+        assert!(app.minibuffer.text_content().contains("Unknown field"));
+    }
 
-        // Arrange — seed podcast list with a tagged podcast, make episode list active
+    #[tokio::test]
+    async fn test_convert_episode_validates_arguments() {
+        // Arrange
         let mut app = make_test_app().await;
-        let mut podcast = Podcast::new(
-            "Test Podcast".to_string(),
-            "https://example.com/feed.xml".to_string(),
-        );
-        podcast.add_tag("tech");
-        let podcast_id = podcast.id.clone();
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            buf.set_podcasts(vec![podcast]);
-        }
-        app.buffer_manager.create_episode_list_buffer(
-            "Test Podcast".to_string(),
-            podcast_id.clone(),
-            app.subscription_manager.clone(),
-            app.download_manager.clone(),
-        );
-        // Switch to episode list so podcast-list is NOT the active buffer
-        let _ = app
-            .buffer_manager
-            .switch_to_buffer(&format!("episodes-{}", podcast_id));
 
-        // Act
-        let result = app.execute_command_direct("untag tech".to_string());
+        // Act / Assert — missing format
+        app.execute_command_direct("convert-episode".to_string())
+            .unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Usage: convert-episode"));
 
-        // Assert — tag was removed from the podcast in the podcast list buffer
-        assert!(result.is_ok());
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            let still_tagged = buf
-                .podcasts()
-                .iter()
-                .any(|p| p.id == podcast_id && p.has_tag("tech"));
-            assert!(
-                !still_tagged,
-                "Expected 'tech' tag to be removed after :untag command"
+        // Unknown format
+        app.execute_command_direct("convert-episode wav".to_string())
+            .unwrap();
+        assert!(app.minibuffer.text_content().contains("Unknown format"));
+
+        // Valid target but nothing selected
+        app.execute_command_direct("convert-episode opus 48k".to_string())
+            .unwrap();
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Select a downloaded episode"));
+    }
+
+    #[tokio::test]
+    async fn test_whats_new_loading_skips_excluded_episodes() {
+        use crate::config::WhatsNewExclusion;
+        use crate::podcast::{Episode, Podcast};
+
+        // Arrange
+        let (app, storage) = make_test_app_with_storage().await;
+        let podcast = Podcast::new("News".to_string(), "https://example.com/feed".to_string());
+        storage.save_podcast(&podcast).await.unwrap();
+        for title in ["Teaser: next season", "Episode 1"] {
+            let episode = Episode::new(
+                podcast.id.clone(),
+                title.to_string(),
+                "https://example.com/a.mp3".to_string(),
+                chrono::Utc::now(),
             );
+            storage.save_episode(&podcast.id, &episode).await.unwrap();
         }
+        let (rules, _) = WhatsNewExclusions::compile(&[WhatsNewExclusion {
+            podcast: None,
+            title_pattern: Some("^Teaser".to_string()),
+        }]);
+
+        // Act
+        let latest = load_aggregated_episodes(
+            &app.subscription_manager,
+            EpisodeScope::Latest,
+            Some(&rules),
+        )
+        .await;
+        let unplayed =
+            load_aggregated_episodes(&app.subscription_manager, EpisodeScope::Unplayed, None).await;
+
+        // Assert — only What's New applies the exclusions
+        let titles: Vec<&str> = latest.iter().map(|e| e.episode.title.as_str()).collect();
+        assert_eq!(titles, vec!["Episode 1"]);
+        assert_eq!(unplayed.len(), 2);
     }
 
     #[tokio::test]
-    async fn test_filter_tag_routes_to_podcast_list_and_switches_buffer() {
+    async fn test_archive_ended_asks_then_archives_complete_shows() {
         use crate::podcast::Podcast;
 
-        // Arrange — 2 podcasts: one tagged "tech", one untagged; episode list is active
-        let mut app = make_test_app().await;
-        let mut tagged_podcast = Podcast::new(
-            "Tech Podcast".to_string(),
-            "https://example.com/tech.xml".to_string(),
-        );
-        tagged_podcast.add_tag("tech");
-        let tagged_id = tagged_podcast.id.clone();
-
-        let other_podcast = Podcast::new(
-            "News Podcast".to_string(),
-            "https://example.com/news.xml".to_string(),
+        // Arrange — one show its feed declares complete, one still running
+        let (mut app, storage) = make_test_app_with_storage().await;
+        let mut finished = Podcast::new(
+            "Serial".to_string(),
+            "https://example.com/serial".to_string(),
         );
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            buf.set_podcasts(vec![tagged_podcast, other_podcast]);
+        finished.complete = true;
+        let running = Podcast::new("News".to_string(), "https://example.com/news".to_string());
+        for podcast in [&finished, &running] {
+            storage.save_podcast(podcast).await.unwrap();
         }
-        app.buffer_manager.create_episode_list_buffer(
-            "Tech Podcast".to_string(),
-            tagged_id.clone(),
-            app.subscription_manager.clone(),
-            app.download_manager.clone(),
-        );
-        // Switch to episode list so podcast-list is NOT the active buffer
-        let _ = app
-            .buffer_manager
-            .switch_to_buffer(&format!("episodes-{}", tagged_id));
-        assert_ne!(
-            app.buffer_manager.current_buffer_id(),
-            Some("podcast-list".to_string()),
-            "Precondition: episode list should be active, not podcast list"
-        );
+        app.buffer_manager
+            .get_podcast_list_buffer_mut()
+            .unwrap()
+            .set_podcasts(vec![finished.clone(), running.clone()]);
 
         // Act
-        let result = app.execute_command_direct("filter-tag tech".to_string());
+        app.execute_command_direct("archive-ended".to_string())
+            .unwrap();
+        let prompt = app.minibuffer.current_prompt();
+        app.handle_minibuffer_input_with_context("y".to_string(), prompt.clone());
+        app.background_tasks.shutdown(Duration::from_secs(5)).await;
 
-        // Assert — command succeeded AND we switched to podcast-list
-        assert!(result.is_ok());
+        // Assert
         assert_eq!(
-            app.buffer_manager.current_buffer_id(),
-            Some("podcast-list".to_string()),
-            "Expected :filter-tag to switch to podcast list buffer"
+            prompt.as_deref(),
+            Some("Archive 1 ended show(s): Serial? (y/n) ")
         );
-        // Only the tagged podcast should be visible after filter
-        if let Some(buf) = app.buffer_manager.get_podcast_list_buffer_mut() {
-            // selected_podcast() returns Some only if a filtered result exists
-            let selected = buf.selected_podcast().map(|p| p.id.clone());
-            assert_eq!(
-                selected,
-                Some(tagged_id),
-                "Expected the tech-tagged podcast to be selected after filter"
-            );
-            // The other (untagged) podcast should not be visible — verify it has the tag
-            let selected_has_tag = buf
-                .selected_podcast()
-                .map(|p| p.has_tag("tech"))
-                .unwrap_or(false);
-            assert!(
-                selected_has_tag,
-                "Selected podcast should have the 'tech' tag after :filter-tag tech"
-            );
-        }
+        assert!(app.pending_archive.is_none());
+        assert!(storage
+            .load_podcast(&finished.id)
+            .await
+            .unwrap()
+            .is_archived());
+        assert!(!storage
+            .load_podcast(&running.id)
+            .await
+            .unwrap()
+            .is_archived());
     }
 
     #[tokio::test]
-    async fn test_tag_command_shows_error_when_no_podcast_selected() {
-        // Arrange — empty podcast list (no selection possible)
+    async fn test_release_notes_respects_disabled_network_checks() {
+        // Arrange
         let mut app = make_test_app().await;
-        // podcast list buffer is created by UIApp::new() but is empty by default
+        app.config.updates.network_checks = false;
 
         // Act
-        let result = app.execute_command_direct("tag tech".to_string());
+        app.execute_command_direct("release-notes".to_string())
+            .unwrap();
 
-        // Assert — returns Ok but shows error in minibuffer (no silent failure)
-        assert!(result.is_ok());
-        assert!(
-            app.minibuffer.is_visible(),
-            "Expected error message in minibuffer when no podcast is selected"
-        );
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should be in error display mode, not input mode"
-        );
+        // Assert
+        assert!(app
+            .minibuffer
+            .text_content()
+            .contains("Update checks are disabled"));
     }
 
     #[tokio::test]
-    async fn test_untag_command_shows_error_when_no_podcast_selected() {
-        // Arrange — empty podcast list (no selection possible)
+    async fn test_releases_checked_sets_hint_and_opens_buffer() {
+        // Arrange
         let mut app = make_test_app().await;
+        let release: crate::update_check::Release =
+            serde_json::from_str(r#"{"tag_name":"v99.0.0","body":"- Big release"}"#).unwrap();
 
         // Act
-        let result = app.execute_command_direct("untag tech".to_string());
+        app.handle_app_event(AppEvent::ReleasesChecked {
+            releases: vec![release],
+            open_buffer: true,
+        })
+        .await
+        .unwrap();
 
-        // Assert — returns Ok but shows error in minibuffer (no silent failure)
-        assert!(result.is_ok());
-        assert!(
-            app.minibuffer.is_visible(),
-            "Expected error message in minibuffer when no podcast is selected for :untag"
+        // Assert
+        assert_eq!(
+            app.status_bar.hint(),
+            "⬆ v99.0.0 available (:release-notes)"
         );
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should be in error display mode, not input mode"
+        assert_eq!(
+            app.buffer_manager.current_buffer_id().as_deref(),
+            Some("release-notes")
         );
     }
 
-    // ── Issue #171: PlayEpisode default keybinding / TogglePlayPause feedback ──
-
     #[tokio::test]
-    async fn test_toggle_play_pause_shows_error_when_audio_none() {
-        // Arrange — audio_command_tx is None (audio init failed / not available)
-        let mut app = make_test_app().await;
-        // Do NOT call set_audio_command_tx — leave audio_command_tx as None
+    async fn test_record_keys_logs_key_action_and_buffer() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-        // Act
-        let result = app.handle_action(UIAction::TogglePlayPause).await;
+        // Arrange
+        let (mut app, storage) = make_test_app_with_storage().await;
+        app.execute_command_direct("record-keys".to_string())
+            .unwrap();
+        assert!(app.key_recorder.is_some());
 
-        // Assert — returns Ok(true) and shows an error in the minibuffer
-        assert!(result.is_ok());
-        assert!(
-            result.unwrap(),
-            "handle_action should return true (continue running)"
-        );
-        assert!(
-            app.minibuffer.is_visible(),
-            "Minibuffer should show error when audio is unavailable"
-        );
-        assert!(
-            !app.minibuffer.is_input_mode(),
-            "Minibuffer should be in error display mode, not input mode"
-        );
-        // Verify the exact error message so we catch silent regressions if the
-        // string changes (e.g. a different branch fires instead)
-        let text = app.minibuffer.text_content();
-        assert!(
-            text.contains(crate::constants::audio::UNAVAILABLE_ERROR),
-            "Minibuffer should contain the audio-unavailable error, got: {text:?}"
-        );
+        // Act — a bound key, then typed minibuffer text
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL,
+        )))
+        .await
+        .unwrap();
+        app.minibuffer.show_prompt("Search: ".to_string());
+        app.handle_event(UIEvent::Key(KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        app.minibuffer.clear();
+        app.execute_command_direct("record-keys".to_string())
+            .unwrap();
+
+        // Assert
+        assert!(app.key_recorder.is_none());
+        let dir = storage
+            .data_dir
+            .join(crate::ui::key_recorder::KEY_RECORDINGS_DIR);
+        let file = std::fs::read_dir(dir).unwrap().next().unwrap().unwrap();
+        let log = std::fs::read_to_string(file.path()).unwrap();
+        assert!(log.contains("C-n          MoveDown"));
+        assert!(log.contains("<text>       minibuffer input"));
+        assert!(!log.contains(" x "));
+        assert!(log.ends_with("# 2 key(s) recorded\n"));
     }
 }
@@ -0,0 +1,151 @@
+// Background tasks - spawned work the app waits for or cancels on quit
+//
+// Feed refreshes only fetch and can be dropped, but storage writes and
+// downloads should not be cut off mid-way. Tasks spawned through
+// `BackgroundTasks` are tracked by kind so `shutdown` can cancel the
+// refreshes, give the rest a bounded time to finish, and report the
+// downloads it had to interrupt.
+
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::storage::{EpisodeId, PodcastId};
+
+/// What a background task does, which decides its fate on quit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskKind {
+    /// Feed fetches and lookups; cancelled on quit
+    Refresh,
+    /// Storage writes; waited for
+    Write,
+    /// An episode download; waited for, then reported if interrupted
+    Download {
+        podcast_id: PodcastId,
+        episode_id: EpisodeId,
+    },
+}
+
+/// What happened to the tracked tasks on shutdown
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShutdownReport {
+    /// Refreshes that were still running and got cancelled
+    pub cancelled: usize,
+    /// Writes and downloads that finished within the timeout
+    pub finished: usize,
+    /// Writes that were still running at the timeout
+    pub interrupted_writes: usize,
+    /// Downloads that were still running at the timeout
+    pub interrupted_downloads: Vec<(PodcastId, EpisodeId)>,
+}
+
+/// Tracked tasks spawned by the UI
+#[derive(Default)]
+pub struct BackgroundTasks {
+    tasks: Vec<(TaskKind, JoinHandle<()>)>,
+}
+
+impl BackgroundTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn `task` on the runtime and track it
+    pub fn spawn<F>(&mut self, kind: TaskKind, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tasks.retain(|(_, handle)| !handle.is_finished());
+        self.tasks.push((kind, tokio::spawn(task)));
+    }
+
+    /// Tracked tasks still running
+    pub fn running(&self) -> usize {
+        self.tasks
+            .iter()
+            .filter(|(_, handle)| !handle.is_finished())
+            .count()
+    }
+
+    /// Cancel refreshes, then wait up to `timeout` for everything else;
+    /// whatever is still running after that is aborted
+    pub async fn shutdown(&mut self, timeout: Duration) -> ShutdownReport {
+        let mut report = ShutdownReport::default();
+        let mut waiting = Vec::new();
+        for (kind, handle) in self.tasks.drain(..) {
+            if handle.is_finished() {
+                continue;
+            }
+            if kind == TaskKind::Refresh {
+                handle.abort();
+                report.cancelled += 1;
+            } else {
+                waiting.push((kind, handle));
+            }
+        }
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        for (kind, mut handle) in waiting {
+            if tokio::time::timeout_at(deadline, &mut handle).await.is_ok() {
+                report.finished += 1;
+                continue;
+            }
+            handle.abort();
+            match kind {
+                TaskKind::Download {
+                    podcast_id,
+                    episode_id,
+                } => report.interrupted_downloads.push((podcast_id, episode_id)),
+                _ => report.interrupted_writes += 1,
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_cancels_refreshes_and_waits_for_writes() {
+        // Arrange
+        let mut tasks = BackgroundTasks::new();
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        tasks.spawn(TaskKind::Refresh, std::future::pending());
+        tasks.spawn(TaskKind::Write, async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let _ = done_tx.send(());
+        });
+
+        // Act
+        let report = tasks.shutdown(Duration::from_secs(5)).await;
+
+        // Assert
+        assert_eq!(report.cancelled, 1);
+        assert_eq!(report.finished, 1);
+        assert!(done_rx.await.is_ok(), "the write ran to completion");
+        assert_eq!(tasks.running(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_reports_downloads_past_the_timeout() {
+        let mut tasks = BackgroundTasks::new();
+        let ids = (PodcastId::new(), EpisodeId::new());
+        tasks.spawn(
+            TaskKind::Download {
+                podcast_id: ids.0.clone(),
+                episode_id: ids.1.clone(),
+            },
+            std::future::pending(),
+        );
+        tasks.spawn(TaskKind::Write, std::future::pending());
+
+        let report = tasks.shutdown(Duration::from_millis(20)).await;
+
+        assert_eq!(report.finished, 0);
+        assert_eq!(report.interrupted_writes, 1);
+        assert_eq!(report.interrupted_downloads, vec![ids]);
+    }
+}
@@ -0,0 +1,345 @@
+// Alerts buffer - new episodes matching `alerts.keywords`
+//
+// Refreshes scan new episodes (and transcripts once written) for the
+// configured keywords; every hit lands here, newest first, for the rest of the
+// session. Opened with `:alerts`, emptied with `:alerts-clear`.
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::{
+    ui::{
+        buffers::{capabilities::Downloadable, Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        events::KeywordAlert,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+    utils::time::format_relative_time,
+};
+
+/// Buffer listing keyword alerts
+pub struct AlertsBuffer {
+    id: String,
+    alerts: Vec<KeywordAlert>,
+    selected_index: Option<usize>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    theme: Theme,
+}
+
+impl Default for AlertsBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertsBuffer {
+    pub fn new() -> Self {
+        Self {
+            id: "alerts".to_string(),
+            alerts: Vec::new(),
+            selected_index: None,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Replace the listed alerts (oldest first, as the app keeps them); the
+    /// cursor stays on the alert it was on
+    pub fn set_alerts(&mut self, alerts: &[KeywordAlert]) {
+        let selected_id = self.selected_alert().map(|alert| alert.episode.id.clone());
+        self.alerts = alerts.iter().rev().cloned().collect();
+        self.selected_index = selected_id
+            .and_then(|id| self.alerts.iter().position(|a| a.episode.id == id))
+            .or(if self.alerts.is_empty() {
+                None
+            } else {
+                Some(0)
+            });
+        if self.alerts.is_empty() {
+            self.scroll_offset = 0;
+        }
+    }
+
+    pub fn selected_alert(&self) -> Option<&KeywordAlert> {
+        self.selected_index.and_then(|i| self.alerts.get(i))
+    }
+
+    fn select_previous(&mut self) {
+        if self.alerts.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(0) => Some(self.alerts.len() - 1),
+            Some(i) => Some(i - 1),
+            None => Some(0),
+        };
+    }
+
+    fn select_next(&mut self) {
+        if self.alerts.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(i) if i >= self.alerts.len() - 1 => Some(0),
+            Some(i) => Some(i + 1),
+            None => Some(0),
+        };
+    }
+}
+
+impl Buffer for AlertsBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Alerts".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        Some(self)
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.alerts.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Alerts", &self.alerts, |a| {
+            a.podcast_title.len() + a.keyword.len() + super::episode_text_bytes(&a.episode)
+        })
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Alerts Commands:".to_string(),
+            "  C-n, ↓    Next alert".to_string(),
+            "  C-p, ↑    Previous alert".to_string(),
+            "  Enter     View episode details".to_string(),
+            "  D         Download episode".to_string(),
+            "  :alerts-clear  Clear all alerts".to_string(),
+            "  C-h       Show help".to_string(),
+        ]
+    }
+}
+
+impl Downloadable for AlertsBuffer {
+    fn download_selected(&mut self) -> UIAction {
+        match self.selected_alert() {
+            Some(alert) => UIAction::TriggerDownload {
+                podcast_id: alert.podcast_id.clone(),
+                episode_id: alert.episode.id.clone(),
+                episode_title: alert.episode.display_title().to_string(),
+            },
+            None => UIAction::ShowMessage("No alert selected".to_string()),
+        }
+    }
+}
+
+impl UIComponent for AlertsBuffer {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                if !self.alerts.is_empty() {
+                    self.selected_index = Some(0);
+                    self.scroll_offset = 0;
+                }
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                if !self.alerts.is_empty() {
+                    self.selected_index = Some(self.alerts.len() - 1);
+                }
+                UIAction::Render
+            }
+            UIAction::SelectItem => match self.selected_alert() {
+                Some(alert) => UIAction::OpenEpisodeDetail {
+                    episode: Box::new(alert.episode.clone()),
+                },
+                None => UIAction::ShowMessage("No alert selected".to_string()),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.scroll_area = area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()))
+            .border_style(if self.focused {
+                self.theme.border_focused_style()
+            } else {
+                self.theme.border_style()
+            });
+
+        if self.alerts.is_empty() {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let message = Paragraph::new(
+                "No alerts yet.\n\nNew episodes matching alerts.keywords in your config show up here after a refresh.",
+            )
+            .style(self.theme.default_style())
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true });
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let visible_height = block.inner(area).height.saturating_sub(1) as usize; // -1 for header
+        if let Some(selected) = self.selected_index {
+            if selected >= self.scroll_offset + visible_height {
+                self.scroll_offset = selected.saturating_sub(visible_height.saturating_sub(1));
+            } else if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            }
+        }
+
+        let header = Row::new(vec![
+            Cell::from("Keyword"),
+            Cell::from("Podcast"),
+            Cell::from("Episode"),
+            Cell::from("Raised"),
+        ])
+        .style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let end = (self.scroll_offset + visible_height).min(self.alerts.len());
+        let rows: Vec<Row> = self.alerts[self.scroll_offset..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, alert)| {
+                let style = if Some(self.scroll_offset + offset) == self.selected_index {
+                    self.theme.selected_style()
+                } else {
+                    self.theme.default_style()
+                };
+                Row::new(vec![
+                    Cell::from(text_layout::cell(&alert.keyword, 20)),
+                    Cell::from(text_layout::cell(&alert.podcast_title, 25)),
+                    Cell::from(text_layout::cell(alert.episode.display_title(), 60)),
+                    Cell::from(format_relative_time(&alert.raised_at)),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(15),
+                Constraint::Percentage(22),
+                Constraint::Percentage(48),
+                Constraint::Percentage(15),
+            ],
+        )
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+        frame.render_widget(table, area);
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn title(&self) -> String {
+        format!("Alerts ({})", self.alerts.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::Episode;
+    use crate::storage::PodcastId;
+
+    fn alert(title: &str) -> KeywordAlert {
+        let podcast_id = PodcastId::new();
+        KeywordAlert {
+            podcast_id: podcast_id.clone(),
+            podcast_title: "Local Radio".to_string(),
+            episode: Episode::new(
+                podcast_id,
+                title.to_string(),
+                "https://example.com/a.mp3".to_string(),
+                chrono::Utc::now(),
+            ),
+            keyword: "rustconf".to_string(),
+            raised_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_alerts_listed_newest_first_and_open_episode() {
+        // Arrange
+        let mut buffer = AlertsBuffer::new();
+        let alerts = vec![alert("Older"), alert("Newer")];
+
+        // Act
+        buffer.set_alerts(&alerts);
+        let action = buffer.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert_eq!(buffer.title(), "Alerts (2)");
+        match action {
+            UIAction::OpenEpisodeDetail { episode } => assert_eq!(episode.title, "Newer"),
+            other => panic!("expected OpenEpisodeDetail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_set_alerts_keeps_cursor_on_same_alert() {
+        let mut buffer = AlertsBuffer::new();
+        let mut alerts = vec![alert("First"), alert("Second")];
+        buffer.set_alerts(&alerts);
+        buffer.handle_action(UIAction::MoveDown);
+        assert_eq!(buffer.selected_alert().unwrap().episode.title, "First");
+
+        alerts.push(alert("Third"));
+        buffer.set_alerts(&alerts);
+
+        assert_eq!(buffer.selected_alert().unwrap().episode.title, "First");
+        buffer.set_alerts(&[]);
+        assert!(buffer.selected_alert().is_none());
+    }
+}
@@ -0,0 +1,216 @@
+// Audio device picker - choose the playback output device
+//
+// Opened by `:select-audio-device` once the audio thread has reported the
+// available devices. The first row is always the system default so a choice
+// can be undone even after the preferred device has been unplugged.
+
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use crate::ui::{
+    buffers::{Buffer, BufferId},
+    themes::Theme,
+    UIAction, UIComponent,
+};
+
+const DEFAULT_LABEL: &str = "System default";
+
+pub struct AudioDevicePickerBuffer {
+    id: String,
+    focused: bool,
+    theme: Theme,
+    /// `None` is the system default, always the first entry
+    devices: Vec<Option<String>>,
+    current: Option<String>,
+    selected_index: usize,
+}
+
+impl AudioDevicePickerBuffer {
+    /// Build the picker with the cursor on the device currently in use
+    pub fn new(devices: Vec<String>, current: Option<String>) -> Self {
+        let devices: Vec<Option<String>> = std::iter::once(None)
+            .chain(devices.into_iter().map(Some))
+            .collect();
+        let selected_index = devices.iter().position(|d| *d == current).unwrap_or(0);
+
+        Self {
+            id: "audio-device-picker".to_string(),
+            focused: false,
+            theme: Theme::default(),
+            devices,
+            current,
+            selected_index,
+        }
+    }
+
+    /// Device under the cursor (`None` = system default)
+    pub fn selected_device(&self) -> Option<&str> {
+        self.devices
+            .get(self.selected_index)
+            .and_then(|d| d.as_deref())
+    }
+
+    fn select_previous(&mut self) {
+        self.selected_index = match self.selected_index {
+            0 => self.devices.len() - 1,
+            index => index - 1,
+        };
+    }
+
+    fn select_next(&mut self) {
+        self.selected_index = (self.selected_index + 1) % self.devices.len();
+    }
+}
+
+impl Buffer for AudioDevicePickerBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Audio Devices".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Audio Device Commands:".to_string(),
+            "  ↑/↓      Navigate devices".to_string(),
+            "  Enter    Play through the selected device".to_string(),
+            "  Esc      Cancel".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for AudioDevicePickerBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::SelectItem => UIAction::SelectAudioDevice {
+                device: self.selected_device().map(str::to_string),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let items: Vec<ListItem> = self
+            .devices
+            .iter()
+            .enumerate()
+            .map(|(index, device)| {
+                let selected = index == self.selected_index;
+                let marker = if selected { "► " } else { "  " };
+                let name = device.as_deref().unwrap_or(DEFAULT_LABEL);
+                let in_use = if *device == self.current {
+                    " (in use)"
+                } else {
+                    ""
+                };
+                let text = format!("{marker}{name}{in_use}");
+                if selected {
+                    ListItem::new(text).style(self.theme.selected_style())
+                } else {
+                    ListItem::new(text).style(self.theme.text_style())
+                }
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Select Audio Device")
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title_style(self.theme.title_style()),
+        );
+        frame.render_widget(list, area);
+    }
+
+    fn title(&self) -> String {
+        "Select Audio Device".to_string()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn devices() -> Vec<String> {
+        vec!["Speakers".to_string(), "USB Headset".to_string()]
+    }
+
+    #[test]
+    fn test_cursor_starts_on_device_in_use() {
+        let picker = AudioDevicePickerBuffer::new(devices(), Some("USB Headset".to_string()));
+        assert_eq!(picker.selected_device(), Some("USB Headset"));
+
+        let picker = AudioDevicePickerBuffer::new(devices(), None);
+        assert_eq!(picker.selected_device(), None, "default is the first row");
+    }
+
+    #[test]
+    fn test_unknown_current_device_falls_back_to_default_row() {
+        let picker = AudioDevicePickerBuffer::new(devices(), Some("Unplugged DAC".to_string()));
+        assert_eq!(picker.selected_device(), None);
+    }
+
+    #[test]
+    fn test_select_item_emits_chosen_device() {
+        // Arrange
+        let mut picker = AudioDevicePickerBuffer::new(devices(), None);
+
+        // Act
+        picker.handle_action(UIAction::MoveDown);
+        let action = picker.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert!(matches!(
+            action,
+            UIAction::SelectAudioDevice { device: Some(ref d) } if d == "Speakers"
+        ));
+    }
+
+    #[test]
+    fn test_navigation_wraps() {
+        let mut picker = AudioDevicePickerBuffer::new(devices(), None);
+        picker.handle_action(UIAction::MoveUp);
+        assert_eq!(picker.selected_device(), Some("USB Headset"));
+        picker.handle_action(UIAction::MoveDown);
+        assert_eq!(picker.selected_device(), None);
+    }
+}
@@ -0,0 +1,251 @@
+// Audio diagnostics buffer - what the startup audio probe found
+//
+// Opened with `:audio-diagnostics`. Shows the backend in use and why, whether
+// the built-in player could open an output, the devices it sees and which
+// external players are installed, so "no sound" can be diagnosed in the app.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    audio::probe::AudioDiagnostics,
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Buffer showing the audio backend probe results
+pub struct AudioDiagnosticsBuffer {
+    id: String,
+    diagnostics: AudioDiagnostics,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl AudioDiagnosticsBuffer {
+    pub fn new(diagnostics: AudioDiagnostics) -> Self {
+        Self {
+            id: "audio-diagnostics".to_string(),
+            diagnostics,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'_>> {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let diagnostics = &self.diagnostics;
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Backend: ", bold),
+                Span::raw(
+                    diagnostics
+                        .selected
+                        .clone()
+                        .unwrap_or_else(|| "none, playback is disabled".to_string()),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Why: ", bold),
+                Span::raw(&diagnostics.reason),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled("Built-in player", bold)),
+        ];
+
+        lines.push(Line::from(match &diagnostics.built_in {
+            Some(Ok(())) => "  Output opened".to_string(),
+            Some(Err(e)) => format!("  Could not open output: {}", e),
+            None => "  Not tried (an external player is configured)".to_string(),
+        }));
+        if diagnostics.output_devices.is_empty() {
+            lines.push(Line::from("  No output devices found"));
+        }
+        for device in &diagnostics.output_devices {
+            let is_default = diagnostics.default_device.as_ref() == Some(device);
+            let mut line = vec![Span::raw(format!("  {}", device))];
+            if is_default {
+                line.push(Span::styled("  (default)", self.theme.muted_style()));
+            }
+            lines.push(Line::from(line));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("External players", bold)));
+        if let Some((player, found)) = &diagnostics.configured_player {
+            lines.push(Line::from(format!(
+                "  audio.external_player: {} ({})",
+                player,
+                if *found { "found" } else { "not found" }
+            )));
+        }
+        if diagnostics.external_players.is_empty() {
+            lines.push(Line::from("  None of mpv, vlc or ffplay is installed"));
+        } else {
+            lines.push(Line::from(format!(
+                "  Installed: {}",
+                diagnostics.external_players.join(", ")
+            )));
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+impl Buffer for AudioDiagnosticsBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Audio Diagnostics".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::new("Audio")
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Audio Diagnostics Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for AudioDiagnosticsBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_explains_a_missing_backend() {
+        // Arrange
+        let buffer = AudioDiagnosticsBuffer::new(AudioDiagnostics {
+            reason: "no output device".to_string(),
+            built_in: Some(Err("Audio device not found".to_string())),
+            configured_player: Some(("mpv".to_string(), false)),
+            ..Default::default()
+        });
+
+        // Act
+        let lines: Vec<String> = buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        // Assert
+        assert!(lines.contains(&"Backend: none, playback is disabled".to_string()));
+        assert!(lines.contains(&"  Could not open output: Audio device not found".to_string()));
+        assert!(lines.contains(&"  audio.external_player: mpv (not found)".to_string()));
+        assert!(lines.contains(&"  None of mpv, vlc or ffplay is installed".to_string()));
+    }
+}
@@ -1,27 +1,84 @@
 // Buffer list display - Emacs-style buffer list
 //
 // This buffer shows all open buffers in a list format, allowing
-// users to navigate and manage buffers like in Emacs.
+// users to navigate and manage buffers like in Emacs. Each row shows the
+// buffer kind, owning podcast, item count and approximate memory use; rows
+// can be sorted and marked for closing in bulk. The app renders a preview of
+// the selected buffer next to the list.
+
+use std::collections::HashSet;
 
 use ratatui::{
-    layout::Rect,
-    style::Style,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
 use crate::ui::{
-    buffers::{Buffer, BufferId},
+    buffers::{Buffer, BufferId, BufferInfo},
     themes::Theme,
     UIAction, UIComponent,
 };
+use crate::utils::fs::format_file_size;
+
+/// Column the buffer list is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BufferListSortField {
+    /// Order the buffers were opened in
+    #[default]
+    Order,
+    Name,
+    Kind,
+    Items,
+    Memory,
+}
+
+impl BufferListSortField {
+    /// Next field in the `o` cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::Order => Self::Name,
+            Self::Name => Self::Kind,
+            Self::Kind => Self::Items,
+            Self::Items => Self::Memory,
+            Self::Memory => Self::Order,
+        }
+    }
+
+    /// Label shown in the title bar
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Order => "order",
+            Self::Name => "name",
+            Self::Kind => "kind",
+            Self::Items => "items",
+            Self::Memory => "memory",
+        }
+    }
+}
+
+/// One row of the buffer list
+#[derive(Debug, Clone)]
+struct BufferListEntry {
+    id: BufferId,
+    name: String,
+    is_current: bool,
+    info: BufferInfo,
+    /// Position in the buffer manager's order, for the default sort
+    order: usize,
+}
 
 /// Buffer that displays a list of all open buffers
 pub struct BufferListBuffer {
-    /// Buffer items with (id, name, current) tuples
-    buffer_items: Vec<(BufferId, String, bool)>,
+    /// One entry per open buffer (excluding this one), in display order
+    entries: Vec<BufferListEntry>,
+    /// Buffers marked for closing with `d`
+    marked: HashSet<BufferId>,
+    sort_field: BufferListSortField,
+    sort_descending: bool,
     /// Current selection
-    list_state: ListState,
+    table_state: TableState,
     /// Theme for styling
     theme: Theme,
     /// Whether this buffer has focus
@@ -32,69 +89,130 @@ impl BufferListBuffer {
     /// Create a new buffer list buffer
     pub fn new() -> Self {
         let mut buffer = Self {
-            buffer_items: Vec::new(),
-            list_state: ListState::default(),
+            entries: Vec::new(),
+            marked: HashSet::new(),
+            sort_field: BufferListSortField::default(),
+            sort_descending: false,
+            table_state: TableState::default(),
             theme: Theme::default(),
             focused: false,
         };
-        buffer.list_state.select(Some(0));
+        buffer.table_state.select(Some(0));
         buffer
     }
 
-    /// Update the buffer list with current buffers
+    /// Update the buffer list with current buffers, keeping the selection,
+    /// sort order and marks for buffers that are still open
     pub fn update_buffer_list(
         &mut self,
-        buffers: Vec<(BufferId, String)>,
+        buffers: Vec<(BufferId, String, BufferInfo)>,
         current_buffer: Option<&BufferId>,
     ) {
-        self.buffer_items = buffers
+        let own_id = self.id();
+        let selected_id = self.selected_buffer_id().cloned();
+
+        self.entries = buffers
             .into_iter()
-            .map(|(id, name)| {
-                let is_current = current_buffer == Some(&id);
-                (id, name, is_current)
+            .filter(|(id, _, _)| *id != own_id)
+            .enumerate()
+            .map(|(order, (id, name, info))| BufferListEntry {
+                is_current: current_buffer == Some(&id),
+                id,
+                name,
+                info,
+                order,
             })
             .collect();
+        self.marked
+            .retain(|id| self.entries.iter().any(|entry| entry.id == *id));
+        self.apply_sort();
+
+        // Keep the same buffer selected if it survived, otherwise clamp
+        let index = selected_id
+            .and_then(|id| self.entries.iter().position(|entry| entry.id == id))
+            .or(self.table_state.selected());
+        if self.entries.is_empty() {
+            self.table_state.select(None);
+        } else {
+            let index = index.unwrap_or(0).min(self.entries.len() - 1);
+            self.table_state.select(Some(index));
+        }
+    }
 
-        // Ensure selection is valid
-        if self.buffer_items.is_empty() {
-            self.list_state.select(None);
-        } else if self.list_state.selected().unwrap_or(0) >= self.buffer_items.len() {
-            self.list_state.select(Some(0));
+    fn apply_sort(&mut self) {
+        let selected_id = self.selected_buffer_id().cloned();
+        match self.sort_field {
+            BufferListSortField::Order => self.entries.sort_by_key(|e| e.order),
+            BufferListSortField::Name => self.entries.sort_by_key(|e| e.name.to_lowercase()),
+            BufferListSortField::Kind => self.entries.sort_by_key(|e| (e.info.kind, e.order)),
+            BufferListSortField::Items => self.entries.sort_by_key(|e| e.info.item_count),
+            BufferListSortField::Memory => self.entries.sort_by_key(|e| e.info.memory_bytes),
+        }
+        if self.sort_descending {
+            self.entries.reverse();
+        }
+        if let Some(id) = selected_id {
+            let index = self.entries.iter().position(|entry| entry.id == id);
+            self.table_state.select(index);
         }
     }
 
     /// Move selection up
     pub fn select_previous(&mut self) {
-        if self.buffer_items.is_empty() {
+        if self.entries.is_empty() {
             return;
         }
 
-        let selected = self.list_state.selected().unwrap_or(0);
+        let selected = self.table_state.selected().unwrap_or(0);
         let new_index = if selected == 0 {
-            self.buffer_items.len() - 1
+            self.entries.len() - 1
         } else {
             selected - 1
         };
-        self.list_state.select(Some(new_index));
+        self.table_state.select(Some(new_index));
     }
 
     /// Move selection down
     pub fn select_next(&mut self) {
-        if self.buffer_items.is_empty() {
+        if self.entries.is_empty() {
             return;
         }
 
-        let selected = self.list_state.selected().unwrap_or(0);
-        let new_index = (selected + 1) % self.buffer_items.len();
-        self.list_state.select(Some(new_index));
+        let selected = self.table_state.selected().unwrap_or(0);
+        let new_index = (selected + 1) % self.entries.len();
+        self.table_state.select(Some(new_index));
     }
 
     /// Get the currently selected buffer ID
     pub fn selected_buffer_id(&self) -> Option<&BufferId> {
-        self.list_state
+        self.table_state
             .selected()
-            .and_then(|index| self.buffer_items.get(index))
-            .map(|(id, _, _)| id)
+            .and_then(|index| self.entries.get(index))
+            .map(|entry| &entry.id)
+    }
+
+    /// Buffers currently marked for closing, in display order
+    pub fn marked_buffer_ids(&self) -> Vec<BufferId> {
+        self.entries
+            .iter()
+            .filter(|entry| self.marked.contains(&entry.id))
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+
+    /// Mark or unmark the selected buffer for closing, then move down
+    fn set_selected_mark(&mut self, mark: bool) {
+        if let Some(id) = self.selected_buffer_id().cloned() {
+            if mark {
+                self.marked.insert(id);
+            } else {
+                self.marked.remove(&id);
+            }
+            let next = self.table_state.selected().unwrap_or(0) + 1;
+            if next < self.entries.len() {
+                self.table_state.select(Some(next));
+            }
+        }
     }
 
     /// Set the theme
@@ -128,6 +246,10 @@ impl Buffer for BufferListBuffer {
         true
     }
 
+    fn info(&self) -> BufferInfo {
+        BufferInfo::new("Buffer List")
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Buffer List Commands:".to_string(),
@@ -138,13 +260,19 @@ impl Buffer for BufferListBuffer {
             "  Enter/Space - Switch to selected buffer".to_string(),
             "".to_string(),
             "Actions:".to_string(),
-            "  d           - Mark buffer for deletion".to_string(),
-            "  x           - Execute deletions".to_string(),
-            "  r/F5        - Refresh buffer list".to_string(),
+            "  d           - Mark buffer for closing".to_string(),
+            "  u           - Unmark buffer".to_string(),
+            "  X           - Close all marked buffers".to_string(),
+            "  o           - Cycle sort column (order/name/kind/items/memory)".to_string(),
+            "  O           - Reverse sort direction".to_string(),
             "  q           - Close this buffer".to_string(),
             "".to_string(),
             "Buffer symbols:".to_string(),
             "  *           - Current buffer".to_string(),
+            "  D           - Marked for closing".to_string(),
+            "".to_string(),
+            "The selected buffer is previewed on the right when the".to_string(),
+            "terminal is wide enough.".to_string(),
         ]
     }
 }
@@ -167,6 +295,32 @@ impl UIComponent for BufferListBuffer {
                     UIAction::ShowMessage("No buffer selected".to_string())
                 }
             }
+            UIAction::DeletePodcast => {
+                self.set_selected_mark(true);
+                UIAction::Render
+            }
+            UIAction::MarkUnplayed => {
+                self.set_selected_mark(false);
+                UIAction::Render
+            }
+            UIAction::DeleteDownloadedEpisode => {
+                let marked = self.marked_buffer_ids();
+                if marked.is_empty() {
+                    UIAction::ShowMessage("No buffers marked (press d to mark)".to_string())
+                } else {
+                    UIAction::CloseBuffers(marked)
+                }
+            }
+            UIAction::CycleSortField => {
+                self.sort_field = self.sort_field.next();
+                self.apply_sort();
+                UIAction::Render
+            }
+            UIAction::ToggleSortDirection => {
+                self.sort_descending = !self.sort_descending;
+                self.apply_sort();
+                UIAction::Render
+            }
             UIAction::Refresh => UIAction::ShowMessage("Buffer list refreshed".to_string()),
             UIAction::Quit => UIAction::CloseBuffer(self.id()),
             _ => UIAction::None,
@@ -174,43 +328,106 @@ impl UIComponent for BufferListBuffer {
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let items: Vec<ListItem> = self
-            .buffer_items
+        let header = Row::new(vec![
+            Cell::from(""),
+            Cell::from("Buffer"),
+            Cell::from("Kind"),
+            Cell::from("Podcast"),
+            Cell::from("Items"),
+            Cell::from("Memory"),
+        ])
+        .style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let rows: Vec<Row> = self
+            .entries
             .iter()
-            .map(|(id, name, is_current)| {
-                let marker = if *is_current { "*" } else { " " };
-                let display_text = if id != name {
-                    format!("{} {} ({})", marker, name, id)
+            .map(|entry| {
+                let mark = if self.marked.contains(&entry.id) {
+                    "D"
+                } else {
+                    " "
+                };
+                let current = if entry.is_current { "*" } else { " " };
+                let name = if entry.id != entry.name {
+                    format!("{} ({})", entry.name, entry.id)
                 } else {
-                    format!("{} {}", marker, name)
+                    entry.name.clone()
                 };
 
-                let style = if *is_current {
+                let style = if self.marked.contains(&entry.id) {
+                    self.theme.error_style()
+                } else if entry.is_current {
                     self.theme.active_indicator_style()
                 } else {
                     Style::default()
                 };
 
-                ListItem::new(display_text).style(style)
+                Row::new(vec![
+                    Cell::from(format!("{}{}", mark, current)),
+                    Cell::from(name),
+                    Cell::from(entry.info.kind),
+                    Cell::from(entry.info.podcast.clone().unwrap_or_default()),
+                    Cell::from(
+                        entry
+                            .info
+                            .item_count
+                            .map(|n| n.to_string())
+                            .unwrap_or_default(),
+                    ),
+                    Cell::from(if entry.info.memory_bytes > 0 {
+                        format_file_size(entry.info.memory_bytes as u64)
+                    } else {
+                        String::new()
+                    }),
+                ])
+                .style(style)
             })
             .collect();
 
-        let list = List::new(items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Buffer List")
-                    .title_style(self.theme.title_style())
-                    .border_style(if self.focused {
-                        self.theme.border_focused_style()
-                    } else {
-                        self.theme.border_style()
-                    }),
+        let direction = if self.sort_descending { "↓" } else { "↑" };
+        let title = if self.marked.is_empty() {
+            format!("Buffer List [{} {}]", self.sort_field.label(), direction)
+        } else {
+            format!(
+                "Buffer List [{} {}] ({} marked, X to close)",
+                self.sort_field.label(),
+                direction,
+                self.marked.len()
             )
-            .style(self.theme.text_style())
-            .highlight_style(self.theme.selected_style());
+        };
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(2),      // Mark + current
+                Constraint::Percentage(35), // Buffer
+                Constraint::Length(11),     // Kind
+                Constraint::Percentage(25), // Podcast
+                Constraint::Length(6),      // Items
+                Constraint::Length(9),      // Memory
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(self.theme.title_style())
+                .border_style(if self.focused {
+                    self.theme.border_focused_style()
+                } else {
+                    self.theme.border_style()
+                }),
+        )
+        .style(self.theme.text_style())
+        .row_highlight_style(self.theme.selected_style())
+        .column_spacing(1);
 
-        frame.render_stateful_widget(list, area, &mut self.list_state);
+        frame.render_stateful_widget(table, area, &mut self.table_state);
     }
 
     fn title(&self) -> String {
@@ -231,3 +448,126 @@ impl Default for BufferListBuffer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(kind: &'static str, items: usize, memory: usize) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(items),
+            memory_bytes: memory,
+            ..BufferInfo::new(kind)
+        }
+    }
+
+    fn make_list() -> BufferListBuffer {
+        let mut list = BufferListBuffer::new();
+        list.update_buffer_list(
+            vec![
+                (
+                    "podcast-list".to_string(),
+                    "Podcasts".to_string(),
+                    info("Podcasts", 12, 4000),
+                ),
+                (
+                    "episodes-a".to_string(),
+                    "Episodes: A".to_string(),
+                    info("Episodes", 300, 90_000),
+                ),
+                (
+                    "*Buffer List*".to_string(),
+                    "*Buffer List*".to_string(),
+                    BufferInfo::new("Buffer List"),
+                ),
+                (
+                    "downloads".to_string(),
+                    "Downloads".to_string(),
+                    info("Downloads", 3, 500),
+                ),
+            ],
+            Some(&"podcast-list".to_string()),
+        );
+        list
+    }
+
+    #[test]
+    fn test_update_excludes_itself() {
+        let list = make_list();
+        assert_eq!(list.entries.len(), 3);
+        assert!(list.entries.iter().all(|e| e.id != "*Buffer List*"));
+    }
+
+    #[test]
+    fn test_sort_cycles_and_keeps_selection() {
+        // Arrange
+        let mut list = make_list();
+        list.select_next(); // episodes-a
+
+        // Act — order → name
+        list.handle_action(UIAction::CycleSortField);
+
+        // Assert
+        let names: Vec<_> = list.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Downloads", "Episodes: A", "Podcasts"]);
+        assert_eq!(list.selected_buffer_id(), Some(&"episodes-a".to_string()));
+
+        // Act — name → kind → items, descending
+        list.handle_action(UIAction::CycleSortField);
+        list.handle_action(UIAction::CycleSortField);
+        list.handle_action(UIAction::ToggleSortDirection);
+
+        // Assert
+        assert_eq!(list.sort_field, BufferListSortField::Items);
+        assert_eq!(list.entries[0].id, "episodes-a");
+    }
+
+    #[test]
+    fn test_mark_and_bulk_close() {
+        // Arrange
+        let mut list = make_list();
+
+        // Act — mark the first two rows (d advances the cursor)
+        list.handle_action(UIAction::DeletePodcast);
+        list.handle_action(UIAction::DeletePodcast);
+        let action = list.handle_action(UIAction::DeleteDownloadedEpisode);
+
+        // Assert
+        assert_eq!(
+            action,
+            UIAction::CloseBuffers(vec!["podcast-list".to_string(), "episodes-a".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_unmark_and_close_with_nothing_marked() {
+        let mut list = make_list();
+        list.handle_action(UIAction::DeletePodcast);
+        list.select_previous();
+        list.handle_action(UIAction::MarkUnplayed);
+
+        let action = list.handle_action(UIAction::DeleteDownloadedEpisode);
+        assert!(matches!(action, UIAction::ShowMessage(_)));
+    }
+
+    #[test]
+    fn test_update_drops_marks_for_closed_buffers() {
+        // Arrange
+        let mut list = make_list();
+        list.handle_action(UIAction::DeletePodcast);
+
+        // Act — podcast-list no longer open
+        list.update_buffer_list(
+            vec![(
+                "downloads".to_string(),
+                "Downloads".to_string(),
+                info("Downloads", 3, 500),
+            )],
+            None,
+        );
+
+        // Assert
+        assert!(list.marked_buffer_ids().is_empty());
+        assert_eq!(list.selected_buffer_id(), Some(&"downloads".to_string()));
+    }
+}
@@ -0,0 +1,139 @@
+// Buffer capabilities - episode actions routed by what a buffer can do
+//
+// Downloading, playing and refreshing used to be handled by one `handle_action`
+// arm per action in the app, each forwarding to the current buffer and matching
+// on what came back. Buffers now declare these capabilities through
+// `Buffer::as_downloadable` / `as_playable` / `as_refreshable`, and `dispatch`
+// routes the action to the capability. A new capability action needs a trait
+// method (with a default where most buffers would refuse it) and a line in
+// `dispatch`; buffers without the capability are left untouched. The app
+// asks a buffer for its selected and shown episodes through
+// `Buffer::as_episode_selection` rather than knowing which buffers list episodes.
+
+use super::Buffer;
+use crate::podcast::Episode;
+use crate::storage::{EpisodeId, PodcastId};
+use crate::ui::UIAction;
+
+/// Buffers whose selection can be downloaded or have its download removed
+pub trait Downloadable {
+    /// Download the selected episode: a `TriggerDownload`, or a message why not
+    fn download_selected(&mut self) -> UIAction {
+        UIAction::ShowMessage("Nothing to download in this buffer".to_string())
+    }
+
+    /// Delete the selected episode's download: a `TriggerDeleteDownload`, or a
+    /// message why not
+    fn delete_selected_download(&mut self) -> UIAction {
+        UIAction::ShowMessage("Downloads can't be deleted from this buffer".to_string())
+    }
+}
+
+/// Buffers whose selection can be played and have its play state changed
+pub trait Playable {
+    /// Play the selected episode: a `PlayEpisode` or `PlayPlaylist`
+    fn play_selected(&mut self) -> UIAction;
+
+    /// Mark the selected episode played (or unplayed): a `TriggerMarkPlayed` /
+    /// `TriggerMarkUnplayed`
+    fn mark_selected_played(&mut self, _played: bool) -> UIAction {
+        UIAction::ShowMessage("Episodes can't be marked played in this buffer".to_string())
+    }
+
+    /// Star or unstar the selected episode: a `TriggerToggleFavorite`
+    fn toggle_selected_favorite(&mut self) -> UIAction {
+        UIAction::ShowMessage("Episodes can't be favorited in this buffer".to_string())
+    }
+}
+
+/// Buffers with an episode under the cursor (or on show)
+pub trait EpisodeSelection {
+    /// The selected episode and its podcast, for buffers holding the episode
+    /// itself; search results only know it by ID
+    fn selected_episode_entry(&self) -> Option<(&PodcastId, &Episode)> {
+        None
+    }
+
+    /// IDs of the selected episode and its podcast
+    fn selected_episode_ids(&self) -> Option<(PodcastId, EpisodeId)> {
+        let (podcast_id, episode) = self.selected_episode_entry()?;
+        Some((podcast_id.clone(), episode.id.clone()))
+    }
+
+    /// Every episode the buffer lists, in display order: what filters and
+    /// search left visible. `None` for buffers showing a single episode.
+    fn shown_episodes(&self) -> Option<Vec<(PodcastId, EpisodeId)>> {
+        None
+    }
+}
+
+/// Buffers that can reload their contents
+pub trait Refreshable {
+    /// What to reload, usually a `RefreshBuffer`
+    fn refresh(&mut self) -> UIAction;
+}
+
+/// Route a capability action to `buffer`. `None` when `action` isn't a
+/// capability action or the buffer lacks the capability; the caller then
+/// hands it to `Buffer::handle_action` as before.
+pub fn dispatch(buffer: &mut dyn Buffer, action: &UIAction) -> Option<UIAction> {
+    match action {
+        UIAction::DownloadEpisode => Some(buffer.as_downloadable()?.download_selected()),
+        UIAction::DeleteDownloadedEpisode => {
+            Some(buffer.as_downloadable()?.delete_selected_download())
+        }
+        UIAction::PlayEpisode { .. } => Some(buffer.as_playable()?.play_selected()),
+        UIAction::MarkPlayed => Some(buffer.as_playable()?.mark_selected_played(true)),
+        UIAction::MarkUnplayed => Some(buffer.as_playable()?.mark_selected_played(false)),
+        UIAction::ToggleFavorite => Some(buffer.as_playable()?.toggle_selected_favorite()),
+        UIAction::Refresh => Some(buffer.as_refreshable()?.refresh()),
+        _ => None,
+    }
+}
+
+/// Whether `dispatch` routes `action`
+pub fn is_capability_action(action: &UIAction) -> bool {
+    matches!(
+        action,
+        UIAction::DownloadEpisode
+            | UIAction::DeleteDownloadedEpisode
+            | UIAction::PlayEpisode { .. }
+            | UIAction::MarkPlayed
+            | UIAction::MarkUnplayed
+            | UIAction::ToggleFavorite
+            | UIAction::Refresh
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::buffers::downloads::DownloadsBuffer;
+    use crate::ui::buffers::help::HelpBuffer;
+    use crate::ui::events::BufferRefreshType;
+
+    #[test]
+    fn test_dispatch_routes_to_capabilities_and_skips_buffers_without_them() {
+        // Arrange
+        let mut downloads = DownloadsBuffer::new();
+        let mut help = HelpBuffer::new();
+
+        // Act & Assert — the downloads buffer refreshes and refuses to download
+        assert_eq!(
+            dispatch(&mut downloads, &UIAction::Refresh),
+            Some(UIAction::RefreshBuffer(BufferRefreshType::Downloads))
+        );
+        assert_eq!(
+            dispatch(&mut downloads, &UIAction::DownloadEpisode),
+            Some(UIAction::ShowMessage(
+                "Nothing to download in this buffer".to_string()
+            ))
+        );
+        // It can't play, so the action goes to its `handle_action`
+        assert_eq!(dispatch(&mut downloads, &UIAction::MarkPlayed), None);
+        assert_eq!(dispatch(&mut help, &UIAction::Refresh), None);
+        assert_eq!(dispatch(&mut downloads, &UIAction::MoveUp), None);
+        assert!(is_capability_action(&UIAction::ToggleFavorite));
+        assert!(!is_capability_action(&UIAction::MoveUp));
+    }
+}
@@ -0,0 +1,231 @@
+// Cast picker - choose a network renderer to play through
+//
+// Opened by `:cast` once discovery has finished. The first row is always
+// this computer, so casting can be stopped from the same list.
+
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use crate::audio::cast::CastTarget;
+use crate::ui::{
+    buffers::{Buffer, BufferId},
+    themes::Theme,
+    UIAction, UIComponent,
+};
+
+const LOCAL_LABEL: &str = "This computer";
+
+pub struct CastPickerBuffer {
+    id: String,
+    focused: bool,
+    theme: Theme,
+    /// `None` is this computer, always the first entry
+    targets: Vec<Option<CastTarget>>,
+    /// Label of the renderer in use (`None` = this computer)
+    current: Option<String>,
+    selected_index: usize,
+}
+
+impl CastPickerBuffer {
+    /// Build the picker with the cursor on the output currently in use
+    pub fn new(targets: Vec<CastTarget>, current: Option<String>) -> Self {
+        let targets: Vec<Option<CastTarget>> = std::iter::once(None)
+            .chain(targets.into_iter().map(Some))
+            .collect();
+        let selected_index = targets
+            .iter()
+            .position(|t| t.as_ref().map(CastTarget::label) == current)
+            .unwrap_or(0);
+
+        Self {
+            id: "cast-picker".to_string(),
+            focused: false,
+            theme: Theme::default(),
+            targets,
+            current,
+            selected_index,
+        }
+    }
+
+    /// Renderer under the cursor (`None` = this computer)
+    pub fn selected_target(&self) -> Option<&CastTarget> {
+        self.targets
+            .get(self.selected_index)
+            .and_then(|t| t.as_ref())
+    }
+
+    fn select_previous(&mut self) {
+        self.selected_index = match self.selected_index {
+            0 => self.targets.len() - 1,
+            index => index - 1,
+        };
+    }
+
+    fn select_next(&mut self) {
+        self.selected_index = (self.selected_index + 1) % self.targets.len();
+    }
+}
+
+impl Buffer for CastPickerBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Cast".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Cast Commands:".to_string(),
+            "  ↑/↓      Navigate targets".to_string(),
+            "  Enter    Play through the selected target".to_string(),
+            "  Esc      Cancel".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for CastPickerBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::SelectItem => UIAction::SelectCastTarget {
+                target: self.selected_target().cloned(),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let items: Vec<ListItem> = self
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(index, target)| {
+                let selected = index == self.selected_index;
+                let marker = if selected { "► " } else { "  " };
+                let label = target.as_ref().map(CastTarget::label);
+                let name = label.as_deref().unwrap_or(LOCAL_LABEL);
+                let in_use = if label == self.current {
+                    " (in use)"
+                } else {
+                    ""
+                };
+                let text = format!("{marker}{name}{in_use}");
+                if selected {
+                    ListItem::new(text).style(self.theme.selected_style())
+                } else {
+                    ListItem::new(text).style(self.theme.text_style())
+                }
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Cast To")
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title_style(self.theme.title_style()),
+        );
+        frame.render_widget(list, area);
+    }
+
+    fn title(&self) -> String {
+        "Cast To".to_string()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::cast::{CastEndpoint, CastKind};
+
+    fn targets() -> Vec<CastTarget> {
+        vec![
+            CastTarget {
+                name: "Kitchen".to_string(),
+                kind: CastKind::Sonos,
+                address: [192, 168, 1, 20].into(),
+                endpoint: CastEndpoint::Upnp {
+                    av_transport: "http://192.168.1.20:1400/AVTransport/Control".to_string(),
+                    rendering_control: None,
+                },
+            },
+            CastTarget {
+                name: "Bedroom".to_string(),
+                kind: CastKind::Chromecast,
+                address: [192, 168, 1, 30].into(),
+                endpoint: CastEndpoint::Chromecast {
+                    addr: "192.168.1.30:8009".parse().unwrap(),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_cursor_starts_on_target_in_use() {
+        let picker = CastPickerBuffer::new(targets(), Some("Bedroom (Chromecast)".to_string()));
+        assert_eq!(
+            picker.selected_target().map(|t| t.name.as_str()),
+            Some("Bedroom")
+        );
+
+        let picker = CastPickerBuffer::new(targets(), None);
+        assert!(
+            picker.selected_target().is_none(),
+            "this computer is the first row"
+        );
+    }
+
+    #[test]
+    fn test_select_item_emits_chosen_target() {
+        // Arrange
+        let mut picker = CastPickerBuffer::new(targets(), None);
+
+        // Act
+        picker.handle_action(UIAction::MoveDown);
+        let action = picker.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert!(matches!(
+            action,
+            UIAction::SelectCastTarget { target: Some(ref t) } if t.name == "Kitchen"
+        ));
+    }
+}
@@ -0,0 +1,336 @@
+// Cleanup preview buffer - what the retention rules would delete
+//
+// Opened with `:cleanup-preview`. A dry run of the cleanup that runs on
+// startup and after each refresh: the downloads it would remove, grouped by
+// the rule that removes them, with the space they would free. Nothing is
+// deleted from here; `:cleanup-now` applies the plan.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    download::{CleanupPlan, CleanupReason},
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+    utils::fs::format_file_size,
+};
+
+/// Buffer for displaying a cleanup dry run
+pub struct CleanupPreviewBuffer {
+    id: String,
+    plan: CleanupPlan,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl CleanupPreviewBuffer {
+    pub fn new(plan: CleanupPlan) -> Self {
+        Self {
+            id: "cleanup-preview".to_string(),
+            plan,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    fn heading(text: String) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                text,
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from("─".repeat(60)),
+        ]
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'static>> {
+        let plan = &self.plan;
+        let in_use = match plan.quota {
+            Some(quota) => format!(
+                "{} of {} in use",
+                format_file_size(plan.used),
+                format_file_size(quota)
+            ),
+            None => format!("{} in use", format_file_size(plan.used)),
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    "Would delete: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(plan.summary()),
+                Span::styled(format!("  ({})", in_use), self.theme.muted_style()),
+            ]),
+            Line::from(vec![Span::styled(
+                "Dry run — nothing has been deleted (:cleanup-now applies it)",
+                self.theme.muted_style(),
+            )]),
+        ];
+
+        if plan.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Every download is within the retention rules."));
+            return lines;
+        }
+
+        // Group by the rule, in the order the rules first appear
+        let mut reasons: Vec<CleanupReason> = Vec::new();
+        for item in &plan.items {
+            if !reasons.contains(&item.reason) {
+                reasons.push(item.reason);
+            }
+        }
+        for reason in reasons {
+            let items: Vec<_> = plan
+                .items
+                .iter()
+                .filter(|item| item.reason == reason)
+                .collect();
+            lines.extend(Self::heading(format!(
+                "{} ({}):",
+                capitalize(&reason.label()),
+                items.len()
+            )));
+            for item in items {
+                lines.push(Line::from(vec![
+                    Span::styled("- ", self.theme.error_style()),
+                    Span::raw(format!(
+                        "{} — {}",
+                        item.download.podcast_title, item.download.title
+                    )),
+                    Span::styled(
+                        format!("  {}", format_file_size(item.download.size)),
+                        self.theme.muted_style(),
+                    ),
+                ]));
+            }
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl Buffer for CleanupPreviewBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Cleanup Preview".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(self.plan.items.len()),
+            ..BufferInfo::new("Cleanup Preview")
+        }
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Cleanup Preview Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Page Down Page down".to_string(),
+            "  Page Up   Page up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for CleanupPreviewBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::PageUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+                UIAction::Render
+            }
+            UIAction::PageDown => {
+                self.scroll_offset = (self.scroll_offset + 10).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::download::retention::{CleanupItem, RetainedDownload};
+    use crate::storage::{EpisodeId, PodcastId};
+
+    fn content_text(buffer: &CleanupPreviewBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn item(title: &str, reason: CleanupReason) -> CleanupItem {
+        CleanupItem {
+            download: RetainedDownload {
+                podcast_id: PodcastId::new(),
+                episode_id: EpisodeId::new(),
+                podcast_title: "Show".to_string(),
+                title: title.to_string(),
+                published: chrono::Utc::now(),
+                played_at: None,
+                played: false,
+                favorited: false,
+                size: 1024,
+                modified: std::time::SystemTime::now(),
+                file: None,
+                links: 1,
+            },
+            reason,
+        }
+    }
+
+    #[test]
+    fn test_items_are_grouped_by_rule() {
+        // Arrange
+        let plan = CleanupPlan {
+            items: vec![
+                item("Ep 1", CleanupReason::KeepLatest(5)),
+                item("Ep 2", CleanupReason::PlayedDaysAgo(7)),
+                item("Ep 3", CleanupReason::KeepLatest(5)),
+            ],
+            used: 4096,
+            quota: None,
+        };
+
+        // Act
+        let lines = content_text(&CleanupPreviewBuffer::new(plan));
+
+        // Assert
+        let keep = lines
+            .iter()
+            .position(|l| l == "Beyond the newest 5 (2):")
+            .unwrap();
+        let played = lines
+            .iter()
+            .position(|l| l == "Played over 7 days ago (1):")
+            .unwrap();
+        assert!(keep < played);
+        assert_eq!(lines[keep + 2], "- Show — Ep 1  1.0 KB");
+        assert_eq!(lines[keep + 3], "- Show — Ep 3  1.0 KB");
+        assert!(lines[0].starts_with("Would delete: 3 download(s)"));
+    }
+
+    #[test]
+    fn test_empty_plan_says_nothing_would_go() {
+        let lines = content_text(&CleanupPreviewBuffer::new(CleanupPlan::default()));
+        assert!(lines.contains(&"Every download is within the retention rules.".to_string()));
+    }
+}
@@ -0,0 +1,206 @@
+// Config report buffer - problems found in the config file
+//
+// Opened at startup when the config file had problems, and with
+// `:config-report`. Lists each one by dotted key: unknown keys (with the
+// closest known one), values of the wrong type, invalid key notations and
+// out-of-range numbers. The rest of the file applies as written.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    config::ConfigIssue,
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Buffer listing the problems found in the config file
+pub struct ConfigReportBuffer {
+    id: String,
+    issues: Vec<ConfigIssue>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl ConfigReportBuffer {
+    pub fn new(issues: Vec<ConfigIssue>) -> Self {
+        Self {
+            id: "config-report".to_string(),
+            issues,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'_>> {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(format!(
+                "{} problem(s) in the config file. Everything else in it applies;",
+                self.issues.len()
+            )),
+            Line::from("the keys below keep their defaults unless noted."),
+            Line::from(""),
+        ];
+        for issue in &self.issues {
+            lines.push(Line::from(vec![
+                Span::styled(issue.key.as_str(), bold),
+                Span::raw(format!("  {}", issue.message)),
+            ]));
+        }
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+impl Buffer for ConfigReportBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Config Report".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::new("Config")
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Config Report Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for ConfigReportBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_lists_each_problem_by_key() {
+        let buffer = ConfigReportBuffer::new(vec![ConfigIssue {
+            key: "audio.volum".to_string(),
+            message: "unknown key (did you mean 'volume'?); ignored".to_string(),
+        }]);
+
+        let lines: Vec<String> = buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        assert!(lines[0].starts_with("1 problem(s) in the config file"));
+        assert!(lines
+            .contains(&"audio.volum  unknown key (did you mean 'volume'?); ignored".to_string()));
+    }
+}
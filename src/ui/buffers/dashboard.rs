@@ -0,0 +1,309 @@
+// Dashboard buffer - listening statistics and goal progress
+//
+// Opened with `:dashboard`. Shows this week's listening figures from the
+// listening log and a progress bar per `goals.targets` entry; goals behind
+// their pace are highlighted. The app fills it in from a background
+// evaluation and refreshes it as episodes are finished.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    podcast::{GoalProgress, ListeningStats},
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Cells in a goal's progress bar
+const BAR_WIDTH: usize = 20;
+
+/// Buffer showing listening statistics and goals
+pub struct DashboardBuffer {
+    id: String,
+    /// `None` until the first evaluation arrives
+    stats: Option<ListeningStats>,
+    goals: Vec<GoalProgress>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl Default for DashboardBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DashboardBuffer {
+    pub fn new() -> Self {
+        Self {
+            id: "dashboard".to_string(),
+            stats: None,
+            goals: Vec::new(),
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn set_progress(&mut self, stats: ListeningStats, goals: Vec<GoalProgress>) {
+        self.stats = Some(stats);
+        self.goals = goals;
+    }
+
+    fn bar(fraction: f64) -> String {
+        let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+        format!(
+            "{}{} {:>3}%",
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH.saturating_sub(filled)),
+            (fraction * 100.0).round() as u32
+        )
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'static>> {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let Some(stats) = self.stats else {
+            return vec![Line::from("Loading listening statistics...")];
+        };
+
+        let minutes = stats.listened_seconds / 60;
+        let mut lines = vec![
+            Line::from(Span::styled("This week", bold)),
+            Line::from("─".repeat(60)),
+            Line::from(format!(
+                "Listened:  {} h {:02} m",
+                minutes / 60,
+                minutes % 60
+            )),
+            Line::from(format!("Finished:  {} episode(s)", stats.episodes_finished)),
+            Line::from(format!("Unplayed:  {} episode(s)", stats.unplayed)),
+            Line::from(""),
+            Line::from(Span::styled("Goals", bold)),
+            Line::from("─".repeat(60)),
+        ];
+
+        if self.goals.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No goals set. Add some under goals.targets in config.json, e.g.",
+                self.theme.muted_style(),
+            )));
+            lines.push(Line::from(Span::styled(
+                r#"  {"kind": "listen_hours", "target": 5, "period": "week"}"#,
+                self.theme.muted_style(),
+            )));
+            return lines;
+        }
+
+        for goal in &self.goals {
+            let (state, style) = if goal.is_met() {
+                ("done", self.theme.success_style())
+            } else if goal.is_behind() {
+                ("behind", self.theme.warning_style())
+            } else {
+                ("on track", self.theme.muted_style())
+            };
+            lines.push(Line::from(vec![
+                Span::styled(goal.label.clone(), bold),
+                Span::styled(format!("  ({})", state), style),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", Self::bar(goal.fraction())), style),
+                Span::raw(format!("  {}", goal.summary())),
+                Span::styled(
+                    format!("  {}", goal.remaining_label()),
+                    self.theme.muted_style(),
+                ),
+            ]));
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+impl Buffer for DashboardBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Dashboard".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(self.goals.len()),
+            ..BufferInfo::new("Goals")
+        }
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Dashboard Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  :dashboard  Recompute statistics".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for DashboardBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_text(buffer: &DashboardBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_goals_render_with_bar_and_pace() {
+        // Arrange
+        let mut buffer = DashboardBuffer::new();
+        let goal = GoalProgress {
+            label: "Listen 5 h per week".to_string(),
+            done: 1.5,
+            target: 5.0,
+            unit: "h",
+            elapsed: 0.6,
+            remaining: chrono::Duration::days(3),
+        };
+
+        // Act
+        buffer.set_progress(
+            ListeningStats {
+                listened_seconds: 5400,
+                episodes_finished: 2,
+                unplayed: 40,
+            },
+            vec![goal],
+        );
+        let lines = content_text(&buffer);
+
+        // Assert
+        assert!(lines.contains(&"Listened:  1 h 30 m".to_string()));
+        assert!(lines.contains(&"Listen 5 h per week  (behind)".to_string()));
+        assert!(lines
+            .iter()
+            .any(|l| l.contains("██████░") && l.contains(" 30%")));
+        assert!(lines.iter().any(|l| l.contains("1.5 / 5 h  3 days left")));
+    }
+
+    #[test]
+    fn test_loading_and_no_goals_states() {
+        let mut buffer = DashboardBuffer::new();
+        assert_eq!(content_text(&buffer), ["Loading listening statistics..."]);
+
+        buffer.set_progress(ListeningStats::default(), Vec::new());
+        assert!(content_text(&buffer)
+            .iter()
+            .any(|l| l.starts_with("No goals set")));
+    }
+}
@@ -21,7 +21,7 @@ use ratatui::{
 use crate::{
     podcast::PodcastSearchResult,
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{Buffer, BufferId, BufferInfo},
         themes::Theme,
         UIAction, UIComponent,
     },
@@ -361,6 +361,12 @@ impl Buffer for DiscoveryBuffer {
         self.theme = theme;
     }
 
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Discovery", &self.results, |r| {
+            r.title.len() + r.author.len() + r.feed_url.len() + r.description.len()
+        })
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "DISCOVERY BUFFER".to_string(),
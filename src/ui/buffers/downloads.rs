@@ -2,24 +2,41 @@
 //
 // This buffer provides a centralized view of all episode downloads,
 // their progress, and management options like canceling or retrying.
+// Running downloads show their speed; a graph of the selected one's recent
+// speed, its average and peak, and the combined speed of all active
+// downloads sit under the list.
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Sparkline},
     Frame,
 };
 
 use crate::{
-    download::{DownloadManager, DownloadStatus},
+    download::{
+        throughput::format_speed, DownloadManager, DownloadStatus, SpeedHistory, StorageUsage,
+    },
+    podcast::DownloadState,
     storage::{EpisodeId, JsonStorage, PodcastId, Storage},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{
+            capabilities::{Downloadable, Refreshable},
+            Buffer, BufferId, BufferInfo,
+        },
+        components::ScrollPosition,
+        events::BufferRefreshType,
+        glyphs::StatusGlyph,
+        text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
+    utils::fs::format_file_size,
 };
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 /// Download entry for tracking downloads
 #[derive(Debug, Clone)]
@@ -40,9 +57,14 @@ pub struct DownloadsBuffer {
     selected_index: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     download_manager: Option<Arc<DownloadManager<JsonStorage>>>,
     storage: Option<Arc<JsonStorage>>,
+    storage_usage: StorageUsage,
+    /// Speed samples of this session's downloads, kept after they finish
+    speeds: HashMap<EpisodeId, SpeedHistory>,
 }
 
 impl Default for DownloadsBuffer {
@@ -60,9 +82,12 @@ impl DownloadsBuffer {
             selected_index: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             download_manager: None,
             storage: None,
+            storage_usage: StorageUsage::default(),
+            speeds: HashMap::new(),
         }
     }
 
@@ -87,35 +112,33 @@ impl DownloadsBuffer {
                     for podcast_id in podcast_ids {
                         // Load the podcast to get its name
                         if let Ok(podcast) = storage.load_podcast(&podcast_id).await {
+                            if podcast.is_removed() {
+                                continue;
+                            }
                             match storage.load_episodes(&podcast_id).await {
                                 Ok(episodes) => {
                                     for episode in episodes {
-                                        if matches!(
-                                            episode.status,
-                                            crate::podcast::EpisodeStatus::Downloading
-                                                | crate::podcast::EpisodeStatus::Downloaded
-                                                | crate::podcast::EpisodeStatus::DownloadFailed
-                                        ) {
-                                            let status = match episode.status {
-                                                crate::podcast::EpisodeStatus::Downloading => {
+                                        if episode.status.download != DownloadState::NotDownloaded {
+                                            let status = match episode.status.download {
+                                                DownloadState::Downloading => {
                                                     DownloadStatus::InProgress
                                                 }
-                                                crate::podcast::EpisodeStatus::Downloaded => {
+                                                DownloadState::Downloaded => {
                                                     DownloadStatus::Completed
                                                 }
-                                                crate::podcast::EpisodeStatus::DownloadFailed => {
-                                                    DownloadStatus::Failed(
-                                                        "Download failed".to_string(),
-                                                    )
+                                                DownloadState::Failed => DownloadStatus::Failed(
+                                                    "Download failed".to_string(),
+                                                ),
+                                                DownloadState::NotDownloaded => {
+                                                    DownloadStatus::Queued
                                                 }
-                                                _ => DownloadStatus::Queued,
                                             };
 
                                             let entry = DownloadEntry {
                                                 podcast_id: podcast.id.clone(),
                                                 episode_id: episode.id.clone(),
                                                 podcast_name: podcast.title.clone(),
-                                                episode_title: episode.title.clone(),
+                                                episode_title: episode.display_title().to_string(),
                                                 status,
                                                 progress: episode.file_size.map(|size| {
                                                     if episode.is_downloaded() {
@@ -157,21 +180,92 @@ impl DownloadsBuffer {
         self.selected_index.and_then(|i| self.downloads.get(i))
     }
 
+    /// Update the progress of a running download (bytes downloaded, total if known)
+    pub fn set_progress(&mut self, episode_id: &EpisodeId, downloaded: u64, total: Option<u64>) {
+        if let Some(entry) = self
+            .downloads
+            .iter_mut()
+            .find(|entry| &entry.episode_id == episode_id)
+        {
+            // A total of 0 means unknown
+            let total = total
+                .or(entry.progress.map(|(_, total)| total))
+                .unwrap_or(0);
+            entry.progress = Some((downloaded, total));
+        }
+        self.speeds
+            .entry(episode_id.clone())
+            .or_default()
+            .record(downloaded, Instant::now());
+    }
+
+    /// Current speed of a running download
+    fn current_speed(&self, download: &DownloadEntry) -> Option<u64> {
+        match download.status {
+            DownloadStatus::InProgress => self.speeds.get(&download.episode_id)?.current(),
+            _ => None,
+        }
+    }
+
+    /// Combined current speed of all running downloads
+    fn aggregate_speed(&self) -> u64 {
+        self.downloads
+            .iter()
+            .filter_map(|download| self.current_speed(download))
+            .sum()
+    }
+
+    /// Set space used against the storage quota (for the quota gauge)
+    pub fn set_storage_usage(&mut self, usage: StorageUsage) {
+        self.storage_usage = usage;
+    }
+
+    /// Quota gauge label, e.g. "14.2 GB of 20.0 GB (71%)"
+    fn storage_label(&self) -> Option<String> {
+        let quota = self.storage_usage.quota?;
+        let ratio = self.storage_usage.ratio().unwrap_or(0.0);
+        Some(format!(
+            "{} of {} ({:.0}%)",
+            format_file_size(self.storage_usage.used),
+            format_file_size(quota),
+            ratio * 100.0
+        ))
+    }
+
     /// Set downloads data directly (for background refresh)
     pub fn set_downloads(&mut self, downloads: Vec<crate::ui::events::DownloadEntry>) {
+        // Live progress of running downloads isn't in storage; carry it over
+        let live_progress: std::collections::HashMap<EpisodeId, (u64, u64)> = self
+            .downloads
+            .iter()
+            .filter(|entry| matches!(entry.status, DownloadStatus::InProgress))
+            .filter_map(|entry| Some((entry.episode_id.clone(), entry.progress?)))
+            .collect();
+
         // Convert from events::DownloadEntry to downloads::DownloadEntry
         self.downloads = downloads
             .into_iter()
             .map(|entry| DownloadEntry {
+                progress: match entry.status {
+                    DownloadStatus::InProgress => live_progress.get(&entry.episode_id).copied(),
+                    _ => None,
+                }
+                .or(entry.file_size.map(|size| (size, size))), // Assume completed downloads are full size
                 podcast_id: entry.podcast_id,
                 episode_id: entry.episode_id,
                 podcast_name: entry.podcast_name,
                 episode_title: entry.episode_title,
                 status: entry.status,
-                progress: entry.file_size.map(|size| (size, size)), // Assume completed downloads are full size
                 error_message: None,
             })
             .collect();
+        let listed: std::collections::HashSet<&EpisodeId> = self
+            .downloads
+            .iter()
+            .map(|entry| &entry.episode_id)
+            .collect();
+        self.speeds
+            .retain(|episode_id, _| listed.contains(episode_id));
 
         // Set selection if we have downloads
         if !self.downloads.is_empty() && self.selected_index.is_none() {
@@ -231,6 +325,9 @@ impl DownloadsBuffer {
     /// Format file size for display
     fn format_progress(&self, progress: Option<(u64, u64)>) -> String {
         match progress {
+            Some((downloaded, 0)) if downloaded > 0 => {
+                format!("{:.1} MB", downloaded as f64 / 1024.0 / 1024.0)
+            }
             Some((downloaded, total)) => {
                 let downloaded_mb = downloaded as f64 / 1024.0 / 1024.0;
                 let total_mb = total as f64 / 1024.0 / 1024.0;
@@ -263,6 +360,23 @@ impl Buffer for DownloadsBuffer {
         self
     }
 
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        Some(self)
+    }
+
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
+    fn on_activate_async(&mut self) -> Option<BufferRefreshType> {
+        // Downloads finish and get cleaned up while the buffer is hidden
+        self.refresh_async()
+    }
+
+    fn refresh_async(&self) -> Option<BufferRefreshType> {
+        Some(BufferRefreshType::Downloads)
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
@@ -271,6 +385,16 @@ impl Buffer for DownloadsBuffer {
         true
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.downloads.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Downloads", &self.downloads, |d| {
+            d.podcast_name.len() + d.episode_title.len()
+        })
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Downloads Buffer Help".to_string(),
@@ -293,6 +417,31 @@ impl Buffer for DownloadsBuffer {
     }
 }
 
+impl Downloadable for DownloadsBuffer {
+    fn delete_selected_download(&mut self) -> UIAction {
+        if let Some(download) = self.selected_download() {
+            if matches!(download.status, DownloadStatus::Completed) {
+                UIAction::TriggerDeleteDownload {
+                    podcast_id: download.podcast_id.clone(),
+                    episode_id: download.episode_id.clone(),
+                    episode_title: download.episode_title.clone(),
+                }
+            } else {
+                UIAction::ShowMessage("Selected item is not a completed download".to_string())
+            }
+        } else {
+            UIAction::ShowMessage("No download selected".to_string())
+        }
+    }
+}
+
+impl Refreshable for DownloadsBuffer {
+    fn refresh(&mut self) -> UIAction {
+        self.refresh_async()
+            .map_or(UIAction::None, UIAction::RefreshBuffer)
+    }
+}
+
 impl UIComponent for DownloadsBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -331,23 +480,6 @@ impl UIComponent for DownloadsBuffer {
                 UIAction::Render
             }
             UIAction::Refresh => UIAction::TriggerRefreshDownloads,
-            UIAction::DeleteDownloadedEpisode => {
-                if let Some(download) = self.selected_download() {
-                    if matches!(download.status, DownloadStatus::Completed) {
-                        UIAction::TriggerDeleteDownload {
-                            podcast_id: download.podcast_id.clone(),
-                            episode_id: download.episode_id.clone(),
-                            episode_title: download.episode_title.clone(),
-                        }
-                    } else {
-                        UIAction::ShowMessage(
-                            "Selected item is not a completed download".to_string(),
-                        )
-                    }
-                } else {
-                    UIAction::ShowMessage("No download selected".to_string())
-                }
-            }
             UIAction::SelectItem => {
                 if let Some(download) = self.selected_download() {
                     UIAction::ShowMinibuffer(format!(
@@ -382,10 +514,26 @@ impl UIComponent for DownloadsBuffer {
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect) {
+        // The quota gauge only appears when a quota is configured
+        let storage_label = self.storage_label();
+        let gauge_height = if storage_label.is_some() { 3 } else { 0 };
+        // The speed graph appears once the selected download has samples
+        let speed = self
+            .selected_download()
+            .and_then(|download| self.speeds.get(&download.episode_id))
+            .filter(|history| !history.samples().is_empty())
+            .cloned();
+        let speed_height = if speed.is_some() { 4 } else { 0 };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(speed_height),
+                Constraint::Length(gauge_height),
+                Constraint::Length(3),
+            ])
             .split(area);
+        self.scroll_area = chunks[0];
 
         // Calculate visible height (subtract 2 for borders)
         let visible_height = chunks[0].height.saturating_sub(2) as usize;
@@ -403,23 +551,30 @@ impl UIComponent for DownloadsBuffer {
             .enumerate()
             .map(|(visible_i, download)| {
                 let actual_i = self.scroll_offset + visible_i;
-                let status_char = match download.status {
-                    DownloadStatus::Queued => "⏳",
-                    DownloadStatus::InProgress => "⬇️",
-                    DownloadStatus::Completed => "✅",
-                    DownloadStatus::Failed(_) => "❌",
-                };
+                let glyph = StatusGlyph::for_download(&download.status);
 
                 let progress_info = if let DownloadStatus::InProgress = download.status {
-                    format!(" [{}]", self.format_progress(download.progress))
+                    match self.current_speed(download) {
+                        Some(speed) => format!(
+                            " [{} · {}]",
+                            self.format_progress(download.progress),
+                            format_speed(speed)
+                        ),
+                        None => format!(" [{}]", self.format_progress(download.progress)),
+                    }
                 } else {
                     String::new()
                 };
 
-                let content = format!(
-                    "{} {} - {}{}",
-                    status_char, download.podcast_name, download.episode_title, progress_info
-                );
+                let content = Line::from(vec![
+                    Span::styled(self.theme.glyph(glyph), self.theme.glyph_style(glyph)),
+                    Span::raw(format!(
+                        " {} - {}{}",
+                        text_layout::single_line(&download.podcast_name),
+                        text_layout::single_line(&download.episode_title),
+                        progress_info
+                    )),
+                ]);
 
                 if Some(actual_i) == self.selected_index {
                     ListItem::new(content).style(self.theme.selected_style())
@@ -446,6 +601,55 @@ impl UIComponent for DownloadsBuffer {
 
         frame.render_widget(downloads_list, chunks[0]);
 
+        if let Some(history) = speed {
+            let block = Block::default().borders(Borders::ALL).title(format!(
+                "Speed · all active: {}",
+                format_speed(self.aggregate_speed())
+            ));
+            let inner = block.inner(chunks[1]);
+            frame.render_widget(block, chunks[1]);
+            let summary = history.summary();
+            let parts = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Min(10),
+                    Constraint::Length(summary.chars().count() as u16 + 2),
+                ])
+                .split(inner);
+            // Newest samples when the graph is narrower than the history
+            let samples = history.samples();
+            let shown = &samples[samples.len().saturating_sub(parts[0].width as usize)..];
+            let sparkline = Sparkline::default()
+                .data(shown)
+                .style(self.theme.success_style());
+            frame.render_widget(sparkline, parts[0]);
+            frame.render_widget(
+                Paragraph::new(format!(" {}", summary)).style(self.theme.text_style()),
+                parts[1],
+            );
+        }
+
+        if let Some(label) = storage_label {
+            let ratio = self.storage_usage.ratio().unwrap_or(0.0);
+            let gauge_style = if ratio >= 0.95 {
+                self.theme.error_style()
+            } else if ratio >= 0.8 {
+                self.theme.warning_style()
+            } else {
+                self.theme.success_style()
+            };
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Storage quota"),
+                )
+                .gauge_style(gauge_style)
+                .ratio(ratio)
+                .label(label);
+            frame.render_widget(gauge, chunks[2]);
+        }
+
         // Status/help bar
         let status_text = if self.downloads.is_empty() {
             "No downloads found. Press 'r' to refresh.".to_string()
@@ -466,6 +670,6 @@ impl UIComponent for DownloadsBuffer {
             .block(Block::default().borders(Borders::ALL).title("Actions"))
             .style(self.theme.text_style());
 
-        frame.render_widget(status_paragraph, chunks[1]);
+        frame.render_widget(status_paragraph, chunks[3]);
     }
 }
@@ -3,6 +3,14 @@
 // This buffer shows comprehensive episode details including description,
 // metadata, and status information. It is a read-only view created from
 // the episode list buffer when pressing Enter on an episode.
+//
+// Long show notes are folded paragraph by paragraph so links at the end are
+// reachable without paging through sponsor copy. `]`/`[` move between folded
+// sections, Enter expands or collapses one, and `v` shows the notes verbatim.
+// People the episode credits (`<podcast:person>`, e.g. its guests) are listed
+// above the notes; `f` searches every podcast for more episodes with them.
+
+use std::collections::BTreeSet;
 
 use ratatui::{
     layout::Rect,
@@ -13,30 +21,102 @@ use ratatui::{
 };
 
 use crate::{
-    podcast::Episode,
+    constants::ui::{DETAIL_FOLD_MIN_ROWS, DETAIL_FOLD_PREVIEW_CHARS, DETAIL_FOLD_PREVIEW_LINES},
+    podcast::{format_file_size, DownloadQuality, Episode},
     storage::{EpisodeId, PodcastId},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{
+            capabilities::{Downloadable, EpisodeSelection},
+            podcast_detail::people_lines,
+            Buffer, BufferId, BufferInfo,
+        },
+        components::ScrollPosition,
+        text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
+    utils::time::{format_duration, format_utc_offset, DisplayZone},
 };
 
+/// Assumed text width when estimating how many rows a paragraph wraps to
+const ESTIMATED_WRAP_WIDTH: usize = 80;
+
+const TRANSLATION_HEADING: &str = "Translation:";
+const TRANSCRIPT_HEADING: &str = "Transcript:";
+
+/// Fold and raw-view state, remembered per episode for the session
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpisodeDetailViewState {
+    /// Indices of foldable sections the user expanded
+    expanded: BTreeSet<usize>,
+    raw: bool,
+}
+
+/// A paragraph of the description, or the whole translation or transcript
+struct Section<'a> {
+    lines: Vec<&'a str>,
+    /// Heading of a translation or transcript; `None` for the description
+    heading: Option<&'static str>,
+    foldable: bool,
+}
+
+/// Approximate wrapped rows for a block of text
+fn estimated_rows(lines: &[&str]) -> usize {
+    lines
+        .iter()
+        .map(|line| {
+            text_layout::display_width(line)
+                .div_ceil(ESTIMATED_WRAP_WIDTH)
+                .max(1)
+        })
+        .sum()
+}
+
+/// First few lines of a folded section, cut to the preview character budget
+fn fold_preview(lines: &[&str]) -> Vec<String> {
+    let mut budget = DETAIL_FOLD_PREVIEW_CHARS;
+    let mut preview = Vec::new();
+    for line in lines.iter().take(DETAIL_FOLD_PREVIEW_LINES) {
+        if budget == 0 {
+            break;
+        }
+        let width = text_layout::display_width(line);
+        if width > budget {
+            preview.push(text_layout::truncate(line, budget).into_owned());
+            break;
+        }
+        budget -= width;
+        preview.push(line.to_string());
+    }
+    preview
+}
+
 /// Buffer for displaying detailed episode information
 pub struct EpisodeDetailBuffer {
     id: String,
     episode_title: String,
     episode: Episode,
     podcast_id: PodcastId,
+    /// Feed language of the episode's podcast, when known
+    podcast_language: Option<String>,
+    /// The podcast's `:quality`, for the download size estimate
+    download_quality: DownloadQuality,
+    view: EpisodeDetailViewState,
+    /// Foldable section under the cursor (index into `sections()`)
+    current_section: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
     theme: Theme,
 }
 
 impl EpisodeDetailBuffer {
     /// Create a new episode detail buffer
     pub fn new(episode: Episode) -> Self {
-        let episode_title = episode.title.clone();
+        let episode_title = episode.display_title().to_string();
         let id = format!("episode-detail-{}", episode.id);
         let podcast_id = episode.podcast_id.clone();
 
@@ -45,10 +125,22 @@ impl EpisodeDetailBuffer {
             episode_title,
             podcast_id,
             episode,
+            podcast_language: None,
+            download_quality: DownloadQuality::default(),
+            view: EpisodeDetailViewState::default(),
+            current_section: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
             theme: Theme::default(),
         }
+        .with_first_section_selected()
+    }
+
+    fn with_first_section_selected(mut self) -> Self {
+        self.current_section = self.foldable_sections().first().copied();
+        self
     }
 
     /// Set the theme for this buffer
@@ -56,6 +148,16 @@ impl EpisodeDetailBuffer {
         self.theme = theme;
     }
 
+    /// Show the podcast's feed language alongside the episode metadata
+    pub fn set_podcast_language(&mut self, language: Option<String>) {
+        self.podcast_language = language;
+    }
+
+    /// Estimate the download at the podcast's quality setting
+    pub fn set_download_quality(&mut self, quality: DownloadQuality) {
+        self.download_quality = quality;
+    }
+
     /// Get the podcast ID for this episode.
     pub fn podcast_id(&self) -> &PodcastId {
         &self.podcast_id
@@ -66,23 +168,155 @@ impl EpisodeDetailBuffer {
         &self.episode.id
     }
 
+    /// The episode being shown
+    pub fn episode(&self) -> &Episode {
+        &self.episode
+    }
+
+    /// Show an updated copy of the episode, e.g. after its metadata was edited
+    pub fn set_episode(&mut self, episode: Episode) {
+        self.episode_title = episode.display_title().to_string();
+        self.episode = episode;
+        self.current_section = self.foldable_sections().first().copied();
+    }
+
+    /// Fold state to remember after this buffer closes
+    pub fn view_state(&self) -> EpisodeDetailViewState {
+        self.view.clone()
+    }
+
+    /// Reapply fold state remembered from an earlier visit
+    pub fn restore_view_state(&mut self, view: EpisodeDetailViewState) {
+        self.view = view;
+    }
+
+    /// Switch between folded show notes and the verbatim text. Returns the new mode.
+    pub fn toggle_raw(&mut self) -> bool {
+        self.view.raw = !self.view.raw;
+        self.view.raw
+    }
+
+    /// Split the show notes into paragraphs plus the translation and transcript
+    fn sections(&self) -> Vec<Section<'_>> {
+        let mut sections = Vec::new();
+
+        if let Some(description) = self.episode.display_description() {
+            let all: Vec<&str> = description.lines().collect();
+            let long = estimated_rows(&all) >= DETAIL_FOLD_MIN_ROWS;
+            for paragraph in all.split(|line| line.trim().is_empty()) {
+                if paragraph.is_empty() {
+                    continue;
+                }
+                let foldable = long && Self::worth_folding(paragraph);
+                sections.push(Section {
+                    lines: paragraph.to_vec(),
+                    heading: None,
+                    foldable,
+                });
+            }
+        }
+
+        let extras = [
+            (TRANSLATION_HEADING, &self.episode.translated_description),
+            (TRANSCRIPT_HEADING, &self.episode.transcript),
+        ];
+        for (heading, text) in extras {
+            if let Some(text) = text {
+                let lines: Vec<&str> = text.lines().collect();
+                sections.push(Section {
+                    foldable: Self::worth_folding(&lines),
+                    lines,
+                    heading: Some(heading),
+                });
+            }
+        }
+
+        sections
+    }
+
+    /// Folding only pays off when more than the preview would be hidden
+    fn worth_folding(lines: &[&str]) -> bool {
+        estimated_rows(lines) > DETAIL_FOLD_PREVIEW_LINES + 1
+    }
+
+    fn foldable_sections(&self) -> Vec<usize> {
+        if self.view.raw {
+            return Vec::new();
+        }
+        self.sections()
+            .iter()
+            .enumerate()
+            .filter(|(_, section)| section.foldable)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Expand or collapse the section under the cursor
+    fn toggle_current_section(&mut self) -> bool {
+        let Some(index) = self.current_section.filter(|_| !self.view.raw) else {
+            return false;
+        };
+        if !self.view.expanded.remove(&index) {
+            self.view.expanded.insert(index);
+        }
+        true
+    }
+
+    /// Move the cursor to the next (`forward`) or previous foldable section
+    /// and scroll it into view
+    fn select_section(&mut self, forward: bool) -> bool {
+        let foldable = self.foldable_sections();
+        let next = match self.current_section {
+            None => foldable.first().copied(),
+            Some(current) if forward => foldable.iter().copied().find(|&i| i > current),
+            Some(current) => foldable.iter().rev().copied().find(|&i| i < current),
+        };
+        let Some(next) = next else {
+            return false;
+        };
+        self.current_section = Some(next);
+        if let Some(&(_, line)) = self.layout().1.iter().find(|(i, _)| *i == next) {
+            self.scroll_offset = line;
+        }
+        true
+    }
+
     /// Generate content lines for display
     fn generate_content(&self) -> Vec<Line<'_>> {
+        let mut lines = self.header_lines();
+        lines.extend(self.layout().0);
+        lines
+    }
+
+    /// Metadata lines above the description
+    fn header_lines(&self) -> Vec<Line<'_>> {
         let mut lines = Vec::new();
 
         // Title section
         lines.push(Line::from(vec![
             Span::styled("Title: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(&self.episode.title),
+            Span::raw(self.episode.display_title()),
         ]));
         lines.push(Line::from(""));
 
-        // Published date
-        let published_str = self
-            .episode
-            .published
-            .format("%Y-%m-%d %H:%M UTC")
-            .to_string();
+        // Published date, on the display zone's clock and, when it differs,
+        // the publisher's
+        let zone = self.theme.time_zone;
+        let original = self.episode.published_offset;
+        let shown = zone.localize(self.episode.published, original);
+        let mut published_str = format!(
+            "{} {}",
+            shown.format("%Y-%m-%d %H:%M"),
+            format_utc_offset(shown.offset().local_minus_utc())
+        );
+        if let Some(offset) = original.filter(|o| *o != shown.offset().local_minus_utc()) {
+            let at_source = DisplayZone::Original.localize(self.episode.published, Some(offset));
+            published_str.push_str(&format!(
+                " (published {} {})",
+                at_source.format("%Y-%m-%d %H:%M"),
+                format_utc_offset(offset)
+            ));
+        }
         lines.push(Line::from(vec![
             Span::styled("Published: ", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(published_str),
@@ -102,6 +336,22 @@ impl EpisodeDetailBuffer {
             ]));
         }
 
+        // Where playing resumes
+        if let Some(position) = self.episode.resume_position() {
+            let progress = self
+                .episode
+                .progress_percent()
+                .map(|percent| format!(" ({}%)", percent))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Resumes At: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("{}{}", format_duration(position), progress)),
+            ]));
+        }
+
         // File size
         if self.episode.file_size.is_some() {
             lines.push(Line::from(vec![
@@ -110,6 +360,47 @@ impl EpisodeDetailBuffer {
             ]));
         }
 
+        // The feed replaced the audio after it was downloaded
+        if let Some(update) = &self.episode.updated_enclosure {
+            let size = update
+                .length
+                .map(|bytes| format!(" ({})", format_file_size(bytes)))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled("New audio: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!(
+                        "the feed has replaced the downloaded file{}; U re-downloads it",
+                        size
+                    ),
+                    self.theme.warning_style(),
+                ),
+            ]));
+        }
+
+        // Which encoding a download takes, when the feed offers several
+        if !self.episode.audio_variants.is_empty() {
+            let encoding = self.episode.encoding_for(self.download_quality);
+            let size = encoding
+                .estimated_size(self.episode.duration)
+                .map(|bytes| format!("~{}", format_file_size(bytes)))
+                .unwrap_or_else(|| "size unknown".to_string());
+            let bitrate = encoding
+                .bitrate_kbps
+                .map(|kbps| format!(" at {} kbps", kbps))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled("Download: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(
+                    "{}{} ({} quality of {} encodings)",
+                    size,
+                    bitrate,
+                    self.download_quality.label(),
+                    self.episode.audio_variants.len() + 1
+                )),
+            ]));
+        }
+
         // Episode number and season
         if let Some(season) = self.episode.season {
             lines.push(Line::from(vec![
@@ -132,6 +423,19 @@ impl EpisodeDetailBuffer {
             ]));
         }
 
+        // The episode's own language, else the feed's
+        if let Some(language) = self
+            .episode
+            .language
+            .as_ref()
+            .or(self.podcast_language.as_ref())
+        {
+            lines.push(Line::from(vec![
+                Span::styled("Language: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(language.clone()),
+            ]));
+        }
+
         // Explicit content warning
         if self.episode.explicit {
             lines.push(Line::from(vec![
@@ -148,47 +452,135 @@ impl EpisodeDetailBuffer {
             ]));
         }
 
-        lines.push(Line::from(""));
-        lines.push(Line::from(vec![Span::styled(
-            "Description:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]));
-        lines.push(Line::from("─".repeat(60)));
-
-        // Description
-        if let Some(ref description) = self.episode.description {
-            // Split description into lines for wrapping
-            for line in description.lines() {
-                if line.trim().is_empty() {
-                    lines.push(Line::from(""));
-                } else {
-                    lines.push(Line::from(line.to_string()));
-                }
-            }
-        } else {
-            lines.push(Line::from("No description available."));
+        // Guests and others the episode credits
+        lines.extend(people_lines(&self.episode.people, &self.theme));
+
+        // The user's own notes (`:episode-note`)
+        if let Some(notes) = self.episode.notes.as_deref().filter(|n| !n.is_empty()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "My notes:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            lines.extend(notes.lines().map(|line| Line::from(line.to_string())));
         }
 
-        // Transcript (if available)
-        if let Some(ref transcript) = self.episode.transcript {
+        // Positions bookmarked while listening (`B`, `:bookmark`)
+        if !self.episode.bookmarks.is_empty() {
             lines.push(Line::from(""));
-            lines.push(Line::from("─".repeat(60)));
             lines.push(Line::from(vec![Span::styled(
-                "Transcript:",
+                "Bookmarks:",
                 Style::default().add_modifier(Modifier::BOLD),
             )]));
-            lines.push(Line::from("─".repeat(60)));
+            lines.extend(self.episode.bookmarks.iter().map(|bookmark| {
+                Line::from(format!(
+                    "  {:>8}  {}",
+                    format_duration(bookmark.position),
+                    bookmark.label
+                ))
+            }));
+        }
 
-            for line in transcript.lines() {
-                if line.trim().is_empty() {
-                    lines.push(Line::from(""));
-                } else {
-                    lines.push(Line::from(line.to_string()));
+        lines
+    }
+
+    /// Description, translation and transcript lines, plus the line each foldable
+    /// section starts on (relative to the full content)
+    fn layout(&self) -> (Vec<Line<'_>>, Vec<(usize, usize)>) {
+        let header_len = self.header_lines().len();
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "Description:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from("─".repeat(60)),
+        ];
+        let mut anchors = Vec::new();
+
+        if self.view.raw {
+            match self.episode.display_description() {
+                Some(description) => {
+                    lines.extend(description.lines().map(|line| Line::from(line.to_string())))
+                }
+                None => lines.push(Line::from("No description available.")),
+            }
+            let extras = [
+                (TRANSLATION_HEADING, &self.episode.translated_description),
+                (TRANSCRIPT_HEADING, &self.episode.transcript),
+            ];
+            for (heading, text) in extras {
+                if let Some(text) = text {
+                    lines.extend(Self::section_heading(heading));
+                    lines.extend(text.lines().map(|line| Line::from(line.to_string())));
                 }
             }
+            return (lines, anchors);
         }
 
-        lines
+        let sections = self.sections();
+        if !sections.iter().any(|section| section.heading.is_none()) {
+            lines.push(Line::from("No description available."));
+        }
+
+        for (index, section) in sections.iter().enumerate() {
+            if let Some(heading) = section.heading {
+                lines.extend(Self::section_heading(heading));
+            } else if index > 0 {
+                lines.push(Line::from(""));
+            }
+            if section.foldable {
+                anchors.push((index, header_len + lines.len()));
+            }
+
+            let expanded = !section.foldable || self.view.expanded.contains(&index);
+            if expanded {
+                lines.extend(
+                    section
+                        .lines
+                        .iter()
+                        .map(|line| Line::from(line.to_string())),
+                );
+            } else {
+                lines.extend(fold_preview(&section.lines).into_iter().map(Line::from));
+            }
+
+            if section.foldable {
+                let marker = if expanded {
+                    "▾ show less".to_string()
+                } else {
+                    let hidden = section
+                        .lines
+                        .len()
+                        .saturating_sub(DETAIL_FOLD_PREVIEW_LINES);
+                    match hidden {
+                        0 => "▸ read more".to_string(),
+                        1 => "▸ read more (1 more line)".to_string(),
+                        n => format!("▸ read more ({} more lines)", n),
+                    }
+                };
+                let style = if self.current_section == Some(index) {
+                    self.theme.selected_style()
+                } else {
+                    self.theme.muted_style()
+                };
+                lines.push(Line::from(Span::styled(marker, style)));
+            }
+        }
+
+        (lines, anchors)
+    }
+
+    fn section_heading(heading: &'static str) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from("─".repeat(60)),
+            Line::from(vec![Span::styled(
+                heading,
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from("─".repeat(60)),
+        ]
     }
 
     /// Scroll up
@@ -244,6 +636,14 @@ impl Buffer for EpisodeDetailBuffer {
         self
     }
 
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        Some(self)
+    }
+
+    fn as_episode_selection(&self) -> Option<&dyn EpisodeSelection> {
+        Some(self)
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
@@ -252,6 +652,20 @@ impl Buffer for EpisodeDetailBuffer {
         true
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            memory_bytes: super::estimate_bytes(
+                std::slice::from_ref(&self.episode),
+                super::episode_text_bytes,
+            ),
+            ..BufferInfo::new("Episode")
+        }
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Episode Detail Commands:".to_string(),
@@ -261,7 +675,12 @@ impl Buffer for EpisodeDetailBuffer {
             "  Page Up   Page up".to_string(),
             "  Home, <   Scroll to top".to_string(),
             "  End, >    Scroll to bottom".to_string(),
+            "  ], [      Next / previous folded section".to_string(),
+            "  Enter     Expand / collapse section".to_string(),
+            "  v         Toggle raw show notes".to_string(),
+            "  f         Find more episodes with a guest".to_string(),
             "  D         Download episode".to_string(),
+            "  U         Re-download updated audio".to_string(),
             "  p         Add episode to a playlist".to_string(),
             "  q, C-k    Close buffer".to_string(),
             "  C-h       Show help".to_string(),
@@ -269,6 +688,39 @@ impl Buffer for EpisodeDetailBuffer {
     }
 }
 
+impl EpisodeSelection for EpisodeDetailBuffer {
+    fn selected_episode_entry(&self) -> Option<(&PodcastId, &Episode)> {
+        Some((self.podcast_id(), self.episode()))
+    }
+}
+
+impl Downloadable for EpisodeDetailBuffer {
+    fn download_selected(&mut self) -> UIAction {
+        if self.episode.is_downloaded() {
+            UIAction::ShowMessage("Episode already downloaded".to_string())
+        } else if self.episode.status.download == crate::podcast::DownloadState::Downloading {
+            UIAction::ShowMessage("Episode is already downloading".to_string())
+        } else if self.episode.audio_url.is_empty()
+            && !self
+                .episode
+                .guid
+                .as_ref()
+                .is_some_and(|g| g.starts_with("http"))
+        {
+            UIAction::ShowMessage(
+                "Cannot download: No audio URL available for this episode".to_string(),
+            )
+        } else {
+            // Return action to trigger async download
+            UIAction::TriggerDownload {
+                podcast_id: self.podcast_id.clone(),
+                episode_id: self.episode.id.clone(),
+                episode_title: self.episode.display_title().to_string(),
+            }
+        }
+    }
+}
+
 impl UIComponent for EpisodeDetailBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -306,31 +758,18 @@ impl UIComponent for EpisodeDetailBuffer {
                 self.scroll_to_bottom(max_lines, 20); // Use reasonable visible height
                 UIAction::Render
             }
-            UIAction::DownloadEpisode => {
-                if self.episode.is_downloaded() {
-                    UIAction::ShowMessage("Episode already downloaded".to_string())
-                } else if matches!(
-                    self.episode.status,
-                    crate::podcast::EpisodeStatus::Downloading
-                ) {
-                    UIAction::ShowMessage("Episode is already downloading".to_string())
-                } else if self.episode.audio_url.is_empty()
-                    && !self
-                        .episode
-                        .guid
-                        .as_ref()
-                        .is_some_and(|g| g.starts_with("http"))
-                {
-                    UIAction::ShowMessage(
-                        "Cannot download: No audio URL available for this episode".to_string(),
-                    )
+            UIAction::SelectItem => {
+                if self.toggle_current_section() {
+                    UIAction::Render
                 } else {
-                    // Return action to trigger async download
-                    UIAction::TriggerDownload {
-                        podcast_id: self.podcast_id.clone(),
-                        episode_id: self.episode.id.clone(),
-                        episode_title: self.episode.title.clone(),
-                    }
+                    UIAction::None
+                }
+            }
+            UIAction::NextTab | UIAction::PreviousTab => {
+                if self.select_section(action == UIAction::NextTab) {
+                    UIAction::Render
+                } else {
+                    UIAction::ShowMessage("No more folded sections".to_string())
                 }
             }
             _ => UIAction::None,
@@ -368,6 +807,8 @@ impl UIComponent for EpisodeDetailBuffer {
 
         // Update scroll offset after rendering
         self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = max_lines;
     }
 
     fn title(&self) -> String {
@@ -466,7 +907,7 @@ mod tests {
         let mut buffer = EpisodeDetailBuffer::new(episode);
 
         // Test triggering download
-        let action = buffer.handle_action(UIAction::DownloadEpisode);
+        let action = buffer.download_selected();
         match action {
             UIAction::TriggerDownload {
                 podcast_id: pid,
@@ -520,13 +961,13 @@ mod tests {
         }
 
         // Set episode as downloaded with the temporary file
-        episode.status = crate::podcast::EpisodeStatus::Downloaded;
+        episode.status.download = crate::podcast::DownloadState::Downloaded;
         episode.local_path = Some(temp_path.clone());
 
         let mut buffer = EpisodeDetailBuffer::new(episode);
 
         // Test attempting to download already downloaded episode
-        let action = buffer.handle_action(UIAction::DownloadEpisode);
+        let action = buffer.download_selected();
 
         // Clean up the temporary file
         let _ = std::fs::remove_file(&temp_path);
@@ -552,7 +993,7 @@ mod tests {
         let mut buffer = EpisodeDetailBuffer::new(episode);
 
         // Test attempting to download episode without audio URL
-        let action = buffer.handle_action(UIAction::DownloadEpisode);
+        let action = buffer.download_selected();
         match action {
             UIAction::ShowMessage(msg) => {
                 assert!(msg.contains("No audio URL available"));
@@ -570,12 +1011,12 @@ mod tests {
             Utc::now(),
         );
         // Set episode as currently downloading
-        episode.status = crate::podcast::EpisodeStatus::Downloading;
+        episode.status.download = crate::podcast::DownloadState::Downloading;
 
         let mut buffer = EpisodeDetailBuffer::new(episode);
 
         // Test attempting to download episode that's already downloading
-        let action = buffer.handle_action(UIAction::DownloadEpisode);
+        let action = buffer.download_selected();
         match action {
             UIAction::ShowMessage(msg) => {
                 assert_eq!(msg, "Episode is already downloading");
@@ -583,4 +1024,155 @@ mod tests {
             _ => panic!("Expected ShowMessage action"),
         }
     }
+
+    #[test]
+    fn test_content_shows_language_and_explicit_flag() {
+        // Arrange
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Test Episode".to_string(),
+            "https://example.com/audio.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.explicit = true;
+        let mut buffer = EpisodeDetailBuffer::new(episode);
+        buffer.set_podcast_language(Some("de".to_string()));
+
+        // Act
+        let text: Vec<String> = buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        // Assert
+        assert!(text.iter().any(|l| l == "Language: de"));
+        assert!(text.iter().any(|l| l == "Explicit: Yes"));
+    }
+
+    #[test]
+    fn test_translation_follows_description_and_episode_language_wins() {
+        // Arrange
+        let mut episode = episode_with_notes("Heute sprechen wir über Kaffee.");
+        episode.language = Some("de-AT".to_string());
+        episode.translated_description = Some("Today we talk about coffee.".to_string());
+        let mut buffer = EpisodeDetailBuffer::new(episode);
+        buffer.set_podcast_language(Some("de".to_string()));
+
+        // Act
+        let text = text(&buffer);
+
+        // Assert
+        assert!(text.iter().any(|l| l == "Language: de-AT"));
+        let original = text
+            .iter()
+            .position(|l| l == "Heute sprechen wir über Kaffee.")
+            .unwrap();
+        let heading = text.iter().position(|l| l == "Translation:").unwrap();
+        assert!(original < heading);
+        assert_eq!(text[heading + 2], "Today we talk about coffee.");
+    }
+
+    fn episode_with_notes(description: &str) -> Episode {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Test Episode".to_string(),
+            "https://example.com/audio.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.description = Some(description.to_string());
+        episode
+    }
+
+    /// Two short paragraphs around 30 lines of sponsor copy
+    fn long_notes() -> String {
+        let sponsor: Vec<String> = (1..=30).map(|i| format!("Sponsor line {}", i)).collect();
+        format!(
+            "Intro paragraph.\n\n{}\n\nLinks: https://example.com",
+            sponsor.join("\n")
+        )
+    }
+
+    fn text(buffer: &EpisodeDetailBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_long_paragraph_is_folded_with_read_more() {
+        // Arrange / Act
+        let buffer = EpisodeDetailBuffer::new(episode_with_notes(&long_notes()));
+        let lines = text(&buffer);
+
+        // Assert
+        assert!(lines.contains(&"Sponsor line 3".to_string()));
+        assert!(!lines.contains(&"Sponsor line 4".to_string()));
+        assert!(lines.contains(&"▸ read more (27 more lines)".to_string()));
+        assert!(lines.contains(&"Links: https://example.com".to_string()));
+    }
+
+    #[test]
+    fn test_short_description_is_not_folded() {
+        let buffer = EpisodeDetailBuffer::new(episode_with_notes("One\nTwo\nThree\nFour\nFive"));
+        let lines = text(&buffer);
+        assert!(lines.contains(&"Five".to_string()));
+        assert!(!lines.iter().any(|l| l.contains("read more")));
+    }
+
+    #[test]
+    fn test_select_item_expands_and_collapses_section() {
+        // Arrange
+        let mut buffer = EpisodeDetailBuffer::new(episode_with_notes(&long_notes()));
+
+        // Act
+        let action = buffer.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert_eq!(action, UIAction::Render);
+        let lines = text(&buffer);
+        assert!(lines.contains(&"Sponsor line 30".to_string()));
+        assert!(lines.contains(&"▾ show less".to_string()));
+
+        buffer.handle_action(UIAction::SelectItem);
+        assert!(!text(&buffer).contains(&"Sponsor line 30".to_string()));
+    }
+
+    #[test]
+    fn test_section_navigation_scrolls_to_section() {
+        // Arrange — description plus a long transcript gives two foldable sections
+        let mut episode = episode_with_notes(&long_notes());
+        episode.transcript = Some("Transcript line\n".repeat(20));
+        let mut buffer = EpisodeDetailBuffer::new(episode);
+
+        // Act
+        let action = buffer.handle_action(UIAction::NextTab);
+
+        // Assert — the transcript preview starts where we scrolled to
+        assert_eq!(action, UIAction::Render);
+        assert_eq!(text(&buffer)[buffer.scroll_offset], "Transcript line");
+        assert!(matches!(
+            buffer.handle_action(UIAction::NextTab),
+            UIAction::ShowMessage(_)
+        ));
+        buffer.handle_action(UIAction::PreviousTab);
+        assert_eq!(text(&buffer)[buffer.scroll_offset], "Sponsor line 1");
+    }
+
+    #[test]
+    fn test_raw_view_shows_notes_verbatim() {
+        // Arrange
+        let mut buffer = EpisodeDetailBuffer::new(episode_with_notes(&long_notes()));
+
+        // Act
+        assert!(buffer.toggle_raw());
+
+        // Assert
+        let lines = text(&buffer);
+        assert!(lines.contains(&"Sponsor line 30".to_string()));
+        assert!(!lines.iter().any(|l| l.contains("read more")));
+        assert_eq!(buffer.handle_action(UIAction::SelectItem), UIAction::None);
+    }
 }
@@ -72,22 +72,32 @@ impl EpisodeSort {
 
 use ratatui::{
     layout::Rect,
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
 use crate::{
+    audio::{PlaybackState, PlaybackStatus},
     download::DownloadManager,
-    podcast::{subscription::SubscriptionManager, Episode},
-    storage::{JsonStorage, PodcastId, Storage},
+    podcast::{subscription::SubscriptionManager, DownloadState, Episode, PlayState},
+    storage::{EpisodeId, JsonStorage, PodcastId, Storage},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{
+            capabilities::{Downloadable, EpisodeSelection, Playable, Refreshable},
+            Buffer, BufferId, BufferInfo,
+        },
+        components::ScrollPosition,
+        events::BufferRefreshType,
         filters::EpisodeFilter,
+        glyphs, text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
+    utils::time::format_time_left,
 };
 use std::sync::Arc;
+use tokio::sync::watch;
 
 /// Buffer for displaying episodes from a podcast
 pub struct EpisodeListBuffer {
@@ -98,6 +108,8 @@ pub struct EpisodeListBuffer {
     selected_index: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     subscription_manager: Option<Arc<SubscriptionManager<JsonStorage>>>,
     download_manager: Option<Arc<DownloadManager<JsonStorage>>>,
@@ -108,21 +120,49 @@ pub struct EpisodeListBuffer {
     filtered_indices: Vec<usize>,
     /// Current sort order applied to `episodes`.
     sort: EpisodeSort,
+    /// Saved view to re-apply once episodes arrive after a restore
+    pending_view: Option<EpisodeListViewState>,
+    /// Live playback status, so the playing episode's time left counts down
+    playback_status_rx: Option<watch::Receiver<PlaybackStatus>>,
+}
+
+/// Scroll, selection, filter and sort of a closed episode buffer, kept so the
+/// buffer looks the same when its podcast is reopened.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpisodeListViewState {
+    pub selected_episode: Option<EpisodeId>,
+    pub scroll_offset: usize,
+    pub filter: EpisodeFilter,
+    pub sort: EpisodeSort,
 }
 
 /// Map `EpisodeStatus` to a numeric sort key for the DownloadStatus sort field.
 ///
-/// Lower key = "more ready to listen" (Downloaded first).
+/// Lower key = "more ready to listen" (Downloaded first, played episodes last).
 fn status_sort_key(status: &crate::podcast::EpisodeStatus) -> u8 {
-    match status {
-        crate::podcast::EpisodeStatus::Downloaded => 0,
-        crate::podcast::EpisodeStatus::Downloading => 1,
-        crate::podcast::EpisodeStatus::New => 2,
-        crate::podcast::EpisodeStatus::DownloadFailed => 3,
-        crate::podcast::EpisodeStatus::Played => 4,
+    if status.play == PlayState::Played {
+        return 4;
+    }
+    match status.download {
+        DownloadState::Downloaded => 0,
+        DownloadState::Downloading => 1,
+        DownloadState::NotDownloaded => 2,
+        DownloadState::Failed => 3,
     }
 }
 
+/// Seconds left in `episode` when it is the one `live` reports playing
+fn live_time_left(live: Option<&PlaybackStatus>, episode: &Episode) -> Option<u32> {
+    let live = live.filter(|s| s.episode_id.as_ref() == Some(&episode.id))?;
+    let position = live.position?.as_secs() as u32;
+    let duration = live
+        .duration
+        .map(|d| d.as_secs() as u32)
+        .or(episode.duration)
+        .filter(|d| *d > 0)?;
+    Some(duration.saturating_sub(position))
+}
+
 impl EpisodeListBuffer {
     /// Create a new episode list buffer for a podcast
     pub fn new(podcast_name: String, podcast_id: PodcastId) -> Self {
@@ -134,15 +174,36 @@ impl EpisodeListBuffer {
             selected_index: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             subscription_manager: None,
             download_manager: None,
             filter: EpisodeFilter::default(),
             filtered_indices: Vec::new(),
             sort: EpisodeSort::default(),
+            pending_view: None,
+            playback_status_rx: None,
         }
     }
 
+    /// Snapshot the view so it can be restored after the buffer is recycled
+    pub fn view_state(&self) -> EpisodeListViewState {
+        EpisodeListViewState {
+            selected_episode: self.selected_episode().map(|e| e.id.clone()),
+            scroll_offset: self.scroll_offset,
+            filter: self.filter.clone(),
+            sort: self.sort,
+        }
+    }
+
+    /// Restore a snapshot from [`view_state`](Self::view_state). Filter and sort
+    /// apply immediately; selection and scroll apply on the next `set_episodes`.
+    pub fn restore_view_state(&mut self, state: EpisodeListViewState) {
+        self.filter = state.filter.clone();
+        self.sort = state.sort;
+        self.pending_view = Some(state);
+    }
+
     /// Set managers
     pub fn set_managers(
         &mut self,
@@ -198,6 +259,18 @@ impl EpisodeListBuffer {
         self.apply_sort();
         // Re-apply filters (this also resets cursor/scroll appropriately)
         self.apply_filters();
+
+        if let Some(view) = self.pending_view.take() {
+            let position = view.selected_episode.and_then(|id| {
+                self.filtered_indices
+                    .iter()
+                    .position(|&i| self.episodes[i].id == id)
+            });
+            if let Some(position) = position {
+                self.selected_index = Some(position);
+                self.scroll_offset = view.scroll_offset.min(position);
+            }
+        }
     }
 
     /// Sort `self.episodes` in place according to `self.sort`.
@@ -261,6 +334,13 @@ impl EpisodeListBuffer {
             .and_then(|&actual| self.episodes.get(actual))
     }
 
+    /// The episodes the list shows after filtering, in display order
+    pub fn shown_episodes(&self) -> impl Iterator<Item = &Episode> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&actual| self.episodes.get(actual))
+    }
+
     /// Download selected episode
     pub async fn download_selected(&self) -> Result<(), String> {
         if let (Some(episode), Some(ref dm)) = (self.selected_episode(), &self.download_manager) {
@@ -349,14 +429,72 @@ impl Buffer for EpisodeListBuffer {
         self
     }
 
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        Some(self)
+    }
+
+    fn as_playable(&mut self) -> Option<&mut dyn Playable> {
+        Some(self)
+    }
+
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
+    fn as_episode_selection(&self) -> Option<&dyn EpisodeSelection> {
+        Some(self)
+    }
+
+    fn current_podcast_id(&self) -> Option<PodcastId> {
+        Some(self.podcast_id.clone())
+    }
+
+    fn refresh_async(&self) -> Option<BufferRefreshType> {
+        Some(BufferRefreshType::EpisodeBuffers {
+            podcast_id: self.podcast_id.clone(),
+        })
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
 
+    fn set_hide_explicit(&mut self, hide: bool) {
+        self.filter.hide_explicit = hide;
+        self.apply_filters();
+    }
+
+    fn set_languages(&mut self, languages: &[String]) {
+        self.filter.languages = languages.to_vec();
+        self.apply_filters();
+    }
+
+    fn set_playback_status_rx(&mut self, rx: watch::Receiver<PlaybackStatus>) {
+        self.playback_status_rx = Some(rx);
+    }
+
     fn can_close(&self) -> bool {
         true
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        let position = ScrollPosition::items(
+            self.scroll_area,
+            self.selected_index?,
+            self.filtered_indices.len(),
+        )?;
+        Some(if self.filter.is_active() {
+            position.with_note(format!("{} total", self.episodes.len()))
+        } else {
+            position
+        })
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Episodes", &self.episodes, super::episode_text_bytes)
+            .with_podcast(self.podcast_name.clone())
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Episode List Commands:".to_string(),
@@ -365,7 +503,8 @@ impl Buffer for EpisodeListBuffer {
             "  Enter     View episode details".to_string(),
             "  D         Download episode".to_string(),
             "  X         Delete downloaded file".to_string(),
-            "  m         Mark as played".to_string(),
+            "  U         Re-download updated audio (↻)".to_string(),
+            "  m         Toggle played".to_string(),
             "  u         Mark as unplayed".to_string(),
             "  *         Toggle favorite (★)".to_string(),
             "  o         Cycle sort field (Date → Title → Duration → Status)".to_string(),
@@ -377,6 +516,149 @@ impl Buffer for EpisodeListBuffer {
     }
 }
 
+impl Downloadable for EpisodeListBuffer {
+    fn download_selected(&mut self) -> UIAction {
+        if let Some(episode) = self.selected_episode() {
+            if episode.is_downloaded() {
+                UIAction::ShowMessage("Episode already downloaded".to_string())
+            } else if episode.status.download == DownloadState::Downloading {
+                UIAction::ShowMessage("Episode is already downloading".to_string())
+            } else if episode.audio_url.is_empty()
+                && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
+            {
+                UIAction::ShowMessage(
+                    "Cannot download: No audio URL available for this episode".to_string(),
+                )
+            } else {
+                // Return action to trigger async download
+                UIAction::TriggerDownload {
+                    podcast_id: self.podcast_id.clone(),
+                    episode_id: episode.id.clone(),
+                    episode_title: episode.display_title().to_string(),
+                }
+            }
+        } else {
+            UIAction::ShowMessage("No episode selected for download".to_string())
+        }
+    }
+
+    fn delete_selected_download(&mut self) -> UIAction {
+        if let Some(episode) = self.selected_episode() {
+            if episode.is_downloaded() {
+                UIAction::TriggerDeleteDownload {
+                    podcast_id: self.podcast_id.clone(),
+                    episode_id: episode.id.clone(),
+                    episode_title: episode.display_title().to_string(),
+                }
+            } else {
+                UIAction::ShowMessage("Episode is not downloaded".to_string())
+            }
+        } else {
+            UIAction::ShowMessage("No episode selected".to_string())
+        }
+    }
+}
+
+impl Playable for EpisodeListBuffer {
+    fn play_selected(&mut self) -> UIAction {
+        if let Some(episode) = self.selected_episode() {
+            if let Some(ref path) = episode.local_path {
+                UIAction::PlayEpisode {
+                    podcast_id: episode.podcast_id.clone(),
+                    episode_id: episode.id.clone(),
+                    path: path.clone(),
+                }
+            } else {
+                UIAction::ShowError("Episode must be downloaded before playing".into())
+            }
+        } else {
+            UIAction::ShowError("No episode selected".into())
+        }
+    }
+
+    /// `m` toggles: on a played episode it marks it unplayed again
+    fn mark_selected_played(&mut self, played: bool) -> UIAction {
+        let Some((actual_idx, episode_id, episode_title, is_played)) = self
+            .selected_index
+            .and_then(|i| self.filtered_indices.get(i))
+            .copied()
+            .map(|actual_idx| {
+                let ep = &self.episodes[actual_idx];
+                (actual_idx, ep.id.clone(), ep.title.clone(), ep.is_played())
+            })
+        else {
+            return UIAction::ShowMessage("No episode selected".to_string());
+        };
+        if played && !is_played {
+            self.episodes[actual_idx].mark_played();
+            UIAction::TriggerMarkPlayed {
+                podcast_id: self.podcast_id.clone(),
+                episode_id,
+                episode_title,
+            }
+        } else if is_played {
+            self.episodes[actual_idx].mark_unplayed();
+            UIAction::TriggerMarkUnplayed {
+                podcast_id: self.podcast_id.clone(),
+                episode_id,
+                episode_title,
+            }
+        } else {
+            UIAction::ShowMessage("Episode already marked as unplayed".to_string())
+        }
+    }
+
+    fn toggle_selected_favorite(&mut self) -> UIAction {
+        let result = self
+            .selected_index
+            .and_then(|i| self.filtered_indices.get(i))
+            .copied()
+            .map(|actual_idx| {
+                let ep = &self.episodes[actual_idx];
+                (actual_idx, ep.id.clone(), ep.title.clone())
+            });
+        match result {
+            Some((actual_idx, episode_id, episode_title)) => {
+                self.episodes[actual_idx].toggle_favorite();
+                let new_favorited = self.episodes[actual_idx].favorited;
+                // Re-apply filters in case favorites_only is active
+                if self.filter.favorites_only {
+                    self.apply_filters();
+                }
+                UIAction::TriggerToggleFavorite {
+                    podcast_id: self.podcast_id.clone(),
+                    episode_id,
+                    episode_title,
+                    favorited: new_favorited,
+                }
+            }
+            None => UIAction::ShowMessage("No episode selected".to_string()),
+        }
+    }
+}
+
+impl EpisodeSelection for EpisodeListBuffer {
+    fn selected_episode_entry(&self) -> Option<(&PodcastId, &Episode)> {
+        self.selected_episode()
+            .map(|episode| (&self.podcast_id, episode))
+    }
+
+    fn shown_episodes(&self) -> Option<Vec<(PodcastId, EpisodeId)>> {
+        Some(
+            self.shown_episodes()
+                .map(|episode| (self.podcast_id.clone(), episode.id.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl Refreshable for EpisodeListBuffer {
+    fn refresh(&mut self) -> UIAction {
+        self.refresh_async()
+            .map_or(UIAction::None, UIAction::RefreshBuffer)
+    }
+}
+
 impl UIComponent for EpisodeListBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -424,135 +706,6 @@ impl UIComponent for EpisodeListBuffer {
                     UIAction::None
                 }
             }
-            UIAction::DownloadEpisode => {
-                if let Some(episode) = self.selected_episode() {
-                    if episode.is_downloaded() {
-                        UIAction::ShowMessage("Episode already downloaded".to_string())
-                    } else if matches!(episode.status, crate::podcast::EpisodeStatus::Downloading) {
-                        UIAction::ShowMessage("Episode is already downloading".to_string())
-                    } else if episode.audio_url.is_empty()
-                        && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
-                    {
-                        UIAction::ShowMessage(
-                            "Cannot download: No audio URL available for this episode".to_string(),
-                        )
-                    } else {
-                        // Return action to trigger async download
-                        UIAction::TriggerDownload {
-                            podcast_id: self.podcast_id.clone(),
-                            episode_id: episode.id.clone(),
-                            episode_title: episode.title.clone(),
-                        }
-                    }
-                } else {
-                    UIAction::ShowMessage("No episode selected for download".to_string())
-                }
-            }
-            UIAction::DeleteDownloadedEpisode => {
-                if let Some(episode) = self.selected_episode() {
-                    if episode.is_downloaded() {
-                        UIAction::TriggerDeleteDownload {
-                            podcast_id: self.podcast_id.clone(),
-                            episode_id: episode.id.clone(),
-                            episode_title: episode.title.clone(),
-                        }
-                    } else {
-                        UIAction::ShowMessage("Episode is not downloaded".to_string())
-                    }
-                } else {
-                    UIAction::ShowMessage("No episode selected".to_string())
-                }
-            }
-            UIAction::PlayEpisode { .. } => {
-                if let Some(episode) = self.selected_episode() {
-                    if let Some(ref path) = episode.local_path {
-                        UIAction::PlayEpisode {
-                            podcast_id: episode.podcast_id.clone(),
-                            episode_id: episode.id.clone(),
-                            path: path.clone(),
-                        }
-                    } else {
-                        UIAction::ShowError("Episode must be downloaded before playing".into())
-                    }
-                } else {
-                    UIAction::ShowError("No episode selected".into())
-                }
-            }
-            UIAction::MarkPlayed => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let ep = &self.episodes[actual_idx];
-                        (actual_idx, ep.id.clone(), ep.title.clone(), ep.is_played())
-                    });
-                match result {
-                    Some((_, _, _, true)) => {
-                        UIAction::ShowMessage("Episode already marked as played".to_string())
-                    }
-                    Some((actual_idx, episode_id, episode_title, false)) => {
-                        self.episodes[actual_idx].mark_played();
-                        UIAction::TriggerMarkPlayed {
-                            podcast_id: self.podcast_id.clone(),
-                            episode_id,
-                            episode_title,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
-            UIAction::MarkUnplayed => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let ep = &self.episodes[actual_idx];
-                        (actual_idx, ep.id.clone(), ep.title.clone(), ep.is_played())
-                    });
-                match result {
-                    Some((_, _, _, false)) => {
-                        UIAction::ShowMessage("Episode already marked as unplayed".to_string())
-                    }
-                    Some((actual_idx, episode_id, episode_title, true)) => {
-                        self.episodes[actual_idx].mark_unplayed();
-                        UIAction::TriggerMarkUnplayed {
-                            podcast_id: self.podcast_id.clone(),
-                            episode_id,
-                            episode_title,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
-            UIAction::ToggleFavorite => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let ep = &self.episodes[actual_idx];
-                        (actual_idx, ep.id.clone(), ep.title.clone())
-                    });
-                match result {
-                    Some((actual_idx, episode_id, episode_title)) => {
-                        self.episodes[actual_idx].toggle_favorite();
-                        let new_favorited = self.episodes[actual_idx].favorited;
-                        // Re-apply filters in case favorites_only is active
-                        if self.filter.favorites_only {
-                            self.apply_filters();
-                        }
-                        UIAction::TriggerToggleFavorite {
-                            podcast_id: self.podcast_id.clone(),
-                            episode_id,
-                            episode_title,
-                            favorited: new_favorited,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
             // --- Search & Filter actions ---
             UIAction::Search => {
                 // Bubble up to UIApp which will open the minibuffer prompt
@@ -661,7 +814,7 @@ impl UIComponent for EpisodeListBuffer {
 
     fn render(&mut self, frame: &mut Frame, area: Rect) {
         let filtered_count = self.filtered_indices.len();
-        let total_count = self.episodes.len();
+        self.scroll_area = area;
 
         // Calculate visible area and viewport
         let visible_height = area.height.saturating_sub(2) as usize; // Account for borders
@@ -677,6 +830,14 @@ impl UIComponent for EpisodeListBuffer {
             }
         }
 
+        // The playing episode counts down from the live position rather than
+        // the one saved when it was last paused
+        let live = self
+            .playback_status_rx
+            .as_ref()
+            .map(|rx| rx.borrow().clone())
+            .filter(|status| status.state != PlaybackState::Stopped);
+
         // Build list items from filtered_indices
         let end_index = (self.scroll_offset + visible_height).min(filtered_count);
         let items: Vec<ListItem> = if filtered_count == 0 {
@@ -689,34 +850,49 @@ impl UIComponent for EpisodeListBuffer {
                     let display_pos = self.scroll_offset + display_index;
                     let episode = &self.episodes[actual_ep_index];
 
-                    let status_indicator = match episode.status {
-                        crate::podcast::EpisodeStatus::New => {
-                            if episode.audio_url.is_empty()
-                                && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
-                            {
-                                "⚠"
-                            } else {
-                                "○"
-                            }
-                        }
-                        crate::podcast::EpisodeStatus::Downloaded => "●",
-                        crate::podcast::EpisodeStatus::Downloading => "◐",
-                        crate::podcast::EpisodeStatus::Played => "✓",
-                        crate::podcast::EpisodeStatus::DownloadFailed => "✗",
-                    };
-
-                    let title_with_info = if episode.audio_url.is_empty()
-                        && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
-                        && episode.status == crate::podcast::EpisodeStatus::New
-                    {
-                        format!("{} (no audio URL)", episode.title)
+                    let title = text_layout::single_line(episode.display_title());
+                    let title_with_info = if glyphs::is_missing_audio(episode) {
+                        format!("{} (no audio URL)", title)
                     } else {
-                        episode.title.clone()
+                        title.into_owned()
                     };
 
-                    let fav_indicator = if episode.favorited { "★ " } else { "" };
-                    let content =
-                        format!(" {} {}{}", status_indicator, fav_indicator, title_with_info);
+                    let mut spans = vec![
+                        Span::raw(" "),
+                        glyphs::episode_span(episode, &self.theme),
+                        Span::raw(" "),
+                    ];
+                    if episode.favorited {
+                        spans.push(Span::styled(
+                            format!("{} ", glyphs::favorite_symbol(self.theme.glyphs)),
+                            self.theme.warning_style(),
+                        ));
+                    }
+                    spans.push(Span::raw(title_with_info));
+                    if episode.updated_enclosure.is_some() {
+                        spans.push(Span::styled(
+                            format!(
+                                " {} new audio",
+                                glyphs::updated_audio_symbol(self.theme.glyphs)
+                            ),
+                            self.theme.warning_style(),
+                        ));
+                    }
+                    if let Some(percent) = episode.progress_percent() {
+                        spans.push(Span::styled(
+                            format!(" {:>3}%", percent),
+                            self.theme.muted_style(),
+                        ));
+                    }
+                    if let Some(left) = live_time_left(live.as_ref(), episode)
+                        .or_else(|| episode.remaining_seconds())
+                    {
+                        spans.push(Span::styled(
+                            format!(" · {}", format_time_left(left)),
+                            self.theme.muted_style(),
+                        ));
+                    }
+                    let content = Line::from(spans);
 
                     if Some(display_pos) == self.selected_index {
                         ListItem::new(content).style(self.theme.selected_style())
@@ -783,26 +959,6 @@ impl UIComponent for EpisodeListBuffer {
             let status =
                 ratatui::widgets::Paragraph::new(empty_msg).style(self.theme.muted_style());
             frame.render_widget(status, status_area);
-        } else if let Some(index) = self.selected_index {
-            let status_msg = if self.filter.is_active() {
-                format!(
-                    " {} of {} matching ({} total) ",
-                    index + 1,
-                    filtered_count,
-                    total_count
-                )
-            } else {
-                format!(" {} of {} episodes ", index + 1, total_count)
-            };
-            let status_area = Rect {
-                x: area.x + area.width.saturating_sub(status_msg.len() as u16 + 2),
-                y: area.y + area.height - 1,
-                width: status_msg.len() as u16,
-                height: 1,
-            };
-            let status =
-                ratatui::widgets::Paragraph::new(status_msg).style(self.theme.muted_style());
-            frame.render_widget(status, status_area);
         }
     }
 
@@ -838,6 +994,33 @@ mod tests {
         assert_eq!(buffer.podcast_id, podcast_id);
     }
 
+    #[test]
+    fn test_time_left_follows_the_live_position_of_the_playing_episode() {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Ep1".to_string(),
+            "url1".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.duration = Some(3600);
+        episode.last_played_position = Some(600);
+        assert_eq!(episode.remaining_seconds(), Some(3000));
+
+        let live = PlaybackStatus {
+            state: PlaybackState::Playing,
+            episode_id: Some(episode.id.clone()),
+            position: Some(std::time::Duration::from_secs(2220)),
+            ..PlaybackStatus::default()
+        };
+        assert_eq!(live_time_left(Some(&live), &episode), Some(1380));
+
+        let other = PlaybackStatus {
+            episode_id: Some(crate::storage::EpisodeId::new()),
+            ..live
+        };
+        assert_eq!(live_time_left(Some(&other), &episode), None);
+    }
+
     #[test]
     fn test_navigation() {
         let mut buffer = EpisodeListBuffer::new("Test".to_string(), PodcastId::new());
@@ -1159,7 +1342,7 @@ mod tests {
             "url2".to_string(),
             chrono::Utc::now(),
         );
-        ep2.status = crate::podcast::EpisodeStatus::Downloaded;
+        ep2.status.download = crate::podcast::DownloadState::Downloaded;
         let episodes = vec![ep1, ep2];
         buffer.set_episodes(episodes);
         assert_eq!(buffer.visible_count(), 2);
@@ -1247,7 +1430,7 @@ mod tests {
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkPlayed);
+        let action = buffer.mark_selected_played(true);
 
         // Assert: returns TriggerMarkPlayed with correct IDs
         assert!(
@@ -1259,7 +1442,7 @@ mod tests {
     }
 
     #[test]
-    fn test_mark_played_on_already_played_episode_returns_message() {
+    fn test_mark_played_on_already_played_episode_toggles_it_back() {
         // Arrange
         let podcast_id = PodcastId::new();
         let mut buffer = EpisodeListBuffer::new("Test".to_string(), podcast_id.clone());
@@ -1268,10 +1451,12 @@ mod tests {
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkPlayed);
+        let action = buffer.mark_selected_played(true);
 
-        // Assert: no-op message, no error
-        assert!(matches!(action, UIAction::ShowMessage(_)));
+        // Assert: `m` on a played episode marks it unplayed
+        assert!(matches!(action, UIAction::TriggerMarkUnplayed { .. }));
+        assert!(!buffer.episodes[0].is_played());
+        assert!(buffer.episodes[0].played_at.is_none());
     }
 
     #[test]
@@ -1285,7 +1470,7 @@ mod tests {
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkUnplayed);
+        let action = buffer.mark_selected_played(false);
 
         // Assert: returns TriggerMarkUnplayed with correct IDs
         assert!(
@@ -1305,7 +1490,7 @@ mod tests {
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkUnplayed);
+        let action = buffer.mark_selected_played(false);
 
         // Assert: no-op message
         assert!(matches!(action, UIAction::ShowMessage(_)));
@@ -1317,7 +1502,7 @@ mod tests {
         let mut buffer = EpisodeListBuffer::new("Test".to_string(), PodcastId::new());
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkPlayed);
+        let action = buffer.mark_selected_played(true);
 
         // Assert
         assert!(matches!(action, UIAction::ShowMessage(_)));
@@ -1329,7 +1514,7 @@ mod tests {
         let mut buffer = EpisodeListBuffer::new("Test".to_string(), PodcastId::new());
 
         // Act
-        let action = buffer.handle_action(UIAction::MarkUnplayed);
+        let action = buffer.mark_selected_played(false);
 
         // Assert
         assert!(matches!(action, UIAction::ShowMessage(_)));
@@ -1346,7 +1531,7 @@ mod tests {
             now - chrono::Duration::hours(48),
         );
         ep_old.duration = Some(3600); // 1 hour
-        ep_old.status = crate::podcast::EpisodeStatus::Downloaded;
+        ep_old.status.download = crate::podcast::DownloadState::Downloaded;
 
         let mut ep_mid = Episode::new(
             PodcastId::new(),
@@ -1355,7 +1540,7 @@ mod tests {
             now - chrono::Duration::hours(24),
         );
         ep_mid.duration = Some(600); // 10 minutes
-        ep_mid.status = crate::podcast::EpisodeStatus::Played;
+        ep_mid.status.play = crate::podcast::PlayState::Played;
 
         let mut ep_new = Episode::new(
             PodcastId::new(),
@@ -1364,7 +1549,7 @@ mod tests {
             now,
         );
         ep_new.duration = Some(1800); // 30 minutes
-        ep_new.status = crate::podcast::EpisodeStatus::New;
+        ep_new.status.download = crate::podcast::DownloadState::NotDownloaded;
 
         vec![ep_old, ep_mid, ep_new]
     }
@@ -1635,11 +1820,7 @@ mod tests {
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::PlayEpisode {
-            podcast_id: crate::storage::PodcastId::new(),
-            episode_id: crate::storage::EpisodeId::new(),
-            path: std::path::PathBuf::new(),
-        });
+        let action = buffer.play_selected();
 
         // Assert — must return ShowError, not PlayEpisode
         assert!(
@@ -1662,16 +1843,12 @@ mod tests {
         );
         let expected_path = std::path::PathBuf::from("/podcasts/episode.mp3");
         episode.local_path = Some(expected_path.clone());
-        episode.status = crate::podcast::EpisodeStatus::Downloaded;
+        episode.status.download = crate::podcast::DownloadState::Downloaded;
         let expected_episode_id = episode.id.clone();
         buffer.set_episodes(vec![episode]);
 
         // Act
-        let action = buffer.handle_action(UIAction::PlayEpisode {
-            podcast_id: crate::storage::PodcastId::new(),
-            episode_id: crate::storage::EpisodeId::new(),
-            path: std::path::PathBuf::new(),
-        });
+        let action = buffer.play_selected();
 
         // Assert — returned PlayEpisode must carry the correct IDs and path
         match action {
@@ -1687,4 +1864,36 @@ mod tests {
             other => panic!("Expected PlayEpisode, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_view_state_round_trip_restores_selection_and_sort() {
+        // Arrange
+        let podcast_id = PodcastId::new();
+        let episodes: Vec<Episode> = (0..5)
+            .map(|i| {
+                Episode::new(
+                    podcast_id.clone(),
+                    format!("Ep{}", i),
+                    format!("url{}", i),
+                    chrono::Utc::now() - chrono::Duration::days(i),
+                )
+            })
+            .collect();
+        let mut original = EpisodeListBuffer::new("Show".to_string(), podcast_id.clone());
+        original.set_episodes(episodes.clone());
+        original.handle_action(UIAction::CycleSortField); // Title
+        original.handle_action(UIAction::MoveDown);
+        original.handle_action(UIAction::MoveDown);
+        let expected_title = original.selected_episode().unwrap().title.clone();
+
+        // Act
+        let state = original.view_state();
+        let mut restored = EpisodeListBuffer::new("Show".to_string(), podcast_id);
+        restored.restore_view_state(state);
+        restored.set_episodes(episodes);
+
+        // Assert
+        assert_eq!(restored.sort, original.sort);
+        assert_eq!(restored.selected_episode().unwrap().title, expected_title);
+    }
 }
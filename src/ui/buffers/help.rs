@@ -4,17 +4,22 @@
 // following Emacs conventions for help display.
 
 use ratatui::{
-    layout::Rect,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    layout::{Alignment, Rect},
+    widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
 use crate::ui::{
-    buffers::{Buffer, BufferId},
+    buffers::{Buffer, BufferId, BufferInfo},
+    components::ScrollPosition,
+    text_layout,
     themes::Theme,
     UIAction, UIComponent,
 };
 
+/// Columns the key column of a keybinding line is padded to
+const KEYS_COLUMN_WIDTH: usize = 24;
+
 /// Help buffer that displays help content
 pub struct HelpBuffer {
     id: String,
@@ -22,6 +27,8 @@ pub struct HelpBuffer {
     content: Vec<String>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
 }
 
@@ -48,6 +55,7 @@ impl HelpBuffer {
             content,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
         }
     }
@@ -93,7 +101,11 @@ impl HelpBuffer {
             if let Some(bindings) = by_category.remove(category) {
                 content.push(format!("{}:", category));
                 for (keys, desc) in &bindings {
-                    content.push(format!("  {:<24} {}", keys, desc));
+                    content.push(format!(
+                        "  {} {}",
+                        text_layout::pad(keys, KEYS_COLUMN_WIDTH, Alignment::Left),
+                        desc
+                    ));
                 }
                 content.push("".to_string());
             }
@@ -105,7 +117,11 @@ impl HelpBuffer {
         for (category, bindings) in remaining {
             content.push(format!("{}:", category));
             for (keys, desc) in &bindings {
-                content.push(format!("  {:<24} {}", keys, desc));
+                content.push(format!(
+                    "  {} {}",
+                    text_layout::pad(keys, KEYS_COLUMN_WIDTH, Alignment::Left),
+                    desc
+                ));
             }
             content.push("".to_string());
         }
@@ -161,6 +177,14 @@ impl Buffer for HelpBuffer {
         true
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Help", &self.content, String::len)
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Help Buffer Navigation:".to_string(),
@@ -235,22 +259,7 @@ impl UIComponent for HelpBuffer {
             .highlight_style(self.theme.focused_style());
 
         frame.render_widget(help_widget, area);
-
-        // Show scroll indicator if content is scrollable
-        if self.content.len() > visible_height {
-            let scroll_info = format!(" {}/{} ", self.scroll_offset + 1, self.content.len());
-
-            let scroll_area = Rect {
-                x: area.x + area.width.saturating_sub(scroll_info.len() as u16 + 2),
-                y: area.y,
-                width: scroll_info.len() as u16 + 2,
-                height: 1,
-            };
-
-            let scroll_widget = Paragraph::new(scroll_info).style(self.theme.muted_style());
-
-            frame.render_widget(scroll_widget, scroll_area);
-        }
+        self.scroll_area = area;
     }
 
     fn title(&self) -> String {
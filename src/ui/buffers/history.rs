@@ -0,0 +1,367 @@
+// History buffer - the episodes played most recently
+//
+// Lists played episodes across every podcast by when they were played
+// (`Episode::played_at`), newest first, up to `HISTORY_LIMIT`. Enter opens
+// the selected episode's details; m or u marks it unplayed again, which takes
+// it off the list. Opened with `:history`.
+
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::{
+    constants::ui::HISTORY_LIMIT,
+    storage::{EpisodeId, PodcastId},
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// One played episode as listed
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryItem {
+    pub podcast_id: PodcastId,
+    pub episode_id: EpisodeId,
+    pub podcast_title: String,
+    pub episode_title: String,
+    pub played_at: DateTime<Utc>,
+}
+
+/// Buffer listing recently played episodes
+pub struct HistoryBuffer {
+    id: String,
+    items: Vec<HistoryItem>,
+    selected_index: Option<usize>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    theme: Theme,
+}
+
+impl Default for HistoryBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HistoryBuffer {
+    pub fn new() -> Self {
+        Self {
+            id: "history".to_string(),
+            items: Vec::new(),
+            selected_index: None,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Replace the listed episodes, newest play first; the cursor stays on
+    /// the episode it was on, or at the same position when that one left
+    pub fn set_items(&mut self, mut items: Vec<HistoryItem>) {
+        items.sort_by_key(|item| std::cmp::Reverse(item.played_at));
+        items.truncate(HISTORY_LIMIT);
+        let selected_id = self.selected_item().map(|item| item.episode_id.clone());
+        let previous_index = self.selected_index.unwrap_or(0);
+        self.items = items;
+        self.selected_index = if self.items.is_empty() {
+            None
+        } else {
+            selected_id
+                .and_then(|id| self.items.iter().position(|item| item.episode_id == id))
+                .or(Some(previous_index.min(self.items.len() - 1)))
+        };
+        if self.items.is_empty() {
+            self.scroll_offset = 0;
+        }
+    }
+
+    pub fn items(&self) -> &[HistoryItem] {
+        &self.items
+    }
+
+    pub fn selected_item(&self) -> Option<&HistoryItem> {
+        self.selected_index.and_then(|i| self.items.get(i))
+    }
+
+    fn select_previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(0) => Some(self.items.len() - 1),
+            Some(i) => Some(i - 1),
+            None => Some(0),
+        };
+    }
+
+    fn select_next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(i) if i >= self.items.len() - 1 => Some(0),
+            Some(i) => Some(i + 1),
+            None => Some(0),
+        };
+    }
+
+    /// Take the selected episode off the list and mark it unplayed
+    fn unplay_selected(&mut self) -> UIAction {
+        let Some(index) = self.selected_index.filter(|&i| i < self.items.len()) else {
+            return UIAction::ShowMessage("No played episode selected".to_string());
+        };
+        let item = self.items.remove(index);
+        self.selected_index = if self.items.is_empty() {
+            None
+        } else {
+            Some(index.min(self.items.len() - 1))
+        };
+        UIAction::TriggerMarkUnplayed {
+            podcast_id: item.podcast_id,
+            episode_id: item.episode_id,
+            episode_title: item.episode_title,
+        }
+    }
+}
+
+impl Buffer for HistoryBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "History".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.items.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("History", &self.items, |item| {
+            item.podcast_title.len() + item.episode_title.len()
+        })
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "History Commands:".to_string(),
+            "  C-n, ↓    Next episode".to_string(),
+            "  C-p, ↑    Previous episode".to_string(),
+            "  Enter     Open episode details".to_string(),
+            "  m, u      Mark unplayed (removes it from the history)".to_string(),
+            "  C-h       Show help".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for HistoryBuffer {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                if !self.items.is_empty() {
+                    self.selected_index = Some(0);
+                    self.scroll_offset = 0;
+                }
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                if !self.items.is_empty() {
+                    self.selected_index = Some(self.items.len() - 1);
+                }
+                UIAction::Render
+            }
+            // Everything listed is played, so `m` toggles it back as well
+            UIAction::MarkPlayed | UIAction::MarkUnplayed => self.unplay_selected(),
+            UIAction::SelectItem => match self.selected_item() {
+                Some(item) => UIAction::OpenEpisodeDetailById {
+                    podcast_id: item.podcast_id.clone(),
+                    episode_id: item.episode_id.clone(),
+                },
+                None => UIAction::ShowMessage("No played episode selected".to_string()),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.scroll_area = area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()))
+            .border_style(if self.focused {
+                self.theme.border_focused_style()
+            } else {
+                self.theme.border_style()
+            });
+
+        if self.items.is_empty() {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let message = Paragraph::new(
+                "Nothing played yet.\n\nEpisodes appear here when they finish playing or are marked played with m.",
+            )
+            .style(self.theme.default_style())
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true });
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let visible_height = block.inner(area).height.saturating_sub(1) as usize; // -1 for header
+        if let Some(selected) = self.selected_index {
+            if selected >= self.scroll_offset + visible_height {
+                self.scroll_offset = selected.saturating_sub(visible_height.saturating_sub(1));
+            } else if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            }
+        }
+
+        let header = Row::new(vec![
+            Cell::from("Played"),
+            Cell::from("Podcast"),
+            Cell::from("Episode"),
+        ])
+        .style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let now = Utc::now();
+        let end = (self.scroll_offset + visible_height).min(self.items.len());
+        let rows: Vec<Row> = self.items[self.scroll_offset..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, item)| {
+                let style = if Some(self.scroll_offset + offset) == self.selected_index {
+                    self.theme.selected_style()
+                } else {
+                    self.theme.default_style()
+                };
+                Row::new(vec![
+                    Cell::from(self.theme.time_zone.day_label(item.played_at, None, now)),
+                    Cell::from(text_layout::cell(&item.podcast_title, 25)),
+                    Cell::from(text_layout::cell(&item.episode_title, 70)),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(15),
+                Constraint::Percentage(25),
+                Constraint::Percentage(75),
+            ],
+        )
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+        frame.render_widget(table, area);
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn title(&self) -> String {
+        format!("History ({})", self.items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn item(title: &str, hours_ago: i64) -> HistoryItem {
+        HistoryItem {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            podcast_title: "Local Radio".to_string(),
+            episode_title: title.to_string(),
+            played_at: Utc::now() - Duration::hours(hours_ago),
+        }
+    }
+
+    #[test]
+    fn test_lists_latest_play_first() {
+        let mut buffer = HistoryBuffer::new();
+
+        buffer.set_items(vec![
+            item("Monday", 48),
+            item("Tonight", 1),
+            item("Noon", 8),
+        ]);
+
+        let titles: Vec<&str> = buffer
+            .items()
+            .iter()
+            .map(|item| item.episode_title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Tonight", "Noon", "Monday"]);
+        assert_eq!(buffer.title(), "History (3)");
+    }
+
+    #[test]
+    fn test_mark_unplayed_drops_selected_episode() {
+        // Arrange
+        let mut buffer = HistoryBuffer::new();
+        let items = vec![item("Tonight", 1), item("Noon", 8)];
+        buffer.set_items(items.clone());
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let action = buffer.handle_action(UIAction::MarkPlayed);
+
+        // Assert
+        assert_eq!(
+            action,
+            UIAction::TriggerMarkUnplayed {
+                podcast_id: items[1].podcast_id.clone(),
+                episode_id: items[1].episode_id.clone(),
+                episode_title: "Noon".to_string(),
+            }
+        );
+        assert_eq!(buffer.items().len(), 1);
+        assert_eq!(buffer.selected_item().unwrap().episode_title, "Tonight");
+    }
+}
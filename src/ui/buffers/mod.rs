@@ -3,17 +3,33 @@
 // This module implements the core buffer system that mimics Emacs buffers,
 // where each buffer represents different content (podcast list, episode list, etc.)
 
+pub mod alerts;
+pub mod audio_device_picker;
+pub mod audio_diagnostics;
 pub mod buffer_list;
+pub mod capabilities;
+pub mod cast_picker;
+pub mod cleanup_preview;
+pub mod config_report;
+pub mod dashboard;
 pub mod discovery;
 pub mod downloads;
 pub mod episode_detail;
 pub mod episode_list;
 pub mod help;
+pub mod history;
 pub mod now_playing;
+pub mod opml_preview;
 pub mod playlist_detail;
 pub mod playlist_list;
 pub mod playlist_picker;
+pub mod podcast_detail;
 pub mod podcast_list;
+pub mod podcast_settings;
+pub mod queue;
+pub mod refresh_diff;
+pub mod release_notes;
+pub mod search;
 pub mod sync;
 pub mod whats_new;
 
@@ -21,7 +37,12 @@ use ratatui::layout::Rect;
 use std::any::Any;
 use std::collections::HashMap;
 
-use crate::ui::{themes::Theme, UIAction, UIComponent, UIError, UIResult};
+use capabilities::{Downloadable, EpisodeSelection, Playable, Refreshable};
+
+use crate::ui::{
+    components::ScrollPosition, events::BufferRefreshType, themes::Theme, UIAction, UIComponent,
+    UIError, UIResult,
+};
 use crate::{
     download::DownloadManager,
     playlist::{manager::PlaylistManager, PlaylistId, PlaylistType},
@@ -33,6 +54,69 @@ use std::sync::Arc;
 /// Unique identifier for buffers
 pub type BufferId = String;
 
+/// Summary of a buffer's contents, shown in the buffer list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferInfo {
+    /// Short human-readable buffer type ("Episodes", "Help", ...)
+    pub kind: &'static str,
+    /// Podcast this buffer belongs to, if any
+    pub podcast: Option<String>,
+    /// Number of items the buffer holds, for list-like buffers
+    pub item_count: Option<usize>,
+    /// Approximate heap size of the buffer's data in bytes
+    pub memory_bytes: usize,
+}
+
+impl BufferInfo {
+    /// Info for a buffer of the given kind with no items
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            podcast: None,
+            item_count: None,
+            memory_bytes: 0,
+        }
+    }
+
+    /// Info for a list buffer, estimating memory from its items
+    pub fn list<T>(kind: &'static str, items: &[T], text_len: impl Fn(&T) -> usize) -> Self {
+        Self {
+            kind,
+            podcast: None,
+            item_count: Some(items.len()),
+            memory_bytes: estimate_bytes(items, text_len),
+        }
+    }
+
+    /// Attach the owning podcast's name
+    pub fn with_podcast(mut self, podcast: impl Into<String>) -> Self {
+        self.podcast = Some(podcast.into());
+        self
+    }
+}
+
+/// Rough in-memory size of a collection: fixed struct size plus owned text.
+/// Good enough to spot which buffers are worth closing.
+pub(crate) fn estimate_bytes<T>(items: &[T], text_len: impl Fn(&T) -> usize) -> usize {
+    items
+        .iter()
+        .map(|item| std::mem::size_of::<T>() + text_len(item))
+        .sum()
+}
+
+/// Owned text held by an episode (title, description, notes, transcript, URLs)
+pub(crate) fn episode_text_bytes(episode: &crate::podcast::Episode) -> usize {
+    let optional = |s: &Option<String>| s.as_ref().map_or(0, String::len);
+    episode.title.len()
+        + episode.audio_url.len()
+        + optional(&episode.description)
+        + optional(&episode.notes)
+        + optional(&episode.transcript)
+        + optional(&episode.guid)
+        + optional(&episode.link)
+        + optional(&episode.image_url)
+}
+
 /// Trait that all buffer types must implement
 pub trait Buffer: UIComponent + Any {
     /// Get the unique ID of this buffer
@@ -47,6 +131,32 @@ pub trait Buffer: UIComponent + Any {
     /// Mutable downcast support for typed buffer access.
     fn as_any_mut(&mut self) -> &mut dyn Any;
 
+    /// The buffer as a `Downloadable`, if its selection can be downloaded
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        None
+    }
+
+    /// The buffer as a `Playable`, if its selection can be played
+    fn as_playable(&mut self) -> Option<&mut dyn Playable> {
+        None
+    }
+
+    /// The buffer as a `Refreshable`, if it can reload its contents
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        None
+    }
+
+    /// The buffer as an `EpisodeSelection`, if it has an episode selected
+    fn as_episode_selection(&self) -> Option<&dyn EpisodeSelection> {
+        None
+    }
+
+    /// Podcast the buffer is about, for commands acting on "this podcast":
+    /// the selected one in a list, or the one a buffer shows
+    fn current_podcast_id(&self) -> Option<PodcastId> {
+        None
+    }
+
     /// Check if this buffer can be closed
     fn can_close(&self) -> bool {
         true
@@ -58,6 +168,23 @@ pub trait Buffer: UIComponent + Any {
     /// Called when the buffer is deactivated (loses focus)  
     fn on_deactivate(&mut self) {}
 
+    /// Data to reload in the background whenever the buffer gains focus.
+    ///
+    /// `BufferManager::switch_to_buffer` queues it and the app runs it as a
+    /// background task, so buffers showing state that changes behind their
+    /// back (downloads, What's New) are current when looked at. The default
+    /// reloads nothing.
+    fn on_activate_async(&mut self) -> Option<BufferRefreshType> {
+        None
+    }
+
+    /// The background reload that brings this buffer's data up to date, for
+    /// refreshes the app starts across open buffers. `None` for buffers that
+    /// load nothing.
+    fn refresh_async(&self) -> Option<BufferRefreshType> {
+        None
+    }
+
     /// Update the theme used by this buffer.
     ///
     /// Called whenever the user changes the active theme (`:theme <name>`) and at
@@ -66,6 +193,33 @@ pub trait Buffer: UIComponent + Any {
     /// overlay buffers that don't store a theme field are not forced to implement it.
     fn set_theme(&mut self, _theme: Theme) {}
 
+    /// Hide explicit podcasts/episodes (`ui.hide_explicit`). Applied at creation
+    /// time like the theme; only list buffers override it.
+    fn set_hide_explicit(&mut self, _hide: bool) {}
+
+    /// Languages episode lists show (`ui.languages`); applied like
+    /// `set_hide_explicit`
+    fn set_languages(&mut self, _languages: &[String]) {}
+
+    /// Live playback status, for lists that show the playing episode's time
+    /// left; applied like `set_hide_explicit`
+    fn set_playback_status_rx(
+        &mut self,
+        _rx: tokio::sync::watch::Receiver<crate::audio::PlaybackStatus>,
+    ) {
+    }
+
+    /// Summary shown in the buffer list (kind, podcast, item count, memory)
+    fn info(&self) -> BufferInfo {
+        BufferInfo::new("Buffer")
+    }
+
+    /// Where the buffer is scrolled to as of its last render, for the shared
+    /// scroll indicator. `None` (the default) draws no indicator.
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        None
+    }
+
     /// Get help text for this buffer's keybindings
     fn help_text(&self) -> Vec<String> {
         vec![
@@ -75,6 +229,37 @@ pub trait Buffer: UIComponent + Any {
     }
 }
 
+/// Whether a buffer ID belongs to a per-podcast episode list
+fn is_episode_buffer(id: &str) -> bool {
+    id.starts_with("episodes-")
+}
+
+/// A named group of buffers with its own ring (`:workspace`). A buffer joins
+/// the ring of every workspace it is shown in.
+struct Workspace {
+    name: String,
+    /// The ring, in the order buffers joined it
+    buffers: Vec<BufferId>,
+    /// Buffer shown when the workspace was last left
+    active: Option<BufferId>,
+}
+
+impl Workspace {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            buffers: Vec::new(),
+            active: None,
+        }
+    }
+
+    fn join(&mut self, buffer_id: &BufferId) {
+        if !self.buffers.contains(buffer_id) {
+            self.buffers.push(buffer_id.clone());
+        }
+    }
+}
+
 /// Buffer manager that handles multiple buffers and switching between them
 pub struct BufferManager {
     buffers: HashMap<BufferId, Box<dyn Buffer>>,
@@ -82,6 +267,26 @@ pub struct BufferManager {
     buffer_order: Vec<BufferId>,
     /// The theme that all open buffers and any newly created buffers should use.
     current_theme: Theme,
+    /// Open `episodes-*` buffers, least recently used first
+    episode_buffer_lru: Vec<BufferId>,
+    /// Maximum open episode buffers before the least recently used is closed (0 = unlimited)
+    max_episode_buffers: usize,
+    /// View state of recycled episode buffers, restored when reopened
+    recycled_episode_views: HashMap<BufferId, episode_list::EpisodeListViewState>,
+    /// Fold state of closed episode detail buffers, restored when reopened
+    episode_detail_views: HashMap<BufferId, episode_detail::EpisodeDetailViewState>,
+    /// Whether list buffers hide explicit content (`ui.hide_explicit`)
+    hide_explicit: bool,
+    /// Languages episode lists show (`ui.languages`)
+    languages: Vec<String>,
+    /// Reloads requested by buffers that gained focus, for the app to run
+    pending_refreshes: Vec<BufferRefreshType>,
+    /// Playback status from the audio thread, handed to every new buffer
+    playback_status_rx: Option<tokio::sync::watch::Receiver<crate::audio::PlaybackStatus>>,
+    /// Buffer groups; the first is the default workspace
+    workspaces: Vec<Workspace>,
+    /// Index of the workspace shown
+    current_workspace: usize,
 }
 
 impl BufferManager {
@@ -92,9 +297,24 @@ impl BufferManager {
             active_buffer: None,
             buffer_order: Vec::new(),
             current_theme: Theme::default(),
+            episode_buffer_lru: Vec::new(),
+            max_episode_buffers: 0,
+            recycled_episode_views: HashMap::new(),
+            episode_detail_views: HashMap::new(),
+            hide_explicit: false,
+            languages: Vec::new(),
+            pending_refreshes: Vec::new(),
+            playback_status_rx: None,
+            workspaces: vec![Workspace::new(crate::constants::ui::DEFAULT_WORKSPACE)],
+            current_workspace: 0,
         }
     }
 
+    /// Cap the number of open episode-list buffers (0 = unlimited)
+    pub fn set_max_episode_buffers(&mut self, max: usize) {
+        self.max_episode_buffers = max;
+    }
+
     /// Add a buffer to the manager
     pub fn add_buffer(&mut self, mut buffer: Box<dyn Buffer>) -> UIResult<()> {
         let id = buffer.id();
@@ -109,9 +329,18 @@ impl BufferManager {
 
         // Apply the current theme so every buffer always starts with the right colours.
         buffer.set_theme(self.current_theme.clone());
+        buffer.set_hide_explicit(self.hide_explicit);
+        buffer.set_languages(&self.languages);
+        if let Some(rx) = &self.playback_status_rx {
+            buffer.set_playback_status_rx(rx.clone());
+        }
 
         self.buffer_order.push(id.clone());
+        self.workspaces[self.current_workspace].join(&id);
         self.buffers.insert(id.clone(), buffer);
+        if is_episode_buffer(&id) {
+            self.episode_buffer_lru.push(id.clone());
+        }
 
         // If this is the first buffer, make it active
         if self.active_buffer.is_none() {
@@ -133,6 +362,22 @@ impl BufferManager {
         }
     }
 
+    /// Apply `ui.hide_explicit` to all open buffers and remember it for future buffers
+    pub fn set_hide_explicit_all(&mut self, hide: bool) {
+        self.hide_explicit = hide;
+        for buffer in self.buffers.values_mut() {
+            buffer.set_hide_explicit(hide);
+        }
+    }
+
+    /// Apply `ui.languages` to all open buffers and remember it for future buffers
+    pub fn set_languages_all(&mut self, languages: &[String]) {
+        self.languages = languages.to_vec();
+        for buffer in self.buffers.values_mut() {
+            buffer.set_languages(languages);
+        }
+    }
+
     /// Remove a buffer from the manager
     pub fn remove_buffer(&mut self, buffer_id: &BufferId) -> UIResult<()> {
         let buffer = self
@@ -147,12 +392,35 @@ impl BufferManager {
             )));
         }
 
+        if let Some(detail) = buffer
+            .as_any()
+            .downcast_ref::<episode_detail::EpisodeDetailBuffer>()
+        {
+            self.episode_detail_views
+                .insert(buffer_id.clone(), detail.view_state());
+        }
+
         self.buffers.remove(buffer_id);
         self.buffer_order.retain(|id| id != buffer_id);
+        self.episode_buffer_lru.retain(|id| id != buffer_id);
+        for workspace in &mut self.workspaces {
+            workspace.buffers.retain(|id| id != buffer_id);
+            if workspace.active.as_ref() == Some(buffer_id) {
+                workspace.active = None;
+            }
+        }
 
-        // If we removed the active buffer, switch to another one
+        // If we removed the active buffer, switch to another one, staying in
+        // the workspace when it has any left
         if self.active_buffer.as_ref() == Some(buffer_id) {
-            self.active_buffer = self.buffer_order.first().cloned();
+            self.active_buffer = self.workspaces[self.current_workspace]
+                .buffers
+                .first()
+                .or(self.buffer_order.first())
+                .cloned();
+            if let Some(id) = &self.active_buffer {
+                self.workspaces[self.current_workspace].join(id);
+            }
         }
 
         Ok(())
@@ -174,17 +442,43 @@ impl BufferManager {
 
         // Activate new buffer
         self.active_buffer = Some(buffer_id.clone());
+        let workspace = &mut self.workspaces[self.current_workspace];
+        workspace.join(buffer_id);
+        workspace.active = Some(buffer_id.clone());
+        if is_episode_buffer(buffer_id) {
+            self.episode_buffer_lru.retain(|id| id != buffer_id);
+            self.episode_buffer_lru.push(buffer_id.clone());
+        }
         if let Some(new_buffer) = self.buffers.get_mut(buffer_id) {
             new_buffer.on_activate();
             new_buffer.set_focus(true);
+            if let Some(refresh) = new_buffer.on_activate_async() {
+                if !self.pending_refreshes.contains(&refresh) {
+                    self.pending_refreshes.push(refresh);
+                }
+            }
         }
 
         Ok(())
     }
 
-    /// Switch to the next buffer in the order
+    /// Reloads queued by buffers that gained focus since the last call
+    pub fn take_pending_refreshes(&mut self) -> Vec<BufferRefreshType> {
+        std::mem::take(&mut self.pending_refreshes)
+    }
+
+    /// The background reload of every open buffer that has one
+    pub fn open_buffer_refreshes(&self) -> Vec<BufferRefreshType> {
+        self.buffer_order
+            .iter()
+            .filter_map(|id| self.buffers.get(id)?.refresh_async())
+            .collect()
+    }
+
+    /// Switch to the next buffer in the current workspace's ring
     pub fn next_buffer(&mut self) -> UIResult<()> {
-        if self.buffer_order.is_empty() {
+        let ring = &self.workspaces[self.current_workspace].buffers;
+        if ring.is_empty() {
             return Err(UIError::InvalidOperation(
                 "No buffers available".to_string(),
             ));
@@ -193,18 +487,19 @@ impl BufferManager {
         let current_index = self
             .active_buffer
             .as_ref()
-            .and_then(|id| self.buffer_order.iter().position(|bid| bid == id))
+            .and_then(|id| ring.iter().position(|bid| bid == id))
             .unwrap_or(0);
 
-        let next_index = (current_index + 1) % self.buffer_order.len();
-        let next_id = self.buffer_order[next_index].clone();
+        let next_index = (current_index + 1) % ring.len();
+        let next_id = ring[next_index].clone();
 
         self.switch_to_buffer(&next_id)
     }
 
-    /// Switch to the previous buffer in the order
+    /// Switch to the previous buffer in the current workspace's ring
     pub fn previous_buffer(&mut self) -> UIResult<()> {
-        if self.buffer_order.is_empty() {
+        let ring = &self.workspaces[self.current_workspace].buffers;
+        if ring.is_empty() {
             return Err(UIError::InvalidOperation(
                 "No buffers available".to_string(),
             ));
@@ -213,19 +508,111 @@ impl BufferManager {
         let current_index = self
             .active_buffer
             .as_ref()
-            .and_then(|id| self.buffer_order.iter().position(|bid| bid == id))
+            .and_then(|id| ring.iter().position(|bid| bid == id))
             .unwrap_or(0);
 
         let prev_index = if current_index == 0 {
-            self.buffer_order.len() - 1
+            ring.len() - 1
         } else {
             current_index - 1
         };
-        let prev_id = self.buffer_order[prev_index].clone();
+        let prev_id = ring[prev_index].clone();
 
         self.switch_to_buffer(&prev_id)
     }
 
+    /// Name of the workspace shown
+    pub fn current_workspace_name(&self) -> &str {
+        &self.workspaces[self.current_workspace].name
+    }
+
+    /// Workspace names with their buffer counts, the default one first
+    pub fn workspaces(&self) -> Vec<(String, usize)> {
+        self.workspaces
+            .iter()
+            .map(|workspace| (workspace.name.clone(), workspace.buffers.len()))
+            .collect()
+    }
+
+    /// Show workspace `name`, creating it when new, and return whether it was
+    /// created. A new workspace starts with `home` (or the buffer shown).
+    pub fn switch_workspace(&mut self, name: &str, home: &BufferId) -> UIResult<bool> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(UIError::InvalidOperation(
+                "Workspace name is empty".to_string(),
+            ));
+        }
+        let created = match self.workspaces.iter().position(|w| w.name == name) {
+            Some(index) => {
+                self.current_workspace = index;
+                false
+            }
+            None => {
+                let mut workspace = Workspace::new(name);
+                if let Some(first) = Some(home)
+                    .filter(|id| self.buffers.contains_key(*id))
+                    .or(self.active_buffer.as_ref())
+                {
+                    workspace.join(first);
+                }
+                self.workspaces.push(workspace);
+                self.current_workspace = self.workspaces.len() - 1;
+                true
+            }
+        };
+
+        let workspace = &self.workspaces[self.current_workspace];
+        if let Some(target) = workspace
+            .active
+            .clone()
+            .or(workspace.buffers.first().cloned())
+        {
+            self.switch_to_buffer(&target)?;
+        }
+        Ok(created)
+    }
+
+    /// Close workspace `name`, and the buffers no other workspace shows;
+    /// returns how many buffers were closed. Leaving it shows the default
+    /// workspace.
+    pub fn close_workspace(&mut self, name: &str) -> UIResult<usize> {
+        let index = self
+            .workspaces
+            .iter()
+            .position(|workspace| workspace.name == name)
+            .ok_or_else(|| UIError::InvalidOperation(format!("No workspace '{}'", name)))?;
+        if index == 0 {
+            return Err(UIError::InvalidOperation(format!(
+                "The '{}' workspace can't be closed",
+                self.workspaces[0].name
+            )));
+        }
+
+        let workspace = self.workspaces.remove(index);
+        if self.current_workspace == index {
+            self.current_workspace = 0;
+            let main = &self.workspaces[0];
+            if let Some(target) = main.active.clone().or(main.buffers.first().cloned()) {
+                self.switch_to_buffer(&target)?;
+            }
+        } else if self.current_workspace > index {
+            self.current_workspace -= 1;
+        }
+
+        let mut closed = 0;
+        for buffer_id in workspace.buffers {
+            let shown_elsewhere = self
+                .workspaces
+                .iter()
+                .any(|other| other.buffers.contains(&buffer_id));
+            if !shown_elsewhere && self.remove_buffer(&buffer_id).is_ok() {
+                closed += 1;
+            }
+        }
+        Ok(closed)
+    }
+
     /// Get the currently active buffer
     pub fn active_buffer(&mut self) -> Option<&mut Box<dyn Buffer>> {
         self.active_buffer
@@ -255,6 +642,20 @@ impl BufferManager {
             .collect()
     }
 
+    /// Get id, name and summary info for every buffer of the current
+    /// workspace, in ring order
+    pub fn buffer_infos(&self) -> Vec<(BufferId, String, BufferInfo)> {
+        self.workspaces[self.current_workspace]
+            .buffers
+            .iter()
+            .filter_map(|id| {
+                self.buffers
+                    .get(id)
+                    .map(|buffer| (id.clone(), buffer.name(), buffer.info()))
+            })
+            .collect()
+    }
+
     /// Get buffer names for completion (just the names)
     pub fn buffer_completion_names(&self) -> Vec<String> {
         self.buffer_order
@@ -312,9 +713,13 @@ impl BufferManager {
     pub fn create_podcast_list_buffer(
         &mut self,
         subscription_manager: Arc<SubscriptionManager<JsonStorage>>,
+        sort: crate::ui::buffers::podcast_list::PodcastSortMode,
+        inactive_after_months: u32,
     ) {
         let mut podcast_buffer = crate::ui::buffers::podcast_list::PodcastListBuffer::new();
         podcast_buffer.set_subscription_manager(subscription_manager);
+        podcast_buffer.set_sort_mode(sort);
+        podcast_buffer.set_inactive_after_months(inactive_after_months);
         let _ = self.add_buffer(Box::new(podcast_buffer));
     }
 
@@ -341,6 +746,44 @@ impl BufferManager {
         let _ = self.add_buffer(Box::new(whats_new_buffer));
     }
 
+    /// Create the Unplayed virtual buffer (all unplayed episodes across podcasts)
+    pub fn create_unplayed_buffer(
+        &mut self,
+        subscription_manager: Arc<SubscriptionManager<JsonStorage>>,
+        download_manager: Arc<DownloadManager<JsonStorage>>,
+    ) {
+        let mut unplayed_buffer = crate::ui::buffers::whats_new::WhatsNewBuffer::unplayed();
+        unplayed_buffer.set_managers(subscription_manager, download_manager);
+        let _ = self.add_buffer(Box::new(unplayed_buffer));
+    }
+
+    /// Create the Alerts buffer
+    pub fn create_alerts_buffer(&mut self) {
+        let _ = self.add_buffer(Box::new(crate::ui::buffers::alerts::AlertsBuffer::new()));
+    }
+
+    /// Create the Queue buffer
+    pub fn create_queue_buffer(&mut self) {
+        let _ = self.add_buffer(Box::new(crate::ui::buffers::queue::QueueBuffer::new()));
+    }
+
+    /// Create the History buffer
+    pub fn create_history_buffer(&mut self) {
+        let _ = self.add_buffer(Box::new(crate::ui::buffers::history::HistoryBuffer::new()));
+    }
+
+    /// Create the Search buffer
+    pub fn create_search_buffer(&mut self) {
+        let _ = self.add_buffer(Box::new(crate::ui::buffers::search::SearchBuffer::new()));
+    }
+
+    /// Create the Dashboard buffer
+    pub fn create_dashboard_buffer(&mut self) {
+        let _ = self.add_buffer(Box::new(
+            crate::ui::buffers::dashboard::DashboardBuffer::new(),
+        ));
+    }
+
     /// Create Sync buffer
     pub fn create_sync_buffer(
         &mut self,
@@ -381,15 +824,87 @@ impl BufferManager {
     pub fn create_playlist_picker_buffer(
         &mut self,
         playlists: Vec<(PlaylistId, String, usize)>,
-        podcast_id: crate::storage::PodcastId,
-        episode_id: crate::storage::EpisodeId,
+        targets: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
     ) {
-        let picker = crate::ui::buffers::playlist_picker::PlaylistPickerBuffer::new(
-            playlists, podcast_id, episode_id,
-        );
+        let picker =
+            crate::ui::buffers::playlist_picker::PlaylistPickerBuffer::new(playlists, targets);
         let _ = self.add_buffer(Box::new(picker));
     }
 
+    /// Get mutable reference to the playlist picker
+    pub fn get_playlist_picker_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::playlist_picker::PlaylistPickerBuffer> {
+        let buffer_id = "playlist-picker".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Create (or replace) the audio output device picker
+    pub fn create_audio_device_picker_buffer(
+        &mut self,
+        devices: Vec<String>,
+        current: Option<String>,
+    ) {
+        let id = "audio-device-picker".to_string();
+        let _ = self.remove_buffer(&id);
+        let picker =
+            crate::ui::buffers::audio_device_picker::AudioDevicePickerBuffer::new(devices, current);
+        let _ = self.add_buffer(Box::new(picker));
+    }
+
+    /// Create (or replace) the settings view of `podcast`
+    pub fn create_podcast_settings_buffer(
+        &mut self,
+        podcast: &crate::podcast::Podcast,
+        defaults: podcast_settings::SettingDefaults,
+    ) -> BufferId {
+        let buffer = podcast_settings::PodcastSettingsBuffer::new(podcast, defaults);
+        let buffer_id = buffer.id();
+        let _ = self.remove_buffer(&buffer_id);
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// The podcast settings view, if open
+    pub fn get_podcast_settings_buffer_mut(
+        &mut self,
+    ) -> Option<&mut podcast_settings::PodcastSettingsBuffer> {
+        self.buffers
+            .get_mut("podcast-settings")?
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    /// Create (or replace) the cast target picker
+    pub fn create_cast_picker_buffer(
+        &mut self,
+        targets: Vec<crate::audio::cast::CastTarget>,
+        current: Option<String>,
+    ) {
+        let id = "cast-picker".to_string();
+        let _ = self.remove_buffer(&id);
+        let picker = crate::ui::buffers::cast_picker::CastPickerBuffer::new(targets, current);
+        let _ = self.add_buffer(Box::new(picker));
+    }
+
+    /// Create the OPML preview buffer, replacing one from an earlier file
+    pub fn create_opml_preview_buffer(&mut self, preview: crate::podcast::OpmlPreview) {
+        let id = "opml-preview".to_string();
+        let _ = self.remove_buffer(&id);
+        let buffer = crate::ui::buffers::opml_preview::OpmlPreviewBuffer::new(preview);
+        let _ = self.add_buffer(Box::new(buffer));
+    }
+
+    /// Get mutable reference to the OPML preview buffer, if it is open
+    pub fn get_opml_preview_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::opml_preview::OpmlPreviewBuffer> {
+        let buffer_id = "opml-preview".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
     /// Get mutable reference to podcast list buffer
     pub fn get_podcast_list_buffer_mut(
         &mut self,
@@ -437,6 +952,58 @@ impl BufferManager {
             .and_then(|buffer| buffer.as_any_mut().downcast_mut())
     }
 
+    /// Get mutable reference to the Unplayed buffer, if it is open
+    pub fn get_unplayed_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::whats_new::WhatsNewBuffer> {
+        let buffer_id = "unplayed".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Get mutable reference to the Alerts buffer, if it is open
+    pub fn get_alerts_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::alerts::AlertsBuffer> {
+        let buffer_id = "alerts".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Get mutable reference to the Queue buffer, if it is open
+    pub fn get_queue_buffer_mut(&mut self) -> Option<&mut crate::ui::buffers::queue::QueueBuffer> {
+        let buffer_id = "queue".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Get mutable reference to the History buffer, if it is open
+    pub fn get_history_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::history::HistoryBuffer> {
+        let buffer_id = "history".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Get mutable reference to the Search buffer, if it is open
+    pub fn get_search_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::search::SearchBuffer> {
+        let buffer_id = "search".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
+    /// Get mutable reference to the Dashboard buffer, if it is open
+    pub fn get_dashboard_buffer_mut(
+        &mut self,
+    ) -> Option<&mut crate::ui::buffers::dashboard::DashboardBuffer> {
+        let buffer_id = "dashboard".to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
     /// Get mutable reference to Sync buffer
     pub fn get_sync_buffer_mut(&mut self) -> Option<&mut crate::ui::buffers::sync::SyncBuffer> {
         let buffer_id = "sync".to_string();
@@ -484,15 +1051,164 @@ impl BufferManager {
         let mut episode_buffer =
             crate::ui::buffers::episode_list::EpisodeListBuffer::new(podcast_name, podcast_id);
         episode_buffer.set_managers(subscription_manager, download_manager);
-        let _ = self.add_buffer(Box::new(episode_buffer));
+        let id = episode_buffer.id();
+        if let Some(view) = self.recycled_episode_views.remove(&id) {
+            episode_buffer.restore_view_state(view);
+        }
+        if self.add_buffer(Box::new(episode_buffer)).is_ok() {
+            self.recycle_episode_buffers(&id);
+        }
+    }
+
+    /// Close least recently used episode buffers beyond the configured cap,
+    /// remembering their view state. Never closes `keep` or the active buffer.
+    /// Returns the IDs of the buffers that were closed.
+    pub fn recycle_episode_buffers(&mut self, keep: &BufferId) -> Vec<BufferId> {
+        let mut closed = Vec::new();
+        if self.max_episode_buffers == 0 {
+            return closed;
+        }
+
+        while self.episode_buffer_lru.len() > self.max_episode_buffers {
+            let Some(victim) = self
+                .episode_buffer_lru
+                .iter()
+                .find(|id| *id != keep && Some(*id) != self.active_buffer.as_ref())
+                .cloned()
+            else {
+                break;
+            };
+
+            if let Some(view) = self
+                .buffers
+                .get(&victim)
+                .and_then(|b| b.as_any().downcast_ref::<episode_list::EpisodeListBuffer>())
+                .map(|b| b.view_state())
+            {
+                self.recycled_episode_views.insert(victim.clone(), view);
+            }
+            if self.remove_buffer(&victim).is_err() {
+                self.episode_buffer_lru.retain(|id| *id != victim);
+            }
+            closed.push(victim);
+        }
+        closed
     }
 
     /// Create episode detail buffer
     pub fn create_episode_detail_buffer(&mut self, episode: crate::podcast::Episode) {
-        let episode_buffer = crate::ui::buffers::episode_detail::EpisodeDetailBuffer::new(episode);
+        let podcast = self
+            .get_podcast_list_buffer_mut()
+            .and_then(|list| list.podcast(&episode.podcast_id));
+        let language = podcast.and_then(|podcast| podcast.language.clone());
+        let quality = podcast
+            .and_then(|podcast| podcast.download_quality)
+            .unwrap_or_default();
+        let mut episode_buffer =
+            crate::ui::buffers::episode_detail::EpisodeDetailBuffer::new(episode);
+        episode_buffer.set_podcast_language(language);
+        episode_buffer.set_download_quality(quality);
+        if let Some(view) = self.episode_detail_views.remove(&episode_buffer.id()) {
+            episode_buffer.restore_view_state(view);
+        }
         let _ = self.add_buffer(Box::new(episode_buffer));
     }
 
+    /// Create the detail buffer for a podcast, replacing a stale one, and return its ID
+    pub fn create_podcast_detail_buffer(
+        &mut self,
+        podcast: crate::podcast::Podcast,
+        stats: Option<crate::podcast::PodcastStats>,
+    ) -> BufferId {
+        let buffer = crate::ui::buffers::podcast_detail::PodcastDetailBuffer::new(podcast, stats);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Create (or replace) the refresh diff buffer for a podcast and return its ID
+    pub fn create_refresh_diff_buffer(&mut self, diff: crate::podcast::RefreshDiff) -> BufferId {
+        let buffer = crate::ui::buffers::refresh_diff::RefreshDiffBuffer::new(diff);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Create (or replace) the cleanup preview buffer and return its ID
+    pub fn create_cleanup_preview_buffer(
+        &mut self,
+        plan: crate::download::CleanupPlan,
+    ) -> BufferId {
+        let buffer = crate::ui::buffers::cleanup_preview::CleanupPreviewBuffer::new(plan);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Create (or replace) the audio diagnostics buffer and return its ID
+    pub fn create_audio_diagnostics_buffer(
+        &mut self,
+        diagnostics: crate::audio::probe::AudioDiagnostics,
+    ) -> BufferId {
+        let buffer =
+            crate::ui::buffers::audio_diagnostics::AudioDiagnosticsBuffer::new(diagnostics);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Create (or replace) the config report buffer and return its ID
+    pub fn create_config_report_buffer(
+        &mut self,
+        issues: Vec<crate::config::ConfigIssue>,
+    ) -> BufferId {
+        let buffer = crate::ui::buffers::config_report::ConfigReportBuffer::new(issues);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Create (or replace) the release notes buffer and return its ID
+    pub fn create_release_notes_buffer(
+        &mut self,
+        current_version: String,
+        releases: Vec<crate::update_check::Release>,
+    ) -> BufferId {
+        let buffer =
+            crate::ui::buffers::release_notes::ReleaseNotesBuffer::new(current_version, releases);
+        let buffer_id = buffer.id();
+        if self.buffers.contains_key(&buffer_id) {
+            let _ = self.remove_buffer(&buffer_id);
+        }
+        let _ = self.add_buffer(Box::new(buffer));
+        buffer_id
+    }
+
+    /// Get mutable reference to a podcast detail buffer by ID
+    pub fn get_podcast_detail_buffer_mut_by_id(
+        &mut self,
+        buffer_id: &str,
+    ) -> Option<&mut crate::ui::buffers::podcast_detail::PodcastDetailBuffer> {
+        let buffer_id = buffer_id.to_string();
+        self.get_buffer(&buffer_id)
+            .and_then(|buffer| buffer.as_any_mut().downcast_mut())
+    }
+
     /// Create a discovery buffer (search or trending) in loading state.
     ///
     /// `buffer_id` must be unique (e.g. `"discovery-rust"` or `"discovery-trending"`).
@@ -532,11 +1248,16 @@ impl BufferManager {
             .and_then(|buffer| buffer.as_any_mut().downcast_mut())
     }
 
-    /// Replace the watch receiver in the NowPlaying buffer (called from #141).
+    /// Replace the watch receiver in the NowPlaying buffer (called from #141),
+    /// and share it with the open and future list buffers.
     pub fn set_now_playing_status_rx(
         &mut self,
         rx: tokio::sync::watch::Receiver<crate::audio::PlaybackStatus>,
     ) {
+        for buffer in self.buffers.values_mut() {
+            buffer.set_playback_status_rx(rx.clone());
+        }
+        self.playback_status_rx = Some(rx.clone());
         if let Some(buf) = self.get_now_playing_buffer_mut() {
             buf.set_status_rx(rx);
         }
@@ -678,6 +1399,125 @@ mod tests {
         assert_eq!(names[0].0, help_id);
     }
 
+    #[test]
+    fn test_switching_queues_the_focused_buffers_background_reload() {
+        // Arrange
+        let mut manager = BufferManager::new();
+        let help = HelpBuffer::new();
+        let help_id = help.id();
+        let downloads = downloads::DownloadsBuffer::new();
+        let downloads_id = downloads.id();
+        manager.add_buffer(Box::new(help)).unwrap();
+        manager.add_buffer(Box::new(downloads)).unwrap();
+
+        // Act — focus the downloads buffer twice, the help buffer once
+        manager.switch_to_buffer(&downloads_id).unwrap();
+        manager.switch_to_buffer(&help_id).unwrap();
+        manager.switch_to_buffer(&downloads_id).unwrap();
+
+        // Assert
+        assert_eq!(
+            manager.take_pending_refreshes(),
+            vec![BufferRefreshType::Downloads]
+        );
+        assert!(manager.take_pending_refreshes().is_empty());
+        assert_eq!(
+            manager.open_buffer_refreshes(),
+            vec![BufferRefreshType::Downloads]
+        );
+    }
+
+    #[test]
+    fn test_buffer_infos_in_order_with_item_counts() {
+        // Arrange
+        let mut manager = BufferManager::new();
+        let help = HelpBuffer::with_content(
+            "notes".to_string(),
+            vec!["one".to_string(), "two".to_string()],
+        );
+        manager.add_buffer(Box::new(help)).unwrap();
+
+        // Act
+        let infos = manager.buffer_infos();
+
+        // Assert
+        assert_eq!(infos.len(), 1);
+        let (_, _, info) = &infos[0];
+        assert_eq!(info.kind, "Help");
+        assert_eq!(info.item_count, Some(2));
+        assert!(info.memory_bytes >= 6);
+    }
+
+    #[test]
+    fn test_recycle_episode_buffers_closes_least_recently_used() {
+        use crate::ui::buffers::episode_list::EpisodeListBuffer;
+
+        // Arrange — cap of 2, three episode buffers, "a" used most recently
+        let mut manager = BufferManager::new();
+        manager.set_max_episode_buffers(2);
+        for name in ["a", "b", "c"] {
+            let buffer = EpisodeListBuffer::new(name.to_string(), PodcastId::new());
+            manager.add_buffer(Box::new(buffer)).unwrap();
+        }
+        manager.switch_to_buffer(&"episodes-a".to_string()).unwrap();
+
+        // Act
+        let closed = manager.recycle_episode_buffers(&"episodes-c".to_string());
+
+        // Assert — "b" was the least recently used
+        assert_eq!(closed, vec!["episodes-b".to_string()]);
+        assert!(manager.recycled_episode_views.contains_key("episodes-b"));
+        assert_eq!(manager.get_buffer_ids(), vec!["episodes-a", "episodes-c"]);
+    }
+
+    #[test]
+    fn test_episode_detail_fold_state_survives_reopen() {
+        use crate::podcast::Episode;
+
+        // Arrange
+        let mut manager = BufferManager::new();
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Notes".to_string(),
+            "https://example.com/a.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.transcript = Some("line\n".repeat(40));
+        let buffer_id = format!("episode-detail-{}", episode.id);
+        manager.create_episode_detail_buffer(episode.clone());
+        let detail = manager
+            .get_episode_detail_buffer_mut_by_id(&buffer_id)
+            .unwrap();
+        detail.toggle_raw();
+        let view = detail.view_state();
+
+        // Act
+        manager.remove_buffer(&buffer_id).unwrap();
+        manager.create_episode_detail_buffer(episode);
+
+        // Assert
+        let reopened = manager
+            .get_episode_detail_buffer_mut_by_id(&buffer_id)
+            .unwrap();
+        assert_eq!(reopened.view_state(), view);
+    }
+
+    #[test]
+    fn test_recycle_episode_buffers_unlimited_when_zero() {
+        use crate::ui::buffers::episode_list::EpisodeListBuffer;
+
+        let mut manager = BufferManager::new();
+        for name in ["a", "b", "c"] {
+            let buffer = EpisodeListBuffer::new(name.to_string(), PodcastId::new());
+            manager.add_buffer(Box::new(buffer)).unwrap();
+        }
+
+        assert!(manager
+            .recycle_episode_buffers(&"episodes-c".to_string())
+            .is_empty());
+        assert_eq!(manager.get_buffer_ids().len(), 3);
+    }
+
     #[test]
     fn test_next_previous_buffer() {
         let mut manager = BufferManager::new();
@@ -701,6 +1541,47 @@ mod tests {
         assert_eq!(manager.active_buffer_id(), initial_id.as_ref());
     }
 
+    #[test]
+    fn test_workspaces_keep_their_own_buffer_rings() {
+        use crate::ui::buffers::episode_list::EpisodeListBuffer;
+
+        // Arrange — "a" and "b" open in main
+        let mut manager = BufferManager::new();
+        for name in ["a", "b"] {
+            let buffer = EpisodeListBuffer::new(name.to_string(), PodcastId::new());
+            manager.add_buffer(Box::new(buffer)).unwrap();
+        }
+        manager.switch_to_buffer(&"episodes-b".to_string()).unwrap();
+        let home = "episodes-a".to_string();
+
+        // Act — a "news" workspace starting from "a", with "c" opened there
+        assert!(manager.switch_workspace("news", &home).unwrap());
+        let c = EpisodeListBuffer::new("c".to_string(), PodcastId::new());
+        manager.add_buffer(Box::new(c)).unwrap();
+        manager.switch_to_buffer(&"episodes-c".to_string()).unwrap();
+
+        // Assert — the ring cycles a and c only
+        manager.next_buffer().unwrap();
+        assert_eq!(manager.active_buffer_id(), Some(&home));
+        manager.next_buffer().unwrap();
+        assert_eq!(manager.active_buffer_id().unwrap(), "episodes-c");
+        let shown: Vec<_> = manager.buffer_infos().into_iter().map(|i| i.0).collect();
+        assert_eq!(shown, vec!["episodes-a", "episodes-c"]);
+
+        // Back in main, where "b" was left showing
+        assert!(!manager.switch_workspace("main", &home).unwrap());
+        assert_eq!(manager.active_buffer_id().unwrap(), "episodes-b");
+        assert_eq!(
+            manager.workspaces(),
+            vec![("main".to_string(), 2), ("news".to_string(), 2)]
+        );
+
+        // Closing "news" closes "c", which only it showed
+        assert_eq!(manager.close_workspace("news").unwrap(), 1);
+        assert_eq!(manager.get_buffer_ids(), vec!["episodes-a", "episodes-b"]);
+        assert!(manager.close_workspace("main").is_err());
+    }
+
     #[test]
     fn test_set_theme_all_propagates_to_all_buffers() {
         use crate::ui::themes::Theme;
@@ -786,4 +1667,55 @@ mod tests {
         let tracker = buf.as_any().downcast_ref::<ThemeTracker>().unwrap();
         assert_eq!(tracker.last_theme_name, "Light");
     }
+
+    #[test]
+    fn test_hide_explicit_applies_to_existing_and_new_list_buffers() {
+        // Arrange
+        let mut clean = crate::podcast::Podcast::new(
+            "Morning Show".to_string(),
+            "https://example.com/clean.xml".to_string(),
+        );
+        clean.explicit = false;
+        let mut late = crate::podcast::Podcast::new(
+            "Late Show".to_string(),
+            "https://example.com/late.xml".to_string(),
+        );
+        late.explicit = true;
+
+        let mut manager = BufferManager::new();
+        manager
+            .add_buffer(Box::new(podcast_list::PodcastListBuffer::new()))
+            .unwrap();
+        let list = manager.get_podcast_list_buffer_mut().unwrap();
+        list.set_podcasts(vec![clean.clone(), late.clone()]);
+
+        // Act
+        manager.set_hide_explicit_all(true);
+
+        // Assert — only the clean podcast remains selectable
+        let list = manager.get_podcast_list_buffer_mut().unwrap();
+        assert_eq!(list.selected_podcast().map(|p| &p.id), Some(&clean.id));
+        list.handle_action(UIAction::MoveDown);
+        assert_eq!(list.selected_podcast().map(|p| &p.id), Some(&clean.id));
+        assert!(list.podcast(&late.id).is_some(), "hidden, not dropped");
+
+        // Buffers created later start with the flag applied
+        manager
+            .add_buffer(Box::new(whats_new::WhatsNewBuffer::new(10)))
+            .unwrap();
+        let mut episode = crate::podcast::Episode::new(
+            late.id.clone(),
+            "Uncut".to_string(),
+            "https://example.com/uncut.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.explicit = true;
+        let whats_new = manager.get_whats_new_buffer_mut().unwrap();
+        whats_new.set_episodes(vec![crate::ui::events::AggregatedEpisode {
+            podcast_id: late.id.clone(),
+            podcast_title: late.title.clone(),
+            episode,
+        }]);
+        assert!(whats_new.selected_episode().is_none());
+    }
 }
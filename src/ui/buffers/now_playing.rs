@@ -5,6 +5,7 @@
 // render tick (~60 fps). The AudioManager (~4 Hz) pushes status into the watch
 // channel; the buffer just drains whatever is already there.
 
+use chrono::Utc;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Modifier,
@@ -18,11 +19,11 @@ use tokio::sync::watch;
 use crate::{
     audio::{PlaybackState, PlaybackStatus},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{Buffer, BufferId, BufferInfo},
         themes::Theme,
         UIAction, UIComponent,
     },
-    utils::time::format_duration,
+    utils::time::{format_duration, format_time_left, DisplayZone},
 };
 
 /// Buffer that displays real-time playback information.
@@ -63,6 +64,12 @@ impl NowPlayingBuffer {
         self.status_rx = rx;
     }
 
+    /// The latest status from the audio thread, whether or not this buffer
+    /// has been rendered since.
+    pub fn current_status(&self) -> PlaybackStatus {
+        self.status_rx.borrow().clone()
+    }
+
     /// Set the human-readable episode title and podcast name for display.
     ///
     /// Called from `AppEvent::PlaybackStarted` in app.rs (wired in #141).
@@ -130,14 +137,15 @@ impl NowPlayingBuffer {
 
     /// Render the playing / paused state (episode info, progress, volume, hints).
     fn render_playing(&self, frame: &mut Frame, inner: Rect) {
-        // We need at least 7 rows to show everything; fall back to compact if smaller.
-        if inner.height < 7 {
+        // We need at least 8 rows to show everything; fall back to compact if smaller.
+        if inner.height < 8 {
             self.render_compact(frame, inner);
             return;
         }
 
         // ── Layout ──────────────────────────────────────────────────────────
-        // [0] info section: podcast name, episode title, blank, state + volume  (4 rows)
+        // [0] info section: podcast name, episode title, blank, state + volume,
+        //     finishing estimate                                                 (5 rows)
         // [1] blank                                                              (1 row)
         // [2] progress gauge                                                     (1 row)
         // [3] spacer (fills remaining space)
@@ -145,7 +153,7 @@ impl NowPlayingBuffer {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(4),
+                Constraint::Length(5),
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Fill(1),
@@ -179,12 +187,23 @@ impl NowPlayingBuffer {
         let volume_pct = (self.status.volume * 100.0).round() as u8;
         let filled = (self.status.volume * 10.0).round() as usize;
         let empty = 10usize.saturating_sub(filled);
-        let volume_bar = format!(
-            "Volume: {}{}  {}%",
+        let attenuation = if self.status.muted {
+            "  (muted)"
+        } else if self.status.ducked {
+            "  (ducked)"
+        } else {
+            ""
+        };
+        let mut volume_bar = format!(
+            "Volume: {}{}  {}%{}",
             "█".repeat(filled),
             "░".repeat(empty),
-            volume_pct
+            volume_pct,
+            attenuation
         );
+        if (self.status.speed - 1.0).abs() > f32::EPSILON {
+            volume_bar.push_str(&format!("    Speed: {}×", self.status.speed));
+        }
 
         let state_line = Line::from(vec![
             Span::raw("  "),
@@ -195,7 +214,26 @@ impl NowPlayingBuffer {
             Span::styled(volume_bar, self.theme.subtext_style()),
         ]);
 
-        let info_lines = vec![podcast_line, episode_line, Line::from(""), state_line];
+        let finish_line = match finish_estimate(
+            self.status.position,
+            self.status.duration,
+            self.status.speed,
+            self.theme.time_zone,
+        ) {
+            Some(estimate) if self.status.state != PlaybackState::Stopped => Line::from(vec![
+                Span::raw("  "),
+                Span::styled(estimate, self.theme.subtext_style()),
+            ]),
+            _ => Line::from(""),
+        };
+
+        let info_lines = vec![
+            podcast_line,
+            episode_line,
+            Line::from(""),
+            state_line,
+            finish_line,
+        ];
         frame.render_widget(
             Paragraph::new(info_lines).style(self.theme.text_style()),
             chunks[0],
@@ -320,6 +358,14 @@ impl Buffer for NowPlayingBuffer {
         false
     }
 
+    fn info(&self) -> BufferInfo {
+        let info = BufferInfo::new("Now Playing");
+        match &self.podcast_name {
+            Some(name) => info.with_podcast(name.clone()),
+            None => info,
+        }
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Now Playing Buffer:".to_string(),
@@ -360,6 +406,27 @@ fn progress_ratio_and_label(
     }
 }
 
+/// "Finishes at 21:40 · 23 min left" for the live position at `speed`, with
+/// the wall-clock time read in `zone`
+fn finish_estimate(
+    position: Option<Duration>,
+    duration: Option<Duration>,
+    speed: f32,
+    zone: DisplayZone,
+) -> Option<String> {
+    let (position, duration) = (position?, duration?);
+    if duration.is_zero() || speed <= 0.0 {
+        return None;
+    }
+    let left = duration.saturating_sub(position).div_f32(speed);
+    let finish = Utc::now() + chrono::Duration::seconds(left.as_secs() as i64);
+    Some(format!(
+        "Finishes at {} · {}",
+        zone.localize(finish, None).format("%H:%M"),
+        format_time_left(left.as_secs() as u32)
+    ))
+}
+
 // ── Tests ────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -471,6 +538,23 @@ mod tests {
         assert_eq!(label, "0:30 / 1:00");
     }
 
+    #[test]
+    fn test_finish_estimate_counts_down_from_the_live_position() {
+        // Arrange
+        let pos = Duration::from_secs(600);
+        let dur = Duration::from_secs(600 + 23 * 60);
+
+        // Act
+        let estimate = finish_estimate(Some(pos), Some(dur), 1.0, DisplayZone::Utc).unwrap();
+        let faster = finish_estimate(Some(pos), Some(dur), 2.0, DisplayZone::Utc).unwrap();
+
+        // Assert
+        assert!(estimate.starts_with("Finishes at "));
+        assert!(estimate.ends_with(" · 23 min left"));
+        assert!(faster.ends_with(" · 11 min left"));
+        assert!(finish_estimate(Some(pos), None, 1.0, DisplayZone::Utc).is_none());
+    }
+
     #[test]
     fn test_now_playing_buffer_status_rx_update() {
         // Arrange
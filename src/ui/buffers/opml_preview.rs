@@ -0,0 +1,370 @@
+// OPML preview buffer - choose which feeds of an OPML file to import
+//
+// `import-opml` (S-A) reads the file first and lists its feeds here with a
+// checkbox each. New feeds start checked; ones already subscribed are shown
+// as such and left out. Space or Enter toggles the selected feed,
+// `:opml-select all|none` all of them, and S-A imports the checked ones.
+
+use std::collections::HashSet;
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::{
+    podcast::OpmlPreview,
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Buffer listing the feeds of an OPML file before importing
+pub struct OpmlPreviewBuffer {
+    id: String,
+    preview: OpmlPreview,
+    /// Per feed of `preview`, whether it will be imported
+    checked: Vec<bool>,
+    selected_index: Option<usize>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    theme: Theme,
+}
+
+impl OpmlPreviewBuffer {
+    pub fn new(preview: OpmlPreview) -> Self {
+        let checked = preview.feeds.iter().map(|feed| !feed.subscribed).collect();
+        Self {
+            id: "opml-preview".to_string(),
+            selected_index: (!preview.feeds.is_empty()).then_some(0),
+            preview,
+            checked,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn preview(&self) -> &OpmlPreview {
+        &self.preview
+    }
+
+    /// Feed URLs that will be imported
+    pub fn selected_urls(&self) -> HashSet<String> {
+        self.preview
+            .feeds
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, checked)| **checked)
+            .map(|(feed, _)| feed.url.clone())
+            .collect()
+    }
+
+    /// Check (or uncheck) every feed not yet subscribed
+    pub fn set_all(&mut self, checked: bool) {
+        for (feed, check) in self.preview.feeds.iter().zip(self.checked.iter_mut()) {
+            *check = checked && !feed.subscribed;
+        }
+    }
+
+    fn toggle_selected(&mut self) -> UIAction {
+        let Some(index) = self.selected_index else {
+            return UIAction::ShowMessage("No feed selected".to_string());
+        };
+        let feed = &self.preview.feeds[index];
+        if feed.subscribed {
+            return UIAction::ShowMessage(format!("Already subscribed to {}", feed.title));
+        }
+        self.checked[index] = !self.checked[index];
+        UIAction::Render
+    }
+
+    fn confirm(&self) -> UIAction {
+        if self.checked.iter().any(|checked| *checked) {
+            UIAction::ConfirmOpmlImport
+        } else {
+            UIAction::ShowMessage("No feeds checked (Space checks the selected one)".to_string())
+        }
+    }
+
+    fn select_previous(&mut self) {
+        let len = self.preview.feeds.len();
+        if len == 0 {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(0) => Some(len - 1),
+            Some(i) => Some(i - 1),
+            None => Some(0),
+        };
+    }
+
+    fn select_next(&mut self) {
+        let len = self.preview.feeds.len();
+        if len == 0 {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(i) if i >= len - 1 => Some(0),
+            Some(i) => Some(i + 1),
+            None => Some(0),
+        };
+    }
+}
+
+impl Buffer for OpmlPreviewBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "OPML Import".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(
+            self.scroll_area,
+            self.selected_index?,
+            self.preview.feeds.len(),
+        )
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("OPML Import", &self.preview.feeds, |feed| {
+            feed.title.len() + feed.url.len()
+        })
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "OPML Import Commands:".to_string(),
+            "  C-n, ↓    Next feed".to_string(),
+            "  C-p, ↑    Previous feed".to_string(),
+            "  Space     Check / uncheck feed".to_string(),
+            "  S-A       Import the checked feeds".to_string(),
+            "  :opml-select all|none  Check or uncheck every new feed".to_string(),
+            "  C-h       Show help".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for OpmlPreviewBuffer {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                if !self.preview.feeds.is_empty() {
+                    self.selected_index = Some(0);
+                    self.scroll_offset = 0;
+                }
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                if !self.preview.feeds.is_empty() {
+                    self.selected_index = Some(self.preview.feeds.len() - 1);
+                }
+                UIAction::Render
+            }
+            UIAction::SelectItem => self.toggle_selected(),
+            UIAction::ImportOpml => self.confirm(),
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.scroll_area = area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()))
+            .border_style(if self.focused {
+                self.theme.border_focused_style()
+            } else {
+                self.theme.border_style()
+            });
+
+        if self.preview.feeds.is_empty() {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let message = Paragraph::new(format!("No feeds found in {}.", self.preview.source))
+                .style(self.theme.default_style())
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(Wrap { trim: true });
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let visible_height = block.inner(area).height.saturating_sub(1) as usize; // -1 for header
+        if let Some(selected) = self.selected_index {
+            if selected >= self.scroll_offset + visible_height {
+                self.scroll_offset = selected.saturating_sub(visible_height.saturating_sub(1));
+            } else if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            }
+        }
+
+        let header = Row::new(vec![Cell::from(""), Cell::from("Feed"), Cell::from("URL")]).style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let end = (self.scroll_offset + visible_height).min(self.preview.feeds.len());
+        let rows: Vec<Row> = self.preview.feeds[self.scroll_offset..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, feed)| {
+                let index = self.scroll_offset + offset;
+                let style = if Some(index) == self.selected_index {
+                    self.theme.selected_style()
+                } else if feed.subscribed {
+                    self.theme.muted_style()
+                } else {
+                    self.theme.default_style()
+                };
+                let checkbox = if feed.subscribed {
+                    "[=]"
+                } else if self.checked[index] {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let title = if feed.subscribed {
+                    format!("{} (subscribed)", text_layout::cell(&feed.title, 60))
+                } else {
+                    text_layout::cell(&feed.title, 70)
+                };
+                Row::new(vec![
+                    Cell::from(checkbox),
+                    Cell::from(title),
+                    Cell::from(text_layout::cell(&feed.url, 80)),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Percentage(45),
+                Constraint::Percentage(55),
+            ],
+        )
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+        frame.render_widget(table, area);
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "Import {}: {} of {} checked",
+            self.preview.source,
+            self.checked.iter().filter(|checked| **checked).count(),
+            self.preview.feeds.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::{OpmlDocument, OpmlPreviewFeed};
+
+    fn preview() -> OpmlPreview {
+        let feed = |title: &str, subscribed| OpmlPreviewFeed {
+            url: format!("https://example.com/{}.xml", title.to_lowercase()),
+            title: title.to_string(),
+            description: None,
+            subscribed,
+        };
+        OpmlPreview {
+            source: "friends.opml".to_string(),
+            document: OpmlDocument {
+                version: "2.0".to_string(),
+                head: None,
+                outlines: Vec::new(),
+            },
+            feeds: vec![
+                feed("Known", true),
+                feed("New", false),
+                feed("Other", false),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_subscribed_feeds_start_unchecked_and_stay_so() {
+        // Arrange
+        let mut buffer = OpmlPreviewBuffer::new(preview());
+
+        // Act
+        let toggled_known = buffer.handle_action(UIAction::SelectItem);
+        buffer.handle_action(UIAction::MoveToBottom);
+        buffer.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert!(matches!(toggled_known, UIAction::ShowMessage(_)));
+        assert_eq!(
+            buffer.selected_urls(),
+            HashSet::from(["https://example.com/new.xml".to_string()])
+        );
+        assert_eq!(buffer.title(), "Import friends.opml: 1 of 3 checked");
+        assert_eq!(
+            buffer.handle_action(UIAction::ImportOpml),
+            UIAction::ConfirmOpmlImport
+        );
+    }
+
+    #[test]
+    fn test_nothing_checked_is_not_imported() {
+        let mut buffer = OpmlPreviewBuffer::new(preview());
+
+        buffer.set_all(false);
+
+        assert!(buffer.selected_urls().is_empty());
+        assert!(matches!(
+            buffer.handle_action(UIAction::ImportOpml),
+            UIAction::ShowMessage(_)
+        ));
+        buffer.set_all(true);
+        assert_eq!(buffer.selected_urls().len(), 2);
+    }
+}
@@ -1,19 +1,61 @@
 use ratatui::{
-    layout::Rect,
-    widgets::{Block, Borders, List, ListItem},
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 use crate::{
-    playlist::{manager::PlaylistManager, Playlist, PlaylistId, PlaylistType},
+    playlist::{
+        manager::PlaylistManager, queue::QueueEntry, PlaybackModes, Playlist, PlaylistId,
+        PlaylistType,
+    },
+    storage::EpisodeId,
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{
+            capabilities::{Playable, Refreshable},
+            Buffer, BufferId, BufferInfo,
+        },
+        components::ScrollPosition,
+        glyphs::StatusGlyph,
+        text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
+    utils::{fs::format_file_size, time::format_duration},
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Episode facts behind the statistics header, looked up by the app
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlaylistEntryDetails {
+    pub duration: Option<u32>,
+    pub file_size: Option<u64>,
+    pub downloaded: bool,
+}
+
+/// Totals over the playlist's entries
+#[derive(Debug, Default, PartialEq, Eq)]
+struct PlaylistStats {
+    total_duration: u64,
+    /// Entries whose duration the feed didn't give
+    unknown_duration: usize,
+    total_size: u64,
+    downloaded: usize,
+    not_downloaded: usize,
+}
+
+/// `3h 05m` / `42m` for totals that can run past a day
+fn format_total_duration(seconds: u64) -> String {
+    let minutes = seconds.div_ceil(60);
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
 pub struct PlaylistDetailBuffer {
     id: String,
     playlist_id: PlaylistId,
@@ -22,10 +64,16 @@ pub struct PlaylistDetailBuffer {
     episodes: Vec<crate::playlist::PlaylistEpisode>,
     selected_index: Option<usize>,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     playlist_manager: Option<Arc<PlaylistManager>>,
     /// True when this buffer holds a smart (dynamic) playlist
     is_smart: bool,
+    entry_details: HashMap<EpisodeId, PlaylistEntryDetails>,
+    /// `playlist.duration_budget_minutes`, in seconds
+    duration_budget: Option<u64>,
+    playback_modes: PlaybackModes,
 }
 
 impl PlaylistDetailBuffer {
@@ -46,9 +94,13 @@ impl PlaylistDetailBuffer {
             episodes: Vec::new(),
             selected_index: None,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             playlist_manager: None,
             is_smart: false,
+            entry_details: HashMap::new(),
+            duration_budget: None,
+            playback_modes: PlaybackModes::default(),
         }
     }
 
@@ -59,6 +111,7 @@ impl PlaylistDetailBuffer {
     pub fn set_playlist(&mut self, playlist: Playlist) {
         self.playlist_type = playlist.playlist_type;
         self.is_smart = playlist.smart_rules.is_some();
+        self.playback_modes = playlist.playback;
         self.episodes = playlist.episodes;
         if self.episodes.is_empty() {
             self.selected_index = None;
@@ -84,6 +137,90 @@ impl PlaylistDetailBuffer {
         }
     }
 
+    /// Durations, sizes and download state for the entries' episodes
+    pub fn set_entry_details(&mut self, details: HashMap<EpisodeId, PlaylistEntryDetails>) {
+        self.entry_details = details;
+    }
+
+    pub fn set_duration_budget(&mut self, minutes: Option<u32>) {
+        self.duration_budget = minutes.map(|minutes| minutes as u64 * 60);
+    }
+
+    fn stats(&self) -> PlaylistStats {
+        let mut stats = PlaylistStats::default();
+        for entry in &self.episodes {
+            let details = self
+                .entry_details
+                .get(&entry.episode_id)
+                .copied()
+                .unwrap_or_default();
+            match details.duration {
+                Some(duration) => stats.total_duration += duration as u64,
+                None => stats.unknown_duration += 1,
+            }
+            stats.total_size += details.file_size.unwrap_or(0);
+            if details.downloaded {
+                stats.downloaded += 1;
+            } else {
+                stats.not_downloaded += 1;
+            }
+        }
+        stats
+    }
+
+    /// Totals line, plus the budget line when a budget is configured
+    fn stats_lines(&self) -> Vec<Line<'static>> {
+        let stats = self.stats();
+        let mut summary = format!(
+            "{} episodes · {} · {} · {} downloaded, {} not downloaded",
+            self.episodes.len(),
+            format_total_duration(stats.total_duration),
+            format_file_size(stats.total_size),
+            stats.downloaded,
+            stats.not_downloaded
+        );
+        if stats.unknown_duration > 0 {
+            summary.push_str(&format!(" · {} without duration", stats.unknown_duration));
+        }
+        let mut lines = vec![Line::from(Span::styled(summary, self.theme.text_style()))];
+
+        if let Some(budget) = self.duration_budget {
+            let line = if stats.total_duration > budget {
+                Span::styled(
+                    format!(
+                        "⚠ Over the {} budget by {}",
+                        format_total_duration(budget),
+                        format_duration((stats.total_duration - budget) as u32)
+                    ),
+                    self.theme.warning_style(),
+                )
+            } else {
+                Span::styled(
+                    format!(
+                        "Budget {}: {} left",
+                        format_total_duration(budget),
+                        format_duration((budget - stats.total_duration) as u32)
+                    ),
+                    self.theme.muted_style(),
+                )
+            };
+            lines.push(Line::from(line));
+        }
+        lines
+    }
+
+    pub fn playback_modes(&self) -> PlaybackModes {
+        self.playback_modes
+    }
+
+    pub fn set_playback_modes(&mut self, modes: PlaybackModes) {
+        self.playback_modes = modes;
+    }
+
+    pub fn playlist_name(&self) -> &str {
+        &self.playlist_name
+    }
+
     pub fn playlist_id(&self) -> &PlaylistId {
         &self.playlist_id
     }
@@ -104,6 +241,36 @@ impl PlaylistDetailBuffer {
             .and_then(|index| self.episodes.get(index))
     }
 
+    /// Move the selected entry to the index `target` picks from its index and
+    /// the entry count (`None` to stay put); the selection follows it
+    fn move_selected(&mut self, target: impl FnOnce(usize, usize) -> Option<usize>) -> UIAction {
+        if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
+            return UIAction::ShowMessage(
+                "Auto-generated playlists cannot be reordered".to_string(),
+            );
+        }
+        if self.is_smart {
+            return UIAction::ShowMessage("Smart playlists cannot be reordered".to_string());
+        }
+        let Some(from_idx) = self
+            .selected_index
+            .filter(|&index| index < self.episodes.len())
+        else {
+            return UIAction::None;
+        };
+        match target(from_idx, self.episodes.len()) {
+            Some(to_idx) if to_idx != from_idx => {
+                self.selected_index = Some(to_idx);
+                UIAction::TriggerReorderPlaylist {
+                    playlist_id: self.playlist_id.clone(),
+                    from_idx,
+                    to_idx,
+                }
+            }
+            _ => UIAction::None,
+        }
+    }
+
     fn select_previous(&mut self) {
         if self.episodes.is_empty() {
             return;
@@ -148,15 +315,35 @@ impl Buffer for PlaylistDetailBuffer {
         self
     }
 
+    fn as_playable(&mut self) -> Option<&mut dyn Playable> {
+        Some(self)
+    }
+
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.episodes.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Playlist", &self.episodes, |e| {
+            e.episode_title.as_ref().map_or(0, String::len)
+                + e.filename.as_ref().map_or(0, String::len)
+        })
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Playlist Detail Commands:".to_string(),
             "  ↑/↓      Navigate episodes".to_string(),
             "  Enter    View episode details".to_string(),
+            "  S-Enter  Play the playlist from this episode".to_string(),
             "  X        Remove episode from playlist".to_string(),
             "  Ctrl+↑   Move episode up".to_string(),
             "  Ctrl+↓   Move episode down".to_string(),
@@ -165,6 +352,72 @@ impl Buffer for PlaylistDetailBuffer {
     }
 }
 
+impl Playable for PlaylistDetailBuffer {
+    fn play_selected(&mut self) -> UIAction {
+        match self.selected_index {
+            Some(start) => UIAction::PlayPlaylist {
+                playlist_id: self.playlist_id.clone(),
+                entries: self
+                    .episodes
+                    .iter()
+                    .map(|entry| QueueEntry {
+                        podcast_id: entry.podcast_id.clone(),
+                        episode_id: entry.episode_id.clone(),
+                    })
+                    .collect(),
+                start,
+            },
+            None => UIAction::ShowError("No episode selected".into()),
+        }
+    }
+
+    fn mark_selected_played(&mut self, played: bool) -> UIAction {
+        if played {
+            if let Some(episode) = self.selected_episode() {
+                let title = episode
+                    .episode_title
+                    .clone()
+                    .or_else(|| episode.filename.clone())
+                    .unwrap_or_else(|| episode.episode_id.to_string());
+                UIAction::TriggerMarkPlayed {
+                    podcast_id: episode.podcast_id.clone(),
+                    episode_id: episode.episode_id.clone(),
+                    episode_title: title,
+                }
+            } else {
+                UIAction::ShowMessage("No episode selected".to_string())
+            }
+        } else {
+            if let Some(episode) = self.selected_episode() {
+                let title = episode
+                    .episode_title
+                    .clone()
+                    .or_else(|| episode.filename.clone())
+                    .unwrap_or_else(|| episode.episode_id.to_string());
+                UIAction::TriggerMarkUnplayed {
+                    podcast_id: episode.podcast_id.clone(),
+                    episode_id: episode.episode_id.clone(),
+                    episode_title: title,
+                }
+            } else {
+                UIAction::ShowMessage("No episode selected".to_string())
+            }
+        }
+    }
+}
+
+impl Refreshable for PlaylistDetailBuffer {
+    fn refresh(&mut self) -> UIAction {
+        if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
+            UIAction::RefreshAutoPlaylists
+        } else {
+            UIAction::RebuildPlaylistFiles {
+                playlist_id: self.playlist_id.clone(),
+            }
+        }
+    }
+}
+
 impl UIComponent for PlaylistDetailBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -186,6 +439,7 @@ impl UIComponent for PlaylistDetailBuffer {
                     UIAction::ShowMessage("No episode selected".to_string())
                 }
             }
+            UIAction::RefreshPodcast => self.refresh(),
             UIAction::DeleteDownloadedEpisode => {
                 if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
                     return UIAction::ShowMessage(
@@ -207,93 +461,11 @@ impl UIComponent for PlaylistDetailBuffer {
                     UIAction::ShowMessage("No episode selected".to_string())
                 }
             }
-            UIAction::MoveEpisodeUp => {
-                if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
-                    return UIAction::ShowMessage(
-                        "Auto-generated playlists cannot be reordered".to_string(),
-                    );
-                }
-                if self.is_smart {
-                    return UIAction::ShowMessage(
-                        "Smart playlists cannot be reordered".to_string(),
-                    );
-                }
-                match self.selected_index {
-                    Some(index) if index > 0 => {
-                        self.selected_index = Some(index - 1);
-                        UIAction::TriggerReorderPlaylist {
-                            playlist_id: self.playlist_id.clone(),
-                            from_idx: index,
-                            to_idx: index - 1,
-                        }
-                    }
-                    _ => UIAction::None,
-                }
-            }
+            UIAction::MoveEpisodeUp => self.move_selected(|index, _| index.checked_sub(1)),
             UIAction::MoveEpisodeDown => {
-                if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
-                    return UIAction::ShowMessage(
-                        "Auto-generated playlists cannot be reordered".to_string(),
-                    );
-                }
-                if self.is_smart {
-                    return UIAction::ShowMessage(
-                        "Smart playlists cannot be reordered".to_string(),
-                    );
-                }
-                match self.selected_index {
-                    Some(index) if index + 1 < self.episodes.len() => {
-                        self.selected_index = Some(index + 1);
-                        UIAction::TriggerReorderPlaylist {
-                            playlist_id: self.playlist_id.clone(),
-                            from_idx: index,
-                            to_idx: index + 1,
-                        }
-                    }
-                    _ => UIAction::None,
-                }
-            }
-            UIAction::Refresh | UIAction::RefreshPodcast => {
-                if matches!(self.playlist_type, PlaylistType::AutoGenerated { .. }) {
-                    UIAction::RefreshAutoPlaylists
-                } else {
-                    UIAction::RebuildPlaylistFiles {
-                        playlist_id: self.playlist_id.clone(),
-                    }
-                }
-            }
-            UIAction::MarkPlayed => {
-                if let Some(episode) = self.selected_episode() {
-                    let title = episode
-                        .episode_title
-                        .clone()
-                        .or_else(|| episode.filename.clone())
-                        .unwrap_or_else(|| episode.episode_id.to_string());
-                    UIAction::TriggerMarkPlayed {
-                        podcast_id: episode.podcast_id.clone(),
-                        episode_id: episode.episode_id.clone(),
-                        episode_title: title,
-                    }
-                } else {
-                    UIAction::ShowMessage("No episode selected".to_string())
-                }
-            }
-            UIAction::MarkUnplayed => {
-                if let Some(episode) = self.selected_episode() {
-                    let title = episode
-                        .episode_title
-                        .clone()
-                        .or_else(|| episode.filename.clone())
-                        .unwrap_or_else(|| episode.episode_id.to_string());
-                    UIAction::TriggerMarkUnplayed {
-                        podcast_id: episode.podcast_id.clone(),
-                        episode_id: episode.episode_id.clone(),
-                        episode_title: title,
-                    }
-                } else {
-                    UIAction::ShowMessage("No episode selected".to_string())
-                }
+                self.move_selected(|index, len| (index + 1 < len).then_some(index + 1))
             }
+            UIAction::MoveEpisodeToTop => self.move_selected(|_, _| Some(0)),
             _ => UIAction::None,
         }
     }
@@ -305,6 +477,8 @@ impl UIComponent for PlaylistDetailBuffer {
             self.theme.border_style()
         };
 
+        self.scroll_area = area;
+
         let items: Vec<ListItem> = if self.episodes.is_empty() {
             vec![ListItem::new("Playlist is empty")]
         } else {
@@ -319,7 +493,21 @@ impl UIComponent for PlaylistDetailBuffer {
                         .clone()
                         .or_else(|| episode.filename.clone())
                         .unwrap_or_else(|| episode.episode_id.to_string());
-                    let text = format!("{marker}{:03} {}", episode.order, display_name);
+                    // Playlist entries track file sync rather than episode status
+                    let glyph = if episode.file_synced {
+                        StatusGlyph::Downloaded
+                    } else {
+                        StatusGlyph::Queued
+                    };
+                    let text = Line::from(vec![
+                        Span::raw(marker),
+                        Span::styled(self.theme.glyph(glyph), self.theme.glyph_style(glyph)),
+                        Span::raw(format!(
+                            " {:03} {}",
+                            episode.order,
+                            text_layout::single_line(&display_name)
+                        )),
+                    ]);
                     if selected {
                         ListItem::new(text).style(self.theme.selected_style())
                     } else {
@@ -329,19 +517,40 @@ impl UIComponent for PlaylistDetailBuffer {
                 .collect()
         };
 
-        let title = if self.is_smart {
+        let mut title = if self.is_smart {
             format!("⚡ Playlist: {}", self.playlist_name)
         } else {
             format!("Playlist: {}", self.playlist_name)
         };
-        let list = List::new(items).block(
+        let modes = self.playback_modes.indicator();
+        if !modes.is_empty() {
+            title.push_str(&format!(" {}", modes));
+        }
+
+        // Statistics sit at the top of the same box as the entries
+        let stats = self.stats_lines();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(stats.len() as u16 + 1),
+                Constraint::Min(0),
+            ])
+            .split(area);
+        let header = Paragraph::new(stats).block(
             Block::default()
                 .title(title)
-                .borders(Borders::ALL)
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::TOP)
                 .border_style(border_style)
                 .title_style(self.theme.title_style()),
         );
-        frame.render_widget(list, area);
+        frame.render_widget(header, chunks[0]);
+
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(border_style),
+        );
+        frame.render_widget(list, chunks[1]);
     }
 
     fn title(&self) -> String {
@@ -390,6 +599,7 @@ mod tests {
             created: Utc::now(),
             last_updated: Utc::now(),
             smart_rules: None,
+            playback: Default::default(),
         });
 
         let action = buffer.handle_action(UIAction::SelectItem);
@@ -405,6 +615,131 @@ mod tests {
         }
     }
 
+    fn entry(title: &str) -> crate::playlist::PlaylistEpisode {
+        crate::playlist::PlaylistEpisode {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            episode_title: Some(title.to_string()),
+            added_at: Utc::now(),
+            order: 1,
+            file_synced: false,
+            filename: None,
+        }
+    }
+
+    #[test]
+    fn test_stats_total_entries_and_flag_budget_overrun() {
+        // Arrange — 40 min downloaded, 30 min not, one entry without details
+        let mut buffer =
+            PlaylistDetailBuffer::new(PlaylistId::new(), "Commute".to_string(), PlaylistType::User);
+        let entries = vec![entry("A"), entry("B"), entry("C")];
+        let mut details = HashMap::new();
+        details.insert(
+            entries[0].episode_id.clone(),
+            PlaylistEntryDetails {
+                duration: Some(40 * 60),
+                file_size: Some(30 * 1024 * 1024),
+                downloaded: true,
+            },
+        );
+        details.insert(
+            entries[1].episode_id.clone(),
+            PlaylistEntryDetails {
+                duration: Some(30 * 60),
+                file_size: Some(20 * 1024 * 1024),
+                downloaded: false,
+            },
+        );
+        buffer.set_evaluated_episodes(entries);
+        buffer.set_entry_details(details);
+
+        // Act
+        let stats = buffer.stats();
+
+        // Assert
+        assert_eq!(stats.total_duration, 70 * 60);
+        assert_eq!(stats.unknown_duration, 1);
+        assert_eq!(stats.total_size, 50 * 1024 * 1024);
+        assert_eq!((stats.downloaded, stats.not_downloaded), (1, 2));
+        assert_eq!(buffer.stats_lines().len(), 1);
+
+        buffer.set_duration_budget(Some(45));
+        let budget = buffer.stats_lines()[1].to_string();
+        assert_eq!(budget, "⚠ Over the 45m budget by 25:00");
+
+        buffer.set_duration_budget(Some(90));
+        let budget = buffer.stats_lines()[1].to_string();
+        assert_eq!(budget, "Budget 1h 30m: 20:00 left");
+    }
+
+    #[test]
+    fn test_play_episode_queues_playlist_from_selection() {
+        let playlist_id = PlaylistId::new();
+        let mut buffer = PlaylistDetailBuffer::new(
+            playlist_id.clone(),
+            "Commute".to_string(),
+            PlaylistType::User,
+        );
+        let entries = vec![entry("A"), entry("B")];
+        buffer.set_evaluated_episodes(entries.clone());
+        buffer.handle_action(UIAction::MoveDown);
+
+        let action = buffer.play_selected();
+
+        match action {
+            UIAction::PlayPlaylist {
+                playlist_id: actual,
+                entries: queued,
+                start,
+            } => {
+                assert_eq!(actual, playlist_id);
+                assert_eq!(start, 1);
+                assert_eq!(queued.len(), 2);
+                assert_eq!(queued[1].episode_id, entries[1].episode_id);
+            }
+            other => panic!("Expected PlayPlaylist, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_move_episode_to_top_reorders_and_follows_the_entry() {
+        // Arrange — the third entry is selected
+        let playlist_id = PlaylistId::new();
+        let mut buffer = PlaylistDetailBuffer::new(
+            playlist_id.clone(),
+            "Commute".to_string(),
+            PlaylistType::User,
+        );
+        buffer.set_evaluated_episodes(vec![entry("A"), entry("B"), entry("C")]);
+        buffer.handle_action(UIAction::MoveDown);
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let action = buffer.handle_action(UIAction::MoveEpisodeToTop);
+
+        // Assert
+        assert_eq!(
+            action,
+            UIAction::TriggerReorderPlaylist {
+                playlist_id,
+                from_idx: 2,
+                to_idx: 0,
+            }
+        );
+        assert_eq!(buffer.selected_index, Some(0));
+        assert_eq!(
+            buffer.handle_action(UIAction::MoveEpisodeToTop),
+            UIAction::None
+        );
+    }
+
+    #[test]
+    fn test_format_total_duration() {
+        assert_eq!(format_total_duration(0), "0m");
+        assert_eq!(format_total_duration(61), "2m");
+        assert_eq!(format_total_duration(26 * 3600 + 5 * 60), "26h 05m");
+    }
+
     #[test]
     fn test_select_item_without_selection_shows_message() {
         let playlist_id = PlaylistId::new();
@@ -422,6 +757,7 @@ mod tests {
             created: Utc::now(),
             last_updated: Utc::now(),
             smart_rules: None,
+            playback: Default::default(),
         });
 
         let action = buffer.handle_action(UIAction::SelectItem);
@@ -7,7 +7,8 @@ use ratatui::{
 use crate::{
     playlist::{manager::PlaylistManager, Playlist, PlaylistType},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{capabilities::Refreshable, Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
         themes::Theme,
         UIAction, UIComponent,
     },
@@ -20,6 +21,8 @@ pub struct PlaylistListBuffer {
     selected_index: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     playlist_manager: Option<Arc<PlaylistManager>>,
 }
@@ -38,6 +41,7 @@ impl PlaylistListBuffer {
             selected_index: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             playlist_manager: None,
         }
@@ -112,10 +116,25 @@ impl Buffer for PlaylistListBuffer {
         self
     }
 
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.playlists.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Playlists", &self.playlists, |p| {
+            p.name.len()
+                + p.episodes.len() * std::mem::size_of::<crate::playlist::PlaylistEpisode>()
+        })
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Playlist Commands:".to_string(),
@@ -129,6 +148,12 @@ impl Buffer for PlaylistListBuffer {
     }
 }
 
+impl Refreshable for PlaylistListBuffer {
+    fn refresh(&mut self) -> UIAction {
+        UIAction::RefreshAutoPlaylists
+    }
+}
+
 impl UIComponent for PlaylistListBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -185,6 +210,8 @@ impl UIComponent for PlaylistListBuffer {
             self.theme.border_style()
         };
 
+        self.scroll_area = area;
+
         let items: Vec<ListItem> = if self.playlists.is_empty() {
             vec![ListItem::new("No playlists yet")]
         } else {
@@ -20,24 +20,33 @@ pub struct PlaylistPickerBuffer {
     theme: Theme,
     playlists: Vec<(PlaylistId, String, usize)>,
     selected_index: Option<usize>,
-    target_podcast_id: PodcastId,
-    target_episode_id: EpisodeId,
+    /// The episodes to add: the selected one, or all shown in a list
+    targets: Vec<(PodcastId, EpisodeId)>,
 }
 
 impl PlaylistPickerBuffer {
     pub fn new(
         playlists: Vec<(PlaylistId, String, usize)>,
-        target_podcast_id: PodcastId,
-        target_episode_id: EpisodeId,
+        targets: Vec<(PodcastId, EpisodeId)>,
     ) -> Self {
         Self {
             id: "playlist-picker".to_string(),
             focused: false,
             theme: Theme::default(),
+            selected_index: (!playlists.is_empty()).then_some(0),
             playlists,
-            selected_index: Some(0),
-            target_podcast_id,
-            target_episode_id,
+            targets,
+        }
+    }
+
+    pub fn targets(&self) -> &[(PodcastId, EpisodeId)] {
+        &self.targets
+    }
+
+    fn heading(&self) -> String {
+        match self.targets.len() {
+            1 => "Add to Playlist".to_string(),
+            count => format!("Add {} episodes to Playlist", count),
         }
     }
 
@@ -94,7 +103,8 @@ impl Buffer for PlaylistPickerBuffer {
         vec![
             "Playlist Picker Commands:".to_string(),
             "  ↑/↓      Navigate playlists".to_string(),
-            "  Enter    Add episode to selected playlist".to_string(),
+            "  Enter    Add episode(s) to selected playlist".to_string(),
+            "  c        Create a playlist and add to it".to_string(),
             "  Esc      Cancel".to_string(),
         ]
     }
@@ -112,17 +122,24 @@ impl UIComponent for PlaylistPickerBuffer {
                 UIAction::Render
             }
             UIAction::SelectItem => {
-                if let Some((playlist_id, _, _)) = self.selected_playlist() {
-                    UIAction::TriggerAddToPlaylist {
+                let Some((playlist_id, _, _)) = self.selected_playlist() else {
+                    return UIAction::ShowMessage("No playlist selected".to_string());
+                };
+                match self.targets.as_slice() {
+                    [(podcast_id, episode_id)] => UIAction::TriggerAddToPlaylist {
                         playlist_id: playlist_id.clone(),
-                        podcast_id: self.target_podcast_id.clone(),
-                        episode_id: self.target_episode_id.clone(),
-                    }
-                } else {
-                    UIAction::ShowMessage("No playlist selected".to_string())
+                        podcast_id: podcast_id.clone(),
+                        episode_id: episode_id.clone(),
+                    },
+                    targets => UIAction::TriggerAddEpisodesToPlaylist {
+                        playlist_id: playlist_id.clone(),
+                        episodes: targets.to_vec(),
+                    },
                 }
             }
-            UIAction::CreatePlaylist => UIAction::PromptInput("Create playlist: ".to_string()),
+            UIAction::CreatePlaylist => {
+                UIAction::PromptInput("Create playlist and add: ".to_string())
+            }
             _ => UIAction::None,
         }
     }
@@ -135,7 +152,7 @@ impl UIComponent for PlaylistPickerBuffer {
         };
 
         let items: Vec<ListItem> = if self.playlists.is_empty() {
-            vec![ListItem::new("No playlists available")]
+            vec![ListItem::new("No playlists yet — press c to create one")]
         } else {
             self.playlists
                 .iter()
@@ -158,7 +175,7 @@ impl UIComponent for PlaylistPickerBuffer {
 
         let list = List::new(items).block(
             Block::default()
-                .title("Add to Playlist")
+                .title(self.heading())
                 .borders(Borders::ALL)
                 .border_style(border_style)
                 .title_style(self.theme.title_style()),
@@ -167,7 +184,7 @@ impl UIComponent for PlaylistPickerBuffer {
     }
 
     fn title(&self) -> String {
-        "Add to Playlist".to_string()
+        self.heading()
     }
 
     fn has_focus(&self) -> bool {
@@ -0,0 +1,472 @@
+// Podcast detail buffer - feed metadata, listening stats and the subscription
+// activity timeline
+//
+// Opened with `i` or `:podcast-info` from the podcast or episode list. The
+// listening stats (hours, completion rate, lag behind releases) help decide
+// which subscriptions to drop. The timeline (subscribed, feed moves, refresh
+// failures, new-episode bursts) is shown newest first so "why did this show
+// stop updating" is answered at a glance. The people the feed credits
+// (`<podcast:person>`) are listed too; `f` searches for their episodes.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    podcast::{Person, Podcast, PodcastEventKind, PodcastStats},
+    storage::PodcastId,
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Buffer for displaying podcast details and activity
+pub struct PodcastDetailBuffer {
+    id: String,
+    podcast: Podcast,
+    /// `None` when the listening log or episodes couldn't be read
+    stats: Option<PodcastStats>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl PodcastDetailBuffer {
+    pub fn new(podcast: Podcast, stats: Option<PodcastStats>) -> Self {
+        Self {
+            id: format!("podcast-detail-{}", podcast.id),
+            podcast,
+            stats,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn podcast_id(&self) -> &PodcastId {
+        &self.podcast.id
+    }
+
+    pub fn podcast(&self) -> &Podcast {
+        &self.podcast
+    }
+
+    /// Show an updated copy of the podcast (e.g. after its notes changed)
+    pub fn set_podcast(&mut self, podcast: Podcast) {
+        self.podcast = podcast;
+    }
+
+    fn field<'a>(label: &'a str, value: String) -> Line<'a> {
+        Line::from(vec![
+            Span::styled(label, Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(value),
+        ])
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'_>> {
+        let podcast = &self.podcast;
+        let mut lines = vec![
+            Self::field("Title: ", podcast.title.clone()),
+            Self::field("Feed: ", podcast.url.clone()),
+        ];
+
+        if let Some(ref author) = podcast.author {
+            lines.push(Self::field("Author: ", author.clone()));
+        }
+        if let Some(ref language) = podcast.language {
+            lines.push(Self::field("Language: ", language.clone()));
+        }
+        if !podcast.categories.is_empty() {
+            lines.push(Self::field("Categories: ", podcast.categories.join(", ")));
+        }
+        if !podcast.tags.is_empty() {
+            lines.push(Self::field("Tags: ", podcast.tags.join(", ")));
+        }
+        if let Some(ref user_agent) = podcast.user_agent {
+            lines.push(Self::field("User agent: ", user_agent.clone()));
+        }
+        if let Some(quality) = podcast.download_quality {
+            lines.push(Self::field(
+                "Download quality: ",
+                quality.label().to_string(),
+            ));
+        }
+        if podcast.explicit {
+            lines.push(Self::field("Explicit: ", "Yes".to_string()));
+        }
+        if let Some(subscribed) = podcast.subscribed_at() {
+            lines.push(Self::field(
+                "Subscribed: ",
+                subscribed.format("%Y-%m-%d").to_string(),
+            ));
+        }
+        lines.push(Self::field(
+            "Last updated: ",
+            podcast
+                .last_updated
+                .format("%Y-%m-%d %H:%M UTC")
+                .to_string(),
+        ));
+
+        if let Some(stats) = &self.stats {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Listening:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            lines.push(Self::field("Listened: ", stats.listened_label()));
+            let completion = match stats.completion_rate() {
+                Some(rate) => format!(
+                    "{:.0}% ({} of {} started episodes finished)",
+                    rate * 100.0,
+                    stats.episodes_finished,
+                    stats.episodes_started
+                ),
+                None => "No episodes started yet".to_string(),
+            };
+            lines.push(Self::field("Completion: ", completion));
+            if let Some(lag) = stats.average_lag_label() {
+                lines.push(Self::field(
+                    "Average lag: ",
+                    format!("{} from release to listen", lag),
+                ));
+            }
+            lines.push(Self::field("Unplayed: ", stats.unplayed.to_string()));
+        }
+
+        lines.extend(people_lines(&podcast.people, &self.theme));
+
+        if let Some(notes) = podcast.notes.as_deref().filter(|n| !n.is_empty()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "My notes:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            lines.extend(notes.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            "Activity:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        lines.push(Line::from("─".repeat(60)));
+
+        if podcast.history.is_empty() {
+            lines.push(Line::from("No activity recorded yet."));
+        }
+        for event in podcast.history.iter().rev() {
+            let style = match event.kind {
+                PodcastEventKind::RefreshFailed { .. } => self.theme.error_style(),
+                _ => self.theme.text_style(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    event.at.format("%Y-%m-%d %H:%M  ").to_string(),
+                    self.theme.muted_style(),
+                ),
+                Span::styled(event.kind.describe(), style),
+            ]));
+        }
+
+        if let Some(ref description) = podcast.description {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                "Description:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            lines.push(Line::from("─".repeat(60)));
+            lines.extend(description.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+/// A "People:" section with one "Role  Name  page" line per person, or
+/// nothing when no one is credited. Shared with the episode detail view.
+pub(crate) fn people_lines(people: &[Person], theme: &Theme) -> Vec<Line<'static>> {
+    if people.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "People:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]),
+    ];
+    lines.extend(people.iter().map(|person| {
+        let mut spans = vec![
+            Span::styled(
+                format!("  {:<10} ", person.role_label()),
+                theme.muted_style(),
+            ),
+            Span::raw(person.name.clone()),
+        ];
+        if let Some(href) = &person.href {
+            spans.push(Span::styled(format!("  {}", href), theme.muted_style()));
+        }
+        Line::from(spans)
+    }));
+    lines
+}
+
+impl Buffer for PodcastDetailBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        format!("Podcast: {}", self.podcast.title)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn current_podcast_id(&self) -> Option<crate::storage::PodcastId> {
+        Some(self.podcast_id().clone())
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(self.podcast.history.len()),
+            ..BufferInfo::new("Podcast")
+        }
+        .with_podcast(self.podcast.title.clone())
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Podcast Detail Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Page Down Page down".to_string(),
+            "  Page Up   Page up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  f         Find episodes with a credited person".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for PodcastDetailBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::PageUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+                UIAction::Render
+            }
+            UIAction::PageDown => {
+                self.scroll_offset = (self.scroll_offset + 10).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_text(buffer: &PodcastDetailBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_timeline_lists_newest_first() {
+        // Arrange
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        podcast.record_event(PodcastEventKind::Subscribed);
+        podcast.record_event(PodcastEventKind::NewEpisodes { count: 3 });
+        podcast.record_event(PodcastEventKind::RefreshFailed {
+            error: "HTTP 404".to_string(),
+            attempts: 1,
+        });
+
+        // Act
+        let lines = content_text(&PodcastDetailBuffer::new(podcast, None));
+
+        // Assert
+        let failed = lines
+            .iter()
+            .position(|l| l.ends_with("Refresh failed: HTTP 404"));
+        let burst = lines.iter().position(|l| l.ends_with("3 new episodes"));
+        let subscribed = lines.iter().position(|l| l.ends_with("Subscribed"));
+        assert!(failed < burst && burst < subscribed);
+        assert!(lines.iter().any(|l| l.starts_with("Subscribed: ")));
+    }
+
+    #[test]
+    fn test_empty_timeline_shows_placeholder() {
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let lines = content_text(&PodcastDetailBuffer::new(podcast, None));
+        assert!(lines.contains(&"No activity recorded yet.".to_string()));
+        assert!(lines.contains(&"Feed: https://example.com/feed".to_string()));
+        assert!(!lines.contains(&"Listening:".to_string()));
+    }
+
+    #[test]
+    fn test_people_section_lists_credits() {
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        podcast.people = vec![
+            Person {
+                name: "Jane Host".to_string(),
+                role: "host".to_string(),
+                href: Some("https://example.com/jane".to_string()),
+                img: None,
+            },
+            Person {
+                name: "Sam Editor".to_string(),
+                role: "editor".to_string(),
+                href: None,
+                img: None,
+            },
+        ];
+
+        let lines = content_text(&PodcastDetailBuffer::new(podcast, None));
+
+        let people = lines.iter().position(|l| l == "People:").unwrap();
+        assert_eq!(
+            lines[people + 1],
+            "  Host       Jane Host  https://example.com/jane"
+        );
+        assert_eq!(lines[people + 2], "  Editor     Sam Editor");
+    }
+
+    #[test]
+    fn test_listening_stats_section() {
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        let stats = PodcastStats {
+            listened_seconds: 5400,
+            episodes_finished: 3,
+            episodes_started: 4,
+            unplayed: 12,
+            average_lag: Some(chrono::Duration::days(2)),
+        };
+
+        let lines = content_text(&PodcastDetailBuffer::new(podcast, Some(stats)));
+
+        assert!(lines.contains(&"Listened: 1.5 h".to_string()));
+        assert!(lines.contains(&"Completion: 75% (3 of 4 started episodes finished)".to_string()));
+        assert!(lines.contains(&"Average lag: 2 days from release to listen".to_string()));
+        assert!(lines.contains(&"Unplayed: 12".to_string()));
+    }
+
+    #[test]
+    fn test_scrolling_to_the_bottom_uses_the_drawn_height() {
+        // Arrange: a long timeline, last drawn 40 rows tall
+        let mut podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        for count in 1..=60 {
+            podcast.record_event(PodcastEventKind::NewEpisodes { count });
+        }
+        let mut buffer = PodcastDetailBuffer::new(podcast, None);
+        buffer.scroll_area = Rect::new(0, 0, 80, 40);
+        let total = buffer.generate_content().len();
+
+        // Act
+        buffer.handle_action(UIAction::MoveToBottom);
+        let bottom = buffer.scroll_offset;
+        buffer.handle_action(UIAction::PageDown);
+
+        // Assert: the last line sits on the last of the 38 inner rows
+        assert_eq!(bottom, total - 38);
+        assert_eq!(buffer.scroll_offset, bottom);
+    }
+}
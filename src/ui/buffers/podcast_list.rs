@@ -2,26 +2,87 @@
 //
 // This buffer shows the list of subscribed podcasts and allows
 // management operations like adding, removing, and refreshing feeds.
+// Each row carries unplayed/downloaded counts, the newest episode date and
+// disk usage, and the list can be ordered by title, activity or backlog.
+// Shows that look finished are flagged so they can be archived in bulk;
+// archived shows get a list of their own (`:archived`).
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
 use crate::{
-    podcast::{subscription::SubscriptionManager, Podcast},
-    storage::JsonStorage,
+    podcast::{subscription::SubscriptionManager, Podcast, ShowEnded},
+    storage::{JsonStorage, PodcastId},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{capabilities::Refreshable, Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        events::BufferRefreshType,
+        events::PodcastSummary,
         filters::PodcastFilter,
+        text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
+    utils::fs::format_file_size,
 };
+use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Ordering of the podcast list, persisted as `ui.podcast_list_sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PodcastSortMode {
+    #[default]
+    Alphabetical,
+    /// Podcasts with the newest episode first
+    RecentActivity,
+    /// Largest unplayed backlog first
+    UnplayedFirst,
+}
+
+impl PodcastSortMode {
+    /// Parse a config/command value; accepts a few aliases
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "alphabetical" | "alpha" | "title" | "name" => Some(Self::Alphabetical),
+            "recent" | "recent-activity" | "activity" | "date" => Some(Self::RecentActivity),
+            "unplayed" | "unplayed-first" | "backlog" => Some(Self::UnplayedFirst),
+            _ => None,
+        }
+    }
+
+    /// Value written to the config file
+    pub fn as_config_str(self) -> &'static str {
+        match self {
+            Self::Alphabetical => "alphabetical",
+            Self::RecentActivity => "recent",
+            Self::UnplayedFirst => "unplayed",
+        }
+    }
+
+    /// Short label shown in the buffer title
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Alphabetical => "A-Z",
+            Self::RecentActivity => "Recent",
+            Self::UnplayedFirst => "Unplayed",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Alphabetical => Self::RecentActivity,
+            Self::RecentActivity => Self::UnplayedFirst,
+            Self::UnplayedFirst => Self::Alphabetical,
+        }
+    }
+}
+
 /// State of the podcast list buffer
 #[derive(Debug, Clone)]
 pub enum PodcastListState {
@@ -36,12 +97,19 @@ pub struct PodcastListBuffer {
     selected_index: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     state: PodcastListState,
     status_message: Option<String>,
     subscription_manager: Option<Arc<SubscriptionManager<JsonStorage>>>,
     filter: PodcastFilter,
     filtered_indices: Vec<usize>,
+    summaries: HashMap<PodcastId, PodcastSummary>,
+    sort: PodcastSortMode,
+    /// Months without a new episode before a show is flagged inactive
+    /// (`ui.inactive_after_months`, 0 = never)
+    inactive_after_months: u32,
 }
 
 impl PodcastListBuffer {
@@ -53,15 +121,49 @@ impl PodcastListBuffer {
             selected_index: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             state: PodcastListState::Ready,
             status_message: None,
             subscription_manager: None,
             filter: PodcastFilter::default(),
             filtered_indices: Vec::new(),
+            summaries: HashMap::new(),
+            sort: PodcastSortMode::default(),
+            inactive_after_months: 0,
         }
     }
 
+    pub fn set_inactive_after_months(&mut self, months: u32) {
+        self.inactive_after_months = months;
+    }
+
+    /// Why `podcast` looks finished, if it does
+    pub fn ended(&self, podcast: &Podcast) -> Option<ShowEnded> {
+        let last_episode = self
+            .summaries
+            .get(&podcast.id)
+            .and_then(|summary| summary.last_episode);
+        podcast.ended(last_episode, self.inactive_after_months, Utc::now())
+    }
+
+    /// Subscribed, unarchived shows that look finished, whether or not the
+    /// current filter shows them
+    pub fn ended_podcasts(&self) -> Vec<&Podcast> {
+        self.podcasts
+            .iter()
+            .filter(|podcast| !podcast.is_archived() && self.ended(podcast).is_some())
+            .collect()
+    }
+
+    /// Switch between the active and the archived podcasts. Returns whether
+    /// the archive is now shown.
+    pub fn toggle_archived(&mut self) -> bool {
+        self.filter.archived = !self.filter.archived;
+        self.apply_filters();
+        self.filter.archived
+    }
+
     /// Set the subscription manager
     pub fn set_subscription_manager(&mut self, manager: Arc<SubscriptionManager<JsonStorage>>) {
         self.subscription_manager = Some(manager);
@@ -93,7 +195,65 @@ impl PodcastListBuffer {
     pub fn set_podcasts(&mut self, podcasts: Vec<Podcast>) {
         self.podcasts = podcasts;
         self.state = PodcastListState::Ready;
+        self.apply_sort();
+        self.apply_filters();
+    }
+
+    /// Replace the per-podcast summary columns. Call before
+    /// [`set_podcasts`](Self::set_podcasts) so the sort sees fresh counts.
+    pub fn set_summaries(&mut self, summaries: HashMap<PodcastId, PodcastSummary>) {
+        self.summaries = summaries;
+    }
+
+    pub fn sort_mode(&self) -> PodcastSortMode {
+        self.sort
+    }
+
+    /// Change the ordering, keeping the selected podcast selected
+    pub fn set_sort_mode(&mut self, mode: PodcastSortMode) {
+        let selected_id = self.selected_podcast().map(|p| p.id.clone());
+        self.sort = mode;
+        self.apply_sort();
         self.apply_filters();
+        if let Some(id) = selected_id {
+            if let Some(pos) = self
+                .filtered_indices
+                .iter()
+                .position(|&i| self.podcasts[i].id == id)
+            {
+                self.selected_index = Some(pos);
+            }
+        }
+    }
+
+    /// Sort `self.podcasts` in place; ties always fall back to the title
+    fn apply_sort(&mut self) {
+        let summaries = &self.summaries;
+        let empty = PodcastSummary::default();
+        let summary = |p: &Podcast| summaries.get(&p.id).unwrap_or(&empty);
+        let by_title =
+            |a: &Podcast, b: &Podcast| a.title.to_lowercase().cmp(&b.title.to_lowercase());
+        match self.sort {
+            PodcastSortMode::Alphabetical => self.podcasts.sort_by(by_title),
+            PodcastSortMode::RecentActivity => self.podcasts.sort_by(|a, b| {
+                summary(b)
+                    .last_episode
+                    .cmp(&summary(a).last_episode)
+                    .then_with(|| by_title(a, b))
+            }),
+            PodcastSortMode::UnplayedFirst => self.podcasts.sort_by(|a, b| {
+                summary(b)
+                    .unplayed
+                    .cmp(&summary(a).unplayed)
+                    .then_with(|| summary(b).last_episode.cmp(&summary(a).last_episode))
+                    .then_with(|| by_title(a, b))
+            }),
+        }
+    }
+
+    /// Look up a loaded podcast by ID, whether or not it is currently visible
+    pub fn podcast(&self, id: &PodcastId) -> Option<&Podcast> {
+        self.podcasts.iter().find(|p| &p.id == id)
     }
 
     /// Get the currently selected podcast (maps through filtered_indices)
@@ -103,6 +263,16 @@ impl PodcastListBuffer {
             .and_then(|&actual| self.podcasts.get(actual))
     }
 
+    /// Podcasts passing the current filter, in display order
+    pub fn visible_podcasts(&self) -> impl Iterator<Item = &Podcast> {
+        self.filtered_indices.iter().map(|&i| &self.podcasts[i])
+    }
+
+    /// Description of the active filter (e.g. `tag: "news"`), if any
+    pub fn active_filter(&self) -> Option<String> {
+        self.filter.is_active().then(|| self.filter.description())
+    }
+
     /// Apply current filter to podcasts, rebuilding filtered_indices
     fn apply_filters(&mut self) {
         self.filtered_indices = self
@@ -207,14 +377,47 @@ impl Buffer for PodcastListBuffer {
         self
     }
 
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
+    fn current_podcast_id(&self) -> Option<crate::storage::PodcastId> {
+        self.selected_podcast().map(|podcast| podcast.id.clone())
+    }
+
+    fn refresh_async(&self) -> Option<BufferRefreshType> {
+        Some(BufferRefreshType::PodcastList)
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
 
+    fn set_hide_explicit(&mut self, hide: bool) {
+        self.filter.hide_explicit = hide;
+        self.apply_filters();
+    }
+
     fn can_close(&self) -> bool {
         false // Main podcast list shouldn't be closeable
     }
 
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        let position =
+            ScrollPosition::items(self.scroll_area, self.selected_index?, self.visible_count())?;
+        Some(if self.filter.is_active() {
+            position.with_note(format!("{} total", self.podcasts.len()))
+        } else {
+            position
+        })
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Podcasts", &self.podcasts, |p| {
+            p.title.len() + p.url.len() + p.description.as_ref().map_or(0, String::len)
+        })
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Podcast List Commands:".to_string(),
@@ -226,11 +429,20 @@ impl Buffer for PodcastListBuffer {
             "  /         Search podcasts".to_string(),
             "  F6        Clear filters".to_string(),
             "  r         Refresh feeds".to_string(),
+            "  o         Cycle sort (A-Z → Recent → Unplayed)".to_string(),
+            "  C         Archive ended shows".to_string(),
             "  C-h       Show help".to_string(),
         ]
     }
 }
 
+impl Refreshable for PodcastListBuffer {
+    fn refresh(&mut self) -> UIAction {
+        self.refresh_async()
+            .map_or(UIAction::None, UIAction::RefreshBuffer)
+    }
+}
+
 impl UIComponent for PodcastListBuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         match action {
@@ -301,16 +513,20 @@ impl UIComponent for PodcastListBuffer {
                     UIAction::None
                 }
             }
-            UIAction::Refresh => {
-                // For now, just show a message. Actual refresh would be async
-                if self.selected_podcast().is_some() {
-                    UIAction::ShowMessage(
-                        "Refresh selected podcast (not implemented yet)".to_string(),
-                    )
-                } else {
-                    UIAction::ShowMessage("No podcast selected to refresh".to_string())
-                }
+            UIAction::CycleSortField => {
+                self.set_sort_mode(self.sort.next());
+                UIAction::Render
             }
+            UIAction::SetSort { field } => match PodcastSortMode::parse(&field) {
+                Some(mode) => {
+                    self.set_sort_mode(mode);
+                    UIAction::ShowMessage(format!("Podcasts sorted by: {}", mode.label()))
+                }
+                None => UIAction::ShowError(format!(
+                    "Unknown podcast sort: '{}'. Use: alphabetical, recent, unplayed",
+                    field
+                )),
+            },
             UIAction::Search => UIAction::Search,
             UIAction::ApplySearch { query } => {
                 self.filter.text_query = if query.is_empty() { None } else { Some(query) };
@@ -318,7 +534,7 @@ impl UIComponent for PodcastListBuffer {
                 UIAction::Render
             }
             UIAction::ClearFilters => {
-                self.filter = PodcastFilter::default();
+                self.filter.clear();
                 self.apply_filters();
                 UIAction::Render
             }
@@ -417,6 +633,7 @@ impl UIComponent for PodcastListBuffer {
                 Constraint::Length(1), // Status line
             ])
             .split(area);
+        self.scroll_area = chunks[0];
 
         match &self.state {
             PodcastListState::Loading => {
@@ -483,8 +700,8 @@ impl UIComponent for PodcastListBuffer {
                 } else {
                     let filtered_count = self.visible_count();
 
-                    // Calculate visible height (subtract 2 for borders)
-                    let visible_height = chunks[0].height.saturating_sub(2) as usize;
+                    // Calculate visible height (subtract 2 for borders, 1 for header)
+                    let visible_height = chunks[0].height.saturating_sub(3) as usize;
 
                     // Adjust scroll to keep selected item visible
                     self.adjust_scroll(visible_height);
@@ -492,7 +709,20 @@ impl UIComponent for PodcastListBuffer {
                     // Calculate the range of items to display
                     let end_index = (self.scroll_offset + visible_height).min(filtered_count);
 
-                    let items: Vec<ListItem> = self.filtered_indices[self.scroll_offset..end_index]
+                    let header = Row::new(vec![
+                        Cell::from("Podcast"),
+                        Cell::from("Unplayed"),
+                        Cell::from("DL"),
+                        Cell::from("Last new"),
+                        Cell::from("Disk"),
+                    ])
+                    .style(
+                        Style::default()
+                            .fg(self.theme.colors.primary)
+                            .add_modifier(Modifier::BOLD),
+                    );
+
+                    let rows: Vec<Row> = self.filtered_indices[self.scroll_offset..end_index]
                         .iter()
                         .enumerate()
                         .map(|(display_index, &actual_index)| {
@@ -508,7 +738,11 @@ impl UIComponent for PodcastListBuffer {
                             };
 
                             let author = podcast.author.as_deref().unwrap_or("Unknown");
-                            let title_text = format!("  {} - {}", podcast.title, author);
+                            let title_text = format!(
+                                "  {} - {}",
+                                text_layout::single_line(&podcast.title),
+                                text_layout::single_line(author)
+                            );
                             spans.push(Span::styled(title_text, base_style));
 
                             // Render tags as [tag] badges
@@ -522,28 +756,96 @@ impl UIComponent for PodcastListBuffer {
                                 spans.push(Span::styled(badge, badge_style));
                             }
 
-                            ListItem::new(Line::from(spans))
+                            if podcast.explicit {
+                                let badge_style = if is_selected {
+                                    self.theme.selected_style()
+                                } else {
+                                    Style::default().fg(self.theme.colors.warning)
+                                };
+                                spans.push(Span::styled(" [E]", badge_style));
+                            }
+
+                            let status = if podcast.is_archived() {
+                                Some("archived")
+                            } else {
+                                self.ended(podcast).map(ShowEnded::label)
+                            };
+                            if let Some(status) = status {
+                                let badge_style = if is_selected {
+                                    self.theme.selected_style()
+                                } else {
+                                    self.theme.muted_style()
+                                };
+                                spans.push(Span::styled(format!(" [{}]", status), badge_style));
+                            }
+
+                            let summary = self.summaries.get(&podcast.id);
+                            let count = |n: usize| {
+                                if n > 0 {
+                                    n.to_string()
+                                } else {
+                                    "-".to_string()
+                                }
+                            };
+                            let last_new = summary
+                                .and_then(|s| s.last_episode)
+                                .map(|d| {
+                                    self.theme
+                                        .time_zone
+                                        .date(d, None)
+                                        .format("%Y-%m-%d")
+                                        .to_string()
+                                })
+                                .unwrap_or_else(|| "-".to_string());
+                            let disk = match summary.map(|s| s.disk_bytes) {
+                                Some(bytes) if bytes > 0 => format_file_size(bytes),
+                                _ => "-".to_string(),
+                            };
+
+                            Row::new(vec![
+                                Cell::from(Line::from(spans)),
+                                Cell::from(count(summary.map_or(0, |s| s.unplayed))),
+                                Cell::from(count(summary.map_or(0, |s| s.downloaded))),
+                                Cell::from(last_new),
+                                Cell::from(disk),
+                            ])
+                            .style(base_style)
                         })
                         .collect();
 
-                    // Build title with filter indicator
+                    // Build title with sort and filter indicators
                     let block_title = if self.filter.is_active() {
-                        format!("Podcasts [{}]", self.filter.description())
+                        format!(
+                            "Podcasts ({}) [{}]",
+                            self.sort.label(),
+                            self.filter.description()
+                        )
                     } else {
-                        "Podcasts".to_string()
+                        format!("Podcasts ({})", self.sort.label())
                     };
 
-                    let list = List::new(items)
-                        .block(
-                            Block::default()
-                                .title(block_title)
-                                .borders(Borders::ALL)
-                                .border_style(border_style)
-                                .title_style(self.theme.title_style()),
-                        )
-                        .style(self.theme.text_style());
+                    let table = Table::new(
+                        rows,
+                        [
+                            Constraint::Min(20),    // Title - author [tags]
+                            Constraint::Length(8),  // Unplayed
+                            Constraint::Length(4),  // Downloaded
+                            Constraint::Length(10), // Last new episode
+                            Constraint::Length(9),  // Disk usage
+                        ],
+                    )
+                    .header(header)
+                    .block(
+                        Block::default()
+                            .title(block_title)
+                            .borders(Borders::ALL)
+                            .border_style(border_style)
+                            .title_style(self.theme.title_style()),
+                    )
+                    .style(self.theme.text_style())
+                    .column_spacing(1);
 
-                    frame.render_widget(list, chunks[0]);
+                    frame.render_widget(table, chunks[0]);
                 }
             }
         }
@@ -683,12 +985,58 @@ mod tests {
         assert_eq!(buffer.visible_count(), 2);
         assert!(buffer.filter.is_active());
 
+        // Default sort is alphabetical
         let podcast = buffer.selected_podcast().expect("should have podcast");
-        assert_eq!(podcast.title, "Rust Radio");
+        assert_eq!(podcast.title, "Rust in Production");
 
         buffer.handle_action(UIAction::MoveDown);
         let podcast = buffer.selected_podcast().expect("should have podcast");
-        assert_eq!(podcast.title, "Rust in Production");
+        assert_eq!(podcast.title, "Rust Radio");
+    }
+
+    #[test]
+    fn test_visible_podcasts_follow_tag_filter() {
+        let mut buffer = PodcastListBuffer::new();
+        let mut news = Podcast::new("Daily News".to_string(), "http://example.com/n".to_string());
+        news.add_tag("news");
+        buffer.set_podcasts(vec![
+            news,
+            Podcast::new("Rust Radio".to_string(), "http://example.com/r".to_string()),
+        ]);
+        assert_eq!(buffer.active_filter(), None);
+
+        buffer.handle_action(UIAction::FilterByTag {
+            tag: "News".to_string(),
+        });
+
+        let titles: Vec<_> = buffer
+            .visible_podcasts()
+            .map(|p| p.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Daily News"]);
+        assert_eq!(buffer.active_filter().as_deref(), Some("tag: \"news\""));
+    }
+
+    #[test]
+    fn test_scroll_position_counts_visible_podcasts() {
+        let mut buffer = PodcastListBuffer::new();
+        buffer.set_podcasts(vec![
+            Podcast::new("Alpha Show".to_string(), "http://example.com/a".to_string()),
+            Podcast::new("Alpha Hour".to_string(), "http://example.com/h".to_string()),
+            Podcast::new("Beta Show".to_string(), "http://example.com/b".to_string()),
+        ]);
+        buffer.handle_action(UIAction::MoveDown);
+        let label = |buffer: &PodcastListBuffer| {
+            buffer
+                .scroll_position()
+                .map(|position| position.label(crate::ui::glyphs::GlyphSet::Unicode))
+        };
+        assert_eq!(label(&buffer).as_deref(), Some("2/3 · 67%"));
+
+        buffer.handle_action(UIAction::ApplySearch {
+            query: "alpha".to_string(),
+        });
+        assert_eq!(label(&buffer).as_deref(), Some("1/2 · 50% · 3 total"));
     }
 
     #[test]
@@ -860,4 +1208,127 @@ mod tests {
         // Assert
         assert!(matches!(result, UIAction::ShowError(_)));
     }
+
+    fn summary(unplayed: usize, days_ago: Option<i64>) -> PodcastSummary {
+        PodcastSummary {
+            unplayed,
+            downloaded: 0,
+            last_episode: days_ago.map(|d| chrono::Utc::now() - chrono::Duration::days(d)),
+            disk_bytes: 0,
+        }
+    }
+
+    fn make_sorted_buffer() -> PodcastListBuffer {
+        let alpha = Podcast::new("alpha".to_string(), "http://example.com/a".to_string());
+        let bravo = Podcast::new("Bravo".to_string(), "http://example.com/b".to_string());
+        let charlie = Podcast::new("Charlie".to_string(), "http://example.com/c".to_string());
+        let mut summaries = HashMap::new();
+        summaries.insert(alpha.id.clone(), summary(1, Some(30)));
+        summaries.insert(bravo.id.clone(), summary(9, None));
+        summaries.insert(charlie.id.clone(), summary(0, Some(1)));
+
+        let mut buffer = PodcastListBuffer::new();
+        buffer.set_summaries(summaries);
+        buffer.set_podcasts(vec![charlie, bravo, alpha]);
+        buffer
+    }
+
+    fn titles(buffer: &PodcastListBuffer) -> Vec<&str> {
+        buffer.podcasts().iter().map(|p| p.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_modes_order_podcasts() {
+        // Arrange
+        let mut buffer = make_sorted_buffer();
+
+        // Assert: default is case-insensitive alphabetical
+        assert_eq!(titles(&buffer), vec!["alpha", "Bravo", "Charlie"]);
+
+        // Act / Assert: newest episode first, podcasts without episodes last
+        buffer.set_sort_mode(PodcastSortMode::RecentActivity);
+        assert_eq!(titles(&buffer), vec!["Charlie", "alpha", "Bravo"]);
+
+        // Act / Assert: biggest backlog first
+        buffer.set_sort_mode(PodcastSortMode::UnplayedFirst);
+        assert_eq!(titles(&buffer), vec!["Bravo", "alpha", "Charlie"]);
+    }
+
+    #[test]
+    fn test_cycle_sort_keeps_selected_podcast() {
+        // Arrange
+        let mut buffer = make_sorted_buffer();
+        buffer.handle_action(UIAction::MoveDown); // Bravo
+
+        // Act
+        let action = buffer.handle_action(UIAction::CycleSortField);
+
+        // Assert
+        assert_eq!(action, UIAction::Render);
+        assert_eq!(buffer.sort_mode(), PodcastSortMode::RecentActivity);
+        assert_eq!(buffer.selected_podcast().unwrap().title, "Bravo");
+    }
+
+    #[test]
+    fn test_set_sort_parses_aliases_and_rejects_unknown() {
+        let mut buffer = make_sorted_buffer();
+
+        let ok = buffer.handle_action(UIAction::SetSort {
+            field: "unplayed".to_string(),
+        });
+        assert!(matches!(ok, UIAction::ShowMessage(_)));
+        assert_eq!(buffer.sort_mode(), PodcastSortMode::UnplayedFirst);
+
+        let err = buffer.handle_action(UIAction::SetSort {
+            field: "duration".to_string(),
+        });
+        assert!(matches!(err, UIAction::ShowError(_)));
+        assert_eq!(buffer.sort_mode(), PodcastSortMode::UnplayedFirst);
+    }
+
+    #[test]
+    fn test_sort_mode_config_round_trip() {
+        for mode in [
+            PodcastSortMode::Alphabetical,
+            PodcastSortMode::RecentActivity,
+            PodcastSortMode::UnplayedFirst,
+        ] {
+            assert_eq!(PodcastSortMode::parse(mode.as_config_str()), Some(mode));
+        }
+    }
+
+    #[test]
+    fn test_summary_from_episodes() {
+        use crate::podcast::{Episode, EpisodeStatus};
+
+        // Arrange
+        let podcast_id = PodcastId::new();
+        let newest = chrono::Utc::now();
+        let make = |status, published| {
+            let mut e = Episode::new(
+                podcast_id.clone(),
+                "Ep".to_string(),
+                "http://example.com/e.mp3".to_string(),
+                published,
+            );
+            e.status = status;
+            e
+        };
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let mut downloaded = make(EpisodeStatus::downloaded(), newest);
+        downloaded.local_path = Some(file.path().to_path_buf());
+        let episodes = vec![
+            make(EpisodeStatus::default(), newest - chrono::Duration::days(3)),
+            downloaded,
+            make(EpisodeStatus::played(), newest - chrono::Duration::days(9)),
+        ];
+
+        // Act
+        let summary = PodcastSummary::from_episodes(&episodes);
+
+        // Assert
+        assert_eq!(summary.unplayed, 2);
+        assert_eq!(summary.downloaded, 1);
+        assert_eq!(summary.last_episode, Some(newest));
+    }
 }
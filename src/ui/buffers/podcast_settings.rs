@@ -0,0 +1,361 @@
+// Podcast settings - one show's overrides of the app-wide settings
+//
+// Opened with `,` in the podcast list (`:podcast-settings`). Each row steps
+// through preset values with Enter or → (← steps back), and every change is
+// saved straight away; the view shows a value once it is saved. "default"
+// follows the config, whose value the row shows.
+
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    constants::audio::PLAYBACK_SPEEDS,
+    podcast::{Podcast, PodcastSettings, RetentionPolicy},
+    storage::PodcastId,
+    ui::{
+        buffers::{Buffer, BufferId},
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+    utils::time::format_cleanup_duration,
+};
+
+const AUTO_DOWNLOAD_CHOICES: &[Option<bool>] = &[None, Some(true), Some(false)];
+
+/// Minutes between refreshes
+const REFRESH_INTERVAL_CHOICES: &[Option<u32>] = &[
+    None,
+    Some(60),
+    Some(3 * 60),
+    Some(6 * 60),
+    Some(12 * 60),
+    Some(24 * 60),
+    Some(7 * 24 * 60),
+];
+
+const RETENTION_CHOICES: &[Option<RetentionPolicy>] = &[
+    None,
+    Some(RetentionPolicy::KeepAll),
+    Some(RetentionPolicy::KeepLatest(1)),
+    Some(RetentionPolicy::KeepLatest(3)),
+    Some(RetentionPolicy::KeepLatest(5)),
+    Some(RetentionPolicy::KeepLatest(10)),
+    Some(RetentionPolicy::DeleteAfterDays(7)),
+    Some(RetentionPolicy::DeleteAfterDays(30)),
+];
+
+/// The config values "default" stands for
+#[derive(Debug, Clone, Default)]
+pub struct SettingDefaults {
+    pub auto_download_new: bool,
+    pub cleanup_after_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    AutoDownload,
+    RefreshInterval,
+    PlaybackSpeed,
+    Retention,
+}
+
+const FIELDS: [Field; 4] = [
+    Field::AutoDownload,
+    Field::RefreshInterval,
+    Field::PlaybackSpeed,
+    Field::Retention,
+];
+
+impl Field {
+    fn label(self) -> &'static str {
+        match self {
+            Self::AutoDownload => "Auto-download new episodes",
+            Self::RefreshInterval => "Refresh interval",
+            Self::PlaybackSpeed => "Playback speed",
+            Self::Retention => "Keep downloads",
+        }
+    }
+}
+
+pub struct PodcastSettingsBuffer {
+    id: String,
+    focused: bool,
+    theme: Theme,
+    podcast_id: PodcastId,
+    podcast_title: String,
+    settings: PodcastSettings,
+    defaults: SettingDefaults,
+    selected_index: usize,
+}
+
+impl PodcastSettingsBuffer {
+    pub fn new(podcast: &Podcast, defaults: SettingDefaults) -> Self {
+        Self {
+            id: "podcast-settings".to_string(),
+            focused: false,
+            theme: Theme::default(),
+            podcast_id: podcast.id.clone(),
+            podcast_title: podcast.title.clone(),
+            settings: podcast.settings.clone(),
+            defaults,
+            selected_index: 0,
+        }
+    }
+
+    pub fn podcast_id(&self) -> &PodcastId {
+        &self.podcast_id
+    }
+
+    pub fn settings(&self) -> &PodcastSettings {
+        &self.settings
+    }
+
+    /// Show the settings as saved
+    pub fn set_settings(&mut self, settings: PodcastSettings) {
+        self.settings = settings;
+    }
+
+    /// Step the selected setting `step` places through its choices and ask
+    /// the app to save the result
+    fn change_selected(&mut self, step: isize) -> UIAction {
+        let mut settings = self.settings.clone();
+        match FIELDS[self.selected_index] {
+            Field::AutoDownload => {
+                settings.auto_download_new =
+                    cycle(AUTO_DOWNLOAD_CHOICES, settings.auto_download_new, step);
+            }
+            Field::RefreshInterval => {
+                settings.refresh_interval_minutes = cycle(
+                    REFRESH_INTERVAL_CHOICES,
+                    settings.refresh_interval_minutes,
+                    step,
+                );
+            }
+            Field::PlaybackSpeed => {
+                // Normal speed is the default rather than an override
+                let choices: Vec<Option<f32>> = PLAYBACK_SPEEDS
+                    .iter()
+                    .map(|&speed| (speed != 1.0).then_some(speed))
+                    .collect();
+                settings.playback_speed = cycle(&choices, settings.playback_speed, step);
+            }
+            Field::Retention => {
+                settings.retention = cycle(RETENTION_CHOICES, settings.retention, step);
+            }
+        }
+        UIAction::SavePodcastSettings {
+            podcast_id: self.podcast_id.clone(),
+            settings,
+        }
+    }
+
+    fn value_label(&self, field: Field) -> String {
+        let settings = &self.settings;
+        match field {
+            Field::AutoDownload => match settings.auto_download_new {
+                Some(true) => "on".to_string(),
+                Some(false) => "off".to_string(),
+                None => format!(
+                    "default ({})",
+                    if self.defaults.auto_download_new {
+                        "on"
+                    } else {
+                        "off"
+                    }
+                ),
+            },
+            Field::RefreshInterval => match settings.refresh_interval_minutes {
+                Some(minutes) => format!(
+                    "at most every {}",
+                    format_cleanup_duration(minutes as u64 / 60)
+                ),
+                None => "default (every refresh)".to_string(),
+            },
+            Field::PlaybackSpeed => match settings.playback_speed {
+                Some(speed) => format!("{}×", speed),
+                None => "default (1×)".to_string(),
+            },
+            Field::Retention => match settings.retention {
+                Some(policy) => policy.label(),
+                None => match self.defaults.cleanup_after_days.filter(|d| *d > 0) {
+                    Some(days) => format!("default (delete after {} days)", days),
+                    None => "default (keep all)".to_string(),
+                },
+            },
+        }
+    }
+}
+
+/// The choice `step` places from `current` (the first choice when `current`
+/// isn't one of them), wrapping around
+fn cycle<T: PartialEq + Copy>(choices: &[T], current: T, step: isize) -> T {
+    let len = choices.len() as isize;
+    let index = choices
+        .iter()
+        .position(|choice| *choice == current)
+        .map_or(0, |index| (index as isize + step).rem_euclid(len));
+    choices[index as usize]
+}
+
+impl Buffer for PodcastSettingsBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Podcast Settings".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Podcast Settings Commands:".to_string(),
+            "  ↑/↓        Navigate settings".to_string(),
+            "  Enter / →  Next value (saved straight away)".to_string(),
+            "  ←          Previous value".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for PodcastSettingsBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.selected_index = self
+                    .selected_index
+                    .checked_sub(1)
+                    .unwrap_or(FIELDS.len() - 1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.selected_index = (self.selected_index + 1) % FIELDS.len();
+                UIAction::Render
+            }
+            UIAction::SelectItem | UIAction::MoveRight => self.change_selected(1),
+            UIAction::MoveLeft => self.change_selected(-1),
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let items: Vec<ListItem> = FIELDS
+            .iter()
+            .enumerate()
+            .map(|(index, &field)| {
+                let selected = index == self.selected_index;
+                let marker = if selected { "► " } else { "  " };
+                let text = format!("{marker}{:<28}{}", field.label(), self.value_label(field));
+                if selected {
+                    ListItem::new(text).style(self.theme.selected_style())
+                } else {
+                    ListItem::new(text).style(self.theme.text_style())
+                }
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(self.title())
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title_style(self.theme.title_style()),
+        );
+        frame.render_widget(list, area);
+    }
+
+    fn title(&self) -> String {
+        format!("Settings: {}", self.podcast_title)
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer() -> PodcastSettingsBuffer {
+        let podcast = Podcast::new("Show".to_string(), "https://example.com/feed".to_string());
+        PodcastSettingsBuffer::new(
+            &podcast,
+            SettingDefaults {
+                auto_download_new: false,
+                cleanup_after_days: Some(30),
+            },
+        )
+    }
+
+    /// Step the selected row and save the result, as the app would
+    fn change(buffer: &mut PodcastSettingsBuffer, action: UIAction) -> PodcastSettings {
+        match buffer.handle_action(action) {
+            UIAction::SavePodcastSettings { settings, .. } => {
+                buffer.set_settings(settings.clone());
+                settings
+            }
+            other => panic!("expected SavePodcastSettings, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_changing_a_row_emits_the_settings_to_save() {
+        // Arrange
+        let mut buffer = buffer();
+        buffer.handle_action(UIAction::MoveDown);
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let action = buffer.handle_action(UIAction::SelectItem);
+
+        // Assert — nothing shows as changed until it is saved
+        assert!(matches!(
+            action,
+            UIAction::SavePodcastSettings { ref settings, .. }
+                if settings.playback_speed == Some(1.1)
+        ));
+        assert_eq!(buffer.value_label(Field::PlaybackSpeed), "default (1×)");
+        assert_eq!(
+            buffer.value_label(Field::Retention),
+            "default (delete after 30 days)"
+        );
+    }
+
+    #[test]
+    fn test_stepping_back_from_default_wraps_to_the_last_choice() {
+        let mut buffer = buffer();
+        buffer.handle_action(UIAction::MoveUp);
+        let settings = change(&mut buffer, UIAction::MoveLeft);
+        assert_eq!(
+            settings.retention,
+            Some(RetentionPolicy::DeleteAfterDays(30))
+        );
+        assert_eq!(buffer.value_label(Field::Retention), "delete after 30 days");
+        let settings = change(&mut buffer, UIAction::MoveRight);
+        assert_eq!(settings.retention, None);
+    }
+}
@@ -0,0 +1,370 @@
+// Queue buffer - the episodes lined up to play next
+//
+// Shows the playback queue (`audio::queue`) in play order. Entries are
+// reordered with C-Up / C-Down / C-Home like playlist entries, removed with
+// X, and Enter plays one straight away. Opened with `:queue` or S-Q.
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::{
+    storage::EpisodeId,
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        glyphs::StatusGlyph,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// One queued episode as listed
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueItem {
+    pub episode_id: EpisodeId,
+    pub podcast_title: String,
+    pub episode_title: String,
+    /// Only downloaded episodes can play; the rest are skipped
+    pub downloaded: bool,
+}
+
+/// Buffer listing the playback queue
+pub struct QueueBuffer {
+    id: String,
+    items: Vec<QueueItem>,
+    selected_index: Option<usize>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    theme: Theme,
+}
+
+impl Default for QueueBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueueBuffer {
+    pub fn new() -> Self {
+        Self {
+            id: "queue".to_string(),
+            items: Vec::new(),
+            selected_index: None,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Replace the listed entries; the cursor stays on the episode it was on,
+    /// or at the same position when that one left the queue
+    pub fn set_items(&mut self, items: Vec<QueueItem>) {
+        let selected_id = self.selected_item().map(|item| item.episode_id.clone());
+        let previous_index = self.selected_index.unwrap_or(0);
+        self.items = items;
+        self.selected_index = if self.items.is_empty() {
+            None
+        } else {
+            selected_id
+                .and_then(|id| self.items.iter().position(|item| item.episode_id == id))
+                .or(Some(previous_index.min(self.items.len() - 1)))
+        };
+        if self.items.is_empty() {
+            self.scroll_offset = 0;
+        }
+    }
+
+    pub fn selected_item(&self) -> Option<&QueueItem> {
+        self.selected_index.and_then(|i| self.items.get(i))
+    }
+
+    fn select_previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(0) => Some(self.items.len() - 1),
+            Some(i) => Some(i - 1),
+            None => Some(0),
+        };
+    }
+
+    fn select_next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(i) if i >= self.items.len() - 1 => Some(0),
+            Some(i) => Some(i + 1),
+            None => Some(0),
+        };
+    }
+
+    /// Move the selected entry to the index `target` picks from its index and
+    /// the entry count (`None` to stay put); the selection follows it
+    fn move_selected(&mut self, target: impl FnOnce(usize, usize) -> Option<usize>) -> UIAction {
+        let Some(from_idx) = self.selected_index.filter(|&i| i < self.items.len()) else {
+            return UIAction::None;
+        };
+        match target(from_idx, self.items.len()) {
+            Some(to_idx) if to_idx != from_idx => {
+                self.selected_index = Some(to_idx);
+                UIAction::TriggerReorderQueue { from_idx, to_idx }
+            }
+            _ => UIAction::None,
+        }
+    }
+}
+
+impl Buffer for QueueBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Queue".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.items.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Queue", &self.items, |item| {
+            item.podcast_title.len() + item.episode_title.len()
+        })
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Queue Commands:".to_string(),
+            "  C-n, ↓    Next entry".to_string(),
+            "  C-p, ↑    Previous entry".to_string(),
+            "  Enter     Play entry now".to_string(),
+            "  C-↑/C-↓   Move entry up/down".to_string(),
+            "  C-Home    Move entry to the top".to_string(),
+            "  X         Remove from queue".to_string(),
+            "  :queue-clear  Empty the queue".to_string(),
+            "  C-h       Show help".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for QueueBuffer {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                if !self.items.is_empty() {
+                    self.selected_index = Some(0);
+                    self.scroll_offset = 0;
+                }
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                if !self.items.is_empty() {
+                    self.selected_index = Some(self.items.len() - 1);
+                }
+                UIAction::Render
+            }
+            UIAction::MoveEpisodeUp => self.move_selected(|index, _| index.checked_sub(1)),
+            UIAction::MoveEpisodeDown => {
+                self.move_selected(|index, len| (index + 1 < len).then_some(index + 1))
+            }
+            UIAction::MoveEpisodeToTop => self.move_selected(|_, _| Some(0)),
+            UIAction::DeleteDownloadedEpisode => match self.selected_index {
+                Some(index) if index < self.items.len() => {
+                    UIAction::TriggerRemoveFromQueue { index }
+                }
+                _ => UIAction::ShowMessage("No queued episode selected".to_string()),
+            },
+            UIAction::SelectItem => match self.selected_index {
+                Some(index) if index < self.items.len() => UIAction::TriggerPlayQueued { index },
+                _ => UIAction::ShowMessage("No queued episode selected".to_string()),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.scroll_area = area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()))
+            .border_style(if self.focused {
+                self.theme.border_focused_style()
+            } else {
+                self.theme.border_style()
+            });
+
+        if self.items.is_empty() {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let message = Paragraph::new(
+                "The queue is empty.\n\nPress e on an episode in an episode list or What's New to play it next.",
+            )
+            .style(self.theme.default_style())
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true });
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let visible_height = block.inner(area).height.saturating_sub(1) as usize; // -1 for header
+        if let Some(selected) = self.selected_index {
+            if selected >= self.scroll_offset + visible_height {
+                self.scroll_offset = selected.saturating_sub(visible_height.saturating_sub(1));
+            } else if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            }
+        }
+
+        let header = Row::new(vec![
+            Cell::from("#"),
+            Cell::from(""),
+            Cell::from("Podcast"),
+            Cell::from("Episode"),
+        ])
+        .style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let end = (self.scroll_offset + visible_height).min(self.items.len());
+        let rows: Vec<Row> = self.items[self.scroll_offset..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, item)| {
+                let index = self.scroll_offset + offset;
+                let style = if Some(index) == self.selected_index {
+                    self.theme.selected_style()
+                } else {
+                    self.theme.default_style()
+                };
+                // Marks the entries that can play; the rest are skipped
+                let glyph = if item.downloaded {
+                    Cell::from(self.theme.glyph(StatusGlyph::Downloaded))
+                        .style(self.theme.glyph_style(StatusGlyph::Downloaded))
+                } else {
+                    Cell::from("")
+                };
+                Row::new(vec![
+                    Cell::from((index + 1).to_string()),
+                    glyph,
+                    Cell::from(text_layout::cell(&item.podcast_title, 25)),
+                    Cell::from(text_layout::cell(&item.episode_title, 70)),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Length(2),
+                Constraint::Percentage(25),
+                Constraint::Percentage(75),
+            ],
+        )
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+        frame.render_widget(table, area);
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn title(&self) -> String {
+        format!("Queue ({})", self.items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str) -> QueueItem {
+        QueueItem {
+            episode_id: EpisodeId::new(),
+            podcast_title: "Local Radio".to_string(),
+            episode_title: title.to_string(),
+            downloaded: true,
+        }
+    }
+
+    #[test]
+    fn test_reorder_and_remove_act_on_selected_entry() {
+        // Arrange
+        let mut buffer = QueueBuffer::new();
+        buffer.set_items(vec![item("First"), item("Second")]);
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let moved = buffer.handle_action(UIAction::MoveEpisodeUp);
+        let removed = buffer.handle_action(UIAction::DeleteDownloadedEpisode);
+
+        // Assert
+        assert_eq!(
+            moved,
+            UIAction::TriggerReorderQueue {
+                from_idx: 1,
+                to_idx: 0
+            }
+        );
+        assert_eq!(removed, UIAction::TriggerRemoveFromQueue { index: 0 });
+        assert_eq!(
+            buffer.handle_action(UIAction::MoveEpisodeUp),
+            UIAction::None
+        );
+    }
+
+    #[test]
+    fn test_set_items_keeps_cursor_near_removed_entry() {
+        let mut buffer = QueueBuffer::new();
+        let items = vec![item("First"), item("Second"), item("Third")];
+        buffer.set_items(items.clone());
+        buffer.handle_action(UIAction::MoveToBottom);
+
+        buffer.set_items(items[..2].to_vec());
+
+        assert_eq!(buffer.selected_item().unwrap().episode_title, "Second");
+        buffer.set_items(Vec::new());
+        assert!(buffer.selected_item().is_none());
+    }
+}
@@ -0,0 +1,350 @@
+// Refresh diff buffer - what the latest refresh of a podcast changed
+//
+// Opened with `:refresh-diff` from the podcast or episode list. Lists new
+// episodes, stored episodes whose title / description / enclosure differ in
+// the feed (before → after), and episodes the feed no longer carries. After a
+// normal refresh the updates are only reported; a hard refresh applies them.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    podcast::{ChangedField, RefreshDiff},
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Widest description excerpt (in columns) shown on either side of a change
+const DESCRIPTION_EXCERPT: usize = 160;
+
+/// Buffer for displaying a refresh diff
+pub struct RefreshDiffBuffer {
+    id: String,
+    diff: RefreshDiff,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl RefreshDiffBuffer {
+    pub fn new(diff: RefreshDiff) -> Self {
+        Self {
+            id: format!("refresh-diff-{}", diff.podcast_id),
+            diff,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    fn heading(text: String) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                text,
+                Style::default().add_modifier(Modifier::BOLD),
+            )]),
+            Line::from("─".repeat(60)),
+        ]
+    }
+
+    fn excerpt(field: ChangedField, value: &str) -> String {
+        if value.is_empty() {
+            return "(none)".to_string();
+        }
+        if field != ChangedField::Description {
+            return value.to_string();
+        }
+        text_layout::truncate(value, DESCRIPTION_EXCERPT).into_owned()
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'static>> {
+        let diff = &self.diff;
+        let mode = if diff.hard {
+            "hard refresh — updates applied"
+        } else {
+            "refresh — updates not applied (use hard refresh to apply)"
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Refreshed: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(diff.refreshed_at.format("%Y-%m-%d %H:%M UTC").to_string()),
+                Span::styled(format!("  ({})", mode), self.theme.muted_style()),
+            ]),
+            Line::from(vec![
+                Span::styled("Summary: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(diff.summary()),
+            ]),
+        ];
+
+        if diff.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("The feed matches what is stored."));
+            return lines;
+        }
+
+        if !diff.new.is_empty() {
+            lines.extend(Self::heading(format!("New ({}):", diff.new.len())));
+            for title in &diff.new {
+                lines.push(Line::from(vec![
+                    Span::styled("+ ", self.theme.success_style()),
+                    Span::raw(title.clone()),
+                ]));
+            }
+        }
+
+        if !diff.updated.is_empty() {
+            lines.extend(Self::heading(format!("Updated ({}):", diff.updated.len())));
+            for episode in &diff.updated {
+                lines.push(Line::from(vec![
+                    Span::styled("~ ", self.theme.warning_style()),
+                    Span::raw(episode.title.clone()),
+                ]));
+                for change in &episode.changes {
+                    let label = format!("    {}: ", change.field.label());
+                    lines.push(Line::from(vec![
+                        Span::styled(label.clone(), self.theme.muted_style()),
+                        Span::styled(
+                            format!("- {}", Self::excerpt(change.field, &change.before)),
+                            self.theme.error_style(),
+                        ),
+                    ]));
+                    lines.push(Line::from(vec![
+                        Span::raw(" ".repeat(text_layout::display_width(&label))),
+                        Span::styled(
+                            format!("+ {}", Self::excerpt(change.field, &change.after)),
+                            self.theme.success_style(),
+                        ),
+                    ]));
+                }
+            }
+        }
+
+        if !diff.removed.is_empty() {
+            lines.extend(Self::heading(format!(
+                "No longer in feed ({}, kept locally):",
+                diff.removed.len()
+            )));
+            for title in &diff.removed {
+                lines.push(Line::from(vec![
+                    Span::styled("- ", self.theme.error_style()),
+                    Span::raw(title.clone()),
+                ]));
+            }
+        }
+
+        if !diff.audio_updated.is_empty() {
+            lines.extend(Self::heading(format!(
+                "New audio since download ({}, U re-downloads):",
+                diff.audio_updated.len()
+            )));
+            for title in &diff.audio_updated {
+                lines.push(Line::from(vec![
+                    Span::styled("↻ ", self.theme.warning_style()),
+                    Span::raw(title.clone()),
+                ]));
+            }
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+impl Buffer for RefreshDiffBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        format!("Changes: {}", self.diff.podcast_title)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(
+                self.diff.new.len()
+                    + self.diff.updated.len()
+                    + self.diff.removed.len()
+                    + self.diff.audio_updated.len(),
+            ),
+            ..BufferInfo::new("Refresh Diff")
+        }
+        .with_podcast(self.diff.podcast_title.clone())
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Refresh Diff Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Page Down Page down".to_string(),
+            "  Page Up   Page up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for RefreshDiffBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::PageUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+                UIAction::Render
+            }
+            UIAction::PageDown => {
+                self.scroll_offset = (self.scroll_offset + 10).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::{EpisodeChange, FieldChange};
+    use crate::storage::{EpisodeId, PodcastId};
+
+    fn content_text(buffer: &RefreshDiffBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_sections_show_new_updated_and_removed() {
+        // Arrange
+        let mut diff = RefreshDiff::new(PodcastId::new(), "Show".to_string(), false);
+        diff.new.push("Ep 3".to_string());
+        diff.updated.push(EpisodeChange {
+            episode_id: EpisodeId::new(),
+            title: "Ep 2".to_string(),
+            changes: vec![FieldChange {
+                field: ChangedField::Enclosure,
+                before: "https://old/2.mp3".to_string(),
+                after: "https://new/2.mp3".to_string(),
+            }],
+        });
+        diff.removed.push("Ep 0".to_string());
+
+        // Act
+        let lines = content_text(&RefreshDiffBuffer::new(diff));
+
+        // Assert
+        assert!(lines.contains(&"+ Ep 3".to_string()));
+        assert!(lines.contains(&"~ Ep 2".to_string()));
+        assert!(lines.contains(&"    Enclosure: - https://old/2.mp3".to_string()));
+        assert!(lines.iter().any(|l| l.trim() == "+ https://new/2.mp3"));
+        assert!(lines.contains(&"- Ep 0".to_string()));
+        assert!(lines.iter().any(|l| l.contains("updates not applied")));
+    }
+
+    #[test]
+    fn test_empty_diff_says_nothing_changed() {
+        let diff = RefreshDiff::new(PodcastId::new(), "Show".to_string(), true);
+        let lines = content_text(&RefreshDiffBuffer::new(diff));
+        assert!(lines.contains(&"The feed matches what is stored.".to_string()));
+        assert!(lines.contains(&"Summary: no changes".to_string()));
+    }
+}
@@ -0,0 +1,271 @@
+// Release notes buffer - changelog of releases newer than this build
+//
+// Opened with `:release-notes`. Release bodies are Markdown; headings are
+// shown in bold without their `#` markers and everything else is kept as-is.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    ui::{
+        buffers::{Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+    update_check::Release,
+};
+
+/// Buffer listing the release notes of newer versions
+pub struct ReleaseNotesBuffer {
+    id: String,
+    current_version: String,
+    releases: Vec<Release>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    /// Lines of content at the last render
+    content_lines: usize,
+    theme: Theme,
+}
+
+impl ReleaseNotesBuffer {
+    pub fn new(current_version: String, releases: Vec<Release>) -> Self {
+        Self {
+            id: "release-notes".to_string(),
+            current_version,
+            releases,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            content_lines: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Generate content lines for display
+    fn generate_content(&self) -> Vec<Line<'_>> {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![Line::from(format!(
+            "Installed version: {}",
+            self.current_version
+        ))];
+
+        if self.releases.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("You are running the latest release."));
+            return lines;
+        }
+
+        for release in &self.releases {
+            lines.push(Line::from(""));
+            let mut heading = vec![Span::styled(format!("v{}", release.version()), bold)];
+            if let Some(published) = release.published_at {
+                heading.push(Span::styled(
+                    published.format("  %Y-%m-%d").to_string(),
+                    self.theme.muted_style(),
+                ));
+            }
+            lines.push(Line::from(heading));
+            lines.push(Line::from("─".repeat(60)));
+
+            let body = release.body.as_deref().unwrap_or("").trim();
+            if body.is_empty() {
+                lines.push(Line::from("No release notes."));
+            }
+            for line in body.lines() {
+                let trimmed = line.trim_end();
+                if trimmed.starts_with('#') {
+                    let title = trimmed.trim_start_matches('#').trim();
+                    lines.push(Line::from(Span::styled(title.to_string(), bold)));
+                } else {
+                    lines.push(Line::from(trimmed.to_string()));
+                }
+            }
+            if !release.html_url.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    release.html_url.clone(),
+                    self.theme.muted_style(),
+                )));
+            }
+        }
+
+        lines
+    }
+
+    /// Furthest the text scrolls in the area it was last drawn in
+    fn max_scroll(&self) -> usize {
+        ScrollPosition::max_top(self.scroll_area, self.generate_content().len())
+    }
+}
+
+impl Buffer for ReleaseNotesBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "Release Notes".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::lines(self.scroll_area, self.scroll_offset, self.content_lines)
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo {
+            item_count: Some(self.releases.len()),
+            ..BufferInfo::new("Releases")
+        }
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Release Notes Commands:".to_string(),
+            "  C-n, ↓    Scroll down".to_string(),
+            "  C-p, ↑    Scroll up".to_string(),
+            "  Page Down Page down".to_string(),
+            "  Page Up   Page up".to_string(),
+            "  Home      Scroll to top".to_string(),
+            "  End       Scroll to bottom".to_string(),
+            "  C-k       Close buffer".to_string(),
+        ]
+    }
+}
+
+impl UIComponent for ReleaseNotesBuffer {
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::PageUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+                UIAction::Render
+            }
+            UIAction::PageDown => {
+                self.scroll_offset = (self.scroll_offset + 10).min(self.max_scroll());
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                self.scroll_offset = 0;
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                self.scroll_offset = self.max_scroll();
+                UIAction::Render
+            }
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border_style = if self.focused {
+            self.theme.border_focused_style()
+        } else {
+            self.theme.border_style()
+        };
+
+        let content = self.generate_content();
+        let content_lines = content.len();
+        let scroll_offset = self
+            .scroll_offset
+            .min(ScrollPosition::max_top(area, content_lines));
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(self.name())
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title_style(self.theme.title_style()),
+            )
+            .style(self.theme.text_style())
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_offset as u16, 0));
+
+        frame.render_widget(paragraph, area);
+        self.scroll_offset = scroll_offset;
+        self.scroll_area = area;
+        self.content_lines = content_lines;
+    }
+
+    fn title(&self) -> String {
+        self.name()
+    }
+
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_text(buffer: &ReleaseNotesBuffer) -> Vec<String> {
+        buffer
+            .generate_content()
+            .iter()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_release_body_headings_are_unmarked() {
+        // Arrange
+        let release = Release {
+            tag_name: "v1.12.0".to_string(),
+            name: None,
+            body: Some("## Added\n- Folded show notes".to_string()),
+            html_url: "https://example.com/v1.12.0".to_string(),
+            published_at: None,
+            draft: false,
+            prerelease: false,
+        };
+
+        // Act
+        let lines = content_text(&ReleaseNotesBuffer::new(
+            "1.11.0".to_string(),
+            vec![release],
+        ));
+
+        // Assert
+        assert!(lines.contains(&"v1.12.0".to_string()));
+        assert!(lines.contains(&"Added".to_string()));
+        assert!(lines.contains(&"- Folded show notes".to_string()));
+        assert!(lines.contains(&"https://example.com/v1.12.0".to_string()));
+    }
+
+    #[test]
+    fn test_no_newer_releases_message() {
+        let lines = content_text(&ReleaseNotesBuffer::new("1.11.0".to_string(), Vec::new()));
+        assert!(lines.contains(&"You are running the latest release.".to_string()));
+    }
+}
@@ -0,0 +1,386 @@
+// Search buffer - episodes across every podcast matching a full-text query
+//
+// Lists the hits of a `search::SearchIndex` query, best first, with the
+// podcast, the episode, its date and which part of it matched. The query is
+// typed in the minibuffer and the list follows along; Enter opens the
+// selected episode's details. Opened with C-s or `:search-all`.
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::{
+    search::SearchHit,
+    storage::{EpisodeId, PodcastId},
+    ui::{
+        buffers::{capabilities::EpisodeSelection, Buffer, BufferId, BufferInfo},
+        components::ScrollPosition,
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+/// Buffer listing full-text search results
+pub struct SearchBuffer {
+    id: String,
+    query: String,
+    hits: Vec<SearchHit>,
+    /// The index is still being built; results follow once it is
+    indexing: bool,
+    selected_index: Option<usize>,
+    scroll_offset: usize,
+    focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
+    theme: Theme,
+}
+
+impl Default for SearchBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchBuffer {
+    pub fn new() -> Self {
+        Self {
+            id: "search".to_string(),
+            query: String::new(),
+            hits: Vec::new(),
+            indexing: false,
+            selected_index: None,
+            scroll_offset: 0,
+            focused: false,
+            scroll_area: Rect::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// The query the listed results are for
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn set_indexing(&mut self, indexing: bool) {
+        self.indexing = indexing;
+    }
+
+    /// Show the results of `query`, selecting the best one
+    pub fn set_results(&mut self, query: String, hits: Vec<SearchHit>) {
+        self.query = query;
+        self.hits = hits;
+        self.selected_index = (!self.hits.is_empty()).then_some(0);
+        self.scroll_offset = 0;
+    }
+
+    pub fn hits(&self) -> &[SearchHit] {
+        &self.hits
+    }
+
+    pub fn selected_hit(&self) -> Option<&SearchHit> {
+        self.selected_index.and_then(|i| self.hits.get(i))
+    }
+
+    fn select_previous(&mut self) {
+        if self.hits.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(0) => Some(self.hits.len() - 1),
+            Some(i) => Some(i - 1),
+            None => Some(0),
+        };
+    }
+
+    fn select_next(&mut self) {
+        if self.hits.is_empty() {
+            return;
+        }
+        self.selected_index = match self.selected_index {
+            Some(i) if i >= self.hits.len() - 1 => Some(0),
+            Some(i) => Some(i + 1),
+            None => Some(0),
+        };
+    }
+
+    fn empty_message(&self) -> String {
+        if self.indexing {
+            "Indexing episodes...".to_string()
+        } else if self.query.trim().is_empty() {
+            "Type to search episode titles, credited people, show notes and your notes across all podcasts.\n\nC-s or :search-all <words> starts a new search."
+                .to_string()
+        } else {
+            format!("No episodes match \"{}\".", self.query)
+        }
+    }
+}
+
+impl Buffer for SearchBuffer {
+    fn id(&self) -> BufferId {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        "*Search*".to_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn as_episode_selection(&self) -> Option<&dyn EpisodeSelection> {
+        Some(self)
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        ScrollPosition::items(self.scroll_area, self.selected_index?, self.hits.len())
+    }
+
+    fn info(&self) -> BufferInfo {
+        BufferInfo::list("Search", &self.hits, |hit| {
+            hit.podcast_title.len() + hit.episode_title.len()
+        })
+    }
+
+    fn help_text(&self) -> Vec<String> {
+        vec![
+            "Search Commands:".to_string(),
+            "  C-n, ↓    Next result".to_string(),
+            "  C-p, ↑    Previous result".to_string(),
+            "  Enter     Open episode details".to_string(),
+            "  C-s       New search".to_string(),
+            "  C-h       Show help".to_string(),
+        ]
+    }
+}
+
+impl EpisodeSelection for SearchBuffer {
+    fn selected_episode_ids(&self) -> Option<(PodcastId, EpisodeId)> {
+        let hit = self.selected_hit()?;
+        Some((hit.podcast_id.clone(), hit.episode_id.clone()))
+    }
+
+    fn shown_episodes(&self) -> Option<Vec<(PodcastId, EpisodeId)>> {
+        Some(
+            self.hits
+                .iter()
+                .map(|hit| (hit.podcast_id.clone(), hit.episode_id.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl UIComponent for SearchBuffer {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn handle_action(&mut self, action: UIAction) -> UIAction {
+        match action {
+            UIAction::MoveUp => {
+                self.select_previous();
+                UIAction::Render
+            }
+            UIAction::MoveDown => {
+                self.select_next();
+                UIAction::Render
+            }
+            UIAction::MoveToTop => {
+                if !self.hits.is_empty() {
+                    self.selected_index = Some(0);
+                    self.scroll_offset = 0;
+                }
+                UIAction::Render
+            }
+            UIAction::MoveToBottom => {
+                if !self.hits.is_empty() {
+                    self.selected_index = Some(self.hits.len() - 1);
+                }
+                UIAction::Render
+            }
+            UIAction::SelectItem => match self.selected_hit() {
+                Some(hit) => UIAction::OpenEpisodeDetailById {
+                    podcast_id: hit.podcast_id.clone(),
+                    episode_id: hit.episode_id.clone(),
+                },
+                None => UIAction::ShowMessage("No search result selected".to_string()),
+            },
+            _ => UIAction::None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.scroll_area = area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()))
+            .border_style(if self.focused {
+                self.theme.border_focused_style()
+            } else {
+                self.theme.border_style()
+            });
+
+        if self.hits.is_empty() {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            let message = Paragraph::new(self.empty_message())
+                .style(self.theme.default_style())
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(Wrap { trim: true });
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let visible_height = block.inner(area).height.saturating_sub(1) as usize; // -1 for header
+        if let Some(selected) = self.selected_index {
+            if selected >= self.scroll_offset + visible_height {
+                self.scroll_offset = selected.saturating_sub(visible_height.saturating_sub(1));
+            } else if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            }
+        }
+
+        let header = Row::new(vec![
+            Cell::from("Podcast"),
+            Cell::from("Episode"),
+            Cell::from("Date"),
+            Cell::from("Matched"),
+        ])
+        .style(
+            Style::default()
+                .fg(self.theme.colors.primary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let end = (self.scroll_offset + visible_height).min(self.hits.len());
+        let rows: Vec<Row> = self.hits[self.scroll_offset..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, hit)| {
+                let style = if Some(self.scroll_offset + offset) == self.selected_index {
+                    self.theme.selected_style()
+                } else {
+                    self.theme.default_style()
+                };
+                Row::new(vec![
+                    Cell::from(text_layout::cell(&hit.podcast_title, 25)),
+                    Cell::from(text_layout::cell(&hit.episode_title, 70)),
+                    Cell::from(
+                        self.theme
+                            .time_zone
+                            .localize(hit.published, hit.published_offset)
+                            .format("%Y-%m-%d")
+                            .to_string(),
+                    ),
+                    Cell::from(hit.matched.label()),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(25),
+                Constraint::Percentage(55),
+                Constraint::Length(10),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+        frame.render_widget(table, area);
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn title(&self) -> String {
+        if self.query.trim().is_empty() {
+            "Search".to_string()
+        } else {
+            format!("Search: {} ({})", self.query, self.hits.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::MatchField;
+    use crate::storage::{EpisodeId, PodcastId};
+
+    fn hit(title: &str) -> SearchHit {
+        SearchHit {
+            podcast_id: PodcastId::new(),
+            episode_id: EpisodeId::new(),
+            podcast_title: "Local Radio".to_string(),
+            episode_title: title.to_string(),
+            published: chrono::Utc::now(),
+            published_offset: None,
+            matched: MatchField::Title,
+        }
+    }
+
+    #[test]
+    fn test_enter_opens_selected_result() {
+        // Arrange
+        let mut buffer = SearchBuffer::new();
+        let hits = vec![hit("First"), hit("Second")];
+        buffer.set_results("radio".to_string(), hits.clone());
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let action = buffer.handle_action(UIAction::SelectItem);
+
+        // Assert
+        assert_eq!(
+            action,
+            UIAction::OpenEpisodeDetailById {
+                podcast_id: hits[1].podcast_id.clone(),
+                episode_id: hits[1].episode_id.clone(),
+            }
+        );
+        assert_eq!(buffer.title(), "Search: radio (2)");
+
+        // A new query starts from the best result
+        buffer.set_results("first".to_string(), hits[..1].to_vec());
+        assert_eq!(buffer.selected_hit().unwrap().episode_title, "First");
+    }
+
+    #[test]
+    fn test_shown_episodes_lists_every_hit_in_order() {
+        // Arrange
+        let mut buffer = SearchBuffer::new();
+        let hits = vec![hit("First"), hit("Second"), hit("Third")];
+        buffer.set_results("radio".to_string(), hits.clone());
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act
+        let shown = buffer.shown_episodes().unwrap();
+        let selected = buffer.selected_episode_ids();
+
+        // Assert
+        let expected: Vec<_> = hits
+            .iter()
+            .map(|hit| (hit.podcast_id.clone(), hit.episode_id.clone()))
+            .collect();
+        assert_eq!(shown, expected);
+        assert_eq!(selected, Some(expected[1].clone()));
+    }
+}
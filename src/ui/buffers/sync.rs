@@ -23,7 +23,7 @@ use crate::{
     download::{DownloadManager, SyncHistorySummary, SyncProgressEvent, SyncReport},
     storage::JsonStorage,
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{Buffer, BufferId, BufferInfo},
         themes::Theme,
         UIAction, UIComponent,
     },
@@ -38,6 +38,9 @@ pub struct SyncTarget {
     pub path: PathBuf,
     pub use_count: u32,
     pub last_used: DateTime<Utc>,
+    /// Sync only this playlist, in order, instead of the download library
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playlist: Option<String>,
 }
 
 /// Persistent sync history entry stored to disk
@@ -226,6 +229,33 @@ impl SyncBuffer {
         self.active_target.as_ref()
     }
 
+    /// The playlist a target syncs instead of the download library, if any
+    pub fn target_playlist(&self, path: &Path) -> Option<&str> {
+        self.saved_targets
+            .iter()
+            .find(|t| t.path == path)
+            .and_then(|t| t.playlist.as_deref())
+    }
+
+    /// Make a target sync only `playlist` (or the whole library again with
+    /// `None`), saving the target if it wasn't yet
+    pub fn set_target_playlist(&mut self, path: PathBuf, playlist: Option<String>) {
+        if let Some(existing) = self.saved_targets.iter_mut().find(|t| t.path == path) {
+            existing.playlist = playlist;
+        } else {
+            self.saved_targets.push(SyncTarget {
+                path,
+                use_count: 0,
+                last_used: Utc::now(),
+                playlist,
+            });
+            self.saved_targets
+                .sort_by_key(|t| std::cmp::Reverse(t.last_used));
+            self.saved_targets.truncate(MAX_SAVED_TARGETS);
+        }
+        self.persist_targets();
+    }
+
     // ── Persistence ──────────────────────────────────────────────────────────
 
     /// Load saved targets and history from disk.
@@ -313,6 +343,7 @@ impl SyncBuffer {
                 path: device_path.clone(),
                 use_count: 1,
                 last_used: now,
+                playlist: None,
             });
         }
 
@@ -627,13 +658,16 @@ impl SyncBuffer {
                 } else {
                     "  "
                 };
-                let content = format!(
+                let mut content = format!(
                     "{}{}  (used {}×, last {})",
                     active_marker,
                     target.path.display(),
                     target.use_count,
                     target.last_used.format("%Y-%m-%d"),
                 );
+                if let Some(playlist) = &target.playlist {
+                    content.push_str(&format!("  → playlist: {}", playlist));
+                }
                 let style = if i == self.selected_index {
                     self.theme.selected_style()
                 } else {
@@ -833,6 +867,10 @@ impl Buffer for SyncBuffer {
         true
     }
 
+    fn info(&self) -> BufferInfo {
+        BufferInfo::new("Sync")
+    }
+
     fn help_text(&self) -> Vec<String> {
         vec![
             "Sync Buffer Help".to_string(),
@@ -1189,6 +1227,7 @@ impl SyncBuffer {
                 path,
                 use_count: 1,
                 last_used: now,
+                playlist: None,
             });
         }
         self.saved_targets
@@ -1631,6 +1670,7 @@ mod tests {
             path: PathBuf::from("/media/usb"),
             use_count: 5,
             last_used: Utc::now(),
+            playlist: None,
         };
 
         // Act
@@ -1682,6 +1722,25 @@ mod tests {
         assert_eq!(buffer.handle_action(UIAction::PageDown), UIAction::Render);
     }
 
+    #[test]
+    fn test_target_playlist_is_saved_and_cleared() {
+        // Arrange
+        let mut buffer = SyncBuffer::new();
+        let path = PathBuf::from("/media/mp3");
+
+        // Act
+        buffer.set_target_playlist(path.clone(), Some("Commute".to_string()));
+
+        // Assert — an unknown path becomes a saved target
+        assert_eq!(buffer.target_playlist(&path), Some("Commute"));
+        assert_eq!(buffer.saved_targets.len(), 1);
+        buffer.add_sync_result(path.clone(), SyncReport::new(), false);
+        assert_eq!(buffer.target_playlist(&path), Some("Commute"));
+        buffer.set_target_playlist(path.clone(), None);
+        assert_eq!(buffer.target_playlist(&path), None);
+        assert_eq!(buffer.target_playlist(Path::new("/media/other")), None);
+    }
+
     #[test]
     fn test_select_item_on_saved_target_activates_it() {
         // Arrange
@@ -1691,6 +1750,7 @@ mod tests {
             path: path.clone(),
             use_count: 1,
             last_used: Utc::now(),
+            playlist: None,
         });
         buffer.selected_index = 0;
 
@@ -3,6 +3,9 @@
 // This buffer aggregates the most recent episodes across all podcasts,
 // sorted in reverse chronological order. Users can download episodes directly
 // from this view, and episodes are removed once downloaded.
+//
+// The same buffer type also backs the "Unplayed" virtual buffer, which lists
+// every unplayed episode (downloaded or not) with no episode limit.
 
 use ratatui::{
     layout::{Constraint, Rect},
@@ -13,15 +16,20 @@ use ratatui::{
 
 use crate::{
     download::DownloadManager,
-    podcast::{subscription::SubscriptionManager, Episode, EpisodeStatus},
-    storage::{JsonStorage, PodcastId, Storage},
+    podcast::{subscription::SubscriptionManager, DownloadState, Episode},
+    storage::{EpisodeId, JsonStorage, PodcastId, Storage},
     ui::{
-        buffers::{Buffer, BufferId},
+        buffers::{
+            capabilities::{Downloadable, EpisodeSelection, Playable, Refreshable},
+            Buffer, BufferId, BufferInfo,
+        },
+        components::ScrollPosition,
+        events::BufferRefreshType,
         filters::EpisodeFilter,
+        glyphs, text_layout,
         themes::Theme,
         UIAction, UIComponent,
     },
-    utils::time::format_relative_time,
 };
 use std::sync::Arc;
 
@@ -33,13 +41,37 @@ pub struct AggregatedEpisode {
     pub episode: Episode,
 }
 
+/// Which episodes an aggregate buffer collects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpisodeScope {
+    /// Newest not-yet-downloaded episodes, capped at `max_episodes` (What's New)
+    Latest,
+    /// Every unplayed episode across all subscriptions
+    Unplayed,
+}
+
+impl EpisodeScope {
+    /// Whether an episode belongs in a buffer with this scope
+    pub fn includes(self, episode: &Episode) -> bool {
+        match self {
+            Self::Latest => {
+                !episode.is_downloaded() && episode.status.download != DownloadState::Downloading
+            }
+            Self::Unplayed => !episode.is_played(),
+        }
+    }
+}
+
 /// Buffer for displaying latest episodes across all podcasts
 pub struct WhatsNewBuffer {
     id: String,
+    scope: EpisodeScope,
     episodes: Vec<AggregatedEpisode>,
     selected_index: Option<usize>,
     scroll_offset: usize,
     focused: bool,
+    /// Area scrolled in at the last render, for the scroll indicator
+    scroll_area: Rect,
     theme: Theme,
     subscription_manager: Option<Arc<SubscriptionManager<JsonStorage>>>,
     download_manager: Option<Arc<DownloadManager<JsonStorage>>>,
@@ -53,10 +85,12 @@ impl WhatsNewBuffer {
     pub fn new(max_episodes: usize) -> Self {
         Self {
             id: "whats-new".to_string(),
+            scope: EpisodeScope::Latest,
             episodes: Vec::new(),
             selected_index: None,
             scroll_offset: 0,
             focused: false,
+            scroll_area: Rect::default(),
             theme: Theme::default(),
             subscription_manager: None,
             download_manager: None,
@@ -66,6 +100,19 @@ impl WhatsNewBuffer {
         }
     }
 
+    /// Create the "Unplayed" virtual buffer: all unplayed episodes, no limit
+    pub fn unplayed() -> Self {
+        Self {
+            id: "unplayed".to_string(),
+            scope: EpisodeScope::Unplayed,
+            ..Self::new(usize::MAX)
+        }
+    }
+
+    pub fn scope(&self) -> EpisodeScope {
+        self.scope
+    }
+
     /// Set managers
     pub fn set_managers(
         &mut self,
@@ -100,12 +147,9 @@ impl WhatsNewBuffer {
                     Err(_) => continue, // Skip if episodes fail to load
                 };
 
-                // Filter out downloaded episodes and aggregate
+                // Keep only the episodes this buffer's scope collects
                 for episode in episodes {
-                    // Skip downloaded or currently downloading episodes
-                    if !episode.is_downloaded()
-                        && !matches!(episode.status, EpisodeStatus::Downloading)
-                    {
+                    if self.scope.includes(&episode) {
                         all_episodes.push(AggregatedEpisode {
                             podcast_id: podcast.id.clone(),
                             podcast_title: podcast.title.clone(),
@@ -143,6 +187,13 @@ impl WhatsNewBuffer {
             .and_then(|&actual| self.episodes.get(actual))
     }
 
+    /// The episodes the buffer shows after filtering, in display order
+    pub fn shown_episodes(&self) -> impl Iterator<Item = &AggregatedEpisode> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&actual| self.episodes.get(actual))
+    }
+
     /// Apply current filter to episodes, rebuilding filtered_indices
     fn apply_filters(&mut self) {
         self.filtered_indices = self
@@ -162,6 +213,20 @@ impl WhatsNewBuffer {
         self.scroll_offset = 0;
     }
 
+    /// Drop an episode that no longer matches the scope, keeping the cursor
+    /// on the row that took its place
+    fn remove_episode(&mut self, actual_idx: usize) {
+        let selected = self.selected_index;
+        let scroll = self.scroll_offset;
+        self.episodes.remove(actual_idx);
+        self.apply_filters();
+        let count = self.visible_count();
+        if count > 0 {
+            self.selected_index = selected.map(|i| i.min(count - 1));
+            self.scroll_offset = scroll.min(count - 1);
+        }
+    }
+
     /// Number of currently visible (filtered) episodes
     fn visible_count(&self) -> usize {
         self.filtered_indices.len()
@@ -169,6 +234,8 @@ impl WhatsNewBuffer {
 
     /// Set episodes data directly (for background refresh)
     pub fn set_episodes(&mut self, episodes: Vec<crate::ui::events::AggregatedEpisode>) {
+        let selected_id = self.selected_episode().map(|agg| agg.episode.id.clone());
+
         // Convert from events::AggregatedEpisode to local AggregatedEpisode format
         self.episodes = episodes
             .into_iter()
@@ -179,8 +246,71 @@ impl WhatsNewBuffer {
             })
             .collect();
 
-        // Reapply filters (resets selection and scroll)
+        // Keep the cursor on the same episode instead of jumping back to the top
+        let scroll = self.scroll_offset;
         self.apply_filters();
+        self.reselect(selected_id, scroll);
+    }
+
+    /// Apply the episodes a feed refresh added or rewrote without reloading
+    /// the whole list: those in scope are inserted (newest first) or updated
+    /// in place, the rest dropped. The cursor stays on its episode.
+    pub fn apply_episode_changes(&mut self, changed: Vec<crate::ui::events::AggregatedEpisode>) {
+        if changed.is_empty() {
+            return;
+        }
+        let selected_id = self.selected_episode().map(|agg| agg.episode.id.clone());
+        let scroll = self.scroll_offset;
+        for change in changed {
+            self.episodes
+                .retain(|agg| agg.episode.id != change.episode.id);
+            if !self.scope.includes(&change.episode) {
+                continue;
+            }
+            let at = self
+                .episodes
+                .partition_point(|agg| agg.episode.published > change.episode.published);
+            self.episodes.insert(
+                at,
+                AggregatedEpisode {
+                    podcast_id: change.podcast_id,
+                    podcast_title: change.podcast_title,
+                    episode: change.episode,
+                },
+            );
+        }
+        self.episodes.truncate(self.max_episodes);
+        self.apply_filters();
+        self.reselect(selected_id, scroll);
+    }
+
+    /// Drop an episode that left the scope elsewhere, e.g. once downloaded
+    pub fn remove_episode_by_id(&mut self, episode_id: &EpisodeId) {
+        if let Some(idx) = self
+            .episodes
+            .iter()
+            .position(|agg| &agg.episode.id == episode_id)
+        {
+            self.remove_episode(idx);
+        }
+    }
+
+    /// After the list changed, put the cursor back on `selected_id` (or the
+    /// row that took its place) with the view scrolled as before
+    fn reselect(&mut self, selected_id: Option<EpisodeId>, scroll: usize) {
+        let count = self.visible_count();
+        if count == 0 {
+            return;
+        }
+        let position = selected_id.and_then(|id| {
+            self.filtered_indices
+                .iter()
+                .position(|&i| self.episodes[i].episode.id == id)
+        });
+        if let Some(position) = position {
+            self.selected_index = Some(position);
+            self.scroll_offset = scroll.min(position);
+        }
     }
 
     /// Move selection up
@@ -233,7 +363,10 @@ impl Buffer for WhatsNewBuffer {
     }
 
     fn name(&self) -> String {
-        "What's New".to_string()
+        match self.scope {
+            EpisodeScope::Latest => "What's New".to_string(),
+            EpisodeScope::Unplayed => "Unplayed".to_string(),
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -244,21 +377,89 @@ impl Buffer for WhatsNewBuffer {
         self
     }
 
+    fn as_downloadable(&mut self) -> Option<&mut dyn Downloadable> {
+        Some(self)
+    }
+
+    fn as_playable(&mut self) -> Option<&mut dyn Playable> {
+        Some(self)
+    }
+
+    fn as_refreshable(&mut self) -> Option<&mut dyn Refreshable> {
+        Some(self)
+    }
+
+    fn as_episode_selection(&self) -> Option<&dyn EpisodeSelection> {
+        Some(self)
+    }
+
+    fn on_activate_async(&mut self) -> Option<BufferRefreshType> {
+        // What's New is kept current by refresh and download events; Unplayed
+        // changes with every episode played from any other buffer
+        match self.scope {
+            EpisodeScope::Latest => None,
+            EpisodeScope::Unplayed => self.refresh_async(),
+        }
+    }
+
+    fn refresh_async(&self) -> Option<BufferRefreshType> {
+        Some(match self.scope {
+            EpisodeScope::Latest => BufferRefreshType::WhatsNew,
+            EpisodeScope::Unplayed => BufferRefreshType::Unplayed,
+        })
+    }
+
     fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
     }
 
+    fn set_hide_explicit(&mut self, hide: bool) {
+        self.filter.hide_explicit = hide;
+        self.apply_filters();
+    }
+
+    fn set_languages(&mut self, languages: &[String]) {
+        self.filter.languages = languages.to_vec();
+        self.apply_filters();
+    }
+
     fn can_close(&self) -> bool {
-        false // Core buffer, cannot be closed
+        // What's New is a core buffer; the Unplayed view is opened on demand
+        self.scope == EpisodeScope::Unplayed
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        let position = ScrollPosition::items(
+            self.scroll_area,
+            self.selected_index?,
+            self.filtered_indices.len(),
+        )?;
+        Some(if self.filter.is_active() {
+            position.with_note(format!("{} total", self.episodes.len()))
+        } else {
+            position
+        })
+    }
+
+    fn info(&self) -> BufferInfo {
+        let kind = match self.scope {
+            EpisodeScope::Latest => "What's New",
+            EpisodeScope::Unplayed => "Unplayed",
+        };
+        BufferInfo::list(kind, &self.episodes, |e| {
+            e.podcast_title.len() + super::episode_text_bytes(&e.episode)
+        })
     }
 
     fn help_text(&self) -> Vec<String> {
         vec![
-            "What's New Commands:".to_string(),
+            format!("{} Commands:", self.name()),
             "  C-n, ↓    Next episode".to_string(),
             "  C-p, ↑    Previous episode".to_string(),
             "  Enter     View episode details".to_string(),
             "  D         Download episode".to_string(),
+            "  S-Enter   Play downloaded episode".to_string(),
+            "  m / u     Toggle played / mark unplayed".to_string(),
             "  p         Add selected episode to a playlist".to_string(),
             "  /         Search episodes".to_string(),
             "  F6        Clear filters".to_string(),
@@ -268,6 +469,146 @@ impl Buffer for WhatsNewBuffer {
     }
 }
 
+impl Downloadable for WhatsNewBuffer {
+    fn download_selected(&mut self) -> UIAction {
+        if let Some(agg_episode) = self.selected_episode() {
+            let episode = &agg_episode.episode;
+
+            if episode.is_downloaded() {
+                UIAction::ShowMessage("Episode already downloaded".to_string())
+            } else if episode.status.download == DownloadState::Downloading {
+                UIAction::ShowMessage("Episode is already downloading".to_string())
+            } else if episode.audio_url.is_empty()
+                && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
+            {
+                UIAction::ShowMessage(
+                    "Cannot download: No audio URL available for this episode".to_string(),
+                )
+            } else {
+                // Return action to trigger async download
+                UIAction::TriggerDownload {
+                    podcast_id: agg_episode.podcast_id.clone(),
+                    episode_id: episode.id.clone(),
+                    episode_title: episode.display_title().to_string(),
+                }
+            }
+        } else {
+            UIAction::ShowMessage("No episode selected for download".to_string())
+        }
+    }
+}
+
+impl Playable for WhatsNewBuffer {
+    fn play_selected(&mut self) -> UIAction {
+        match self.selected_episode() {
+            Some(agg) => match agg.episode.local_path {
+                Some(ref path) if agg.episode.is_downloaded() => UIAction::PlayEpisode {
+                    podcast_id: agg.podcast_id.clone(),
+                    episode_id: agg.episode.id.clone(),
+                    path: path.clone(),
+                },
+                _ => UIAction::ShowError("Episode must be downloaded before playing".into()),
+            },
+            None => UIAction::ShowError("No episode selected".into()),
+        }
+    }
+
+    /// `m` toggles: on a played episode it marks it unplayed again
+    fn mark_selected_played(&mut self, played: bool) -> UIAction {
+        let Some((actual_idx, podcast_id, episode_id, episode_title, is_played)) = self
+            .selected_index
+            .and_then(|i| self.filtered_indices.get(i))
+            .copied()
+            .map(|actual_idx| {
+                let agg = &self.episodes[actual_idx];
+                (
+                    actual_idx,
+                    agg.podcast_id.clone(),
+                    agg.episode.id.clone(),
+                    agg.episode.display_title().to_string(),
+                    agg.episode.is_played(),
+                )
+            })
+        else {
+            return UIAction::ShowMessage("No episode selected".to_string());
+        };
+        if played && !is_played {
+            self.episodes[actual_idx].episode.mark_played();
+            if self.scope == EpisodeScope::Unplayed {
+                self.remove_episode(actual_idx);
+            }
+            UIAction::TriggerMarkPlayed {
+                podcast_id,
+                episode_id,
+                episode_title,
+            }
+        } else if is_played {
+            self.episodes[actual_idx].episode.mark_unplayed();
+            UIAction::TriggerMarkUnplayed {
+                podcast_id,
+                episode_id,
+                episode_title,
+            }
+        } else {
+            UIAction::ShowMessage("Episode already marked as unplayed".to_string())
+        }
+    }
+
+    fn toggle_selected_favorite(&mut self) -> UIAction {
+        let result = self
+            .selected_index
+            .and_then(|i| self.filtered_indices.get(i))
+            .copied()
+            .map(|actual_idx| {
+                let agg = &self.episodes[actual_idx];
+                (
+                    actual_idx,
+                    agg.podcast_id.clone(),
+                    agg.episode.id.clone(),
+                    agg.episode.display_title().to_string(),
+                )
+            });
+        match result {
+            Some((actual_idx, podcast_id, episode_id, episode_title)) => {
+                self.episodes[actual_idx].episode.toggle_favorite();
+                let new_favorited = self.episodes[actual_idx].episode.favorited;
+                if self.filter.favorites_only {
+                    self.apply_filters();
+                }
+                UIAction::TriggerToggleFavorite {
+                    podcast_id,
+                    episode_id,
+                    episode_title,
+                    favorited: new_favorited,
+                }
+            }
+            None => UIAction::ShowMessage("No episode selected".to_string()),
+        }
+    }
+}
+
+impl EpisodeSelection for WhatsNewBuffer {
+    fn selected_episode_entry(&self) -> Option<(&PodcastId, &Episode)> {
+        self.selected_episode()
+            .map(|entry| (&entry.podcast_id, &entry.episode))
+    }
+
+    fn shown_episodes(&self) -> Option<Vec<(PodcastId, EpisodeId)>> {
+        Some(
+            self.shown_episodes()
+                .map(|entry| (entry.podcast_id.clone(), entry.episode.id.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl Refreshable for WhatsNewBuffer {
+    fn refresh(&mut self) -> UIAction {
+        self.refresh_async()
+            .map_or(UIAction::None, UIAction::RefreshBuffer)
+    }
+}
+
 impl UIComponent for WhatsNewBuffer {
     fn has_focus(&self) -> bool {
         self.focused
@@ -310,123 +651,6 @@ impl UIComponent for WhatsNewBuffer {
                     UIAction::ShowMessage("No episode selected".to_string())
                 }
             }
-            UIAction::DownloadEpisode => {
-                if let Some(agg_episode) = self.selected_episode() {
-                    let episode = &agg_episode.episode;
-
-                    if episode.is_downloaded() {
-                        UIAction::ShowMessage("Episode already downloaded".to_string())
-                    } else if matches!(episode.status, EpisodeStatus::Downloading) {
-                        UIAction::ShowMessage("Episode is already downloading".to_string())
-                    } else if episode.audio_url.is_empty()
-                        && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
-                    {
-                        UIAction::ShowMessage(
-                            "Cannot download: No audio URL available for this episode".to_string(),
-                        )
-                    } else {
-                        // Return action to trigger async download
-                        UIAction::TriggerDownload {
-                            podcast_id: agg_episode.podcast_id.clone(),
-                            episode_id: episode.id.clone(),
-                            episode_title: episode.title.clone(),
-                        }
-                    }
-                } else {
-                    UIAction::ShowMessage("No episode selected for download".to_string())
-                }
-            }
-            UIAction::MarkPlayed => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let agg = &self.episodes[actual_idx];
-                        (
-                            actual_idx,
-                            agg.podcast_id.clone(),
-                            agg.episode.id.clone(),
-                            agg.episode.title.clone(),
-                            agg.episode.is_played(),
-                        )
-                    });
-                match result {
-                    Some((_, _, _, _, true)) => {
-                        UIAction::ShowMessage("Episode already marked as played".to_string())
-                    }
-                    Some((actual_idx, podcast_id, episode_id, episode_title, false)) => {
-                        self.episodes[actual_idx].episode.mark_played();
-                        UIAction::TriggerMarkPlayed {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
-            UIAction::MarkUnplayed => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let agg = &self.episodes[actual_idx];
-                        (
-                            actual_idx,
-                            agg.podcast_id.clone(),
-                            agg.episode.id.clone(),
-                            agg.episode.title.clone(),
-                            agg.episode.is_played(),
-                        )
-                    });
-                match result {
-                    Some((_, _, _, _, false)) => {
-                        UIAction::ShowMessage("Episode already marked as unplayed".to_string())
-                    }
-                    Some((actual_idx, podcast_id, episode_id, episode_title, true)) => {
-                        self.episodes[actual_idx].episode.mark_unplayed();
-                        UIAction::TriggerMarkUnplayed {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
-            UIAction::ToggleFavorite => {
-                let result = self
-                    .selected_index
-                    .and_then(|i| self.filtered_indices.get(i))
-                    .copied()
-                    .map(|actual_idx| {
-                        let agg = &self.episodes[actual_idx];
-                        (
-                            actual_idx,
-                            agg.podcast_id.clone(),
-                            agg.episode.id.clone(),
-                            agg.episode.title.clone(),
-                        )
-                    });
-                match result {
-                    Some((actual_idx, podcast_id, episode_id, episode_title)) => {
-                        self.episodes[actual_idx].episode.toggle_favorite();
-                        let new_favorited = self.episodes[actual_idx].episode.favorited;
-                        if self.filter.favorites_only {
-                            self.apply_filters();
-                        }
-                        UIAction::TriggerToggleFavorite {
-                            podcast_id,
-                            episode_id,
-                            episode_title,
-                            favorited: new_favorited,
-                        }
-                    }
-                    None => UIAction::ShowMessage("No episode selected".to_string()),
-                }
-            }
             UIAction::Search => UIAction::Search,
             UIAction::ApplySearch { query } => {
                 self.filter.text_query = if query.is_empty() { None } else { Some(query) };
@@ -476,17 +700,19 @@ impl UIComponent for WhatsNewBuffer {
     fn render(&mut self, frame: &mut Frame, area: Rect) {
         let filtered_count = self.filtered_indices.len();
         let total_count = self.episodes.len();
+        self.scroll_area = area;
 
         // Build title with filter indicator
         let title = if self.filter.is_active() {
             format!(
-                " What's New ({} of {} episodes) [{}] ",
+                " {} ({} of {} episodes) [{}] ",
+                self.name(),
                 filtered_count,
                 total_count,
                 self.filter.description()
             )
         } else {
-            format!(" What's New ({} episodes) ", total_count)
+            format!(" {} ({} episodes) ", self.name(), total_count)
         };
 
         let block = Block::default()
@@ -503,12 +729,14 @@ impl UIComponent for WhatsNewBuffer {
             let inner = block.inner(area);
             frame.render_widget(block, area);
 
-            let message = ratatui::widgets::Paragraph::new(
-                "No new episodes available.\n\nEpisodes will appear here after refreshing podcasts.\nPress 'R' to refresh all podcasts.",
-            )
-            .style(self.theme.default_style())
-            .alignment(ratatui::layout::Alignment::Center)
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            let empty_text = match self.scope {
+                EpisodeScope::Latest => "No new episodes available.\n\nEpisodes will appear here after refreshing podcasts.\nPress 'R' to refresh all podcasts.",
+                EpisodeScope::Unplayed => "No unplayed episodes.\n\nYou're all caught up.",
+            };
+            let message = ratatui::widgets::Paragraph::new(empty_text)
+                .style(self.theme.default_style())
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(ratatui::widgets::Wrap { trim: true });
 
             frame.render_widget(message, inner);
             return;
@@ -545,6 +773,7 @@ impl UIComponent for WhatsNewBuffer {
 
         // Create table headers
         let header = Row::new(vec![
+            Cell::from(""),
             Cell::from("Podcast"),
             Cell::from("Episode"),
             Cell::from("Published"),
@@ -557,6 +786,7 @@ impl UIComponent for WhatsNewBuffer {
 
         // Create table rows from filtered_indices
         let end_index = (self.scroll_offset + visible_height).min(filtered_count);
+        let now = chrono::Utc::now();
         let rows: Vec<Row> = self.filtered_indices[self.scroll_offset..end_index]
             .iter()
             .enumerate()
@@ -565,24 +795,29 @@ impl UIComponent for WhatsNewBuffer {
                 let agg_episode = &self.episodes[actual_index];
                 let episode = &agg_episode.episode;
                 let style = if Some(display_pos) == self.selected_index {
-                    Style::default()
-                        .bg(self.theme.colors.selection)
-                        .fg(self.theme.colors.text)
+                    self.theme.selected_style()
                 } else {
                     self.theme.default_style()
                 };
 
-                // Format published date as relative time
-                let published_str = format_relative_time(&episode.published);
+                // Day the episode came out on the display zone's calendar
+                let published_str = self.theme.time_zone.day_label(
+                    episode.published,
+                    episode.published_offset,
+                    now,
+                );
+
+                let fav_indicator = if episode.favorited {
+                    format!("{} ", glyphs::favorite_symbol(self.theme.glyphs))
+                } else {
+                    String::new()
+                };
 
                 Row::new(vec![
-                    Cell::from(truncate_string(&agg_episode.podcast_title, 25)),
-                    Cell::from(truncate_string(
-                        &format!(
-                            "{}{}",
-                            if episode.favorited { "★ " } else { "" },
-                            episode.title
-                        ),
+                    Cell::from(glyphs::episode_span(episode, &self.theme)),
+                    Cell::from(text_layout::cell(&agg_episode.podcast_title, 25)),
+                    Cell::from(text_layout::cell(
+                        &format!("{}{}", fav_indicator, episode.display_title()),
                         65,
                     )),
                     Cell::from(published_str),
@@ -595,6 +830,7 @@ impl UIComponent for WhatsNewBuffer {
         let table = Table::new(
             rows,
             [
+                Constraint::Length(1),      // Status glyph
                 Constraint::Percentage(25), // Podcast
                 Constraint::Percentage(60), // Episode (more space!)
                 Constraint::Percentage(15), // Published
@@ -614,31 +850,21 @@ impl UIComponent for WhatsNewBuffer {
     fn title(&self) -> String {
         if self.filter.is_active() {
             format!(
-                "What's New ({} of {} episodes)",
+                "{} ({} of {} episodes)",
+                self.name(),
                 self.filtered_indices.len(),
                 self.episodes.len()
             )
         } else {
-            format!("What's New ({} episodes)", self.episodes.len())
+            format!("{} ({} episodes)", self.name(), self.episodes.len())
         }
     }
 }
 
-/// Truncate a string to a maximum length with ellipsis
-/// Uses character-aware truncation to handle multi-byte UTF-8 characters
-fn truncate_string(s: &str, max_len: usize) -> String {
-    let char_count = s.chars().count();
-    if char_count <= max_len {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max_len.saturating_sub(3)).collect();
-        format!("{}...", truncated)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::podcast::EpisodeStatus;
 
     #[test]
     fn test_whats_new_buffer_creation() {
@@ -650,13 +876,48 @@ mod tests {
     }
 
     #[test]
-    fn test_truncate_string() {
-        assert_eq!(truncate_string("short", 10), "short");
+    fn test_episode_changes_apply_in_place_and_keep_the_selection() {
+        use crate::podcast::Episode;
+        use crate::ui::events::AggregatedEpisode as Row;
+        use chrono::{Duration, Utc};
+
+        // Arrange — three episodes, the cursor on the middle one
+        let podcast_id = PodcastId::new();
+        let row = |title: &str, days_ago: i64| Row {
+            podcast_id: podcast_id.clone(),
+            podcast_title: "Show".to_string(),
+            episode: Episode::new(
+                podcast_id.clone(),
+                title.to_string(),
+                format!("https://example.com/{}.mp3", title),
+                Utc::now() - Duration::days(days_ago),
+            ),
+        };
+        let (newest, middle, oldest) = (row("newest", 1), row("middle", 3), row("oldest", 5));
+        let mut buffer = WhatsNewBuffer::new(3);
+        buffer.set_episodes(vec![newest.clone(), middle.clone(), oldest.clone()]);
+        buffer.select_next();
+        let mut downloading = newest.clone();
+        downloading.episode.status.download = DownloadState::Downloading;
+
+        // Act — a refresh adds an episode and the newest one is downloading
+        buffer.apply_episode_changes(vec![row("added", 2), downloading]);
+
+        // Assert — inserted by date, the cap holds, the cursor stays put
+        let titles: Vec<_> = buffer
+            .episodes
+            .iter()
+            .map(|agg| agg.episode.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["added", "middle", "oldest"]);
         assert_eq!(
-            truncate_string("this is a very long string", 10),
-            "this is..."
+            buffer.selected_episode().map(|agg| agg.episode.id.clone()),
+            Some(middle.episode.id.clone())
         );
-        assert_eq!(truncate_string("exactly10!", 10), "exactly10!");
+
+        buffer.remove_episode_by_id(&middle.episode.id);
+        assert_eq!(buffer.episodes.len(), 2);
+        assert!(buffer.selected_episode().is_some());
     }
 
     #[test]
@@ -951,4 +1212,88 @@ mod tests {
         assert_eq!(buffer.selected_index, None);
         assert!(buffer.selected_episode().is_none());
     }
+
+    fn make_agg(title: &str, status: EpisodeStatus) -> crate::ui::events::AggregatedEpisode {
+        let podcast_id = PodcastId::new();
+        let mut episode = Episode::new(
+            podcast_id.clone(),
+            title.to_string(),
+            "https://example.com/audio.mp3".to_string(),
+            chrono::Utc::now(),
+        );
+        episode.status = status;
+        crate::ui::events::AggregatedEpisode {
+            podcast_id,
+            podcast_title: "Show".to_string(),
+            episode,
+        }
+    }
+
+    #[test]
+    fn test_unplayed_buffer_identity() {
+        let buffer = WhatsNewBuffer::unplayed();
+        assert_eq!(buffer.id(), "unplayed");
+        assert_eq!(buffer.name(), "Unplayed");
+        assert_eq!(buffer.scope(), EpisodeScope::Unplayed);
+        assert!(buffer.can_close());
+    }
+
+    #[test]
+    fn test_scope_includes() {
+        let new = make_agg("New", EpisodeStatus::default()).episode;
+        let played = make_agg("Played", EpisodeStatus::played()).episode;
+
+        assert!(EpisodeScope::Unplayed.includes(&new));
+        assert!(!EpisodeScope::Unplayed.includes(&played));
+        // What's New keeps played-but-not-downloaded episodes, Unplayed does not
+        assert!(EpisodeScope::Latest.includes(&played));
+    }
+
+    #[test]
+    fn test_unplayed_mark_played_removes_row() {
+        // Arrange
+        let mut buffer = WhatsNewBuffer::unplayed();
+        buffer.set_episodes(vec![
+            make_agg("First", EpisodeStatus::default()),
+            make_agg("Second", EpisodeStatus::default()),
+        ]);
+
+        // Act
+        let action = buffer.mark_selected_played(true);
+
+        // Assert
+        assert!(matches!(action, UIAction::TriggerMarkPlayed { .. }));
+        assert_eq!(buffer.episodes.len(), 1);
+        assert_eq!(buffer.selected_episode().unwrap().episode.title, "Second");
+    }
+
+    #[test]
+    fn test_unplayed_refresh_keeps_selected_episode() {
+        // Arrange
+        let mut buffer = WhatsNewBuffer::unplayed();
+        let first = make_agg("First", EpisodeStatus::default());
+        let second = make_agg("Second", EpisodeStatus::default());
+        buffer.set_episodes(vec![first.clone(), second.clone()]);
+        buffer.handle_action(UIAction::MoveDown);
+
+        // Act: a refresh delivers a new episode at the top
+        buffer.set_episodes(vec![
+            make_agg("Newest", EpisodeStatus::default()),
+            first,
+            second,
+        ]);
+
+        // Assert
+        assert_eq!(buffer.selected_episode().unwrap().episode.title, "Second");
+    }
+
+    #[test]
+    fn test_play_requires_downloaded_episode() {
+        let mut buffer = WhatsNewBuffer::unplayed();
+        buffer.set_episodes(vec![make_agg("Remote", EpisodeStatus::default())]);
+
+        let action = buffer.play_selected();
+
+        assert!(matches!(action, UIAction::ShowError(_)));
+    }
 }
@@ -3,13 +3,27 @@
 // The minibuffer is used for command input, prompts, and status messages,
 // following Emacs conventions for user interaction.
 
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
 use ratatui::{
     layout::Rect,
-    widgets::{Block, Borders, Paragraph},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-use crate::ui::{themes::Theme, UIAction, UIComponent};
+use crate::constants::ui::{COMPLETION_POPUP_MAX_ROWS, MULTILINE_POPUP_MIN_ROWS};
+use crate::ui::{components::TextArea, text_layout, themes::Theme, UIAction, UIComponent};
+
+/// A completion candidate that matches the current input
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionMatch {
+    pub candidate: String,
+    /// Byte range of the matched text within `candidate` (for highlighting)
+    pub matched: Range<usize>,
+}
 
 /// Types of minibuffer content
 #[derive(Debug, Clone, PartialEq)]
@@ -48,6 +62,10 @@ pub enum MinibufferContent {
     /// Command prompt (alias for Command)
     CommandPrompt,
 
+    /// Multi-line input edited in a popup above the minibuffer; Enter adds a
+    /// line and C-s submits
+    Multiline { prompt: String, area: TextArea },
+
     /// Status message
     Status(String),
 
@@ -66,6 +84,11 @@ pub struct Minibuffer {
     completion_candidates: Vec<String>,
     /// Current completion prefix
     completion_prefix: String,
+    /// Typing pause before the completion popup updates
+    /// (`ui.performance.completion_debounce_ms`)
+    completion_debounce: Duration,
+    /// When the completion input was last edited
+    last_edit: Option<Instant>,
 }
 
 impl Minibuffer {
@@ -79,9 +102,22 @@ impl Minibuffer {
             history_index: None,
             completion_candidates: Vec::new(),
             completion_prefix: String::new(),
+            completion_debounce: Duration::ZERO,
+            last_edit: None,
         }
     }
 
+    /// Hold the completion popup back until typing pauses for `debounce`
+    pub fn set_completion_debounce(&mut self, debounce: Duration) {
+        self.completion_debounce = debounce;
+    }
+
+    /// Whether the user is still typing within the debounce window
+    fn is_debouncing(&self) -> bool {
+        self.last_edit
+            .is_some_and(|edited| edited.elapsed() < self.completion_debounce)
+    }
+
     /// Show a simple message
     pub fn show_message(&mut self, message: String) {
         self.content = MinibufferContent::Message(message);
@@ -107,6 +143,27 @@ impl Minibuffer {
         self.focused = true;
     }
 
+    /// Show a multi-line prompt, starting from `initial`
+    pub fn show_multiline_prompt(&mut self, prompt: String, initial: &str) {
+        self.content = MinibufferContent::Multiline {
+            prompt,
+            area: TextArea::new(initial),
+        };
+        self.focused = true;
+    }
+
+    /// Check if the minibuffer is taking multi-line input
+    pub fn is_multiline(&self) -> bool {
+        matches!(self.content, MinibufferContent::Multiline { .. })
+    }
+
+    /// Apply an edit to the multi-line input, if that is what is shown
+    pub fn edit_multiline(&mut self, edit: impl FnOnce(&mut TextArea)) {
+        if let MinibufferContent::Multiline { area, .. } = &mut self.content {
+            edit(area);
+        }
+    }
+
     /// Show command input prompt (M-x)
     pub fn show_command_prompt(&mut self) {
         self.content = MinibufferContent::Command {
@@ -154,6 +211,7 @@ impl Minibuffer {
                 | MinibufferContent::Prompt { .. }
                 | MinibufferContent::PromptWithCompletion { .. }
                 | MinibufferContent::Command { .. }
+                | MinibufferContent::Multiline { .. }
         )
     }
 
@@ -178,11 +236,13 @@ impl Minibuffer {
                 input.insert(*cursor_pos, ch);
                 *cursor_pos += 1;
                 *completion_index = None; // Reset completion when typing
+                self.last_edit = Some(Instant::now());
             }
             MinibufferContent::Command { input, cursor_pos } => {
                 input.insert(*cursor_pos, ch);
                 *cursor_pos += 1;
             }
+            MinibufferContent::Multiline { area, .. } => area.insert_char(ch),
             _ => {}
         }
     }
@@ -211,6 +271,7 @@ impl Minibuffer {
                     *cursor_pos -= 1;
                     input.remove(*cursor_pos);
                     *completion_index = None; // Reset completion when editing
+                    self.last_edit = Some(Instant::now());
                 }
             }
             MinibufferContent::Command { input, cursor_pos } => {
@@ -219,6 +280,7 @@ impl Minibuffer {
                     input.remove(*cursor_pos);
                 }
             }
+            MinibufferContent::Multiline { area, .. } => area.backspace(),
             _ => {}
         }
     }
@@ -241,6 +303,7 @@ impl Minibuffer {
                     *cursor_pos -= 1;
                 }
             }
+            MinibufferContent::Multiline { area, .. } => area.cursor_left(),
             _ => {}
         }
     }
@@ -267,6 +330,7 @@ impl Minibuffer {
                     *cursor_pos += 1;
                 }
             }
+            MinibufferContent::Multiline { area, .. } => area.cursor_right(),
             _ => {}
         }
     }
@@ -278,6 +342,7 @@ impl Minibuffer {
             MinibufferContent::Prompt { input, .. } => Some(input.clone()),
             MinibufferContent::PromptWithCompletion { input, .. } => Some(input.clone()),
             MinibufferContent::Command { input, .. } => Some(input.clone()),
+            MinibufferContent::Multiline { area, .. } => Some(area.text().to_string()),
             _ => None,
         }
     }
@@ -288,16 +353,20 @@ impl Minibuffer {
             MinibufferContent::Input { prompt, .. } => Some(prompt.clone()),
             MinibufferContent::Prompt { prompt, .. } => Some(prompt.clone()),
             MinibufferContent::PromptWithCompletion { prompt, .. } => Some(prompt.clone()),
+            MinibufferContent::Multiline { prompt, .. } => Some(prompt.clone()),
             _ => None,
         }
     }
 
     /// Submit the current input and return the result
     pub fn submit(&mut self) -> Option<String> {
-        let input = self.current_input()?;
+        // A candidate highlighted in the completion popup wins over the typed text
+        let input = self
+            .selected_completion()
+            .or_else(|| self.current_input())?;
 
-        // Add to history if not empty
-        if !input.is_empty() && !self.history.contains(&input) {
+        // Add to history if not empty; notes are too long to recall usefully
+        if !input.is_empty() && !self.is_multiline() && !self.history.contains(&input) {
             self.history.push(input.clone());
             // Keep history to a reasonable size
             if self.history.len() > 100 {
@@ -431,67 +500,41 @@ impl Minibuffer {
     /// Handle tab completion
     pub fn tab_complete(&mut self) {
         match &mut self.content {
-            MinibufferContent::PromptWithCompletion {
-                input,
-                completions,
-                completion_index,
-                cursor_pos,
-                ..
-            } => {
-                // Filter completions based on current input
-                let filtered: Vec<String> = if input.is_empty() {
-                    // If input is empty, show all completions
-                    completions.clone()
-                } else {
-                    // For buffer name completion, we need to handle partial command+buffer patterns
-                    completions
-                        .iter()
-                        .filter(|completion| {
-                            let completion_lower = completion.to_lowercase();
-                            let input_lower = input.to_lowercase();
-
-                            // Direct starts_with match
-                            if completion_lower.starts_with(&input_lower) {
-                                return true;
-                            }
-
-                            // For buffer commands, also check if the buffer name part matches
-                            if let Some(space_pos) = completion.rfind(' ') {
-                                let buffer_name = &completion[space_pos + 1..];
-                                if let Some(input_space_pos) = input.rfind(' ') {
-                                    let input_buffer_part = &input[input_space_pos + 1..];
-                                    if buffer_name
-                                        .to_lowercase()
-                                        .starts_with(&input_buffer_part.to_lowercase())
-                                    {
-                                        return true;
-                                    }
-                                }
-                            }
-
-                            false
-                        })
-                        .cloned()
-                        .collect()
-                };
-
-                if filtered.is_empty() {
+            MinibufferContent::PromptWithCompletion { .. } => {
+                // Tab asks for completions now, whatever the debounce
+                self.last_edit = None;
+                let matches = self.completion_matches();
+                if matches.is_empty() {
                     return;
                 }
 
-                match completion_index {
-                    None => {
-                        // Start completion with first match
-                        *completion_index = Some(0);
-                        *input = filtered[0].clone();
-                        *cursor_pos = input.len();
-                    }
-                    Some(current_index) => {
-                        // Cycle to next completion
-                        let next_index = (*current_index + 1) % filtered.len();
-                        *completion_index = Some(next_index);
-                        *input = filtered[next_index].clone();
-                        *cursor_pos = input.len();
+                let selected = self.selected_completion();
+                let input_text = self.current_input().unwrap_or_default();
+                let new_input = if let Some(candidate) = selected {
+                    // Accept the highlighted candidate
+                    Some(candidate)
+                } else if matches.len() == 1 {
+                    Some(matches[0].candidate.clone())
+                } else {
+                    // Extend to the longest common prefix, or open the selection
+                    let prefix = common_prefix(&matches, &input_text);
+                    (prefix.len() > input_text.len()).then_some(prefix)
+                };
+
+                if let MinibufferContent::PromptWithCompletion {
+                    input,
+                    cursor_pos,
+                    completion_index,
+                    ..
+                } = &mut self.content
+                {
+                    match new_input {
+                        Some(text) => {
+                            *input = text;
+                            *cursor_pos = input.len();
+                            *completion_index = None;
+                        }
+                        None => *completion_index = Some(0),
                     }
                 }
             }
@@ -543,6 +586,234 @@ impl Minibuffer {
         &self.completion_candidates
     }
 
+    /// Candidates matching the current input, prefix matches first.
+    ///
+    /// A candidate matches when it contains the input (case-insensitive), or
+    /// when its last word starts with the input's last word, so
+    /// `buffer pod` still finds `buffer Podcasts`.
+    pub fn completion_matches(&self) -> Vec<CompletionMatch> {
+        let MinibufferContent::PromptWithCompletion {
+            input, completions, ..
+        } = &self.content
+        else {
+            return Vec::new();
+        };
+
+        let mut matches: Vec<CompletionMatch> = completions
+            .iter()
+            .filter_map(|candidate| {
+                match_candidate(candidate, input).map(|matched| CompletionMatch {
+                    candidate: candidate.clone(),
+                    matched,
+                })
+            })
+            .collect();
+        matches.sort_by_key(|m| m.matched.start > 0);
+        matches
+    }
+
+    /// Whether the completion popup should be shown. With empty input the
+    /// popup only opens after Tab, so Up/Down keep browsing history; while
+    /// typing it waits out the completion debounce.
+    pub fn has_completion_popup(&self) -> bool {
+        let MinibufferContent::PromptWithCompletion {
+            input,
+            completion_index,
+            ..
+        } = &self.content
+        else {
+            return false;
+        };
+        if completion_index.is_none() && (input.is_empty() || self.is_debouncing()) {
+            return false;
+        }
+        let matches = self.completion_matches();
+        !(matches.is_empty() || matches.len() == 1 && matches[0].candidate == *input)
+    }
+
+    /// The candidate highlighted in the popup, if the user has selected one
+    pub fn selected_completion(&self) -> Option<String> {
+        match &self.content {
+            MinibufferContent::PromptWithCompletion {
+                completion_index: Some(index),
+                ..
+            } => self
+                .completion_matches()
+                .get(*index)
+                .map(|m| m.candidate.clone()),
+            _ => None,
+        }
+    }
+
+    /// Move the popup selection down one row (wraps)
+    pub fn select_next_completion(&mut self) {
+        self.move_completion_selection(|current, len| match current {
+            None => 0,
+            Some(i) => (i + 1) % len,
+        });
+    }
+
+    /// Move the popup selection up one row (wraps)
+    pub fn select_prev_completion(&mut self) {
+        self.move_completion_selection(|current, len| match current {
+            None | Some(0) => len - 1,
+            Some(i) => i - 1,
+        });
+    }
+
+    /// Move the popup selection down one page
+    pub fn page_completions_down(&mut self) {
+        self.move_completion_selection(|current, len| {
+            current
+                .map_or(0, |i| i + COMPLETION_POPUP_MAX_ROWS)
+                .min(len - 1)
+        });
+    }
+
+    /// Move the popup selection up one page
+    pub fn page_completions_up(&mut self) {
+        self.move_completion_selection(|current, _| {
+            current.map_or(0, |i| i.saturating_sub(COMPLETION_POPUP_MAX_ROWS))
+        });
+    }
+
+    fn move_completion_selection(&mut self, step: impl Fn(Option<usize>, usize) -> usize) {
+        let len = self.completion_matches().len();
+        if len == 0 {
+            return;
+        }
+        if let MinibufferContent::PromptWithCompletion {
+            completion_index, ..
+        } = &mut self.content
+        {
+            *completion_index = Some(step(*completion_index, len));
+        }
+    }
+
+    /// Render the completion popup anchored to the bottom of `area` (the
+    /// region directly above the minibuffer).
+    pub fn render_completion_popup(&self, frame: &mut Frame, area: Rect) {
+        if !self.has_completion_popup() {
+            return;
+        }
+        let matches = self.completion_matches();
+        let selected = match &self.content {
+            MinibufferContent::PromptWithCompletion {
+                completion_index, ..
+            } => *completion_index,
+            _ => None,
+        };
+
+        let rows = COMPLETION_POPUP_MAX_ROWS
+            .min(matches.len())
+            .min(area.height.saturating_sub(2) as usize);
+        if rows == 0 {
+            return;
+        }
+        // Page-based scrolling keeps the selected row on the visible page
+        let offset = selected.map_or(0, |i| (i / rows) * rows);
+
+        let lines: Vec<Line> = matches
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(rows)
+            .map(|(i, m)| {
+                let c = &m.candidate;
+                let line = Line::from(vec![
+                    Span::raw(format!(" {}", &c[..m.matched.start])),
+                    Span::styled(c[m.matched.clone()].to_string(), self.theme.primary_style()),
+                    Span::raw(c[m.matched.end..].to_string()),
+                ]);
+                if Some(i) == selected {
+                    line.style(self.theme.selected_style())
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        let title = match selected {
+            Some(i) => format!(" {}/{} ", i + 1, matches.len()),
+            None => format!(" {} matches ", matches.len()),
+        };
+        let longest = matches
+            .iter()
+            .map(|m| text_layout::display_width(&m.candidate))
+            .max()
+            .unwrap_or(0);
+        let width = ((longest + 4).max(title.len() + 4) as u16).min(area.width);
+        let height = rows as u16 + 2;
+        let popup = Rect::new(area.x, area.y + area.height - height, width, height);
+
+        let list = Paragraph::new(lines)
+            .style(self.theme.minibuffer_style())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(self.theme.border_focused_style()),
+            );
+        frame.render_widget(Clear, popup);
+        frame.render_widget(list, popup);
+    }
+
+    /// Render the multi-line input popup anchored to the bottom of `area`
+    /// (the region directly above the minibuffer), scrolled to the cursor
+    pub fn render_multiline_popup(&self, frame: &mut Frame, area: Rect) {
+        let MinibufferContent::Multiline { prompt, area: text } = &self.content else {
+            return;
+        };
+        let (cursor_line, cursor_column) = text.cursor_position();
+        let cursor_style = self.theme.selected_style().add_modifier(Modifier::REVERSED);
+
+        let lines: Vec<Line> = text
+            .text()
+            .split('\n')
+            .enumerate()
+            .map(|(i, line)| {
+                if i != cursor_line {
+                    return Line::raw(line.to_string());
+                }
+                let split = line
+                    .char_indices()
+                    .nth(cursor_column)
+                    .map_or(line.len(), |(b, _)| b);
+                let (before, rest) = line.split_at(split);
+                let mut chars = rest.chars();
+                let under = chars.next().map_or(" ".to_string(), String::from);
+                Line::from(vec![
+                    Span::raw(before.to_string()),
+                    Span::styled(under, cursor_style),
+                    Span::raw(chars.as_str().to_string()),
+                ])
+            })
+            .collect();
+
+        let max_rows = (area.height / 2).max(MULTILINE_POPUP_MIN_ROWS);
+        let rows = (lines.len() as u16)
+            .clamp(MULTILINE_POPUP_MIN_ROWS, max_rows)
+            .min(area.height.saturating_sub(2));
+        if rows == 0 {
+            return;
+        }
+        let scroll = (cursor_line as u16).saturating_sub(rows - 1);
+        let height = rows + 2;
+        let popup = Rect::new(area.x, area.y + area.height - height, area.width, height);
+
+        let editor = Paragraph::new(lines)
+            .style(self.theme.minibuffer_style())
+            .scroll((scroll, 0))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", prompt.trim_end().trim_end_matches(':')))
+                    .border_style(self.theme.border_focused_style()),
+            );
+        frame.render_widget(Clear, popup);
+        frame.render_widget(editor, popup);
+    }
+
     /// Check if minibuffer is in command prompt mode (M-x)
     pub fn is_command_prompt(&self) -> bool {
         match &self.content {
@@ -574,6 +845,14 @@ impl Minibuffer {
             MinibufferContent::Status(status) => status.clone(),
             MinibufferContent::CommandPrompt => "M-x ".to_string(),
             MinibufferContent::Hidden => String::new(),
+            MinibufferContent::Multiline { prompt, area } => {
+                let (line, column) = area.cursor_position();
+                format!(
+                    "{prompt}line {}, col {} · Enter new line · C-s save · C-g cancel",
+                    line + 1,
+                    column + 1
+                )
+            }
             MinibufferContent::Input { prompt, input } => {
                 format!("{prompt}{input}█")
             }
@@ -595,8 +874,7 @@ impl Minibuffer {
                 prompt,
                 input,
                 cursor_pos,
-                completions,
-                completion_index,
+                ..
             } => {
                 let mut text = format!("{prompt}{input}");
                 if self.focused && *cursor_pos <= input.len() {
@@ -606,18 +884,6 @@ impl Minibuffer {
                     }
                 }
 
-                // Add completion hint if available
-                if let Some(index) = completion_index {
-                    if let Some(completion) = completions.get(*index) {
-                        // Show the remaining part of the completion
-                        if completion.starts_with(input) {
-                            let suffix = &completion[input.len()..];
-                            text.push_str(&format!(" [{}]", suffix));
-                        } else {
-                            text.push_str(&format!(" [{}]", completion));
-                        }
-                    }
-                }
                 text
             }
             MinibufferContent::Command { input, cursor_pos } => {
@@ -645,6 +911,60 @@ impl Minibuffer {
     }
 }
 
+/// Match `input` against `candidate`, returning the byte range to highlight
+fn match_candidate(candidate: &str, input: &str) -> Option<Range<usize>> {
+    if input.is_empty() {
+        return Some(0..0);
+    }
+    if let Some(range) = find_ignore_case(candidate, input) {
+        return Some(range);
+    }
+    // Fall back to matching the last word, e.g. buffer names after a command
+    let (_, input_word) = input.rsplit_once(' ')?;
+    let (head, word) = candidate.rsplit_once(' ')?;
+    if input_word.is_empty() {
+        return None;
+    }
+    let range = find_ignore_case(word, input_word).filter(|r| r.start == 0)?;
+    let base = head.len() + 1;
+    Some(base + range.start..base + range.end)
+}
+
+/// Case-insensitive substring search returning a byte range into `haystack`
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<Range<usize>> {
+    haystack.char_indices().find_map(|(start, _)| {
+        let mut hay = haystack[start..].char_indices();
+        for n in needle.chars() {
+            let (_, h) = hay.next()?;
+            if !h.to_lowercase().eq(n.to_lowercase()) {
+                return None;
+            }
+        }
+        let end = hay.next().map_or(haystack.len(), |(i, _)| start + i);
+        Some(start..end)
+    })
+}
+
+/// Longest common prefix of all candidates that start with `input`
+fn common_prefix(matches: &[CompletionMatch], input: &str) -> String {
+    let mut prefixed = matches
+        .iter()
+        .filter(|m| m.matched.start == 0 && m.matched.len() == input.len())
+        .map(|m| m.candidate.as_str());
+    let Some(first) = prefixed.next() else {
+        return input.to_string();
+    };
+    let mut end = first.len();
+    for other in prefixed {
+        end = first
+            .char_indices()
+            .zip(other.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(end.min(other.len()), |((i, _), _)| i.min(end));
+    }
+    first[..end].to_string()
+}
+
 impl UIComponent for Minibuffer {
     fn handle_action(&mut self, action: UIAction) -> UIAction {
         if !self.is_input_mode() {
@@ -726,6 +1046,25 @@ mod tests {
         assert!(!minibuffer.has_focus());
     }
 
+    #[test]
+    fn test_multiline_prompt_submits_text_without_history() {
+        // Arrange
+        let mut minibuffer = Minibuffer::new();
+        minibuffer.show_multiline_prompt("Notes for episode: ".to_string(), "first");
+
+        // Act
+        minibuffer.edit_multiline(TextArea::insert_newline);
+        minibuffer.add_char('x');
+        let prompt = minibuffer.current_prompt();
+        let submitted = minibuffer.submit();
+
+        // Assert
+        assert_eq!(prompt.as_deref(), Some("Notes for episode: "));
+        assert_eq!(submitted.as_deref(), Some("first\nx"));
+        assert!(!minibuffer.is_visible());
+        assert!(minibuffer.history.is_empty());
+    }
+
     #[test]
     fn test_show_message() {
         let mut minibuffer = Minibuffer::new();
@@ -816,4 +1155,140 @@ mod tests {
         minibuffer.add_char('X');
         assert_eq!(minibuffer.current_input(), Some("AXBC".to_string()));
     }
+
+    fn completion_minibuffer(input: &str) -> Minibuffer {
+        let mut minibuffer = Minibuffer::new();
+        minibuffer.show_prompt_with_completion(
+            "M-x ".to_string(),
+            vec![
+                "refresh".to_string(),
+                "hard-refresh".to_string(),
+                "refresh-all".to_string(),
+                "buffer Podcasts".to_string(),
+                "quit".to_string(),
+            ],
+        );
+        for ch in input.chars() {
+            minibuffer.add_char(ch);
+        }
+        minibuffer
+    }
+
+    #[test]
+    fn test_completion_matches_rank_prefix_first_and_record_range() {
+        // Arrange
+        let minibuffer = completion_minibuffer("REFR");
+
+        // Act
+        let matches = minibuffer.completion_matches();
+
+        // Assert — substring matches follow prefix matches
+        let names: Vec<_> = matches.iter().map(|m| m.candidate.as_str()).collect();
+        assert_eq!(names, vec!["refresh", "refresh-all", "hard-refresh"]);
+        assert_eq!(matches[0].matched, 0..4);
+        assert_eq!(matches[2].matched, 5..9);
+    }
+
+    #[test]
+    fn test_completion_matches_last_word() {
+        let minibuffer = completion_minibuffer("b pod");
+        let matches = minibuffer.completion_matches();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].candidate, "buffer Podcasts");
+        assert_eq!(&matches[0].candidate[matches[0].matched.clone()], "Pod");
+    }
+
+    #[test]
+    fn test_completion_popup_hidden_for_empty_input_until_tab() {
+        // Arrange
+        let mut minibuffer = completion_minibuffer("");
+        assert!(!minibuffer.has_completion_popup());
+
+        // Act
+        minibuffer.tab_complete();
+
+        // Assert
+        assert!(minibuffer.has_completion_popup());
+        assert_eq!(
+            minibuffer.selected_completion(),
+            Some("refresh".to_string())
+        );
+    }
+
+    #[test]
+    fn test_completion_popup_waits_for_typing_to_pause() {
+        // Arrange
+        let mut minibuffer = completion_minibuffer("");
+        minibuffer.set_completion_debounce(Duration::from_secs(60));
+
+        // Act
+        minibuffer.add_char('r');
+
+        // Assert: held back while typing, but Tab opens it at once
+        assert!(!minibuffer.has_completion_popup());
+        minibuffer.tab_complete();
+        assert!(minibuffer.has_completion_popup());
+    }
+
+    #[test]
+    fn test_completion_selection_wraps_and_submit_uses_selection() {
+        // Arrange
+        let mut minibuffer = completion_minibuffer("refr");
+
+        // Act
+        minibuffer.select_prev_completion();
+
+        // Assert — wraps to the last match
+        assert_eq!(
+            minibuffer.selected_completion(),
+            Some("hard-refresh".to_string())
+        );
+        minibuffer.select_next_completion();
+        assert_eq!(
+            minibuffer.selected_completion(),
+            Some("refresh".to_string())
+        );
+        assert_eq!(minibuffer.submit(), Some("refresh".to_string()));
+    }
+
+    #[test]
+    fn test_tab_extends_common_prefix_then_accepts_selection() {
+        // Arrange
+        let mut minibuffer = completion_minibuffer("re");
+
+        // Act — only prefix matches contribute to the common prefix
+        minibuffer.tab_complete();
+
+        // Assert
+        assert_eq!(minibuffer.current_input(), Some("refresh".to_string()));
+
+        // A second Tab selects, a third accepts
+        minibuffer.tab_complete();
+        minibuffer.select_next_completion();
+        minibuffer.tab_complete();
+        assert_eq!(minibuffer.current_input(), Some("refresh-all".to_string()));
+    }
+
+    #[test]
+    fn test_page_completions_clamps_to_bounds() {
+        let mut minibuffer = completion_minibuffer("e");
+        let len = minibuffer.completion_matches().len();
+
+        minibuffer.page_completions_down();
+        minibuffer.page_completions_down();
+        let last = minibuffer.completion_matches()[len - 1].candidate.clone();
+        assert_eq!(minibuffer.selected_completion(), Some(last));
+
+        minibuffer.page_completions_up();
+        let first = minibuffer.completion_matches()[0].candidate.clone();
+        assert_eq!(minibuffer.selected_completion(), Some(first));
+    }
+
+    #[test]
+    fn test_typing_resets_completion_selection() {
+        let mut minibuffer = completion_minibuffer("re");
+        minibuffer.select_next_completion();
+        minibuffer.add_char('f');
+        assert_eq!(minibuffer.selected_completion(), None);
+    }
 }
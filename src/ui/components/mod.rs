@@ -4,7 +4,13 @@
 //! to build the application interface.
 
 pub mod minibuffer;
+pub mod qr_code;
+pub mod scroll_indicator;
 pub mod statusbar;
+pub mod text_area;
 
 pub use minibuffer::Minibuffer;
+pub use qr_code::QrPopup;
+pub use scroll_indicator::ScrollPosition;
 pub use statusbar::StatusBar;
+pub use text_area::TextArea;
@@ -0,0 +1,157 @@
+// QR code popup - scannable text drawn with half-block characters
+//
+// `:share-podcast` shows a feed URL (or an OPML snippet) as a QR code so a
+// phone can subscribe straight from the screen. Each text row carries two
+// module rows (▀ ▄ █), and the code is always drawn black on white with a
+// quiet zone, whatever the theme, since scanners need that contrast.
+
+use qrcode::{Color as Module, EcLevel, QrCode, QrResult};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::ui::themes::Theme;
+
+/// Light modules around the code; scanners need a margin to find it
+const QUIET_ZONE: usize = 2;
+
+/// A QR code in a centred popup, dismissed by any key
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrPopup {
+    title: String,
+    /// Shown under the code, e.g. the shared URL
+    caption: String,
+    lines: Vec<String>,
+}
+
+impl QrPopup {
+    pub fn new(title: String, caption: String, data: &str) -> QrResult<Self> {
+        Ok(Self {
+            title,
+            caption,
+            lines: qr_lines(data)?,
+        })
+    }
+
+    /// Columns of the code itself
+    pub fn code_width(&self) -> u16 {
+        self.lines
+            .first()
+            .map_or(0, |line| line.chars().count() as u16)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = (self.code_width() + 2).max(30);
+        let caption_rows = (self.caption.chars().count() as u16).div_ceil(width - 2);
+        let height = self.lines.len() as u16 + 3 + caption_rows; // borders + blank line
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title))
+            .title_style(theme.title_style())
+            .border_style(theme.border_focused_style());
+
+        if width > area.width || height > area.height {
+            let popup = centered(area, 40.min(area.width), 5.min(area.height));
+            let message = Paragraph::new(format!(
+                "Enlarge the terminal to at least {}x{} to show the QR code",
+                width, height
+            ))
+            .block(block)
+            .style(theme.default_style())
+            .wrap(Wrap { trim: true });
+            frame.render_widget(Clear, popup);
+            frame.render_widget(message, popup);
+            return;
+        }
+
+        let popup = centered(area, width, height);
+        let code_style = Style::default().fg(Color::Black).bg(Color::White);
+        let mut lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| Line::styled(line.clone(), code_style))
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::styled(self.caption.clone(), theme.muted_style()));
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .style(theme.default_style())
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    )
+}
+
+/// `data` as a QR code, one string per text row, quiet zone included.
+/// Low error correction keeps codes small enough for a terminal.
+pub fn qr_lines(data: &str) -> QrResult<Vec<String>> {
+    let code = QrCode::with_error_correction_level(data.as_bytes(), EcLevel::L)?;
+    let width = code.width();
+    let colors = code.to_colors();
+    let size = width + 2 * QUIET_ZONE;
+    let dark = |x: usize, y: usize| {
+        let inside = QUIET_ZONE..QUIET_ZONE + width;
+        inside.contains(&x)
+            && inside.contains(&y)
+            && colors[(y - QUIET_ZONE) * width + (x - QUIET_ZONE)] == Module::Dark
+    };
+
+    Ok((0..size)
+        .step_by(2)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (dark(x, y), dark(x, y + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_lines_pack_two_module_rows_per_line_with_quiet_zone() {
+        // Arrange — a version 2 code is 25 modules wide
+        let url = "https://example.com/feed.xml";
+
+        // Act
+        let lines = qr_lines(url).unwrap();
+
+        // Assert
+        let size = 25 + 2 * QUIET_ZONE;
+        assert_eq!(lines.len(), size.div_ceil(2));
+        assert!(lines.iter().all(|line| line.chars().count() == size));
+        assert!(lines[0].trim().is_empty(), "quiet zone on top");
+        // Top edge of the top-left finder pattern
+        let finder: String = lines[1].chars().skip(QUIET_ZONE).take(7).collect();
+        assert_eq!(finder, "█▀▀▀▀▀█");
+    }
+
+    #[test]
+    fn test_qr_popup_rejects_data_too_long_for_a_code() {
+        let data = "x".repeat(5000);
+
+        assert!(QrPopup::new("Share".to_string(), String::new(), &data).is_err());
+    }
+}
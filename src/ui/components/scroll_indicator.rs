@@ -0,0 +1,207 @@
+// Scroll position indicator shared by list and text buffers
+//
+// Buffers report where they are scrolled to through `Buffer::scroll_position`,
+// together with the bordered area they scroll in; the app then draws a
+// scrollbar on that area's right border and a "134/982 · 14%" label on its
+// bottom border in the theme's colours. `ui.scroll_indicators = false` turns
+// the indicator off.
+
+use ratatui::{
+    layout::{Margin, Rect},
+    symbols::scrollbar,
+    widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+use crate::ui::{glyphs::GlyphSet, text_layout, themes::Theme};
+
+/// Scrollbar drawn with plain ASCII (`ui.ascii_glyphs`)
+const ASCII_SCROLLBAR: scrollbar::Set = scrollbar::Set {
+    track: "|",
+    thumb: "#",
+    begin: "^",
+    end: "v",
+};
+
+/// Where a buffer is scrolled to, and the bordered area it scrolls in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollPosition {
+    /// Bordered block the list or text is drawn in
+    pub area: Rect,
+    /// 1-based selected item or top line
+    pub current: usize,
+    /// Items or lines in total
+    pub total: usize,
+    pub percent: u8,
+    /// Scrollbar range and thumb position
+    scroll_range: usize,
+    scroll_offset: usize,
+    /// Appended to the label, e.g. "120 total" for a filtered list
+    note: Option<String>,
+}
+
+impl ScrollPosition {
+    /// Selected item (0-based) of a list of `total` items; `None` for an
+    /// empty list
+    pub fn items(area: Rect, selected: usize, total: usize) -> Option<Self> {
+        if total == 0 {
+            return None;
+        }
+        let current = selected.min(total - 1) + 1;
+        Some(Self {
+            area,
+            current,
+            total,
+            percent: Self::percent(current, total),
+            scroll_range: total,
+            scroll_offset: current - 1,
+            note: None,
+        })
+    }
+
+    /// Furthest top line (0-based) for `total` lines of text shown in the
+    /// bordered `area`: the one that puts the last line at the bottom
+    pub fn max_top(area: Rect, total: usize) -> usize {
+        total.saturating_sub(area.height.saturating_sub(2) as usize)
+    }
+
+    /// Top line (0-based) of `total` lines of text shown in `area`; `None`
+    /// when everything fits and there is nothing to scroll
+    pub fn lines(area: Rect, top: usize, total: usize) -> Option<Self> {
+        let max_top = Self::max_top(area, total);
+        if max_top == 0 {
+            return None;
+        }
+        let top = top.min(max_top);
+        Some(Self {
+            area,
+            current: top + 1,
+            total,
+            percent: Self::percent(top, max_top),
+            scroll_range: max_top + 1,
+            scroll_offset: top,
+            note: None,
+        })
+    }
+
+    /// Add a note after the position, e.g. "120 total"
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    fn percent(part: usize, whole: usize) -> u8 {
+        if whole == 0 {
+            return 100;
+        }
+        ((part as f64 / whole as f64) * 100.0).round().min(100.0) as u8
+    }
+
+    /// e.g. "134/982 · 14%"
+    pub fn label(&self, glyphs: GlyphSet) -> String {
+        let separator = match glyphs {
+            GlyphSet::Unicode => "·",
+            GlyphSet::Ascii => "-",
+        };
+        let mut label = format!(
+            "{}/{} {} {}%",
+            self.current, self.total, separator, self.percent
+        );
+        if let Some(ref note) = self.note {
+            label.push_str(&format!(" {} {}", separator, note));
+        }
+        label
+    }
+}
+
+/// Draw the indicator over the border of `position.area`
+pub fn render(frame: &mut Frame, position: &ScrollPosition, theme: &Theme) {
+    let area = position.area.intersection(frame.area());
+    if area.height < 3 || area.width < 4 {
+        return;
+    }
+
+    // Scrollbar only once there is more than fits
+    let visible = area.height.saturating_sub(2) as usize;
+    if position.scroll_range > 1 && position.total > visible {
+        let symbols = match theme.glyphs {
+            GlyphSet::Unicode => scrollbar::VERTICAL,
+            GlyphSet::Ascii => ASCII_SCROLLBAR,
+        };
+        let bar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .symbols(symbols)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .thumb_style(theme.scrollbar_thumb_style())
+            .track_style(theme.scrollbar_track_style());
+        let mut state = ScrollbarState::new(position.scroll_range).position(position.scroll_offset);
+        frame.render_stateful_widget(
+            bar,
+            area.inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut state,
+        );
+    }
+
+    let label = format!(" {} ", position.label(theme.glyphs));
+    let width = text_layout::display_width(&label) as u16;
+    if width + 3 > area.width {
+        return;
+    }
+    let label_area = Rect {
+        x: area.right() - width - 2,
+        y: area.bottom() - 1,
+        width,
+        height: 1,
+    };
+    frame.render_widget(
+        Paragraph::new(label).style(theme.scrollbar_label_style()),
+        label_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(height: u16) -> Rect {
+        Rect::new(0, 0, 60, height)
+    }
+
+    #[test]
+    fn test_item_label_shows_position_total_and_percent() {
+        let position = ScrollPosition::items(area(20), 133, 982).unwrap();
+        assert_eq!(position.label(GlyphSet::Unicode), "134/982 · 14%");
+        assert_eq!(position.label(GlyphSet::Ascii), "134/982 - 14%");
+        assert!(ScrollPosition::items(area(20), 0, 0).is_none());
+    }
+
+    #[test]
+    fn test_line_percent_reaches_100_at_the_bottom() {
+        // 18 visible lines of 100
+        let top = ScrollPosition::lines(area(20), 0, 100).unwrap();
+        let bottom = ScrollPosition::lines(area(20), 500, 100).unwrap();
+
+        assert_eq!(top.label(GlyphSet::Unicode), "1/100 · 0%");
+        assert_eq!(bottom.current, 83);
+        assert_eq!(bottom.percent, 100);
+        assert!(ScrollPosition::lines(area(20), 0, 18).is_none());
+    }
+
+    #[test]
+    fn test_max_top_follows_the_area_height() {
+        assert_eq!(ScrollPosition::max_top(area(20), 100), 82);
+        assert_eq!(ScrollPosition::max_top(area(50), 100), 52);
+        assert_eq!(ScrollPosition::max_top(area(20), 10), 0);
+    }
+
+    #[test]
+    fn test_note_follows_the_position() {
+        let position = ScrollPosition::items(area(10), 2, 20)
+            .unwrap()
+            .with_note("120 total");
+        assert_eq!(position.label(GlyphSet::Unicode), "3/20 · 15% · 120 total");
+    }
+}
@@ -1,35 +1,206 @@
 // Status bar component - displays application status information
 //
-// The status bar shows current buffer information, key hints,
-// and application status at the bottom of the screen.
+// The status bar is built from the segments listed in `ui.statusbar`
+// (buffer name, key sequence, playback, download activity, clock, battery,
+// network state, ...). Segments before `fill` are left-aligned, the rest are
+// right-aligned; each can override its style with a theme role or a color.
 
+use chrono::format::{Item, StrftimeItems};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
     widgets::{Block, Paragraph},
     Frame,
 };
+use tokio::sync::watch;
+
+use crate::{
+    audio::{PlaybackState, PlaybackStatus},
+    config::StatusBarConfig,
+    constants::ui::DEFAULT_STATUSBAR_CLOCK_FORMAT,
+    ui::{text_layout, theme_loader::parse_color, themes::Theme, UIAction, UIComponent},
+    utils::{power::PowerSource, time::format_duration},
+};
+
+/// Kinds of status bar segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKind {
+    Buffer,
+    Keys,
+    Playback,
+    Downloads,
+    Clock,
+    Battery,
+    Network,
+    Indicator,
+    Hint,
+    Help,
+    Fill,
+}
+
+impl SegmentKind {
+    pub const NAMES: &'static str =
+        "buffer, keys, playback, downloads, clock, battery, network, indicator, hint, help, fill";
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "buffer" => Some(Self::Buffer),
+            "keys" => Some(Self::Keys),
+            "playback" => Some(Self::Playback),
+            "downloads" => Some(Self::Downloads),
+            "clock" => Some(Self::Clock),
+            "battery" => Some(Self::Battery),
+            "network" => Some(Self::Network),
+            "indicator" => Some(Self::Indicator),
+            "hint" => Some(Self::Hint),
+            "help" => Some(Self::Help),
+            "fill" => Some(Self::Fill),
+            _ => None,
+        }
+    }
+}
 
-use crate::ui::{themes::Theme, UIAction, UIComponent};
+/// Style override for a segment
+#[derive(Debug, Clone, PartialEq)]
+enum SegmentStyle {
+    /// Theme role, resolved at render time so theme changes apply
+    Role(String),
+    /// Fixed foreground color
+    Color(ratatui::style::Color),
+}
+
+/// A configured segment
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    kind: SegmentKind,
+    style: Option<SegmentStyle>,
+    format: Option<String>,
+}
+
+/// Theme style for a role name used in `ui.statusbar`
+fn role_style(theme: &Theme, role: &str) -> Option<Style> {
+    match role {
+        "statusbar" => Some(theme.statusbar_style()),
+        "text" => Some(theme.text_style()),
+        "muted" => Some(theme.muted_style()),
+        "primary" => Some(theme.primary_style()),
+        "success" => Some(theme.success_style()),
+        "warning" => Some(theme.warning_style()),
+        "error" => Some(theme.error_style()),
+        "title" => Some(theme.title_style()),
+        "selected" => Some(theme.selected_style()),
+        _ => None,
+    }
+}
+
+/// Rendered segment text with its style
+type Piece = (String, Style);
 
 /// Status bar component
 pub struct StatusBar {
     theme: Theme,
+    segments: Vec<Segment>,
     buffer_name: String,
     key_sequence: String,
     status_message: String,
+    /// Persistent state shown ahead of the help hint (e.g. paused downloads)
+    indicator: String,
+    /// Low-key notice shown after the indicator (e.g. an available update)
+    hint: String,
+    playback_status_rx: Option<watch::Receiver<PlaybackStatus>>,
+    now_playing: Option<String>,
+    /// Shuffle / repeat marker of the playlist being played through
+    queue_modes: String,
+    active_downloads: usize,
+    power_source: PowerSource,
+    /// Whether the last feed request reached the network; `None` until one has run
+    network_online: Option<bool>,
     focused: bool,
 }
 
 impl StatusBar {
-    /// Create a new status bar
+    /// Create a new status bar with the default layout
     pub fn new() -> Self {
-        Self {
+        let mut status_bar = Self {
             theme: Theme::default(),
+            segments: Vec::new(),
             buffer_name: String::new(),
             key_sequence: String::new(),
             status_message: String::new(),
+            indicator: String::new(),
+            hint: String::new(),
+            playback_status_rx: None,
+            now_playing: None,
+            queue_modes: String::new(),
+            active_downloads: 0,
+            power_source: PowerSource::Unknown,
+            network_online: None,
             focused: false,
+        };
+        status_bar.configure(&StatusBarConfig::default());
+        status_bar
+    }
+
+    /// Apply a segment layout. Invalid segments are skipped and described in
+    /// the returned errors.
+    pub fn configure(&mut self, config: &StatusBarConfig) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut segments = Vec::new();
+        for (index, entry) in config.segments.iter().enumerate() {
+            let Some(kind) = SegmentKind::parse(&entry.segment) else {
+                errors.push(format!(
+                    "Segment {}: unknown segment '{}' (expected one of {})",
+                    index + 1,
+                    entry.segment,
+                    SegmentKind::NAMES
+                ));
+                continue;
+            };
+            if kind == SegmentKind::Fill && segments.iter().any(|s: &Segment| s.kind == kind) {
+                errors.push(format!("Segment {}: only one 'fill' is allowed", index + 1));
+                continue;
+            }
+            let style = match entry.style.as_deref().map(str::trim) {
+                None => None,
+                Some(role) if role_style(&self.theme, role).is_some() => {
+                    Some(SegmentStyle::Role(role.to_string()))
+                }
+                Some(color) => match parse_color(color) {
+                    Ok(color) => Some(SegmentStyle::Color(color)),
+                    Err(e) => {
+                        errors.push(format!("Segment {} ({}): {}", index + 1, entry.segment, e));
+                        None
+                    }
+                },
+            };
+            let format = match entry.format.as_deref() {
+                Some(format)
+                    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) =>
+                {
+                    errors.push(format!(
+                        "Segment {} ({}): invalid format '{}'",
+                        index + 1,
+                        entry.segment,
+                        format
+                    ));
+                    None
+                }
+                format => format.map(str::to_string),
+            };
+            segments.push(Segment {
+                kind,
+                style,
+                format,
+            });
         }
+        self.segments = segments;
+        errors
+    }
+
+    /// Whether the current layout includes a segment of this kind
+    pub fn shows(&self, kind: SegmentKind) -> bool {
+        self.segments.iter().any(|segment| segment.kind == kind)
     }
 
     /// Set the current buffer name
@@ -37,6 +208,11 @@ impl StatusBar {
         self.buffer_name = name;
     }
 
+    /// Get the current buffer name
+    pub fn buffer_name(&self) -> &str {
+        &self.buffer_name
+    }
+
     /// Set the current key sequence being typed
     pub fn set_key_sequence(&mut self, sequence: String) {
         self.key_sequence = sequence;
@@ -52,6 +228,61 @@ impl StatusBar {
         self.status_message.clear();
     }
 
+    /// Get the status message
+    pub fn status_message(&self) -> &str {
+        &self.status_message
+    }
+
+    /// Set the persistent indicator; an empty string hides it
+    pub fn set_indicator(&mut self, indicator: String) {
+        self.indicator = indicator;
+    }
+
+    /// Current persistent indicator (empty when hidden)
+    pub fn indicator(&self) -> &str {
+        &self.indicator
+    }
+
+    /// Set the subtle hint; an empty string hides it
+    pub fn set_hint(&mut self, hint: String) {
+        self.hint = hint;
+    }
+
+    /// Current hint (empty when hidden)
+    pub fn hint(&self) -> &str {
+        &self.hint
+    }
+
+    /// Live playback state for the `playback` segment
+    pub fn set_playback_status_rx(&mut self, rx: watch::Receiver<PlaybackStatus>) {
+        self.playback_status_rx = Some(rx);
+    }
+
+    /// Title of the episode being played
+    pub fn set_now_playing(&mut self, title: Option<String>) {
+        self.now_playing = title;
+    }
+
+    /// Shuffle / repeat marker shown after the playback position (empty for none)
+    pub fn set_queue_modes(&mut self, indicator: String) {
+        self.queue_modes = indicator;
+    }
+
+    /// Number of downloads in progress; zero hides the `downloads` segment
+    pub fn set_active_downloads(&mut self, count: usize) {
+        self.active_downloads = count;
+    }
+
+    /// Power source for the `battery` segment
+    pub fn set_power_source(&mut self, source: PowerSource) {
+        self.power_source = source;
+    }
+
+    /// Network state for the `network` segment
+    pub fn set_network_online(&mut self, online: Option<bool>) {
+        self.network_online = online;
+    }
+
     /// Set the theme
     pub fn set_theme(&mut self, theme: Theme) {
         self.theme = theme;
@@ -77,9 +308,143 @@ impl StatusBar {
         }
     }
 
-    /// Get the right section content (help hint)
+    /// "▶ Title 12:34/58:00", or empty when nothing is playing
+    fn playback_text(&self) -> String {
+        let Some(rx) = self.playback_status_rx.as_ref() else {
+            return String::new();
+        };
+        let status = rx.borrow();
+        let icon = match status.state {
+            PlaybackState::Playing => "▶",
+            PlaybackState::Paused => "⏸",
+            PlaybackState::Stopped => return String::new(),
+        };
+        let mut text = icon.to_string();
+        if let Some(ref title) = self.now_playing {
+            text.push(' ');
+            text.push_str(title);
+        }
+        if let Some(position) = status.position {
+            text.push(' ');
+            text.push_str(&format_duration(position.as_secs() as u32));
+            if let Some(duration) = status.duration {
+                text.push('/');
+                text.push_str(&format_duration(duration.as_secs() as u32));
+            }
+        }
+        if !self.queue_modes.is_empty() {
+            text.push(' ');
+            text.push_str(&self.queue_modes);
+        }
+        if status.muted {
+            text.push_str(" 🔇");
+        } else if status.ducked {
+            text.push_str(" (ducked)");
+        }
+        text
+    }
+
+    /// Text of one segment; empty hides it
+    fn segment_text(&self, segment: &Segment) -> String {
+        let padded = |text: String| {
+            if text.is_empty() {
+                text
+            } else {
+                format!(" {} ", text)
+            }
+        };
+        match segment.kind {
+            SegmentKind::Buffer => self.left_content(),
+            SegmentKind::Keys => self.center_content(),
+            SegmentKind::Playback => padded(self.playback_text()),
+            SegmentKind::Downloads if self.active_downloads > 0 => {
+                padded(format!("⬇ {}", self.active_downloads))
+            }
+            SegmentKind::Downloads => String::new(),
+            SegmentKind::Clock => padded(
+                chrono::Local::now()
+                    .format(
+                        segment
+                            .format
+                            .as_deref()
+                            .unwrap_or(DEFAULT_STATUSBAR_CLOCK_FORMAT),
+                    )
+                    .to_string(),
+            ),
+            SegmentKind::Battery => match self.power_source {
+                PowerSource::Unknown => String::new(),
+                source => padded(source.label()),
+            },
+            SegmentKind::Network => match self.network_online {
+                Some(true) => padded("online".to_string()),
+                Some(false) => padded("offline".to_string()),
+                None => String::new(),
+            },
+            SegmentKind::Indicator => padded(self.indicator.clone()),
+            SegmentKind::Hint => padded(self.hint.clone()),
+            SegmentKind::Help => padded("C-h for help, C-x C-c to quit".to_string()),
+            SegmentKind::Fill => String::new(),
+        }
+    }
+
+    /// Style of one segment: its override, else the segment's default
+    fn segment_style(&self, segment: &Segment) -> Style {
+        match &segment.style {
+            Some(SegmentStyle::Role(role)) => {
+                role_style(&self.theme, role).unwrap_or_else(|| self.theme.statusbar_style())
+            }
+            Some(SegmentStyle::Color(color)) => Style::default().fg(*color),
+            None => match segment.kind {
+                SegmentKind::Buffer | SegmentKind::Fill => self.theme.statusbar_style(),
+                SegmentKind::Keys if !self.status_message.is_empty() => self.theme.success_style(),
+                SegmentKind::Keys | SegmentKind::Indicator => self.theme.warning_style(),
+                SegmentKind::Playback | SegmentKind::Downloads => self.theme.primary_style(),
+                SegmentKind::Network if self.network_online == Some(false) => {
+                    self.theme.error_style()
+                }
+                SegmentKind::Clock
+                | SegmentKind::Battery
+                | SegmentKind::Network
+                | SegmentKind::Hint
+                | SegmentKind::Help => self.theme.muted_style(),
+            },
+        }
+    }
+
+    /// Visible left-aligned and right-aligned pieces with their styles.
+    /// Right-aligned pieces are separated by `|`.
+    fn sections(&self) -> (Vec<Piece>, Vec<Piece>) {
+        let fill = self
+            .segments
+            .iter()
+            .position(|segment| segment.kind == SegmentKind::Fill)
+            .unwrap_or(self.segments.len());
+        let visible = |segments: &[Segment]| -> Vec<Piece> {
+            segments
+                .iter()
+                .map(|segment| (self.segment_text(segment), self.segment_style(segment)))
+                .filter(|(text, _)| !text.is_empty())
+                .collect()
+        };
+
+        let left = visible(&self.segments[..fill]);
+        let mut right = visible(self.segments.get(fill + 1..).unwrap_or_default());
+        let last = right.len().saturating_sub(1);
+        for (text, _) in right.iter_mut().take(last) {
+            text.truncate(text.trim_end().len());
+            text.push_str(" |");
+        }
+        (left, right)
+    }
+
+    /// Get the right section content (segments after `fill`)
+    #[cfg(test)]
     fn right_content(&self) -> String {
-        " C-h for help, C-x C-c to quit ".to_string()
+        self.sections()
+            .1
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect()
     }
 }
 
@@ -90,59 +455,47 @@ impl UIComponent for StatusBar {
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect) {
-        // Split the status bar into three sections
+        let (left, right) = self.sections();
+        let width = |pieces: &[Piece]| {
+            pieces
+                .iter()
+                .map(|(text, _)| text_layout::display_width(text) as u16)
+                .sum::<u16>()
+        };
+        let spans = |pieces: Vec<Piece>| {
+            Line::from(
+                pieces
+                    .into_iter()
+                    .map(|(text, style)| Span::styled(text, style))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        // Left segments, fill, right segments
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Length(self.left_content().len() as u16),
+                Constraint::Length(width(&left)),
                 Constraint::Min(0),
-                Constraint::Length(self.right_content().len() as u16),
+                Constraint::Length(width(&right)),
             ])
             .split(area);
 
-        // Left section - buffer name
-        let left_text = self.left_content();
-        let left_paragraph = Paragraph::new(left_text)
-            .style(self.theme.statusbar_style())
-            .block(Block::default());
-        frame.render_widget(left_paragraph, chunks[0]);
-
-        // Center section - status message or key sequence
-        let center_text = self.center_content();
-        if !center_text.is_empty() {
-            let center_style = if !self.status_message.is_empty() {
-                self.theme.success_style()
-            } else {
-                self.theme.warning_style()
-            };
-
-            let center_paragraph = Paragraph::new(center_text.clone())
-                .style(center_style)
-                .block(Block::default());
-            frame.render_widget(center_paragraph, chunks[1]);
-        }
+        frame.render_widget(
+            Paragraph::new(spans(left)).block(Block::default()),
+            chunks[0],
+        );
 
-        // Right section - help hint
-        let right_text = self.right_content();
-        let right_paragraph = Paragraph::new(right_text)
-            .style(self.theme.muted_style())
+        // Fill the gap with the status bar background
+        let fill_paragraph = Paragraph::new("")
+            .style(self.theme.statusbar_style())
             .block(Block::default());
-        frame.render_widget(right_paragraph, chunks[2]);
-
-        // If there's extra space in the middle, fill it with the status bar background
-        if chunks[1].width > center_text.len() as u16 {
-            let fill_area = Rect {
-                x: chunks[1].x + center_text.len() as u16,
-                y: chunks[1].y,
-                width: chunks[1].width - center_text.len() as u16,
-                height: chunks[1].height,
-            };
+        frame.render_widget(fill_paragraph, chunks[1]);
 
-            let fill_paragraph = Paragraph::new("")
-                .style(self.theme.statusbar_style())
-                .block(Block::default());
-            frame.render_widget(fill_paragraph, fill_area);
-        }
+        frame.render_widget(
+            Paragraph::new(spans(right)).block(Block::default()),
+            chunks[2],
+        );
     }
 
     fn title(&self) -> String {
@@ -227,4 +580,140 @@ mod tests {
         status_bar.clear_status_message();
         assert_eq!(status_bar.center_content(), " C-x  ");
     }
+
+    #[test]
+    fn test_indicator_precedes_help_hint() {
+        let mut status_bar = StatusBar::new();
+        status_bar.set_indicator("⏸ I/O paused (battery 20%)".to_string());
+        assert_eq!(
+            status_bar.right_content(),
+            " ⏸ I/O paused (battery 20%) | C-h for help, C-x C-c to quit "
+        );
+
+        status_bar.set_indicator(String::new());
+        assert!(status_bar.right_content().starts_with(" C-h for help"));
+    }
+
+    #[test]
+    fn test_hint_follows_indicator() {
+        let mut status_bar = StatusBar::new();
+        status_bar.set_hint("⬆ v1.12.0 available".to_string());
+        assert_eq!(
+            status_bar.right_content(),
+            " ⬆ v1.12.0 available | C-h for help, C-x C-c to quit "
+        );
+
+        status_bar.set_indicator("⏸ I/O paused".to_string());
+        assert!(status_bar
+            .right_content()
+            .starts_with(" ⏸ I/O paused | ⬆ v1.12.0 available |"));
+    }
+
+    fn layout(segments: &[(&str, Option<&str>)]) -> StatusBarConfig {
+        StatusBarConfig {
+            segments: segments
+                .iter()
+                .map(|(segment, style)| crate::config::StatusBarSegment {
+                    segment: segment.to_string(),
+                    style: style.map(str::to_string),
+                    format: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_custom_layout_orders_segments() {
+        // Arrange
+        let mut status_bar = StatusBar::new();
+        let errors = status_bar.configure(&layout(&[
+            ("downloads", None),
+            ("buffer", None),
+            ("fill", None),
+            ("battery", None),
+            ("network", None),
+            ("help", None),
+        ]));
+        status_bar.set_buffer_name("Podcasts".to_string());
+        status_bar.set_active_downloads(2);
+        status_bar.set_power_source(PowerSource::Battery { percent: Some(40) });
+        status_bar.set_network_online(Some(false));
+
+        // Act
+        let (left, _) = status_bar.sections();
+
+        // Assert
+        assert!(errors.is_empty());
+        let left: String = left.into_iter().map(|(text, _)| text).collect();
+        assert_eq!(left, " ⬇ 2  Podcasts ");
+        assert_eq!(
+            status_bar.right_content(),
+            " battery 40% | offline | C-h for help, C-x C-c to quit "
+        );
+        assert!(!status_bar.shows(SegmentKind::Clock));
+    }
+
+    #[test]
+    fn test_empty_segments_are_hidden() {
+        let mut status_bar = StatusBar::new();
+        status_bar.configure(&layout(&[
+            ("fill", None),
+            ("playback", None),
+            ("downloads", None),
+            ("battery", None),
+            ("help", None),
+        ]));
+        assert_eq!(
+            status_bar.right_content(),
+            " C-h for help, C-x C-c to quit "
+        );
+    }
+
+    #[test]
+    fn test_segment_styles() {
+        // Arrange
+        let mut status_bar = StatusBar::new();
+        let theme = Theme::default();
+
+        // Act
+        let errors = status_bar.configure(&layout(&[
+            ("buffer", Some("error")),
+            ("help", Some("#ff0000")),
+            ("clock", Some("not-a-color")),
+            ("weather", None),
+            ("fill", None),
+            ("fill", None),
+        ]));
+
+        // Assert
+        assert_eq!(errors.len(), 3);
+        assert!(errors[1].contains("unknown segment 'weather'"));
+        assert_eq!(status_bar.segments.len(), 4);
+        assert_eq!(
+            status_bar.segment_style(&status_bar.segments[0]),
+            theme.error_style()
+        );
+        assert_eq!(
+            status_bar.segment_style(&status_bar.segments[1]),
+            Style::default().fg(ratatui::style::Color::Rgb(255, 0, 0))
+        );
+        assert_eq!(
+            status_bar.segment_style(&status_bar.segments[2]),
+            theme.muted_style()
+        );
+    }
+
+    #[test]
+    fn test_invalid_clock_format_falls_back() {
+        let mut status_bar = StatusBar::new();
+        let errors = status_bar.configure(&StatusBarConfig {
+            segments: vec![crate::config::StatusBarSegment {
+                segment: "clock".to_string(),
+                style: None,
+                format: Some("%Q".to_string()),
+            }],
+        });
+        assert_eq!(errors.len(), 1);
+        assert_eq!(status_bar.segments[0].format, None);
+    }
 }
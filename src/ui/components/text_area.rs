@@ -0,0 +1,165 @@
+// Text area - multi-line text with a cursor
+//
+// Backs the minibuffer's multi-line input mode (episode and podcast notes,
+// long descriptions). Only the editing state lives here; the minibuffer
+// draws it in a popup above itself.
+
+/// Multi-line text being edited; the cursor is a byte offset on a char boundary
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextArea {
+    text: String,
+    cursor: usize,
+}
+
+impl TextArea {
+    /// Start editing `text` with the cursor at its end
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = text.len();
+        Self { text, cursor }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.text.insert(self.cursor, ch);
+        self.cursor += ch.len_utf8();
+    }
+
+    pub fn insert_newline(&mut self) {
+        self.insert_char('\n');
+    }
+
+    /// Remove the character before the cursor, joining lines at a line start
+    pub fn backspace(&mut self) {
+        if let Some(ch) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= ch.len_utf8();
+            self.text.remove(self.cursor);
+        }
+    }
+
+    /// Remove the character under the cursor
+    pub fn delete(&mut self) {
+        if self.cursor < self.text.len() {
+            self.text.remove(self.cursor);
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        if let Some(ch) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= ch.len_utf8();
+        }
+    }
+
+    pub fn cursor_right(&mut self) {
+        if let Some(ch) = self.text[self.cursor..].chars().next() {
+            self.cursor += ch.len_utf8();
+        }
+    }
+
+    pub fn cursor_line_start(&mut self) {
+        self.cursor = self.line_start(self.cursor);
+    }
+
+    pub fn cursor_line_end(&mut self) {
+        self.cursor = self.line_end(self.cursor);
+    }
+
+    /// Move to the previous line, keeping the column where it fits
+    pub fn cursor_up(&mut self) {
+        let start = self.line_start(self.cursor);
+        if start == 0 {
+            self.cursor = 0;
+            return;
+        }
+        let column = self.text[start..self.cursor].chars().count();
+        let previous = self.line_start(start - 1);
+        self.cursor = self.offset_in_line(previous, column);
+    }
+
+    /// Move to the next line, keeping the column where it fits
+    pub fn cursor_down(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end == self.text.len() {
+            self.cursor = end;
+            return;
+        }
+        let column = self.text[self.line_start(self.cursor)..self.cursor]
+            .chars()
+            .count();
+        self.cursor = self.offset_in_line(end + 1, column);
+    }
+
+    /// Cursor position as (line, column), both in characters from zero
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor];
+        let line = before.matches('\n').count();
+        let column = before[self.line_start(self.cursor)..].chars().count();
+        (line, column)
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.text[..offset].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |i| offset + i)
+    }
+
+    /// Byte offset of `column` chars into the line starting at `start`,
+    /// clamped to the line's end
+    fn offset_in_line(&self, start: usize, column: usize) -> usize {
+        let end = self.line_end(start);
+        self.text[start..end]
+            .char_indices()
+            .nth(column)
+            .map_or(end, |(i, _)| start + i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typing_newlines_and_backspace_join_lines() {
+        // Arrange
+        let mut area = TextArea::new("");
+
+        // Act
+        for ch in "ab".chars() {
+            area.insert_char(ch);
+        }
+        area.insert_newline();
+        area.insert_char('ü');
+
+        // Assert
+        assert_eq!(area.text(), "ab\nü");
+        assert_eq!(area.cursor_position(), (1, 1));
+
+        area.cursor_line_start();
+        area.backspace();
+        assert_eq!(area.text(), "abü");
+        assert_eq!(area.cursor_position(), (0, 2));
+    }
+
+    #[test]
+    fn test_vertical_movement_keeps_column_where_it_fits() {
+        let mut area = TextArea::new("first line\nab\nthird line");
+
+        area.cursor_up();
+        assert_eq!(area.cursor_position(), (1, 2));
+        area.cursor_up();
+        assert_eq!(area.cursor_position(), (0, 2));
+        area.cursor_line_end();
+        area.cursor_down();
+        assert_eq!(area.cursor_position(), (1, 2));
+        area.cursor_down();
+        area.cursor_down();
+        assert_eq!(area.cursor_position(), (2, 10));
+    }
+}
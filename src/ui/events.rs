@@ -3,25 +3,164 @@
 // This module provides the core event system for handling keyboard input,
 // converting them to UI actions, and managing the event loop.
 
-use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use crate::config::PerformanceConfig;
+use crate::constants::ui as ui_constants;
+
+/// UI timing resolved from `ui.performance`: the preset's values with any
+/// explicitly configured ones on top
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerformanceSettings {
+    /// Interval between `UIEvent::Tick`s
+    pub tick_rate: Duration,
+    /// Longest the render loop waits before redrawing
+    pub frame_interval: Duration,
+    /// Minimum gap between repeats of a held navigation key
+    pub key_repeat: Duration,
+    /// Typing pause before the completion popup updates
+    pub completion_debounce: Duration,
+}
+
+impl PerformanceSettings {
+    /// Current defaults: 250 ms ticks, ~60 FPS, no throttling
+    pub fn balanced() -> Self {
+        Self {
+            tick_rate: Duration::from_millis(ui_constants::UI_TICK_RATE_MS),
+            frame_interval: Duration::from_millis(ui_constants::MIN_FRAME_INTERVAL_MS),
+            key_repeat: Duration::ZERO,
+            completion_debounce: Duration::ZERO,
+        }
+    }
+
+    /// Look up a preset by name; `None` for an unknown one
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "balanced" => Some(Self::balanced()),
+            "responsive" => Some(Self {
+                tick_rate: Duration::from_millis(ui_constants::RESPONSIVE_TICK_RATE_MS),
+                ..Self::balanced()
+            }),
+            "battery-saver" => Some(Self {
+                tick_rate: Duration::from_millis(ui_constants::BATTERY_SAVER_TICK_RATE_MS),
+                frame_interval: Duration::from_millis(
+                    ui_constants::BATTERY_SAVER_FRAME_INTERVAL_MS,
+                ),
+                key_repeat: Duration::from_millis(ui_constants::BATTERY_SAVER_KEY_REPEAT_MS),
+                completion_debounce: Duration::from_millis(
+                    ui_constants::BATTERY_SAVER_COMPLETION_DEBOUNCE_MS,
+                ),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Settings for `config`, with an error message when the preset is
+    /// unknown (balanced is used instead)
+    pub fn from_config(config: &PerformanceConfig) -> (Self, Option<String>) {
+        let (base, error) = match Self::preset(&config.preset) {
+            Some(base) => (base, None),
+            None => (
+                Self::balanced(),
+                Some(format!(
+                    "Unknown ui.performance.preset '{}' (expected balanced, responsive or battery-saver)",
+                    config.preset
+                )),
+            ),
+        };
+        let millis = |value: Option<u64>, default: Duration| {
+            value.map(Duration::from_millis).unwrap_or(default)
+        };
+        let settings = Self {
+            // A zero tick rate would spin the event loop
+            tick_rate: millis(config.tick_rate_ms, base.tick_rate).max(Duration::from_millis(1)),
+            frame_interval: millis(config.frame_interval_ms, base.frame_interval)
+                .max(Duration::from_millis(1)),
+            key_repeat: millis(config.key_repeat_ms, base.key_repeat),
+            completion_debounce: millis(config.completion_debounce_ms, base.completion_debounce),
+        };
+        (settings, error)
+    }
+}
+
+impl Default for PerformanceSettings {
+    fn default() -> Self {
+        Self::balanced()
+    }
+}
+
+/// Drops repeats of a held navigation key that arrive within `interval` of
+/// the last one delivered. Plain characters always pass so typing is never
+/// lost, whatever the interval.
+#[derive(Debug, Clone)]
+struct KeyRepeatFilter {
+    interval: Duration,
+    last: Option<(KeyCode, KeyModifiers, Instant)>,
+}
+
+impl KeyRepeatFilter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    fn accept(&mut self, key: &KeyEvent, now: Instant) -> bool {
+        if self.interval.is_zero() || Self::is_typing(key) {
+            self.last = None;
+            return true;
+        }
+        if let Some((code, modifiers, at)) = self.last {
+            if code == key.code
+                && modifiers == key.modifiers
+                && now.duration_since(at) < self.interval
+            {
+                return false;
+            }
+        }
+        self.last = Some((key.code, key.modifiers, now));
+        true
+    }
+
+    fn is_typing(key: &KeyEvent) -> bool {
+        matches!(key.code, KeyCode::Char(_))
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+}
+
 /// UI event handler for processing terminal events
 #[derive(Clone)]
 pub struct UIEventHandler {
     tick_rate: Duration,
+    key_repeat: Duration,
 }
 
 impl UIEventHandler {
     /// Create a new event handler with the specified tick rate
     pub fn new(tick_rate: Duration) -> Self {
-        Self { tick_rate }
+        Self {
+            tick_rate,
+            key_repeat: Duration::ZERO,
+        }
+    }
+
+    /// Create an event handler with the tick rate and key repeat of `settings`
+    pub fn with_settings(settings: &PerformanceSettings) -> Self {
+        Self {
+            tick_rate: settings.tick_rate,
+            key_repeat: settings.key_repeat,
+        }
     }
 
     /// Run the event loop, sending events to the provided channel
     pub async fn run(&self, event_tx: mpsc::UnboundedSender<UIEvent>) {
         let mut last_tick = Instant::now();
+        let mut key_repeat = KeyRepeatFilter::new(self.key_repeat);
 
         loop {
             let timeout = self
@@ -51,6 +190,11 @@ impl UIEventHandler {
                     match read_result {
                         Ok(Ok(Ok(crossterm_event))) => {
                             let ui_event = Self::convert_event(crossterm_event);
+                            if let UIEvent::Key(key) = &ui_event {
+                                if !key_repeat.accept(key, Instant::now()) {
+                                    continue;
+                                }
+                            }
                             if event_tx.send(ui_event).is_err() {
                                 break;
                             }
@@ -85,8 +229,9 @@ impl UIEventHandler {
         match event {
             Event::Key(key) => {
                 // On Windows, crossterm fires both Press and Release events.
-                // We only want to handle Press events to avoid duplicate input.
-                if key.kind == KeyEventKind::Press {
+                // We only want to handle Press (and held-key Repeat) events to
+                // avoid duplicate input.
+                if key.kind != KeyEventKind::Release {
                     UIEvent::Key(key)
                 } else {
                     UIEvent::Tick
@@ -136,17 +281,48 @@ pub enum AppEvent {
     PodcastRefreshed {
         podcast_id: crate::storage::PodcastId,
         new_episode_count: usize,
+        /// Episodes the refresh added or rewrote, less those What's New
+        /// exclusions hide, applied to What's New in place. `None` when they
+        /// couldn't be gathered; What's New then reloads in full.
+        changed_episodes: Option<Vec<AggregatedEpisode>>,
     },
 
     /// Podcast refresh failed
     PodcastRefreshFailed {
         podcast_id: crate::storage::PodcastId,
         error: String,
+        /// The feed couldn't be reached at all
+        offline: bool,
     },
 
     /// All podcasts refresh completed
     AllPodcastsRefreshed {
         total_new_episodes: usize,
+        /// Podcasts refreshed
+        podcasts: usize,
+        /// Podcasts whose feed couldn't be reached at all
+        unreachable: Vec<crate::storage::PodcastId>,
+        /// What a selective refresh was limited to (e.g. `tag: "news"`);
+        /// `None` for a refresh of every subscription
+        scope: Option<String>,
+    },
+
+    /// A connectivity check made while offline finished
+    ConnectivityProbed {
+        online: bool,
+    },
+
+    /// A podcast finished during a hard refresh of every subscription
+    HardRefreshAllProgress {
+        done: usize,
+        total: usize,
+        podcast_title: String,
+    },
+
+    /// Hard refresh of every subscription completed
+    HardRefreshAllCompleted {
+        scope: crate::podcast::HardRefreshScope,
+        summary: crate::podcast::HardRefreshSummary,
     },
 
     /// Background buffer data refreshed
@@ -155,10 +331,37 @@ pub enum AppEvent {
         data: BufferRefreshData,
     },
 
+    /// Podcast loaded for the podcast detail buffer
+    PodcastDetailLoaded {
+        podcast: crate::podcast::Podcast,
+        /// `None` when the listening log or episodes couldn't be read
+        stats: Option<crate::podcast::PodcastStats>,
+    },
+
+    /// Podcast detail could not be loaded
+    PodcastDetailLoadFailed {
+        error: String,
+    },
+
+    /// Release check finished; `releases` are newer than this build, newest first
+    ReleasesChecked {
+        releases: Vec<crate::update_check::Release>,
+        /// Open the release notes buffer (`:release-notes`) rather than only hinting
+        open_buffer: bool,
+    },
+
+    /// Release check failed
+    ReleaseCheckFailed {
+        error: String,
+        open_buffer: bool,
+    },
+
     /// Podcast deleted successfully
     PodcastDeleted {
         podcast_id: crate::storage::PodcastId,
         podcast_title: String,
+        /// What was kept of its data
+        mode: crate::podcast::subscription::UnsubscribeMode,
     },
 
     /// Podcast deletion failed
@@ -167,6 +370,21 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// Recently removed podcasts listed for `restore-podcast`
+    RemovedPodcastsListed {
+        podcasts: Vec<crate::podcast::Podcast>,
+    },
+
+    /// Removed podcast restored successfully
+    PodcastRestored {
+        podcast_title: String,
+    },
+
+    /// Podcast restore failed
+    PodcastRestoreFailed {
+        error: String,
+    },
+
     /// Podcast downloads deleted during unsubscribe
     PodcastDownloadsDeleted {
         podcast_id: crate::storage::PodcastId,
@@ -192,11 +410,156 @@ pub enum AppEvent {
         episode_id: crate::storage::EpisodeId,
     },
 
+    /// ffmpeg progress for a running episode conversion
+    EpisodeConversionProgress {
+        episode_title: String,
+        percent: u8,
+    },
+
+    /// Episode converted; `path` is the new file
+    EpisodeConverted {
+        podcast_id: crate::storage::PodcastId,
+        episode_title: String,
+        path: std::path::PathBuf,
+    },
+
+    /// Episode conversion failed (the original file is untouched)
+    EpisodeConversionFailed {
+        episode_title: String,
+        error: String,
+    },
+
+    /// whisper progress for a transcription started with `:transcribe`
+    EpisodeTranscriptionProgress {
+        episode_title: String,
+        percent: u8,
+    },
+
+    /// A transcript was stored on `episode`
+    EpisodeTranscribed {
+        episode: Box<crate::podcast::Episode>,
+    },
+
+    EpisodeTranscriptionFailed {
+        episode_title: String,
+        error: String,
+    },
+
+    /// A translated description was stored on `episode` (`:translate`)
+    EpisodeTranslated {
+        episode: Box<crate::podcast::Episode>,
+    },
+
+    EpisodeTranslationFailed {
+        episode_title: String,
+        error: String,
+    },
+
+    /// Listening statistics and goal progress were computed; `remind` shows
+    /// a reminder for a goal falling behind
+    ListeningGoalsEvaluated {
+        stats: crate::podcast::ListeningStats,
+        progress: Vec<crate::podcast::GoalProgress>,
+        remind: bool,
+    },
+
+    /// The full-text search index finished building
+    SearchIndexBuilt {
+        index: std::sync::Arc<crate::search::SearchIndex>,
+    },
+
+    /// The playback queue's entries were looked up for the Queue buffer
+    QueueItemsLoaded {
+        items: Vec<crate::ui::buffers::queue::QueueItem>,
+    },
+
+    /// Played episodes were collected for the History buffer
+    HistoryItemsLoaded {
+        items: Vec<crate::ui::buffers::history::HistoryItem>,
+    },
+
+    /// Writing the playback queue failed
+    QueueSaveFailed {
+        error: String,
+    },
+
+    /// A refresh found new episodes of podcasts that auto-download
+    AutoDownloadsQueued {
+        episodes: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    },
+
+    /// A refresh turned up new episodes matching alert keywords
+    KeywordAlertsRaised {
+        alerts: Vec<KeywordAlert>,
+    },
+
+    /// A local metadata override was saved (`field: None` when all were reverted);
+    /// `retagged` when the downloaded file's tags were rewritten
+    EpisodeMetadataEdited {
+        episode: Box<crate::podcast::Episode>,
+        field: Option<crate::podcast::EpisodeField>,
+        retagged: bool,
+    },
+
+    /// Saving a local metadata override failed
+    EpisodeMetadataEditFailed {
+        error: String,
+    },
+
+    /// The user's notes were saved; `episode` is set for episode notes and
+    /// `podcast` for podcast notes
+    NotesSaved {
+        podcast: Option<Box<crate::podcast::Podcast>>,
+        episode: Option<Box<crate::podcast::Episode>>,
+    },
+
+    /// Saving notes failed
+    NotesSaveFailed {
+        error: String,
+    },
+
+    /// A bookmark was added at `position` seconds
+    BookmarkAdded {
+        episode: Box<crate::podcast::Episode>,
+        position: u32,
+    },
+
+    /// An episode's bookmarks were written as chapter files
+    BookmarksExported {
+        episode_title: String,
+        paths: Vec<std::path::PathBuf>,
+    },
+
+    /// Adding or exporting bookmarks failed
+    BookmarkFailed {
+        error: String,
+    },
+
+    /// Persisting a playlist's shuffle / repeat modes failed
+    PlaybackModesSaveFailed {
+        name: String,
+        error: String,
+    },
+
+    /// Bytes fetched so far for a download that reports progress
+    EpisodeDownloadProgress {
+        episode_id: crate::storage::EpisodeId,
+        downloaded: u64,
+        total: Option<u64>,
+    },
+
+    /// A download was refused because the episode is already downloading
+    EpisodeAlreadyDownloading {
+        episode_id: crate::storage::EpisodeId,
+    },
+
     /// Episode download failed
     EpisodeDownloadFailed {
         podcast_id: crate::storage::PodcastId,
         episode_id: crate::storage::EpisodeId,
         error: String,
+        /// The server couldn't be reached at all
+        offline: bool,
     },
 
     /// Episode download deleted successfully
@@ -212,6 +575,13 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// A stale download was deleted and the feed's new enclosure adopted,
+    /// ready to download again
+    RedownloadPrepared {
+        podcast_id: crate::storage::PodcastId,
+        episode_id: crate::storage::EpisodeId,
+    },
+
     /// Episode marked as played successfully
     EpisodeMarkedPlayed {
         podcast_id: crate::storage::PodcastId,
@@ -276,7 +646,9 @@ pub enum AppEvent {
 
     /// OPML import progress update
     OpmlImportProgress {
-        current: usize,
+        /// Feeds imported or skipped so far
+        done: usize,
+        failed: usize,
         total: usize,
         status: String,
     },
@@ -293,6 +665,17 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// An OPML file was read for the preview buffer
+    OpmlPreviewLoaded {
+        preview: crate::podcast::OpmlPreview,
+    },
+
+    /// Reading an OPML file for the preview buffer failed
+    OpmlPreviewFailed {
+        source: String,
+        error: String,
+    },
+
     /// OPML export started
     OpmlExportStarted {
         path: String,
@@ -315,6 +698,40 @@ pub enum AppEvent {
         error: String,
     },
 
+    // Settings bundle events
+    /// Settings bundle written
+    SettingsExported {
+        path: String,
+        theme_count: usize,
+        playlist_count: usize,
+    },
+
+    /// Settings export failed
+    SettingsExportFailed {
+        path: String,
+        error: String,
+    },
+
+    /// Settings bundle loaded and diffed against the current setup, awaiting confirmation
+    SettingsImportPreviewReady {
+        path: String,
+        bundle: Box<crate::settings_bundle::SettingsBundle>,
+        preview: Vec<String>,
+    },
+
+    /// Settings bundle applied
+    SettingsImported {
+        config: Box<crate::config::Config>,
+        theme_count: usize,
+        playlist_count: usize,
+    },
+
+    /// Settings import failed
+    SettingsImportFailed {
+        path: String,
+        error: String,
+    },
+
     // Playlist events
     PlaylistCreated {
         playlist: crate::playlist::Playlist,
@@ -339,6 +756,14 @@ pub enum AppEvent {
         episode_title: String,
         error: String,
     },
+    EpisodesAddedToPlaylist {
+        playlist_name: String,
+        result: crate::playlist::AddEpisodesResult,
+    },
+    EpisodesAddToPlaylistFailed {
+        playlist_name: String,
+        error: String,
+    },
     EpisodeRemovedFromPlaylist {
         playlist_name: String,
         episode_title: String,
@@ -350,6 +775,9 @@ pub enum AppEvent {
     },
     PlaylistReordered {
         name: String,
+        playlist_id: crate::playlist::PlaylistId,
+        /// The playlist's entries in their new order
+        entries: Vec<crate::playlist::queue::QueueEntry>,
     },
     PlaylistReorderFailed {
         name: String,
@@ -410,6 +838,91 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// Startup filename collision check found episodes sharing a download file
+    FilenameCollisionsResolved {
+        reset: usize,
+        ambiguous: usize,
+    },
+
+    /// Duration backfill probed downloaded files; `manual` runs always report
+    DurationsBackfilled {
+        filled: usize,
+        manual: bool,
+    },
+
+    /// Idle prefetch picked episodes to download ahead of the playing one
+    PrefetchEpisodes {
+        episodes: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    },
+
+    /// `:backfill-durations` couldn't read the library
+    DurationBackfillFailed {
+        error: String,
+    },
+
+    /// A podcast's User-Agent override was saved (`None` = back to the config's)
+    PodcastUserAgentSet {
+        podcast_title: String,
+        user_agent: Option<String>,
+    },
+
+    /// Saving a podcast's User-Agent override failed
+    PodcastUserAgentSetFailed {
+        error: String,
+    },
+
+    /// A podcast's auto-play setting was saved (`None` = back to the config's)
+    PodcastAutoPlaySet {
+        podcast_title: String,
+        auto_play: Option<bool>,
+    },
+
+    /// Saving a podcast's auto-play setting failed
+    PodcastAutoPlaySetFailed {
+        error: String,
+    },
+
+    /// A podcast's download quality was saved
+    PodcastQualitySet {
+        podcast_title: String,
+        quality: crate::podcast::DownloadQuality,
+    },
+
+    /// Saving a podcast's download quality failed
+    PodcastQualitySetFailed {
+        error: String,
+    },
+
+    /// A podcast's settings were saved from the podcast settings view
+    PodcastSettingsSaved {
+        podcast_id: crate::storage::PodcastId,
+        podcast_title: String,
+        settings: crate::podcast::PodcastSettings,
+    },
+
+    /// Saving a podcast's settings failed
+    PodcastSettingsSaveFailed {
+        error: String,
+    },
+
+    /// Downloads deleted under the retention rules
+    RetentionCleanupCompleted {
+        deleted_count: usize,
+    },
+
+    /// Dry run of the retention rules, for `:cleanup-preview`
+    CleanupPlanned {
+        plan: crate::download::CleanupPlan,
+    },
+
+    /// Podcasts were archived (`archived: true`) or taken out of the archive
+    PodcastsArchived {
+        titles: Vec<String>,
+        archived: bool,
+        /// Podcasts that could not be saved
+        failed: usize,
+    },
+
     /// Podcast tag added successfully
     PodcastTagAdded {
         podcast_id: crate::storage::PodcastId,
@@ -446,6 +959,17 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// `:commute` picked episodes to fit the budget
+    CommuteFilled {
+        budget_secs: u32,
+        plan: crate::playlist::commute::CommutePlan,
+    },
+
+    /// `:commute` could not read the library
+    CommuteFillFailed {
+        error: String,
+    },
+
     /// Discovery results loaded (search or trending)
     DiscoveryResultsLoaded {
         buffer_id: String,
@@ -460,6 +984,12 @@ pub enum AppEvent {
         error: String,
     },
 
+    /// A pasted web page advertises several feeds; the user picks one
+    FeedCandidatesFound {
+        page_url: String,
+        candidates: Vec<crate::podcast::FeedLink>,
+    },
+
     // Audio playback lifecycle events
     /// Playback started successfully for an episode
     PlaybackStarted {
@@ -480,10 +1010,106 @@ pub enum AppEvent {
     PlaybackError {
         error: String,
     },
+
+    /// Playback at startup isn't as configured, or there is no audio output
+    /// at all (see `AudioDiagnostics::startup_warning`)
+    AudioStartupWarning {
+        message: String,
+    },
+
+    /// The built-in player couldn't decode the episode (`error`), so it's
+    /// playing in the external `player` instead
+    PlaybackFellBack {
+        player: String,
+        error: String,
+    },
+
+    /// Output devices available to the audio backend (`current: None` = system default)
+    AudioDevicesListed {
+        devices: Vec<String>,
+        current: Option<String>,
+    },
+
+    /// Output switched to `device` (`None` = system default)
+    AudioDeviceChanged {
+        device: Option<String>,
+    },
+
+    /// Switching to `device` failed; playback continues on the previous device
+    AudioDeviceSelectFailed {
+        device: String,
+        error: String,
+    },
+
+    /// The output device disappeared; playback moved to the system default
+    /// (`error` is set if that failed too)
+    AudioDeviceLost {
+        device: Option<String>,
+        error: Option<String>,
+    },
+
+    /// Network renderers found by `:cast`
+    CastTargetsDiscovered {
+        targets: Vec<crate::audio::cast::CastTarget>,
+    },
+
+    /// Playback now goes to `target` (`None` = this computer)
+    CastTargetChanged {
+        target: Option<String>,
+    },
+
+    /// Casting to `target` failed; playback stays on, or went back to, this computer
+    CastFailed {
+        target: String,
+        error: String,
+    },
+
+    /// The renderer stopped answering; playback moved back to this computer
+    CastConnectionLost {
+        target: String,
+    },
+
+    /// Sign-in started: the user approves `authorization.user_code` at its
+    /// verification URL while we wait
+    FeedSignInCode {
+        provider: String,
+        authorization: crate::podcast::DeviceAuthorization,
+    },
+
+    /// Signed in to an OAuth provider; its feeds now fetch with a token
+    FeedSignedIn {
+        provider: String,
+    },
+
+    FeedSignInFailed {
+        provider: String,
+        error: String,
+    },
+
+    /// Stored tokens for `provider` were removed (`false`: there were none)
+    FeedSignedOut {
+        provider: String,
+        was_signed_in: bool,
+    },
+
+    /// `export-archive` wrote a podcast's HTML archive
+    ArchiveExported {
+        podcast_title: String,
+        summary: crate::podcast::ArchiveSummary,
+    },
+
+    ArchiveExportFailed {
+        error: String,
+    },
+
+    /// The power monitor saw the machine switch power source or battery level
+    PowerSourceChanged {
+        source: crate::utils::power::PowerSource,
+    },
 }
 
 /// Types of buffer refresh operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum BufferRefreshType {
     /// Refresh podcast list buffer
     PodcastList,
@@ -491,6 +1117,8 @@ pub enum BufferRefreshType {
     Downloads,
     /// Refresh What's New buffer
     WhatsNew,
+    /// Refresh the Unplayed buffer
+    Unplayed,
     /// Refresh all episode buffers
     AllEpisodeBuffers,
     /// Refresh episode buffers for specific podcast
@@ -505,11 +1133,17 @@ pub enum BufferRefreshData {
     /// Podcast list data
     PodcastList {
         podcasts: Vec<crate::podcast::Podcast>,
+        summaries: std::collections::HashMap<crate::storage::PodcastId, PodcastSummary>,
     },
     /// Download entries data
-    Downloads { downloads: Vec<DownloadEntry> },
+    Downloads {
+        downloads: Vec<DownloadEntry>,
+        usage: crate::download::StorageUsage,
+    },
     /// What's New episodes data
     WhatsNew { episodes: Vec<AggregatedEpisode> },
+    /// Unplayed episodes across all podcasts
+    Unplayed { episodes: Vec<AggregatedEpisode> },
     /// Episode list data for specific podcast
     Episodes {
         podcast_id: crate::storage::PodcastId,
@@ -519,6 +1153,29 @@ pub enum BufferRefreshData {
     Error { message: String },
 }
 
+/// Per-podcast episode counts shown as columns in the podcast list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PodcastSummary {
+    pub unplayed: usize,
+    pub downloaded: usize,
+    /// Publish date of the newest episode
+    pub last_episode: Option<chrono::DateTime<chrono::Utc>>,
+    /// Bytes used by downloaded files (filled in from file metadata by the refresh)
+    pub disk_bytes: u64,
+}
+
+impl PodcastSummary {
+    /// Count unplayed/downloaded episodes and find the newest publish date
+    pub fn from_episodes(episodes: &[crate::podcast::Episode]) -> Self {
+        Self {
+            unplayed: episodes.iter().filter(|e| !e.is_played()).count(),
+            downloaded: episodes.iter().filter(|e| e.is_downloaded()).count(),
+            last_episode: episodes.iter().map(|e| e.published).max(),
+            disk_bytes: 0,
+        }
+    }
+}
+
 /// Aggregated episode with podcast information (moved from whats_new buffer)
 #[derive(Debug, Clone)]
 pub struct AggregatedEpisode {
@@ -527,6 +1184,17 @@ pub struct AggregatedEpisode {
     pub episode: crate::podcast::Episode,
 }
 
+/// A new episode matching an `alerts.keywords` entry
+#[derive(Debug, Clone)]
+pub struct KeywordAlert {
+    pub podcast_id: crate::storage::PodcastId,
+    pub podcast_title: String,
+    pub episode: crate::podcast::Episode,
+    /// The keyword as configured
+    pub keyword: String,
+    pub raised_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// Download entry for tracking downloads (moved from downloads buffer)
 #[derive(Debug, Clone)]
 pub struct DownloadEntry {
@@ -538,3 +1206,61 @@ pub struct DownloadEntry {
     pub file_path: Option<std::path::PathBuf>,
     pub file_size: Option<u64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_presets_and_overrides() {
+        let (balanced, error) = PerformanceSettings::from_config(&PerformanceConfig::default());
+        assert_eq!(balanced, PerformanceSettings::balanced());
+        assert!(error.is_none());
+
+        let config = PerformanceConfig {
+            preset: "battery-saver".to_string(),
+            completion_debounce_ms: Some(0),
+            ..PerformanceConfig::default()
+        };
+        let (saver, _) = PerformanceSettings::from_config(&config);
+        assert_eq!(saver.tick_rate, Duration::from_secs(1));
+        assert_eq!(saver.completion_debounce, Duration::ZERO);
+
+        let config = PerformanceConfig {
+            preset: "turbo".to_string(),
+            tick_rate_ms: Some(0),
+            ..PerformanceConfig::default()
+        };
+        let (fallback, error) = PerformanceSettings::from_config(&config);
+        assert!(error.unwrap().contains("turbo"));
+        assert_eq!(fallback.tick_rate, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_key_repeat_filter_throttles_held_navigation_keys_only() {
+        let mut filter = KeyRepeatFilter::new(Duration::from_millis(50));
+        let start = Instant::now();
+        let down = key(KeyCode::Down, KeyModifiers::NONE);
+
+        assert!(filter.accept(&down, start));
+        assert!(!filter.accept(&down, start + Duration::from_millis(20)));
+        assert!(filter.accept(&down, start + Duration::from_millis(60)));
+        // A different key is never held back
+        assert!(filter.accept(
+            &key(KeyCode::Up, KeyModifiers::NONE),
+            start + Duration::from_millis(61)
+        ));
+
+        let letter = key(KeyCode::Char('l'), KeyModifiers::NONE);
+        assert!(filter.accept(&letter, start));
+        assert!(filter.accept(&letter, start + Duration::from_millis(1)));
+
+        let mut off = KeyRepeatFilter::new(Duration::ZERO);
+        assert!(off.accept(&down, start));
+        assert!(off.accept(&down, start));
+    }
+}
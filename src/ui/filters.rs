@@ -4,9 +4,11 @@
 // EpisodeListBuffer, WhatsNewBuffer, and PodcastListBuffer to
 // implement inline filtering (narrowing views in-place).
 
-use crate::podcast::{Episode, EpisodeStatus, Podcast};
+use crate::config::WhatsNewExclusion;
+use crate::podcast::{DownloadState, Episode, EpisodeStatus, PlayState, Podcast, TriageState};
 use crate::utils::time::parse_cleanup_duration;
 use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
 
 /// Filter criteria for episode lists.
 ///
@@ -36,6 +38,14 @@ pub struct EpisodeFilter {
     /// Configurable threshold: episodes longer than this (minutes) are "long".
     /// Set from `UiConfig.filter_long_min_minutes`. Default: 45.
     pub long_min_minutes: u32,
+
+    /// Hide explicit episodes. Set from `UiConfig.hide_explicit`; not cleared
+    /// by `clear()` and not reported as an active filter.
+    pub hide_explicit: bool,
+
+    /// Languages to show, from `UiConfig.languages`; empty shows all. Episodes
+    /// without a language always show. Not cleared by `clear()`.
+    pub languages: Vec<String>,
 }
 
 impl Default for EpisodeFilter {
@@ -48,6 +58,8 @@ impl Default for EpisodeFilter {
             favorites_only: false,
             short_max_minutes: DEFAULT_SHORT_MAX_MINUTES,
             long_min_minutes: DEFAULT_LONG_MIN_MINUTES,
+            hide_explicit: false,
+            languages: Vec::new(),
         }
     }
 }
@@ -83,6 +95,8 @@ impl EpisodeFilter {
             && self.matches_date_range(episode)
             && self.matches_duration(episode)
             && self.matches_favorites(episode)
+            && !(self.hide_explicit && episode.explicit)
+            && self.matches_language(episode)
     }
 
     /// Clear all filters.
@@ -124,6 +138,15 @@ impl EpisodeFilter {
 
     // --- Private matching helpers ---
 
+    fn matches_language(&self, episode: &Episode) -> bool {
+        match episode.language.as_deref() {
+            Some(language) if !self.languages.is_empty() => {
+                language_allowed(&self.languages, language)
+            }
+            _ => true,
+        }
+    }
+
     fn matches_text(&self, episode: &Episode) -> bool {
         let query = match &self.text_query {
             Some(q) if !q.is_empty() => q.to_lowercase(),
@@ -139,8 +162,12 @@ impl EpisodeFilter {
             .notes
             .as_deref()
             .is_some_and(|n| n.to_lowercase().contains(&query));
+        let transcript_match = episode
+            .transcript
+            .as_deref()
+            .is_some_and(|t| t.to_lowercase().contains(&query));
 
-        title_match || desc_match || notes_match
+        title_match || desc_match || notes_match || transcript_match
     }
 
     fn matches_status(&self, episode: &Episode) -> bool {
@@ -173,30 +200,34 @@ impl EpisodeFilter {
     }
 }
 
-/// Status filter options — maps to `EpisodeStatus`.
+/// Status filter options — each looks at one dimension of `EpisodeStatus`,
+/// so e.g. a downloaded, half-played episode matches both `downloaded` and
+/// `in-progress`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EpisodeStatusFilter {
     New,
     Downloaded,
     Played,
+    Unplayed,
+    InProgress,
     Downloading,
     DownloadFailed,
+    Archived,
 }
 
 impl EpisodeStatusFilter {
     /// Check if this filter matches the given episode status.
     pub fn matches(&self, status: &EpisodeStatus) -> bool {
-        matches!(
-            (self, status),
-            (EpisodeStatusFilter::New, EpisodeStatus::New)
-                | (EpisodeStatusFilter::Downloaded, EpisodeStatus::Downloaded)
-                | (EpisodeStatusFilter::Played, EpisodeStatus::Played)
-                | (EpisodeStatusFilter::Downloading, EpisodeStatus::Downloading)
-                | (
-                    EpisodeStatusFilter::DownloadFailed,
-                    EpisodeStatus::DownloadFailed
-                )
-        )
+        match self {
+            EpisodeStatusFilter::New => status.is_new(),
+            EpisodeStatusFilter::Downloaded => status.download == DownloadState::Downloaded,
+            EpisodeStatusFilter::Played => status.play == PlayState::Played,
+            EpisodeStatusFilter::Unplayed => status.play == PlayState::Unplayed,
+            EpisodeStatusFilter::InProgress => status.play == PlayState::InProgress,
+            EpisodeStatusFilter::Downloading => status.download == DownloadState::Downloading,
+            EpisodeStatusFilter::DownloadFailed => status.download == DownloadState::Failed,
+            EpisodeStatusFilter::Archived => status.triage == TriageState::Archived,
+        }
     }
 }
 
@@ -206,8 +237,11 @@ impl std::fmt::Display for EpisodeStatusFilter {
             Self::New => write!(f, "new"),
             Self::Downloaded => write!(f, "downloaded"),
             Self::Played => write!(f, "played"),
+            Self::Unplayed => write!(f, "unplayed"),
+            Self::InProgress => write!(f, "in-progress"),
             Self::Downloading => write!(f, "downloading"),
             Self::DownloadFailed => write!(f, "failed"),
+            Self::Archived => write!(f, "archived"),
         }
     }
 }
@@ -341,17 +375,24 @@ pub struct PodcastFilter {
     pub text_query: Option<String>,
     /// Tag filter — when set, only podcasts with this tag are shown.
     pub tag_filter: Option<String>,
+    /// Hide explicit podcasts. Set from `UiConfig.hide_explicit`; survives `clear()`.
+    pub hide_explicit: bool,
+    /// Show the archived podcasts instead of the active ones (`:archived`)
+    pub archived: bool,
 }
 
 impl PodcastFilter {
     /// Check if the filter is active.
     pub fn is_active(&self) -> bool {
-        self.text_query.is_some() || self.tag_filter.is_some()
+        self.text_query.is_some() || self.tag_filter.is_some() || self.archived
     }
 
     /// Check if a podcast matches this filter.
     pub fn matches(&self, podcast: &Podcast) -> bool {
-        self.matches_text(podcast) && self.matches_tag(podcast)
+        self.matches_text(podcast)
+            && self.matches_tag(podcast)
+            && !(self.hide_explicit && podcast.explicit)
+            && podcast.is_archived() == self.archived
     }
 
     fn matches_text(&self, podcast: &Podcast) -> bool {
@@ -382,18 +423,153 @@ impl PodcastFilter {
 
     /// Build a human-readable description of the active filter.
     pub fn description(&self) -> String {
-        match (&self.text_query, &self.tag_filter) {
+        let mut description = match (&self.text_query, &self.tag_filter) {
             (Some(q), Some(t)) => format!("search: \"{}\" tag: \"{}\"", q, t),
             (Some(q), None) => format!("search: \"{}\"", q),
             (None, Some(t)) => format!("tag: \"{}\"", t),
             (None, None) => String::new(),
+        };
+        if self.archived {
+            if !description.is_empty() {
+                description.push(' ');
+            }
+            description.push_str("archived");
         }
+        description
     }
 
     /// Clear the filter.
     pub fn clear(&mut self) {
         self.text_query = None;
         self.tag_filter = None;
+        self.archived = false;
+    }
+}
+
+/// Compiled `ui.whats_new_exclusions` rules.
+///
+/// Unlike the interactive filters these are permanent: excluded episodes never
+/// reach What's New and don't count towards "N new episode(s)" messages.
+#[derive(Debug, Clone, Default)]
+pub struct WhatsNewExclusions {
+    rules: Vec<ExclusionRule>,
+}
+
+#[derive(Debug, Clone)]
+struct ExclusionRule {
+    /// Lowercased podcast title or feed URL; `None` applies to every podcast
+    podcast: Option<String>,
+    /// `None` excludes every episode of `podcast`
+    title: Option<Regex>,
+}
+
+impl WhatsNewExclusions {
+    /// Compile configured rules. Rules with an invalid pattern or no fields
+    /// are skipped and described in the returned error list.
+    pub fn compile(rules: &[WhatsNewExclusion]) -> (Self, Vec<String>) {
+        let mut compiled = Vec::new();
+        let mut errors = Vec::new();
+
+        for rule in rules {
+            let podcast = rule
+                .podcast
+                .as_deref()
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(str::to_lowercase);
+            let title = match rule.title_pattern.as_deref() {
+                Some(pattern) => match Regex::new(pattern) {
+                    Ok(regex) => Some(regex),
+                    Err(e) => {
+                        errors.push(format!("Invalid title pattern '{}': {}", pattern, e));
+                        continue;
+                    }
+                },
+                None => None,
+            };
+            if podcast.is_none() && title.is_none() {
+                errors.push("Exclusion rule needs a podcast or a title_pattern".to_string());
+                continue;
+            }
+            compiled.push(ExclusionRule { podcast, title });
+        }
+
+        (Self { rules: compiled }, errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// True when any rule excludes `episode_title` from `podcast`
+    pub fn excludes(&self, podcast: &Podcast, episode_title: &str) -> bool {
+        self.rules.iter().any(|rule| {
+            let podcast_matches = rule.podcast.as_deref().is_none_or(|wanted| {
+                podcast.title.to_lowercase() == wanted || podcast.url.to_lowercase() == wanted
+            });
+            podcast_matches
+                && rule
+                    .title
+                    .as_ref()
+                    .is_none_or(|regex| regex.is_match(episode_title))
+        })
+    }
+}
+
+/// Compiled `alerts.keywords`.
+///
+/// Keywords match case-insensitively anywhere in an episode's title,
+/// description or transcript. One written as `/pattern/` is a regex; anything
+/// else is literal text.
+#[derive(Debug, Clone, Default)]
+pub struct KeywordAlerts {
+    keywords: Vec<(String, Regex)>,
+}
+
+impl KeywordAlerts {
+    /// Compile configured keywords. Blank keywords and invalid patterns are
+    /// skipped and described in the returned error list.
+    pub fn compile(keywords: &[String]) -> (Self, Vec<String>) {
+        let mut compiled = Vec::new();
+        let mut errors = Vec::new();
+
+        for keyword in keywords {
+            let keyword = keyword.trim();
+            let pattern = match keyword
+                .strip_prefix('/')
+                .and_then(|rest| rest.strip_suffix('/'))
+            {
+                Some(pattern) if !pattern.is_empty() => pattern.to_string(),
+                _ if keyword.is_empty() || keyword == "/" || keyword == "//" => {
+                    errors.push("Alert keyword is empty".to_string());
+                    continue;
+                }
+                _ => regex::escape(keyword),
+            };
+            match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+                Ok(regex) => compiled.push((keyword.to_string(), regex)),
+                Err(e) => errors.push(format!("Invalid alert keyword '{}': {}", keyword, e)),
+            }
+        }
+
+        (Self { keywords: compiled }, errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty()
+    }
+
+    /// The first keyword (as configured) found in `episode`
+    pub fn matching_keyword(&self, episode: &Episode) -> Option<&str> {
+        let fields = [
+            Some(episode.title.as_str()),
+            episode.description.as_deref(),
+            episode.transcript.as_deref(),
+        ];
+        self.keywords
+            .iter()
+            .find(|(_, regex)| fields.iter().flatten().any(|text| regex.is_match(text)))
+            .map(|(keyword, _)| keyword.as_str())
     }
 }
 
@@ -401,12 +577,16 @@ impl PodcastFilter {
 
 /// Parse a status filter string from minibuffer/command input.
 ///
-/// Accepts: `new`, `downloaded`, `played`, `downloading`, `failed`
+/// Accepts: `new`, `downloaded`, `played`, `unplayed`, `in-progress`,
+/// `downloading`, `failed`, `archived`
 pub fn parse_status_filter(s: &str) -> Option<EpisodeStatusFilter> {
     match s.trim().to_lowercase().as_str() {
         "new" => Some(EpisodeStatusFilter::New),
         "downloaded" => Some(EpisodeStatusFilter::Downloaded),
         "played" => Some(EpisodeStatusFilter::Played),
+        "unplayed" => Some(EpisodeStatusFilter::Unplayed),
+        "in-progress" | "started" => Some(EpisodeStatusFilter::InProgress),
+        "archived" => Some(EpisodeStatusFilter::Archived),
         "downloading" => Some(EpisodeStatusFilter::Downloading),
         "failed" | "download-failed" => Some(EpisodeStatusFilter::DownloadFailed),
         _ => None,
@@ -437,6 +617,20 @@ pub fn parse_duration_filter(s: &str) -> Option<DurationFilter> {
     }
 }
 
+/// Whether `language` (e.g. `en-US`) is one of `wanted`, comparing primary
+/// subtags case-insensitively so `en` and `en-gb` both allow `en-us`
+pub fn language_allowed(wanted: &[String], language: &str) -> bool {
+    let primary = |tag: &str| {
+        tag.trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    let language = primary(language);
+    wanted.iter().any(|tag| primary(tag) == language)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -481,7 +675,7 @@ mod tests {
     #[test]
     fn test_inactive_filter_matches_everything() {
         let filter = EpisodeFilter::default();
-        let ep = make_episode("Any Title", EpisodeStatus::New, None);
+        let ep = make_episode("Any Title", EpisodeStatus::default(), None);
         assert!(filter.matches(&ep));
     }
 
@@ -491,13 +685,13 @@ mod tests {
             text_query: Some("RUST".to_string()),
             ..Default::default()
         };
-        let ep = make_episode("Learning Rust Basics", EpisodeStatus::New, None);
+        let ep = make_episode("Learning Rust Basics", EpisodeStatus::default(), None);
         assert!(filter.matches(&ep));
     }
 
     #[test]
     fn test_text_search_in_description() {
-        let mut ep = make_episode("Episode 1", EpisodeStatus::New, None);
+        let mut ep = make_episode("Episode 1", EpisodeStatus::default(), None);
         ep.description = Some("This episode covers Rust programming".to_string());
 
         let filter = EpisodeFilter {
@@ -507,9 +701,21 @@ mod tests {
         assert!(filter.matches(&ep));
     }
 
+    #[test]
+    fn test_text_search_in_transcript() {
+        let mut ep = make_episode("Episode 1", EpisodeStatus::default(), None);
+        ep.transcript = Some("and then we talked about borrow checking".to_string());
+
+        let filter = EpisodeFilter {
+            text_query: Some("Borrow".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&ep));
+    }
+
     #[test]
     fn test_text_search_in_notes() {
-        let mut ep = make_episode("Episode 1", EpisodeStatus::New, None);
+        let mut ep = make_episode("Episode 1", EpisodeStatus::default(), None);
         ep.notes = Some("Great episode about Rust".to_string());
 
         let filter = EpisodeFilter {
@@ -525,7 +731,7 @@ mod tests {
             text_query: Some("xyzzy".to_string()),
             ..Default::default()
         };
-        let ep = make_episode("Normal Episode", EpisodeStatus::New, None);
+        let ep = make_episode("Normal Episode", EpisodeStatus::default(), None);
         assert!(!filter.matches(&ep));
     }
 
@@ -536,8 +742,8 @@ mod tests {
             ..Default::default()
         };
 
-        let downloaded = make_episode("Ep1", EpisodeStatus::Downloaded, None);
-        let new_ep = make_episode("Ep2", EpisodeStatus::New, None);
+        let downloaded = make_episode("Ep1", EpisodeStatus::downloaded(), None);
+        let new_ep = make_episode("Ep2", EpisodeStatus::default(), None);
 
         assert!(filter.matches(&downloaded));
         assert!(!filter.matches(&new_ep));
@@ -549,10 +755,10 @@ mod tests {
             status: Some(EpisodeStatusFilter::New),
             ..Default::default()
         };
-        let ep = make_episode("Ep", EpisodeStatus::New, None);
+        let ep = make_episode("Ep", EpisodeStatus::default(), None);
         assert!(filter.matches(&ep));
 
-        let played = make_episode("Ep", EpisodeStatus::Played, None);
+        let played = make_episode("Ep", EpisodeStatus::played(), None);
         assert!(!filter.matches(&played));
     }
 
@@ -563,11 +769,11 @@ mod tests {
             ..Default::default()
         };
         // 10 minutes = 600 seconds → short
-        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(600))));
+        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(600))));
         // 20 minutes → not short
-        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(1200))));
+        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(1200))));
         // No duration → doesn't match
-        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::New, None)));
+        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::default(), None)));
     }
 
     #[test]
@@ -577,11 +783,11 @@ mod tests {
             ..Default::default()
         };
         // 30 minutes = 1800s → medium
-        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(1800))));
+        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(1800))));
         // 10 minutes → not medium
-        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(600))));
+        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(600))));
         // 60 minutes → not medium
-        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(3600))));
+        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(3600))));
     }
 
     #[test]
@@ -591,9 +797,9 @@ mod tests {
             ..Default::default()
         };
         // 60 minutes → long
-        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(3600))));
+        assert!(filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(3600))));
         // 30 minutes → not long
-        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::New, Some(1800))));
+        assert!(!filter.matches(&make_episode("Ep", EpisodeStatus::default(), Some(1800))));
     }
 
     #[test]
@@ -663,15 +869,15 @@ mod tests {
         };
 
         // Matches text AND status
-        let matching = make_episode("Learning Rust", EpisodeStatus::Downloaded, None);
+        let matching = make_episode("Learning Rust", EpisodeStatus::downloaded(), None);
         assert!(filter.matches(&matching));
 
         // Matches text but not status
-        let text_only = make_episode("Learning Rust", EpisodeStatus::New, None);
+        let text_only = make_episode("Learning Rust", EpisodeStatus::default(), None);
         assert!(!filter.matches(&text_only));
 
         // Matches status but not text
-        let status_only = make_episode("Learning Go", EpisodeStatus::Downloaded, None);
+        let status_only = make_episode("Learning Go", EpisodeStatus::downloaded(), None);
         assert!(!filter.matches(&status_only));
     }
 
@@ -769,6 +975,25 @@ mod tests {
 
     // --- PodcastFilter tests ---
 
+    #[test]
+    fn test_podcast_filter_separates_archived_podcasts() {
+        let mut filter = PodcastFilter::default();
+        let active = Podcast::new("Weekly".to_string(), "http://example.com/a".to_string());
+        let mut archived = Podcast::new("Finished".to_string(), "http://example.com/b".to_string());
+        archived.archived_at = Some(Utc::now());
+
+        assert!(filter.matches(&active));
+        assert!(!filter.matches(&archived));
+
+        filter.archived = true;
+        assert!(filter.is_active());
+        assert_eq!(filter.description(), "archived");
+        assert!(!filter.matches(&active));
+        assert!(filter.matches(&archived));
+        filter.clear();
+        assert!(!filter.archived);
+    }
+
     #[test]
     fn test_podcast_filter_inactive_matches_all() {
         let filter = PodcastFilter::default();
@@ -781,6 +1006,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: Some("rust".to_string()),
             tag_filter: None,
+            hide_explicit: false,
+            archived: false,
         };
         let podcast = Podcast::new(
             "Rustacean Station".to_string(),
@@ -794,6 +1021,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: Some("chris".to_string()),
             tag_filter: None,
+            hide_explicit: false,
+            archived: false,
         };
         let mut podcast =
             Podcast::new("Some Podcast".to_string(), "http://example.com".to_string());
@@ -806,6 +1035,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: Some("xyzzy".to_string()),
             tag_filter: None,
+            hide_explicit: false,
+            archived: false,
         };
         let podcast = Podcast::new(
             "Normal Podcast".to_string(),
@@ -819,6 +1050,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: Some("rust".to_string()),
             tag_filter: None,
+            hide_explicit: false,
+            archived: false,
         };
         assert_eq!(filter.description(), "search: \"rust\"");
     }
@@ -828,6 +1061,8 @@ mod tests {
         let mut filter = PodcastFilter {
             text_query: Some("rust".to_string()),
             tag_filter: Some("tech".to_string()),
+            hide_explicit: false,
+            archived: false,
         };
         filter.clear();
         assert!(!filter.is_active());
@@ -839,6 +1074,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: None,
             tag_filter: Some("tech".to_string()),
+            hide_explicit: false,
+            archived: false,
         };
         let mut podcast = Podcast::new("Tech Show".to_string(), "http://example.com".to_string());
         podcast.add_tag("tech");
@@ -853,6 +1090,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: None,
             tag_filter: Some("tech".to_string()),
+            hide_explicit: false,
+            archived: false,
         };
         let podcast = Podcast::new("News Show".to_string(), "http://example.com".to_string());
 
@@ -866,6 +1105,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: None,
             tag_filter: Some("news".to_string()),
+            hide_explicit: false,
+            archived: false,
         };
 
         // Assert
@@ -879,6 +1120,8 @@ mod tests {
         let filter = PodcastFilter {
             text_query: Some("rust".to_string()),
             tag_filter: Some("tech".to_string()),
+            hide_explicit: false,
+            archived: false,
         };
         let mut matching = Podcast::new(
             "Rustacean Station".to_string(),
@@ -902,20 +1145,20 @@ mod tests {
 
     #[test]
     fn test_episode_status_filter_all_variants() {
-        assert!(EpisodeStatusFilter::New.matches(&EpisodeStatus::New));
-        assert!(!EpisodeStatusFilter::New.matches(&EpisodeStatus::Downloaded));
+        assert!(EpisodeStatusFilter::New.matches(&EpisodeStatus::default()));
+        assert!(!EpisodeStatusFilter::New.matches(&EpisodeStatus::downloaded()));
 
-        assert!(EpisodeStatusFilter::Downloaded.matches(&EpisodeStatus::Downloaded));
-        assert!(!EpisodeStatusFilter::Downloaded.matches(&EpisodeStatus::New));
+        assert!(EpisodeStatusFilter::Downloaded.matches(&EpisodeStatus::downloaded()));
+        assert!(!EpisodeStatusFilter::Downloaded.matches(&EpisodeStatus::default()));
 
-        assert!(EpisodeStatusFilter::Played.matches(&EpisodeStatus::Played));
-        assert!(!EpisodeStatusFilter::Played.matches(&EpisodeStatus::New));
+        assert!(EpisodeStatusFilter::Played.matches(&EpisodeStatus::played()));
+        assert!(!EpisodeStatusFilter::Played.matches(&EpisodeStatus::default()));
 
-        assert!(EpisodeStatusFilter::Downloading.matches(&EpisodeStatus::Downloading));
-        assert!(!EpisodeStatusFilter::Downloading.matches(&EpisodeStatus::New));
+        assert!(EpisodeStatusFilter::Downloading.matches(&EpisodeStatus::downloading()));
+        assert!(!EpisodeStatusFilter::Downloading.matches(&EpisodeStatus::default()));
 
-        assert!(EpisodeStatusFilter::DownloadFailed.matches(&EpisodeStatus::DownloadFailed));
-        assert!(!EpisodeStatusFilter::DownloadFailed.matches(&EpisodeStatus::New));
+        assert!(EpisodeStatusFilter::DownloadFailed.matches(&EpisodeStatus::download_failed()));
+        assert!(!EpisodeStatusFilter::DownloadFailed.matches(&EpisodeStatus::default()));
     }
 
     // --- Duration boundary tests ---
@@ -972,7 +1215,7 @@ mod tests {
     #[test]
     fn test_favorites_filter_inactive_matches_all() {
         let filter = EpisodeFilter::default();
-        let mut ep = make_episode("Ep", EpisodeStatus::New, None);
+        let mut ep = make_episode("Ep", EpisodeStatus::default(), None);
         ep.favorited = false;
         assert!(filter.matches(&ep));
 
@@ -987,11 +1230,11 @@ mod tests {
             ..Default::default()
         };
 
-        let mut ep_fav = make_episode("Favorited", EpisodeStatus::New, None);
+        let mut ep_fav = make_episode("Favorited", EpisodeStatus::default(), None);
         ep_fav.favorited = true;
         assert!(filter.matches(&ep_fav));
 
-        let ep_not_fav = make_episode("Not Favorited", EpisodeStatus::New, None);
+        let ep_not_fav = make_episode("Not Favorited", EpisodeStatus::default(), None);
         assert!(!filter.matches(&ep_not_fav));
     }
 
@@ -1032,15 +1275,177 @@ mod tests {
             ..Default::default()
         };
 
-        let mut ep = make_episode("Ep", EpisodeStatus::Downloaded, None);
+        let mut ep = make_episode("Ep", EpisodeStatus::downloaded(), None);
         ep.favorited = true;
         assert!(filter.matches(&ep)); // downloaded AND favorited
 
         ep.favorited = false;
         assert!(!filter.matches(&ep)); // downloaded but NOT favorited
 
-        let mut ep2 = make_episode("Ep2", EpisodeStatus::New, None);
+        let mut ep2 = make_episode("Ep2", EpisodeStatus::default(), None);
         ep2.favorited = true;
         assert!(!filter.matches(&ep2)); // favorited but NOT downloaded
     }
+
+    #[test]
+    fn test_hide_explicit_excludes_explicit_episodes_and_survives_clear() {
+        // Arrange
+        let mut filter = EpisodeFilter {
+            hide_explicit: true,
+            favorites_only: true,
+            ..Default::default()
+        };
+        let mut explicit = make_episode("Late Show", EpisodeStatus::default(), None);
+        explicit.explicit = true;
+        explicit.favorited = true;
+        let mut clean = make_episode("Morning Show", EpisodeStatus::default(), None);
+        clean.favorited = true;
+
+        // Act
+        filter.clear();
+
+        // Assert — config-driven, so not an "active" user filter
+        assert!(!filter.is_active());
+        assert!(filter.hide_explicit);
+        assert!(!filter.matches(&explicit));
+        assert!(filter.matches(&clean));
+    }
+
+    #[test]
+    fn test_podcast_filter_hide_explicit() {
+        // Arrange
+        let mut filter = PodcastFilter {
+            text_query: Some("show".to_string()),
+            tag_filter: None,
+            hide_explicit: true,
+            archived: false,
+        };
+        let mut podcast = Podcast::new("Late Show".to_string(), "http://example.com".to_string());
+
+        // Act / Assert
+        assert!(filter.matches(&podcast));
+        podcast.explicit = true;
+        assert!(!filter.matches(&podcast));
+        filter.clear();
+        assert!(!filter.matches(&podcast), "clear keeps the config flag");
+    }
+
+    // --- WhatsNewExclusions tests ---
+
+    fn exclusion(podcast: Option<&str>, pattern: Option<&str>) -> WhatsNewExclusion {
+        WhatsNewExclusion {
+            podcast: podcast.map(str::to_string),
+            title_pattern: pattern.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_exclusions_global_pattern_and_whole_podcast() {
+        // Arrange
+        let (rules, errors) = WhatsNewExclusions::compile(&[
+            exclusion(None, Some("^(Teaser|Preview)")),
+            exclusion(Some("daily rebroadcasts"), None),
+        ]);
+        let news = Podcast::new(
+            "News Hour".to_string(),
+            "https://a.example/feed".to_string(),
+        );
+        let reruns = Podcast::new(
+            "Daily Rebroadcasts".to_string(),
+            "https://b.example/feed".to_string(),
+        );
+
+        // Assert
+        assert!(errors.is_empty());
+        assert!(rules.excludes(&news, "Teaser: next week"));
+        assert!(!rules.excludes(&news, "Episode 12: no teaser here"));
+        assert!(rules.excludes(&reruns, "Anything at all"));
+    }
+
+    #[test]
+    fn test_exclusions_pattern_scoped_to_podcast_by_url() {
+        let (rules, _) = WhatsNewExclusions::compile(&[exclusion(
+            Some("https://a.example/feed"),
+            Some("(?i)rebroadcast"),
+        )]);
+        let scoped = Podcast::new(
+            "News Hour".to_string(),
+            "https://a.example/feed".to_string(),
+        );
+        let other = Podcast::new("Other".to_string(), "https://c.example/feed".to_string());
+
+        assert!(rules.excludes(&scoped, "REBROADCAST: best of"));
+        assert!(!rules.excludes(&other, "Rebroadcast: best of"));
+    }
+
+    #[test]
+    fn test_exclusions_report_invalid_rules() {
+        let (rules, errors) = WhatsNewExclusions::compile(&[
+            exclusion(None, Some("(unclosed")),
+            exclusion(Some("  "), None),
+        ]);
+
+        assert!(rules.is_empty());
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("Invalid title pattern '(unclosed'"));
+    }
+
+    // --- KeywordAlerts tests ---
+
+    #[test]
+    fn test_keyword_alerts_match_text_and_regex_in_any_field() {
+        // Arrange
+        let (alerts, errors) = KeywordAlerts::compile(&[
+            "RustConf".to_string(),
+            "/\\bsprings?field\\b/".to_string(),
+            "c++".to_string(),
+        ]);
+        let mut described = make_episode("Weekly roundup", EpisodeStatus::default(), None);
+        described.description = Some("Live from rustconf 2026".to_string());
+        let mut transcribed = make_episode("Local news", EpisodeStatus::default(), None);
+        transcribed.transcript = Some("...back in Springfield today...".to_string());
+        let literal = make_episode("Modern C++ in practice", EpisodeStatus::default(), None);
+        let unrelated = make_episode("Springfielders unite", EpisodeStatus::default(), None);
+
+        // Assert
+        assert!(errors.is_empty());
+        assert_eq!(alerts.matching_keyword(&described), Some("RustConf"));
+        assert_eq!(
+            alerts.matching_keyword(&transcribed),
+            Some("/\\bsprings?field\\b/")
+        );
+        assert_eq!(alerts.matching_keyword(&literal), Some("c++"));
+        assert_eq!(alerts.matching_keyword(&unrelated), None);
+    }
+
+    #[test]
+    fn test_keyword_alerts_report_invalid_keywords() {
+        let (alerts, errors) =
+            KeywordAlerts::compile(&["/(unclosed/".to_string(), "  ".to_string()]);
+
+        assert!(alerts.is_empty());
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("Invalid alert keyword '/(unclosed/'"));
+    }
+
+    #[test]
+    fn test_language_filter_matches_primary_subtag() {
+        let filter = EpisodeFilter {
+            languages: vec!["EN".to_string(), "pt-br".to_string()],
+            ..Default::default()
+        };
+        let episode_in = |language: Option<&str>| {
+            let mut episode = make_episode("Episode", EpisodeStatus::default(), None);
+            episode.language = language.map(str::to_string);
+            episode
+        };
+
+        assert!(filter.matches(&episode_in(Some("en-US"))));
+        assert!(filter.matches(&episode_in(Some("pt_PT"))));
+        assert!(!filter.matches(&episode_in(Some("de"))));
+        // Episodes whose feed doesn't say stay visible
+        assert!(filter.matches(&episode_in(None)));
+        assert!(!filter.is_active(), "a preference, not a filter to clear");
+        assert!(EpisodeFilter::default().matches(&episode_in(Some("de"))));
+    }
 }
@@ -0,0 +1,305 @@
+// Status glyphs - shared episode/download status icons
+//
+// Every list buffer renders episode and download state through this module so
+// that the same state always looks the same, is colored by the active theme,
+// and can fall back to plain ASCII on terminals without good Unicode fonts.
+
+use ratatui::{style::Style, text::Span};
+
+use crate::download::DownloadStatus;
+use crate::podcast::{DownloadState, Episode, PlayState};
+use crate::ui::themes::Theme;
+
+/// Which character set to draw status glyphs with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphSet {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl GlyphSet {
+    /// Pick the glyph set from the `ui.ascii_glyphs` config switch
+    pub fn from_ascii_flag(ascii_only: bool) -> Self {
+        if ascii_only {
+            Self::Ascii
+        } else {
+            Self::Unicode
+        }
+    }
+}
+
+/// A displayable episode or download state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusGlyph {
+    New,
+    InProgress,
+    Played,
+    Downloading,
+    Downloaded,
+    Failed,
+    Queued,
+}
+
+impl StatusGlyph {
+    /// All glyphs in legend order
+    pub const ALL: [StatusGlyph; 7] = [
+        StatusGlyph::New,
+        StatusGlyph::InProgress,
+        StatusGlyph::Played,
+        StatusGlyph::Queued,
+        StatusGlyph::Downloading,
+        StatusGlyph::Downloaded,
+        StatusGlyph::Failed,
+    ];
+
+    /// Derive the glyph for an episode. A partially listened episode that
+    /// hasn't been marked played shows as in-progress.
+    pub fn for_episode(episode: &Episode) -> Self {
+        let started = episode.status.play == PlayState::InProgress
+            || episode.last_played_position.is_some_and(|p| p > 0);
+        match episode.status.download {
+            _ if episode.status.play == PlayState::Played => Self::Played,
+            DownloadState::Downloading => Self::Downloading,
+            DownloadState::Failed => Self::Failed,
+            _ if started => Self::InProgress,
+            DownloadState::Downloaded => Self::Downloaded,
+            DownloadState::NotDownloaded => Self::New,
+        }
+    }
+
+    /// Derive the glyph for a download queue entry
+    pub fn for_download(status: &DownloadStatus) -> Self {
+        match status {
+            DownloadStatus::Queued => Self::Queued,
+            DownloadStatus::InProgress => Self::Downloading,
+            DownloadStatus::Completed => Self::Downloaded,
+            DownloadStatus::Failed(_) => Self::Failed,
+        }
+    }
+
+    /// The symbol to draw. ASCII symbols are single characters so column alignment
+    /// is identical between the two sets.
+    pub fn symbol(self, set: GlyphSet) -> &'static str {
+        match (set, self) {
+            (GlyphSet::Unicode, Self::New) => "○",
+            (GlyphSet::Unicode, Self::InProgress) => "◔",
+            (GlyphSet::Unicode, Self::Played) => "✓",
+            (GlyphSet::Unicode, Self::Downloading) => "◐",
+            (GlyphSet::Unicode, Self::Downloaded) => "●",
+            (GlyphSet::Unicode, Self::Failed) => "✗",
+            (GlyphSet::Unicode, Self::Queued) => "…",
+            (GlyphSet::Ascii, Self::New) => "o",
+            (GlyphSet::Ascii, Self::InProgress) => ">",
+            (GlyphSet::Ascii, Self::Played) => "v",
+            (GlyphSet::Ascii, Self::Downloading) => "~",
+            (GlyphSet::Ascii, Self::Downloaded) => "*",
+            (GlyphSet::Ascii, Self::Failed) => "x",
+            (GlyphSet::Ascii, Self::Queued) => ".",
+        }
+    }
+
+    /// Human-readable name shown in the legend
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::InProgress => "In progress",
+            Self::Played => "Played",
+            Self::Downloading => "Downloading",
+            Self::Downloaded => "Downloaded",
+            Self::Failed => "Failed",
+            Self::Queued => "Queued",
+        }
+    }
+
+    /// Theme style for this glyph
+    pub fn style(self, theme: &Theme) -> Style {
+        let colors = &theme.colors;
+        let color = match self {
+            Self::New => colors.primary,
+            Self::InProgress => colors.paused,
+            Self::Played => colors.muted,
+            Self::Downloading => colors.downloading,
+            Self::Downloaded => colors.downloaded,
+            Self::Failed => colors.error,
+            Self::Queued => colors.queued,
+        };
+        Style::default().fg(color)
+    }
+}
+
+/// Warning glyph for episodes without a playable audio URL
+pub fn missing_audio_symbol(set: GlyphSet) -> &'static str {
+    match set {
+        GlyphSet::Unicode => "⚠",
+        GlyphSet::Ascii => "!",
+    }
+}
+
+/// Marker for downloads whose audio the feed has since replaced
+pub fn updated_audio_symbol(set: GlyphSet) -> &'static str {
+    match set {
+        GlyphSet::Unicode => "↻",
+        GlyphSet::Ascii => "@",
+    }
+}
+
+/// Favorite marker shown before an episode title
+pub fn favorite_symbol(set: GlyphSet) -> &'static str {
+    match set {
+        GlyphSet::Unicode => "★",
+        GlyphSet::Ascii => "+",
+    }
+}
+
+/// True when a new episode has nothing playable (no enclosure and no URL-like GUID)
+pub fn is_missing_audio(episode: &Episode) -> bool {
+    episode.status.is_new()
+        && episode.audio_url.is_empty()
+        && !episode.guid.as_ref().is_some_and(|g| g.starts_with("http"))
+}
+
+/// Styled status span for an episode row, including the missing-audio warning
+pub fn episode_span(episode: &Episode, theme: &Theme) -> Span<'static> {
+    if is_missing_audio(episode) {
+        Span::styled(
+            missing_audio_symbol(theme.glyphs),
+            Style::default().fg(theme.colors.warning),
+        )
+    } else {
+        let glyph = StatusGlyph::for_episode(episode);
+        Span::styled(theme.glyph(glyph), theme.glyph_style(glyph))
+    }
+}
+
+/// Legend lines (`symbol  label`) for the active glyph set
+pub fn legend_lines(set: GlyphSet) -> Vec<(StatusGlyph, String)> {
+    StatusGlyph::ALL
+        .iter()
+        .map(|g| (*g, format!("{}  {}", g.symbol(set), g.label())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::podcast::EpisodeStatus;
+    use crate::storage::PodcastId;
+    use chrono::Utc;
+
+    fn make_episode(status: EpisodeStatus) -> Episode {
+        let mut episode = Episode::new(
+            PodcastId::new(),
+            "Episode".to_string(),
+            "https://example.com/ep.mp3".to_string(),
+            Utc::now(),
+        );
+        episode.status = status;
+        episode
+    }
+
+    #[test]
+    fn test_for_episode_maps_statuses() {
+        assert_eq!(
+            StatusGlyph::for_episode(&make_episode(EpisodeStatus::default())),
+            StatusGlyph::New
+        );
+        assert_eq!(
+            StatusGlyph::for_episode(&make_episode(EpisodeStatus::downloaded())),
+            StatusGlyph::Downloaded
+        );
+        assert_eq!(
+            StatusGlyph::for_episode(&make_episode(EpisodeStatus::played())),
+            StatusGlyph::Played
+        );
+        assert_eq!(
+            StatusGlyph::for_episode(&make_episode(EpisodeStatus::downloading())),
+            StatusGlyph::Downloading
+        );
+        assert_eq!(
+            StatusGlyph::for_episode(&make_episode(EpisodeStatus::download_failed())),
+            StatusGlyph::Failed
+        );
+    }
+
+    #[test]
+    fn test_for_episode_partial_playback_is_in_progress() {
+        // Arrange
+        let mut episode = make_episode(EpisodeStatus::downloaded());
+        episode.last_played_position = Some(120);
+
+        // Act / Assert
+        assert_eq!(StatusGlyph::for_episode(&episode), StatusGlyph::InProgress);
+
+        // Played wins over a leftover position
+        episode.status.play = PlayState::Played;
+        assert_eq!(StatusGlyph::for_episode(&episode), StatusGlyph::Played);
+    }
+
+    #[test]
+    fn test_for_download_maps_statuses() {
+        assert_eq!(
+            StatusGlyph::for_download(&DownloadStatus::Queued),
+            StatusGlyph::Queued
+        );
+        assert_eq!(
+            StatusGlyph::for_download(&DownloadStatus::Failed("x".to_string())),
+            StatusGlyph::Failed
+        );
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_single_ascii_chars() {
+        for glyph in StatusGlyph::ALL {
+            let symbol = glyph.symbol(GlyphSet::Ascii);
+            assert_eq!(symbol.len(), 1, "{:?}", glyph);
+            assert!(symbol.is_ascii());
+        }
+        assert!(missing_audio_symbol(GlyphSet::Ascii).is_ascii());
+        assert!(favorite_symbol(GlyphSet::Ascii).is_ascii());
+    }
+
+    #[test]
+    fn test_glyph_symbols_are_distinct_within_a_set() {
+        for set in [GlyphSet::Unicode, GlyphSet::Ascii] {
+            let mut symbols: Vec<_> = StatusGlyph::ALL.iter().map(|g| g.symbol(set)).collect();
+            symbols.sort_unstable();
+            symbols.dedup();
+            assert_eq!(symbols.len(), StatusGlyph::ALL.len());
+        }
+    }
+
+    #[test]
+    fn test_glyph_style_follows_theme() {
+        // Arrange
+        let theme = Theme::default_dark();
+
+        // Act
+        let style = StatusGlyph::Failed.style(&theme);
+
+        // Assert
+        assert_eq!(style.fg, Some(theme.colors.error));
+    }
+
+    #[test]
+    fn test_episode_span_flags_missing_audio() {
+        // Arrange
+        let mut episode = make_episode(EpisodeStatus::default());
+        episode.audio_url.clear();
+        let theme = Theme::default_dark().with_glyphs(GlyphSet::Ascii);
+
+        // Act
+        let span = episode_span(&episode, &theme);
+
+        // Assert
+        assert_eq!(span.content, "!");
+        assert_eq!(span.style.fg, Some(theme.colors.warning));
+    }
+
+    #[test]
+    fn test_legend_lists_every_glyph() {
+        let lines = legend_lines(GlyphSet::Ascii);
+        assert_eq!(lines.len(), StatusGlyph::ALL.len());
+        assert!(lines.iter().any(|(_, l)| l == "x  Failed"));
+    }
+}
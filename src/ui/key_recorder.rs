@@ -0,0 +1,186 @@
+// Key recorder - `:record-keys` diagnostic log for bug reports
+//
+// While recording, every key press is written to a plain-text file in
+// `key-recordings/` under the data directory: the key, the action it resolved
+// to, and the buffer before and after (with the position the user was looking
+// at). Attaching the file to a "pressing X in buffer Y does nothing" report
+// makes it reproducible. Text typed into the minibuffer is not recorded.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::Utc;
+
+/// Directory under the data directory that recordings are written to
+pub const KEY_RECORDINGS_DIR: &str = "key-recordings";
+
+/// What the active buffer looked like around a key press
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedBufferState {
+    pub buffer_id: String,
+    pub kind: &'static str,
+    /// e.g. "3/40", from the buffer's scroll position
+    pub position: Option<String>,
+}
+
+impl RecordedBufferState {
+    fn describe(&self) -> String {
+        match &self.position {
+            Some(position) => format!("{} [{} {}]", self.buffer_id, self.kind, position),
+            None => format!("{} [{}]", self.buffer_id, self.kind),
+        }
+    }
+}
+
+/// One recorded key press
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRecord {
+    pub key: String,
+    pub action: String,
+    pub before: Option<RecordedBufferState>,
+    pub after: Option<RecordedBufferState>,
+    /// Minibuffer text after the key was handled
+    pub message: String,
+}
+
+impl KeyRecord {
+    /// One log line; `elapsed` is the time since recording started
+    fn format(&self, elapsed: f64) -> String {
+        let describe = |state: &Option<RecordedBufferState>| {
+            state
+                .as_ref()
+                .map_or("-".to_string(), RecordedBufferState::describe)
+        };
+        let mut line = format!(
+            "+{:>8.3}s  {:<12} {:<28} {} -> {}",
+            elapsed,
+            self.key,
+            self.action,
+            describe(&self.before),
+            describe(&self.after)
+        );
+        if !self.message.is_empty() {
+            line.push_str(&format!("  \"{}\"", self.message));
+        }
+        line
+    }
+}
+
+/// An open recording
+pub struct KeyRecorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    started: Instant,
+    keys: usize,
+}
+
+impl KeyRecorder {
+    /// Start a new recording in `data_dir/key-recordings/`
+    pub fn start(data_dir: &Path) -> io::Result<Self> {
+        let dir = data_dir.join(KEY_RECORDINGS_DIR);
+        fs::create_dir_all(&dir)?;
+        let now = Utc::now();
+        let path = dir.join(format!("keys-{}.log", now.format("%Y%m%d-%H%M%S")));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        writeln!(
+            writer,
+            "# podcast-tui {} key recording, started {}",
+            env!("CARGO_PKG_VERSION"),
+            now.format("%Y-%m-%d %H:%M:%S UTC")
+        )?;
+        writeln!(
+            writer,
+            "# {} on {}; minibuffer text is shown as <text>",
+            std::env::consts::OS,
+            std::env::var("TERM").unwrap_or_else(|_| "unknown terminal".to_string())
+        )?;
+        writeln!(writer, "# time       key          action                       buffer before -> buffer after  \"message\"")?;
+        writer.flush()?;
+
+        Ok(Self {
+            path,
+            writer,
+            started: Instant::now(),
+            keys: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keys recorded so far
+    pub fn keys(&self) -> usize {
+        self.keys
+    }
+
+    /// Append a key press, flushed right away so a crash keeps it
+    pub fn record(&mut self, record: &KeyRecord) -> io::Result<()> {
+        let line = record.format(self.started.elapsed().as_secs_f64());
+        writeln!(self.writer, "{}", line)?;
+        self.writer.flush()?;
+        self.keys += 1;
+        Ok(())
+    }
+
+    /// Close the recording and return its path
+    pub fn finish(mut self) -> io::Result<PathBuf> {
+        writeln!(self.writer, "# {} key(s) recorded", self.keys)?;
+        self.writer.flush()?;
+        Ok(self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn state(buffer_id: &str, position: Option<&str>) -> Option<RecordedBufferState> {
+        Some(RecordedBufferState {
+            buffer_id: buffer_id.to_string(),
+            kind: "Podcasts",
+            position: position.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_recording_writes_header_keys_and_footer() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let mut recorder = KeyRecorder::start(dir.path()).unwrap();
+
+        // Act
+        recorder
+            .record(&KeyRecord {
+                key: "j".to_string(),
+                action: "MoveDown".to_string(),
+                before: state("podcast-list", Some("1/3")),
+                after: state("podcast-list", None),
+                message: String::new(),
+            })
+            .unwrap();
+        recorder
+            .record(&KeyRecord {
+                key: "X".to_string(),
+                action: "None".to_string(),
+                before: state("podcast-list", Some("2/3")),
+                after: state("podcast-list", None),
+                message: "Not available".to_string(),
+            })
+            .unwrap();
+        let path = recorder.finish().unwrap();
+
+        // Assert
+        assert!(path.starts_with(dir.path().join(KEY_RECORDINGS_DIR)));
+        let log = fs::read_to_string(path).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        assert!(lines[0].starts_with("# podcast-tui "));
+        assert!(lines[3].contains("j            MoveDown"));
+        assert!(lines[3].ends_with("podcast-list [Podcasts 1/3] -> podcast-list [Podcasts]"));
+        assert!(lines[4].ends_with("\"Not available\""));
+        assert_eq!(lines.last(), Some(&"# 2 key(s) recorded"));
+    }
+}
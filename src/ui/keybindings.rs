@@ -115,6 +115,7 @@ impl KeyHandler {
         self.bind_key(KeyChord::none(KeyCode::End), UIAction::MoveToBottom);
         self.bind_key(KeyChord::ctrl(KeyCode::Up), UIAction::MoveEpisodeUp);
         self.bind_key(KeyChord::ctrl(KeyCode::Down), UIAction::MoveEpisodeDown);
+        self.bind_key(KeyChord::ctrl(KeyCode::Home), UIAction::MoveEpisodeToTop);
 
         // Vim-style navigation aliases
         self.bind_key(KeyChord::none(KeyCode::Char('j')), UIAction::MoveDown);
@@ -167,12 +168,22 @@ impl KeyHandler {
         self.bind_key(KeyChord::none(KeyCode::Char('s')), UIAction::SyncToDevice);
         self.bind_key(KeyChord::none(KeyCode::Char('c')), UIAction::CreatePlaylist);
         self.bind_key(KeyChord::none(KeyCode::Char('p')), UIAction::AddToPlaylist);
+        self.bind_key(
+            KeyChord::alt(KeyCode::Char('p')),
+            UIAction::AddAllToPlaylist,
+        );
         self.bind_key(KeyChord::none(KeyCode::Char('r')), UIAction::RefreshPodcast);
         self.bind_key(KeyChord::shift(KeyCode::Char('R')), UIAction::RefreshAll);
+        self.bind_key(KeyChord::alt(KeyCode::Char('r')), UIAction::RefreshFiltered);
         self.bind_key(
             KeyChord::ctrl(KeyCode::Char('r')),
             UIAction::HardRefreshPodcast,
         );
+        // M-i re-imports the feeds the last OPML import couldn't add
+        self.bind_key(
+            KeyChord::alt(KeyCode::Char('i')),
+            UIAction::ExecuteCommand("retry-failed-imports".to_string()),
+        );
         self.bind_key(
             KeyChord::shift(KeyCode::Char('D')),
             UIAction::DownloadEpisode,
@@ -195,6 +206,10 @@ impl KeyHandler {
         self.bind_key(KeyChord::none(KeyCode::Char('?')), UIAction::ShowHelp);
         // Also bind with shift modifier in case some terminals report it that way
         self.bind_key(KeyChord::shift(KeyCode::Char('?')), UIAction::ShowHelp);
+        self.bind_key(
+            KeyChord::shift(KeyCode::Char('L')),
+            UIAction::ShowGlyphLegend,
+        );
         // Bind ':' without modifiers (crossterm handles the shift automatically for the char)
         self.bind_key(KeyChord::none(KeyCode::Char(':')), UIAction::PromptCommand);
         // Also bind with shift modifier in case some terminals report it that way
@@ -212,6 +227,48 @@ impl KeyHandler {
             UIAction::ExecuteCommand("list-buffers".to_string()),
         );
 
+        // Podcast details and activity timeline
+        self.bind_key(
+            KeyChord::none(KeyCode::Char('i')),
+            UIAction::ExecuteCommand("podcast-info".to_string()),
+        );
+
+        // Per-podcast settings of the podcast under the cursor
+        self.bind_key(
+            KeyChord::none(KeyCode::Char(',')),
+            UIAction::ExecuteCommand("podcast-settings".to_string()),
+        );
+
+        // Raw show notes in the episode detail view
+        self.bind_key(
+            KeyChord::none(KeyCode::Char('v')),
+            UIAction::ExecuteCommand("view-raw".to_string()),
+        );
+
+        // Switch to (or start) a workspace of buffers
+        self.bind_key(
+            KeyChord::alt(KeyCode::Char('w')),
+            UIAction::ExecuteCommand("workspace".to_string()),
+        );
+
+        // Hide everything but errors, e.g. while screen-sharing
+        self.bind_key(
+            KeyChord::none(KeyCode::F(12)),
+            UIAction::ExecuteCommand("focus".to_string()),
+        );
+
+        // Replace a download whose audio the feed has since updated
+        self.bind_key(
+            KeyChord::shift(KeyCode::Char('U')),
+            UIAction::ExecuteCommand("redownload".to_string()),
+        );
+
+        // More episodes with someone credited in a detail view
+        self.bind_key(
+            KeyChord::none(KeyCode::Char('f')),
+            UIAction::ExecuteCommand("search-person".to_string()),
+        );
+
         // Close current buffer
         self.bind_key(
             KeyChord::ctrl(KeyCode::Char('k')),
@@ -221,6 +278,7 @@ impl KeyHandler {
         // OPML Import/Export
         self.bind_key(KeyChord::shift(KeyCode::Char('A')), UIAction::ImportOpml);
         self.bind_key(KeyChord::shift(KeyCode::Char('E')), UIAction::ExportOpml);
+        self.bind_key(KeyChord::ctrl(KeyCode::Char('g')), UIAction::CancelImport);
 
         // Episode status
         self.bind_key(KeyChord::none(KeyCode::Char('m')), UIAction::MarkPlayed);
@@ -238,8 +296,12 @@ impl KeyHandler {
             UIAction::ToggleSortDirection,
         );
 
-        // Search and filter
+        // Search and filter — '/' filters the current buffer, C-s searches every episode
         self.bind_key(KeyChord::none(KeyCode::Char('/')), UIAction::Search);
+        self.bind_key(
+            KeyChord::ctrl(KeyCode::Char('s')),
+            UIAction::ExecuteCommand("search-all".to_string()),
+        );
 
         // F6 clears active filters; F9 opens the NowPlaying buffer
         self.bind_key(KeyChord::none(KeyCode::F(6)), UIAction::ClearFilters);
@@ -261,6 +323,7 @@ impl KeyHandler {
         // '=' shares a physical key with '+' on US keyboards — bind both
         self.bind_key(KeyChord::none(KeyCode::Char('=')), UIAction::VolumeUp);
         self.bind_key(KeyChord::none(KeyCode::Char('-')), UIAction::VolumeDown);
+        self.bind_key(KeyChord::shift(KeyCode::Char('M')), UIAction::ToggleMute);
 
         // Audio play/pause — 'P' (Shift+P) is mnemonic, free, and consistent with
         // TUI media players like cmus/ncmpcpp. Lowercase 'p' is AddToPlaylist.
@@ -277,6 +340,29 @@ impl KeyHandler {
             KeyChord::none(KeyCode::Media(MediaKeyCode::Play)),
             UIAction::TogglePlayPause,
         );
+        self.bind_key(
+            KeyChord::none(KeyCode::Media(MediaKeyCode::MuteVolume)),
+            UIAction::ToggleMute,
+        );
+
+        // 'B' bookmarks the playing episode at its current position
+        self.bind_key(
+            KeyChord::shift(KeyCode::Char('B')),
+            UIAction::ExecuteCommand("bookmark".to_string()),
+        );
+
+        // 'C' archives the shows that have stopped publishing
+        self.bind_key(
+            KeyChord::shift(KeyCode::Char('C')),
+            UIAction::ExecuteCommand("archive-ended".to_string()),
+        );
+
+        // Playback queue — 'e' enqueues the selected episode, 'Q' shows the queue
+        self.bind_key(KeyChord::none(KeyCode::Char('e')), UIAction::EnqueueEpisode);
+        self.bind_key(
+            KeyChord::shift(KeyCode::Char('Q')),
+            UIAction::ExecuteCommand("queue".to_string()),
+        );
 
         // Play episode — S-Enter (Shift+Enter) plays the selected downloaded episode.
         // Stores nil placeholder IDs; the episode list buffer replaces them with real
@@ -323,6 +409,17 @@ impl KeyHandler {
         self.bindings.get(chord)
     }
 
+    /// Shortest key notation bound to an action of the same kind as `action`
+    /// (fields are ignored), for on-screen hints.
+    pub fn key_for(&self, action: &UIAction) -> Option<String> {
+        let kind = std::mem::discriminant(action);
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| std::mem::discriminant(*bound) == kind)
+            .map(|(chord, _)| key_to_notation(chord))
+            .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+    }
+
     /// Build a `KeyHandler` from a `KeybindingConfig`.
     ///
     /// 1. Starts with all default bindings (from `new()`).
@@ -360,6 +457,10 @@ impl KeyHandler {
             KeyChord::none(KeyCode::Media(MediaKeyCode::Play)),
             UIAction::TogglePlayPause,
         );
+        handler.bind_key(
+            KeyChord::none(KeyCode::Media(MediaKeyCode::MuteVolume)),
+            UIAction::ToggleMute,
+        );
 
         handler
     }
@@ -409,6 +510,7 @@ impl KeyHandler {
         self.override_binding(&keys.move_to_bottom, UIAction::MoveToBottom);
         self.override_binding(&keys.move_episode_up, UIAction::MoveEpisodeUp);
         self.override_binding(&keys.move_episode_down, UIAction::MoveEpisodeDown);
+        self.override_binding(&keys.move_episode_to_top, UIAction::MoveEpisodeToTop);
 
         // Buffer navigation
         self.override_binding(&keys.next_buffer, UIAction::NextBuffer);
@@ -428,6 +530,7 @@ impl KeyHandler {
         // Application control
         self.override_binding(&keys.quit, UIAction::Quit);
         self.override_binding(&keys.show_help, UIAction::ShowHelp);
+        self.override_binding(&keys.show_legend, UIAction::ShowGlyphLegend);
         self.override_binding(&keys.search, UIAction::Search);
         self.override_binding(&keys.clear_filters, UIAction::ClearFilters);
         self.override_binding(&keys.refresh, UIAction::Refresh);
@@ -450,6 +553,7 @@ impl KeyHandler {
         self.override_binding(&keys.delete_podcast, UIAction::DeletePodcast);
         self.override_binding(&keys.refresh_podcast, UIAction::RefreshPodcast);
         self.override_binding(&keys.refresh_all, UIAction::RefreshAll);
+        self.override_binding(&keys.refresh_filtered, UIAction::RefreshFiltered);
         self.override_binding(&keys.hard_refresh_podcast, UIAction::HardRefreshPodcast);
 
         // Episode actions
@@ -468,10 +572,12 @@ impl KeyHandler {
         // Playlist
         self.override_binding(&keys.create_playlist, UIAction::CreatePlaylist);
         self.override_binding(&keys.add_to_playlist, UIAction::AddToPlaylist);
+        self.override_binding(&keys.add_all_to_playlist, UIAction::AddAllToPlaylist);
 
         // OPML
         self.override_binding(&keys.import_opml, UIAction::ImportOpml);
         self.override_binding(&keys.export_opml, UIAction::ExportOpml);
+        self.override_binding(&keys.cancel_import, UIAction::CancelImport);
 
         // Sync
         self.override_binding(&keys.sync_to_device, UIAction::SyncToDevice);
@@ -500,10 +606,13 @@ impl KeyHandler {
                 }
             }
         }
+        self.override_binding(&keys.enqueue_episode, UIAction::EnqueueEpisode);
         self.override_binding(&keys.seek_backward, UIAction::SeekBackward);
         self.override_binding(&keys.seek_forward, UIAction::SeekForward);
         self.override_binding(&keys.volume_up, UIAction::VolumeUp);
         self.override_binding(&keys.volume_down, UIAction::VolumeDown);
+        self.override_binding(&keys.toggle_mute, UIAction::ToggleMute);
+        self.override_binding(&keys.toggle_duck, UIAction::ToggleDuck);
         self.override_binding(
             &keys.open_now_playing,
             UIAction::SwitchBuffer("now-playing".to_string()),
@@ -625,6 +734,15 @@ mod tests {
         assert_eq!(action, UIAction::MoveUp);
     }
 
+    #[test]
+    fn test_key_for_picks_shortest_binding() {
+        let handler = KeyHandler::new();
+
+        assert_eq!(handler.key_for(&UIAction::AddPodcast).as_deref(), Some("a"));
+        assert_eq!(handler.key_for(&UIAction::Quit).as_deref(), Some("q"));
+        assert_eq!(handler.key_for(&UIAction::Render), None);
+    }
+
     #[test]
     fn test_function_key() {
         let mut handler = KeyHandler::new();
@@ -1274,6 +1392,11 @@ mod tests {
             Some(&UIAction::VolumeDown),
             "- must lower volume"
         );
+        assert_eq!(
+            handler.lookup(&KeyChord::shift(KeyCode::Char('M'))),
+            Some(&UIAction::ToggleMute),
+            "S-M must toggle mute"
+        );
     }
 
     #[test]
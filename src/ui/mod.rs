@@ -7,17 +7,24 @@
 // - Command system with minibuffer
 
 pub mod app;
+pub mod background;
 pub mod buffers;
 pub mod components;
 pub mod events;
 pub mod filters;
+pub mod glyphs;
 pub mod key_parser;
+pub mod key_recorder;
 pub mod keybindings;
+pub mod text_layout;
 pub mod theme_loader;
 pub mod themes;
 
+#[cfg(test)]
+mod snapshot_tests;
+
 pub use app::UIApp;
-pub use events::{UIEvent, UIEventHandler};
+pub use events::{PerformanceSettings, UIEvent, UIEventHandler};
 pub use keybindings::KeyHandler;
 
 /// Result type for UI operations
@@ -56,11 +63,13 @@ pub enum UIAction {
     MoveToBottom,
     MoveEpisodeUp,
     MoveEpisodeDown,
+    MoveEpisodeToTop,
 
     // Buffer actions
     SwitchBuffer(String),
     CreateBuffer(String),
     CloseBuffer(String),
+    CloseBuffers(Vec<String>),
     NextBuffer,
     PreviousBuffer,
 
@@ -75,6 +84,7 @@ pub enum UIAction {
     Quit,
     Refresh,
     ShowHelp,
+    ShowGlyphLegend,
     ExecuteCommand(String),
     PromptCommand,
 
@@ -135,6 +145,8 @@ pub enum UIAction {
     TriggerRefreshDownloads,
     RefreshPodcast,
     RefreshAll,
+    /// Refresh the podcasts passing the podcast list's current filter
+    RefreshFiltered,
     /// Hard refresh podcast (re-parse existing episodes)
     HardRefreshPodcast,
 
@@ -147,7 +159,11 @@ pub enum UIAction {
     CreatePlaylist,
     DeletePlaylist,
     AddToPlaylist,
+    /// Add every episode the list shows (after filters and search) to a playlist
+    AddAllToPlaylist,
     RefreshAutoPlaylists,
+    /// Reload a buffer's contents in the background
+    RefreshBuffer(crate::ui::events::BufferRefreshType),
     RebuildPlaylistFiles {
         playlist_id: crate::playlist::PlaylistId,
     },
@@ -160,6 +176,10 @@ pub enum UIAction {
         podcast_id: crate::storage::PodcastId,
         episode_id: crate::storage::EpisodeId,
     },
+    TriggerAddEpisodesToPlaylist {
+        playlist_id: crate::playlist::PlaylistId,
+        episodes: Vec<(crate::storage::PodcastId, crate::storage::EpisodeId)>,
+    },
     TriggerRemoveFromPlaylist {
         playlist_id: crate::playlist::PlaylistId,
         episode_id: crate::storage::EpisodeId,
@@ -172,6 +192,21 @@ pub enum UIAction {
         from_idx: usize,
         to_idx: usize,
     },
+
+    // Playback queue actions
+    /// Add the selected episode to the end of the playback queue
+    EnqueueEpisode,
+    TriggerReorderQueue {
+        from_idx: usize,
+        to_idx: usize,
+    },
+    TriggerRemoveFromQueue {
+        index: usize,
+    },
+    /// Take a queued episode out of the queue and play it now
+    TriggerPlayQueued {
+        index: usize,
+    },
     SyncPlaylist {
         playlist_id: crate::playlist::PlaylistId,
     },
@@ -181,10 +216,14 @@ pub enum UIAction {
     ImportOpml,
     /// Export subscriptions to OPML file
     ExportOpml,
+    /// Stop a running OPML import after the feeds already being fetched
+    CancelImport,
     /// Trigger async OPML import with source path
     TriggerOpmlImport {
         source: String,
     },
+    /// Import the feeds checked in the OPML preview buffer
+    ConfirmOpmlImport,
     /// Trigger async OPML export with output path
     TriggerOpmlExport {
         path: Option<String>,
@@ -311,6 +350,12 @@ pub enum UIAction {
         episode_id: crate::storage::EpisodeId,
         path: std::path::PathBuf,
     },
+    /// Play through a playlist starting at entry `start`, honouring its shuffle / repeat modes
+    PlayPlaylist {
+        playlist_id: crate::playlist::PlaylistId,
+        entries: Vec<crate::playlist::queue::QueueEntry>,
+        start: usize,
+    },
     /// Toggle play/pause for the current track
     TogglePlayPause,
     /// Stop playback and clear the current track
@@ -323,6 +368,23 @@ pub enum UIAction {
     VolumeUp,
     /// Decrease volume by `constants::audio::VOLUME_STEP`
     VolumeDown,
+    /// Silence output / restore the volume from before muting
+    ToggleMute,
+    /// Drop to `constants::audio::DUCK_LEVEL` of the volume, or back to full
+    ToggleDuck,
+    /// Switch the audio output device (`None` = system default) and remember it
+    SelectAudioDevice {
+        device: Option<String>,
+    },
+    /// Play through a network renderer (`None` = this computer)
+    SelectCastTarget {
+        target: Option<crate::audio::cast::CastTarget>,
+    },
+    /// Save a podcast's settings as changed in the podcast settings view
+    SavePodcastSettings {
+        podcast_id: crate::storage::PodcastId,
+        settings: crate::podcast::PodcastSettings,
+    },
 }
 
 impl UIAction {
@@ -341,6 +403,7 @@ impl UIAction {
             UIAction::MoveToBottom => "Move to bottom",
             UIAction::MoveEpisodeUp => "Reorder episode up",
             UIAction::MoveEpisodeDown => "Reorder episode down",
+            UIAction::MoveEpisodeToTop => "Move episode to top",
             // Buffer management
             UIAction::NextBuffer => "Next buffer",
             UIAction::PreviousBuffer => "Previous buffer",
@@ -355,6 +418,7 @@ impl UIAction {
             // Application
             UIAction::Quit => "Quit application",
             UIAction::ShowHelp => "Show help",
+            UIAction::ShowGlyphLegend => "Show status icon legend",
             UIAction::Search => "Search",
             UIAction::ClearFilters => "Clear filters",
             UIAction::Refresh => "Refresh current buffer",
@@ -364,6 +428,17 @@ impl UIAction {
             UIAction::ExecuteCommand(cmd) => match cmd.as_str() {
                 "switch-to-buffer" => "Switch to buffer by name",
                 "list-buffers" => "List all buffers",
+                "workspace" => "Switch workspace",
+                "podcast-info" => "Show podcast details and activity",
+                "podcast-settings" => "Edit the podcast's own settings",
+                "view-raw" => "Toggle raw show notes",
+                "queue" => "Show playback queue",
+                "bookmark" => "Bookmark the playing episode",
+                "search-all" => "Search all episodes",
+                "search-person" => "Find episodes with a credited person",
+                "redownload" => "Re-download updated audio",
+                "focus" => "Toggle focus mode (errors only)",
+                "retry-failed-imports" => "Retry failed OPML imports",
                 _ => "",
             },
             // Podcast management
@@ -374,6 +449,7 @@ impl UIAction {
             UIAction::DeleteAllDownloads => "Delete all downloaded episodes",
             UIAction::RefreshPodcast => "Refresh selected podcast",
             UIAction::RefreshAll => "Refresh all podcasts",
+            UIAction::RefreshFiltered => "Refresh filtered podcasts",
             UIAction::HardRefreshPodcast => "Hard refresh podcast (re-parse episodes)",
             // Episode status
             UIAction::MarkPlayed => "Mark episode as played",
@@ -385,21 +461,27 @@ impl UIAction {
             UIAction::OpenPlaylistList => "Switch to playlists",
             UIAction::CreatePlaylist => "Create playlist",
             UIAction::AddToPlaylist => "Add episode to playlist",
+            UIAction::AddAllToPlaylist => "Add all shown episodes to playlist",
+            UIAction::EnqueueEpisode => "Add episode to playback queue",
             // OPML
             UIAction::ImportOpml => "Import OPML",
+            UIAction::CancelImport => "Cancel running OPML import",
             UIAction::ExportOpml => "Export OPML",
             // Sync / tabs
             UIAction::SyncToDevice => "Sync to device",
-            UIAction::PreviousTab => "Previous tab",
-            UIAction::NextTab => "Next tab",
+            UIAction::PreviousTab => "Previous tab / folded section",
+            UIAction::NextTab => "Next tab / folded section",
             // Audio playback
             UIAction::PlayEpisode { .. } => "Play selected episode",
+            UIAction::PlayPlaylist { .. } => "Play playlist",
             UIAction::TogglePlayPause => "Toggle play / pause",
             UIAction::StopPlayback => "Stop playback",
             UIAction::SeekForward => "Seek forward",
             UIAction::SeekBackward => "Seek backward",
             UIAction::VolumeUp => "Volume up",
             UIAction::VolumeDown => "Volume down",
+            UIAction::ToggleMute => "Toggle mute",
+            UIAction::ToggleDuck => "Toggle ducking (lowered volume)",
             // Internal / trigger actions — not shown in help
             _ => "",
         }
@@ -435,6 +517,7 @@ impl UIAction {
             // Application
             UIAction::Quit
             | UIAction::ShowHelp
+            | UIAction::ShowGlyphLegend
             | UIAction::Refresh
             | UIAction::PromptCommand
             | UIAction::SelectItem
@@ -447,6 +530,7 @@ impl UIAction {
             | UIAction::DeletePodcast
             | UIAction::RefreshPodcast
             | UIAction::RefreshAll
+            | UIAction::RefreshFiltered
             | UIAction::HardRefreshPodcast
             | UIAction::DownloadEpisode
             | UIAction::DeleteDownloadedEpisode
@@ -459,31 +543,44 @@ impl UIAction {
             | UIAction::CycleSortField
             | UIAction::ToggleSortDirection
             | UIAction::MoveEpisodeUp
-            | UIAction::MoveEpisodeDown => "EPISODE STATUS & SORTING",
+            | UIAction::MoveEpisodeDown
+            | UIAction::MoveEpisodeToTop => "EPISODE STATUS & SORTING",
 
             // Playlists
-            UIAction::OpenPlaylistList | UIAction::CreatePlaylist | UIAction::AddToPlaylist => {
-                "PLAYLISTS"
-            }
+            UIAction::OpenPlaylistList
+            | UIAction::CreatePlaylist
+            | UIAction::AddToPlaylist
+            | UIAction::AddAllToPlaylist => "PLAYLISTS",
 
             // OPML
-            UIAction::ImportOpml | UIAction::ExportOpml => "OPML IMPORT/EXPORT",
+            UIAction::ImportOpml | UIAction::ExportOpml | UIAction::CancelImport => {
+                "OPML IMPORT/EXPORT"
+            }
 
             // Sync & tabs
             UIAction::SyncToDevice | UIAction::PreviousTab | UIAction::NextTab => "DEVICE SYNC",
 
             // Audio playback
             UIAction::PlayEpisode { .. }
+            | UIAction::PlayPlaylist { .. }
+            | UIAction::EnqueueEpisode
             | UIAction::TogglePlayPause
             | UIAction::StopPlayback
             | UIAction::SeekForward
             | UIAction::SeekBackward
             | UIAction::VolumeUp
-            | UIAction::VolumeDown => "AUDIO PLAYBACK",
+            | UIAction::VolumeDown
+            | UIAction::ToggleMute
+            | UIAction::ToggleDuck => "AUDIO PLAYBACK",
 
             // ExecuteCommand — categorize by the command name
             UIAction::ExecuteCommand(cmd) => match cmd.as_str() {
-                "switch-to-buffer" | "list-buffers" => "BUFFER MANAGEMENT",
+                "switch-to-buffer" | "list-buffers" | "workspace" => "BUFFER MANAGEMENT",
+                "podcast-info" | "podcast-settings" | "retry-failed-imports" | "redownload" => {
+                    "PODCAST MANAGEMENT"
+                }
+                "view-raw" | "search-all" | "search-person" | "focus" => "APPLICATION",
+                "queue" | "bookmark" => "AUDIO PLAYBACK",
                 _ => "",
             },
 
@@ -491,6 +588,53 @@ impl UIAction {
             _ => "",
         }
     }
+
+    /// Whether a key-bound action is available with `ui.simple_mode` on:
+    /// navigation, subscribing, refreshing, playback and downloading. Advanced
+    /// buffers and anything destructive are left out.
+    pub fn allowed_in_simple_mode(&self) -> bool {
+        match self {
+            UIAction::MoveUp
+            | UIAction::MoveDown
+            | UIAction::MoveLeft
+            | UIAction::MoveRight
+            | UIAction::PageUp
+            | UIAction::PageDown
+            | UIAction::MoveToTop
+            | UIAction::MoveToBottom
+            | UIAction::NextBuffer
+            | UIAction::PreviousBuffer
+            | UIAction::CloseCurrentBuffer
+            | UIAction::Quit
+            | UIAction::ShowHelp
+            | UIAction::ShowGlyphLegend
+            | UIAction::Refresh
+            | UIAction::PromptCommand
+            | UIAction::SelectItem
+            | UIAction::HideMinibuffer
+            | UIAction::Search
+            | UIAction::ClearFilters
+            | UIAction::AddPodcast
+            | UIAction::RefreshPodcast
+            | UIAction::RefreshAll
+            | UIAction::DownloadEpisode
+            | UIAction::MarkPlayed
+            | UIAction::MarkUnplayed
+            | UIAction::PlayEpisode { .. }
+            | UIAction::TogglePlayPause
+            | UIAction::StopPlayback
+            | UIAction::SeekForward
+            | UIAction::SeekBackward
+            | UIAction::VolumeUp
+            | UIAction::VolumeDown
+            | UIAction::ToggleMute
+            | UIAction::None => true,
+            UIAction::SwitchBuffer(name) => {
+                matches!(name.as_str(), "podcast-list" | "downloads" | "now-playing")
+            }
+            _ => false,
+        }
+    }
 }
 
 /// Trait for UI components that can handle events and render themselves
@@ -532,4 +676,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_simple_mode_allows_basics_and_blocks_destructive_actions() {
+        assert!(UIAction::AddPodcast.allowed_in_simple_mode());
+        assert!(UIAction::DownloadEpisode.allowed_in_simple_mode());
+        assert!(UIAction::SwitchBuffer("downloads".to_string()).allowed_in_simple_mode());
+
+        assert!(!UIAction::DeletePodcast.allowed_in_simple_mode());
+        assert!(!UIAction::DeleteAllDownloads.allowed_in_simple_mode());
+        assert!(!UIAction::OpenPlaylistList.allowed_in_simple_mode());
+        assert!(!UIAction::SwitchBuffer("sync".to_string()).allowed_in_simple_mode());
+    }
 }
@@ -0,0 +1,268 @@
+// Snapshot tests for buffer rendering
+//
+// Each test draws a buffer (or the minibuffer) into a ratatui `TestBackend`
+// and compares the cell contents, one text line per terminal row, with a
+// stored snapshot in `src/ui/snapshots/`. Column drift, truncation bugs and
+// borders broken by wide characters show up as a diff of that file.
+//
+// A missing snapshot is written on the first run. After an intended rendering
+// change, re-run with `UPDATE_SNAPSHOTS=1 cargo test snapshot_` and review the
+// changed `.snap` files before committing them.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{TimeZone, Utc};
+use ratatui::{backend::TestBackend, layout::Rect, Frame, Terminal};
+
+use crate::{
+    download::{DownloadStatus, SyncReport},
+    podcast::{Episode, EpisodeStatus, Podcast},
+    storage::{EpisodeId, PodcastId},
+    ui::{
+        buffers::{
+            downloads::DownloadsBuffer, episode_list::EpisodeListBuffer,
+            podcast_list::PodcastListBuffer, sync::SyncBuffer, Buffer,
+        },
+        components::{minibuffer::Minibuffer, scroll_indicator},
+        events::{DownloadEntry, PodcastSummary},
+        text_layout,
+        themes::Theme,
+        UIAction, UIComponent,
+    },
+};
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 16;
+
+/// Draw into a `width` x `height` test terminal and return the cells as text.
+///
+/// The cells hidden behind a wide character are skipped so every row reads
+/// as it appears on screen; trailing spaces are trimmed.
+fn render_cells(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(draw).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    let mut rows = Vec::with_capacity(height as usize);
+    for y in 0..height {
+        let mut row = String::new();
+        let mut hidden = 0;
+        for x in 0..width {
+            if hidden > 0 {
+                hidden -= 1;
+                continue;
+            }
+            let symbol = buffer[(x, y)].symbol();
+            hidden = text_layout::display_width(symbol).saturating_sub(1);
+            row.push_str(symbol);
+        }
+        rows.push(row.trim_end().to_string());
+    }
+    rows.join("\n") + "\n"
+}
+
+/// Render a buffer over the whole test terminal, with its scroll indicator
+fn render_buffer<B: Buffer + UIComponent + ?Sized>(buffer: &mut B) -> String {
+    render_cells(WIDTH, HEIGHT, |frame| {
+        buffer.render(frame, frame.area());
+        if let Some(position) = buffer.scroll_position() {
+            scroll_indicator::render(frame, &position, &Theme::default());
+        }
+    })
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/ui/snapshots")
+        .join(format!("{name}.snap"))
+}
+
+/// Compare `actual` with the stored snapshot `name`, writing it when missing
+/// or when `UPDATE_SNAPSHOTS` is set
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if !update => {
+            if expected != actual {
+                let diff: Vec<String> = expected
+                    .lines()
+                    .zip(actual.lines())
+                    .enumerate()
+                    .filter(|(_, (old, new))| old != new)
+                    .map(|(row, (old, new))| format!("row {row}:\n- {old}\n+ {new}"))
+                    .collect();
+                panic!(
+                    "snapshot '{name}' changed (re-run with UPDATE_SNAPSHOTS=1 if intended)\n{}\n\nactual:\n{actual}",
+                    diff.join("\n")
+                );
+            }
+        }
+        _ => {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, actual).unwrap();
+        }
+    }
+}
+
+fn published(day: u32) -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap()
+}
+
+fn podcast(title: &str, author: &str) -> Podcast {
+    let mut podcast = Podcast::new(title.to_string(), format!("https://example.com/{author}"));
+    podcast.author = Some(author.to_string());
+    podcast
+}
+
+fn episode(podcast_id: &PodcastId, title: &str, day: u32, status: EpisodeStatus) -> Episode {
+    let mut episode = Episode::new(
+        podcast_id.clone(),
+        title.to_string(),
+        format!("https://example.com/{day}.mp3"),
+        published(day),
+    );
+    episode.status = status;
+    episode.duration = Some(day * 300);
+    episode
+}
+
+#[test]
+fn snapshot_podcast_list_with_wide_titles() {
+    // Arrange
+    let podcasts = vec![
+        podcast("Rust Weekly", "Ferris"),
+        podcast("日本語のポッドキャスト番組", "山田"),
+        podcast("🎧 Headphones On 🎧", "Emoji Media"),
+        podcast(
+            "A podcast title that is much too long to fit into its column at all",
+            "Someone",
+        ),
+    ];
+    let summaries = podcasts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let summary = PodcastSummary {
+                unplayed: i * 3,
+                downloaded: i,
+                last_episode: Some(published(i as u32 + 1)),
+                disk_bytes: i as u64 * 50 * 1024 * 1024,
+            };
+            (p.id.clone(), summary)
+        })
+        .collect();
+    let mut buffer = PodcastListBuffer::new();
+    buffer.set_podcasts(podcasts);
+    buffer.set_summaries(summaries);
+    buffer.handle_action(UIAction::MoveDown);
+
+    // Act
+    let screen = render_buffer(&mut buffer);
+
+    // Assert
+    assert_snapshot("podcast_list", &screen);
+}
+
+#[test]
+fn snapshot_episode_list() {
+    let podcast_id = PodcastId::new();
+    let mut buffer = EpisodeListBuffer::new("Rust Weekly".to_string(), podcast_id.clone());
+    buffer.set_episodes(vec![
+        episode(&podcast_id, "Async in depth", 12, EpisodeStatus::default()),
+        episode(
+            &podcast_id,
+            "Borrowing\nacross lines",
+            9,
+            EpisodeStatus::downloaded(),
+        ),
+        episode(&podcast_id, "ゼロコスト抽象化", 5, EpisodeStatus::played()),
+    ]);
+
+    assert_snapshot("episode_list", &render_buffer(&mut buffer));
+}
+
+#[test]
+fn snapshot_downloads() {
+    let entry = |title: &str, status: DownloadStatus| DownloadEntry {
+        podcast_id: PodcastId::new(),
+        episode_id: EpisodeId::new(),
+        podcast_name: "Rust Weekly".to_string(),
+        episode_title: title.to_string(),
+        status,
+        file_path: None,
+        file_size: Some(42 * 1024 * 1024),
+    };
+    let downloading = entry("Async in depth", DownloadStatus::InProgress);
+    let mut buffer = DownloadsBuffer::new();
+    buffer.set_downloads(vec![
+        downloading.clone(),
+        entry("Borrowing", DownloadStatus::Queued),
+        entry("ゼロコスト抽象化", DownloadStatus::Completed),
+        entry("Lifetimes", DownloadStatus::Failed("HTTP 404".to_string())),
+    ]);
+    buffer.set_progress(
+        &downloading.episode_id,
+        21 * 1024 * 1024,
+        Some(42 * 1024 * 1024),
+    );
+
+    assert_snapshot("downloads", &render_buffer(&mut buffer));
+}
+
+#[test]
+fn snapshot_sync_overview_and_dry_run() {
+    let mut buffer = SyncBuffer::new();
+    assert_snapshot("sync_overview", &render_buffer(&mut buffer));
+
+    let mut report = SyncReport::new();
+    for name in ["Rust Weekly/async.mp3", "日本語/ep1.mp3"] {
+        let path = PathBuf::from(name);
+        report.file_sizes.insert(path.clone(), 30 * 1024 * 1024);
+        report.files_copied.push(path);
+    }
+    report
+        .files_deleted
+        .push(PathBuf::from("Old Show/gone.mp3"));
+    buffer.enter_dry_run_preview(PathBuf::from("/media/player"), report);
+    assert_snapshot("sync_dry_run", &render_buffer(&mut buffer));
+}
+
+#[test]
+fn snapshot_minibuffer_states() {
+    // Laid out as in the app: the popup opens in the main area directly
+    // above the two-row minibuffer (top border + text)
+    let (width, height) = (60, 9);
+    let draw = |minibuffer: &mut Minibuffer| {
+        render_cells(width, height, |frame| {
+            let main = Rect::new(0, 0, width, height - 2);
+            let minibuffer_area = Rect::new(0, height - 2, width, 2);
+            minibuffer.render_completion_popup(frame, main);
+            minibuffer.render(frame, minibuffer_area);
+        })
+    };
+
+    let mut minibuffer = Minibuffer::new();
+    minibuffer.show_message("Refreshed 3 podcasts".to_string());
+    let message = draw(&mut minibuffer);
+
+    minibuffer.show_error("Could not reach https://example.com/feed".to_string());
+    let error = draw(&mut minibuffer);
+
+    minibuffer.show_prompt_with_completion(
+        "M-x ".to_string(),
+        ["refresh", "refresh-diff", "hard-refresh", "quit"]
+            .iter()
+            .map(|c| c.to_string())
+            .collect(),
+    );
+    for ch in "refr".chars() {
+        minibuffer.add_char(ch);
+    }
+    minibuffer.select_next_completion();
+    let completion = draw(&mut minibuffer);
+
+    assert_snapshot("minibuffer_message", &message);
+    assert_snapshot("minibuffer_error", &error);
+    assert_snapshot("minibuffer_completion", &completion);
+}
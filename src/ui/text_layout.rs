@@ -0,0 +1,138 @@
+// Text layout - width-aware measuring, truncation and padding
+//
+// Terminal cells aren't characters: CJK ideographs and most emoji take two
+// columns, while combining marks and zero-width joiners take none. Measuring
+// or cutting titles by `chars()` lets wide text push columns out of line and
+// through borders, so buffers measure, truncate and pad through this module,
+// which works in display columns and never splits a grapheme cluster.
+
+use std::borrow::Cow;
+
+use ratatui::layout::Alignment;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Marks text cut short by [`truncate`]
+pub const ELLIPSIS: &str = "…";
+
+/// Columns `text` takes up in a terminal
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Collapse line breaks, tabs and other control characters (with the spaces
+/// around them) to single spaces, so feed text stays on one row
+pub fn single_line(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(char::is_control) {
+        return Cow::Borrowed(text);
+    }
+    let spaced: String = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    Cow::Owned(spaced.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Cut `text` to at most `max_width` columns, ending in an ellipsis when
+/// anything was removed
+pub fn truncate(text: &str, max_width: usize) -> Cow<'_, str> {
+    if display_width(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+    let budget = max_width.saturating_sub(display_width(ELLIPSIS));
+    let mut width = 0;
+    let mut end = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        let grapheme_width = display_width(grapheme);
+        if width + grapheme_width > budget {
+            break;
+        }
+        width += grapheme_width;
+        end = index + grapheme.len();
+    }
+    if max_width < display_width(ELLIPSIS) {
+        return Cow::Owned(text[..end].to_string());
+    }
+    Cow::Owned(format!("{}{}", text[..end].trim_end(), ELLIPSIS))
+}
+
+/// Table cell text: `text` on one line, cut to `max_width` columns
+pub fn cell(text: &str, max_width: usize) -> String {
+    truncate(&single_line(text), max_width).into_owned()
+}
+
+/// Pad `text` with spaces to at least `width` columns; wider text is left as is
+pub fn pad(text: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    let (left, right) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// `text` on one line, truncated and padded to exactly `width` columns
+pub fn fit(text: &str, width: usize, alignment: Alignment) -> String {
+    let line = single_line(text);
+    pad(&truncate(&line, width), width, alignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_characters_count_two_columns() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("🎧"), 2);
+        assert_eq!(display_width("e\u{301}"), 1); // e + combining acute
+    }
+
+    #[test]
+    fn test_truncate_measures_columns_not_characters() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("this is a very long string", 10), "this is a…");
+        assert_eq!(truncate("exactly10!", 10), "exactly10!");
+        // The third ideograph would overrun the 5 columns left for text
+        assert_eq!(truncate("日本語テキスト", 6), "日本…");
+        assert!(display_width(&truncate("🎧🎧🎧🎧", 5)) <= 5);
+        assert_eq!(truncate("anything", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_keeps_grapheme_clusters_whole() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let text = format!("{}{} podcast", family, family);
+        let cut = truncate(&text, display_width(family) + 1);
+        assert_eq!(cut, format!("{}{}", family, ELLIPSIS));
+    }
+
+    #[test]
+    fn test_single_line_collapses_control_characters() {
+        assert_eq!(single_line("Plain title"), "Plain title");
+        assert_eq!(
+            single_line("Part one\r\n  Part two\tend"),
+            "Part one Part two end"
+        );
+    }
+
+    #[test]
+    fn test_fit_pads_to_exact_width() {
+        assert_eq!(fit("ab", 5, Alignment::Left), "ab   ");
+        assert_eq!(fit("ab", 5, Alignment::Right), "   ab");
+        assert_eq!(fit("ab", 5, Alignment::Center), " ab  ");
+        assert_eq!(display_width(&fit("日本語テキスト", 7, Alignment::Left)), 7);
+        assert_eq!(fit("two\nlines", 9, Alignment::Left), "two lines");
+        assert_eq!(pad("much too wide", 4, Alignment::Left), "much too wide");
+    }
+
+    #[test]
+    fn test_cell_joins_lines_and_truncates_without_padding() {
+        assert_eq!(cell("two\nlines", 20), "two lines");
+        assert_eq!(display_width(&cell("a rather long title", 8)), 8);
+        assert!(cell("a rather long title", 8).ends_with(ELLIPSIS));
+        assert_eq!(cell("ポッドキャスト番組", 10), "ポッドキ…");
+    }
+}
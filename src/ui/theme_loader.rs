@@ -49,7 +49,7 @@ pub enum ThemeError {
 
     #[error(
         "Unknown base theme '{0}' in extends field — valid names: \
-         dark, default, light, high-contrast, solarized, \
+         dark, default, light, high-contrast, solarized, deuteranopia, protanopia, \
          catppuccin-mocha, dracula, nord, gruvbox-dark, tokyo-night"
     )]
     UnknownBaseTheme(String),
@@ -70,6 +70,8 @@ const BUNDLED_THEME_KEYS: &[&str] = &[
     "light",
     "high-contrast",
     "solarized",
+    "deuteranopia",
+    "protanopia",
     // TOML-embedded community themes
     "catppuccin-mocha",
     "dracula",
@@ -91,7 +93,7 @@ struct ThemeFile {
 struct ThemeMetadata {
     name: String,
     /// Name of a bundled theme to inherit colors from (`dark`, `light`,
-    /// `high-contrast`, or `solarized`). When absent the default dark theme
+    /// `high-contrast`, `solarized`, `deuteranopia` or `protanopia`). When absent the default dark theme
     /// is used as the base.
     extends: Option<String>,
     // Informational fields stored in the file but not consumed by the app.
@@ -387,7 +389,8 @@ impl ThemeRegistry {
     /// Create a new registry pre-populated with all bundled themes.
     ///
     /// Bundled keys (case-insensitive):
-    ///   - Rust-defined: `dark`, `default` (alias for dark), `light`, `high-contrast`, `solarized`
+    ///   - Rust-defined: `dark`, `default` (alias for dark), `light`, `high-contrast`, `solarized`,
+    ///     `deuteranopia`, `protanopia`
     ///   - TOML-embedded: `catppuccin-mocha`, `dracula`, `nord`, `gruvbox-dark`, `tokyo-night`
     ///
     /// The inserted keys must stay in sync with [`BUNDLED_THEME_KEYS`].
@@ -400,6 +403,8 @@ impl ThemeRegistry {
         themes.insert("light".to_string(), Theme::light());
         themes.insert("high-contrast".to_string(), Theme::high_contrast());
         themes.insert("solarized".to_string(), Theme::solarized());
+        themes.insert("deuteranopia".to_string(), Theme::deuteranopia());
+        themes.insert("protanopia".to_string(), Theme::protanopia());
 
         // Community themes embedded as TOML (assets/themes/)
         for (key, toml_str) in [
@@ -468,8 +473,8 @@ impl ThemeRegistry {
 
     /// Look up a theme by name, restricting to bundled themes only (case-insensitive).
     ///
-    /// Only the five built-in keys (`dark`, `default`, `light`, `high-contrast`,
-    /// `solarized`) are returned. User-defined themes are never returned, making
+    /// Only the built-in keys (`dark`, `default`, `light`, `high-contrast`,
+    /// `solarized`, `deuteranopia`, `protanopia` and the community themes) are returned. User-defined themes are never returned, making
     /// this the right method to use when resolving `extends` in a theme file
     /// (single-level, bundled-parent-only inheritance).
     pub fn get_bundled(&self, name: &str) -> Option<&Theme> {
@@ -917,6 +922,8 @@ name = "Minimal"
         assert!(registry.get("light").is_some());
         assert!(registry.get("high-contrast").is_some());
         assert!(registry.get("solarized").is_some());
+        assert!(registry.get("deuteranopia").is_some());
+        assert!(registry.get("protanopia").is_some());
     }
 
     #[test]
@@ -1045,12 +1052,14 @@ primary = "#ff79c6"
         // Arrange
         let registry = ThemeRegistry::new();
 
-        // Act / Assert: all five built-in keys are accessible
+        // Act / Assert: all Rust-defined built-in keys are accessible
         assert!(registry.get_bundled("dark").is_some());
         assert!(registry.get_bundled("default").is_some());
         assert!(registry.get_bundled("light").is_some());
         assert!(registry.get_bundled("high-contrast").is_some());
         assert!(registry.get_bundled("solarized").is_some());
+        assert!(registry.get_bundled("deuteranopia").is_some());
+        assert!(registry.get_bundled("protanopia").is_some());
     }
 
     #[test]
@@ -1,11 +1,14 @@
 // Theme and styling system for the UI
 //
 // This module provides color schemes and styling options for the TUI,
-// supporting different themes like dark mode, light mode, and high contrast.
+// supporting different themes like dark mode, light mode, and high contrast,
+// plus palettes for deuteranopia and protanopia.
 
 use ratatui::style::{Color, Modifier, Style};
 
+use crate::ui::glyphs::{GlyphSet, StatusGlyph};
 use crate::ui::UIError;
+use crate::utils::time::DisplayZone;
 
 /// Available color themes
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +18,8 @@ pub enum ThemeType {
     Light,
     HighContrast,
     Solarized,
+    Deuteranopia,
+    Protanopia,
 }
 
 /// Color scheme definition
@@ -59,12 +64,25 @@ pub struct ColorScheme {
 pub struct Theme {
     pub name: String,
     pub colors: ColorScheme,
+    /// Character set used for status glyphs (driven by `ui.ascii_glyphs`)
+    pub glyphs: GlyphSet,
+    /// Never rely on color alone: selections are also bold and underlined
+    /// (driven by `ui.color_blind_mode`)
+    pub color_blind_mode: bool,
+    /// Zone publication dates are shown in (driven by `ui.timezone`)
+    pub time_zone: DisplayZone,
 }
 
 impl Theme {
     /// Create a new theme with the specified color scheme
     pub fn new(name: String, colors: ColorScheme) -> Self {
-        Self { name, colors }
+        Self {
+            name,
+            colors,
+            glyphs: GlyphSet::default(),
+            color_blind_mode: false,
+            time_zone: DisplayZone::default(),
+        }
     }
 
     /// Get the default dark theme
@@ -203,6 +221,80 @@ impl Theme {
         Self::new("Solarized".to_string(), colors)
     }
 
+    /// Get a dark theme for deuteranopia (weak green perception).
+    ///
+    /// Status colors sit on the blue/orange axis of the Okabe-Ito palette so
+    /// success and error never differ only by red versus green.
+    pub fn deuteranopia() -> Self {
+        let colors = ColorScheme {
+            background: Color::Rgb(16, 20, 24),
+            surface: Color::Rgb(28, 33, 40),
+            overlay: Color::Rgb(44, 50, 60),
+
+            text: Color::Rgb(235, 235, 235),
+            subtext: Color::Rgb(190, 190, 190),
+            muted: Color::Rgb(130, 130, 130),
+
+            primary: Color::Rgb(86, 180, 233),  // sky blue
+            secondary: Color::Rgb(0, 114, 178), // blue
+            success: Color::Rgb(86, 180, 233),  // sky blue
+            warning: Color::Rgb(240, 228, 66),  // yellow
+            error: Color::Rgb(213, 94, 0),      // vermillion
+
+            border: Color::Rgb(90, 95, 105),
+            border_focused: Color::Rgb(86, 180, 233),
+            selection: Color::Rgb(40, 60, 90),
+            cursor: Color::Rgb(235, 235, 235),
+
+            playing: Color::Rgb(86, 180, 233),    // sky blue
+            paused: Color::Rgb(240, 228, 66),     // yellow
+            downloaded: Color::Rgb(0, 114, 178),  // blue
+            downloading: Color::Rgb(230, 159, 0), // orange
+            queued: Color::Rgb(204, 121, 167),    // reddish purple
+
+            active_indicator: Color::Rgb(240, 228, 66),
+        };
+
+        Self::new("Deuteranopia".to_string(), colors)
+    }
+
+    /// Get a dark theme for protanopia (weak red perception).
+    ///
+    /// Reds look dark to protanopes, so errors use a bright orange and the
+    /// remaining states are told apart by blue versus yellow and lightness.
+    pub fn protanopia() -> Self {
+        let colors = ColorScheme {
+            background: Color::Rgb(16, 20, 24),
+            surface: Color::Rgb(28, 33, 40),
+            overlay: Color::Rgb(44, 50, 60),
+
+            text: Color::Rgb(235, 235, 235),
+            subtext: Color::Rgb(190, 190, 190),
+            muted: Color::Rgb(130, 130, 130),
+
+            primary: Color::Rgb(86, 180, 233),  // sky blue
+            secondary: Color::Rgb(0, 114, 178), // blue
+            success: Color::Rgb(0, 114, 178),   // blue
+            warning: Color::Rgb(240, 228, 66),  // yellow
+            error: Color::Rgb(255, 140, 0),     // bright orange
+
+            border: Color::Rgb(90, 95, 105),
+            border_focused: Color::Rgb(86, 180, 233),
+            selection: Color::Rgb(40, 60, 90),
+            cursor: Color::Rgb(235, 235, 235),
+
+            playing: Color::Rgb(86, 180, 233),      // sky blue
+            paused: Color::Rgb(240, 228, 66),       // yellow
+            downloaded: Color::Rgb(86, 180, 233),   // sky blue
+            downloading: Color::Rgb(255, 255, 255), // white
+            queued: Color::Rgb(160, 160, 200),      // lavender grey
+
+            active_indicator: Color::Rgb(240, 228, 66),
+        };
+
+        Self::new("Protanopia".to_string(), colors)
+    }
+
     /// Return a reference to the theme's color scheme.
     pub fn color_scheme(&self) -> &ColorScheme {
         &self.colors
@@ -210,7 +302,13 @@ impl Theme {
 
     /// Create a `Theme` from an explicit name and [`ColorScheme`].
     pub fn from_color_scheme(name: String, colors: ColorScheme) -> Self {
-        Self { name, colors }
+        Self {
+            name,
+            colors,
+            glyphs: GlyphSet::default(),
+            color_blind_mode: false,
+            time_zone: DisplayZone::default(),
+        }
     }
 
     /// Create a theme from a name
@@ -220,6 +318,8 @@ impl Theme {
             "light" => Ok(Self::light()),
             "high-contrast" | "high_contrast" => Ok(Self::high_contrast()),
             "solarized" => Ok(Self::solarized()),
+            "deuteranopia" => Ok(Self::deuteranopia()),
+            "protanopia" => Ok(Self::protanopia()),
             _ => Err(UIError::InvalidOperation(format!(
                 "Unknown theme: {}",
                 name
@@ -227,6 +327,43 @@ impl Theme {
         }
     }
 
+    /// Return a copy of this theme drawing glyphs with the given character set
+    pub fn with_glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Return a copy of this theme showing dates in `time_zone`
+    pub fn with_time_zone(mut self, time_zone: DisplayZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Return a copy of this theme with color-blind mode switched on or off
+    pub fn with_color_blind_mode(mut self, enabled: bool) -> Self {
+        self.color_blind_mode = enabled;
+        self
+    }
+
+    /// Extra modifiers that mark a selection without relying on color
+    fn selection_cue(&self) -> Modifier {
+        if self.color_blind_mode {
+            Modifier::BOLD | Modifier::UNDERLINED
+        } else {
+            Modifier::empty()
+        }
+    }
+
+    /// Symbol for a status glyph in this theme's character set
+    pub fn glyph(&self, glyph: StatusGlyph) -> &'static str {
+        glyph.symbol(self.glyphs)
+    }
+
+    /// Style for a status glyph
+    pub fn glyph_style(&self, glyph: StatusGlyph) -> Style {
+        glyph.style(self)
+    }
+
     /// Get default style
     pub fn default_style(&self) -> Style {
         Style::default()
@@ -242,6 +379,8 @@ impl Theme {
             ThemeType::Light => Self::light(),
             ThemeType::HighContrast => Self::high_contrast(),
             ThemeType::Solarized => Self::solarized(),
+            ThemeType::Deuteranopia => Self::deuteranopia(),
+            ThemeType::Protanopia => Self::protanopia(),
         }
     }
 
@@ -267,7 +406,7 @@ impl Theme {
         Style::default()
             .fg(self.colors.text)
             .bg(self.colors.selection)
-            .add_modifier(Modifier::BOLD)
+            .add_modifier(Modifier::BOLD | self.selection_cue())
     }
 
     /// Style for selected items
@@ -275,6 +414,7 @@ impl Theme {
         Style::default()
             .fg(self.colors.text)
             .bg(self.colors.selection)
+            .add_modifier(self.selection_cue())
     }
 
     /// Style for borders
@@ -355,6 +495,21 @@ impl Theme {
     pub fn active_indicator_style(&self) -> Style {
         Style::default().fg(self.colors.active_indicator)
     }
+
+    /// Style for the scrollbar thumb of the scroll indicator
+    pub fn scrollbar_thumb_style(&self) -> Style {
+        Style::default().fg(self.colors.border_focused)
+    }
+
+    /// Style for the scrollbar track of the scroll indicator
+    pub fn scrollbar_track_style(&self) -> Style {
+        Style::default().fg(self.colors.border)
+    }
+
+    /// Style for the scroll indicator's "134/982 · 14%" label
+    pub fn scrollbar_label_style(&self) -> Style {
+        Style::default().fg(self.colors.muted)
+    }
 }
 
 impl Default for Theme {
@@ -408,6 +563,37 @@ mod tests {
 
         let high_contrast = Theme::by_type(ThemeType::HighContrast);
         assert_eq!(high_contrast.name, "High Contrast");
+
+        let deuteranopia = Theme::by_type(ThemeType::Deuteranopia);
+        assert_eq!(deuteranopia.name, "Deuteranopia");
+    }
+
+    #[test]
+    fn test_color_blind_palettes_keep_states_apart() {
+        for theme in [Theme::deuteranopia(), Theme::protanopia()] {
+            let c = &theme.colors;
+            let states = [c.error, c.success, c.warning, c.downloading, c.downloaded];
+            for (i, a) in states.iter().enumerate() {
+                for b in &states[i + 1..] {
+                    assert_ne!(a, b, "{} reuses a status color", theme.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_blind_mode_marks_selection_without_color() {
+        let theme = Theme::default_dark();
+        assert!(!theme
+            .selected_style()
+            .add_modifier
+            .contains(Modifier::UNDERLINED));
+
+        let theme = theme.with_color_blind_mode(true);
+        assert!(theme
+            .selected_style()
+            .add_modifier
+            .contains(Modifier::BOLD | Modifier::UNDERLINED));
     }
 
     #[test]
@@ -0,0 +1,149 @@
+// Release update check against the GitHub releases API
+//
+// Opt-in via `updates.check_on_startup`; `:release-notes` fetches on demand.
+// Only published, non-prerelease releases whose tag is a newer `x.y.z` than
+// this build are reported, newest first.
+
+use crate::constants::updates::{CURRENT_VERSION, RELEASES_API_URL, UPDATE_CHECK_TIMEOUT};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Errors that can occur while checking for updates
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCheckError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("GitHub API error ({0})")]
+    Api(u16),
+}
+
+/// A published release, as returned by the GitHub API
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Release notes (Markdown)
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+impl Release {
+    /// Version without the leading `v` ("1.12.0")
+    pub fn version(&self) -> &str {
+        self.tag_name.trim_start_matches('v')
+    }
+}
+
+/// Parse `x.y.z` (optionally `v`-prefixed). Pre-release and build suffixes
+/// make the version unparseable so they are never offered as updates.
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.trim().trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+/// Releases newer than `current`, newest first
+pub fn newer_releases(releases: Vec<Release>, current: &str) -> Vec<Release> {
+    let Some(current) = parse_version(current) else {
+        return Vec::new();
+    };
+    let mut newer: Vec<(_, Release)> = releases
+        .into_iter()
+        .filter(|release| !release.draft && !release.prerelease)
+        .filter_map(|release| Some((parse_version(&release.tag_name)?, release)))
+        .filter(|(version, _)| *version > current)
+        .collect();
+    newer.sort_by_key(|(version, _)| std::cmp::Reverse(*version));
+    newer.into_iter().map(|(_, release)| release).collect()
+}
+
+/// Fetch the project's releases and keep those newer than this build
+pub async fn fetch_newer_releases() -> Result<Vec<Release>, UpdateCheckError> {
+    let client = Client::builder()
+        .timeout(UPDATE_CHECK_TIMEOUT)
+        .user_agent(crate::constants::network::USER_AGENT)
+        .build()?;
+    let response = client
+        .get(RELEASES_API_URL)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(UpdateCheckError::Api(response.status().as_u16()));
+    }
+    let releases: Vec<Release> = response.json().await?;
+    Ok(newer_releases(releases, CURRENT_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            name: None,
+            body: None,
+            html_url: String::new(),
+            published_at: None,
+            draft: false,
+            prerelease: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v1.12.0"), Some((1, 12, 0)));
+        assert_eq!(parse_version("2.0.3"), Some((2, 0, 3)));
+        assert_eq!(parse_version("1.12.0-beta.1"), None);
+        assert_eq!(parse_version("1.12"), None);
+    }
+
+    #[test]
+    fn test_newer_releases_filters_and_sorts() {
+        // Arrange
+        let mut draft = release("v9.0.0");
+        draft.draft = true;
+        let mut beta = release("v1.13.0");
+        beta.prerelease = true;
+        let releases = vec![
+            release("v1.11.0"),
+            release("v1.12.0"),
+            draft,
+            beta,
+            release("v1.12.1"),
+            release("v1.10.2"),
+        ];
+
+        // Act
+        let newer = newer_releases(releases, "1.11.0");
+
+        // Assert
+        let tags: Vec<&str> = newer.iter().map(|r| r.tag_name.as_str()).collect();
+        assert_eq!(tags, vec!["v1.12.1", "v1.12.0"]);
+    }
+
+    #[test]
+    fn test_release_deserializes_from_github_json() {
+        let json = r#"[{"tag_name":"v1.12.0","name":"1.12.0","body":"- Fix","html_url":"https://github.com/lqdev/podcast-tui/releases/tag/v1.12.0","published_at":"2026-03-01T12:00:00Z","draft":false,"prerelease":false,"assets":[]}]"#;
+        let releases: Vec<Release> = serde_json::from_str(json).unwrap();
+        assert_eq!(releases[0].version(), "1.12.0");
+        assert_eq!(releases[0].body.as_deref(), Some("- Fix"));
+    }
+}
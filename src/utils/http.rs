@@ -0,0 +1,536 @@
+// HTTP client abstraction
+//
+// Feed fetching, episode downloads and OPML import go through `HttpClient`
+// rather than `reqwest` directly, so tests can swap in `MockHttpClient` and
+// exercise refresh, download and error paths deterministically without a
+// network. `ReqwestClient` is the real implementation; each component still
+// builds its own `reqwest::Client` (user agent, timeouts, redirects) and
+// wraps it.
+
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use futures_util::stream::{self, Stream, StreamExt};
+
+/// A response body, delivered in chunks as it arrives
+pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, HttpError>> + Send>>;
+
+/// Errors from an HTTP request, independent of the client behind it
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HttpError {
+    #[error("HTTP status {} for url ({url})", status_text(*.status))]
+    Status { status: u16, url: String },
+
+    #[error("request timed out: {0}")]
+    Timeout(String),
+
+    #[error("connection failed: {0}")]
+    Connect(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl HttpError {
+    /// The server couldn't be reached (offline, DNS, refused)
+    pub fn is_connect(&self) -> bool {
+        matches!(self, Self::Connect(_))
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
+    /// Status code of an error response
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(error: reqwest::Error) -> Self {
+        if let Some(status) = error.status() {
+            Self::Status {
+                status: status.as_u16(),
+                url: error.url().map(|u| u.to_string()).unwrap_or_default(),
+            }
+        } else if error.is_timeout() {
+            Self::Timeout(error.to_string())
+        } else if error.is_connect() {
+            Self::Connect(error.to_string())
+        } else {
+            Self::Other(error.to_string())
+        }
+    }
+}
+
+/// "404 Not Found" for 404
+fn status_text(status: u16) -> String {
+    reqwest::StatusCode::from_u16(status)
+        .map(|code| code.to_string())
+        .unwrap_or_else(|_| status.to_string())
+}
+
+/// A GET request, or a form POST when `form` is set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// `application/x-www-form-urlencoded` fields to POST
+    pub form: Option<Vec<(String, String)>>,
+}
+
+impl HttpRequest {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            form: None,
+        }
+    }
+
+    /// POST `fields` as a urlencoded form (e.g. to an OAuth token endpoint)
+    pub fn post_form(url: impl Into<String>, fields: &[(&str, &str)]) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            form: Some(
+                fields
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Value of a form field, for POSTs
+    pub fn form_value(&self, name: &str) -> Option<&str> {
+        self.form
+            .as_deref()
+            .and_then(|form| find_header(form, name))
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send `user_agent` instead of the client's own, when set
+    pub fn user_agent(self, user_agent: Option<&str>) -> Self {
+        match user_agent {
+            Some(user_agent) => self.header("User-Agent", user_agent),
+            None => self,
+        }
+    }
+
+    /// Value of a request header (case-insensitive name)
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Status, headers and (streamed) body of a response
+pub struct HttpResponse {
+    pub status: u16,
+    /// Final URL after redirects
+    pub url: String,
+    headers: Vec<(String, String)>,
+    body: BodyStream,
+}
+
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("url", &self.url)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpResponse {
+    pub fn new(
+        status: u16,
+        url: impl Into<String>,
+        headers: Vec<(String, String)>,
+        body: BodyStream,
+    ) -> Self {
+        Self {
+            status,
+            url: url.into(),
+            headers,
+            body,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Value of a response header (case-insensitive name)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// `Content-Length`, when the server sent one
+    pub fn content_length(&self) -> Option<u64> {
+        self.header("content-length")?.trim().parse().ok()
+    }
+
+    /// The response itself when successful, an `HttpError::Status` otherwise
+    pub fn error_for_status(self) -> Result<Self, HttpError> {
+        if self.is_success() {
+            Ok(self)
+        } else {
+            Err(HttpError::Status {
+                status: self.status,
+                url: self.url,
+            })
+        }
+    }
+
+    /// The body in chunks, for writing large downloads as they arrive
+    pub fn bytes_stream(self) -> BodyStream {
+        self.body
+    }
+
+    /// The whole body
+    pub async fn bytes(self) -> Result<Vec<u8>, HttpError> {
+        let mut body = Vec::new();
+        let mut stream = self.body;
+        while let Some(chunk) = stream.next().await {
+            body.extend_from_slice(&chunk?);
+        }
+        Ok(body)
+    }
+
+    /// The whole body as text (invalid UTF-8 is replaced)
+    pub async fn text(self) -> Result<String, HttpError> {
+        let bytes = self.bytes().await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Sends HTTP requests: `ReqwestClient` for real, `MockHttpClient` in tests
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse, HttpError>;
+}
+
+/// `HttpClient` backed by a configured `reqwest::Client`
+#[derive(Clone)]
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+impl ReqwestClient {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpClient for ReqwestClient {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let mut builder = match &request.form {
+            Some(form) => self.client.post(&request.url).form(form),
+            None => self.client.get(&request.url),
+        };
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder.send().await?;
+
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        Ok(HttpResponse::new(
+            response.status().as_u16(),
+            response.url().to_string(),
+            headers,
+            Box::pin(
+                response
+                    .bytes_stream()
+                    .map(|chunk| chunk.map(|bytes| bytes.to_vec()).map_err(HttpError::from)),
+            ),
+        ))
+    }
+}
+
+/// Default size of the body chunks a mock response streams
+const MOCK_CHUNK_SIZE: usize = 8192;
+
+/// A canned response for [`MockHttpClient`]
+#[derive(Debug, Clone)]
+pub enum MockResponse {
+    Reply {
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+        /// Body delivered in chunks of this size
+        chunk_size: usize,
+        /// Fail the body stream after this many bytes (a dropped connection)
+        fail_after: Option<usize>,
+    },
+    /// The request fails before any response arrives
+    Error(HttpError),
+}
+
+impl MockResponse {
+    /// An empty response with `status`
+    pub fn status(status: u16) -> Self {
+        Self::Reply {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            chunk_size: MOCK_CHUNK_SIZE,
+            fail_after: None,
+        }
+    }
+
+    /// 200 OK with `body`
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self::status(200).with_body(body)
+    }
+
+    pub fn with_body(mut self, new_body: impl Into<Vec<u8>>) -> Self {
+        if let Self::Reply { body, .. } = &mut self {
+            *body = new_body.into();
+        }
+        self
+    }
+
+    pub fn timeout() -> Self {
+        Self::Error(HttpError::Timeout("mock timeout".to_string()))
+    }
+
+    pub fn connect_error() -> Self {
+        Self::Error(HttpError::Connect("mock connection refused".to_string()))
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        if let Self::Reply { headers, .. } = &mut self {
+            headers.push((name.into(), value.into()));
+        }
+        self
+    }
+
+    pub fn with_chunk_size(mut self, size: usize) -> Self {
+        if let Self::Reply { chunk_size, .. } = &mut self {
+            *chunk_size = size.max(1);
+        }
+        self
+    }
+
+    /// Break the connection after `bytes` of the body
+    pub fn fail_after(mut self, bytes: usize) -> Self {
+        if let Self::Reply { fail_after, .. } = &mut self {
+            *fail_after = Some(bytes);
+        }
+        self
+    }
+
+    fn into_response(self, url: &str) -> Result<HttpResponse, HttpError> {
+        let (status, mut headers, body, chunk_size, fail_after) = match self {
+            Self::Reply {
+                status,
+                headers,
+                body,
+                chunk_size,
+                fail_after,
+            } => (status, headers, body, chunk_size, fail_after),
+            Self::Error(error) => return Err(error),
+        };
+        if find_header(&headers, "content-length").is_none() {
+            headers.push(("content-length".to_string(), body.len().to_string()));
+        }
+
+        let sent = fail_after.map_or(body.len(), |limit| limit.min(body.len()));
+        let mut chunks: Vec<Result<Vec<u8>, HttpError>> = body[..sent]
+            .chunks(chunk_size)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect();
+        if fail_after.is_some() {
+            chunks.push(Err(HttpError::Other(
+                "mock connection reset while reading body".to_string(),
+            )));
+        }
+        Ok(HttpResponse::new(
+            status,
+            url,
+            headers,
+            Box::pin(stream::iter(chunks)),
+        ))
+    }
+}
+
+/// `HttpClient` answering from canned responses, for tests.
+///
+/// Responses are queued per URL and handed out in order; the last one keeps
+/// answering once the queue is down to it. Unknown URLs fail as unreachable.
+/// Every request is recorded for assertions.
+#[derive(Default)]
+pub struct MockHttpClient {
+    routes: Mutex<HashMap<String, VecDeque<MockResponse>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockHttpClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `response` for `url`
+    pub fn respond(&self, url: impl Into<String>, response: MockResponse) -> &Self {
+        self.routes
+            .lock()
+            .unwrap()
+            .entry(url.into())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// Answer `url` with `response` from now on, dropping anything queued
+    pub fn replace(&self, url: impl Into<String>, response: MockResponse) -> &Self {
+        self.routes
+            .lock()
+            .unwrap()
+            .insert(url.into(), VecDeque::from([response]));
+        self
+    }
+
+    /// Every request made so far, in order
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// How many requests were made for `url`
+    pub fn request_count(&self, url: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.url == url)
+            .count()
+    }
+
+    /// Shareable handle, as taken by `with_http_client`
+    pub fn shared(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let url = request.url.clone();
+        self.requests.lock().unwrap().push(request);
+
+        let response = {
+            let mut routes = self.routes.lock().unwrap();
+            let queue = routes.get_mut(&url);
+            match queue {
+                Some(queue) if queue.len() > 1 => queue.pop_front(),
+                Some(queue) => queue.front().cloned(),
+                None => None,
+            }
+        };
+        match response {
+            Some(response) => response.into_response(&url),
+            None => Err(HttpError::Connect(format!("no mock response for {url}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_replays_queued_responses_then_repeats_the_last() {
+        // Arrange
+        let mock = MockHttpClient::new();
+        mock.respond("https://a/feed", MockResponse::status(503))
+            .respond("https://a/feed", MockResponse::ok("<rss/>"));
+
+        // Act
+        let first = mock.get(HttpRequest::get("https://a/feed")).await.unwrap();
+        let second = mock.get(HttpRequest::get("https://a/feed")).await.unwrap();
+        let third = mock.get(HttpRequest::get("https://a/feed")).await.unwrap();
+        let unknown = mock.get(HttpRequest::get("https://b/feed")).await;
+
+        // Assert
+        assert_eq!(first.status, 503);
+        assert_eq!(second.text().await.unwrap(), "<rss/>");
+        assert!(third.is_success());
+        assert!(unknown.unwrap_err().is_connect());
+        assert_eq!(mock.request_count("https://a/feed"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_mock_body_streams_in_chunks_and_can_break_midway() {
+        let mock = MockHttpClient::new();
+        mock.respond(
+            "https://a/ep.mp3",
+            MockResponse::ok(vec![7u8; 10])
+                .with_chunk_size(4)
+                .with_header("Content-Type", "audio/mpeg"),
+        )
+        .respond(
+            "https://a/broken.mp3",
+            MockResponse::ok(vec![7u8; 10]).fail_after(6),
+        );
+
+        let response = mock
+            .get(HttpRequest::get("https://a/ep.mp3"))
+            .await
+            .unwrap();
+        assert_eq!(response.header("content-type"), Some("audio/mpeg"));
+        assert_eq!(response.content_length(), Some(10));
+        let chunks: Vec<usize> = response
+            .bytes_stream()
+            .map(|chunk| chunk.unwrap().len())
+            .collect()
+            .await;
+        assert_eq!(chunks, vec![4, 4, 2]);
+
+        let broken = mock
+            .get(HttpRequest::get("https://a/broken.mp3"))
+            .await
+            .unwrap();
+        assert!(broken.bytes().await.is_err());
+    }
+
+    #[test]
+    fn test_status_error_message_names_code_and_url() {
+        let error = HttpError::Status {
+            status: 404,
+            url: "https://a/feed".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "HTTP status 404 Not Found for url (https://a/feed)"
+        );
+        assert_eq!(error.status(), Some(404));
+    }
+}
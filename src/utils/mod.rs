@@ -2,6 +2,12 @@
 // This module will contain common functionality used across the application
 
 pub mod fs;
+pub mod http;
+pub mod network;
+pub mod notify;
+pub mod power;
+pub mod rate_limit;
 pub mod text;
 pub mod time;
+pub mod user_agent;
 pub mod validation;
@@ -0,0 +1,120 @@
+// Network metering and reachability detection
+//
+// Idle prefetching shouldn't spend a phone tether's data allowance.
+// `read_network_metering` asks NetworkManager (over `busctl`) whether the
+// primary connection is metered; where it can't tell (no NetworkManager,
+// other platforms) the result is `Unknown`, which callers treat as unmetered.
+//
+// After requests fail because the network can't be reached, the app goes
+// offline and checks with `is_reachable` whether a TCP connection to a host
+// it was trying to reach opens again.
+
+use std::process::Command;
+use std::time::Duration;
+
+/// Whether the current network connection is billed by usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkMetering {
+    Unmetered,
+    Metered,
+    Unknown,
+}
+
+impl NetworkMetering {
+    pub fn is_metered(self) -> bool {
+        self == Self::Metered
+    }
+}
+
+/// Host and port to probe for `url`: its host, on its port or the scheme's default
+pub fn probe_target(url: &str) -> Option<(String, u16)> {
+    let url = url::Url::parse(url).ok()?;
+    Some((url.host_str()?.to_string(), url.port_or_known_default()?))
+}
+
+/// Whether a TCP connection to `host:port` opens within `timeout`
+pub async fn is_reachable(host: &str, port: u16, timeout: Duration) -> bool {
+    matches!(
+        tokio::time::timeout(timeout, tokio::net::TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
+/// Metering of this machine's primary connection
+pub fn read_network_metering() -> NetworkMetering {
+    if !cfg!(target_os = "linux") {
+        return NetworkMetering::Unknown;
+    }
+    let output = Command::new("busctl")
+        .args([
+            "--system",
+            "get-property",
+            "org.freedesktop.NetworkManager",
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "Metered",
+        ])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            parse_nm_metered(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => NetworkMetering::Unknown,
+    }
+}
+
+/// Parse `busctl`'s reply for NetworkManager's `Metered` property (`u 4`).
+///
+/// NetworkManager reports 1 (yes) and 3 (guessed yes) for metered
+/// connections, 2 (no) and 4 (guessed no) for unmetered ones, 0 for unknown.
+pub fn parse_nm_metered(reply: &str) -> NetworkMetering {
+    let value = reply
+        .split_whitespace()
+        .nth(1)
+        .and_then(|v| v.parse::<u32>().ok());
+    match value {
+        Some(1 | 3) => NetworkMetering::Metered,
+        Some(2 | 4) => NetworkMetering::Unmetered,
+        _ => NetworkMetering::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nm_metered_values() {
+        assert_eq!(parse_nm_metered("u 1\n"), NetworkMetering::Metered);
+        assert_eq!(parse_nm_metered("u 3"), NetworkMetering::Metered);
+        assert_eq!(parse_nm_metered("u 2"), NetworkMetering::Unmetered);
+        assert_eq!(parse_nm_metered("u 4\n"), NetworkMetering::Unmetered);
+        assert_eq!(parse_nm_metered("u 0"), NetworkMetering::Unknown);
+        assert_eq!(parse_nm_metered(""), NetworkMetering::Unknown);
+        assert!(!NetworkMetering::Unknown.is_metered());
+    }
+
+    #[test]
+    fn test_probe_target_uses_scheme_default_port() {
+        assert_eq!(
+            probe_target("https://feeds.example.com/show.xml"),
+            Some(("feeds.example.com".to_string(), 443))
+        );
+        assert_eq!(
+            probe_target("http://127.0.0.1:8080/ep.mp3"),
+            Some(("127.0.0.1".to_string(), 8080))
+        );
+        assert_eq!(probe_target("not a url"), None);
+    }
+
+    #[tokio::test]
+    async fn test_is_reachable_needs_a_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let timeout = Duration::from_secs(2);
+
+        assert!(is_reachable("127.0.0.1", port, timeout).await);
+        drop(listener);
+        assert!(!is_reachable("127.0.0.1", port, timeout).await);
+    }
+}
@@ -0,0 +1,53 @@
+// Desktop notifications
+//
+// Best effort: `notify-send` on Linux and the BSDs, `osascript` on macOS,
+// nothing elsewhere. A missing tool or a failure is ignored — the in-app
+// message stays the record of what happened.
+
+use std::process::Stdio;
+
+use tokio::process::Command;
+
+/// Show a desktop notification and wait for the notifier to exit
+pub async fn send(summary: &str, body: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(summary)
+        ));
+        command
+    } else if cfg!(unix) {
+        let mut command = Command::new("notify-send");
+        command.args(["--app-name=podcast-tui", summary, body]);
+        command
+    } else {
+        return;
+    };
+
+    let _ = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await;
+}
+
+/// `text` as an AppleScript string literal
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applescript_string_escapes_quotes_and_backslashes() {
+        assert_eq!(
+            applescript_string(r#"Say "hi" \ bye"#),
+            r#""Say \"hi\" \\ bye""#
+        );
+    }
+}
@@ -0,0 +1,219 @@
+// Power source detection and the heavy-I/O pause gate
+//
+// Laptops on battery shouldn't burn charge on bulk downloads or device syncs.
+// `read_power_source` reports AC vs battery where the platform exposes it
+// (Linux sysfs today; other platforms report `Unknown` and are never paused),
+// and `IoGate` lets the download manager hold new work until it is reopened.
+
+use std::path::Path;
+
+use tokio::sync::watch;
+
+/// Where the machine is drawing power from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    Ac,
+    /// Discharging, with the remaining charge when the battery reports it
+    Battery {
+        percent: Option<u8>,
+    },
+    /// No battery information available (desktop, container, unsupported OS)
+    Unknown,
+}
+
+impl PowerSource {
+    /// True when on battery at or below `threshold` percent. A battery that
+    /// doesn't report its charge counts as low.
+    pub fn is_low_battery(self, threshold: u8) -> bool {
+        match self {
+            Self::Battery { percent } => percent.is_none_or(|p| p <= threshold),
+            Self::Ac | Self::Unknown => false,
+        }
+    }
+
+    /// Short label for messages ("battery 42%", "AC")
+    pub fn label(self) -> String {
+        match self {
+            Self::Ac => "AC".to_string(),
+            Self::Battery { percent: Some(p) } => format!("battery {}%", p),
+            Self::Battery { percent: None } => "battery".to_string(),
+            Self::Unknown => "unknown".to_string(),
+        }
+    }
+}
+
+/// Current power source of this machine
+pub fn read_power_source() -> PowerSource {
+    if cfg!(target_os = "linux") {
+        read_power_source_from(Path::new("/sys/class/power_supply"))
+    } else {
+        PowerSource::Unknown
+    }
+}
+
+/// Read a Linux `power_supply` sysfs tree rooted at `root`.
+///
+/// Any online mains/USB adapter means AC. Otherwise a discharging battery
+/// means battery power, using the lowest reported capacity when there are several.
+pub fn read_power_source_from(root: &Path) -> PowerSource {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return PowerSource::Unknown;
+    };
+
+    let read = |dir: &Path, name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .ok()
+            .map(|s| s.trim().to_string())
+    };
+
+    let mut on_ac = false;
+    let mut discharging = false;
+    let mut lowest: Option<u8> = None;
+
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        match read(&dir, "type").as_deref() {
+            Some("Mains") | Some("USB") => {
+                on_ac |= read(&dir, "online").as_deref() == Some("1");
+            }
+            Some("Battery") if read(&dir, "status").as_deref() == Some("Discharging") => {
+                discharging = true;
+                if let Some(capacity) = read(&dir, "capacity").and_then(|c| c.parse().ok()) {
+                    lowest = Some(lowest.map_or(capacity, |l: u8| l.min(capacity)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if on_ac {
+        PowerSource::Ac
+    } else if discharging {
+        PowerSource::Battery { percent: lowest }
+    } else {
+        PowerSource::Unknown
+    }
+}
+
+/// Shared open/closed switch for heavy I/O. Work calls [`wait_until_open`]
+/// before starting; closing the gate never interrupts work already running.
+///
+/// [`wait_until_open`]: IoGate::wait_until_open
+#[derive(Debug)]
+pub struct IoGate {
+    paused: watch::Sender<bool>,
+}
+
+impl IoGate {
+    pub fn new() -> Self {
+        Self {
+            paused: watch::Sender::new(false),
+        }
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.send_replace(paused);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Return immediately when open, otherwise wait until the gate reopens
+    pub async fn wait_until_open(&self) {
+        let mut rx = self.paused.subscribe();
+        // The sender lives in `self`, so the channel can't close while we wait
+        let _ = rx.wait_for(|paused| !paused).await;
+    }
+}
+
+impl Default for IoGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn supply(root: &Path, name: &str, files: &[(&str, &str)]) {
+        let dir = root.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file, value) in files {
+            std::fs::write(dir.join(file), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_discharging_battery_reports_capacity() {
+        // Arrange
+        let tmp = TempDir::new().unwrap();
+        supply(tmp.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+        supply(
+            tmp.path(),
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("capacity", "42"),
+            ],
+        );
+
+        // Act
+        let source = read_power_source_from(tmp.path());
+
+        // Assert
+        assert_eq!(source, PowerSource::Battery { percent: Some(42) });
+        assert!(source.is_low_battery(50));
+        assert!(!source.is_low_battery(30));
+    }
+
+    #[test]
+    fn test_online_adapter_means_ac() {
+        let tmp = TempDir::new().unwrap();
+        supply(tmp.path(), "AC", &[("type", "Mains"), ("online", "1")]);
+        supply(
+            tmp.path(),
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Charging"),
+                ("capacity", "10"),
+            ],
+        );
+
+        assert_eq!(read_power_source_from(tmp.path()), PowerSource::Ac);
+    }
+
+    #[test]
+    fn test_missing_sysfs_is_unknown_and_never_low() {
+        let source = read_power_source_from(Path::new("/nonexistent/power_supply"));
+        assert_eq!(source, PowerSource::Unknown);
+        assert!(!source.is_low_battery(100));
+    }
+
+    #[tokio::test]
+    async fn test_io_gate_holds_work_until_reopened() {
+        // Arrange
+        let gate = Arc::new(IoGate::new());
+        gate.set_paused(true);
+        let waiter = tokio::spawn({
+            let gate = gate.clone();
+            async move { gate.wait_until_open().await }
+        });
+
+        // Act / Assert — still waiting while paused
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished());
+
+        gate.set_paused(false);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should finish once the gate opens")
+            .unwrap();
+    }
+}
@@ -0,0 +1,344 @@
+// Per-host request spacing and politeness
+//
+// Bulk operations (OPML import, refresh-all) fan out over many feeds, and a
+// single publisher often hosts dozens of them. `HostRateLimiter` hands out
+// start slots per host so concurrent tasks never hit the same host more often
+// than the configured interval, while different hosts proceed in parallel.
+// `HostPoliteness` caps concurrent requests per host, honours `Retry-After`
+// and rations retries so one struggling CDN can't turn into a retry storm.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+use url::Url;
+
+/// Spaces out requests to the same host by at least `min_interval`
+#[derive(Debug)]
+pub struct HostRateLimiter {
+    min_interval: Duration,
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostRateLimiter {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserve the next start slot for `url`'s host and wait until it arrives.
+    /// URLs without a parseable host are never delayed.
+    pub async fn acquire(&self, url: &str) {
+        let Some(host) = host_key(url) else {
+            return;
+        };
+        if self.min_interval.is_zero() {
+            return;
+        }
+
+        let slot = {
+            let mut slots = self.next_slot.lock().await;
+            let now = Instant::now();
+            let slot = slots.get(&host).copied().unwrap_or(now).max(now);
+            slots.insert(host, slot + self.min_interval);
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Per-host concurrency cap, back-off and retry budget for feed requests
+#[derive(Debug)]
+pub struct HostPoliteness {
+    max_per_host: usize,
+    retry_budget: u32,
+    budget_window: Duration,
+    hosts: Mutex<HashMap<String, HostState>>,
+}
+
+#[derive(Debug)]
+struct HostState {
+    permits: Arc<Semaphore>,
+    /// Set from `Retry-After`; no request starts before it
+    not_before: Option<Instant>,
+    budget_started: Instant,
+    retries_used: u32,
+}
+
+/// Held for the duration of a request; frees the host slot when dropped
+#[derive(Debug)]
+pub struct HostPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl HostPoliteness {
+    /// At most `max_per_host` concurrent requests and `retry_budget` retries
+    /// per host in any `budget_window`
+    pub fn new(max_per_host: usize, retry_budget: u32, budget_window: Duration) -> Self {
+        Self {
+            max_per_host: max_per_host.max(1),
+            retry_budget,
+            budget_window,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for a free slot on `url`'s host and for any back-off it is under.
+    /// URLs without a parseable host are never delayed.
+    pub async fn acquire(&self, url: &str) -> HostPermit {
+        let Some(host) = host_key(url) else {
+            return HostPermit { _permit: None };
+        };
+        let semaphore = {
+            let mut hosts = self.hosts.lock().await;
+            self.state(&mut hosts, host.clone()).permits.clone()
+        };
+        let permit = semaphore.acquire_owned().await.ok();
+
+        // Re-read after getting the slot: a 429 may have arrived meanwhile
+        let not_before = self
+            .hosts
+            .lock()
+            .await
+            .get(&host)
+            .and_then(|state| state.not_before);
+        if let Some(not_before) = not_before {
+            tokio::time::sleep_until(not_before).await;
+        }
+        HostPermit { _permit: permit }
+    }
+
+    /// Hold every new request to `url`'s host back for `delay`
+    pub async fn defer(&self, url: &str, delay: Duration) {
+        let Some(host) = host_key(url) else {
+            return;
+        };
+        let mut hosts = self.hosts.lock().await;
+        let state = self.state(&mut hosts, host);
+        let until = Instant::now() + delay;
+        state.not_before = Some(state.not_before.map_or(until, |current| current.max(until)));
+    }
+
+    /// Take one retry from the host's budget; `false` once it is spent
+    pub async fn try_retry(&self, url: &str) -> bool {
+        let Some(host) = host_key(url) else {
+            return false;
+        };
+        let mut hosts = self.hosts.lock().await;
+        let window = self.budget_window;
+        let state = self.state(&mut hosts, host);
+        if state.budget_started.elapsed() >= window {
+            state.budget_started = Instant::now();
+            state.retries_used = 0;
+        }
+        if state.retries_used >= self.retry_budget {
+            return false;
+        }
+        state.retries_used += 1;
+        true
+    }
+
+    fn state<'a>(
+        &self,
+        hosts: &'a mut HashMap<String, HostState>,
+        host: String,
+    ) -> &'a mut HostState {
+        hosts.entry(host).or_insert_with(|| HostState {
+            permits: Arc::new(Semaphore::new(self.max_per_host)),
+            not_before: None,
+            budget_started: Instant::now(),
+            retries_used: 0,
+        })
+    }
+}
+
+/// Delay requested by a `Retry-After` header: delta-seconds or an HTTP date
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let delta = at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(delta.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// `base * 2^attempt`, scaled by a random factor in [0.5, 1.5) so retries from
+/// many tasks don't arrive in lockstep
+pub fn jittered_backoff(base: Duration, attempt: u32) -> Duration {
+    let exponential = base.saturating_mul(2u32.saturating_pow(attempt));
+    let jitter = (uuid::Uuid::new_v4().as_u128() % 1000) as f64 / 1000.0;
+    exponential.mul_f64(0.5 + jitter)
+}
+
+/// Reorder `items` round-robin across hosts (first host's first item, second
+/// host's first item, ...), keeping each host's items in their original order
+pub fn interleave_by_host<T>(items: Vec<T>, url: impl Fn(&T) -> &str) -> Vec<T> {
+    let mut order: Vec<Option<String>> = Vec::new();
+    let mut groups: HashMap<Option<String>, std::collections::VecDeque<T>> = HashMap::new();
+    for item in items {
+        let host = host_key(url(&item));
+        if !groups.contains_key(&host) {
+            order.push(host.clone());
+        }
+        groups.entry(host).or_default().push_back(item);
+    }
+
+    let mut interleaved = Vec::new();
+    loop {
+        let before = interleaved.len();
+        for host in &order {
+            if let Some(item) = groups.get_mut(host).and_then(|group| group.pop_front()) {
+                interleaved.push(item);
+            }
+        }
+        if interleaved.len() == before {
+            return interleaved;
+        }
+    }
+}
+
+/// Lower-cased host name used as the rate limiting key
+pub fn host_key(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_ascii_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_key_normalizes_case() {
+        assert_eq!(
+            host_key("https://Feeds.Example.COM/a.xml"),
+            Some("feeds.example.com".to_string())
+        );
+        assert_eq!(host_key("not a url"), None);
+    }
+
+    #[tokio::test]
+    async fn test_same_host_is_spaced_out() {
+        // Arrange
+        let limiter = HostRateLimiter::new(Duration::from_millis(50));
+        let start = Instant::now();
+
+        // Act
+        limiter.acquire("https://example.com/a.xml").await;
+        limiter.acquire("https://example.com/b.xml").await;
+        limiter.acquire("https://example.com/c.xml").await;
+
+        // Assert
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_different_hosts_are_not_delayed() {
+        // Arrange
+        let limiter = HostRateLimiter::new(Duration::from_secs(5));
+        let start = Instant::now();
+
+        // Act
+        limiter.acquire("https://one.example.com/feed").await;
+        limiter.acquire("https://two.example.com/feed").await;
+
+        // Assert
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_interleave_by_host_round_robins() {
+        let urls = vec![
+            "https://cdn.example.com/a",
+            "https://cdn.example.com/b",
+            "https://cdn.example.com/c",
+            "https://other.example.com/x",
+            "https://third.example.com/y",
+        ];
+
+        let interleaved = interleave_by_host(urls, |url| url);
+
+        assert_eq!(
+            interleaved,
+            vec![
+                "https://cdn.example.com/a",
+                "https://other.example.com/x",
+                "https://third.example.com/y",
+                "https://cdn.example.com/b",
+                "https://cdn.example.com/c",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_politeness_caps_concurrency_per_host() {
+        // Arrange
+        let politeness = HostPoliteness::new(2, 0, Duration::from_secs(60));
+        let _a = politeness.acquire("https://cdn.example.com/a.xml").await;
+        let _b = politeness.acquire("https://cdn.example.com/b.xml").await;
+
+        // Act
+        let third = tokio::time::timeout(
+            Duration::from_millis(50),
+            politeness.acquire("https://cdn.example.com/c.xml"),
+        )
+        .await;
+        let other_host = tokio::time::timeout(
+            Duration::from_millis(50),
+            politeness.acquire("https://other.example.com/feed"),
+        )
+        .await;
+
+        // Assert
+        assert!(third.is_err(), "third request to the host should wait");
+        assert!(other_host.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_politeness_defer_delays_next_request() {
+        let politeness = HostPoliteness::new(2, 0, Duration::from_secs(60));
+        let start = Instant::now();
+
+        politeness
+            .defer("https://cdn.example.com/a.xml", Duration::from_millis(80))
+            .await;
+        let _permit = politeness.acquire("https://cdn.example.com/b.xml").await;
+
+        assert!(start.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_is_per_host() {
+        let politeness = HostPoliteness::new(1, 2, Duration::from_secs(60));
+
+        assert!(politeness.try_retry("https://cdn.example.com/a.xml").await);
+        assert!(politeness.try_retry("https://cdn.example.com/b.xml").await);
+        assert!(!politeness.try_retry("https://cdn.example.com/c.xml").await);
+        assert!(politeness.try_retry("https://other.example.com/feed").await);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn test_jittered_backoff_stays_in_range() {
+        for attempt in 0..3 {
+            let delay = jittered_backoff(Duration::from_secs(1), attempt);
+            let nominal = Duration::from_secs(1 << attempt);
+            assert!(delay >= nominal / 2 && delay < nominal * 3 / 2);
+        }
+    }
+}
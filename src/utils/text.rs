@@ -24,10 +24,25 @@ pub fn strip_html(input: &str) -> String {
         return String::new();
     }
 
+    // Feeds that double-wrap CDATA leave the markers in the text
+    let input = input.replace("<![CDATA[", "").replace("]]>", "");
+
     // First, remove HTML tags using regex
     // This regex matches any HTML tag: <...>
     let re = Regex::new(r"<[^>]*>").expect("Invalid regex");
-    let without_tags = re.replace_all(input, "");
+    // Block-level tags separate words ("<p>a</p><p>b</p>" is "a b", not "ab")
+    let block = Regex::new(r"(?i)^</?(br|p|div|li|ul|ol|h[1-6]|tr|td|blockquote)\b")
+        .expect("Invalid regex");
+    let without_tags = re.replace_all(
+        &input,
+        |caps: &regex::Captures| {
+            if block.is_match(&caps[0]) {
+                " "
+            } else {
+                ""
+            }
+        },
+    );
 
     // Then decode HTML entities (after stripping tags)
     let decoded = decode_html_entities(&without_tags);
@@ -230,6 +245,12 @@ Articles/Webpages: <a href="https://www.nps.gov/yose/learn/historyculture/wright
         assert!(!result.contains("  ")); // No double spaces
     }
 
+    #[test]
+    fn test_strip_html_separates_block_elements_and_drops_cdata_markers() {
+        let html = "<![CDATA[<p>One &amp; two</p><ul><li>three</li><li>four</li></ul>]]>";
+        assert_eq!(strip_html(html), "One & two three four");
+    }
+
     #[test]
     fn test_libsyn_clean_text() {
         // Libsyn feeds often have clean text that should pass through unchanged
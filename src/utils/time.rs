@@ -1,4 +1,4 @@
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, Offset, Utc};
 
 /// Format duration in seconds to HH:MM:SS or MM:SS format
 pub fn format_duration(seconds: u32) -> String {
@@ -13,6 +13,21 @@ pub fn format_duration(seconds: u32) -> String {
     }
 }
 
+/// Format the time still to go in an episode, e.g. "23 min left" or
+/// "1 h 5 min left"; anything under a minute reads "<1 min left"
+pub fn format_time_left(seconds: u32) -> String {
+    let minutes = seconds / 60;
+    if minutes == 0 {
+        "<1 min left".to_string()
+    } else if minutes < 60 {
+        format!("{} min left", minutes)
+    } else if minutes.is_multiple_of(60) {
+        format!("{} h left", minutes / 60)
+    } else {
+        format!("{} h {} min left", minutes / 60, minutes % 60)
+    }
+}
+
 /// Parse duration string (HH:MM:SS or MM:SS) to seconds
 pub fn parse_duration(duration_str: &str) -> Option<u32> {
     let parts: Vec<&str> = duration_str.split(':').collect();
@@ -62,6 +77,105 @@ pub fn format_relative_time(datetime: &DateTime<Utc>) -> String {
     time_ago(*datetime)
 }
 
+/// Zone publication dates are shown in (`ui.timezone`).
+///
+/// Dates are stored and sorted in UTC; this only decides which wall clock
+/// they are read off when displayed or grouped by day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    /// The system's local zone
+    #[default]
+    Local,
+    Utc,
+    /// The zone the feed wrote the date in, else local
+    Original,
+    /// A fixed offset such as `+05:30`
+    Fixed(FixedOffset),
+}
+
+impl DisplayZone {
+    /// Parse `local`, `utc`, `original` or an offset (`+02:00`, `-0530`, `+9`)
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_lowercase();
+        match input.as_str() {
+            "" | "local" => return Some(Self::Local),
+            "utc" | "gmt" | "z" => return Some(Self::Utc),
+            "original" | "feed" => return Some(Self::Original),
+            _ => {}
+        }
+        let sign = match input.chars().next()? {
+            '+' => 1,
+            '-' => -1,
+            _ => return None,
+        };
+        let digits = input[1..].replace(':', "");
+        if digits.is_empty() || digits.len() > 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let (hours, minutes) = if digits.len() > 2 {
+            digits.split_at(digits.len() - 2)
+        } else {
+            (digits.as_str(), "0")
+        };
+        let hours: i32 = hours.parse().ok()?;
+        let minutes: i32 = minutes.parse().ok()?;
+        if hours > 14 || minutes > 59 {
+            return None;
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).map(Self::Fixed)
+    }
+
+    /// Offset used for `at`; `original` is the feed's offset in seconds east of UTC
+    pub fn offset_at(&self, at: DateTime<Utc>, original: Option<i32>) -> FixedOffset {
+        let local = || at.with_timezone(&Local).offset().fix();
+        match self {
+            Self::Local => local(),
+            Self::Utc => Utc.fix(),
+            Self::Original => original
+                .and_then(FixedOffset::east_opt)
+                .unwrap_or_else(local),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+
+    /// `at` on this zone's wall clock
+    pub fn localize(&self, at: DateTime<Utc>, original: Option<i32>) -> DateTime<FixedOffset> {
+        at.with_timezone(&self.offset_at(at, original))
+    }
+
+    /// Calendar day `at` falls on in this zone
+    pub fn date(&self, at: DateTime<Utc>, original: Option<i32>) -> NaiveDate {
+        self.localize(at, original).date_naive()
+    }
+
+    /// "Today 21:40", "Yesterday 23:55", else the date — days are counted on
+    /// this zone's calendar, so late-evening episodes stay on their own day
+    pub fn day_label(
+        &self,
+        at: DateTime<Utc>,
+        original: Option<i32>,
+        now: DateTime<Utc>,
+    ) -> String {
+        let local = self.localize(at, original);
+        let days = self
+            .date(now, original)
+            .signed_duration_since(local.date_naive())
+            .num_days();
+        match days {
+            0 => local.format("Today %H:%M").to_string(),
+            1 => local.format("Yesterday %H:%M").to_string(),
+            _ => local.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// UTC offset as `+HH:MM`
+pub fn format_utc_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    format!("{}{:02}:{:02}", sign, seconds / 3600, seconds % 3600 / 60)
+}
+
 /// Parse a human-readable duration string into total hours.
 ///
 /// Supported formats: `12h` (hours), `7d` (days), `2w` (weeks), `1m` (months = 30 days)
@@ -143,6 +257,14 @@ mod tests {
         assert_eq!(format_duration(30), "0:30");
     }
 
+    #[test]
+    fn test_format_time_left() {
+        assert_eq!(format_time_left(30), "<1 min left");
+        assert_eq!(format_time_left(23 * 60 + 59), "23 min left");
+        assert_eq!(format_time_left(2 * 3600), "2 h left");
+        assert_eq!(format_time_left(3600 + 5 * 60), "1 h 5 min left");
+    }
+
     #[test]
     fn test_parse_duration() {
         assert_eq!(parse_duration("1:01"), Some(61));
@@ -208,6 +330,51 @@ mod tests {
         assert_eq!(parse_cleanup_duration(" 2w "), Some(336));
     }
 
+    #[test]
+    fn test_display_zone_parse() {
+        assert_eq!(DisplayZone::parse("local"), Some(DisplayZone::Local));
+        assert_eq!(DisplayZone::parse("UTC"), Some(DisplayZone::Utc));
+        assert_eq!(DisplayZone::parse("original"), Some(DisplayZone::Original));
+        let offset = |secs| Some(DisplayZone::Fixed(FixedOffset::east_opt(secs).unwrap()));
+        assert_eq!(DisplayZone::parse("+02:00"), offset(7200));
+        assert_eq!(DisplayZone::parse("-0530"), offset(-19800));
+        assert_eq!(DisplayZone::parse("+9"), offset(32400));
+        assert_eq!(DisplayZone::parse("Europe/Paris"), None);
+        assert_eq!(DisplayZone::parse("+25:00"), None);
+    }
+
+    #[test]
+    fn test_display_zone_groups_days_on_its_own_calendar() {
+        // 23:30 in New York is already the next day in UTC
+        let published = DateTime::parse_from_rfc2822("Tue, 13 Oct 2026 23:30:00 -0400")
+            .unwrap()
+            .with_timezone(&Utc);
+        let now = DateTime::parse_from_rfc3339("2026-10-14T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let new_york = DisplayZone::parse("-04:00").unwrap();
+
+        assert_eq!(
+            DisplayZone::Utc.day_label(published, Some(-14400), now),
+            "Today 03:30"
+        );
+        assert_eq!(
+            new_york.day_label(published, Some(-14400), now),
+            "Yesterday 23:30"
+        );
+        assert_eq!(
+            DisplayZone::Original.date(published, Some(-14400)),
+            NaiveDate::from_ymd_opt(2026, 10, 13).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_utc_offset() {
+        assert_eq!(format_utc_offset(7200), "+02:00");
+        assert_eq!(format_utc_offset(-19800), "-05:30");
+        assert_eq!(format_utc_offset(0), "+00:00");
+    }
+
     #[test]
     fn test_format_cleanup_duration() {
         assert_eq!(format_cleanup_duration(1), "1 hour");
@@ -0,0 +1,221 @@
+// User-Agent selection for feed fetches and episode downloads
+//
+// Some hosts block or throttle clients they don't recognise. A podcast can
+// override the User-Agent (a preset name or any custom string), and
+// `network.user_agent` / `network.spoof_user_agent` in the config set the
+// fallback for every other podcast. The winner is sent as a per-request
+// header; requests with no override keep the client's built-in agent.
+
+use std::fmt;
+
+/// Longest custom User-Agent accepted
+pub const MAX_USER_AGENT_LEN: usize = 512;
+
+/// Setting value that clears a podcast's override
+pub const RESET: &str = "default";
+
+/// Built-in User-Agents known to be accepted by common podcast hosts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAgentPreset {
+    /// podcast-tui's own agent (what is sent without any override)
+    PodcastTui,
+    /// A current desktop Chrome
+    Browser,
+    ApplePodcasts,
+    Overcast,
+    PocketCasts,
+    Spotify,
+}
+
+impl UserAgentPreset {
+    pub const ALL: [Self; 6] = [
+        Self::PodcastTui,
+        Self::Browser,
+        Self::ApplePodcasts,
+        Self::Overcast,
+        Self::PocketCasts,
+        Self::Spotify,
+    ];
+
+    /// Name used in settings and commands
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::PodcastTui => "podcast-tui",
+            Self::Browser => "browser",
+            Self::ApplePodcasts => "apple-podcasts",
+            Self::Overcast => "overcast",
+            Self::PocketCasts => "pocket-casts",
+            Self::Spotify => "spotify",
+        }
+    }
+
+    /// The User-Agent header sent for this preset
+    pub fn header_value(self) -> &'static str {
+        match self {
+            Self::PodcastTui => crate::constants::network::FEED_USER_AGENT,
+            Self::Browser => "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36",
+            Self::ApplePodcasts => "Podcasts/1.0 CFNetwork/1496.0.7 Darwin/23.5.0",
+            Self::Overcast => "Overcast/3.0 (+http://overcast.fm/; iOS podcast app)",
+            Self::PocketCasts => "PocketCasts/1.0 (Pocket Casts Feed Parser; +http://pocketcasts.com/)",
+            Self::Spotify => "Spotify/8.9.36 iOS/17.4.1 (iPhone15,2)",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A podcast's or the config's User-Agent setting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserAgentSetting {
+    Preset(UserAgentPreset),
+    Custom(String),
+}
+
+impl UserAgentSetting {
+    /// Parse a preset name or custom string; `None` for blank or `default`
+    pub fn parse(value: &str) -> Result<Option<Self>, String> {
+        let value = value.trim();
+        if value.is_empty() || value.eq_ignore_ascii_case(RESET) {
+            return Ok(None);
+        }
+        if let Some(preset) = UserAgentPreset::from_name(value) {
+            return Ok(Some(Self::Preset(preset)));
+        }
+        if value.len() > MAX_USER_AGENT_LEN {
+            return Err(format!(
+                "User agent is longer than {} characters",
+                MAX_USER_AGENT_LEN
+            ));
+        }
+        // Anything else would be rejected (or smuggle headers) on the wire
+        if !value.bytes().all(|b| b == b' ' || b.is_ascii_graphic()) {
+            return Err("User agent may only contain printable ASCII".to_string());
+        }
+        Ok(Some(Self::Custom(value.to_string())))
+    }
+
+    pub fn header_value(&self) -> &str {
+        match self {
+            Self::Preset(preset) => preset.header_value(),
+            Self::Custom(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for UserAgentSetting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Preset(preset) => f.write_str(preset.name()),
+            Self::Custom(value) => f.write_str(value),
+        }
+    }
+}
+
+/// The config-wide fallback, applied to podcasts without an override
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserAgentPolicy {
+    global: Option<UserAgentSetting>,
+    spoof: bool,
+}
+
+impl UserAgentPolicy {
+    /// Build from the `network` config section; an invalid `user_agent` is
+    /// ignored
+    pub fn from_config(config: &crate::config::NetworkConfig) -> Self {
+        Self {
+            global: config
+                .user_agent
+                .as_deref()
+                .and_then(|value| UserAgentSetting::parse(value).ok().flatten()),
+            spoof: config.spoof_user_agent,
+        }
+    }
+
+    /// User-Agent header for a request on behalf of a podcast whose own
+    /// setting is `podcast` (as stored). `None` keeps the client's agent.
+    pub fn resolve(&self, podcast: Option<&str>) -> Option<String> {
+        let podcast = podcast.and_then(|value| UserAgentSetting::parse(value).ok().flatten());
+        podcast
+            .or_else(|| self.global.clone())
+            .or_else(|| {
+                self.spoof
+                    .then_some(UserAgentSetting::Preset(UserAgentPreset::Browser))
+            })
+            .map(|setting| setting.header_value().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NetworkConfig;
+
+    #[test]
+    fn test_presets_round_trip_and_are_valid_headers() {
+        for preset in UserAgentPreset::ALL {
+            assert_eq!(UserAgentPreset::from_name(preset.name()), Some(preset));
+            assert!(
+                reqwest::header::HeaderValue::from_str(preset.header_value()).is_ok(),
+                "{} is not a valid header",
+                preset.name()
+            );
+            assert_eq!(
+                UserAgentSetting::parse(preset.name()),
+                Ok(Some(UserAgentSetting::Preset(preset)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_custom_and_reset() {
+        assert_eq!(UserAgentSetting::parse(" Default "), Ok(None));
+        assert_eq!(UserAgentSetting::parse(""), Ok(None));
+        assert_eq!(
+            UserAgentSetting::parse("MyPlayer/2.0"),
+            Ok(Some(UserAgentSetting::Custom("MyPlayer/2.0".to_string())))
+        );
+        assert!(UserAgentSetting::parse("evil\r\nX-Injected: 1").is_err());
+        assert!(UserAgentSetting::parse(&"a".repeat(MAX_USER_AGENT_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_prefers_podcast_then_config_then_spoofing() {
+        // Arrange
+        let plain = UserAgentPolicy::default();
+        let spoofing = UserAgentPolicy::from_config(&NetworkConfig {
+            user_agent: None,
+            spoof_user_agent: true,
+            ..Default::default()
+        });
+        let global = UserAgentPolicy::from_config(&NetworkConfig {
+            user_agent: Some("overcast".to_string()),
+            spoof_user_agent: true,
+            ..Default::default()
+        });
+
+        // Act & Assert
+        assert_eq!(plain.resolve(None), None);
+        assert_eq!(
+            spoofing.resolve(None).as_deref(),
+            Some(UserAgentPreset::Browser.header_value())
+        );
+        assert_eq!(
+            global.resolve(None).as_deref(),
+            Some(UserAgentPreset::Overcast.header_value())
+        );
+        assert_eq!(
+            global.resolve(Some("Custom/1.0")).as_deref(),
+            Some("Custom/1.0")
+        );
+        // A podcast can opt back into the built-in agent
+        assert_eq!(
+            spoofing.resolve(Some("podcast-tui")).as_deref(),
+            Some(crate::constants::network::FEED_USER_AGENT)
+        );
+    }
+}
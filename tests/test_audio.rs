@@ -145,6 +145,7 @@ async fn test_audio_manager_play_and_stop_lifecycle_with_external_player() {
         path: wav_path,
         episode_id: ep_id.clone(),
         podcast_id: pod_id.clone(),
+        start_at: None,
     });
 
     // Assert — PlaybackStarted event arrives
@@ -181,33 +182,28 @@ async fn test_audio_manager_play_and_stop_lifecycle_with_external_player() {
 }
 
 #[tokio::test]
-async fn test_audio_manager_play_error_fires_playback_error_event() {
-    // Arrange — use a non-existent player to trigger a spawn error
+async fn test_audio_manager_start_skips_a_missing_external_player() {
+    // Arrange — a configured player that can't be run
     let config = AudioConfig {
         external_player: Some("__nonexistent_player_abc123__".into()),
         ..Default::default()
     };
-    let (app_tx, mut app_rx) = mpsc::unbounded_channel::<AppEvent>();
-    let manager = AudioManager::new(&config, app_tx).expect("AudioManager should init");
+    let (app_tx, _app_rx) = mpsc::unbounded_channel::<AppEvent>();
 
-    let ep_id = EpisodeId::new();
-    let pod_id = PodcastId::new();
+    // Act — whether another backend is found depends on the machine
+    let (manager, diagnostics) = AudioManager::start(&config, app_tx);
 
-    // Act — Play with a non-existent player → should fire PlaybackError
-    let dir = tempfile::TempDir::new().expect("temp dir");
-    let fake_path = dir.path().join("fake.mp3");
-    manager.send(AudioCommand::Play {
-        path: fake_path,
-        episode_id: ep_id,
-        podcast_id: pod_id,
-    });
-
-    // Assert — PlaybackError event arrives
-    let event = wait_for_event(&mut app_rx, Duration::from_secs(2), "PlaybackError", |e| {
-        matches!(e, AppEvent::PlaybackError { .. })
-    })
-    .await;
-    assert!(matches!(event, AppEvent::PlaybackError { .. }));
+    // Assert — the missing player is reported, never selected
+    assert_eq!(
+        diagnostics.configured_player,
+        Some(("__nonexistent_player_abc123__".into(), false))
+    );
+    assert_eq!(manager.is_some(), diagnostics.selected.is_some());
+    assert!(diagnostics
+        .selected
+        .as_deref()
+        .is_none_or(|selected| !selected.contains("__nonexistent_player_abc123__")));
+    assert!(diagnostics.startup_warning().is_some());
 }
 
 #[tokio::test]
@@ -331,6 +327,7 @@ async fn test_full_playback_lifecycle_play_pause_resume_stop() {
         path: wav_path,
         episode_id: ep_id.clone(),
         podcast_id: pod_id.clone(),
+        start_at: None,
     });
     wait_for_event(
         &mut app_rx,
@@ -407,6 +404,7 @@ async fn test_full_playback_position_advances_and_resets() {
         path: wav_path,
         episode_id: ep_id,
         podcast_id: pod_id,
+        start_at: None,
     });
     wait_for_event(
         &mut app_rx,
@@ -0,0 +1,716 @@
+//! Integration tests for feed refresh, downloads and OPML import against a
+//! mocked HTTP layer
+//!
+//! Every request goes to a `MockHttpClient` with canned responses, so the
+//! refresh, retry, download and error paths run deterministically offline.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use podcast_tui::{
+    config::{DownloadConfig, NetworkConfig, OAuthProviderConfig},
+    download::{DownloadError, DownloadManager},
+    podcast::{
+        subscription::SubscriptionManager, DownloadQuality, DownloadState, FeedAuth, FeedError,
+    },
+    storage::{JsonStorage, SecretStore, Storage},
+    utils::{
+        http::{HttpError, MockHttpClient, MockResponse},
+        user_agent::{UserAgentPolicy, UserAgentPreset},
+    },
+};
+use tempfile::TempDir;
+
+const FEED_URL: &str = "https://feeds.example.com/show.xml";
+
+fn feed(episodes: &[(&str, &str)]) -> String {
+    let items: String = episodes
+        .iter()
+        .map(|(guid, title)| {
+            format!(
+                r#"<item>
+      <guid>{guid}</guid>
+      <title>{title}</title>
+      <pubDate>Mon, 02 Mar 2026 09:00:00 GMT</pubDate>
+      <enclosure url="https://cdn.example.com/{guid}.mp3" type="audio/mpeg" length="4"/>
+    </item>"#
+            )
+        })
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Mock Show</title>
+    <description>Served by the mock HTTP client</description>
+    {items}
+  </channel>
+</rss>"#
+    )
+}
+
+struct Harness {
+    _temp_dir: TempDir,
+    storage: Arc<JsonStorage>,
+    http: Arc<MockHttpClient>,
+    subscriptions: SubscriptionManager<JsonStorage>,
+    downloads: DownloadManager<JsonStorage>,
+}
+
+async fn harness() -> Result<Harness> {
+    let temp_dir = TempDir::new()?;
+    let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+    storage.initialize().await?;
+    let http = MockHttpClient::new().shared();
+
+    let subscriptions = SubscriptionManager::new(storage.clone()).with_http_client(http.clone());
+    let downloads = DownloadManager::new(
+        storage.clone(),
+        temp_dir.path().join("downloads"),
+        DownloadConfig::default(),
+    )?
+    .with_http_client(http.clone());
+
+    Ok(Harness {
+        _temp_dir: temp_dir,
+        storage,
+        http,
+        subscriptions,
+        downloads,
+    })
+}
+
+#[tokio::test]
+async fn test_refresh_picks_up_new_episodes_from_the_feed() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+
+    // Act
+    h.http.replace(
+        FEED_URL,
+        MockResponse::ok(feed(&[("ep2", "Episode 2"), ("ep1", "Episode 1")])),
+    );
+    let new_episodes = h.subscriptions.refresh_feed(&podcast.id).await?;
+
+    // Assert
+    assert_eq!(podcast.title, "Mock Show");
+    assert_eq!(new_episodes.len(), 1);
+    assert_eq!(new_episodes[0].title, "Episode 2");
+    assert_eq!(h.storage.load_episodes(&podcast.id).await?.len(), 2);
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_transient_server_errors_are_retried() -> Result<()> {
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::status(503))
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+
+    h.subscriptions.subscribe(FEED_URL).await?;
+
+    // One 503, then the parse and the episode fetch
+    assert_eq!(h.http.request_count(FEED_URL), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_missing_feed_fails_without_retrying() -> Result<()> {
+    let h = harness().await?;
+    h.http.respond(FEED_URL, MockResponse::status(404));
+
+    let error = h.subscriptions.subscribe(FEED_URL).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        podcast_tui::podcast::subscription::SubscriptionError::Feed(FeedError::Network(
+            HttpError::Status { status: 404, .. }
+        ))
+    ));
+    assert!(!error.is_network());
+    assert_eq!(h.http.request_count(FEED_URL), 1);
+    Ok(())
+}
+
+// Connection failures are retried with back-off; the paused clock skips it
+#[tokio::test(start_paused = true)]
+async fn test_unreachable_feed_counts_as_offline() -> Result<()> {
+    let h = harness().await?;
+    h.http.respond(FEED_URL, MockResponse::connect_error());
+
+    let error = h.subscriptions.subscribe(FEED_URL).await.unwrap_err();
+
+    assert!(error.is_network());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_interrupted_download_fails_then_succeeds_on_retry() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    let audio = vec![0xFFu8; 64 * 1024];
+    h.http
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(audio.clone())
+                .with_header("Content-Type", "audio/mpeg")
+                .fail_after(10_000),
+        )
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(audio.clone()).with_header("Content-Type", "audio/mpeg"),
+        );
+
+    // Act
+    let interrupted = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let retried = h.downloads.download_episode(&podcast.id, &episode.id).await;
+
+    // Assert
+    assert!(matches!(interrupted, Err(DownloadError::Http(_))));
+    assert!(retried.is_ok(), "retry failed: {retried:?}");
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    assert_eq!(stored.status.download, DownloadState::Downloaded);
+    let path = stored.local_path.expect("downloaded file path");
+    // Tagging adds an ID3 header in front of the audio
+    assert!(std::fs::read(path)?.ends_with(&audio));
+    Ok(())
+}
+
+// Same title and date give the same filename; each download must still get its own file
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_same_titled_episodes_downloading_together_get_their_own_files() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http.respond(
+        FEED_URL,
+        MockResponse::ok(feed(&[("ep1", "Rerun"), ("ep2", "Rerun")])),
+    );
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episodes = h.storage.load_episodes(&podcast.id).await?;
+    let (first, second) = (&episodes[0], &episodes[1]);
+    let first_audio = vec![0x11u8; 256 * 1024];
+    let second_audio = vec![0x22u8; 256 * 1024];
+    for (episode, audio) in [(first, &first_audio), (second, &second_audio)] {
+        h.http.respond(
+            &episode.audio_url,
+            MockResponse::ok(audio.clone())
+                .with_header("Content-Type", "audio/mpeg")
+                .with_chunk_size(4096),
+        );
+    }
+
+    // Act
+    let (a, b) = tokio::join!(
+        h.downloads.download_episode(&podcast.id, &first.id),
+        h.downloads.download_episode(&podcast.id, &second.id),
+    );
+
+    // Assert
+    assert!(a.is_ok() && b.is_ok(), "downloads failed: {a:?} {b:?}");
+    let first_path = h
+        .storage
+        .load_episode(&podcast.id, &first.id)
+        .await?
+        .local_path
+        .expect("first download path");
+    let second_path = h
+        .storage
+        .load_episode(&podcast.id, &second.id)
+        .await?
+        .local_path
+        .expect("second download path");
+    assert_ne!(first_path, second_path);
+    // Tagging adds an ID3 header in front of the audio
+    assert!(std::fs::read(first_path)?.ends_with(&first_audio));
+    assert!(std::fs::read(second_path)?.ends_with(&second_audio));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_interrupted_download_resumes_with_range_request() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    let audio: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    h.http
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(audio.clone())
+                .with_header("Content-Type", "audio/mpeg")
+                .with_header("ETag", "\"v1\"")
+                .fail_after(10_000),
+        )
+        .respond(
+            &episode.audio_url,
+            MockResponse::status(206)
+                .with_body(audio[10_000..].to_vec())
+                .with_header("Content-Type", "audio/mpeg")
+                .with_header("ETag", "\"v1\"")
+                .with_header(
+                    "Content-Range",
+                    format!("bytes 10000-{}/{}", audio.len() - 1, audio.len()),
+                ),
+        );
+
+    // Act
+    let interrupted = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let resumed = h.downloads.download_episode(&podcast.id, &episode.id).await;
+
+    // Assert — only the missing bytes were asked for
+    assert!(interrupted.is_err());
+    assert!(resumed.is_ok(), "resume failed: {resumed:?}");
+    let requests: Vec<_> = h
+        .http
+        .requests()
+        .into_iter()
+        .filter(|request| request.url == episode.audio_url)
+        .collect();
+    assert_eq!(requests[0].header_value("Range"), None);
+    assert_eq!(requests[1].header_value("Range"), Some("bytes=10000-"));
+    assert_eq!(requests[1].header_value("If-Range"), Some("\"v1\""));
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    let path = stored.local_path.expect("downloaded file path");
+    assert!(std::fs::read(&path)?.ends_with(&audio));
+    assert!(partial_files(&h).is_empty());
+    Ok(())
+}
+
+/// `.part` files and their sources left in the downloads directory
+fn partial_files(h: &Harness) -> Vec<std::path::PathBuf> {
+    let downloads = h._temp_dir.path().join("downloads");
+    let Ok(folders) = std::fs::read_dir(downloads) else {
+        return Vec::new();
+    };
+    folders
+        .flatten()
+        .filter_map(|folder| std::fs::read_dir(folder.path()).ok())
+        .flat_map(|files| files.flatten().map(|file| file.path()))
+        .filter(|path| {
+            let name = path.to_string_lossy();
+            name.ends_with(".part") || name.ends_with(".part.json")
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_changed_audio_restarts_an_interrupted_download() -> Result<()> {
+    // Arrange — the enclosure is re-uploaded between the two attempts, so
+    // the server answers the guarded range request with the whole new file
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    let old_audio: Vec<u8> = vec![1; 32 * 1024];
+    let new_audio: Vec<u8> = vec![2; 24 * 1024];
+    h.http
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(old_audio)
+                .with_header("Content-Type", "audio/mpeg")
+                .with_header("Last-Modified", "Mon, 02 Mar 2026 09:00:00 GMT")
+                .fail_after(10_000),
+        )
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(new_audio.clone()).with_header("Content-Type", "audio/mpeg"),
+        );
+
+    // Act
+    let interrupted = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let restarted = h.downloads.download_episode(&podcast.id, &episode.id).await;
+
+    // Assert — none of the old bytes were kept
+    assert!(interrupted.is_err());
+    assert!(restarted.is_ok(), "restart failed: {restarted:?}");
+    let requests: Vec<_> = h
+        .http
+        .requests()
+        .into_iter()
+        .filter(|request| request.url == episode.audio_url)
+        .collect();
+    assert_eq!(
+        requests[1].header_value("If-Range"),
+        Some("Mon, 02 Mar 2026 09:00:00 GMT")
+    );
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    let bytes = std::fs::read(stored.local_path.expect("downloaded file path"))?;
+    assert!(bytes.ends_with(&new_audio));
+    // Tagging adds an ID3 header in front, but no run of the old audio
+    assert!(!bytes.windows(64).any(|window| window == [1; 64]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_partial_without_validators_is_not_resumed() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    let audio: Vec<u8> = vec![7; 32 * 1024];
+    h.http
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(audio.clone())
+                .with_header("Content-Type", "audio/mpeg")
+                .fail_after(10_000),
+        )
+        .respond(
+            &episode.audio_url,
+            MockResponse::ok(audio).with_header("Content-Type", "audio/mpeg"),
+        );
+
+    // Act
+    let _ = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let retried = h.downloads.download_episode(&podcast.id, &episode.id).await;
+
+    // Assert — nothing to check the partial by, so it was fetched whole
+    assert!(retried.is_ok(), "retry failed: {retried:?}");
+    let requests: Vec<_> = h
+        .http
+        .requests()
+        .into_iter()
+        .filter(|request| request.url == episode.audio_url)
+        .collect();
+    assert_eq!(requests[1].header_value("Range"), None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clearing_an_episode_removes_its_partial_download() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    h.http.respond(
+        &episode.audio_url,
+        MockResponse::ok(vec![3; 32 * 1024])
+            .with_header("Content-Type", "audio/mpeg")
+            .with_header("ETag", "\"v1\"")
+            .fail_after(10_000),
+    );
+    let _ = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let left_behind = partial_files(&h).len();
+
+    // Act
+    h.downloads.delete_episode(&podcast.id, &episode.id).await?;
+
+    // Assert
+    assert_eq!(left_behind, 2);
+    assert!(partial_files(&h).is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_startup_cleanup_removes_partials_of_deleted_episodes() -> Result<()> {
+    // Arrange — a partial whose episode no longer exists, and a stray one
+    // with no source at all
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    h.http.respond(
+        &episode.audio_url,
+        MockResponse::ok(vec![3; 32 * 1024])
+            .with_header("Content-Type", "audio/mpeg")
+            .with_header("ETag", "\"v1\"")
+            .fail_after(10_000),
+    );
+    let _ = h.downloads.download_episode(&podcast.id, &episode.id).await;
+    let folder = partial_files(&h)[0].parent().unwrap().to_path_buf();
+    std::fs::write(folder.join("stray.mp3.part"), b"junk")?;
+    h.storage.delete_episode(&podcast.id, &episode.id).await?;
+
+    // Act
+    h.downloads.cleanup_stuck_downloads().await?;
+
+    // Assert
+    assert!(partial_files(&h).is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_html_error_page_is_not_saved_as_audio() -> Result<()> {
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    h.http.respond(
+        &episode.audio_url,
+        MockResponse::ok("<html>Gone</html>").with_header("Content-Type", "text/html"),
+    );
+
+    let result = h.downloads.download_episode(&podcast.id, &episode.id).await;
+
+    assert!(result.is_err());
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    assert_ne!(stored.status.download, DownloadState::Downloaded);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_flags_downloads_whose_audio_was_replaced() -> Result<()> {
+    // Arrange: a downloaded episode
+    let h = harness().await?;
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    h.http.respond(
+        &episode.audio_url,
+        MockResponse::ok(vec![0xFFu8; 1024]).with_header("Content-Type", "audio/mpeg"),
+    );
+    h.downloads
+        .download_episode(&podcast.id, &episode.id)
+        .await?;
+
+    // Act: the show re-uploads fixed audio under a new URL
+    let fixed = feed(&[("ep1", "Episode 1")]).replace("ep1.mp3", "ep1-fixed.mp3");
+    h.http.replace(FEED_URL, MockResponse::ok(fixed));
+    let new_episodes = h.subscriptions.refresh_feed(&podcast.id).await?;
+
+    // Assert — flagged, not mistaken for a new episode
+    assert!(new_episodes.is_empty());
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    assert_eq!(stored.status.download, DownloadState::Downloaded);
+    let update = stored.updated_enclosure.expect("flagged enclosure");
+    assert_eq!(update.url, "https://cdn.example.com/ep1-fixed.mp3");
+
+    assert!(
+        h.downloads
+            .prepare_redownload(&podcast.id, &episode.id)
+            .await?
+    );
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    assert_eq!(stored.audio_url, update.url);
+    assert_ne!(stored.status.download, DownloadState::Downloaded);
+    assert!(stored.updated_enclosure.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_low_quality_podcast_downloads_the_smallest_encoding() -> Result<()> {
+    // Arrange: the main enclosure plus a 32 kbps alternate
+    let h = harness().await?;
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+  <channel>
+    <title>Mock Show</title>
+    <item>
+      <guid>ep1</guid>
+      <title>Episode 1</title>
+      <enclosure url="https://cdn.example.com/ep1.mp3" type="audio/mpeg" length="40000000"/>
+      <podcast:alternateEnclosure type="audio/opus" length="9000000" bitrate="32000">
+        <podcast:source uri="https://cdn.example.com/ep1-low.opus"/>
+      </podcast:alternateEnclosure>
+    </item>
+  </channel>
+</rss>"#;
+    h.http.respond(FEED_URL, MockResponse::ok(content));
+    let mut podcast = h.subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    let low = "https://cdn.example.com/ep1-low.opus";
+    h.http.respond(
+        low,
+        MockResponse::ok(vec![0xFFu8; 1024]).with_header("Content-Type", "audio/opus"),
+    );
+
+    // Act
+    podcast.download_quality = Some(DownloadQuality::Low);
+    h.storage.save_podcast(&podcast).await?;
+    h.downloads
+        .download_episode(&podcast.id, &episode.id)
+        .await?;
+
+    // Assert
+    assert_eq!(episode.audio_url, "https://cdn.example.com/ep1.mp3");
+    assert_eq!(h.http.requests().last().unwrap().url, low);
+    let stored = h.storage.load_episode(&podcast.id, &episode.id).await?;
+    assert_eq!(stored.status.download, DownloadState::Downloaded);
+    let path = stored.local_path.unwrap();
+    assert_eq!(path.extension().unwrap(), "opus");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_podcast_user_agent_overrides_config_for_feed_and_download() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    let policy = UserAgentPolicy::from_config(&NetworkConfig {
+        user_agent: None,
+        spoof_user_agent: true,
+        ..Default::default()
+    });
+    let subscriptions = h.subscriptions.with_user_agent_policy(policy.clone());
+    let downloads = h.downloads.with_user_agent_policy(policy);
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    let mut podcast = subscriptions.subscribe(FEED_URL).await?;
+    let episode = h.storage.load_episodes(&podcast.id).await?.remove(0);
+    h.http.respond(
+        &episode.audio_url,
+        MockResponse::ok(vec![0xFFu8; 1024]).with_header("Content-Type", "audio/mpeg"),
+    );
+
+    // Act
+    podcast.user_agent = Some("overcast".to_string());
+    h.storage.save_podcast(&podcast).await?;
+    subscriptions.refresh_feed(&podcast.id).await?;
+    downloads.download_episode(&podcast.id, &episode.id).await?;
+
+    // Assert
+    let requests = h.http.requests();
+    let agent = |index: usize| requests[index].header_value("User-Agent");
+    let browser = Some(UserAgentPreset::Browser.header_value());
+    let overcast = Some(UserAgentPreset::Overcast.header_value());
+    assert_eq!(requests[0].url, FEED_URL);
+    assert_eq!(agent(0), browser, "subscribing uses the config's agent");
+    let last = requests.len() - 1;
+    assert_eq!(requests[last].url, episode.audio_url);
+    assert_eq!(agent(last), overcast);
+    assert_eq!(requests[last - 1].url, FEED_URL);
+    assert_eq!(
+        agent(last - 1),
+        overcast,
+        "refresh uses the podcast's agent"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_feeds_behind_oauth_provider_are_fetched_with_bearer_token() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    let token_url = "https://auth.example.com/token";
+    let secrets = SecretStore::new(h._temp_dir.path());
+    secrets.set("oauth:members", "refresh-1").await?;
+    let auth = FeedAuth::new(
+        vec![OAuthProviderConfig {
+            name: "members".to_string(),
+            hosts: vec!["feeds.example.com".to_string()],
+            client_id: "client-1".to_string(),
+            device_authorization_url: "https://auth.example.com/device".to_string(),
+            token_url: token_url.to_string(),
+            scope: None,
+        }],
+        secrets,
+        h.http.clone(),
+    );
+    let subscriptions = h.subscriptions.with_feed_auth(Arc::new(auth));
+    h.http.respond(
+        token_url,
+        MockResponse::ok(r#"{"access_token":"access-1","expires_in":3600}"#),
+    );
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+
+    // Act
+    subscriptions.subscribe(FEED_URL).await?;
+
+    // Assert
+    let feed_requests: Vec<_> = h
+        .http
+        .requests()
+        .into_iter()
+        .filter(|r| r.url == FEED_URL)
+        .collect();
+    assert!(!feed_requests.is_empty());
+    assert!(feed_requests
+        .iter()
+        .all(|r| r.header_value("Authorization") == Some("Bearer access-1")));
+    // The access token is reused until it expires
+    assert_eq!(h.http.request_count(token_url), 1);
+    Ok(())
+}
+
+// The dead feed's retries back off; the paused clock skips the waits
+#[tokio::test(start_paused = true)]
+async fn test_opml_import_from_url() -> Result<()> {
+    // Arrange
+    let h = harness().await?;
+    let opml_url = "https://example.com/subscriptions.opml";
+    h.http.respond(
+        opml_url,
+        MockResponse::ok(format!(
+            r#"<?xml version="1.0"?>
+<opml version="2.0">
+  <head><title>Mine</title></head>
+  <body>
+    <outline type="rss" text="Mock Show" xmlUrl="{FEED_URL}"/>
+    <outline type="rss" text="Dead Show" xmlUrl="https://gone.example.com/feed.xml"/>
+  </body>
+</opml>"#
+        )),
+    );
+    h.http
+        .respond(FEED_URL, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+
+    // Act
+    let (result, _log) = h.subscriptions.import_opml(opml_url, |_| {}).await?;
+
+    // Assert: the unknown host is unreachable in the mock
+    assert_eq!(result.total_feeds, 2);
+    assert_eq!(result.imported, 1);
+    assert_eq!(result.failed.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_opml_preview_marks_subscribed_and_imports_only_selected() -> Result<()> {
+    // Arrange — one feed already subscribed, two new ones
+    let h = harness().await?;
+    let second = "https://feeds.example.com/second.xml";
+    let third = "https://feeds.example.com/third.xml";
+    let opml_url = "https://example.com/friends.opml";
+    h.http.respond(
+        opml_url,
+        MockResponse::ok(format!(
+            r#"<?xml version="1.0"?>
+<opml version="2.0">
+  <body>
+    <outline type="rss" text="Mock Show" xmlUrl="{FEED_URL}"/>
+    <outline type="rss" text="Second Show" xmlUrl="{second}"/>
+    <outline type="rss" text="Third Show" xmlUrl="{third}"/>
+  </body>
+</opml>"#
+        )),
+    );
+    for url in [FEED_URL, second, third] {
+        h.http
+            .respond(url, MockResponse::ok(feed(&[("ep1", "Episode 1")])));
+    }
+    h.subscriptions.subscribe(FEED_URL).await?;
+
+    // Act
+    let preview = h.subscriptions.preview_opml(opml_url).await?;
+    let selected = [second.to_string()].into_iter().collect();
+    let (result, _log) = h
+        .subscriptions
+        .import_opml_selected(&preview, &selected, &Default::default(), |_| {})
+        .await?;
+
+    // Assert
+    let subscribed: Vec<bool> = preview.feeds.iter().map(|f| f.subscribed).collect();
+    assert_eq!(subscribed, vec![true, false, false]);
+    assert_eq!(result.imported, 1);
+    assert_eq!(result.skipped, 2);
+    assert!(h.subscriptions.is_subscribed(second).await);
+    assert!(!h.subscriptions.is_subscribed(third).await);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use podcast_tui::podcast::{Episode, EpisodeStatus, Podcast};
+use podcast_tui::podcast::{Episode, PlayState, Podcast};
 use podcast_tui::storage::{JsonStorage, PodcastId, Storage};
 use std::sync::Arc;
 use tempfile::TempDir;
@@ -64,7 +64,7 @@ async fn test_mark_played_persists_to_storage() {
         .await
         .expect("should reload episode");
     assert!(reloaded.is_played());
-    assert_eq!(reloaded.status, EpisodeStatus::Played);
+    assert_eq!(reloaded.status.play, PlayState::Played);
 }
 
 #[tokio::test]
@@ -98,7 +98,7 @@ async fn test_mark_unplayed_persists_to_storage() {
         .await
         .expect("should reload episode");
     assert!(!reloaded.is_played());
-    assert_ne!(reloaded.status, EpisodeStatus::Played);
+    assert_ne!(reloaded.status.play, PlayState::Played);
 }
 
 #[tokio::test]
@@ -3,7 +3,7 @@ use podcast_tui::download::DownloadManager;
 use podcast_tui::playlist::{
     auto_generator::TodayGenerator, manager::PlaylistManager, RefreshPolicy,
 };
-use podcast_tui::podcast::{Episode, EpisodeStatus, Podcast};
+use podcast_tui::podcast::{DownloadState, Episode, Podcast};
 use podcast_tui::storage::{EpisodeId, JsonStorage, PodcastId, Storage};
 use std::sync::Arc;
 use tempfile::TempDir;
@@ -92,7 +92,7 @@ async fn seed_downloaded_episode(
         published,
     );
     episode.id = episode_id.clone();
-    episode.status = EpisodeStatus::Downloaded;
+    episode.status.download = DownloadState::Downloaded;
     let file_path = setup.downloads_dir.join(format!("{title}.mp3"));
     fs::write(&file_path, b"integration-audio")
         .await
@@ -1,13 +1,16 @@
 //! Integration test for the unsubscribe feature that deletes downloaded episodes
 //!
-//! This test verifies that when unsubscribing from a podcast,
-//! all downloaded episodes are automatically deleted.
+//! This test verifies that unsubscribing from a podcast retains its data for the
+//! restore window, and that purging it deletes all downloaded episodes.
 
 use anyhow::Result;
 use podcast_tui::{
     config::DownloadConfig,
     download::DownloadManager,
-    podcast::{subscription::SubscriptionManager, Episode, EpisodeStatus, Podcast},
+    podcast::{
+        subscription::{SubscriptionManager, UnsubscribeMode},
+        DownloadState, Episode, Podcast,
+    },
     storage::{EpisodeId, JsonStorage, PodcastId, Storage},
 };
 use std::sync::Arc;
@@ -50,7 +53,7 @@ async fn test_unsubscribe_deletes_downloaded_episodes() -> Result<()> {
         chrono::Utc::now(),
     );
     episode1.id = episode1_id.clone();
-    episode1.status = EpisodeStatus::Downloaded;
+    episode1.status.download = DownloadState::Downloaded;
 
     let mut episode2 = Episode::new(
         podcast_id.clone(),
@@ -59,7 +62,7 @@ async fn test_unsubscribe_deletes_downloaded_episodes() -> Result<()> {
         chrono::Utc::now(),
     );
     episode2.id = episode2_id.clone();
-    episode2.status = EpisodeStatus::New; // Not downloaded
+    episode2.status.download = DownloadState::NotDownloaded;
 
     // Add episodes to podcast
     podcast.add_episode(episode1_id.clone());
@@ -85,14 +88,31 @@ async fn test_unsubscribe_deletes_downloaded_episodes() -> Result<()> {
 
     // Verify initial state
     let loaded_episode1 = storage.load_episode(&podcast_id, &episode1_id).await?;
-    assert_eq!(loaded_episode1.status, EpisodeStatus::Downloaded);
+    assert_eq!(loaded_episode1.status.download, DownloadState::Downloaded);
     assert!(loaded_episode1.local_path.as_ref().unwrap().exists());
 
     let loaded_episode2 = storage.load_episode(&podcast_id, &episode2_id).await?;
-    assert_eq!(loaded_episode2.status, EpisodeStatus::New);
+    assert_eq!(
+        loaded_episode2.status.download,
+        DownloadState::NotDownloaded
+    );
 
     // Unsubscribe from the podcast
-    subscription_manager.unsubscribe(&podcast_id).await?;
+    subscription_manager
+        .unsubscribe(&podcast_id, UnsubscribeMode::KeepAll)
+        .await?;
+
+    // Verify podcast is hidden but its data is retained for the restore window
+    assert!(subscription_manager.list_subscriptions().await?.is_empty());
+    assert!(storage.podcast_exists(&podcast_id).await?);
+    assert!(storage
+        .load_episode(&podcast_id, &episode1_id)
+        .await
+        .is_ok());
+    assert!(episode_file_path.exists());
+
+    // Purge the podcast once the restore window is over
+    subscription_manager.purge_podcast(&podcast_id).await?;
 
     // Verify podcast is deleted
     assert!(!storage.podcast_exists(&podcast_id).await?);
@@ -140,11 +160,105 @@ async fn test_unsubscribe_handles_missing_download_manager() -> Result<()> {
     // Verify podcast exists
     assert!(storage.podcast_exists(&podcast_id).await?);
 
-    // Unsubscribe should work even without download manager
-    subscription_manager.unsubscribe(&podcast_id).await?;
+    // Unsubscribe and purge should work even without download manager
+    subscription_manager
+        .unsubscribe(&podcast_id, UnsubscribeMode::KeepAll)
+        .await?;
+    subscription_manager.purge_podcast(&podcast_id).await?;
 
     // Verify podcast is deleted
     assert!(!storage.podcast_exists(&podcast_id).await?);
 
     Ok(())
 }
+
+/// A podcast with one downloaded episode; returns the episode and its file
+async fn downloaded_podcast(
+    storage: &JsonStorage,
+    downloads_dir: &std::path::Path,
+) -> Result<(PodcastId, EpisodeId, std::path::PathBuf)> {
+    let mut podcast = Podcast::new(
+        "Test Podcast".to_string(),
+        "https://example.com/feed.xml".to_string(),
+    );
+    let mut episode = Episode::new(
+        podcast.id.clone(),
+        "Episode 1".to_string(),
+        "https://example.com/episode1.mp3".to_string(),
+        chrono::Utc::now(),
+    );
+    let folder = downloads_dir.join("Test Podcast");
+    tokio::fs::create_dir_all(&folder).await?;
+    let file = folder.join("episode1.mp3");
+    tokio::fs::write(&file, b"fake audio data").await?;
+    episode.status.download = DownloadState::Downloaded;
+    episode.local_path = Some(file.clone());
+    podcast.add_episode(episode.id.clone());
+    storage.save_podcast(&podcast).await?;
+    storage.save_episode(&podcast.id, &episode).await?;
+    Ok((podcast.id, episode.id, file))
+}
+
+#[tokio::test]
+async fn test_unsubscribe_keeping_history_deletes_only_downloads() -> Result<()> {
+    // Arrange
+    let temp_dir = TempDir::new()?;
+    let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+    storage.initialize().await?;
+    let downloads_dir = temp_dir.path().join("downloads");
+    let download_manager = Arc::new(DownloadManager::new(
+        storage.clone(),
+        downloads_dir.clone(),
+        DownloadConfig::default(),
+    )?);
+    let subscription_manager =
+        SubscriptionManager::with_download_manager(storage.clone(), download_manager);
+    let (podcast_id, episode_id, file) = downloaded_podcast(&storage, &downloads_dir).await?;
+
+    // Act
+    subscription_manager
+        .unsubscribe(&podcast_id, UnsubscribeMode::KeepHistory)
+        .await?;
+
+    // Assert — the history can be restored, the file is gone
+    assert!(!file.exists());
+    let episode = storage.load_episode(&podcast_id, &episode_id).await?;
+    assert_eq!(episode.status.download, DownloadState::NotDownloaded);
+    assert_eq!(subscription_manager.list_removed_podcasts().await?.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unsubscribe_keeping_downloads_deletes_only_history() -> Result<()> {
+    // Arrange
+    let temp_dir = TempDir::new()?;
+    let storage = Arc::new(JsonStorage::with_data_dir(temp_dir.path().to_path_buf()));
+    storage.initialize().await?;
+    let downloads_dir = temp_dir.path().join("downloads");
+    let download_manager = Arc::new(DownloadManager::new(
+        storage.clone(),
+        downloads_dir.clone(),
+        DownloadConfig::default(),
+    )?);
+    let subscription_manager =
+        SubscriptionManager::with_download_manager(storage.clone(), download_manager);
+    let (podcast_id, episode_id, file) = downloaded_podcast(&storage, &downloads_dir).await?;
+
+    // Act
+    subscription_manager
+        .unsubscribe(&podcast_id, UnsubscribeMode::KeepDownloads)
+        .await?;
+
+    // Assert
+    assert!(file.exists());
+    assert!(!storage.podcast_exists(&podcast_id).await?);
+    assert!(storage
+        .load_episode(&podcast_id, &episode_id)
+        .await
+        .is_err());
+    assert!(subscription_manager
+        .list_removed_podcasts()
+        .await?
+        .is_empty());
+    Ok(())
+}